@@ -9,8 +9,12 @@ use crate::services::{
     document::ws_receiver::{make_document_ws_receiver, HttpDocumentCloudPersistence},
     folder::ws_receiver::{make_folder_ws_receiver, HttpFolderCloudPersistence},
     kv::revision_kv::RevisionKVPersistence,
+    object::ws_receiver::{make_object_ws_receiver, HttpObjectCloudPersistence},
+};
+use flowy_collaboration::{
+    server_board::ServerBoardManager, server_comment::ServerCommentManager, server_document::ServerDocumentManager,
+    server_folder::ServerFolderManager, server_grid::ServerGridManager,
 };
-use flowy_collaboration::{server_document::ServerDocumentManager, server_folder::ServerFolderManager};
 use lib_ws::WSChannel;
 use sqlx::PgPool;
 use std::sync::Arc;
@@ -22,6 +26,9 @@ pub struct AppContext {
     pub ws_receivers: Data<WebSocketReceivers>,
     pub document_manager: Data<Arc<ServerDocumentManager>>,
     pub folder_manager: Data<Arc<ServerFolderManager>>,
+    pub grid_manager: Data<Arc<ServerGridManager>>,
+    pub board_manager: Data<Arc<ServerBoardManager>>,
+    pub comment_manager: Data<Arc<ServerCommentManager>>,
 }
 
 impl AppContext {
@@ -31,10 +38,16 @@ impl AppContext {
 
         let document_store = make_document_kv_store(pg_pool.clone());
         let folder_store = make_folder_kv_store(pg_pool.clone());
+        let grid_store = make_grid_kv_store(pg_pool.clone());
+        let board_store = make_board_kv_store(pg_pool.clone());
+        let comment_store = make_comment_kv_store(pg_pool.clone());
         let flowy_persistence = Arc::new(FlowyPersistence {
             pg_pool,
             document_store,
             folder_store,
+            grid_store,
+            board_store,
+            comment_store,
         });
 
         let document_persistence = Arc::new(HttpDocumentCloudPersistence(flowy_persistence.document_kv_store()));
@@ -47,18 +60,42 @@ impl AppContext {
         let folder_ws_receiver = make_folder_ws_receiver(flowy_persistence.clone(), folder_manager.clone());
         ws_receivers.set(WSChannel::Folder, folder_ws_receiver);
 
+        let grid_persistence = Arc::new(HttpObjectCloudPersistence(flowy_persistence.grid_kv_store()));
+        let grid_manager = Arc::new(ServerGridManager::new(grid_persistence));
+        let grid_ws_receiver =
+            make_object_ws_receiver(flowy_persistence.clone(), grid_manager.clone(), WSChannel::Grid);
+        ws_receivers.set(WSChannel::Grid, grid_ws_receiver);
+
+        let board_persistence = Arc::new(HttpObjectCloudPersistence(flowy_persistence.board_kv_store()));
+        let board_manager = Arc::new(ServerBoardManager::new(board_persistence));
+        let board_ws_receiver =
+            make_object_ws_receiver(flowy_persistence.clone(), board_manager.clone(), WSChannel::Board);
+        ws_receivers.set(WSChannel::Board, board_ws_receiver);
+
+        let comment_persistence = Arc::new(HttpObjectCloudPersistence(flowy_persistence.comment_kv_store()));
+        let comment_manager = Arc::new(ServerCommentManager::new(comment_persistence));
+        let comment_ws_receiver =
+            make_object_ws_receiver(flowy_persistence.clone(), comment_manager.clone(), WSChannel::Comment);
+        ws_receivers.set(WSChannel::Comment, comment_ws_receiver);
+
         AppContext {
             ws_server,
             persistence: Data::new(flowy_persistence),
             ws_receivers: Data::new(ws_receivers),
             document_manager: Data::new(document_manager),
             folder_manager: Data::new(folder_manager),
+            grid_manager: Data::new(grid_manager),
+            board_manager: Data::new(board_manager),
+            comment_manager: Data::new(comment_manager),
         }
     }
 }
 
 pub type DocumentRevisionKV = RevisionKVPersistence;
 pub type FolderRevisionKV = RevisionKVPersistence;
+pub type GridRevisionKV = RevisionKVPersistence;
+pub type BoardRevisionKV = RevisionKVPersistence;
+pub type CommentRevisionKV = RevisionKVPersistence;
 
 fn make_document_kv_store(pg_pool: PgPool) -> Arc<DocumentRevisionKV> {
     let kv_impl = Arc::new(PostgresKV { pg_pool });
@@ -70,11 +107,29 @@ fn make_folder_kv_store(pg_pool: PgPool) -> Arc<FolderRevisionKV> {
     Arc::new(FolderRevisionKV::new(kv_impl))
 }
 
+fn make_grid_kv_store(pg_pool: PgPool) -> Arc<GridRevisionKV> {
+    let kv_impl = Arc::new(PostgresKV { pg_pool });
+    Arc::new(GridRevisionKV::new(kv_impl))
+}
+
+fn make_board_kv_store(pg_pool: PgPool) -> Arc<BoardRevisionKV> {
+    let kv_impl = Arc::new(PostgresKV { pg_pool });
+    Arc::new(BoardRevisionKV::new(kv_impl))
+}
+
+fn make_comment_kv_store(pg_pool: PgPool) -> Arc<CommentRevisionKV> {
+    let kv_impl = Arc::new(PostgresKV { pg_pool });
+    Arc::new(CommentRevisionKV::new(kv_impl))
+}
+
 #[derive(Clone)]
 pub struct FlowyPersistence {
     pg_pool: PgPool,
     document_store: Arc<DocumentRevisionKV>,
     folder_store: Arc<FolderRevisionKV>,
+    grid_store: Arc<GridRevisionKV>,
+    board_store: Arc<BoardRevisionKV>,
+    comment_store: Arc<CommentRevisionKV>,
 }
 
 impl FlowyPersistence {
@@ -89,4 +144,16 @@ impl FlowyPersistence {
     pub fn folder_kv_store(&self) -> Arc<FolderRevisionKV> {
         self.folder_store.clone()
     }
+
+    pub fn grid_kv_store(&self) -> Arc<GridRevisionKV> {
+        self.grid_store.clone()
+    }
+
+    pub fn board_kv_store(&self) -> Arc<BoardRevisionKV> {
+        self.board_store.clone()
+    }
+
+    pub fn comment_kv_store(&self) -> Arc<CommentRevisionKV> {
+        self.comment_store.clone()
+    }
 }
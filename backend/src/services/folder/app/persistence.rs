@@ -39,13 +39,20 @@ impl NewAppSqlBuilder {
         let app_id = check_app_id(app.id)?;
         let create_time = DateTime::<Utc>::from_utc(NaiveDateTime::from_timestamp(app.create_time, 0), Utc);
         let modified_time = DateTime::<Utc>::from_utc(NaiveDateTime::from_timestamp(app.modified_time, 0), Utc);
+        let color_style = match app.has_color_style() {
+            false => default_color_style(),
+            true => app
+                .get_color_style()
+                .write_to_bytes()
+                .unwrap_or_else(|_| default_color_style()),
+        };
 
         let table = AppTable {
             id: app_id,
             workspace_id: app.workspace_id,
             name: app.name,
             description: app.desc,
-            color_style: default_color_style(),
+            color_style,
             last_view_id: "".to_string(),
             modified_time,
             create_time,
@@ -134,6 +141,9 @@ impl std::convert::From<AppTable> for AppPB {
         app.set_belongings(RepeatedViewPB::default());
         app.set_modified_time(table.modified_time.timestamp());
         app.set_create_time(table.create_time.timestamp());
+        if let Ok(color_style) = ColorStylePB::parse_from_bytes(&table.color_style) {
+            app.set_color_style(color_style);
+        }
 
         app
     }
@@ -1,4 +1,6 @@
-use crate::services::folder::{app::persistence::AppTable, view::persistence::ViewTable};
+use crate::services::folder::{
+    app::persistence::AppTable, view::persistence::ViewTable, workspace::persistence::WorkspaceTable,
+};
 use flowy_core_data_model::protobuf::{Trash, TrashType};
 
 pub(crate) const TRASH_TABLE: &str = "trash_table";
@@ -19,6 +21,8 @@ impl std::convert::From<AppTable> for Trash {
             modified_time: table.modified_time.timestamp(),
             create_time: table.create_time.timestamp(),
             ty: TrashType::App,
+            deleted_time: 0,
+            deleted_by: "".to_owned(),
             unknown_fields: Default::default(),
             cached_size: Default::default(),
         }
@@ -33,6 +37,24 @@ impl std::convert::From<ViewTable> for Trash {
             modified_time: table.modified_time.timestamp(),
             create_time: table.create_time.timestamp(),
             ty: TrashType::View,
+            deleted_time: 0,
+            deleted_by: "".to_owned(),
+            unknown_fields: Default::default(),
+            cached_size: Default::default(),
+        }
+    }
+}
+
+impl std::convert::From<WorkspaceTable> for Trash {
+    fn from(table: WorkspaceTable) -> Self {
+        Trash {
+            id: table.id.to_string(),
+            name: table.name,
+            modified_time: table.modified_time.timestamp(),
+            create_time: table.create_time.timestamp(),
+            ty: TrashType::Workspace,
+            deleted_time: 0,
+            deleted_by: "".to_owned(),
             unknown_fields: Default::default(),
             cached_size: Default::default(),
         }
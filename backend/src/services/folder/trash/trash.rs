@@ -5,6 +5,7 @@ use crate::{
         app::controller::{delete_app, read_app_table},
         trash::persistence::{TrashTable, TRASH_TABLE},
         view::{delete_view, read_view_table},
+        workspace::{delete_workspace, read_workspace_table},
     },
     util::sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
 };
@@ -122,6 +123,9 @@ async fn delete_trash_associate_targets(
                 TrashType::App => {
                     let _ = delete_app(transaction as &mut DBTransaction<'_>, id).await;
                 }
+                TrashType::Workspace => {
+                    let _ = delete_workspace(transaction as &mut DBTransaction<'_>, id).await;
+                }
             },
         }
     }
@@ -169,6 +173,9 @@ pub(crate) async fn read_trash(
                 TrashType::App => {
                     trash.push(read_app_table(table.id, transaction).await?.into());
                 }
+                TrashType::Workspace => {
+                    trash.push(read_workspace_table(table.id, transaction).await?.into());
+                }
             },
         }
     }
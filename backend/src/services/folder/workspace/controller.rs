@@ -69,6 +69,23 @@ pub(crate) async fn delete_workspace(
     Ok(())
 }
 
+pub(crate) async fn read_workspace_table(
+    workspace_id: Uuid,
+    transaction: &mut DBTransaction<'_>,
+) -> Result<WorkspaceTable, ServerError> {
+    let (sql, args) = SqlBuilder::select(WORKSPACE_TABLE)
+        .add_field("*")
+        .and_where_eq("id", workspace_id)
+        .build()?;
+
+    let table = sqlx::query_as_with::<Postgres, WorkspaceTable, PgArguments>(&sql, args)
+        .fetch_one(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(table)
+}
+
 #[tracing::instrument(skip(transaction, logged_user), err)]
 pub async fn read_workspaces(
     transaction: &mut DBTransaction<'_>,
@@ -2,5 +2,6 @@ pub mod document;
 pub mod folder;
 pub mod kv;
 pub(crate) mod log;
+pub mod object;
 pub mod user;
 pub mod web_socket;
@@ -0,0 +1,2 @@
+pub(crate) mod ws_actor;
+pub(crate) mod ws_receiver;
@@ -0,0 +1,163 @@
+use crate::{
+    context::FlowyPersistence,
+    services::web_socket::{entities::Socket, revision_data_to_ws_message, WSClientData, WSUser, WebSocketMessage},
+    util::serde_ext::parse_from_bytes,
+};
+use actix_rt::task::spawn_blocking;
+use async_stream::stream;
+use backend_service::errors::{internal_error, Result};
+
+use flowy_collaboration::{
+    protobuf::{
+        ClientRevisionWSData as ClientRevisionWSDataPB, ClientRevisionWSDataType as ClientRevisionWSDataTypePB,
+    },
+    server_object::{ServerObjectManager, ServerSyncObject},
+    synchronizer::{RevisionSyncResponse, RevisionUser},
+};
+use futures::stream::StreamExt;
+use lib_ws::WSChannel;
+use std::sync::Arc;
+use tokio::sync::{mpsc, oneshot};
+
+pub enum ObjectWSActorMessage {
+    ClientData {
+        client_data: WSClientData,
+        persistence: Arc<FlowyPersistence>,
+        ret: oneshot::Sender<Result<()>>,
+    },
+}
+
+// Handles the messages of the objects that are synced by the ServerObjectManager, e.g. the grid,
+// the board and the comment threads. Each type of object has its own actor and channel.
+pub struct ObjectWebSocketActor<T: ServerSyncObject> {
+    actor_msg_receiver: Option<mpsc::Receiver<ObjectWSActorMessage>>,
+    object_manager: Arc<ServerObjectManager<T>>,
+    channel: WSChannel,
+}
+
+impl<T: ServerSyncObject> ObjectWebSocketActor<T> {
+    pub fn new(
+        receiver: mpsc::Receiver<ObjectWSActorMessage>,
+        object_manager: Arc<ServerObjectManager<T>>,
+        channel: WSChannel,
+    ) -> Self {
+        Self {
+            actor_msg_receiver: Some(receiver),
+            object_manager,
+            channel,
+        }
+    }
+
+    pub async fn run(mut self) {
+        let mut actor_msg_receiver = self
+            .actor_msg_receiver
+            .take()
+            .expect("ObjectWebSocketActor's receiver should only take one time");
+        let stream = stream! {
+            loop {
+                match actor_msg_receiver.recv().await {
+                    Some(msg) => yield msg,
+                    None => {
+                        break
+                    },
+                }
+            }
+        };
+        stream.for_each(|msg| self.handle_message(msg)).await;
+    }
+
+    async fn handle_message(&self, msg: ObjectWSActorMessage) {
+        match msg {
+            ObjectWSActorMessage::ClientData {
+                client_data,
+                persistence: _,
+                ret,
+            } => {
+                let _ = ret.send(self.handle_object_data(client_data).await);
+            }
+        }
+    }
+
+    async fn handle_object_data(&self, client_data: WSClientData) -> Result<()> {
+        let WSClientData { user, socket, data } = client_data;
+        let object_client_data = spawn_blocking(move || parse_from_bytes::<ClientRevisionWSDataPB>(&data))
+            .await
+            .map_err(internal_error)??;
+
+        tracing::debug!(
+            "[ObjectWebSocketActor]: receive {}: {}:{}, {:?}",
+            T::NAME,
+            object_client_data.object_id,
+            object_client_data.data_id,
+            object_client_data.ty
+        );
+
+        let user = Arc::new(ObjectRevisionUser {
+            user,
+            socket,
+            channel: self.channel.clone(),
+        });
+        match &object_client_data.ty {
+            ClientRevisionWSDataTypePB::ClientPushRev => {
+                let _ = self
+                    .object_manager
+                    .handle_client_revisions(user, object_client_data)
+                    .await
+                    .map_err(internal_error)?;
+            }
+            ClientRevisionWSDataTypePB::ClientPing => {
+                let _ = self
+                    .object_manager
+                    .handle_client_ping(user, object_client_data)
+                    .await
+                    .map_err(internal_error)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct ObjectRevisionUser {
+    pub user: Arc<WSUser>,
+    pub(crate) socket: Socket,
+    channel: WSChannel,
+}
+
+impl std::fmt::Debug for ObjectRevisionUser {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ObjectRevisionUser")
+            .field("user", &self.user)
+            .field("socket", &self.socket)
+            .field("channel", &self.channel)
+            .finish()
+    }
+}
+
+impl RevisionUser for ObjectRevisionUser {
+    fn user_id(&self) -> String {
+        self.user.id().to_string()
+    }
+
+    fn receive(&self, resp: RevisionSyncResponse) {
+        let result = match resp {
+            RevisionSyncResponse::Pull(data) => {
+                let msg: WebSocketMessage = revision_data_to_ws_message(data, self.channel.clone());
+                self.socket.try_send(msg).map_err(internal_error)
+            }
+            RevisionSyncResponse::Push(data) => {
+                let msg: WebSocketMessage = revision_data_to_ws_message(data, self.channel.clone());
+                self.socket.try_send(msg).map_err(internal_error)
+            }
+            RevisionSyncResponse::Ack(data) => {
+                let msg: WebSocketMessage = revision_data_to_ws_message(data, self.channel.clone());
+                self.socket.try_send(msg).map_err(internal_error)
+            }
+        };
+
+        match result {
+            Ok(_) => {}
+            Err(e) => log::error!("[ObjectRevisionUser]: {}", e),
+        }
+    }
+}
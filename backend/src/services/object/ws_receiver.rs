@@ -0,0 +1,170 @@
+use crate::{
+    context::FlowyPersistence,
+    services::{
+        object::ws_actor::{ObjectWSActorMessage, ObjectWebSocketActor},
+        web_socket::{WSClientData, WebSocketReceiver},
+    },
+};
+use std::fmt::{Debug, Formatter};
+
+use crate::services::kv::revision_kv::{revisions_to_key_value_items, RevisionKVPersistence};
+use flowy_collaboration::{
+    errors::CollaborateError,
+    protobuf::{RepeatedRevision as RepeatedRevisionPB, Revision as RevisionPB},
+    server_object::{ServerObjectManager, ServerObjectPersistence, ServerSyncObject},
+    util::make_object_info_from_revisions_pb,
+};
+use lib_infra::future::BoxResultFuture;
+use lib_ws::WSChannel;
+use std::sync::Arc;
+use tokio::sync::{mpsc, oneshot};
+
+pub fn make_object_ws_receiver<T: ServerSyncObject>(
+    persistence: Arc<FlowyPersistence>,
+    object_manager: Arc<ServerObjectManager<T>>,
+    channel: WSChannel,
+) -> Arc<ObjectWebSocketReceiver> {
+    let (actor_msg_sender, rx) = tokio::sync::mpsc::channel(1000);
+    let actor = ObjectWebSocketActor::new(rx, object_manager, channel);
+    tokio::task::spawn(actor.run());
+    Arc::new(ObjectWebSocketReceiver::new(persistence, actor_msg_sender))
+}
+
+pub struct ObjectWebSocketReceiver {
+    actor_msg_sender: mpsc::Sender<ObjectWSActorMessage>,
+    persistence: Arc<FlowyPersistence>,
+}
+
+impl ObjectWebSocketReceiver {
+    pub fn new(persistence: Arc<FlowyPersistence>, actor_msg_sender: mpsc::Sender<ObjectWSActorMessage>) -> Self {
+        Self {
+            actor_msg_sender,
+            persistence,
+        }
+    }
+}
+
+impl WebSocketReceiver for ObjectWebSocketReceiver {
+    fn receive(&self, data: WSClientData) {
+        let (ret, rx) = oneshot::channel();
+        let actor_msg_sender = self.actor_msg_sender.clone();
+        let persistence = self.persistence.clone();
+
+        actix_rt::spawn(async move {
+            let msg = ObjectWSActorMessage::ClientData {
+                client_data: data,
+                persistence,
+                ret,
+            };
+
+            match actor_msg_sender.send(msg).await {
+                Ok(_) => {}
+                Err(e) => {
+                    log::error!("[ObjectWebSocketReceiver]: send message to actor failed: {}", e);
+                }
+            }
+            match rx.await {
+                Ok(_) => {}
+                Err(e) => log::error!("[ObjectWebSocketReceiver]: message ret failed {:?}", e),
+            };
+        });
+    }
+}
+
+pub struct HttpObjectCloudPersistence(pub Arc<RevisionKVPersistence>);
+impl Debug for HttpObjectCloudPersistence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("HttpObjectCloudPersistence")
+    }
+}
+
+impl<T: ServerSyncObject> ServerObjectPersistence<T> for HttpObjectCloudPersistence {
+    fn read_object(&self, _user_id: &str, object_id: &str) -> BoxResultFuture<T::Info, CollaborateError> {
+        let object_store = self.0.clone();
+        let object_id = object_id.to_owned();
+        Box::pin(async move {
+            let revisions = object_store
+                .get_revisions(&object_id, None)
+                .await
+                .map_err(|e| e.to_collaborate_error())?;
+            match make_object_info_from_revisions_pb(&object_id, revisions)? {
+                Some(info) => Ok(info),
+                None => Err(CollaborateError::record_not_found().context(format!("{} not exist", object_id))),
+            }
+        })
+    }
+
+    fn create_object(
+        &self,
+        _user_id: &str,
+        object_id: &str,
+        mut repeated_revision: RepeatedRevisionPB,
+    ) -> BoxResultFuture<Option<T::Info>, CollaborateError> {
+        let object_store = self.0.clone();
+        let object_id = object_id.to_owned();
+        Box::pin(async move {
+            let info = make_object_info_from_revisions_pb(&object_id, repeated_revision.clone())?;
+            let revisions: Vec<RevisionPB> = repeated_revision.take_items().into();
+            let _ = object_store
+                .set_revision(revisions)
+                .await
+                .map_err(|e| e.to_collaborate_error())?;
+            Ok(info)
+        })
+    }
+
+    fn save_object_revisions(
+        &self,
+        mut repeated_revision: RepeatedRevisionPB,
+    ) -> BoxResultFuture<(), CollaborateError> {
+        let object_store = self.0.clone();
+        Box::pin(async move {
+            let revisions: Vec<RevisionPB> = repeated_revision.take_items().into();
+            let _ = object_store
+                .set_revision(revisions)
+                .await
+                .map_err(|e| e.to_collaborate_error())?;
+            Ok(())
+        })
+    }
+
+    fn read_object_revisions(
+        &self,
+        object_id: &str,
+        rev_ids: Option<Vec<i64>>,
+    ) -> BoxResultFuture<Vec<RevisionPB>, CollaborateError> {
+        let object_store = self.0.clone();
+        let object_id = object_id.to_owned();
+        Box::pin(async move {
+            let mut repeated_revision = object_store
+                .get_revisions(&object_id, rev_ids)
+                .await
+                .map_err(|e| e.to_collaborate_error())?;
+            let revisions: Vec<RevisionPB> = repeated_revision.take_items().into();
+            Ok(revisions)
+        })
+    }
+
+    fn reset_object_revisions(
+        &self,
+        object_id: &str,
+        mut repeated_revision: RepeatedRevisionPB,
+    ) -> BoxResultFuture<(), CollaborateError> {
+        let object_store = self.0.clone();
+        let object_id = object_id.to_owned();
+        Box::pin(async move {
+            let _ = object_store
+                .transaction(|mut transaction| {
+                    Box::pin(async move {
+                        let _ = transaction.batch_delete_key_start_with(&object_id).await?;
+                        let items = revisions_to_key_value_items(repeated_revision.take_items().into())?;
+                        let _ = transaction.batch_set(items).await?;
+                        Ok(())
+                    })
+                })
+                .await
+                .map_err(|e| e.to_collaborate_error())?;
+            Ok(())
+        })
+    }
+}
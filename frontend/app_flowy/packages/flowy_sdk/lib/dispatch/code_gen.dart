@@ -101,6 +101,23 @@ class WorkspaceEventReadWorkspaceApps {
     }
 }
 
+class WorkspaceEventSetWorkspaceEncryption {
+     WorkspaceEncryptionRequest request;
+     WorkspaceEventSetWorkspaceEncryption(this.request);
+
+    Future<Either<Unit, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.SetWorkspaceEncryption.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (bytes) => left(unit),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
 class WorkspaceEventCreateApp {
      CreateAppRequest request;
      WorkspaceEventCreateApp(this.request);
@@ -169,6 +186,23 @@ class WorkspaceEventUpdateApp {
     }
 }
 
+class WorkspaceEventDuplicateApp {
+     QueryAppRequest request;
+     WorkspaceEventDuplicateApp(this.request);
+
+    Future<Either<App, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.DuplicateApp.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(App.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
 class WorkspaceEventCreateView {
      CreateViewRequest request;
      WorkspaceEventCreateView(this.request);
@@ -302,6 +336,57 @@ class WorkspaceEventCloseView {
     }
 }
 
+class WorkspaceEventUpdateViewTags {
+     UpdateViewTagsRequest request;
+     WorkspaceEventUpdateViewTags(this.request);
+
+    Future<Either<View, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.UpdateViewTags.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(View.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventUpdateViewProperty {
+     UpdateViewPropertyRequest request;
+     WorkspaceEventUpdateViewProperty(this.request);
+
+    Future<Either<View, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.UpdateViewProperty.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(View.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventQueryViews {
+     ViewFilterRequest request;
+     WorkspaceEventQueryViews(this.request);
+
+    Future<Either<RepeatedView, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.QueryViews.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(RepeatedView.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
 class WorkspaceEventReadTrash {
     WorkspaceEventReadTrash();
 
@@ -378,6 +463,37 @@ class WorkspaceEventDeleteAllTrash {
     }
 }
 
+class WorkspaceEventReadTrashRetentionPolicy {
+    WorkspaceEventReadTrashRetentionPolicy();
+
+    Future<Either<TrashRetentionPolicy, FlowyError>> send() {
+     final request = FFIRequest.create()
+        ..event = WorkspaceEvent.ReadTrashRetentionPolicy.toString();
+
+     return Dispatch.asyncRequest(request).then((bytesResult) => bytesResult.fold(
+        (okBytes) => left(TrashRetentionPolicy.fromBuffer(okBytes)),
+        (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+      ));
+    }
+}
+
+class WorkspaceEventUpdateTrashRetentionPolicy {
+     TrashRetentionPolicy request;
+     WorkspaceEventUpdateTrashRetentionPolicy(this.request);
+
+    Future<Either<Unit, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.UpdateTrashRetentionPolicy.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (bytes) => left(unit),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
 class WorkspaceEventApplyDocDelta {
      DocumentDelta request;
      WorkspaceEventApplyDocDelta(this.request);
@@ -395,6 +511,156 @@ class WorkspaceEventApplyDocDelta {
     }
 }
 
+class WorkspaceEventHighlightCode {
+     HighlightCodeRequest request;
+     WorkspaceEventHighlightCode(this.request);
+
+    Future<Either<RepeatedHighlightSpan, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.HighlightCode.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(RepeatedHighlightSpan.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventReadDocumentOutline {
+     QueryViewRequest request;
+     WorkspaceEventReadDocumentOutline(this.request);
+
+    Future<Either<RepeatedOutlineItem, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.ReadDocumentOutline.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(RepeatedOutlineItem.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventJumpToSection {
+     JumpToSectionRequest request;
+     WorkspaceEventJumpToSection(this.request);
+
+    Future<Either<DocumentSection, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.JumpToSection.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(DocumentSection.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventReadDocumentStatistics {
+     DocumentStatisticsRequest request;
+     WorkspaceEventReadDocumentStatistics(this.request);
+
+    Future<Either<DocumentStatistics, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.ReadDocumentStatistics.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(DocumentStatistics.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventCheckDocumentIntegrity {
+     DocumentIntegrityRequest request;
+     WorkspaceEventCheckDocumentIntegrity(this.request);
+
+    Future<Either<DocumentIntegrityReport, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.CheckDocumentIntegrity.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(DocumentIntegrityReport.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventReadDocumentMergeBackups {
+     QueryViewRequest request;
+     WorkspaceEventReadDocumentMergeBackups(this.request);
+
+    Future<Either<RepeatedDocumentMergeBackup, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.ReadDocumentMergeBackups.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(RepeatedDocumentMergeBackup.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventRestoreDocumentMergeBackup {
+     DocumentMergeBackupId request;
+     WorkspaceEventRestoreDocumentMergeBackup(this.request);
+
+    Future<Either<DocumentDelta, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.RestoreDocumentMergeBackup.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(DocumentDelta.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventDeleteDocumentMergeBackup {
+     DocumentMergeBackupId request;
+     WorkspaceEventDeleteDocumentMergeBackup(this.request);
+
+    Future<Either<Unit, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.DeleteDocumentMergeBackup.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (bytes) => left(unit),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventReadOpenDocumentsUsage {
+    WorkspaceEventReadOpenDocumentsUsage();
+
+    Future<Either<OpenDocumentsUsage, FlowyError>> send() {
+     final request = FFIRequest.create()
+        ..event = WorkspaceEvent.ReadOpenDocumentsUsage.toString();
+
+     return Dispatch.asyncRequest(request).then((bytesResult) => bytesResult.fold(
+        (okBytes) => left(OpenDocumentsUsage.fromBuffer(okBytes)),
+        (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+      ));
+    }
+}
+
 class WorkspaceEventExportDocument {
      ExportRequest request;
      WorkspaceEventExportDocument(this.request);
@@ -412,6 +678,394 @@ class WorkspaceEventExportDocument {
     }
 }
 
+class WorkspaceEventCreateTemplate {
+     CreateTemplateRequest request;
+     WorkspaceEventCreateTemplate(this.request);
+
+    Future<Either<Template, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.CreateTemplate.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(Template.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventReadTemplates {
+    WorkspaceEventReadTemplates();
+
+    Future<Either<RepeatedTemplate, FlowyError>> send() {
+     final request = FFIRequest.create()
+        ..event = WorkspaceEvent.ReadTemplates.toString();
+
+     return Dispatch.asyncRequest(request).then((bytesResult) => bytesResult.fold(
+        (okBytes) => left(RepeatedTemplate.fromBuffer(okBytes)),
+        (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+      ));
+    }
+}
+
+class WorkspaceEventDeleteTemplate {
+     QueryTemplateRequest request;
+     WorkspaceEventDeleteTemplate(this.request);
+
+    Future<Either<Unit, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.DeleteTemplate.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (bytes) => left(unit),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventCreateViewFromTemplate {
+     CreateViewFromTemplateRequest request;
+     WorkspaceEventCreateViewFromTemplate(this.request);
+
+    Future<Either<View, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.CreateViewFromTemplate.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(View.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventOpenGrid {
+     QueryGridRequest request;
+     WorkspaceEventOpenGrid(this.request);
+
+    Future<Either<Grid, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.OpenGrid.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(Grid.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventCreateField {
+     CreateFieldRequest request;
+     WorkspaceEventCreateField(this.request);
+
+    Future<Either<Field, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.CreateField.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(Field.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventUpdateField {
+     UpdateFieldRequest request;
+     WorkspaceEventUpdateField(this.request);
+
+    Future<Either<Field, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.UpdateField.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(Field.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventDeleteField {
+     QueryFieldRequest request;
+     WorkspaceEventDeleteField(this.request);
+
+    Future<Either<Unit, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.DeleteField.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (bytes) => left(unit),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventCreateRow {
+     QueryGridRequest request;
+     WorkspaceEventCreateRow(this.request);
+
+    Future<Either<Row, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.CreateRow.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(Row.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventUpdateRow {
+     UpdateRowRequest request;
+     WorkspaceEventUpdateRow(this.request);
+
+    Future<Either<Row, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.UpdateRow.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(Row.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventDeleteRow {
+     QueryRowRequest request;
+     WorkspaceEventDeleteRow(this.request);
+
+    Future<Either<Unit, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.DeleteRow.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (bytes) => left(unit),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventUpdateCell {
+     UpdateCellRequest request;
+     WorkspaceEventUpdateCell(this.request);
+
+    Future<Either<Row, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.UpdateCell.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(Row.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventDeleteCell {
+     QueryCellRequest request;
+     WorkspaceEventDeleteCell(this.request);
+
+    Future<Either<Unit, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.DeleteCell.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (bytes) => left(unit),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventOpenBoard {
+     QueryBoardRequest request;
+     WorkspaceEventOpenBoard(this.request);
+
+    Future<Either<Board, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.OpenBoard.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(Board.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventUpdateBoardProperty {
+     UpdateBoardPropertyRequest request;
+     WorkspaceEventUpdateBoardProperty(this.request);
+
+    Future<Either<Board, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.UpdateBoardProperty.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(Board.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventCreateCard {
+     CreateCardRequest request;
+     WorkspaceEventCreateCard(this.request);
+
+    Future<Either<Card, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.CreateCard.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(Card.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventUpdateCard {
+     UpdateCardRequest request;
+     WorkspaceEventUpdateCard(this.request);
+
+    Future<Either<Card, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.UpdateCard.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(Card.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventMoveCard {
+     MoveCardRequest request;
+     WorkspaceEventMoveCard(this.request);
+
+    Future<Either<Board, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.MoveCard.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(Board.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventDeleteCard {
+     QueryCardRequest request;
+     WorkspaceEventDeleteCard(this.request);
+
+    Future<Either<Unit, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.DeleteCard.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (bytes) => left(unit),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventCreateComment {
+     CreateCommentRequest request;
+     WorkspaceEventCreateComment(this.request);
+
+    Future<Either<CommentThread, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.CreateComment.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(CommentThread.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventReplyComment {
+     ReplyCommentRequest request;
+     WorkspaceEventReplyComment(this.request);
+
+    Future<Either<CommentThread, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.ReplyComment.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(CommentThread.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventResolveComment {
+     ResolveCommentRequest request;
+     WorkspaceEventResolveComment(this.request);
+
+    Future<Either<CommentThread, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.ResolveComment.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(CommentThread.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventReadComments {
+     QueryCommentsRequest request;
+     WorkspaceEventReadComments(this.request);
+
+    Future<Either<RepeatedCommentThread, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.ReadComments.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(RepeatedCommentThread.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
 class NetworkEventUpdateNetworkType {
      NetworkState request;
      NetworkEventUpdateNetworkType(this.request);
@@ -429,6 +1083,99 @@ class NetworkEventUpdateNetworkType {
     }
 }
 
+class NetworkEventReadObjectSyncStatus {
+     SyncObjectId request;
+     NetworkEventReadObjectSyncStatus(this.request);
+
+    Future<Either<ObjectSyncStatus, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = NetworkEvent.ReadObjectSyncStatus.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(ObjectSyncStatus.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class NetworkEventReadSyncStatus {
+    NetworkEventReadSyncStatus();
+
+    Future<Either<SyncStatus, FlowyError>> send() {
+     final request = FFIRequest.create()
+        ..event = NetworkEvent.ReadSyncStatus.toString();
+
+     return Dispatch.asyncRequest(request).then((bytesResult) => bytesResult.fold(
+        (okBytes) => left(SyncStatus.fromBuffer(okBytes)),
+        (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+      ));
+    }
+}
+
+class NetworkEventReadLanSyncSetting {
+    NetworkEventReadLanSyncSetting();
+
+    Future<Either<LanSyncSetting, FlowyError>> send() {
+     final request = FFIRequest.create()
+        ..event = NetworkEvent.ReadLanSyncSetting.toString();
+
+     return Dispatch.asyncRequest(request).then((bytesResult) => bytesResult.fold(
+        (okBytes) => left(LanSyncSetting.fromBuffer(okBytes)),
+        (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+      ));
+    }
+}
+
+class NetworkEventEnableLanSyncHost {
+     LanSyncHostRequest request;
+     NetworkEventEnableLanSyncHost(this.request);
+
+    Future<Either<LanSyncSetting, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = NetworkEvent.EnableLanSyncHost.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(LanSyncSetting.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class NetworkEventPairLanSyncPeer {
+     LanSyncPeerRequest request;
+     NetworkEventPairLanSyncPeer(this.request);
+
+    Future<Either<LanSyncSetting, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = NetworkEvent.PairLanSyncPeer.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(LanSyncSetting.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class NetworkEventDisableLanSync {
+    NetworkEventDisableLanSync();
+
+    Future<Either<LanSyncSetting, FlowyError>> send() {
+     final request = FFIRequest.create()
+        ..event = NetworkEvent.DisableLanSync.toString();
+
+     return Dispatch.asyncRequest(request).then((bytesResult) => bytesResult.fold(
+        (okBytes) => left(LanSyncSetting.fromBuffer(okBytes)),
+        (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+      ));
+    }
+}
+
 class UserEventInitUser {
     UserEventInitUser();
 
@@ -62,16 +62,25 @@ typedef _invoke_sync_Dart = Pointer<Uint8> Function(
 /// C function `init_sdk`.
 int init_sdk(
   Pointer<ffi.Utf8> path,
+  Pointer<ffi.Utf8> databaseKey,
+  int documentCacheMaxCount,
+  int documentCacheMaxSize,
 ) {
-  return _init_sdk(path);
+  return _init_sdk(path, databaseKey, documentCacheMaxCount, documentCacheMaxSize);
 }
 
 final _init_sdk_Dart _init_sdk = _dl.lookupFunction<_init_sdk_C, _init_sdk_Dart>('init_sdk');
 typedef _init_sdk_C = Int64 Function(
   Pointer<ffi.Utf8> path,
+  Pointer<ffi.Utf8> databaseKey,
+  Int64 documentCacheMaxCount,
+  Int64 documentCacheMaxSize,
 );
 typedef _init_sdk_Dart = int Function(
   Pointer<ffi.Utf8> path,
+  Pointer<ffi.Utf8> databaseKey,
+  int documentCacheMaxCount,
+  int documentCacheMaxSize,
 );
 
 /// C function `init_stream`.
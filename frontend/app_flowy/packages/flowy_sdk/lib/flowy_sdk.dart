@@ -18,11 +18,24 @@ class FlowySDK {
 
   void dispose() {}
 
-  Future<void> init(Directory sdkDir) async {
+  // The databaseKey is the hex of the key that encrypts the local databases, they are not encrypted if it's empty.
+  // The documentCacheMaxCount and documentCacheMaxSize (in bytes) are the budget of the opened documents, the
+  // default budget is used if they are not positive.
+  Future<void> init(
+    Directory sdkDir, {
+    String databaseKey = '',
+    int documentCacheMaxCount = 0,
+    int documentCacheMaxSize = 0,
+  }) async {
     final port = RustStreamReceiver.shared.port;
     ffi.set_stream_port(port);
 
     ffi.store_dart_post_cobject(NativeApi.postCObject);
-    ffi.init_sdk(sdkDir.path.toNativeUtf8());
+    ffi.init_sdk(
+      sdkDir.path.toNativeUtf8(),
+      databaseKey.toNativeUtf8(),
+      documentCacheMaxCount,
+      documentCacheMaxSize,
+    );
   }
 }
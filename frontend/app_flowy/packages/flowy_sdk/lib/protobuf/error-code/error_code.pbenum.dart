@@ -18,6 +18,7 @@ class ErrorCode extends $pb.ProtobufEnum {
   static const ErrorCode AppColorStyleInvalid = ErrorCode._(102, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'AppColorStyleInvalid');
   static const ErrorCode WorkspaceDescTooLong = ErrorCode._(103, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'WorkspaceDescTooLong');
   static const ErrorCode WorkspaceNameTooLong = ErrorCode._(104, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'WorkspaceNameTooLong');
+  static const ErrorCode WorkspacePassphraseInvalid = ErrorCode._(105, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'WorkspacePassphraseInvalid');
   static const ErrorCode AppIdInvalid = ErrorCode._(110, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'AppIdInvalid');
   static const ErrorCode AppNameInvalid = ErrorCode._(111, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'AppNameInvalid');
   static const ErrorCode ViewNameInvalid = ErrorCode._(120, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewNameInvalid');
@@ -26,7 +27,26 @@ class ErrorCode extends $pb.ProtobufEnum {
   static const ErrorCode ViewDescTooLong = ErrorCode._(123, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewDescTooLong');
   static const ErrorCode ViewDataInvalid = ErrorCode._(124, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewDataInvalid');
   static const ErrorCode ViewNameTooLong = ErrorCode._(125, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewNameTooLong');
+  static const ErrorCode ViewTagInvalid = ErrorCode._(126, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewTagInvalid');
+  static const ErrorCode ViewPropertyKeyInvalid = ErrorCode._(127, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewPropertyKeyInvalid');
+  static const ErrorCode ViewPropertyValueInvalid = ErrorCode._(128, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewPropertyValueInvalid');
+  static const ErrorCode TemplateIdInvalid = ErrorCode._(130, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'TemplateIdInvalid');
+  static const ErrorCode TemplateTypeInvalid = ErrorCode._(131, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'TemplateTypeInvalid');
+  static const ErrorCode FieldIdInvalid = ErrorCode._(140, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'FieldIdInvalid');
+  static const ErrorCode FieldNameInvalid = ErrorCode._(141, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'FieldNameInvalid');
+  static const ErrorCode RowIdInvalid = ErrorCode._(142, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'RowIdInvalid');
+  static const ErrorCode CellDataInvalid = ErrorCode._(143, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CellDataInvalid');
+  static const ErrorCode CardIdInvalid = ErrorCode._(150, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CardIdInvalid');
+  static const ErrorCode CardTitleInvalid = ErrorCode._(151, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CardTitleInvalid');
+  static const ErrorCode BoardColumnInvalid = ErrorCode._(152, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'BoardColumnInvalid');
+  static const ErrorCode CommentIdInvalid = ErrorCode._(160, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CommentIdInvalid');
+  static const ErrorCode CommentContentInvalid = ErrorCode._(161, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CommentContentInvalid');
+  static const ErrorCode CommentRangeInvalid = ErrorCode._(162, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CommentRangeInvalid');
+  static const ErrorCode RevisionDecryptFailed = ErrorCode._(170, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'RevisionDecryptFailed');
+  static const ErrorCode RevisionNotSynced = ErrorCode._(171, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'RevisionNotSynced');
   static const ErrorCode ConnectError = ErrorCode._(200, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ConnectError');
+  static const ErrorCode LanSyncAddrInvalid = ErrorCode._(201, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'LanSyncAddrInvalid');
+  static const ErrorCode LanSyncSecretInvalid = ErrorCode._(202, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'LanSyncSecretInvalid');
   static const ErrorCode EmailIsEmpty = ErrorCode._(300, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'EmailIsEmpty');
   static const ErrorCode EmailFormatInvalid = ErrorCode._(301, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'EmailFormatInvalid');
   static const ErrorCode EmailAlreadyExists = ErrorCode._(302, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'EmailAlreadyExists');
@@ -40,6 +60,7 @@ class ErrorCode extends $pb.ProtobufEnum {
   static const ErrorCode UserNameIsEmpty = ErrorCode._(310, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UserNameIsEmpty');
   static const ErrorCode UserIdInvalid = ErrorCode._(311, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UserIdInvalid');
   static const ErrorCode UserNotExist = ErrorCode._(312, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UserNotExist');
+  static const ErrorCode UserDatabaseLocked = ErrorCode._(313, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UserDatabaseLocked');
 
   static const $core.List<ErrorCode> values = <ErrorCode> [
     Internal,
@@ -50,6 +71,7 @@ class ErrorCode extends $pb.ProtobufEnum {
     AppColorStyleInvalid,
     WorkspaceDescTooLong,
     WorkspaceNameTooLong,
+    WorkspacePassphraseInvalid,
     AppIdInvalid,
     AppNameInvalid,
     ViewNameInvalid,
@@ -58,7 +80,26 @@ class ErrorCode extends $pb.ProtobufEnum {
     ViewDescTooLong,
     ViewDataInvalid,
     ViewNameTooLong,
+    ViewTagInvalid,
+    ViewPropertyKeyInvalid,
+    ViewPropertyValueInvalid,
+    TemplateIdInvalid,
+    TemplateTypeInvalid,
+    FieldIdInvalid,
+    FieldNameInvalid,
+    RowIdInvalid,
+    CellDataInvalid,
+    CardIdInvalid,
+    CardTitleInvalid,
+    BoardColumnInvalid,
+    CommentIdInvalid,
+    CommentContentInvalid,
+    CommentRangeInvalid,
+    RevisionDecryptFailed,
+    RevisionNotSynced,
     ConnectError,
+    LanSyncAddrInvalid,
+    LanSyncSecretInvalid,
     EmailIsEmpty,
     EmailFormatInvalid,
     EmailAlreadyExists,
@@ -72,6 +113,7 @@ class ErrorCode extends $pb.ProtobufEnum {
     UserNameIsEmpty,
     UserIdInvalid,
     UserNotExist,
+    UserDatabaseLocked,
   ];
 
   static final $core.Map<$core.int, ErrorCode> _byValue = $pb.ProtobufEnum.initByValue(values);
@@ -20,6 +20,7 @@ const ErrorCode$json = const {
     const {'1': 'AppColorStyleInvalid', '2': 102},
     const {'1': 'WorkspaceDescTooLong', '2': 103},
     const {'1': 'WorkspaceNameTooLong', '2': 104},
+    const {'1': 'WorkspacePassphraseInvalid', '2': 105},
     const {'1': 'AppIdInvalid', '2': 110},
     const {'1': 'AppNameInvalid', '2': 111},
     const {'1': 'ViewNameInvalid', '2': 120},
@@ -28,7 +29,26 @@ const ErrorCode$json = const {
     const {'1': 'ViewDescTooLong', '2': 123},
     const {'1': 'ViewDataInvalid', '2': 124},
     const {'1': 'ViewNameTooLong', '2': 125},
+    const {'1': 'ViewTagInvalid', '2': 126},
+    const {'1': 'ViewPropertyKeyInvalid', '2': 127},
+    const {'1': 'ViewPropertyValueInvalid', '2': 128},
+    const {'1': 'TemplateIdInvalid', '2': 130},
+    const {'1': 'TemplateTypeInvalid', '2': 131},
+    const {'1': 'FieldIdInvalid', '2': 140},
+    const {'1': 'FieldNameInvalid', '2': 141},
+    const {'1': 'RowIdInvalid', '2': 142},
+    const {'1': 'CellDataInvalid', '2': 143},
+    const {'1': 'CardIdInvalid', '2': 150},
+    const {'1': 'CardTitleInvalid', '2': 151},
+    const {'1': 'BoardColumnInvalid', '2': 152},
+    const {'1': 'CommentIdInvalid', '2': 160},
+    const {'1': 'CommentContentInvalid', '2': 161},
+    const {'1': 'CommentRangeInvalid', '2': 162},
+    const {'1': 'RevisionDecryptFailed', '2': 170},
+    const {'1': 'RevisionNotSynced', '2': 171},
     const {'1': 'ConnectError', '2': 200},
+    const {'1': 'LanSyncAddrInvalid', '2': 201},
+    const {'1': 'LanSyncSecretInvalid', '2': 202},
     const {'1': 'EmailIsEmpty', '2': 300},
     const {'1': 'EmailFormatInvalid', '2': 301},
     const {'1': 'EmailAlreadyExists', '2': 302},
@@ -42,8 +62,9 @@ const ErrorCode$json = const {
     const {'1': 'UserNameIsEmpty', '2': 310},
     const {'1': 'UserIdInvalid', '2': 311},
     const {'1': 'UserNotExist', '2': 312},
+    const {'1': 'UserDatabaseLocked', '2': 313},
   ],
 };
 
 /// Descriptor for `ErrorCode`. Decode as a `google.protobuf.EnumDescriptorProto`.
-final $typed_data.Uint8List errorCodeDescriptor = $convert.base64Decode('CglFcnJvckNvZGUSDAoISW50ZXJuYWwQABIUChBVc2VyVW5hdXRob3JpemVkEAISEgoOUmVjb3JkTm90Rm91bmQQAxIYChRXb3Jrc3BhY2VOYW1lSW52YWxpZBBkEhYKEldvcmtzcGFjZUlkSW52YWxpZBBlEhgKFEFwcENvbG9yU3R5bGVJbnZhbGlkEGYSGAoUV29ya3NwYWNlRGVzY1Rvb0xvbmcQZxIYChRXb3Jrc3BhY2VOYW1lVG9vTG9uZxBoEhAKDEFwcElkSW52YWxpZBBuEhIKDkFwcE5hbWVJbnZhbGlkEG8SEwoPVmlld05hbWVJbnZhbGlkEHgSGAoUVmlld1RodW1ibmFpbEludmFsaWQQeRIRCg1WaWV3SWRJbnZhbGlkEHoSEwoPVmlld0Rlc2NUb29Mb25nEHsSEwoPVmlld0RhdGFJbnZhbGlkEHwSEwoPVmlld05hbWVUb29Mb25nEH0SEQoMQ29ubmVjdEVycm9yEMgBEhEKDEVtYWlsSXNFbXB0eRCsAhIXChJFbWFpbEZvcm1hdEludmFsaWQQrQISFwoSRW1haWxBbHJlYWR5RXhpc3RzEK4CEhQKD1Bhc3N3b3JkSXNFbXB0eRCvAhIUCg9QYXNzd29yZFRvb0xvbmcQsAISJQogUGFzc3dvcmRDb250YWluc0ZvcmJpZENoYXJhY3RlcnMQsQISGgoVUGFzc3dvcmRGb3JtYXRJbnZhbGlkELICEhUKEFBhc3N3b3JkTm90TWF0Y2gQswISFAoPVXNlck5hbWVUb29Mb25nELQCEicKIlVzZXJOYW1lQ29udGFpbkZvcmJpZGRlbkNoYXJhY3RlcnMQtQISFAoPVXNlck5hbWVJc0VtcHR5ELYCEhIKDVVzZXJJZEludmFsaWQQtwISEQoMVXNlck5vdEV4aXN0ELgC');
+final $typed_data.Uint8List errorCodeDescriptor = $convert.base64Decode('CglFcnJvckNvZGUSDAoISW50ZXJuYWwQABIUChBVc2VyVW5hdXRob3JpemVkEAISEgoOUmVjb3JkTm90Rm91bmQQAxIYChRXb3Jrc3BhY2VOYW1lSW52YWxpZBBkEhYKEldvcmtzcGFjZUlkSW52YWxpZBBlEhgKFEFwcENvbG9yU3R5bGVJbnZhbGlkEGYSGAoUV29ya3NwYWNlRGVzY1Rvb0xvbmcQZxIYChRXb3Jrc3BhY2VOYW1lVG9vTG9uZxBoEh4KGldvcmtzcGFjZVBhc3NwaHJhc2VJbnZhbGlkEGkSEAoMQXBwSWRJbnZhbGlkEG4SEgoOQXBwTmFtZUludmFsaWQQbxITCg9WaWV3TmFtZUludmFsaWQQeBIYChRWaWV3VGh1bWJuYWlsSW52YWxpZBB5EhEKDVZpZXdJZEludmFsaWQQehITCg9WaWV3RGVzY1Rvb0xvbmcQexITCg9WaWV3RGF0YUludmFsaWQQfBITCg9WaWV3TmFtZVRvb0xvbmcQfRISCg5WaWV3VGFnSW52YWxpZBB+EhoKFlZpZXdQcm9wZXJ0eUtleUludmFsaWQQfxIdChhWaWV3UHJvcGVydHlWYWx1ZUludmFsaWQQgAESFgoRVGVtcGxhdGVJZEludmFsaWQQggESGAoTVGVtcGxhdGVUeXBlSW52YWxpZBCDARITCg5GaWVsZElkSW52YWxpZBCMARIVChBGaWVsZE5hbWVJbnZhbGlkEI0BEhEKDFJvd0lkSW52YWxpZBCOARIUCg9DZWxsRGF0YUludmFsaWQQjwESEgoNQ2FyZElkSW52YWxpZBCWARIVChBDYXJkVGl0bGVJbnZhbGlkEJcBEhcKEkJvYXJkQ29sdW1uSW52YWxpZBCYARIVChBDb21tZW50SWRJbnZhbGlkEKABEhoKFUNvbW1lbnRDb250ZW50SW52YWxpZBChARIYChNDb21tZW50UmFuZ2VJbnZhbGlkEKIBEhoKFVJldmlzaW9uRGVjcnlwdEZhaWxlZBCqARIWChFSZXZpc2lvbk5vdFN5bmNlZBCrARIRCgxDb25uZWN0RXJyb3IQyAESFwoSTGFuU3luY0FkZHJJbnZhbGlkEMkBEhkKFExhblN5bmNTZWNyZXRJbnZhbGlkEMoBEhEKDEVtYWlsSXNFbXB0eRCsAhIXChJFbWFpbEZvcm1hdEludmFsaWQQrQISFwoSRW1haWxBbHJlYWR5RXhpc3RzEK4CEhQKD1Bhc3N3b3JkSXNFbXB0eRCvAhIUCg9QYXNzd29yZFRvb0xvbmcQsAISJQogUGFzc3dvcmRDb250YWluc0ZvcmJpZENoYXJhY3RlcnMQsQISGgoVUGFzc3dvcmRGb3JtYXRJbnZhbGlkELICEhUKEFBhc3N3b3JkTm90TWF0Y2gQswISFAoPVXNlck5hbWVUb29Mb25nELQCEicKIlVzZXJOYW1lQ29udGFpbkZvcmJpZGRlbkNoYXJhY3RlcnMQtQISFAoPVXNlck5hbWVJc0VtcHR5ELYCEhIKDVVzZXJJZEludmFsaWQQtwISEQoMVXNlck5vdEV4aXN0ELgCEhcKElVzZXJEYXRhYmFzZUxvY2tlZBC5Ag==');
@@ -0,0 +1,23 @@
+///
+//  Generated code. Do not modify.
+//  source: board_info.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use boardInfoDescriptor instead')
+const BoardInfo$json = const {
+  '1': 'BoardInfo',
+  '2': const [
+    const {'1': 'board_id', '3': 1, '4': 1, '5': 9, '10': 'boardId'},
+    const {'1': 'text', '3': 2, '4': 1, '5': 9, '10': 'text'},
+    const {'1': 'rev_id', '3': 3, '4': 1, '5': 3, '10': 'revId'},
+    const {'1': 'base_rev_id', '3': 4, '4': 1, '5': 3, '10': 'baseRevId'},
+  ],
+};
+
+/// Descriptor for `BoardInfo`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List boardInfoDescriptor = $convert.base64Decode('CglCb2FyZEluZm8SGQoIYm9hcmRfaWQYASABKAlSB2JvYXJkSWQSEgoEdGV4dBgCIAEoCVIEdGV4dBIVCgZyZXZfaWQYAyABKANSBXJldklkEh4KC2Jhc2VfcmV2X2lkGAQgASgDUgliYXNlUmV2SWQ=');
@@ -0,0 +1,23 @@
+///
+//  Generated code. Do not modify.
+//  source: comment_info.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use commentInfoDescriptor instead')
+const CommentInfo$json = const {
+  '1': 'CommentInfo',
+  '2': const [
+    const {'1': 'object_id', '3': 1, '4': 1, '5': 9, '10': 'objectId'},
+    const {'1': 'text', '3': 2, '4': 1, '5': 9, '10': 'text'},
+    const {'1': 'rev_id', '3': 3, '4': 1, '5': 3, '10': 'revId'},
+    const {'1': 'base_rev_id', '3': 4, '4': 1, '5': 3, '10': 'baseRevId'},
+  ],
+};
+
+/// Descriptor for `CommentInfo`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List commentInfoDescriptor = $convert.base64Decode('CgtDb21tZW50SW5mbxIbCglvYmplY3RfaWQYASABKAlSCG9iamVjdElkEhIKBHRleHQYAiABKAlSBHRleHQSFQoGcmV2X2lkGAMgASgDUgVyZXZJZBIeCgtiYXNlX3Jldl9pZBgEIAEoA1IJYmFzZVJldklk');
@@ -81,6 +81,7 @@ class DocumentInfo extends $pb.GeneratedMessage {
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'text')
     ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'revId')
     ..aInt64(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'baseRevId')
+    ..aOM<$0.RepeatedRevision>(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'encryptedRevisions', subBuilder: $0.RepeatedRevision.create)
     ..hasRequiredFields = false
   ;
 
@@ -90,6 +91,7 @@ class DocumentInfo extends $pb.GeneratedMessage {
     $core.String? text,
     $fixnum.Int64? revId,
     $fixnum.Int64? baseRevId,
+    $0.RepeatedRevision? encryptedRevisions,
   }) {
     final _result = create();
     if (docId != null) {
@@ -104,6 +106,9 @@ class DocumentInfo extends $pb.GeneratedMessage {
     if (baseRevId != null) {
       _result.baseRevId = baseRevId;
     }
+    if (encryptedRevisions != null) {
+      _result.encryptedRevisions = encryptedRevisions;
+    }
     return _result;
   }
   factory DocumentInfo.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -162,6 +167,17 @@ class DocumentInfo extends $pb.GeneratedMessage {
   $core.bool hasBaseRevId() => $_has(3);
   @$pb.TagNumber(4)
   void clearBaseRevId() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $0.RepeatedRevision get encryptedRevisions => $_getN(4);
+  @$pb.TagNumber(5)
+  set encryptedRevisions($0.RepeatedRevision v) { setField(5, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasEncryptedRevisions() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearEncryptedRevisions() => clearField(5);
+  @$pb.TagNumber(5)
+  $0.RepeatedRevision ensureEncryptedRevisions() => $_ensure(4);
 }
 
 class ResetDocumentParams extends $pb.GeneratedMessage {
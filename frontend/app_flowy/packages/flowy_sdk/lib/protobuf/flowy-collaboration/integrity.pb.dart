@@ -0,0 +1,304 @@
+///
+//  Generated code. Do not modify.
+//  source: integrity.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+import 'dart:core' as $core;
+
+import 'package:fixnum/fixnum.dart' as $fixnum;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+import 'revision.pb.dart' as $0;
+
+class DocumentIntegrityRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'DocumentIntegrityRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'docId')
+    ..aOB(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'repair')
+    ..hasRequiredFields = false
+  ;
+
+  DocumentIntegrityRequest._() : super();
+  factory DocumentIntegrityRequest({
+    $core.String? docId,
+    $core.bool? repair,
+  }) {
+    final _result = create();
+    if (docId != null) {
+      _result.docId = docId;
+    }
+    if (repair != null) {
+      _result.repair = repair;
+    }
+    return _result;
+  }
+  factory DocumentIntegrityRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory DocumentIntegrityRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  DocumentIntegrityRequest clone() => DocumentIntegrityRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  DocumentIntegrityRequest copyWith(void Function(DocumentIntegrityRequest) updates) => super.copyWith((message) => updates(message as DocumentIntegrityRequest)) as DocumentIntegrityRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static DocumentIntegrityRequest create() => DocumentIntegrityRequest._();
+  DocumentIntegrityRequest createEmptyInstance() => create();
+  static $pb.PbList<DocumentIntegrityRequest> createRepeated() => $pb.PbList<DocumentIntegrityRequest>();
+  @$core.pragma('dart2js:noInline')
+  static DocumentIntegrityRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<DocumentIntegrityRequest>(create);
+  static DocumentIntegrityRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get docId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set docId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasDocId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearDocId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.bool get repair => $_getBF(1);
+  @$pb.TagNumber(2)
+  set repair($core.bool v) { $_setBool(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasRepair() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearRepair() => clearField(2);
+}
+
+enum DocumentIntegrityReport_OneOfComposeError {
+  composeError, 
+  notSet
+}
+
+enum DocumentIntegrityReport_OneOfServerError {
+  serverError, 
+  notSet
+}
+
+class DocumentIntegrityReport extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, DocumentIntegrityReport_OneOfComposeError> _DocumentIntegrityReport_OneOfComposeErrorByTag = {
+    8 : DocumentIntegrityReport_OneOfComposeError.composeError,
+    0 : DocumentIntegrityReport_OneOfComposeError.notSet
+  };
+  static const $core.Map<$core.int, DocumentIntegrityReport_OneOfServerError> _DocumentIntegrityReport_OneOfServerErrorByTag = {
+    12 : DocumentIntegrityReport_OneOfServerError.serverError,
+    0 : DocumentIntegrityReport_OneOfServerError.notSet
+  };
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'DocumentIntegrityReport', createEmptyInstance: create)
+    ..oo(0, [8])
+    ..oo(1, [12])
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'docId')
+    ..aOB(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'isValid')
+    ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'revisionCount')
+    ..aInt64(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'pendingCount')
+    ..aInt64(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'lastRevId')
+    ..pc<$0.RevisionRange>(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'missingRanges', $pb.PbFieldType.PM, subBuilder: $0.RevisionRange.create)
+    ..pc<$0.RevisionRange>(7, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'invalidRanges', $pb.PbFieldType.PM, subBuilder: $0.RevisionRange.create)
+    ..aOS(8, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'composeError')
+    ..aOS(9, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'localMd5')
+    ..aInt64(10, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'serverRevId')
+    ..aOS(11, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'serverMd5')
+    ..aOS(12, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'serverError')
+    ..aOB(13, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'repaired')
+    ..hasRequiredFields = false
+  ;
+
+  DocumentIntegrityReport._() : super();
+  factory DocumentIntegrityReport({
+    $core.String? docId,
+    $core.bool? isValid,
+    $fixnum.Int64? revisionCount,
+    $fixnum.Int64? pendingCount,
+    $fixnum.Int64? lastRevId,
+    $core.Iterable<$0.RevisionRange>? missingRanges,
+    $core.Iterable<$0.RevisionRange>? invalidRanges,
+    $core.String? composeError,
+    $core.String? localMd5,
+    $fixnum.Int64? serverRevId,
+    $core.String? serverMd5,
+    $core.String? serverError,
+    $core.bool? repaired,
+  }) {
+    final _result = create();
+    if (docId != null) {
+      _result.docId = docId;
+    }
+    if (isValid != null) {
+      _result.isValid = isValid;
+    }
+    if (revisionCount != null) {
+      _result.revisionCount = revisionCount;
+    }
+    if (pendingCount != null) {
+      _result.pendingCount = pendingCount;
+    }
+    if (lastRevId != null) {
+      _result.lastRevId = lastRevId;
+    }
+    if (missingRanges != null) {
+      _result.missingRanges.addAll(missingRanges);
+    }
+    if (invalidRanges != null) {
+      _result.invalidRanges.addAll(invalidRanges);
+    }
+    if (composeError != null) {
+      _result.composeError = composeError;
+    }
+    if (localMd5 != null) {
+      _result.localMd5 = localMd5;
+    }
+    if (serverRevId != null) {
+      _result.serverRevId = serverRevId;
+    }
+    if (serverMd5 != null) {
+      _result.serverMd5 = serverMd5;
+    }
+    if (serverError != null) {
+      _result.serverError = serverError;
+    }
+    if (repaired != null) {
+      _result.repaired = repaired;
+    }
+    return _result;
+  }
+  factory DocumentIntegrityReport.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory DocumentIntegrityReport.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  DocumentIntegrityReport clone() => DocumentIntegrityReport()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  DocumentIntegrityReport copyWith(void Function(DocumentIntegrityReport) updates) => super.copyWith((message) => updates(message as DocumentIntegrityReport)) as DocumentIntegrityReport; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static DocumentIntegrityReport create() => DocumentIntegrityReport._();
+  DocumentIntegrityReport createEmptyInstance() => create();
+  static $pb.PbList<DocumentIntegrityReport> createRepeated() => $pb.PbList<DocumentIntegrityReport>();
+  @$core.pragma('dart2js:noInline')
+  static DocumentIntegrityReport getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<DocumentIntegrityReport>(create);
+  static DocumentIntegrityReport? _defaultInstance;
+
+  DocumentIntegrityReport_OneOfComposeError whichOneOfComposeError() => _DocumentIntegrityReport_OneOfComposeErrorByTag[$_whichOneof(0)]!;
+  void clearOneOfComposeError() => clearField($_whichOneof(0));
+
+  DocumentIntegrityReport_OneOfServerError whichOneOfServerError() => _DocumentIntegrityReport_OneOfServerErrorByTag[$_whichOneof(1)]!;
+  void clearOneOfServerError() => clearField($_whichOneof(1));
+
+  @$pb.TagNumber(1)
+  $core.String get docId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set docId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasDocId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearDocId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.bool get isValid => $_getBF(1);
+  @$pb.TagNumber(2)
+  set isValid($core.bool v) { $_setBool(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasIsValid() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearIsValid() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $fixnum.Int64 get revisionCount => $_getI64(2);
+  @$pb.TagNumber(3)
+  set revisionCount($fixnum.Int64 v) { $_setInt64(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasRevisionCount() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearRevisionCount() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $fixnum.Int64 get pendingCount => $_getI64(3);
+  @$pb.TagNumber(4)
+  set pendingCount($fixnum.Int64 v) { $_setInt64(3, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasPendingCount() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearPendingCount() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $fixnum.Int64 get lastRevId => $_getI64(4);
+  @$pb.TagNumber(5)
+  set lastRevId($fixnum.Int64 v) { $_setInt64(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasLastRevId() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearLastRevId() => clearField(5);
+
+  @$pb.TagNumber(6)
+  $core.List<$0.RevisionRange> get missingRanges => $_getList(5);
+
+  @$pb.TagNumber(7)
+  $core.List<$0.RevisionRange> get invalidRanges => $_getList(6);
+
+  @$pb.TagNumber(8)
+  $core.String get composeError => $_getSZ(7);
+  @$pb.TagNumber(8)
+  set composeError($core.String v) { $_setString(7, v); }
+  @$pb.TagNumber(8)
+  $core.bool hasComposeError() => $_has(7);
+  @$pb.TagNumber(8)
+  void clearComposeError() => clearField(8);
+
+  @$pb.TagNumber(9)
+  $core.String get localMd5 => $_getSZ(8);
+  @$pb.TagNumber(9)
+  set localMd5($core.String v) { $_setString(8, v); }
+  @$pb.TagNumber(9)
+  $core.bool hasLocalMd5() => $_has(8);
+  @$pb.TagNumber(9)
+  void clearLocalMd5() => clearField(9);
+
+  @$pb.TagNumber(10)
+  $fixnum.Int64 get serverRevId => $_getI64(9);
+  @$pb.TagNumber(10)
+  set serverRevId($fixnum.Int64 v) { $_setInt64(9, v); }
+  @$pb.TagNumber(10)
+  $core.bool hasServerRevId() => $_has(9);
+  @$pb.TagNumber(10)
+  void clearServerRevId() => clearField(10);
+
+  @$pb.TagNumber(11)
+  $core.String get serverMd5 => $_getSZ(10);
+  @$pb.TagNumber(11)
+  set serverMd5($core.String v) { $_setString(10, v); }
+  @$pb.TagNumber(11)
+  $core.bool hasServerMd5() => $_has(10);
+  @$pb.TagNumber(11)
+  void clearServerMd5() => clearField(11);
+
+  @$pb.TagNumber(12)
+  $core.String get serverError => $_getSZ(11);
+  @$pb.TagNumber(12)
+  set serverError($core.String v) { $_setString(11, v); }
+  @$pb.TagNumber(12)
+  $core.bool hasServerError() => $_has(11);
+  @$pb.TagNumber(12)
+  void clearServerError() => clearField(12);
+
+  @$pb.TagNumber(13)
+  $core.bool get repaired => $_getBF(12);
+  @$pb.TagNumber(13)
+  set repaired($core.bool v) { $_setBool(12, v); }
+  @$pb.TagNumber(13)
+  $core.bool hasRepaired() => $_has(12);
+  @$pb.TagNumber(13)
+  void clearRepaired() => clearField(13);
+}
+
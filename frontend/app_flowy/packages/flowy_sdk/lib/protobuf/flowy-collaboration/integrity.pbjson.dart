@@ -0,0 +1,47 @@
+///
+//  Generated code. Do not modify.
+//  source: integrity.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use documentIntegrityRequestDescriptor instead')
+const DocumentIntegrityRequest$json = const {
+  '1': 'DocumentIntegrityRequest',
+  '2': const [
+    const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'repair', '3': 2, '4': 1, '5': 8, '10': 'repair'},
+  ],
+};
+
+/// Descriptor for `DocumentIntegrityRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List documentIntegrityRequestDescriptor = $convert.base64Decode('ChhEb2N1bWVudEludGVncml0eVJlcXVlc3QSFQoGZG9jX2lkGAEgASgJUgVkb2NJZBIWCgZyZXBhaXIYAiABKAhSBnJlcGFpcg==');
+@$core.Deprecated('Use documentIntegrityReportDescriptor instead')
+const DocumentIntegrityReport$json = const {
+  '1': 'DocumentIntegrityReport',
+  '2': const [
+    const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'is_valid', '3': 2, '4': 1, '5': 8, '10': 'isValid'},
+    const {'1': 'revision_count', '3': 3, '4': 1, '5': 3, '10': 'revisionCount'},
+    const {'1': 'pending_count', '3': 4, '4': 1, '5': 3, '10': 'pendingCount'},
+    const {'1': 'last_rev_id', '3': 5, '4': 1, '5': 3, '10': 'lastRevId'},
+    const {'1': 'missing_ranges', '3': 6, '4': 3, '5': 11, '6': '.RevisionRange', '10': 'missingRanges'},
+    const {'1': 'invalid_ranges', '3': 7, '4': 3, '5': 11, '6': '.RevisionRange', '10': 'invalidRanges'},
+    const {'1': 'compose_error', '3': 8, '4': 1, '5': 9, '9': 0, '10': 'composeError'},
+    const {'1': 'local_md5', '3': 9, '4': 1, '5': 9, '10': 'localMd5'},
+    const {'1': 'server_rev_id', '3': 10, '4': 1, '5': 3, '10': 'serverRevId'},
+    const {'1': 'server_md5', '3': 11, '4': 1, '5': 9, '10': 'serverMd5'},
+    const {'1': 'server_error', '3': 12, '4': 1, '5': 9, '9': 1, '10': 'serverError'},
+    const {'1': 'repaired', '3': 13, '4': 1, '5': 8, '10': 'repaired'},
+  ],
+  '8': const [
+    const {'1': 'one_of_compose_error'},
+    const {'1': 'one_of_server_error'},
+  ],
+};
+
+/// Descriptor for `DocumentIntegrityReport`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List documentIntegrityReportDescriptor = $convert.base64Decode('ChdEb2N1bWVudEludGVncml0eVJlcG9ydBIVCgZkb2NfaWQYASABKAlSBWRvY0lkEhkKCGlzX3ZhbGlkGAIgASgIUgdpc1ZhbGlkEiUKDnJldmlzaW9uX2NvdW50GAMgASgDUg1yZXZpc2lvbkNvdW50EiMKDXBlbmRpbmdfY291bnQYBCABKANSDHBlbmRpbmdDb3VudBIeCgtsYXN0X3Jldl9pZBgFIAEoA1IJbGFzdFJldklkEjUKDm1pc3NpbmdfcmFuZ2VzGAYgAygLMg4uUmV2aXNpb25SYW5nZVINbWlzc2luZ1JhbmdlcxI1Cg5pbnZhbGlkX3JhbmdlcxgHIAMoCzIOLlJldmlzaW9uUmFuZ2VSDWludmFsaWRSYW5nZXMSJQoNY29tcG9zZV9lcnJvchgIIAEoCUgAUgxjb21wb3NlRXJyb3ISGwoJbG9jYWxfbWQ1GAkgASgJUghsb2NhbE1kNRIiCg1zZXJ2ZXJfcmV2X2lkGAogASgDUgtzZXJ2ZXJSZXZJZBIdCgpzZXJ2ZXJfbWQ1GAsgASgJUglzZXJ2ZXJNZDUSIwoMc2VydmVyX2Vycm9yGAwgASgJSAFSC3NlcnZlckVycm9yEhoKCHJlcGFpcmVkGA0gASgIUghyZXBhaXJlZEIWChRvbmVfb2ZfY29tcG9zZV9lcnJvckIVChNvbmVfb2Zfc2VydmVyX2Vycm9y');
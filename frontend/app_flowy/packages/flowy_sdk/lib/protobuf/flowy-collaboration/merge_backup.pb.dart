@@ -0,0 +1,231 @@
+///
+//  Generated code. Do not modify.
+//  source: merge_backup.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+import 'dart:core' as $core;
+
+import 'package:fixnum/fixnum.dart' as $fixnum;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+class DocumentMergeBackup extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'DocumentMergeBackup', createEmptyInstance: create)
+    ..aInt64(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'id')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'docId')
+    ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'baseRevId')
+    ..aInt64(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'localRevisionCount')
+    ..aOS(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'documentJson')
+    ..aInt64(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'createTime')
+    ..hasRequiredFields = false
+  ;
+
+  DocumentMergeBackup._() : super();
+  factory DocumentMergeBackup({
+    $fixnum.Int64? id,
+    $core.String? docId,
+    $fixnum.Int64? baseRevId,
+    $fixnum.Int64? localRevisionCount,
+    $core.String? documentJson,
+    $fixnum.Int64? createTime,
+  }) {
+    final _result = create();
+    if (id != null) {
+      _result.id = id;
+    }
+    if (docId != null) {
+      _result.docId = docId;
+    }
+    if (baseRevId != null) {
+      _result.baseRevId = baseRevId;
+    }
+    if (localRevisionCount != null) {
+      _result.localRevisionCount = localRevisionCount;
+    }
+    if (documentJson != null) {
+      _result.documentJson = documentJson;
+    }
+    if (createTime != null) {
+      _result.createTime = createTime;
+    }
+    return _result;
+  }
+  factory DocumentMergeBackup.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory DocumentMergeBackup.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  DocumentMergeBackup clone() => DocumentMergeBackup()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  DocumentMergeBackup copyWith(void Function(DocumentMergeBackup) updates) => super.copyWith((message) => updates(message as DocumentMergeBackup)) as DocumentMergeBackup; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static DocumentMergeBackup create() => DocumentMergeBackup._();
+  DocumentMergeBackup createEmptyInstance() => create();
+  static $pb.PbList<DocumentMergeBackup> createRepeated() => $pb.PbList<DocumentMergeBackup>();
+  @$core.pragma('dart2js:noInline')
+  static DocumentMergeBackup getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<DocumentMergeBackup>(create);
+  static DocumentMergeBackup? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $fixnum.Int64 get id => $_getI64(0);
+  @$pb.TagNumber(1)
+  set id($fixnum.Int64 v) { $_setInt64(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get docId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set docId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasDocId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearDocId() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $fixnum.Int64 get baseRevId => $_getI64(2);
+  @$pb.TagNumber(3)
+  set baseRevId($fixnum.Int64 v) { $_setInt64(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasBaseRevId() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearBaseRevId() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $fixnum.Int64 get localRevisionCount => $_getI64(3);
+  @$pb.TagNumber(4)
+  set localRevisionCount($fixnum.Int64 v) { $_setInt64(3, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasLocalRevisionCount() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearLocalRevisionCount() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $core.String get documentJson => $_getSZ(4);
+  @$pb.TagNumber(5)
+  set documentJson($core.String v) { $_setString(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasDocumentJson() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearDocumentJson() => clearField(5);
+
+  @$pb.TagNumber(6)
+  $fixnum.Int64 get createTime => $_getI64(5);
+  @$pb.TagNumber(6)
+  set createTime($fixnum.Int64 v) { $_setInt64(5, v); }
+  @$pb.TagNumber(6)
+  $core.bool hasCreateTime() => $_has(5);
+  @$pb.TagNumber(6)
+  void clearCreateTime() => clearField(6);
+}
+
+class RepeatedDocumentMergeBackup extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepeatedDocumentMergeBackup', createEmptyInstance: create)
+    ..pc<DocumentMergeBackup>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items', $pb.PbFieldType.PM, subBuilder: DocumentMergeBackup.create)
+    ..hasRequiredFields = false
+  ;
+
+  RepeatedDocumentMergeBackup._() : super();
+  factory RepeatedDocumentMergeBackup({
+    $core.Iterable<DocumentMergeBackup>? items,
+  }) {
+    final _result = create();
+    if (items != null) {
+      _result.items.addAll(items);
+    }
+    return _result;
+  }
+  factory RepeatedDocumentMergeBackup.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RepeatedDocumentMergeBackup.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RepeatedDocumentMergeBackup clone() => RepeatedDocumentMergeBackup()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RepeatedDocumentMergeBackup copyWith(void Function(RepeatedDocumentMergeBackup) updates) => super.copyWith((message) => updates(message as RepeatedDocumentMergeBackup)) as RepeatedDocumentMergeBackup; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RepeatedDocumentMergeBackup create() => RepeatedDocumentMergeBackup._();
+  RepeatedDocumentMergeBackup createEmptyInstance() => create();
+  static $pb.PbList<RepeatedDocumentMergeBackup> createRepeated() => $pb.PbList<RepeatedDocumentMergeBackup>();
+  @$core.pragma('dart2js:noInline')
+  static RepeatedDocumentMergeBackup getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RepeatedDocumentMergeBackup>(create);
+  static RepeatedDocumentMergeBackup? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.List<DocumentMergeBackup> get items => $_getList(0);
+}
+
+class DocumentMergeBackupId extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'DocumentMergeBackupId', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'docId')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'backupId')
+    ..hasRequiredFields = false
+  ;
+
+  DocumentMergeBackupId._() : super();
+  factory DocumentMergeBackupId({
+    $core.String? docId,
+    $fixnum.Int64? backupId,
+  }) {
+    final _result = create();
+    if (docId != null) {
+      _result.docId = docId;
+    }
+    if (backupId != null) {
+      _result.backupId = backupId;
+    }
+    return _result;
+  }
+  factory DocumentMergeBackupId.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory DocumentMergeBackupId.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  DocumentMergeBackupId clone() => DocumentMergeBackupId()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  DocumentMergeBackupId copyWith(void Function(DocumentMergeBackupId) updates) => super.copyWith((message) => updates(message as DocumentMergeBackupId)) as DocumentMergeBackupId; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static DocumentMergeBackupId create() => DocumentMergeBackupId._();
+  DocumentMergeBackupId createEmptyInstance() => create();
+  static $pb.PbList<DocumentMergeBackupId> createRepeated() => $pb.PbList<DocumentMergeBackupId>();
+  @$core.pragma('dart2js:noInline')
+  static DocumentMergeBackupId getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<DocumentMergeBackupId>(create);
+  static DocumentMergeBackupId? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get docId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set docId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasDocId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearDocId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get backupId => $_getI64(1);
+  @$pb.TagNumber(2)
+  set backupId($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasBackupId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearBackupId() => clearField(2);
+}
+
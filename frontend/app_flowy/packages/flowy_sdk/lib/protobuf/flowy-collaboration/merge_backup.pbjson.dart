@@ -0,0 +1,46 @@
+///
+//  Generated code. Do not modify.
+//  source: merge_backup.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use documentMergeBackupDescriptor instead')
+const DocumentMergeBackup$json = const {
+  '1': 'DocumentMergeBackup',
+  '2': const [
+    const {'1': 'id', '3': 1, '4': 1, '5': 3, '10': 'id'},
+    const {'1': 'doc_id', '3': 2, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'base_rev_id', '3': 3, '4': 1, '5': 3, '10': 'baseRevId'},
+    const {'1': 'local_revision_count', '3': 4, '4': 1, '5': 3, '10': 'localRevisionCount'},
+    const {'1': 'document_json', '3': 5, '4': 1, '5': 9, '10': 'documentJson'},
+    const {'1': 'create_time', '3': 6, '4': 1, '5': 3, '10': 'createTime'},
+  ],
+};
+
+/// Descriptor for `DocumentMergeBackup`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List documentMergeBackupDescriptor = $convert.base64Decode('ChNEb2N1bWVudE1lcmdlQmFja3VwEg4KAmlkGAEgASgDUgJpZBIVCgZkb2NfaWQYAiABKAlSBWRvY0lkEh4KC2Jhc2VfcmV2X2lkGAMgASgDUgliYXNlUmV2SWQSMAoUbG9jYWxfcmV2aXNpb25fY291bnQYBCABKANSEmxvY2FsUmV2aXNpb25Db3VudBIjCg1kb2N1bWVudF9qc29uGAUgASgJUgxkb2N1bWVudEpzb24SHwoLY3JlYXRlX3RpbWUYBiABKANSCmNyZWF0ZVRpbWU=');
+@$core.Deprecated('Use repeatedDocumentMergeBackupDescriptor instead')
+const RepeatedDocumentMergeBackup$json = const {
+  '1': 'RepeatedDocumentMergeBackup',
+  '2': const [
+    const {'1': 'items', '3': 1, '4': 3, '5': 11, '6': '.DocumentMergeBackup', '10': 'items'},
+  ],
+};
+
+/// Descriptor for `RepeatedDocumentMergeBackup`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List repeatedDocumentMergeBackupDescriptor = $convert.base64Decode('ChtSZXBlYXRlZERvY3VtZW50TWVyZ2VCYWNrdXASKgoFaXRlbXMYASADKAsyFC5Eb2N1bWVudE1lcmdlQmFja3VwUgVpdGVtcw==');
+@$core.Deprecated('Use documentMergeBackupIdDescriptor instead')
+const DocumentMergeBackupId$json = const {
+  '1': 'DocumentMergeBackupId',
+  '2': const [
+    const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'backup_id', '3': 2, '4': 1, '5': 3, '10': 'backupId'},
+  ],
+};
+
+/// Descriptor for `DocumentMergeBackupId`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List documentMergeBackupIdDescriptor = $convert.base64Decode('ChVEb2N1bWVudE1lcmdlQmFja3VwSWQSFQoGZG9jX2lkGAEgASgJUgVkb2NJZBIbCgliYWNrdXBfaWQYAiABKANSCGJhY2t1cElk');
@@ -0,0 +1,264 @@
+///
+//  Generated code. Do not modify.
+//  source: outline.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+import 'dart:core' as $core;
+
+import 'package:fixnum/fixnum.dart' as $fixnum;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+class OutlineItem extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'OutlineItem', createEmptyInstance: create)
+    ..aInt64(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'level')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'text')
+    ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'start')
+    ..aInt64(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'end')
+    ..hasRequiredFields = false
+  ;
+
+  OutlineItem._() : super();
+  factory OutlineItem({
+    $fixnum.Int64? level,
+    $core.String? text,
+    $fixnum.Int64? start,
+    $fixnum.Int64? end,
+  }) {
+    final _result = create();
+    if (level != null) {
+      _result.level = level;
+    }
+    if (text != null) {
+      _result.text = text;
+    }
+    if (start != null) {
+      _result.start = start;
+    }
+    if (end != null) {
+      _result.end = end;
+    }
+    return _result;
+  }
+  factory OutlineItem.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory OutlineItem.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  OutlineItem clone() => OutlineItem()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  OutlineItem copyWith(void Function(OutlineItem) updates) => super.copyWith((message) => updates(message as OutlineItem)) as OutlineItem; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static OutlineItem create() => OutlineItem._();
+  OutlineItem createEmptyInstance() => create();
+  static $pb.PbList<OutlineItem> createRepeated() => $pb.PbList<OutlineItem>();
+  @$core.pragma('dart2js:noInline')
+  static OutlineItem getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<OutlineItem>(create);
+  static OutlineItem? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $fixnum.Int64 get level => $_getI64(0);
+  @$pb.TagNumber(1)
+  set level($fixnum.Int64 v) { $_setInt64(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasLevel() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearLevel() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get text => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set text($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasText() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearText() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $fixnum.Int64 get start => $_getI64(2);
+  @$pb.TagNumber(3)
+  set start($fixnum.Int64 v) { $_setInt64(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasStart() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearStart() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $fixnum.Int64 get end => $_getI64(3);
+  @$pb.TagNumber(4)
+  set end($fixnum.Int64 v) { $_setInt64(3, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasEnd() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearEnd() => clearField(4);
+}
+
+class RepeatedOutlineItem extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepeatedOutlineItem', createEmptyInstance: create)
+    ..pc<OutlineItem>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items', $pb.PbFieldType.PM, subBuilder: OutlineItem.create)
+    ..hasRequiredFields = false
+  ;
+
+  RepeatedOutlineItem._() : super();
+  factory RepeatedOutlineItem({
+    $core.Iterable<OutlineItem>? items,
+  }) {
+    final _result = create();
+    if (items != null) {
+      _result.items.addAll(items);
+    }
+    return _result;
+  }
+  factory RepeatedOutlineItem.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RepeatedOutlineItem.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RepeatedOutlineItem clone() => RepeatedOutlineItem()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RepeatedOutlineItem copyWith(void Function(RepeatedOutlineItem) updates) => super.copyWith((message) => updates(message as RepeatedOutlineItem)) as RepeatedOutlineItem; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RepeatedOutlineItem create() => RepeatedOutlineItem._();
+  RepeatedOutlineItem createEmptyInstance() => create();
+  static $pb.PbList<RepeatedOutlineItem> createRepeated() => $pb.PbList<RepeatedOutlineItem>();
+  @$core.pragma('dart2js:noInline')
+  static RepeatedOutlineItem getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RepeatedOutlineItem>(create);
+  static RepeatedOutlineItem? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.List<OutlineItem> get items => $_getList(0);
+}
+
+class JumpToSectionRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'JumpToSectionRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'docId')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'index')
+    ..hasRequiredFields = false
+  ;
+
+  JumpToSectionRequest._() : super();
+  factory JumpToSectionRequest({
+    $core.String? docId,
+    $fixnum.Int64? index,
+  }) {
+    final _result = create();
+    if (docId != null) {
+      _result.docId = docId;
+    }
+    if (index != null) {
+      _result.index = index;
+    }
+    return _result;
+  }
+  factory JumpToSectionRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory JumpToSectionRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  JumpToSectionRequest clone() => JumpToSectionRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  JumpToSectionRequest copyWith(void Function(JumpToSectionRequest) updates) => super.copyWith((message) => updates(message as JumpToSectionRequest)) as JumpToSectionRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static JumpToSectionRequest create() => JumpToSectionRequest._();
+  JumpToSectionRequest createEmptyInstance() => create();
+  static $pb.PbList<JumpToSectionRequest> createRepeated() => $pb.PbList<JumpToSectionRequest>();
+  @$core.pragma('dart2js:noInline')
+  static JumpToSectionRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<JumpToSectionRequest>(create);
+  static JumpToSectionRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get docId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set docId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasDocId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearDocId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get index => $_getI64(1);
+  @$pb.TagNumber(2)
+  set index($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasIndex() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearIndex() => clearField(2);
+}
+
+class DocumentSection extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'DocumentSection', createEmptyInstance: create)
+    ..aInt64(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'start')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'end')
+    ..hasRequiredFields = false
+  ;
+
+  DocumentSection._() : super();
+  factory DocumentSection({
+    $fixnum.Int64? start,
+    $fixnum.Int64? end,
+  }) {
+    final _result = create();
+    if (start != null) {
+      _result.start = start;
+    }
+    if (end != null) {
+      _result.end = end;
+    }
+    return _result;
+  }
+  factory DocumentSection.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory DocumentSection.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  DocumentSection clone() => DocumentSection()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  DocumentSection copyWith(void Function(DocumentSection) updates) => super.copyWith((message) => updates(message as DocumentSection)) as DocumentSection; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static DocumentSection create() => DocumentSection._();
+  DocumentSection createEmptyInstance() => create();
+  static $pb.PbList<DocumentSection> createRepeated() => $pb.PbList<DocumentSection>();
+  @$core.pragma('dart2js:noInline')
+  static DocumentSection getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<DocumentSection>(create);
+  static DocumentSection? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $fixnum.Int64 get start => $_getI64(0);
+  @$pb.TagNumber(1)
+  set start($fixnum.Int64 v) { $_setInt64(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasStart() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearStart() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get end => $_getI64(1);
+  @$pb.TagNumber(2)
+  set end($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasEnd() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearEnd() => clearField(2);
+}
+
@@ -0,0 +1,55 @@
+///
+//  Generated code. Do not modify.
+//  source: outline.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use outlineItemDescriptor instead')
+const OutlineItem$json = const {
+  '1': 'OutlineItem',
+  '2': const [
+    const {'1': 'level', '3': 1, '4': 1, '5': 3, '10': 'level'},
+    const {'1': 'text', '3': 2, '4': 1, '5': 9, '10': 'text'},
+    const {'1': 'start', '3': 3, '4': 1, '5': 3, '10': 'start'},
+    const {'1': 'end', '3': 4, '4': 1, '5': 3, '10': 'end'},
+  ],
+};
+
+/// Descriptor for `OutlineItem`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List outlineItemDescriptor = $convert.base64Decode('CgtPdXRsaW5lSXRlbRIUCgVsZXZlbBgBIAEoA1IFbGV2ZWwSEgoEdGV4dBgCIAEoCVIEdGV4dBIUCgVzdGFydBgDIAEoA1IFc3RhcnQSEAoDZW5kGAQgASgDUgNlbmQ=');
+@$core.Deprecated('Use repeatedOutlineItemDescriptor instead')
+const RepeatedOutlineItem$json = const {
+  '1': 'RepeatedOutlineItem',
+  '2': const [
+    const {'1': 'items', '3': 1, '4': 3, '5': 11, '6': '.OutlineItem', '10': 'items'},
+  ],
+};
+
+/// Descriptor for `RepeatedOutlineItem`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List repeatedOutlineItemDescriptor = $convert.base64Decode('ChNSZXBlYXRlZE91dGxpbmVJdGVtEiIKBWl0ZW1zGAEgAygLMgwuT3V0bGluZUl0ZW1SBWl0ZW1z');
+@$core.Deprecated('Use jumpToSectionRequestDescriptor instead')
+const JumpToSectionRequest$json = const {
+  '1': 'JumpToSectionRequest',
+  '2': const [
+    const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'index', '3': 2, '4': 1, '5': 3, '10': 'index'},
+  ],
+};
+
+/// Descriptor for `JumpToSectionRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List jumpToSectionRequestDescriptor = $convert.base64Decode('ChRKdW1wVG9TZWN0aW9uUmVxdWVzdBIVCgZkb2NfaWQYASABKAlSBWRvY0lkEhQKBWluZGV4GAIgASgDUgVpbmRleA==');
+@$core.Deprecated('Use documentSectionDescriptor instead')
+const DocumentSection$json = const {
+  '1': 'DocumentSection',
+  '2': const [
+    const {'1': 'start', '3': 1, '4': 1, '5': 3, '10': 'start'},
+    const {'1': 'end', '3': 2, '4': 1, '5': 3, '10': 'end'},
+  ],
+};
+
+/// Descriptor for `DocumentSection`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List documentSectionDescriptor = $convert.base64Decode('Cg9Eb2N1bWVudFNlY3Rpb24SFAoFc3RhcnQYASABKANSBXN0YXJ0EhAKA2VuZBgCIAEoA1IDZW5k');
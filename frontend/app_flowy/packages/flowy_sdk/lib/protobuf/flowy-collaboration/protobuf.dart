@@ -3,3 +3,10 @@ export './folder_info.pb.dart';
 export './ws_data.pb.dart';
 export './revision.pb.dart';
 export './document_info.pb.dart';
+export './grid_info.pb.dart';
+export './board_info.pb.dart';
+export './comment_info.pb.dart';
+export './outline.pb.dart';
+export './statistics.pb.dart';
+export './integrity.pb.dart';
+export './merge_backup.pb.dart';
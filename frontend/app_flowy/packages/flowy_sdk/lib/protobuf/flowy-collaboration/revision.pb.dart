@@ -23,6 +23,7 @@ class Revision extends $pb.GeneratedMessage {
     ..aOS(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'objectId')
     ..e<RevType>(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'ty', $pb.PbFieldType.OE, defaultOrMaker: RevType.DeprecatedLocal, valueOf: RevType.valueOf, enumValues: RevType.values)
     ..aOS(7, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'userId')
+    ..aOS(8, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'keyId')
     ..hasRequiredFields = false
   ;
 
@@ -35,6 +36,7 @@ class Revision extends $pb.GeneratedMessage {
     $core.String? objectId,
     RevType? ty,
     $core.String? userId,
+    $core.String? keyId,
   }) {
     final _result = create();
     if (baseRevId != null) {
@@ -58,6 +60,9 @@ class Revision extends $pb.GeneratedMessage {
     if (userId != null) {
       _result.userId = userId;
     }
+    if (keyId != null) {
+      _result.keyId = keyId;
+    }
     return _result;
   }
   factory Revision.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -143,6 +148,15 @@ class Revision extends $pb.GeneratedMessage {
   $core.bool hasUserId() => $_has(6);
   @$pb.TagNumber(7)
   void clearUserId() => clearField(7);
+
+  @$pb.TagNumber(8)
+  $core.String get keyId => $_getSZ(7);
+  @$pb.TagNumber(8)
+  set keyId($core.String v) { $_setString(7, v); }
+  @$pb.TagNumber(8)
+  $core.bool hasKeyId() => $_has(7);
+  @$pb.TagNumber(8)
+  void clearKeyId() => clearField(8);
 }
 
 class RepeatedRevision extends $pb.GeneratedMessage {
@@ -0,0 +1,333 @@
+///
+//  Generated code. Do not modify.
+//  source: statistics.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+import 'dart:core' as $core;
+
+import 'package:fixnum/fixnum.dart' as $fixnum;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+class DocumentStatistics extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'DocumentStatistics', createEmptyInstance: create)
+    ..aInt64(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'words')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'characters')
+    ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'charactersWithoutSpaces')
+    ..aInt64(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'paragraphs')
+    ..aInt64(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'headers')
+    ..aInt64(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'readingTimeInSeconds')
+    ..hasRequiredFields = false
+  ;
+
+  DocumentStatistics._() : super();
+  factory DocumentStatistics({
+    $fixnum.Int64? words,
+    $fixnum.Int64? characters,
+    $fixnum.Int64? charactersWithoutSpaces,
+    $fixnum.Int64? paragraphs,
+    $fixnum.Int64? headers,
+    $fixnum.Int64? readingTimeInSeconds,
+  }) {
+    final _result = create();
+    if (words != null) {
+      _result.words = words;
+    }
+    if (characters != null) {
+      _result.characters = characters;
+    }
+    if (charactersWithoutSpaces != null) {
+      _result.charactersWithoutSpaces = charactersWithoutSpaces;
+    }
+    if (paragraphs != null) {
+      _result.paragraphs = paragraphs;
+    }
+    if (headers != null) {
+      _result.headers = headers;
+    }
+    if (readingTimeInSeconds != null) {
+      _result.readingTimeInSeconds = readingTimeInSeconds;
+    }
+    return _result;
+  }
+  factory DocumentStatistics.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory DocumentStatistics.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  DocumentStatistics clone() => DocumentStatistics()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  DocumentStatistics copyWith(void Function(DocumentStatistics) updates) => super.copyWith((message) => updates(message as DocumentStatistics)) as DocumentStatistics; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static DocumentStatistics create() => DocumentStatistics._();
+  DocumentStatistics createEmptyInstance() => create();
+  static $pb.PbList<DocumentStatistics> createRepeated() => $pb.PbList<DocumentStatistics>();
+  @$core.pragma('dart2js:noInline')
+  static DocumentStatistics getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<DocumentStatistics>(create);
+  static DocumentStatistics? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $fixnum.Int64 get words => $_getI64(0);
+  @$pb.TagNumber(1)
+  set words($fixnum.Int64 v) { $_setInt64(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasWords() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearWords() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get characters => $_getI64(1);
+  @$pb.TagNumber(2)
+  set characters($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasCharacters() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearCharacters() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $fixnum.Int64 get charactersWithoutSpaces => $_getI64(2);
+  @$pb.TagNumber(3)
+  set charactersWithoutSpaces($fixnum.Int64 v) { $_setInt64(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasCharactersWithoutSpaces() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearCharactersWithoutSpaces() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $fixnum.Int64 get paragraphs => $_getI64(3);
+  @$pb.TagNumber(4)
+  set paragraphs($fixnum.Int64 v) { $_setInt64(3, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasParagraphs() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearParagraphs() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $fixnum.Int64 get headers => $_getI64(4);
+  @$pb.TagNumber(5)
+  set headers($fixnum.Int64 v) { $_setInt64(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasHeaders() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearHeaders() => clearField(5);
+
+  @$pb.TagNumber(6)
+  $fixnum.Int64 get readingTimeInSeconds => $_getI64(5);
+  @$pb.TagNumber(6)
+  set readingTimeInSeconds($fixnum.Int64 v) { $_setInt64(5, v); }
+  @$pb.TagNumber(6)
+  $core.bool hasReadingTimeInSeconds() => $_has(5);
+  @$pb.TagNumber(6)
+  void clearReadingTimeInSeconds() => clearField(6);
+}
+
+enum DocumentStatisticsRequest_OneOfStart {
+  start, 
+  notSet
+}
+
+enum DocumentStatisticsRequest_OneOfEnd {
+  end, 
+  notSet
+}
+
+class DocumentStatisticsRequest extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, DocumentStatisticsRequest_OneOfStart> _DocumentStatisticsRequest_OneOfStartByTag = {
+    2 : DocumentStatisticsRequest_OneOfStart.start,
+    0 : DocumentStatisticsRequest_OneOfStart.notSet
+  };
+  static const $core.Map<$core.int, DocumentStatisticsRequest_OneOfEnd> _DocumentStatisticsRequest_OneOfEndByTag = {
+    3 : DocumentStatisticsRequest_OneOfEnd.end,
+    0 : DocumentStatisticsRequest_OneOfEnd.notSet
+  };
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'DocumentStatisticsRequest', createEmptyInstance: create)
+    ..oo(0, [2])
+    ..oo(1, [3])
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'docId')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'start')
+    ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'end')
+    ..hasRequiredFields = false
+  ;
+
+  DocumentStatisticsRequest._() : super();
+  factory DocumentStatisticsRequest({
+    $core.String? docId,
+    $fixnum.Int64? start,
+    $fixnum.Int64? end,
+  }) {
+    final _result = create();
+    if (docId != null) {
+      _result.docId = docId;
+    }
+    if (start != null) {
+      _result.start = start;
+    }
+    if (end != null) {
+      _result.end = end;
+    }
+    return _result;
+  }
+  factory DocumentStatisticsRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory DocumentStatisticsRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  DocumentStatisticsRequest clone() => DocumentStatisticsRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  DocumentStatisticsRequest copyWith(void Function(DocumentStatisticsRequest) updates) => super.copyWith((message) => updates(message as DocumentStatisticsRequest)) as DocumentStatisticsRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static DocumentStatisticsRequest create() => DocumentStatisticsRequest._();
+  DocumentStatisticsRequest createEmptyInstance() => create();
+  static $pb.PbList<DocumentStatisticsRequest> createRepeated() => $pb.PbList<DocumentStatisticsRequest>();
+  @$core.pragma('dart2js:noInline')
+  static DocumentStatisticsRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<DocumentStatisticsRequest>(create);
+  static DocumentStatisticsRequest? _defaultInstance;
+
+  DocumentStatisticsRequest_OneOfStart whichOneOfStart() => _DocumentStatisticsRequest_OneOfStartByTag[$_whichOneof(0)]!;
+  void clearOneOfStart() => clearField($_whichOneof(0));
+
+  DocumentStatisticsRequest_OneOfEnd whichOneOfEnd() => _DocumentStatisticsRequest_OneOfEndByTag[$_whichOneof(1)]!;
+  void clearOneOfEnd() => clearField($_whichOneof(1));
+
+  @$pb.TagNumber(1)
+  $core.String get docId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set docId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasDocId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearDocId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get start => $_getI64(1);
+  @$pb.TagNumber(2)
+  set start($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasStart() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearStart() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $fixnum.Int64 get end => $_getI64(2);
+  @$pb.TagNumber(3)
+  set end($fixnum.Int64 v) { $_setInt64(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasEnd() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearEnd() => clearField(3);
+}
+
+class OpenDocumentsUsage extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'OpenDocumentsUsage', createEmptyInstance: create)
+    ..aInt64(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'count')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'estimatedSize')
+    ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'maxCount')
+    ..aInt64(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'maxSize')
+    ..aInt64(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'evictedCount')
+    ..hasRequiredFields = false
+  ;
+
+  OpenDocumentsUsage._() : super();
+  factory OpenDocumentsUsage({
+    $fixnum.Int64? count,
+    $fixnum.Int64? estimatedSize,
+    $fixnum.Int64? maxCount,
+    $fixnum.Int64? maxSize,
+    $fixnum.Int64? evictedCount,
+  }) {
+    final _result = create();
+    if (count != null) {
+      _result.count = count;
+    }
+    if (estimatedSize != null) {
+      _result.estimatedSize = estimatedSize;
+    }
+    if (maxCount != null) {
+      _result.maxCount = maxCount;
+    }
+    if (maxSize != null) {
+      _result.maxSize = maxSize;
+    }
+    if (evictedCount != null) {
+      _result.evictedCount = evictedCount;
+    }
+    return _result;
+  }
+  factory OpenDocumentsUsage.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory OpenDocumentsUsage.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  OpenDocumentsUsage clone() => OpenDocumentsUsage()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  OpenDocumentsUsage copyWith(void Function(OpenDocumentsUsage) updates) => super.copyWith((message) => updates(message as OpenDocumentsUsage)) as OpenDocumentsUsage; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static OpenDocumentsUsage create() => OpenDocumentsUsage._();
+  OpenDocumentsUsage createEmptyInstance() => create();
+  static $pb.PbList<OpenDocumentsUsage> createRepeated() => $pb.PbList<OpenDocumentsUsage>();
+  @$core.pragma('dart2js:noInline')
+  static OpenDocumentsUsage getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<OpenDocumentsUsage>(create);
+  static OpenDocumentsUsage? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $fixnum.Int64 get count => $_getI64(0);
+  @$pb.TagNumber(1)
+  set count($fixnum.Int64 v) { $_setInt64(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasCount() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearCount() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get estimatedSize => $_getI64(1);
+  @$pb.TagNumber(2)
+  set estimatedSize($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasEstimatedSize() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearEstimatedSize() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $fixnum.Int64 get maxCount => $_getI64(2);
+  @$pb.TagNumber(3)
+  set maxCount($fixnum.Int64 v) { $_setInt64(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasMaxCount() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearMaxCount() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $fixnum.Int64 get maxSize => $_getI64(3);
+  @$pb.TagNumber(4)
+  set maxSize($fixnum.Int64 v) { $_setInt64(3, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasMaxSize() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearMaxSize() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $fixnum.Int64 get evictedCount => $_getI64(4);
+  @$pb.TagNumber(5)
+  set evictedCount($fixnum.Int64 v) { $_setInt64(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasEvictedCount() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearEvictedCount() => clearField(5);
+}
+
@@ -0,0 +1,55 @@
+///
+//  Generated code. Do not modify.
+//  source: statistics.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use documentStatisticsDescriptor instead')
+const DocumentStatistics$json = const {
+  '1': 'DocumentStatistics',
+  '2': const [
+    const {'1': 'words', '3': 1, '4': 1, '5': 3, '10': 'words'},
+    const {'1': 'characters', '3': 2, '4': 1, '5': 3, '10': 'characters'},
+    const {'1': 'characters_without_spaces', '3': 3, '4': 1, '5': 3, '10': 'charactersWithoutSpaces'},
+    const {'1': 'paragraphs', '3': 4, '4': 1, '5': 3, '10': 'paragraphs'},
+    const {'1': 'headers', '3': 5, '4': 1, '5': 3, '10': 'headers'},
+    const {'1': 'reading_time_in_seconds', '3': 6, '4': 1, '5': 3, '10': 'readingTimeInSeconds'},
+  ],
+};
+
+/// Descriptor for `DocumentStatistics`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List documentStatisticsDescriptor = $convert.base64Decode('ChJEb2N1bWVudFN0YXRpc3RpY3MSFAoFd29yZHMYASABKANSBXdvcmRzEh4KCmNoYXJhY3RlcnMYAiABKANSCmNoYXJhY3RlcnMSOgoZY2hhcmFjdGVyc193aXRob3V0X3NwYWNlcxgDIAEoA1IXY2hhcmFjdGVyc1dpdGhvdXRTcGFjZXMSHgoKcGFyYWdyYXBocxgEIAEoA1IKcGFyYWdyYXBocxIYCgdoZWFkZXJzGAUgASgDUgdoZWFkZXJzEjUKF3JlYWRpbmdfdGltZV9pbl9zZWNvbmRzGAYgASgDUhRyZWFkaW5nVGltZUluU2Vjb25kcw==');
+@$core.Deprecated('Use documentStatisticsRequestDescriptor instead')
+const DocumentStatisticsRequest$json = const {
+  '1': 'DocumentStatisticsRequest',
+  '2': const [
+    const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'start', '3': 2, '4': 1, '5': 3, '9': 0, '10': 'start'},
+    const {'1': 'end', '3': 3, '4': 1, '5': 3, '9': 1, '10': 'end'},
+  ],
+  '8': const [
+    const {'1': 'one_of_start'},
+    const {'1': 'one_of_end'},
+  ],
+};
+
+/// Descriptor for `DocumentStatisticsRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List documentStatisticsRequestDescriptor = $convert.base64Decode('ChlEb2N1bWVudFN0YXRpc3RpY3NSZXF1ZXN0EhUKBmRvY19pZBgBIAEoCVIFZG9jSWQSFgoFc3RhcnQYAiABKANIAFIFc3RhcnQSEgoDZW5kGAMgASgDSAFSA2VuZEIOCgxvbmVfb2Zfc3RhcnRCDAoKb25lX29mX2VuZA==');
+@$core.Deprecated('Use openDocumentsUsageDescriptor instead')
+const OpenDocumentsUsage$json = const {
+  '1': 'OpenDocumentsUsage',
+  '2': const [
+    const {'1': 'count', '3': 1, '4': 1, '5': 3, '10': 'count'},
+    const {'1': 'estimated_size', '3': 2, '4': 1, '5': 3, '10': 'estimatedSize'},
+    const {'1': 'max_count', '3': 3, '4': 1, '5': 3, '10': 'maxCount'},
+    const {'1': 'max_size', '3': 4, '4': 1, '5': 3, '10': 'maxSize'},
+    const {'1': 'evicted_count', '3': 5, '4': 1, '5': 3, '10': 'evictedCount'},
+  ],
+};
+
+/// Descriptor for `OpenDocumentsUsage`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List openDocumentsUsageDescriptor = $convert.base64Decode('ChJPcGVuRG9jdW1lbnRzVXNhZ2USFAoFY291bnQYASABKANSBWNvdW50EiUKDmVzdGltYXRlZF9zaXplGAIgASgDUg1lc3RpbWF0ZWRTaXplEhsKCW1heF9jb3VudBgDIAEoA1IIbWF4Q291bnQSGQoIbWF4X3NpemUYBCABKANSB21heFNpemUSIwoNZXZpY3RlZF9jb3VudBgFIAEoA1IMZXZpY3RlZENvdW50');
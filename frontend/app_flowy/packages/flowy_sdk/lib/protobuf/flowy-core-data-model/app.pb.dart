@@ -22,6 +22,7 @@ class App extends $pb.GeneratedMessage {
     ..aInt64(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'version')
     ..aInt64(7, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'modifiedTime')
     ..aInt64(8, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'createTime')
+    ..aOM<ColorStyle>(9, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'colorStyle', subBuilder: ColorStyle.create)
     ..hasRequiredFields = false
   ;
 
@@ -35,6 +36,7 @@ class App extends $pb.GeneratedMessage {
     $fixnum.Int64? version,
     $fixnum.Int64? modifiedTime,
     $fixnum.Int64? createTime,
+    ColorStyle? colorStyle,
   }) {
     final _result = create();
     if (id != null) {
@@ -61,6 +63,9 @@ class App extends $pb.GeneratedMessage {
     if (createTime != null) {
       _result.createTime = createTime;
     }
+    if (colorStyle != null) {
+      _result.colorStyle = colorStyle;
+    }
     return _result;
   }
   factory App.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -157,6 +162,17 @@ class App extends $pb.GeneratedMessage {
   $core.bool hasCreateTime() => $_has(7);
   @$pb.TagNumber(8)
   void clearCreateTime() => clearField(8);
+
+  @$pb.TagNumber(9)
+  ColorStyle get colorStyle => $_getN(8);
+  @$pb.TagNumber(9)
+  set colorStyle(ColorStyle v) { setField(9, v); }
+  @$pb.TagNumber(9)
+  $core.bool hasColorStyle() => $_has(8);
+  @$pb.TagNumber(9)
+  void clearColorStyle() => clearField(9);
+  @$pb.TagNumber(9)
+  ColorStyle ensureColorStyle() => $_ensure(8);
 }
 
 class RepeatedApp extends $pb.GeneratedMessage {
@@ -0,0 +1,1284 @@
+///
+//  Generated code. Do not modify.
+//  source: board.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+import 'dart:core' as $core;
+
+import 'package:protobuf/protobuf.dart' as $pb;
+
+class Board extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'Board', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'id')
+    ..aOM<BoardProperty>(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'property', subBuilder: BoardProperty.create)
+    ..pc<BoardColumn>(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'columns', $pb.PbFieldType.PM, subBuilder: BoardColumn.create)
+    ..hasRequiredFields = false
+  ;
+
+  Board._() : super();
+  factory Board({
+    $core.String? id,
+    BoardProperty? property,
+    $core.Iterable<BoardColumn>? columns,
+  }) {
+    final _result = create();
+    if (id != null) {
+      _result.id = id;
+    }
+    if (property != null) {
+      _result.property = property;
+    }
+    if (columns != null) {
+      _result.columns.addAll(columns);
+    }
+    return _result;
+  }
+  factory Board.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory Board.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  Board clone() => Board()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  Board copyWith(void Function(Board) updates) => super.copyWith((message) => updates(message as Board)) as Board; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static Board create() => Board._();
+  Board createEmptyInstance() => create();
+  static $pb.PbList<Board> createRepeated() => $pb.PbList<Board>();
+  @$core.pragma('dart2js:noInline')
+  static Board getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<Board>(create);
+  static Board? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get id => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set id($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  BoardProperty get property => $_getN(1);
+  @$pb.TagNumber(2)
+  set property(BoardProperty v) { setField(2, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasProperty() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearProperty() => clearField(2);
+  @$pb.TagNumber(2)
+  BoardProperty ensureProperty() => $_ensure(1);
+
+  @$pb.TagNumber(3)
+  $core.List<BoardColumn> get columns => $_getList(2);
+}
+
+class BoardProperty extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'BoardProperty', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'key')
+    ..pPS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'options')
+    ..hasRequiredFields = false
+  ;
+
+  BoardProperty._() : super();
+  factory BoardProperty({
+    $core.String? key,
+    $core.Iterable<$core.String>? options,
+  }) {
+    final _result = create();
+    if (key != null) {
+      _result.key = key;
+    }
+    if (options != null) {
+      _result.options.addAll(options);
+    }
+    return _result;
+  }
+  factory BoardProperty.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory BoardProperty.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  BoardProperty clone() => BoardProperty()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  BoardProperty copyWith(void Function(BoardProperty) updates) => super.copyWith((message) => updates(message as BoardProperty)) as BoardProperty; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static BoardProperty create() => BoardProperty._();
+  BoardProperty createEmptyInstance() => create();
+  static $pb.PbList<BoardProperty> createRepeated() => $pb.PbList<BoardProperty>();
+  @$core.pragma('dart2js:noInline')
+  static BoardProperty getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<BoardProperty>(create);
+  static BoardProperty? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get key => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set key($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasKey() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearKey() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.List<$core.String> get options => $_getList(1);
+}
+
+class BoardColumn extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'BoardColumn', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
+    ..pc<Card>(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'cards', $pb.PbFieldType.PM, subBuilder: Card.create)
+    ..hasRequiredFields = false
+  ;
+
+  BoardColumn._() : super();
+  factory BoardColumn({
+    $core.String? name,
+    $core.Iterable<Card>? cards,
+  }) {
+    final _result = create();
+    if (name != null) {
+      _result.name = name;
+    }
+    if (cards != null) {
+      _result.cards.addAll(cards);
+    }
+    return _result;
+  }
+  factory BoardColumn.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory BoardColumn.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  BoardColumn clone() => BoardColumn()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  BoardColumn copyWith(void Function(BoardColumn) updates) => super.copyWith((message) => updates(message as BoardColumn)) as BoardColumn; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static BoardColumn create() => BoardColumn._();
+  BoardColumn createEmptyInstance() => create();
+  static $pb.PbList<BoardColumn> createRepeated() => $pb.PbList<BoardColumn>();
+  @$core.pragma('dart2js:noInline')
+  static BoardColumn getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<BoardColumn>(create);
+  static BoardColumn? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get name => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set name($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasName() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearName() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.List<Card> get cards => $_getList(1);
+}
+
+class Card extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'Card', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'id')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'title')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'description')
+    ..pc<CardProperty>(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'properties', $pb.PbFieldType.PM, subBuilder: CardProperty.create)
+    ..hasRequiredFields = false
+  ;
+
+  Card._() : super();
+  factory Card({
+    $core.String? id,
+    $core.String? title,
+    $core.String? description,
+    $core.Iterable<CardProperty>? properties,
+  }) {
+    final _result = create();
+    if (id != null) {
+      _result.id = id;
+    }
+    if (title != null) {
+      _result.title = title;
+    }
+    if (description != null) {
+      _result.description = description;
+    }
+    if (properties != null) {
+      _result.properties.addAll(properties);
+    }
+    return _result;
+  }
+  factory Card.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory Card.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  Card clone() => Card()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  Card copyWith(void Function(Card) updates) => super.copyWith((message) => updates(message as Card)) as Card; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static Card create() => Card._();
+  Card createEmptyInstance() => create();
+  static $pb.PbList<Card> createRepeated() => $pb.PbList<Card>();
+  @$core.pragma('dart2js:noInline')
+  static Card getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<Card>(create);
+  static Card? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get id => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set id($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get title => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set title($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasTitle() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearTitle() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get description => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set description($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasDescription() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearDescription() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $core.List<CardProperty> get properties => $_getList(3);
+}
+
+class CardProperty extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'CardProperty', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'key')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'value')
+    ..hasRequiredFields = false
+  ;
+
+  CardProperty._() : super();
+  factory CardProperty({
+    $core.String? key,
+    $core.String? value,
+  }) {
+    final _result = create();
+    if (key != null) {
+      _result.key = key;
+    }
+    if (value != null) {
+      _result.value = value;
+    }
+    return _result;
+  }
+  factory CardProperty.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory CardProperty.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  CardProperty clone() => CardProperty()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  CardProperty copyWith(void Function(CardProperty) updates) => super.copyWith((message) => updates(message as CardProperty)) as CardProperty; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static CardProperty create() => CardProperty._();
+  CardProperty createEmptyInstance() => create();
+  static $pb.PbList<CardProperty> createRepeated() => $pb.PbList<CardProperty>();
+  @$core.pragma('dart2js:noInline')
+  static CardProperty getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<CardProperty>(create);
+  static CardProperty? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get key => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set key($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasKey() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearKey() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get value => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set value($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasValue() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearValue() => clearField(2);
+}
+
+class QueryBoardRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'QueryBoardRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'boardId')
+    ..hasRequiredFields = false
+  ;
+
+  QueryBoardRequest._() : super();
+  factory QueryBoardRequest({
+    $core.String? boardId,
+  }) {
+    final _result = create();
+    if (boardId != null) {
+      _result.boardId = boardId;
+    }
+    return _result;
+  }
+  factory QueryBoardRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory QueryBoardRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  QueryBoardRequest clone() => QueryBoardRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  QueryBoardRequest copyWith(void Function(QueryBoardRequest) updates) => super.copyWith((message) => updates(message as QueryBoardRequest)) as QueryBoardRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static QueryBoardRequest create() => QueryBoardRequest._();
+  QueryBoardRequest createEmptyInstance() => create();
+  static $pb.PbList<QueryBoardRequest> createRepeated() => $pb.PbList<QueryBoardRequest>();
+  @$core.pragma('dart2js:noInline')
+  static QueryBoardRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<QueryBoardRequest>(create);
+  static QueryBoardRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get boardId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set boardId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBoardId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBoardId() => clearField(1);
+}
+
+class BoardId extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'BoardId', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'boardId')
+    ..hasRequiredFields = false
+  ;
+
+  BoardId._() : super();
+  factory BoardId({
+    $core.String? boardId,
+  }) {
+    final _result = create();
+    if (boardId != null) {
+      _result.boardId = boardId;
+    }
+    return _result;
+  }
+  factory BoardId.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory BoardId.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  BoardId clone() => BoardId()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  BoardId copyWith(void Function(BoardId) updates) => super.copyWith((message) => updates(message as BoardId)) as BoardId; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static BoardId create() => BoardId._();
+  BoardId createEmptyInstance() => create();
+  static $pb.PbList<BoardId> createRepeated() => $pb.PbList<BoardId>();
+  @$core.pragma('dart2js:noInline')
+  static BoardId getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<BoardId>(create);
+  static BoardId? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get boardId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set boardId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBoardId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBoardId() => clearField(1);
+}
+
+class UpdateBoardPropertyRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'UpdateBoardPropertyRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'boardId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'key')
+    ..pPS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'options')
+    ..hasRequiredFields = false
+  ;
+
+  UpdateBoardPropertyRequest._() : super();
+  factory UpdateBoardPropertyRequest({
+    $core.String? boardId,
+    $core.String? key,
+    $core.Iterable<$core.String>? options,
+  }) {
+    final _result = create();
+    if (boardId != null) {
+      _result.boardId = boardId;
+    }
+    if (key != null) {
+      _result.key = key;
+    }
+    if (options != null) {
+      _result.options.addAll(options);
+    }
+    return _result;
+  }
+  factory UpdateBoardPropertyRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory UpdateBoardPropertyRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  UpdateBoardPropertyRequest clone() => UpdateBoardPropertyRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  UpdateBoardPropertyRequest copyWith(void Function(UpdateBoardPropertyRequest) updates) => super.copyWith((message) => updates(message as UpdateBoardPropertyRequest)) as UpdateBoardPropertyRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static UpdateBoardPropertyRequest create() => UpdateBoardPropertyRequest._();
+  UpdateBoardPropertyRequest createEmptyInstance() => create();
+  static $pb.PbList<UpdateBoardPropertyRequest> createRepeated() => $pb.PbList<UpdateBoardPropertyRequest>();
+  @$core.pragma('dart2js:noInline')
+  static UpdateBoardPropertyRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<UpdateBoardPropertyRequest>(create);
+  static UpdateBoardPropertyRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get boardId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set boardId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBoardId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBoardId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get key => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set key($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasKey() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearKey() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.List<$core.String> get options => $_getList(2);
+}
+
+class UpdateBoardPropertyParams extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'UpdateBoardPropertyParams', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'boardId')
+    ..aOM<BoardProperty>(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'property', subBuilder: BoardProperty.create)
+    ..hasRequiredFields = false
+  ;
+
+  UpdateBoardPropertyParams._() : super();
+  factory UpdateBoardPropertyParams({
+    $core.String? boardId,
+    BoardProperty? property,
+  }) {
+    final _result = create();
+    if (boardId != null) {
+      _result.boardId = boardId;
+    }
+    if (property != null) {
+      _result.property = property;
+    }
+    return _result;
+  }
+  factory UpdateBoardPropertyParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory UpdateBoardPropertyParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  UpdateBoardPropertyParams clone() => UpdateBoardPropertyParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  UpdateBoardPropertyParams copyWith(void Function(UpdateBoardPropertyParams) updates) => super.copyWith((message) => updates(message as UpdateBoardPropertyParams)) as UpdateBoardPropertyParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static UpdateBoardPropertyParams create() => UpdateBoardPropertyParams._();
+  UpdateBoardPropertyParams createEmptyInstance() => create();
+  static $pb.PbList<UpdateBoardPropertyParams> createRepeated() => $pb.PbList<UpdateBoardPropertyParams>();
+  @$core.pragma('dart2js:noInline')
+  static UpdateBoardPropertyParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<UpdateBoardPropertyParams>(create);
+  static UpdateBoardPropertyParams? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get boardId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set boardId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBoardId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBoardId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  BoardProperty get property => $_getN(1);
+  @$pb.TagNumber(2)
+  set property(BoardProperty v) { setField(2, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasProperty() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearProperty() => clearField(2);
+  @$pb.TagNumber(2)
+  BoardProperty ensureProperty() => $_ensure(1);
+}
+
+class CreateCardRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'CreateCardRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'boardId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'title')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'column')
+    ..hasRequiredFields = false
+  ;
+
+  CreateCardRequest._() : super();
+  factory CreateCardRequest({
+    $core.String? boardId,
+    $core.String? title,
+    $core.String? column,
+  }) {
+    final _result = create();
+    if (boardId != null) {
+      _result.boardId = boardId;
+    }
+    if (title != null) {
+      _result.title = title;
+    }
+    if (column != null) {
+      _result.column = column;
+    }
+    return _result;
+  }
+  factory CreateCardRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory CreateCardRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  CreateCardRequest clone() => CreateCardRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  CreateCardRequest copyWith(void Function(CreateCardRequest) updates) => super.copyWith((message) => updates(message as CreateCardRequest)) as CreateCardRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static CreateCardRequest create() => CreateCardRequest._();
+  CreateCardRequest createEmptyInstance() => create();
+  static $pb.PbList<CreateCardRequest> createRepeated() => $pb.PbList<CreateCardRequest>();
+  @$core.pragma('dart2js:noInline')
+  static CreateCardRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<CreateCardRequest>(create);
+  static CreateCardRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get boardId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set boardId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBoardId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBoardId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get title => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set title($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasTitle() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearTitle() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get column => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set column($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasColumn() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearColumn() => clearField(3);
+}
+
+class CreateCardParams extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'CreateCardParams', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'boardId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'title')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'column')
+    ..hasRequiredFields = false
+  ;
+
+  CreateCardParams._() : super();
+  factory CreateCardParams({
+    $core.String? boardId,
+    $core.String? title,
+    $core.String? column,
+  }) {
+    final _result = create();
+    if (boardId != null) {
+      _result.boardId = boardId;
+    }
+    if (title != null) {
+      _result.title = title;
+    }
+    if (column != null) {
+      _result.column = column;
+    }
+    return _result;
+  }
+  factory CreateCardParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory CreateCardParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  CreateCardParams clone() => CreateCardParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  CreateCardParams copyWith(void Function(CreateCardParams) updates) => super.copyWith((message) => updates(message as CreateCardParams)) as CreateCardParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static CreateCardParams create() => CreateCardParams._();
+  CreateCardParams createEmptyInstance() => create();
+  static $pb.PbList<CreateCardParams> createRepeated() => $pb.PbList<CreateCardParams>();
+  @$core.pragma('dart2js:noInline')
+  static CreateCardParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<CreateCardParams>(create);
+  static CreateCardParams? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get boardId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set boardId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBoardId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBoardId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get title => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set title($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasTitle() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearTitle() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get column => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set column($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasColumn() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearColumn() => clearField(3);
+}
+
+enum UpdateCardRequest_OneOfTitle {
+  title, 
+  notSet
+}
+
+enum UpdateCardRequest_OneOfDescription {
+  description, 
+  notSet
+}
+
+class UpdateCardRequest extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, UpdateCardRequest_OneOfTitle> _UpdateCardRequest_OneOfTitleByTag = {
+    3 : UpdateCardRequest_OneOfTitle.title,
+    0 : UpdateCardRequest_OneOfTitle.notSet
+  };
+  static const $core.Map<$core.int, UpdateCardRequest_OneOfDescription> _UpdateCardRequest_OneOfDescriptionByTag = {
+    4 : UpdateCardRequest_OneOfDescription.description,
+    0 : UpdateCardRequest_OneOfDescription.notSet
+  };
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'UpdateCardRequest', createEmptyInstance: create)
+    ..oo(0, [3])
+    ..oo(1, [4])
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'boardId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'cardId')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'title')
+    ..aOS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'description')
+    ..pc<CardProperty>(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'properties', $pb.PbFieldType.PM, subBuilder: CardProperty.create)
+    ..hasRequiredFields = false
+  ;
+
+  UpdateCardRequest._() : super();
+  factory UpdateCardRequest({
+    $core.String? boardId,
+    $core.String? cardId,
+    $core.String? title,
+    $core.String? description,
+    $core.Iterable<CardProperty>? properties,
+  }) {
+    final _result = create();
+    if (boardId != null) {
+      _result.boardId = boardId;
+    }
+    if (cardId != null) {
+      _result.cardId = cardId;
+    }
+    if (title != null) {
+      _result.title = title;
+    }
+    if (description != null) {
+      _result.description = description;
+    }
+    if (properties != null) {
+      _result.properties.addAll(properties);
+    }
+    return _result;
+  }
+  factory UpdateCardRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory UpdateCardRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  UpdateCardRequest clone() => UpdateCardRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  UpdateCardRequest copyWith(void Function(UpdateCardRequest) updates) => super.copyWith((message) => updates(message as UpdateCardRequest)) as UpdateCardRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static UpdateCardRequest create() => UpdateCardRequest._();
+  UpdateCardRequest createEmptyInstance() => create();
+  static $pb.PbList<UpdateCardRequest> createRepeated() => $pb.PbList<UpdateCardRequest>();
+  @$core.pragma('dart2js:noInline')
+  static UpdateCardRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<UpdateCardRequest>(create);
+  static UpdateCardRequest? _defaultInstance;
+
+  UpdateCardRequest_OneOfTitle whichOneOfTitle() => _UpdateCardRequest_OneOfTitleByTag[$_whichOneof(0)]!;
+  void clearOneOfTitle() => clearField($_whichOneof(0));
+
+  UpdateCardRequest_OneOfDescription whichOneOfDescription() => _UpdateCardRequest_OneOfDescriptionByTag[$_whichOneof(1)]!;
+  void clearOneOfDescription() => clearField($_whichOneof(1));
+
+  @$pb.TagNumber(1)
+  $core.String get boardId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set boardId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBoardId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBoardId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get cardId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set cardId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasCardId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearCardId() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get title => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set title($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasTitle() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearTitle() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $core.String get description => $_getSZ(3);
+  @$pb.TagNumber(4)
+  set description($core.String v) { $_setString(3, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasDescription() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearDescription() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $core.List<CardProperty> get properties => $_getList(4);
+}
+
+enum UpdateCardParams_OneOfTitle {
+  title, 
+  notSet
+}
+
+enum UpdateCardParams_OneOfDescription {
+  description, 
+  notSet
+}
+
+class UpdateCardParams extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, UpdateCardParams_OneOfTitle> _UpdateCardParams_OneOfTitleByTag = {
+    3 : UpdateCardParams_OneOfTitle.title,
+    0 : UpdateCardParams_OneOfTitle.notSet
+  };
+  static const $core.Map<$core.int, UpdateCardParams_OneOfDescription> _UpdateCardParams_OneOfDescriptionByTag = {
+    4 : UpdateCardParams_OneOfDescription.description,
+    0 : UpdateCardParams_OneOfDescription.notSet
+  };
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'UpdateCardParams', createEmptyInstance: create)
+    ..oo(0, [3])
+    ..oo(1, [4])
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'boardId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'cardId')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'title')
+    ..aOS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'description')
+    ..pc<CardProperty>(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'properties', $pb.PbFieldType.PM, subBuilder: CardProperty.create)
+    ..hasRequiredFields = false
+  ;
+
+  UpdateCardParams._() : super();
+  factory UpdateCardParams({
+    $core.String? boardId,
+    $core.String? cardId,
+    $core.String? title,
+    $core.String? description,
+    $core.Iterable<CardProperty>? properties,
+  }) {
+    final _result = create();
+    if (boardId != null) {
+      _result.boardId = boardId;
+    }
+    if (cardId != null) {
+      _result.cardId = cardId;
+    }
+    if (title != null) {
+      _result.title = title;
+    }
+    if (description != null) {
+      _result.description = description;
+    }
+    if (properties != null) {
+      _result.properties.addAll(properties);
+    }
+    return _result;
+  }
+  factory UpdateCardParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory UpdateCardParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  UpdateCardParams clone() => UpdateCardParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  UpdateCardParams copyWith(void Function(UpdateCardParams) updates) => super.copyWith((message) => updates(message as UpdateCardParams)) as UpdateCardParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static UpdateCardParams create() => UpdateCardParams._();
+  UpdateCardParams createEmptyInstance() => create();
+  static $pb.PbList<UpdateCardParams> createRepeated() => $pb.PbList<UpdateCardParams>();
+  @$core.pragma('dart2js:noInline')
+  static UpdateCardParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<UpdateCardParams>(create);
+  static UpdateCardParams? _defaultInstance;
+
+  UpdateCardParams_OneOfTitle whichOneOfTitle() => _UpdateCardParams_OneOfTitleByTag[$_whichOneof(0)]!;
+  void clearOneOfTitle() => clearField($_whichOneof(0));
+
+  UpdateCardParams_OneOfDescription whichOneOfDescription() => _UpdateCardParams_OneOfDescriptionByTag[$_whichOneof(1)]!;
+  void clearOneOfDescription() => clearField($_whichOneof(1));
+
+  @$pb.TagNumber(1)
+  $core.String get boardId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set boardId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBoardId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBoardId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get cardId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set cardId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasCardId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearCardId() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get title => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set title($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasTitle() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearTitle() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $core.String get description => $_getSZ(3);
+  @$pb.TagNumber(4)
+  set description($core.String v) { $_setString(3, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasDescription() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearDescription() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $core.List<CardProperty> get properties => $_getList(4);
+}
+
+enum MoveCardRequest_OneOfAfterCardId {
+  afterCardId, 
+  notSet
+}
+
+class MoveCardRequest extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, MoveCardRequest_OneOfAfterCardId> _MoveCardRequest_OneOfAfterCardIdByTag = {
+    4 : MoveCardRequest_OneOfAfterCardId.afterCardId,
+    0 : MoveCardRequest_OneOfAfterCardId.notSet
+  };
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'MoveCardRequest', createEmptyInstance: create)
+    ..oo(0, [4])
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'boardId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'cardId')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'column')
+    ..aOS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'afterCardId')
+    ..hasRequiredFields = false
+  ;
+
+  MoveCardRequest._() : super();
+  factory MoveCardRequest({
+    $core.String? boardId,
+    $core.String? cardId,
+    $core.String? column,
+    $core.String? afterCardId,
+  }) {
+    final _result = create();
+    if (boardId != null) {
+      _result.boardId = boardId;
+    }
+    if (cardId != null) {
+      _result.cardId = cardId;
+    }
+    if (column != null) {
+      _result.column = column;
+    }
+    if (afterCardId != null) {
+      _result.afterCardId = afterCardId;
+    }
+    return _result;
+  }
+  factory MoveCardRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory MoveCardRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  MoveCardRequest clone() => MoveCardRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  MoveCardRequest copyWith(void Function(MoveCardRequest) updates) => super.copyWith((message) => updates(message as MoveCardRequest)) as MoveCardRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static MoveCardRequest create() => MoveCardRequest._();
+  MoveCardRequest createEmptyInstance() => create();
+  static $pb.PbList<MoveCardRequest> createRepeated() => $pb.PbList<MoveCardRequest>();
+  @$core.pragma('dart2js:noInline')
+  static MoveCardRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<MoveCardRequest>(create);
+  static MoveCardRequest? _defaultInstance;
+
+  MoveCardRequest_OneOfAfterCardId whichOneOfAfterCardId() => _MoveCardRequest_OneOfAfterCardIdByTag[$_whichOneof(0)]!;
+  void clearOneOfAfterCardId() => clearField($_whichOneof(0));
+
+  @$pb.TagNumber(1)
+  $core.String get boardId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set boardId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBoardId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBoardId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get cardId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set cardId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasCardId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearCardId() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get column => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set column($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasColumn() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearColumn() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $core.String get afterCardId => $_getSZ(3);
+  @$pb.TagNumber(4)
+  set afterCardId($core.String v) { $_setString(3, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasAfterCardId() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearAfterCardId() => clearField(4);
+}
+
+enum MoveCardParams_OneOfAfterCardId {
+  afterCardId, 
+  notSet
+}
+
+class MoveCardParams extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, MoveCardParams_OneOfAfterCardId> _MoveCardParams_OneOfAfterCardIdByTag = {
+    4 : MoveCardParams_OneOfAfterCardId.afterCardId,
+    0 : MoveCardParams_OneOfAfterCardId.notSet
+  };
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'MoveCardParams', createEmptyInstance: create)
+    ..oo(0, [4])
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'boardId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'cardId')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'column')
+    ..aOS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'afterCardId')
+    ..hasRequiredFields = false
+  ;
+
+  MoveCardParams._() : super();
+  factory MoveCardParams({
+    $core.String? boardId,
+    $core.String? cardId,
+    $core.String? column,
+    $core.String? afterCardId,
+  }) {
+    final _result = create();
+    if (boardId != null) {
+      _result.boardId = boardId;
+    }
+    if (cardId != null) {
+      _result.cardId = cardId;
+    }
+    if (column != null) {
+      _result.column = column;
+    }
+    if (afterCardId != null) {
+      _result.afterCardId = afterCardId;
+    }
+    return _result;
+  }
+  factory MoveCardParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory MoveCardParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  MoveCardParams clone() => MoveCardParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  MoveCardParams copyWith(void Function(MoveCardParams) updates) => super.copyWith((message) => updates(message as MoveCardParams)) as MoveCardParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static MoveCardParams create() => MoveCardParams._();
+  MoveCardParams createEmptyInstance() => create();
+  static $pb.PbList<MoveCardParams> createRepeated() => $pb.PbList<MoveCardParams>();
+  @$core.pragma('dart2js:noInline')
+  static MoveCardParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<MoveCardParams>(create);
+  static MoveCardParams? _defaultInstance;
+
+  MoveCardParams_OneOfAfterCardId whichOneOfAfterCardId() => _MoveCardParams_OneOfAfterCardIdByTag[$_whichOneof(0)]!;
+  void clearOneOfAfterCardId() => clearField($_whichOneof(0));
+
+  @$pb.TagNumber(1)
+  $core.String get boardId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set boardId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBoardId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBoardId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get cardId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set cardId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasCardId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearCardId() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get column => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set column($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasColumn() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearColumn() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $core.String get afterCardId => $_getSZ(3);
+  @$pb.TagNumber(4)
+  set afterCardId($core.String v) { $_setString(3, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasAfterCardId() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearAfterCardId() => clearField(4);
+}
+
+class QueryCardRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'QueryCardRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'boardId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'cardId')
+    ..hasRequiredFields = false
+  ;
+
+  QueryCardRequest._() : super();
+  factory QueryCardRequest({
+    $core.String? boardId,
+    $core.String? cardId,
+  }) {
+    final _result = create();
+    if (boardId != null) {
+      _result.boardId = boardId;
+    }
+    if (cardId != null) {
+      _result.cardId = cardId;
+    }
+    return _result;
+  }
+  factory QueryCardRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory QueryCardRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  QueryCardRequest clone() => QueryCardRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  QueryCardRequest copyWith(void Function(QueryCardRequest) updates) => super.copyWith((message) => updates(message as QueryCardRequest)) as QueryCardRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static QueryCardRequest create() => QueryCardRequest._();
+  QueryCardRequest createEmptyInstance() => create();
+  static $pb.PbList<QueryCardRequest> createRepeated() => $pb.PbList<QueryCardRequest>();
+  @$core.pragma('dart2js:noInline')
+  static QueryCardRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<QueryCardRequest>(create);
+  static QueryCardRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get boardId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set boardId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBoardId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBoardId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get cardId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set cardId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasCardId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearCardId() => clearField(2);
+}
+
+class CardIdentifier extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'CardIdentifier', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'boardId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'cardId')
+    ..hasRequiredFields = false
+  ;
+
+  CardIdentifier._() : super();
+  factory CardIdentifier({
+    $core.String? boardId,
+    $core.String? cardId,
+  }) {
+    final _result = create();
+    if (boardId != null) {
+      _result.boardId = boardId;
+    }
+    if (cardId != null) {
+      _result.cardId = cardId;
+    }
+    return _result;
+  }
+  factory CardIdentifier.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory CardIdentifier.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  CardIdentifier clone() => CardIdentifier()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  CardIdentifier copyWith(void Function(CardIdentifier) updates) => super.copyWith((message) => updates(message as CardIdentifier)) as CardIdentifier; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static CardIdentifier create() => CardIdentifier._();
+  CardIdentifier createEmptyInstance() => create();
+  static $pb.PbList<CardIdentifier> createRepeated() => $pb.PbList<CardIdentifier>();
+  @$core.pragma('dart2js:noInline')
+  static CardIdentifier getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<CardIdentifier>(create);
+  static CardIdentifier? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get boardId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set boardId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBoardId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBoardId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get cardId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set cardId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasCardId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearCardId() => clearField(2);
+}
+
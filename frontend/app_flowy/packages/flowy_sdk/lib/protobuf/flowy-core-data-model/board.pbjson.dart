@@ -0,0 +1,225 @@
+///
+//  Generated code. Do not modify.
+//  source: board.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use boardDescriptor instead')
+const Board$json = const {
+  '1': 'Board',
+  '2': const [
+    const {'1': 'id', '3': 1, '4': 1, '5': 9, '10': 'id'},
+    const {'1': 'property', '3': 2, '4': 1, '5': 11, '6': '.BoardProperty', '10': 'property'},
+    const {'1': 'columns', '3': 3, '4': 3, '5': 11, '6': '.BoardColumn', '10': 'columns'},
+  ],
+};
+
+/// Descriptor for `Board`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List boardDescriptor = $convert.base64Decode('CgVCb2FyZBIOCgJpZBgBIAEoCVICaWQSKgoIcHJvcGVydHkYAiABKAsyDi5Cb2FyZFByb3BlcnR5Ughwcm9wZXJ0eRImCgdjb2x1bW5zGAMgAygLMgwuQm9hcmRDb2x1bW5SB2NvbHVtbnM=');
+@$core.Deprecated('Use boardPropertyDescriptor instead')
+const BoardProperty$json = const {
+  '1': 'BoardProperty',
+  '2': const [
+    const {'1': 'key', '3': 1, '4': 1, '5': 9, '10': 'key'},
+    const {'1': 'options', '3': 2, '4': 3, '5': 9, '10': 'options'},
+  ],
+};
+
+/// Descriptor for `BoardProperty`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List boardPropertyDescriptor = $convert.base64Decode('Cg1Cb2FyZFByb3BlcnR5EhAKA2tleRgBIAEoCVIDa2V5EhgKB29wdGlvbnMYAiADKAlSB29wdGlvbnM=');
+@$core.Deprecated('Use boardColumnDescriptor instead')
+const BoardColumn$json = const {
+  '1': 'BoardColumn',
+  '2': const [
+    const {'1': 'name', '3': 1, '4': 1, '5': 9, '10': 'name'},
+    const {'1': 'cards', '3': 2, '4': 3, '5': 11, '6': '.Card', '10': 'cards'},
+  ],
+};
+
+/// Descriptor for `BoardColumn`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List boardColumnDescriptor = $convert.base64Decode('CgtCb2FyZENvbHVtbhISCgRuYW1lGAEgASgJUgRuYW1lEhsKBWNhcmRzGAIgAygLMgUuQ2FyZFIFY2FyZHM=');
+@$core.Deprecated('Use cardDescriptor instead')
+const Card$json = const {
+  '1': 'Card',
+  '2': const [
+    const {'1': 'id', '3': 1, '4': 1, '5': 9, '10': 'id'},
+    const {'1': 'title', '3': 2, '4': 1, '5': 9, '10': 'title'},
+    const {'1': 'description', '3': 3, '4': 1, '5': 9, '10': 'description'},
+    const {'1': 'properties', '3': 4, '4': 3, '5': 11, '6': '.CardProperty', '10': 'properties'},
+  ],
+};
+
+/// Descriptor for `Card`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List cardDescriptor = $convert.base64Decode('CgRDYXJkEg4KAmlkGAEgASgJUgJpZBIUCgV0aXRsZRgCIAEoCVIFdGl0bGUSIAoLZGVzY3JpcHRpb24YAyABKAlSC2Rlc2NyaXB0aW9uEi0KCnByb3BlcnRpZXMYBCADKAsyDS5DYXJkUHJvcGVydHlSCnByb3BlcnRpZXM=');
+@$core.Deprecated('Use cardPropertyDescriptor instead')
+const CardProperty$json = const {
+  '1': 'CardProperty',
+  '2': const [
+    const {'1': 'key', '3': 1, '4': 1, '5': 9, '10': 'key'},
+    const {'1': 'value', '3': 2, '4': 1, '5': 9, '10': 'value'},
+  ],
+};
+
+/// Descriptor for `CardProperty`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List cardPropertyDescriptor = $convert.base64Decode('CgxDYXJkUHJvcGVydHkSEAoDa2V5GAEgASgJUgNrZXkSFAoFdmFsdWUYAiABKAlSBXZhbHVl');
+@$core.Deprecated('Use queryBoardRequestDescriptor instead')
+const QueryBoardRequest$json = const {
+  '1': 'QueryBoardRequest',
+  '2': const [
+    const {'1': 'board_id', '3': 1, '4': 1, '5': 9, '10': 'boardId'},
+  ],
+};
+
+/// Descriptor for `QueryBoardRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List queryBoardRequestDescriptor = $convert.base64Decode('ChFRdWVyeUJvYXJkUmVxdWVzdBIZCghib2FyZF9pZBgBIAEoCVIHYm9hcmRJZA==');
+@$core.Deprecated('Use boardIdDescriptor instead')
+const BoardId$json = const {
+  '1': 'BoardId',
+  '2': const [
+    const {'1': 'board_id', '3': 1, '4': 1, '5': 9, '10': 'boardId'},
+  ],
+};
+
+/// Descriptor for `BoardId`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List boardIdDescriptor = $convert.base64Decode('CgdCb2FyZElkEhkKCGJvYXJkX2lkGAEgASgJUgdib2FyZElk');
+@$core.Deprecated('Use updateBoardPropertyRequestDescriptor instead')
+const UpdateBoardPropertyRequest$json = const {
+  '1': 'UpdateBoardPropertyRequest',
+  '2': const [
+    const {'1': 'board_id', '3': 1, '4': 1, '5': 9, '10': 'boardId'},
+    const {'1': 'key', '3': 2, '4': 1, '5': 9, '10': 'key'},
+    const {'1': 'options', '3': 3, '4': 3, '5': 9, '10': 'options'},
+  ],
+};
+
+/// Descriptor for `UpdateBoardPropertyRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List updateBoardPropertyRequestDescriptor = $convert.base64Decode('ChpVcGRhdGVCb2FyZFByb3BlcnR5UmVxdWVzdBIZCghib2FyZF9pZBgBIAEoCVIHYm9hcmRJZBIQCgNrZXkYAiABKAlSA2tleRIYCgdvcHRpb25zGAMgAygJUgdvcHRpb25z');
+@$core.Deprecated('Use updateBoardPropertyParamsDescriptor instead')
+const UpdateBoardPropertyParams$json = const {
+  '1': 'UpdateBoardPropertyParams',
+  '2': const [
+    const {'1': 'board_id', '3': 1, '4': 1, '5': 9, '10': 'boardId'},
+    const {'1': 'property', '3': 2, '4': 1, '5': 11, '6': '.BoardProperty', '10': 'property'},
+  ],
+};
+
+/// Descriptor for `UpdateBoardPropertyParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List updateBoardPropertyParamsDescriptor = $convert.base64Decode('ChlVcGRhdGVCb2FyZFByb3BlcnR5UGFyYW1zEhkKCGJvYXJkX2lkGAEgASgJUgdib2FyZElkEioKCHByb3BlcnR5GAIgASgLMg4uQm9hcmRQcm9wZXJ0eVIIcHJvcGVydHk=');
+@$core.Deprecated('Use createCardRequestDescriptor instead')
+const CreateCardRequest$json = const {
+  '1': 'CreateCardRequest',
+  '2': const [
+    const {'1': 'board_id', '3': 1, '4': 1, '5': 9, '10': 'boardId'},
+    const {'1': 'title', '3': 2, '4': 1, '5': 9, '10': 'title'},
+    const {'1': 'column', '3': 3, '4': 1, '5': 9, '10': 'column'},
+  ],
+};
+
+/// Descriptor for `CreateCardRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List createCardRequestDescriptor = $convert.base64Decode('ChFDcmVhdGVDYXJkUmVxdWVzdBIZCghib2FyZF9pZBgBIAEoCVIHYm9hcmRJZBIUCgV0aXRsZRgCIAEoCVIFdGl0bGUSFgoGY29sdW1uGAMgASgJUgZjb2x1bW4=');
+@$core.Deprecated('Use createCardParamsDescriptor instead')
+const CreateCardParams$json = const {
+  '1': 'CreateCardParams',
+  '2': const [
+    const {'1': 'board_id', '3': 1, '4': 1, '5': 9, '10': 'boardId'},
+    const {'1': 'title', '3': 2, '4': 1, '5': 9, '10': 'title'},
+    const {'1': 'column', '3': 3, '4': 1, '5': 9, '10': 'column'},
+  ],
+};
+
+/// Descriptor for `CreateCardParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List createCardParamsDescriptor = $convert.base64Decode('ChBDcmVhdGVDYXJkUGFyYW1zEhkKCGJvYXJkX2lkGAEgASgJUgdib2FyZElkEhQKBXRpdGxlGAIgASgJUgV0aXRsZRIWCgZjb2x1bW4YAyABKAlSBmNvbHVtbg==');
+@$core.Deprecated('Use updateCardRequestDescriptor instead')
+const UpdateCardRequest$json = const {
+  '1': 'UpdateCardRequest',
+  '2': const [
+    const {'1': 'board_id', '3': 1, '4': 1, '5': 9, '10': 'boardId'},
+    const {'1': 'card_id', '3': 2, '4': 1, '5': 9, '10': 'cardId'},
+    const {'1': 'title', '3': 3, '4': 1, '5': 9, '9': 0, '10': 'title'},
+    const {'1': 'description', '3': 4, '4': 1, '5': 9, '9': 1, '10': 'description'},
+    const {'1': 'properties', '3': 5, '4': 3, '5': 11, '6': '.CardProperty', '10': 'properties'},
+  ],
+  '8': const [
+    const {'1': 'one_of_title'},
+    const {'1': 'one_of_description'},
+  ],
+};
+
+/// Descriptor for `UpdateCardRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List updateCardRequestDescriptor = $convert.base64Decode('ChFVcGRhdGVDYXJkUmVxdWVzdBIZCghib2FyZF9pZBgBIAEoCVIHYm9hcmRJZBIXCgdjYXJkX2lkGAIgASgJUgZjYXJkSWQSFgoFdGl0bGUYAyABKAlIAFIFdGl0bGUSIgoLZGVzY3JpcHRpb24YBCABKAlIAVILZGVzY3JpcHRpb24SLQoKcHJvcGVydGllcxgFIAMoCzINLkNhcmRQcm9wZXJ0eVIKcHJvcGVydGllc0IOCgxvbmVfb2ZfdGl0bGVCFAoSb25lX29mX2Rlc2NyaXB0aW9u');
+@$core.Deprecated('Use updateCardParamsDescriptor instead')
+const UpdateCardParams$json = const {
+  '1': 'UpdateCardParams',
+  '2': const [
+    const {'1': 'board_id', '3': 1, '4': 1, '5': 9, '10': 'boardId'},
+    const {'1': 'card_id', '3': 2, '4': 1, '5': 9, '10': 'cardId'},
+    const {'1': 'title', '3': 3, '4': 1, '5': 9, '9': 0, '10': 'title'},
+    const {'1': 'description', '3': 4, '4': 1, '5': 9, '9': 1, '10': 'description'},
+    const {'1': 'properties', '3': 5, '4': 3, '5': 11, '6': '.CardProperty', '10': 'properties'},
+  ],
+  '8': const [
+    const {'1': 'one_of_title'},
+    const {'1': 'one_of_description'},
+  ],
+};
+
+/// Descriptor for `UpdateCardParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List updateCardParamsDescriptor = $convert.base64Decode('ChBVcGRhdGVDYXJkUGFyYW1zEhkKCGJvYXJkX2lkGAEgASgJUgdib2FyZElkEhcKB2NhcmRfaWQYAiABKAlSBmNhcmRJZBIWCgV0aXRsZRgDIAEoCUgAUgV0aXRsZRIiCgtkZXNjcmlwdGlvbhgEIAEoCUgBUgtkZXNjcmlwdGlvbhItCgpwcm9wZXJ0aWVzGAUgAygLMg0uQ2FyZFByb3BlcnR5Ugpwcm9wZXJ0aWVzQg4KDG9uZV9vZl90aXRsZUIUChJvbmVfb2ZfZGVzY3JpcHRpb24=');
+@$core.Deprecated('Use moveCardRequestDescriptor instead')
+const MoveCardRequest$json = const {
+  '1': 'MoveCardRequest',
+  '2': const [
+    const {'1': 'board_id', '3': 1, '4': 1, '5': 9, '10': 'boardId'},
+    const {'1': 'card_id', '3': 2, '4': 1, '5': 9, '10': 'cardId'},
+    const {'1': 'column', '3': 3, '4': 1, '5': 9, '10': 'column'},
+    const {'1': 'after_card_id', '3': 4, '4': 1, '5': 9, '9': 0, '10': 'afterCardId'},
+  ],
+  '8': const [
+    const {'1': 'one_of_after_card_id'},
+  ],
+};
+
+/// Descriptor for `MoveCardRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List moveCardRequestDescriptor = $convert.base64Decode('Cg9Nb3ZlQ2FyZFJlcXVlc3QSGQoIYm9hcmRfaWQYASABKAlSB2JvYXJkSWQSFwoHY2FyZF9pZBgCIAEoCVIGY2FyZElkEhYKBmNvbHVtbhgDIAEoCVIGY29sdW1uEiQKDWFmdGVyX2NhcmRfaWQYBCABKAlIAFILYWZ0ZXJDYXJkSWRCFgoUb25lX29mX2FmdGVyX2NhcmRfaWQ=');
+@$core.Deprecated('Use moveCardParamsDescriptor instead')
+const MoveCardParams$json = const {
+  '1': 'MoveCardParams',
+  '2': const [
+    const {'1': 'board_id', '3': 1, '4': 1, '5': 9, '10': 'boardId'},
+    const {'1': 'card_id', '3': 2, '4': 1, '5': 9, '10': 'cardId'},
+    const {'1': 'column', '3': 3, '4': 1, '5': 9, '10': 'column'},
+    const {'1': 'after_card_id', '3': 4, '4': 1, '5': 9, '9': 0, '10': 'afterCardId'},
+  ],
+  '8': const [
+    const {'1': 'one_of_after_card_id'},
+  ],
+};
+
+/// Descriptor for `MoveCardParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List moveCardParamsDescriptor = $convert.base64Decode('Cg5Nb3ZlQ2FyZFBhcmFtcxIZCghib2FyZF9pZBgBIAEoCVIHYm9hcmRJZBIXCgdjYXJkX2lkGAIgASgJUgZjYXJkSWQSFgoGY29sdW1uGAMgASgJUgZjb2x1bW4SJAoNYWZ0ZXJfY2FyZF9pZBgEIAEoCUgAUgthZnRlckNhcmRJZEIWChRvbmVfb2ZfYWZ0ZXJfY2FyZF9pZA==');
+@$core.Deprecated('Use queryCardRequestDescriptor instead')
+const QueryCardRequest$json = const {
+  '1': 'QueryCardRequest',
+  '2': const [
+    const {'1': 'board_id', '3': 1, '4': 1, '5': 9, '10': 'boardId'},
+    const {'1': 'card_id', '3': 2, '4': 1, '5': 9, '10': 'cardId'},
+  ],
+};
+
+/// Descriptor for `QueryCardRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List queryCardRequestDescriptor = $convert.base64Decode('ChBRdWVyeUNhcmRSZXF1ZXN0EhkKCGJvYXJkX2lkGAEgASgJUgdib2FyZElkEhcKB2NhcmRfaWQYAiABKAlSBmNhcmRJZA==');
+@$core.Deprecated('Use cardIdentifierDescriptor instead')
+const CardIdentifier$json = const {
+  '1': 'CardIdentifier',
+  '2': const [
+    const {'1': 'board_id', '3': 1, '4': 1, '5': 9, '10': 'boardId'},
+    const {'1': 'card_id', '3': 2, '4': 1, '5': 9, '10': 'cardId'},
+  ],
+};
+
+/// Descriptor for `CardIdentifier`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List cardIdentifierDescriptor = $convert.base64Decode('Cg5DYXJkSWRlbnRpZmllchIZCghib2FyZF9pZBgBIAEoCVIHYm9hcmRJZBIXCgdjYXJkX2lkGAIgASgJUgZjYXJkSWQ=');
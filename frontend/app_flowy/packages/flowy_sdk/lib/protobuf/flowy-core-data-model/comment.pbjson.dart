@@ -0,0 +1,147 @@
+///
+//  Generated code. Do not modify.
+//  source: comment.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use commentThreadDescriptor instead')
+const CommentThread$json = const {
+  '1': 'CommentThread',
+  '2': const [
+    const {'1': 'id', '3': 1, '4': 1, '5': 9, '10': 'id'},
+    const {'1': 'doc_id', '3': 2, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'author', '3': 3, '4': 1, '5': 9, '10': 'author'},
+    const {'1': 'content', '3': 4, '4': 1, '5': 9, '10': 'content'},
+    const {'1': 'create_time', '3': 5, '4': 1, '5': 3, '10': 'createTime'},
+    const {'1': 'resolved', '3': 6, '4': 1, '5': 8, '10': 'resolved'},
+    const {'1': 'orphaned', '3': 7, '4': 1, '5': 8, '10': 'orphaned'},
+    const {'1': 'anchor_start', '3': 8, '4': 1, '5': 3, '10': 'anchorStart'},
+    const {'1': 'anchor_end', '3': 9, '4': 1, '5': 3, '10': 'anchorEnd'},
+    const {'1': 'replies', '3': 10, '4': 3, '5': 11, '6': '.Comment', '10': 'replies'},
+  ],
+};
+
+/// Descriptor for `CommentThread`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List commentThreadDescriptor = $convert.base64Decode('Cg1Db21tZW50VGhyZWFkEg4KAmlkGAEgASgJUgJpZBIVCgZkb2NfaWQYAiABKAlSBWRvY0lkEhYKBmF1dGhvchgDIAEoCVIGYXV0aG9yEhgKB2NvbnRlbnQYBCABKAlSB2NvbnRlbnQSHwoLY3JlYXRlX3RpbWUYBSABKANSCmNyZWF0ZVRpbWUSGgoIcmVzb2x2ZWQYBiABKAhSCHJlc29sdmVkEhoKCG9ycGhhbmVkGAcgASgIUghvcnBoYW5lZBIhCgxhbmNob3Jfc3RhcnQYCCABKANSC2FuY2hvclN0YXJ0Eh0KCmFuY2hvcl9lbmQYCSABKANSCWFuY2hvckVuZBIiCgdyZXBsaWVzGAogAygLMgguQ29tbWVudFIHcmVwbGllcw==');
+@$core.Deprecated('Use commentDescriptor instead')
+const Comment$json = const {
+  '1': 'Comment',
+  '2': const [
+    const {'1': 'id', '3': 1, '4': 1, '5': 9, '10': 'id'},
+    const {'1': 'thread_id', '3': 2, '4': 1, '5': 9, '10': 'threadId'},
+    const {'1': 'author', '3': 3, '4': 1, '5': 9, '10': 'author'},
+    const {'1': 'content', '3': 4, '4': 1, '5': 9, '10': 'content'},
+    const {'1': 'create_time', '3': 5, '4': 1, '5': 3, '10': 'createTime'},
+  ],
+};
+
+/// Descriptor for `Comment`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List commentDescriptor = $convert.base64Decode('CgdDb21tZW50Eg4KAmlkGAEgASgJUgJpZBIbCgl0aHJlYWRfaWQYAiABKAlSCHRocmVhZElkEhYKBmF1dGhvchgDIAEoCVIGYXV0aG9yEhgKB2NvbnRlbnQYBCABKAlSB2NvbnRlbnQSHwoLY3JlYXRlX3RpbWUYBSABKANSCmNyZWF0ZVRpbWU=');
+@$core.Deprecated('Use repeatedCommentThreadDescriptor instead')
+const RepeatedCommentThread$json = const {
+  '1': 'RepeatedCommentThread',
+  '2': const [
+    const {'1': 'items', '3': 1, '4': 3, '5': 11, '6': '.CommentThread', '10': 'items'},
+  ],
+};
+
+/// Descriptor for `RepeatedCommentThread`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List repeatedCommentThreadDescriptor = $convert.base64Decode('ChVSZXBlYXRlZENvbW1lbnRUaHJlYWQSJAoFaXRlbXMYASADKAsyDi5Db21tZW50VGhyZWFkUgVpdGVtcw==');
+@$core.Deprecated('Use queryCommentsRequestDescriptor instead')
+const QueryCommentsRequest$json = const {
+  '1': 'QueryCommentsRequest',
+  '2': const [
+    const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
+  ],
+};
+
+/// Descriptor for `QueryCommentsRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List queryCommentsRequestDescriptor = $convert.base64Decode('ChRRdWVyeUNvbW1lbnRzUmVxdWVzdBIVCgZkb2NfaWQYASABKAlSBWRvY0lk');
+@$core.Deprecated('Use queryCommentsParamsDescriptor instead')
+const QueryCommentsParams$json = const {
+  '1': 'QueryCommentsParams',
+  '2': const [
+    const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
+  ],
+};
+
+/// Descriptor for `QueryCommentsParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List queryCommentsParamsDescriptor = $convert.base64Decode('ChNRdWVyeUNvbW1lbnRzUGFyYW1zEhUKBmRvY19pZBgBIAEoCVIFZG9jSWQ=');
+@$core.Deprecated('Use createCommentRequestDescriptor instead')
+const CreateCommentRequest$json = const {
+  '1': 'CreateCommentRequest',
+  '2': const [
+    const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'start', '3': 2, '4': 1, '5': 3, '10': 'start'},
+    const {'1': 'end', '3': 3, '4': 1, '5': 3, '10': 'end'},
+    const {'1': 'content', '3': 4, '4': 1, '5': 9, '10': 'content'},
+  ],
+};
+
+/// Descriptor for `CreateCommentRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List createCommentRequestDescriptor = $convert.base64Decode('ChRDcmVhdGVDb21tZW50UmVxdWVzdBIVCgZkb2NfaWQYASABKAlSBWRvY0lkEhQKBXN0YXJ0GAIgASgDUgVzdGFydBIQCgNlbmQYAyABKANSA2VuZBIYCgdjb250ZW50GAQgASgJUgdjb250ZW50');
+@$core.Deprecated('Use createCommentParamsDescriptor instead')
+const CreateCommentParams$json = const {
+  '1': 'CreateCommentParams',
+  '2': const [
+    const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'start', '3': 2, '4': 1, '5': 3, '10': 'start'},
+    const {'1': 'end', '3': 3, '4': 1, '5': 3, '10': 'end'},
+    const {'1': 'content', '3': 4, '4': 1, '5': 9, '10': 'content'},
+  ],
+};
+
+/// Descriptor for `CreateCommentParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List createCommentParamsDescriptor = $convert.base64Decode('ChNDcmVhdGVDb21tZW50UGFyYW1zEhUKBmRvY19pZBgBIAEoCVIFZG9jSWQSFAoFc3RhcnQYAiABKANSBXN0YXJ0EhAKA2VuZBgDIAEoA1IDZW5kEhgKB2NvbnRlbnQYBCABKAlSB2NvbnRlbnQ=');
+@$core.Deprecated('Use replyCommentRequestDescriptor instead')
+const ReplyCommentRequest$json = const {
+  '1': 'ReplyCommentRequest',
+  '2': const [
+    const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'thread_id', '3': 2, '4': 1, '5': 9, '10': 'threadId'},
+    const {'1': 'content', '3': 3, '4': 1, '5': 9, '10': 'content'},
+  ],
+};
+
+/// Descriptor for `ReplyCommentRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List replyCommentRequestDescriptor = $convert.base64Decode('ChNSZXBseUNvbW1lbnRSZXF1ZXN0EhUKBmRvY19pZBgBIAEoCVIFZG9jSWQSGwoJdGhyZWFkX2lkGAIgASgJUgh0aHJlYWRJZBIYCgdjb250ZW50GAMgASgJUgdjb250ZW50');
+@$core.Deprecated('Use replyCommentParamsDescriptor instead')
+const ReplyCommentParams$json = const {
+  '1': 'ReplyCommentParams',
+  '2': const [
+    const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'thread_id', '3': 2, '4': 1, '5': 9, '10': 'threadId'},
+    const {'1': 'content', '3': 3, '4': 1, '5': 9, '10': 'content'},
+  ],
+};
+
+/// Descriptor for `ReplyCommentParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List replyCommentParamsDescriptor = $convert.base64Decode('ChJSZXBseUNvbW1lbnRQYXJhbXMSFQoGZG9jX2lkGAEgASgJUgVkb2NJZBIbCgl0aHJlYWRfaWQYAiABKAlSCHRocmVhZElkEhgKB2NvbnRlbnQYAyABKAlSB2NvbnRlbnQ=');
+@$core.Deprecated('Use resolveCommentRequestDescriptor instead')
+const ResolveCommentRequest$json = const {
+  '1': 'ResolveCommentRequest',
+  '2': const [
+    const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'thread_id', '3': 2, '4': 1, '5': 9, '10': 'threadId'},
+    const {'1': 'resolved', '3': 3, '4': 1, '5': 8, '10': 'resolved'},
+  ],
+};
+
+/// Descriptor for `ResolveCommentRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List resolveCommentRequestDescriptor = $convert.base64Decode('ChVSZXNvbHZlQ29tbWVudFJlcXVlc3QSFQoGZG9jX2lkGAEgASgJUgVkb2NJZBIbCgl0aHJlYWRfaWQYAiABKAlSCHRocmVhZElkEhoKCHJlc29sdmVkGAMgASgIUghyZXNvbHZlZA==');
+@$core.Deprecated('Use resolveCommentParamsDescriptor instead')
+const ResolveCommentParams$json = const {
+  '1': 'ResolveCommentParams',
+  '2': const [
+    const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'thread_id', '3': 2, '4': 1, '5': 9, '10': 'threadId'},
+    const {'1': 'resolved', '3': 3, '4': 1, '5': 8, '10': 'resolved'},
+  ],
+};
+
+/// Descriptor for `ResolveCommentParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List resolveCommentParamsDescriptor = $convert.base64Decode('ChRSZXNvbHZlQ29tbWVudFBhcmFtcxIVCgZkb2NfaWQYASABKAlSBWRvY0lkEhsKCXRocmVhZF9pZBgCIAEoCVIIdGhyZWFkSWQSGgoIcmVzb2x2ZWQYAyABKAhSCHJlc29sdmVk');
@@ -0,0 +1,279 @@
+///
+//  Generated code. Do not modify.
+//  source: grid.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use fieldTypeDescriptor instead')
+const FieldType$json = const {
+  '1': 'FieldType',
+  '2': const [
+    const {'1': 'Text', '2': 0},
+    const {'1': 'Number', '2': 1},
+    const {'1': 'DateTime', '2': 2},
+    const {'1': 'SingleSelect', '2': 3},
+    const {'1': 'Checkbox', '2': 4},
+  ],
+};
+
+/// Descriptor for `FieldType`. Decode as a `google.protobuf.EnumDescriptorProto`.
+final $typed_data.Uint8List fieldTypeDescriptor = $convert.base64Decode('CglGaWVsZFR5cGUSCAoEVGV4dBAAEgoKBk51bWJlchABEgwKCERhdGVUaW1lEAISEAoMU2luZ2xlU2VsZWN0EAMSDAoIQ2hlY2tib3gQBA==');
+@$core.Deprecated('Use gridDescriptor instead')
+const Grid$json = const {
+  '1': 'Grid',
+  '2': const [
+    const {'1': 'id', '3': 1, '4': 1, '5': 9, '10': 'id'},
+    const {'1': 'fields', '3': 2, '4': 3, '5': 11, '6': '.Field', '10': 'fields'},
+    const {'1': 'rows', '3': 3, '4': 3, '5': 11, '6': '.Row', '10': 'rows'},
+  ],
+};
+
+/// Descriptor for `Grid`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List gridDescriptor = $convert.base64Decode('CgRHcmlkEg4KAmlkGAEgASgJUgJpZBIeCgZmaWVsZHMYAiADKAsyBi5GaWVsZFIGZmllbGRzEhgKBHJvd3MYAyADKAsyBC5Sb3dSBHJvd3M=');
+@$core.Deprecated('Use fieldDescriptor instead')
+const Field$json = const {
+  '1': 'Field',
+  '2': const [
+    const {'1': 'id', '3': 1, '4': 1, '5': 9, '10': 'id'},
+    const {'1': 'name', '3': 2, '4': 1, '5': 9, '10': 'name'},
+    const {'1': 'field_type', '3': 3, '4': 1, '5': 14, '6': '.FieldType', '10': 'fieldType'},
+    const {'1': 'options', '3': 4, '4': 3, '5': 9, '10': 'options'},
+  ],
+};
+
+/// Descriptor for `Field`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List fieldDescriptor = $convert.base64Decode('CgVGaWVsZBIOCgJpZBgBIAEoCVICaWQSEgoEbmFtZRgCIAEoCVIEbmFtZRIpCgpmaWVsZF90eXBlGAMgASgOMgouRmllbGRUeXBlUglmaWVsZFR5cGUSGAoHb3B0aW9ucxgEIAMoCVIHb3B0aW9ucw==');
+@$core.Deprecated('Use fieldOptionsDescriptor instead')
+const FieldOptions$json = const {
+  '1': 'FieldOptions',
+  '2': const [
+    const {'1': 'items', '3': 1, '4': 3, '5': 9, '10': 'items'},
+  ],
+};
+
+/// Descriptor for `FieldOptions`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List fieldOptionsDescriptor = $convert.base64Decode('CgxGaWVsZE9wdGlvbnMSFAoFaXRlbXMYASADKAlSBWl0ZW1z');
+@$core.Deprecated('Use rowDescriptor instead')
+const Row$json = const {
+  '1': 'Row',
+  '2': const [
+    const {'1': 'id', '3': 1, '4': 1, '5': 9, '10': 'id'},
+    const {'1': 'cells', '3': 2, '4': 3, '5': 11, '6': '.Cell', '10': 'cells'},
+  ],
+};
+
+/// Descriptor for `Row`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List rowDescriptor = $convert.base64Decode('CgNSb3cSDgoCaWQYASABKAlSAmlkEhsKBWNlbGxzGAIgAygLMgUuQ2VsbFIFY2VsbHM=');
+@$core.Deprecated('Use cellDescriptor instead')
+const Cell$json = const {
+  '1': 'Cell',
+  '2': const [
+    const {'1': 'field_id', '3': 1, '4': 1, '5': 9, '10': 'fieldId'},
+    const {'1': 'data', '3': 2, '4': 1, '5': 9, '10': 'data'},
+  ],
+};
+
+/// Descriptor for `Cell`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List cellDescriptor = $convert.base64Decode('CgRDZWxsEhkKCGZpZWxkX2lkGAEgASgJUgdmaWVsZElkEhIKBGRhdGEYAiABKAlSBGRhdGE=');
+@$core.Deprecated('Use queryGridRequestDescriptor instead')
+const QueryGridRequest$json = const {
+  '1': 'QueryGridRequest',
+  '2': const [
+    const {'1': 'grid_id', '3': 1, '4': 1, '5': 9, '10': 'gridId'},
+  ],
+};
+
+/// Descriptor for `QueryGridRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List queryGridRequestDescriptor = $convert.base64Decode('ChBRdWVyeUdyaWRSZXF1ZXN0EhcKB2dyaWRfaWQYASABKAlSBmdyaWRJZA==');
+@$core.Deprecated('Use gridIdDescriptor instead')
+const GridId$json = const {
+  '1': 'GridId',
+  '2': const [
+    const {'1': 'grid_id', '3': 1, '4': 1, '5': 9, '10': 'gridId'},
+  ],
+};
+
+/// Descriptor for `GridId`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List gridIdDescriptor = $convert.base64Decode('CgZHcmlkSWQSFwoHZ3JpZF9pZBgBIAEoCVIGZ3JpZElk');
+@$core.Deprecated('Use createFieldRequestDescriptor instead')
+const CreateFieldRequest$json = const {
+  '1': 'CreateFieldRequest',
+  '2': const [
+    const {'1': 'grid_id', '3': 1, '4': 1, '5': 9, '10': 'gridId'},
+    const {'1': 'name', '3': 2, '4': 1, '5': 9, '10': 'name'},
+    const {'1': 'field_type', '3': 3, '4': 1, '5': 14, '6': '.FieldType', '10': 'fieldType'},
+    const {'1': 'options', '3': 4, '4': 3, '5': 9, '10': 'options'},
+  ],
+};
+
+/// Descriptor for `CreateFieldRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List createFieldRequestDescriptor = $convert.base64Decode('ChJDcmVhdGVGaWVsZFJlcXVlc3QSFwoHZ3JpZF9pZBgBIAEoCVIGZ3JpZElkEhIKBG5hbWUYAiABKAlSBG5hbWUSKQoKZmllbGRfdHlwZRgDIAEoDjIKLkZpZWxkVHlwZVIJZmllbGRUeXBlEhgKB29wdGlvbnMYBCADKAlSB29wdGlvbnM=');
+@$core.Deprecated('Use createFieldParamsDescriptor instead')
+const CreateFieldParams$json = const {
+  '1': 'CreateFieldParams',
+  '2': const [
+    const {'1': 'grid_id', '3': 1, '4': 1, '5': 9, '10': 'gridId'},
+    const {'1': 'name', '3': 2, '4': 1, '5': 9, '10': 'name'},
+    const {'1': 'field_type', '3': 3, '4': 1, '5': 14, '6': '.FieldType', '10': 'fieldType'},
+    const {'1': 'options', '3': 4, '4': 3, '5': 9, '10': 'options'},
+  ],
+};
+
+/// Descriptor for `CreateFieldParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List createFieldParamsDescriptor = $convert.base64Decode('ChFDcmVhdGVGaWVsZFBhcmFtcxIXCgdncmlkX2lkGAEgASgJUgZncmlkSWQSEgoEbmFtZRgCIAEoCVIEbmFtZRIpCgpmaWVsZF90eXBlGAMgASgOMgouRmllbGRUeXBlUglmaWVsZFR5cGUSGAoHb3B0aW9ucxgEIAMoCVIHb3B0aW9ucw==');
+@$core.Deprecated('Use updateFieldRequestDescriptor instead')
+const UpdateFieldRequest$json = const {
+  '1': 'UpdateFieldRequest',
+  '2': const [
+    const {'1': 'grid_id', '3': 1, '4': 1, '5': 9, '10': 'gridId'},
+    const {'1': 'field_id', '3': 2, '4': 1, '5': 9, '10': 'fieldId'},
+    const {'1': 'name', '3': 3, '4': 1, '5': 9, '9': 0, '10': 'name'},
+    const {'1': 'options', '3': 4, '4': 1, '5': 11, '6': '.FieldOptions', '9': 1, '10': 'options'},
+  ],
+  '8': const [
+    const {'1': 'one_of_name'},
+    const {'1': 'one_of_options'},
+  ],
+};
+
+/// Descriptor for `UpdateFieldRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List updateFieldRequestDescriptor = $convert.base64Decode('ChJVcGRhdGVGaWVsZFJlcXVlc3QSFwoHZ3JpZF9pZBgBIAEoCVIGZ3JpZElkEhkKCGZpZWxkX2lkGAIgASgJUgdmaWVsZElkEhQKBG5hbWUYAyABKAlIAFIEbmFtZRIpCgdvcHRpb25zGAQgASgLMg0uRmllbGRPcHRpb25zSAFSB29wdGlvbnNCDQoLb25lX29mX25hbWVCEAoOb25lX29mX29wdGlvbnM=');
+@$core.Deprecated('Use updateFieldParamsDescriptor instead')
+const UpdateFieldParams$json = const {
+  '1': 'UpdateFieldParams',
+  '2': const [
+    const {'1': 'grid_id', '3': 1, '4': 1, '5': 9, '10': 'gridId'},
+    const {'1': 'field_id', '3': 2, '4': 1, '5': 9, '10': 'fieldId'},
+    const {'1': 'name', '3': 3, '4': 1, '5': 9, '9': 0, '10': 'name'},
+    const {'1': 'options', '3': 4, '4': 1, '5': 11, '6': '.FieldOptions', '9': 1, '10': 'options'},
+  ],
+  '8': const [
+    const {'1': 'one_of_name'},
+    const {'1': 'one_of_options'},
+  ],
+};
+
+/// Descriptor for `UpdateFieldParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List updateFieldParamsDescriptor = $convert.base64Decode('ChFVcGRhdGVGaWVsZFBhcmFtcxIXCgdncmlkX2lkGAEgASgJUgZncmlkSWQSGQoIZmllbGRfaWQYAiABKAlSB2ZpZWxkSWQSFAoEbmFtZRgDIAEoCUgAUgRuYW1lEikKB29wdGlvbnMYBCABKAsyDS5GaWVsZE9wdGlvbnNIAVIHb3B0aW9uc0INCgtvbmVfb2ZfbmFtZUIQCg5vbmVfb2Zfb3B0aW9ucw==');
+@$core.Deprecated('Use queryFieldRequestDescriptor instead')
+const QueryFieldRequest$json = const {
+  '1': 'QueryFieldRequest',
+  '2': const [
+    const {'1': 'grid_id', '3': 1, '4': 1, '5': 9, '10': 'gridId'},
+    const {'1': 'field_id', '3': 2, '4': 1, '5': 9, '10': 'fieldId'},
+  ],
+};
+
+/// Descriptor for `QueryFieldRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List queryFieldRequestDescriptor = $convert.base64Decode('ChFRdWVyeUZpZWxkUmVxdWVzdBIXCgdncmlkX2lkGAEgASgJUgZncmlkSWQSGQoIZmllbGRfaWQYAiABKAlSB2ZpZWxkSWQ=');
+@$core.Deprecated('Use fieldIdentifierDescriptor instead')
+const FieldIdentifier$json = const {
+  '1': 'FieldIdentifier',
+  '2': const [
+    const {'1': 'grid_id', '3': 1, '4': 1, '5': 9, '10': 'gridId'},
+    const {'1': 'field_id', '3': 2, '4': 1, '5': 9, '10': 'fieldId'},
+  ],
+};
+
+/// Descriptor for `FieldIdentifier`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List fieldIdentifierDescriptor = $convert.base64Decode('Cg9GaWVsZElkZW50aWZpZXISFwoHZ3JpZF9pZBgBIAEoCVIGZ3JpZElkEhkKCGZpZWxkX2lkGAIgASgJUgdmaWVsZElk');
+@$core.Deprecated('Use queryRowRequestDescriptor instead')
+const QueryRowRequest$json = const {
+  '1': 'QueryRowRequest',
+  '2': const [
+    const {'1': 'grid_id', '3': 1, '4': 1, '5': 9, '10': 'gridId'},
+    const {'1': 'row_id', '3': 2, '4': 1, '5': 9, '10': 'rowId'},
+  ],
+};
+
+/// Descriptor for `QueryRowRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List queryRowRequestDescriptor = $convert.base64Decode('Cg9RdWVyeVJvd1JlcXVlc3QSFwoHZ3JpZF9pZBgBIAEoCVIGZ3JpZElkEhUKBnJvd19pZBgCIAEoCVIFcm93SWQ=');
+@$core.Deprecated('Use rowIdentifierDescriptor instead')
+const RowIdentifier$json = const {
+  '1': 'RowIdentifier',
+  '2': const [
+    const {'1': 'grid_id', '3': 1, '4': 1, '5': 9, '10': 'gridId'},
+    const {'1': 'row_id', '3': 2, '4': 1, '5': 9, '10': 'rowId'},
+  ],
+};
+
+/// Descriptor for `RowIdentifier`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List rowIdentifierDescriptor = $convert.base64Decode('Cg1Sb3dJZGVudGlmaWVyEhcKB2dyaWRfaWQYASABKAlSBmdyaWRJZBIVCgZyb3dfaWQYAiABKAlSBXJvd0lk');
+@$core.Deprecated('Use updateRowRequestDescriptor instead')
+const UpdateRowRequest$json = const {
+  '1': 'UpdateRowRequest',
+  '2': const [
+    const {'1': 'grid_id', '3': 1, '4': 1, '5': 9, '10': 'gridId'},
+    const {'1': 'row_id', '3': 2, '4': 1, '5': 9, '10': 'rowId'},
+    const {'1': 'cells', '3': 3, '4': 3, '5': 11, '6': '.Cell', '10': 'cells'},
+  ],
+};
+
+/// Descriptor for `UpdateRowRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List updateRowRequestDescriptor = $convert.base64Decode('ChBVcGRhdGVSb3dSZXF1ZXN0EhcKB2dyaWRfaWQYASABKAlSBmdyaWRJZBIVCgZyb3dfaWQYAiABKAlSBXJvd0lkEhsKBWNlbGxzGAMgAygLMgUuQ2VsbFIFY2VsbHM=');
+@$core.Deprecated('Use updateRowParamsDescriptor instead')
+const UpdateRowParams$json = const {
+  '1': 'UpdateRowParams',
+  '2': const [
+    const {'1': 'grid_id', '3': 1, '4': 1, '5': 9, '10': 'gridId'},
+    const {'1': 'row_id', '3': 2, '4': 1, '5': 9, '10': 'rowId'},
+    const {'1': 'cells', '3': 3, '4': 3, '5': 11, '6': '.Cell', '10': 'cells'},
+  ],
+};
+
+/// Descriptor for `UpdateRowParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List updateRowParamsDescriptor = $convert.base64Decode('Cg9VcGRhdGVSb3dQYXJhbXMSFwoHZ3JpZF9pZBgBIAEoCVIGZ3JpZElkEhUKBnJvd19pZBgCIAEoCVIFcm93SWQSGwoFY2VsbHMYAyADKAsyBS5DZWxsUgVjZWxscw==');
+@$core.Deprecated('Use updateCellRequestDescriptor instead')
+const UpdateCellRequest$json = const {
+  '1': 'UpdateCellRequest',
+  '2': const [
+    const {'1': 'grid_id', '3': 1, '4': 1, '5': 9, '10': 'gridId'},
+    const {'1': 'row_id', '3': 2, '4': 1, '5': 9, '10': 'rowId'},
+    const {'1': 'field_id', '3': 3, '4': 1, '5': 9, '10': 'fieldId'},
+    const {'1': 'data', '3': 4, '4': 1, '5': 9, '10': 'data'},
+  ],
+};
+
+/// Descriptor for `UpdateCellRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List updateCellRequestDescriptor = $convert.base64Decode('ChFVcGRhdGVDZWxsUmVxdWVzdBIXCgdncmlkX2lkGAEgASgJUgZncmlkSWQSFQoGcm93X2lkGAIgASgJUgVyb3dJZBIZCghmaWVsZF9pZBgDIAEoCVIHZmllbGRJZBISCgRkYXRhGAQgASgJUgRkYXRh');
+@$core.Deprecated('Use updateCellParamsDescriptor instead')
+const UpdateCellParams$json = const {
+  '1': 'UpdateCellParams',
+  '2': const [
+    const {'1': 'grid_id', '3': 1, '4': 1, '5': 9, '10': 'gridId'},
+    const {'1': 'row_id', '3': 2, '4': 1, '5': 9, '10': 'rowId'},
+    const {'1': 'field_id', '3': 3, '4': 1, '5': 9, '10': 'fieldId'},
+    const {'1': 'data', '3': 4, '4': 1, '5': 9, '10': 'data'},
+  ],
+};
+
+/// Descriptor for `UpdateCellParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List updateCellParamsDescriptor = $convert.base64Decode('ChBVcGRhdGVDZWxsUGFyYW1zEhcKB2dyaWRfaWQYASABKAlSBmdyaWRJZBIVCgZyb3dfaWQYAiABKAlSBXJvd0lkEhkKCGZpZWxkX2lkGAMgASgJUgdmaWVsZElkEhIKBGRhdGEYBCABKAlSBGRhdGE=');
+@$core.Deprecated('Use queryCellRequestDescriptor instead')
+const QueryCellRequest$json = const {
+  '1': 'QueryCellRequest',
+  '2': const [
+    const {'1': 'grid_id', '3': 1, '4': 1, '5': 9, '10': 'gridId'},
+    const {'1': 'row_id', '3': 2, '4': 1, '5': 9, '10': 'rowId'},
+    const {'1': 'field_id', '3': 3, '4': 1, '5': 9, '10': 'fieldId'},
+  ],
+};
+
+/// Descriptor for `QueryCellRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List queryCellRequestDescriptor = $convert.base64Decode('ChBRdWVyeUNlbGxSZXF1ZXN0EhcKB2dyaWRfaWQYASABKAlSBmdyaWRJZBIVCgZyb3dfaWQYAiABKAlSBXJvd0lkEhkKCGZpZWxkX2lkGAMgASgJUgdmaWVsZElk');
+@$core.Deprecated('Use cellIdentifierDescriptor instead')
+const CellIdentifier$json = const {
+  '1': 'CellIdentifier',
+  '2': const [
+    const {'1': 'grid_id', '3': 1, '4': 1, '5': 9, '10': 'gridId'},
+    const {'1': 'row_id', '3': 2, '4': 1, '5': 9, '10': 'rowId'},
+    const {'1': 'field_id', '3': 3, '4': 1, '5': 9, '10': 'fieldId'},
+  ],
+};
+
+/// Descriptor for `CellIdentifier`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List cellIdentifierDescriptor = $convert.base64Decode('Cg5DZWxsSWRlbnRpZmllchIXCgdncmlkX2lkGAEgASgJUgZncmlkSWQSFQoGcm93X2lkGAIgASgJUgVyb3dJZBIZCghmaWVsZF9pZBgDIAEoCVIHZmllbGRJZA==');
@@ -0,0 +1,193 @@
+///
+//  Generated code. Do not modify.
+//  source: highlight.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+import 'dart:core' as $core;
+
+import 'package:fixnum/fixnum.dart' as $fixnum;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+import 'highlight.pbenum.dart';
+
+export 'highlight.pbenum.dart';
+
+class HighlightSpan extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'HighlightSpan', createEmptyInstance: create)
+    ..aInt64(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'start')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'end')
+    ..e<HighlightKind>(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'kind', $pb.PbFieldType.OE, defaultOrMaker: HighlightKind.Keyword, valueOf: HighlightKind.valueOf, enumValues: HighlightKind.values)
+    ..hasRequiredFields = false
+  ;
+
+  HighlightSpan._() : super();
+  factory HighlightSpan({
+    $fixnum.Int64? start,
+    $fixnum.Int64? end,
+    HighlightKind? kind,
+  }) {
+    final _result = create();
+    if (start != null) {
+      _result.start = start;
+    }
+    if (end != null) {
+      _result.end = end;
+    }
+    if (kind != null) {
+      _result.kind = kind;
+    }
+    return _result;
+  }
+  factory HighlightSpan.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory HighlightSpan.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  HighlightSpan clone() => HighlightSpan()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  HighlightSpan copyWith(void Function(HighlightSpan) updates) => super.copyWith((message) => updates(message as HighlightSpan)) as HighlightSpan; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static HighlightSpan create() => HighlightSpan._();
+  HighlightSpan createEmptyInstance() => create();
+  static $pb.PbList<HighlightSpan> createRepeated() => $pb.PbList<HighlightSpan>();
+  @$core.pragma('dart2js:noInline')
+  static HighlightSpan getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<HighlightSpan>(create);
+  static HighlightSpan? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $fixnum.Int64 get start => $_getI64(0);
+  @$pb.TagNumber(1)
+  set start($fixnum.Int64 v) { $_setInt64(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasStart() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearStart() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get end => $_getI64(1);
+  @$pb.TagNumber(2)
+  set end($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasEnd() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearEnd() => clearField(2);
+
+  @$pb.TagNumber(3)
+  HighlightKind get kind => $_getN(2);
+  @$pb.TagNumber(3)
+  set kind(HighlightKind v) { setField(3, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasKind() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearKind() => clearField(3);
+}
+
+class RepeatedHighlightSpan extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepeatedHighlightSpan', createEmptyInstance: create)
+    ..pc<HighlightSpan>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items', $pb.PbFieldType.PM, subBuilder: HighlightSpan.create)
+    ..hasRequiredFields = false
+  ;
+
+  RepeatedHighlightSpan._() : super();
+  factory RepeatedHighlightSpan({
+    $core.Iterable<HighlightSpan>? items,
+  }) {
+    final _result = create();
+    if (items != null) {
+      _result.items.addAll(items);
+    }
+    return _result;
+  }
+  factory RepeatedHighlightSpan.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RepeatedHighlightSpan.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RepeatedHighlightSpan clone() => RepeatedHighlightSpan()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RepeatedHighlightSpan copyWith(void Function(RepeatedHighlightSpan) updates) => super.copyWith((message) => updates(message as RepeatedHighlightSpan)) as RepeatedHighlightSpan; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RepeatedHighlightSpan create() => RepeatedHighlightSpan._();
+  RepeatedHighlightSpan createEmptyInstance() => create();
+  static $pb.PbList<RepeatedHighlightSpan> createRepeated() => $pb.PbList<RepeatedHighlightSpan>();
+  @$core.pragma('dart2js:noInline')
+  static RepeatedHighlightSpan getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RepeatedHighlightSpan>(create);
+  static RepeatedHighlightSpan? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.List<HighlightSpan> get items => $_getList(0);
+}
+
+class HighlightCodeRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'HighlightCodeRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'language')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'code')
+    ..hasRequiredFields = false
+  ;
+
+  HighlightCodeRequest._() : super();
+  factory HighlightCodeRequest({
+    $core.String? language,
+    $core.String? code,
+  }) {
+    final _result = create();
+    if (language != null) {
+      _result.language = language;
+    }
+    if (code != null) {
+      _result.code = code;
+    }
+    return _result;
+  }
+  factory HighlightCodeRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory HighlightCodeRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  HighlightCodeRequest clone() => HighlightCodeRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  HighlightCodeRequest copyWith(void Function(HighlightCodeRequest) updates) => super.copyWith((message) => updates(message as HighlightCodeRequest)) as HighlightCodeRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static HighlightCodeRequest create() => HighlightCodeRequest._();
+  HighlightCodeRequest createEmptyInstance() => create();
+  static $pb.PbList<HighlightCodeRequest> createRepeated() => $pb.PbList<HighlightCodeRequest>();
+  @$core.pragma('dart2js:noInline')
+  static HighlightCodeRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<HighlightCodeRequest>(create);
+  static HighlightCodeRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get language => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set language($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasLanguage() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearLanguage() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get code => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set code($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasCode() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearCode() => clearField(2);
+}
+
@@ -0,0 +1,30 @@
+///
+//  Generated code. Do not modify.
+//  source: highlight.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+// ignore_for_file: UNDEFINED_SHOWN_NAME
+import 'dart:core' as $core;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+class HighlightKind extends $pb.ProtobufEnum {
+  static const HighlightKind Keyword = HighlightKind._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Keyword');
+  static const HighlightKind StringLiteral = HighlightKind._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'StringLiteral');
+  static const HighlightKind Number = HighlightKind._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Number');
+  static const HighlightKind Comment = HighlightKind._(3, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Comment');
+
+  static const $core.List<HighlightKind> values = <HighlightKind> [
+    Keyword,
+    StringLiteral,
+    Number,
+    Comment,
+  ];
+
+  static final $core.Map<$core.int, HighlightKind> _byValue = $pb.ProtobufEnum.initByValue(values);
+  static HighlightKind? valueOf($core.int value) => _byValue[value];
+
+  const HighlightKind._($core.int v, $core.String n) : super(v, n);
+}
+
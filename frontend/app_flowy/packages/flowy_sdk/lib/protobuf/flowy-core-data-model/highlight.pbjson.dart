@@ -0,0 +1,56 @@
+///
+//  Generated code. Do not modify.
+//  source: highlight.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use highlightKindDescriptor instead')
+const HighlightKind$json = const {
+  '1': 'HighlightKind',
+  '2': const [
+    const {'1': 'Keyword', '2': 0},
+    const {'1': 'StringLiteral', '2': 1},
+    const {'1': 'Number', '2': 2},
+    const {'1': 'Comment', '2': 3},
+  ],
+};
+
+/// Descriptor for `HighlightKind`. Decode as a `google.protobuf.EnumDescriptorProto`.
+final $typed_data.Uint8List highlightKindDescriptor = $convert.base64Decode('Cg1IaWdobGlnaHRLaW5kEgsKB0tleXdvcmQQABIRCg1TdHJpbmdMaXRlcmFsEAESCgoGTnVtYmVyEAISCwoHQ29tbWVudBAD');
+@$core.Deprecated('Use highlightSpanDescriptor instead')
+const HighlightSpan$json = const {
+  '1': 'HighlightSpan',
+  '2': const [
+    const {'1': 'start', '3': 1, '4': 1, '5': 3, '10': 'start'},
+    const {'1': 'end', '3': 2, '4': 1, '5': 3, '10': 'end'},
+    const {'1': 'kind', '3': 3, '4': 1, '5': 14, '6': '.HighlightKind', '10': 'kind'},
+  ],
+};
+
+/// Descriptor for `HighlightSpan`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List highlightSpanDescriptor = $convert.base64Decode('Cg1IaWdobGlnaHRTcGFuEhQKBXN0YXJ0GAEgASgDUgVzdGFydBIQCgNlbmQYAiABKANSA2VuZBIiCgRraW5kGAMgASgOMg4uSGlnaGxpZ2h0S2luZFIEa2luZA==');
+@$core.Deprecated('Use repeatedHighlightSpanDescriptor instead')
+const RepeatedHighlightSpan$json = const {
+  '1': 'RepeatedHighlightSpan',
+  '2': const [
+    const {'1': 'items', '3': 1, '4': 3, '5': 11, '6': '.HighlightSpan', '10': 'items'},
+  ],
+};
+
+/// Descriptor for `RepeatedHighlightSpan`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List repeatedHighlightSpanDescriptor = $convert.base64Decode('ChVSZXBlYXRlZEhpZ2hsaWdodFNwYW4SJAoFaXRlbXMYASADKAsyDi5IaWdobGlnaHRTcGFuUgVpdGVtcw==');
+@$core.Deprecated('Use highlightCodeRequestDescriptor instead')
+const HighlightCodeRequest$json = const {
+  '1': 'HighlightCodeRequest',
+  '2': const [
+    const {'1': 'language', '3': 1, '4': 1, '5': 9, '10': 'language'},
+    const {'1': 'code', '3': 2, '4': 1, '5': 9, '10': 'code'},
+  ],
+};
+
+/// Descriptor for `HighlightCodeRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List highlightCodeRequestDescriptor = $convert.base64Decode('ChRIaWdobGlnaHRDb2RlUmVxdWVzdBIaCghsYW5ndWFnZRgBIAEoCVIIbGFuZ3VhZ2USEgoEY29kZRgCIAEoCVIEY29kZQ==');
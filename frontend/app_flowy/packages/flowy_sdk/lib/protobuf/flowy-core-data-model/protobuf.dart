@@ -5,3 +5,8 @@ export './errors.pb.dart';
 export './view.pb.dart';
 export './trash.pb.dart';
 export './workspace.pb.dart';
+export './template.pb.dart';
+export './grid.pb.dart';
+export './board.pb.dart';
+export './comment.pb.dart';
+export './highlight.pb.dart';
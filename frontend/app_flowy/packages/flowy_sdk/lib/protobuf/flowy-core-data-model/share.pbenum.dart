@@ -13,11 +13,13 @@ class ExportType extends $pb.ProtobufEnum {
   static const ExportType Text = ExportType._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Text');
   static const ExportType Markdown = ExportType._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Markdown');
   static const ExportType Link = ExportType._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Link');
+  static const ExportType Html = ExportType._(3, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Html');
 
   static const $core.List<ExportType> values = <ExportType> [
     Text,
     Markdown,
     Link,
+    Html,
   ];
 
   static final $core.Map<$core.int, ExportType> _byValue = $pb.ProtobufEnum.initByValue(values);
@@ -15,11 +15,12 @@ const ExportType$json = const {
     const {'1': 'Text', '2': 0},
     const {'1': 'Markdown', '2': 1},
     const {'1': 'Link', '2': 2},
+    const {'1': 'Html', '2': 3},
   ],
 };
 
 /// Descriptor for `ExportType`. Decode as a `google.protobuf.EnumDescriptorProto`.
-final $typed_data.Uint8List exportTypeDescriptor = $convert.base64Decode('CgpFeHBvcnRUeXBlEggKBFRleHQQABIMCghNYXJrZG93bhABEggKBExpbmsQAg==');
+final $typed_data.Uint8List exportTypeDescriptor = $convert.base64Decode('CgpFeHBvcnRUeXBlEggKBFRleHQQABIMCghNYXJrZG93bhABEggKBExpbmsQAhIICgRIdG1sEAM=');
 @$core.Deprecated('Use exportRequestDescriptor instead')
 const ExportRequest$json = const {
   '1': 'ExportRequest',
@@ -0,0 +1,566 @@
+///
+//  Generated code. Do not modify.
+//  source: template.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+import 'dart:core' as $core;
+
+import 'package:fixnum/fixnum.dart' as $fixnum;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+import 'template.pbenum.dart';
+
+export 'template.pbenum.dart';
+
+class Template extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'Template', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'id')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'workspaceId')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
+    ..e<TemplateType>(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'ty', $pb.PbFieldType.OE, defaultOrMaker: TemplateType.Unknown, valueOf: TemplateType.valueOf, enumValues: TemplateType.values)
+    ..aInt64(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'createTime')
+    ..aOS(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'createdBy')
+    ..hasRequiredFields = false
+  ;
+
+  Template._() : super();
+  factory Template({
+    $core.String? id,
+    $core.String? workspaceId,
+    $core.String? name,
+    TemplateType? ty,
+    $fixnum.Int64? createTime,
+    $core.String? createdBy,
+  }) {
+    final _result = create();
+    if (id != null) {
+      _result.id = id;
+    }
+    if (workspaceId != null) {
+      _result.workspaceId = workspaceId;
+    }
+    if (name != null) {
+      _result.name = name;
+    }
+    if (ty != null) {
+      _result.ty = ty;
+    }
+    if (createTime != null) {
+      _result.createTime = createTime;
+    }
+    if (createdBy != null) {
+      _result.createdBy = createdBy;
+    }
+    return _result;
+  }
+  factory Template.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory Template.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  Template clone() => Template()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  Template copyWith(void Function(Template) updates) => super.copyWith((message) => updates(message as Template)) as Template; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static Template create() => Template._();
+  Template createEmptyInstance() => create();
+  static $pb.PbList<Template> createRepeated() => $pb.PbList<Template>();
+  @$core.pragma('dart2js:noInline')
+  static Template getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<Template>(create);
+  static Template? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get id => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set id($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get workspaceId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set workspaceId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasWorkspaceId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearWorkspaceId() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get name => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set name($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasName() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearName() => clearField(3);
+
+  @$pb.TagNumber(4)
+  TemplateType get ty => $_getN(3);
+  @$pb.TagNumber(4)
+  set ty(TemplateType v) { setField(4, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasTy() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearTy() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $fixnum.Int64 get createTime => $_getI64(4);
+  @$pb.TagNumber(5)
+  set createTime($fixnum.Int64 v) { $_setInt64(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasCreateTime() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearCreateTime() => clearField(5);
+
+  @$pb.TagNumber(6)
+  $core.String get createdBy => $_getSZ(5);
+  @$pb.TagNumber(6)
+  set createdBy($core.String v) { $_setString(5, v); }
+  @$pb.TagNumber(6)
+  $core.bool hasCreatedBy() => $_has(5);
+  @$pb.TagNumber(6)
+  void clearCreatedBy() => clearField(6);
+}
+
+class RepeatedTemplate extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepeatedTemplate', createEmptyInstance: create)
+    ..pc<Template>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items', $pb.PbFieldType.PM, subBuilder: Template.create)
+    ..hasRequiredFields = false
+  ;
+
+  RepeatedTemplate._() : super();
+  factory RepeatedTemplate({
+    $core.Iterable<Template>? items,
+  }) {
+    final _result = create();
+    if (items != null) {
+      _result.items.addAll(items);
+    }
+    return _result;
+  }
+  factory RepeatedTemplate.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RepeatedTemplate.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RepeatedTemplate clone() => RepeatedTemplate()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RepeatedTemplate copyWith(void Function(RepeatedTemplate) updates) => super.copyWith((message) => updates(message as RepeatedTemplate)) as RepeatedTemplate; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RepeatedTemplate create() => RepeatedTemplate._();
+  RepeatedTemplate createEmptyInstance() => create();
+  static $pb.PbList<RepeatedTemplate> createRepeated() => $pb.PbList<RepeatedTemplate>();
+  @$core.pragma('dart2js:noInline')
+  static RepeatedTemplate getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RepeatedTemplate>(create);
+  static RepeatedTemplate? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.List<Template> get items => $_getList(0);
+}
+
+class CreateTemplateRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'CreateTemplateRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'id')
+    ..e<TemplateType>(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'ty', $pb.PbFieldType.OE, defaultOrMaker: TemplateType.Unknown, valueOf: TemplateType.valueOf, enumValues: TemplateType.values)
+    ..hasRequiredFields = false
+  ;
+
+  CreateTemplateRequest._() : super();
+  factory CreateTemplateRequest({
+    $core.String? id,
+    TemplateType? ty,
+  }) {
+    final _result = create();
+    if (id != null) {
+      _result.id = id;
+    }
+    if (ty != null) {
+      _result.ty = ty;
+    }
+    return _result;
+  }
+  factory CreateTemplateRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory CreateTemplateRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  CreateTemplateRequest clone() => CreateTemplateRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  CreateTemplateRequest copyWith(void Function(CreateTemplateRequest) updates) => super.copyWith((message) => updates(message as CreateTemplateRequest)) as CreateTemplateRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static CreateTemplateRequest create() => CreateTemplateRequest._();
+  CreateTemplateRequest createEmptyInstance() => create();
+  static $pb.PbList<CreateTemplateRequest> createRepeated() => $pb.PbList<CreateTemplateRequest>();
+  @$core.pragma('dart2js:noInline')
+  static CreateTemplateRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<CreateTemplateRequest>(create);
+  static CreateTemplateRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get id => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set id($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  TemplateType get ty => $_getN(1);
+  @$pb.TagNumber(2)
+  set ty(TemplateType v) { setField(2, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasTy() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearTy() => clearField(2);
+}
+
+class CreateTemplateParams extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'CreateTemplateParams', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'id')
+    ..e<TemplateType>(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'ty', $pb.PbFieldType.OE, defaultOrMaker: TemplateType.Unknown, valueOf: TemplateType.valueOf, enumValues: TemplateType.values)
+    ..hasRequiredFields = false
+  ;
+
+  CreateTemplateParams._() : super();
+  factory CreateTemplateParams({
+    $core.String? id,
+    TemplateType? ty,
+  }) {
+    final _result = create();
+    if (id != null) {
+      _result.id = id;
+    }
+    if (ty != null) {
+      _result.ty = ty;
+    }
+    return _result;
+  }
+  factory CreateTemplateParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory CreateTemplateParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  CreateTemplateParams clone() => CreateTemplateParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  CreateTemplateParams copyWith(void Function(CreateTemplateParams) updates) => super.copyWith((message) => updates(message as CreateTemplateParams)) as CreateTemplateParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static CreateTemplateParams create() => CreateTemplateParams._();
+  CreateTemplateParams createEmptyInstance() => create();
+  static $pb.PbList<CreateTemplateParams> createRepeated() => $pb.PbList<CreateTemplateParams>();
+  @$core.pragma('dart2js:noInline')
+  static CreateTemplateParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<CreateTemplateParams>(create);
+  static CreateTemplateParams? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get id => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set id($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  TemplateType get ty => $_getN(1);
+  @$pb.TagNumber(2)
+  set ty(TemplateType v) { setField(2, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasTy() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearTy() => clearField(2);
+}
+
+class QueryTemplateRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'QueryTemplateRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'templateId')
+    ..hasRequiredFields = false
+  ;
+
+  QueryTemplateRequest._() : super();
+  factory QueryTemplateRequest({
+    $core.String? templateId,
+  }) {
+    final _result = create();
+    if (templateId != null) {
+      _result.templateId = templateId;
+    }
+    return _result;
+  }
+  factory QueryTemplateRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory QueryTemplateRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  QueryTemplateRequest clone() => QueryTemplateRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  QueryTemplateRequest copyWith(void Function(QueryTemplateRequest) updates) => super.copyWith((message) => updates(message as QueryTemplateRequest)) as QueryTemplateRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static QueryTemplateRequest create() => QueryTemplateRequest._();
+  QueryTemplateRequest createEmptyInstance() => create();
+  static $pb.PbList<QueryTemplateRequest> createRepeated() => $pb.PbList<QueryTemplateRequest>();
+  @$core.pragma('dart2js:noInline')
+  static QueryTemplateRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<QueryTemplateRequest>(create);
+  static QueryTemplateRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get templateId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set templateId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasTemplateId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearTemplateId() => clearField(1);
+}
+
+class TemplateId extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'TemplateId', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'templateId')
+    ..hasRequiredFields = false
+  ;
+
+  TemplateId._() : super();
+  factory TemplateId({
+    $core.String? templateId,
+  }) {
+    final _result = create();
+    if (templateId != null) {
+      _result.templateId = templateId;
+    }
+    return _result;
+  }
+  factory TemplateId.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory TemplateId.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  TemplateId clone() => TemplateId()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  TemplateId copyWith(void Function(TemplateId) updates) => super.copyWith((message) => updates(message as TemplateId)) as TemplateId; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static TemplateId create() => TemplateId._();
+  TemplateId createEmptyInstance() => create();
+  static $pb.PbList<TemplateId> createRepeated() => $pb.PbList<TemplateId>();
+  @$core.pragma('dart2js:noInline')
+  static TemplateId getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<TemplateId>(create);
+  static TemplateId? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get templateId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set templateId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasTemplateId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearTemplateId() => clearField(1);
+}
+
+enum CreateViewFromTemplateRequest_OneOfName {
+  name, 
+  notSet
+}
+
+class CreateViewFromTemplateRequest extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, CreateViewFromTemplateRequest_OneOfName> _CreateViewFromTemplateRequest_OneOfNameByTag = {
+    3 : CreateViewFromTemplateRequest_OneOfName.name,
+    0 : CreateViewFromTemplateRequest_OneOfName.notSet
+  };
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'CreateViewFromTemplateRequest', createEmptyInstance: create)
+    ..oo(0, [3])
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'templateId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'belongToId')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
+    ..hasRequiredFields = false
+  ;
+
+  CreateViewFromTemplateRequest._() : super();
+  factory CreateViewFromTemplateRequest({
+    $core.String? templateId,
+    $core.String? belongToId,
+    $core.String? name,
+  }) {
+    final _result = create();
+    if (templateId != null) {
+      _result.templateId = templateId;
+    }
+    if (belongToId != null) {
+      _result.belongToId = belongToId;
+    }
+    if (name != null) {
+      _result.name = name;
+    }
+    return _result;
+  }
+  factory CreateViewFromTemplateRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory CreateViewFromTemplateRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  CreateViewFromTemplateRequest clone() => CreateViewFromTemplateRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  CreateViewFromTemplateRequest copyWith(void Function(CreateViewFromTemplateRequest) updates) => super.copyWith((message) => updates(message as CreateViewFromTemplateRequest)) as CreateViewFromTemplateRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static CreateViewFromTemplateRequest create() => CreateViewFromTemplateRequest._();
+  CreateViewFromTemplateRequest createEmptyInstance() => create();
+  static $pb.PbList<CreateViewFromTemplateRequest> createRepeated() => $pb.PbList<CreateViewFromTemplateRequest>();
+  @$core.pragma('dart2js:noInline')
+  static CreateViewFromTemplateRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<CreateViewFromTemplateRequest>(create);
+  static CreateViewFromTemplateRequest? _defaultInstance;
+
+  CreateViewFromTemplateRequest_OneOfName whichOneOfName() => _CreateViewFromTemplateRequest_OneOfNameByTag[$_whichOneof(0)]!;
+  void clearOneOfName() => clearField($_whichOneof(0));
+
+  @$pb.TagNumber(1)
+  $core.String get templateId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set templateId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasTemplateId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearTemplateId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get belongToId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set belongToId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasBelongToId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearBelongToId() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get name => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set name($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasName() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearName() => clearField(3);
+}
+
+enum CreateViewFromTemplateParams_OneOfName {
+  name, 
+  notSet
+}
+
+class CreateViewFromTemplateParams extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, CreateViewFromTemplateParams_OneOfName> _CreateViewFromTemplateParams_OneOfNameByTag = {
+    3 : CreateViewFromTemplateParams_OneOfName.name,
+    0 : CreateViewFromTemplateParams_OneOfName.notSet
+  };
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'CreateViewFromTemplateParams', createEmptyInstance: create)
+    ..oo(0, [3])
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'templateId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'belongToId')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
+    ..hasRequiredFields = false
+  ;
+
+  CreateViewFromTemplateParams._() : super();
+  factory CreateViewFromTemplateParams({
+    $core.String? templateId,
+    $core.String? belongToId,
+    $core.String? name,
+  }) {
+    final _result = create();
+    if (templateId != null) {
+      _result.templateId = templateId;
+    }
+    if (belongToId != null) {
+      _result.belongToId = belongToId;
+    }
+    if (name != null) {
+      _result.name = name;
+    }
+    return _result;
+  }
+  factory CreateViewFromTemplateParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory CreateViewFromTemplateParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  CreateViewFromTemplateParams clone() => CreateViewFromTemplateParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  CreateViewFromTemplateParams copyWith(void Function(CreateViewFromTemplateParams) updates) => super.copyWith((message) => updates(message as CreateViewFromTemplateParams)) as CreateViewFromTemplateParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static CreateViewFromTemplateParams create() => CreateViewFromTemplateParams._();
+  CreateViewFromTemplateParams createEmptyInstance() => create();
+  static $pb.PbList<CreateViewFromTemplateParams> createRepeated() => $pb.PbList<CreateViewFromTemplateParams>();
+  @$core.pragma('dart2js:noInline')
+  static CreateViewFromTemplateParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<CreateViewFromTemplateParams>(create);
+  static CreateViewFromTemplateParams? _defaultInstance;
+
+  CreateViewFromTemplateParams_OneOfName whichOneOfName() => _CreateViewFromTemplateParams_OneOfNameByTag[$_whichOneof(0)]!;
+  void clearOneOfName() => clearField($_whichOneof(0));
+
+  @$pb.TagNumber(1)
+  $core.String get templateId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set templateId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasTemplateId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearTemplateId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get belongToId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set belongToId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasBelongToId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearBelongToId() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get name => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set name($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasName() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearName() => clearField(3);
+}
+
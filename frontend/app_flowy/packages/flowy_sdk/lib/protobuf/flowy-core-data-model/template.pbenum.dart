@@ -0,0 +1,28 @@
+///
+//  Generated code. Do not modify.
+//  source: template.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+// ignore_for_file: UNDEFINED_SHOWN_NAME
+import 'dart:core' as $core;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+class TemplateType extends $pb.ProtobufEnum {
+  static const TemplateType Unknown = TemplateType._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Unknown');
+  static const TemplateType View = TemplateType._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'View');
+  static const TemplateType App = TemplateType._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'App');
+
+  static const $core.List<TemplateType> values = <TemplateType> [
+    Unknown,
+    View,
+    App,
+  ];
+
+  static final $core.Map<$core.int, TemplateType> _byValue = $pb.ProtobufEnum.initByValue(values);
+  static TemplateType? valueOf($core.int value) => _byValue[value];
+
+  const TemplateType._($core.int v, $core.String n) : super(v, n);
+}
+
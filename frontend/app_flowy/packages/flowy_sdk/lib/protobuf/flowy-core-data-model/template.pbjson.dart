@@ -0,0 +1,119 @@
+///
+//  Generated code. Do not modify.
+//  source: template.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use templateTypeDescriptor instead')
+const TemplateType$json = const {
+  '1': 'TemplateType',
+  '2': const [
+    const {'1': 'Unknown', '2': 0},
+    const {'1': 'View', '2': 1},
+    const {'1': 'App', '2': 2},
+  ],
+};
+
+/// Descriptor for `TemplateType`. Decode as a `google.protobuf.EnumDescriptorProto`.
+final $typed_data.Uint8List templateTypeDescriptor = $convert.base64Decode('CgxUZW1wbGF0ZVR5cGUSCwoHVW5rbm93bhAAEggKBFZpZXcQARIHCgNBcHAQAg==');
+@$core.Deprecated('Use templateDescriptor instead')
+const Template$json = const {
+  '1': 'Template',
+  '2': const [
+    const {'1': 'id', '3': 1, '4': 1, '5': 9, '10': 'id'},
+    const {'1': 'workspace_id', '3': 2, '4': 1, '5': 9, '10': 'workspaceId'},
+    const {'1': 'name', '3': 3, '4': 1, '5': 9, '10': 'name'},
+    const {'1': 'ty', '3': 4, '4': 1, '5': 14, '6': '.TemplateType', '10': 'ty'},
+    const {'1': 'create_time', '3': 5, '4': 1, '5': 3, '10': 'createTime'},
+    const {'1': 'created_by', '3': 6, '4': 1, '5': 9, '10': 'createdBy'},
+  ],
+};
+
+/// Descriptor for `Template`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List templateDescriptor = $convert.base64Decode('CghUZW1wbGF0ZRIOCgJpZBgBIAEoCVICaWQSIQoMd29ya3NwYWNlX2lkGAIgASgJUgt3b3Jrc3BhY2VJZBISCgRuYW1lGAMgASgJUgRuYW1lEh0KAnR5GAQgASgOMg0uVGVtcGxhdGVUeXBlUgJ0eRIfCgtjcmVhdGVfdGltZRgFIAEoA1IKY3JlYXRlVGltZRIdCgpjcmVhdGVkX2J5GAYgASgJUgljcmVhdGVkQnk=');
+@$core.Deprecated('Use repeatedTemplateDescriptor instead')
+const RepeatedTemplate$json = const {
+  '1': 'RepeatedTemplate',
+  '2': const [
+    const {'1': 'items', '3': 1, '4': 3, '5': 11, '6': '.Template', '10': 'items'},
+  ],
+};
+
+/// Descriptor for `RepeatedTemplate`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List repeatedTemplateDescriptor = $convert.base64Decode('ChBSZXBlYXRlZFRlbXBsYXRlEh8KBWl0ZW1zGAEgAygLMgkuVGVtcGxhdGVSBWl0ZW1z');
+@$core.Deprecated('Use createTemplateRequestDescriptor instead')
+const CreateTemplateRequest$json = const {
+  '1': 'CreateTemplateRequest',
+  '2': const [
+    const {'1': 'id', '3': 1, '4': 1, '5': 9, '10': 'id'},
+    const {'1': 'ty', '3': 2, '4': 1, '5': 14, '6': '.TemplateType', '10': 'ty'},
+  ],
+};
+
+/// Descriptor for `CreateTemplateRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List createTemplateRequestDescriptor = $convert.base64Decode('ChVDcmVhdGVUZW1wbGF0ZVJlcXVlc3QSDgoCaWQYASABKAlSAmlkEh0KAnR5GAIgASgOMg0uVGVtcGxhdGVUeXBlUgJ0eQ==');
+@$core.Deprecated('Use createTemplateParamsDescriptor instead')
+const CreateTemplateParams$json = const {
+  '1': 'CreateTemplateParams',
+  '2': const [
+    const {'1': 'id', '3': 1, '4': 1, '5': 9, '10': 'id'},
+    const {'1': 'ty', '3': 2, '4': 1, '5': 14, '6': '.TemplateType', '10': 'ty'},
+  ],
+};
+
+/// Descriptor for `CreateTemplateParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List createTemplateParamsDescriptor = $convert.base64Decode('ChRDcmVhdGVUZW1wbGF0ZVBhcmFtcxIOCgJpZBgBIAEoCVICaWQSHQoCdHkYAiABKA4yDS5UZW1wbGF0ZVR5cGVSAnR5');
+@$core.Deprecated('Use queryTemplateRequestDescriptor instead')
+const QueryTemplateRequest$json = const {
+  '1': 'QueryTemplateRequest',
+  '2': const [
+    const {'1': 'template_id', '3': 1, '4': 1, '5': 9, '10': 'templateId'},
+  ],
+};
+
+/// Descriptor for `QueryTemplateRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List queryTemplateRequestDescriptor = $convert.base64Decode('ChRRdWVyeVRlbXBsYXRlUmVxdWVzdBIfCgt0ZW1wbGF0ZV9pZBgBIAEoCVIKdGVtcGxhdGVJZA==');
+@$core.Deprecated('Use templateIdDescriptor instead')
+const TemplateId$json = const {
+  '1': 'TemplateId',
+  '2': const [
+    const {'1': 'template_id', '3': 1, '4': 1, '5': 9, '10': 'templateId'},
+  ],
+};
+
+/// Descriptor for `TemplateId`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List templateIdDescriptor = $convert.base64Decode('CgpUZW1wbGF0ZUlkEh8KC3RlbXBsYXRlX2lkGAEgASgJUgp0ZW1wbGF0ZUlk');
+@$core.Deprecated('Use createViewFromTemplateRequestDescriptor instead')
+const CreateViewFromTemplateRequest$json = const {
+  '1': 'CreateViewFromTemplateRequest',
+  '2': const [
+    const {'1': 'template_id', '3': 1, '4': 1, '5': 9, '10': 'templateId'},
+    const {'1': 'belong_to_id', '3': 2, '4': 1, '5': 9, '10': 'belongToId'},
+    const {'1': 'name', '3': 3, '4': 1, '5': 9, '9': 0, '10': 'name'},
+  ],
+  '8': const [
+    const {'1': 'one_of_name'},
+  ],
+};
+
+/// Descriptor for `CreateViewFromTemplateRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List createViewFromTemplateRequestDescriptor = $convert.base64Decode('Ch1DcmVhdGVWaWV3RnJvbVRlbXBsYXRlUmVxdWVzdBIfCgt0ZW1wbGF0ZV9pZBgBIAEoCVIKdGVtcGxhdGVJZBIgCgxiZWxvbmdfdG9faWQYAiABKAlSCmJlbG9uZ1RvSWQSFAoEbmFtZRgDIAEoCUgAUgRuYW1lQg0KC29uZV9vZl9uYW1l');
+@$core.Deprecated('Use createViewFromTemplateParamsDescriptor instead')
+const CreateViewFromTemplateParams$json = const {
+  '1': 'CreateViewFromTemplateParams',
+  '2': const [
+    const {'1': 'template_id', '3': 1, '4': 1, '5': 9, '10': 'templateId'},
+    const {'1': 'belong_to_id', '3': 2, '4': 1, '5': 9, '10': 'belongToId'},
+    const {'1': 'name', '3': 3, '4': 1, '5': 9, '9': 0, '10': 'name'},
+  ],
+  '8': const [
+    const {'1': 'one_of_name'},
+  ],
+};
+
+/// Descriptor for `CreateViewFromTemplateParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List createViewFromTemplateParamsDescriptor = $convert.base64Decode('ChxDcmVhdGVWaWV3RnJvbVRlbXBsYXRlUGFyYW1zEh8KC3RlbXBsYXRlX2lkGAEgASgJUgp0ZW1wbGF0ZUlkEiAKDGJlbG9uZ190b19pZBgCIAEoCVIKYmVsb25nVG9JZBIUCgRuYW1lGAMgASgJSABSBG5hbWVCDQoLb25lX29mX25hbWU=');
@@ -21,6 +21,8 @@ class Trash extends $pb.GeneratedMessage {
     ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'modifiedTime')
     ..aInt64(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'createTime')
     ..e<TrashType>(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'ty', $pb.PbFieldType.OE, defaultOrMaker: TrashType.Unknown, valueOf: TrashType.valueOf, enumValues: TrashType.values)
+    ..aInt64(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'deletedTime')
+    ..aOS(7, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'deletedBy')
     ..hasRequiredFields = false
   ;
 
@@ -31,6 +33,8 @@ class Trash extends $pb.GeneratedMessage {
     $fixnum.Int64? modifiedTime,
     $fixnum.Int64? createTime,
     TrashType? ty,
+    $fixnum.Int64? deletedTime,
+    $core.String? deletedBy,
   }) {
     final _result = create();
     if (id != null) {
@@ -48,6 +52,12 @@ class Trash extends $pb.GeneratedMessage {
     if (ty != null) {
       _result.ty = ty;
     }
+    if (deletedTime != null) {
+      _result.deletedTime = deletedTime;
+    }
+    if (deletedBy != null) {
+      _result.deletedBy = deletedBy;
+    }
     return _result;
   }
   factory Trash.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -115,6 +125,24 @@ class Trash extends $pb.GeneratedMessage {
   $core.bool hasTy() => $_has(4);
   @$pb.TagNumber(5)
   void clearTy() => clearField(5);
+
+  @$pb.TagNumber(6)
+  $fixnum.Int64 get deletedTime => $_getI64(5);
+  @$pb.TagNumber(6)
+  set deletedTime($fixnum.Int64 v) { $_setInt64(5, v); }
+  @$pb.TagNumber(6)
+  $core.bool hasDeletedTime() => $_has(5);
+  @$pb.TagNumber(6)
+  void clearDeletedTime() => clearField(6);
+
+  @$pb.TagNumber(7)
+  $core.String get deletedBy => $_getSZ(6);
+  @$pb.TagNumber(7)
+  set deletedBy($core.String v) { $_setString(6, v); }
+  @$pb.TagNumber(7)
+  $core.bool hasDeletedBy() => $_has(6);
+  @$pb.TagNumber(7)
+  void clearDeletedBy() => clearField(7);
 }
 
 class RepeatedTrash extends $pb.GeneratedMessage {
@@ -158,6 +186,53 @@ class RepeatedTrash extends $pb.GeneratedMessage {
   $core.List<Trash> get items => $_getList(0);
 }
 
+class TrashRetentionPolicy extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'TrashRetentionPolicy', createEmptyInstance: create)
+    ..aInt64(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'retentionDays')
+    ..hasRequiredFields = false
+  ;
+
+  TrashRetentionPolicy._() : super();
+  factory TrashRetentionPolicy({
+    $fixnum.Int64? retentionDays,
+  }) {
+    final _result = create();
+    if (retentionDays != null) {
+      _result.retentionDays = retentionDays;
+    }
+    return _result;
+  }
+  factory TrashRetentionPolicy.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory TrashRetentionPolicy.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  TrashRetentionPolicy clone() => TrashRetentionPolicy()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  TrashRetentionPolicy copyWith(void Function(TrashRetentionPolicy) updates) => super.copyWith((message) => updates(message as TrashRetentionPolicy)) as TrashRetentionPolicy; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static TrashRetentionPolicy create() => TrashRetentionPolicy._();
+  TrashRetentionPolicy createEmptyInstance() => create();
+  static $pb.PbList<TrashRetentionPolicy> createRepeated() => $pb.PbList<TrashRetentionPolicy>();
+  @$core.pragma('dart2js:noInline')
+  static TrashRetentionPolicy getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<TrashRetentionPolicy>(create);
+  static TrashRetentionPolicy? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $fixnum.Int64 get retentionDays => $_getI64(0);
+  @$pb.TagNumber(1)
+  set retentionDays($fixnum.Int64 v) { $_setInt64(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasRetentionDays() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearRetentionDays() => clearField(1);
+}
+
 class RepeatedTrashId extends $pb.GeneratedMessage {
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepeatedTrashId', createEmptyInstance: create)
     ..pc<TrashId>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items', $pb.PbFieldType.PM, subBuilder: TrashId.create)
@@ -15,11 +15,12 @@ const TrashType$json = const {
     const {'1': 'Unknown', '2': 0},
     const {'1': 'View', '2': 1},
     const {'1': 'App', '2': 2},
+    const {'1': 'Workspace', '2': 3},
   ],
 };
 
 /// Descriptor for `TrashType`. Decode as a `google.protobuf.EnumDescriptorProto`.
-final $typed_data.Uint8List trashTypeDescriptor = $convert.base64Decode('CglUcmFzaFR5cGUSCwoHVW5rbm93bhAAEggKBFZpZXcQARIHCgNBcHAQAg==');
+final $typed_data.Uint8List trashTypeDescriptor = $convert.base64Decode('CglUcmFzaFR5cGUSCwoHVW5rbm93bhAAEggKBFZpZXcQARIHCgNBcHAQAhINCglXb3Jrc3BhY2UQAw==');
 @$core.Deprecated('Use trashDescriptor instead')
 const Trash$json = const {
   '1': 'Trash',
@@ -29,11 +30,13 @@ const Trash$json = const {
     const {'1': 'modified_time', '3': 3, '4': 1, '5': 3, '10': 'modifiedTime'},
     const {'1': 'create_time', '3': 4, '4': 1, '5': 3, '10': 'createTime'},
     const {'1': 'ty', '3': 5, '4': 1, '5': 14, '6': '.TrashType', '10': 'ty'},
+    const {'1': 'deleted_time', '3': 6, '4': 1, '5': 3, '10': 'deletedTime'},
+    const {'1': 'deleted_by', '3': 7, '4': 1, '5': 9, '10': 'deletedBy'},
   ],
 };
 
 /// Descriptor for `Trash`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List trashDescriptor = $convert.base64Decode('CgVUcmFzaBIOCgJpZBgBIAEoCVICaWQSEgoEbmFtZRgCIAEoCVIEbmFtZRIjCg1tb2RpZmllZF90aW1lGAMgASgDUgxtb2RpZmllZFRpbWUSHwoLY3JlYXRlX3RpbWUYBCABKANSCmNyZWF0ZVRpbWUSGgoCdHkYBSABKA4yCi5UcmFzaFR5cGVSAnR5');
+final $typed_data.Uint8List trashDescriptor = $convert.base64Decode('CgVUcmFzaBIOCgJpZBgBIAEoCVICaWQSEgoEbmFtZRgCIAEoCVIEbmFtZRIjCg1tb2RpZmllZF90aW1lGAMgASgDUgxtb2RpZmllZFRpbWUSHwoLY3JlYXRlX3RpbWUYBCABKANSCmNyZWF0ZVRpbWUSGgoCdHkYBSABKA4yCi5UcmFzaFR5cGVSAnR5EiEKDGRlbGV0ZWRfdGltZRgGIAEoA1ILZGVsZXRlZFRpbWUSHQoKZGVsZXRlZF9ieRgHIAEoCVIJZGVsZXRlZEJ5');
 @$core.Deprecated('Use repeatedTrashDescriptor instead')
 const RepeatedTrash$json = const {
   '1': 'RepeatedTrash',
@@ -44,6 +47,16 @@ const RepeatedTrash$json = const {
 
 /// Descriptor for `RepeatedTrash`. Decode as a `google.protobuf.DescriptorProto`.
 final $typed_data.Uint8List repeatedTrashDescriptor = $convert.base64Decode('Cg1SZXBlYXRlZFRyYXNoEhwKBWl0ZW1zGAEgAygLMgYuVHJhc2hSBWl0ZW1z');
+@$core.Deprecated('Use trashRetentionPolicyDescriptor instead')
+const TrashRetentionPolicy$json = const {
+  '1': 'TrashRetentionPolicy',
+  '2': const [
+    const {'1': 'retention_days', '3': 1, '4': 1, '5': 3, '10': 'retentionDays'},
+  ],
+};
+
+/// Descriptor for `TrashRetentionPolicy`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List trashRetentionPolicyDescriptor = $convert.base64Decode('ChRUcmFzaFJldGVudGlvblBvbGljeRIlCg5yZXRlbnRpb25fZGF5cxgBIAEoA1INcmV0ZW50aW9uRGF5cw==');
 @$core.Deprecated('Use repeatedTrashIdDescriptor instead')
 const RepeatedTrashId$json = const {
   '1': 'RepeatedTrashId',
@@ -25,6 +25,8 @@ class View extends $pb.GeneratedMessage {
     ..aOM<RepeatedView>(7, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'belongings', subBuilder: RepeatedView.create)
     ..aInt64(8, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'modifiedTime')
     ..aInt64(9, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'createTime')
+    ..pPS(10, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'tags')
+    ..pc<ViewProperty>(11, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'properties', $pb.PbFieldType.PM, subBuilder: ViewProperty.create)
     ..hasRequiredFields = false
   ;
 
@@ -39,6 +41,8 @@ class View extends $pb.GeneratedMessage {
     RepeatedView? belongings,
     $fixnum.Int64? modifiedTime,
     $fixnum.Int64? createTime,
+    $core.Iterable<$core.String>? tags,
+    $core.Iterable<ViewProperty>? properties,
   }) {
     final _result = create();
     if (id != null) {
@@ -68,6 +72,12 @@ class View extends $pb.GeneratedMessage {
     if (createTime != null) {
       _result.createTime = createTime;
     }
+    if (tags != null) {
+      _result.tags.addAll(tags);
+    }
+    if (properties != null) {
+      _result.properties.addAll(properties);
+    }
     return _result;
   }
   factory View.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -173,6 +183,12 @@ class View extends $pb.GeneratedMessage {
   $core.bool hasCreateTime() => $_has(8);
   @$pb.TagNumber(9)
   void clearCreateTime() => clearField(9);
+
+  @$pb.TagNumber(10)
+  $core.List<$core.String> get tags => $_getList(9);
+
+  @$pb.TagNumber(11)
+  $core.List<ViewProperty> get properties => $_getList(10);
 }
 
 class RepeatedView extends $pb.GeneratedMessage {
@@ -848,3 +864,624 @@ class UpdateViewParams extends $pb.GeneratedMessage {
   void clearThumbnail() => clearField(4);
 }
 
+class ViewProperty extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'ViewProperty', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'key')
+    ..e<ViewPropertyType>(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'ty', $pb.PbFieldType.OE, defaultOrMaker: ViewPropertyType.Text, valueOf: ViewPropertyType.valueOf, enumValues: ViewPropertyType.values)
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'value')
+    ..pPS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'options')
+    ..hasRequiredFields = false
+  ;
+
+  ViewProperty._() : super();
+  factory ViewProperty({
+    $core.String? key,
+    ViewPropertyType? ty,
+    $core.String? value,
+    $core.Iterable<$core.String>? options,
+  }) {
+    final _result = create();
+    if (key != null) {
+      _result.key = key;
+    }
+    if (ty != null) {
+      _result.ty = ty;
+    }
+    if (value != null) {
+      _result.value = value;
+    }
+    if (options != null) {
+      _result.options.addAll(options);
+    }
+    return _result;
+  }
+  factory ViewProperty.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory ViewProperty.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  ViewProperty clone() => ViewProperty()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  ViewProperty copyWith(void Function(ViewProperty) updates) => super.copyWith((message) => updates(message as ViewProperty)) as ViewProperty; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static ViewProperty create() => ViewProperty._();
+  ViewProperty createEmptyInstance() => create();
+  static $pb.PbList<ViewProperty> createRepeated() => $pb.PbList<ViewProperty>();
+  @$core.pragma('dart2js:noInline')
+  static ViewProperty getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<ViewProperty>(create);
+  static ViewProperty? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get key => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set key($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasKey() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearKey() => clearField(1);
+
+  @$pb.TagNumber(2)
+  ViewPropertyType get ty => $_getN(1);
+  @$pb.TagNumber(2)
+  set ty(ViewPropertyType v) { setField(2, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasTy() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearTy() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get value => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set value($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasValue() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearValue() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $core.List<$core.String> get options => $_getList(3);
+}
+
+class UpdateViewTagsRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'UpdateViewTagsRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewId')
+    ..pPS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'tags')
+    ..hasRequiredFields = false
+  ;
+
+  UpdateViewTagsRequest._() : super();
+  factory UpdateViewTagsRequest({
+    $core.String? viewId,
+    $core.Iterable<$core.String>? tags,
+  }) {
+    final _result = create();
+    if (viewId != null) {
+      _result.viewId = viewId;
+    }
+    if (tags != null) {
+      _result.tags.addAll(tags);
+    }
+    return _result;
+  }
+  factory UpdateViewTagsRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory UpdateViewTagsRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  UpdateViewTagsRequest clone() => UpdateViewTagsRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  UpdateViewTagsRequest copyWith(void Function(UpdateViewTagsRequest) updates) => super.copyWith((message) => updates(message as UpdateViewTagsRequest)) as UpdateViewTagsRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static UpdateViewTagsRequest create() => UpdateViewTagsRequest._();
+  UpdateViewTagsRequest createEmptyInstance() => create();
+  static $pb.PbList<UpdateViewTagsRequest> createRepeated() => $pb.PbList<UpdateViewTagsRequest>();
+  @$core.pragma('dart2js:noInline')
+  static UpdateViewTagsRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<UpdateViewTagsRequest>(create);
+  static UpdateViewTagsRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get viewId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set viewId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasViewId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearViewId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.List<$core.String> get tags => $_getList(1);
+}
+
+class UpdateViewTagsParams extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'UpdateViewTagsParams', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewId')
+    ..pPS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'tags')
+    ..hasRequiredFields = false
+  ;
+
+  UpdateViewTagsParams._() : super();
+  factory UpdateViewTagsParams({
+    $core.String? viewId,
+    $core.Iterable<$core.String>? tags,
+  }) {
+    final _result = create();
+    if (viewId != null) {
+      _result.viewId = viewId;
+    }
+    if (tags != null) {
+      _result.tags.addAll(tags);
+    }
+    return _result;
+  }
+  factory UpdateViewTagsParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory UpdateViewTagsParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  UpdateViewTagsParams clone() => UpdateViewTagsParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  UpdateViewTagsParams copyWith(void Function(UpdateViewTagsParams) updates) => super.copyWith((message) => updates(message as UpdateViewTagsParams)) as UpdateViewTagsParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static UpdateViewTagsParams create() => UpdateViewTagsParams._();
+  UpdateViewTagsParams createEmptyInstance() => create();
+  static $pb.PbList<UpdateViewTagsParams> createRepeated() => $pb.PbList<UpdateViewTagsParams>();
+  @$core.pragma('dart2js:noInline')
+  static UpdateViewTagsParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<UpdateViewTagsParams>(create);
+  static UpdateViewTagsParams? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get viewId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set viewId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasViewId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearViewId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.List<$core.String> get tags => $_getList(1);
+}
+
+enum UpdateViewPropertyRequest_OneOfValue {
+  value, 
+  notSet
+}
+
+class UpdateViewPropertyRequest extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, UpdateViewPropertyRequest_OneOfValue> _UpdateViewPropertyRequest_OneOfValueByTag = {
+    4 : UpdateViewPropertyRequest_OneOfValue.value,
+    0 : UpdateViewPropertyRequest_OneOfValue.notSet
+  };
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'UpdateViewPropertyRequest', createEmptyInstance: create)
+    ..oo(0, [4])
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'key')
+    ..e<ViewPropertyType>(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'ty', $pb.PbFieldType.OE, defaultOrMaker: ViewPropertyType.Text, valueOf: ViewPropertyType.valueOf, enumValues: ViewPropertyType.values)
+    ..aOS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'value')
+    ..pPS(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'options')
+    ..hasRequiredFields = false
+  ;
+
+  UpdateViewPropertyRequest._() : super();
+  factory UpdateViewPropertyRequest({
+    $core.String? viewId,
+    $core.String? key,
+    ViewPropertyType? ty,
+    $core.String? value,
+    $core.Iterable<$core.String>? options,
+  }) {
+    final _result = create();
+    if (viewId != null) {
+      _result.viewId = viewId;
+    }
+    if (key != null) {
+      _result.key = key;
+    }
+    if (ty != null) {
+      _result.ty = ty;
+    }
+    if (value != null) {
+      _result.value = value;
+    }
+    if (options != null) {
+      _result.options.addAll(options);
+    }
+    return _result;
+  }
+  factory UpdateViewPropertyRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory UpdateViewPropertyRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  UpdateViewPropertyRequest clone() => UpdateViewPropertyRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  UpdateViewPropertyRequest copyWith(void Function(UpdateViewPropertyRequest) updates) => super.copyWith((message) => updates(message as UpdateViewPropertyRequest)) as UpdateViewPropertyRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static UpdateViewPropertyRequest create() => UpdateViewPropertyRequest._();
+  UpdateViewPropertyRequest createEmptyInstance() => create();
+  static $pb.PbList<UpdateViewPropertyRequest> createRepeated() => $pb.PbList<UpdateViewPropertyRequest>();
+  @$core.pragma('dart2js:noInline')
+  static UpdateViewPropertyRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<UpdateViewPropertyRequest>(create);
+  static UpdateViewPropertyRequest? _defaultInstance;
+
+  UpdateViewPropertyRequest_OneOfValue whichOneOfValue() => _UpdateViewPropertyRequest_OneOfValueByTag[$_whichOneof(0)]!;
+  void clearOneOfValue() => clearField($_whichOneof(0));
+
+  @$pb.TagNumber(1)
+  $core.String get viewId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set viewId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasViewId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearViewId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get key => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set key($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasKey() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearKey() => clearField(2);
+
+  @$pb.TagNumber(3)
+  ViewPropertyType get ty => $_getN(2);
+  @$pb.TagNumber(3)
+  set ty(ViewPropertyType v) { setField(3, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasTy() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearTy() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $core.String get value => $_getSZ(3);
+  @$pb.TagNumber(4)
+  set value($core.String v) { $_setString(3, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasValue() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearValue() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $core.List<$core.String> get options => $_getList(4);
+}
+
+enum UpdateViewPropertyParams_OneOfProperty {
+  property, 
+  notSet
+}
+
+class UpdateViewPropertyParams extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, UpdateViewPropertyParams_OneOfProperty> _UpdateViewPropertyParams_OneOfPropertyByTag = {
+    3 : UpdateViewPropertyParams_OneOfProperty.property,
+    0 : UpdateViewPropertyParams_OneOfProperty.notSet
+  };
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'UpdateViewPropertyParams', createEmptyInstance: create)
+    ..oo(0, [3])
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'key')
+    ..aOM<ViewProperty>(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'property', subBuilder: ViewProperty.create)
+    ..hasRequiredFields = false
+  ;
+
+  UpdateViewPropertyParams._() : super();
+  factory UpdateViewPropertyParams({
+    $core.String? viewId,
+    $core.String? key,
+    ViewProperty? property,
+  }) {
+    final _result = create();
+    if (viewId != null) {
+      _result.viewId = viewId;
+    }
+    if (key != null) {
+      _result.key = key;
+    }
+    if (property != null) {
+      _result.property = property;
+    }
+    return _result;
+  }
+  factory UpdateViewPropertyParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory UpdateViewPropertyParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  UpdateViewPropertyParams clone() => UpdateViewPropertyParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  UpdateViewPropertyParams copyWith(void Function(UpdateViewPropertyParams) updates) => super.copyWith((message) => updates(message as UpdateViewPropertyParams)) as UpdateViewPropertyParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static UpdateViewPropertyParams create() => UpdateViewPropertyParams._();
+  UpdateViewPropertyParams createEmptyInstance() => create();
+  static $pb.PbList<UpdateViewPropertyParams> createRepeated() => $pb.PbList<UpdateViewPropertyParams>();
+  @$core.pragma('dart2js:noInline')
+  static UpdateViewPropertyParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<UpdateViewPropertyParams>(create);
+  static UpdateViewPropertyParams? _defaultInstance;
+
+  UpdateViewPropertyParams_OneOfProperty whichOneOfProperty() => _UpdateViewPropertyParams_OneOfPropertyByTag[$_whichOneof(0)]!;
+  void clearOneOfProperty() => clearField($_whichOneof(0));
+
+  @$pb.TagNumber(1)
+  $core.String get viewId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set viewId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasViewId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearViewId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get key => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set key($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasKey() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearKey() => clearField(2);
+
+  @$pb.TagNumber(3)
+  ViewProperty get property => $_getN(2);
+  @$pb.TagNumber(3)
+  set property(ViewProperty v) { setField(3, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasProperty() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearProperty() => clearField(3);
+  @$pb.TagNumber(3)
+  ViewProperty ensureProperty() => $_ensure(2);
+}
+
+enum ViewFilterRequest_OneOfTag {
+  tag, 
+  notSet
+}
+
+enum ViewFilterRequest_OneOfPropertyKey {
+  propertyKey, 
+  notSet
+}
+
+enum ViewFilterRequest_OneOfPropertyValue {
+  propertyValue, 
+  notSet
+}
+
+class ViewFilterRequest extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, ViewFilterRequest_OneOfTag> _ViewFilterRequest_OneOfTagByTag = {
+    1 : ViewFilterRequest_OneOfTag.tag,
+    0 : ViewFilterRequest_OneOfTag.notSet
+  };
+  static const $core.Map<$core.int, ViewFilterRequest_OneOfPropertyKey> _ViewFilterRequest_OneOfPropertyKeyByTag = {
+    2 : ViewFilterRequest_OneOfPropertyKey.propertyKey,
+    0 : ViewFilterRequest_OneOfPropertyKey.notSet
+  };
+  static const $core.Map<$core.int, ViewFilterRequest_OneOfPropertyValue> _ViewFilterRequest_OneOfPropertyValueByTag = {
+    3 : ViewFilterRequest_OneOfPropertyValue.propertyValue,
+    0 : ViewFilterRequest_OneOfPropertyValue.notSet
+  };
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'ViewFilterRequest', createEmptyInstance: create)
+    ..oo(0, [1])
+    ..oo(1, [2])
+    ..oo(2, [3])
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'tag')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'propertyKey')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'propertyValue')
+    ..hasRequiredFields = false
+  ;
+
+  ViewFilterRequest._() : super();
+  factory ViewFilterRequest({
+    $core.String? tag,
+    $core.String? propertyKey,
+    $core.String? propertyValue,
+  }) {
+    final _result = create();
+    if (tag != null) {
+      _result.tag = tag;
+    }
+    if (propertyKey != null) {
+      _result.propertyKey = propertyKey;
+    }
+    if (propertyValue != null) {
+      _result.propertyValue = propertyValue;
+    }
+    return _result;
+  }
+  factory ViewFilterRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory ViewFilterRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  ViewFilterRequest clone() => ViewFilterRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  ViewFilterRequest copyWith(void Function(ViewFilterRequest) updates) => super.copyWith((message) => updates(message as ViewFilterRequest)) as ViewFilterRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static ViewFilterRequest create() => ViewFilterRequest._();
+  ViewFilterRequest createEmptyInstance() => create();
+  static $pb.PbList<ViewFilterRequest> createRepeated() => $pb.PbList<ViewFilterRequest>();
+  @$core.pragma('dart2js:noInline')
+  static ViewFilterRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<ViewFilterRequest>(create);
+  static ViewFilterRequest? _defaultInstance;
+
+  ViewFilterRequest_OneOfTag whichOneOfTag() => _ViewFilterRequest_OneOfTagByTag[$_whichOneof(0)]!;
+  void clearOneOfTag() => clearField($_whichOneof(0));
+
+  ViewFilterRequest_OneOfPropertyKey whichOneOfPropertyKey() => _ViewFilterRequest_OneOfPropertyKeyByTag[$_whichOneof(1)]!;
+  void clearOneOfPropertyKey() => clearField($_whichOneof(1));
+
+  ViewFilterRequest_OneOfPropertyValue whichOneOfPropertyValue() => _ViewFilterRequest_OneOfPropertyValueByTag[$_whichOneof(2)]!;
+  void clearOneOfPropertyValue() => clearField($_whichOneof(2));
+
+  @$pb.TagNumber(1)
+  $core.String get tag => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set tag($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasTag() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearTag() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get propertyKey => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set propertyKey($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasPropertyKey() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearPropertyKey() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get propertyValue => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set propertyValue($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasPropertyValue() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearPropertyValue() => clearField(3);
+}
+
+enum ViewFilterParams_OneOfTag {
+  tag, 
+  notSet
+}
+
+enum ViewFilterParams_OneOfPropertyKey {
+  propertyKey, 
+  notSet
+}
+
+enum ViewFilterParams_OneOfPropertyValue {
+  propertyValue, 
+  notSet
+}
+
+class ViewFilterParams extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, ViewFilterParams_OneOfTag> _ViewFilterParams_OneOfTagByTag = {
+    1 : ViewFilterParams_OneOfTag.tag,
+    0 : ViewFilterParams_OneOfTag.notSet
+  };
+  static const $core.Map<$core.int, ViewFilterParams_OneOfPropertyKey> _ViewFilterParams_OneOfPropertyKeyByTag = {
+    2 : ViewFilterParams_OneOfPropertyKey.propertyKey,
+    0 : ViewFilterParams_OneOfPropertyKey.notSet
+  };
+  static const $core.Map<$core.int, ViewFilterParams_OneOfPropertyValue> _ViewFilterParams_OneOfPropertyValueByTag = {
+    3 : ViewFilterParams_OneOfPropertyValue.propertyValue,
+    0 : ViewFilterParams_OneOfPropertyValue.notSet
+  };
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'ViewFilterParams', createEmptyInstance: create)
+    ..oo(0, [1])
+    ..oo(1, [2])
+    ..oo(2, [3])
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'tag')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'propertyKey')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'propertyValue')
+    ..hasRequiredFields = false
+  ;
+
+  ViewFilterParams._() : super();
+  factory ViewFilterParams({
+    $core.String? tag,
+    $core.String? propertyKey,
+    $core.String? propertyValue,
+  }) {
+    final _result = create();
+    if (tag != null) {
+      _result.tag = tag;
+    }
+    if (propertyKey != null) {
+      _result.propertyKey = propertyKey;
+    }
+    if (propertyValue != null) {
+      _result.propertyValue = propertyValue;
+    }
+    return _result;
+  }
+  factory ViewFilterParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory ViewFilterParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  ViewFilterParams clone() => ViewFilterParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  ViewFilterParams copyWith(void Function(ViewFilterParams) updates) => super.copyWith((message) => updates(message as ViewFilterParams)) as ViewFilterParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static ViewFilterParams create() => ViewFilterParams._();
+  ViewFilterParams createEmptyInstance() => create();
+  static $pb.PbList<ViewFilterParams> createRepeated() => $pb.PbList<ViewFilterParams>();
+  @$core.pragma('dart2js:noInline')
+  static ViewFilterParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<ViewFilterParams>(create);
+  static ViewFilterParams? _defaultInstance;
+
+  ViewFilterParams_OneOfTag whichOneOfTag() => _ViewFilterParams_OneOfTagByTag[$_whichOneof(0)]!;
+  void clearOneOfTag() => clearField($_whichOneof(0));
+
+  ViewFilterParams_OneOfPropertyKey whichOneOfPropertyKey() => _ViewFilterParams_OneOfPropertyKeyByTag[$_whichOneof(1)]!;
+  void clearOneOfPropertyKey() => clearField($_whichOneof(1));
+
+  ViewFilterParams_OneOfPropertyValue whichOneOfPropertyValue() => _ViewFilterParams_OneOfPropertyValueByTag[$_whichOneof(2)]!;
+  void clearOneOfPropertyValue() => clearField($_whichOneof(2));
+
+  @$pb.TagNumber(1)
+  $core.String get tag => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set tag($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasTag() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearTag() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get propertyKey => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set propertyKey($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasPropertyKey() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearPropertyKey() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get propertyValue => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set propertyValue($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasPropertyValue() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearPropertyValue() => clearField(3);
+}
+
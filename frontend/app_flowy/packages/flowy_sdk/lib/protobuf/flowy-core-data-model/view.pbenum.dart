@@ -12,10 +12,14 @@ import 'package:protobuf/protobuf.dart' as $pb;
 class ViewType extends $pb.ProtobufEnum {
   static const ViewType Blank = ViewType._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Blank');
   static const ViewType Doc = ViewType._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Doc');
+  static const ViewType Grid = ViewType._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Grid');
+  static const ViewType Board = ViewType._(3, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Board');
 
   static const $core.List<ViewType> values = <ViewType> [
     Blank,
     Doc,
+    Grid,
+    Board,
   ];
 
   static final $core.Map<$core.int, ViewType> _byValue = $pb.ProtobufEnum.initByValue(values);
@@ -24,3 +28,22 @@ class ViewType extends $pb.ProtobufEnum {
   const ViewType._($core.int v, $core.String n) : super(v, n);
 }
 
+class ViewPropertyType extends $pb.ProtobufEnum {
+  static const ViewPropertyType Text = ViewPropertyType._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Text');
+  static const ViewPropertyType Number = ViewPropertyType._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Number');
+  static const ViewPropertyType Date = ViewPropertyType._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Date');
+  static const ViewPropertyType Select = ViewPropertyType._(3, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Select');
+
+  static const $core.List<ViewPropertyType> values = <ViewPropertyType> [
+    Text,
+    Number,
+    Date,
+    Select,
+  ];
+
+  static final $core.Map<$core.int, ViewPropertyType> _byValue = $pb.ProtobufEnum.initByValue(values);
+  static ViewPropertyType? valueOf($core.int value) => _byValue[value];
+
+  const ViewPropertyType._($core.int v, $core.String n) : super(v, n);
+}
+
@@ -14,11 +14,26 @@ const ViewType$json = const {
   '2': const [
     const {'1': 'Blank', '2': 0},
     const {'1': 'Doc', '2': 1},
+    const {'1': 'Grid', '2': 2},
+    const {'1': 'Board', '2': 3},
   ],
 };
 
 /// Descriptor for `ViewType`. Decode as a `google.protobuf.EnumDescriptorProto`.
-final $typed_data.Uint8List viewTypeDescriptor = $convert.base64Decode('CghWaWV3VHlwZRIJCgVCbGFuaxAAEgcKA0RvYxAB');
+final $typed_data.Uint8List viewTypeDescriptor = $convert.base64Decode('CghWaWV3VHlwZRIJCgVCbGFuaxAAEgcKA0RvYxABEggKBEdyaWQQAhIJCgVCb2FyZBAD');
+@$core.Deprecated('Use viewPropertyTypeDescriptor instead')
+const ViewPropertyType$json = const {
+  '1': 'ViewPropertyType',
+  '2': const [
+    const {'1': 'Text', '2': 0},
+    const {'1': 'Number', '2': 1},
+    const {'1': 'Date', '2': 2},
+    const {'1': 'Select', '2': 3},
+  ],
+};
+
+/// Descriptor for `ViewPropertyType`. Decode as a `google.protobuf.EnumDescriptorProto`.
+final $typed_data.Uint8List viewPropertyTypeDescriptor = $convert.base64Decode('ChBWaWV3UHJvcGVydHlUeXBlEggKBFRleHQQABIKCgZOdW1iZXIQARIICgREYXRlEAISCgoGU2VsZWN0EAM=');
 @$core.Deprecated('Use viewDescriptor instead')
 const View$json = const {
   '1': 'View',
@@ -32,11 +47,13 @@ const View$json = const {
     const {'1': 'belongings', '3': 7, '4': 1, '5': 11, '6': '.RepeatedView', '10': 'belongings'},
     const {'1': 'modified_time', '3': 8, '4': 1, '5': 3, '10': 'modifiedTime'},
     const {'1': 'create_time', '3': 9, '4': 1, '5': 3, '10': 'createTime'},
+    const {'1': 'tags', '3': 10, '4': 3, '5': 9, '10': 'tags'},
+    const {'1': 'properties', '3': 11, '4': 3, '5': 11, '6': '.ViewProperty', '10': 'properties'},
   ],
 };
 
 /// Descriptor for `View`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List viewDescriptor = $convert.base64Decode('CgRWaWV3Eg4KAmlkGAEgASgJUgJpZBIgCgxiZWxvbmdfdG9faWQYAiABKAlSCmJlbG9uZ1RvSWQSEgoEbmFtZRgDIAEoCVIEbmFtZRISCgRkZXNjGAQgASgJUgRkZXNjEiYKCXZpZXdfdHlwZRgFIAEoDjIJLlZpZXdUeXBlUgh2aWV3VHlwZRIYCgd2ZXJzaW9uGAYgASgDUgd2ZXJzaW9uEi0KCmJlbG9uZ2luZ3MYByABKAsyDS5SZXBlYXRlZFZpZXdSCmJlbG9uZ2luZ3MSIwoNbW9kaWZpZWRfdGltZRgIIAEoA1IMbW9kaWZpZWRUaW1lEh8KC2NyZWF0ZV90aW1lGAkgASgDUgpjcmVhdGVUaW1l');
+final $typed_data.Uint8List viewDescriptor = $convert.base64Decode('CgRWaWV3Eg4KAmlkGAEgASgJUgJpZBIgCgxiZWxvbmdfdG9faWQYAiABKAlSCmJlbG9uZ1RvSWQSEgoEbmFtZRgDIAEoCVIEbmFtZRISCgRkZXNjGAQgASgJUgRkZXNjEiYKCXZpZXdfdHlwZRgFIAEoDjIJLlZpZXdUeXBlUgh2aWV3VHlwZRIYCgd2ZXJzaW9uGAYgASgDUgd2ZXJzaW9uEi0KCmJlbG9uZ2luZ3MYByABKAsyDS5SZXBlYXRlZFZpZXdSCmJlbG9uZ2luZ3MSIwoNbW9kaWZpZWRfdGltZRgIIAEoA1IMbW9kaWZpZWRUaW1lEh8KC2NyZWF0ZV90aW1lGAkgASgDUgpjcmVhdGVUaW1lEhIKBHRhZ3MYCiADKAlSBHRhZ3MSLQoKcHJvcGVydGllcxgLIAMoCzINLlZpZXdQcm9wZXJ0eVIKcHJvcGVydGllcw==');
 @$core.Deprecated('Use repeatedViewDescriptor instead')
 const RepeatedView$json = const {
   '1': 'RepeatedView',
@@ -146,3 +163,104 @@ const UpdateViewParams$json = const {
 
 /// Descriptor for `UpdateViewParams`. Decode as a `google.protobuf.DescriptorProto`.
 final $typed_data.Uint8List updateViewParamsDescriptor = $convert.base64Decode('ChBVcGRhdGVWaWV3UGFyYW1zEhcKB3ZpZXdfaWQYASABKAlSBnZpZXdJZBIUCgRuYW1lGAIgASgJSABSBG5hbWUSFAoEZGVzYxgDIAEoCUgBUgRkZXNjEh4KCXRodW1ibmFpbBgEIAEoCUgCUgl0aHVtYm5haWxCDQoLb25lX29mX25hbWVCDQoLb25lX29mX2Rlc2NCEgoQb25lX29mX3RodW1ibmFpbA==');
+@$core.Deprecated('Use viewPropertyDescriptor instead')
+const ViewProperty$json = const {
+  '1': 'ViewProperty',
+  '2': const [
+    const {'1': 'key', '3': 1, '4': 1, '5': 9, '10': 'key'},
+    const {'1': 'ty', '3': 2, '4': 1, '5': 14, '6': '.ViewPropertyType', '10': 'ty'},
+    const {'1': 'value', '3': 3, '4': 1, '5': 9, '10': 'value'},
+    const {'1': 'options', '3': 4, '4': 3, '5': 9, '10': 'options'},
+  ],
+};
+
+/// Descriptor for `ViewProperty`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List viewPropertyDescriptor = $convert.base64Decode('CgxWaWV3UHJvcGVydHkSEAoDa2V5GAEgASgJUgNrZXkSIQoCdHkYAiABKA4yES5WaWV3UHJvcGVydHlUeXBlUgJ0eRIUCgV2YWx1ZRgDIAEoCVIFdmFsdWUSGAoHb3B0aW9ucxgEIAMoCVIHb3B0aW9ucw==');
+@$core.Deprecated('Use updateViewTagsRequestDescriptor instead')
+const UpdateViewTagsRequest$json = const {
+  '1': 'UpdateViewTagsRequest',
+  '2': const [
+    const {'1': 'view_id', '3': 1, '4': 1, '5': 9, '10': 'viewId'},
+    const {'1': 'tags', '3': 2, '4': 3, '5': 9, '10': 'tags'},
+  ],
+};
+
+/// Descriptor for `UpdateViewTagsRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List updateViewTagsRequestDescriptor = $convert.base64Decode('ChVVcGRhdGVWaWV3VGFnc1JlcXVlc3QSFwoHdmlld19pZBgBIAEoCVIGdmlld0lkEhIKBHRhZ3MYAiADKAlSBHRhZ3M=');
+@$core.Deprecated('Use updateViewTagsParamsDescriptor instead')
+const UpdateViewTagsParams$json = const {
+  '1': 'UpdateViewTagsParams',
+  '2': const [
+    const {'1': 'view_id', '3': 1, '4': 1, '5': 9, '10': 'viewId'},
+    const {'1': 'tags', '3': 2, '4': 3, '5': 9, '10': 'tags'},
+  ],
+};
+
+/// Descriptor for `UpdateViewTagsParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List updateViewTagsParamsDescriptor = $convert.base64Decode('ChRVcGRhdGVWaWV3VGFnc1BhcmFtcxIXCgd2aWV3X2lkGAEgASgJUgZ2aWV3SWQSEgoEdGFncxgCIAMoCVIEdGFncw==');
+@$core.Deprecated('Use updateViewPropertyRequestDescriptor instead')
+const UpdateViewPropertyRequest$json = const {
+  '1': 'UpdateViewPropertyRequest',
+  '2': const [
+    const {'1': 'view_id', '3': 1, '4': 1, '5': 9, '10': 'viewId'},
+    const {'1': 'key', '3': 2, '4': 1, '5': 9, '10': 'key'},
+    const {'1': 'ty', '3': 3, '4': 1, '5': 14, '6': '.ViewPropertyType', '10': 'ty'},
+    const {'1': 'value', '3': 4, '4': 1, '5': 9, '9': 0, '10': 'value'},
+    const {'1': 'options', '3': 5, '4': 3, '5': 9, '10': 'options'},
+  ],
+  '8': const [
+    const {'1': 'one_of_value'},
+  ],
+};
+
+/// Descriptor for `UpdateViewPropertyRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List updateViewPropertyRequestDescriptor = $convert.base64Decode('ChlVcGRhdGVWaWV3UHJvcGVydHlSZXF1ZXN0EhcKB3ZpZXdfaWQYASABKAlSBnZpZXdJZBIQCgNrZXkYAiABKAlSA2tleRIhCgJ0eRgDIAEoDjIRLlZpZXdQcm9wZXJ0eVR5cGVSAnR5EhYKBXZhbHVlGAQgASgJSABSBXZhbHVlEhgKB29wdGlvbnMYBSADKAlSB29wdGlvbnNCDgoMb25lX29mX3ZhbHVl');
+@$core.Deprecated('Use updateViewPropertyParamsDescriptor instead')
+const UpdateViewPropertyParams$json = const {
+  '1': 'UpdateViewPropertyParams',
+  '2': const [
+    const {'1': 'view_id', '3': 1, '4': 1, '5': 9, '10': 'viewId'},
+    const {'1': 'key', '3': 2, '4': 1, '5': 9, '10': 'key'},
+    const {'1': 'property', '3': 3, '4': 1, '5': 11, '6': '.ViewProperty', '9': 0, '10': 'property'},
+  ],
+  '8': const [
+    const {'1': 'one_of_property'},
+  ],
+};
+
+/// Descriptor for `UpdateViewPropertyParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List updateViewPropertyParamsDescriptor = $convert.base64Decode('ChhVcGRhdGVWaWV3UHJvcGVydHlQYXJhbXMSFwoHdmlld19pZBgBIAEoCVIGdmlld0lkEhAKA2tleRgCIAEoCVIDa2V5EisKCHByb3BlcnR5GAMgASgLMg0uVmlld1Byb3BlcnR5SABSCHByb3BlcnR5QhEKD29uZV9vZl9wcm9wZXJ0eQ==');
+@$core.Deprecated('Use viewFilterRequestDescriptor instead')
+const ViewFilterRequest$json = const {
+  '1': 'ViewFilterRequest',
+  '2': const [
+    const {'1': 'tag', '3': 1, '4': 1, '5': 9, '9': 0, '10': 'tag'},
+    const {'1': 'property_key', '3': 2, '4': 1, '5': 9, '9': 1, '10': 'propertyKey'},
+    const {'1': 'property_value', '3': 3, '4': 1, '5': 9, '9': 2, '10': 'propertyValue'},
+  ],
+  '8': const [
+    const {'1': 'one_of_tag'},
+    const {'1': 'one_of_property_key'},
+    const {'1': 'one_of_property_value'},
+  ],
+};
+
+/// Descriptor for `ViewFilterRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List viewFilterRequestDescriptor = $convert.base64Decode('ChFWaWV3RmlsdGVyUmVxdWVzdBISCgN0YWcYASABKAlIAFIDdGFnEiMKDHByb3BlcnR5X2tleRgCIAEoCUgBUgtwcm9wZXJ0eUtleRInCg5wcm9wZXJ0eV92YWx1ZRgDIAEoCUgCUg1wcm9wZXJ0eVZhbHVlQgwKCm9uZV9vZl90YWdCFQoTb25lX29mX3Byb3BlcnR5X2tleUIXChVvbmVfb2ZfcHJvcGVydHlfdmFsdWU=');
+@$core.Deprecated('Use viewFilterParamsDescriptor instead')
+const ViewFilterParams$json = const {
+  '1': 'ViewFilterParams',
+  '2': const [
+    const {'1': 'tag', '3': 1, '4': 1, '5': 9, '9': 0, '10': 'tag'},
+    const {'1': 'property_key', '3': 2, '4': 1, '5': 9, '9': 1, '10': 'propertyKey'},
+    const {'1': 'property_value', '3': 3, '4': 1, '5': 9, '9': 2, '10': 'propertyValue'},
+  ],
+  '8': const [
+    const {'1': 'one_of_tag'},
+    const {'1': 'one_of_property_key'},
+    const {'1': 'one_of_property_value'},
+  ],
+};
+
+/// Descriptor for `ViewFilterParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List viewFilterParamsDescriptor = $convert.base64Decode('ChBWaWV3RmlsdGVyUGFyYW1zEhIKA3RhZxgBIAEoCUgAUgN0YWcSIwoMcHJvcGVydHlfa2V5GAIgASgJSAFSC3Byb3BlcnR5S2V5EicKDnByb3BlcnR5X3ZhbHVlGAMgASgJSAJSDXByb3BlcnR5VmFsdWVCDAoKb25lX29mX3RhZ0IVChNvbmVfb2ZfcHJvcGVydHlfa2V5QhcKFW9uZV9vZl9wcm9wZXJ0eV92YWx1ZQ==');
@@ -695,3 +695,125 @@ class UpdateWorkspaceParams extends $pb.GeneratedMessage {
   void clearDesc() => clearField(3);
 }
 
+class WorkspaceEncryptionRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'WorkspaceEncryptionRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'workspaceId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'passphrase')
+    ..hasRequiredFields = false
+  ;
+
+  WorkspaceEncryptionRequest._() : super();
+  factory WorkspaceEncryptionRequest({
+    $core.String? workspaceId,
+    $core.String? passphrase,
+  }) {
+    final _result = create();
+    if (workspaceId != null) {
+      _result.workspaceId = workspaceId;
+    }
+    if (passphrase != null) {
+      _result.passphrase = passphrase;
+    }
+    return _result;
+  }
+  factory WorkspaceEncryptionRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory WorkspaceEncryptionRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  WorkspaceEncryptionRequest clone() => WorkspaceEncryptionRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  WorkspaceEncryptionRequest copyWith(void Function(WorkspaceEncryptionRequest) updates) => super.copyWith((message) => updates(message as WorkspaceEncryptionRequest)) as WorkspaceEncryptionRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static WorkspaceEncryptionRequest create() => WorkspaceEncryptionRequest._();
+  WorkspaceEncryptionRequest createEmptyInstance() => create();
+  static $pb.PbList<WorkspaceEncryptionRequest> createRepeated() => $pb.PbList<WorkspaceEncryptionRequest>();
+  @$core.pragma('dart2js:noInline')
+  static WorkspaceEncryptionRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<WorkspaceEncryptionRequest>(create);
+  static WorkspaceEncryptionRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get workspaceId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set workspaceId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasWorkspaceId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearWorkspaceId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get passphrase => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set passphrase($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasPassphrase() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearPassphrase() => clearField(2);
+}
+
+class WorkspaceEncryptionParams extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'WorkspaceEncryptionParams', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'workspaceId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'passphrase')
+    ..hasRequiredFields = false
+  ;
+
+  WorkspaceEncryptionParams._() : super();
+  factory WorkspaceEncryptionParams({
+    $core.String? workspaceId,
+    $core.String? passphrase,
+  }) {
+    final _result = create();
+    if (workspaceId != null) {
+      _result.workspaceId = workspaceId;
+    }
+    if (passphrase != null) {
+      _result.passphrase = passphrase;
+    }
+    return _result;
+  }
+  factory WorkspaceEncryptionParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory WorkspaceEncryptionParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  WorkspaceEncryptionParams clone() => WorkspaceEncryptionParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  WorkspaceEncryptionParams copyWith(void Function(WorkspaceEncryptionParams) updates) => super.copyWith((message) => updates(message as WorkspaceEncryptionParams)) as WorkspaceEncryptionParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static WorkspaceEncryptionParams create() => WorkspaceEncryptionParams._();
+  WorkspaceEncryptionParams createEmptyInstance() => create();
+  static $pb.PbList<WorkspaceEncryptionParams> createRepeated() => $pb.PbList<WorkspaceEncryptionParams>();
+  @$core.pragma('dart2js:noInline')
+  static WorkspaceEncryptionParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<WorkspaceEncryptionParams>(create);
+  static WorkspaceEncryptionParams? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get workspaceId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set workspaceId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasWorkspaceId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearWorkspaceId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get passphrase => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set passphrase($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasPassphrase() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearPassphrase() => clearField(2);
+}
+
@@ -127,3 +127,25 @@ const UpdateWorkspaceParams$json = const {
 
 /// Descriptor for `UpdateWorkspaceParams`. Decode as a `google.protobuf.DescriptorProto`.
 final $typed_data.Uint8List updateWorkspaceParamsDescriptor = $convert.base64Decode('ChVVcGRhdGVXb3Jrc3BhY2VQYXJhbXMSDgoCaWQYASABKAlSAmlkEhQKBG5hbWUYAiABKAlIAFIEbmFtZRIUCgRkZXNjGAMgASgJSAFSBGRlc2NCDQoLb25lX29mX25hbWVCDQoLb25lX29mX2Rlc2M=');
+@$core.Deprecated('Use workspaceEncryptionRequestDescriptor instead')
+const WorkspaceEncryptionRequest$json = const {
+  '1': 'WorkspaceEncryptionRequest',
+  '2': const [
+    const {'1': 'workspace_id', '3': 1, '4': 1, '5': 9, '10': 'workspaceId'},
+    const {'1': 'passphrase', '3': 2, '4': 1, '5': 9, '10': 'passphrase'},
+  ],
+};
+
+/// Descriptor for `WorkspaceEncryptionRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List workspaceEncryptionRequestDescriptor = $convert.base64Decode('ChpXb3Jrc3BhY2VFbmNyeXB0aW9uUmVxdWVzdBIhCgx3b3Jrc3BhY2VfaWQYASABKAlSC3dvcmtzcGFjZUlkEh4KCnBhc3NwaHJhc2UYAiABKAlSCnBhc3NwaHJhc2U=');
+@$core.Deprecated('Use workspaceEncryptionParamsDescriptor instead')
+const WorkspaceEncryptionParams$json = const {
+  '1': 'WorkspaceEncryptionParams',
+  '2': const [
+    const {'1': 'workspace_id', '3': 1, '4': 1, '5': 9, '10': 'workspaceId'},
+    const {'1': 'passphrase', '3': 2, '4': 1, '5': 9, '10': 'passphrase'},
+  ],
+};
+
+/// Descriptor for `WorkspaceEncryptionParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List workspaceEncryptionParamsDescriptor = $convert.base64Decode('ChlXb3Jrc3BhY2VFbmNyeXB0aW9uUGFyYW1zEiEKDHdvcmtzcGFjZV9pZBgBIAEoCVILd29ya3NwYWNlSWQSHgoKcGFzc3BocmFzZRgCIAEoCVIKcGFzc3BocmFzZQ==');
@@ -23,6 +23,10 @@ class WorkspaceNotification extends $pb.ProtobufEnum {
   static const WorkspaceNotification ViewRestored = WorkspaceNotification._(33, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewRestored');
   static const WorkspaceNotification UserUnauthorized = WorkspaceNotification._(100, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UserUnauthorized');
   static const WorkspaceNotification TrashUpdated = WorkspaceNotification._(1000, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'TrashUpdated');
+  static const WorkspaceNotification TemplatesUpdated = WorkspaceNotification._(1100, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'TemplatesUpdated');
+  static const WorkspaceNotification GridUpdated = WorkspaceNotification._(1200, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'GridUpdated');
+  static const WorkspaceNotification BoardUpdated = WorkspaceNotification._(1300, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'BoardUpdated');
+  static const WorkspaceNotification CommentsUpdated = WorkspaceNotification._(1400, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CommentsUpdated');
 
   static const $core.List<WorkspaceNotification> values = <WorkspaceNotification> [
     Unknown,
@@ -38,6 +42,10 @@ class WorkspaceNotification extends $pb.ProtobufEnum {
     ViewRestored,
     UserUnauthorized,
     TrashUpdated,
+    TemplatesUpdated,
+    GridUpdated,
+    BoardUpdated,
+    CommentsUpdated,
   ];
 
   static final $core.Map<$core.int, WorkspaceNotification> _byValue = $pb.ProtobufEnum.initByValue(values);
@@ -16,10 +16,12 @@ class WorkspaceEvent extends $pb.ProtobufEnum {
   static const WorkspaceEvent DeleteWorkspace = WorkspaceEvent._(3, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DeleteWorkspace');
   static const WorkspaceEvent OpenWorkspace = WorkspaceEvent._(4, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'OpenWorkspace');
   static const WorkspaceEvent ReadWorkspaceApps = WorkspaceEvent._(5, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadWorkspaceApps');
+  static const WorkspaceEvent SetWorkspaceEncryption = WorkspaceEvent._(6, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'SetWorkspaceEncryption');
   static const WorkspaceEvent CreateApp = WorkspaceEvent._(101, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CreateApp');
   static const WorkspaceEvent DeleteApp = WorkspaceEvent._(102, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DeleteApp');
   static const WorkspaceEvent ReadApp = WorkspaceEvent._(103, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadApp');
   static const WorkspaceEvent UpdateApp = WorkspaceEvent._(104, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UpdateApp');
+  static const WorkspaceEvent DuplicateApp = WorkspaceEvent._(105, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DuplicateApp');
   static const WorkspaceEvent CreateView = WorkspaceEvent._(201, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CreateView');
   static const WorkspaceEvent ReadView = WorkspaceEvent._(202, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadView');
   static const WorkspaceEvent UpdateView = WorkspaceEvent._(203, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UpdateView');
@@ -28,13 +30,50 @@ class WorkspaceEvent extends $pb.ProtobufEnum {
   static const WorkspaceEvent CopyLink = WorkspaceEvent._(206, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CopyLink');
   static const WorkspaceEvent OpenDocument = WorkspaceEvent._(207, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'OpenDocument');
   static const WorkspaceEvent CloseView = WorkspaceEvent._(208, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CloseView');
+  static const WorkspaceEvent UpdateViewTags = WorkspaceEvent._(209, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UpdateViewTags');
+  static const WorkspaceEvent UpdateViewProperty = WorkspaceEvent._(210, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UpdateViewProperty');
+  static const WorkspaceEvent QueryViews = WorkspaceEvent._(211, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'QueryViews');
   static const WorkspaceEvent ReadTrash = WorkspaceEvent._(300, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadTrash');
   static const WorkspaceEvent PutbackTrash = WorkspaceEvent._(301, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'PutbackTrash');
   static const WorkspaceEvent DeleteTrash = WorkspaceEvent._(302, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DeleteTrash');
   static const WorkspaceEvent RestoreAllTrash = WorkspaceEvent._(303, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'RestoreAllTrash');
   static const WorkspaceEvent DeleteAllTrash = WorkspaceEvent._(304, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DeleteAllTrash');
+  static const WorkspaceEvent ReadTrashRetentionPolicy = WorkspaceEvent._(305, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadTrashRetentionPolicy');
+  static const WorkspaceEvent UpdateTrashRetentionPolicy = WorkspaceEvent._(306, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UpdateTrashRetentionPolicy');
   static const WorkspaceEvent ApplyDocDelta = WorkspaceEvent._(400, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ApplyDocDelta');
+  static const WorkspaceEvent HighlightCode = WorkspaceEvent._(401, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'HighlightCode');
+  static const WorkspaceEvent ReadDocumentOutline = WorkspaceEvent._(402, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadDocumentOutline');
+  static const WorkspaceEvent JumpToSection = WorkspaceEvent._(403, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'JumpToSection');
+  static const WorkspaceEvent ReadDocumentStatistics = WorkspaceEvent._(404, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadDocumentStatistics');
+  static const WorkspaceEvent CheckDocumentIntegrity = WorkspaceEvent._(405, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CheckDocumentIntegrity');
+  static const WorkspaceEvent ReadDocumentMergeBackups = WorkspaceEvent._(406, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadDocumentMergeBackups');
+  static const WorkspaceEvent RestoreDocumentMergeBackup = WorkspaceEvent._(407, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'RestoreDocumentMergeBackup');
+  static const WorkspaceEvent DeleteDocumentMergeBackup = WorkspaceEvent._(408, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DeleteDocumentMergeBackup');
+  static const WorkspaceEvent ReadOpenDocumentsUsage = WorkspaceEvent._(409, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadOpenDocumentsUsage');
   static const WorkspaceEvent ExportDocument = WorkspaceEvent._(500, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ExportDocument');
+  static const WorkspaceEvent CreateTemplate = WorkspaceEvent._(600, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CreateTemplate');
+  static const WorkspaceEvent ReadTemplates = WorkspaceEvent._(601, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadTemplates');
+  static const WorkspaceEvent DeleteTemplate = WorkspaceEvent._(602, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DeleteTemplate');
+  static const WorkspaceEvent CreateViewFromTemplate = WorkspaceEvent._(603, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CreateViewFromTemplate');
+  static const WorkspaceEvent OpenGrid = WorkspaceEvent._(700, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'OpenGrid');
+  static const WorkspaceEvent CreateField = WorkspaceEvent._(701, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CreateField');
+  static const WorkspaceEvent UpdateField = WorkspaceEvent._(702, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UpdateField');
+  static const WorkspaceEvent DeleteField = WorkspaceEvent._(703, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DeleteField');
+  static const WorkspaceEvent CreateRow = WorkspaceEvent._(704, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CreateRow');
+  static const WorkspaceEvent UpdateRow = WorkspaceEvent._(705, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UpdateRow');
+  static const WorkspaceEvent DeleteRow = WorkspaceEvent._(706, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DeleteRow');
+  static const WorkspaceEvent UpdateCell = WorkspaceEvent._(707, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UpdateCell');
+  static const WorkspaceEvent DeleteCell = WorkspaceEvent._(708, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DeleteCell');
+  static const WorkspaceEvent OpenBoard = WorkspaceEvent._(800, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'OpenBoard');
+  static const WorkspaceEvent UpdateBoardProperty = WorkspaceEvent._(801, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UpdateBoardProperty');
+  static const WorkspaceEvent CreateCard = WorkspaceEvent._(802, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CreateCard');
+  static const WorkspaceEvent UpdateCard = WorkspaceEvent._(803, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UpdateCard');
+  static const WorkspaceEvent MoveCard = WorkspaceEvent._(804, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'MoveCard');
+  static const WorkspaceEvent DeleteCard = WorkspaceEvent._(805, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DeleteCard');
+  static const WorkspaceEvent CreateComment = WorkspaceEvent._(900, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CreateComment');
+  static const WorkspaceEvent ReplyComment = WorkspaceEvent._(901, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReplyComment');
+  static const WorkspaceEvent ResolveComment = WorkspaceEvent._(902, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ResolveComment');
+  static const WorkspaceEvent ReadComments = WorkspaceEvent._(903, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadComments');
 
   static const $core.List<WorkspaceEvent> values = <WorkspaceEvent> [
     CreateWorkspace,
@@ -43,10 +82,12 @@ class WorkspaceEvent extends $pb.ProtobufEnum {
     DeleteWorkspace,
     OpenWorkspace,
     ReadWorkspaceApps,
+    SetWorkspaceEncryption,
     CreateApp,
     DeleteApp,
     ReadApp,
     UpdateApp,
+    DuplicateApp,
     CreateView,
     ReadView,
     UpdateView,
@@ -55,13 +96,50 @@ class WorkspaceEvent extends $pb.ProtobufEnum {
     CopyLink,
     OpenDocument,
     CloseView,
+    UpdateViewTags,
+    UpdateViewProperty,
+    QueryViews,
     ReadTrash,
     PutbackTrash,
     DeleteTrash,
     RestoreAllTrash,
     DeleteAllTrash,
+    ReadTrashRetentionPolicy,
+    UpdateTrashRetentionPolicy,
     ApplyDocDelta,
+    HighlightCode,
+    ReadDocumentOutline,
+    JumpToSection,
+    ReadDocumentStatistics,
+    CheckDocumentIntegrity,
+    ReadDocumentMergeBackups,
+    RestoreDocumentMergeBackup,
+    DeleteDocumentMergeBackup,
+    ReadOpenDocumentsUsage,
     ExportDocument,
+    CreateTemplate,
+    ReadTemplates,
+    DeleteTemplate,
+    CreateViewFromTemplate,
+    OpenGrid,
+    CreateField,
+    UpdateField,
+    DeleteField,
+    CreateRow,
+    UpdateRow,
+    DeleteRow,
+    UpdateCell,
+    DeleteCell,
+    OpenBoard,
+    UpdateBoardProperty,
+    CreateCard,
+    UpdateCard,
+    MoveCard,
+    DeleteCard,
+    CreateComment,
+    ReplyComment,
+    ResolveComment,
+    ReadComments,
   ];
 
   static final $core.Map<$core.int, WorkspaceEvent> _byValue = $pb.ProtobufEnum.initByValue(values);
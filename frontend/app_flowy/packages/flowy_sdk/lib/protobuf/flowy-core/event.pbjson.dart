@@ -18,10 +18,12 @@ const WorkspaceEvent$json = const {
     const {'1': 'DeleteWorkspace', '2': 3},
     const {'1': 'OpenWorkspace', '2': 4},
     const {'1': 'ReadWorkspaceApps', '2': 5},
+    const {'1': 'SetWorkspaceEncryption', '2': 6},
     const {'1': 'CreateApp', '2': 101},
     const {'1': 'DeleteApp', '2': 102},
     const {'1': 'ReadApp', '2': 103},
     const {'1': 'UpdateApp', '2': 104},
+    const {'1': 'DuplicateApp', '2': 105},
     const {'1': 'CreateView', '2': 201},
     const {'1': 'ReadView', '2': 202},
     const {'1': 'UpdateView', '2': 203},
@@ -30,15 +32,52 @@ const WorkspaceEvent$json = const {
     const {'1': 'CopyLink', '2': 206},
     const {'1': 'OpenDocument', '2': 207},
     const {'1': 'CloseView', '2': 208},
+    const {'1': 'UpdateViewTags', '2': 209},
+    const {'1': 'UpdateViewProperty', '2': 210},
+    const {'1': 'QueryViews', '2': 211},
     const {'1': 'ReadTrash', '2': 300},
     const {'1': 'PutbackTrash', '2': 301},
     const {'1': 'DeleteTrash', '2': 302},
     const {'1': 'RestoreAllTrash', '2': 303},
     const {'1': 'DeleteAllTrash', '2': 304},
+    const {'1': 'ReadTrashRetentionPolicy', '2': 305},
+    const {'1': 'UpdateTrashRetentionPolicy', '2': 306},
     const {'1': 'ApplyDocDelta', '2': 400},
+    const {'1': 'HighlightCode', '2': 401},
+    const {'1': 'ReadDocumentOutline', '2': 402},
+    const {'1': 'JumpToSection', '2': 403},
+    const {'1': 'ReadDocumentStatistics', '2': 404},
+    const {'1': 'CheckDocumentIntegrity', '2': 405},
+    const {'1': 'ReadDocumentMergeBackups', '2': 406},
+    const {'1': 'RestoreDocumentMergeBackup', '2': 407},
+    const {'1': 'DeleteDocumentMergeBackup', '2': 408},
+    const {'1': 'ReadOpenDocumentsUsage', '2': 409},
     const {'1': 'ExportDocument', '2': 500},
+    const {'1': 'CreateTemplate', '2': 600},
+    const {'1': 'ReadTemplates', '2': 601},
+    const {'1': 'DeleteTemplate', '2': 602},
+    const {'1': 'CreateViewFromTemplate', '2': 603},
+    const {'1': 'OpenGrid', '2': 700},
+    const {'1': 'CreateField', '2': 701},
+    const {'1': 'UpdateField', '2': 702},
+    const {'1': 'DeleteField', '2': 703},
+    const {'1': 'CreateRow', '2': 704},
+    const {'1': 'UpdateRow', '2': 705},
+    const {'1': 'DeleteRow', '2': 706},
+    const {'1': 'UpdateCell', '2': 707},
+    const {'1': 'DeleteCell', '2': 708},
+    const {'1': 'OpenBoard', '2': 800},
+    const {'1': 'UpdateBoardProperty', '2': 801},
+    const {'1': 'CreateCard', '2': 802},
+    const {'1': 'UpdateCard', '2': 803},
+    const {'1': 'MoveCard', '2': 804},
+    const {'1': 'DeleteCard', '2': 805},
+    const {'1': 'CreateComment', '2': 900},
+    const {'1': 'ReplyComment', '2': 901},
+    const {'1': 'ResolveComment', '2': 902},
+    const {'1': 'ReadComments', '2': 903},
   ],
 };
 
 /// Descriptor for `WorkspaceEvent`. Decode as a `google.protobuf.EnumDescriptorProto`.
-final $typed_data.Uint8List workspaceEventDescriptor = $convert.base64Decode('Cg5Xb3Jrc3BhY2VFdmVudBITCg9DcmVhdGVXb3Jrc3BhY2UQABIUChBSZWFkQ3VyV29ya3NwYWNlEAESEgoOUmVhZFdvcmtzcGFjZXMQAhITCg9EZWxldGVXb3Jrc3BhY2UQAxIRCg1PcGVuV29ya3NwYWNlEAQSFQoRUmVhZFdvcmtzcGFjZUFwcHMQBRINCglDcmVhdGVBcHAQZRINCglEZWxldGVBcHAQZhILCgdSZWFkQXBwEGcSDQoJVXBkYXRlQXBwEGgSDwoKQ3JlYXRlVmlldxDJARINCghSZWFkVmlldxDKARIPCgpVcGRhdGVWaWV3EMsBEg8KCkRlbGV0ZVZpZXcQzAESEgoNRHVwbGljYXRlVmlldxDNARINCghDb3B5TGluaxDOARIRCgxPcGVuRG9jdW1lbnQQzwESDgoJQ2xvc2VWaWV3ENABEg4KCVJlYWRUcmFzaBCsAhIRCgxQdXRiYWNrVHJhc2gQrQISEAoLRGVsZXRlVHJhc2gQrgISFAoPUmVzdG9yZUFsbFRyYXNoEK8CEhMKDkRlbGV0ZUFsbFRyYXNoELACEhIKDUFwcGx5RG9jRGVsdGEQkAMSEwoORXhwb3J0RG9jdW1lbnQQ9AM=');
+final $typed_data.Uint8List workspaceEventDescriptor = $convert.base64Decode('Cg5Xb3Jrc3BhY2VFdmVudBITCg9DcmVhdGVXb3Jrc3BhY2UQABIUChBSZWFkQ3VyV29ya3NwYWNlEAESEgoOUmVhZFdvcmtzcGFjZXMQAhITCg9EZWxldGVXb3Jrc3BhY2UQAxIRCg1PcGVuV29ya3NwYWNlEAQSFQoRUmVhZFdvcmtzcGFjZUFwcHMQBRIaChZTZXRXb3Jrc3BhY2VFbmNyeXB0aW9uEAYSDQoJQ3JlYXRlQXBwEGUSDQoJRGVsZXRlQXBwEGYSCwoHUmVhZEFwcBBnEg0KCVVwZGF0ZUFwcBBoEhAKDER1cGxpY2F0ZUFwcBBpEg8KCkNyZWF0ZVZpZXcQyQESDQoIUmVhZFZpZXcQygESDwoKVXBkYXRlVmlldxDLARIPCgpEZWxldGVWaWV3EMwBEhIKDUR1cGxpY2F0ZVZpZXcQzQESDQoIQ29weUxpbmsQzgESEQoMT3BlbkRvY3VtZW50EM8BEg4KCUNsb3NlVmlldxDQARITCg5VcGRhdGVWaWV3VGFncxDRARIXChJVcGRhdGVWaWV3UHJvcGVydHkQ0gESDwoKUXVlcnlWaWV3cxDTARIOCglSZWFkVHJhc2gQrAISEQoMUHV0YmFja1RyYXNoEK0CEhAKC0RlbGV0ZVRyYXNoEK4CEhQKD1Jlc3RvcmVBbGxUcmFzaBCvAhITCg5EZWxldGVBbGxUcmFzaBCwAhIdChhSZWFkVHJhc2hSZXRlbnRpb25Qb2xpY3kQsQISHwoaVXBkYXRlVHJhc2hSZXRlbnRpb25Qb2xpY3kQsgISEgoNQXBwbHlEb2NEZWx0YRCQAxISCg1IaWdobGlnaHRDb2RlEJEDEhgKE1JlYWREb2N1bWVudE91dGxpbmUQkgMSEgoNSnVtcFRvU2VjdGlvbhCTAxIbChZSZWFkRG9jdW1lbnRTdGF0aXN0aWNzEJQDEhsKFkNoZWNrRG9jdW1lbnRJbnRlZ3JpdHkQlQMSHQoYUmVhZERvY3VtZW50TWVyZ2VCYWNrdXBzEJYDEh8KGlJlc3RvcmVEb2N1bWVudE1lcmdlQmFja3VwEJcDEh4KGURlbGV0ZURvY3VtZW50TWVyZ2VCYWNrdXAQmAMSGwoWUmVhZE9wZW5Eb2N1bWVudHNVc2FnZRCZAxITCg5FeHBvcnREb2N1bWVudBD0AxITCg5DcmVhdGVUZW1wbGF0ZRDYBBISCg1SZWFkVGVtcGxhdGVzENkEEhMKDkRlbGV0ZVRlbXBsYXRlENoEEhsKFkNyZWF0ZVZpZXdGcm9tVGVtcGxhdGUQ2wQSDQoIT3BlbkdyaWQQvAUSEAoLQ3JlYXRlRmllbGQQvQUSEAoLVXBkYXRlRmllbGQQvgUSEAoLRGVsZXRlRmllbGQQvwUSDgoJQ3JlYXRlUm93EMAFEg4KCVVwZGF0ZVJvdxDBBRIOCglEZWxldGVSb3cQwgUSDwoKVXBkYXRlQ2VsbBDDBRIPCgpEZWxldGVDZWxsEMQFEg4KCU9wZW5Cb2FyZBCgBhIYChNVcGRhdGVCb2FyZFByb3BlcnR5EKEGEg8KCkNyZWF0ZUNhcmQQogYSDwoKVXBkYXRlQ2FyZBCjBhINCghNb3ZlQ2FyZBCkBhIPCgpEZWxldGVDYXJkEKUGEhIKDUNyZWF0ZUNvbW1lbnQQhAcSEQoMUmVwbHlDb21tZW50EIUHEhMKDlJlc29sdmVDb21tZW50EIYHEhEKDFJlYWRDb21tZW50cxCHBw==');
@@ -0,0 +1,30 @@
+///
+//  Generated code. Do not modify.
+//  source: dart_notification.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+// ignore_for_file: UNDEFINED_SHOWN_NAME
+import 'dart:core' as $core;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+class NetworkNotification extends $pb.ProtobufEnum {
+  static const NetworkNotification Unknown = NetworkNotification._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Unknown');
+  static const NetworkNotification ObjectSyncStatusChanged = NetworkNotification._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ObjectSyncStatusChanged');
+  static const NetworkNotification SyncStatusChanged = NetworkNotification._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'SyncStatusChanged');
+  static const NetworkNotification ObjectMerged = NetworkNotification._(3, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ObjectMerged');
+
+  static const $core.List<NetworkNotification> values = <NetworkNotification> [
+    Unknown,
+    ObjectSyncStatusChanged,
+    SyncStatusChanged,
+    ObjectMerged,
+  ];
+
+  static final $core.Map<$core.int, NetworkNotification> _byValue = $pb.ProtobufEnum.initByValue(values);
+  static NetworkNotification? valueOf($core.int value) => _byValue[value];
+
+  const NetworkNotification._($core.int v, $core.String n) : super(v, n);
+}
+
@@ -0,0 +1,23 @@
+///
+//  Generated code. Do not modify.
+//  source: dart_notification.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use networkNotificationDescriptor instead')
+const NetworkNotification$json = const {
+  '1': 'NetworkNotification',
+  '2': const [
+    const {'1': 'Unknown', '2': 0},
+    const {'1': 'ObjectSyncStatusChanged', '2': 1},
+    const {'1': 'SyncStatusChanged', '2': 2},
+    const {'1': 'ObjectMerged', '2': 3},
+  ],
+};
+
+/// Descriptor for `NetworkNotification`. Decode as a `google.protobuf.EnumDescriptorProto`.
+final $typed_data.Uint8List networkNotificationDescriptor = $convert.base64Decode('ChNOZXR3b3JrTm90aWZpY2F0aW9uEgsKB1Vua25vd24QABIbChdPYmplY3RTeW5jU3RhdHVzQ2hhbmdlZBABEhUKEVN5bmNTdGF0dXNDaGFuZ2VkEAISEAoMT2JqZWN0TWVyZ2VkEAM=');
@@ -11,9 +11,21 @@ import 'package:protobuf/protobuf.dart' as $pb;
 
 class NetworkEvent extends $pb.ProtobufEnum {
   static const NetworkEvent UpdateNetworkType = NetworkEvent._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UpdateNetworkType');
+  static const NetworkEvent ReadObjectSyncStatus = NetworkEvent._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadObjectSyncStatus');
+  static const NetworkEvent ReadSyncStatus = NetworkEvent._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadSyncStatus');
+  static const NetworkEvent ReadLanSyncSetting = NetworkEvent._(3, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadLanSyncSetting');
+  static const NetworkEvent EnableLanSyncHost = NetworkEvent._(4, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'EnableLanSyncHost');
+  static const NetworkEvent PairLanSyncPeer = NetworkEvent._(5, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'PairLanSyncPeer');
+  static const NetworkEvent DisableLanSync = NetworkEvent._(6, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DisableLanSync');
 
   static const $core.List<NetworkEvent> values = <NetworkEvent> [
     UpdateNetworkType,
+    ReadObjectSyncStatus,
+    ReadSyncStatus,
+    ReadLanSyncSetting,
+    EnableLanSyncHost,
+    PairLanSyncPeer,
+    DisableLanSync,
   ];
 
   static final $core.Map<$core.int, NetworkEvent> _byValue = $pb.ProtobufEnum.initByValue(values);
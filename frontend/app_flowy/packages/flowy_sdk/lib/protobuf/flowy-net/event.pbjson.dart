@@ -13,8 +13,14 @@ const NetworkEvent$json = const {
   '1': 'NetworkEvent',
   '2': const [
     const {'1': 'UpdateNetworkType', '2': 0},
+    const {'1': 'ReadObjectSyncStatus', '2': 1},
+    const {'1': 'ReadSyncStatus', '2': 2},
+    const {'1': 'ReadLanSyncSetting', '2': 3},
+    const {'1': 'EnableLanSyncHost', '2': 4},
+    const {'1': 'PairLanSyncPeer', '2': 5},
+    const {'1': 'DisableLanSync', '2': 6},
   ],
 };
 
 /// Descriptor for `NetworkEvent`. Decode as a `google.protobuf.EnumDescriptorProto`.
-final $typed_data.Uint8List networkEventDescriptor = $convert.base64Decode('CgxOZXR3b3JrRXZlbnQSFQoRVXBkYXRlTmV0d29ya1R5cGUQAA==');
+final $typed_data.Uint8List networkEventDescriptor = $convert.base64Decode('CgxOZXR3b3JrRXZlbnQSFQoRVXBkYXRlTmV0d29ya1R5cGUQABIYChRSZWFkT2JqZWN0U3luY1N0YXR1cxABEhIKDlJlYWRTeW5jU3RhdHVzEAISFgoSUmVhZExhblN5bmNTZXR0aW5nEAMSFQoRRW5hYmxlTGFuU3luY0hvc3QQBBITCg9QYWlyTGFuU3luY1BlZXIQBRISCg5EaXNhYmxlTGFuU3luYxAG');
@@ -0,0 +1,212 @@
+///
+//  Generated code. Do not modify.
+//  source: lan_sync.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+import 'dart:core' as $core;
+
+import 'package:protobuf/protobuf.dart' as $pb;
+
+import 'lan_sync.pbenum.dart';
+
+export 'lan_sync.pbenum.dart';
+
+class LanSyncSetting extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'LanSyncSetting', createEmptyInstance: create)
+    ..e<LanSyncRole>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'role', $pb.PbFieldType.OE, defaultOrMaker: LanSyncRole.LanSyncDisabled, valueOf: LanSyncRole.valueOf, enumValues: LanSyncRole.values)
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'hostAddr')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'secret')
+    ..aOB(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'isActive')
+    ..hasRequiredFields = false
+  ;
+
+  LanSyncSetting._() : super();
+  factory LanSyncSetting({
+    LanSyncRole? role,
+    $core.String? hostAddr,
+    $core.String? secret,
+    $core.bool? isActive,
+  }) {
+    final _result = create();
+    if (role != null) {
+      _result.role = role;
+    }
+    if (hostAddr != null) {
+      _result.hostAddr = hostAddr;
+    }
+    if (secret != null) {
+      _result.secret = secret;
+    }
+    if (isActive != null) {
+      _result.isActive = isActive;
+    }
+    return _result;
+  }
+  factory LanSyncSetting.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory LanSyncSetting.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  LanSyncSetting clone() => LanSyncSetting()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  LanSyncSetting copyWith(void Function(LanSyncSetting) updates) => super.copyWith((message) => updates(message as LanSyncSetting)) as LanSyncSetting; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static LanSyncSetting create() => LanSyncSetting._();
+  LanSyncSetting createEmptyInstance() => create();
+  static $pb.PbList<LanSyncSetting> createRepeated() => $pb.PbList<LanSyncSetting>();
+  @$core.pragma('dart2js:noInline')
+  static LanSyncSetting getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<LanSyncSetting>(create);
+  static LanSyncSetting? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  LanSyncRole get role => $_getN(0);
+  @$pb.TagNumber(1)
+  set role(LanSyncRole v) { setField(1, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasRole() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearRole() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get hostAddr => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set hostAddr($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasHostAddr() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearHostAddr() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get secret => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set secret($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasSecret() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearSecret() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $core.bool get isActive => $_getBF(3);
+  @$pb.TagNumber(4)
+  set isActive($core.bool v) { $_setBool(3, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasIsActive() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearIsActive() => clearField(4);
+}
+
+class LanSyncHostRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'LanSyncHostRequest', createEmptyInstance: create)
+    ..a<$core.int>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'port', $pb.PbFieldType.O3)
+    ..hasRequiredFields = false
+  ;
+
+  LanSyncHostRequest._() : super();
+  factory LanSyncHostRequest({
+    $core.int? port,
+  }) {
+    final _result = create();
+    if (port != null) {
+      _result.port = port;
+    }
+    return _result;
+  }
+  factory LanSyncHostRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory LanSyncHostRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  LanSyncHostRequest clone() => LanSyncHostRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  LanSyncHostRequest copyWith(void Function(LanSyncHostRequest) updates) => super.copyWith((message) => updates(message as LanSyncHostRequest)) as LanSyncHostRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static LanSyncHostRequest create() => LanSyncHostRequest._();
+  LanSyncHostRequest createEmptyInstance() => create();
+  static $pb.PbList<LanSyncHostRequest> createRepeated() => $pb.PbList<LanSyncHostRequest>();
+  @$core.pragma('dart2js:noInline')
+  static LanSyncHostRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<LanSyncHostRequest>(create);
+  static LanSyncHostRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.int get port => $_getIZ(0);
+  @$pb.TagNumber(1)
+  set port($core.int v) { $_setSignedInt32(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasPort() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearPort() => clearField(1);
+}
+
+class LanSyncPeerRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'LanSyncPeerRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'hostAddr')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'secret')
+    ..hasRequiredFields = false
+  ;
+
+  LanSyncPeerRequest._() : super();
+  factory LanSyncPeerRequest({
+    $core.String? hostAddr,
+    $core.String? secret,
+  }) {
+    final _result = create();
+    if (hostAddr != null) {
+      _result.hostAddr = hostAddr;
+    }
+    if (secret != null) {
+      _result.secret = secret;
+    }
+    return _result;
+  }
+  factory LanSyncPeerRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory LanSyncPeerRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  LanSyncPeerRequest clone() => LanSyncPeerRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  LanSyncPeerRequest copyWith(void Function(LanSyncPeerRequest) updates) => super.copyWith((message) => updates(message as LanSyncPeerRequest)) as LanSyncPeerRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static LanSyncPeerRequest create() => LanSyncPeerRequest._();
+  LanSyncPeerRequest createEmptyInstance() => create();
+  static $pb.PbList<LanSyncPeerRequest> createRepeated() => $pb.PbList<LanSyncPeerRequest>();
+  @$core.pragma('dart2js:noInline')
+  static LanSyncPeerRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<LanSyncPeerRequest>(create);
+  static LanSyncPeerRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get hostAddr => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set hostAddr($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasHostAddr() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearHostAddr() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get secret => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set secret($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasSecret() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearSecret() => clearField(2);
+}
+
@@ -0,0 +1,28 @@
+///
+//  Generated code. Do not modify.
+//  source: lan_sync.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+// ignore_for_file: UNDEFINED_SHOWN_NAME
+import 'dart:core' as $core;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+class LanSyncRole extends $pb.ProtobufEnum {
+  static const LanSyncRole LanSyncDisabled = LanSyncRole._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'LanSyncDisabled');
+  static const LanSyncRole LanSyncHost = LanSyncRole._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'LanSyncHost');
+  static const LanSyncRole LanSyncPeer = LanSyncRole._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'LanSyncPeer');
+
+  static const $core.List<LanSyncRole> values = <LanSyncRole> [
+    LanSyncDisabled,
+    LanSyncHost,
+    LanSyncPeer,
+  ];
+
+  static final $core.Map<$core.int, LanSyncRole> _byValue = $pb.ProtobufEnum.initByValue(values);
+  static LanSyncRole? valueOf($core.int value) => _byValue[value];
+
+  const LanSyncRole._($core.int v, $core.String n) : super(v, n);
+}
+
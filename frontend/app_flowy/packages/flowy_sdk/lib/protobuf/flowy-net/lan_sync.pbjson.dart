@@ -0,0 +1,56 @@
+///
+//  Generated code. Do not modify.
+//  source: lan_sync.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use lanSyncRoleDescriptor instead')
+const LanSyncRole$json = const {
+  '1': 'LanSyncRole',
+  '2': const [
+    const {'1': 'LanSyncDisabled', '2': 0},
+    const {'1': 'LanSyncHost', '2': 1},
+    const {'1': 'LanSyncPeer', '2': 2},
+  ],
+};
+
+/// Descriptor for `LanSyncRole`. Decode as a `google.protobuf.EnumDescriptorProto`.
+final $typed_data.Uint8List lanSyncRoleDescriptor = $convert.base64Decode('CgtMYW5TeW5jUm9sZRITCg9MYW5TeW5jRGlzYWJsZWQQABIPCgtMYW5TeW5jSG9zdBABEg8KC0xhblN5bmNQZWVyEAI=');
+@$core.Deprecated('Use lanSyncSettingDescriptor instead')
+const LanSyncSetting$json = const {
+  '1': 'LanSyncSetting',
+  '2': const [
+    const {'1': 'role', '3': 1, '4': 1, '5': 14, '6': '.LanSyncRole', '10': 'role'},
+    const {'1': 'host_addr', '3': 2, '4': 1, '5': 9, '10': 'hostAddr'},
+    const {'1': 'secret', '3': 3, '4': 1, '5': 9, '10': 'secret'},
+    const {'1': 'is_active', '3': 4, '4': 1, '5': 8, '10': 'isActive'},
+  ],
+};
+
+/// Descriptor for `LanSyncSetting`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List lanSyncSettingDescriptor = $convert.base64Decode('Cg5MYW5TeW5jU2V0dGluZxIgCgRyb2xlGAEgASgOMgwuTGFuU3luY1JvbGVSBHJvbGUSGwoJaG9zdF9hZGRyGAIgASgJUghob3N0QWRkchIWCgZzZWNyZXQYAyABKAlSBnNlY3JldBIbCglpc19hY3RpdmUYBCABKAhSCGlzQWN0aXZl');
+@$core.Deprecated('Use lanSyncHostRequestDescriptor instead')
+const LanSyncHostRequest$json = const {
+  '1': 'LanSyncHostRequest',
+  '2': const [
+    const {'1': 'port', '3': 1, '4': 1, '5': 5, '10': 'port'},
+  ],
+};
+
+/// Descriptor for `LanSyncHostRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List lanSyncHostRequestDescriptor = $convert.base64Decode('ChJMYW5TeW5jSG9zdFJlcXVlc3QSEgoEcG9ydBgBIAEoBVIEcG9ydA==');
+@$core.Deprecated('Use lanSyncPeerRequestDescriptor instead')
+const LanSyncPeerRequest$json = const {
+  '1': 'LanSyncPeerRequest',
+  '2': const [
+    const {'1': 'host_addr', '3': 1, '4': 1, '5': 9, '10': 'hostAddr'},
+    const {'1': 'secret', '3': 2, '4': 1, '5': 9, '10': 'secret'},
+  ],
+};
+
+/// Descriptor for `LanSyncPeerRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List lanSyncPeerRequestDescriptor = $convert.base64Decode('ChJMYW5TeW5jUGVlclJlcXVlc3QSGwoJaG9zdF9hZGRyGAEgASgJUghob3N0QWRkchIWCgZzZWNyZXQYAiABKAlSBnNlY3JldA==');
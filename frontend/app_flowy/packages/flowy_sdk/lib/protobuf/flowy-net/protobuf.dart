@@ -1,3 +1,6 @@
 // Auto-generated, do not edit 
 export './network_state.pb.dart';
 export './event.pb.dart';
+export './sync_status.pb.dart';
+export './dart_notification.pb.dart';
+export './lan_sync.pb.dart';
@@ -0,0 +1,399 @@
+///
+//  Generated code. Do not modify.
+//  source: sync_status.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+import 'dart:core' as $core;
+
+import 'package:fixnum/fixnum.dart' as $fixnum;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+import 'sync_status.pbenum.dart';
+
+export 'sync_status.pbenum.dart';
+
+class SyncObjectId extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'SyncObjectId', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'objectId')
+    ..hasRequiredFields = false
+  ;
+
+  SyncObjectId._() : super();
+  factory SyncObjectId({
+    $core.String? objectId,
+  }) {
+    final _result = create();
+    if (objectId != null) {
+      _result.objectId = objectId;
+    }
+    return _result;
+  }
+  factory SyncObjectId.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory SyncObjectId.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  SyncObjectId clone() => SyncObjectId()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  SyncObjectId copyWith(void Function(SyncObjectId) updates) => super.copyWith((message) => updates(message as SyncObjectId)) as SyncObjectId; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static SyncObjectId create() => SyncObjectId._();
+  SyncObjectId createEmptyInstance() => create();
+  static $pb.PbList<SyncObjectId> createRepeated() => $pb.PbList<SyncObjectId>();
+  @$core.pragma('dart2js:noInline')
+  static SyncObjectId getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<SyncObjectId>(create);
+  static SyncObjectId? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get objectId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set objectId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasObjectId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearObjectId() => clearField(1);
+}
+
+class ObjectSyncStatus extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'ObjectSyncStatus', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'objectId')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'pendingCount')
+    ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'lastAckedRevId')
+    ..aInt64(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'lastSyncTime')
+    ..e<SyncConnectState>(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'connectState', $pb.PbFieldType.OE, defaultOrMaker: SyncConnectState.SyncInit, valueOf: SyncConnectState.valueOf, enumValues: SyncConnectState.values)
+    ..hasRequiredFields = false
+  ;
+
+  ObjectSyncStatus._() : super();
+  factory ObjectSyncStatus({
+    $core.String? objectId,
+    $fixnum.Int64? pendingCount,
+    $fixnum.Int64? lastAckedRevId,
+    $fixnum.Int64? lastSyncTime,
+    SyncConnectState? connectState,
+  }) {
+    final _result = create();
+    if (objectId != null) {
+      _result.objectId = objectId;
+    }
+    if (pendingCount != null) {
+      _result.pendingCount = pendingCount;
+    }
+    if (lastAckedRevId != null) {
+      _result.lastAckedRevId = lastAckedRevId;
+    }
+    if (lastSyncTime != null) {
+      _result.lastSyncTime = lastSyncTime;
+    }
+    if (connectState != null) {
+      _result.connectState = connectState;
+    }
+    return _result;
+  }
+  factory ObjectSyncStatus.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory ObjectSyncStatus.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  ObjectSyncStatus clone() => ObjectSyncStatus()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  ObjectSyncStatus copyWith(void Function(ObjectSyncStatus) updates) => super.copyWith((message) => updates(message as ObjectSyncStatus)) as ObjectSyncStatus; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static ObjectSyncStatus create() => ObjectSyncStatus._();
+  ObjectSyncStatus createEmptyInstance() => create();
+  static $pb.PbList<ObjectSyncStatus> createRepeated() => $pb.PbList<ObjectSyncStatus>();
+  @$core.pragma('dart2js:noInline')
+  static ObjectSyncStatus getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<ObjectSyncStatus>(create);
+  static ObjectSyncStatus? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get objectId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set objectId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasObjectId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearObjectId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get pendingCount => $_getI64(1);
+  @$pb.TagNumber(2)
+  set pendingCount($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasPendingCount() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearPendingCount() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $fixnum.Int64 get lastAckedRevId => $_getI64(2);
+  @$pb.TagNumber(3)
+  set lastAckedRevId($fixnum.Int64 v) { $_setInt64(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasLastAckedRevId() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearLastAckedRevId() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $fixnum.Int64 get lastSyncTime => $_getI64(3);
+  @$pb.TagNumber(4)
+  set lastSyncTime($fixnum.Int64 v) { $_setInt64(3, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasLastSyncTime() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearLastSyncTime() => clearField(4);
+
+  @$pb.TagNumber(5)
+  SyncConnectState get connectState => $_getN(4);
+  @$pb.TagNumber(5)
+  set connectState(SyncConnectState v) { setField(5, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasConnectState() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearConnectState() => clearField(5);
+}
+
+enum ObjectMergeSummary_OneOfError {
+  error, 
+  notSet
+}
+
+class ObjectMergeSummary extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, ObjectMergeSummary_OneOfError> _ObjectMergeSummary_OneOfErrorByTag = {
+    6 : ObjectMergeSummary_OneOfError.error,
+    0 : ObjectMergeSummary_OneOfError.notSet
+  };
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'ObjectMergeSummary', createEmptyInstance: create)
+    ..oo(0, [6])
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'objectId')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'localRevisionCount')
+    ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'remoteRevisionCount')
+    ..aInt64(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'mergedRevId')
+    ..aOB(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'hasBackup')
+    ..aOS(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'error')
+    ..hasRequiredFields = false
+  ;
+
+  ObjectMergeSummary._() : super();
+  factory ObjectMergeSummary({
+    $core.String? objectId,
+    $fixnum.Int64? localRevisionCount,
+    $fixnum.Int64? remoteRevisionCount,
+    $fixnum.Int64? mergedRevId,
+    $core.bool? hasBackup,
+    $core.String? error,
+  }) {
+    final _result = create();
+    if (objectId != null) {
+      _result.objectId = objectId;
+    }
+    if (localRevisionCount != null) {
+      _result.localRevisionCount = localRevisionCount;
+    }
+    if (remoteRevisionCount != null) {
+      _result.remoteRevisionCount = remoteRevisionCount;
+    }
+    if (mergedRevId != null) {
+      _result.mergedRevId = mergedRevId;
+    }
+    if (hasBackup != null) {
+      _result.hasBackup = hasBackup;
+    }
+    if (error != null) {
+      _result.error = error;
+    }
+    return _result;
+  }
+  factory ObjectMergeSummary.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory ObjectMergeSummary.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  ObjectMergeSummary clone() => ObjectMergeSummary()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  ObjectMergeSummary copyWith(void Function(ObjectMergeSummary) updates) => super.copyWith((message) => updates(message as ObjectMergeSummary)) as ObjectMergeSummary; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static ObjectMergeSummary create() => ObjectMergeSummary._();
+  ObjectMergeSummary createEmptyInstance() => create();
+  static $pb.PbList<ObjectMergeSummary> createRepeated() => $pb.PbList<ObjectMergeSummary>();
+  @$core.pragma('dart2js:noInline')
+  static ObjectMergeSummary getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<ObjectMergeSummary>(create);
+  static ObjectMergeSummary? _defaultInstance;
+
+  ObjectMergeSummary_OneOfError whichOneOfError() => _ObjectMergeSummary_OneOfErrorByTag[$_whichOneof(0)]!;
+  void clearOneOfError() => clearField($_whichOneof(0));
+
+  @$pb.TagNumber(1)
+  $core.String get objectId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set objectId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasObjectId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearObjectId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get localRevisionCount => $_getI64(1);
+  @$pb.TagNumber(2)
+  set localRevisionCount($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasLocalRevisionCount() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearLocalRevisionCount() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $fixnum.Int64 get remoteRevisionCount => $_getI64(2);
+  @$pb.TagNumber(3)
+  set remoteRevisionCount($fixnum.Int64 v) { $_setInt64(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasRemoteRevisionCount() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearRemoteRevisionCount() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $fixnum.Int64 get mergedRevId => $_getI64(3);
+  @$pb.TagNumber(4)
+  set mergedRevId($fixnum.Int64 v) { $_setInt64(3, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasMergedRevId() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearMergedRevId() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $core.bool get hasBackup => $_getBF(4);
+  @$pb.TagNumber(5)
+  set hasBackup($core.bool v) { $_setBool(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasHasBackup() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearHasBackup() => clearField(5);
+
+  @$pb.TagNumber(6)
+  $core.String get error => $_getSZ(5);
+  @$pb.TagNumber(6)
+  set error($core.String v) { $_setString(5, v); }
+  @$pb.TagNumber(6)
+  $core.bool hasError() => $_has(5);
+  @$pb.TagNumber(6)
+  void clearError() => clearField(6);
+}
+
+class SyncStatus extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'SyncStatus', createEmptyInstance: create)
+    ..aInt64(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'pendingCount')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'pendingObjectCount')
+    ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'lastSyncTime')
+    ..e<SyncConnectState>(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'connectState', $pb.PbFieldType.OE, defaultOrMaker: SyncConnectState.SyncInit, valueOf: SyncConnectState.valueOf, enumValues: SyncConnectState.values)
+    ..aOB(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'isSynced')
+    ..hasRequiredFields = false
+  ;
+
+  SyncStatus._() : super();
+  factory SyncStatus({
+    $fixnum.Int64? pendingCount,
+    $fixnum.Int64? pendingObjectCount,
+    $fixnum.Int64? lastSyncTime,
+    SyncConnectState? connectState,
+    $core.bool? isSynced,
+  }) {
+    final _result = create();
+    if (pendingCount != null) {
+      _result.pendingCount = pendingCount;
+    }
+    if (pendingObjectCount != null) {
+      _result.pendingObjectCount = pendingObjectCount;
+    }
+    if (lastSyncTime != null) {
+      _result.lastSyncTime = lastSyncTime;
+    }
+    if (connectState != null) {
+      _result.connectState = connectState;
+    }
+    if (isSynced != null) {
+      _result.isSynced = isSynced;
+    }
+    return _result;
+  }
+  factory SyncStatus.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory SyncStatus.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  SyncStatus clone() => SyncStatus()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  SyncStatus copyWith(void Function(SyncStatus) updates) => super.copyWith((message) => updates(message as SyncStatus)) as SyncStatus; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static SyncStatus create() => SyncStatus._();
+  SyncStatus createEmptyInstance() => create();
+  static $pb.PbList<SyncStatus> createRepeated() => $pb.PbList<SyncStatus>();
+  @$core.pragma('dart2js:noInline')
+  static SyncStatus getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<SyncStatus>(create);
+  static SyncStatus? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $fixnum.Int64 get pendingCount => $_getI64(0);
+  @$pb.TagNumber(1)
+  set pendingCount($fixnum.Int64 v) { $_setInt64(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasPendingCount() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearPendingCount() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get pendingObjectCount => $_getI64(1);
+  @$pb.TagNumber(2)
+  set pendingObjectCount($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasPendingObjectCount() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearPendingObjectCount() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $fixnum.Int64 get lastSyncTime => $_getI64(2);
+  @$pb.TagNumber(3)
+  set lastSyncTime($fixnum.Int64 v) { $_setInt64(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasLastSyncTime() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearLastSyncTime() => clearField(3);
+
+  @$pb.TagNumber(4)
+  SyncConnectState get connectState => $_getN(3);
+  @$pb.TagNumber(4)
+  set connectState(SyncConnectState v) { setField(4, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasConnectState() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearConnectState() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $core.bool get isSynced => $_getBF(4);
+  @$pb.TagNumber(5)
+  set isSynced($core.bool v) { $_setBool(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasIsSynced() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearIsSynced() => clearField(5);
+}
+
@@ -0,0 +1,30 @@
+///
+//  Generated code. Do not modify.
+//  source: sync_status.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+// ignore_for_file: UNDEFINED_SHOWN_NAME
+import 'dart:core' as $core;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+class SyncConnectState extends $pb.ProtobufEnum {
+  static const SyncConnectState SyncInit = SyncConnectState._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'SyncInit');
+  static const SyncConnectState SyncConnecting = SyncConnectState._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'SyncConnecting');
+  static const SyncConnectState SyncConnected = SyncConnectState._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'SyncConnected');
+  static const SyncConnectState SyncDisconnected = SyncConnectState._(3, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'SyncDisconnected');
+
+  static const $core.List<SyncConnectState> values = <SyncConnectState> [
+    SyncInit,
+    SyncConnecting,
+    SyncConnected,
+    SyncDisconnected,
+  ];
+
+  static final $core.Map<$core.int, SyncConnectState> _byValue = $pb.ProtobufEnum.initByValue(values);
+  static SyncConnectState? valueOf($core.int value) => _byValue[value];
+
+  const SyncConnectState._($core.int v, $core.String n) : super(v, n);
+}
+
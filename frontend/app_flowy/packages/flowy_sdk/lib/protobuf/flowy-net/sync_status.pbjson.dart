@@ -0,0 +1,79 @@
+///
+//  Generated code. Do not modify.
+//  source: sync_status.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use syncConnectStateDescriptor instead')
+const SyncConnectState$json = const {
+  '1': 'SyncConnectState',
+  '2': const [
+    const {'1': 'SyncInit', '2': 0},
+    const {'1': 'SyncConnecting', '2': 1},
+    const {'1': 'SyncConnected', '2': 2},
+    const {'1': 'SyncDisconnected', '2': 3},
+  ],
+};
+
+/// Descriptor for `SyncConnectState`. Decode as a `google.protobuf.EnumDescriptorProto`.
+final $typed_data.Uint8List syncConnectStateDescriptor = $convert.base64Decode('ChBTeW5jQ29ubmVjdFN0YXRlEgwKCFN5bmNJbml0EAASEgoOU3luY0Nvbm5lY3RpbmcQARIRCg1TeW5jQ29ubmVjdGVkEAISFAoQU3luY0Rpc2Nvbm5lY3RlZBAD');
+@$core.Deprecated('Use syncObjectIdDescriptor instead')
+const SyncObjectId$json = const {
+  '1': 'SyncObjectId',
+  '2': const [
+    const {'1': 'object_id', '3': 1, '4': 1, '5': 9, '10': 'objectId'},
+  ],
+};
+
+/// Descriptor for `SyncObjectId`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List syncObjectIdDescriptor = $convert.base64Decode('CgxTeW5jT2JqZWN0SWQSGwoJb2JqZWN0X2lkGAEgASgJUghvYmplY3RJZA==');
+@$core.Deprecated('Use objectSyncStatusDescriptor instead')
+const ObjectSyncStatus$json = const {
+  '1': 'ObjectSyncStatus',
+  '2': const [
+    const {'1': 'object_id', '3': 1, '4': 1, '5': 9, '10': 'objectId'},
+    const {'1': 'pending_count', '3': 2, '4': 1, '5': 3, '10': 'pendingCount'},
+    const {'1': 'last_acked_rev_id', '3': 3, '4': 1, '5': 3, '10': 'lastAckedRevId'},
+    const {'1': 'last_sync_time', '3': 4, '4': 1, '5': 3, '10': 'lastSyncTime'},
+    const {'1': 'connect_state', '3': 5, '4': 1, '5': 14, '6': '.SyncConnectState', '10': 'connectState'},
+  ],
+};
+
+/// Descriptor for `ObjectSyncStatus`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List objectSyncStatusDescriptor = $convert.base64Decode('ChBPYmplY3RTeW5jU3RhdHVzEhsKCW9iamVjdF9pZBgBIAEoCVIIb2JqZWN0SWQSIwoNcGVuZGluZ19jb3VudBgCIAEoA1IMcGVuZGluZ0NvdW50EikKEWxhc3RfYWNrZWRfcmV2X2lkGAMgASgDUg5sYXN0QWNrZWRSZXZJZBIkCg5sYXN0X3N5bmNfdGltZRgEIAEoA1IMbGFzdFN5bmNUaW1lEjYKDWNvbm5lY3Rfc3RhdGUYBSABKA4yES5TeW5jQ29ubmVjdFN0YXRlUgxjb25uZWN0U3RhdGU=');
+@$core.Deprecated('Use objectMergeSummaryDescriptor instead')
+const ObjectMergeSummary$json = const {
+  '1': 'ObjectMergeSummary',
+  '2': const [
+    const {'1': 'object_id', '3': 1, '4': 1, '5': 9, '10': 'objectId'},
+    const {'1': 'local_revision_count', '3': 2, '4': 1, '5': 3, '10': 'localRevisionCount'},
+    const {'1': 'remote_revision_count', '3': 3, '4': 1, '5': 3, '10': 'remoteRevisionCount'},
+    const {'1': 'merged_rev_id', '3': 4, '4': 1, '5': 3, '10': 'mergedRevId'},
+    const {'1': 'has_backup', '3': 5, '4': 1, '5': 8, '10': 'hasBackup'},
+    const {'1': 'error', '3': 6, '4': 1, '5': 9, '9': 0, '10': 'error'},
+  ],
+  '8': const [
+    const {'1': 'one_of_error'},
+  ],
+};
+
+/// Descriptor for `ObjectMergeSummary`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List objectMergeSummaryDescriptor = $convert.base64Decode('ChJPYmplY3RNZXJnZVN1bW1hcnkSGwoJb2JqZWN0X2lkGAEgASgJUghvYmplY3RJZBIwChRsb2NhbF9yZXZpc2lvbl9jb3VudBgCIAEoA1ISbG9jYWxSZXZpc2lvbkNvdW50EjIKFXJlbW90ZV9yZXZpc2lvbl9jb3VudBgDIAEoA1ITcmVtb3RlUmV2aXNpb25Db3VudBIiCg1tZXJnZWRfcmV2X2lkGAQgASgDUgttZXJnZWRSZXZJZBIdCgpoYXNfYmFja3VwGAUgASgIUgloYXNCYWNrdXASFgoFZXJyb3IYBiABKAlIAFIFZXJyb3JCDgoMb25lX29mX2Vycm9y');
+@$core.Deprecated('Use syncStatusDescriptor instead')
+const SyncStatus$json = const {
+  '1': 'SyncStatus',
+  '2': const [
+    const {'1': 'pending_count', '3': 1, '4': 1, '5': 3, '10': 'pendingCount'},
+    const {'1': 'pending_object_count', '3': 2, '4': 1, '5': 3, '10': 'pendingObjectCount'},
+    const {'1': 'last_sync_time', '3': 3, '4': 1, '5': 3, '10': 'lastSyncTime'},
+    const {'1': 'connect_state', '3': 4, '4': 1, '5': 14, '6': '.SyncConnectState', '10': 'connectState'},
+    const {'1': 'is_synced', '3': 5, '4': 1, '5': 8, '10': 'isSynced'},
+  ],
+};
+
+/// Descriptor for `SyncStatus`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List syncStatusDescriptor = $convert.base64Decode('CgpTeW5jU3RhdHVzEiMKDXBlbmRpbmdfY291bnQYASABKANSDHBlbmRpbmdDb3VudBIwChRwZW5kaW5nX29iamVjdF9jb3VudBgCIAEoA1IScGVuZGluZ09iamVjdENvdW50EiQKDmxhc3Rfc3luY190aW1lGAMgASgDUgxsYXN0U3luY1RpbWUSNgoNY29ubmVjdF9zdGF0ZRgEIAEoDjIRLlN5bmNDb25uZWN0U3RhdGVSDGNvbm5lY3RTdGF0ZRIbCglpc19zeW5jZWQYBSABKAhSCGlzU3luY2Vk');
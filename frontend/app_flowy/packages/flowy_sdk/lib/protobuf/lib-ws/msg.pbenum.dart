@@ -12,10 +12,16 @@ import 'package:protobuf/protobuf.dart' as $pb;
 class WSChannel extends $pb.ProtobufEnum {
   static const WSChannel Document = WSChannel._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Document');
   static const WSChannel Folder = WSChannel._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Folder');
+  static const WSChannel Grid = WSChannel._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Grid');
+  static const WSChannel Board = WSChannel._(3, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Board');
+  static const WSChannel Comment = WSChannel._(4, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Comment');
 
   static const $core.List<WSChannel> values = <WSChannel> [
     Document,
     Folder,
+    Grid,
+    Board,
+    Comment,
   ];
 
   static final $core.Map<$core.int, WSChannel> _byValue = $pb.ProtobufEnum.initByValue(values);
@@ -14,11 +14,14 @@ const WSChannel$json = const {
   '2': const [
     const {'1': 'Document', '2': 0},
     const {'1': 'Folder', '2': 1},
+    const {'1': 'Grid', '2': 2},
+    const {'1': 'Board', '2': 3},
+    const {'1': 'Comment', '2': 4},
   ],
 };
 
 /// Descriptor for `WSChannel`. Decode as a `google.protobuf.EnumDescriptorProto`.
-final $typed_data.Uint8List wSChannelDescriptor = $convert.base64Decode('CglXU0NoYW5uZWwSDAoIRG9jdW1lbnQQABIKCgZGb2xkZXIQAQ==');
+final $typed_data.Uint8List wSChannelDescriptor = $convert.base64Decode('CglXU0NoYW5uZWwSDAoIRG9jdW1lbnQQABIKCgZGb2xkZXIQARIICgRHcmlkEAISCQoFQm9hcmQQAxILCgdDb21tZW50EAQ=');
 @$core.Deprecated('Use webSocketRawMessageDescriptor instead')
 const WebSocketRawMessage$json = const {
   '1': 'WebSocketRawMessage',
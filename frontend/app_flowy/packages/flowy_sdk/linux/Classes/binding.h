@@ -4,7 +4,10 @@
 #include <stdlib.h>
 
 
-int64_t init_sdk(char *path);
+int64_t init_sdk(char *path,
+                 char *database_key,
+                 int64_t document_cache_max_count,
+                 int64_t document_cache_max_size);
 
 void async_command(int64_t port, const uint8_t *input, uintptr_t len);
 
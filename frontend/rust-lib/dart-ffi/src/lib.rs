@@ -23,17 +23,48 @@ fn dispatch() -> Arc<EventDispatcher> {
 }
 
 #[no_mangle]
-pub extern "C" fn init_sdk(path: *mut c_char) -> i64 {
+pub extern "C" fn init_sdk(
+    path: *mut c_char,
+    database_key: *mut c_char,
+    document_cache_max_count: i64,
+    document_cache_max_size: i64,
+) -> i64 {
     let c_str: &CStr = unsafe { CStr::from_ptr(path) };
     let path: &str = c_str.to_str().unwrap();
 
+    // The hex of the 32 bytes key that encrypts the local databases, it's read by the app from the keychain.
+    // The databases are not encrypted if it's empty.
+    let c_str: &CStr = unsafe { CStr::from_ptr(database_key) };
+    let database_key = c_str.to_str().unwrap().to_owned();
+
     let server_config = get_client_server_configuration().unwrap();
-    let config = FlowySDKConfig::new(path, server_config, "appflowy").log_filter("debug");
+    let config = FlowySDKConfig::new(path, server_config, "appflowy")
+        .log_filter("debug")
+        .document_cache(document_cache_config(document_cache_max_count, document_cache_max_size))
+        .database_key(Some(database_key).filter(|key| !key.is_empty()));
     *FLOWY_SDK.write() = Some(Arc::new(FlowySDK::new(config)));
 
     0
 }
 
+// The budget of the opened documents that is set by the app, the max size is in bytes. The default
+// value is used for the one that is not positive.
+fn document_cache_config(max_count: i64, max_size: i64) -> DocumentCacheConfig {
+    let default = DocumentCacheConfig::default();
+    DocumentCacheConfig {
+        max_count: if max_count > 0 {
+            max_count as usize
+        } else {
+            default.max_count
+        },
+        max_size: if max_size > 0 {
+            max_size as usize
+        } else {
+            default.max_size
+        },
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn async_event(port: i64, input: *const u8, len: usize) {
     let request: ModuleRequest = FFIRequest::from_u8_pointer(input, len).into();
@@ -2,7 +2,7 @@ use bytes::Bytes;
 use chrono::Utc;
 use flowy_collaboration::client_document::default::{initial_delta, initial_read_me};
 use flowy_core_data_model::user_default;
-use flowy_sync::RevisionWebSocket;
+use flowy_sync::{RevisionCipher, RevisionWebSocket};
 use lazy_static::lazy_static;
 
 use flowy_collaboration::{entities::ws_data::ServerRevisionWSData, folder::FolderPad};
@@ -13,12 +13,13 @@ use tokio::sync::RwLock as TokioRwLock;
 
 use crate::{
     dart_notification::{send_dart_notification, WorkspaceNotification},
-    entities::workspace::RepeatedWorkspace,
+    entities::workspace::{RepeatedWorkspace, WorkspaceEncryptionParams},
     errors::FlowyResult,
     module::{FolderCouldServiceV1, WorkspaceDatabase, WorkspaceUser},
     services::{
-        folder_editor::FolderEditor, persistence::FolderPersistence, set_current_workspace, AppController,
-        TrashController, ViewController, WorkspaceController,
+        folder_editor::FolderEditor, persistence::FolderPersistence, set_current_workspace,
+        set_workspace_revision_cipher, AppController, BoardController, CommentController, GridController,
+        TemplateController, TrashController, ViewController, WorkspaceController,
     },
 };
 
@@ -62,6 +63,10 @@ pub struct FolderManager {
     pub(crate) app_controller: Arc<AppController>,
     pub(crate) view_controller: Arc<ViewController>,
     pub(crate) trash_controller: Arc<TrashController>,
+    pub(crate) template_controller: Arc<TemplateController>,
+    pub(crate) grid_controller: Arc<GridController>,
+    pub(crate) board_controller: Arc<BoardController>,
+    pub(crate) comment_controller: Arc<CommentController>,
     web_socket: Arc<dyn RevisionWebSocket>,
     folder_editor: Arc<TokioRwLock<Option<Arc<FolderEditor>>>>,
 }
@@ -73,6 +78,9 @@ impl FolderManager {
         database: Arc<dyn WorkspaceDatabase>,
         document_manager: Arc<FlowyDocumentManager>,
         web_socket: Arc<dyn RevisionWebSocket>,
+        grid_web_socket: Arc<dyn RevisionWebSocket>,
+        board_web_socket: Arc<dyn RevisionWebSocket>,
+        comment_web_socket: Arc<dyn RevisionWebSocket>,
     ) -> Self {
         let folder_editor = Arc::new(TokioRwLock::new(None));
         let persistence = Arc::new(FolderPersistence::new(database.clone(), folder_editor.clone()));
@@ -83,12 +91,28 @@ impl FolderManager {
             user.clone(),
         ));
 
+        let grid_controller = Arc::new(GridController::new(user.clone(), persistence.clone(), grid_web_socket));
+        let board_controller = Arc::new(BoardController::new(
+            user.clone(),
+            persistence.clone(),
+            board_web_socket,
+        ));
+        let comment_controller = Arc::new(CommentController::new(
+            user.clone(),
+            persistence.clone(),
+            comment_web_socket,
+            document_manager.clone(),
+        ));
+
         let view_controller = Arc::new(ViewController::new(
             user.clone(),
             persistence.clone(),
             cloud_service.clone(),
             trash_controller.clone(),
-            document_manager,
+            document_manager.clone(),
+            grid_controller.clone(),
+            board_controller.clone(),
+            comment_controller.clone(),
         ));
 
         let app_controller = Arc::new(AppController::new(
@@ -103,6 +127,16 @@ impl FolderManager {
             persistence.clone(),
             trash_controller.clone(),
             cloud_service.clone(),
+            document_manager,
+            grid_controller.clone(),
+            board_controller.clone(),
+        ));
+
+        let template_controller = Arc::new(TemplateController::new(
+            user.clone(),
+            persistence.clone(),
+            trash_controller.clone(),
+            view_controller.clone(),
         ));
 
         Self {
@@ -113,6 +147,10 @@ impl FolderManager {
             app_controller,
             view_controller,
             trash_controller,
+            template_controller,
+            grid_controller,
+            board_controller,
+            comment_controller,
             web_socket,
             folder_editor,
         }
@@ -143,6 +181,18 @@ impl FolderManager {
         }
     }
 
+    pub async fn did_receive_grid_ws_data(&self, data: Bytes) {
+        self.grid_controller.did_receive_ws_data(data).await;
+    }
+
+    pub async fn did_receive_board_ws_data(&self, data: Bytes) {
+        self.board_controller.did_receive_ws_data(data).await;
+    }
+
+    pub async fn did_receive_comment_ws_data(&self, data: Bytes) {
+        self.comment_controller.did_receive_ws_data(data).await;
+    }
+
     pub async fn initialize(&self, user_id: &str, token: &str) -> FlowyResult<()> {
         let mut write_guard = INIT_FOLDER_FLAG.write().await;
         if let Some(is_init) = write_guard.get(user_id) {
@@ -157,8 +207,10 @@ impl FolderManager {
         let folder_editor = FolderEditor::new(user_id, &folder_id, token, pool, self.web_socket.clone()).await?;
         *self.folder_editor.write().await = Some(Arc::new(folder_editor));
 
+        let _ = self.workspace_controller.initialize()?;
         let _ = self.app_controller.initialize()?;
         let _ = self.view_controller.initialize()?;
+        let _ = self.trash_controller.initialize()?;
         write_guard.insert(user_id.to_owned(), true);
         Ok(())
     }
@@ -168,6 +220,24 @@ impl FolderManager {
         self.initialize(user_id, token).await
     }
 
+    // Derives the key of the workspace from the passphrase, and replaces the documents of the
+    // workspace on the server with the encrypted ones. The other clients must set the same
+    // passphrase to read the documents. The folder, the grids, the boards and the comment threads
+    // of the workspace are not encrypted.
+    pub async fn set_workspace_encryption(&self, params: WorkspaceEncryptionParams) -> FlowyResult<()> {
+        let cipher = RevisionCipher::from_passphrase(&params.passphrase, &params.workspace_id)?;
+        let _ = set_workspace_revision_cipher(&self.persistence, &params.workspace_id, &cipher)?;
+        self.view_controller.apply_current_workspace_cipher();
+        self.view_controller
+            .encrypt_workspace_documents(&params.workspace_id, &cipher)
+            .await
+    }
+
+    // The expired trash is purged periodically, it's purged immediately with the time `now` here.
+    pub async fn purge_expired_trash(&self, now: i64) -> FlowyResult<()> {
+        self.trash_controller.purge_expired_trash(now).await
+    }
+
     pub async fn clear(&self) {
         *self.folder_editor.write().await = None;
     }
@@ -17,6 +17,10 @@ pub(crate) enum WorkspaceNotification {
     ViewRestored = 33,
     UserUnauthorized = 100,
     TrashUpdated = 1000,
+    TemplatesUpdated = 1100,
+    GridUpdated = 1200,
+    BoardUpdated = 1300,
+    CommentsUpdated = 1400,
 }
 
 impl std::default::Default for WorkspaceNotification {
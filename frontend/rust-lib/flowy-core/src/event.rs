@@ -22,6 +22,10 @@ pub enum WorkspaceEvent {
     #[event(input = "QueryWorkspaceRequest", output = "RepeatedApp")]
     ReadWorkspaceApps = 5,
 
+    // Encrypts the documents of the workspace, the other objects of the workspace are not encrypted.
+    #[event(input = "WorkspaceEncryptionRequest")]
+    SetWorkspaceEncryption = 6,
+
     #[event(input = "CreateAppRequest", output = "App")]
     CreateApp = 101,
 
@@ -34,6 +38,9 @@ pub enum WorkspaceEvent {
     #[event(input = "UpdateAppRequest")]
     UpdateApp = 104,
 
+    #[event(input = "QueryAppRequest", output = "App")]
+    DuplicateApp = 105,
+
     #[event(input = "CreateViewRequest", output = "View")]
     CreateView = 201,
 
@@ -58,6 +65,15 @@ pub enum WorkspaceEvent {
     #[event(input = "QueryViewRequest")]
     CloseView = 208,
 
+    #[event(input = "UpdateViewTagsRequest", output = "View")]
+    UpdateViewTags = 209,
+
+    #[event(input = "UpdateViewPropertyRequest", output = "View")]
+    UpdateViewProperty = 210,
+
+    #[event(input = "ViewFilterRequest", output = "RepeatedView")]
+    QueryViews = 211,
+
     #[event(output = "RepeatedTrash")]
     ReadTrash = 300,
 
@@ -73,9 +89,113 @@ pub enum WorkspaceEvent {
     #[event()]
     DeleteAllTrash = 304,
 
+    #[event(output = "TrashRetentionPolicy")]
+    ReadTrashRetentionPolicy = 305,
+
+    #[event(input = "TrashRetentionPolicy")]
+    UpdateTrashRetentionPolicy = 306,
+
     #[event(input = "DocumentDelta", output = "DocumentDelta")]
     ApplyDocDelta = 400,
 
+    #[event(input = "HighlightCodeRequest", output = "RepeatedHighlightSpan")]
+    HighlightCode = 401,
+
+    #[event(input = "QueryViewRequest", output = "RepeatedOutlineItem")]
+    ReadDocumentOutline = 402,
+
+    #[event(input = "JumpToSectionRequest", output = "DocumentSection")]
+    JumpToSection = 403,
+
+    #[event(input = "DocumentStatisticsRequest", output = "DocumentStatistics")]
+    ReadDocumentStatistics = 404,
+
+    #[event(input = "DocumentIntegrityRequest", output = "DocumentIntegrityReport")]
+    CheckDocumentIntegrity = 405,
+
+    // The backups of the local document that were made before its pending revisions were merged
+    // with the revisions of the server.
+    #[event(input = "QueryViewRequest", output = "RepeatedDocumentMergeBackup")]
+    ReadDocumentMergeBackups = 406,
+
+    #[event(input = "DocumentMergeBackupId", output = "DocumentDelta")]
+    RestoreDocumentMergeBackup = 407,
+
+    #[event(input = "DocumentMergeBackupId")]
+    DeleteDocumentMergeBackup = 408,
+
+    #[event(output = "OpenDocumentsUsage")]
+    ReadOpenDocumentsUsage = 409,
+
     #[event(input = "ExportRequest", output = "ExportData")]
     ExportDocument = 500,
+
+    #[event(input = "CreateTemplateRequest", output = "Template")]
+    CreateTemplate = 600,
+
+    #[event(output = "RepeatedTemplate")]
+    ReadTemplates = 601,
+
+    #[event(input = "QueryTemplateRequest")]
+    DeleteTemplate = 602,
+
+    #[event(input = "CreateViewFromTemplateRequest", output = "View")]
+    CreateViewFromTemplate = 603,
+
+    #[event(input = "QueryGridRequest", output = "Grid")]
+    OpenGrid = 700,
+
+    #[event(input = "CreateFieldRequest", output = "Field")]
+    CreateField = 701,
+
+    #[event(input = "UpdateFieldRequest", output = "Field")]
+    UpdateField = 702,
+
+    #[event(input = "QueryFieldRequest")]
+    DeleteField = 703,
+
+    #[event(input = "QueryGridRequest", output = "Row")]
+    CreateRow = 704,
+
+    #[event(input = "UpdateRowRequest", output = "Row")]
+    UpdateRow = 705,
+
+    #[event(input = "QueryRowRequest")]
+    DeleteRow = 706,
+
+    #[event(input = "UpdateCellRequest", output = "Row")]
+    UpdateCell = 707,
+
+    #[event(input = "QueryCellRequest")]
+    DeleteCell = 708,
+
+    #[event(input = "QueryBoardRequest", output = "Board")]
+    OpenBoard = 800,
+
+    #[event(input = "UpdateBoardPropertyRequest", output = "Board")]
+    UpdateBoardProperty = 801,
+
+    #[event(input = "CreateCardRequest", output = "Card")]
+    CreateCard = 802,
+
+    #[event(input = "UpdateCardRequest", output = "Card")]
+    UpdateCard = 803,
+
+    #[event(input = "MoveCardRequest", output = "Board")]
+    MoveCard = 804,
+
+    #[event(input = "QueryCardRequest")]
+    DeleteCard = 805,
+
+    #[event(input = "CreateCommentRequest", output = "CommentThread")]
+    CreateComment = 900,
+
+    #[event(input = "ReplyCommentRequest", output = "CommentThread")]
+    ReplyComment = 901,
+
+    #[event(input = "ResolveCommentRequest", output = "CommentThread")]
+    ResolveComment = 902,
+
+    #[event(input = "QueryCommentsRequest", output = "RepeatedCommentThread")]
+    ReadComments = 903,
 }
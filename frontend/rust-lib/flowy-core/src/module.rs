@@ -8,7 +8,10 @@ use crate::{
     },
     errors::FlowyError,
     event::WorkspaceEvent,
-    services::{app::event_handler::*, trash::event_handler::*, view::event_handler::*, workspace::event_handler::*},
+    services::{
+        app::event_handler::*, board::event_handler::*, comment::event_handler::*, grid::event_handler::*,
+        template::event_handler::*, trash::event_handler::*, view::event_handler::*, workspace::event_handler::*,
+    },
 };
 use flowy_database::DBConnection;
 
@@ -22,6 +25,7 @@ pub trait WorkspaceDeps: WorkspaceUser + WorkspaceDatabase {}
 pub trait WorkspaceUser: Send + Sync {
     fn user_id(&self) -> Result<String, FlowyError>;
     fn token(&self) -> Result<String, FlowyError>;
+    fn user_name(&self) -> Result<String, FlowyError>;
 }
 
 pub trait WorkspaceDatabase: Send + Sync {
@@ -41,20 +45,27 @@ pub fn create(folder: Arc<FolderManager>) -> Module {
         .data(folder.app_controller.clone())
         .data(folder.view_controller.clone())
         .data(folder.trash_controller.clone())
+        .data(folder.template_controller.clone())
+        .data(folder.grid_controller.clone())
+        .data(folder.board_controller.clone())
+        .data(folder.comment_controller.clone())
         .data(folder.clone());
 
     module = module
         .event(WorkspaceEvent::CreateWorkspace, create_workspace_handler)
         .event(WorkspaceEvent::ReadCurWorkspace, read_cur_workspace_handler)
         .event(WorkspaceEvent::ReadWorkspaces, read_workspaces_handler)
+        .event(WorkspaceEvent::DeleteWorkspace, delete_workspace_handler)
         .event(WorkspaceEvent::OpenWorkspace, open_workspace_handler)
-        .event(WorkspaceEvent::ReadWorkspaceApps, read_workspace_apps_handler);
+        .event(WorkspaceEvent::ReadWorkspaceApps, read_workspace_apps_handler)
+        .event(WorkspaceEvent::SetWorkspaceEncryption, set_workspace_encryption_handler);
 
     module = module
         .event(WorkspaceEvent::CreateApp, create_app_handler)
         .event(WorkspaceEvent::ReadApp, read_app_handler)
         .event(WorkspaceEvent::UpdateApp, update_app_handler)
-        .event(WorkspaceEvent::DeleteApp, delete_app_handler);
+        .event(WorkspaceEvent::DeleteApp, delete_app_handler)
+        .event(WorkspaceEvent::DuplicateApp, duplicate_app_handler);
 
     module = module
         .event(WorkspaceEvent::CreateView, create_view_handler)
@@ -64,17 +75,83 @@ pub fn create(folder: Arc<FolderManager>) -> Module {
         .event(WorkspaceEvent::DuplicateView, duplicate_view_handler)
         .event(WorkspaceEvent::OpenDocument, open_document_handler)
         .event(WorkspaceEvent::CloseView, close_view_handler)
-        .event(WorkspaceEvent::ApplyDocDelta, document_delta_handler);
+        .event(WorkspaceEvent::UpdateViewTags, update_view_tags_handler)
+        .event(WorkspaceEvent::UpdateViewProperty, update_view_property_handler)
+        .event(WorkspaceEvent::QueryViews, query_views_handler)
+        .event(WorkspaceEvent::ApplyDocDelta, document_delta_handler)
+        .event(WorkspaceEvent::HighlightCode, highlight_code_handler)
+        .event(WorkspaceEvent::ReadDocumentOutline, read_document_outline_handler)
+        .event(WorkspaceEvent::JumpToSection, jump_to_section_handler)
+        .event(WorkspaceEvent::ReadDocumentStatistics, read_document_statistics_handler)
+        .event(WorkspaceEvent::CheckDocumentIntegrity, check_document_integrity_handler)
+        .event(
+            WorkspaceEvent::ReadDocumentMergeBackups,
+            read_document_merge_backups_handler,
+        )
+        .event(
+            WorkspaceEvent::RestoreDocumentMergeBackup,
+            restore_document_merge_backup_handler,
+        )
+        .event(
+            WorkspaceEvent::DeleteDocumentMergeBackup,
+            delete_document_merge_backup_handler,
+        )
+        .event(
+            WorkspaceEvent::ReadOpenDocumentsUsage,
+            read_open_documents_usage_handler,
+        );
 
     module = module
         .event(WorkspaceEvent::ReadTrash, read_trash_handler)
         .event(WorkspaceEvent::PutbackTrash, putback_trash_handler)
         .event(WorkspaceEvent::DeleteTrash, delete_trash_handler)
         .event(WorkspaceEvent::RestoreAllTrash, restore_all_trash_handler)
-        .event(WorkspaceEvent::DeleteAllTrash, delete_all_trash_handler);
+        .event(WorkspaceEvent::DeleteAllTrash, delete_all_trash_handler)
+        .event(
+            WorkspaceEvent::ReadTrashRetentionPolicy,
+            read_trash_retention_policy_handler,
+        )
+        .event(
+            WorkspaceEvent::UpdateTrashRetentionPolicy,
+            update_trash_retention_policy_handler,
+        );
 
     module = module.event(WorkspaceEvent::ExportDocument, export_handler);
 
+    module = module
+        .event(WorkspaceEvent::CreateTemplate, create_template_handler)
+        .event(WorkspaceEvent::ReadTemplates, read_templates_handler)
+        .event(WorkspaceEvent::DeleteTemplate, delete_template_handler)
+        .event(
+            WorkspaceEvent::CreateViewFromTemplate,
+            create_view_from_template_handler,
+        );
+
+    module = module
+        .event(WorkspaceEvent::OpenGrid, open_grid_handler)
+        .event(WorkspaceEvent::CreateField, create_field_handler)
+        .event(WorkspaceEvent::UpdateField, update_field_handler)
+        .event(WorkspaceEvent::DeleteField, delete_field_handler)
+        .event(WorkspaceEvent::CreateRow, create_row_handler)
+        .event(WorkspaceEvent::UpdateRow, update_row_handler)
+        .event(WorkspaceEvent::DeleteRow, delete_row_handler)
+        .event(WorkspaceEvent::UpdateCell, update_cell_handler)
+        .event(WorkspaceEvent::DeleteCell, delete_cell_handler);
+
+    module = module
+        .event(WorkspaceEvent::OpenBoard, open_board_handler)
+        .event(WorkspaceEvent::UpdateBoardProperty, update_board_property_handler)
+        .event(WorkspaceEvent::CreateCard, create_card_handler)
+        .event(WorkspaceEvent::UpdateCard, update_card_handler)
+        .event(WorkspaceEvent::MoveCard, move_card_handler)
+        .event(WorkspaceEvent::DeleteCard, delete_card_handler);
+
+    module = module
+        .event(WorkspaceEvent::CreateComment, create_comment_handler)
+        .event(WorkspaceEvent::ReplyComment, reply_comment_handler)
+        .event(WorkspaceEvent::ResolveComment, resolve_comment_handler)
+        .event(WorkspaceEvent::ReadComments, read_comments_handler);
+
     module
 }
 
@@ -38,6 +38,10 @@ pub enum WorkspaceNotification {
     ViewRestored = 33,
     UserUnauthorized = 100,
     TrashUpdated = 1000,
+    TemplatesUpdated = 1100,
+    GridUpdated = 1200,
+    BoardUpdated = 1300,
+    CommentsUpdated = 1400,
 }
 
 impl ::protobuf::ProtobufEnum for WorkspaceNotification {
@@ -60,6 +64,10 @@ impl ::protobuf::ProtobufEnum for WorkspaceNotification {
             33 => ::std::option::Option::Some(WorkspaceNotification::ViewRestored),
             100 => ::std::option::Option::Some(WorkspaceNotification::UserUnauthorized),
             1000 => ::std::option::Option::Some(WorkspaceNotification::TrashUpdated),
+            1100 => ::std::option::Option::Some(WorkspaceNotification::TemplatesUpdated),
+            1200 => ::std::option::Option::Some(WorkspaceNotification::GridUpdated),
+            1300 => ::std::option::Option::Some(WorkspaceNotification::BoardUpdated),
+            1400 => ::std::option::Option::Some(WorkspaceNotification::CommentsUpdated),
             _ => ::std::option::Option::None
         }
     }
@@ -79,6 +87,10 @@ impl ::protobuf::ProtobufEnum for WorkspaceNotification {
             WorkspaceNotification::ViewRestored,
             WorkspaceNotification::UserUnauthorized,
             WorkspaceNotification::TrashUpdated,
+            WorkspaceNotification::TemplatesUpdated,
+            WorkspaceNotification::GridUpdated,
+            WorkspaceNotification::BoardUpdated,
+            WorkspaceNotification::CommentsUpdated,
         ];
         values
     }
@@ -107,29 +119,31 @@ impl ::protobuf::reflect::ProtobufValue for WorkspaceNotification {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x17dart_notification.proto*\xa2\x02\n\x15WorkspaceNotification\x12\
+    \n\x17dart_notification.proto*\xf4\x02\n\x15WorkspaceNotification\x12\
     \x0b\n\x07Unknown\x10\0\x12\x17\n\x13UserCreateWorkspace\x10\n\x12\x17\n\
     \x13UserDeleteWorkspace\x10\x0b\x12\x14\n\x10WorkspaceUpdated\x10\x0c\
     \x12\x18\n\x14WorkspaceListUpdated\x10\r\x12\x18\n\x14WorkspaceAppsChang\
     ed\x10\x0e\x12\x0e\n\nAppUpdated\x10\x15\x12\x13\n\x0fAppViewsChanged\
     \x10\x18\x12\x0f\n\x0bViewUpdated\x10\x1f\x12\x0f\n\x0bViewDeleted\x10\
     \x20\x12\x10\n\x0cViewRestored\x10!\x12\x14\n\x10UserUnauthorized\x10d\
-    \x12\x11\n\x0cTrashUpdated\x10\xe8\x07J\xbf\x04\n\x06\x12\x04\0\0\x10\
-    \x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\x10\
-    \x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x1a\n\x0b\n\x04\x05\0\x02\0\x12\
-    \x03\x03\x04\x10\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x0b\n\x0c\n\
-    \x05\x05\0\x02\0\x02\x12\x03\x03\x0e\x0f\n\x0b\n\x04\x05\0\x02\x01\x12\
-    \x03\x04\x04\x1d\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x17\n\x0c\
-    \n\x05\x05\0\x02\x01\x02\x12\x03\x04\x1a\x1c\n\x0b\n\x04\x05\0\x02\x02\
-    \x12\x03\x05\x04\x1d\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\x17\n\
-    \x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x05\x1a\x1c\n\x0b\n\x04\x05\0\x02\
-    \x03\x12\x03\x06\x04\x1a\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\x04\
-    \x14\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x06\x17\x19\n\x0b\n\x04\x05\0\
-    \x02\x04\x12\x03\x07\x04\x1e\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x07\
-    \x04\x18\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x07\x1b\x1d\n\x0b\n\x04\
-    \x05\0\x02\x05\x12\x03\x08\x04\x1e\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\
-    \x08\x04\x18\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x08\x1b\x1d\n\x0b\n\
-    \x04\x05\0\x02\x06\x12\x03\t\x04\x14\n\x0c\n\x05\x05\0\x02\x06\x01\x12\
+    \x12\x11\n\x0cTrashUpdated\x10\xe8\x07\x12\x15\n\x10TemplatesUpdated\x10\
+    \xcc\x08\x12\x10\n\x0bGridUpdated\x10\xb0\t\x12\x11\n\x0cBoardUpdated\
+    \x10\x94\n\x12\x14\n\x0fCommentsUpdated\x10\xf8\nJ\xe3\x05\n\x06\x12\x04\
+    \0\0\x14\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\
+    \0\x14\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x1a\n\x0b\n\x04\x05\0\x02\
+    \0\x12\x03\x03\x04\x10\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x0b\n\
+    \x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x0e\x0f\n\x0b\n\x04\x05\0\x02\x01\
+    \x12\x03\x04\x04\x1d\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x17\n\
+    \x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x04\x1a\x1c\n\x0b\n\x04\x05\0\x02\
+    \x02\x12\x03\x05\x04\x1d\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\
+    \x17\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x05\x1a\x1c\n\x0b\n\x04\x05\0\
+    \x02\x03\x12\x03\x06\x04\x1a\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\
+    \x04\x14\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x06\x17\x19\n\x0b\n\x04\
+    \x05\0\x02\x04\x12\x03\x07\x04\x1e\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\
+    \x07\x04\x18\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x07\x1b\x1d\n\x0b\n\
+    \x04\x05\0\x02\x05\x12\x03\x08\x04\x1e\n\x0c\n\x05\x05\0\x02\x05\x01\x12\
+    \x03\x08\x04\x18\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x08\x1b\x1d\n\x0b\
+    \n\x04\x05\0\x02\x06\x12\x03\t\x04\x14\n\x0c\n\x05\x05\0\x02\x06\x01\x12\
     \x03\t\x04\x0e\n\x0c\n\x05\x05\0\x02\x06\x02\x12\x03\t\x11\x13\n\x0b\n\
     \x04\x05\0\x02\x07\x12\x03\n\x04\x19\n\x0c\n\x05\x05\0\x02\x07\x01\x12\
     \x03\n\x04\x13\n\x0c\n\x05\x05\0\x02\x07\x02\x12\x03\n\x16\x18\n\x0b\n\
@@ -142,8 +156,16 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x02\x0b\x12\x03\x0e\x04\x1b\n\x0c\n\x05\x05\0\x02\x0b\x01\x12\x03\x0e\
     \x04\x14\n\x0c\n\x05\x05\0\x02\x0b\x02\x12\x03\x0e\x17\x1a\n\x0b\n\x04\
     \x05\0\x02\x0c\x12\x03\x0f\x04\x18\n\x0c\n\x05\x05\0\x02\x0c\x01\x12\x03\
-    \x0f\x04\x10\n\x0c\n\x05\x05\0\x02\x0c\x02\x12\x03\x0f\x13\x17b\x06proto\
-    3\
+    \x0f\x04\x10\n\x0c\n\x05\x05\0\x02\x0c\x02\x12\x03\x0f\x13\x17\n\x0b\n\
+    \x04\x05\0\x02\r\x12\x03\x10\x04\x1c\n\x0c\n\x05\x05\0\x02\r\x01\x12\x03\
+    \x10\x04\x14\n\x0c\n\x05\x05\0\x02\r\x02\x12\x03\x10\x17\x1b\n\x0b\n\x04\
+    \x05\0\x02\x0e\x12\x03\x11\x04\x17\n\x0c\n\x05\x05\0\x02\x0e\x01\x12\x03\
+    \x11\x04\x0f\n\x0c\n\x05\x05\0\x02\x0e\x02\x12\x03\x11\x12\x16\n\x0b\n\
+    \x04\x05\0\x02\x0f\x12\x03\x12\x04\x18\n\x0c\n\x05\x05\0\x02\x0f\x01\x12\
+    \x03\x12\x04\x10\n\x0c\n\x05\x05\0\x02\x0f\x02\x12\x03\x12\x13\x17\n\x0b\
+    \n\x04\x05\0\x02\x10\x12\x03\x13\x04\x1b\n\x0c\n\x05\x05\0\x02\x10\x01\
+    \x12\x03\x13\x04\x13\n\x0c\n\x05\x05\0\x02\x10\x02\x12\x03\x13\x16\x1ab\
+    \x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
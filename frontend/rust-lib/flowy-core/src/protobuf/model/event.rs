@@ -31,10 +31,12 @@ pub enum WorkspaceEvent {
     DeleteWorkspace = 3,
     OpenWorkspace = 4,
     ReadWorkspaceApps = 5,
+    SetWorkspaceEncryption = 6,
     CreateApp = 101,
     DeleteApp = 102,
     ReadApp = 103,
     UpdateApp = 104,
+    DuplicateApp = 105,
     CreateView = 201,
     ReadView = 202,
     UpdateView = 203,
@@ -43,13 +45,50 @@ pub enum WorkspaceEvent {
     CopyLink = 206,
     OpenDocument = 207,
     CloseView = 208,
+    UpdateViewTags = 209,
+    UpdateViewProperty = 210,
+    QueryViews = 211,
     ReadTrash = 300,
     PutbackTrash = 301,
     DeleteTrash = 302,
     RestoreAllTrash = 303,
     DeleteAllTrash = 304,
+    ReadTrashRetentionPolicy = 305,
+    UpdateTrashRetentionPolicy = 306,
     ApplyDocDelta = 400,
+    HighlightCode = 401,
+    ReadDocumentOutline = 402,
+    JumpToSection = 403,
+    ReadDocumentStatistics = 404,
+    CheckDocumentIntegrity = 405,
+    ReadDocumentMergeBackups = 406,
+    RestoreDocumentMergeBackup = 407,
+    DeleteDocumentMergeBackup = 408,
+    ReadOpenDocumentsUsage = 409,
     ExportDocument = 500,
+    CreateTemplate = 600,
+    ReadTemplates = 601,
+    DeleteTemplate = 602,
+    CreateViewFromTemplate = 603,
+    OpenGrid = 700,
+    CreateField = 701,
+    UpdateField = 702,
+    DeleteField = 703,
+    CreateRow = 704,
+    UpdateRow = 705,
+    DeleteRow = 706,
+    UpdateCell = 707,
+    DeleteCell = 708,
+    OpenBoard = 800,
+    UpdateBoardProperty = 801,
+    CreateCard = 802,
+    UpdateCard = 803,
+    MoveCard = 804,
+    DeleteCard = 805,
+    CreateComment = 900,
+    ReplyComment = 901,
+    ResolveComment = 902,
+    ReadComments = 903,
 }
 
 impl ::protobuf::ProtobufEnum for WorkspaceEvent {
@@ -65,10 +104,12 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             3 => ::std::option::Option::Some(WorkspaceEvent::DeleteWorkspace),
             4 => ::std::option::Option::Some(WorkspaceEvent::OpenWorkspace),
             5 => ::std::option::Option::Some(WorkspaceEvent::ReadWorkspaceApps),
+            6 => ::std::option::Option::Some(WorkspaceEvent::SetWorkspaceEncryption),
             101 => ::std::option::Option::Some(WorkspaceEvent::CreateApp),
             102 => ::std::option::Option::Some(WorkspaceEvent::DeleteApp),
             103 => ::std::option::Option::Some(WorkspaceEvent::ReadApp),
             104 => ::std::option::Option::Some(WorkspaceEvent::UpdateApp),
+            105 => ::std::option::Option::Some(WorkspaceEvent::DuplicateApp),
             201 => ::std::option::Option::Some(WorkspaceEvent::CreateView),
             202 => ::std::option::Option::Some(WorkspaceEvent::ReadView),
             203 => ::std::option::Option::Some(WorkspaceEvent::UpdateView),
@@ -77,13 +118,50 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             206 => ::std::option::Option::Some(WorkspaceEvent::CopyLink),
             207 => ::std::option::Option::Some(WorkspaceEvent::OpenDocument),
             208 => ::std::option::Option::Some(WorkspaceEvent::CloseView),
+            209 => ::std::option::Option::Some(WorkspaceEvent::UpdateViewTags),
+            210 => ::std::option::Option::Some(WorkspaceEvent::UpdateViewProperty),
+            211 => ::std::option::Option::Some(WorkspaceEvent::QueryViews),
             300 => ::std::option::Option::Some(WorkspaceEvent::ReadTrash),
             301 => ::std::option::Option::Some(WorkspaceEvent::PutbackTrash),
             302 => ::std::option::Option::Some(WorkspaceEvent::DeleteTrash),
             303 => ::std::option::Option::Some(WorkspaceEvent::RestoreAllTrash),
             304 => ::std::option::Option::Some(WorkspaceEvent::DeleteAllTrash),
+            305 => ::std::option::Option::Some(WorkspaceEvent::ReadTrashRetentionPolicy),
+            306 => ::std::option::Option::Some(WorkspaceEvent::UpdateTrashRetentionPolicy),
             400 => ::std::option::Option::Some(WorkspaceEvent::ApplyDocDelta),
+            401 => ::std::option::Option::Some(WorkspaceEvent::HighlightCode),
+            402 => ::std::option::Option::Some(WorkspaceEvent::ReadDocumentOutline),
+            403 => ::std::option::Option::Some(WorkspaceEvent::JumpToSection),
+            404 => ::std::option::Option::Some(WorkspaceEvent::ReadDocumentStatistics),
+            405 => ::std::option::Option::Some(WorkspaceEvent::CheckDocumentIntegrity),
+            406 => ::std::option::Option::Some(WorkspaceEvent::ReadDocumentMergeBackups),
+            407 => ::std::option::Option::Some(WorkspaceEvent::RestoreDocumentMergeBackup),
+            408 => ::std::option::Option::Some(WorkspaceEvent::DeleteDocumentMergeBackup),
+            409 => ::std::option::Option::Some(WorkspaceEvent::ReadOpenDocumentsUsage),
             500 => ::std::option::Option::Some(WorkspaceEvent::ExportDocument),
+            600 => ::std::option::Option::Some(WorkspaceEvent::CreateTemplate),
+            601 => ::std::option::Option::Some(WorkspaceEvent::ReadTemplates),
+            602 => ::std::option::Option::Some(WorkspaceEvent::DeleteTemplate),
+            603 => ::std::option::Option::Some(WorkspaceEvent::CreateViewFromTemplate),
+            700 => ::std::option::Option::Some(WorkspaceEvent::OpenGrid),
+            701 => ::std::option::Option::Some(WorkspaceEvent::CreateField),
+            702 => ::std::option::Option::Some(WorkspaceEvent::UpdateField),
+            703 => ::std::option::Option::Some(WorkspaceEvent::DeleteField),
+            704 => ::std::option::Option::Some(WorkspaceEvent::CreateRow),
+            705 => ::std::option::Option::Some(WorkspaceEvent::UpdateRow),
+            706 => ::std::option::Option::Some(WorkspaceEvent::DeleteRow),
+            707 => ::std::option::Option::Some(WorkspaceEvent::UpdateCell),
+            708 => ::std::option::Option::Some(WorkspaceEvent::DeleteCell),
+            800 => ::std::option::Option::Some(WorkspaceEvent::OpenBoard),
+            801 => ::std::option::Option::Some(WorkspaceEvent::UpdateBoardProperty),
+            802 => ::std::option::Option::Some(WorkspaceEvent::CreateCard),
+            803 => ::std::option::Option::Some(WorkspaceEvent::UpdateCard),
+            804 => ::std::option::Option::Some(WorkspaceEvent::MoveCard),
+            805 => ::std::option::Option::Some(WorkspaceEvent::DeleteCard),
+            900 => ::std::option::Option::Some(WorkspaceEvent::CreateComment),
+            901 => ::std::option::Option::Some(WorkspaceEvent::ReplyComment),
+            902 => ::std::option::Option::Some(WorkspaceEvent::ResolveComment),
+            903 => ::std::option::Option::Some(WorkspaceEvent::ReadComments),
             _ => ::std::option::Option::None
         }
     }
@@ -96,10 +174,12 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             WorkspaceEvent::DeleteWorkspace,
             WorkspaceEvent::OpenWorkspace,
             WorkspaceEvent::ReadWorkspaceApps,
+            WorkspaceEvent::SetWorkspaceEncryption,
             WorkspaceEvent::CreateApp,
             WorkspaceEvent::DeleteApp,
             WorkspaceEvent::ReadApp,
             WorkspaceEvent::UpdateApp,
+            WorkspaceEvent::DuplicateApp,
             WorkspaceEvent::CreateView,
             WorkspaceEvent::ReadView,
             WorkspaceEvent::UpdateView,
@@ -108,13 +188,50 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             WorkspaceEvent::CopyLink,
             WorkspaceEvent::OpenDocument,
             WorkspaceEvent::CloseView,
+            WorkspaceEvent::UpdateViewTags,
+            WorkspaceEvent::UpdateViewProperty,
+            WorkspaceEvent::QueryViews,
             WorkspaceEvent::ReadTrash,
             WorkspaceEvent::PutbackTrash,
             WorkspaceEvent::DeleteTrash,
             WorkspaceEvent::RestoreAllTrash,
             WorkspaceEvent::DeleteAllTrash,
+            WorkspaceEvent::ReadTrashRetentionPolicy,
+            WorkspaceEvent::UpdateTrashRetentionPolicy,
             WorkspaceEvent::ApplyDocDelta,
+            WorkspaceEvent::HighlightCode,
+            WorkspaceEvent::ReadDocumentOutline,
+            WorkspaceEvent::JumpToSection,
+            WorkspaceEvent::ReadDocumentStatistics,
+            WorkspaceEvent::CheckDocumentIntegrity,
+            WorkspaceEvent::ReadDocumentMergeBackups,
+            WorkspaceEvent::RestoreDocumentMergeBackup,
+            WorkspaceEvent::DeleteDocumentMergeBackup,
+            WorkspaceEvent::ReadOpenDocumentsUsage,
             WorkspaceEvent::ExportDocument,
+            WorkspaceEvent::CreateTemplate,
+            WorkspaceEvent::ReadTemplates,
+            WorkspaceEvent::DeleteTemplate,
+            WorkspaceEvent::CreateViewFromTemplate,
+            WorkspaceEvent::OpenGrid,
+            WorkspaceEvent::CreateField,
+            WorkspaceEvent::UpdateField,
+            WorkspaceEvent::DeleteField,
+            WorkspaceEvent::CreateRow,
+            WorkspaceEvent::UpdateRow,
+            WorkspaceEvent::DeleteRow,
+            WorkspaceEvent::UpdateCell,
+            WorkspaceEvent::DeleteCell,
+            WorkspaceEvent::OpenBoard,
+            WorkspaceEvent::UpdateBoardProperty,
+            WorkspaceEvent::CreateCard,
+            WorkspaceEvent::UpdateCard,
+            WorkspaceEvent::MoveCard,
+            WorkspaceEvent::DeleteCard,
+            WorkspaceEvent::CreateComment,
+            WorkspaceEvent::ReplyComment,
+            WorkspaceEvent::ResolveComment,
+            WorkspaceEvent::ReadComments,
         ];
         values
     }
@@ -143,72 +260,165 @@ impl ::protobuf::reflect::ProtobufValue for WorkspaceEvent {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0bevent.proto*\xd9\x03\n\x0eWorkspaceEvent\x12\x13\n\x0fCreateWorksp\
-    ace\x10\0\x12\x14\n\x10ReadCurWorkspace\x10\x01\x12\x12\n\x0eReadWorkspa\
-    ces\x10\x02\x12\x13\n\x0fDeleteWorkspace\x10\x03\x12\x11\n\rOpenWorkspac\
-    e\x10\x04\x12\x15\n\x11ReadWorkspaceApps\x10\x05\x12\r\n\tCreateApp\x10e\
-    \x12\r\n\tDeleteApp\x10f\x12\x0b\n\x07ReadApp\x10g\x12\r\n\tUpdateApp\
-    \x10h\x12\x0f\n\nCreateView\x10\xc9\x01\x12\r\n\x08ReadView\x10\xca\x01\
+    \n\x0bevent.proto*\xac\n\n\x0eWorkspaceEvent\x12\x13\n\x0fCreateWorkspac\
+    e\x10\0\x12\x14\n\x10ReadCurWorkspace\x10\x01\x12\x12\n\x0eReadWorkspace\
+    s\x10\x02\x12\x13\n\x0fDeleteWorkspace\x10\x03\x12\x11\n\rOpenWorkspace\
+    \x10\x04\x12\x15\n\x11ReadWorkspaceApps\x10\x05\x12\x1a\n\x16SetWorkspac\
+    eEncryption\x10\x06\x12\r\n\tCreateApp\x10e\x12\r\n\tDeleteApp\x10f\x12\
+    \x0b\n\x07ReadApp\x10g\x12\r\n\tUpdateApp\x10h\x12\x10\n\x0cDuplicateApp\
+    \x10i\x12\x0f\n\nCreateView\x10\xc9\x01\x12\r\n\x08ReadView\x10\xca\x01\
     \x12\x0f\n\nUpdateView\x10\xcb\x01\x12\x0f\n\nDeleteView\x10\xcc\x01\x12\
     \x12\n\rDuplicateView\x10\xcd\x01\x12\r\n\x08CopyLink\x10\xce\x01\x12\
     \x11\n\x0cOpenDocument\x10\xcf\x01\x12\x0e\n\tCloseView\x10\xd0\x01\x12\
-    \x0e\n\tReadTrash\x10\xac\x02\x12\x11\n\x0cPutbackTrash\x10\xad\x02\x12\
-    \x10\n\x0bDeleteTrash\x10\xae\x02\x12\x14\n\x0fRestoreAllTrash\x10\xaf\
-    \x02\x12\x13\n\x0eDeleteAllTrash\x10\xb0\x02\x12\x12\n\rApplyDocDelta\
-    \x10\x90\x03\x12\x13\n\x0eExportDocument\x10\xf4\x03J\xab\x08\n\x06\x12\
-    \x04\0\0\x1c\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\
-    \x02\0\x1c\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x13\n\x0b\n\x04\x05\0\
-    \x02\0\x12\x03\x03\x04\x18\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\
-    \x13\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x16\x17\n\x0b\n\x04\x05\0\
-    \x02\x01\x12\x03\x04\x04\x19\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\
-    \x04\x14\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x04\x17\x18\n\x0b\n\x04\
-    \x05\0\x02\x02\x12\x03\x05\x04\x17\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\
-    \x05\x04\x12\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x05\x15\x16\n\x0b\n\
-    \x04\x05\0\x02\x03\x12\x03\x06\x04\x18\n\x0c\n\x05\x05\0\x02\x03\x01\x12\
-    \x03\x06\x04\x13\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x06\x16\x17\n\x0b\
-    \n\x04\x05\0\x02\x04\x12\x03\x07\x04\x16\n\x0c\n\x05\x05\0\x02\x04\x01\
-    \x12\x03\x07\x04\x11\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x07\x14\x15\n\
-    \x0b\n\x04\x05\0\x02\x05\x12\x03\x08\x04\x1a\n\x0c\n\x05\x05\0\x02\x05\
-    \x01\x12\x03\x08\x04\x15\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x08\x18\
-    \x19\n\x0b\n\x04\x05\0\x02\x06\x12\x03\t\x04\x14\n\x0c\n\x05\x05\0\x02\
-    \x06\x01\x12\x03\t\x04\r\n\x0c\n\x05\x05\0\x02\x06\x02\x12\x03\t\x10\x13\
-    \n\x0b\n\x04\x05\0\x02\x07\x12\x03\n\x04\x14\n\x0c\n\x05\x05\0\x02\x07\
-    \x01\x12\x03\n\x04\r\n\x0c\n\x05\x05\0\x02\x07\x02\x12\x03\n\x10\x13\n\
-    \x0b\n\x04\x05\0\x02\x08\x12\x03\x0b\x04\x12\n\x0c\n\x05\x05\0\x02\x08\
-    \x01\x12\x03\x0b\x04\x0b\n\x0c\n\x05\x05\0\x02\x08\x02\x12\x03\x0b\x0e\
-    \x11\n\x0b\n\x04\x05\0\x02\t\x12\x03\x0c\x04\x14\n\x0c\n\x05\x05\0\x02\t\
-    \x01\x12\x03\x0c\x04\r\n\x0c\n\x05\x05\0\x02\t\x02\x12\x03\x0c\x10\x13\n\
-    \x0b\n\x04\x05\0\x02\n\x12\x03\r\x04\x15\n\x0c\n\x05\x05\0\x02\n\x01\x12\
-    \x03\r\x04\x0e\n\x0c\n\x05\x05\0\x02\n\x02\x12\x03\r\x11\x14\n\x0b\n\x04\
-    \x05\0\x02\x0b\x12\x03\x0e\x04\x13\n\x0c\n\x05\x05\0\x02\x0b\x01\x12\x03\
-    \x0e\x04\x0c\n\x0c\n\x05\x05\0\x02\x0b\x02\x12\x03\x0e\x0f\x12\n\x0b\n\
-    \x04\x05\0\x02\x0c\x12\x03\x0f\x04\x15\n\x0c\n\x05\x05\0\x02\x0c\x01\x12\
-    \x03\x0f\x04\x0e\n\x0c\n\x05\x05\0\x02\x0c\x02\x12\x03\x0f\x11\x14\n\x0b\
-    \n\x04\x05\0\x02\r\x12\x03\x10\x04\x15\n\x0c\n\x05\x05\0\x02\r\x01\x12\
-    \x03\x10\x04\x0e\n\x0c\n\x05\x05\0\x02\r\x02\x12\x03\x10\x11\x14\n\x0b\n\
-    \x04\x05\0\x02\x0e\x12\x03\x11\x04\x18\n\x0c\n\x05\x05\0\x02\x0e\x01\x12\
-    \x03\x11\x04\x11\n\x0c\n\x05\x05\0\x02\x0e\x02\x12\x03\x11\x14\x17\n\x0b\
-    \n\x04\x05\0\x02\x0f\x12\x03\x12\x04\x13\n\x0c\n\x05\x05\0\x02\x0f\x01\
-    \x12\x03\x12\x04\x0c\n\x0c\n\x05\x05\0\x02\x0f\x02\x12\x03\x12\x0f\x12\n\
-    \x0b\n\x04\x05\0\x02\x10\x12\x03\x13\x04\x17\n\x0c\n\x05\x05\0\x02\x10\
-    \x01\x12\x03\x13\x04\x10\n\x0c\n\x05\x05\0\x02\x10\x02\x12\x03\x13\x13\
-    \x16\n\x0b\n\x04\x05\0\x02\x11\x12\x03\x14\x04\x14\n\x0c\n\x05\x05\0\x02\
-    \x11\x01\x12\x03\x14\x04\r\n\x0c\n\x05\x05\0\x02\x11\x02\x12\x03\x14\x10\
-    \x13\n\x0b\n\x04\x05\0\x02\x12\x12\x03\x15\x04\x14\n\x0c\n\x05\x05\0\x02\
-    \x12\x01\x12\x03\x15\x04\r\n\x0c\n\x05\x05\0\x02\x12\x02\x12\x03\x15\x10\
-    \x13\n\x0b\n\x04\x05\0\x02\x13\x12\x03\x16\x04\x17\n\x0c\n\x05\x05\0\x02\
-    \x13\x01\x12\x03\x16\x04\x10\n\x0c\n\x05\x05\0\x02\x13\x02\x12\x03\x16\
-    \x13\x16\n\x0b\n\x04\x05\0\x02\x14\x12\x03\x17\x04\x16\n\x0c\n\x05\x05\0\
-    \x02\x14\x01\x12\x03\x17\x04\x0f\n\x0c\n\x05\x05\0\x02\x14\x02\x12\x03\
-    \x17\x12\x15\n\x0b\n\x04\x05\0\x02\x15\x12\x03\x18\x04\x1a\n\x0c\n\x05\
-    \x05\0\x02\x15\x01\x12\x03\x18\x04\x13\n\x0c\n\x05\x05\0\x02\x15\x02\x12\
-    \x03\x18\x16\x19\n\x0b\n\x04\x05\0\x02\x16\x12\x03\x19\x04\x19\n\x0c\n\
-    \x05\x05\0\x02\x16\x01\x12\x03\x19\x04\x12\n\x0c\n\x05\x05\0\x02\x16\x02\
-    \x12\x03\x19\x15\x18\n\x0b\n\x04\x05\0\x02\x17\x12\x03\x1a\x04\x18\n\x0c\
-    \n\x05\x05\0\x02\x17\x01\x12\x03\x1a\x04\x11\n\x0c\n\x05\x05\0\x02\x17\
-    \x02\x12\x03\x1a\x14\x17\n\x0b\n\x04\x05\0\x02\x18\x12\x03\x1b\x04\x19\n\
-    \x0c\n\x05\x05\0\x02\x18\x01\x12\x03\x1b\x04\x12\n\x0c\n\x05\x05\0\x02\
-    \x18\x02\x12\x03\x1b\x15\x18b\x06proto3\
+    \x13\n\x0eUpdateViewTags\x10\xd1\x01\x12\x17\n\x12UpdateViewProperty\x10\
+    \xd2\x01\x12\x0f\n\nQueryViews\x10\xd3\x01\x12\x0e\n\tReadTrash\x10\xac\
+    \x02\x12\x11\n\x0cPutbackTrash\x10\xad\x02\x12\x10\n\x0bDeleteTrash\x10\
+    \xae\x02\x12\x14\n\x0fRestoreAllTrash\x10\xaf\x02\x12\x13\n\x0eDeleteAll\
+    Trash\x10\xb0\x02\x12\x1d\n\x18ReadTrashRetentionPolicy\x10\xb1\x02\x12\
+    \x1f\n\x1aUpdateTrashRetentionPolicy\x10\xb2\x02\x12\x12\n\rApplyDocDelt\
+    a\x10\x90\x03\x12\x12\n\rHighlightCode\x10\x91\x03\x12\x18\n\x13ReadDocu\
+    mentOutline\x10\x92\x03\x12\x12\n\rJumpToSection\x10\x93\x03\x12\x1b\n\
+    \x16ReadDocumentStatistics\x10\x94\x03\x12\x1b\n\x16CheckDocumentIntegri\
+    ty\x10\x95\x03\x12\x1d\n\x18ReadDocumentMergeBackups\x10\x96\x03\x12\x1f\
+    \n\x1aRestoreDocumentMergeBackup\x10\x97\x03\x12\x1e\n\x19DeleteDocument\
+    MergeBackup\x10\x98\x03\x12\x1b\n\x16ReadOpenDocumentsUsage\x10\x99\x03\
+    \x12\x13\n\x0eExportDocument\x10\xf4\x03\x12\x13\n\x0eCreateTemplate\x10\
+    \xd8\x04\x12\x12\n\rReadTemplates\x10\xd9\x04\x12\x13\n\x0eDeleteTemplat\
+    e\x10\xda\x04\x12\x1b\n\x16CreateViewFromTemplate\x10\xdb\x04\x12\r\n\
+    \x08OpenGrid\x10\xbc\x05\x12\x10\n\x0bCreateField\x10\xbd\x05\x12\x10\n\
+    \x0bUpdateField\x10\xbe\x05\x12\x10\n\x0bDeleteField\x10\xbf\x05\x12\x0e\
+    \n\tCreateRow\x10\xc0\x05\x12\x0e\n\tUpdateRow\x10\xc1\x05\x12\x0e\n\tDe\
+    leteRow\x10\xc2\x05\x12\x0f\n\nUpdateCell\x10\xc3\x05\x12\x0f\n\nDeleteC\
+    ell\x10\xc4\x05\x12\x0e\n\tOpenBoard\x10\xa0\x06\x12\x18\n\x13UpdateBoar\
+    dProperty\x10\xa1\x06\x12\x0f\n\nCreateCard\x10\xa2\x06\x12\x0f\n\nUpdat\
+    eCard\x10\xa3\x06\x12\r\n\x08MoveCard\x10\xa4\x06\x12\x0f\n\nDeleteCard\
+    \x10\xa5\x06\x12\x12\n\rCreateComment\x10\x84\x07\x12\x11\n\x0cReplyComm\
+    ent\x10\x85\x07\x12\x13\n\x0eResolveComment\x10\x86\x07\x12\x11\n\x0cRea\
+    dComments\x10\x87\x07J\xea\x14\n\x06\x12\x04\0\0C\x01\n\x08\n\x01\x0c\
+    \x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0C\x01\n\n\n\x03\x05\0\x01\
+    \x12\x03\x02\x05\x13\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x18\n\x0c\n\
+    \x05\x05\0\x02\0\x01\x12\x03\x03\x04\x13\n\x0c\n\x05\x05\0\x02\0\x02\x12\
+    \x03\x03\x16\x17\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\x19\n\x0c\n\
+    \x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x14\n\x0c\n\x05\x05\0\x02\x01\x02\
+    \x12\x03\x04\x17\x18\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x04\x17\n\x0c\
+    \n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\x12\n\x0c\n\x05\x05\0\x02\x02\
+    \x02\x12\x03\x05\x15\x16\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x06\x04\x18\n\
+    \x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x13\n\x0c\n\x05\x05\0\x02\
+    \x03\x02\x12\x03\x06\x16\x17\n\x0b\n\x04\x05\0\x02\x04\x12\x03\x07\x04\
+    \x16\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x11\n\x0c\n\x05\x05\0\
+    \x02\x04\x02\x12\x03\x07\x14\x15\n\x0b\n\x04\x05\0\x02\x05\x12\x03\x08\
+    \x04\x1a\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x08\x04\x15\n\x0c\n\x05\
+    \x05\0\x02\x05\x02\x12\x03\x08\x18\x19\n\x0b\n\x04\x05\0\x02\x06\x12\x03\
+    \t\x04\x1f\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\t\x04\x1a\n\x0c\n\x05\
+    \x05\0\x02\x06\x02\x12\x03\t\x1d\x1e\n\x0b\n\x04\x05\0\x02\x07\x12\x03\n\
+    \x04\x14\n\x0c\n\x05\x05\0\x02\x07\x01\x12\x03\n\x04\r\n\x0c\n\x05\x05\0\
+    \x02\x07\x02\x12\x03\n\x10\x13\n\x0b\n\x04\x05\0\x02\x08\x12\x03\x0b\x04\
+    \x14\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03\x0b\x04\r\n\x0c\n\x05\x05\0\
+    \x02\x08\x02\x12\x03\x0b\x10\x13\n\x0b\n\x04\x05\0\x02\t\x12\x03\x0c\x04\
+    \x12\n\x0c\n\x05\x05\0\x02\t\x01\x12\x03\x0c\x04\x0b\n\x0c\n\x05\x05\0\
+    \x02\t\x02\x12\x03\x0c\x0e\x11\n\x0b\n\x04\x05\0\x02\n\x12\x03\r\x04\x14\
+    \n\x0c\n\x05\x05\0\x02\n\x01\x12\x03\r\x04\r\n\x0c\n\x05\x05\0\x02\n\x02\
+    \x12\x03\r\x10\x13\n\x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04\x17\n\x0c\n\
+    \x05\x05\0\x02\x0b\x01\x12\x03\x0e\x04\x10\n\x0c\n\x05\x05\0\x02\x0b\x02\
+    \x12\x03\x0e\x13\x16\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\x04\x15\n\x0c\
+    \n\x05\x05\0\x02\x0c\x01\x12\x03\x0f\x04\x0e\n\x0c\n\x05\x05\0\x02\x0c\
+    \x02\x12\x03\x0f\x11\x14\n\x0b\n\x04\x05\0\x02\r\x12\x03\x10\x04\x13\n\
+    \x0c\n\x05\x05\0\x02\r\x01\x12\x03\x10\x04\x0c\n\x0c\n\x05\x05\0\x02\r\
+    \x02\x12\x03\x10\x0f\x12\n\x0b\n\x04\x05\0\x02\x0e\x12\x03\x11\x04\x15\n\
+    \x0c\n\x05\x05\0\x02\x0e\x01\x12\x03\x11\x04\x0e\n\x0c\n\x05\x05\0\x02\
+    \x0e\x02\x12\x03\x11\x11\x14\n\x0b\n\x04\x05\0\x02\x0f\x12\x03\x12\x04\
+    \x15\n\x0c\n\x05\x05\0\x02\x0f\x01\x12\x03\x12\x04\x0e\n\x0c\n\x05\x05\0\
+    \x02\x0f\x02\x12\x03\x12\x11\x14\n\x0b\n\x04\x05\0\x02\x10\x12\x03\x13\
+    \x04\x18\n\x0c\n\x05\x05\0\x02\x10\x01\x12\x03\x13\x04\x11\n\x0c\n\x05\
+    \x05\0\x02\x10\x02\x12\x03\x13\x14\x17\n\x0b\n\x04\x05\0\x02\x11\x12\x03\
+    \x14\x04\x13\n\x0c\n\x05\x05\0\x02\x11\x01\x12\x03\x14\x04\x0c\n\x0c\n\
+    \x05\x05\0\x02\x11\x02\x12\x03\x14\x0f\x12\n\x0b\n\x04\x05\0\x02\x12\x12\
+    \x03\x15\x04\x17\n\x0c\n\x05\x05\0\x02\x12\x01\x12\x03\x15\x04\x10\n\x0c\
+    \n\x05\x05\0\x02\x12\x02\x12\x03\x15\x13\x16\n\x0b\n\x04\x05\0\x02\x13\
+    \x12\x03\x16\x04\x14\n\x0c\n\x05\x05\0\x02\x13\x01\x12\x03\x16\x04\r\n\
+    \x0c\n\x05\x05\0\x02\x13\x02\x12\x03\x16\x10\x13\n\x0b\n\x04\x05\0\x02\
+    \x14\x12\x03\x17\x04\x19\n\x0c\n\x05\x05\0\x02\x14\x01\x12\x03\x17\x04\
+    \x12\n\x0c\n\x05\x05\0\x02\x14\x02\x12\x03\x17\x15\x18\n\x0b\n\x04\x05\0\
+    \x02\x15\x12\x03\x18\x04\x1d\n\x0c\n\x05\x05\0\x02\x15\x01\x12\x03\x18\
+    \x04\x16\n\x0c\n\x05\x05\0\x02\x15\x02\x12\x03\x18\x19\x1c\n\x0b\n\x04\
+    \x05\0\x02\x16\x12\x03\x19\x04\x15\n\x0c\n\x05\x05\0\x02\x16\x01\x12\x03\
+    \x19\x04\x0e\n\x0c\n\x05\x05\0\x02\x16\x02\x12\x03\x19\x11\x14\n\x0b\n\
+    \x04\x05\0\x02\x17\x12\x03\x1a\x04\x14\n\x0c\n\x05\x05\0\x02\x17\x01\x12\
+    \x03\x1a\x04\r\n\x0c\n\x05\x05\0\x02\x17\x02\x12\x03\x1a\x10\x13\n\x0b\n\
+    \x04\x05\0\x02\x18\x12\x03\x1b\x04\x17\n\x0c\n\x05\x05\0\x02\x18\x01\x12\
+    \x03\x1b\x04\x10\n\x0c\n\x05\x05\0\x02\x18\x02\x12\x03\x1b\x13\x16\n\x0b\
+    \n\x04\x05\0\x02\x19\x12\x03\x1c\x04\x16\n\x0c\n\x05\x05\0\x02\x19\x01\
+    \x12\x03\x1c\x04\x0f\n\x0c\n\x05\x05\0\x02\x19\x02\x12\x03\x1c\x12\x15\n\
+    \x0b\n\x04\x05\0\x02\x1a\x12\x03\x1d\x04\x1a\n\x0c\n\x05\x05\0\x02\x1a\
+    \x01\x12\x03\x1d\x04\x13\n\x0c\n\x05\x05\0\x02\x1a\x02\x12\x03\x1d\x16\
+    \x19\n\x0b\n\x04\x05\0\x02\x1b\x12\x03\x1e\x04\x19\n\x0c\n\x05\x05\0\x02\
+    \x1b\x01\x12\x03\x1e\x04\x12\n\x0c\n\x05\x05\0\x02\x1b\x02\x12\x03\x1e\
+    \x15\x18\n\x0b\n\x04\x05\0\x02\x1c\x12\x03\x1f\x04#\n\x0c\n\x05\x05\0\
+    \x02\x1c\x01\x12\x03\x1f\x04\x1c\n\x0c\n\x05\x05\0\x02\x1c\x02\x12\x03\
+    \x1f\x1f\"\n\x0b\n\x04\x05\0\x02\x1d\x12\x03\x20\x04%\n\x0c\n\x05\x05\0\
+    \x02\x1d\x01\x12\x03\x20\x04\x1e\n\x0c\n\x05\x05\0\x02\x1d\x02\x12\x03\
+    \x20!$\n\x0b\n\x04\x05\0\x02\x1e\x12\x03!\x04\x18\n\x0c\n\x05\x05\0\x02\
+    \x1e\x01\x12\x03!\x04\x11\n\x0c\n\x05\x05\0\x02\x1e\x02\x12\x03!\x14\x17\
+    \n\x0b\n\x04\x05\0\x02\x1f\x12\x03\"\x04\x18\n\x0c\n\x05\x05\0\x02\x1f\
+    \x01\x12\x03\"\x04\x11\n\x0c\n\x05\x05\0\x02\x1f\x02\x12\x03\"\x14\x17\n\
+    \x0b\n\x04\x05\0\x02\x20\x12\x03#\x04\x1e\n\x0c\n\x05\x05\0\x02\x20\x01\
+    \x12\x03#\x04\x17\n\x0c\n\x05\x05\0\x02\x20\x02\x12\x03#\x1a\x1d\n\x0b\n\
+    \x04\x05\0\x02!\x12\x03$\x04\x18\n\x0c\n\x05\x05\0\x02!\x01\x12\x03$\x04\
+    \x11\n\x0c\n\x05\x05\0\x02!\x02\x12\x03$\x14\x17\n\x0b\n\x04\x05\0\x02\"\
+    \x12\x03%\x04!\n\x0c\n\x05\x05\0\x02\"\x01\x12\x03%\x04\x1a\n\x0c\n\x05\
+    \x05\0\x02\"\x02\x12\x03%\x1d\x20\n\x0b\n\x04\x05\0\x02#\x12\x03&\x04!\n\
+    \x0c\n\x05\x05\0\x02#\x01\x12\x03&\x04\x1a\n\x0c\n\x05\x05\0\x02#\x02\
+    \x12\x03&\x1d\x20\n\x0b\n\x04\x05\0\x02$\x12\x03'\x04#\n\x0c\n\x05\x05\0\
+    \x02$\x01\x12\x03'\x04\x1c\n\x0c\n\x05\x05\0\x02$\x02\x12\x03'\x1f\"\n\
+    \x0b\n\x04\x05\0\x02%\x12\x03(\x04%\n\x0c\n\x05\x05\0\x02%\x01\x12\x03(\
+    \x04\x1e\n\x0c\n\x05\x05\0\x02%\x02\x12\x03(!$\n\x0b\n\x04\x05\0\x02&\
+    \x12\x03)\x04$\n\x0c\n\x05\x05\0\x02&\x01\x12\x03)\x04\x1d\n\x0c\n\x05\
+    \x05\0\x02&\x02\x12\x03)\x20#\n\x0b\n\x04\x05\0\x02'\x12\x03*\x04!\n\x0c\
+    \n\x05\x05\0\x02'\x01\x12\x03*\x04\x1a\n\x0c\n\x05\x05\0\x02'\x02\x12\
+    \x03*\x1d\x20\n\x0b\n\x04\x05\0\x02(\x12\x03+\x04\x19\n\x0c\n\x05\x05\0\
+    \x02(\x01\x12\x03+\x04\x12\n\x0c\n\x05\x05\0\x02(\x02\x12\x03+\x15\x18\n\
+    \x0b\n\x04\x05\0\x02)\x12\x03,\x04\x19\n\x0c\n\x05\x05\0\x02)\x01\x12\
+    \x03,\x04\x12\n\x0c\n\x05\x05\0\x02)\x02\x12\x03,\x15\x18\n\x0b\n\x04\
+    \x05\0\x02*\x12\x03-\x04\x18\n\x0c\n\x05\x05\0\x02*\x01\x12\x03-\x04\x11\
+    \n\x0c\n\x05\x05\0\x02*\x02\x12\x03-\x14\x17\n\x0b\n\x04\x05\0\x02+\x12\
+    \x03.\x04\x19\n\x0c\n\x05\x05\0\x02+\x01\x12\x03.\x04\x12\n\x0c\n\x05\
+    \x05\0\x02+\x02\x12\x03.\x15\x18\n\x0b\n\x04\x05\0\x02,\x12\x03/\x04!\n\
+    \x0c\n\x05\x05\0\x02,\x01\x12\x03/\x04\x1a\n\x0c\n\x05\x05\0\x02,\x02\
+    \x12\x03/\x1d\x20\n\x0b\n\x04\x05\0\x02-\x12\x030\x04\x13\n\x0c\n\x05\
+    \x05\0\x02-\x01\x12\x030\x04\x0c\n\x0c\n\x05\x05\0\x02-\x02\x12\x030\x0f\
+    \x12\n\x0b\n\x04\x05\0\x02.\x12\x031\x04\x16\n\x0c\n\x05\x05\0\x02.\x01\
+    \x12\x031\x04\x0f\n\x0c\n\x05\x05\0\x02.\x02\x12\x031\x12\x15\n\x0b\n\
+    \x04\x05\0\x02/\x12\x032\x04\x16\n\x0c\n\x05\x05\0\x02/\x01\x12\x032\x04\
+    \x0f\n\x0c\n\x05\x05\0\x02/\x02\x12\x032\x12\x15\n\x0b\n\x04\x05\0\x020\
+    \x12\x033\x04\x16\n\x0c\n\x05\x05\0\x020\x01\x12\x033\x04\x0f\n\x0c\n\
+    \x05\x05\0\x020\x02\x12\x033\x12\x15\n\x0b\n\x04\x05\0\x021\x12\x034\x04\
+    \x14\n\x0c\n\x05\x05\0\x021\x01\x12\x034\x04\r\n\x0c\n\x05\x05\0\x021\
+    \x02\x12\x034\x10\x13\n\x0b\n\x04\x05\0\x022\x12\x035\x04\x14\n\x0c\n\
+    \x05\x05\0\x022\x01\x12\x035\x04\r\n\x0c\n\x05\x05\0\x022\x02\x12\x035\
+    \x10\x13\n\x0b\n\x04\x05\0\x023\x12\x036\x04\x14\n\x0c\n\x05\x05\0\x023\
+    \x01\x12\x036\x04\r\n\x0c\n\x05\x05\0\x023\x02\x12\x036\x10\x13\n\x0b\n\
+    \x04\x05\0\x024\x12\x037\x04\x15\n\x0c\n\x05\x05\0\x024\x01\x12\x037\x04\
+    \x0e\n\x0c\n\x05\x05\0\x024\x02\x12\x037\x11\x14\n\x0b\n\x04\x05\0\x025\
+    \x12\x038\x04\x15\n\x0c\n\x05\x05\0\x025\x01\x12\x038\x04\x0e\n\x0c\n\
+    \x05\x05\0\x025\x02\x12\x038\x11\x14\n\x0b\n\x04\x05\0\x026\x12\x039\x04\
+    \x14\n\x0c\n\x05\x05\0\x026\x01\x12\x039\x04\r\n\x0c\n\x05\x05\0\x026\
+    \x02\x12\x039\x10\x13\n\x0b\n\x04\x05\0\x027\x12\x03:\x04\x1e\n\x0c\n\
+    \x05\x05\0\x027\x01\x12\x03:\x04\x17\n\x0c\n\x05\x05\0\x027\x02\x12\x03:\
+    \x1a\x1d\n\x0b\n\x04\x05\0\x028\x12\x03;\x04\x15\n\x0c\n\x05\x05\0\x028\
+    \x01\x12\x03;\x04\x0e\n\x0c\n\x05\x05\0\x028\x02\x12\x03;\x11\x14\n\x0b\
+    \n\x04\x05\0\x029\x12\x03<\x04\x15\n\x0c\n\x05\x05\0\x029\x01\x12\x03<\
+    \x04\x0e\n\x0c\n\x05\x05\0\x029\x02\x12\x03<\x11\x14\n\x0b\n\x04\x05\0\
+    \x02:\x12\x03=\x04\x13\n\x0c\n\x05\x05\0\x02:\x01\x12\x03=\x04\x0c\n\x0c\
+    \n\x05\x05\0\x02:\x02\x12\x03=\x0f\x12\n\x0b\n\x04\x05\0\x02;\x12\x03>\
+    \x04\x15\n\x0c\n\x05\x05\0\x02;\x01\x12\x03>\x04\x0e\n\x0c\n\x05\x05\0\
+    \x02;\x02\x12\x03>\x11\x14\n\x0b\n\x04\x05\0\x02<\x12\x03?\x04\x18\n\x0c\
+    \n\x05\x05\0\x02<\x01\x12\x03?\x04\x11\n\x0c\n\x05\x05\0\x02<\x02\x12\
+    \x03?\x14\x17\n\x0b\n\x04\x05\0\x02=\x12\x03@\x04\x17\n\x0c\n\x05\x05\0\
+    \x02=\x01\x12\x03@\x04\x10\n\x0c\n\x05\x05\0\x02=\x02\x12\x03@\x13\x16\n\
+    \x0b\n\x04\x05\0\x02>\x12\x03A\x04\x19\n\x0c\n\x05\x05\0\x02>\x01\x12\
+    \x03A\x04\x12\n\x0c\n\x05\x05\0\x02>\x02\x12\x03A\x15\x18\n\x0b\n\x04\
+    \x05\0\x02?\x12\x03B\x04\x17\n\x0c\n\x05\x05\0\x02?\x01\x12\x03B\x04\x10\
+    \n\x0c\n\x05\x05\0\x02?\x02\x12\x03B\x13\x16b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
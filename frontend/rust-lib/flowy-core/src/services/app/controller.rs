@@ -95,6 +95,30 @@ impl AppController {
         Ok(())
     }
 
+    // Creates a copy of the app without the views. The views are duplicated by the ViewController.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn duplicate_app(&self, app_id: &str) -> Result<App, FlowyError> {
+        let app = self
+            .persistence
+            .begin_transaction(|transaction| {
+                let app = transaction.read_app(app_id)?;
+                let trash_ids = self.trash_controller.read_trash_ids(&transaction)?;
+                if trash_ids.contains(&app.id) {
+                    return Err(FlowyError::record_not_found());
+                }
+                Ok(app)
+            })
+            .await?;
+
+        let params = CreateAppParams {
+            workspace_id: app.workspace_id,
+            name: format!("{} (copy)", &app.name),
+            desc: app.desc,
+            color_style: app.color_style,
+        };
+        self.create_app_from_params(params).await
+    }
+
     pub(crate) async fn read_local_apps(&self, ids: Vec<String>) -> Result<Vec<App>, FlowyError> {
         let apps = self
             .persistence
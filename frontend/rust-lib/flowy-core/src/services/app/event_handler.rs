@@ -36,6 +36,20 @@ pub(crate) async fn delete_app_handler(
     Ok(())
 }
 
+#[tracing::instrument(skip(data, app_controller, view_controller), err)]
+pub(crate) async fn duplicate_app_handler(
+    data: Data<QueryAppRequest>,
+    app_controller: Unit<Arc<AppController>>,
+    view_controller: Unit<Arc<ViewController>>,
+) -> DataResult<App, FlowyError> {
+    let params: AppId = data.into_inner().try_into()?;
+    let mut app = app_controller.duplicate_app(&params.app_id).await?;
+    let _ = view_controller.duplicate_belongings(&params.app_id, &app.id).await?;
+    app.belongings = view_controller.read_views_belong_to(&app.id).await?;
+
+    data_result(app)
+}
+
 #[tracing::instrument(skip(data, controller))]
 pub(crate) async fn update_app_handler(
     data: Data<UpdateAppRequest>,
@@ -0,0 +1,231 @@
+use crate::{
+    dart_notification::{send_dart_notification, WorkspaceNotification},
+    entities::board::{
+        Board, BoardProperty, Card, CardIdentifier, CreateCardParams, MoveCardParams, UpdateBoardPropertyParams,
+        UpdateCardParams,
+    },
+    errors::{ErrorCode, FlowyError, FlowyResult},
+    module::WorkspaceUser,
+    services::{persistence::FolderPersistence, plain_text_editor::PlainTextEditor, BOARD_SYNC_INTERVAL_IN_MILLIS},
+};
+use bytes::Bytes;
+use flowy_collaboration::{
+    board::{default_board_delta, BoardChange, BoardPad},
+    entities::{
+        revision::{RepeatedRevision, Revision},
+        ws_data::ServerRevisionWSData,
+    },
+};
+use flowy_sync::{RevisionCache, RevisionManager, RevisionWebSocket};
+use lib_infra::uuid_string;
+use parking_lot::RwLock;
+use std::{collections::HashMap, convert::TryInto, sync::Arc, time::Duration};
+
+type BoardEditor = PlainTextEditor<BoardPad>;
+
+pub(crate) struct BoardController {
+    user: Arc<dyn WorkspaceUser>,
+    persistence: Arc<FolderPersistence>,
+    web_socket: Arc<dyn RevisionWebSocket>,
+    editors: RwLock<HashMap<String, Arc<BoardEditor>>>,
+}
+
+impl BoardController {
+    pub(crate) fn new(
+        user: Arc<dyn WorkspaceUser>,
+        persistence: Arc<FolderPersistence>,
+        web_socket: Arc<dyn RevisionWebSocket>,
+    ) -> Self {
+        Self {
+            user,
+            persistence,
+            web_socket,
+            editors: RwLock::new(HashMap::new()),
+        }
+    }
+
+    // Saves the initial revision of the board. The board_data is the json of the board delta, and
+    // a board with the default property is created if it's empty.
+    #[tracing::instrument(level = "debug", skip(self, board_data), err)]
+    pub(crate) async fn create_board(&self, board_id: &str, board_data: String) -> Result<(), FlowyError> {
+        let board_data = if board_data.is_empty() {
+            default_board_delta().to_json()
+        } else {
+            board_data
+        };
+
+        let user_id = self.user.user_id()?;
+        let repeated_revision: RepeatedRevision =
+            Revision::initial_revision(&user_id, board_id, Bytes::from(board_data)).into();
+        let cache = Arc::new(RevisionCache::new(&user_id, board_id, self.persistence.db_pool()?));
+        let rev_manager = RevisionManager::new(&user_id, board_id, cache);
+        let _ = rev_manager.reset_object(repeated_revision).await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn open_board(&self, board_id: &str) -> Result<Board, FlowyError> {
+        let editor = self.get_editor(board_id).await?;
+        Ok(editor.read(|board| board.board(board_id)))
+    }
+
+    // Returns the json of the board delta that can be used to create a copy of the board.
+    pub(crate) async fn read_board_data(&self, board_id: &str) -> Result<String, FlowyError> {
+        let editor = self.get_editor(board_id).await?;
+        Ok(editor.delta_json())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn close_board(&self, board_id: &str) -> Result<(), FlowyError> {
+        if let Some(editor) = self.editors.write().remove(board_id) {
+            editor.stop();
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn update_property(&self, params: UpdateBoardPropertyParams) -> Result<Board, FlowyError> {
+        let UpdateBoardPropertyParams { board_id, property } = params;
+        let editor = self
+            .modify(&board_id, |board| Ok(board.update_property(property)?))
+            .await?;
+        Ok(editor.read(|board| board.board(&board_id)))
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn create_card(&self, params: CreateCardParams) -> Result<Card, FlowyError> {
+        let card = Card {
+            id: uuid_string(),
+            title: params.title,
+            ..Default::default()
+        };
+        let card_id = card.id.clone();
+        let editor = self
+            .modify(&params.board_id, |board| {
+                let _ = validate_column(&board.property(), &params.column)?;
+                Ok(board.create_card(card, &params.column)?)
+            })
+            .await?;
+
+        let card = editor.read(|board| board.read_card(&card_id));
+        card.ok_or_else(FlowyError::record_not_found)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn update_card(&self, params: UpdateCardParams) -> Result<Card, FlowyError> {
+        let UpdateCardParams {
+            board_id,
+            card_id,
+            title,
+            description,
+            properties,
+        } = params;
+        let editor = self
+            .modify(&board_id, |board| {
+                let _ = read_card(board, &card_id)?;
+                let property = board.property();
+                for card_property in properties.iter().filter(|p| p.key == property.key) {
+                    let _ = validate_column(&property, &card_property.value)?;
+                }
+                Ok(board.update_card(&card_id, title, description, properties)?)
+            })
+            .await?;
+
+        let card = editor.read(|board| board.read_card(&card_id));
+        card.ok_or_else(FlowyError::record_not_found)
+    }
+
+    // Moving the card is a single revision, no matter whether the column is renumbered or not.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn move_card(&self, params: MoveCardParams) -> Result<Board, FlowyError> {
+        let editor = self
+            .modify(&params.board_id, |board| {
+                let _ = read_card(board, &params.card_id)?;
+                let _ = validate_column(&board.property(), &params.column)?;
+                Ok(board.move_card(&params.card_id, &params.column, params.after_card_id.as_deref())?)
+            })
+            .await?;
+        Ok(editor.read(|board| board.board(&params.board_id)))
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn delete_card(&self, params: CardIdentifier) -> Result<(), FlowyError> {
+        let _ = self
+            .modify(&params.board_id, |board| Ok(board.delete_card(&params.card_id)?))
+            .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn did_receive_ws_data(&self, data: Bytes) {
+        let result: Result<ServerRevisionWSData, protobuf::ProtobufError> = data.try_into();
+        match result {
+            Ok(data) => {
+                let editor = self.editors.read().get(&data.object_id).cloned();
+                match editor {
+                    None => tracing::error!("Can't find any board editor for {:?}-{:?}", data.object_id, data.ty),
+                    Some(editor) => match editor.receive_ws_data(data).await {
+                        Ok(_) => {}
+                        Err(e) => tracing::error!("Board receive data error: {:?}", e),
+                    },
+                }
+            }
+            Err(e) => {
+                tracing::error!("Board ws data parser failed: {:?}", e);
+            }
+        }
+    }
+}
+
+impl BoardController {
+    async fn get_editor(&self, board_id: &str) -> FlowyResult<Arc<BoardEditor>> {
+        if let Some(editor) = self.editors.read().get(board_id).cloned() {
+            return Ok(editor);
+        }
+
+        let user_id = self.user.user_id()?;
+        let pool = self.persistence.db_pool()?;
+        let ping_duration = Duration::from_millis(BOARD_SYNC_INTERVAL_IN_MILLIS);
+        let editor = BoardEditor::new(
+            "Board",
+            &user_id,
+            board_id,
+            pool,
+            self.web_socket.clone(),
+            ping_duration,
+        )
+        .await?;
+        let editor = Arc::new(editor);
+        self.editors.write().insert(board_id.to_owned(), editor.clone());
+        Ok(editor)
+    }
+
+    // Applies the change to the board and notifies the new board if something changed.
+    async fn modify<F>(&self, board_id: &str, f: F) -> FlowyResult<Arc<BoardEditor>>
+    where
+        F: FnOnce(&mut BoardPad) -> FlowyResult<Option<BoardChange>>,
+    {
+        let editor = self.get_editor(board_id).await?;
+        if editor.modify(f).await? {
+            send_dart_notification(board_id, WorkspaceNotification::BoardUpdated)
+                .payload(editor.read(|board| board.board(board_id)))
+                .send();
+        }
+        Ok(editor)
+    }
+}
+
+fn read_card(board: &BoardPad, card_id: &str) -> FlowyResult<Card> {
+    board
+        .read_card(card_id)
+        .ok_or_else(|| FlowyError::record_not_found().context(format!("Can't find the card: {}", card_id)))
+}
+
+// The empty column contains the cards that don't belong to any option of the property.
+fn validate_column(property: &BoardProperty, column: &str) -> FlowyResult<()> {
+    if column.is_empty() || property.options.iter().any(|option| option == column) {
+        Ok(())
+    } else {
+        Err(FlowyError::from(ErrorCode::BoardColumnInvalid)
+            .context(format!("{} is not the option of {}", column, property.key)))
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    entities::board::{
+        Board, BoardId, Card, CardIdentifier, CreateCardParams, CreateCardRequest, MoveCardParams, MoveCardRequest,
+        QueryBoardRequest, QueryCardRequest, UpdateBoardPropertyParams, UpdateBoardPropertyRequest, UpdateCardParams,
+        UpdateCardRequest,
+    },
+    errors::FlowyError,
+    services::BoardController,
+};
+use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn open_board_handler(
+    data: Data<QueryBoardRequest>,
+    controller: Unit<Arc<BoardController>>,
+) -> DataResult<Board, FlowyError> {
+    let params: BoardId = data.into_inner().try_into()?;
+    let board = controller.open_board(&params.board_id).await?;
+    data_result(board)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_board_property_handler(
+    data: Data<UpdateBoardPropertyRequest>,
+    controller: Unit<Arc<BoardController>>,
+) -> DataResult<Board, FlowyError> {
+    let params: UpdateBoardPropertyParams = data.into_inner().try_into()?;
+    let board = controller.update_property(params).await?;
+    data_result(board)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_card_handler(
+    data: Data<CreateCardRequest>,
+    controller: Unit<Arc<BoardController>>,
+) -> DataResult<Card, FlowyError> {
+    let params: CreateCardParams = data.into_inner().try_into()?;
+    let card = controller.create_card(params).await?;
+    data_result(card)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_card_handler(
+    data: Data<UpdateCardRequest>,
+    controller: Unit<Arc<BoardController>>,
+) -> DataResult<Card, FlowyError> {
+    let params: UpdateCardParams = data.into_inner().try_into()?;
+    let card = controller.update_card(params).await?;
+    data_result(card)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn move_card_handler(
+    data: Data<MoveCardRequest>,
+    controller: Unit<Arc<BoardController>>,
+) -> DataResult<Board, FlowyError> {
+    let params: MoveCardParams = data.into_inner().try_into()?;
+    let board = controller.move_card(params).await?;
+    data_result(board)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_card_handler(
+    data: Data<QueryCardRequest>,
+    controller: Unit<Arc<BoardController>>,
+) -> Result<(), FlowyError> {
+    let params: CardIdentifier = data.into_inner().try_into()?;
+    let _ = controller.delete_card(params).await?;
+    Ok(())
+}
@@ -0,0 +1,229 @@
+use crate::{
+    dart_notification::{send_dart_notification, WorkspaceNotification},
+    entities::comment::{
+        Comment, CommentThread, CreateCommentParams, QueryCommentsParams, RepeatedCommentThread, ReplyCommentParams,
+        ResolveCommentParams,
+    },
+    errors::{ErrorCode, FlowyError, FlowyResult},
+    module::WorkspaceUser,
+    services::{persistence::FolderPersistence, plain_text_editor::PlainTextEditor, COMMENT_SYNC_INTERVAL_IN_MILLIS},
+};
+use bytes::Bytes;
+use flowy_collaboration::{
+    client_document::ClientDocument,
+    comment::{comments_object_id, CommentChange, CommentPad},
+    entities::ws_data::ServerRevisionWSData,
+};
+use flowy_document::FlowyDocumentManager;
+use flowy_sync::RevisionWebSocket;
+use lib_infra::{timestamp, uuid_string};
+use lib_ot::core::Interval;
+use parking_lot::RwLock;
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryInto,
+    sync::Arc,
+    time::Duration,
+};
+
+// The comment threads of a document are synced as a separate object whose id is made by the
+// comments_object_id, so the revisions of the document are not touched by the comments.
+type CommentEditor = PlainTextEditor<CommentPad>;
+
+// The threads are anchored to the document by the comment attribute of the text, so the anchors
+// are read from the document every time the threads are returned. A thread whose text was deleted
+// is marked as orphaned instead of being removed.
+pub(crate) struct CommentController {
+    user: Arc<dyn WorkspaceUser>,
+    persistence: Arc<FolderPersistence>,
+    web_socket: Arc<dyn RevisionWebSocket>,
+    document_manager: Arc<FlowyDocumentManager>,
+    editors: RwLock<HashMap<String, Arc<CommentEditor>>>,
+}
+
+impl CommentController {
+    pub(crate) fn new(
+        user: Arc<dyn WorkspaceUser>,
+        persistence: Arc<FolderPersistence>,
+        web_socket: Arc<dyn RevisionWebSocket>,
+        document_manager: Arc<FlowyDocumentManager>,
+    ) -> Self {
+        Self {
+            user,
+            persistence,
+            web_socket,
+            document_manager,
+            editors: RwLock::new(HashMap::new()),
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn create_comment(&self, params: CreateCommentParams) -> Result<CommentThread, FlowyError> {
+        let thread = CommentThread {
+            id: uuid_string(),
+            doc_id: params.doc_id.clone(),
+            author: self.user.user_name()?,
+            content: params.content,
+            create_time: timestamp(),
+            ..Default::default()
+        };
+
+        let interval = Interval::new(params.start as usize, params.end as usize);
+        let document_editor = self.document_manager.open_document(&params.doc_id).await?;
+        let _ = document_editor
+            .add_comment_anchor(interval, &thread.id)
+            .await
+            .map_err(|e| FlowyError::from(ErrorCode::CommentRangeInvalid).context(e))?;
+
+        let _ = self
+            .modify(&params.doc_id, |comments| Ok(comments.create_thread(&thread)?))
+            .await?;
+        self.read_thread(&params.doc_id, &thread.id).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn reply_comment(&self, params: ReplyCommentParams) -> Result<CommentThread, FlowyError> {
+        let comment = Comment {
+            id: uuid_string(),
+            thread_id: params.thread_id.clone(),
+            author: self.user.user_name()?,
+            content: params.content,
+            create_time: timestamp(),
+        };
+        let _ = self
+            .modify(&params.doc_id, |comments| Ok(comments.reply(comment)?))
+            .await?;
+        self.read_thread(&params.doc_id, &params.thread_id).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn resolve_comment(&self, params: ResolveCommentParams) -> Result<CommentThread, FlowyError> {
+        let _ = self
+            .modify(&params.doc_id, |comments| {
+                Ok(comments.resolve_thread(&params.thread_id, params.resolved)?)
+            })
+            .await?;
+        self.read_thread(&params.doc_id, &params.thread_id).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn read_comments(&self, params: QueryCommentsParams) -> Result<RepeatedCommentThread, FlowyError> {
+        let (_, threads) = self.sync_anchors(&params.doc_id).await?;
+        Ok(RepeatedCommentThread { items: threads })
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn close_comments(&self, doc_id: &str) -> Result<(), FlowyError> {
+        if let Some(editor) = self.editors.write().remove(doc_id) {
+            editor.stop();
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn did_receive_ws_data(&self, data: Bytes) {
+        let result: Result<ServerRevisionWSData, protobuf::ProtobufError> = data.try_into();
+        match result {
+            Ok(data) => {
+                let editor = self
+                    .editors
+                    .read()
+                    .values()
+                    .find(|editor| editor.object_id == data.object_id)
+                    .cloned();
+                match editor {
+                    None => tracing::error!("Can't find any comment editor for {:?}-{:?}", data.object_id, data.ty),
+                    Some(editor) => match editor.receive_ws_data(data).await {
+                        Ok(_) => {}
+                        Err(e) => tracing::error!("Comment receive data error: {:?}", e),
+                    },
+                }
+            }
+            Err(e) => {
+                tracing::error!("Comment ws data parser failed: {:?}", e);
+            }
+        }
+    }
+}
+
+impl CommentController {
+    async fn get_editor(&self, doc_id: &str) -> FlowyResult<Arc<CommentEditor>> {
+        if let Some(editor) = self.editors.read().get(doc_id).cloned() {
+            return Ok(editor);
+        }
+
+        let user_id = self.user.user_id()?;
+        let pool = self.persistence.db_pool()?;
+        let object_id = comments_object_id(doc_id);
+        let ping_duration = Duration::from_millis(COMMENT_SYNC_INTERVAL_IN_MILLIS);
+        let editor = CommentEditor::new(
+            "Comment",
+            &user_id,
+            &object_id,
+            pool,
+            self.web_socket.clone(),
+            ping_duration,
+        );
+        let editor = Arc::new(editor.await?);
+        self.editors.write().insert(doc_id.to_owned(), editor.clone());
+        Ok(editor)
+    }
+
+    async fn read_thread(&self, doc_id: &str, thread_id: &str) -> FlowyResult<CommentThread> {
+        let (_, threads) = self.sync_anchors(doc_id).await?;
+        threads
+            .into_iter()
+            .find(|thread| thread.id == thread_id)
+            .ok_or_else(|| FlowyError::record_not_found().context(format!("Can't find the comment: {}", thread_id)))
+    }
+
+    // Applies the change to the comments, then syncs the orphaned flags with the document and
+    // notifies the threads if something changed.
+    async fn modify<F>(&self, doc_id: &str, f: F) -> FlowyResult<()>
+    where
+        F: FnOnce(&mut CommentPad) -> FlowyResult<Option<CommentChange>>,
+    {
+        let editor = self.get_editor(doc_id).await?;
+        let is_changed = editor.modify(f).await?;
+        let (is_orphaned_changed, threads) = self.sync_anchors(doc_id).await?;
+        if is_changed && !is_orphaned_changed {
+            notify_threads(doc_id, threads);
+        }
+        Ok(())
+    }
+
+    // Reads the anchors from the document and marks the threads that lost their text as orphaned.
+    // Returns the threads with their anchors, and whether the orphaned flags were changed.
+    async fn sync_anchors(&self, doc_id: &str) -> FlowyResult<(bool, Vec<CommentThread>)> {
+        let editor = self.get_editor(doc_id).await?;
+        let document_editor = self.document_manager.open_document(doc_id).await?;
+        let document_json = document_editor.document_json().await?;
+        let anchors = ClientDocument::from_json(&document_json)?.comment_anchors();
+        let anchored_ids = anchors.keys().cloned().collect::<HashSet<String>>();
+        let is_changed = editor
+            .modify(|comments| Ok(comments.update_orphaned(&anchored_ids)?))
+            .await?;
+
+        let threads = editor
+            .read(|comments| comments.threads(doc_id))
+            .into_iter()
+            .map(|mut thread| {
+                if let Some(interval) = anchors.get(&thread.id) {
+                    thread.anchor_start = interval.start as i64;
+                    thread.anchor_end = interval.end as i64;
+                }
+                thread
+            })
+            .collect::<Vec<CommentThread>>();
+
+        if is_changed {
+            notify_threads(doc_id, threads.clone());
+        }
+        Ok((is_changed, threads))
+    }
+}
+
+fn notify_threads(doc_id: &str, threads: Vec<CommentThread>) {
+    send_dart_notification(doc_id, WorkspaceNotification::CommentsUpdated)
+        .payload(RepeatedCommentThread { items: threads })
+        .send();
+}
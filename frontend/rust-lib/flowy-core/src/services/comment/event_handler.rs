@@ -0,0 +1,50 @@
+use crate::{
+    entities::comment::{
+        CommentThread, CreateCommentParams, CreateCommentRequest, QueryCommentsParams, QueryCommentsRequest,
+        RepeatedCommentThread, ReplyCommentParams, ReplyCommentRequest, ResolveCommentParams, ResolveCommentRequest,
+    },
+    errors::FlowyError,
+    services::CommentController,
+};
+use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_comment_handler(
+    data: Data<CreateCommentRequest>,
+    controller: Unit<Arc<CommentController>>,
+) -> DataResult<CommentThread, FlowyError> {
+    let params: CreateCommentParams = data.into_inner().try_into()?;
+    let thread = controller.create_comment(params).await?;
+    data_result(thread)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn reply_comment_handler(
+    data: Data<ReplyCommentRequest>,
+    controller: Unit<Arc<CommentController>>,
+) -> DataResult<CommentThread, FlowyError> {
+    let params: ReplyCommentParams = data.into_inner().try_into()?;
+    let thread = controller.reply_comment(params).await?;
+    data_result(thread)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn resolve_comment_handler(
+    data: Data<ResolveCommentRequest>,
+    controller: Unit<Arc<CommentController>>,
+) -> DataResult<CommentThread, FlowyError> {
+    let params: ResolveCommentParams = data.into_inner().try_into()?;
+    let thread = controller.resolve_comment(params).await?;
+    data_result(thread)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_comments_handler(
+    data: Data<QueryCommentsRequest>,
+    controller: Unit<Arc<CommentController>>,
+) -> DataResult<RepeatedCommentThread, FlowyError> {
+    let params: QueryCommentsParams = data.into_inner().try_into()?;
+    let threads = controller.read_comments(params).await?;
+    data_result(threads)
+}
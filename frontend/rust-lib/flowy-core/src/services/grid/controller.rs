@@ -0,0 +1,257 @@
+use crate::{
+    dart_notification::{send_dart_notification, WorkspaceNotification},
+    entities::grid::{
+        Cell, CellIdentifier, CreateFieldParams, Field, FieldIdentifier, Grid, Row, RowIdentifier, UpdateCellParams,
+        UpdateFieldParams, UpdateRowParams,
+    },
+    errors::{FlowyError, FlowyResult},
+    module::WorkspaceUser,
+    services::{persistence::FolderPersistence, plain_text_editor::PlainTextEditor, GRID_SYNC_INTERVAL_IN_MILLIS},
+};
+use bytes::Bytes;
+use flowy_collaboration::{
+    entities::{
+        revision::{RepeatedRevision, Revision},
+        ws_data::ServerRevisionWSData,
+    },
+    grid::{default_grid_delta, GridChange, GridPad},
+};
+use flowy_sync::{RevisionCache, RevisionManager, RevisionWebSocket};
+use lib_infra::uuid_string;
+use parking_lot::RwLock;
+use std::{collections::HashMap, convert::TryInto, sync::Arc, time::Duration};
+
+type GridEditor = PlainTextEditor<GridPad>;
+
+pub(crate) struct GridController {
+    user: Arc<dyn WorkspaceUser>,
+    persistence: Arc<FolderPersistence>,
+    web_socket: Arc<dyn RevisionWebSocket>,
+    editors: RwLock<HashMap<String, Arc<GridEditor>>>,
+}
+
+impl GridController {
+    pub(crate) fn new(
+        user: Arc<dyn WorkspaceUser>,
+        persistence: Arc<FolderPersistence>,
+        web_socket: Arc<dyn RevisionWebSocket>,
+    ) -> Self {
+        Self {
+            user,
+            persistence,
+            web_socket,
+            editors: RwLock::new(HashMap::new()),
+        }
+    }
+
+    // Saves the initial revision of the grid. The grid_data is the json of the grid delta, and an
+    // empty grid is created if it's empty.
+    #[tracing::instrument(level = "debug", skip(self, grid_data), err)]
+    pub(crate) async fn create_grid(&self, grid_id: &str, grid_data: String) -> Result<(), FlowyError> {
+        let grid_data = if grid_data.is_empty() {
+            default_grid_delta().to_json()
+        } else {
+            grid_data
+        };
+
+        let user_id = self.user.user_id()?;
+        let repeated_revision: RepeatedRevision =
+            Revision::initial_revision(&user_id, grid_id, Bytes::from(grid_data)).into();
+        let cache = Arc::new(RevisionCache::new(&user_id, grid_id, self.persistence.db_pool()?));
+        let rev_manager = RevisionManager::new(&user_id, grid_id, cache);
+        let _ = rev_manager.reset_object(repeated_revision).await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn open_grid(&self, grid_id: &str) -> Result<Grid, FlowyError> {
+        let editor = self.get_editor(grid_id).await?;
+        Ok(editor.read(|grid| grid.grid(grid_id)))
+    }
+
+    // Returns the json of the grid delta that can be used to create a copy of the grid.
+    pub(crate) async fn read_grid_data(&self, grid_id: &str) -> Result<String, FlowyError> {
+        let editor = self.get_editor(grid_id).await?;
+        Ok(editor.delta_json())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn close_grid(&self, grid_id: &str) -> Result<(), FlowyError> {
+        if let Some(editor) = self.editors.write().remove(grid_id) {
+            editor.stop();
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn create_field(&self, params: CreateFieldParams) -> Result<Field, FlowyError> {
+        let field = Field {
+            id: uuid_string(),
+            name: params.name,
+            field_type: params.field_type,
+            options: params.options,
+        };
+        let cloned_field = field.clone();
+        let _ = self
+            .modify(&params.grid_id, |grid| Ok(grid.create_field(cloned_field)?))
+            .await?;
+        Ok(field)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn update_field(&self, params: UpdateFieldParams) -> Result<Field, FlowyError> {
+        let UpdateFieldParams {
+            grid_id,
+            field_id,
+            name,
+            options,
+        } = params;
+        let options = options.map(|options| options.items);
+        let editor = self
+            .modify(&grid_id, |grid| {
+                if grid.read_field(&field_id).is_none() {
+                    return Err(FlowyError::record_not_found().context("Can't find the field"));
+                }
+                Ok(grid.update_field(&field_id, name, options)?)
+            })
+            .await?;
+
+        let field = editor.read(|grid| grid.read_field(&field_id));
+        field.ok_or_else(FlowyError::record_not_found)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn delete_field(&self, params: FieldIdentifier) -> Result<(), FlowyError> {
+        let _ = self
+            .modify(&params.grid_id, |grid| Ok(grid.delete_field(&params.field_id)?))
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn create_row(&self, grid_id: &str) -> Result<Row, FlowyError> {
+        let row = Row {
+            id: uuid_string(),
+            cells: vec![],
+        };
+        let cloned_row = row.clone();
+        let _ = self.modify(grid_id, |grid| Ok(grid.create_row(cloned_row)?)).await?;
+        Ok(row)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn update_row(&self, params: UpdateRowParams) -> Result<Row, FlowyError> {
+        let UpdateRowParams { grid_id, row_id, cells } = params;
+        let editor = self
+            .modify(&grid_id, |grid| {
+                let mut valid_cells = vec![];
+                for cell in cells {
+                    valid_cells.push(make_valid_cell(grid.read_field(&cell.field_id), cell)?);
+                }
+                Ok(grid.update_cells(&row_id, valid_cells)?)
+            })
+            .await?;
+
+        let row = editor.read(|grid| grid.read_row(&row_id));
+        row.ok_or_else(FlowyError::record_not_found)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn delete_row(&self, params: RowIdentifier) -> Result<(), FlowyError> {
+        let _ = self
+            .modify(&params.grid_id, |grid| Ok(grid.delete_row(&params.row_id)?))
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn update_cell(&self, params: UpdateCellParams) -> Result<Row, FlowyError> {
+        let cell = Cell {
+            field_id: params.field_id,
+            data: params.data,
+        };
+        let row_id = params.row_id.clone();
+        let editor = self
+            .modify(&params.grid_id, |grid| {
+                let cell = make_valid_cell(grid.read_field(&cell.field_id), cell)?;
+                Ok(grid.update_cells(&row_id, vec![cell])?)
+            })
+            .await?;
+
+        let row = editor.read(|grid| grid.read_row(&params.row_id));
+        row.ok_or_else(FlowyError::record_not_found)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn delete_cell(&self, params: CellIdentifier) -> Result<(), FlowyError> {
+        let _ = self
+            .modify(&params.grid_id, |grid| {
+                Ok(grid.delete_cell(&params.row_id, &params.field_id)?)
+            })
+            .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn did_receive_ws_data(&self, data: Bytes) {
+        let result: Result<ServerRevisionWSData, protobuf::ProtobufError> = data.try_into();
+        match result {
+            Ok(data) => {
+                let editor = self.editors.read().get(&data.object_id).cloned();
+                match editor {
+                    None => tracing::error!("Can't find any grid editor for {:?}-{:?}", data.object_id, data.ty),
+                    Some(editor) => match editor.receive_ws_data(data).await {
+                        Ok(_) => {}
+                        Err(e) => tracing::error!("Grid receive data error: {:?}", e),
+                    },
+                }
+            }
+            Err(e) => {
+                tracing::error!("Grid ws data parser failed: {:?}", e);
+            }
+        }
+    }
+}
+
+impl GridController {
+    async fn get_editor(&self, grid_id: &str) -> FlowyResult<Arc<GridEditor>> {
+        if let Some(editor) = self.editors.read().get(grid_id).cloned() {
+            return Ok(editor);
+        }
+
+        let user_id = self.user.user_id()?;
+        let pool = self.persistence.db_pool()?;
+        let ping_duration = Duration::from_millis(GRID_SYNC_INTERVAL_IN_MILLIS);
+        let editor = GridEditor::new("Grid", &user_id, grid_id, pool, self.web_socket.clone(), ping_duration).await?;
+        let editor = Arc::new(editor);
+        self.editors.write().insert(grid_id.to_owned(), editor.clone());
+        Ok(editor)
+    }
+
+    // Applies the change to the grid and notifies the new grid if something changed.
+    async fn modify<F>(&self, grid_id: &str, f: F) -> FlowyResult<Arc<GridEditor>>
+    where
+        F: FnOnce(&mut GridPad) -> FlowyResult<Option<GridChange>>,
+    {
+        let editor = self.get_editor(grid_id).await?;
+        if editor.modify(f).await? {
+            send_dart_notification(grid_id, WorkspaceNotification::GridUpdated)
+                .payload(editor.read(|grid| grid.grid(grid_id)))
+                .send();
+        }
+        Ok(editor)
+    }
+}
+
+// Returns the cell if its data matches the type of the field.
+fn make_valid_cell(field: Option<Field>, cell: Cell) -> FlowyResult<Cell> {
+    match field {
+        None => Err(FlowyError::record_not_found().context(format!("Can't find the field: {}", cell.field_id))),
+        Some(field) => {
+            let data = field.parse_cell_data(cell.data)?;
+            Ok(Cell {
+                field_id: cell.field_id,
+                data,
+            })
+        }
+    }
+}
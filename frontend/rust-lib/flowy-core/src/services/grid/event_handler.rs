@@ -0,0 +1,101 @@
+use crate::{
+    entities::grid::{
+        CellIdentifier, CreateFieldParams, CreateFieldRequest, Field, FieldIdentifier, Grid, GridId, QueryCellRequest,
+        QueryFieldRequest, QueryGridRequest, QueryRowRequest, Row, RowIdentifier, UpdateCellParams, UpdateCellRequest,
+        UpdateFieldParams, UpdateFieldRequest, UpdateRowParams, UpdateRowRequest,
+    },
+    errors::FlowyError,
+    services::GridController,
+};
+use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn open_grid_handler(
+    data: Data<QueryGridRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Grid, FlowyError> {
+    let params: GridId = data.into_inner().try_into()?;
+    let grid = controller.open_grid(&params.grid_id).await?;
+    data_result(grid)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_field_handler(
+    data: Data<CreateFieldRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Field, FlowyError> {
+    let params: CreateFieldParams = data.into_inner().try_into()?;
+    let field = controller.create_field(params).await?;
+    data_result(field)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_field_handler(
+    data: Data<UpdateFieldRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Field, FlowyError> {
+    let params: UpdateFieldParams = data.into_inner().try_into()?;
+    let field = controller.update_field(params).await?;
+    data_result(field)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_field_handler(
+    data: Data<QueryFieldRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> Result<(), FlowyError> {
+    let params: FieldIdentifier = data.into_inner().try_into()?;
+    let _ = controller.delete_field(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_row_handler(
+    data: Data<QueryGridRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Row, FlowyError> {
+    let params: GridId = data.into_inner().try_into()?;
+    let row = controller.create_row(&params.grid_id).await?;
+    data_result(row)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_row_handler(
+    data: Data<UpdateRowRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Row, FlowyError> {
+    let params: UpdateRowParams = data.into_inner().try_into()?;
+    let row = controller.update_row(params).await?;
+    data_result(row)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_row_handler(
+    data: Data<QueryRowRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> Result<(), FlowyError> {
+    let params: RowIdentifier = data.into_inner().try_into()?;
+    let _ = controller.delete_row(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_cell_handler(
+    data: Data<UpdateCellRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Row, FlowyError> {
+    let params: UpdateCellParams = data.into_inner().try_into()?;
+    let row = controller.update_cell(params).await?;
+    data_result(row)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_cell_handler(
+    data: Data<QueryCellRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> Result<(), FlowyError> {
+    let params: CellIdentifier = data.into_inner().try_into()?;
+    let _ = controller.delete_cell(params).await?;
+    Ok(())
+}
@@ -1,14 +1,26 @@
 pub(crate) use app::controller::*;
+pub(crate) use board::controller::*;
+pub(crate) use comment::controller::*;
+pub(crate) use grid::controller::*;
+pub(crate) use template::controller::*;
 pub(crate) use trash::controller::*;
 pub(crate) use view::controller::*;
 pub(crate) use workspace::controller::*;
 
 pub(crate) mod app;
+pub(crate) mod board;
+pub(crate) mod comment;
 pub mod folder_editor;
+pub(crate) mod grid;
 pub(crate) mod persistence;
+mod plain_text_editor;
+pub(crate) mod template;
 pub(crate) mod trash;
 pub(crate) mod view;
 mod web_socket;
 pub(crate) mod workspace;
 
+pub const BOARD_SYNC_INTERVAL_IN_MILLIS: u64 = 5000;
+pub const COMMENT_SYNC_INTERVAL_IN_MILLIS: u64 = 5000;
 pub const FOLDER_SYNC_INTERVAL_IN_MILLIS: u64 = 5000;
+pub const GRID_SYNC_INTERVAL_IN_MILLIS: u64 = 5000;
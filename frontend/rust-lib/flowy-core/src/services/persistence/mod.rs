@@ -17,8 +17,9 @@ use crate::{
 };
 use flowy_core_data_model::entities::{
     app::App,
+    template::Template,
     trash::{RepeatedTrash, Trash},
-    view::View,
+    view::{View, ViewProperty},
     workspace::Workspace,
 };
 use flowy_error::{FlowyError, FlowyResult};
@@ -41,11 +42,17 @@ pub trait FolderPersistenceTransaction {
     fn read_view(&self, view_id: &str) -> FlowyResult<View>;
     fn read_views(&self, belong_to_id: &str) -> FlowyResult<Vec<View>>;
     fn update_view(&self, changeset: ViewChangeset) -> FlowyResult<()>;
+    fn update_view_tags(&self, view_id: &str, tags: Vec<String>) -> FlowyResult<()>;
+    fn update_view_property(&self, view_id: &str, key: &str, property: Option<ViewProperty>) -> FlowyResult<()>;
     fn delete_view(&self, view_id: &str) -> FlowyResult<()>;
 
     fn create_trash(&self, trashes: Vec<Trash>) -> FlowyResult<()>;
     fn read_trash(&self, trash_id: Option<String>) -> FlowyResult<RepeatedTrash>;
     fn delete_trash(&self, trash_ids: Option<Vec<String>>) -> FlowyResult<()>;
+
+    fn create_template(&self, template: Template) -> FlowyResult<()>;
+    fn read_templates(&self, template_id: Option<String>) -> FlowyResult<Vec<Template>>;
+    fn delete_templates(&self, template_ids: Vec<String>) -> FlowyResult<()>;
 }
 
 pub struct FolderPersistence {
@@ -99,6 +106,16 @@ impl FolderPersistence {
         self.database.db_pool()
     }
 
+    pub(crate) fn save_workspace_secret(&self, workspace_id: &str, secret: &[u8]) -> FlowyResult<()> {
+        let conn = self.database.db_connection()?;
+        WorkspaceSecretTableSql::save_secret(workspace_id, secret, &*conn)
+    }
+
+    pub(crate) fn read_workspace_secret(&self, workspace_id: &str) -> FlowyResult<Option<Vec<u8>>> {
+        let conn = self.database.db_connection()?;
+        WorkspaceSecretTableSql::read_secret(workspace_id, &*conn)
+    }
+
     pub async fn initialize(&self, user_id: &str, folder_id: &FolderId) -> FlowyResult<()> {
         let migrations = FolderMigration::new(user_id, self.database.clone());
         if let Some(migrated_folder) = migrations.run_v1_migration()? {
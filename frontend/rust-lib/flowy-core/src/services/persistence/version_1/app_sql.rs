@@ -101,7 +101,7 @@ impl AppTable {
             workspace_id: app.workspace_id,
             name: app.name,
             desc: app.desc,
-            color_style: ColorStyleCol::default(),
+            color_style: app.color_style.into(),
             last_view_id: None,
             modified_time: app.modified_time,
             create_time: app.create_time,
@@ -119,6 +119,8 @@ impl std::convert::From<AppTable> for Trash {
             modified_time: table.modified_time,
             create_time: table.create_time,
             ty: TrashType::App,
+            deleted_time: 0,
+            deleted_by: "".to_owned(),
         }
     }
 }
@@ -137,6 +139,14 @@ impl std::convert::From<ColorStyle> for ColorStyleCol {
     }
 }
 
+impl std::convert::From<ColorStyleCol> for ColorStyle {
+    fn from(s: ColorStyleCol) -> Self {
+        Self {
+            theme_color: s.theme_color,
+        }
+    }
+}
+
 impl std::convert::TryInto<Vec<u8>> for &ColorStyleCol {
     type Error = String;
 
@@ -161,6 +171,7 @@ pub struct AppChangeset {
     pub id: String,
     pub name: Option<String>,
     pub desc: Option<String>,
+    pub color_style: Option<ColorStyleCol>,
     pub is_trash: Option<bool>,
 }
 
@@ -170,6 +181,7 @@ impl AppChangeset {
             id: params.app_id,
             name: params.name,
             desc: params.desc,
+            color_style: params.color_style.map(ColorStyleCol::from),
             is_trash: params.is_trash,
         }
     }
@@ -179,6 +191,7 @@ impl AppChangeset {
             id: table.id,
             name: Some(table.name),
             desc: Some(table.desc),
+            color_style: Some(table.color_style),
             is_trash: Some(table.is_trash),
         }
     }
@@ -194,6 +207,7 @@ impl std::convert::From<AppTable> for App {
             version: table.version,
             modified_time: table.modified_time,
             create_time: table.create_time,
+            color_style: table.color_style.into(),
         }
     }
 }
@@ -68,6 +68,8 @@ impl std::convert::From<TrashTable> for Trash {
             modified_time: table.modified_time,
             create_time: table.create_time,
             ty: table.ty.into(),
+            deleted_time: 0,
+            deleted_by: "".to_owned(),
         }
     }
 }
@@ -110,6 +112,7 @@ pub(crate) enum SqlTrashType {
     Unknown = 0,
     View = 1,
     App = 2,
+    Workspace = 3,
 }
 
 impl std::convert::From<i32> for SqlTrashType {
@@ -118,6 +121,7 @@ impl std::convert::From<i32> for SqlTrashType {
             0 => SqlTrashType::Unknown,
             1 => SqlTrashType::View,
             2 => SqlTrashType::App,
+            3 => SqlTrashType::Workspace,
             _o => SqlTrashType::Unknown,
         }
     }
@@ -131,6 +135,7 @@ impl std::convert::From<SqlTrashType> for TrashType {
             SqlTrashType::Unknown => TrashType::Unknown,
             SqlTrashType::View => TrashType::View,
             SqlTrashType::App => TrashType::App,
+            SqlTrashType::Workspace => TrashType::Workspace,
         }
     }
 }
@@ -141,6 +146,7 @@ impl std::convert::From<TrashType> for SqlTrashType {
             TrashType::Unknown => SqlTrashType::Unknown,
             TrashType::View => SqlTrashType::View,
             TrashType::App => SqlTrashType::App,
+            TrashType::Workspace => SqlTrashType::Workspace,
         }
     }
 }
@@ -8,11 +8,12 @@ use crate::services::persistence::{
 };
 use flowy_core_data_model::entities::{
     app::App,
+    template::Template,
     trash::{RepeatedTrash, Trash},
-    view::View,
+    view::{View, ViewProperty},
     workspace::Workspace,
 };
-use flowy_error::FlowyResult;
+use flowy_error::{FlowyError, FlowyResult};
 use lib_sqlite::DBConnection;
 
 pub struct V1Transaction<'a>(pub &'a DBConnection);
@@ -84,6 +85,14 @@ impl<'a> FolderPersistenceTransaction for V1Transaction<'a> {
         Ok(())
     }
 
+    fn update_view_tags(&self, _view_id: &str, _tags: Vec<String>) -> FlowyResult<()> {
+        Err(FlowyError::internal().context("View tags are not supported in folder v1"))
+    }
+
+    fn update_view_property(&self, _view_id: &str, _key: &str, _property: Option<ViewProperty>) -> FlowyResult<()> {
+        Err(FlowyError::internal().context("View properties are not supported in folder v1"))
+    }
+
     fn delete_view(&self, view_id: &str) -> FlowyResult<()> {
         let _ = ViewTableSql::delete_view(view_id, &*self.0)?;
         Ok(())
@@ -117,6 +126,19 @@ impl<'a> FolderPersistenceTransaction for V1Transaction<'a> {
             }
         }
     }
+
+    // The templates are only supported by the folder that is stored as revisions.
+    fn create_template(&self, _template: Template) -> FlowyResult<()> {
+        Err(FlowyError::internal().context("Template is not supported in folder v1"))
+    }
+
+    fn read_templates(&self, _template_id: Option<String>) -> FlowyResult<Vec<Template>> {
+        Ok(vec![])
+    }
+
+    fn delete_templates(&self, _template_ids: Vec<String>) -> FlowyResult<()> {
+        Ok(())
+    }
 }
 
 // https://www.reddit.com/r/rust/comments/droxdg/why_arent_traits_impld_for_boxdyn_trait/
@@ -176,6 +198,14 @@ where
         (**self).update_view(changeset)
     }
 
+    fn update_view_tags(&self, view_id: &str, tags: Vec<String>) -> FlowyResult<()> {
+        (**self).update_view_tags(view_id, tags)
+    }
+
+    fn update_view_property(&self, view_id: &str, key: &str, property: Option<ViewProperty>) -> FlowyResult<()> {
+        (**self).update_view_property(view_id, key, property)
+    }
+
     fn delete_view(&self, view_id: &str) -> FlowyResult<()> {
         (**self).delete_view(view_id)
     }
@@ -191,4 +221,16 @@ where
     fn delete_trash(&self, trash_ids: Option<Vec<String>>) -> FlowyResult<()> {
         (**self).delete_trash(trash_ids)
     }
+
+    fn create_template(&self, template: Template) -> FlowyResult<()> {
+        (**self).create_template(template)
+    }
+
+    fn read_templates(&self, template_id: Option<String>) -> FlowyResult<Vec<Template>> {
+        (**self).read_templates(template_id)
+    }
+
+    fn delete_templates(&self, template_ids: Vec<String>) -> FlowyResult<()> {
+        (**self).delete_templates(template_ids)
+    }
 }
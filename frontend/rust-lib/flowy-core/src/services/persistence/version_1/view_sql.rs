@@ -129,6 +129,8 @@ impl ViewTable {
         let view_type = match view.view_type {
             ViewType::Blank => ViewTableType::Docs,
             ViewType::Doc => ViewTableType::Docs,
+            ViewType::Grid => ViewTableType::Grid,
+            ViewType::Board => ViewTableType::Board,
         };
 
         ViewTable {
@@ -151,6 +153,8 @@ impl std::convert::From<ViewTable> for View {
     fn from(table: ViewTable) -> Self {
         let view_type = match table.view_type {
             ViewTableType::Docs => ViewType::Doc,
+            ViewTableType::Grid => ViewType::Grid,
+            ViewTableType::Board => ViewType::Board,
         };
 
         View {
@@ -163,6 +167,8 @@ impl std::convert::From<ViewTable> for View {
             modified_time: table.modified_time,
             version: table.version,
             create_time: table.create_time,
+            tags: vec![],
+            properties: vec![],
         }
     }
 }
@@ -175,6 +181,8 @@ impl std::convert::From<ViewTable> for Trash {
             modified_time: table.modified_time,
             create_time: table.create_time,
             ty: TrashType::View,
+            deleted_time: 0,
+            deleted_by: "".to_owned(),
         }
     }
 }
@@ -216,6 +224,8 @@ impl ViewChangeset {
 #[sql_type = "Integer"]
 pub enum ViewTableType {
     Docs = 0,
+    Grid = 1,
+    Board = 2,
 }
 
 impl std::default::Default for ViewTableType {
@@ -228,6 +238,8 @@ impl std::convert::From<i32> for ViewTableType {
     fn from(value: i32) -> Self {
         match value {
             0 => ViewTableType::Docs,
+            1 => ViewTableType::Grid,
+            2 => ViewTableType::Board,
             o => {
                 log::error!("Unsupported view type {}, fallback to ViewType::Docs", o);
                 ViewTableType::Docs
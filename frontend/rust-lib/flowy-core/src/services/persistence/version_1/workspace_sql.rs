@@ -5,10 +5,10 @@ use crate::{
     },
     errors::FlowyError,
 };
-use diesel::SqliteConnection;
+use diesel::{OptionalExtension, SqliteConnection};
 use flowy_database::{
     prelude::*,
-    schema::{workspace_table, workspace_table::dsl},
+    schema::{workspace_secret_table, workspace_table, workspace_table::dsl},
 };
 pub(crate) struct WorkspaceTableSql();
 impl WorkspaceTableSql {
@@ -60,6 +60,39 @@ impl WorkspaceTableSql {
     }
 }
 
+// The secret of the workspace that encrypts the revisions, the id is the workspace id. It's kept in
+// the user database, which is encrypted with the key of the user, instead of the kv.db that is
+// shared by all the users of the device.
+pub(crate) struct WorkspaceSecretTableSql();
+impl WorkspaceSecretTableSql {
+    pub(crate) fn save_secret(workspace_id: &str, secret: &[u8], conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let table = WorkspaceSecretTable {
+            id: workspace_id.to_owned(),
+            secret: secret.to_vec(),
+        };
+        let _ = diesel::replace_into(workspace_secret_table::table)
+            .values(&table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_secret(workspace_id: &str, conn: &SqliteConnection) -> Result<Option<Vec<u8>>, FlowyError> {
+        let secret = workspace_secret_table::dsl::workspace_secret_table
+            .filter(workspace_secret_table::id.eq(workspace_id))
+            .select(workspace_secret_table::secret)
+            .first::<Vec<u8>>(conn)
+            .optional()?;
+        Ok(secret)
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "workspace_secret_table"]
+pub(crate) struct WorkspaceSecretTable {
+    pub id: String,
+    pub secret: Vec<u8>,
+}
+
 #[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
 #[table_name = "workspace_table"]
 pub struct WorkspaceTable {
@@ -3,12 +3,14 @@ use crate::services::{
     persistence::{AppChangeset, FolderPersistenceTransaction, ViewChangeset, WorkspaceChangeset},
 };
 use flowy_core_data_model::entities::{
-    app::App,
+    app::{App, ColorStyle},
+    template::Template,
     trash::{RepeatedTrash, Trash},
-    view::View,
+    view::{View, ViewProperty},
     workspace::Workspace,
 };
 use flowy_error::{FlowyError, FlowyResult};
+use lib_infra::timestamp;
 use std::sync::Arc;
 
 impl FolderPersistenceTransaction for FolderEditor {
@@ -50,11 +52,12 @@ impl FolderPersistenceTransaction for FolderEditor {
     }
 
     fn update_app(&self, changeset: AppChangeset) -> FlowyResult<()> {
-        if let Some(change) = self
-            .folder
-            .write()
-            .update_app(&changeset.id, changeset.name, changeset.desc)?
-        {
+        if let Some(change) = self.folder.write().update_app(
+            &changeset.id,
+            changeset.name,
+            changeset.desc,
+            changeset.color_style.map(ColorStyle::from),
+        )? {
             let _ = self.apply_change(change)?;
         }
         Ok(())
@@ -111,6 +114,24 @@ impl FolderPersistenceTransaction for FolderEditor {
         Ok(())
     }
 
+    fn update_view_tags(&self, view_id: &str, tags: Vec<String>) -> FlowyResult<()> {
+        if let Some(change) = self.folder.write().update_view_tags(view_id, tags, timestamp())? {
+            let _ = self.apply_change(change)?;
+        }
+        Ok(())
+    }
+
+    fn update_view_property(&self, view_id: &str, key: &str, property: Option<ViewProperty>) -> FlowyResult<()> {
+        if let Some(change) = self
+            .folder
+            .write()
+            .update_view_property(view_id, key, property, timestamp())?
+        {
+            let _ = self.apply_change(change)?;
+        }
+        Ok(())
+    }
+
     fn delete_view(&self, view_id: &str) -> FlowyResult<()> {
         if let Some(change) = self.folder.write().delete_view(view_id)? {
             let _ = self.apply_change(change)?;
@@ -136,6 +157,25 @@ impl FolderPersistenceTransaction for FolderEditor {
         }
         Ok(())
     }
+
+    fn create_template(&self, template: Template) -> FlowyResult<()> {
+        if let Some(change) = self.folder.write().create_template(template)? {
+            let _ = self.apply_change(change)?;
+        }
+        Ok(())
+    }
+
+    fn read_templates(&self, template_id: Option<String>) -> FlowyResult<Vec<Template>> {
+        let templates = self.folder.read().read_templates(template_id)?;
+        Ok(templates)
+    }
+
+    fn delete_templates(&self, template_ids: Vec<String>) -> FlowyResult<()> {
+        if let Some(change) = self.folder.write().delete_templates(template_ids)? {
+            let _ = self.apply_change(change)?;
+        }
+        Ok(())
+    }
 }
 
 impl<T> FolderPersistenceTransaction for Arc<T>
@@ -194,6 +234,14 @@ where
         (**self).update_view(changeset)
     }
 
+    fn update_view_tags(&self, view_id: &str, tags: Vec<String>) -> FlowyResult<()> {
+        (**self).update_view_tags(view_id, tags)
+    }
+
+    fn update_view_property(&self, view_id: &str, key: &str, property: Option<ViewProperty>) -> FlowyResult<()> {
+        (**self).update_view_property(view_id, key, property)
+    }
+
     fn delete_view(&self, view_id: &str) -> FlowyResult<()> {
         (**self).delete_view(view_id)
     }
@@ -209,4 +257,16 @@ where
     fn delete_trash(&self, trash_ids: Option<Vec<String>>) -> FlowyResult<()> {
         (**self).delete_trash(trash_ids)
     }
+
+    fn create_template(&self, template: Template) -> FlowyResult<()> {
+        (**self).create_template(template)
+    }
+
+    fn read_templates(&self, template_id: Option<String>) -> FlowyResult<Vec<Template>> {
+        (**self).read_templates(template_id)
+    }
+
+    fn delete_templates(&self, template_ids: Vec<String>) -> FlowyResult<()> {
+        (**self).delete_templates(template_ids)
+    }
 }
@@ -0,0 +1,227 @@
+use crate::services::web_socket::make_plain_text_ws_manager;
+use flowy_collaboration::{
+    board::{BoardChange, BoardPad},
+    comment::{CommentChange, CommentPad},
+    entities::{revision::Revision, ws_data::ServerRevisionWSData},
+    errors::CollaborateResult,
+    grid::{GridChange, GridPad},
+};
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_sync::{
+    RevisionCache, RevisionCloudService, RevisionManager, RevisionObjectBuilder, RevisionWebSocket,
+    RevisionWebSocketManager,
+};
+use lib_infra::future::FutureResult;
+use lib_ot::core::PlainDelta;
+use lib_sqlite::ConnectionPool;
+use parking_lot::RwLock;
+use std::{marker::PhantomData, sync::Arc, time::Duration};
+
+// The pad of the object that is synced as a plain text delta, e.g. the grid, the board and the
+// comment threads of a document. They share the same editor and ws manager, only the pad is different.
+pub(crate) trait PlainTextPad: Sized + Send + Sync + 'static {
+    type Change: Send;
+
+    fn from_revisions(revisions: Vec<Revision>) -> CollaborateResult<Self>;
+    fn delta(&self) -> &PlainDelta;
+    fn compose_remote_delta(&mut self, delta: PlainDelta) -> CollaborateResult<String>;
+    fn reset_delta(&mut self, delta: PlainDelta) -> CollaborateResult<String>;
+    // Returns the delta of the change and the md5 of the pad after applying it.
+    fn split_change(change: Self::Change) -> (PlainDelta, String);
+}
+
+pub(crate) struct PlainTextEditor<P: PlainTextPad> {
+    user_id: String,
+    pub(crate) object_id: String,
+    pad: Arc<RwLock<P>>,
+    rev_manager: Arc<RevisionManager>,
+    ws_manager: Arc<RevisionWebSocketManager>,
+}
+
+impl<P: PlainTextPad> PlainTextEditor<P> {
+    pub(crate) async fn new(
+        object_name: &'static str,
+        user_id: &str,
+        object_id: &str,
+        pool: Arc<ConnectionPool>,
+        web_socket: Arc<dyn RevisionWebSocket>,
+        ping_duration: Duration,
+    ) -> FlowyResult<Self> {
+        let cache = Arc::new(RevisionCache::new(user_id, object_id, pool));
+        let mut rev_manager = RevisionManager::new(user_id, object_id, cache);
+        let cloud = Arc::new(PlainTextRevisionCloudService());
+        let pad = Arc::new(RwLock::new(rev_manager.load::<PlainTextPadBuilder<P>>(cloud).await?));
+        let rev_manager = Arc::new(rev_manager);
+        let ws_manager = make_plain_text_ws_manager(
+            object_name,
+            user_id,
+            object_id,
+            rev_manager.clone(),
+            web_socket,
+            pad.clone(),
+            ping_duration,
+        )
+        .await;
+
+        Ok(Self {
+            user_id: user_id.to_owned(),
+            object_id: object_id.to_owned(),
+            pad,
+            rev_manager,
+            ws_manager,
+        })
+    }
+
+    pub(crate) async fn receive_ws_data(&self, data: ServerRevisionWSData) -> FlowyResult<()> {
+        let _ = self.ws_manager.ws_passthrough_tx.send(data).await.map_err(|e| {
+            let err_msg = format!("{} passthrough error: {}", self.object_id, e);
+            FlowyError::internal().context(err_msg)
+        })?;
+        Ok(())
+    }
+
+    pub(crate) fn read<F, T>(&self, f: F) -> T
+    where
+        F: FnOnce(&P) -> T,
+    {
+        f(&*self.pad.read())
+    }
+
+    pub(crate) fn delta_json(&self) -> String {
+        self.pad.read().delta().to_json()
+    }
+
+    pub(crate) fn stop(&self) {
+        self.ws_manager.stop();
+    }
+
+    // Applies the change to the pad. Returns false if nothing changed.
+    pub(crate) async fn modify<F>(&self, f: F) -> FlowyResult<bool>
+    where
+        F: FnOnce(&mut P) -> FlowyResult<Option<P::Change>>,
+    {
+        let change = f(&mut *self.pad.write())?;
+        match change {
+            None => Ok(false),
+            Some(change) => {
+                let _ = self.apply_change(change).await?;
+                Ok(true)
+            }
+        }
+    }
+
+    async fn apply_change(&self, change: P::Change) -> FlowyResult<()> {
+        let (delta, md5) = P::split_change(change);
+        let (base_rev_id, rev_id) = self.rev_manager.next_rev_id_pair();
+        let delta_data = delta.to_bytes();
+        let revision = Revision::new(
+            &self.rev_manager.object_id,
+            base_rev_id,
+            rev_id,
+            delta_data,
+            &self.user_id,
+            md5,
+        );
+        let _ = self.rev_manager.add_local_revision(&revision).await?;
+        Ok(())
+    }
+}
+
+struct PlainTextPadBuilder<P>(PhantomData<P>);
+impl<P: PlainTextPad> RevisionObjectBuilder for PlainTextPadBuilder<P> {
+    type Output = P;
+
+    fn build_with_revisions(_object_id: &str, revisions: Vec<Revision>) -> FlowyResult<Self::Output> {
+        let pad = P::from_revisions(revisions)?;
+        Ok(pad)
+    }
+}
+
+// The objects are created locally and their revisions are pushed by the server through the web
+// socket, so there is nothing to fetch when the object doesn't have any local revisions.
+struct PlainTextRevisionCloudService();
+impl RevisionCloudService for PlainTextRevisionCloudService {
+    #[tracing::instrument(level = "trace", skip(self))]
+    fn fetch_object(&self, _user_id: &str, _object_id: &str) -> FutureResult<Vec<Revision>, FlowyError> {
+        FutureResult::new(async move { Ok(vec![]) })
+    }
+}
+
+impl PlainTextPad for GridPad {
+    type Change = GridChange;
+
+    fn from_revisions(revisions: Vec<Revision>) -> CollaborateResult<Self> {
+        GridPad::from_revisions(revisions)
+    }
+
+    fn delta(&self) -> &PlainDelta {
+        self.delta()
+    }
+
+    fn compose_remote_delta(&mut self, delta: PlainDelta) -> CollaborateResult<String> {
+        self.compose_remote_delta(delta)
+    }
+
+    fn reset_delta(&mut self, delta: PlainDelta) -> CollaborateResult<String> {
+        self.reset_grid(delta)
+    }
+
+    fn split_change(change: GridChange) -> (PlainDelta, String) {
+        (change.delta, change.md5)
+    }
+}
+
+impl PlainTextPad for BoardPad {
+    type Change = BoardChange;
+
+    fn from_revisions(revisions: Vec<Revision>) -> CollaborateResult<Self> {
+        BoardPad::from_revisions(revisions)
+    }
+
+    fn delta(&self) -> &PlainDelta {
+        self.delta()
+    }
+
+    fn compose_remote_delta(&mut self, delta: PlainDelta) -> CollaborateResult<String> {
+        self.compose_remote_delta(delta)
+    }
+
+    fn reset_delta(&mut self, delta: PlainDelta) -> CollaborateResult<String> {
+        self.reset_board(delta)
+    }
+
+    fn split_change(change: BoardChange) -> (PlainDelta, String) {
+        (change.delta, change.md5)
+    }
+}
+
+impl PlainTextPad for CommentPad {
+    type Change = CommentChange;
+
+    fn from_revisions(revisions: Vec<Revision>) -> CollaborateResult<Self> {
+        CommentPad::from_revisions(revisions)
+    }
+
+    fn delta(&self) -> &PlainDelta {
+        self.delta()
+    }
+
+    fn compose_remote_delta(&mut self, delta: PlainDelta) -> CollaborateResult<String> {
+        self.compose_remote_delta(delta)
+    }
+
+    fn reset_delta(&mut self, delta: PlainDelta) -> CollaborateResult<String> {
+        self.reset_comments(delta)
+    }
+
+    fn split_change(change: CommentChange) -> (PlainDelta, String) {
+        (change.delta, change.md5)
+    }
+}
+
+#[cfg(feature = "flowy_unit_test")]
+impl<P: PlainTextPad> PlainTextEditor<P> {
+    pub fn rev_manager(&self) -> Arc<RevisionManager> {
+        self.rev_manager.clone()
+    }
+}
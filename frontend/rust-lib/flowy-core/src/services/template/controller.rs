@@ -0,0 +1,247 @@
+use crate::{
+    dart_notification::{send_dart_notification, WorkspaceNotification},
+    entities::{
+        template::{CreateTemplateParams, CreateViewFromTemplateParams, RepeatedTemplate, Template, TemplateType},
+        view::{CreateViewParams, View, ViewType},
+    },
+    errors::FlowyError,
+    module::WorkspaceUser,
+    services::{
+        get_current_workspace, is_view_in_trash,
+        persistence::{FolderPersistence, FolderPersistenceTransaction},
+        TrashController, ViewController,
+    },
+};
+use chrono::{Local, Utc};
+use lib_infra::uuid_string;
+use std::sync::Arc;
+
+pub const DATE_PLACEHOLDER: &str = "{{date}}";
+pub const USER_NAME_PLACEHOLDER: &str = "{{user_name}}";
+pub const TITLE_PLACEHOLDER: &str = "{{title}}";
+
+pub(crate) struct TemplateController {
+    user: Arc<dyn WorkspaceUser>,
+    persistence: Arc<FolderPersistence>,
+    trash_controller: Arc<TrashController>,
+    view_controller: Arc<ViewController>,
+}
+
+impl TemplateController {
+    pub(crate) fn new(
+        user: Arc<dyn WorkspaceUser>,
+        persistence: Arc<FolderPersistence>,
+        trash_controller: Arc<TrashController>,
+        view_controller: Arc<ViewController>,
+    ) -> Self {
+        Self {
+            user,
+            persistence,
+            trash_controller,
+            view_controller,
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn create_template(&self, params: CreateTemplateParams) -> Result<Template, FlowyError> {
+        let workspace_id = get_current_workspace()?;
+        let user_id = self.user.user_id()?;
+        self.persistence
+            .begin_transaction(|transaction| {
+                let trash_ids = self.trash_controller.read_trash_ids(&transaction)?;
+                let name = match params.ty {
+                    TemplateType::View => {
+                        let view = transaction.read_view(&params.id)?;
+                        if is_view_in_trash(&view, &trash_ids, &transaction) {
+                            return Err(FlowyError::record_not_found());
+                        }
+                        view.name
+                    }
+                    TemplateType::App => {
+                        let app = transaction.read_app(&params.id)?;
+                        if trash_ids.contains(&app.id) {
+                            return Err(FlowyError::record_not_found());
+                        }
+                        app.name
+                    }
+                    TemplateType::Unknown => {
+                        return Err(FlowyError::internal().context("Unknown template type"));
+                    }
+                };
+
+                let template = Template {
+                    id: params.id,
+                    workspace_id: workspace_id.clone(),
+                    name,
+                    ty: params.ty,
+                    create_time: Utc::now().timestamp(),
+                    created_by: user_id,
+                };
+                let _ = transaction.create_template(template.clone())?;
+                let _ = notify_templates_changed(&workspace_id, &trash_ids, &transaction)?;
+                Ok(template)
+            })
+            .await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn read_templates(&self) -> Result<RepeatedTemplate, FlowyError> {
+        let workspace_id = get_current_workspace()?;
+        let templates = self
+            .persistence
+            .begin_transaction(|transaction| {
+                let trash_ids = self.trash_controller.read_trash_ids(&transaction)?;
+                read_local_templates(&workspace_id, &trash_ids, &transaction)
+            })
+            .await?;
+        Ok(RepeatedTemplate { items: templates })
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn delete_template(&self, template_id: &str) -> Result<(), FlowyError> {
+        let workspace_id = get_current_workspace()?;
+        self.persistence
+            .begin_transaction(|transaction| {
+                let _ = transaction.delete_templates(vec![template_id.to_owned()])?;
+                let trash_ids = self.trash_controller.read_trash_ids(&transaction)?;
+                let _ = notify_templates_changed(&workspace_id, &trash_ids, &transaction)?;
+                Ok(())
+            })
+            .await
+    }
+
+    // Instantiates the template into the belong_to_id. The template of the app is instantiated as a view
+    // that contains the views of the app.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn create_view_from_template(
+        &self,
+        params: CreateViewFromTemplateParams,
+    ) -> Result<View, FlowyError> {
+        let template = self
+            .persistence
+            .begin_transaction(|transaction| {
+                let template_id = params.template_id.clone();
+                match transaction.read_templates(Some(template_id))?.pop() {
+                    None => Err(FlowyError::record_not_found().context("Can't find the template")),
+                    Some(template) => Ok(template),
+                }
+            })
+            .await?;
+
+        let name = params.name.unwrap_or_else(|| template.name.clone());
+        let context = TemplateContext::new(self.user.user_name()?, &name);
+        match template.ty {
+            TemplateType::View => {
+                let mut views = self.view_controller.read_view_tree(&template.id).await?;
+                views[0].name = context.title.clone();
+                let from_belong_to_id = views[0].belong_to_id.clone();
+                let mut new_views = self
+                    .view_controller
+                    .duplicate_views(views, &from_belong_to_id, &params.belong_to_id, Some(&context))
+                    .await?;
+                Ok(new_views.remove(0))
+            }
+            TemplateType::App => {
+                let views = self.view_controller.read_belonging_view_trees(&template.id).await?;
+                let view = self
+                    .view_controller
+                    .create_view_from_params(CreateViewParams {
+                        belong_to_id: params.belong_to_id,
+                        name: context.title.clone(),
+                        desc: "".to_owned(),
+                        thumbnail: "".to_owned(),
+                        view_type: ViewType::Doc,
+                        view_data: "".to_owned(),
+                        view_id: uuid_string(),
+                    })
+                    .await?;
+                let _ = self
+                    .view_controller
+                    .duplicate_views(views, &template.id, &view.id, Some(&context))
+                    .await?;
+                Ok(view)
+            }
+            TemplateType::Unknown => Err(FlowyError::internal().context("Unknown template type")),
+        }
+    }
+}
+
+// The values of the placeholders that get substituted when instantiating a template:
+// {{date}}: the local date, e.g. 2022-01-20
+// {{user_name}}: the name of the current user
+// {{title}}: the name of the new view, which may contain the other placeholders.
+pub(crate) struct TemplateContext {
+    date: String,
+    user_name: String,
+    pub(crate) title: String,
+}
+
+impl TemplateContext {
+    pub(crate) fn new(user_name: String, title: &str) -> Self {
+        let mut context = Self {
+            date: Local::now().format("%Y-%m-%d").to_string(),
+            user_name,
+            title: "".to_owned(),
+        };
+        context.title = context.render(title);
+        context
+    }
+
+    pub(crate) fn render(&self, s: &str) -> String {
+        if !s.contains("{{") {
+            return s.to_owned();
+        }
+
+        s.replace(DATE_PLACEHOLDER, &self.date)
+            .replace(USER_NAME_PLACEHOLDER, &self.user_name)
+            .replace(TITLE_PLACEHOLDER, &self.title)
+    }
+}
+
+// Removes the templates whose views or apps were deleted, and ignores the ones in the trash.
+fn read_local_templates<'a>(
+    workspace_id: &str,
+    trash_ids: &[String],
+    transaction: &'a (dyn FolderPersistenceTransaction + 'a),
+) -> Result<Vec<Template>, FlowyError> {
+    let mut templates = vec![];
+    let mut invalid_template_ids = vec![];
+    for template in transaction.read_templates(None)? {
+        if template.workspace_id != workspace_id {
+            continue;
+        }
+
+        let is_in_trash = match template.ty {
+            TemplateType::View => transaction
+                .read_view(&template.id)
+                .map(|view| is_view_in_trash(&view, trash_ids, transaction)),
+            TemplateType::App => transaction
+                .read_app(&template.id)
+                .map(|app| trash_ids.contains(&app.id)),
+            TemplateType::Unknown => Err(FlowyError::record_not_found()),
+        };
+
+        match is_in_trash {
+            Ok(false) => templates.push(template),
+            Ok(true) => {}
+            Err(_) => invalid_template_ids.push(template.id),
+        }
+    }
+
+    if !invalid_template_ids.is_empty() {
+        let _ = transaction.delete_templates(invalid_template_ids)?;
+    }
+    Ok(templates)
+}
+
+fn notify_templates_changed<'a>(
+    workspace_id: &str,
+    trash_ids: &[String],
+    transaction: &'a (dyn FolderPersistenceTransaction + 'a),
+) -> Result<(), FlowyError> {
+    let templates = read_local_templates(workspace_id, trash_ids, transaction)?;
+    send_dart_notification(workspace_id, WorkspaceNotification::TemplatesUpdated)
+        .payload(RepeatedTemplate { items: templates })
+        .send();
+    Ok(())
+}
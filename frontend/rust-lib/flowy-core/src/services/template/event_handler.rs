@@ -0,0 +1,51 @@
+use crate::{
+    entities::{
+        template::{
+            CreateTemplateParams, CreateTemplateRequest, CreateViewFromTemplateParams, CreateViewFromTemplateRequest,
+            QueryTemplateRequest, RepeatedTemplate, Template, TemplateId,
+        },
+        view::View,
+    },
+    errors::FlowyError,
+    services::TemplateController,
+};
+use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_template_handler(
+    data: Data<CreateTemplateRequest>,
+    controller: Unit<Arc<TemplateController>>,
+) -> DataResult<Template, FlowyError> {
+    let params: CreateTemplateParams = data.into_inner().try_into()?;
+    let template = controller.create_template(params).await?;
+    data_result(template)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_templates_handler(
+    controller: Unit<Arc<TemplateController>>,
+) -> DataResult<RepeatedTemplate, FlowyError> {
+    let templates = controller.read_templates().await?;
+    data_result(templates)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_template_handler(
+    data: Data<QueryTemplateRequest>,
+    controller: Unit<Arc<TemplateController>>,
+) -> Result<(), FlowyError> {
+    let params: TemplateId = data.into_inner().try_into()?;
+    let _ = controller.delete_template(&params.template_id).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_view_from_template_handler(
+    data: Data<CreateViewFromTemplateRequest>,
+    controller: Unit<Arc<TemplateController>>,
+) -> DataResult<View, FlowyError> {
+    let params: CreateViewFromTemplateParams = data.into_inner().try_into()?;
+    let view = controller.create_view_from_template(params).await?;
+    data_result(view)
+}
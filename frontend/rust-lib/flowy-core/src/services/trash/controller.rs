@@ -1,19 +1,33 @@
 use crate::{
     dart_notification::{send_anonymous_dart_notification, WorkspaceNotification},
-    entities::trash::{RepeatedTrash, RepeatedTrashId, Trash, TrashId, TrashType},
+    entities::trash::{
+        RepeatedTrash, RepeatedTrashId, Trash, TrashId, TrashRetentionPolicy, TrashType, DEFAULT_TRASH_RETENTION_DAYS,
+    },
     errors::{FlowyError, FlowyResult},
     module::{FolderCouldServiceV1, WorkspaceUser},
     services::persistence::{FolderPersistence, FolderPersistenceTransaction},
 };
-
-use std::{fmt::Formatter, sync::Arc};
+use chrono::Utc;
+use flowy_database::kv::KV;
+use std::{
+    fmt::Formatter,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 use tokio::sync::{broadcast, mpsc};
 
+const TRASH_RETENTION_DAYS: &str = "trash_retention_days";
+const TRASH_PURGE_INTERVAL_IN_SECONDS: u64 = 60 * 60;
+
 pub struct TrashController {
     persistence: Arc<FolderPersistence>,
     notify: broadcast::Sender<TrashEvent>,
     cloud_service: Arc<dyn FolderCouldServiceV1>,
     user: Arc<dyn WorkspaceUser>,
+    is_purging_scheduled: AtomicBool,
 }
 
 impl TrashController {
@@ -28,9 +42,77 @@ impl TrashController {
             notify: tx,
             cloud_service,
             user,
+            is_purging_scheduled: AtomicBool::new(false),
         }
     }
 
+    pub(crate) fn initialize(self: &Arc<Self>) -> FlowyResult<()> {
+        if self.is_purging_scheduled.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let weak_controller = Arc::downgrade(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(TRASH_PURGE_INTERVAL_IN_SECONDS));
+            loop {
+                interval.tick().await;
+                match weak_controller.upgrade() {
+                    None => break,
+                    Some(controller) => match controller.purge_expired_trash(Utc::now().timestamp()).await {
+                        Ok(_) => {}
+                        Err(e) => log::error!("Purge expired trash failed: {:?}", e),
+                    },
+                }
+            }
+        });
+        Ok(())
+    }
+
+    // The retention policy is kept for each user, so the users that sign in on the same device don't
+    // share it.
+    pub fn read_retention_policy(&self) -> FlowyResult<TrashRetentionPolicy> {
+        let key = self.retention_days_key()?;
+        let retention_days = KV::get_int(&key).unwrap_or(DEFAULT_TRASH_RETENTION_DAYS);
+        Ok(TrashRetentionPolicy::new(retention_days))
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn update_retention_policy(&self, policy: TrashRetentionPolicy) -> FlowyResult<()> {
+        if policy.retention_days < 0 {
+            return Err(FlowyError::internal().context("The retention days of the trash should not be negative"));
+        }
+        KV::set_int(&self.retention_days_key()?, policy.retention_days);
+        let _ = self.purge_expired_trash(Utc::now().timestamp()).await?;
+        Ok(())
+    }
+
+    // Deletes the trash that has outlived the retention policy at the time `now` permanently. It goes
+    // through the same path as deleting the trash manually, so the apps and views will receive the
+    // TrashEvent::Delete.
+    #[tracing::instrument(level = "debug", skip(self), fields(n_expired), err)]
+    pub async fn purge_expired_trash(&self, now: i64) -> FlowyResult<()> {
+        let policy = self.read_retention_policy()?;
+        if !policy.is_enabled() {
+            return Ok(());
+        }
+
+        let expired_trash = self
+            .persistence
+            .begin_transaction(|transaction| transaction.read_trash(None))
+            .await?
+            .into_inner()
+            .into_iter()
+            .filter(|trash| trash.is_expired(&policy, now))
+            .collect::<Vec<Trash>>();
+
+        tracing::Span::current().record("n_expired", &expired_trash.len());
+        if expired_trash.is_empty() {
+            return Ok(());
+        }
+
+        self.delete(expired_trash.into()).await
+    }
+
     #[tracing::instrument(level = "debug", skip(self), fields(putback)  err)]
     pub async fn putback(&self, trash_id: &str) -> FlowyResult<()> {
         let (tx, mut rx) = mpsc::channel::<FlowyResult<()>>(1);
@@ -149,7 +231,17 @@ impl TrashController {
     #[tracing::instrument(name = "add_trash", level = "debug", skip(self, trash), fields(trash_ids), err)]
     pub async fn add<T: Into<Trash>>(&self, trash: Vec<T>) -> Result<(), FlowyError> {
         let (tx, mut rx) = mpsc::channel::<FlowyResult<()>>(1);
-        let repeated_trash = trash.into_iter().map(|t| t.into()).collect::<Vec<Trash>>();
+        let deleted_time = Utc::now().timestamp();
+        let deleted_by = self.user.user_id()?;
+        let repeated_trash = trash
+            .into_iter()
+            .map(|t| {
+                let mut trash: Trash = t.into();
+                trash.deleted_time = deleted_time;
+                trash.deleted_by = deleted_by.clone();
+                trash
+            })
+            .collect::<Vec<Trash>>();
         let identifiers = repeated_trash.iter().map(|t| t.into()).collect::<Vec<TrashId>>();
 
         tracing::Span::current().record(
@@ -207,6 +299,11 @@ impl TrashController {
 }
 
 impl TrashController {
+    fn retention_days_key(&self) -> FlowyResult<String> {
+        let user_id = self.user.user_id()?;
+        Ok(format!("{}:{}", TRASH_RETENTION_DAYS, user_id))
+    }
+
     #[tracing::instrument(level = "trace", skip(self, trash), err)]
     fn create_trash_on_server<T: Into<RepeatedTrashId>>(&self, trash: T) -> FlowyResult<()> {
         let token = self.user.token()?;
@@ -275,7 +372,7 @@ impl TrashController {
 }
 
 #[tracing::instrument(skip(repeated_trash), fields(n_trash))]
-fn notify_trash_changed(repeated_trash: RepeatedTrash) {
+pub(crate) fn notify_trash_changed(repeated_trash: RepeatedTrash) {
     tracing::Span::current().record("n_trash", &repeated_trash.len());
     send_anonymous_dart_notification(WorkspaceNotification::TrashUpdated)
         .payload(repeated_trash)
@@ -1,5 +1,5 @@
 use crate::{
-    entities::trash::{RepeatedTrash, RepeatedTrashId, TrashId},
+    entities::trash::{RepeatedTrash, RepeatedTrashId, TrashId, TrashRetentionPolicy},
     errors::FlowyError,
     services::TrashController,
 };
@@ -43,3 +43,20 @@ pub(crate) async fn delete_all_trash_handler(controller: Unit<Arc<TrashControlle
     let _ = controller.delete_all_trash().await?;
     Ok(())
 }
+
+#[tracing::instrument(skip(controller))]
+pub(crate) async fn read_trash_retention_policy_handler(
+    controller: Unit<Arc<TrashController>>,
+) -> DataResult<TrashRetentionPolicy, FlowyError> {
+    let policy = controller.read_retention_policy()?;
+    data_result(policy)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_trash_retention_policy_handler(
+    data: Data<TrashRetentionPolicy>,
+    controller: Unit<Arc<TrashController>>,
+) -> Result<(), FlowyError> {
+    let _ = controller.update_retention_policy(data.into_inner()).await?;
+    Ok(())
+}
@@ -1,32 +1,52 @@
 use bytes::Bytes;
 use flowy_collaboration::entities::{
     document_info::{DocumentDelta, DocumentId},
+    integrity::{DocumentIntegrityReport, DocumentIntegrityRequest},
+    merge_backup::{DocumentMergeBackupId, RepeatedDocumentMergeBackup},
+    outline::{DocumentSection, RepeatedOutlineItem},
     revision::{RepeatedRevision, Revision},
+    statistics::{DocumentStatistics, DocumentStatisticsRequest, OpenDocumentsUsage},
 };
 
-use flowy_collaboration::client_document::default::initial_delta_string;
+use flowy_collaboration::client_document::{default::initial_delta_string, ClientDocument};
 use futures::{FutureExt, StreamExt};
-use std::{collections::HashSet, sync::Arc};
+use lib_ot::{
+    core::Operation,
+    rich_text::{RichTextAttributeKey, RichTextAttributeValue, RichTextDelta},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use crate::{
     dart_notification::{send_dart_notification, WorkspaceNotification},
     entities::{
         trash::{RepeatedTrashId, TrashType},
-        view::{CreateViewParams, RepeatedView, UpdateViewParams, View, ViewId},
+        view::{
+            CreateViewParams, RepeatedView, UpdateViewParams, UpdateViewPropertyParams, UpdateViewTagsParams, View,
+            ViewFilterParams, ViewId, ViewType,
+        },
     },
     errors::{FlowyError, FlowyResult},
     module::{FolderCouldServiceV1, WorkspaceUser},
     services::{
+        get_current_workspace, get_workspace_revision_cipher, notify_trash_changed,
         persistence::{FolderPersistence, FolderPersistenceTransaction, ViewChangeset},
-        TrashController, TrashEvent,
+        BoardController, CommentController, GridController, TemplateContext, TrashController, TrashEvent,
     },
 };
-use flowy_core_data_model::entities::share::{ExportData, ExportParams};
+use flowy_core_data_model::entities::share::{ExportData, ExportParams, ExportType};
 use flowy_database::kv::KV;
 use flowy_document::FlowyDocumentManager;
+use flowy_sync::{mk_revision_disk_cache, RevisionCipher};
 use lib_infra::uuid_string;
 
 const LATEST_VIEW_ID: &str = "latest_view_id";
+const BACKGROUND_SYNC_INTERVAL_IN_SECS: u64 = 60;
+const STALE_DOCUMENT_INTERVAL_IN_SECS: u64 = 30 * 60;
+const MAX_PULLED_DOCUMENTS_PER_SYNC: usize = 10;
 
 pub(crate) struct ViewController {
     user: Arc<dyn WorkspaceUser>,
@@ -34,6 +54,9 @@ pub(crate) struct ViewController {
     persistence: Arc<FolderPersistence>,
     trash_controller: Arc<TrashController>,
     document_manager: Arc<FlowyDocumentManager>,
+    grid_controller: Arc<GridController>,
+    board_controller: Arc<BoardController>,
+    comment_controller: Arc<CommentController>,
 }
 
 impl ViewController {
@@ -43,6 +66,9 @@ impl ViewController {
         cloud_service: Arc<dyn FolderCouldServiceV1>,
         trash_can: Arc<TrashController>,
         document_manager: Arc<FlowyDocumentManager>,
+        grid_controller: Arc<GridController>,
+        board_controller: Arc<BoardController>,
+        comment_controller: Arc<CommentController>,
     ) -> Self {
         Self {
             user,
@@ -50,31 +76,52 @@ impl ViewController {
             persistence,
             trash_controller: trash_can,
             document_manager,
+            grid_controller,
+            board_controller,
+            comment_controller,
         }
     }
 
     pub(crate) fn initialize(&self) -> Result<(), FlowyError> {
         let _ = self.document_manager.init()?;
+        self.apply_current_workspace_cipher();
         self.listen_trash_can_event();
+        self.sync_pending_documents_in_background();
         Ok(())
     }
 
     #[tracing::instrument(level = "trace", skip(self, params), fields(name = %params.name), err)]
     pub(crate) async fn create_view_from_params(&self, params: CreateViewParams) -> Result<View, FlowyError> {
-        let view_data = if params.view_data.is_empty() {
-            initial_delta_string()
-        } else {
-            params.view_data.clone()
-        };
-
-        let delta_data = Bytes::from(view_data);
-        let user_id = self.user.user_id()?;
-        let repeated_revision: RepeatedRevision =
-            Revision::initial_revision(&user_id, &params.view_id, delta_data).into();
-        let _ = self
-            .document_manager
-            .save_document(&params.view_id, repeated_revision)
-            .await?;
+        match params.view_type {
+            ViewType::Grid => {
+                let _ = self
+                    .grid_controller
+                    .create_grid(&params.view_id, params.view_data.clone())
+                    .await?;
+            }
+            ViewType::Board => {
+                let _ = self
+                    .board_controller
+                    .create_board(&params.view_id, params.view_data.clone())
+                    .await?;
+            }
+            _ => {
+                let view_data = if params.view_data.is_empty() {
+                    initial_delta_string()
+                } else {
+                    params.view_data.clone()
+                };
+
+                let delta_data = Bytes::from(view_data);
+                let user_id = self.user.user_id()?;
+                let repeated_revision: RepeatedRevision =
+                    Revision::initial_revision(&user_id, &params.view_id, delta_data).into();
+                let _ = self
+                    .document_manager
+                    .save_document(&params.view_id, repeated_revision)
+                    .await?;
+            }
+        }
         let view = self.create_view_on_server(params).await?;
         let _ = self.create_view_on_local(view.clone()).await?;
 
@@ -117,7 +164,7 @@ impl ViewController {
             .begin_transaction(|transaction| {
                 let view = transaction.read_view(&params.view_id)?;
                 let trash_ids = self.trash_controller.read_trash_ids(&transaction)?;
-                if trash_ids.contains(&view.id) {
+                if is_view_in_trash(&view, &trash_ids, &transaction) {
                     return Err(FlowyError::record_not_found());
                 }
                 Ok(view)
@@ -153,6 +200,9 @@ impl ViewController {
     #[tracing::instrument(level = "debug", skip(self), err)]
     pub(crate) async fn close_view(&self, doc_id: &str) -> Result<(), FlowyError> {
         let _ = self.document_manager.close_document(doc_id)?;
+        let _ = self.grid_controller.close_grid(doc_id)?;
+        let _ = self.board_controller.close_board(doc_id)?;
+        let _ = self.comment_controller.close_comments(doc_id)?;
         Ok(())
     }
 
@@ -164,42 +214,197 @@ impl ViewController {
             }
         }
         let _ = self.document_manager.close_document(&params.doc_id)?;
+        let _ = self.grid_controller.close_grid(&params.doc_id)?;
+        let _ = self.board_controller.close_board(&params.doc_id)?;
+        let _ = self.comment_controller.close_comments(&params.doc_id)?;
         Ok(())
     }
 
+    // Duplicates the view with its nested views. The views in the trash are ignored.
     #[tracing::instrument(level = "debug", skip(self), err)]
-    pub(crate) async fn duplicate_view(&self, doc_id: &str) -> Result<(), FlowyError> {
-        let view = self
-            .persistence
-            .begin_transaction(|transaction| transaction.read_view(doc_id))
-            .await?;
+    pub(crate) async fn duplicate_view(&self, view_id: &str) -> Result<View, FlowyError> {
+        let mut views = self.read_view_tree(view_id).await?;
+        views[0].name = format!("{} (copy)", &views[0].name);
+        let belong_to_id = views[0].belong_to_id.clone();
+        let mut duplicated_views = self.duplicate_views(views, &belong_to_id, &belong_to_id, None).await?;
+        Ok(duplicated_views.remove(0))
+    }
 
-        let editor = self.document_manager.open_document(doc_id).await?;
-        let document_json = editor.document_json().await?;
-        let duplicate_params = CreateViewParams {
-            belong_to_id: view.belong_to_id.clone(),
-            name: format!("{} (copy)", &view.name),
-            desc: view.desc.clone(),
-            thumbnail: "".to_owned(),
-            view_type: view.view_type.clone(),
-            view_data: document_json,
-            view_id: uuid_string(),
-        };
+    // Duplicates the views that belong to from_belong_to_id, including the nested views, and put them
+    // into to_belong_to_id.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn duplicate_belongings(
+        &self,
+        from_belong_to_id: &str,
+        to_belong_to_id: &str,
+    ) -> Result<Vec<View>, FlowyError> {
+        let views = self.read_belonging_view_trees(from_belong_to_id).await?;
+        self.duplicate_views(views, from_belong_to_id, to_belong_to_id, None)
+            .await
+    }
 
-        let _ = self.create_view_from_params(duplicate_params).await?;
-        Ok(())
+    // Returns the view followed by its nested views in preorder. The views in the trash are ignored.
+    pub(crate) async fn read_view_tree(&self, view_id: &str) -> Result<Vec<View>, FlowyError> {
+        self.persistence
+            .begin_transaction(|transaction| {
+                let view = transaction.read_view(view_id)?;
+                let trash_ids = self.trash_controller.read_trash_ids(&transaction)?;
+                if is_view_in_trash(&view, &trash_ids, &transaction) {
+                    return Err(FlowyError::record_not_found());
+                }
+
+                let mut views = vec![view];
+                views.extend(read_nested_views_not_in_trash(view_id, &trash_ids, &transaction)?);
+                Ok(views)
+            })
+            .await
+    }
+
+    // Returns the views that belong to the belong_to_id, each one followed by its nested views.
+    pub(crate) async fn read_belonging_view_trees(&self, belong_to_id: &str) -> Result<Vec<View>, FlowyError> {
+        self.persistence
+            .begin_transaction(|transaction| {
+                let trash_ids = self.trash_controller.read_trash_ids(&transaction)?;
+                let mut views = vec![];
+                for view in read_belonging_views_on_local(belong_to_id, self.trash_controller.clone(), &transaction)?
+                    .into_inner()
+                {
+                    let nested_views = read_nested_views_not_in_trash(&view.id, &trash_ids, &transaction)?;
+                    views.push(view);
+                    views.extend(nested_views);
+                }
+                Ok(views)
+            })
+            .await
+    }
+
+    // The views should be ordered as parents first. Each view gets a new id, and the links in the
+    // documents that point to the duplicated views are replaced with the new ids. The placeholders
+    // in the names and the documents are substituted if the template context is provided.
+    pub(crate) async fn duplicate_views(
+        &self,
+        views: Vec<View>,
+        from_belong_to_id: &str,
+        to_belong_to_id: &str,
+        context: Option<&TemplateContext>,
+    ) -> Result<Vec<View>, FlowyError> {
+        let id_map = views
+            .iter()
+            .map(|view| (view.id.clone(), uuid_string()))
+            .collect::<HashMap<String, String>>();
+
+        let mut duplicated_views = vec![];
+        for view in views {
+            let view_data = match view.view_type {
+                // The grid and the board don't contain any links to the views, so they're copied as they are.
+                ViewType::Grid => self.grid_controller.read_grid_data(&view.id).await?,
+                ViewType::Board => self.board_controller.read_board_data(&view.id).await?,
+                _ => {
+                    let document_json = self.document_manager.read_document_json(&view.id).await?;
+                    rewrite_document(document_json, &id_map, context)?
+                }
+            };
+            let belong_to_id = if view.belong_to_id == from_belong_to_id {
+                to_belong_to_id.to_owned()
+            } else {
+                id_map.get(&view.belong_to_id).cloned().unwrap_or(view.belong_to_id)
+            };
+            let name = match context {
+                None => view.name,
+                Some(context) => context.render(&view.name),
+            };
+
+            let params = CreateViewParams {
+                belong_to_id,
+                name,
+                desc: view.desc,
+                thumbnail: "".to_owned(),
+                view_type: view.view_type,
+                view_data,
+                view_id: id_map[&view.id].clone(),
+            };
+            duplicated_views.push(self.create_view_from_params(params).await?);
+        }
+        Ok(duplicated_views)
     }
 
     #[tracing::instrument(level = "debug", skip(self, params), err)]
     pub(crate) async fn export_doc(&self, params: ExportParams) -> Result<ExportData, FlowyError> {
         let editor = self.document_manager.open_document(&params.doc_id).await?;
         let delta_json = editor.document_json().await?;
+        let data = match params.export_type {
+            ExportType::Markdown => ClientDocument::from_json(&delta_json)?.to_markdown(),
+            ExportType::Html => ClientDocument::from_json(&delta_json)?.to_html(),
+            ExportType::Text | ExportType::Link => delta_json,
+        };
         Ok(ExportData {
-            data: delta_json,
+            data,
             export_type: params.export_type,
         })
     }
 
+    pub(crate) async fn read_document_outline(&self, doc_id: &str) -> Result<RepeatedOutlineItem, FlowyError> {
+        let editor = self.document_manager.open_document(doc_id).await?;
+        let items = editor.outline().await?;
+        Ok(RepeatedOutlineItem { items })
+    }
+
+    // The section is the interval of the header and the content below it, the UI scrolls to the
+    // start of the section.
+    pub(crate) async fn read_document_section(&self, doc_id: &str, index: i64) -> Result<DocumentSection, FlowyError> {
+        let editor = self.document_manager.open_document(doc_id).await?;
+        let section = match index {
+            index if index < 0 => None,
+            index => editor.outline_section(index as usize).await?,
+        };
+        section.ok_or_else(|| FlowyError::record_not_found().context(format!("Can't find the section {}", index)))
+    }
+
+    pub(crate) async fn read_document_statistics(
+        &self,
+        request: DocumentStatisticsRequest,
+    ) -> Result<DocumentStatistics, FlowyError> {
+        self.document_manager.document_statistics(request).await
+    }
+
+    pub(crate) async fn check_document_integrity(
+        &self,
+        request: DocumentIntegrityRequest,
+    ) -> Result<DocumentIntegrityReport, FlowyError> {
+        self.document_manager.check_document_integrity(request).await
+    }
+
+    pub(crate) fn read_open_documents_usage(&self) -> OpenDocumentsUsage {
+        self.document_manager.open_documents_usage()
+    }
+
+    pub(crate) fn read_document_merge_backups(&self, view_id: &str) -> Result<RepeatedDocumentMergeBackup, FlowyError> {
+        let items = self.document_manager.read_merge_backups(view_id)?;
+        Ok(RepeatedDocumentMergeBackup { items })
+    }
+
+    // The backups of the folder, the grids and the boards are not restored, so the backup must
+    // belong to the document of a view.
+    #[tracing::instrument(level = "debug", skip(self, params), fields(doc_id = %params.doc_id), err)]
+    pub(crate) async fn restore_document_merge_backup(
+        &self,
+        params: DocumentMergeBackupId,
+    ) -> Result<DocumentDelta, FlowyError> {
+        let view = self
+            .persistence
+            .begin_transaction(|transaction| transaction.read_view(&params.doc_id))
+            .await?;
+        if view.view_type != ViewType::Doc {
+            let msg = format!("The view {} is not a document", params.doc_id);
+            return Err(FlowyError::internal().context(msg));
+        }
+        self.document_manager.restore_merge_backup(params).await
+    }
+
+    pub(crate) fn delete_document_merge_backup(&self, params: DocumentMergeBackupId) -> Result<(), FlowyError> {
+        self.document_manager.delete_merge_backup(params)
+    }
+
     // belong_to_id will be the app_id or view_id.
     #[tracing::instrument(level = "debug", skip(self), err)]
     pub(crate) async fn read_views_belong_to(&self, belong_to_id: &str) -> Result<RepeatedView, FlowyError> {
@@ -231,6 +436,55 @@ impl ViewController {
         Ok(view)
     }
 
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn update_view_tags(&self, params: UpdateViewTagsParams) -> Result<View, FlowyError> {
+        self.persistence
+            .begin_transaction(|transaction| {
+                let _ = transaction.update_view_tags(&params.view_id, params.tags)?;
+                let view = transaction.read_view(&params.view_id)?;
+                send_dart_notification(&view.id, WorkspaceNotification::ViewUpdated)
+                    .payload(view.clone())
+                    .send();
+                let _ = notify_views_changed(&view.belong_to_id, self.trash_controller.clone(), &transaction)?;
+                Ok(view)
+            })
+            .await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn update_view_property(&self, params: UpdateViewPropertyParams) -> Result<View, FlowyError> {
+        self.persistence
+            .begin_transaction(|transaction| {
+                let _ = transaction.update_view_property(&params.view_id, &params.key, params.property)?;
+                let view = transaction.read_view(&params.view_id)?;
+                send_dart_notification(&view.id, WorkspaceNotification::ViewUpdated)
+                    .payload(view.clone())
+                    .send();
+                let _ = notify_views_changed(&view.belong_to_id, self.trash_controller.clone(), &transaction)?;
+                Ok(view)
+            })
+            .await
+    }
+
+    // Returns the views of the current workspace that match the filter. The views in the trash are ignored.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn query_views(&self, params: ViewFilterParams) -> Result<RepeatedView, FlowyError> {
+        let workspace_id = get_current_workspace()?;
+        self.persistence
+            .begin_transaction(|transaction| {
+                let trash_ids = self.trash_controller.read_trash_ids(&transaction)?;
+                let mut views = vec![];
+                for app in transaction.read_workspace_apps(&workspace_id)? {
+                    if trash_ids.contains(&app.id) {
+                        continue;
+                    }
+                    filter_views(app.belongings.into_inner(), &params, &trash_ids, &mut views);
+                }
+                Ok(RepeatedView { items: views })
+            })
+            .await
+    }
+
     pub(crate) async fn receive_document_delta(&self, params: DocumentDelta) -> Result<DocumentDelta, FlowyError> {
         let doc = self.document_manager.receive_local_delta(params).await?;
         Ok(doc)
@@ -252,6 +506,32 @@ impl ViewController {
     pub(crate) fn set_latest_view(&self, view: &View) {
         KV::set_str(LATEST_VIEW_ID, view.id.clone());
     }
+
+    // The documents of the current workspace are encrypted with the cipher of the workspace, it
+    // should be called after the current workspace was changed.
+    pub(crate) fn apply_current_workspace_cipher(&self) {
+        let cipher = get_current_workspace()
+            .ok()
+            .and_then(|workspace_id| get_workspace_revision_cipher(&self.persistence, &workspace_id));
+        self.document_manager.set_revision_cipher(cipher);
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, cipher), err)]
+    pub(crate) async fn encrypt_workspace_documents(
+        &self,
+        workspace_id: &str,
+        cipher: &RevisionCipher,
+    ) -> FlowyResult<()> {
+        let doc_ids = self
+            .persistence
+            .begin_transaction(|transaction| read_workspace_document_ids(&transaction, workspace_id))
+            .await?;
+
+        for doc_id in doc_ids {
+            let _ = self.document_manager.encrypt_remote_document(&doc_id, cipher).await?;
+        }
+        Ok(())
+    }
 }
 
 impl ViewController {
@@ -306,10 +586,33 @@ impl ViewController {
         Ok(())
     }
 
+    // The documents that have the revisions that are not acked are synced in the background, even
+    // if they are not opened, so the edits reach the other users without opening the documents. The
+    // documents that were never opened on this device or not pulled for a while are synced too, so
+    // they receive the edits of the other users.
+    fn sync_pending_documents_in_background(&self) {
+        let user = self.user.clone();
+        let persistence = self.persistence.clone();
+        let document_manager = self.document_manager.clone();
+        let _ = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(BACKGROUND_SYNC_INTERVAL_IN_SECS));
+            let mut pulled_times = HashMap::new();
+            loop {
+                interval.tick().await;
+                match read_background_sync_document_ids(&user, &persistence, &mut pulled_times).await {
+                    Ok(doc_ids) => document_manager.sync_documents_in_background(doc_ids),
+                    Err(e) => tracing::debug!("Read the pending documents failed: {}", e),
+                }
+            }
+        });
+    }
+
     fn listen_trash_can_event(&self) {
         let mut rx = self.trash_controller.subscribe();
         let persistence = self.persistence.clone();
         let document_manager = self.document_manager.clone();
+        let grid_controller = self.grid_controller.clone();
+        let board_controller = self.board_controller.clone();
         let trash_controller = self.trash_controller.clone();
         let _ = tokio::spawn(async move {
             loop {
@@ -324,6 +627,8 @@ impl ViewController {
                     handle_trash_event(
                         persistence.clone(),
                         document_manager.clone(),
+                        grid_controller.clone(),
+                        board_controller.clone(),
                         trash_controller.clone(),
                         event,
                     )
@@ -334,10 +639,15 @@ impl ViewController {
     }
 }
 
-#[tracing::instrument(level = "trace", skip(persistence, document_manager, trash_can))]
+#[tracing::instrument(
+    level = "trace",
+    skip(persistence, document_manager, grid_controller, board_controller, trash_can)
+)]
 async fn handle_trash_event(
     persistence: Arc<FolderPersistence>,
     document_manager: Arc<FlowyDocumentManager>,
+    grid_controller: Arc<GridController>,
+    board_controller: Arc<BoardController>,
     trash_can: Arc<TrashController>,
     event: TrashEvent,
 ) {
@@ -348,6 +658,13 @@ async fn handle_trash_event(
                     let views = read_local_views_with_transaction(identifiers, &transaction)?;
                     for view in views {
                         let _ = notify_views_changed(&view.belong_to_id, trash_can.clone(), &transaction)?;
+                        // The nested views are moved to the trash along with their parent.
+                        for nested_view in read_nested_views(&view.id, &transaction)? {
+                            let _ = document_manager.close_document(&nested_view.id)?;
+                            let _ = grid_controller.close_grid(&nested_view.id)?;
+                            let _ = board_controller.close_board(&nested_view.id)?;
+                            notify_dart(nested_view, WorkspaceNotification::ViewDeleted);
+                        }
                         notify_dart(view, WorkspaceNotification::ViewDeleted);
                     }
                     Ok(())
@@ -359,8 +676,31 @@ async fn handle_trash_event(
             let result = persistence
                 .begin_transaction(|transaction| {
                     let views = read_local_views_with_transaction(identifiers, &transaction)?;
-                    for view in views {
+                    // Restore the ancestors that are in the trash too, otherwise the view is still invisible.
+                    let trash_ids = trash_can.read_trash_ids(&transaction)?;
+                    let mut ancestors = vec![];
+                    for view in &views {
+                        let mut trashed_ancestors = read_ancestor_views(view, &transaction)
+                            .into_iter()
+                            .filter(|ancestor| trash_ids.contains(&ancestor.id))
+                            .collect::<Vec<View>>();
+                        ancestors.append(&mut trashed_ancestors);
+                    }
+
+                    if !ancestors.is_empty() {
+                        let ancestor_ids = ancestors.iter().map(|view| view.id.clone()).collect::<Vec<String>>();
+                        let _ = transaction.delete_trash(Some(ancestor_ids))?;
+                        notify_trash_changed(transaction.read_trash(None)?);
+                    }
+
+                    let trash_ids = trash_can.read_trash_ids(&transaction)?;
+                    for view in views.into_iter().chain(ancestors.into_iter()) {
                         let _ = notify_views_changed(&view.belong_to_id, trash_can.clone(), &transaction)?;
+                        for nested_view in read_nested_views(&view.id, &transaction)? {
+                            if !is_view_in_trash(&nested_view, &trash_ids, &transaction) {
+                                notify_dart(nested_view, WorkspaceNotification::ViewRestored);
+                            }
+                        }
                         notify_dart(view, WorkspaceNotification::ViewRestored);
                     }
                     Ok(())
@@ -374,8 +714,25 @@ async fn handle_trash_event(
                     let mut notify_ids = HashSet::new();
                     for identifier in identifiers.items {
                         let view = transaction.read_view(&identifier.id)?;
+                        // Deleting the view removes its nested views as well, so the documents and the
+                        // trash of the nested views should be removed too.
+                        let nested_view_ids = read_nested_views(&view.id, &transaction)?
+                            .into_iter()
+                            .map(|nested_view| nested_view.id)
+                            .collect::<Vec<String>>();
+                        for nested_view_id in &nested_view_ids {
+                            let _ = document_manager.delete(nested_view_id)?;
+                            let _ = grid_controller.close_grid(nested_view_id)?;
+                            let _ = board_controller.close_board(nested_view_id)?;
+                        }
+                        if !nested_view_ids.is_empty() {
+                            let _ = transaction.delete_trash(Some(nested_view_ids))?;
+                        }
+
                         let _ = transaction.delete_view(&identifier.id)?;
                         let _ = document_manager.delete(&identifier.id)?;
+                        let _ = grid_controller.close_grid(&identifier.id)?;
+                        let _ = board_controller.close_board(&identifier.id)?;
                         notify_ids.insert(view.belong_to_id);
                     }
 
@@ -426,9 +783,223 @@ fn read_belonging_views_on_local<'a>(
     trash_controller: Arc<TrashController>,
     transaction: &'a (dyn FolderPersistenceTransaction + 'a),
 ) -> FlowyResult<RepeatedView> {
-    let mut views = transaction.read_views(belong_to_id)?;
     let trash_ids = trash_controller.read_trash_ids(transaction)?;
+    // The belong_to_id may be the id of a view. Its belongings are invisible if it is in the trash.
+    if let Ok(parent_view) = transaction.read_view(belong_to_id) {
+        if is_view_in_trash(&parent_view, &trash_ids, transaction) {
+            return Ok(RepeatedView::default());
+        }
+    }
+
+    let mut views = transaction.read_views(belong_to_id)?;
     views.retain(|view_table| !trash_ids.contains(&view_table.id));
 
     Ok(RepeatedView { items: views })
 }
+
+// The matched views are returned without their belongings.
+fn filter_views(views: Vec<View>, params: &ViewFilterParams, trash_ids: &[String], matched_views: &mut Vec<View>) {
+    for mut view in views {
+        if trash_ids.contains(&view.id) {
+            continue;
+        }
+
+        let belongings = std::mem::take(&mut view.belongings).into_inner();
+        if params.is_match(&view) {
+            matched_views.push(view);
+        }
+        filter_views(belongings, params, trash_ids, matched_views);
+    }
+}
+
+// Returns true if the view or any of its ancestor views is in the trash.
+pub(crate) fn is_view_in_trash<'a>(
+    view: &View,
+    trash_ids: &[String],
+    transaction: &'a (dyn FolderPersistenceTransaction + 'a),
+) -> bool {
+    if trash_ids.contains(&view.id) {
+        return true;
+    }
+
+    read_ancestor_views(view, transaction)
+        .iter()
+        .any(|ancestor| trash_ids.contains(&ancestor.id))
+}
+
+// Walks up the belong_to_id until reaching the app. It stops at the view that was visited before,
+// so the corrupted belong_to_id that forms a cycle doesn't loop forever.
+fn read_ancestor_views<'a>(view: &View, transaction: &'a (dyn FolderPersistenceTransaction + 'a)) -> Vec<View> {
+    let mut ancestors = vec![];
+    let mut visited_ids = HashSet::new();
+    visited_ids.insert(view.id.clone());
+    let mut belong_to_id = view.belong_to_id.clone();
+    while let Ok(parent_view) = transaction.read_view(&belong_to_id) {
+        if !visited_ids.insert(parent_view.id.clone()) {
+            tracing::error!(
+                "The ancestors of the view {} form a cycle at {}",
+                view.id,
+                parent_view.id
+            );
+            break;
+        }
+        belong_to_id = parent_view.belong_to_id.clone();
+        ancestors.push(parent_view);
+    }
+    ancestors
+}
+
+pub(crate) fn read_nested_views<'a>(
+    view_id: &str,
+    transaction: &'a (dyn FolderPersistenceTransaction + 'a),
+) -> FlowyResult<Vec<View>> {
+    let mut nested_views = vec![];
+    for view in transaction.read_views(view_id)? {
+        let mut views = read_nested_views(&view.id, transaction)?;
+        nested_views.push(view);
+        nested_views.append(&mut views);
+    }
+    Ok(nested_views)
+}
+
+fn read_nested_views_not_in_trash<'a>(
+    view_id: &str,
+    trash_ids: &[String],
+    transaction: &'a (dyn FolderPersistenceTransaction + 'a),
+) -> FlowyResult<Vec<View>> {
+    let mut nested_views = vec![];
+    for view in transaction.read_views(view_id)? {
+        if trash_ids.contains(&view.id) {
+            continue;
+        }
+        let mut views = read_nested_views_not_in_trash(&view.id, trash_ids, transaction)?;
+        nested_views.push(view);
+        nested_views.append(&mut views);
+    }
+    Ok(nested_views)
+}
+
+// Replace the view ids in the link attributes with the ids in the id_map, and substitute the placeholders
+// in the text if the context is provided.
+fn rewrite_document(
+    document_json: String,
+    id_map: &HashMap<String, String>,
+    context: Option<&TemplateContext>,
+) -> FlowyResult<String> {
+    let delta = RichTextDelta::from_json(&document_json).map_err(|e| FlowyError::internal().context(e))?;
+    let mut new_delta = RichTextDelta::new();
+    let mut is_changed = false;
+    for op in delta.ops.into_iter() {
+        let mut attributes = op.get_attributes();
+        let new_link = match attributes.get(&RichTextAttributeKey::Link) {
+            Some(RichTextAttributeValue(Some(link))) => id_map
+                .iter()
+                .find(|(old_id, _)| link.contains(old_id.as_str()))
+                .map(|(old_id, new_id)| link.replace(old_id.as_str(), new_id)),
+            _ => None,
+        };
+        if let Some(new_link) = new_link {
+            attributes.add_kv(RichTextAttributeKey::Link, RichTextAttributeValue(Some(new_link)));
+            is_changed = true;
+        }
+
+        match (op, context) {
+            (Operation::Insert(insert), Some(context)) => {
+                let s = context.render(&insert.s);
+                if s != insert.s.as_str() {
+                    is_changed = true;
+                }
+                new_delta.insert(&s, attributes);
+            }
+            (mut op, _) => {
+                op.set_attributes(attributes);
+                new_delta.add(op);
+            }
+        }
+    }
+
+    if is_changed {
+        Ok(new_delta.to_json())
+    } else {
+        Ok(document_json)
+    }
+}
+
+// Returns the documents that have the revisions that are not acked, followed by at most
+// MAX_PULLED_DOCUMENTS_PER_SYNC documents of the current workspace that were not pulled in the last
+// STALE_DOCUMENT_INTERVAL_IN_SECS. The documents that were never opened on this device are pulled
+// first. The rev_table contains the revisions of all kinds of objects, only the ids of the document
+// views are returned.
+async fn read_background_sync_document_ids(
+    user: &Arc<dyn WorkspaceUser>,
+    persistence: &Arc<FolderPersistence>,
+    pulled_times: &mut HashMap<String, Instant>,
+) -> FlowyResult<Vec<String>> {
+    let user_id = user.user_id()?;
+    let disk_cache = mk_revision_disk_cache(&user_id, persistence.db_pool()?);
+    let pending_object_ids = disk_cache
+        .read_pending_revision_counts()?
+        .into_iter()
+        .map(|(object_id, _)| object_id)
+        .collect::<Vec<String>>();
+    let local_object_ids = disk_cache.read_object_ids()?;
+    let workspace_id = get_current_workspace().ok();
+
+    let (mut doc_ids, workspace_doc_ids) = persistence
+        .begin_transaction(|transaction| {
+            let pending_doc_ids = pending_object_ids
+                .into_iter()
+                .filter(|object_id| match transaction.read_view(object_id) {
+                    Ok(view) => !matches!(view.view_type, ViewType::Grid | ViewType::Board),
+                    Err(_) => false,
+                })
+                .collect::<Vec<String>>();
+            let workspace_doc_ids = match workspace_id {
+                None => vec![],
+                Some(workspace_id) => read_workspace_document_ids(&transaction, &workspace_id)?,
+            };
+            Ok((pending_doc_ids, workspace_doc_ids))
+        })
+        .await?;
+
+    let now = Instant::now();
+    let stale_duration = Duration::from_secs(STALE_DOCUMENT_INTERVAL_IN_SECS);
+    let mut stale_doc_ids = workspace_doc_ids
+        .into_iter()
+        .filter(|doc_id| !doc_ids.contains(doc_id))
+        .filter(|doc_id| match pulled_times.get(doc_id) {
+            None => true,
+            Some(pulled_time) => now.duration_since(*pulled_time) >= stale_duration,
+        })
+        .collect::<Vec<String>>();
+    stale_doc_ids.sort_by_key(|doc_id| (local_object_ids.contains(doc_id), pulled_times.get(doc_id).cloned()));
+    stale_doc_ids.truncate(MAX_PULLED_DOCUMENTS_PER_SYNC);
+    doc_ids.extend(stale_doc_ids);
+
+    for doc_id in &doc_ids {
+        pulled_times.insert(doc_id.clone(), now);
+    }
+    Ok(doc_ids)
+}
+
+// Returns the ids of the documents of the workspace, including the nested ones.
+fn read_workspace_document_ids<'a>(
+    transaction: &'a (dyn FolderPersistenceTransaction + 'a),
+    workspace_id: &str,
+) -> FlowyResult<Vec<String>> {
+    let mut doc_ids = vec![];
+    let mut belong_to_ids = transaction
+        .read_workspace_apps(workspace_id)?
+        .into_iter()
+        .map(|app| app.id)
+        .collect::<Vec<String>>();
+    while let Some(belong_to_id) = belong_to_ids.pop() {
+        for view in transaction.read_views(&belong_to_id)? {
+            if !matches!(view.view_type, ViewType::Grid | ViewType::Board) {
+                doc_ids.push(view.id.clone());
+            }
+            belong_to_ids.push(view.id);
+        }
+    }
+    Ok(doc_ids)
+}
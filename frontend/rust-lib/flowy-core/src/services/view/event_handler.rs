@@ -2,15 +2,28 @@ use crate::{
     entities::{
         trash::Trash,
         view::{
-            CreateViewParams, CreateViewRequest, QueryViewRequest, RepeatedViewId, UpdateViewParams, UpdateViewRequest,
-            View, ViewId,
+            CreateViewParams, CreateViewRequest, QueryViewRequest, RepeatedView, RepeatedViewId, UpdateViewParams,
+            UpdateViewPropertyParams, UpdateViewPropertyRequest, UpdateViewRequest, UpdateViewTagsParams,
+            UpdateViewTagsRequest, View, ViewFilterParams, ViewFilterRequest, ViewId,
         },
     },
     errors::FlowyError,
     services::{TrashController, ViewController},
 };
-use flowy_collaboration::entities::document_info::DocumentDelta;
-use flowy_core_data_model::entities::share::{ExportData, ExportParams, ExportRequest};
+use flowy_collaboration::{
+    client_document::highlight_code,
+    entities::{
+        document_info::DocumentDelta,
+        integrity::{DocumentIntegrityReport, DocumentIntegrityRequest},
+        merge_backup::{DocumentMergeBackupId, RepeatedDocumentMergeBackup},
+        outline::{DocumentSection, JumpToSectionRequest, RepeatedOutlineItem},
+        statistics::{DocumentStatistics, DocumentStatisticsRequest, OpenDocumentsUsage},
+    },
+};
+use flowy_core_data_model::entities::{
+    highlight::{HighlightCodeRequest, RepeatedHighlightSpan},
+    share::{ExportData, ExportParams, ExportRequest},
+};
 use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
 use std::{convert::TryInto, sync::Arc};
 
@@ -47,6 +60,36 @@ pub(crate) async fn update_view_handler(
     Ok(())
 }
 
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_view_tags_handler(
+    data: Data<UpdateViewTagsRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<View, FlowyError> {
+    let params: UpdateViewTagsParams = data.into_inner().try_into()?;
+    let view = controller.update_view_tags(params).await?;
+    data_result(view)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_view_property_handler(
+    data: Data<UpdateViewPropertyRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<View, FlowyError> {
+    let params: UpdateViewPropertyParams = data.into_inner().try_into()?;
+    let view = controller.update_view_property(params).await?;
+    data_result(view)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn query_views_handler(
+    data: Data<ViewFilterRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedView, FlowyError> {
+    let params: ViewFilterParams = data.into_inner().try_into()?;
+    let views = controller.query_views(params).await?;
+    data_result(views)
+}
+
 pub(crate) async fn document_delta_handler(
     data: Data<DocumentDelta>,
     controller: Unit<Arc<ViewController>>,
@@ -55,6 +98,80 @@ pub(crate) async fn document_delta_handler(
     data_result(doc)
 }
 
+pub(crate) async fn highlight_code_handler(
+    data: Data<HighlightCodeRequest>,
+) -> DataResult<RepeatedHighlightSpan, FlowyError> {
+    let HighlightCodeRequest { language, code } = data.into_inner();
+    let items = highlight_code(&language, &code);
+    data_result(RepeatedHighlightSpan { items })
+}
+
+pub(crate) async fn read_document_outline_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedOutlineItem, FlowyError> {
+    let params: ViewId = data.into_inner().try_into()?;
+    let outline = controller.read_document_outline(&params.view_id).await?;
+    data_result(outline)
+}
+
+pub(crate) async fn jump_to_section_handler(
+    data: Data<JumpToSectionRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocumentSection, FlowyError> {
+    let JumpToSectionRequest { doc_id, index } = data.into_inner();
+    let section = controller.read_document_section(&doc_id, index).await?;
+    data_result(section)
+}
+
+pub(crate) async fn read_document_statistics_handler(
+    data: Data<DocumentStatisticsRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocumentStatistics, FlowyError> {
+    let statistics = controller.read_document_statistics(data.into_inner()).await?;
+    data_result(statistics)
+}
+
+pub(crate) async fn check_document_integrity_handler(
+    data: Data<DocumentIntegrityRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocumentIntegrityReport, FlowyError> {
+    let report = controller.check_document_integrity(data.into_inner()).await?;
+    data_result(report)
+}
+
+pub(crate) async fn read_document_merge_backups_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedDocumentMergeBackup, FlowyError> {
+    let params: ViewId = data.into_inner().try_into()?;
+    let backups = controller.read_document_merge_backups(&params.view_id)?;
+    data_result(backups)
+}
+
+pub(crate) async fn restore_document_merge_backup_handler(
+    data: Data<DocumentMergeBackupId>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocumentDelta, FlowyError> {
+    let document_delta = controller.restore_document_merge_backup(data.into_inner()).await?;
+    data_result(document_delta)
+}
+
+pub(crate) async fn delete_document_merge_backup_handler(
+    data: Data<DocumentMergeBackupId>,
+    controller: Unit<Arc<ViewController>>,
+) -> Result<(), FlowyError> {
+    let _ = controller.delete_document_merge_backup(data.into_inner())?;
+    Ok(())
+}
+
+pub(crate) async fn read_open_documents_usage_handler(
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<OpenDocumentsUsage, FlowyError> {
+    let usage = controller.read_open_documents_usage();
+    data_result(usage)
+}
+
 pub(crate) async fn delete_view_handler(
     data: Data<QueryViewRequest>,
     view_controller: Unit<Arc<ViewController>>,
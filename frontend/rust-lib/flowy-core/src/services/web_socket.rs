@@ -1,4 +1,4 @@
-use crate::services::FOLDER_SYNC_INTERVAL_IN_MILLIS;
+use crate::services::{plain_text_editor::PlainTextPad, FOLDER_SYNC_INTERVAL_IN_MILLIS};
 use bytes::Bytes;
 use flowy_collaboration::{
     entities::{
@@ -30,11 +30,11 @@ pub(crate) async fn make_folder_ws_manager(
         rev_manager,
     );
 
-    let ws_stream_consumer = Arc::new(FolderWSStreamConsumerAdapter {
+    let ws_stream_consumer = Arc::new(PlainTextWSStreamConsumerAdapter {
         resolver: Arc::new(resolver),
     });
 
-    let sink_provider = Arc::new(FolderWSSinkDataProviderAdapter(composite_sink_provider));
+    let sink_provider = Arc::new(PlainTextWSSinkDataProviderAdapter(composite_sink_provider));
     let ping_duration = Duration::from_millis(FOLDER_SYNC_INTERVAL_IN_MILLIS);
     Arc::new(RevisionWebSocketManager::new(
         "Folder",
@@ -46,8 +46,41 @@ pub(crate) async fn make_folder_ws_manager(
     ))
 }
 
-pub(crate) struct FolderWSSinkDataProviderAdapter(Arc<CompositeWSSinkDataProvider>);
-impl RevisionWSSinkDataProvider for FolderWSSinkDataProviderAdapter {
+pub(crate) async fn make_plain_text_ws_manager<P: PlainTextPad>(
+    object_name: &'static str,
+    user_id: &str,
+    object_id: &str,
+    rev_manager: Arc<RevisionManager>,
+    web_socket: Arc<dyn RevisionWebSocket>,
+    pad: Arc<RwLock<P>>,
+    ping_duration: Duration,
+) -> Arc<RevisionWebSocketManager> {
+    let composite_sink_provider = Arc::new(CompositeWSSinkDataProvider::new(object_id, rev_manager.clone()));
+    let resolve_target = Arc::new(PlainTextRevisionResolveTarget { pad });
+    let resolver = RevisionConflictResolver::<PlainTextAttributes>::new(
+        user_id,
+        resolve_target,
+        Arc::new(composite_sink_provider.clone()),
+        rev_manager,
+    );
+
+    let ws_stream_consumer = Arc::new(PlainTextWSStreamConsumerAdapter {
+        resolver: Arc::new(resolver),
+    });
+
+    let sink_provider = Arc::new(PlainTextWSSinkDataProviderAdapter(composite_sink_provider));
+    Arc::new(RevisionWebSocketManager::new(
+        object_name,
+        object_id,
+        web_socket,
+        sink_provider,
+        ws_stream_consumer,
+        ping_duration,
+    ))
+}
+
+pub(crate) struct PlainTextWSSinkDataProviderAdapter(Arc<CompositeWSSinkDataProvider>);
+impl RevisionWSSinkDataProvider for PlainTextWSSinkDataProviderAdapter {
     fn next(&self) -> FutureResult<Option<ClientRevisionWSData>, FlowyError> {
         let sink_provider = self.0.clone();
         FutureResult::new(async move { sink_provider.next().await })
@@ -101,11 +134,47 @@ impl ResolverTarget<PlainTextAttributes> for FolderRevisionResolveTarget {
     }
 }
 
-struct FolderWSStreamConsumerAdapter {
+struct PlainTextRevisionResolveTarget<P: PlainTextPad> {
+    pad: Arc<RwLock<P>>,
+}
+
+impl<P: PlainTextPad> ResolverTarget<PlainTextAttributes> for PlainTextRevisionResolveTarget<P> {
+    fn compose_delta(&self, delta: Delta<PlainTextAttributes>) -> BoxResultFuture<DeltaMD5, FlowyError> {
+        let pad = self.pad.clone();
+        Box::pin(async move {
+            let md5 = pad.write().compose_remote_delta(delta)?;
+            Ok(md5)
+        })
+    }
+
+    fn transform_delta(
+        &self,
+        delta: Delta<PlainTextAttributes>,
+    ) -> BoxResultFuture<TransformDeltas<PlainTextAttributes>, FlowyError> {
+        let pad = self.pad.clone();
+        Box::pin(async move {
+            let (server_prime, client_prime) = pad.read().delta().transform(&delta)?;
+            Ok(TransformDeltas {
+                client_prime,
+                server_prime: Some(server_prime),
+            })
+        })
+    }
+
+    fn reset_delta(&self, delta: Delta<PlainTextAttributes>) -> BoxResultFuture<DeltaMD5, FlowyError> {
+        let pad = self.pad.clone();
+        Box::pin(async move {
+            let md5 = pad.write().reset_delta(delta)?;
+            Ok(md5)
+        })
+    }
+}
+
+struct PlainTextWSStreamConsumerAdapter {
     resolver: Arc<RevisionConflictResolver<PlainTextAttributes>>,
 }
 
-impl RevisionWSSteamConsumer for FolderWSStreamConsumerAdapter {
+impl RevisionWSSteamConsumer for PlainTextWSStreamConsumerAdapter {
     fn receive_push_revision(&self, bytes: Bytes) -> BoxResultFuture<(), FlowyError> {
         let resolver = self.resolver.clone();
         Box::pin(async move { resolver.receive_bytes(bytes).await })
@@ -4,11 +4,18 @@ use crate::{
     module::{FolderCouldServiceV1, WorkspaceUser},
     services::{
         persistence::{FolderPersistence, FolderPersistenceTransaction, WorkspaceChangeset},
-        read_local_workspace_apps, TrashController,
+        read_local_workspace_apps, read_nested_views, BoardController, GridController, TrashController, TrashEvent,
     },
 };
-use flowy_core_data_model::entities::{app::RepeatedApp, workspace::*};
+use flowy_core_data_model::entities::{
+    app::RepeatedApp,
+    trash::{Trash, TrashType},
+    workspace::*,
+};
 use flowy_database::kv::KV;
+use flowy_document::FlowyDocumentManager;
+use flowy_sync::RevisionCipher;
+use futures::{FutureExt, StreamExt};
 use std::sync::Arc;
 
 pub struct WorkspaceController {
@@ -16,6 +23,9 @@ pub struct WorkspaceController {
     persistence: Arc<FolderPersistence>,
     pub(crate) trash_controller: Arc<TrashController>,
     cloud_service: Arc<dyn FolderCouldServiceV1>,
+    document_manager: Arc<FlowyDocumentManager>,
+    grid_controller: Arc<GridController>,
+    board_controller: Arc<BoardController>,
 }
 
 impl WorkspaceController {
@@ -24,15 +34,26 @@ impl WorkspaceController {
         persistence: Arc<FolderPersistence>,
         trash_can: Arc<TrashController>,
         cloud_service: Arc<dyn FolderCouldServiceV1>,
+        document_manager: Arc<FlowyDocumentManager>,
+        grid_controller: Arc<GridController>,
+        board_controller: Arc<BoardController>,
     ) -> Self {
         Self {
             user,
             persistence,
             trash_controller: trash_can,
             cloud_service,
+            document_manager,
+            grid_controller,
+            board_controller,
         }
     }
 
+    pub(crate) fn initialize(&self) -> Result<(), FlowyError> {
+        self.listen_trash_controller_event();
+        Ok(())
+    }
+
     pub(crate) async fn create_workspace_from_params(
         &self,
         params: CreateWorkspaceParams,
@@ -76,21 +97,23 @@ impl WorkspaceController {
         Ok(())
     }
 
-    #[allow(dead_code)]
+    // The workspace is moved to the trash. It will be deleted permanently when the trash is deleted.
+    #[tracing::instrument(level = "debug", skip(self), err)]
     pub(crate) async fn delete_workspace(&self, workspace_id: &str) -> Result<(), FlowyError> {
+        if let Ok(current_workspace_id) = get_current_workspace() {
+            if current_workspace_id == workspace_id {
+                return Err(FlowyError::workspace_id().context("Can't delete the current workspace"));
+            }
+        }
+
         let user_id = self.user.user_id()?;
-        let token = self.user.token()?;
-        let repeated_workspace = self
+        let workspace = self
             .persistence
-            .begin_transaction(|transaction| {
-                let _ = transaction.delete_workspace(workspace_id)?;
-                self.read_local_workspaces(None, &user_id, &transaction)
-            })
+            .begin_transaction(|transaction| self.read_local_workspace(workspace_id.to_owned(), &user_id, &transaction))
             .await?;
-        send_dart_notification(&token, WorkspaceNotification::UserDeleteWorkspace)
-            .payload(repeated_workspace)
-            .send();
-        let _ = self.delete_workspace_on_server(workspace_id)?;
+
+        let trash: Trash = workspace.into();
+        let _ = self.trash_controller.add(vec![trash]).await?;
         Ok(())
     }
 
@@ -128,7 +151,9 @@ impl WorkspaceController {
         transaction: &'a (dyn FolderPersistenceTransaction + 'a),
     ) -> Result<RepeatedWorkspace, FlowyError> {
         let workspace_id = workspace_id.to_owned();
-        let workspaces = transaction.read_workspaces(user_id, workspace_id)?;
+        let mut workspaces = transaction.read_workspaces(user_id, workspace_id)?;
+        let trash_ids = self.trash_controller.read_trash_ids(transaction)?;
+        workspaces.retain(|workspace| !trash_ids.contains(&workspace.id));
         Ok(RepeatedWorkspace { items: workspaces })
     }
 
@@ -138,7 +163,9 @@ impl WorkspaceController {
         user_id: &str,
         transaction: &'a (dyn FolderPersistenceTransaction + 'a),
     ) -> Result<Workspace, FlowyError> {
-        let mut workspaces = transaction.read_workspaces(user_id, Some(workspace_id.clone()))?;
+        let mut workspaces = self
+            .read_local_workspaces(Some(workspace_id.clone()), user_id, transaction)?
+            .into_inner();
         if workspaces.is_empty() {
             return Err(FlowyError::record_not_found().context(format!("{} workspace not found", workspace_id)));
         }
@@ -171,25 +198,113 @@ impl WorkspaceController {
         Ok(())
     }
 
-    #[tracing::instrument(level = "trace", skip(self), err)]
-    fn delete_workspace_on_server(&self, workspace_id: &str) -> Result<(), FlowyError> {
-        let params = WorkspaceId {
-            workspace_id: Some(workspace_id.to_string()),
-        };
-        let (token, server) = (self.user.token()?, self.cloud_service.clone());
-        tokio::spawn(async move {
-            match server.delete_workspace(&token, params).await {
-                Ok(_) => {}
-                Err(e) => {
-                    // TODO: retry?
-                    log::error!("Delete workspace failed: {:?}", e);
+    fn listen_trash_controller_event(&self) {
+        let mut rx = self.trash_controller.subscribe();
+        let user = self.user.clone();
+        let persistence = self.persistence.clone();
+        let trash_controller = self.trash_controller.clone();
+        let document_manager = self.document_manager.clone();
+        let grid_controller = self.grid_controller.clone();
+        let board_controller = self.board_controller.clone();
+        let _ = tokio::spawn(async move {
+            loop {
+                let mut stream = Box::pin(rx.recv().into_stream().filter_map(|result| async move {
+                    match result {
+                        Ok(event) => event.select(TrashType::Workspace),
+                        Err(_e) => None,
+                    }
+                }));
+                if let Some(event) = stream.next().await {
+                    handle_trash_event(
+                        user.clone(),
+                        persistence.clone(),
+                        document_manager.clone(),
+                        grid_controller.clone(),
+                        board_controller.clone(),
+                        trash_controller.clone(),
+                        event,
+                    )
+                    .await
                 }
             }
         });
-        Ok(())
     }
 }
 
+#[tracing::instrument(
+    level = "trace",
+    skip(
+        user,
+        persistence,
+        document_manager,
+        grid_controller,
+        board_controller,
+        trash_controller
+    )
+)]
+async fn handle_trash_event(
+    user: Arc<dyn WorkspaceUser>,
+    persistence: Arc<FolderPersistence>,
+    document_manager: Arc<FlowyDocumentManager>,
+    grid_controller: Arc<GridController>,
+    board_controller: Arc<BoardController>,
+    trash_controller: Arc<TrashController>,
+    event: TrashEvent,
+) {
+    match event {
+        TrashEvent::NewTrash(_, ret) | TrashEvent::Putback(_, ret) => {
+            let result = persistence
+                .begin_transaction(|transaction| {
+                    notify_workspaces_changed(user.clone(), trash_controller.clone(), &transaction)
+                })
+                .await;
+            let _ = ret.send(result).await;
+        }
+        TrashEvent::Delete(identifiers, ret) => {
+            let result = persistence
+                .begin_transaction(|transaction| {
+                    for identifier in identifiers.items {
+                        // Deleting the workspace removes its apps and views as well, so the objects of the
+                        // views and the trash of the apps and views should be removed too.
+                        let mut trash_ids = vec![];
+                        for app in transaction.read_workspace_apps(&identifier.id)? {
+                            for view in read_nested_views(&app.id, &transaction)? {
+                                let _ = document_manager.delete(&view.id)?;
+                                let _ = grid_controller.close_grid(&view.id)?;
+                                let _ = board_controller.close_board(&view.id)?;
+                                trash_ids.push(view.id);
+                            }
+                            trash_ids.push(app.id);
+                        }
+                        if !trash_ids.is_empty() {
+                            let _ = transaction.delete_trash(Some(trash_ids))?;
+                        }
+                        let _ = transaction.delete_workspace(&identifier.id)?;
+                    }
+                    notify_workspaces_changed(user.clone(), trash_controller.clone(), &transaction)
+                })
+                .await;
+            let _ = ret.send(result).await;
+        }
+    }
+}
+
+#[tracing::instrument(skip(user, trash_controller, transaction), err)]
+fn notify_workspaces_changed<'a>(
+    user: Arc<dyn WorkspaceUser>,
+    trash_controller: Arc<TrashController>,
+    transaction: &'a (dyn FolderPersistenceTransaction + 'a),
+) -> FlowyResult<()> {
+    let (token, user_id) = (user.token()?, user.user_id()?);
+    let mut workspaces = transaction.read_workspaces(&user_id, None)?;
+    let trash_ids = trash_controller.read_trash_ids(transaction)?;
+    workspaces.retain(|workspace| !trash_ids.contains(&workspace.id));
+    send_dart_notification(&token, WorkspaceNotification::WorkspaceListUpdated)
+        .payload(RepeatedWorkspace { items: workspaces })
+        .send();
+    Ok(())
+}
+
 const CURRENT_WORKSPACE_ID: &str = "current_workspace_id";
 
 pub fn set_current_workspace(workspace_id: &str) {
@@ -205,3 +320,35 @@ pub fn get_current_workspace() -> Result<String, FlowyError> {
         Some(workspace_id) => Ok(workspace_id),
     }
 }
+
+// The secret that is derived from the passphrase is kept in the user database, so the passphrase is
+// only required once on each device.
+pub(crate) fn set_workspace_revision_cipher(
+    persistence: &FolderPersistence,
+    workspace_id: &str,
+    cipher: &RevisionCipher,
+) -> FlowyResult<()> {
+    persistence.save_workspace_secret(workspace_id, cipher.secret())
+}
+
+// Returns None if the encryption of the workspace is not enabled on this device.
+pub(crate) fn get_workspace_revision_cipher(
+    persistence: &FolderPersistence,
+    workspace_id: &str,
+) -> Option<Arc<RevisionCipher>> {
+    let secret = match persistence.read_workspace_secret(workspace_id) {
+        Ok(Some(secret)) => secret,
+        Ok(None) => return None,
+        Err(e) => {
+            tracing::error!("Read the revision secret of {} failed: {:?}", workspace_id, e);
+            return None;
+        }
+    };
+    match RevisionCipher::from_secret(secret) {
+        Ok(cipher) => Some(Arc::new(cipher)),
+        Err(e) => {
+            tracing::error!("Restore the revision cipher of {} failed: {:?}", workspace_id, e);
+            None
+        }
+    }
+}
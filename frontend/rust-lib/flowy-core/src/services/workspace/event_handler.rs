@@ -2,7 +2,7 @@ use crate::{
     controller::FolderManager,
     dart_notification::{send_dart_notification, WorkspaceNotification},
     errors::FlowyError,
-    services::{get_current_workspace, read_local_workspace_apps, WorkspaceController},
+    services::{get_current_workspace, read_local_workspace_apps, ViewController, WorkspaceController},
 };
 use flowy_core_data_model::entities::{
     app::RepeatedApp,
@@ -13,17 +13,31 @@ use flowy_core_data_model::entities::{
 use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
 use std::{convert::TryInto, sync::Arc};
 
-#[tracing::instrument(skip(data, controller), err)]
+#[tracing::instrument(skip(data, controller, view_controller), err)]
 pub(crate) async fn create_workspace_handler(
     data: Data<CreateWorkspaceRequest>,
     controller: Unit<Arc<WorkspaceController>>,
+    view_controller: Unit<Arc<ViewController>>,
 ) -> DataResult<Workspace, FlowyError> {
     let controller = controller.get_ref().clone();
     let params: CreateWorkspaceParams = data.into_inner().try_into()?;
     let detail = controller.create_workspace_from_params(params).await?;
+    view_controller.apply_current_workspace_cipher();
     data_result(detail)
 }
 
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_workspace_handler(
+    data: Data<QueryWorkspaceRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> Result<(), FlowyError> {
+    let params: WorkspaceId = data.into_inner().try_into()?;
+    match params.workspace_id {
+        None => Err(FlowyError::workspace_id().context("The deleted workspace id should not be empty")),
+        Some(workspace_id) => controller.delete_workspace(&workspace_id).await,
+    }
+}
+
 #[tracing::instrument(skip(controller), err)]
 pub(crate) async fn read_workspace_apps_handler(
     controller: Unit<Arc<WorkspaceController>>,
@@ -32,16 +46,28 @@ pub(crate) async fn read_workspace_apps_handler(
     data_result(repeated_app)
 }
 
-#[tracing::instrument(skip(data, controller), err)]
+#[tracing::instrument(skip(data, controller, view_controller), err)]
 pub(crate) async fn open_workspace_handler(
     data: Data<QueryWorkspaceRequest>,
     controller: Unit<Arc<WorkspaceController>>,
+    view_controller: Unit<Arc<ViewController>>,
 ) -> DataResult<Workspace, FlowyError> {
     let params: WorkspaceId = data.into_inner().try_into()?;
     let workspaces = controller.open_workspace(params).await?;
+    view_controller.apply_current_workspace_cipher();
     data_result(workspaces)
 }
 
+#[tracing::instrument(skip(data, folder), err)]
+pub(crate) async fn set_workspace_encryption_handler(
+    data: Data<WorkspaceEncryptionRequest>,
+    folder: Unit<Arc<FolderManager>>,
+) -> Result<(), FlowyError> {
+    let params: WorkspaceEncryptionParams = data.into_inner().try_into()?;
+    let _ = folder.set_workspace_encryption(params).await?;
+    Ok(())
+}
+
 #[tracing::instrument(skip(data, folder), err)]
 pub(crate) async fn read_workspaces_handler(
     data: Data<QueryWorkspaceRequest>,
@@ -1,7 +1,15 @@
-use crate::script::{invalid_workspace_name_test_case, FolderScript::*, FolderTest};
+use crate::{
+    helper::read_open_documents_usage,
+    script::{invalid_workspace_name_test_case, FolderScript::*, FolderTest},
+};
 use flowy_collaboration::{client_document::default::initial_delta_string, entities::revision::RevisionState};
-use flowy_core::entities::workspace::CreateWorkspaceRequest;
+use flowy_core::entities::{
+    app::ColorStyle, grid::FieldType, view::ViewPropertyType, workspace::CreateWorkspaceRequest,
+};
+use flowy_sync::{RevisionCache, REVISION_WRITE_INTERVAL_IN_MILLIS};
 use flowy_test::{event_builder::*, FlowySDKTest};
+use std::time::Duration;
+use tokio::time::sleep;
 
 #[tokio::test]
 async fn workspace_read_all() {
@@ -95,6 +103,71 @@ async fn workspace_create_with_invalid_name() {
     }
 }
 
+#[tokio::test]
+async fn workspace_delete_then_restore() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![ReadAllWorkspaces]).await;
+    let default_workspace = test.all_workspace[0].clone();
+
+    test.run_scripts(vec![
+        DeleteWorkspace(default_workspace.id.clone()),
+        ReadAllWorkspaces,
+        ReadTrash,
+    ])
+    .await;
+    assert_eq!(test.all_workspace.len(), 1);
+    assert_eq!(test.trash.len(), 1);
+    assert_eq!(test.trash[0].id, default_workspace.id);
+
+    test.run_scripts(vec![
+        RestoreWorkspaceFromTrash(default_workspace.id.clone()),
+        ReadAllWorkspaces,
+        ReadTrash,
+    ])
+    .await;
+    assert_eq!(test.all_workspace.len(), 2);
+    assert_eq!(test.trash.len(), 0);
+}
+
+#[tokio::test]
+async fn workspace_delete_from_trash() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![ReadAllWorkspaces]).await;
+    let default_workspace = test.all_workspace[0].clone();
+    let view = default_workspace.apps[0].belongings[0].clone();
+
+    test.run_scripts(vec![ReadView(view.id.clone()), OpenDocument]).await;
+    let open_count = read_open_documents_usage(&test.sdk).await.count;
+
+    test.run_scripts(vec![
+        DeleteWorkspace(default_workspace.id.clone()),
+        DeleteAllTrash,
+        ReadAllWorkspaces,
+        ReadTrash,
+    ])
+    .await;
+    assert_eq!(test.all_workspace.len(), 1);
+    assert_eq!(test.trash.len(), 0);
+    // The document of the view in the workspace is closed when the workspace is deleted.
+    assert_eq!(read_open_documents_usage(&test.sdk).await.count, open_count - 1);
+}
+
+#[tokio::test]
+async fn workspace_delete_current_workspace() {
+    let mut test = FolderTest::new().await;
+    let workspace = test.workspace.clone();
+    test.run_scripts(vec![
+        DeleteWorkspace(workspace.id.clone()),
+        ReadTrash,
+        ReadAllWorkspaces,
+    ])
+    .await;
+
+    // The current workspace can't be deleted.
+    assert_eq!(test.trash.len(), 0);
+    assert_eq!(test.all_workspace.len(), 2);
+}
+
 #[tokio::test]
 #[should_panic]
 async fn app_delete() {
@@ -134,6 +207,7 @@ async fn app_update() {
         UpdateApp {
             name: Some(new_name.clone()),
             desc: None,
+            color_style: None,
         },
         ReadApp(app.id),
     ])
@@ -213,6 +287,298 @@ async fn view_delete_then_restore() {
     .await;
 }
 
+#[tokio::test]
+#[should_panic]
+async fn nested_view_delete_with_parent() {
+    let mut test = FolderTest::new().await;
+    let parent_view = test.view.clone();
+    test.run_scripts(vec![CreateNestedView {
+        name: "Nested View",
+        desc: "Nested view description",
+    }])
+    .await;
+
+    let nested_view = test.view.clone();
+    test.run_scripts(vec![DeleteViews(vec![parent_view.id]), ReadView(nested_view.id)])
+        .await;
+}
+
+#[tokio::test]
+async fn nested_view_delete_then_restore_with_parent() {
+    let mut test = FolderTest::new().await;
+    let parent_view = test.view.clone();
+    test.run_scripts(vec![
+        CreateNestedView {
+            name: "Nested View",
+            desc: "Nested view description",
+        },
+        ReadView(parent_view.id.clone()),
+    ])
+    .await;
+    assert_eq!(test.view.belongings.len(), 1);
+    let nested_view = test.view.belongings[0].clone();
+
+    test.run_scripts(vec![DeleteView, ReadTrash]).await;
+    // Only the parent view is moved to the trash.
+    assert_eq!(test.trash.len(), 1);
+
+    test.run_scripts(vec![
+        RestoreViewFromTrash,
+        ReadView(nested_view.id.clone()),
+        AssertView(nested_view),
+        ReadView(parent_view.id),
+    ])
+    .await;
+    assert_eq!(test.view.belongings.len(), 1);
+}
+
+#[tokio::test]
+async fn view_duplicate_with_nested_view() {
+    let mut test = FolderTest::new().await;
+    let app = test.app.clone();
+    let parent_view = test.view.clone();
+    test.run_scripts(vec![
+        CreateNestedView {
+            name: "Nested View",
+            desc: "Nested view description",
+        },
+        ReadView(parent_view.id.clone()),
+    ])
+    .await;
+    let nested_view = test.view.belongings[0].clone();
+
+    test.run_scripts(vec![DuplicateView, ReadApp(app.id)]).await;
+
+    assert_eq!(test.app.belongings.len(), 2);
+    let duplicated_view = test.app.belongings[1].clone();
+    assert_eq!(duplicated_view.name, format!("{} (copy)", parent_view.name));
+    assert_ne!(duplicated_view.id, parent_view.id);
+
+    test.run_scripts(vec![ReadView(duplicated_view.id)]).await;
+    assert_eq!(test.view.belongings.len(), 1);
+    assert_eq!(test.view.belongings[0].name, "Nested View");
+    assert_ne!(test.view.belongings[0].id, nested_view.id);
+}
+
+#[tokio::test]
+async fn app_duplicate_with_views() {
+    let mut test = FolderTest::new().await;
+    let app = test.app.clone();
+    let color_style = ColorStyle {
+        theme_color: "#FF8A65".to_owned(),
+    };
+    test.run_scripts(vec![
+        UpdateApp {
+            name: None,
+            desc: None,
+            color_style: Some(color_style.clone()),
+        },
+        CreateView {
+            name: "View A",
+            desc: "View A description",
+        },
+        CreateNestedView {
+            name: "Nested View",
+            desc: "Nested view description",
+        },
+        DuplicateApp,
+    ])
+    .await;
+
+    assert_eq!(test.app.name, format!("{} (copy)", app.name));
+    assert_ne!(test.app.id, app.id);
+    assert_eq!(test.app.color_style, color_style);
+    assert_eq!(test.app.belongings.len(), 2);
+    let duplicated_view = test.app.belongings[1].clone();
+    test.run_scripts(vec![ReadView(duplicated_view.id)]).await;
+    assert_eq!(test.view.belongings.len(), 1);
+}
+
+#[tokio::test]
+async fn view_update_tags() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![UpdateViewTags(vec!["rust", " draft ", "rust"])])
+        .await;
+    assert_eq!(test.view.tags, vec!["rust".to_owned(), "draft".to_owned()]);
+
+    let view_id = test.view.id.clone();
+    test.run_scripts(vec![UpdateViewTags(vec![]), ReadView(view_id)]).await;
+    assert!(test.view.tags.is_empty());
+}
+
+#[tokio::test]
+async fn view_update_property() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![UpdateViewProperty {
+        key: "status",
+        ty: ViewPropertyType::Select,
+        value: Some("draft"),
+        options: vec!["draft", "review", "published"],
+    }])
+    .await;
+    let property = test.view.get_property("status").unwrap();
+    assert_eq!(property.value, "draft");
+
+    test.run_scripts(vec![UpdateViewProperty {
+        key: "status",
+        ty: ViewPropertyType::Select,
+        value: None,
+        options: vec![],
+    }])
+    .await;
+    assert!(test.view.properties.is_empty());
+}
+
+#[tokio::test]
+#[should_panic]
+async fn view_update_property_with_invalid_value() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![UpdateViewProperty {
+        key: "estimate",
+        ty: ViewPropertyType::Number,
+        value: Some("two days"),
+        options: vec![],
+    }])
+    .await;
+}
+
+#[tokio::test]
+async fn view_query_by_tag_and_property() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![
+        UpdateViewTags(vec!["meeting"]),
+        UpdateViewProperty {
+            key: "status",
+            ty: ViewPropertyType::Select,
+            value: Some("draft"),
+            options: vec!["draft", "published"],
+        },
+        CreateNestedView {
+            name: "Nested View",
+            desc: "",
+        },
+        UpdateViewTags(vec!["meeting"]),
+        UpdateViewProperty {
+            key: "status",
+            ty: ViewPropertyType::Select,
+            value: Some("published"),
+            options: vec!["draft", "published"],
+        },
+        QueryViews {
+            tag: Some("meeting"),
+            property_key: None,
+            property_value: None,
+        },
+    ])
+    .await;
+    assert_eq!(test.views.len(), 2);
+
+    let nested_view_id = test.view.id.clone();
+    test.run_scripts(vec![QueryViews {
+        tag: Some("meeting"),
+        property_key: Some("status"),
+        property_value: Some("published"),
+    }])
+    .await;
+    assert_eq!(test.views.len(), 1);
+    assert_eq!(test.views[0].id, nested_view_id);
+
+    test.run_scripts(vec![
+        DeleteView,
+        QueryViews {
+            tag: Some("meeting"),
+            property_key: None,
+            property_value: None,
+        },
+    ])
+    .await;
+    assert_eq!(test.views.len(), 1);
+}
+
+#[tokio::test]
+async fn template_create_view_from_view_template() {
+    let mut test = FolderTest::new().await;
+    let template_view = test.view.clone();
+    test.run_scripts(vec![
+        ApplyDocumentDelta(r#"[{"insert":"{{title}} notes"},{"retain":1}]"#),
+        CreateNestedView {
+            name: "{{title}} agenda",
+            desc: "",
+        },
+        ReadView(template_view.id.clone()),
+        CreateViewTemplate,
+        ReadTemplates,
+    ])
+    .await;
+    assert_eq!(test.templates.len(), 1);
+    assert_eq!(test.templates[0].id, template_view.id);
+
+    let template_id = test.templates[0].id.clone();
+    test.run_scripts(vec![
+        CreateViewFromTemplate {
+            template_id,
+            name: Some("Weekly meeting".to_owned()),
+        },
+        OpenDocument,
+    ])
+    .await;
+    assert_eq!(test.view.name, "Weekly meeting");
+    assert_ne!(test.view.id, template_view.id);
+    let document_info = test.document_info.as_ref().unwrap();
+    assert!(document_info.text.contains("Weekly meeting notes"));
+
+    let view_id = test.view.id.clone();
+    test.run_scripts(vec![ReadView(view_id)]).await;
+    assert_eq!(test.view.belongings.len(), 1);
+    assert_eq!(test.view.belongings[0].name, "Weekly meeting agenda");
+}
+
+#[tokio::test]
+async fn template_create_view_from_app_template() {
+    let mut test = FolderTest::new().await;
+    let app = test.app.clone();
+    test.run_scripts(vec![
+        CreateView {
+            name: "{{title}} kickoff",
+            desc: "",
+        },
+        CreateAppTemplate,
+    ])
+    .await;
+
+    let template_id = test.templates[0].id.clone();
+    test.run_scripts(vec![CreateViewFromTemplate {
+        template_id,
+        name: None,
+    }])
+    .await;
+    let view_id = test.view.id.clone();
+    test.run_scripts(vec![ReadView(view_id)]).await;
+    assert_eq!(test.view.name, app.name);
+    assert_eq!(test.view.belongings.len(), 2);
+    assert_eq!(test.view.belongings[1].name, format!("{} kickoff", app.name));
+}
+
+#[tokio::test]
+async fn template_delete() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![CreateViewTemplate]).await;
+    let template_id = test.templates[0].id.clone();
+    test.run_scripts(vec![DeleteTemplate(template_id), ReadTemplates]).await;
+    assert!(test.templates.is_empty());
+}
+
+#[tokio::test]
+async fn template_of_view_in_trash_is_hidden() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![CreateViewTemplate, DeleteView, ReadTemplates])
+        .await;
+    assert!(test.templates.is_empty());
+
+    test.run_scripts(vec![RestoreViewFromTrash, ReadTemplates]).await;
+    assert_eq!(test.templates.len(), 1);
+}
+
 #[tokio::test]
 async fn view_delete_all() {
     let mut test = FolderTest::new().await;
@@ -270,6 +636,66 @@ async fn view_delete_all_permanent() {
     assert_eq!(test.trash.len(), 0);
 }
 
+#[tokio::test]
+async fn view_delete_record_deleted_time() {
+    let mut test = FolderTest::new().await;
+    let user_id = test.sdk.user_session.user_id().unwrap();
+    test.run_scripts(vec![DeleteView, ReadTrash]).await;
+
+    assert_eq!(test.trash.len(), 1);
+    assert!(test.trash[0].deleted_time > 0);
+    assert_eq!(test.trash[0].deleted_by, user_id);
+}
+
+#[tokio::test]
+async fn trash_retention_policy_disabled_by_default() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![AssertTrashRetentionPolicy(0), DeleteView, ReadTrash])
+        .await;
+    assert_eq!(test.trash.len(), 1);
+
+    let now = test.trash[0].deleted_time + 365 * 24 * 3600;
+    test.sdk.folder_manager.purge_expired_trash(now).await.unwrap();
+    test.run_scripts(vec![ReadTrash]).await;
+    assert_eq!(test.trash.len(), 1);
+}
+
+#[tokio::test]
+async fn trash_retention_policy_update() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![
+        UpdateTrashRetentionPolicy(7),
+        AssertTrashRetentionPolicy(7),
+        DeleteView,
+        ReadTrash,
+    ])
+    .await;
+
+    // The trash was just deleted, so it should not be purged.
+    assert_eq!(test.trash.len(), 1);
+}
+
+#[tokio::test]
+async fn trash_purge_expired() {
+    let mut test = FolderTest::new().await;
+    let view_id = test.view.id.clone();
+    let user_id = test.sdk.user_session.user_id().unwrap();
+    let pool = test.sdk.user_session.db_pool().unwrap();
+    test.run_scripts(vec![UpdateTrashRetentionPolicy(7), DeleteView, ReadTrash])
+        .await;
+    assert_eq!(test.trash.len(), 1);
+
+    sleep(Duration::from_millis(REVISION_WRITE_INTERVAL_IN_MILLIS * 2)).await;
+    let cache = RevisionCache::new(&user_id, &view_id, pool);
+    assert!(!cache.batch_get(&view_id).unwrap().is_empty());
+
+    let now = test.trash[0].deleted_time + 8 * 24 * 3600;
+    test.sdk.folder_manager.purge_expired_trash(now).await.unwrap();
+    test.run_scripts(vec![ReadTrash]).await;
+    assert!(test.trash.is_empty());
+    assert!(cache.batch_get(&view_id).unwrap().is_empty());
+}
+
 #[tokio::test]
 async fn folder_sync_revision_state() {
     let mut test = FolderTest::new().await;
@@ -322,6 +748,385 @@ async fn folder_sync_revision_seq() {
     .await;
 }
 
+#[tokio::test]
+async fn grid_create_field_and_row() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![
+        CreateGridView,
+        OpenGrid,
+        AssertGrid {
+            field_count: 0,
+            row_count: 0,
+        },
+        CreateField {
+            name: "Name",
+            field_type: FieldType::Text,
+            options: vec![],
+        },
+        CreateRow,
+        CreateRow,
+        OpenGrid,
+        AssertGrid {
+            field_count: 1,
+            row_count: 2,
+        },
+        DeleteRow,
+        OpenGrid,
+        AssertGrid {
+            field_count: 1,
+            row_count: 1,
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_update_cell() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![
+        CreateGridView,
+        CreateField {
+            name: "Price",
+            field_type: FieldType::Number,
+            options: vec![],
+        },
+        CreateRow,
+        UpdateCell("12.5"),
+        AssertCell(Some("12.5")),
+        DeleteCell,
+        OpenGrid,
+        AssertCell(None),
+    ])
+    .await;
+}
+
+#[tokio::test]
+#[should_panic]
+async fn grid_update_cell_with_invalid_data() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![
+        CreateGridView,
+        CreateField {
+            name: "Done",
+            field_type: FieldType::Checkbox,
+            options: vec![],
+        },
+        CreateRow,
+        UpdateCell("maybe"),
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_update_field_options_removes_invalid_cells() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![
+        CreateGridView,
+        CreateField {
+            name: "Status",
+            field_type: FieldType::SingleSelect,
+            options: vec!["Todo", "Done"],
+        },
+        CreateRow,
+        UpdateCell("Todo"),
+        UpdateField {
+            name: Some("State"),
+            options: Some(vec!["Done"]),
+        },
+        OpenGrid,
+        AssertCell(None),
+    ])
+    .await;
+    assert_eq!(test.field.name, "State");
+}
+
+#[tokio::test]
+async fn grid_delete_field_removes_cells() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![
+        CreateGridView,
+        CreateField {
+            name: "Name",
+            field_type: FieldType::Text,
+            options: vec![],
+        },
+        CreateRow,
+        UpdateCell("hello"),
+        DeleteField,
+        OpenGrid,
+        AssertGrid {
+            field_count: 0,
+            row_count: 1,
+        },
+        AssertCell(None),
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_duplicate_view() {
+    let mut test = FolderTest::new().await;
+    let app_id = test.app.id.clone();
+    test.run_scripts(vec![
+        CreateGridView,
+        CreateField {
+            name: "Name",
+            field_type: FieldType::Text,
+            options: vec![],
+        },
+        CreateRow,
+        UpdateCell("hello"),
+        DuplicateView,
+        ReadApp(app_id),
+    ])
+    .await;
+
+    // The grid is copied as it is, so the ids of the rows and fields stay the same.
+    assert_eq!(test.app.belongings.len(), 3);
+    test.view = test.app.belongings[2].clone();
+    test.run_scripts(vec![
+        OpenGrid,
+        AssertGrid {
+            field_count: 1,
+            row_count: 1,
+        },
+        AssertCell(Some("hello")),
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn board_create_card() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![
+        CreateBoardView,
+        OpenBoard,
+        CreateCard {
+            title: "card 1",
+            column: "To Do",
+        },
+        CreateCard {
+            title: "card 2",
+            column: "To Do",
+        },
+        CreateCard {
+            title: "card 3",
+            column: "Done",
+        },
+        OpenBoard,
+        AssertColumn {
+            column: "To Do",
+            titles: vec!["card 1", "card 2"],
+        },
+        AssertColumn {
+            column: "In Progress",
+            titles: vec![],
+        },
+        AssertColumn {
+            column: "Done",
+            titles: vec!["card 3"],
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+#[should_panic]
+async fn board_create_card_with_invalid_column() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![
+        CreateBoardView,
+        CreateCard {
+            title: "card 1",
+            column: "Not exist",
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn board_move_card() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![
+        CreateBoardView,
+        CreateCard {
+            title: "card 1",
+            column: "To Do",
+        },
+        CreateCard {
+            title: "card 2",
+            column: "To Do",
+        },
+        CreateCard {
+            title: "card 3",
+            column: "To Do",
+        },
+        MoveCard {
+            title: "card 3",
+            column: "To Do",
+            after_title: None,
+        },
+        AssertColumn {
+            column: "To Do",
+            titles: vec!["card 3", "card 1", "card 2"],
+        },
+        MoveCard {
+            title: "card 1",
+            column: "Done",
+            after_title: None,
+        },
+        MoveCard {
+            title: "card 2",
+            column: "Done",
+            after_title: Some("card 1"),
+        },
+        AssertColumn {
+            column: "To Do",
+            titles: vec!["card 3"],
+        },
+        AssertColumn {
+            column: "Done",
+            titles: vec!["card 1", "card 2"],
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn board_delete_card() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![
+        CreateBoardView,
+        CreateCard {
+            title: "card 1",
+            column: "To Do",
+        },
+        CreateCard {
+            title: "card 2",
+            column: "To Do",
+        },
+        DeleteCard("card 1"),
+        OpenBoard,
+        AssertColumn {
+            column: "To Do",
+            titles: vec!["card 2"],
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn board_update_property_ungroups_removed_column() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![
+        CreateBoardView,
+        OpenBoard,
+        CreateCard {
+            title: "card 1",
+            column: "Done",
+        },
+        UpdateBoardProperty(vec!["To Do", "In Progress"]),
+        AssertColumn {
+            column: "Done",
+            titles: vec![],
+        },
+        AssertColumn {
+            column: "",
+            titles: vec!["card 1"],
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn comment_create_and_reply() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![
+        ApplyDocumentDelta(r#"[{"insert":"123456"},{"retain":1}]"#),
+        CreateComment {
+            start: 1,
+            end: 4,
+            content: "comment 1",
+        },
+        ReplyComment("reply 1"),
+        ReplyComment("reply 2"),
+        ReadComments,
+        AssertComment {
+            anchor: (1, 4),
+            replies: vec!["reply 1", "reply 2"],
+            resolved: false,
+            orphaned: false,
+        },
+    ])
+    .await;
+    assert_eq!(test.comments.len(), 1);
+}
+
+#[tokio::test]
+async fn comment_resolve_and_reopen() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![
+        ApplyDocumentDelta(r#"[{"insert":"123456"},{"retain":1}]"#),
+        CreateComment {
+            start: 1,
+            end: 4,
+            content: "comment 1",
+        },
+        ResolveComment(true),
+        ReadComments,
+        AssertComment {
+            anchor: (1, 4),
+            replies: vec![],
+            resolved: true,
+            orphaned: false,
+        },
+        ResolveComment(false),
+        ReadComments,
+        AssertComment {
+            anchor: (1, 4),
+            replies: vec![],
+            resolved: false,
+            orphaned: false,
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn comment_orphaned_after_deleting_text() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![
+        ApplyDocumentDelta(r#"[{"insert":"123456"},{"retain":1}]"#),
+        CreateComment {
+            start: 1,
+            end: 4,
+            content: "comment 1",
+        },
+        ReplyComment("reply 1"),
+        ApplyDocumentDelta(r#"[{"delete":6},{"retain":1}]"#),
+        ReadComments,
+        AssertComment {
+            anchor: (0, 0),
+            replies: vec!["reply 1"],
+            resolved: false,
+            orphaned: true,
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+#[should_panic]
+async fn comment_create_with_invalid_range() {
+    let mut test = FolderTest::new().await;
+    test.run_scripts(vec![CreateComment {
+        start: 1,
+        end: 100,
+        content: "comment 1",
+    }])
+    .await;
+}
+
 #[tokio::test]
 async fn folder_sync_revision_with_new_app() {
     let mut test = FolderTest::new().await;
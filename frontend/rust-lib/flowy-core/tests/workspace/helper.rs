@@ -1,9 +1,31 @@
-use flowy_collaboration::entities::document_info::DocumentInfo;
+use flowy_collaboration::entities::{
+    document_info::{DocumentDelta, DocumentInfo},
+    statistics::OpenDocumentsUsage,
+};
 use flowy_core::event::WorkspaceEvent::*;
 use flowy_core_data_model::entities::{
-    app::{App, AppId, CreateAppRequest, QueryAppRequest, UpdateAppRequest},
-    trash::{RepeatedTrash, TrashId, TrashType},
-    view::{CreateViewRequest, QueryViewRequest, UpdateViewRequest, View, ViewType},
+    app::{App, AppId, ColorStyle, CreateAppRequest, QueryAppRequest, UpdateAppRequest},
+    board::{
+        Board, Card, CreateCardRequest, MoveCardRequest, QueryBoardRequest, QueryCardRequest,
+        UpdateBoardPropertyRequest,
+    },
+    comment::{
+        CommentThread, CreateCommentRequest, QueryCommentsRequest, RepeatedCommentThread, ReplyCommentRequest,
+        ResolveCommentRequest,
+    },
+    grid::{
+        Cell, CreateFieldRequest, Field, FieldOptions, FieldType, Grid, QueryCellRequest, QueryFieldRequest,
+        QueryGridRequest, QueryRowRequest, Row, UpdateCellRequest, UpdateFieldRequest,
+    },
+    template::{
+        CreateTemplateRequest, CreateViewFromTemplateRequest, QueryTemplateRequest, RepeatedTemplate, Template,
+        TemplateType,
+    },
+    trash::{RepeatedTrash, TrashId, TrashRetentionPolicy, TrashType},
+    view::{
+        CreateViewRequest, QueryViewRequest, RepeatedView, UpdateViewPropertyRequest, UpdateViewRequest,
+        UpdateViewTagsRequest, View, ViewFilterRequest, ViewPropertyType, ViewType,
+    },
     workspace::{CreateWorkspaceRequest, QueryWorkspaceRequest, RepeatedWorkspace, Workspace},
 };
 use flowy_test::{event_builder::*, FlowySDKTest};
@@ -47,6 +69,17 @@ pub async fn read_workspace(sdk: &FlowySDKTest, workspace_id: Option<String>) ->
     workspaces
 }
 
+pub async fn delete_workspace(sdk: &FlowySDKTest, workspace_id: &str) {
+    let request = QueryWorkspaceRequest {
+        workspace_id: Some(workspace_id.to_owned()),
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(DeleteWorkspace)
+        .request(request)
+        .async_send()
+        .await;
+}
+
 pub async fn create_app(sdk: &FlowySDKTest, workspace_id: &str, name: &str, desc: &str) -> App {
     let create_app_request = CreateAppRequest {
         workspace_id: workspace_id.to_owned(),
@@ -79,12 +112,18 @@ pub async fn read_app(sdk: &FlowySDKTest, app_id: &str) -> App {
     app
 }
 
-pub async fn update_app(sdk: &FlowySDKTest, app_id: &str, name: Option<String>, desc: Option<String>) {
+pub async fn update_app(
+    sdk: &FlowySDKTest,
+    app_id: &str,
+    name: Option<String>,
+    desc: Option<String>,
+    color_style: Option<ColorStyle>,
+) {
     let request = UpdateAppRequest {
         app_id: app_id.to_string(),
         name,
         desc,
-        color_style: None,
+        color_style,
         is_trash: None,
     };
 
@@ -107,6 +146,19 @@ pub async fn delete_app(sdk: &FlowySDKTest, app_id: &str) {
         .await;
 }
 
+pub async fn duplicate_app(sdk: &FlowySDKTest, app_id: &str) -> App {
+    let request = QueryAppRequest {
+        app_ids: vec![app_id.to_owned()],
+    };
+
+    FolderEventBuilder::new(sdk.clone())
+        .event(DuplicateApp)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<App>()
+}
+
 pub async fn create_view(sdk: &FlowySDKTest, app_id: &str, name: &str, desc: &str, view_type: ViewType) -> View {
     let request = CreateViewRequest {
         belong_to_id: app_id.to_string(),
@@ -148,6 +200,61 @@ pub async fn update_view(sdk: &FlowySDKTest, view_id: &str, name: Option<String>
         .await;
 }
 
+pub async fn update_view_tags(sdk: &FlowySDKTest, view_id: &str, tags: Vec<String>) -> View {
+    let request = UpdateViewTagsRequest {
+        view_id: view_id.to_owned(),
+        tags,
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(UpdateViewTags)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<View>()
+}
+
+pub async fn update_view_property(
+    sdk: &FlowySDKTest,
+    view_id: &str,
+    key: &str,
+    ty: ViewPropertyType,
+    value: Option<String>,
+    options: Vec<String>,
+) -> View {
+    let request = UpdateViewPropertyRequest {
+        view_id: view_id.to_owned(),
+        key: key.to_owned(),
+        ty,
+        value,
+        options,
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(UpdateViewProperty)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<View>()
+}
+
+pub async fn query_views(
+    sdk: &FlowySDKTest,
+    tag: Option<String>,
+    property_key: Option<String>,
+    property_value: Option<String>,
+) -> RepeatedView {
+    let request = ViewFilterRequest {
+        tag,
+        property_key,
+        property_value,
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(QueryViews)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<RepeatedView>()
+}
+
 pub async fn delete_view(sdk: &FlowySDKTest, view_ids: Vec<String>) {
     let request = QueryViewRequest { view_ids };
     FolderEventBuilder::new(sdk.clone())
@@ -157,6 +264,17 @@ pub async fn delete_view(sdk: &FlowySDKTest, view_ids: Vec<String>) {
         .await;
 }
 
+pub async fn duplicate_view(sdk: &FlowySDKTest, view_id: &str) {
+    let request = QueryViewRequest {
+        view_ids: vec![view_id.to_owned()],
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(DuplicateView)
+        .request(request)
+        .async_send()
+        .await;
+}
+
 pub async fn open_document(sdk: &FlowySDKTest, view_id: &str) -> DocumentInfo {
     let request = QueryViewRequest {
         view_ids: vec![view_id.to_owned()],
@@ -169,6 +287,19 @@ pub async fn open_document(sdk: &FlowySDKTest, view_id: &str) -> DocumentInfo {
         .parse::<DocumentInfo>()
 }
 
+pub async fn apply_document_delta(sdk: &FlowySDKTest, view_id: &str, delta_json: &str) -> DocumentDelta {
+    let request = DocumentDelta {
+        doc_id: view_id.to_owned(),
+        delta_json: delta_json.to_owned(),
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(ApplyDocDelta)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<DocumentDelta>()
+}
+
 pub async fn read_trash(sdk: &FlowySDKTest) -> RepeatedTrash {
     FolderEventBuilder::new(sdk.clone())
         .event(ReadTrash)
@@ -177,6 +308,14 @@ pub async fn read_trash(sdk: &FlowySDKTest) -> RepeatedTrash {
         .parse::<RepeatedTrash>()
 }
 
+pub async fn read_open_documents_usage(sdk: &FlowySDKTest) -> OpenDocumentsUsage {
+    FolderEventBuilder::new(sdk.clone())
+        .event(ReadOpenDocumentsUsage)
+        .async_send()
+        .await
+        .parse::<OpenDocumentsUsage>()
+}
+
 pub async fn restore_app_from_trash(sdk: &FlowySDKTest, app_id: &str) {
     let id = TrashId {
         id: app_id.to_owned(),
@@ -201,9 +340,332 @@ pub async fn restore_view_from_trash(sdk: &FlowySDKTest, view_id: &str) {
         .await;
 }
 
+pub async fn restore_workspace_from_trash(sdk: &FlowySDKTest, workspace_id: &str) {
+    let id = TrashId {
+        id: workspace_id.to_owned(),
+        ty: TrashType::Workspace,
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(PutbackTrash)
+        .request(id)
+        .async_send()
+        .await;
+}
+
 pub async fn delete_all_trash(sdk: &FlowySDKTest) {
     FolderEventBuilder::new(sdk.clone())
         .event(DeleteAllTrash)
         .async_send()
         .await;
 }
+
+pub async fn read_trash_retention_policy(sdk: &FlowySDKTest) -> TrashRetentionPolicy {
+    FolderEventBuilder::new(sdk.clone())
+        .event(ReadTrashRetentionPolicy)
+        .async_send()
+        .await
+        .parse::<TrashRetentionPolicy>()
+}
+
+pub async fn update_trash_retention_policy(sdk: &FlowySDKTest, retention_days: i64) {
+    let policy = TrashRetentionPolicy::new(retention_days);
+    FolderEventBuilder::new(sdk.clone())
+        .event(UpdateTrashRetentionPolicy)
+        .request(policy)
+        .async_send()
+        .await;
+}
+
+pub async fn create_template(sdk: &FlowySDKTest, id: &str, ty: TemplateType) -> Template {
+    let request = CreateTemplateRequest { id: id.to_owned(), ty };
+    FolderEventBuilder::new(sdk.clone())
+        .event(CreateTemplate)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<Template>()
+}
+
+pub async fn read_templates(sdk: &FlowySDKTest) -> RepeatedTemplate {
+    FolderEventBuilder::new(sdk.clone())
+        .event(ReadTemplates)
+        .async_send()
+        .await
+        .parse::<RepeatedTemplate>()
+}
+
+pub async fn delete_template(sdk: &FlowySDKTest, template_id: &str) {
+    let request = QueryTemplateRequest {
+        template_id: template_id.to_owned(),
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(DeleteTemplate)
+        .request(request)
+        .async_send()
+        .await;
+}
+
+pub async fn create_view_from_template(
+    sdk: &FlowySDKTest,
+    template_id: &str,
+    belong_to_id: &str,
+    name: Option<String>,
+) -> View {
+    let request = CreateViewFromTemplateRequest {
+        template_id: template_id.to_owned(),
+        belong_to_id: belong_to_id.to_owned(),
+        name,
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(CreateViewFromTemplate)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<View>()
+}
+
+pub async fn open_grid(sdk: &FlowySDKTest, grid_id: &str) -> Grid {
+    let request = QueryGridRequest {
+        grid_id: grid_id.to_owned(),
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(OpenGrid)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<Grid>()
+}
+
+pub async fn create_field(
+    sdk: &FlowySDKTest,
+    grid_id: &str,
+    name: &str,
+    field_type: FieldType,
+    options: Vec<String>,
+) -> Field {
+    let request = CreateFieldRequest {
+        grid_id: grid_id.to_owned(),
+        name: name.to_owned(),
+        field_type,
+        options,
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(CreateField)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<Field>()
+}
+
+pub async fn update_field(
+    sdk: &FlowySDKTest,
+    grid_id: &str,
+    field_id: &str,
+    name: Option<String>,
+    options: Option<Vec<String>>,
+) -> Field {
+    let request = UpdateFieldRequest {
+        grid_id: grid_id.to_owned(),
+        field_id: field_id.to_owned(),
+        name,
+        options: options.map(|items| FieldOptions { items }),
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(UpdateField)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<Field>()
+}
+
+pub async fn delete_field(sdk: &FlowySDKTest, grid_id: &str, field_id: &str) {
+    let request = QueryFieldRequest {
+        grid_id: grid_id.to_owned(),
+        field_id: field_id.to_owned(),
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(DeleteField)
+        .request(request)
+        .async_send()
+        .await;
+}
+
+pub async fn create_row(sdk: &FlowySDKTest, grid_id: &str) -> Row {
+    let request = QueryGridRequest {
+        grid_id: grid_id.to_owned(),
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(CreateRow)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<Row>()
+}
+
+pub async fn delete_row(sdk: &FlowySDKTest, grid_id: &str, row_id: &str) {
+    let request = QueryRowRequest {
+        grid_id: grid_id.to_owned(),
+        row_id: row_id.to_owned(),
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(DeleteRow)
+        .request(request)
+        .async_send()
+        .await;
+}
+
+pub async fn update_cell(sdk: &FlowySDKTest, grid_id: &str, row_id: &str, cell: Cell) -> Row {
+    let request = UpdateCellRequest {
+        grid_id: grid_id.to_owned(),
+        row_id: row_id.to_owned(),
+        field_id: cell.field_id,
+        data: cell.data,
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(UpdateCell)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<Row>()
+}
+
+pub async fn delete_cell(sdk: &FlowySDKTest, grid_id: &str, row_id: &str, field_id: &str) {
+    let request = QueryCellRequest {
+        grid_id: grid_id.to_owned(),
+        row_id: row_id.to_owned(),
+        field_id: field_id.to_owned(),
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(DeleteCell)
+        .request(request)
+        .async_send()
+        .await;
+}
+
+pub async fn open_board(sdk: &FlowySDKTest, board_id: &str) -> Board {
+    let request = QueryBoardRequest {
+        board_id: board_id.to_owned(),
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(OpenBoard)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<Board>()
+}
+
+pub async fn update_board_property(sdk: &FlowySDKTest, board_id: &str, key: &str, options: Vec<String>) -> Board {
+    let request = UpdateBoardPropertyRequest {
+        board_id: board_id.to_owned(),
+        key: key.to_owned(),
+        options,
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(UpdateBoardProperty)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<Board>()
+}
+
+pub async fn create_card(sdk: &FlowySDKTest, board_id: &str, title: &str, column: &str) -> Card {
+    let request = CreateCardRequest {
+        board_id: board_id.to_owned(),
+        title: title.to_owned(),
+        column: column.to_owned(),
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(CreateCard)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<Card>()
+}
+
+pub async fn move_card(
+    sdk: &FlowySDKTest,
+    board_id: &str,
+    card_id: &str,
+    column: &str,
+    after_card_id: Option<String>,
+) -> Board {
+    let request = MoveCardRequest {
+        board_id: board_id.to_owned(),
+        card_id: card_id.to_owned(),
+        column: column.to_owned(),
+        after_card_id,
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(MoveCard)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<Board>()
+}
+
+pub async fn delete_card(sdk: &FlowySDKTest, board_id: &str, card_id: &str) {
+    let request = QueryCardRequest {
+        board_id: board_id.to_owned(),
+        card_id: card_id.to_owned(),
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(DeleteCard)
+        .request(request)
+        .async_send()
+        .await;
+}
+
+pub async fn create_comment(sdk: &FlowySDKTest, doc_id: &str, start: i64, end: i64, content: &str) -> CommentThread {
+    let request = CreateCommentRequest {
+        doc_id: doc_id.to_owned(),
+        start,
+        end,
+        content: content.to_owned(),
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(CreateComment)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<CommentThread>()
+}
+
+pub async fn reply_comment(sdk: &FlowySDKTest, doc_id: &str, thread_id: &str, content: &str) -> CommentThread {
+    let request = ReplyCommentRequest {
+        doc_id: doc_id.to_owned(),
+        thread_id: thread_id.to_owned(),
+        content: content.to_owned(),
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(ReplyComment)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<CommentThread>()
+}
+
+pub async fn resolve_comment(sdk: &FlowySDKTest, doc_id: &str, thread_id: &str, resolved: bool) -> CommentThread {
+    let request = ResolveCommentRequest {
+        doc_id: doc_id.to_owned(),
+        thread_id: thread_id.to_owned(),
+        resolved,
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(ResolveComment)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<CommentThread>()
+}
+
+pub async fn read_comments(sdk: &FlowySDKTest, doc_id: &str) -> RepeatedCommentThread {
+    let request = QueryCommentsRequest {
+        doc_id: doc_id.to_owned(),
+    };
+    FolderEventBuilder::new(sdk.clone())
+        .event(ReadComments)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<RepeatedCommentThread>()
+}
@@ -2,9 +2,13 @@ use crate::helper::*;
 use flowy_collaboration::entities::{document_info::DocumentInfo, revision::RevisionState};
 use flowy_core::{errors::ErrorCode, services::folder_editor::FolderEditor};
 use flowy_core_data_model::entities::{
-    app::{App, RepeatedApp},
+    app::{App, ColorStyle, RepeatedApp},
+    board::{Board, Card},
+    comment::CommentThread,
+    grid::{Cell, Field, FieldType, Grid, Row},
+    template::{Template, TemplateType},
     trash::Trash,
-    view::{RepeatedView, View, ViewType},
+    view::{RepeatedView, View, ViewPropertyType, ViewType},
     workspace::Workspace,
 };
 use flowy_sync::REVISION_WRITE_INTERVAL_IN_MILLIS;
@@ -15,40 +19,153 @@ use tokio::time::sleep;
 pub enum FolderScript {
     // Workspace
     ReadAllWorkspaces,
-    CreateWorkspace { name: String, desc: String },
+    CreateWorkspace {
+        name: String,
+        desc: String,
+    },
     AssertWorkspaceJson(String),
     AssertWorkspace(Workspace),
     ReadWorkspace(Option<String>),
+    DeleteWorkspace(String),
 
     // App
-    CreateApp { name: &'static str, desc: &'static str },
+    CreateApp {
+        name: &'static str,
+        desc: &'static str,
+    },
     AssertAppJson(String),
     AssertApp(App),
     ReadApp(String),
-    UpdateApp { name: Option<String>, desc: Option<String> },
+    UpdateApp {
+        name: Option<String>,
+        desc: Option<String>,
+        color_style: Option<ColorStyle>,
+    },
     DeleteApp,
+    DuplicateApp,
 
     // View
-    CreateView { name: &'static str, desc: &'static str },
+    CreateView {
+        name: &'static str,
+        desc: &'static str,
+    },
+    CreateNestedView {
+        name: &'static str,
+        desc: &'static str,
+    },
     AssertView(View),
     ReadView(String),
-    UpdateView { name: Option<String>, desc: Option<String> },
+    UpdateView {
+        name: Option<String>,
+        desc: Option<String>,
+    },
     DeleteView,
     DeleteViews(Vec<String>),
+    DuplicateView,
+    UpdateViewTags(Vec<&'static str>),
+    UpdateViewProperty {
+        key: &'static str,
+        ty: ViewPropertyType,
+        value: Option<&'static str>,
+        options: Vec<&'static str>,
+    },
+    QueryViews {
+        tag: Option<&'static str>,
+        property_key: Option<&'static str>,
+        property_value: Option<&'static str>,
+    },
 
     // Trash
     RestoreAppFromTrash,
     RestoreViewFromTrash,
+    RestoreWorkspaceFromTrash(String),
     ReadTrash,
     DeleteAllTrash,
+    UpdateTrashRetentionPolicy(i64),
+    AssertTrashRetentionPolicy(i64),
+
+    // Template
+    CreateViewTemplate,
+    CreateAppTemplate,
+    ReadTemplates,
+    DeleteTemplate(String),
+    CreateViewFromTemplate {
+        template_id: String,
+        name: Option<String>,
+    },
+
+    // Grid
+    CreateGridView,
+    OpenGrid,
+    CreateField {
+        name: &'static str,
+        field_type: FieldType,
+        options: Vec<&'static str>,
+    },
+    UpdateField {
+        name: Option<&'static str>,
+        options: Option<Vec<&'static str>>,
+    },
+    DeleteField,
+    CreateRow,
+    DeleteRow,
+    UpdateCell(&'static str),
+    DeleteCell,
+    AssertGrid {
+        field_count: usize,
+        row_count: usize,
+    },
+    AssertCell(Option<&'static str>),
+
+    // Board
+    CreateBoardView,
+    OpenBoard,
+    UpdateBoardProperty(Vec<&'static str>),
+    CreateCard {
+        title: &'static str,
+        column: &'static str,
+    },
+    // Moves the card with the title into the column, right after the card with the after_title.
+    MoveCard {
+        title: &'static str,
+        column: &'static str,
+        after_title: Option<&'static str>,
+    },
+    DeleteCard(&'static str),
+    // Asserts the titles of the cards in the column.
+    AssertColumn {
+        column: &'static str,
+        titles: Vec<&'static str>,
+    },
+
+    // Comment
+    CreateComment {
+        start: i64,
+        end: i64,
+        content: &'static str,
+    },
+    ReplyComment(&'static str),
+    ResolveComment(bool),
+    // Reads all the threads of the document and refreshes the current thread.
+    ReadComments,
+    AssertComment {
+        anchor: (i64, i64),
+        replies: Vec<&'static str>,
+        resolved: bool,
+        orphaned: bool,
+    },
 
     // Document
     OpenDocument,
+    ApplyDocumentDelta(&'static str),
 
     // Sync
     AssertCurrentRevId(i64),
     AssertNextSyncRevId(Option<i64>),
-    AssertRevisionState { rev_id: i64, state: RevisionState },
+    AssertRevisionState {
+        rev_id: i64,
+        state: RevisionState,
+    },
 }
 
 pub struct FolderTest {
@@ -57,8 +174,17 @@ pub struct FolderTest {
     pub workspace: Workspace,
     pub app: App,
     pub view: View,
+    pub views: Vec<View>,
     pub trash: Vec<Trash>,
+    pub templates: Vec<Template>,
     pub document_info: Option<DocumentInfo>,
+    pub grid: Grid,
+    pub field: Field,
+    pub row: Row,
+    pub board: Board,
+    pub cards: Vec<Card>,
+    pub comment: CommentThread,
+    pub comments: Vec<CommentThread>,
     // pub folder_editor:
 }
 
@@ -82,8 +208,17 @@ impl FolderTest {
             workspace,
             app,
             view,
+            views: vec![],
             trash: vec![],
+            templates: vec![],
             document_info: None,
+            grid: Grid::default(),
+            field: Field::default(),
+            row: Row::default(),
+            board: Board::default(),
+            cards: vec![],
+            comment: CommentThread::default(),
+            comments: vec![],
         }
     }
 
@@ -123,6 +258,9 @@ impl FolderTest {
                 let workspace = read_workspace(sdk, workspace_id).await.pop().unwrap();
                 self.workspace = workspace;
             }
+            FolderScript::DeleteWorkspace(workspace_id) => {
+                delete_workspace(sdk, &workspace_id).await;
+            }
             FolderScript::CreateApp { name, desc } => {
                 let app = create_app(sdk, &self.workspace.id, name, desc).await;
                 self.app = app;
@@ -138,17 +276,29 @@ impl FolderTest {
                 let app = read_app(sdk, &app_id).await;
                 self.app = app;
             }
-            FolderScript::UpdateApp { name, desc } => {
-                update_app(sdk, &self.app.id, name, desc).await;
+            FolderScript::UpdateApp {
+                name,
+                desc,
+                color_style,
+            } => {
+                update_app(sdk, &self.app.id, name, desc, color_style).await;
             }
             FolderScript::DeleteApp => {
                 delete_app(sdk, &self.app.id).await;
             }
+            FolderScript::DuplicateApp => {
+                let app = duplicate_app(sdk, &self.app.id).await;
+                self.app = app;
+            }
 
             FolderScript::CreateView { name, desc } => {
                 let view = create_view(sdk, &self.app.id, name, desc, ViewType::Doc).await;
                 self.view = view;
             }
+            FolderScript::CreateNestedView { name, desc } => {
+                let view = create_view(sdk, &self.view.id, name, desc, ViewType::Doc).await;
+                self.view = view;
+            }
             FolderScript::AssertView(view) => {
                 assert_eq!(self.view, view);
             }
@@ -165,12 +315,48 @@ impl FolderTest {
             FolderScript::DeleteViews(view_ids) => {
                 delete_view(sdk, view_ids).await;
             }
+            FolderScript::DuplicateView => {
+                duplicate_view(sdk, &self.view.id).await;
+            }
+            FolderScript::UpdateViewTags(tags) => {
+                let tags = tags.into_iter().map(|tag| tag.to_owned()).collect();
+                let view = update_view_tags(sdk, &self.view.id, tags).await;
+                self.view = view;
+            }
+            FolderScript::UpdateViewProperty {
+                key,
+                ty,
+                value,
+                options,
+            } => {
+                let value = value.map(|value| value.to_owned());
+                let options = options.into_iter().map(|option| option.to_owned()).collect();
+                let view = update_view_property(sdk, &self.view.id, key, ty, value, options).await;
+                self.view = view;
+            }
+            FolderScript::QueryViews {
+                tag,
+                property_key,
+                property_value,
+            } => {
+                let views = query_views(
+                    sdk,
+                    tag.map(|s| s.to_owned()),
+                    property_key.map(|s| s.to_owned()),
+                    property_value.map(|s| s.to_owned()),
+                )
+                .await;
+                self.views = views.into_inner();
+            }
             FolderScript::RestoreAppFromTrash => {
                 restore_app_from_trash(sdk, &self.app.id).await;
             }
             FolderScript::RestoreViewFromTrash => {
                 restore_view_from_trash(sdk, &self.view.id).await;
             }
+            FolderScript::RestoreWorkspaceFromTrash(workspace_id) => {
+                restore_workspace_from_trash(sdk, &workspace_id).await;
+            }
             FolderScript::ReadTrash => {
                 let trash = read_trash(sdk).await;
                 self.trash = trash.into_inner();
@@ -179,6 +365,166 @@ impl FolderTest {
                 delete_all_trash(sdk).await;
                 self.trash = vec![];
             }
+            FolderScript::UpdateTrashRetentionPolicy(retention_days) => {
+                update_trash_retention_policy(sdk, retention_days).await;
+            }
+            FolderScript::AssertTrashRetentionPolicy(retention_days) => {
+                let policy = read_trash_retention_policy(sdk).await;
+                assert_eq!(policy.retention_days, retention_days);
+            }
+            FolderScript::CreateViewTemplate => {
+                let template = create_template(sdk, &self.view.id, TemplateType::View).await;
+                self.templates = vec![template];
+            }
+            FolderScript::CreateAppTemplate => {
+                let template = create_template(sdk, &self.app.id, TemplateType::App).await;
+                self.templates = vec![template];
+            }
+            FolderScript::ReadTemplates => {
+                let templates = read_templates(sdk).await;
+                self.templates = templates.into_inner();
+            }
+            FolderScript::DeleteTemplate(template_id) => {
+                delete_template(sdk, &template_id).await;
+            }
+            FolderScript::CreateViewFromTemplate { template_id, name } => {
+                let view = create_view_from_template(sdk, &template_id, &self.app.id, name).await;
+                self.view = view;
+            }
+            FolderScript::CreateGridView => {
+                let view = create_view(sdk, &self.app.id, "Grid", "Grid view", ViewType::Grid).await;
+                self.view = view;
+            }
+            FolderScript::OpenGrid => {
+                let grid = open_grid(sdk, &self.view.id).await;
+                if let Some(row) = grid.rows.iter().find(|row| row.id == self.row.id) {
+                    self.row = row.clone();
+                }
+                self.grid = grid;
+            }
+            FolderScript::CreateField {
+                name,
+                field_type,
+                options,
+            } => {
+                let options = options.into_iter().map(|option| option.to_owned()).collect();
+                let field = create_field(sdk, &self.view.id, name, field_type, options).await;
+                self.field = field;
+            }
+            FolderScript::UpdateField { name, options } => {
+                let name = name.map(|name| name.to_owned());
+                let options = options.map(|options| options.into_iter().map(|option| option.to_owned()).collect());
+                let field = update_field(sdk, &self.view.id, &self.field.id, name, options).await;
+                self.field = field;
+            }
+            FolderScript::DeleteField => {
+                delete_field(sdk, &self.view.id, &self.field.id).await;
+            }
+            FolderScript::CreateRow => {
+                let row = create_row(sdk, &self.view.id).await;
+                self.row = row;
+            }
+            FolderScript::DeleteRow => {
+                delete_row(sdk, &self.view.id, &self.row.id).await;
+            }
+            FolderScript::UpdateCell(data) => {
+                let cell = Cell {
+                    field_id: self.field.id.clone(),
+                    data: data.to_owned(),
+                };
+                let row = update_cell(sdk, &self.view.id, &self.row.id, cell).await;
+                self.row = row;
+            }
+            FolderScript::DeleteCell => {
+                delete_cell(sdk, &self.view.id, &self.row.id, &self.field.id).await;
+            }
+            FolderScript::AssertGrid { field_count, row_count } => {
+                assert_eq!(self.grid.fields.len(), field_count);
+                assert_eq!(self.grid.rows.len(), row_count);
+            }
+            FolderScript::AssertCell(data) => {
+                let cell_data = self.row.get_cell(&self.field.id).map(|cell| cell.data.as_str());
+                assert_eq!(cell_data, data);
+            }
+            FolderScript::CreateBoardView => {
+                let view = create_view(sdk, &self.app.id, "Board", "Board view", ViewType::Board).await;
+                self.view = view;
+            }
+            FolderScript::OpenBoard => {
+                let board = open_board(sdk, &self.view.id).await;
+                self.board = board;
+            }
+            FolderScript::UpdateBoardProperty(options) => {
+                let options = options.into_iter().map(|option| option.to_owned()).collect();
+                let board = update_board_property(sdk, &self.view.id, &self.board.property.key, options).await;
+                self.board = board;
+            }
+            FolderScript::CreateCard { title, column } => {
+                let card = create_card(sdk, &self.view.id, title, column).await;
+                self.cards.push(card);
+            }
+            FolderScript::MoveCard {
+                title,
+                column,
+                after_title,
+            } => {
+                let card_id = self.card_id(title);
+                let after_card_id = after_title.map(|after_title| self.card_id(after_title));
+                let board = move_card(sdk, &self.view.id, &card_id, column, after_card_id).await;
+                self.board = board;
+            }
+            FolderScript::DeleteCard(title) => {
+                let card_id = self.card_id(title);
+                delete_card(sdk, &self.view.id, &card_id).await;
+            }
+            FolderScript::AssertColumn { column, titles } => {
+                let column_titles = self
+                    .board
+                    .get_column(column)
+                    .map(|column| {
+                        column
+                            .cards
+                            .iter()
+                            .map(|card| card.title.as_str())
+                            .collect::<Vec<&str>>()
+                    })
+                    .unwrap_or_default();
+                assert_eq!(column_titles, titles);
+            }
+            FolderScript::CreateComment { start, end, content } => {
+                self.comment = create_comment(sdk, &self.view.id, start, end, content).await;
+            }
+            FolderScript::ReplyComment(content) => {
+                self.comment = reply_comment(sdk, &self.view.id, &self.comment.id, content).await;
+            }
+            FolderScript::ResolveComment(resolved) => {
+                self.comment = resolve_comment(sdk, &self.view.id, &self.comment.id, resolved).await;
+            }
+            FolderScript::ReadComments => {
+                self.comments = read_comments(sdk, &self.view.id).await.items;
+                let comment = self.comments.iter().find(|comment| comment.id == self.comment.id);
+                self.comment = comment.cloned().unwrap();
+            }
+            FolderScript::AssertComment {
+                anchor,
+                replies,
+                resolved,
+                orphaned,
+            } => {
+                let reply_contents = self
+                    .comment
+                    .replies
+                    .iter()
+                    .map(|reply| reply.content.as_str())
+                    .collect::<Vec<&str>>();
+                assert_eq!((self.comment.anchor_start, self.comment.anchor_end), anchor);
+                assert_eq!(reply_contents, replies);
+                assert_eq!(self.comment.resolved, resolved);
+                assert_eq!(self.comment.orphaned, orphaned);
+            }
+            FolderScript::ApplyDocumentDelta(delta_json) => {
+                apply_document_delta(sdk, &self.view.id, delta_json).await;
+            }
             FolderScript::OpenDocument => {
                 let document_info = open_document(sdk, &self.view.id).await;
                 self.document_info = Some(document_info);
@@ -208,6 +554,11 @@ impl FolderTest {
             }
         }
     }
+
+    fn card_id(&self, title: &str) -> String {
+        let card = self.cards.iter().find(|card| card.title == title);
+        card.map(|card| card.id.clone()).unwrap()
+    }
 }
 
 pub fn invalid_workspace_name_test_case() -> Vec<(String, ErrorCode)> {
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+CREATE TABLE workspace_secret_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    secret BLOB NOT NULL DEFAULT (x'')
+);
\ No newline at end of file
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TABLE rev_merge_backup_table (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    object_id TEXT NOT NULL DEFAULT '',
+    base_rev_id BIGINT NOT NULL DEFAULT 0,
+    rev_ids TEXT NOT NULL DEFAULT '',
+    base_json TEXT NOT NULL DEFAULT '',
+    delta_json TEXT NOT NULL DEFAULT '',
+    create_time BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
@@ -2,7 +2,7 @@ use crate::kv::schema::{kv_table, kv_table::dsl, KV_SQL};
 use ::diesel::{query_dsl::*, ExpressionMethods};
 use diesel::{Connection, SqliteConnection};
 use lazy_static::lazy_static;
-use lib_sqlite::{DBConnection, Database, PoolConfig};
+use lib_sqlite::{DBConnection, Database, DatabaseKey, PoolConfig};
 use std::{collections::HashMap, path::Path, sync::RwLock};
 
 const DB_NAME: &str = "kv.db";
@@ -70,14 +70,23 @@ impl KV {
     }
 
     pub fn init(root: &str) -> Result<(), String> {
+        Self::init_with_key(root, None)
+    }
+
+    // The kv.db keeps the session of the user, it should be encrypted if the user database is
+    // encrypted.
+    pub fn init_with_key(root: &str, key: Option<DatabaseKey>) -> Result<(), String> {
         if !Path::new(root).exists() {
             return Err(format!("Init KVStore failed. {} not exists", root));
         }
 
         let pool_config = PoolConfig::default();
-        let database = Database::new(root, DB_NAME, pool_config).unwrap();
-        let conn = database.get_connection().unwrap();
-        SqliteConnection::execute(&*conn, KV_SQL).unwrap();
+        let database = Database::new_with_key(root, DB_NAME, pool_config, key)
+            .map_err(|e| format!("Open KVStore failed: {:?}", e))?;
+        let conn = database
+            .get_connection()
+            .map_err(|e| format!("Open KVStore failed: {:?}", e))?;
+        SqliteConnection::execute(&*conn, KV_SQL).map_err(|e| format!("Init KVStore failed: {:?}", e))?;
 
         let mut store = KV_HOLDER
             .write()
@@ -5,7 +5,7 @@ use std::{fmt::Debug, io, path::Path};
 pub mod kv;
 
 use lib_sqlite::PoolConfig;
-pub use lib_sqlite::{ConnectionPool, DBConnection, Database};
+pub use lib_sqlite::{ConnectionPool, DBConnection, Database, DatabaseKey};
 
 pub mod schema;
 
@@ -31,11 +31,15 @@ embed_migrations!("../flowy-database/migrations/");
 pub const DB_NAME: &str = "flowy-database.db";
 
 pub fn init(storage_path: &str) -> Result<Database, io::Error> {
+    init_with_key(storage_path, None)
+}
+
+pub fn init_with_key(storage_path: &str, key: Option<DatabaseKey>) -> Result<Database, io::Error> {
     if !Path::new(storage_path).exists() {
         std::fs::create_dir_all(storage_path)?;
     }
     let pool_config = PoolConfig::default();
-    let database = Database::new(storage_path, DB_NAME, pool_config).map_err(as_io_error)?;
+    let database = Database::new_with_key(storage_path, DB_NAME, pool_config, key).map_err(as_io_error)?;
     let conn = database.get_connection().map_err(as_io_error)?;
     let _ = embedded_migrations::run(&*conn).map_err(as_io_error)?;
     Ok(database)
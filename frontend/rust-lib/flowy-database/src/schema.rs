@@ -21,6 +21,18 @@ table! {
     }
 }
 
+table! {
+    rev_merge_backup_table (id) {
+        id -> Integer,
+        object_id -> Text,
+        base_rev_id -> BigInt,
+        rev_ids -> Text,
+        base_json -> Text,
+        delta_json -> Text,
+        create_time -> BigInt,
+    }
+}
+
 table! {
     rev_table (id) {
         id -> Integer,
@@ -81,12 +93,21 @@ table! {
     }
 }
 
+table! {
+    workspace_secret_table (id) {
+        id -> Text,
+        secret -> Binary,
+    }
+}
+
 allow_tables_to_appear_in_same_query!(
     app_table,
     doc_table,
+    rev_merge_backup_table,
     rev_table,
     trash_table,
     user_table,
     view_table,
+    workspace_secret_table,
     workspace_table,
 );
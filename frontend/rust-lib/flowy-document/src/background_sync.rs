@@ -0,0 +1,73 @@
+use crate::core::{ClientDocumentEditor, DOCUMENT_SYNC_INTERVAL_IN_MILLIS};
+use dashmap::DashMap;
+use flowy_sync::read_sync_status;
+use std::sync::Arc;
+use tokio::{
+    sync::Semaphore,
+    time::{sleep, Duration, Instant},
+};
+
+const MAX_CONCURRENT_BACKGROUND_SYNC: usize = 3;
+const BACKGROUND_SYNC_TIMEOUT_IN_SECS: u64 = 60;
+// The number of the sync intervals to wait after the revisions were acked, so the document pings
+// the server and receives the revisions that were pushed by the other users.
+const PULL_INTERVAL_COUNT: u32 = 2;
+
+// The documents that are synced in the background have their own editors, which are not in the
+// OpenDocCache. The editor is moved to the OpenDocCache if the document is opened while it's
+// syncing, otherwise it's stopped after the document was synced or the sync timed out.
+pub(crate) struct BackgroundSyncScheduler {
+    editors: DashMap<String, Arc<ClientDocumentEditor>>,
+    semaphore: Arc<Semaphore>,
+}
+
+impl BackgroundSyncScheduler {
+    pub(crate) fn new() -> Self {
+        Self {
+            editors: DashMap::new(),
+            semaphore: Arc::new(Semaphore::new(MAX_CONCURRENT_BACKGROUND_SYNC)),
+        }
+    }
+
+    pub(crate) fn semaphore(&self) -> Arc<Semaphore> {
+        self.semaphore.clone()
+    }
+
+    pub(crate) fn contains(&self, doc_id: &str) -> bool {
+        self.editors.contains_key(doc_id)
+    }
+
+    pub(crate) fn get(&self, doc_id: &str) -> Option<Arc<ClientDocumentEditor>> {
+        self.editors.get(doc_id).map(|editor| editor.clone())
+    }
+
+    pub(crate) fn insert(&self, editor: Arc<ClientDocumentEditor>) {
+        self.editors.insert(editor.doc_id.clone(), editor);
+    }
+
+    pub(crate) fn doc_ids(&self) -> Vec<String> {
+        self.editors.iter().map(|item| item.key().clone()).collect()
+    }
+
+    // Returns None if the editor was taken by the OpenDocCache or the sync was cancelled.
+    pub(crate) fn take(&self, doc_id: &str) -> Option<Arc<ClientDocumentEditor>> {
+        self.editors.remove(doc_id).map(|(_, editor)| editor)
+    }
+}
+
+// Waits until the revisions of the document were acked and the remote revisions were pulled, or
+// until the sync timed out. Returns true if the document was synced.
+pub(crate) async fn wait_until_synced(doc_id: &str) -> bool {
+    let interval = Duration::from_millis(DOCUMENT_SYNC_INTERVAL_IN_MILLIS);
+    let deadline = Instant::now() + Duration::from_secs(BACKGROUND_SYNC_TIMEOUT_IN_SECS);
+    let mut synced_intervals = 0;
+    while Instant::now() < deadline {
+        sleep(interval).await;
+        let is_synced = read_sync_status(doc_id).map_or(false, |status| status.is_synced());
+        synced_intervals = if is_synced { synced_intervals + 1 } else { 0 };
+        if synced_intervals >= PULL_INTERVAL_COUNT {
+            return true;
+        }
+    }
+    false
+}
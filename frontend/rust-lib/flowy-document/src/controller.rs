@@ -1,18 +1,44 @@
-use crate::{core::ClientDocumentEditor, errors::FlowyError, DocumentCloudService};
+use crate::{
+    background_sync::{wait_until_synced, BackgroundSyncScheduler},
+    core::{ClientDocumentEditor, DocumentRevisionValidator},
+    errors::FlowyError,
+    DocumentCloudService,
+};
 use async_trait::async_trait;
 use bytes::Bytes;
 use dashmap::DashMap;
-use flowy_collaboration::entities::{
-    document_info::{DocumentDelta, DocumentId},
-    revision::{md5, RepeatedRevision, Revision},
-    ws_data::ServerRevisionWSData,
+use flowy_collaboration::{
+    entities::{
+        document_info::{DocumentDelta, DocumentId, ResetDocumentParams},
+        integrity::{DocumentIntegrityReport, DocumentIntegrityRequest},
+        merge_backup::{DocumentMergeBackup, DocumentMergeBackupId},
+        revision::{md5, RepeatedRevision, Revision, RevisionRange, RevisionState},
+        statistics::{DocumentStatistics, DocumentStatisticsRequest, OpenDocumentsUsage},
+        ws_data::ServerRevisionWSData,
+    },
+    util::make_delta_from_revisions,
 };
 use flowy_database::ConnectionPool;
-use flowy_error::FlowyResult;
-use flowy_sync::{RevisionCache, RevisionCloudService, RevisionManager, RevisionWebSocket};
+use flowy_error::{internal_error, FlowyResult};
+use flowy_sync::{
+    decrypt_revisions, read_sync_status, RevisionCache, RevisionCipher, RevisionCloudService, RevisionIntegrityChecker,
+    RevisionIntegrityReport, RevisionManager, RevisionMergeBackup, RevisionMergeBackupSql, RevisionWebSocket,
+};
 use lib_infra::future::FutureResult;
+use lib_ot::{
+    core::Interval,
+    rich_text::{RichTextAttributes, RichTextDelta},
+};
 use lib_ws::WSConnectState;
-use std::{convert::TryInto, sync::Arc};
+use parking_lot::RwLock;
+use std::{
+    convert::TryInto,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+use tokio::sync::Semaphore;
 
 pub trait DocumentUser: Send + Sync {
     fn user_dir(&self) -> Result<String, FlowyError>;
@@ -32,7 +58,10 @@ pub struct FlowyDocumentManager {
     ws_receivers: WebSocketDataReceivers,
     web_socket: Arc<dyn RevisionWebSocket>,
     open_cache: Arc<OpenDocCache>,
+    background_sync: Arc<BackgroundSyncScheduler>,
     user: Arc<dyn DocumentUser>,
+    // The cipher of the current workspace, the documents are not encrypted if it's None.
+    revision_cipher: RwLock<Option<Arc<RevisionCipher>>>,
 }
 
 impl FlowyDocumentManager {
@@ -40,15 +69,19 @@ impl FlowyDocumentManager {
         cloud_service: Arc<dyn DocumentCloudService>,
         user: Arc<dyn DocumentUser>,
         web_socket: Arc<dyn RevisionWebSocket>,
+        cache_config: DocumentCacheConfig,
     ) -> Self {
         let ws_receivers = Arc::new(DashMap::new());
-        let open_cache = Arc::new(OpenDocCache::new());
+        let open_cache = Arc::new(OpenDocCache::new(cache_config));
+        let background_sync = Arc::new(BackgroundSyncScheduler::new());
         Self {
             cloud_service,
             ws_receivers,
             web_socket,
             open_cache,
+            background_sync,
             user,
+            revision_cipher: RwLock::new(None),
         }
     }
 
@@ -65,24 +98,149 @@ impl FlowyDocumentManager {
         self.get_editor(doc_id).await
     }
 
+    // The document that still has the revisions that are not acked keeps syncing in the background
+    // after it was closed.
     #[tracing::instrument(level = "trace", skip(self, doc_id), fields(doc_id), err)]
     pub fn close_document<T: AsRef<str>>(&self, doc_id: T) -> Result<(), FlowyError> {
         let doc_id = doc_id.as_ref();
         tracing::Span::current().record("doc_id", &doc_id);
-        self.open_cache.remove(doc_id);
-        self.remove_ws_receiver(doc_id);
+        match self.open_cache.take(doc_id) {
+            Some(editor) => self.close_editor(editor),
+            None => self.remove_ws_receiver(doc_id),
+        }
         Ok(())
     }
 
+    // Reads the document without adding it to the OpenDocCache, so reading many documents doesn't
+    // evict the documents that are opened by the user. The editor that is created for reading is
+    // stopped after the document was read.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn read_document_json(&self, doc_id: &str) -> FlowyResult<String> {
+        if let Some(editor) = self.open_cache.get(doc_id).or_else(|| self.background_sync.get(doc_id)) {
+            return editor.document_json().await;
+        }
+
+        let editor = self.make_editor(doc_id, self.user.db_pool()?).await?;
+        let document_json = editor.document_json().await;
+        editor.stop();
+        document_json
+    }
+
+    pub fn open_documents_usage(&self) -> OpenDocumentsUsage {
+        self.open_cache.usage()
+    }
+
+    // Syncs the documents that are not opened, e.g. the documents that have the revisions that are
+    // not acked or the documents that may be outdated. At most MAX_CONCURRENT_BACKGROUND_SYNC
+    // documents are synced at the same time, and they are not added to the OpenDocCache.
+    pub fn sync_documents_in_background(self: &Arc<Self>, doc_ids: Vec<String>) {
+        for doc_id in doc_ids {
+            if self.open_cache.contains(&doc_id) || self.background_sync.contains(&doc_id) {
+                continue;
+            }
+            let semaphore = self.background_sync.semaphore();
+            self.spawn_background_sync(doc_id, semaphore);
+        }
+    }
+
+    // The document is deleted permanently, so its local revisions are removed as well.
     #[tracing::instrument(level = "debug", skip(self, doc_id), fields(doc_id), err)]
     pub fn delete<T: AsRef<str>>(&self, doc_id: T) -> Result<(), FlowyError> {
         let doc_id = doc_id.as_ref();
         tracing::Span::current().record("doc_id", &doc_id);
-        self.open_cache.remove(doc_id);
-        self.remove_ws_receiver(doc_id);
+        self.stop_editor(doc_id);
+        let user_id = self.user.user_id()?;
+        let _ = RevisionCache::new(&user_id, doc_id, self.user.db_pool()?).delete_all()?;
         Ok(())
     }
 
+    // Checks the revisions of the document that are stored in the rev_table. If the repair is true
+    // and the document is not valid, the document is closed and its revisions are replaced with the
+    // revisions of the server, then it's checked again. The document that has pending revisions is
+    // not repaired, otherwise the local changes would be dropped.
+    #[tracing::instrument(level = "debug", skip(self, request), fields(doc_id = %request.doc_id), err)]
+    pub async fn check_document_integrity(
+        &self,
+        request: DocumentIntegrityRequest,
+    ) -> FlowyResult<DocumentIntegrityReport> {
+        let user_id = self.user.user_id()?;
+        let cloud = Arc::new(DocumentRevisionCloudServiceImpl {
+            token: self.user.token()?,
+            server: self.cloud_service.clone(),
+            cipher: self.revision_cipher.read().clone(),
+        });
+        let checker = RevisionIntegrityChecker::new(&user_id, &request.doc_id, self.user.db_pool()?, cloud);
+        let report = checker.check::<DocumentRevisionValidator>().await?;
+        if !request.repair || report.is_valid() {
+            return Ok(make_integrity_report(report, false));
+        }
+
+        if report.pending_count > 0 {
+            let msg = format!(
+                "{} revisions of {} are not synced",
+                report.pending_count, request.doc_id
+            );
+            return Err(FlowyError::revision_not_synced().context(msg));
+        }
+
+        tracing::warn!("Repair the document {}: {:?}", request.doc_id, report);
+        self.stop_editor(&request.doc_id);
+        let _ = checker.repair::<DocumentRevisionValidator>().await?;
+        let report = checker.check::<DocumentRevisionValidator>().await?;
+        Ok(make_integrity_report(report, true))
+    }
+
+    // The opened documents are closed, so they are reopened with the new cipher when they are edited
+    // next time. The revisions that are not synced yet are encrypted when they are sent.
+    pub fn set_revision_cipher(&self, cipher: Option<Arc<RevisionCipher>>) {
+        let key_id = |cipher: &Option<Arc<RevisionCipher>>| cipher.as_ref().map(|cipher| cipher.key_id().to_owned());
+        if key_id(&self.revision_cipher.read()) == key_id(&cipher) {
+            return;
+        }
+        *self.revision_cipher.write() = cipher;
+        for doc_id in self.open_cache.doc_ids() {
+            self.stop_editor(&doc_id);
+        }
+        for doc_id in self.background_sync.doc_ids() {
+            self.stop_editor(&doc_id);
+        }
+    }
+
+    // Replaces the revisions of the document on the server with one encrypted revision that has the
+    // acked revisions, so the server doesn't keep the plaintext revisions after the encryption was
+    // enabled.
+    #[tracing::instrument(level = "debug", skip(self, cipher), err)]
+    pub async fn encrypt_remote_document(&self, doc_id: &str, cipher: &RevisionCipher) -> FlowyResult<()> {
+        let user_id = self.user.user_id()?;
+        let cache = RevisionCache::new(&user_id, doc_id, self.user.db_pool()?);
+        let mut records = cache.batch_get(doc_id)?;
+        records.sort_by_key(|record| record.revision.rev_id);
+        let revisions = records
+            .into_iter()
+            .take_while(|record| record.state == RevisionState::Ack)
+            .map(|record| record.revision)
+            .collect::<Vec<Revision>>();
+        let last_revision = match revisions.last() {
+            None => return Ok(()),
+            Some(revision) => revision.clone(),
+        };
+
+        let delta = make_delta_from_revisions::<RichTextAttributes>(revisions)?;
+        let revision = Revision::new(
+            doc_id,
+            last_revision.base_rev_id,
+            last_revision.rev_id,
+            delta.to_bytes(),
+            &user_id,
+            last_revision.md5,
+        );
+        let params = ResetDocumentParams {
+            doc_id: doc_id.to_owned(),
+            revisions: RepeatedRevision::new(vec![cipher.encrypt_revision(revision)?]),
+        };
+        self.cloud_service.update_document(&self.user.token()?, params).await
+    }
+
     #[tracing::instrument(level = "debug", skip(self, delta), fields(doc_id = %delta.doc_id), err)]
     pub async fn receive_local_delta(&self, delta: DocumentDelta) -> Result<DocumentDelta, FlowyError> {
         let editor = self.get_editor(&delta.doc_id).await?;
@@ -94,6 +252,52 @@ impl FlowyDocumentManager {
         })
     }
 
+    // Returns the backups of the local document that were made before its pending revisions were
+    // merged with the revisions of the server, the latest one is the first.
+    pub fn read_merge_backups(&self, doc_id: &str) -> FlowyResult<Vec<DocumentMergeBackup>> {
+        let conn = self.user.db_pool()?.get().map_err(internal_error)?;
+        let backups = RevisionMergeBackupSql::read_all(doc_id, &*conn)?;
+        backups.into_iter().map(make_merge_backup).collect()
+    }
+
+    // Replaces the content of the document with the local document of the backup. The change is a
+    // local revision, so it's synced to the server like the other edits.
+    #[tracing::instrument(level = "debug", skip(self, params), fields(doc_id = %params.doc_id), err)]
+    pub async fn restore_merge_backup(&self, params: DocumentMergeBackupId) -> FlowyResult<DocumentDelta> {
+        let backup = self.read_merge_backup(&params)?;
+        let mut delta = backup.local_delta::<RichTextAttributes>()?;
+        let editor = self.get_editor(&params.doc_id).await?;
+        let document = RichTextDelta::from_json(&editor.document_json().await?)?;
+        delta.delete(document.utf16_target_len);
+        let _ = editor.compose_local_delta(Bytes::from(delta.to_json())).await?;
+        let document_json = editor.document_json().await?;
+        Ok(DocumentDelta {
+            doc_id: params.doc_id,
+            delta_json: document_json,
+        })
+    }
+
+    pub fn delete_merge_backup(&self, params: DocumentMergeBackupId) -> FlowyResult<()> {
+        let backup = self.read_merge_backup(&params)?;
+        let conn = self.user.db_pool()?.get().map_err(internal_error)?;
+        RevisionMergeBackupSql::delete(backup.id, &*conn)
+    }
+
+    // The statistics of the selection are returned if the request has both the start and the end.
+    #[tracing::instrument(level = "debug", skip(self, request), fields(doc_id = %request.doc_id), err)]
+    pub async fn document_statistics(&self, request: DocumentStatisticsRequest) -> FlowyResult<DocumentStatistics> {
+        let interval = match (request.start, request.end) {
+            (Some(start), Some(end)) if 0 <= start && start <= end => Some(Interval::new(start as usize, end as usize)),
+            (Some(start), Some(end)) => {
+                let msg = format!("Invalid selection {}..{}", start, end);
+                return Err(FlowyError::internal().context(msg));
+            }
+            _ => None,
+        };
+        let editor = self.get_editor(&request.doc_id).await?;
+        editor.statistics(interval).await
+    }
+
     pub async fn save_document<T: AsRef<str>>(&self, doc_id: T, revisions: RepeatedRevision) -> FlowyResult<()> {
         let doc_id = doc_id.as_ref().to_owned();
         let db_pool = self.user.db_pool()?;
@@ -127,15 +331,55 @@ impl FlowyDocumentManager {
 
 impl FlowyDocumentManager {
     async fn get_editor(&self, doc_id: &str) -> FlowyResult<Arc<ClientDocumentEditor>> {
-        match self.open_cache.get(doc_id) {
-            None => {
-                let db_pool = self.user.db_pool()?;
-                self.make_editor(doc_id, db_pool).await
-            }
-            Some(editor) => Ok(editor),
+        if let Some(editor) = self.open_cache.get(doc_id) {
+            return Ok(editor);
+        }
+
+        // The editor that is syncing in the background is reused, its ws receiver is registered.
+        if let Some(editor) = self.background_sync.take(doc_id) {
+            self.open_cache.insert(doc_id, &editor);
+            self.evict_documents(doc_id);
+            return Ok(editor);
+        }
+
+        let db_pool = self.user.db_pool()?;
+        let doc_editor = self.make_editor(doc_id, db_pool).await?;
+        self.add_ws_receiver(doc_id, doc_editor.ws_handler());
+        self.open_cache.insert(doc_id, &doc_editor);
+        self.evict_documents(doc_id);
+        Ok(doc_editor)
+    }
+
+    // Closes the least recently used documents until the opened documents are within the budget.
+    // The evicted document is reopened when it's accessed next time.
+    fn evict_documents(&self, accessed_doc_id: &str) {
+        for editor in self.open_cache.evict(accessed_doc_id) {
+            tracing::debug!(
+                "Evict the document {}: {} bytes",
+                editor.doc_id,
+                editor.estimated_size()
+            );
+            self.close_editor(editor);
         }
     }
 
+    // The editor that still has the revisions that are not acked is moved to the background sync,
+    // and it's stopped after the revisions were acked.
+    fn close_editor(&self, editor: Arc<ClientDocumentEditor>) {
+        let doc_id = editor.doc_id.clone();
+        let is_synced = read_sync_status(&doc_id).map_or(true, |status| status.is_synced());
+        if is_synced {
+            editor.stop();
+            self.remove_ws_receiver(&doc_id);
+            return;
+        }
+
+        self.background_sync.insert(editor);
+        let background_sync = self.background_sync.clone();
+        let ws_receivers = self.ws_receivers.clone();
+        tokio::spawn(async move { stop_after_synced(&doc_id, &background_sync, &ws_receivers).await });
+    }
+
     async fn make_editor(
         &self,
         doc_id: &str,
@@ -143,21 +387,68 @@ impl FlowyDocumentManager {
     ) -> Result<Arc<ClientDocumentEditor>, FlowyError> {
         let user = self.user.clone();
         let token = self.user.token()?;
-        let rev_manager = self.make_rev_manager(doc_id, pool.clone())?;
+        let rev_manager = self.make_rev_manager(doc_id, pool)?;
         let server = Arc::new(DocumentRevisionCloudServiceImpl {
             token,
             server: self.cloud_service.clone(),
+            cipher: self.revision_cipher.read().clone(),
         });
-        let doc_editor = ClientDocumentEditor::new(doc_id, user, rev_manager, self.web_socket.clone(), server).await?;
-        self.add_ws_receiver(doc_id, doc_editor.ws_handler());
-        self.open_cache.insert(doc_id, &doc_editor);
-        Ok(doc_editor)
+        ClientDocumentEditor::new(doc_id, user, rev_manager, self.web_socket.clone(), server).await
+    }
+
+    // Creates the editor of the document after the permit was acquired, and stops it after the
+    // document was synced.
+    fn spawn_background_sync(self: &Arc<Self>, doc_id: String, semaphore: Arc<Semaphore>) {
+        let manager = self.clone();
+        tokio::spawn(async move {
+            let _permit = match semaphore.acquire().await {
+                Ok(permit) => permit,
+                Err(_) => return,
+            };
+            if manager.open_cache.contains(&doc_id) || manager.background_sync.contains(&doc_id) {
+                return;
+            }
+            let editor = match manager.user.db_pool() {
+                Ok(pool) => manager.make_editor(&doc_id, pool).await,
+                Err(e) => Err(e),
+            };
+            match editor {
+                Ok(editor) => {
+                    manager.add_ws_receiver(&doc_id, editor.ws_handler());
+                    manager.background_sync.insert(editor);
+                }
+                Err(e) => {
+                    tracing::error!("Background sync {} failed: {}", doc_id, e);
+                    return;
+                }
+            }
+            stop_after_synced(&doc_id, &manager.background_sync, &manager.ws_receivers).await;
+        });
+    }
+
+    fn read_merge_backup(&self, params: &DocumentMergeBackupId) -> FlowyResult<RevisionMergeBackup> {
+        let conn = self.user.db_pool()?.get().map_err(internal_error)?;
+        let backup = RevisionMergeBackupSql::read(params.backup_id as i32, &*conn)?;
+        if backup.object_id != params.doc_id {
+            let msg = format!("The backup {} doesn't belong to {}", params.backup_id, params.doc_id);
+            return Err(FlowyError::record_not_found().context(msg));
+        }
+        Ok(backup)
     }
 
     fn make_rev_manager(&self, doc_id: &str, pool: Arc<ConnectionPool>) -> Result<RevisionManager, FlowyError> {
         let user_id = self.user.user_id()?;
         let cache = Arc::new(RevisionCache::new(&user_id, doc_id, pool));
-        Ok(RevisionManager::new(&user_id, doc_id, cache))
+        let cipher = self.revision_cipher.read().clone();
+        Ok(RevisionManager::new(&user_id, doc_id, cache).with_cipher(cipher))
+    }
+
+    fn stop_editor(&self, doc_id: &str) {
+        self.open_cache.remove(doc_id);
+        if let Some(editor) = self.background_sync.take(doc_id) {
+            editor.stop();
+        }
+        self.remove_ws_receiver(doc_id);
     }
 
     fn add_ws_receiver(&self, object_id: &str, receiver: Arc<dyn DocumentWSReceiver>) {
@@ -175,6 +466,7 @@ impl FlowyDocumentManager {
 struct DocumentRevisionCloudServiceImpl {
     token: String,
     server: Arc<dyn DocumentCloudService>,
+    cipher: Option<Arc<RevisionCipher>>,
 }
 
 impl RevisionCloudService for DocumentRevisionCloudServiceImpl {
@@ -186,10 +478,14 @@ impl RevisionCloudService for DocumentRevisionCloudServiceImpl {
         let server = self.server.clone();
         let token = self.token.clone();
         let user_id = user_id.to_string();
+        let cipher = self.cipher.clone();
 
         FutureResult::new(async move {
             match server.read_document(&token, params).await? {
                 None => Err(FlowyError::record_not_found().context("Remote doesn't have this document")),
+                Some(doc) if !doc.encrypted_revisions.is_empty() => {
+                    decrypt_revisions(cipher.as_deref(), doc.encrypted_revisions.into_inner())
+                }
                 Some(doc) => {
                     let delta_data = Bytes::from(doc.text.clone());
                     let doc_md5 = md5(&delta_data);
@@ -202,40 +498,191 @@ impl RevisionCloudService for DocumentRevisionCloudServiceImpl {
     }
 }
 
+fn make_merge_backup(backup: RevisionMergeBackup) -> FlowyResult<DocumentMergeBackup> {
+    let document_json = backup.local_delta::<RichTextAttributes>()?.to_json();
+    Ok(DocumentMergeBackup {
+        id: backup.id as i64,
+        doc_id: backup.object_id,
+        base_rev_id: backup.base_rev_id,
+        local_revision_count: backup.rev_ids.len() as i64,
+        document_json,
+        create_time: backup.created_at,
+    })
+}
+
+fn make_integrity_report(report: RevisionIntegrityReport, repaired: bool) -> DocumentIntegrityReport {
+    DocumentIntegrityReport {
+        is_valid: report.is_valid(),
+        invalid_ranges: ranges_from_rev_ids(&report.object_id, &report.invalid_rev_ids),
+        doc_id: report.object_id,
+        revision_count: report.revision_count,
+        pending_count: report.pending_count,
+        last_rev_id: report.last_rev_id,
+        missing_ranges: report.missing_ranges,
+        compose_error: report.compose_error,
+        local_md5: report.local_md5,
+        server_rev_id: report.server_rev_id,
+        server_md5: report.server_md5,
+        server_error: report.server_error,
+        repaired,
+    }
+}
+
+// Groups the sorted rev ids into the ranges of the consecutive rev ids.
+fn ranges_from_rev_ids(object_id: &str, rev_ids: &[i64]) -> Vec<RevisionRange> {
+    let mut ranges: Vec<RevisionRange> = vec![];
+    for rev_id in rev_ids {
+        match ranges.last_mut() {
+            Some(range) if range.end + 1 == *rev_id => range.end = *rev_id,
+            _ => ranges.push(RevisionRange {
+                object_id: object_id.to_owned(),
+                start: *rev_id,
+                end: *rev_id,
+            }),
+        }
+    }
+    ranges
+}
+
+#[derive(Debug, Clone)]
+pub struct DocumentCacheConfig {
+    // The maximum number of the opened documents.
+    pub max_count: usize,
+    // The maximum estimated size of the opened documents in bytes.
+    pub max_size: usize,
+}
+
+impl std::default::Default for DocumentCacheConfig {
+    fn default() -> Self {
+        Self {
+            max_count: 20,
+            max_size: 64 * 1024 * 1024,
+        }
+    }
+}
+
+struct OpenDoc {
+    editor: Arc<ClientDocumentEditor>,
+    last_access: AtomicU64,
+}
+
+// Keeps the editors of the opened documents. The least recently used documents are evicted when
+// the number or the estimated size of the opened documents exceeds the DocumentCacheConfig.
 pub struct OpenDocCache {
-    inner: DashMap<String, Arc<ClientDocumentEditor>>,
+    inner: DashMap<String, OpenDoc>,
+    config: DocumentCacheConfig,
+    access_tick: AtomicU64,
+    evicted_count: AtomicU64,
 }
 
 impl OpenDocCache {
-    fn new() -> Self {
-        Self { inner: DashMap::new() }
+    fn new(config: DocumentCacheConfig) -> Self {
+        Self {
+            inner: DashMap::new(),
+            config,
+            access_tick: AtomicU64::new(0),
+            evicted_count: AtomicU64::new(0),
+        }
     }
 
     pub(crate) fn insert(&self, doc_id: &str, doc: &Arc<ClientDocumentEditor>) {
         if self.inner.contains_key(doc_id) {
             log::warn!("Doc:{} already exists in cache", doc_id);
         }
-        self.inner.insert(doc_id.to_string(), doc.clone());
+        let doc = OpenDoc {
+            editor: doc.clone(),
+            last_access: AtomicU64::new(self.next_tick()),
+        };
+        self.inner.insert(doc_id.to_string(), doc);
     }
 
     pub(crate) fn contains(&self, doc_id: &str) -> bool {
         self.inner.get(doc_id).is_some()
     }
 
+    pub(crate) fn doc_ids(&self) -> Vec<String> {
+        self.inner.iter().map(|item| item.key().clone()).collect()
+    }
+
     pub(crate) fn get(&self, doc_id: &str) -> Option<Arc<ClientDocumentEditor>> {
-        if !self.contains(doc_id) {
-            return None;
-        }
-        let opened_doc = self.inner.get(doc_id).unwrap();
-        Some(opened_doc.clone())
+        let opened_doc = self.inner.get(doc_id)?;
+        opened_doc.last_access.store(self.next_tick(), Ordering::SeqCst);
+        Some(opened_doc.editor.clone())
+    }
+
+    // Removes the editor from the cache without stopping it.
+    pub(crate) fn take(&self, doc_id: &str) -> Option<Arc<ClientDocumentEditor>> {
+        self.inner.remove(doc_id).map(|(_, doc)| doc.editor)
     }
 
     pub(crate) fn remove(&self, id: &str) {
-        let doc_id = id.to_string();
-        if let Some(editor) = self.get(id) {
+        if let Some(editor) = self.take(id) {
             editor.stop()
         }
-        self.inner.remove(&doc_id);
+    }
+
+    // Takes the least recently used editors out of the cache until it's within the budget. The
+    // editor of the except_doc_id is never evicted, even if it exceeds the budget by itself.
+    pub(crate) fn evict(&self, except_doc_id: &str) -> Vec<Arc<ClientDocumentEditor>> {
+        let mut docs = self
+            .inner
+            .iter()
+            .map(|doc| {
+                let last_access = doc.last_access.load(Ordering::SeqCst);
+                (doc.key().clone(), last_access, doc.editor.estimated_size())
+            })
+            .collect::<Vec<(String, u64, usize)>>();
+        docs.sort_by_key(|(_, last_access, _)| *last_access);
+
+        let mut count = docs.len();
+        let mut size = docs.iter().map(|(_, _, size)| size).sum::<usize>();
+        let mut evicted = vec![];
+        for (doc_id, _, doc_size) in docs {
+            if count <= self.config.max_count && size <= self.config.max_size {
+                break;
+            }
+            if doc_id == except_doc_id {
+                continue;
+            }
+            if let Some(editor) = self.take(&doc_id) {
+                count -= 1;
+                size -= doc_size;
+                evicted.push(editor);
+            }
+        }
+        self.evicted_count.fetch_add(evicted.len() as u64, Ordering::SeqCst);
+        evicted
+    }
+
+    pub(crate) fn usage(&self) -> OpenDocumentsUsage {
+        let estimated_size: usize = self.inner.iter().map(|doc| doc.editor.estimated_size()).sum();
+        OpenDocumentsUsage {
+            count: self.inner.len() as i64,
+            estimated_size: estimated_size as i64,
+            max_count: self.config.max_count as i64,
+            max_size: self.config.max_size as i64,
+            evicted_count: self.evicted_count.load(Ordering::SeqCst) as i64,
+        }
+    }
+
+    fn next_tick(&self) -> u64 {
+        self.access_tick.fetch_add(1, Ordering::SeqCst)
+    }
+}
+
+// Waits until the editor that was moved to the background sync was synced, then stops it if it's
+// not reopened.
+async fn stop_after_synced(
+    doc_id: &str,
+    background_sync: &BackgroundSyncScheduler,
+    ws_receivers: &WebSocketDataReceivers,
+) {
+    if !wait_until_synced(doc_id).await {
+        tracing::warn!("Background sync {} timed out", doc_id);
+    }
+    if let Some(editor) = background_sync.take(doc_id) {
+        editor.stop();
+        ws_receivers.remove(doc_id);
     }
 }
 
@@ -5,19 +5,29 @@ use crate::{
 };
 use bytes::Bytes;
 use flowy_collaboration::{
-    entities::{document_info::DocumentInfo, revision::Revision},
+    client_document::{BlockCommand, TableCommand},
+    entities::{
+        document_info::DocumentInfo,
+        outline::{DocumentSection, OutlineItem},
+        revision::{md5, RepeatedRevision, Revision},
+        statistics::DocumentStatistics,
+    },
     errors::CollaborateResult,
     util::make_delta_from_revisions,
 };
 use flowy_error::{internal_error, FlowyResult};
 use flowy_sync::{
-    RevisionCloudService, RevisionManager, RevisionObjectBuilder, RevisionWebSocket, RevisionWebSocketManager,
+    RevisionCloudService, RevisionManager, RevisionObjectBuilder, RevisionObjectValidator, RevisionWebSocket,
+    RevisionWebSocketManager,
 };
 use lib_ot::{
     core::{Interval, Operation},
     rich_text::{RichTextAttribute, RichTextDelta},
 };
-use std::sync::Arc;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 use tokio::sync::{mpsc, oneshot};
 
 pub struct ClientDocumentEditor {
@@ -26,6 +36,7 @@ pub struct ClientDocumentEditor {
     rev_manager: Arc<RevisionManager>,
     ws_manager: Arc<RevisionWebSocketManager>,
     edit_cmd_tx: EditorCommandSender,
+    size: Arc<AtomicUsize>,
 }
 
 impl ClientDocumentEditor {
@@ -42,7 +53,8 @@ impl ClientDocumentEditor {
         let doc_id = doc_id.to_string();
         let user_id = user.user_id()?;
 
-        let edit_cmd_tx = spawn_edit_queue(user, rev_manager.clone(), delta);
+        let size = Arc::new(AtomicUsize::new(0));
+        let edit_cmd_tx = spawn_edit_queue(user, rev_manager.clone(), delta, size.clone());
         let ws_manager = make_document_ws_manager(
             doc_id.clone(),
             user_id.clone(),
@@ -56,6 +68,7 @@ impl ClientDocumentEditor {
             rev_manager,
             ws_manager,
             edit_cmd_tx,
+            size,
         });
         Ok(editor)
     }
@@ -104,6 +117,57 @@ impl ClientDocumentEditor {
         Ok(())
     }
 
+    // Pastes the fragment that is copied by the copy_delta, the text in the interval is replaced.
+    pub async fn paste_delta(&self, interval: Interval, fragment: RichTextDelta) -> Result<(), FlowyError> {
+        let (ret, rx) = oneshot::channel::<CollaborateResult<()>>();
+        let msg = EditorCommand::PasteDelta {
+            interval,
+            fragment,
+            ret,
+        };
+        let _ = self.edit_cmd_tx.send(msg).await;
+        let _ = rx.await.map_err(internal_error)??;
+        Ok(())
+    }
+
+    pub async fn copy_delta(&self, interval: Interval) -> Result<RichTextDelta, FlowyError> {
+        let (ret, rx) = oneshot::channel::<CollaborateResult<RichTextDelta>>();
+        let msg = EditorCommand::CopyDelta { interval, ret };
+        let _ = self.edit_cmd_tx.send(msg).await;
+        let fragment = rx.await.map_err(internal_error)??;
+        Ok(fragment)
+    }
+
+    pub async fn edit_table(&self, command: TableCommand) -> Result<(), FlowyError> {
+        let (ret, rx) = oneshot::channel::<CollaborateResult<()>>();
+        let msg = EditorCommand::EditTable { command, ret };
+        let _ = self.edit_cmd_tx.send(msg).await;
+        let _ = rx.await.map_err(internal_error)??;
+        Ok(())
+    }
+
+    pub async fn edit_block(&self, command: BlockCommand) -> Result<(), FlowyError> {
+        let (ret, rx) = oneshot::channel::<CollaborateResult<()>>();
+        let msg = EditorCommand::EditBlock { command, ret };
+        let _ = self.edit_cmd_tx.send(msg).await;
+        let _ = rx.await.map_err(internal_error)??;
+        Ok(())
+    }
+
+    // Anchors the comment thread to the text in the interval, the anchor is undone with the other
+    // edits of the document.
+    pub async fn add_comment_anchor(&self, interval: Interval, thread_id: &str) -> Result<(), FlowyError> {
+        let (ret, rx) = oneshot::channel::<CollaborateResult<()>>();
+        let msg = EditorCommand::AddCommentAnchor {
+            interval,
+            thread_id: thread_id.to_owned(),
+            ret,
+        };
+        let _ = self.edit_cmd_tx.send(msg).await;
+        let _ = rx.await.map_err(internal_error)??;
+        Ok(())
+    }
+
     pub async fn can_undo(&self) -> bool {
         let (ret, rx) = oneshot::channel::<bool>();
         let msg = EditorCommand::CanUndo { ret };
@@ -134,6 +198,33 @@ impl ClientDocumentEditor {
         Ok(())
     }
 
+    pub async fn outline(&self) -> FlowyResult<Vec<OutlineItem>> {
+        let (ret, rx) = oneshot::channel::<Vec<OutlineItem>>();
+        let msg = EditorCommand::ReadOutline { ret };
+        let _ = self.edit_cmd_tx.send(msg).await;
+        let items = rx.await.map_err(internal_error)?;
+        Ok(items)
+    }
+
+    // Returns None if there is no header at the index of the outline.
+    pub async fn outline_section(&self, index: usize) -> FlowyResult<Option<DocumentSection>> {
+        let (ret, rx) = oneshot::channel::<Option<DocumentSection>>();
+        let msg = EditorCommand::ReadOutlineSection { index, ret };
+        let _ = self.edit_cmd_tx.send(msg).await;
+        let section = rx.await.map_err(internal_error)?;
+        Ok(section)
+    }
+
+    // Returns the statistics of the text in the interval, or the whole document if the interval
+    // is None.
+    pub async fn statistics(&self, interval: Option<Interval>) -> FlowyResult<DocumentStatistics> {
+        let (ret, rx) = oneshot::channel::<CollaborateResult<DocumentStatistics>>();
+        let msg = EditorCommand::ReadStatistics { interval, ret };
+        let _ = self.edit_cmd_tx.send(msg).await;
+        let statistics = rx.await.map_err(internal_error)??;
+        Ok(statistics)
+    }
+
     pub async fn document_json(&self) -> FlowyResult<String> {
         let (ret, rx) = oneshot::channel::<CollaborateResult<String>>();
         let msg = EditorCommand::ReadDocumentAsJson { ret };
@@ -159,6 +250,11 @@ impl ClientDocumentEditor {
         self.ws_manager.stop();
     }
 
+    // The estimated memory size of the document in bytes.
+    pub fn estimated_size(&self) -> usize {
+        self.size.load(Ordering::SeqCst)
+    }
+
     pub(crate) fn ws_handler(&self) -> Arc<dyn DocumentWSReceiver> {
         self.ws_manager.clone()
     }
@@ -175,9 +271,10 @@ fn spawn_edit_queue(
     user: Arc<dyn DocumentUser>,
     rev_manager: Arc<RevisionManager>,
     delta: RichTextDelta,
+    size: Arc<AtomicUsize>,
 ) -> EditorCommandSender {
     let (sender, receiver) = mpsc::channel(1000);
-    let actor = EditorCommandQueue::new(user, rev_manager, delta, receiver);
+    let actor = EditorCommandQueue::new(user, rev_manager, delta, receiver, size);
     tokio::spawn(actor.run());
     sender
 }
@@ -219,10 +316,26 @@ impl RevisionObjectBuilder for DocumentInfoBuilder {
             text: delta.to_json(),
             rev_id,
             base_rev_id,
+            encrypted_revisions: RepeatedRevision::empty(),
         })
     }
 }
 
+pub(crate) struct DocumentRevisionValidator();
+impl RevisionObjectValidator for DocumentRevisionValidator {
+    fn validate_revision(revision: &Revision) -> FlowyResult<()> {
+        let _ = RichTextDelta::from_bytes(&revision.delta_data).map_err(internal_error)?;
+        Ok(())
+    }
+
+    // The delta is not corrected like the DocumentInfoBuilder does, so the md5 is the same as the
+    // md5 of the document on the server.
+    fn compose_revisions(_object_id: &str, revisions: Vec<Revision>) -> FlowyResult<String> {
+        let delta: RichTextDelta = make_delta_from_revisions(revisions)?;
+        Ok(md5(delta.to_bytes()))
+    }
+}
+
 // quill-editor requires the delta should end with '\n' and only contains the
 // insert operation. The function, correct_delta maybe be removed in the future.
 fn correct_delta(delta: &mut RichTextDelta) {
@@ -1,18 +1,28 @@
 use crate::{core::web_socket::EditorCommandReceiver, DocumentUser};
 use async_stream::stream;
 use flowy_collaboration::{
-    client_document::{history::UndoResult, ClientDocument},
-    entities::revision::{RevId, Revision},
+    client_document::{history::UndoResult, BlockCommand, ClientDocument, TableCommand},
+    entities::{
+        outline::{DocumentSection, OutlineItem},
+        revision::{RevId, Revision},
+        statistics::DocumentStatistics,
+    },
     errors::CollaborateError,
 };
 use flowy_error::FlowyError;
 use flowy_sync::{DeltaMD5, RevisionManager, TransformDeltas};
 use futures::stream::StreamExt;
 use lib_ot::{
-    core::{Interval, OperationTransformable},
+    core::{Interval, Operation, OperationTransformable},
     rich_text::{RichTextAttribute, RichTextAttributes, RichTextDelta},
 };
-use std::sync::Arc;
+use std::{
+    mem::size_of,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
 use tokio::sync::{oneshot, RwLock};
 
 // The EditorCommandQueue executes each command that will alter the document in
@@ -22,6 +32,8 @@ pub(crate) struct EditorCommandQueue {
     user: Arc<dyn DocumentUser>,
     rev_manager: Arc<RevisionManager>,
     receiver: Option<EditorCommandReceiver>,
+    // The estimated size of the document in bytes, it's updated after each command.
+    size: Arc<AtomicUsize>,
 }
 
 impl EditorCommandQueue {
@@ -30,13 +42,16 @@ impl EditorCommandQueue {
         rev_manager: Arc<RevisionManager>,
         delta: RichTextDelta,
         receiver: EditorCommandReceiver,
+        size: Arc<AtomicUsize>,
     ) -> Self {
+        size.store(estimate_delta_size(&delta), Ordering::SeqCst);
         let document = Arc::new(RwLock::new(ClientDocument::from_delta(delta)));
         Self {
             document,
             user,
             rev_manager,
             receiver: Some(receiver),
+            size,
         }
     }
 
@@ -56,6 +71,8 @@ impl EditorCommandQueue {
                     Ok(_) => {}
                     Err(e) => tracing::debug!("[EditCommandQueue]: {}", e),
                 }
+                let size = estimate_delta_size(self.document.read().await.delta());
+                self.size.store(size, Ordering::SeqCst);
             })
             .await;
     }
@@ -139,6 +156,47 @@ impl EditorCommandQueue {
                 let _ = self.save_local_delta(delta, md5).await?;
                 let _ = ret.send(Ok(()));
             }
+            EditorCommand::PasteDelta {
+                interval,
+                fragment,
+                ret,
+            } => {
+                let mut write_guard = self.document.write().await;
+                let delta = write_guard.replace_with_delta(interval, fragment)?;
+                let md5 = write_guard.md5();
+                let _ = self.save_local_delta(delta, md5).await?;
+                let _ = ret.send(Ok(()));
+            }
+            EditorCommand::CopyDelta { interval, ret } => {
+                let _ = ret.send(self.document.read().await.copy_delta(interval));
+            }
+            EditorCommand::EditTable { command, ret } => {
+                let mut write_guard = self.document.write().await;
+                let delta = write_guard.edit_table(command)?;
+                let md5 = write_guard.md5();
+                let _ = self.save_local_delta(delta, md5).await?;
+                let _ = ret.send(Ok(()));
+            }
+            EditorCommand::EditBlock { command, ret } => {
+                let mut write_guard = self.document.write().await;
+                let delta = write_guard.edit_block(command)?;
+                if !delta.is_empty() {
+                    let md5 = write_guard.md5();
+                    let _ = self.save_local_delta(delta, md5).await?;
+                }
+                let _ = ret.send(Ok(()));
+            }
+            EditorCommand::AddCommentAnchor {
+                interval,
+                thread_id,
+                ret,
+            } => {
+                let mut write_guard = self.document.write().await;
+                let delta = write_guard.add_comment_anchor(interval, &thread_id)?;
+                let md5 = write_guard.md5();
+                let _ = self.save_local_delta(delta, md5).await?;
+                let _ = ret.send(Ok(()));
+            }
             EditorCommand::CanUndo { ret } => {
                 let _ = ret.send(self.document.read().await.can_undo());
             }
@@ -159,6 +217,20 @@ impl EditorCommandQueue {
                 let _ = self.save_local_delta(delta, md5).await?;
                 let _ = ret.send(Ok(()));
             }
+            EditorCommand::ReadOutline { ret } => {
+                let _ = ret.send(self.document.read().await.outline());
+            }
+            EditorCommand::ReadOutlineSection { index, ret } => {
+                let _ = ret.send(self.document.read().await.outline_section(index));
+            }
+            EditorCommand::ReadStatistics { interval, ret } => {
+                let document = self.document.read().await;
+                let statistics = match interval {
+                    None => Ok(document.statistics()),
+                    Some(interval) => document.selection_statistics(interval),
+                };
+                let _ = ret.send(statistics);
+            }
             EditorCommand::ReadDocumentAsJson { ret } => {
                 let data = self.document.read().await.to_json();
                 let _ = ret.send(Ok(data));
@@ -188,6 +260,11 @@ impl EditorCommandQueue {
     }
 }
 
+// The text is counted as utf16, and the history is not counted because it keeps at most 20 deltas.
+fn estimate_delta_size(delta: &RichTextDelta) -> usize {
+    delta.utf16_target_len * size_of::<u16>() + delta.ops.len() * size_of::<Operation<RichTextAttributes>>()
+}
+
 pub(crate) type Ret<T> = oneshot::Sender<Result<T, CollaborateError>>;
 
 pub(crate) enum EditorCommand {
@@ -226,6 +303,28 @@ pub(crate) enum EditorCommand {
         data: String,
         ret: Ret<()>,
     },
+    PasteDelta {
+        interval: Interval,
+        fragment: RichTextDelta,
+        ret: Ret<()>,
+    },
+    CopyDelta {
+        interval: Interval,
+        ret: Ret<RichTextDelta>,
+    },
+    EditTable {
+        command: TableCommand,
+        ret: Ret<()>,
+    },
+    EditBlock {
+        command: BlockCommand,
+        ret: Ret<()>,
+    },
+    AddCommentAnchor {
+        interval: Interval,
+        thread_id: String,
+        ret: Ret<()>,
+    },
     CanUndo {
         ret: oneshot::Sender<bool>,
     },
@@ -238,6 +337,17 @@ pub(crate) enum EditorCommand {
     Redo {
         ret: Ret<()>,
     },
+    ReadOutline {
+        ret: oneshot::Sender<Vec<OutlineItem>>,
+    },
+    ReadOutlineSection {
+        index: usize,
+        ret: oneshot::Sender<Option<DocumentSection>>,
+    },
+    ReadStatistics {
+        interval: Option<Interval>,
+        ret: Ret<DocumentStatistics>,
+    },
     ReadDocumentAsJson {
         ret: Ret<String>,
     },
@@ -258,10 +368,18 @@ impl std::fmt::Debug for EditorCommand {
             EditorCommand::Delete { .. } => "Delete",
             EditorCommand::Format { .. } => "Format",
             EditorCommand::Replace { .. } => "Replace",
+            EditorCommand::PasteDelta { .. } => "PasteDelta",
+            EditorCommand::CopyDelta { .. } => "CopyDelta",
+            EditorCommand::EditTable { .. } => "EditTable",
+            EditorCommand::EditBlock { .. } => "EditBlock",
+            EditorCommand::AddCommentAnchor { .. } => "AddCommentAnchor",
             EditorCommand::CanUndo { .. } => "CanUndo",
             EditorCommand::CanRedo { .. } => "CanRedo",
             EditorCommand::Undo { .. } => "Undo",
             EditorCommand::Redo { .. } => "Redo",
+            EditorCommand::ReadOutline { .. } => "ReadOutline",
+            EditorCommand::ReadOutlineSection { .. } => "ReadOutlineSection",
+            EditorCommand::ReadStatistics { .. } => "ReadStatistics",
             EditorCommand::ReadDocumentAsJson { .. } => "ReadDocumentAsJson",
             EditorCommand::ReadDocumentAsDelta { .. } => "ReadDocumentAsDelta",
         };
@@ -1,3 +1,4 @@
+mod background_sync;
 pub mod controller;
 pub mod core;
 // mod notify;
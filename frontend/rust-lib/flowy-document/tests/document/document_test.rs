@@ -1,5 +1,6 @@
 use crate::document::edit_script::{EditorScript::*, *};
 use flowy_collaboration::entities::revision::RevisionState;
+use flowy_document::{errors::ErrorCode, DocumentCacheConfig};
 use lib_ot::core::{count_utf16_code_units, Interval};
 
 #[tokio::test]
@@ -103,3 +104,125 @@ async fn document_sync_replace_test() {
     ];
     EditorTest::new().await.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn document_outline_test() {
+    let scripts = vec![
+        InsertText("a\nb\nc", 0),
+        Format(Interval::new(0, 1), RichTextAttribute::Header(1)),
+        Format(Interval::new(2, 3), RichTextAttribute::Header(2)),
+        AssertOutline(vec![(1, "a"), (2, "b")]),
+        InsertText("1", 2),
+        AssertOutline(vec![(1, "a"), (2, "1b")]),
+        AssertOutlineSection(0, Some((0, 7))),
+        AssertOutlineSection(1, Some((2, 7))),
+        AssertOutlineSection(2, None),
+        Delete(Interval::new(1, 2)),
+        AssertOutline(vec![(2, "a1b")]),
+    ];
+    EditorTest::new().await.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn document_statistics_test() {
+    let scripts = vec![
+        InsertText("hello world", 0),
+        AssertStatistics(None, 2, 11),
+        InsertText("\n你好", 11),
+        AssertStatistics(None, 4, 13),
+        AssertStatistics(Some(Interval::new(6, 14)), 3, 7),
+        Delete(Interval::new(0, 6)),
+        AssertStatistics(None, 3, 7),
+    ];
+    EditorTest::new().await.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn document_sync_status_test() {
+    let scripts = vec![
+        InsertText("1", 0),
+        InsertText("2", 1),
+        InsertText("3", 2),
+        AssertSyncStatus(0, 3),
+        AssertNextRevId(None),
+    ];
+    EditorTest::new().await.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn document_sync_after_close_test() {
+    let scripts = vec![
+        InsertText("1", 0),
+        InsertText("2", 1),
+        CloseDocument,
+        AssertSyncStatus(0, 2),
+        AssertRevisionState(2, RevisionState::Ack),
+    ];
+    EditorTest::new().await.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn document_integrity_test() {
+    let scripts = vec![
+        InsertText("1", 0),
+        InsertText("2", 1),
+        InsertText("3", 2),
+        AssertSyncStatus(0, 3),
+        AssertIntegrity(true, 4),
+    ];
+    EditorTest::new().await.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn document_repair_with_pending_revisions_test() {
+    let scripts = vec![
+        InsertText("1", 0),
+        InsertText("2", 1),
+        InsertText("3", 2),
+        AssertSyncStatus(0, 3),
+        RemoveRevision(2),
+        SetRevisionState(3, RevisionState::Sync),
+        AssertIntegrity(false, 3),
+        RepairDocument(Some(ErrorCode::RevisionNotSynced)),
+        AssertRevisionState(3, RevisionState::Sync),
+        AssertIntegrity(false, 3),
+    ];
+    EditorTest::new().await.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn document_encrypted_sync_test() {
+    let scripts = vec![
+        InsertText("1", 0),
+        SetPassphrase("passphrase"),
+        InsertText("2", 1),
+        InsertText("3", 2),
+        AssertSyncStatus(0, 3),
+        AssertJson(r#"[{"insert":"123\n"}]"#),
+        AssertIntegrity(true, 4),
+    ];
+    EditorTest::new().await.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn document_lru_eviction_test() {
+    let cache_config = DocumentCacheConfig {
+        max_count: 1,
+        ..Default::default()
+    };
+    let scripts = vec![
+        InsertText("1", 0),
+        OpenAnotherDocument,
+        AssertOpenDocuments(1, 1),
+        AssertSyncStatus(0, 1),
+        ReopenDocument,
+        AssertOpenDocuments(1, 2),
+        InsertText("2", 1),
+        AssertSyncStatus(0, 2),
+        AssertJson(r#"[{"insert":"12\n"}]"#),
+    ];
+    EditorTest::new_with_cache(cache_config)
+        .await
+        .run_scripts(scripts)
+        .await;
+}
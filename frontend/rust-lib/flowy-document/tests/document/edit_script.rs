@@ -1,7 +1,18 @@
-use flowy_collaboration::entities::revision::RevisionState;
-use flowy_document::core::{ClientDocumentEditor, DOCUMENT_SYNC_INTERVAL_IN_MILLIS};
+use flowy_collaboration::entities::{
+    integrity::DocumentIntegrityRequest,
+    revision::{Revision, RevisionState},
+};
+use flowy_document::{
+    core::{ClientDocumentEditor, DOCUMENT_SYNC_INTERVAL_IN_MILLIS},
+    errors::ErrorCode,
+    DocumentCacheConfig,
+};
+use flowy_sync::{read_sync_status, RevisionCache, RevisionCipher};
 use flowy_test::{helper::ViewTest, FlowySDKTest};
-use lib_ot::{core::Interval, rich_text::RichTextDelta};
+use lib_ot::{
+    core::Interval,
+    rich_text::{RichTextAttribute, RichTextDelta},
+};
 use std::sync::Arc;
 use tokio::time::{sleep, Duration};
 
@@ -9,11 +20,36 @@ pub enum EditorScript {
     InsertText(&'static str, usize),
     Delete(Interval),
     Replace(Interval, &'static str),
+    Format(Interval, RichTextAttribute),
+    CloseDocument,
+    // Encrypts the revisions with the key of the passphrase, the document is reopened
+    SetPassphrase(&'static str),
+    // Opens the document of a new view, the document of the editor is not accessed
+    OpenAnotherDocument,
+    ReopenDocument,
+    // Removes the revision from the rev_table, the revisions after it are kept
+    RemoveRevision(i64),
+    // Changes the state of the revision in the rev_table without syncing it
+    SetRevisionState(i64, RevisionState),
+    // The error code of the repair, None if it's repaired
+    RepairDocument(Option<ErrorCode>),
 
     AssertRevisionState(i64, RevisionState),
     AssertNextRevId(Option<i64>),
     AssertCurrentRevId(i64),
     AssertJson(&'static str),
+    // level, text
+    AssertOutline(Vec<(i64, &'static str)>),
+    // index, start and end of the section
+    AssertOutlineSection(usize, Option<(i64, i64)>),
+    // selection, words, characters
+    AssertStatistics(Option<Interval>, i64, i64),
+    // pending count, last acked rev_id
+    AssertSyncStatus(i64, i64),
+    // is valid, revision count
+    AssertIntegrity(bool, i64),
+    // opened count, evicted count
+    AssertOpenDocuments(i64, i64),
 }
 
 pub struct EditorTest {
@@ -23,7 +59,14 @@ pub struct EditorTest {
 
 impl EditorTest {
     pub async fn new() -> Self {
-        let sdk = FlowySDKTest::default();
+        Self::with_sdk(FlowySDKTest::default()).await
+    }
+
+    pub async fn new_with_cache(cache_config: DocumentCacheConfig) -> Self {
+        Self::with_sdk(FlowySDKTest::with_document_cache(cache_config)).await
+    }
+
+    async fn with_sdk(sdk: FlowySDKTest) -> Self {
         let _ = sdk.init_user().await;
         let test = ViewTest::new(&sdk).await;
         let editor = sdk.document_manager.open_document(&test.view.id).await.unwrap();
@@ -53,6 +96,57 @@ impl EditorTest {
             EditorScript::Replace(interval, s) => {
                 self.editor.replace(interval, s).await.unwrap();
             }
+            EditorScript::Format(interval, attribute) => {
+                self.editor.format(interval, attribute).await.unwrap();
+            }
+            EditorScript::CloseDocument => {
+                self.sdk.document_manager.close_document(&self.editor.doc_id).unwrap();
+            }
+            EditorScript::SetPassphrase(passphrase) => {
+                let cipher = RevisionCipher::from_passphrase(passphrase, &self.editor.doc_id).unwrap();
+                self.sdk.document_manager.set_revision_cipher(Some(Arc::new(cipher)));
+                self.editor = self
+                    .sdk
+                    .document_manager
+                    .open_document(&self.editor.doc_id)
+                    .await
+                    .unwrap();
+            }
+            EditorScript::OpenAnotherDocument => {
+                let test = ViewTest::new(&self.sdk).await;
+                let _ = self.sdk.document_manager.open_document(&test.view.id).await.unwrap();
+            }
+            EditorScript::ReopenDocument => {
+                self.editor = self
+                    .sdk
+                    .document_manager
+                    .open_document(&self.editor.doc_id)
+                    .await
+                    .unwrap();
+            }
+            EditorScript::RemoveRevision(rev_id) => {
+                let mut revisions = revisions_after(&cache, rev_id).await;
+                let end_rev_id = revisions.last().map_or(rev_id, |(revision, _)| revision.rev_id);
+                revisions.retain(|(revision, _)| revision.rev_id != rev_id);
+                cache.replace_revisions(rev_id, end_rev_id, revisions).await.unwrap();
+            }
+            EditorScript::SetRevisionState(rev_id, state) => {
+                let mut revisions = revisions_after(&cache, rev_id).await;
+                let end_rev_id = revisions.last().map_or(rev_id, |(revision, _)| revision.rev_id);
+                revisions[0].1 = state;
+                cache.replace_revisions(rev_id, end_rev_id, revisions).await.unwrap();
+            }
+            EditorScript::RepairDocument(error_code) => {
+                let request = DocumentIntegrityRequest {
+                    doc_id: self.editor.doc_id.clone(),
+                    repair: true,
+                };
+                let result = self.sdk.document_manager.check_document_integrity(request).await;
+                match error_code {
+                    None => assert!(result.unwrap().repaired),
+                    Some(error_code) => assert_eq!(result.unwrap_err().code, error_code.value()),
+                }
+            }
             EditorScript::AssertRevisionState(rev_id, state) => {
                 let record = cache.get(rev_id).await.unwrap();
                 assert_eq!(record.state, state);
@@ -78,7 +172,70 @@ impl EditorTest {
                 }
                 assert_eq!(expected_delta, delta);
             }
+            EditorScript::AssertOutline(expected) => {
+                let outline = self
+                    .editor
+                    .outline()
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|item| (item.level, item.text))
+                    .collect::<Vec<(i64, String)>>();
+                let expected = expected
+                    .into_iter()
+                    .map(|(level, text)| (level, text.to_owned()))
+                    .collect::<Vec<(i64, String)>>();
+                assert_eq!(outline, expected);
+            }
+            EditorScript::AssertOutlineSection(index, expected) => {
+                let section = self.editor.outline_section(index).await.unwrap();
+                assert_eq!(section.map(|section| (section.start, section.end)), expected);
+            }
+            EditorScript::AssertStatistics(interval, words, characters) => {
+                let statistics = self.editor.statistics(interval).await.unwrap();
+                assert_eq!((statistics.words, statistics.characters), (words, characters));
+            }
+            EditorScript::AssertSyncStatus(pending_count, last_acked_rev_id) => {
+                let status = read_sync_status(&rev_manager.object_id).unwrap();
+                assert_eq!(
+                    (status.pending_count, status.last_acked_rev_id),
+                    (pending_count, last_acked_rev_id)
+                );
+                if pending_count == 0 {
+                    assert!(status.last_sync_time > 0);
+                }
+            }
+            EditorScript::AssertIntegrity(is_valid, revision_count) => {
+                let request = DocumentIntegrityRequest {
+                    doc_id: self.editor.doc_id.clone(),
+                    repair: false,
+                };
+                let report = self
+                    .sdk
+                    .document_manager
+                    .check_document_integrity(request)
+                    .await
+                    .unwrap();
+                assert_eq!((report.is_valid, report.revision_count), (is_valid, revision_count));
+            }
+            EditorScript::AssertOpenDocuments(count, evicted_count) => {
+                let usage = self.sdk.document_manager.open_documents_usage();
+                assert_eq!((usage.count, usage.evicted_count), (count, evicted_count));
+                assert!(usage.estimated_size <= usage.max_size);
+            }
         }
         sleep(Duration::from_millis(DOCUMENT_SYNC_INTERVAL_IN_MILLIS)).await;
     }
 }
+
+// Returns the revisions from the rev_id to the latest revision with their states.
+async fn revisions_after(cache: &Arc<RevisionCache>, rev_id: i64) -> Vec<(Revision, RevisionState)> {
+    let latest_rev_id = cache.latest_revision().await.rev_id;
+    let mut revisions = vec![];
+    for rev_id in rev_id..=latest_rev_id {
+        if let Some(record) = cache.get(rev_id).await {
+            revisions.push((record.revision, record.state));
+        }
+    }
+    revisions
+}
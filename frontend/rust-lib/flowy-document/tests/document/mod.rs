@@ -1,2 +1,4 @@
 mod document_test;
 mod edit_script;
+mod revision_script;
+mod revision_test;
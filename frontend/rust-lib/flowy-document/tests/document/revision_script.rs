@@ -0,0 +1,77 @@
+use bytes::Bytes;
+use flowy_collaboration::{
+    entities::revision::{Revision, RevisionState},
+    util::md5,
+};
+use flowy_sync::{RevisionCache, RevisionManager};
+use flowy_test::FlowySDKTest;
+use lib_infra::uuid_string;
+use std::{ops::RangeInclusive, sync::Arc};
+
+pub enum RevisionScript {
+    // count, size of the delta data. The revisions are not synced, because the object isn't opened.
+    AddLocalRevisions(usize, usize),
+    AckRevision(i64),
+    // max count, max bytes, rev_ids of the batch
+    AssertNextSyncRevisions(usize, usize, RangeInclusive<i64>),
+    AssertPendingRevisions(RangeInclusive<i64>),
+    AssertRevisionState(i64, RevisionState),
+}
+
+pub struct RevisionTest {
+    pub sdk: FlowySDKTest,
+    pub rev_manager: RevisionManager,
+}
+
+impl RevisionTest {
+    pub async fn new() -> Self {
+        let sdk = FlowySDKTest::default();
+        let _ = sdk.init_user().await;
+        let user_id = sdk.user_session.user_id().unwrap();
+        let object_id = uuid_string();
+        let pool = sdk.user_session.db_pool().unwrap();
+        let cache = Arc::new(RevisionCache::new(&user_id, &object_id, pool));
+        let rev_manager = RevisionManager::new(&user_id, &object_id, cache);
+        Self { sdk, rev_manager }
+    }
+
+    pub async fn run_scripts(self, scripts: Vec<RevisionScript>) {
+        for script in scripts {
+            self.run_script(script).await;
+        }
+    }
+
+    async fn run_script(&self, script: RevisionScript) {
+        let rev_manager = &self.rev_manager;
+        match script {
+            RevisionScript::AddLocalRevisions(count, size) => {
+                let user_id = self.sdk.user_session.user_id().unwrap();
+                for _ in 0..count {
+                    let (base_rev_id, rev_id) = rev_manager.next_rev_id_pair();
+                    let delta_data = Bytes::from(vec![b'a'; size]);
+                    let md5 = md5(&delta_data);
+                    let revision =
+                        Revision::new(&rev_manager.object_id, base_rev_id, rev_id, delta_data, &user_id, md5);
+                    rev_manager.add_local_revision(&revision).await.unwrap();
+                }
+            }
+            RevisionScript::AckRevision(rev_id) => {
+                rev_manager.ack_revision(rev_id).await.unwrap();
+            }
+            RevisionScript::AssertNextSyncRevisions(max_count, max_bytes, expected) => {
+                let revisions = rev_manager.next_sync_revisions(max_count, max_bytes).await.unwrap();
+                let rev_ids = revisions.iter().map(|revision| revision.rev_id).collect::<Vec<i64>>();
+                assert_eq!(rev_ids, expected.collect::<Vec<i64>>());
+            }
+            RevisionScript::AssertPendingRevisions(expected) => {
+                let revisions = rev_manager.pending_revisions().await.unwrap();
+                let rev_ids = revisions.iter().map(|revision| revision.rev_id).collect::<Vec<i64>>();
+                assert_eq!(rev_ids, expected.collect::<Vec<i64>>());
+            }
+            RevisionScript::AssertRevisionState(rev_id, state) => {
+                let record = rev_manager.revision_cache().get(rev_id).await.unwrap();
+                assert_eq!(record.state, state);
+            }
+        }
+    }
+}
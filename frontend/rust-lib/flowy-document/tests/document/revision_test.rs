@@ -0,0 +1,71 @@
+use crate::document::revision_script::{RevisionScript::*, RevisionTest};
+use flowy_collaboration::entities::revision::RevisionState;
+use flowy_sync::{MAX_BATCH_BYTES, MAX_BATCH_REVISIONS};
+
+#[tokio::test]
+async fn revision_batch_split_at_max_count_test() {
+    let max_count = MAX_BATCH_REVISIONS as i64;
+    let scripts = vec![
+        AddLocalRevisions(MAX_BATCH_REVISIONS + 10, 1),
+        AssertNextSyncRevisions(MAX_BATCH_REVISIONS, MAX_BATCH_BYTES, 1..=max_count),
+        AckRevision(max_count),
+        AssertNextSyncRevisions(MAX_BATCH_REVISIONS, MAX_BATCH_BYTES, max_count + 1..=max_count + 10),
+    ];
+    RevisionTest::new().await.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn revision_batch_split_at_max_bytes_test() {
+    let scripts = vec![
+        AddLocalRevisions(6, MAX_BATCH_BYTES / 4),
+        AssertNextSyncRevisions(MAX_BATCH_REVISIONS, MAX_BATCH_BYTES, 1..=4),
+        AckRevision(4),
+        AssertNextSyncRevisions(MAX_BATCH_REVISIONS, MAX_BATCH_BYTES, 5..=6),
+    ];
+    RevisionTest::new().await.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn revision_batch_contains_oversized_revision_test() {
+    let scripts = vec![
+        AddLocalRevisions(1, MAX_BATCH_BYTES + 1),
+        AddLocalRevisions(1, 1),
+        AssertNextSyncRevisions(MAX_BATCH_REVISIONS, MAX_BATCH_BYTES, 1..=1),
+        AckRevision(1),
+        AssertNextSyncRevisions(MAX_BATCH_REVISIONS, MAX_BATCH_BYTES, 2..=2),
+    ];
+    RevisionTest::new().await.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn revision_range_ack_test() {
+    let scripts = vec![
+        AddLocalRevisions(5, 1),
+        AckRevision(3),
+        AssertPendingRevisions(4..=5),
+        AssertRevisionState(1, RevisionState::Ack),
+        AssertRevisionState(3, RevisionState::Ack),
+        AssertRevisionState(4, RevisionState::Sync),
+        AckRevision(5),
+        AssertPendingRevisions(1..=0),
+        AssertNextSyncRevisions(MAX_BATCH_REVISIONS, MAX_BATCH_BYTES, 1..=0),
+    ];
+    RevisionTest::new().await.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn revision_stale_or_out_of_range_ack_test() {
+    let scripts = vec![
+        AddLocalRevisions(3, 1),
+        AckRevision(2),
+        AssertPendingRevisions(3..=3),
+        // The rev_id was acked before
+        AckRevision(1),
+        AssertPendingRevisions(3..=3),
+        // The rev_id is greater than the last pending revision
+        AckRevision(10),
+        AssertPendingRevisions(3..=3),
+        AssertRevisionState(3, RevisionState::Sync),
+    ];
+    RevisionTest::new().await.run_scripts(scripts).await;
+}
@@ -0,0 +1,126 @@
+use crate::editor::{TestBuilder, TestOp::*};
+use flowy_collaboration::client_document::{BlockCommand, BlockType, NewlineDoc};
+use lib_ot::core::Interval;
+
+#[test]
+fn block_move_up() {
+    let ops = vec![
+        Insert(0, "a\nb\nc", 0),
+        EditBlock(0, BlockCommand::MoveUp { index: 2 }),
+        AssertStr(0, "b\na\nc\n"),
+        EditBlock(0, BlockCommand::MoveUp { index: 0 }),
+        AssertStr(0, "b\na\nc\n"),
+        Undo(0),
+        AssertStr(0, "a\nb\nc\n"),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn block_move_down_with_children() {
+    let ops = vec![
+        Insert(0, "a\nb\nc", 0),
+        EditBlock(0, BlockCommand::Indent { index: 2 }),
+        EditBlock(0, BlockCommand::MoveDown { index: 0 }),
+        AssertDocJson(0, r#"[{"insert":"c\na\nb"},{"insert":"\n","attributes":{"indent":1}}]"#),
+        EditBlock(0, BlockCommand::MoveDown { index: 2 }),
+        AssertStr(0, "c\na\nb\n"),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn block_indent_and_outdent_list() {
+    let ops = vec![
+        Insert(0, "a\nb", 0),
+        Bullet(0, Interval::new(0, 3), true),
+        EditBlock(0, BlockCommand::Indent { index: 0 }),
+        EditBlock(0, BlockCommand::Indent { index: 2 }),
+        EditBlock(0, BlockCommand::Indent { index: 2 }),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"a"},{"insert":"\n","attributes":{"list":"bullet"}},{"insert":"b"},{"insert":"\n","attributes":{"list":"bullet","indent":1}}]"#,
+        ),
+        EditBlock(0, BlockCommand::Outdent { index: 2 }),
+        EditBlock(0, BlockCommand::Outdent { index: 2 }),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"a"},{"insert":"\n","attributes":{"list":"bullet"}},{"insert":"b\n"}]"#,
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn block_toggle_checkbox() {
+    let ops = vec![
+        Insert(0, "a", 0),
+        EditBlock(
+            0,
+            BlockCommand::Convert {
+                index: 0,
+                block_type: BlockType::Checkbox,
+            },
+        ),
+        EditBlock(0, BlockCommand::ToggleCheckbox { index: 1 }),
+        AssertDocJson(0, r#"[{"insert":"a"},{"insert":"\n","attributes":{"list":"checked"}}]"#),
+        EditBlock(0, BlockCommand::ToggleCheckbox { index: 0 }),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"a"},{"insert":"\n","attributes":{"list":"unchecked"}}]"#,
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn block_split() {
+    let ops = vec![
+        Insert(0, "abc", 0),
+        Header(0, Interval::new(0, 3), 1),
+        EditBlock(0, BlockCommand::Split { index: 1 }),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"a"},{"insert":"\n","attributes":{"header":1}},{"insert":"bc"},{"insert":"\n","attributes":{"header":1}}]"#,
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn block_merge_with_next() {
+    let ops = vec![
+        Insert(0, "a\nb", 0),
+        Header(0, Interval::new(0, 1), 1),
+        EditBlock(0, BlockCommand::MergeWithNext { index: 0 }),
+        AssertDocJson(0, r#"[{"insert":"ab"},{"insert":"\n","attributes":{"header":1}}]"#),
+        Undo(0),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"a"},{"insert":"\n","attributes":{"header":1}},{"insert":"b\n"}]"#,
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn block_convert_keeps_indent() {
+    let ops = vec![
+        Insert(0, "a", 0),
+        Header(0, Interval::new(0, 1), 1),
+        EditBlock(0, BlockCommand::Indent { index: 0 }),
+        EditBlock(
+            0,
+            BlockCommand::Convert {
+                index: 0,
+                block_type: BlockType::BulletList,
+            },
+        ),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"a"},{"insert":"\n","attributes":{"list":"bullet","indent":1}}]"#,
+        ),
+        AssertMarkdown(0, "- a"),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
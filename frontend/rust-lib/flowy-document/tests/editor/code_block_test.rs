@@ -0,0 +1,95 @@
+use crate::editor::{TestBuilder, TestOp::*};
+use flowy_collaboration::client_document::NewlineDoc;
+use lib_ot::core::{Interval, NEW_LINE};
+
+#[test]
+fn code_block_language_format() {
+    let ops = vec![
+        Insert(0, "12", 0),
+        CodeBlock(0, Interval::new(0, 2), " Rust "),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"12"},{"insert":"\n","attributes":{"code_block":"rust"}}]"#,
+        ),
+        CodeBlock(0, Interval::new(0, 2), ""),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"12"},{"insert":"\n","attributes":{"code_block":true}}]"#,
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn code_block_language_preserve_when_insert_newline() {
+    let ops = vec![
+        Insert(0, "12", 0),
+        CodeBlock(0, Interval::new(0, 2), "rust"),
+        Insert(0, NEW_LINE, 1),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"1"},{"insert":"\n","attributes":{"code_block":"rust"}},{"insert":"2"},{"insert":"\n","attributes":{"code_block":"rust"}}]"#,
+        ),
+        Insert(0, NEW_LINE, 4),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"1"},{"insert":"\n","attributes":{"code_block":"rust"}},{"insert":"2"},{"insert":"\n\n","attributes":{"code_block":"rust"}}]"#,
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn code_block_language_auto_exit() {
+    let ops = vec![
+        Insert(0, "a", 0),
+        CodeBlock(0, Interval::new(0, 1), "python"),
+        Insert(0, NEW_LINE, 1),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"a"},{"insert":"\n\n","attributes":{"code_block":"python"}}]"#,
+        ),
+        Insert(0, NEW_LINE, 2),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"a"},{"insert":"\n","attributes":{"code_block":"python"}},{"insert":"\n"}]"#,
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn code_block_export_markdown() {
+    let ops = vec![
+        Insert(0, "fn a", 0),
+        Insert(0, NEW_LINE, 4),
+        Insert(0, "x = 1", 5),
+        Insert(0, NEW_LINE, 10),
+        Insert(0, "end", 11),
+        CodeBlock(0, Interval::new(0, 4), "rust"),
+        CodeBlock(0, Interval::new(5, 10), "python"),
+        AssertMarkdown(0, "```rust\nfn a\n```\n```python\nx = 1\n```\nend"),
+        CodeBlock(0, Interval::new(5, 10), ""),
+        AssertMarkdown(0, "```rust\nfn a\n```\n```\nx = 1\n```\nend"),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn code_block_export_html() {
+    let ops = vec![
+        Insert(0, "fn a", 0),
+        Insert(0, NEW_LINE, 4),
+        Insert(0, "\"<b>\" // 1", 5),
+        Insert(0, NEW_LINE, 15),
+        Insert(0, "end", 16),
+        CodeBlock(0, Interval::new(0, 15), "rust"),
+        AssertHtml(
+            0,
+            "<pre><code class=\"language-rust\"><span class=\"hl-keyword\">fn</span> a\n<span class=\"hl-string\">&quot;&lt;b&gt;&quot;</span> <span class=\"hl-comment\">// 1</span>\n</code></pre><p>end</p>",
+        ),
+        CodeBlock(0, Interval::new(0, 15), ""),
+        AssertHtml(0, "<pre>fn a\n&quot;&lt;b&gt;&quot; // 1\n</pre><p>end</p>"),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
@@ -0,0 +1,85 @@
+use crate::editor::{TestBuilder, TestOp::*};
+use flowy_collaboration::client_document::{ClientDocument, NewlineDoc, RECORD_THRESHOLD};
+use lib_ot::core::Interval;
+
+#[test]
+fn comment_add_anchor() {
+    let ops = vec![
+        Insert(0, "123456", 0),
+        AddCommentAnchor(0, Interval::new(1, 4), "t1"),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"1"},{"insert":"234","attributes":{"comment":"t1"}},{"insert":"56\n"}]"#,
+        ),
+        AssertCommentAnchor(0, "t1", Some(Interval::new(1, 4))),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn comment_add_overlapping_anchors() {
+    let ops = vec![
+        Insert(0, "123456", 0),
+        AddCommentAnchor(0, Interval::new(1, 4), "t1"),
+        AddCommentAnchor(0, Interval::new(2, 5), "t2"),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"1"},{"insert":"2","attributes":{"comment":"t1"}},{"insert":"34","attributes":{"comment":"t1,t2"}},{"insert":"5","attributes":{"comment":"t2"}},{"insert":"6\n"}]"#,
+        ),
+        AssertCommentAnchor(0, "t1", Some(Interval::new(1, 4))),
+        AssertCommentAnchor(0, "t2", Some(Interval::new(2, 5))),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn comment_anchor_skips_newline() {
+    let ops = vec![
+        Insert(0, "123", 0),
+        Insert(0, "\n", 3),
+        Insert(0, "456", 4),
+        AddCommentAnchor(0, Interval::new(1, 6), "t1"),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"1"},{"insert":"23","attributes":{"comment":"t1"}},{"insert":"\n"},{"insert":"45","attributes":{"comment":"t1"}},{"insert":"6\n"}]"#,
+        ),
+        AssertCommentAnchor(0, "t1", Some(Interval::new(1, 6))),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn comment_anchor_moves_with_text() {
+    let ops = vec![
+        Insert(0, "123456", 0),
+        AddCommentAnchor(0, Interval::new(1, 4), "t1"),
+        Insert(0, "ab", 0),
+        AssertCommentAnchor(0, "t1", Some(Interval::new(3, 6))),
+        Delete(0, Interval::new(4, 5)),
+        AssertCommentAnchor(0, "t1", Some(Interval::new(3, 5))),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn comment_anchor_orphaned_after_delete() {
+    let ops = vec![
+        Insert(0, "123456", 0),
+        Wait(RECORD_THRESHOLD),
+        AddCommentAnchor(0, Interval::new(1, 4), "t1"),
+        Wait(RECORD_THRESHOLD),
+        Delete(0, Interval::new(0, 5)),
+        AssertCommentAnchor(0, "t1", None),
+        Undo(0),
+        AssertCommentAnchor(0, "t1", Some(Interval::new(1, 4))),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn comment_anchor_out_of_bound() {
+    let mut document = ClientDocument::new::<NewlineDoc>();
+    let _ = document.insert(0, "123").unwrap();
+    assert!(document.add_comment_anchor(Interval::new(1, 1), "t1").is_err());
+    assert!(document.add_comment_anchor(Interval::new(1, 10), "t1").is_err());
+}
@@ -0,0 +1,99 @@
+use crate::editor::{TestBuilder, TestOp::*};
+use flowy_collaboration::client_document::NewlineDoc;
+use lib_ot::core::{Interval, NEW_LINE};
+
+#[test]
+fn math_inline_auto_format() {
+    let ops = vec![
+        Insert(0, "a $x^2", 0),
+        Insert(0, "$", 6),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"a "},{"insert":"x^2","attributes":{"formula":true}},{"insert":"\n"}]"#,
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn math_inline_insert_after_formula() {
+    let ops = vec![
+        Insert(0, "$x^2", 0),
+        Insert(0, "$", 4),
+        Insert(0, " b", 3),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"x^2","attributes":{"formula":true}},{"insert":" b\n"}]"#,
+        ),
+        Insert(0, "+1", 1),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"x+1^2","attributes":{"formula":true}},{"insert":" b\n"}]"#,
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn math_inline_not_format_with_whitespace() {
+    let ops = vec![
+        Insert(0, "$5 and ", 0),
+        Insert(0, "$", 7),
+        AssertDocJson(0, r#"[{"insert":"$5 and $\n"}]"#),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn math_inline_not_format_in_code_block() {
+    let ops = vec![
+        Insert(0, "$a", 0),
+        CodeBlock(0, Interval::new(0, 2), "shell"),
+        Insert(0, "$", 2),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"$a$"},{"insert":"\n","attributes":{"code_block":"shell"}}]"#,
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn math_block_auto_format() {
+    let ops = vec![
+        Insert(0, "$$", 0),
+        Insert(0, NEW_LINE, 2),
+        AssertDocJson(0, r#"[{"insert":"\n","attributes":{"math_block":true}}]"#),
+        Insert(0, "E=mc^2", 0),
+        Insert(0, NEW_LINE, 6),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"E=mc^2"},{"insert":"\n\n","attributes":{"math_block":true}}]"#,
+        ),
+        Insert(0, NEW_LINE, 7),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"E=mc^2"},{"insert":"\n","attributes":{"math_block":true}},{"insert":"\n"}]"#,
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn math_export() {
+    let ops = vec![
+        Insert(0, "a $x<2", 0),
+        Insert(0, "$", 6),
+        Insert(0, " b", 5),
+        Insert(0, NEW_LINE, 7),
+        Insert(0, "$$", 8),
+        Insert(0, NEW_LINE, 10),
+        Insert(0, "E=mc^2", 8),
+        AssertMarkdown(0, "a $x<2$ b\n$$\nE=mc^2\n$$"),
+        AssertHtml(
+            0,
+            r#"<p>a <span class="math-inline">\(x&lt;2\)</span> b</p><div class="math-display">\[E=mc^2\]</div>"#,
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
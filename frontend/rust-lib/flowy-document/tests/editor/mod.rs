@@ -1,11 +1,17 @@
 #![allow(clippy::module_inception)]
 mod attribute_test;
+mod block_test;
+mod code_block_test;
+mod comment_test;
+mod math_test;
 mod op_test;
+mod paste_test;
 mod serde_test;
+mod table_test;
 mod undo_redo_test;
 
 use derive_more::Display;
-use flowy_collaboration::client_document::{ClientDocument, InitialDocumentText};
+use flowy_collaboration::client_document::{BlockCommand, ClientDocument, InitialDocumentText, TableCommand};
 use lib_ot::{
     core::*,
     rich_text::{RichTextAttribute, RichTextAttributes, RichTextDelta},
@@ -46,6 +52,10 @@ pub enum TestOp {
     #[display(fmt = "Bullet")]
     Bullet(usize, Interval, bool),
 
+    // delta_i, interval, language
+    #[display(fmt = "CodeBlock")]
+    CodeBlock(usize, Interval, &'static str),
+
     #[display(fmt = "Transform")]
     Transform(usize, usize),
 
@@ -79,6 +89,53 @@ pub enum TestOp {
 
     #[display(fmt = "ApplyPrimeDelta")]
     DocComposePrime(usize, usize),
+
+    // delta_i, index, rows, columns
+    #[display(fmt = "InsertTable")]
+    InsertTable(usize, usize, usize, usize),
+
+    // The row and column ops edit the first table of the document.
+    #[display(fmt = "InsertTableRow")]
+    InsertTableRow(usize, usize),
+
+    #[display(fmt = "DeleteTableRow")]
+    DeleteTableRow(usize, usize),
+
+    #[display(fmt = "InsertTableColumn")]
+    InsertTableColumn(usize, usize),
+
+    #[display(fmt = "DeleteTableColumn")]
+    DeleteTableColumn(usize, usize),
+
+    // The texts of the first table's cells, row by row.
+    #[display(fmt = "AssertTable")]
+    AssertTable(usize, &'static [&'static [&'static str]]),
+
+    #[display(fmt = "AssertTableCount")]
+    AssertTableCount(usize, usize),
+
+    #[display(fmt = "AssertMarkdown")]
+    AssertMarkdown(usize, &'static str),
+
+    #[display(fmt = "AssertHtml")]
+    AssertHtml(usize, &'static str),
+
+    // delta_i, interval, the json of the pasted fragment
+    #[display(fmt = "Paste")]
+    Paste(usize, Interval, &'static str),
+
+    #[display(fmt = "AssertCopy")]
+    AssertCopy(usize, Interval, &'static str),
+
+    #[display(fmt = "EditBlock")]
+    EditBlock(usize, BlockCommand),
+
+    #[display(fmt = "AddCommentAnchor")]
+    AddCommentAnchor(usize, Interval, &'static str),
+
+    // The anchor of the thread, None if the text of the thread was deleted.
+    #[display(fmt = "AssertCommentAnchor")]
+    AssertCommentAnchor(usize, &'static str, Option<Interval>),
 }
 
 pub struct TestBuilder {
@@ -168,6 +225,13 @@ impl TestBuilder {
 
                 self.deltas.insert(*delta_i, Some(delta));
             }
+            TestOp::CodeBlock(delta_i, iv, language) => {
+                let document = &mut self.documents[*delta_i];
+                let attribute = RichTextAttribute::CodeBlockLanguage(language);
+                let delta = document.format(*iv, attribute).unwrap();
+                tracing::trace!("CodeBlock delta: {}", delta.to_json());
+                self.deltas.insert(*delta_i, Some(delta));
+            }
             TestOp::Transform(delta_a_i, delta_b_i) => {
                 let (a_prime, b_prime) = self.documents[*delta_a_i]
                     .delta()
@@ -262,9 +326,108 @@ impl TestBuilder {
                 let new_delta = self.documents[*doc_index].delta().compose(delta).unwrap();
                 self.documents[*doc_index].set_delta(new_delta);
             }
+            TestOp::InsertTable(delta_i, index, rows, columns) => {
+                let command = TableCommand::InsertTable {
+                    index: *index,
+                    rows: *rows,
+                    columns: *columns,
+                };
+                self.edit_table(*delta_i, command);
+            }
+            TestOp::InsertTableRow(delta_i, row_index) => {
+                let table_id = self.first_table_id(*delta_i);
+                let command = TableCommand::InsertRow {
+                    table_id,
+                    row_index: *row_index,
+                };
+                self.edit_table(*delta_i, command);
+            }
+            TestOp::DeleteTableRow(delta_i, row_index) => {
+                let table_id = self.first_table_id(*delta_i);
+                let command = TableCommand::DeleteRow {
+                    table_id,
+                    row_index: *row_index,
+                };
+                self.edit_table(*delta_i, command);
+            }
+            TestOp::InsertTableColumn(delta_i, column_index) => {
+                let table_id = self.first_table_id(*delta_i);
+                let command = TableCommand::InsertColumn {
+                    table_id,
+                    column_index: *column_index,
+                };
+                self.edit_table(*delta_i, command);
+            }
+            TestOp::DeleteTableColumn(delta_i, column_index) => {
+                let table_id = self.first_table_id(*delta_i);
+                let command = TableCommand::DeleteColumn {
+                    table_id,
+                    column_index: *column_index,
+                };
+                self.edit_table(*delta_i, command);
+            }
+            TestOp::AssertTable(delta_i, expected) => {
+                let texts = self.documents[*delta_i].tables()[0].texts();
+                let expected = expected
+                    .iter()
+                    .map(|row| row.iter().map(|s| s.to_string()).collect::<Vec<String>>())
+                    .collect::<Vec<Vec<String>>>();
+                assert_eq!(texts, expected);
+            }
+            TestOp::Paste(delta_i, interval, fragment) => {
+                let fragment = RichTextDelta::from_json(fragment).unwrap();
+                let delta = self.documents[*delta_i]
+                    .replace_with_delta(*interval, fragment)
+                    .unwrap();
+                tracing::trace!("Paste delta: {}", delta.to_json());
+                self.deltas.insert(*delta_i, Some(delta));
+            }
+            TestOp::AssertCopy(delta_i, interval, expected) => {
+                let fragment = self.documents[*delta_i].copy_delta(*interval).unwrap();
+                let expected_fragment: RichTextDelta = serde_json::from_str(expected).unwrap();
+                if expected_fragment != fragment {
+                    log::error!("✅ expect: {}", expected,);
+                    log::error!("❌ receive: {}", fragment.to_json());
+                }
+                assert_eq!(fragment, expected_fragment);
+            }
+            TestOp::EditBlock(delta_i, command) => {
+                let delta = self.documents[*delta_i].edit_block(command.clone()).unwrap();
+                tracing::trace!("Block delta: {}", delta.to_json());
+                self.deltas.insert(*delta_i, Some(delta));
+            }
+            TestOp::AssertTableCount(delta_i, expected) => {
+                assert_eq!(self.documents[*delta_i].tables().len(), *expected);
+            }
+            TestOp::AssertMarkdown(delta_i, expected) => {
+                assert_eq!(&self.documents[*delta_i].to_markdown(), expected);
+            }
+            TestOp::AssertHtml(delta_i, expected) => {
+                assert_eq!(&self.documents[*delta_i].to_html(), expected);
+            }
+            TestOp::AddCommentAnchor(delta_i, interval, thread_id) => {
+                let document = &mut self.documents[*delta_i];
+                let delta = document.add_comment_anchor(*interval, thread_id).unwrap();
+                tracing::trace!("Comment anchor delta: {}", delta.to_json());
+                self.deltas.insert(*delta_i, Some(delta));
+            }
+            TestOp::AssertCommentAnchor(delta_i, thread_id, expected) => {
+                let anchor = self.documents[*delta_i].comment_anchors().get(*thread_id).cloned();
+                assert_eq!(anchor, *expected);
+            }
         }
     }
 
+    fn edit_table(&mut self, delta_i: usize, command: TableCommand) {
+        let delta = self.documents[delta_i].edit_table(command).unwrap();
+        tracing::trace!("Table delta: {}", delta.to_json());
+        self.deltas.insert(delta_i, Some(delta));
+    }
+
+    fn first_table_id(&self, delta_i: usize) -> String {
+        self.documents[delta_i].tables()[0].id.clone()
+    }
+
     pub fn run_scripts<C: InitialDocumentText>(mut self, scripts: Vec<TestOp>) {
         self.documents = vec![ClientDocument::new::<C>(), ClientDocument::new::<C>()];
         self.primes = vec![None, None];
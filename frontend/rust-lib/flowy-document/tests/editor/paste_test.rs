@@ -0,0 +1,96 @@
+use crate::editor::{TestBuilder, TestOp::*};
+use flowy_collaboration::client_document::NewlineDoc;
+use lib_ot::core::Interval;
+
+#[test]
+fn paste_inline_format() {
+    let ops = vec![
+        Insert(0, "ab", 0),
+        Paste(0, Interval::new(1, 1), r#"[{"insert":"x","attributes":{"bold":true}}]"#),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"a"},{"insert":"x","attributes":{"bold":true}},{"insert":"b\n"}]"#,
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn paste_list_into_paragraph() {
+    let ops = vec![
+        Insert(0, "ab", 0),
+        Paste(
+            0,
+            Interval::new(1, 1),
+            r#"[{"insert":"x"},{"insert":"\n","attributes":{"list":"bullet"}},{"insert":"y"}]"#,
+        ),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"ax"},{"insert":"\n","attributes":{"list":"bullet"}},{"insert":"yb\n"}]"#,
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn paste_plain_lines_into_list() {
+    let ops = vec![
+        Insert(0, "ab", 0),
+        Bullet(0, Interval::new(0, 2), true),
+        Paste(0, Interval::new(1, 1), r#"[{"insert":"x\ny\nz"}]"#),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"ax"},{"insert":"\n","attributes":{"list":"bullet"}},{"insert":"y"},{"insert":"\n","attributes":{"list":"bullet"}},{"insert":"zb"},{"insert":"\n","attributes":{"list":"bullet"}}]"#,
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn paste_newline_at_the_end_of_header() {
+    let ops = vec![
+        Insert(0, "ab", 0),
+        Header(0, Interval::new(0, 2), 1),
+        Paste(0, Interval::new(2, 2), r#"[{"insert":"x\n"}]"#),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"abx"},{"insert":"\n","attributes":{"header":1}},{"insert":"\n"}]"#,
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn paste_replace_and_undo() {
+    let ops = vec![
+        Insert(0, "abc", 0),
+        Paste(
+            0,
+            Interval::new(1, 2),
+            r#"[{"insert":"X","attributes":{"italic":true}}]"#,
+        ),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"a"},{"insert":"X","attributes":{"italic":true}},{"insert":"c\n"}]"#,
+        ),
+        Undo(0),
+        AssertStr(0, "abc\n"),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn copy_with_block_format() {
+    let ops = vec![
+        Insert(0, "abc", 0),
+        Header(0, Interval::new(0, 3), 1),
+        Bold(0, Interval::new(0, 1), true),
+        AssertCopy(0, Interval::new(1, 3), r#"[{"insert":"bc"}]"#),
+        AssertCopy(
+            0,
+            Interval::new(0, 4),
+            r#"[{"insert":"a","attributes":{"bold":true}},{"insert":"bc"},{"insert":"\n","attributes":{"header":1}}]"#,
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
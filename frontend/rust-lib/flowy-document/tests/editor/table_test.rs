@@ -0,0 +1,156 @@
+use crate::editor::{TestBuilder, TestOp::*};
+use flowy_collaboration::client_document::NewlineDoc;
+use lib_ot::core::Interval;
+
+#[test]
+fn table_insert() {
+    let ops = vec![
+        Insert(0, "123", 0),
+        InsertTable(0, 0, 2, 3),
+        AssertTableCount(0, 1),
+        AssertTable(0, &[&["", "", ""], &["", "", ""]]),
+        AssertStr(0, "123\n\n\n\n\n\n\n\n"),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn table_insert_text_into_cells() {
+    let ops = vec![
+        Insert(0, "123", 0),
+        InsertTable(0, 0, 2, 3),
+        Insert(0, "a", 4),
+        Insert(0, "b", 6),
+        AssertTable(0, &[&["a", "b", ""], &["", "", ""]]),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn table_insert_newline_into_cell() {
+    let ops = vec![
+        Insert(0, "123", 0),
+        InsertTable(0, 0, 2, 3),
+        Insert(0, "ab", 4),
+        Insert(0, "\n", 5),
+        AssertTable(0, &[&["a\nb", "", ""], &["", "", ""]]),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn table_delete_across_cells() {
+    let ops = vec![
+        Insert(0, "123", 0),
+        InsertTable(0, 0, 2, 3),
+        Insert(0, "a", 4),
+        Insert(0, "b", 6),
+        Delete(0, Interval::new(4, 7)),
+        AssertTable(0, &[&["", "", ""], &["", "", ""]]),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn table_delete_newline_before_table() {
+    let ops = vec![
+        Insert(0, "123", 0),
+        InsertTable(0, 0, 1, 1),
+        Delete(0, Interval::new(3, 4)),
+        AssertStr(0, "123\n\n\n"),
+        AssertTable(0, &[&[""]]),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn table_insert_and_delete_row() {
+    let ops = vec![
+        Insert(0, "123", 0),
+        InsertTable(0, 0, 2, 3),
+        Insert(0, "a", 4),
+        Insert(0, "b", 6),
+        InsertTableRow(0, 1),
+        AssertTable(0, &[&["a", "b", ""], &["", "", ""], &["", "", ""]]),
+        DeleteTableRow(0, 0),
+        AssertTable(0, &[&["", "", ""], &["", "", ""]]),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn table_delete_last_row() {
+    let ops = vec![
+        Insert(0, "123", 0),
+        InsertTable(0, 0, 1, 2),
+        DeleteTableRow(0, 0),
+        AssertTableCount(0, 0),
+        AssertStr(0, "123\n\n"),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn table_insert_and_delete_column() {
+    let ops = vec![
+        Insert(0, "123", 0),
+        InsertTable(0, 0, 2, 3),
+        Insert(0, "a", 4),
+        Insert(0, "b", 6),
+        InsertTableColumn(0, 1),
+        AssertTable(0, &[&["a", "", "b", ""], &["", "", "", ""]]),
+        DeleteTableColumn(0, 0),
+        AssertTable(0, &[&["", "b", ""], &["", "", ""]]),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn table_undo_redo_row_insert() {
+    let ops = vec![
+        Insert(0, "123", 0),
+        InsertTable(0, 0, 2, 3),
+        Insert(0, "a", 4),
+        InsertTableRow(0, 2),
+        AssertTable(0, &[&["a", "", ""], &["", "", ""], &["", "", ""]]),
+        Undo(0),
+        AssertTable(0, &[&["a", "", ""], &["", "", ""]]),
+        Redo(0),
+        AssertTable(0, &[&["a", "", ""], &["", "", ""], &["", "", ""]]),
+        Undo(0),
+        Undo(0),
+        AssertTable(0, &[&["", "", ""], &["", "", ""]]),
+        Undo(0),
+        AssertDocJson(0, r#"[{"insert":"123\n"}]"#),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn table_export_markdown() {
+    let ops = vec![
+        Insert(0, "123", 0),
+        InsertTable(0, 0, 2, 2),
+        Insert(0, "a", 4),
+        Insert(0, "b", 6),
+        Insert(0, "c", 8),
+        Insert(0, "d|e", 10),
+        AssertMarkdown(0, "123\n| a | b |\n| --- | --- |\n| c | d\\|e |\n"),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
+
+#[test]
+fn table_export_html() {
+    let ops = vec![
+        Insert(0, "123", 0),
+        InsertTable(0, 0, 1, 2),
+        Insert(0, "a", 4),
+        Insert(0, "<b>", 6),
+        AssertHtml(
+            0,
+            "<p>123</p><table><tr><td>a</td><td>&lt;b&gt;</td></tr></table><p></p>",
+        ),
+    ];
+    TestBuilder::new().run_scripts::<NewlineDoc>(ops);
+}
@@ -42,6 +42,7 @@ impl FlowyError {
     static_flowy_error!(workspace_id, ErrorCode::WorkspaceIdInvalid);
     static_flowy_error!(color_style, ErrorCode::AppColorStyleInvalid);
     static_flowy_error!(workspace_desc, ErrorCode::WorkspaceDescTooLong);
+    static_flowy_error!(workspace_passphrase, ErrorCode::WorkspacePassphraseInvalid);
     static_flowy_error!(app_name, ErrorCode::AppNameInvalid);
     static_flowy_error!(invalid_app_id, ErrorCode::AppIdInvalid);
     static_flowy_error!(view_name, ErrorCode::ViewNameInvalid);
@@ -49,8 +50,12 @@ impl FlowyError {
     static_flowy_error!(invalid_view_id, ErrorCode::ViewIdInvalid);
     static_flowy_error!(view_desc, ErrorCode::ViewDescTooLong);
     static_flowy_error!(view_data, ErrorCode::ViewDataInvalid);
+    static_flowy_error!(revision_decrypt, ErrorCode::RevisionDecryptFailed);
+    static_flowy_error!(revision_not_synced, ErrorCode::RevisionNotSynced);
     static_flowy_error!(unauthorized, ErrorCode::UserUnauthorized);
     static_flowy_error!(connection, ErrorCode::ConnectError);
+    static_flowy_error!(lan_sync_addr, ErrorCode::LanSyncAddrInvalid);
+    static_flowy_error!(lan_sync_secret, ErrorCode::LanSyncSecretInvalid);
     static_flowy_error!(email_empty, ErrorCode::EmailIsEmpty);
     static_flowy_error!(email_format, ErrorCode::EmailFormatInvalid);
     static_flowy_error!(email_exist, ErrorCode::EmailAlreadyExists);
@@ -64,6 +69,7 @@ impl FlowyError {
     static_flowy_error!(name_empty, ErrorCode::UserNameIsEmpty);
     static_flowy_error!(user_id, ErrorCode::UserIdInvalid);
     static_flowy_error!(user_not_exist, ErrorCode::UserNotExist);
+    static_flowy_error!(user_database_locked, ErrorCode::UserDatabaseLocked);
 }
 
 impl std::convert::From<ErrorCode> for FlowyError {
@@ -0,0 +1,27 @@
+use dart_notify::DartNotifyBuilder;
+use flowy_derive::ProtoBuf_Enum;
+const OBSERVABLE_CATEGORY: &str = "Network";
+
+#[derive(ProtoBuf_Enum, Debug)]
+pub(crate) enum NetworkNotification {
+    Unknown = 0,
+    ObjectSyncStatusChanged = 1,
+    SyncStatusChanged = 2,
+    ObjectMerged = 3,
+}
+
+impl std::default::Default for NetworkNotification {
+    fn default() -> Self {
+        NetworkNotification::Unknown
+    }
+}
+
+impl std::convert::From<NetworkNotification> for i32 {
+    fn from(notification: NetworkNotification) -> Self {
+        notification as i32
+    }
+}
+
+pub(crate) fn dart_notify(id: &str, ty: NetworkNotification) -> DartNotifyBuilder {
+    DartNotifyBuilder::new(id, ty, OBSERVABLE_CATEGORY)
+}
@@ -0,0 +1,47 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(ProtoBuf_Enum, Debug, Clone, Eq, PartialEq)]
+pub enum LanSyncRole {
+    LanSyncDisabled = 0,
+    LanSyncHost = 1,
+    LanSyncPeer = 2,
+}
+
+impl std::default::Default for LanSyncRole {
+    fn default() -> Self {
+        LanSyncRole::LanSyncDisabled
+    }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct LanSyncSetting {
+    #[pb(index = 1)]
+    pub role: LanSyncRole,
+
+    // The address of the Host, e.g. 192.168.1.2:8600. The Peer is paired with the address and the
+    // secret that are shown by the Host.
+    #[pb(index = 2)]
+    pub host_addr: String,
+
+    #[pb(index = 3)]
+    pub secret: String,
+
+    // False if the setting takes effect after restarting the app.
+    #[pb(index = 4)]
+    pub is_active: bool,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct LanSyncHostRequest {
+    #[pb(index = 1)]
+    pub port: i32,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct LanSyncPeerRequest {
+    #[pb(index = 1)]
+    pub host_addr: String,
+
+    #[pb(index = 2)]
+    pub secret: String,
+}
@@ -1,2 +1,7 @@
+mod lan_sync;
 mod network_state;
+mod sync_status;
+
+pub use lan_sync::*;
 pub use network_state::*;
+pub use sync_status::*;
@@ -0,0 +1,122 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_sync::{RevisionMergeSummary, RevisionSyncStatus};
+use lib_ws::WSConnectState;
+
+#[derive(ProtoBuf_Enum, Debug, Clone, Eq, PartialEq)]
+pub enum SyncConnectState {
+    SyncInit = 0,
+    SyncConnecting = 1,
+    SyncConnected = 2,
+    SyncDisconnected = 3,
+}
+
+impl std::default::Default for SyncConnectState {
+    fn default() -> Self {
+        SyncConnectState::SyncInit
+    }
+}
+
+impl std::convert::From<WSConnectState> for SyncConnectState {
+    fn from(state: WSConnectState) -> Self {
+        match state {
+            WSConnectState::Init => SyncConnectState::SyncInit,
+            WSConnectState::Connecting => SyncConnectState::SyncConnecting,
+            WSConnectState::Connected => SyncConnectState::SyncConnected,
+            WSConnectState::Disconnected => SyncConnectState::SyncDisconnected,
+        }
+    }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct SyncObjectId {
+    #[pb(index = 1)]
+    pub object_id: String,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct ObjectSyncStatus {
+    #[pb(index = 1)]
+    pub object_id: String,
+
+    #[pb(index = 2)]
+    pub pending_count: i64,
+
+    #[pb(index = 3)]
+    pub last_acked_rev_id: i64,
+
+    // The timestamp in seconds, 0 if the object was never synced since the app was launched.
+    #[pb(index = 4)]
+    pub last_sync_time: i64,
+
+    #[pb(index = 5)]
+    pub connect_state: SyncConnectState,
+}
+
+impl ObjectSyncStatus {
+    pub fn new(status: RevisionSyncStatus, connect_state: SyncConnectState) -> Self {
+        Self {
+            object_id: status.object_id,
+            pending_count: status.pending_count,
+            last_acked_rev_id: status.last_acked_rev_id,
+            last_sync_time: status.last_sync_time,
+            connect_state,
+        }
+    }
+}
+
+// The local revisions that diverged from the server were merged with the revisions of the server.
+// The local state before the merge is backed up if has_backup is true.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct ObjectMergeSummary {
+    #[pb(index = 1)]
+    pub object_id: String,
+
+    #[pb(index = 2)]
+    pub local_revision_count: i64,
+
+    #[pb(index = 3)]
+    pub remote_revision_count: i64,
+
+    #[pb(index = 4)]
+    pub merged_rev_id: i64,
+
+    #[pb(index = 5)]
+    pub has_backup: bool,
+
+    #[pb(index = 6, one_of)]
+    pub error: Option<String>,
+}
+
+impl std::convert::From<RevisionMergeSummary> for ObjectMergeSummary {
+    fn from(summary: RevisionMergeSummary) -> Self {
+        Self {
+            object_id: summary.object_id,
+            local_revision_count: summary.local_revision_count,
+            remote_revision_count: summary.remote_revision_count,
+            merged_rev_id: summary.merged_rev_id,
+            has_backup: summary.has_backup,
+            error: summary.error,
+        }
+    }
+}
+
+// The sync status of all the objects of the current user, including the objects that are not
+// opened but still have the revisions that are not acked by the server.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct SyncStatus {
+    #[pb(index = 1)]
+    pub pending_count: i64,
+
+    #[pb(index = 2)]
+    pub pending_object_count: i64,
+
+    #[pb(index = 3)]
+    pub last_sync_time: i64,
+
+    #[pb(index = 4)]
+    pub connect_state: SyncConnectState,
+
+    // It's safe to quit if all the revisions are acked by the server.
+    #[pb(index = 5)]
+    pub is_synced: bool,
+}
@@ -6,4 +6,22 @@ use strum_macros::Display;
 pub enum NetworkEvent {
     #[event(input = "NetworkState")]
     UpdateNetworkType = 0,
+
+    #[event(input = "SyncObjectId", output = "ObjectSyncStatus")]
+    ReadObjectSyncStatus = 1,
+
+    #[event(output = "SyncStatus")]
+    ReadSyncStatus = 2,
+
+    #[event(output = "LanSyncSetting")]
+    ReadLanSyncSetting = 3,
+
+    #[event(input = "LanSyncHostRequest", output = "LanSyncSetting")]
+    EnableLanSyncHost = 4,
+
+    #[event(input = "LanSyncPeerRequest", output = "LanSyncSetting")]
+    PairLanSyncPeer = 5,
+
+    #[event(output = "LanSyncSetting")]
+    DisableLanSync = 6,
 }
@@ -1,6 +1,14 @@
-use crate::{entities::NetworkState, ws::connection::FlowyWebSocketConnect};
+use crate::{
+    entities::{
+        LanSyncHostRequest, LanSyncPeerRequest, LanSyncSetting, NetworkState, ObjectSyncStatus, SyncObjectId,
+        SyncStatus,
+    },
+    local_server::LanSyncController,
+    sync_status::SyncStatusController,
+    ws::connection::FlowyWebSocketConnect,
+};
 use flowy_error::FlowyError;
-use lib_dispatch::prelude::{Data, Unit};
+use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
 use std::sync::Arc;
 
 #[tracing::instrument(skip(data, ws_manager))]
@@ -12,3 +20,55 @@ pub async fn update_network_ty(
     ws_manager.update_network_type(&network_state.ty);
     Ok(())
 }
+
+#[tracing::instrument(skip(data, controller), err)]
+pub async fn read_object_sync_status_handler(
+    data: Data<SyncObjectId>,
+    controller: Unit<Arc<SyncStatusController>>,
+) -> DataResult<ObjectSyncStatus, FlowyError> {
+    let object_id = data.into_inner().object_id;
+    let status = controller.object_sync_status(&object_id)?;
+    data_result(status)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub async fn read_sync_status_handler(
+    controller: Unit<Arc<SyncStatusController>>,
+) -> DataResult<SyncStatus, FlowyError> {
+    let status = controller.sync_status()?;
+    data_result(status)
+}
+
+#[tracing::instrument(skip(controller))]
+pub async fn read_lan_sync_setting_handler(
+    controller: Unit<Arc<LanSyncController>>,
+) -> DataResult<LanSyncSetting, FlowyError> {
+    data_result(controller.setting())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub async fn enable_lan_sync_host_handler(
+    data: Data<LanSyncHostRequest>,
+    controller: Unit<Arc<LanSyncController>>,
+) -> DataResult<LanSyncSetting, FlowyError> {
+    let setting = controller.enable_host(data.into_inner().port).await?;
+    data_result(setting)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub async fn pair_lan_sync_peer_handler(
+    data: Data<LanSyncPeerRequest>,
+    controller: Unit<Arc<LanSyncController>>,
+) -> DataResult<LanSyncSetting, FlowyError> {
+    let request = data.into_inner();
+    let setting = controller.pair_peer(&request.host_addr, &request.secret).await?;
+    data_result(setting)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub async fn disable_lan_sync_handler(
+    controller: Unit<Arc<LanSyncController>>,
+) -> DataResult<LanSyncSetting, FlowyError> {
+    let setting = controller.disable().await?;
+    data_result(setting)
+}
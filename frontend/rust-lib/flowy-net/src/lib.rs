@@ -1,3 +1,4 @@
+mod dart_notification;
 pub mod entities;
 mod event;
 mod handlers;
@@ -5,4 +6,5 @@ pub mod http_server;
 pub mod local_server;
 pub mod module;
 pub mod protobuf;
+pub mod sync_status;
 pub mod ws;
@@ -0,0 +1,437 @@
+use crate::local_server::persistence::RevisionCloudStorage;
+use flowy_collaboration::{
+    errors::CollaborateError,
+    protobuf::{RepeatedRevision as RepeatedRevisionPB, Revision as RevisionPB},
+};
+use lib_infra::future::BoxResultFuture;
+use parking_lot::Mutex;
+use protobuf::Message;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs::{self, File, OpenOptions},
+    io::{BufReader, ErrorKind, Read, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+const REVISION_FILE_EXTENSION: &str = "revs";
+
+// The record that is longer than it is treated as the partially written record, so the corrupted
+// length doesn't allocate the memory that is larger than the file.
+const MAX_RECORD_LEN: u64 = 64 * 1024 * 1024;
+
+// The file is compacted if the revisions that were replaced by the later ones are more than the
+// revisions that are kept, and they are more than it.
+const COMPACT_STALE_RECORDS: usize = 100;
+
+// Each object has an append-only file in the root directory. The revisions are written as the
+// length-prefixed protobuf bytes, and the revision that is written later replaces the revision
+// with the same rev_id. A record that was partially written, e.g. the app was killed while
+// writing, is ignored when reading the file, and is truncated before the file is appended to.
+pub(crate) struct FileRevisionCloudStorage {
+    root: PathBuf,
+    // Each file has its own lock, so the reads and writes of different objects don't wait for
+    // each other. The files are read and written in the blocking threads.
+    files: Arc<Mutex<HashMap<PathBuf, Arc<Mutex<RevisionFile>>>>>,
+}
+
+impl FileRevisionCloudStorage {
+    pub(crate) fn new(root: &str) -> Result<Self, CollaborateError> {
+        let root = PathBuf::from(root);
+        let _ = fs::create_dir_all(&root).map_err(|e| CollaborateError::internal().context(e))?;
+        Ok(Self {
+            root,
+            files: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+
+    fn revision_file(&self, object_id: &str) -> Arc<Mutex<RevisionFile>> {
+        let path = revision_file_path(&self.root, object_id);
+        self.files
+            .lock()
+            .entry(path.clone())
+            .or_insert_with(|| Arc::new(Mutex::new(RevisionFile::new(path))))
+            .clone()
+    }
+}
+
+impl RevisionCloudStorage for FileRevisionCloudStorage {
+    fn set_revisions(&self, repeated_revision: RepeatedRevisionPB) -> BoxResultFuture<(), CollaborateError> {
+        let mut revisions_by_object: HashMap<String, Vec<RevisionPB>> = HashMap::new();
+        for revision in repeated_revision.get_items() {
+            revisions_by_object
+                .entry(revision.get_object_id().to_owned())
+                .or_default()
+                .push(revision.clone());
+        }
+        let files = revisions_by_object
+            .into_iter()
+            .map(|(object_id, revisions)| (self.revision_file(&object_id), revisions))
+            .collect::<Vec<_>>();
+
+        Box::pin(async move {
+            spawn_blocking(move || {
+                for (file, revisions) in files {
+                    let _ = file.lock().append(&revisions)?;
+                }
+                Ok(())
+            })
+            .await
+        })
+    }
+
+    fn get_revisions(
+        &self,
+        object_id: &str,
+        rev_ids: Option<Vec<i64>>,
+    ) -> BoxResultFuture<RepeatedRevisionPB, CollaborateError> {
+        let file = self.revision_file(object_id);
+        Box::pin(async move {
+            let mut revisions = spawn_blocking(move || file.lock().read()).await?;
+            let items: Vec<RevisionPB> = match rev_ids {
+                None => revisions.into_iter().map(|(_, revision)| revision).collect(),
+                Some(rev_ids) => rev_ids.iter().flat_map(|rev_id| revisions.remove(rev_id)).collect(),
+            };
+
+            let mut repeated_revision = RepeatedRevisionPB::new();
+            repeated_revision.set_items(items.into());
+            Ok(repeated_revision)
+        })
+    }
+
+    fn reset_object(
+        &self,
+        object_id: &str,
+        repeated_revision: RepeatedRevisionPB,
+    ) -> BoxResultFuture<(), CollaborateError> {
+        let file = self.revision_file(object_id);
+        Box::pin(async move { spawn_blocking(move || file.lock().reset(repeated_revision.get_items())).await })
+    }
+}
+
+async fn spawn_blocking<F, T>(f: F) -> Result<T, CollaborateError>
+where
+    F: FnOnce() -> Result<T, CollaborateError> + Send + 'static,
+    T: Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| CollaborateError::internal().context(e))?
+}
+
+struct RevisionFile {
+    path: PathBuf,
+    // The file is checked for the partially written record when it's accessed at the first time
+    // since the app was launched.
+    checked: bool,
+    rev_ids: HashSet<i64>,
+    // The number of the records that were replaced by the later records with the same rev_id.
+    stale_records: usize,
+}
+
+impl RevisionFile {
+    fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            checked: false,
+            rev_ids: HashSet::new(),
+            stale_records: 0,
+        }
+    }
+
+    fn read(&mut self) -> Result<BTreeMap<i64, RevisionPB>, CollaborateError> {
+        let _ = self.check()?;
+        Ok(read_revisions(&self.path)?.revisions)
+    }
+
+    fn append(&mut self, revisions: &[RevisionPB]) -> Result<(), CollaborateError> {
+        let _ = self.check()?;
+        let _ = append_revisions(&self.path, revisions)?;
+        for revision in revisions {
+            if !self.rev_ids.insert(revision.get_rev_id()) {
+                self.stale_records += 1;
+            }
+        }
+
+        if self.stale_records > COMPACT_STALE_RECORDS && self.stale_records > self.rev_ids.len() {
+            tracing::debug!("Compact the revision file {:?}", self.path);
+            let revisions = read_revisions(&self.path)?
+                .revisions
+                .into_iter()
+                .map(|(_, revision)| revision);
+            let _ = self.reset(&revisions.collect::<Vec<RevisionPB>>())?;
+        }
+        Ok(())
+    }
+
+    // Writes the revisions to a temporary file and replaces the old file with it, so the old
+    // revisions are kept if the app was killed while writing. The file only has the latest record
+    // of each revision after it.
+    fn reset(&mut self, revisions: &[RevisionPB]) -> Result<(), CollaborateError> {
+        let mut latest_revisions: BTreeMap<i64, RevisionPB> = BTreeMap::new();
+        for revision in revisions {
+            latest_revisions.insert(revision.get_rev_id(), revision.clone());
+        }
+        let revisions = latest_revisions
+            .into_iter()
+            .map(|(_, revision)| revision)
+            .collect::<Vec<_>>();
+
+        let tmp_path = self.path.with_extension("tmp");
+        let _ = fs::remove_file(&tmp_path);
+        let _ = append_revisions(&tmp_path, &revisions)?;
+        let _ = fs::rename(&tmp_path, &self.path).map_err(|e| CollaborateError::internal().context(e))?;
+
+        self.checked = true;
+        self.rev_ids = revisions.iter().map(|revision| revision.get_rev_id()).collect();
+        self.stale_records = 0;
+        Ok(())
+    }
+
+    fn check(&mut self) -> Result<(), CollaborateError> {
+        if self.checked {
+            return Ok(());
+        }
+
+        let RevisionRecords {
+            revisions,
+            record_count,
+            valid_len,
+        } = read_revisions(&self.path)?;
+        match fs::metadata(&self.path) {
+            Ok(metadata) if metadata.len() > valid_len => {
+                tracing::warn!("Truncate the partially written revision in {:?}", self.path);
+                let file = OpenOptions::new()
+                    .write(true)
+                    .open(&self.path)
+                    .map_err(|e| CollaborateError::internal().context(e))?;
+                let _ = file
+                    .set_len(valid_len)
+                    .map_err(|e| CollaborateError::internal().context(e))?;
+            }
+            _ => {}
+        }
+
+        self.checked = true;
+        self.stale_records = record_count - revisions.len();
+        self.rev_ids = revisions.into_iter().map(|(rev_id, _)| rev_id).collect();
+        Ok(())
+    }
+}
+
+// The object id is used as the file name, the characters that are not allowed in the file name
+// are escaped, e.g. '/' is escaped as "%2F".
+fn revision_file_path(root: &Path, object_id: &str) -> PathBuf {
+    let mut file_name = String::with_capacity(object_id.len());
+    for c in object_id.chars() {
+        if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+            file_name.push(c);
+        } else {
+            let mut buf = [0; 4];
+            for byte in c.encode_utf8(&mut buf).bytes() {
+                file_name.push_str(&format!("%{:02X}", byte));
+            }
+        }
+    }
+    root.join(file_name).with_extension(REVISION_FILE_EXTENSION)
+}
+
+fn append_revisions(path: &Path, revisions: &[RevisionPB]) -> Result<(), CollaborateError> {
+    let mut buf = vec![];
+    for revision in revisions {
+        let bytes = revision.write_to_bytes()?;
+        buf.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+        buf.extend_from_slice(&bytes);
+    }
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| CollaborateError::internal().context(e))?;
+    let _ = file
+        .write_all(&buf)
+        .map_err(|e| CollaborateError::internal().context(e))?;
+    let _ = file.sync_data().map_err(|e| CollaborateError::internal().context(e))?;
+    Ok(())
+}
+
+struct RevisionRecords {
+    revisions: BTreeMap<i64, RevisionPB>,
+    // The number of the records that were read, including the records that were replaced.
+    record_count: usize,
+    // The length of the bytes that were read from the file, the bytes after it belong to the
+    // partially written record.
+    valid_len: u64,
+}
+
+// The record that has an invalid length or can't be parsed is treated as the partially written
+// record, the records after it are ignored.
+fn read_revisions(path: &Path) -> Result<RevisionRecords, CollaborateError> {
+    let mut records = RevisionRecords {
+        revisions: BTreeMap::new(),
+        record_count: 0,
+        valid_len: 0,
+    };
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(records),
+        Err(e) => return Err(CollaborateError::internal().context(e)),
+    };
+    let file_len = file
+        .metadata()
+        .map_err(|e| CollaborateError::internal().context(e))?
+        .len();
+
+    let mut reader = BufReader::new(file);
+    loop {
+        let mut len_bytes = [0; 4];
+        if reader.read_exact(&mut len_bytes).is_err() {
+            break;
+        }
+        let len = u32::from_be_bytes(len_bytes) as u64;
+        let remaining_len = file_len.saturating_sub(records.valid_len + len_bytes.len() as u64);
+        if len > MAX_RECORD_LEN || len > remaining_len {
+            break;
+        }
+        let mut bytes = vec![0; len as usize];
+        if reader.read_exact(&mut bytes).is_err() {
+            break;
+        }
+        let revision = match RevisionPB::parse_from_bytes(&bytes) {
+            Ok(revision) => revision,
+            Err(e) => {
+                tracing::warn!("Parse the revision in {:?} failed: {:?}", path, e);
+                break;
+            }
+        };
+        records.revisions.insert(revision.get_rev_id(), revision);
+        records.record_count += 1;
+        records.valid_len += (len_bytes.len() + bytes.len()) as u64;
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lib_infra::uuid_string;
+
+    fn make_revision(object_id: &str, rev_id: i64, delta_data: &str) -> RevisionPB {
+        let mut revision = RevisionPB::new();
+        revision.set_object_id(object_id.to_owned());
+        revision.set_rev_id(rev_id);
+        revision.set_delta_data(delta_data.as_bytes().to_vec());
+        revision
+    }
+
+    fn make_repeated_revision(revisions: Vec<RevisionPB>) -> RepeatedRevisionPB {
+        let mut repeated_revision = RepeatedRevisionPB::new();
+        repeated_revision.set_items(revisions.into());
+        repeated_revision
+    }
+
+    async fn read_delta_data(storage: &FileRevisionCloudStorage, object_id: &str) -> Vec<(i64, String)> {
+        let repeated_revision = storage.get_revisions(object_id, None).await.unwrap();
+        repeated_revision
+            .get_items()
+            .iter()
+            .map(|revision| {
+                let delta_data = String::from_utf8(revision.get_delta_data().to_vec()).unwrap();
+                (revision.get_rev_id(), delta_data)
+            })
+            .collect()
+    }
+
+    fn test_root() -> String {
+        std::env::temp_dir()
+            .join(format!("flowy_file_storage_{}", uuid_string()))
+            .to_str()
+            .unwrap()
+            .to_owned()
+    }
+
+    #[tokio::test]
+    async fn file_storage_reload_after_restart() {
+        let root = test_root();
+        let storage = FileRevisionCloudStorage::new(&root).unwrap();
+        let revisions = vec![make_revision("a/b", 1, "1"), make_revision("a/b", 2, "2")];
+        storage.set_revisions(make_repeated_revision(revisions)).await.unwrap();
+        storage
+            .set_revisions(make_repeated_revision(vec![make_revision("a/b", 2, "22")]))
+            .await
+            .unwrap();
+        drop(storage);
+
+        let storage = FileRevisionCloudStorage::new(&root).unwrap();
+        assert_eq!(
+            read_delta_data(&storage, "a/b").await,
+            vec![(1, "1".to_owned()), (2, "22".to_owned())]
+        );
+        assert!(read_delta_data(&storage, "c").await.is_empty());
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[tokio::test]
+    async fn file_storage_truncate_partially_written_record() {
+        let root = test_root();
+        let storage = FileRevisionCloudStorage::new(&root).unwrap();
+        storage
+            .set_revisions(make_repeated_revision(vec![make_revision("a", 1, "1")]))
+            .await
+            .unwrap();
+        drop(storage);
+
+        // The length of the record is written but the app was killed before writing its bytes.
+        let path = revision_file_path(Path::new(&root), "a");
+        let valid_len = fs::metadata(&path).unwrap().len();
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(&100_u32.to_be_bytes()).unwrap();
+        file.write_all(b"12").unwrap();
+        drop(file);
+
+        let storage = FileRevisionCloudStorage::new(&root).unwrap();
+        assert_eq!(read_delta_data(&storage, "a").await, vec![(1, "1".to_owned())]);
+        assert_eq!(fs::metadata(&path).unwrap().len(), valid_len);
+
+        storage
+            .set_revisions(make_repeated_revision(vec![make_revision("a", 2, "2")]))
+            .await
+            .unwrap();
+        drop(storage);
+
+        let storage = FileRevisionCloudStorage::new(&root).unwrap();
+        assert_eq!(
+            read_delta_data(&storage, "a").await,
+            vec![(1, "1".to_owned()), (2, "2".to_owned())]
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[tokio::test]
+    async fn file_storage_compact_stale_records() {
+        let root = test_root();
+        let storage = FileRevisionCloudStorage::new(&root).unwrap();
+        for i in 0..=COMPACT_STALE_RECORDS + 1 {
+            let revision = make_revision("a", 1, &format!("{:03}", i));
+            storage
+                .set_revisions(make_repeated_revision(vec![revision]))
+                .await
+                .unwrap();
+        }
+
+        // Only the latest record is kept after the stale records exceed the limit.
+        let path = revision_file_path(Path::new(&root), "a");
+        let records = read_revisions(&path).unwrap();
+        assert_eq!(records.record_count, 1);
+        assert_eq!(records.valid_len, fs::metadata(&path).unwrap().len());
+
+        let expected = vec![(1, format!("{:03}", COMPACT_STALE_RECORDS + 1))];
+        assert_eq!(read_delta_data(&storage, "a").await, expected);
+        drop(storage);
+
+        let storage = FileRevisionCloudStorage::new(&root).unwrap();
+        assert_eq!(read_delta_data(&storage, "a").await, expected);
+        let _ = fs::remove_dir_all(&root);
+    }
+}
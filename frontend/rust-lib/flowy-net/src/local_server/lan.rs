@@ -0,0 +1,414 @@
+use crate::{
+    entities::{LanSyncRole, LanSyncSetting},
+    local_server::LocalServer,
+};
+use bytes::Bytes;
+use flowy_database::kv::KV;
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use futures_util::{SinkExt, StreamExt};
+use lib_infra::uuid_string;
+use lib_ws::WebSocketRawMessage;
+use parking_lot::RwLock;
+use std::{
+    convert::TryFrom,
+    net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket},
+    sync::Arc,
+};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::{mpsc, watch},
+    time::{timeout, Duration},
+};
+use tokio_tungstenite::{
+    accept_async,
+    tungstenite::{
+        protocol::{frame::coding::CloseCode, CloseFrame},
+        Message,
+    },
+    WebSocketStream,
+};
+
+const LAN_SYNC_PATH: &str = "lan";
+const LAN_SYNC_HANDSHAKE_TIMEOUT_IN_SECS: u64 = 5;
+const LAN_SYNC_HANDSHAKE_ACCEPTED: &str = "accepted";
+
+const LAN_SYNC_ROLE: &str = "lan_sync_role";
+const LAN_SYNC_HOST_ADDR: &str = "lan_sync_host_addr";
+const LAN_SYNC_SECRET: &str = "lan_sync_secret";
+const LAN_SYNC_HOST: &str = "host";
+const LAN_SYNC_PEER: &str = "peer";
+
+// The client that is the Host exposes its LocalServer to the LAN, and the clients that are the
+// Peers use the Host as their sync server. The Peer connects to ws://{host_addr}/lan/{token} and
+// sends the secret as the handshake message, the Host closes the connection if it's not matched.
+#[derive(Clone, PartialEq)]
+pub enum LanSyncConfig {
+    Host { port: u16, secret: String },
+    Peer { addr: String, secret: String },
+}
+
+impl std::fmt::Debug for LanSyncConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LanSyncConfig::Host { port, .. } => f.debug_struct("Host").field("port", port).finish(),
+            LanSyncConfig::Peer { addr, .. } => f.debug_struct("Peer").field("addr", addr).finish(),
+        }
+    }
+}
+
+impl LanSyncConfig {
+    // Reads the config that was saved by the pairing events. It takes effect when the app is launched.
+    pub fn read() -> Option<Self> {
+        let secret = KV::get_str(LAN_SYNC_SECRET)?;
+        let addr = KV::get_str(LAN_SYNC_HOST_ADDR)?;
+        match KV::get_str(LAN_SYNC_ROLE)?.as_str() {
+            LAN_SYNC_HOST => match addr.parse::<u16>() {
+                Ok(port) => Some(LanSyncConfig::Host { port, secret }),
+                Err(e) => {
+                    tracing::error!("Invalid LAN sync port {}: {:?}", addr, e);
+                    None
+                }
+            },
+            LAN_SYNC_PEER => Some(LanSyncConfig::Peer { addr, secret }),
+            _ => None,
+        }
+    }
+
+    fn save(config: Option<&LanSyncConfig>) {
+        let (role, addr, secret) = match config {
+            None => {
+                for key in [LAN_SYNC_ROLE, LAN_SYNC_HOST_ADDR, LAN_SYNC_SECRET] {
+                    if let Err(e) = KV::remove(key) {
+                        tracing::error!("Remove {} failed: {}", key, e);
+                    }
+                }
+                return;
+            }
+            Some(LanSyncConfig::Host { port, secret }) => (LAN_SYNC_HOST, port.to_string(), secret),
+            Some(LanSyncConfig::Peer { addr, secret }) => (LAN_SYNC_PEER, addr.clone(), secret),
+        };
+        KV::set_str(LAN_SYNC_ROLE, role.to_owned());
+        KV::set_str(LAN_SYNC_HOST_ADDR, addr);
+        KV::set_str(LAN_SYNC_SECRET, secret.clone());
+    }
+
+    // The websocket address of the Host that is used by the Peer.
+    pub fn peer_ws_addr(&self) -> Option<String> {
+        match self {
+            LanSyncConfig::Host { .. } => None,
+            LanSyncConfig::Peer { addr, .. } => Some(format!("ws://{}/{}", addr, LAN_SYNC_PATH)),
+        }
+    }
+
+    pub fn secret(&self) -> &str {
+        match self {
+            LanSyncConfig::Host { secret, .. } => secret,
+            LanSyncConfig::Peer { secret, .. } => secret,
+        }
+    }
+}
+
+// Pairs this client with the other clients in the LAN. The Host starts or stops listening right
+// away, but the Peer syncs with the Host after the app is restarted, because the web socket of the
+// Peer is created when the app is launched.
+pub struct LanSyncController {
+    local_server: Option<Arc<LocalServer>>,
+    // The config that the app was launched with, or the Host that was enabled after launching.
+    active_config: RwLock<Option<LanSyncConfig>>,
+    listener: RwLock<Option<LanSyncListener>>,
+}
+
+impl LanSyncController {
+    pub fn new(local_server: Option<Arc<LocalServer>>, active_config: Option<LanSyncConfig>) -> Self {
+        Self {
+            local_server,
+            active_config: RwLock::new(active_config),
+            listener: RwLock::new(None),
+        }
+    }
+
+    // Starts listening if the app was launched as the Host.
+    pub async fn run(&self) {
+        let active_config = self.active_config.read().clone();
+        if let Some(LanSyncConfig::Host { port, secret }) = active_config {
+            match self.listen(port, &secret).await {
+                Ok(listener) => *self.listener.write() = Some(listener),
+                Err(e) => tracing::error!("Start the LAN sync server failed: {:?}", e),
+            }
+        }
+    }
+
+    pub fn setting(&self) -> LanSyncSetting {
+        let config = LanSyncConfig::read();
+        let is_active = config == *self.active_config.read();
+        let (role, host_addr) = match config.as_ref() {
+            None => (LanSyncRole::LanSyncDisabled, "".to_owned()),
+            Some(LanSyncConfig::Host { .. }) => {
+                let host_addr = self
+                    .listener
+                    .read()
+                    .as_ref()
+                    .map(|listener| listener.addr().to_string());
+                (LanSyncRole::LanSyncHost, host_addr.unwrap_or_default())
+            }
+            Some(LanSyncConfig::Peer { addr, .. }) => (LanSyncRole::LanSyncPeer, addr.clone()),
+        };
+        LanSyncSetting {
+            role,
+            host_addr,
+            secret: config.map(|config| config.secret().to_owned()).unwrap_or_default(),
+            is_active,
+        }
+    }
+
+    // The secret is kept if the Host was enabled before, so the Peers don't need to pair again.
+    pub async fn enable_host(&self, port: i32) -> FlowyResult<LanSyncSetting> {
+        if port <= 0 || port > u16::MAX as i32 {
+            return Err(FlowyError::lan_sync_addr().context(format!("Invalid port: {}", port)));
+        }
+        let secret = match LanSyncConfig::read() {
+            Some(LanSyncConfig::Host { secret, .. }) => secret,
+            _ => uuid_string(),
+        };
+        let config = LanSyncConfig::Host {
+            port: port as u16,
+            secret,
+        };
+        let _ = self.apply_config(Some(config)).await?;
+        Ok(self.setting())
+    }
+
+    pub async fn pair_peer(&self, host_addr: &str, secret: &str) -> FlowyResult<LanSyncSetting> {
+        let host_addr = host_addr.trim();
+        if host_addr.parse::<SocketAddr>().is_err() {
+            return Err(FlowyError::lan_sync_addr().context(format!("Invalid address: {}", host_addr)));
+        }
+        if secret.trim().is_empty() {
+            return Err(FlowyError::lan_sync_secret());
+        }
+        let config = LanSyncConfig::Peer {
+            addr: host_addr.to_owned(),
+            secret: secret.trim().to_owned(),
+        };
+        let _ = self.apply_config(Some(config)).await?;
+        Ok(self.setting())
+    }
+
+    pub async fn disable(&self) -> FlowyResult<LanSyncSetting> {
+        let _ = self.apply_config(None).await?;
+        Ok(self.setting())
+    }
+
+    // Saves the config and applies the Host right away. The client that was launched as the Peer
+    // keeps syncing with its Host until the app is restarted.
+    async fn apply_config(&self, config: Option<LanSyncConfig>) -> FlowyResult<()> {
+        let is_peer = matches!(*self.active_config.read(), Some(LanSyncConfig::Peer { .. }));
+        if !is_peer {
+            // The old listener is dropped first, so the Host can listen on the same port again.
+            *self.listener.write() = None;
+            *self.active_config.write() = None;
+            if let Some(LanSyncConfig::Host { port, secret }) = config.as_ref() {
+                let listener = self.listen(*port, secret).await?;
+                *self.listener.write() = Some(listener);
+                *self.active_config.write() = config.clone();
+            }
+        }
+        LanSyncConfig::save(config.as_ref());
+        Ok(())
+    }
+
+    async fn listen(&self, port: u16, secret: &str) -> FlowyResult<LanSyncListener> {
+        let local_server = self
+            .local_server
+            .clone()
+            .ok_or_else(|| FlowyError::internal().context("The LAN sync requires the LocalServer"))?;
+        let addr = SocketAddr::new(lan_ip()?, port);
+        LanSyncServer::new(local_server, secret).listen(addr).await
+    }
+}
+
+pub struct LanSyncServer {
+    local_server: Arc<LocalServer>,
+    secret: Arc<String>,
+}
+
+impl LanSyncServer {
+    pub fn new(local_server: Arc<LocalServer>, secret: &str) -> Self {
+        Self {
+            local_server,
+            secret: Arc::new(secret.to_owned()),
+        }
+    }
+
+    pub async fn listen(self, addr: SocketAddr) -> Result<LanSyncListener, FlowyError> {
+        let listener = TcpListener::bind(addr).await.map_err(internal_error)?;
+        let addr = listener.local_addr().map_err(internal_error)?;
+        tracing::info!("[LanSyncServer]: listening on {}", addr);
+        let (stop_tx, stop_rx) = watch::channel(());
+        tokio::spawn(async move {
+            let mut accept_stop_rx = stop_rx.clone();
+            loop {
+                let result = tokio::select! {
+                    result = listener.accept() => result,
+                    _ = accept_stop_rx.changed() => break,
+                };
+                match result {
+                    Ok((stream, peer_addr)) => {
+                        let local_server = self.local_server.clone();
+                        let secret = self.secret.clone();
+                        let stop_rx = stop_rx.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = handle_peer(local_server, stream, peer_addr, secret, stop_rx).await {
+                                tracing::error!("[LanSyncServer]: {} disconnected: {:?}", peer_addr, e);
+                            }
+                        });
+                    }
+                    Err(e) => tracing::error!("[LanSyncServer]: accept failed: {:?}", e),
+                }
+            }
+            tracing::info!("[LanSyncServer]: stop listening on {}", addr);
+        });
+        Ok(LanSyncListener {
+            addr,
+            _stop_tx: stop_tx,
+        })
+    }
+}
+
+// The Host stops listening and disconnects all the Peers when the listener is dropped.
+pub struct LanSyncListener {
+    addr: SocketAddr,
+    _stop_tx: watch::Sender<()>,
+}
+
+impl LanSyncListener {
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+}
+
+async fn handle_peer(
+    local_server: Arc<LocalServer>,
+    stream: TcpStream,
+    peer_addr: SocketAddr,
+    secret: Arc<String>,
+    mut stop_rx: watch::Receiver<()>,
+) -> Result<(), FlowyError> {
+    let mut stream = accept_async(stream).await.map_err(internal_error)?;
+    if let Err(e) = verify_handshake(&mut stream, &secret).await {
+        tracing::warn!("[LanSyncServer]: reject {}: {}", peer_addr, e);
+        let frame = CloseFrame {
+            code: CloseCode::Policy,
+            reason: "Unauthorized".into(),
+        };
+        let _ = stream.close(Some(frame)).await;
+        return Ok(());
+    }
+    let _ = stream
+        .send(Message::Text(LAN_SYNC_HANDSHAKE_ACCEPTED.to_owned()))
+        .await
+        .map_err(internal_error)?;
+    tracing::info!("[LanSyncServer]: {} connected", peer_addr);
+
+    let (mut ws_write, mut ws_read) = stream.split();
+    let (client_ws_sender, mut client_ws_receiver) = mpsc::unbounded_channel::<WebSocketRawMessage>();
+    tokio::spawn(async move {
+        while let Some(message) = client_ws_receiver.recv().await {
+            if let Err(e) = ws_write.send(message.into()).await {
+                tracing::error!("[LanSyncServer]: send message failed: {:?}", e);
+                break;
+            }
+        }
+    });
+
+    // The revisions of the peers are kept by the synchronizers with the peer_id, so each
+    // connection is a different user even if the same user is signed in on multiple clients.
+    let peer_id = format!("{}:{}", LAN_SYNC_PATH, peer_addr);
+    loop {
+        let message = tokio::select! {
+            message = ws_read.next() => message,
+            _ = stop_rx.changed() => break,
+        };
+        match message {
+            None => break,
+            Some(message) => match message.map_err(internal_error)? {
+                Message::Binary(bytes) => {
+                    let message = WebSocketRawMessage::try_from(Bytes::from(bytes)).map_err(internal_error)?;
+                    if let Err(e) = local_server
+                        .handle_peer_message(message, peer_id.clone(), client_ws_sender.clone())
+                        .await
+                    {
+                        tracing::error!("[LanSyncServer]: {}", e);
+                    }
+                }
+                Message::Close(_) => break,
+                _ => {}
+            },
+        }
+    }
+    tracing::info!("[LanSyncServer]: {} disconnected", peer_addr);
+    Ok(())
+}
+
+// The first message of the Peer should be the secret.
+async fn verify_handshake(stream: &mut WebSocketStream<TcpStream>, secret: &str) -> Result<(), FlowyError> {
+    let duration = Duration::from_secs(LAN_SYNC_HANDSHAKE_TIMEOUT_IN_SECS);
+    match timeout(duration, stream.next()).await {
+        Err(_) => Err(FlowyError::unauthorized().context("The handshake timed out")),
+        Ok(Some(Ok(Message::Text(handshake)))) if constant_time_eq(handshake.as_bytes(), secret.as_bytes()) => Ok(()),
+        Ok(_) => Err(FlowyError::unauthorized().context("The secret is not matched")),
+    }
+}
+
+// The Host only listens on the private address of the LAN instead of all the interfaces.
+// Connecting the UDP socket doesn't send anything, it only picks the interface of the route.
+fn lan_ip() -> FlowyResult<IpAddr> {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).map_err(internal_error)?;
+    let _ = socket
+        .connect((Ipv4Addr::new(192, 168, 0, 1), 9))
+        .map_err(|e| FlowyError::lan_sync_addr().context(e))?;
+    let ip = socket.local_addr().map_err(internal_error)?.ip();
+    match ip {
+        IpAddr::V4(ipv4) if ipv4.is_private() => Ok(ip),
+        _ => Err(FlowyError::lan_sync_addr().context(format!("{} is not a private address", ip))),
+    }
+}
+
+// Compares all the bytes, so the time doesn't tell how many bytes of the secret are matched.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::local_server::persistence::LocalDocumentCloudPersistence;
+    use tokio::sync::broadcast;
+    use tokio_tungstenite::connect_async;
+
+    #[tokio::test]
+    async fn lan_sync_listener_verify_secret() {
+        let (client_ws_sender, _) = mpsc::unbounded_channel();
+        let (client_ws_receiver, _) = broadcast::channel(16);
+        let persistence = LocalDocumentCloudPersistence::default();
+        let local_server = Arc::new(LocalServer::new(client_ws_sender, client_ws_receiver, persistence));
+        let listener = LanSyncServer::new(local_server, "secret")
+            .listen("127.0.0.1:0".parse().unwrap())
+            .await
+            .unwrap();
+        let url = format!("ws://{}/{}/token", listener.addr(), LAN_SYNC_PATH);
+
+        let (mut stream, _) = connect_async(&url).await.unwrap();
+        stream.send(Message::Text("secret".to_owned())).await.unwrap();
+        let message = stream.next().await.unwrap().unwrap();
+        assert_eq!(message, Message::Text(LAN_SYNC_HANDSHAKE_ACCEPTED.to_owned()));
+
+        let (mut stream, _) = connect_async(&url).await.unwrap();
+        stream.send(Message::Text("wrong secret".to_owned())).await.unwrap();
+        let message = stream.next().await.unwrap().unwrap();
+        assert!(matches!(message, Message::Close(_)));
+    }
+}
@@ -1,10 +1,18 @@
+use crate::local_server::{
+    file_storage::FileRevisionCloudStorage,
+    persistence::{LocalDocumentCloudPersistence, MemoryDocumentCloudStorage, RevisionCloudStorage},
+};
 use backend_service::configuration::ClientServerConfiguration;
+use std::sync::Arc;
 use tokio::sync::{broadcast, mpsc};
 
+mod file_storage;
+mod lan;
 mod persistence;
 mod server;
 mod ws;
 
+pub use lan::*;
 pub use server::*;
 pub use ws::*;
 
@@ -13,7 +21,11 @@ pub struct LocalServerContext {
     pub local_server: LocalServer,
 }
 
-pub fn build_server(_config: &ClientServerConfiguration) -> LocalServerContext {
+const LOCAL_SERVER_DIR: &str = "local_server";
+
+// The documents and folders that are synced to the local server are stored in the root
+// directory, so they are kept across restarts.
+pub fn build_server(_config: &ClientServerConfiguration, root: &str) -> LocalServerContext {
     let (client_ws_sender, server_ws_receiver) = mpsc::unbounded_channel();
     let (server_ws_sender, _) = broadcast::channel(16);
 
@@ -21,7 +33,19 @@ pub fn build_server(_config: &ClientServerConfiguration) -> LocalServerContext {
     // server_ws_receiver <- client_ws_sender
     let local_ws = LocalWebSocket::new(server_ws_receiver, server_ws_sender.clone());
     let client_ws_receiver = server_ws_sender;
-    let local_server = LocalServer::new(client_ws_sender, client_ws_receiver);
+    let persistence = LocalDocumentCloudPersistence::new(mk_revision_cloud_storage(root));
+    let local_server = LocalServer::new(client_ws_sender, client_ws_receiver, persistence);
 
     LocalServerContext { local_ws, local_server }
 }
+
+fn mk_revision_cloud_storage(root: &str) -> Arc<dyn RevisionCloudStorage> {
+    let dir = format!("{}/{}", root, LOCAL_SERVER_DIR);
+    match FileRevisionCloudStorage::new(&dir) {
+        Ok(storage) => Arc::new(storage),
+        Err(e) => {
+            tracing::error!("Open the local server storage at {} failed: {:?}", dir, e);
+            Arc::new(MemoryDocumentCloudStorage::default())
+        }
+    }
+}
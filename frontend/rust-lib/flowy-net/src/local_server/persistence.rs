@@ -4,7 +4,8 @@ use flowy_collaboration::{
     protobuf::{RepeatedRevision as RepeatedRevisionPB, Revision as RevisionPB},
     server_document::*,
     server_folder::FolderCloudPersistence,
-    util::{make_document_info_from_revisions_pb, make_folder_from_revisions_pb},
+    server_object::{ServerObjectPersistence, ServerSyncObject},
+    util::{make_document_info_from_revisions_pb, make_folder_from_revisions_pb, make_object_info_from_revisions_pb},
 };
 use lib_infra::future::BoxResultFuture;
 use std::{
@@ -12,8 +13,9 @@ use std::{
     sync::Arc,
 };
 
-// For the moment, we use memory to cache the data, it will be implemented with
-// other storage. Like the Firestore,Dropbox.etc.
+// The FileRevisionCloudStorage keeps the data in the app data directory, and the
+// MemoryDocumentCloudStorage is used if the directory is not available. It will be implemented
+// with other storage. Like the Firestore,Dropbox.etc.
 pub trait RevisionCloudStorage: Send + Sync {
     fn set_revisions(&self, repeated_revision: RepeatedRevisionPB) -> BoxResultFuture<(), CollaborateError>;
     fn get_revisions(
@@ -39,6 +41,12 @@ impl Debug for LocalDocumentCloudPersistence {
     }
 }
 
+impl LocalDocumentCloudPersistence {
+    pub(crate) fn new(storage: Arc<dyn RevisionCloudStorage>) -> Self {
+        LocalDocumentCloudPersistence { storage }
+    }
+}
+
 impl std::default::Default for LocalDocumentCloudPersistence {
     fn default() -> Self {
         LocalDocumentCloudPersistence {
@@ -110,6 +118,69 @@ impl FolderCloudPersistence for LocalDocumentCloudPersistence {
     }
 }
 
+impl<T: ServerSyncObject> ServerObjectPersistence<T> for LocalDocumentCloudPersistence {
+    fn read_object(&self, _user_id: &str, object_id: &str) -> BoxResultFuture<T::Info, CollaborateError> {
+        let storage = self.storage.clone();
+        let object_id = object_id.to_owned();
+        Box::pin(async move {
+            let repeated_revision = storage.get_revisions(&object_id, None).await?;
+            match make_object_info_from_revisions_pb(&object_id, repeated_revision)? {
+                Some(info) => Ok(info),
+                None => Err(CollaborateError::record_not_found()),
+            }
+        })
+    }
+
+    fn create_object(
+        &self,
+        _user_id: &str,
+        object_id: &str,
+        repeated_revision: RepeatedRevisionPB,
+    ) -> BoxResultFuture<Option<T::Info>, CollaborateError> {
+        let object_id = object_id.to_owned();
+        let storage = self.storage.clone();
+        Box::pin(async move {
+            let _ = storage.set_revisions(repeated_revision.clone()).await?;
+            make_object_info_from_revisions_pb(&object_id, repeated_revision)
+        })
+    }
+
+    fn save_object_revisions(&self, repeated_revision: RepeatedRevisionPB) -> BoxResultFuture<(), CollaborateError> {
+        let storage = self.storage.clone();
+        Box::pin(async move {
+            let _ = storage.set_revisions(repeated_revision).await?;
+            Ok(())
+        })
+    }
+
+    fn read_object_revisions(
+        &self,
+        object_id: &str,
+        rev_ids: Option<Vec<i64>>,
+    ) -> BoxResultFuture<Vec<RevisionPB>, CollaborateError> {
+        let object_id = object_id.to_owned();
+        let storage = self.storage.clone();
+        Box::pin(async move {
+            let mut repeated_revision = storage.get_revisions(&object_id, rev_ids).await?;
+            let revisions: Vec<RevisionPB> = repeated_revision.take_items().into();
+            Ok(revisions)
+        })
+    }
+
+    fn reset_object_revisions(
+        &self,
+        object_id: &str,
+        repeated_revision: RepeatedRevisionPB,
+    ) -> BoxResultFuture<(), CollaborateError> {
+        let storage = self.storage.clone();
+        let object_id = object_id.to_owned();
+        Box::pin(async move {
+            let _ = storage.reset_object(&object_id, repeated_revision).await?;
+            Ok(())
+        })
+    }
+}
+
 impl DocumentCloudPersistence for LocalDocumentCloudPersistence {
     fn read_document(&self, doc_id: &str) -> BoxResultFuture<DocumentInfo, CollaborateError> {
         let storage = self.storage.clone();
@@ -169,7 +240,7 @@ impl DocumentCloudPersistence for LocalDocumentCloudPersistence {
 }
 
 #[derive(Default)]
-struct MemoryDocumentCloudStorage {}
+pub(crate) struct MemoryDocumentCloudStorage {}
 impl RevisionCloudStorage for MemoryDocumentCloudStorage {
     fn set_revisions(&self, _repeated_revision: RepeatedRevisionPB) -> BoxResultFuture<(), CollaborateError> {
         Box::pin(async move { Ok(()) })
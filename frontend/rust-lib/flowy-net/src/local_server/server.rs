@@ -5,12 +5,17 @@ use flowy_collaboration::{
     client_document::default::initial_delta_string,
     entities::{
         document_info::{CreateDocParams, DocumentId, DocumentInfo, ResetDocumentParams},
+        revision::RepeatedRevision,
         ws_data::{ClientRevisionWSData, ClientRevisionWSDataType},
     },
-    errors::CollaborateError,
-    protobuf::ClientRevisionWSData as ClientRevisionWSDataPB,
-    server_document::ServerDocumentManager,
+    errors::{CollaborateError, ErrorCode},
+    protobuf::{ClientRevisionWSData as ClientRevisionWSDataPB, RepeatedRevision as RepeatedRevisionPB},
+    server_board::ServerBoardManager,
+    server_comment::ServerCommentManager,
+    server_document::{DocumentCloudPersistence, ServerDocumentManager},
     server_folder::ServerFolderManager,
+    server_grid::ServerGridManager,
+    server_object::{ServerObjectManager, ServerSyncObject},
     synchronizer::{RevisionSyncResponse, RevisionUser},
 };
 use flowy_core::module::{FolderCouldServiceV1, FolderCouldServiceV2};
@@ -26,26 +31,37 @@ use std::{
 use tokio::sync::{broadcast, mpsc, mpsc::UnboundedSender};
 
 pub struct LocalServer {
-    doc_manager: Arc<ServerDocumentManager>,
-    folder_manager: Arc<ServerFolderManager>,
+    persistence: Arc<LocalDocumentCloudPersistence>,
+    sync_handler: Arc<LocalSyncHandler>,
     stop_tx: RwLock<Option<mpsc::Sender<()>>>,
     client_ws_sender: mpsc::UnboundedSender<WebSocketRawMessage>,
     client_ws_receiver: broadcast::Sender<WebSocketRawMessage>,
 }
 
 impl LocalServer {
-    pub fn new(
+    pub(crate) fn new(
         client_ws_sender: mpsc::UnboundedSender<WebSocketRawMessage>,
         client_ws_receiver: broadcast::Sender<WebSocketRawMessage>,
+        persistence: LocalDocumentCloudPersistence,
     ) -> Self {
-        let persistence = Arc::new(LocalDocumentCloudPersistence::default());
+        let persistence = Arc::new(persistence);
         let doc_manager = Arc::new(ServerDocumentManager::new(persistence.clone()));
-        let folder_manager = Arc::new(ServerFolderManager::new(persistence));
+        let folder_manager = Arc::new(ServerFolderManager::new(persistence.clone()));
+        let grid_manager = Arc::new(ServerGridManager::new(persistence.clone()));
+        let board_manager = Arc::new(ServerBoardManager::new(persistence.clone()));
+        let comment_manager = Arc::new(ServerCommentManager::new(persistence.clone()));
+        let sync_handler = Arc::new(LocalSyncHandler {
+            doc_manager,
+            folder_manager,
+            grid_manager,
+            board_manager,
+            comment_manager,
+        });
         let stop_tx = RwLock::new(None);
 
         LocalServer {
-            doc_manager,
-            folder_manager,
+            persistence,
+            sync_handler,
             stop_tx,
             client_ws_sender,
             client_ws_receiver,
@@ -62,19 +78,30 @@ impl LocalServer {
         let (stop_tx, stop_rx) = mpsc::channel(1);
         *self.stop_tx.write() = Some(stop_tx);
         let runner = LocalWebSocketRunner {
-            doc_manager: self.doc_manager.clone(),
-            folder_manager: self.folder_manager.clone(),
+            sync_handler: self.sync_handler.clone(),
             stop_rx: Some(stop_rx),
             client_ws_sender: self.client_ws_sender.clone(),
             client_ws_receiver: Some(self.client_ws_receiver.subscribe()),
         };
         tokio::spawn(runner.run());
     }
+
+    // Handles the message of the peer that is connected to this server over the LAN. The peer_id
+    // identifies the connection, and the responses are sent back through the client_ws_sender.
+    pub(crate) async fn handle_peer_message(
+        &self,
+        message: WebSocketRawMessage,
+        peer_id: String,
+        client_ws_sender: mpsc::UnboundedSender<WebSocketRawMessage>,
+    ) -> Result<(), FlowyError> {
+        self.sync_handler
+            .handle_message(message, peer_id, client_ws_sender)
+            .await
+    }
 }
 
 struct LocalWebSocketRunner {
-    doc_manager: Arc<ServerDocumentManager>,
-    folder_manager: Arc<ServerFolderManager>,
+    sync_handler: Arc<LocalSyncHandler>,
     stop_rx: Option<mpsc::Receiver<()>>,
     client_ws_sender: mpsc::UnboundedSender<WebSocketRawMessage>,
     client_ws_receiver: Option<broadcast::Receiver<WebSocketRawMessage>>,
@@ -102,33 +129,91 @@ impl LocalWebSocketRunner {
         };
         stream
             .for_each(|message| async {
-                match self.handle_message(message).await {
+                let client_ws_sender = self.client_ws_sender.clone();
+                match self
+                    .sync_handler
+                    .handle_message(message, "".to_owned(), client_ws_sender)
+                    .await
+                {
                     Ok(_) => {}
                     Err(e) => tracing::error!("[LocalWebSocketRunner]: {}", e),
                 }
             })
             .await;
     }
+}
+
+struct LocalSyncHandler {
+    doc_manager: Arc<ServerDocumentManager>,
+    folder_manager: Arc<ServerFolderManager>,
+    grid_manager: Arc<ServerGridManager>,
+    board_manager: Arc<ServerBoardManager>,
+    comment_manager: Arc<ServerCommentManager>,
+}
 
-    async fn handle_message(&self, message: WebSocketRawMessage) -> Result<(), FlowyError> {
+impl LocalSyncHandler {
+    async fn handle_message(
+        &self,
+        message: WebSocketRawMessage,
+        user_id: String,
+        client_ws_sender: mpsc::UnboundedSender<WebSocketRawMessage>,
+    ) -> Result<(), FlowyError> {
         let bytes = Bytes::from(message.data);
         let client_data = ClientRevisionWSData::try_from(bytes).map_err(internal_error)?;
         match message.channel {
             WSChannel::Document => {
-                let _ = self.handle_document_client_data(client_data, "".to_owned()).await?;
+                let _ = self
+                    .handle_document_client_data(client_data, user_id, client_ws_sender)
+                    .await?;
                 Ok(())
             }
             WSChannel::Folder => {
-                let _ = self.handle_folder_client_data(client_data, "".to_owned()).await?;
+                let _ = self
+                    .handle_folder_client_data(client_data, user_id, client_ws_sender)
+                    .await?;
+                Ok(())
+            }
+            WSChannel::Grid => {
+                let _ = handle_object_client_data(
+                    &self.grid_manager,
+                    WSChannel::Grid,
+                    client_data,
+                    user_id,
+                    client_ws_sender,
+                )
+                .await?;
+                Ok(())
+            }
+            WSChannel::Board => {
+                let _ = handle_object_client_data(
+                    &self.board_manager,
+                    WSChannel::Board,
+                    client_data,
+                    user_id,
+                    client_ws_sender,
+                )
+                .await?;
+                Ok(())
+            }
+            WSChannel::Comment => {
+                let _ = handle_object_client_data(
+                    &self.comment_manager,
+                    WSChannel::Comment,
+                    client_data,
+                    user_id,
+                    client_ws_sender,
+                )
+                .await?;
                 Ok(())
             }
         }
     }
 
-    pub async fn handle_folder_client_data(
+    async fn handle_folder_client_data(
         &self,
         client_data: ClientRevisionWSData,
         user_id: String,
+        client_ws_sender: mpsc::UnboundedSender<WebSocketRawMessage>,
     ) -> Result<(), CollaborateError> {
         tracing::trace!(
             "[LocalFolderServer] receive: {}:{}-{:?} ",
@@ -136,7 +221,6 @@ impl LocalWebSocketRunner {
             client_data.id(),
             client_data.ty,
         );
-        let client_ws_sender = self.client_ws_sender.clone();
         let user = Arc::new(LocalRevisionUser {
             user_id,
             client_ws_sender,
@@ -161,10 +245,11 @@ impl LocalWebSocketRunner {
         Ok(())
     }
 
-    pub async fn handle_document_client_data(
+    async fn handle_document_client_data(
         &self,
         client_data: ClientRevisionWSData,
         user_id: String,
+        client_ws_sender: mpsc::UnboundedSender<WebSocketRawMessage>,
     ) -> Result<(), CollaborateError> {
         tracing::trace!(
             "[LocalDocumentServer] receive: {}:{}-{:?} ",
@@ -172,7 +257,6 @@ impl LocalWebSocketRunner {
             client_data.id(),
             client_data.ty,
         );
-        let client_ws_sender = self.client_ws_sender.clone();
         let user = Arc::new(LocalRevisionUser {
             user_id,
             client_ws_sender,
@@ -195,6 +279,40 @@ impl LocalWebSocketRunner {
     }
 }
 
+// The grid, the board and the comment threads are synced by the same ServerObjectManager with its
+// own channel.
+async fn handle_object_client_data<T: ServerSyncObject>(
+    object_manager: &Arc<ServerObjectManager<T>>,
+    channel: WSChannel,
+    client_data: ClientRevisionWSData,
+    user_id: String,
+    client_ws_sender: mpsc::UnboundedSender<WebSocketRawMessage>,
+) -> Result<(), CollaborateError> {
+    tracing::trace!(
+        "[Local{}Server] receive: {}:{}-{:?} ",
+        T::NAME,
+        client_data.object_id,
+        client_data.id(),
+        client_data.ty,
+    );
+    let user = Arc::new(LocalRevisionUser {
+        user_id,
+        client_ws_sender,
+        channel,
+    });
+    let ty = client_data.ty.clone();
+    let client_data: ClientRevisionWSDataPB = client_data.try_into().unwrap();
+    match ty {
+        ClientRevisionWSDataType::ClientPushRev => {
+            let _ = object_manager.handle_client_revisions(user, client_data).await?;
+        }
+        ClientRevisionWSDataType::ClientPing => {
+            let _ = object_manager.handle_client_ping(user, client_data).await?;
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug)]
 struct LocalRevisionUser {
     user_id: String,
@@ -307,6 +425,8 @@ impl FolderCouldServiceV1 for LocalServer {
             belongings: RepeatedView::default(),
             modified_time: time,
             create_time: time,
+            tags: vec![],
+            properties: vec![],
         };
         FutureResult::new(async { Ok(view) })
     }
@@ -334,6 +454,7 @@ impl FolderCouldServiceV1 for LocalServer {
             version: 0,
             modified_time: time,
             create_time: time,
+            color_style: params.color_style,
         };
         FutureResult::new(async { Ok(app) })
     }
@@ -413,17 +534,32 @@ impl DocumentCloudService for LocalServer {
         FutureResult::new(async { Ok(()) })
     }
 
+    // Returns the initial document if the document was never synced to the local server.
     fn read_document(&self, _token: &str, params: DocumentId) -> FutureResult<Option<DocumentInfo>, FlowyError> {
-        let doc = DocumentInfo {
-            doc_id: params.doc_id,
-            text: initial_delta_string(),
-            rev_id: 0,
-            base_rev_id: 0,
-        };
-        FutureResult::new(async { Ok(Some(doc)) })
+        let persistence = self.persistence.clone();
+        FutureResult::new(async move {
+            match persistence.read_document(&params.doc_id).await {
+                Ok(doc) => Ok(Some(doc)),
+                Err(e) if e.code == ErrorCode::RecordNotFound => Ok(Some(DocumentInfo {
+                    doc_id: params.doc_id,
+                    text: initial_delta_string(),
+                    rev_id: 0,
+                    base_rev_id: 0,
+                    encrypted_revisions: RepeatedRevision::empty(),
+                })),
+                Err(e) => Err(internal_error(e)),
+            }
+        })
     }
 
-    fn update_document(&self, _token: &str, _params: ResetDocumentParams) -> FutureResult<(), FlowyError> {
-        FutureResult::new(async { Ok(()) })
+    fn update_document(&self, _token: &str, params: ResetDocumentParams) -> FutureResult<(), FlowyError> {
+        let doc_manager = self.sync_handler.doc_manager.clone();
+        FutureResult::new(async move {
+            let repeated_revision: RepeatedRevisionPB = params.revisions.try_into().map_err(internal_error)?;
+            doc_manager
+                .handle_document_reset(&params.doc_id, repeated_revision)
+                .await
+                .map_err(internal_error)
+        })
     }
 }
@@ -1,10 +1,25 @@
-use crate::{event::NetworkEvent, handlers::*, ws::connection::FlowyWebSocketConnect};
+use crate::{
+    event::NetworkEvent, handlers::*, local_server::LanSyncController, sync_status::SyncStatusController,
+    ws::connection::FlowyWebSocketConnect,
+};
 use lib_dispatch::prelude::*;
 use std::sync::Arc;
 
-pub fn create(ws_conn: Arc<FlowyWebSocketConnect>) -> Module {
+pub fn create(
+    ws_conn: Arc<FlowyWebSocketConnect>,
+    sync_status_controller: Arc<SyncStatusController>,
+    lan_sync_controller: Arc<LanSyncController>,
+) -> Module {
     Module::new()
         .name("Flowy-Network")
         .data(ws_conn)
+        .data(sync_status_controller)
+        .data(lan_sync_controller)
         .event(NetworkEvent::UpdateNetworkType, update_network_ty)
+        .event(NetworkEvent::ReadObjectSyncStatus, read_object_sync_status_handler)
+        .event(NetworkEvent::ReadSyncStatus, read_sync_status_handler)
+        .event(NetworkEvent::ReadLanSyncSetting, read_lan_sync_setting_handler)
+        .event(NetworkEvent::EnableLanSyncHost, enable_lan_sync_host_handler)
+        .event(NetworkEvent::PairLanSyncPeer, pair_lan_sync_peer_handler)
+        .event(NetworkEvent::DisableLanSync, disable_lan_sync_handler)
 }
@@ -0,0 +1,109 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `dart_notification.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum NetworkNotification {
+    Unknown = 0,
+    ObjectSyncStatusChanged = 1,
+    SyncStatusChanged = 2,
+    ObjectMerged = 3,
+}
+
+impl ::protobuf::ProtobufEnum for NetworkNotification {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<NetworkNotification> {
+        match value {
+            0 => ::std::option::Option::Some(NetworkNotification::Unknown),
+            1 => ::std::option::Option::Some(NetworkNotification::ObjectSyncStatusChanged),
+            2 => ::std::option::Option::Some(NetworkNotification::SyncStatusChanged),
+            3 => ::std::option::Option::Some(NetworkNotification::ObjectMerged),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [NetworkNotification] = &[
+            NetworkNotification::Unknown,
+            NetworkNotification::ObjectSyncStatusChanged,
+            NetworkNotification::SyncStatusChanged,
+            NetworkNotification::ObjectMerged,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<NetworkNotification>("NetworkNotification", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for NetworkNotification {
+}
+
+impl ::std::default::Default for NetworkNotification {
+    fn default() -> Self {
+        NetworkNotification::Unknown
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NetworkNotification {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x17dart_notification.proto*h\n\x13NetworkNotification\x12\x0b\n\x07Un\
+    known\x10\0\x12\x1b\n\x17ObjectSyncStatusChanged\x10\x01\x12\x15\n\x11Sy\
+    ncStatusChanged\x10\x02\x12\x10\n\x0cObjectMerged\x10\x03J\xce\x01\n\x06\
+    \x12\x04\0\0\x07\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\
+    \x04\x02\0\x07\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x18\n\x0b\n\x04\
+    \x05\0\x02\0\x12\x03\x03\x04\x10\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\
+    \x04\x0b\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x0e\x0f\n\x0b\n\x04\x05\
+    \0\x02\x01\x12\x03\x04\x04\x20\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\
+    \x04\x1b\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x04\x1e\x1f\n\x0b\n\x04\
+    \x05\0\x02\x02\x12\x03\x05\x04\x1a\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\
+    \x05\x04\x15\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x05\x18\x19\n\x0b\n\
+    \x04\x05\0\x02\x03\x12\x03\x06\x04\x15\n\x0c\n\x05\x05\0\x02\x03\x01\x12\
+    \x03\x06\x04\x10\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x06\x13\x14b\x06p\
+    roto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
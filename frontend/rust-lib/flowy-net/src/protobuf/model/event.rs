@@ -26,6 +26,12 @@
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum NetworkEvent {
     UpdateNetworkType = 0,
+    ReadObjectSyncStatus = 1,
+    ReadSyncStatus = 2,
+    ReadLanSyncSetting = 3,
+    EnableLanSyncHost = 4,
+    PairLanSyncPeer = 5,
+    DisableLanSync = 6,
 }
 
 impl ::protobuf::ProtobufEnum for NetworkEvent {
@@ -36,6 +42,12 @@ impl ::protobuf::ProtobufEnum for NetworkEvent {
     fn from_i32(value: i32) -> ::std::option::Option<NetworkEvent> {
         match value {
             0 => ::std::option::Option::Some(NetworkEvent::UpdateNetworkType),
+            1 => ::std::option::Option::Some(NetworkEvent::ReadObjectSyncStatus),
+            2 => ::std::option::Option::Some(NetworkEvent::ReadSyncStatus),
+            3 => ::std::option::Option::Some(NetworkEvent::ReadLanSyncSetting),
+            4 => ::std::option::Option::Some(NetworkEvent::EnableLanSyncHost),
+            5 => ::std::option::Option::Some(NetworkEvent::PairLanSyncPeer),
+            6 => ::std::option::Option::Some(NetworkEvent::DisableLanSync),
             _ => ::std::option::Option::None
         }
     }
@@ -43,6 +55,12 @@ impl ::protobuf::ProtobufEnum for NetworkEvent {
     fn values() -> &'static [Self] {
         static values: &'static [NetworkEvent] = &[
             NetworkEvent::UpdateNetworkType,
+            NetworkEvent::ReadObjectSyncStatus,
+            NetworkEvent::ReadSyncStatus,
+            NetworkEvent::ReadLanSyncSetting,
+            NetworkEvent::EnableLanSyncHost,
+            NetworkEvent::PairLanSyncPeer,
+            NetworkEvent::DisableLanSync,
         ];
         values
     }
@@ -71,12 +89,27 @@ impl ::protobuf::reflect::ProtobufValue for NetworkEvent {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0bevent.proto*%\n\x0cNetworkEvent\x12\x15\n\x11UpdateNetworkType\x10\
-    \0JS\n\x06\x12\x04\0\0\x04\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\
-    \x05\0\x12\x04\x02\0\x04\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x11\n\
-    \x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x1a\n\x0c\n\x05\x05\0\x02\0\x01\
-    \x12\x03\x03\x04\x15\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x18\x19b\
-    \x06proto3\
+    \n\x0bevent.proto*\xab\x01\n\x0cNetworkEvent\x12\x15\n\x11UpdateNetworkT\
+    ype\x10\0\x12\x18\n\x14ReadObjectSyncStatus\x10\x01\x12\x12\n\x0eReadSyn\
+    cStatus\x10\x02\x12\x16\n\x12ReadLanSyncSetting\x10\x03\x12\x15\n\x11Ena\
+    bleLanSyncHost\x10\x04\x12\x13\n\x0fPairLanSyncPeer\x10\x05\x12\x12\n\
+    \x0eDisableLanSync\x10\x06J\xc9\x02\n\x06\x12\x04\0\0\n\x01\n\x08\n\x01\
+    \x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\n\x01\n\n\n\x03\x05\0\
+    \x01\x12\x03\x02\x05\x11\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x1a\n\
+    \x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x15\n\x0c\n\x05\x05\0\x02\0\
+    \x02\x12\x03\x03\x18\x19\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\x1d\n\
+    \x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x18\n\x0c\n\x05\x05\0\x02\
+    \x01\x02\x12\x03\x04\x1b\x1c\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x04\
+    \x17\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\x12\n\x0c\n\x05\x05\0\
+    \x02\x02\x02\x12\x03\x05\x15\x16\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x06\
+    \x04\x1b\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x16\n\x0c\n\x05\
+    \x05\0\x02\x03\x02\x12\x03\x06\x19\x1a\n\x0b\n\x04\x05\0\x02\x04\x12\x03\
+    \x07\x04\x1a\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x15\n\x0c\n\
+    \x05\x05\0\x02\x04\x02\x12\x03\x07\x18\x19\n\x0b\n\x04\x05\0\x02\x05\x12\
+    \x03\x08\x04\x18\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x08\x04\x13\n\x0c\
+    \n\x05\x05\0\x02\x05\x02\x12\x03\x08\x16\x17\n\x0b\n\x04\x05\0\x02\x06\
+    \x12\x03\t\x04\x17\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\t\x04\x12\n\x0c\
+    \n\x05\x05\0\x02\x06\x02\x12\x03\t\x15\x16b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,751 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `lan_sync.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LanSyncSetting {
+    // message fields
+    pub role: LanSyncRole,
+    pub host_addr: ::std::string::String,
+    pub secret: ::std::string::String,
+    pub is_active: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LanSyncSetting {
+    fn default() -> &'a LanSyncSetting {
+        <LanSyncSetting as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LanSyncSetting {
+    pub fn new() -> LanSyncSetting {
+        ::std::default::Default::default()
+    }
+
+    // .LanSyncRole role = 1;
+
+
+    pub fn get_role(&self) -> LanSyncRole {
+        self.role
+    }
+    pub fn clear_role(&mut self) {
+        self.role = LanSyncRole::LanSyncDisabled;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: LanSyncRole) {
+        self.role = v;
+    }
+
+    // string host_addr = 2;
+
+
+    pub fn get_host_addr(&self) -> &str {
+        &self.host_addr
+    }
+    pub fn clear_host_addr(&mut self) {
+        self.host_addr.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_host_addr(&mut self, v: ::std::string::String) {
+        self.host_addr = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_host_addr(&mut self) -> &mut ::std::string::String {
+        &mut self.host_addr
+    }
+
+    // Take field
+    pub fn take_host_addr(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.host_addr, ::std::string::String::new())
+    }
+
+    // string secret = 3;
+
+
+    pub fn get_secret(&self) -> &str {
+        &self.secret
+    }
+    pub fn clear_secret(&mut self) {
+        self.secret.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_secret(&mut self, v: ::std::string::String) {
+        self.secret = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_secret(&mut self) -> &mut ::std::string::String {
+        &mut self.secret
+    }
+
+    // Take field
+    pub fn take_secret(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.secret, ::std::string::String::new())
+    }
+
+    // bool is_active = 4;
+
+
+    pub fn get_is_active(&self) -> bool {
+        self.is_active
+    }
+    pub fn clear_is_active(&mut self) {
+        self.is_active = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_active(&mut self, v: bool) {
+        self.is_active = v;
+    }
+}
+
+impl ::protobuf::Message for LanSyncSetting {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.role, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.host_addr)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.secret)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_active = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.role != LanSyncRole::LanSyncDisabled {
+            my_size += ::protobuf::rt::enum_size(1, self.role);
+        }
+        if !self.host_addr.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.host_addr);
+        }
+        if !self.secret.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.secret);
+        }
+        if self.is_active != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.role != LanSyncRole::LanSyncDisabled {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.role))?;
+        }
+        if !self.host_addr.is_empty() {
+            os.write_string(2, &self.host_addr)?;
+        }
+        if !self.secret.is_empty() {
+            os.write_string(3, &self.secret)?;
+        }
+        if self.is_active != false {
+            os.write_bool(4, self.is_active)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LanSyncSetting {
+        LanSyncSetting::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<LanSyncRole>>(
+                "role",
+                |m: &LanSyncSetting| { &m.role },
+                |m: &mut LanSyncSetting| { &mut m.role },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "host_addr",
+                |m: &LanSyncSetting| { &m.host_addr },
+                |m: &mut LanSyncSetting| { &mut m.host_addr },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "secret",
+                |m: &LanSyncSetting| { &m.secret },
+                |m: &mut LanSyncSetting| { &mut m.secret },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_active",
+                |m: &LanSyncSetting| { &m.is_active },
+                |m: &mut LanSyncSetting| { &mut m.is_active },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LanSyncSetting>(
+                "LanSyncSetting",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LanSyncSetting {
+        static instance: ::protobuf::rt::LazyV2<LanSyncSetting> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LanSyncSetting::new)
+    }
+}
+
+impl ::protobuf::Clear for LanSyncSetting {
+    fn clear(&mut self) {
+        self.role = LanSyncRole::LanSyncDisabled;
+        self.host_addr.clear();
+        self.secret.clear();
+        self.is_active = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LanSyncSetting {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LanSyncSetting {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LanSyncHostRequest {
+    // message fields
+    pub port: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LanSyncHostRequest {
+    fn default() -> &'a LanSyncHostRequest {
+        <LanSyncHostRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LanSyncHostRequest {
+    pub fn new() -> LanSyncHostRequest {
+        ::std::default::Default::default()
+    }
+
+    // int32 port = 1;
+
+
+    pub fn get_port(&self) -> i32 {
+        self.port
+    }
+    pub fn clear_port(&mut self) {
+        self.port = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_port(&mut self, v: i32) {
+        self.port = v;
+    }
+}
+
+impl ::protobuf::Message for LanSyncHostRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.port = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.port != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.port, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.port != 0 {
+            os.write_int32(1, self.port)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LanSyncHostRequest {
+        LanSyncHostRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "port",
+                |m: &LanSyncHostRequest| { &m.port },
+                |m: &mut LanSyncHostRequest| { &mut m.port },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LanSyncHostRequest>(
+                "LanSyncHostRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LanSyncHostRequest {
+        static instance: ::protobuf::rt::LazyV2<LanSyncHostRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LanSyncHostRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for LanSyncHostRequest {
+    fn clear(&mut self) {
+        self.port = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LanSyncHostRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LanSyncHostRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LanSyncPeerRequest {
+    // message fields
+    pub host_addr: ::std::string::String,
+    pub secret: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LanSyncPeerRequest {
+    fn default() -> &'a LanSyncPeerRequest {
+        <LanSyncPeerRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LanSyncPeerRequest {
+    pub fn new() -> LanSyncPeerRequest {
+        ::std::default::Default::default()
+    }
+
+    // string host_addr = 1;
+
+
+    pub fn get_host_addr(&self) -> &str {
+        &self.host_addr
+    }
+    pub fn clear_host_addr(&mut self) {
+        self.host_addr.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_host_addr(&mut self, v: ::std::string::String) {
+        self.host_addr = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_host_addr(&mut self) -> &mut ::std::string::String {
+        &mut self.host_addr
+    }
+
+    // Take field
+    pub fn take_host_addr(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.host_addr, ::std::string::String::new())
+    }
+
+    // string secret = 2;
+
+
+    pub fn get_secret(&self) -> &str {
+        &self.secret
+    }
+    pub fn clear_secret(&mut self) {
+        self.secret.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_secret(&mut self, v: ::std::string::String) {
+        self.secret = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_secret(&mut self) -> &mut ::std::string::String {
+        &mut self.secret
+    }
+
+    // Take field
+    pub fn take_secret(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.secret, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for LanSyncPeerRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.host_addr)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.secret)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.host_addr.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.host_addr);
+        }
+        if !self.secret.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.secret);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.host_addr.is_empty() {
+            os.write_string(1, &self.host_addr)?;
+        }
+        if !self.secret.is_empty() {
+            os.write_string(2, &self.secret)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LanSyncPeerRequest {
+        LanSyncPeerRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "host_addr",
+                |m: &LanSyncPeerRequest| { &m.host_addr },
+                |m: &mut LanSyncPeerRequest| { &mut m.host_addr },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "secret",
+                |m: &LanSyncPeerRequest| { &m.secret },
+                |m: &mut LanSyncPeerRequest| { &mut m.secret },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LanSyncPeerRequest>(
+                "LanSyncPeerRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LanSyncPeerRequest {
+        static instance: ::protobuf::rt::LazyV2<LanSyncPeerRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LanSyncPeerRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for LanSyncPeerRequest {
+    fn clear(&mut self) {
+        self.host_addr.clear();
+        self.secret.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LanSyncPeerRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LanSyncPeerRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum LanSyncRole {
+    LanSyncDisabled = 0,
+    LanSyncHost = 1,
+    LanSyncPeer = 2,
+}
+
+impl ::protobuf::ProtobufEnum for LanSyncRole {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<LanSyncRole> {
+        match value {
+            0 => ::std::option::Option::Some(LanSyncRole::LanSyncDisabled),
+            1 => ::std::option::Option::Some(LanSyncRole::LanSyncHost),
+            2 => ::std::option::Option::Some(LanSyncRole::LanSyncPeer),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [LanSyncRole] = &[
+            LanSyncRole::LanSyncDisabled,
+            LanSyncRole::LanSyncHost,
+            LanSyncRole::LanSyncPeer,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<LanSyncRole>("LanSyncRole", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for LanSyncRole {
+}
+
+impl ::std::default::Default for LanSyncRole {
+    fn default() -> Self {
+        LanSyncRole::LanSyncDisabled
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LanSyncRole {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0elan_sync.proto\"\x84\x01\n\x0eLanSyncSetting\x12\x20\n\x04role\x18\
+    \x01\x20\x01(\x0e2\x0c.LanSyncRoleR\x04role\x12\x1b\n\thost_addr\x18\x02\
+    \x20\x01(\tR\x08hostAddr\x12\x16\n\x06secret\x18\x03\x20\x01(\tR\x06secr\
+    et\x12\x1b\n\tis_active\x18\x04\x20\x01(\x08R\x08isActive\"(\n\x12LanSyn\
+    cHostRequest\x12\x12\n\x04port\x18\x01\x20\x01(\x05R\x04port\"I\n\x12Lan\
+    SyncPeerRequest\x12\x1b\n\thost_addr\x18\x01\x20\x01(\tR\x08hostAddr\x12\
+    \x16\n\x06secret\x18\x02\x20\x01(\tR\x06secret*D\n\x0bLanSyncRole\x12\
+    \x13\n\x0fLanSyncDisabled\x10\0\x12\x0f\n\x0bLanSyncHost\x10\x01\x12\x0f\
+    \n\x0bLanSyncPeer\x10\x02J\xee\x04\n\x06\x12\x04\0\0\x13\x01\n\x08\n\x01\
+    \x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x07\x01\n\n\n\x03\x04\
+    \0\x01\x12\x03\x02\x08\x16\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x19\n\
+    \x0c\n\x05\x04\0\x02\0\x06\x12\x03\x03\x04\x0f\n\x0c\n\x05\x04\0\x02\0\
+    \x01\x12\x03\x03\x10\x14\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x17\x18\
+    \n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x19\n\x0c\n\x05\x04\0\x02\x01\
+    \x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\x14\
+    \n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x17\x18\n\x0b\n\x04\x04\0\x02\
+    \x02\x12\x03\x05\x04\x16\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\n\
+    \n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x0b\x11\n\x0c\n\x05\x04\0\x02\
+    \x02\x03\x12\x03\x05\x14\x15\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x04\
+    \x17\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x04\x08\n\x0c\n\x05\x04\0\
+    \x02\x03\x01\x12\x03\x06\t\x12\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x06\
+    \x15\x16\n\n\n\x02\x04\x01\x12\x04\x08\0\n\x01\n\n\n\x03\x04\x01\x01\x12\
+    \x03\x08\x08\x1a\n\x0b\n\x04\x04\x01\x02\0\x12\x03\t\x04\x13\n\x0c\n\x05\
+    \x04\x01\x02\0\x05\x12\x03\t\x04\t\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\
+    \t\n\x0e\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\t\x11\x12\n\n\n\x02\x04\
+    \x02\x12\x04\x0b\0\x0e\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0b\x08\x1a\n\
+    \x0b\n\x04\x04\x02\x02\0\x12\x03\x0c\x04\x19\n\x0c\n\x05\x04\x02\x02\0\
+    \x05\x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0c\x0b\x14\
+    \n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x0c\x17\x18\n\x0b\n\x04\x04\x02\
+    \x02\x01\x12\x03\r\x04\x16\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\r\x04\
+    \n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\r\x0b\x11\n\x0c\n\x05\x04\x02\
+    \x02\x01\x03\x12\x03\r\x14\x15\n\n\n\x02\x05\0\x12\x04\x0f\0\x13\x01\n\n\
+    \n\x03\x05\0\x01\x12\x03\x0f\x05\x10\n\x0b\n\x04\x05\0\x02\0\x12\x03\x10\
+    \x04\x18\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x10\x04\x13\n\x0c\n\x05\x05\
+    \0\x02\0\x02\x12\x03\x10\x16\x17\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x11\
+    \x04\x14\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x11\x04\x0f\n\x0c\n\x05\
+    \x05\0\x02\x01\x02\x12\x03\x11\x12\x13\n\x0b\n\x04\x05\0\x02\x02\x12\x03\
+    \x12\x04\x14\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x12\x04\x0f\n\x0c\n\
+    \x05\x05\0\x02\x02\x02\x12\x03\x12\x12\x13b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -6,3 +6,12 @@ pub use network_state::*;
 
 mod event;
 pub use event::*;
+
+mod sync_status;
+pub use sync_status::*;
+
+mod dart_notification;
+pub use dart_notification::*;
+
+mod lan_sync;
+pub use lan_sync::*;
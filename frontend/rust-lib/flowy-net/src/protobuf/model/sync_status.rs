@@ -0,0 +1,1301 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `sync_status.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SyncObjectId {
+    // message fields
+    pub object_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SyncObjectId {
+    fn default() -> &'a SyncObjectId {
+        <SyncObjectId as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncObjectId {
+    pub fn new() -> SyncObjectId {
+        ::std::default::Default::default()
+    }
+
+    // string object_id = 1;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for SyncObjectId {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.object_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.object_id.is_empty() {
+            os.write_string(1, &self.object_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SyncObjectId {
+        SyncObjectId::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &SyncObjectId| { &m.object_id },
+                |m: &mut SyncObjectId| { &mut m.object_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SyncObjectId>(
+                "SyncObjectId",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SyncObjectId {
+        static instance: ::protobuf::rt::LazyV2<SyncObjectId> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SyncObjectId::new)
+    }
+}
+
+impl ::protobuf::Clear for SyncObjectId {
+    fn clear(&mut self) {
+        self.object_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SyncObjectId {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncObjectId {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ObjectSyncStatus {
+    // message fields
+    pub object_id: ::std::string::String,
+    pub pending_count: i64,
+    pub last_acked_rev_id: i64,
+    pub last_sync_time: i64,
+    pub connect_state: SyncConnectState,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ObjectSyncStatus {
+    fn default() -> &'a ObjectSyncStatus {
+        <ObjectSyncStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ObjectSyncStatus {
+    pub fn new() -> ObjectSyncStatus {
+        ::std::default::Default::default()
+    }
+
+    // string object_id = 1;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
+
+    // int64 pending_count = 2;
+
+
+    pub fn get_pending_count(&self) -> i64 {
+        self.pending_count
+    }
+    pub fn clear_pending_count(&mut self) {
+        self.pending_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pending_count(&mut self, v: i64) {
+        self.pending_count = v;
+    }
+
+    // int64 last_acked_rev_id = 3;
+
+
+    pub fn get_last_acked_rev_id(&self) -> i64 {
+        self.last_acked_rev_id
+    }
+    pub fn clear_last_acked_rev_id(&mut self) {
+        self.last_acked_rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_acked_rev_id(&mut self, v: i64) {
+        self.last_acked_rev_id = v;
+    }
+
+    // int64 last_sync_time = 4;
+
+
+    pub fn get_last_sync_time(&self) -> i64 {
+        self.last_sync_time
+    }
+    pub fn clear_last_sync_time(&mut self) {
+        self.last_sync_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_sync_time(&mut self, v: i64) {
+        self.last_sync_time = v;
+    }
+
+    // .SyncConnectState connect_state = 5;
+
+
+    pub fn get_connect_state(&self) -> SyncConnectState {
+        self.connect_state
+    }
+    pub fn clear_connect_state(&mut self) {
+        self.connect_state = SyncConnectState::SyncInit;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_connect_state(&mut self, v: SyncConnectState) {
+        self.connect_state = v;
+    }
+}
+
+impl ::protobuf::Message for ObjectSyncStatus {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.pending_count = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.last_acked_rev_id = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.last_sync_time = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.connect_state, 5, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.object_id);
+        }
+        if self.pending_count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.pending_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.last_acked_rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.last_acked_rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.last_sync_time != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.last_sync_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.connect_state != SyncConnectState::SyncInit {
+            my_size += ::protobuf::rt::enum_size(5, self.connect_state);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.object_id.is_empty() {
+            os.write_string(1, &self.object_id)?;
+        }
+        if self.pending_count != 0 {
+            os.write_int64(2, self.pending_count)?;
+        }
+        if self.last_acked_rev_id != 0 {
+            os.write_int64(3, self.last_acked_rev_id)?;
+        }
+        if self.last_sync_time != 0 {
+            os.write_int64(4, self.last_sync_time)?;
+        }
+        if self.connect_state != SyncConnectState::SyncInit {
+            os.write_enum(5, ::protobuf::ProtobufEnum::value(&self.connect_state))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ObjectSyncStatus {
+        ObjectSyncStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &ObjectSyncStatus| { &m.object_id },
+                |m: &mut ObjectSyncStatus| { &mut m.object_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "pending_count",
+                |m: &ObjectSyncStatus| { &m.pending_count },
+                |m: &mut ObjectSyncStatus| { &mut m.pending_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "last_acked_rev_id",
+                |m: &ObjectSyncStatus| { &m.last_acked_rev_id },
+                |m: &mut ObjectSyncStatus| { &mut m.last_acked_rev_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "last_sync_time",
+                |m: &ObjectSyncStatus| { &m.last_sync_time },
+                |m: &mut ObjectSyncStatus| { &mut m.last_sync_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SyncConnectState>>(
+                "connect_state",
+                |m: &ObjectSyncStatus| { &m.connect_state },
+                |m: &mut ObjectSyncStatus| { &mut m.connect_state },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ObjectSyncStatus>(
+                "ObjectSyncStatus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ObjectSyncStatus {
+        static instance: ::protobuf::rt::LazyV2<ObjectSyncStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ObjectSyncStatus::new)
+    }
+}
+
+impl ::protobuf::Clear for ObjectSyncStatus {
+    fn clear(&mut self) {
+        self.object_id.clear();
+        self.pending_count = 0;
+        self.last_acked_rev_id = 0;
+        self.last_sync_time = 0;
+        self.connect_state = SyncConnectState::SyncInit;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ObjectSyncStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ObjectSyncStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ObjectMergeSummary {
+    // message fields
+    pub object_id: ::std::string::String,
+    pub local_revision_count: i64,
+    pub remote_revision_count: i64,
+    pub merged_rev_id: i64,
+    pub has_backup: bool,
+    // message oneof groups
+    pub one_of_error: ::std::option::Option<ObjectMergeSummary_oneof_one_of_error>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ObjectMergeSummary {
+    fn default() -> &'a ObjectMergeSummary {
+        <ObjectMergeSummary as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ObjectMergeSummary_oneof_one_of_error {
+    error(::std::string::String),
+}
+
+impl ObjectMergeSummary {
+    pub fn new() -> ObjectMergeSummary {
+        ::std::default::Default::default()
+    }
+
+    // string object_id = 1;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
+
+    // int64 local_revision_count = 2;
+
+
+    pub fn get_local_revision_count(&self) -> i64 {
+        self.local_revision_count
+    }
+    pub fn clear_local_revision_count(&mut self) {
+        self.local_revision_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_local_revision_count(&mut self, v: i64) {
+        self.local_revision_count = v;
+    }
+
+    // int64 remote_revision_count = 3;
+
+
+    pub fn get_remote_revision_count(&self) -> i64 {
+        self.remote_revision_count
+    }
+    pub fn clear_remote_revision_count(&mut self) {
+        self.remote_revision_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remote_revision_count(&mut self, v: i64) {
+        self.remote_revision_count = v;
+    }
+
+    // int64 merged_rev_id = 4;
+
+
+    pub fn get_merged_rev_id(&self) -> i64 {
+        self.merged_rev_id
+    }
+    pub fn clear_merged_rev_id(&mut self) {
+        self.merged_rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_merged_rev_id(&mut self, v: i64) {
+        self.merged_rev_id = v;
+    }
+
+    // bool has_backup = 5;
+
+
+    pub fn get_has_backup(&self) -> bool {
+        self.has_backup
+    }
+    pub fn clear_has_backup(&mut self) {
+        self.has_backup = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_has_backup(&mut self, v: bool) {
+        self.has_backup = v;
+    }
+
+    // string error = 6;
+
+
+    pub fn get_error(&self) -> &str {
+        match self.one_of_error {
+            ::std::option::Option::Some(ObjectMergeSummary_oneof_one_of_error::error(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_error(&mut self) {
+        self.one_of_error = ::std::option::Option::None;
+    }
+
+    pub fn has_error(&self) -> bool {
+        match self.one_of_error {
+            ::std::option::Option::Some(ObjectMergeSummary_oneof_one_of_error::error(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.one_of_error = ::std::option::Option::Some(ObjectMergeSummary_oneof_one_of_error::error(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ObjectMergeSummary_oneof_one_of_error::error(_)) = self.one_of_error {
+        } else {
+            self.one_of_error = ::std::option::Option::Some(ObjectMergeSummary_oneof_one_of_error::error(::std::string::String::new()));
+        }
+        match self.one_of_error {
+            ::std::option::Option::Some(ObjectMergeSummary_oneof_one_of_error::error(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        if self.has_error() {
+            match self.one_of_error.take() {
+                ::std::option::Option::Some(ObjectMergeSummary_oneof_one_of_error::error(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for ObjectMergeSummary {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.local_revision_count = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.remote_revision_count = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.merged_rev_id = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.has_backup = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_error = ::std::option::Option::Some(ObjectMergeSummary_oneof_one_of_error::error(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.object_id);
+        }
+        if self.local_revision_count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.local_revision_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.remote_revision_count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.remote_revision_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.merged_rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.merged_rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.has_backup != false {
+            my_size += 2;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_error {
+            match v {
+                &ObjectMergeSummary_oneof_one_of_error::error(ref v) => {
+                    my_size += ::protobuf::rt::string_size(6, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.object_id.is_empty() {
+            os.write_string(1, &self.object_id)?;
+        }
+        if self.local_revision_count != 0 {
+            os.write_int64(2, self.local_revision_count)?;
+        }
+        if self.remote_revision_count != 0 {
+            os.write_int64(3, self.remote_revision_count)?;
+        }
+        if self.merged_rev_id != 0 {
+            os.write_int64(4, self.merged_rev_id)?;
+        }
+        if self.has_backup != false {
+            os.write_bool(5, self.has_backup)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_error {
+            match v {
+                &ObjectMergeSummary_oneof_one_of_error::error(ref v) => {
+                    os.write_string(6, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ObjectMergeSummary {
+        ObjectMergeSummary::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &ObjectMergeSummary| { &m.object_id },
+                |m: &mut ObjectMergeSummary| { &mut m.object_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "local_revision_count",
+                |m: &ObjectMergeSummary| { &m.local_revision_count },
+                |m: &mut ObjectMergeSummary| { &mut m.local_revision_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "remote_revision_count",
+                |m: &ObjectMergeSummary| { &m.remote_revision_count },
+                |m: &mut ObjectMergeSummary| { &mut m.remote_revision_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "merged_rev_id",
+                |m: &ObjectMergeSummary| { &m.merged_rev_id },
+                |m: &mut ObjectMergeSummary| { &mut m.merged_rev_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "has_backup",
+                |m: &ObjectMergeSummary| { &m.has_backup },
+                |m: &mut ObjectMergeSummary| { &mut m.has_backup },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "error",
+                ObjectMergeSummary::has_error,
+                ObjectMergeSummary::get_error,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ObjectMergeSummary>(
+                "ObjectMergeSummary",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ObjectMergeSummary {
+        static instance: ::protobuf::rt::LazyV2<ObjectMergeSummary> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ObjectMergeSummary::new)
+    }
+}
+
+impl ::protobuf::Clear for ObjectMergeSummary {
+    fn clear(&mut self) {
+        self.object_id.clear();
+        self.local_revision_count = 0;
+        self.remote_revision_count = 0;
+        self.merged_rev_id = 0;
+        self.has_backup = false;
+        self.one_of_error = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ObjectMergeSummary {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ObjectMergeSummary {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SyncStatus {
+    // message fields
+    pub pending_count: i64,
+    pub pending_object_count: i64,
+    pub last_sync_time: i64,
+    pub connect_state: SyncConnectState,
+    pub is_synced: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SyncStatus {
+    fn default() -> &'a SyncStatus {
+        <SyncStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncStatus {
+    pub fn new() -> SyncStatus {
+        ::std::default::Default::default()
+    }
+
+    // int64 pending_count = 1;
+
+
+    pub fn get_pending_count(&self) -> i64 {
+        self.pending_count
+    }
+    pub fn clear_pending_count(&mut self) {
+        self.pending_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pending_count(&mut self, v: i64) {
+        self.pending_count = v;
+    }
+
+    // int64 pending_object_count = 2;
+
+
+    pub fn get_pending_object_count(&self) -> i64 {
+        self.pending_object_count
+    }
+    pub fn clear_pending_object_count(&mut self) {
+        self.pending_object_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pending_object_count(&mut self, v: i64) {
+        self.pending_object_count = v;
+    }
+
+    // int64 last_sync_time = 3;
+
+
+    pub fn get_last_sync_time(&self) -> i64 {
+        self.last_sync_time
+    }
+    pub fn clear_last_sync_time(&mut self) {
+        self.last_sync_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_sync_time(&mut self, v: i64) {
+        self.last_sync_time = v;
+    }
+
+    // .SyncConnectState connect_state = 4;
+
+
+    pub fn get_connect_state(&self) -> SyncConnectState {
+        self.connect_state
+    }
+    pub fn clear_connect_state(&mut self) {
+        self.connect_state = SyncConnectState::SyncInit;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_connect_state(&mut self, v: SyncConnectState) {
+        self.connect_state = v;
+    }
+
+    // bool is_synced = 5;
+
+
+    pub fn get_is_synced(&self) -> bool {
+        self.is_synced
+    }
+    pub fn clear_is_synced(&mut self) {
+        self.is_synced = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_synced(&mut self, v: bool) {
+        self.is_synced = v;
+    }
+}
+
+impl ::protobuf::Message for SyncStatus {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.pending_count = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.pending_object_count = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.last_sync_time = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.connect_state, 4, &mut self.unknown_fields)?
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_synced = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.pending_count != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.pending_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.pending_object_count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.pending_object_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.last_sync_time != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.last_sync_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.connect_state != SyncConnectState::SyncInit {
+            my_size += ::protobuf::rt::enum_size(4, self.connect_state);
+        }
+        if self.is_synced != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.pending_count != 0 {
+            os.write_int64(1, self.pending_count)?;
+        }
+        if self.pending_object_count != 0 {
+            os.write_int64(2, self.pending_object_count)?;
+        }
+        if self.last_sync_time != 0 {
+            os.write_int64(3, self.last_sync_time)?;
+        }
+        if self.connect_state != SyncConnectState::SyncInit {
+            os.write_enum(4, ::protobuf::ProtobufEnum::value(&self.connect_state))?;
+        }
+        if self.is_synced != false {
+            os.write_bool(5, self.is_synced)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SyncStatus {
+        SyncStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "pending_count",
+                |m: &SyncStatus| { &m.pending_count },
+                |m: &mut SyncStatus| { &mut m.pending_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "pending_object_count",
+                |m: &SyncStatus| { &m.pending_object_count },
+                |m: &mut SyncStatus| { &mut m.pending_object_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "last_sync_time",
+                |m: &SyncStatus| { &m.last_sync_time },
+                |m: &mut SyncStatus| { &mut m.last_sync_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SyncConnectState>>(
+                "connect_state",
+                |m: &SyncStatus| { &m.connect_state },
+                |m: &mut SyncStatus| { &mut m.connect_state },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_synced",
+                |m: &SyncStatus| { &m.is_synced },
+                |m: &mut SyncStatus| { &mut m.is_synced },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SyncStatus>(
+                "SyncStatus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SyncStatus {
+        static instance: ::protobuf::rt::LazyV2<SyncStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SyncStatus::new)
+    }
+}
+
+impl ::protobuf::Clear for SyncStatus {
+    fn clear(&mut self) {
+        self.pending_count = 0;
+        self.pending_object_count = 0;
+        self.last_sync_time = 0;
+        self.connect_state = SyncConnectState::SyncInit;
+        self.is_synced = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SyncStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum SyncConnectState {
+    SyncInit = 0,
+    SyncConnecting = 1,
+    SyncConnected = 2,
+    SyncDisconnected = 3,
+}
+
+impl ::protobuf::ProtobufEnum for SyncConnectState {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<SyncConnectState> {
+        match value {
+            0 => ::std::option::Option::Some(SyncConnectState::SyncInit),
+            1 => ::std::option::Option::Some(SyncConnectState::SyncConnecting),
+            2 => ::std::option::Option::Some(SyncConnectState::SyncConnected),
+            3 => ::std::option::Option::Some(SyncConnectState::SyncDisconnected),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [SyncConnectState] = &[
+            SyncConnectState::SyncInit,
+            SyncConnectState::SyncConnecting,
+            SyncConnectState::SyncConnected,
+            SyncConnectState::SyncDisconnected,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<SyncConnectState>("SyncConnectState", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for SyncConnectState {
+}
+
+impl ::std::default::Default for SyncConnectState {
+    fn default() -> Self {
+        SyncConnectState::SyncInit
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncConnectState {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x11sync_status.proto\"+\n\x0cSyncObjectId\x12\x1b\n\tobject_id\x18\
+    \x01\x20\x01(\tR\x08objectId\"\xdd\x01\n\x10ObjectSyncStatus\x12\x1b\n\t\
+    object_id\x18\x01\x20\x01(\tR\x08objectId\x12#\n\rpending_count\x18\x02\
+    \x20\x01(\x03R\x0cpendingCount\x12)\n\x11last_acked_rev_id\x18\x03\x20\
+    \x01(\x03R\x0elastAckedRevId\x12$\n\x0elast_sync_time\x18\x04\x20\x01(\
+    \x03R\x0clastSyncTime\x126\n\rconnect_state\x18\x05\x20\x01(\x0e2\x11.Sy\
+    ncConnectStateR\x0cconnectState\"\x82\x02\n\x12ObjectMergeSummary\x12\
+    \x1b\n\tobject_id\x18\x01\x20\x01(\tR\x08objectId\x120\n\x14local_revisi\
+    on_count\x18\x02\x20\x01(\x03R\x12localRevisionCount\x122\n\x15remote_re\
+    vision_count\x18\x03\x20\x01(\x03R\x13remoteRevisionCount\x12\"\n\rmerge\
+    d_rev_id\x18\x04\x20\x01(\x03R\x0bmergedRevId\x12\x1d\n\nhas_backup\x18\
+    \x05\x20\x01(\x08R\thasBackup\x12\x16\n\x05error\x18\x06\x20\x01(\tH\0R\
+    \x05errorB\x0e\n\x0cone_of_error\"\xde\x01\n\nSyncStatus\x12#\n\rpending\
+    _count\x18\x01\x20\x01(\x03R\x0cpendingCount\x120\n\x14pending_object_co\
+    unt\x18\x02\x20\x01(\x03R\x12pendingObjectCount\x12$\n\x0elast_sync_time\
+    \x18\x03\x20\x01(\x03R\x0clastSyncTime\x126\n\rconnect_state\x18\x04\x20\
+    \x01(\x0e2\x11.SyncConnectStateR\x0cconnectState\x12\x1b\n\tis_synced\
+    \x18\x05\x20\x01(\x08R\x08isSynced*]\n\x10SyncConnectState\x12\x0c\n\x08\
+    SyncInit\x10\0\x12\x12\n\x0eSyncConnecting\x10\x01\x12\x11\n\rSyncConnec\
+    ted\x10\x02\x12\x14\n\x10SyncDisconnected\x10\x03J\xf0\t\n\x06\x12\x04\0\
+    \0\x20\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\
+    \x04\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x14\n\x0b\n\x04\x04\0\x02\0\
+    \x12\x03\x03\x04\x19\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\
+    \n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x14\n\x0c\n\x05\x04\0\x02\0\x03\
+    \x12\x03\x03\x17\x18\n\n\n\x02\x04\x01\x12\x04\x05\0\x0b\x01\n\n\n\x03\
+    \x04\x01\x01\x12\x03\x05\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x06\
+    \x04\x19\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\
+    \x01\x02\0\x01\x12\x03\x06\x0b\x14\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\
+    \x06\x17\x18\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x07\x04\x1c\n\x0c\n\x05\
+    \x04\x01\x02\x01\x05\x12\x03\x07\x04\t\n\x0c\n\x05\x04\x01\x02\x01\x01\
+    \x12\x03\x07\n\x17\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x07\x1a\x1b\n\
+    \x0b\n\x04\x04\x01\x02\x02\x12\x03\x08\x04\x20\n\x0c\n\x05\x04\x01\x02\
+    \x02\x05\x12\x03\x08\x04\t\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x08\n\
+    \x1b\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x08\x1e\x1f\n\x0b\n\x04\x04\
+    \x01\x02\x03\x12\x03\t\x04\x1d\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\t\
+    \x04\t\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\t\n\x18\n\x0c\n\x05\x04\
+    \x01\x02\x03\x03\x12\x03\t\x1b\x1c\n\x0b\n\x04\x04\x01\x02\x04\x12\x03\n\
+    \x04'\n\x0c\n\x05\x04\x01\x02\x04\x06\x12\x03\n\x04\x14\n\x0c\n\x05\x04\
+    \x01\x02\x04\x01\x12\x03\n\x15\"\n\x0c\n\x05\x04\x01\x02\x04\x03\x12\x03\
+    \n%&\n\n\n\x02\x04\x02\x12\x04\x0c\0\x13\x01\n\n\n\x03\x04\x02\x01\x12\
+    \x03\x0c\x08\x1a\n\x0b\n\x04\x04\x02\x02\0\x12\x03\r\x04\x19\n\x0c\n\x05\
+    \x04\x02\x02\0\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\
+    \r\x0b\x14\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\r\x17\x18\n\x0b\n\x04\
+    \x04\x02\x02\x01\x12\x03\x0e\x04#\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\
+    \x03\x0e\x04\t\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x0e\n\x1e\n\x0c\n\
+    \x05\x04\x02\x02\x01\x03\x12\x03\x0e!\"\n\x0b\n\x04\x04\x02\x02\x02\x12\
+    \x03\x0f\x04$\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x0f\x04\t\n\x0c\n\
+    \x05\x04\x02\x02\x02\x01\x12\x03\x0f\n\x1f\n\x0c\n\x05\x04\x02\x02\x02\
+    \x03\x12\x03\x0f\"#\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\x10\x04\x1c\n\
+    \x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x10\x04\t\n\x0c\n\x05\x04\x02\x02\
+    \x03\x01\x12\x03\x10\n\x17\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x10\
+    \x1a\x1b\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x11\x04\x18\n\x0c\n\x05\x04\
+    \x02\x02\x04\x05\x12\x03\x11\x04\x08\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\
+    \x03\x11\t\x13\n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\x11\x16\x17\n\x0b\
+    \n\x04\x04\x02\x08\0\x12\x03\x12\x04,\n\x0c\n\x05\x04\x02\x08\0\x01\x12\
+    \x03\x12\n\x16\n\x0b\n\x04\x04\x02\x02\x05\x12\x03\x12\x19*\n\x0c\n\x05\
+    \x04\x02\x02\x05\x05\x12\x03\x12\x19\x1f\n\x0c\n\x05\x04\x02\x02\x05\x01\
+    \x12\x03\x12\x20%\n\x0c\n\x05\x04\x02\x02\x05\x03\x12\x03\x12()\n\n\n\
+    \x02\x04\x03\x12\x04\x14\0\x1a\x01\n\n\n\x03\x04\x03\x01\x12\x03\x14\x08\
+    \x12\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x15\x04\x1c\n\x0c\n\x05\x04\x03\
+    \x02\0\x05\x12\x03\x15\x04\t\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x15\n\
+    \x17\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x15\x1a\x1b\n\x0b\n\x04\x04\
+    \x03\x02\x01\x12\x03\x16\x04#\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\
+    \x16\x04\t\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x16\n\x1e\n\x0c\n\x05\
+    \x04\x03\x02\x01\x03\x12\x03\x16!\"\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\
+    \x17\x04\x1d\n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x17\x04\t\n\x0c\n\
+    \x05\x04\x03\x02\x02\x01\x12\x03\x17\n\x18\n\x0c\n\x05\x04\x03\x02\x02\
+    \x03\x12\x03\x17\x1b\x1c\n\x0b\n\x04\x04\x03\x02\x03\x12\x03\x18\x04'\n\
+    \x0c\n\x05\x04\x03\x02\x03\x06\x12\x03\x18\x04\x14\n\x0c\n\x05\x04\x03\
+    \x02\x03\x01\x12\x03\x18\x15\"\n\x0c\n\x05\x04\x03\x02\x03\x03\x12\x03\
+    \x18%&\n\x0b\n\x04\x04\x03\x02\x04\x12\x03\x19\x04\x17\n\x0c\n\x05\x04\
+    \x03\x02\x04\x05\x12\x03\x19\x04\x08\n\x0c\n\x05\x04\x03\x02\x04\x01\x12\
+    \x03\x19\t\x12\n\x0c\n\x05\x04\x03\x02\x04\x03\x12\x03\x19\x15\x16\n\n\n\
+    \x02\x05\0\x12\x04\x1b\0\x20\x01\n\n\n\x03\x05\0\x01\x12\x03\x1b\x05\x15\
+    \n\x0b\n\x04\x05\0\x02\0\x12\x03\x1c\x04\x11\n\x0c\n\x05\x05\0\x02\0\x01\
+    \x12\x03\x1c\x04\x0c\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x1c\x0f\x10\n\
+    \x0b\n\x04\x05\0\x02\x01\x12\x03\x1d\x04\x17\n\x0c\n\x05\x05\0\x02\x01\
+    \x01\x12\x03\x1d\x04\x12\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x1d\x15\
+    \x16\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x1e\x04\x16\n\x0c\n\x05\x05\0\x02\
+    \x02\x01\x12\x03\x1e\x04\x11\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x1e\
+    \x14\x15\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x1f\x04\x19\n\x0c\n\x05\x05\0\
+    \x02\x03\x01\x12\x03\x1f\x04\x14\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\
+    \x1f\x17\x18b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
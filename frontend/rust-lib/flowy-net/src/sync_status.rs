@@ -0,0 +1,177 @@
+use crate::{
+    dart_notification::{dart_notify, NetworkNotification},
+    entities::{ObjectMergeSummary, ObjectSyncStatus, SyncConnectState, SyncStatus},
+    ws::connection::FlowyWebSocketConnect,
+};
+use flowy_database::ConnectionPool;
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_sync::{
+    mk_revision_disk_cache, read_all_sync_status, read_sync_status, subscribe_merge_summary, subscribe_sync_status,
+    RevisionSyncStatus,
+};
+use parking_lot::RwLock;
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::broadcast::error::RecvError;
+
+pub trait SyncStatusUser: Send + Sync {
+    fn user_id(&self) -> Result<String, FlowyError>;
+    fn db_pool(&self) -> Result<Arc<ConnectionPool>, FlowyError>;
+}
+
+// The pending revisions of the objects that are not opened are read from the rev_table. They are
+// cached and only read again when the user is changed or the sync status is read by the event,
+// because the objects that are opened report their status through the RevisionManager.
+#[derive(Default)]
+struct DiskPendingCounts {
+    user_id: String,
+    counts: HashMap<String, i64>,
+}
+
+pub struct SyncStatusController {
+    user: Arc<dyn SyncStatusUser>,
+    ws_conn: Arc<FlowyWebSocketConnect>,
+    connect_state: RwLock<SyncConnectState>,
+    disk_pending_counts: RwLock<DiskPendingCounts>,
+    last_sync_status: RwLock<Option<SyncStatus>>,
+}
+
+impl SyncStatusController {
+    pub fn new(user: Arc<dyn SyncStatusUser>, ws_conn: Arc<FlowyWebSocketConnect>) -> Self {
+        Self {
+            user,
+            ws_conn,
+            connect_state: RwLock::new(SyncConnectState::default()),
+            disk_pending_counts: RwLock::new(DiskPendingCounts::default()),
+            last_sync_status: RwLock::new(None),
+        }
+    }
+
+    pub fn object_sync_status(&self, object_id: &str) -> FlowyResult<ObjectSyncStatus> {
+        let connect_state = self.connect_state.read().clone();
+        match read_sync_status(object_id) {
+            Some(status) => Ok(ObjectSyncStatus::new(status, connect_state)),
+            None => {
+                let _ = self.read_disk_pending_counts()?;
+                let pending_count = self
+                    .disk_pending_counts
+                    .read()
+                    .counts
+                    .get(object_id)
+                    .cloned()
+                    .unwrap_or(0);
+                let status = RevisionSyncStatus {
+                    object_id: object_id.to_owned(),
+                    pending_count,
+                    ..Default::default()
+                };
+                Ok(ObjectSyncStatus::new(status, connect_state))
+            }
+        }
+    }
+
+    pub fn sync_status(&self) -> FlowyResult<SyncStatus> {
+        let _ = self.read_disk_pending_counts()?;
+        Ok(self.merge_sync_status())
+    }
+
+    pub fn listen(self: &Arc<Self>) {
+        let controller = self.clone();
+        tokio::spawn(async move {
+            let mut receiver = subscribe_sync_status();
+            loop {
+                match receiver.recv().await {
+                    Ok(status) => controller.notify_object_sync_status(status),
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+
+        tokio::spawn(async move {
+            let mut receiver = subscribe_merge_summary();
+            loop {
+                match receiver.recv().await {
+                    Ok(summary) => {
+                        dart_notify(&summary.object_id, NetworkNotification::ObjectMerged)
+                            .payload(ObjectMergeSummary::from(summary))
+                            .send();
+                    }
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+
+        let controller = self.clone();
+        tokio::spawn(async move {
+            let mut receiver = controller.ws_conn.subscribe_websocket_state().await;
+            loop {
+                match receiver.recv().await {
+                    Ok(state) => {
+                        *controller.connect_state.write() = state.into();
+                        controller.notify_sync_status();
+                    }
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
+    fn notify_object_sync_status(&self, status: RevisionSyncStatus) {
+        let connect_state = self.connect_state.read().clone();
+        dart_notify(&status.object_id, NetworkNotification::ObjectSyncStatusChanged)
+            .payload(ObjectSyncStatus::new(status, connect_state))
+            .send();
+        self.notify_sync_status();
+    }
+
+    // Only notifies the sync status of all the objects when it's changed.
+    fn notify_sync_status(&self) {
+        if let Ok(user_id) = self.user.user_id() {
+            if self.disk_pending_counts.read().user_id != user_id {
+                if let Err(e) = self.read_disk_pending_counts() {
+                    tracing::error!("Read the pending revisions failed: {:?}", e);
+                }
+            }
+        }
+
+        let sync_status = self.merge_sync_status();
+        let mut last_sync_status = self.last_sync_status.write();
+        if last_sync_status.as_ref() == Some(&sync_status) {
+            return;
+        }
+        *last_sync_status = Some(sync_status.clone());
+        dart_notify("", NetworkNotification::SyncStatusChanged)
+            .payload(sync_status)
+            .send();
+    }
+
+    fn read_disk_pending_counts(&self) -> FlowyResult<()> {
+        let user_id = self.user.user_id()?;
+        let disk_cache = mk_revision_disk_cache(&user_id, self.user.db_pool()?);
+        let counts = disk_cache.read_pending_revision_counts()?;
+        *self.disk_pending_counts.write() = DiskPendingCounts { user_id, counts };
+        Ok(())
+    }
+
+    // The status of the opened objects takes precedence over the pending revisions in the
+    // rev_table, because their revisions are written to the rev_table later.
+    fn merge_sync_status(&self) -> SyncStatus {
+        let mut pending_counts = self.disk_pending_counts.read().counts.clone();
+        let mut last_sync_time = 0;
+        for status in read_all_sync_status() {
+            last_sync_time = last_sync_time.max(status.last_sync_time);
+            pending_counts.insert(status.object_id, status.pending_count);
+        }
+
+        let pending_count = pending_counts.values().sum::<i64>();
+        SyncStatus {
+            pending_count,
+            pending_object_count: pending_counts.values().filter(|count| **count > 0).count() as i64,
+            last_sync_time,
+            connect_state: self.connect_state.read().clone(),
+            is_synced: pending_count == 0,
+        }
+    }
+}
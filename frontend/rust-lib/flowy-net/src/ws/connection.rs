@@ -43,6 +43,18 @@ impl FlowyWebSocketConnect {
         }
     }
 
+    // The handshake is sent first after each connection, see WSController::with_handshake.
+    pub fn with_handshake(addr: String, handshake: String) -> Self {
+        let ws = Arc::new(Arc::new(WSController::with_handshake(handshake)));
+        let (status_notifier, _) = broadcast::channel(10);
+        FlowyWebSocketConnect {
+            inner: ws,
+            connect_type: RwLock::new(NetworkType::default()),
+            status_notifier,
+            addr,
+        }
+    }
+
     pub fn from_local(addr: String, ws: Arc<dyn FlowyRawWebSocket>) -> Self {
         let (status_notifier, _) = broadcast::channel(10);
         FlowyWebSocketConnect {
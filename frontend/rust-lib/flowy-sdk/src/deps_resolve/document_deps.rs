@@ -4,7 +4,7 @@ use flowy_collaboration::entities::ws_data::ClientRevisionWSData;
 use flowy_database::ConnectionPool;
 use flowy_document::{
     errors::{internal_error, FlowyError},
-    DocumentCloudService, DocumentUser, FlowyDocumentManager,
+    DocumentCacheConfig, DocumentCloudService, DocumentUser, FlowyDocumentManager,
 };
 use flowy_net::{
     http_server::document::DocumentHttpCloudService, local_server::LocalServer, ws::connection::FlowyWebSocketConnect,
@@ -23,6 +23,7 @@ impl DocumentDepsResolver {
         ws_conn: Arc<FlowyWebSocketConnect>,
         user_session: Arc<UserSession>,
         server_config: &ClientServerConfiguration,
+        cache_config: DocumentCacheConfig,
     ) -> Arc<FlowyDocumentManager> {
         let user = Arc::new(DocumentUserImpl(user_session));
         let ws_sender = Arc::new(DocumentWebSocketImpl(ws_conn.clone()));
@@ -31,7 +32,7 @@ impl DocumentDepsResolver {
             Some(local_server) => local_server,
         };
 
-        let manager = Arc::new(FlowyDocumentManager::new(cloud_service, user, ws_sender));
+        let manager = Arc::new(FlowyDocumentManager::new(cloud_service, user, ws_sender, cache_config));
         let receiver = Arc::new(DocumentWSMessageReceiverImpl(manager.clone()));
         ws_conn.add_ws_message_receiver(receiver).unwrap();
 
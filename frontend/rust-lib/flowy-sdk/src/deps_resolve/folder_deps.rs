@@ -29,7 +29,10 @@ impl FolderDepsResolver {
     ) -> Arc<FolderManager> {
         let user: Arc<dyn WorkspaceUser> = Arc::new(WorkspaceUserImpl(user_session.clone()));
         let database: Arc<dyn WorkspaceDatabase> = Arc::new(WorkspaceDatabaseImpl(user_session));
-        let web_socket = Arc::new(FolderWebSocketImpl(ws_conn.clone()));
+        let web_socket = Arc::new(FolderWebSocketImpl::new(WSChannel::Folder, ws_conn.clone()));
+        let grid_web_socket = Arc::new(FolderWebSocketImpl::new(WSChannel::Grid, ws_conn.clone()));
+        let board_web_socket = Arc::new(FolderWebSocketImpl::new(WSChannel::Board, ws_conn.clone()));
+        let comment_web_socket = Arc::new(FolderWebSocketImpl::new(WSChannel::Comment, ws_conn.clone()));
         let cloud_service: Arc<dyn FolderCouldServiceV1> = match local_server {
             None => Arc::new(CoreHttpCloudService::new(server_config.clone())),
             Some(local_server) => local_server,
@@ -42,6 +45,9 @@ impl FolderDepsResolver {
                 database,
                 document_manager.clone(),
                 web_socket,
+                grid_web_socket,
+                board_web_socket,
+                comment_web_socket,
             )
             .await,
         );
@@ -53,8 +59,13 @@ impl FolderDepsResolver {
             }
         }
 
-        let receiver = Arc::new(FolderWSMessageReceiverImpl(folder_manager.clone()));
-        ws_conn.add_ws_message_receiver(receiver).unwrap();
+        for channel in [WSChannel::Folder, WSChannel::Grid, WSChannel::Board, WSChannel::Comment] {
+            let receiver = Arc::new(FolderWSMessageReceiverImpl {
+                channel,
+                folder_manager: folder_manager.clone(),
+            });
+            ws_conn.add_ws_message_receiver(receiver).unwrap();
+        }
 
         folder_manager
     }
@@ -76,18 +87,34 @@ impl WorkspaceUser for WorkspaceUserImpl {
     fn token(&self) -> Result<String, FlowyError> {
         self.0.token().map_err(|e| FlowyError::internal().context(e))
     }
+
+    fn user_name(&self) -> Result<String, FlowyError> {
+        self.0.user_name().map_err(|e| FlowyError::internal().context(e))
+    }
+}
+
+// The folder, the grids, the boards and the comments are synced through the same connection, the
+// messages of them are distinguished by the channel.
+struct FolderWebSocketImpl {
+    channel: WSChannel,
+    ws_conn: Arc<FlowyWebSocketConnect>,
+}
+
+impl FolderWebSocketImpl {
+    fn new(channel: WSChannel, ws_conn: Arc<FlowyWebSocketConnect>) -> Self {
+        Self { channel, ws_conn }
+    }
 }
 
-struct FolderWebSocketImpl(Arc<FlowyWebSocketConnect>);
 impl RevisionWebSocket for FolderWebSocketImpl {
     fn send(&self, data: ClientRevisionWSData) -> BoxResultFuture<(), FlowyError> {
         let bytes: Bytes = data.try_into().unwrap();
         let msg = WebSocketRawMessage {
-            channel: WSChannel::Folder,
+            channel: self.channel.clone(),
             data: bytes.to_vec(),
         };
 
-        let ws_conn = self.0.clone();
+        let ws_conn = self.ws_conn.clone();
         Box::pin(async move {
             match ws_conn.web_socket().await? {
                 None => {}
@@ -100,20 +127,31 @@ impl RevisionWebSocket for FolderWebSocketImpl {
     }
 
     fn subscribe_state_changed(&self) -> BoxFuture<WSStateReceiver> {
-        let ws_conn = self.0.clone();
+        let ws_conn = self.ws_conn.clone();
         Box::pin(async move { ws_conn.subscribe_websocket_state().await })
     }
 }
 
-struct FolderWSMessageReceiverImpl(Arc<FolderManager>);
+struct FolderWSMessageReceiverImpl {
+    channel: WSChannel,
+    folder_manager: Arc<FolderManager>,
+}
+
 impl WSMessageReceiver for FolderWSMessageReceiverImpl {
     fn source(&self) -> WSChannel {
-        WSChannel::Folder
+        self.channel.clone()
     }
     fn receive_message(&self, msg: WebSocketRawMessage) {
-        let handler = self.0.clone();
+        let handler = self.folder_manager.clone();
+        let channel = self.channel.clone();
         tokio::spawn(async move {
-            handler.did_receive_ws_data(Bytes::from(msg.data)).await;
+            let data = Bytes::from(msg.data);
+            match channel {
+                WSChannel::Grid => handler.did_receive_grid_ws_data(data).await,
+                WSChannel::Board => handler.did_receive_board_ws_data(data).await,
+                WSChannel::Comment => handler.did_receive_comment_ws_data(data).await,
+                _ => handler.did_receive_ws_data(data).await,
+            }
         });
     }
 }
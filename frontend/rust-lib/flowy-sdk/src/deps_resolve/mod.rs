@@ -1,7 +1,9 @@
 mod document_deps;
 mod folder_deps;
+mod net_deps;
 mod user_deps;
 
 pub use document_deps::*;
 pub use folder_deps::*;
+pub use net_deps::*;
 pub use user_deps::*;
@@ -0,0 +1,27 @@
+use flowy_core::errors::FlowyError;
+use flowy_database::ConnectionPool;
+use flowy_net::{
+    sync_status::{SyncStatusController, SyncStatusUser},
+    ws::connection::FlowyWebSocketConnect,
+};
+use flowy_user::services::UserSession;
+use std::sync::Arc;
+
+pub struct NetworkDepsResolver();
+impl NetworkDepsResolver {
+    pub fn resolve(ws_conn: Arc<FlowyWebSocketConnect>, user_session: Arc<UserSession>) -> Arc<SyncStatusController> {
+        let user: Arc<dyn SyncStatusUser> = Arc::new(SyncStatusUserImpl(user_session));
+        Arc::new(SyncStatusController::new(user, ws_conn))
+    }
+}
+
+struct SyncStatusUserImpl(Arc<UserSession>);
+impl SyncStatusUser for SyncStatusUserImpl {
+    fn user_id(&self) -> Result<String, FlowyError> {
+        self.0.user_id()
+    }
+
+    fn db_pool(&self) -> Result<Arc<ConnectionPool>, FlowyError> {
+        self.0.db_pool()
+    }
+}
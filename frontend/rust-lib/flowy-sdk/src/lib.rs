@@ -3,14 +3,17 @@ pub mod module;
 use crate::deps_resolve::*;
 use backend_service::configuration::ClientServerConfiguration;
 use flowy_core::{controller::FolderManager, errors::FlowyError};
+use flowy_database::DatabaseKey;
 use flowy_net::{
     entities::NetworkType,
-    local_server::LocalServer,
+    local_server::{LanSyncConfig, LanSyncController, LocalServer},
+    sync_status::SyncStatusController,
     ws::connection::{listen_on_websocket, FlowyWebSocketConnect},
 };
 use flowy_user::services::{notifier::UserStatus, UserSession, UserSessionConfig};
 use lib_dispatch::prelude::*;
 
+pub use flowy_document::DocumentCacheConfig;
 use flowy_document::FlowyDocumentManager;
 use lib_dispatch::util::tokio_default_runtime;
 use module::mk_modules;
@@ -32,6 +35,11 @@ pub struct FlowySDKConfig {
     root: String,
     log_filter: String,
     server_config: ClientServerConfiguration,
+    // The hex of the key that encrypts the kv.db, it's kept by the keychain of the platform. It also
+    // wraps the key of the user database until the user signs in. The databases are not encrypted
+    // if it's None.
+    database_key: Option<String>,
+    document_cache: DocumentCacheConfig,
 }
 
 impl fmt::Debug for FlowySDKConfig {
@@ -40,6 +48,8 @@ impl fmt::Debug for FlowySDKConfig {
             .field("name", &self.name)
             .field("root", &self.root)
             .field("server_config", &self.server_config)
+            .field("encrypt_database", &self.database_key.is_some())
+            .field("document_cache", &self.document_cache)
             .finish()
     }
 }
@@ -51,6 +61,8 @@ impl FlowySDKConfig {
             root: root.to_owned(),
             log_filter: crate_log_filter("info".to_owned()),
             server_config,
+            database_key: None,
+            document_cache: DocumentCacheConfig::default(),
         }
     }
 
@@ -58,6 +70,16 @@ impl FlowySDKConfig {
         self.log_filter = crate_log_filter(filter.to_owned());
         self
     }
+
+    pub fn database_key(mut self, database_key: Option<String>) -> Self {
+        self.database_key = database_key;
+        self
+    }
+
+    pub fn document_cache(mut self, document_cache: DocumentCacheConfig) -> Self {
+        self.document_cache = document_cache;
+        self
+    }
 }
 
 fn crate_log_filter(level: String) -> String {
@@ -94,40 +116,67 @@ pub struct FlowySDK {
 impl FlowySDK {
     pub fn new(config: FlowySDKConfig) -> Self {
         init_log(&config);
-        init_kv(&config.root);
+        init_kv(&config.root, &config.database_key);
         tracing::debug!("🔥 {:?}", config);
         let runtime = tokio_default_runtime().unwrap();
-        let (local_server, ws_conn) = mk_local_server(&config.server_config);
-        let (user_session, document_manager, folder_manager, local_server) = runtime.block_on(async {
-            let user_session = mk_user_session(&config, &local_server, &config.server_config);
-            let document_manager = DocumentDepsResolver::resolve(
-                local_server.clone(),
-                ws_conn.clone(),
-                user_session.clone(),
-                &config.server_config,
-            );
+        // The LAN sync is paired by the events, and the pairing of the Peer takes effect when the
+        // app is launched.
+        let lan_sync = LanSyncConfig::read();
+        tracing::debug!("LAN sync: {:?}", lan_sync);
+        let (local_server, ws_conn) = mk_local_server(&config.server_config, &config.root, &lan_sync);
+        let (user_session, document_manager, folder_manager, local_server, lan_sync_controller) =
+            runtime.block_on(async {
+                let user_session = mk_user_session(&config, &local_server, &config.server_config);
+                let document_manager = DocumentDepsResolver::resolve(
+                    local_server.clone(),
+                    ws_conn.clone(),
+                    user_session.clone(),
+                    &config.server_config,
+                    config.document_cache.clone(),
+                );
 
-            let folder_manager = FolderDepsResolver::resolve(
-                local_server.clone(),
-                user_session.clone(),
-                &config.server_config,
-                &document_manager,
-                ws_conn.clone(),
-            )
-            .await;
+                let folder_manager = FolderDepsResolver::resolve(
+                    local_server.clone(),
+                    user_session.clone(),
+                    &config.server_config,
+                    &document_manager,
+                    ws_conn.clone(),
+                )
+                .await;
 
-            if let Some(local_server) = local_server.as_ref() {
-                local_server.run();
-            }
-            ws_conn.init().await;
-            (user_session, document_manager, folder_manager, local_server)
-        });
+                if let Some(local_server) = local_server.as_ref() {
+                    local_server.run();
+                }
+                let lan_sync_controller = Arc::new(LanSyncController::new(local_server.clone(), lan_sync));
+                lan_sync_controller.run().await;
+                ws_conn.init().await;
+                (
+                    user_session,
+                    document_manager,
+                    folder_manager,
+                    local_server,
+                    lan_sync_controller,
+                )
+            });
 
+        let sync_status_controller = NetworkDepsResolver::resolve(ws_conn.clone(), user_session.clone());
         let dispatcher = Arc::new(EventDispatcher::construct(runtime, || {
-            mk_modules(&ws_conn, &folder_manager, &user_session)
+            mk_modules(
+                &ws_conn,
+                &folder_manager,
+                &user_session,
+                &sync_status_controller,
+                &lan_sync_controller,
+            )
         }));
 
-        _start_listening(&dispatcher, &ws_conn, &user_session, &folder_manager);
+        _start_listening(
+            &dispatcher,
+            &ws_conn,
+            &user_session,
+            &folder_manager,
+            &sync_status_controller,
+        );
 
         Self {
             config,
@@ -150,6 +199,7 @@ fn _start_listening(
     ws_conn: &Arc<FlowyWebSocketConnect>,
     user_session: &Arc<UserSession>,
     folder_manager: &Arc<FolderManager>,
+    sync_status_controller: &Arc<SyncStatusController>,
 ) {
     let subscribe_user_status = user_session.notifier.subscribe_user_status();
     let subscribe_network_type = ws_conn.subscribe_network_ty();
@@ -157,6 +207,11 @@ fn _start_listening(
     let cloned_folder_manager = folder_manager.clone();
     let ws_conn = ws_conn.clone();
     let user_session = user_session.clone();
+    let sync_status_controller = sync_status_controller.clone();
+
+    dispatch.spawn(async move {
+        sync_status_controller.listen();
+    });
 
     dispatch.spawn(async move {
         user_session.init();
@@ -171,15 +226,27 @@ fn _start_listening(
 
 fn mk_local_server(
     server_config: &ClientServerConfiguration,
+    root: &str,
+    lan_sync: &Option<LanSyncConfig>,
 ) -> (Option<Arc<LocalServer>>, Arc<FlowyWebSocketConnect>) {
     let ws_addr = server_config.ws_addr();
     if cfg!(feature = "http_server") {
         let ws_conn = Arc::new(FlowyWebSocketConnect::new(ws_addr));
         (None, ws_conn)
     } else {
-        let context = flowy_net::local_server::build_server(server_config);
-        let local_ws = Arc::new(context.local_ws);
-        let ws_conn = Arc::new(FlowyWebSocketConnect::from_local(ws_addr, local_ws));
+        let context = flowy_net::local_server::build_server(server_config, root);
+        // The Peer uses its LocalServer for the user and the folder services, and syncs the
+        // revisions with the LocalServer of the Host. The secret is sent as the handshake.
+        let peer = lan_sync
+            .as_ref()
+            .and_then(|lan_sync| Some((lan_sync.peer_ws_addr()?, lan_sync.secret().to_owned())));
+        let ws_conn = match peer {
+            Some((peer_ws_addr, secret)) => Arc::new(FlowyWebSocketConnect::with_handshake(peer_ws_addr, secret)),
+            None => {
+                let local_ws = Arc::new(context.local_ws);
+                Arc::new(FlowyWebSocketConnect::from_local(ws_addr, local_ws))
+            }
+        };
         (Some(Arc::new(context.local_server)), ws_conn)
     }
 }
@@ -200,11 +267,13 @@ async fn _listen_user_status(
                 UserStatus::Logout { .. } => {
                     tracing::trace!("User did logout");
                     folder_manager.clear().await;
+                    flowy_sync::clear_sync_status();
                     let _ = ws_conn.stop().await;
                 }
                 UserStatus::Expired { .. } => {
                     tracing::trace!("User session has been expired");
                     folder_manager.clear().await;
+                    flowy_sync::clear_sync_status();
                     let _ = ws_conn.stop().await;
                 }
                 UserStatus::SignUp { profile, ret } => {
@@ -232,8 +301,19 @@ async fn _listen_network_status(mut subscribe: broadcast::Receiver<NetworkType>,
     }
 }
 
-fn init_kv(root: &str) {
-    match flowy_database::kv::KV::init(root) {
+fn init_kv(root: &str, database_key: &Option<String>) {
+    let key = match database_key {
+        None => None,
+        Some(hex) => match DatabaseKey::from_hex(hex) {
+            Ok(key) => Some(key),
+            Err(e) => {
+                // The kv.db is not opened without the key, so the session is not stored as plaintext.
+                tracing::error!("Init kv store failed, the database key is invalid: {:?}", e);
+                return;
+            }
+        },
+    };
+    match flowy_database::kv::KV::init_with_key(root, key) {
         Ok(_) => {}
         Err(e) => tracing::error!("Init kv store failedL: {}", e),
     }
@@ -255,7 +335,11 @@ fn mk_user_session(
     server_config: &ClientServerConfiguration,
 ) -> Arc<UserSession> {
     let session_cache_key = format!("{}_session_cache", &config.name);
-    let user_config = UserSessionConfig::new(&config.root, &session_cache_key);
+    let database_key = config
+        .database_key
+        .as_ref()
+        .and_then(|hex| DatabaseKey::from_hex(hex).ok());
+    let user_config = UserSessionConfig::new(&config.root, &session_cache_key).database_key(database_key);
     let cloud_service = UserDepsResolver::resolve(local_server, server_config);
     Arc::new(UserSession::new(user_config, cloud_service))
 }
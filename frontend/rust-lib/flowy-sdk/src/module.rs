@@ -1,5 +1,7 @@
 use flowy_core::controller::FolderManager;
-use flowy_net::ws::connection::FlowyWebSocketConnect;
+use flowy_net::{
+    local_server::LanSyncController, sync_status::SyncStatusController, ws::connection::FlowyWebSocketConnect,
+};
 use flowy_user::services::UserSession;
 use lib_dispatch::prelude::Module;
 use std::sync::Arc;
@@ -8,10 +10,16 @@ pub fn mk_modules(
     ws_conn: &Arc<FlowyWebSocketConnect>,
     folder_manager: &Arc<FolderManager>,
     user_session: &Arc<UserSession>,
+    sync_status_controller: &Arc<SyncStatusController>,
+    lan_sync_controller: &Arc<LanSyncController>,
 ) -> Vec<Module> {
     let user_module = mk_user_module(user_session.clone());
     let folder_module = mk_folder_module(folder_manager.clone());
-    let network_module = mk_network_module(ws_conn.clone());
+    let network_module = mk_network_module(
+        ws_conn.clone(),
+        sync_status_controller.clone(),
+        lan_sync_controller.clone(),
+    );
     vec![user_module, folder_module, network_module]
 }
 
@@ -23,6 +31,10 @@ fn mk_folder_module(core: Arc<FolderManager>) -> Module {
     flowy_core::module::create(core)
 }
 
-fn mk_network_module(ws_conn: Arc<FlowyWebSocketConnect>) -> Module {
-    flowy_net::module::create(ws_conn)
+fn mk_network_module(
+    ws_conn: Arc<FlowyWebSocketConnect>,
+    sync_status_controller: Arc<SyncStatusController>,
+    lan_sync_controller: Arc<LanSyncController>,
+) -> Module {
+    flowy_net::module::create(ws_conn, sync_status_controller, lan_sync_controller)
 }
@@ -5,7 +5,10 @@ use flowy_collaboration::entities::revision::RevisionRange;
 pub use sql_impl::*;
 
 use flowy_error::FlowyResult;
-use std::fmt::Debug;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+};
 
 pub trait RevisionDiskCache: Sync + Send {
     type Error: Debug;
@@ -39,4 +42,18 @@ pub trait RevisionDiskCache: Sync + Send {
     ) -> Result<(), Self::Error>;
 
     fn reset_object(&self, object_id: &str, revision_records: Vec<RevisionRecord>) -> Result<(), Self::Error>;
+
+    // Deletes the records of the rev_ids and writes the new records in one transaction.
+    fn replace_revision_records(
+        &self,
+        object_id: &str,
+        deleted_rev_ids: Vec<i64>,
+        revision_records: Vec<RevisionRecord>,
+    ) -> Result<(), Self::Error>;
+
+    // Returns the number of the revisions that are not acked by the server, grouped by the object id.
+    fn read_pending_revision_counts(&self) -> Result<HashMap<String, i64>, Self::Error>;
+
+    // Returns the ids of the objects that have any revisions on this device.
+    fn read_object_ids(&self) -> Result<HashSet<String>, Self::Error>;
 }
@@ -12,7 +12,10 @@ use flowy_database::{
     ConnectionPool,
 };
 use flowy_error::{internal_error, FlowyError, FlowyResult};
-use std::sync::Arc;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 pub struct SQLitePersistence {
     user_id: String,
@@ -80,6 +83,32 @@ impl RevisionDiskCache for SQLitePersistence {
             Ok(())
         })
     }
+
+    fn replace_revision_records(
+        &self,
+        object_id: &str,
+        deleted_rev_ids: Vec<i64>,
+        revision_records: Vec<RevisionRecord>,
+    ) -> Result<(), Self::Error> {
+        let conn = self.pool.get().map_err(internal_error)?;
+        conn.immediate_transaction::<_, FlowyError, _>(|| {
+            let _ = self.delete_revision_records(object_id, Some(deleted_rev_ids), &*conn)?;
+            let _ = self.write_revision_records(revision_records, &*conn)?;
+            Ok(())
+        })
+    }
+
+    fn read_pending_revision_counts(&self) -> Result<HashMap<String, i64>, Self::Error> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        let counts = RevisionTableSql::read_pending_counts(conn)?;
+        Ok(counts.into_iter().collect())
+    }
+
+    fn read_object_ids(&self) -> Result<HashSet<String>, Self::Error> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        let object_ids = RevisionTableSql::read_object_ids(conn)?;
+        Ok(object_ids.into_iter().collect())
+    }
 }
 
 impl SQLitePersistence {
@@ -167,6 +196,21 @@ impl RevisionTableSql {
         Ok(revisions)
     }
 
+    // Returns the number of the revisions whose state is RevisionTableState::Sync of each object.
+    pub(crate) fn read_pending_counts(conn: &SqliteConnection) -> Result<Vec<(String, i64)>, FlowyError> {
+        let counts = dsl::rev_table
+            .filter(dsl::state.eq(RevisionTableState::Sync))
+            .group_by(dsl::doc_id)
+            .select((dsl::doc_id, diesel::dsl::count_star()))
+            .load::<(String, i64)>(conn)?;
+        Ok(counts)
+    }
+
+    pub(crate) fn read_object_ids(conn: &SqliteConnection) -> Result<Vec<String>, FlowyError> {
+        let object_ids = dsl::rev_table.select(dsl::doc_id).distinct().load::<String>(conn)?;
+        Ok(object_ids)
+    }
+
     pub(crate) fn delete(
         object_id: &str,
         rev_ids: Option<Vec<i64>>,
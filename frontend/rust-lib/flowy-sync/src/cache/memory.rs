@@ -100,6 +100,17 @@ impl RevisionMemoryCache {
         Ok(())
     }
 
+    // Removes the records after the rev_id and adds the new records. The new records are not written
+    // by the checkpoint, they are written to the disk by the caller.
+    pub(crate) async fn replace_after(&self, rev_id: i64, revision_records: &[RevisionRecord]) {
+        let mut write_guard = self.pending_write_revs.write().await;
+        write_guard.retain(|pending_rev_id| *pending_rev_id <= rev_id);
+        self.revs_map.retain(|record_rev_id, _| *record_rev_id <= rev_id);
+        for record in revision_records {
+            self.revs_map.insert(record.revision.rev_id, record.clone());
+        }
+    }
+
     async fn make_checkpoint(&self) {
         // https://github.com/async-graphql/async-graphql/blob/ed8449beec3d9c54b94da39bab33cec809903953/src/dataloader/mod.rs#L362
         if let Some(handler) = self.defer_save.write().await.take() {
@@ -23,6 +23,7 @@ pub struct RevisionCache {
     disk_cache: Arc<dyn RevisionDiskCache<Error = FlowyError>>,
     memory_cache: Arc<RevisionMemoryCache>,
     latest_rev_id: AtomicI64,
+    pool: Arc<ConnectionPool>,
 }
 
 pub fn mk_revision_disk_cache(
@@ -34,7 +35,7 @@ pub fn mk_revision_disk_cache(
 
 impl RevisionCache {
     pub fn new(user_id: &str, object_id: &str, pool: Arc<ConnectionPool>) -> RevisionCache {
-        let disk_cache = Arc::new(SQLitePersistence::new(user_id, pool));
+        let disk_cache = Arc::new(SQLitePersistence::new(user_id, pool.clone()));
         let memory_cache = Arc::new(RevisionMemoryCache::new(object_id, Arc::new(disk_cache.clone())));
         let object_id = object_id.to_owned();
         Self {
@@ -42,9 +43,14 @@ impl RevisionCache {
             disk_cache,
             memory_cache,
             latest_rev_id: AtomicI64::new(0),
+            pool,
         }
     }
 
+    pub(crate) fn db_pool(&self) -> Arc<ConnectionPool> {
+        self.pool.clone()
+    }
+
     pub async fn add(
         &self,
         revision: Revision,
@@ -96,6 +102,12 @@ impl RevisionCache {
         self.disk_cache.read_revision_records(doc_id, None)
     }
 
+    // Deletes the revisions of the object from the disk, e.g. the object was deleted permanently.
+    pub fn delete_all(&self) -> FlowyResult<()> {
+        let conn = self.pool.get().map_err(internal_error)?;
+        self.disk_cache.delete_revision_records(&self.object_id, None, &*conn)
+    }
+
     pub async fn latest_revision(&self) -> Revision {
         let rev_id = self.latest_rev_id.load(SeqCst);
         self.get(rev_id).await.unwrap().revision
@@ -122,13 +134,18 @@ impl RevisionCache {
     }
 
     #[tracing::instrument(level = "debug", skip(self, doc_id, revisions))]
-    pub async fn reset_with_revisions(&self, doc_id: &str, revisions: Vec<Revision>) -> FlowyResult<()> {
+    pub async fn reset_with_revisions(
+        &self,
+        doc_id: &str,
+        revisions: Vec<Revision>,
+        state: RevisionState,
+    ) -> FlowyResult<()> {
         let revision_records = revisions
             .to_vec()
             .into_iter()
             .map(|revision| RevisionRecord {
                 revision,
-                state: RevisionState::Sync,
+                state: state.clone(),
                 write_to_disk: false,
             })
             .collect::<Vec<_>>();
@@ -138,6 +155,38 @@ impl RevisionCache {
         Ok(())
     }
 
+    // Replaces the revisions from the start_rev_id to the end_rev_id with the revisions, the revisions
+    // before the start_rev_id are kept.
+    #[tracing::instrument(level = "debug", skip(self, revisions))]
+    pub async fn replace_revisions(
+        &self,
+        start_rev_id: i64,
+        end_rev_id: i64,
+        revisions: Vec<(Revision, RevisionState)>,
+    ) -> FlowyResult<()> {
+        let revision_records = revisions
+            .into_iter()
+            .map(|(revision, state)| RevisionRecord {
+                revision,
+                state,
+                write_to_disk: false,
+            })
+            .collect::<Vec<_>>();
+        let latest_rev_id = revision_records
+            .last()
+            .map_or(start_rev_id - 1, |record| record.revision.rev_id);
+
+        self.memory_cache
+            .replace_after(start_rev_id - 1, &revision_records)
+            .await;
+        let deleted_rev_ids = (start_rev_id..=end_rev_id).collect::<Vec<i64>>();
+        let _ = self
+            .disk_cache
+            .replace_revision_records(&self.object_id, deleted_rev_ids, revision_records)?;
+        self.set_latest_rev_id(latest_rev_id);
+        Ok(())
+    }
+
     #[inline]
     fn set_latest_rev_id(&self, rev_id: i64) {
         let _ = self.latest_rev_id.fetch_update(SeqCst, SeqCst, |_e| Some(rev_id));
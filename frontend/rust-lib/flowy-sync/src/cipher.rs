@@ -0,0 +1,248 @@
+use aes_gcm::{
+    aead::{Aead, NewAead, Payload},
+    Aes256Gcm, Key, Nonce,
+};
+use argon2::Argon2;
+use flowy_collaboration::entities::revision::{md5, Revision};
+use flowy_error::{FlowyError, FlowyResult};
+use rand::RngCore;
+
+pub const MIN_PASSPHRASE_LEN: usize = 8;
+
+const CIPHER_VERSION: u8 = 1;
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+const KEY_ID_SEED_LEN: usize = 16;
+const TAG_KEY_LEN: usize = 16;
+const SECRET_LEN: usize = KEY_LEN + KEY_ID_SEED_LEN + TAG_KEY_LEN;
+const SALT_PREFIX: &str = "appflowy-revision-cipher:";
+
+// Encrypts the delta_data of the revisions with AES-256-GCM before they leave the client, and
+// decrypts the revisions that are received from the server. The encrypted delta_data is
+// [version][nonce][ciphertext], and the object_id and the rev ids of the revision are authenticated
+// with it, so the server can't move the revision to another object or position.
+//
+// The md5 of the revision is the md5 of the whole object, so it's encrypted with the delta_data and
+// replaced with a tag that is computed with the key. The tag is the same every time the revision is
+// encrypted, which lets the server tell whether the revision was received before.
+//
+// Only the revisions of the documents are encrypted. The server composes the revisions of the
+// folder, the grids, the boards and the comment threads into the objects that it returns to the
+// clients, e.g. the folder is read before the passphrase can be set, so they are synced as
+// plaintext.
+pub struct RevisionCipher {
+    key_id: String,
+    secret: Vec<u8>,
+    cipher: Aes256Gcm,
+}
+
+impl std::fmt::Debug for RevisionCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RevisionCipher").field("key_id", &self.key_id).finish()
+    }
+}
+
+impl RevisionCipher {
+    // The clients that use the same passphrase and salt, e.g. the workspace id, derive the same key.
+    pub fn from_passphrase(passphrase: &str, salt: &str) -> FlowyResult<Self> {
+        if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+            return Err(FlowyError::workspace_passphrase());
+        }
+
+        let salt = format!("{}{}", SALT_PREFIX, salt);
+        let mut secret = vec![0; SECRET_LEN];
+        let _ = Argon2::default()
+            .hash_password_into(passphrase.as_bytes(), salt.as_bytes(), &mut secret)
+            .map_err(|e| FlowyError::internal().context(format!("Derive the revision key failed: {}", e)))?;
+        Self::from_secret(secret)
+    }
+
+    // Restores the cipher from the secret that was derived from the passphrase before.
+    pub fn from_secret(secret: Vec<u8>) -> FlowyResult<Self> {
+        if secret.len() != SECRET_LEN {
+            return Err(FlowyError::internal().context("The length of the revision secret is invalid"));
+        }
+        let cipher = Aes256Gcm::new(Key::from_slice(&secret[..KEY_LEN]));
+        let key_id = md5(&secret[KEY_LEN..KEY_LEN + KEY_ID_SEED_LEN]);
+        Ok(Self { key_id, secret, cipher })
+    }
+
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    pub fn secret(&self) -> &[u8] {
+        &self.secret
+    }
+
+    pub fn encrypt_revision(&self, mut revision: Revision) -> FlowyResult<Revision> {
+        if revision.is_encrypted() {
+            return Ok(revision);
+        }
+        if revision.md5.len() > u8::MAX as usize {
+            return Err(FlowyError::internal().context("The md5 of the revision is too long"));
+        }
+
+        let mut plaintext = Vec::with_capacity(1 + revision.md5.len() + revision.delta_data.len());
+        plaintext.push(revision.md5.len() as u8);
+        plaintext.extend_from_slice(revision.md5.as_bytes());
+        plaintext.extend_from_slice(&revision.delta_data);
+
+        let mut nonce = [0; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let aad = associated_data(&revision);
+        let ciphertext = self
+            .cipher
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &plaintext,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| FlowyError::internal().context("Encrypt the revision failed"))?;
+
+        let mut delta_data = Vec::with_capacity(1 + NONCE_LEN + ciphertext.len());
+        delta_data.push(CIPHER_VERSION);
+        delta_data.extend_from_slice(&nonce);
+        delta_data.extend_from_slice(&ciphertext);
+
+        revision.md5 = self.tag(&revision.md5);
+        revision.delta_data = delta_data;
+        revision.key_id = self.key_id.clone();
+        Ok(revision)
+    }
+
+    pub fn decrypt_revision(&self, mut revision: Revision) -> FlowyResult<Revision> {
+        if !revision.is_encrypted() {
+            return Ok(revision);
+        }
+        if revision.key_id != self.key_id {
+            let msg = format!(
+                "{}:{} is encrypted with another key",
+                revision.object_id, revision.rev_id
+            );
+            return Err(FlowyError::revision_decrypt().context(msg));
+        }
+
+        let data = &revision.delta_data;
+        if data.len() < 1 + NONCE_LEN || data[0] != CIPHER_VERSION {
+            let msg = format!(
+                "{}:{} is not a valid encrypted revision",
+                revision.object_id, revision.rev_id
+            );
+            return Err(FlowyError::revision_decrypt().context(msg));
+        }
+        let aad = associated_data(&revision);
+        let plaintext = self
+            .cipher
+            .decrypt(
+                Nonce::from_slice(&data[1..1 + NONCE_LEN]),
+                Payload {
+                    msg: &data[1 + NONCE_LEN..],
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| {
+                let msg = format!("{}:{} failed the authentication", revision.object_id, revision.rev_id);
+                FlowyError::revision_decrypt().context(msg)
+            })?;
+
+        let md5_len = *plaintext.first().unwrap_or(&0) as usize;
+        if plaintext.len() < 1 + md5_len {
+            return Err(FlowyError::revision_decrypt().context("The decrypted revision is truncated"));
+        }
+        let md5 = String::from_utf8(plaintext[1..1 + md5_len].to_vec())
+            .map_err(|e| FlowyError::revision_decrypt().context(e))?;
+
+        revision.delta_data = plaintext[1 + md5_len..].to_vec();
+        revision.md5 = md5;
+        revision.key_id = String::new();
+        Ok(revision)
+    }
+
+    pub fn encrypt_revisions(&self, revisions: Vec<Revision>) -> FlowyResult<Vec<Revision>> {
+        revisions
+            .into_iter()
+            .map(|revision| self.encrypt_revision(revision))
+            .collect()
+    }
+
+    pub fn decrypt_revisions(&self, revisions: Vec<Revision>) -> FlowyResult<Vec<Revision>> {
+        revisions
+            .into_iter()
+            .map(|revision| self.decrypt_revision(revision))
+            .collect()
+    }
+
+    fn tag(&self, md5_str: &str) -> String {
+        let mut data = self.secret[KEY_LEN + KEY_ID_SEED_LEN..].to_vec();
+        data.extend_from_slice(md5_str.as_bytes());
+        md5(data)
+    }
+}
+
+// The plaintext revisions are returned as they are, e.g. the revisions that were synced before the
+// encryption was enabled.
+pub fn decrypt_revisions(cipher: Option<&RevisionCipher>, revisions: Vec<Revision>) -> FlowyResult<Vec<Revision>> {
+    match cipher {
+        Some(cipher) => cipher.decrypt_revisions(revisions),
+        None => match revisions.iter().find(|revision| revision.is_encrypted()) {
+            None => Ok(revisions),
+            Some(revision) => {
+                let msg = format!(
+                    "{}:{} is encrypted, the passphrase is required",
+                    revision.object_id, revision.rev_id
+                );
+                Err(FlowyError::revision_decrypt().context(msg))
+            }
+        },
+    }
+}
+
+fn associated_data(revision: &Revision) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(revision.object_id.len() + 16);
+    aad.extend_from_slice(revision.object_id.as_bytes());
+    aad.extend_from_slice(&revision.base_rev_id.to_be_bytes());
+    aad.extend_from_slice(&revision.rev_id.to_be_bytes());
+    aad
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RevisionCipher;
+    use bytes::Bytes;
+    use flowy_collaboration::entities::revision::Revision;
+
+    fn revision(rev_id: i64) -> Revision {
+        let delta_data = Bytes::from(r#"[{"insert":"123\n"}]"#);
+        Revision::new("doc", rev_id - 1, rev_id, delta_data, "user", "md5".to_owned())
+    }
+
+    #[test]
+    fn revision_cipher_round_trip_test() {
+        let cipher = RevisionCipher::from_passphrase("passphrase", "workspace").unwrap();
+        let encrypted = cipher.encrypt_revision(revision(1)).unwrap();
+        assert!(encrypted.is_encrypted());
+        assert_ne!(encrypted.delta_data, revision(1).delta_data);
+        assert_ne!(encrypted.md5, "md5");
+        assert_eq!(encrypted.md5, cipher.encrypt_revision(revision(1)).unwrap().md5);
+
+        let restored = RevisionCipher::from_secret(cipher.secret().to_vec()).unwrap();
+        assert_eq!(restored.decrypt_revision(encrypted).unwrap(), revision(1));
+    }
+
+    #[test]
+    fn revision_cipher_reject_test() {
+        let cipher = RevisionCipher::from_passphrase("passphrase", "workspace").unwrap();
+        let other = RevisionCipher::from_passphrase("passphrase", "other workspace").unwrap();
+        let encrypted = cipher.encrypt_revision(revision(1)).unwrap();
+        assert!(other.decrypt_revision(encrypted.clone()).is_err());
+
+        let mut moved = encrypted;
+        moved.rev_id = 2;
+        assert!(cipher.decrypt_revision(moved).is_err());
+
+        assert!(RevisionCipher::from_passphrase("short", "workspace").is_err());
+    }
+}
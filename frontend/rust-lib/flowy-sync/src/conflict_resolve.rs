@@ -1,4 +1,4 @@
-use crate::RevisionManager;
+use crate::{notify_merge_summary, RevisionManager, RevisionMergeBackup, RevisionMergeSummary};
 use bytes::Bytes;
 use flowy_collaboration::{
     entities::{
@@ -9,7 +9,7 @@ use flowy_collaboration::{
 };
 use flowy_error::{FlowyError, FlowyResult};
 use lib_infra::future::BoxResultFuture;
-use lib_ot::core::{Attributes, Delta};
+use lib_ot::core::{Attributes, Delta, OperationTransformable};
 use serde::de::DeserializeOwned;
 use std::{convert::TryFrom, sync::Arc};
 
@@ -63,6 +63,8 @@ where
         if repeated_revision.is_empty() {
             return Ok(());
         }
+        let revisions = self.rev_manager.decrypt_revisions(repeated_revision.into_inner())?;
+        let repeated_revision = RepeatedRevision::new(revisions);
 
         match self.handle_revision(repeated_revision).await? {
             None => {}
@@ -86,18 +88,24 @@ where
 
     async fn handle_revision(&self, repeated_revision: RepeatedRevision) -> FlowyResult<Option<Revision>> {
         let mut revisions = repeated_revision.into_inner();
-        let first_revision = revisions.first().unwrap();
-        if let Some(local_revision) = self.rev_manager.get_revision(first_revision.rev_id).await {
-            if local_revision.md5 == first_revision.md5 {
-                // The local revision is equal to the pushed revision. Just ignore it.
-                revisions = revisions.split_off(1);
-                if revisions.is_empty() {
+        while let Some(first_revision) = revisions.first() {
+            match self.rev_manager.get_revision(first_revision.rev_id).await {
+                // The local revision is equal to the pushed revision. Just ignore it, and ack it if
+                // it's pending because the ack was lost.
+                Some(local_revision) if local_revision.md5 == first_revision.md5 => {
+                    let _ = self.rev_manager.ack_revision(local_revision.rev_id).await?;
+                    let _ = revisions.remove(0);
+                }
+                Some(_) => {
+                    let _ = self.merge_diverged_revisions(revisions).await?;
                     return Ok(None);
                 }
-            } else {
-                return Ok(None);
+                None => break,
             }
         }
+        if revisions.is_empty() {
+            return Ok(None);
+        }
 
         let new_delta = make_delta_from_revisions(revisions.clone())?;
 
@@ -133,6 +141,123 @@ where
             }
         }
     }
+
+    // The pending revisions diverged from the revisions of the server, e.g. the client was offline
+    // for a long time. They are composed into one delta and transformed against the composed
+    // revisions of the server in one step. The pending revisions are backed up before the merge and
+    // they are kept if the merge failed, so the local changes are never discarded.
+    async fn merge_diverged_revisions(&self, remote_revisions: Vec<Revision>) -> FlowyResult<()> {
+        let pending_revisions = self.rev_manager.pending_revisions().await?;
+        match (pending_revisions.first(), remote_revisions.first()) {
+            (Some(pending_revision), Some(remote_revision)) if pending_revision.rev_id == remote_revision.rev_id => {}
+            _ => {
+                // The server pushes the revisions from the first pending revision after it received
+                // the pending revisions.
+                tracing::debug!("{} waits for the revisions of the server", self.rev_manager.object_id);
+                return Ok(());
+            }
+        }
+
+        let mut summary = RevisionMergeSummary {
+            object_id: self.rev_manager.object_id.clone(),
+            local_revision_count: pending_revisions.len() as i64,
+            remote_revision_count: remote_revisions.len() as i64,
+            ..Default::default()
+        };
+        if let Err(e) = self
+            .merge_revisions(pending_revisions, remote_revisions, &mut summary)
+            .await
+        {
+            tracing::error!("Merge the revisions of {} failed: {}", summary.object_id, e);
+            summary.error = Some(e.to_string());
+        }
+        notify_merge_summary(summary);
+        Ok(())
+    }
+
+    async fn merge_revisions(
+        &self,
+        pending_revisions: Vec<Revision>,
+        remote_revisions: Vec<Revision>,
+        summary: &mut RevisionMergeSummary,
+    ) -> FlowyResult<()> {
+        let (local_delta, local_prime, remote_prime) =
+            transform_diverged_revisions::<T>(pending_revisions.clone(), remote_revisions.clone())?;
+        let base_rev_id = pending_revisions[0].rev_id - 1;
+        let base_delta = self.read_base_delta(base_rev_id).await?;
+        let backup = RevisionMergeBackup::new(
+            &self.rev_manager.object_id,
+            base_rev_id,
+            pending_revisions.iter().map(|revision| revision.rev_id).collect(),
+            base_delta.to_json(),
+            local_delta.to_json(),
+        );
+        let _ = self.rev_manager.save_merge_backup(&backup)?;
+        summary.has_backup = true;
+
+        // The remote_prime is composed with the document that has the local changes, and the
+        // local_prime is sent to the server as the next revision.
+        let md5 = self.target.compose_delta(remote_prime).await?;
+        let last_remote_rev_id = remote_revisions.last().unwrap().rev_id;
+        let merged_revision = if local_prime.is_empty() {
+            None
+        } else {
+            Some(Revision::new(
+                &self.rev_manager.object_id,
+                last_remote_rev_id,
+                last_remote_rev_id + 1,
+                local_prime.to_bytes(),
+                &self.user_id,
+                md5,
+            ))
+        };
+        summary.merged_rev_id = merged_revision.as_ref().map_or(0, |revision| revision.rev_id);
+        if let Err(e) = self
+            .rev_manager
+            .rebase_pending_revisions(remote_revisions, merged_revision)
+            .await
+        {
+            // The pending revisions are kept if the rebase failed, so the document is reset to the one
+            // that has the local changes only. It's merged again when the server pushes the revisions.
+            let _ = self.target.reset_delta(base_delta.compose(&local_delta)?).await?;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    // Returns the document that the pending revisions were applied to.
+    async fn read_base_delta(&self, base_rev_id: i64) -> FlowyResult<Delta<T>> {
+        // The RevisionRange that starts and ends at the same rev_id is treated as empty.
+        let revisions = match base_rev_id {
+            rev_id if rev_id < 0 => vec![],
+            0 => self.rev_manager.get_revision(0).await.into_iter().collect(),
+            rev_id => {
+                let range = RevisionRange {
+                    object_id: self.rev_manager.object_id.clone(),
+                    start: 0,
+                    end: rev_id,
+                };
+                self.rev_manager.get_revisions_in_range(range).await?
+            }
+        };
+        Ok(make_delta_from_revisions::<T>(revisions)?)
+    }
+}
+
+// Returns the composed pending revisions, and the primes of the pending and the remote revisions.
+// The local_delta composed with the remote_prime is equal to the remote_delta composed with the
+// local_prime.
+fn transform_diverged_revisions<T>(
+    pending_revisions: Vec<Revision>,
+    remote_revisions: Vec<Revision>,
+) -> FlowyResult<(Delta<T>, Delta<T>, Delta<T>)>
+where
+    T: Attributes + DeserializeOwned,
+{
+    let local_delta = make_delta_from_revisions::<T>(pending_revisions)?;
+    let remote_delta = make_delta_from_revisions::<T>(remote_revisions)?;
+    let (local_prime, remote_prime) = local_delta.transform(&remote_delta)?;
+    Ok((local_delta, local_prime, remote_prime))
 }
 
 fn make_client_and_server_revision<T>(
@@ -178,3 +303,197 @@ where
     pub client_prime: Delta<T>,
     pub server_prime: Option<Delta<T>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        transform_diverged_revisions, DeltaMD5, ResolverRevisionSink, ResolverTarget, RevisionConflictResolver,
+        TransformDeltas,
+    };
+    use crate::{subscribe_merge_summary, RevisionCache, RevisionManager, RevisionMergeBackupSql};
+    use bytes::Bytes;
+    use flowy_collaboration::entities::{
+        revision::{md5, RepeatedRevision, Revision},
+        ws_data::ServerRevisionWSDataType,
+    };
+    use flowy_database::Database;
+    use flowy_error::FlowyError;
+    use lib_infra::future::BoxResultFuture;
+    use lib_ot::core::{Delta, OperationTransformable, PlainDelta, PlainDeltaBuilder, PlainTextAttributes};
+    use parking_lot::RwLock;
+    use std::{
+        convert::TryInto,
+        sync::Arc,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    fn make_revision(rev_id: i64, delta: PlainDelta) -> Revision {
+        let bytes = delta.to_bytes();
+        let md5 = md5(&bytes);
+        Revision::new("object", rev_id - 1, rev_id, bytes, "user", md5)
+    }
+
+    struct MockDocument {
+        delta: RwLock<PlainDelta>,
+    }
+
+    impl ResolverTarget<PlainTextAttributes> for MockDocument {
+        fn compose_delta(&self, delta: Delta<PlainTextAttributes>) -> BoxResultFuture<DeltaMD5, FlowyError> {
+            let mut document = self.delta.write();
+            let result = document.compose(&delta).map(|new_delta| {
+                let md5 = md5(&new_delta.to_bytes());
+                *document = new_delta;
+                md5
+            });
+            Box::pin(async move { Ok(result?) })
+        }
+
+        fn transform_delta(
+            &self,
+            delta: Delta<PlainTextAttributes>,
+        ) -> BoxResultFuture<TransformDeltas<PlainTextAttributes>, FlowyError> {
+            let result = self.delta.read().transform(&delta);
+            Box::pin(async move {
+                let (server_prime, client_prime) = result?;
+                Ok(TransformDeltas {
+                    client_prime,
+                    server_prime: Some(server_prime),
+                })
+            })
+        }
+
+        fn reset_delta(&self, delta: Delta<PlainTextAttributes>) -> BoxResultFuture<DeltaMD5, FlowyError> {
+            let md5 = md5(&delta.to_bytes());
+            *self.delta.write() = delta;
+            Box::pin(async move { Ok(md5) })
+        }
+    }
+
+    #[derive(Default)]
+    struct MockRevisionSink {
+        sent_revisions: RwLock<Vec<Revision>>,
+    }
+
+    impl ResolverRevisionSink for MockRevisionSink {
+        fn send(&self, revisions: Vec<Revision>) -> BoxResultFuture<(), FlowyError> {
+            self.sent_revisions.write().extend(revisions);
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn ack(&self, _rev_id: String, _ty: ServerRevisionWSDataType) -> BoxResultFuture<(), FlowyError> {
+            Box::pin(async move { Ok(()) })
+        }
+    }
+
+    fn make_rev_manager(object_id: &str) -> (Database, Arc<RevisionManager>) {
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+        let dir = std::env::temp_dir().join(format!("flowy_sync_merge_test_{}", nanos));
+        let database = flowy_database::init(dir.to_str().unwrap()).unwrap();
+        let cache = Arc::new(RevisionCache::new("user", object_id, database.get_pool()));
+        let rev_manager = Arc::new(RevisionManager::new("user", object_id, cache));
+        (database, rev_manager)
+    }
+
+    #[tokio::test]
+    async fn merge_diverged_revisions_test() {
+        let (database, rev_manager) = make_rev_manager("object");
+        let base = PlainDeltaBuilder::new().insert("abc").build();
+        rev_manager
+            .add_remote_revision(&make_revision(0, base.clone()))
+            .await
+            .unwrap();
+
+        // The local revisions 1 and 2 were not acked by the server.
+        let mut document = base;
+        for (rev_id, delta) in vec![
+            (1, PlainDeltaBuilder::new().insert("1").retain(3).build()),
+            (2, PlainDeltaBuilder::new().retain(4).insert("2").build()),
+        ] {
+            document = document.compose(&delta).unwrap();
+            rev_manager
+                .add_local_revision(&make_revision(rev_id, delta))
+                .await
+                .unwrap();
+        }
+        assert_eq!(document.apply("").unwrap(), "1abc2");
+
+        // The server has the different revisions 1 and 2 that were made by another client.
+        let remote_revisions = vec![
+            make_revision(1, PlainDeltaBuilder::new().retain(1).delete(1).retain(1).build()),
+            make_revision(2, PlainDeltaBuilder::new().retain(2).insert("X").build()),
+        ];
+        let target = Arc::new(MockDocument {
+            delta: RwLock::new(document),
+        });
+        let rev_sink = Arc::new(MockRevisionSink::default());
+        let resolver = RevisionConflictResolver::new("user", target.clone(), rev_sink.clone(), rev_manager.clone());
+        let mut summary_receiver = subscribe_merge_summary();
+        let bytes: Bytes = RepeatedRevision::new(remote_revisions.clone()).try_into().unwrap();
+        resolver.receive_bytes(bytes).await.unwrap();
+
+        let summary = summary_receiver.recv().await.unwrap();
+        assert!(summary.is_merged());
+        assert!(summary.has_backup);
+        assert_eq!(
+            (
+                summary.local_revision_count,
+                summary.remote_revision_count,
+                summary.merged_rev_id
+            ),
+            (2, 2, 3)
+        );
+        assert_eq!(target.delta.read().apply("").unwrap(), "1ac2X");
+        assert!(rev_sink.sent_revisions.read().is_empty());
+
+        // The revisions of the server replace the local revisions, and the merged revision is the
+        // only pending revision.
+        for remote_revision in &remote_revisions {
+            let revision = rev_manager.get_revision(remote_revision.rev_id).await.unwrap();
+            assert_eq!(revision.md5, remote_revision.md5);
+        }
+        let pending_revisions = rev_manager.pending_revisions().await.unwrap();
+        assert_eq!(pending_revisions.len(), 1);
+        assert_eq!(pending_revisions[0].pair_rev_id(), (2, 3));
+        let merged_delta = PlainDelta::from_bytes(&pending_revisions[0].delta_data).unwrap();
+        assert_eq!(merged_delta.apply("acX").unwrap(), "1ac2X");
+        assert_eq!(rev_manager.rev_id(), 3);
+
+        // The backup has the local document before the merge.
+        let conn = database.get_connection().unwrap();
+        let backups = RevisionMergeBackupSql::read_all("object", &*conn).unwrap();
+        assert_eq!(backups.len(), 1);
+        assert_eq!((backups[0].base_rev_id, backups[0].rev_ids.clone()), (0, vec![1, 2]));
+        let local_delta = backups[0].local_delta::<PlainTextAttributes>().unwrap();
+        assert_eq!(local_delta.apply("").unwrap(), "1abc2");
+    }
+
+    #[test]
+    fn transform_diverged_revisions_test() {
+        let base = "abc";
+        let pending_revisions = vec![
+            make_revision(2, PlainDeltaBuilder::new().insert("1").retain(3).build()),
+            make_revision(3, PlainDeltaBuilder::new().retain(4).insert("2").build()),
+        ];
+        let remote_revisions = vec![
+            make_revision(2, PlainDeltaBuilder::new().retain(1).delete(1).retain(1).build()),
+            make_revision(3, PlainDeltaBuilder::new().retain(2).insert("X").build()),
+        ];
+
+        let (local_delta, local_prime, remote_prime) =
+            transform_diverged_revisions::<PlainTextAttributes>(pending_revisions, remote_revisions.clone()).unwrap();
+        let local_text = local_delta.apply(base).unwrap();
+        assert_eq!(local_text, "1abc2");
+
+        let remote_delta = remote_revisions
+            .into_iter()
+            .map(|revision| PlainDelta::from_bytes(&revision.delta_data).unwrap())
+            .fold(PlainDelta::default(), |delta, other| delta.compose(&other).unwrap());
+        let merged_text = remote_prime.apply(&local_text).unwrap();
+        assert_eq!(
+            merged_text,
+            local_prime.apply(&remote_delta.apply(base).unwrap()).unwrap()
+        );
+        // The local insert is placed before the remote insert at the same index.
+        assert_eq!(merged_text, "1ac2X");
+    }
+}
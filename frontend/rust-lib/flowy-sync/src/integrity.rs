@@ -0,0 +1,175 @@
+use crate::{RevisionCache, RevisionCloudService, RevisionManager, RevisionRecord};
+use flowy_collaboration::entities::revision::{RepeatedRevision, Revision, RevisionRange, RevisionState};
+use flowy_database::ConnectionPool;
+use flowy_error::{FlowyError, FlowyResult};
+use std::sync::Arc;
+
+pub trait RevisionObjectValidator: Send + Sync {
+    // Returns error if the delta data of the revision can't be deserialized.
+    fn validate_revision(revision: &Revision) -> FlowyResult<()>;
+
+    // Composes the revisions and returns the md5 of the object, it's the same as the md5 of the
+    // object that is built by the server with the same revisions.
+    fn compose_revisions(object_id: &str, revisions: Vec<Revision>) -> FlowyResult<String>;
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RevisionIntegrityReport {
+    pub object_id: String,
+    pub revision_count: i64,
+    pub pending_count: i64,
+    pub last_rev_id: i64,
+
+    // The rev ids that are missing between the first and the last revision.
+    pub missing_ranges: Vec<RevisionRange>,
+
+    // The revisions whose delta data is empty or can't be deserialized.
+    pub invalid_rev_ids: Vec<i64>,
+
+    // The error of composing the revisions, the object can't be loaded if it's not None.
+    pub compose_error: Option<String>,
+
+    // The md5 of the object that is composed with the acked revisions up to the server_rev_id.
+    pub local_md5: String,
+
+    pub server_rev_id: i64,
+    pub server_md5: String,
+    // The error of fetching the object from the cloud service, e.g. the network is disconnected.
+    pub server_error: Option<String>,
+}
+
+impl RevisionIntegrityReport {
+    // The md5 is only compared if the server has the revisions of the object and the local
+    // revisions are not behind the server's.
+    pub fn is_md5_matched(&self) -> bool {
+        self.server_md5.is_empty()
+            || self.server_rev_id == 0
+            || self.last_rev_id < self.server_rev_id
+            || self.local_md5 == self.server_md5
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.missing_ranges.is_empty()
+            && self.invalid_rev_ids.is_empty()
+            && self.compose_error.is_none()
+            && self.is_md5_matched()
+    }
+}
+
+// Replays the revisions of the object that are stored in the rev_table, and compares the result
+// with the object of the cloud service. The md5 of each revision is computed from its data when
+// it's read from the rev_table, so the stored revisions can only be verified by composing them.
+pub struct RevisionIntegrityChecker {
+    user_id: String,
+    object_id: String,
+    revision_cache: Arc<RevisionCache>,
+    cloud: Arc<dyn RevisionCloudService>,
+}
+
+impl RevisionIntegrityChecker {
+    pub fn new(
+        user_id: &str,
+        object_id: &str,
+        pool: Arc<ConnectionPool>,
+        cloud: Arc<dyn RevisionCloudService>,
+    ) -> Self {
+        let revision_cache = Arc::new(RevisionCache::new(user_id, object_id, pool));
+        Self {
+            user_id: user_id.to_owned(),
+            object_id: object_id.to_owned(),
+            revision_cache,
+            cloud,
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(object_id = %self.object_id), err)]
+    pub async fn check<V: RevisionObjectValidator>(&self) -> FlowyResult<RevisionIntegrityReport> {
+        let mut records = self.revision_cache.batch_get(&self.object_id)?;
+        records.sort_by_key(|record| record.revision.rev_id);
+
+        let mut report = RevisionIntegrityReport {
+            object_id: self.object_id.clone(),
+            revision_count: records.len() as i64,
+            pending_count: records.iter().filter(|record| record.state.is_need_sync()).count() as i64,
+            last_rev_id: records.last().map_or(0, |record| record.revision.rev_id),
+            missing_ranges: missing_ranges(&self.object_id, &records),
+            ..Default::default()
+        };
+
+        for record in &records {
+            let revision = &record.revision;
+            if revision.delta_data.is_empty() || V::validate_revision(revision).is_err() {
+                report.invalid_rev_ids.push(revision.rev_id);
+            }
+        }
+
+        match self.cloud.fetch_object(&self.user_id, &self.object_id).await {
+            Ok(server_revisions) => {
+                report.server_rev_id = server_revisions.last().map_or(0, |revision| revision.rev_id);
+                if !server_revisions.is_empty() {
+                    match V::compose_revisions(&self.object_id, server_revisions) {
+                        Ok(md5) => report.server_md5 = md5,
+                        Err(e) => report.server_error = Some(format!("{:?}", e)),
+                    }
+                }
+            }
+            Err(e) => report.server_error = Some(format!("{:?}", e)),
+        }
+
+        // The pending revisions are not on the server, so only the acked revisions are compared.
+        let server_rev_id = report.server_rev_id;
+        let acked_revisions = records
+            .into_iter()
+            .filter(|record| record.state == RevisionState::Ack)
+            .filter(|record| server_rev_id == 0 || record.revision.rev_id <= server_rev_id)
+            .map(|record| record.revision)
+            .collect::<Vec<Revision>>();
+        match V::compose_revisions(&self.object_id, acked_revisions) {
+            Ok(md5) => report.local_md5 = md5,
+            Err(e) => report.compose_error = Some(format!("{:?}", e)),
+        }
+
+        Ok(report)
+    }
+
+    // Replaces the revisions of the object with the revisions of the cloud service, so the object
+    // must be closed before repairing it. The local changes would be lost if the object has revisions
+    // that are not synced, so the repair is refused until they are synced.
+    #[tracing::instrument(level = "debug", skip(self), fields(object_id = %self.object_id), err)]
+    pub async fn repair<V: RevisionObjectValidator>(&self) -> FlowyResult<()> {
+        let pending_count = self
+            .revision_cache
+            .batch_get(&self.object_id)?
+            .iter()
+            .filter(|record| record.state.is_need_sync())
+            .count();
+        if pending_count > 0 {
+            let msg = format!("{} revisions of {} are not synced", pending_count, self.object_id);
+            return Err(FlowyError::revision_not_synced().context(msg));
+        }
+
+        let server_revisions = self.cloud.fetch_object(&self.user_id, &self.object_id).await?;
+        if server_revisions.last().map_or(0, |revision| revision.rev_id) == 0 {
+            return Err(FlowyError::record_not_found().context("The cloud service doesn't have this object"));
+        }
+        let _ = V::compose_revisions(&self.object_id, server_revisions.clone())?;
+
+        let rev_manager = RevisionManager::new(&self.user_id, &self.object_id, self.revision_cache.clone());
+        let _ = rev_manager
+            .reset_object_with_remote_revisions(RepeatedRevision::new(server_revisions))
+            .await?;
+        Ok(())
+    }
+}
+
+fn missing_ranges(object_id: &str, records: &[RevisionRecord]) -> Vec<RevisionRange> {
+    records
+        .windows(2)
+        .filter(|pair| pair[1].revision.rev_id > pair[0].revision.rev_id + 1)
+        .map(|pair| RevisionRange {
+            object_id: object_id.to_owned(),
+            start: pair[0].revision.rev_id + 1,
+            end: pair[1].revision.rev_id - 1,
+        })
+        .collect()
+}
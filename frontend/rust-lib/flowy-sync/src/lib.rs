@@ -1,11 +1,19 @@
 mod cache;
+mod cipher;
 mod conflict_resolve;
+mod integrity;
+mod merge;
 mod rev_manager;
+mod sync_status;
 mod ws_manager;
 
 pub use cache::*;
+pub use cipher::*;
 pub use conflict_resolve::*;
+pub use integrity::*;
+pub use merge::*;
 pub use rev_manager::*;
+pub use sync_status::*;
 pub use ws_manager::*;
 
 #[macro_use]
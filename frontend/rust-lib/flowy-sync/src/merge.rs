@@ -0,0 +1,181 @@
+use crate::sync_status::timestamp;
+use diesel::SqliteConnection;
+use flowy_database::{
+    insert_into,
+    prelude::*,
+    schema::{rev_merge_backup_table, rev_merge_backup_table::dsl},
+};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use lazy_static::lazy_static;
+use lib_ot::core::{Attributes, Delta, OperationTransformable};
+use serde::de::DeserializeOwned;
+use tokio::sync::broadcast;
+
+// The number of the backups that are kept for each object, the older ones are deleted when a new
+// one is saved.
+const MAX_MERGE_BACKUP_COUNT: usize = 5;
+
+// The backups that are older than it are deleted when any backup is saved.
+const MERGE_BACKUP_RETENTION_DAYS: i64 = 30;
+
+lazy_static! {
+    static ref MERGE_SUMMARY_NOTIFIER: broadcast::Sender<RevisionMergeSummary> = broadcast::channel(100).0;
+}
+
+// The pending revisions of the object before they were merged with the revisions of the server.
+// The base document composed with the delta is the local state before the merge, so the local work
+// can be recovered if the merge result is not expected. The backups are stored in the user
+// database.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RevisionMergeBackup {
+    // The id is assigned when the backup is saved.
+    pub id: i32,
+    pub object_id: String,
+    // The rev_id of the last revision before the pending revisions.
+    pub base_rev_id: i64,
+    pub rev_ids: Vec<i64>,
+    // The document at the base_rev_id.
+    pub base_json: String,
+    // The composed pending revisions.
+    pub delta_json: String,
+    // The timestamp in seconds when the backup was created.
+    pub created_at: i64,
+}
+
+impl RevisionMergeBackup {
+    pub fn new(object_id: &str, base_rev_id: i64, rev_ids: Vec<i64>, base_json: String, delta_json: String) -> Self {
+        Self {
+            id: 0,
+            object_id: object_id.to_owned(),
+            base_rev_id,
+            rev_ids,
+            base_json,
+            delta_json,
+            created_at: timestamp(),
+        }
+    }
+
+    // Returns the local document before the merge.
+    pub fn local_delta<T>(&self) -> FlowyResult<Delta<T>>
+    where
+        T: Attributes + DeserializeOwned,
+    {
+        let base = Delta::<T>::from_json(&self.base_json)?;
+        let delta = Delta::<T>::from_json(&self.delta_json)?;
+        Ok(base.compose(&delta)?)
+    }
+}
+
+pub struct RevisionMergeBackupSql {}
+
+impl RevisionMergeBackupSql {
+    // Saves the backup, then deletes the backups of the object that exceed the MAX_MERGE_BACKUP_COUNT
+    // and the backups of all the objects that are older than the MERGE_BACKUP_RETENTION_DAYS.
+    pub fn create(backup: &RevisionMergeBackup, conn: &SqliteConnection) -> FlowyResult<()> {
+        let rev_ids = serde_json::to_string(&backup.rev_ids).map_err(internal_error)?;
+        conn.immediate_transaction::<_, FlowyError, _>(|| {
+            let _ = insert_into(dsl::rev_merge_backup_table)
+                .values((
+                    dsl::object_id.eq(&backup.object_id),
+                    dsl::base_rev_id.eq(backup.base_rev_id),
+                    dsl::rev_ids.eq(rev_ids),
+                    dsl::base_json.eq(&backup.base_json),
+                    dsl::delta_json.eq(&backup.delta_json),
+                    dsl::create_time.eq(backup.created_at),
+                ))
+                .execute(conn)?;
+
+            let exceeded_ids = dsl::rev_merge_backup_table
+                .filter(dsl::object_id.eq(&backup.object_id))
+                .select(dsl::id)
+                .order(dsl::id.desc())
+                .load::<i32>(conn)?
+                .into_iter()
+                .skip(MAX_MERGE_BACKUP_COUNT)
+                .collect::<Vec<i32>>();
+            let _ = diesel::delete(dsl::rev_merge_backup_table.filter(dsl::id.eq_any(exceeded_ids))).execute(conn)?;
+
+            let expired_time = backup.created_at - MERGE_BACKUP_RETENTION_DAYS * 24 * 3600;
+            let affected_row =
+                diesel::delete(dsl::rev_merge_backup_table.filter(dsl::create_time.lt(expired_time))).execute(conn)?;
+            tracing::trace!("[RevisionMergeBackupTable] Delete {} expired rows", affected_row);
+            Ok(())
+        })
+    }
+
+    // Returns the backups of the object, the latest one is the first.
+    pub fn read_all(object_id: &str, conn: &SqliteConnection) -> FlowyResult<Vec<RevisionMergeBackup>> {
+        let rows = dsl::rev_merge_backup_table
+            .filter(dsl::object_id.eq(object_id))
+            .order(dsl::id.desc())
+            .load::<RevisionMergeBackupTable>(conn)?;
+        rows.into_iter().map(mk_merge_backup_from_table).collect()
+    }
+
+    pub fn read(id: i32, conn: &SqliteConnection) -> FlowyResult<RevisionMergeBackup> {
+        let row = dsl::rev_merge_backup_table
+            .filter(dsl::id.eq(id))
+            .first::<RevisionMergeBackupTable>(conn)?;
+        mk_merge_backup_from_table(row)
+    }
+
+    pub fn delete(id: i32, conn: &SqliteConnection) -> FlowyResult<()> {
+        let _ = diesel::delete(dsl::rev_merge_backup_table.filter(dsl::id.eq(id))).execute(conn)?;
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable)]
+#[table_name = "rev_merge_backup_table"]
+struct RevisionMergeBackupTable {
+    id: i32,
+    object_id: String,
+    base_rev_id: i64,
+    rev_ids: String,
+    base_json: String,
+    delta_json: String,
+    create_time: i64,
+}
+
+fn mk_merge_backup_from_table(table: RevisionMergeBackupTable) -> FlowyResult<RevisionMergeBackup> {
+    let rev_ids = serde_json::from_str(&table.rev_ids).map_err(internal_error)?;
+    Ok(RevisionMergeBackup {
+        id: table.id,
+        object_id: table.object_id,
+        base_rev_id: table.base_rev_id,
+        rev_ids,
+        base_json: table.base_json,
+        delta_json: table.delta_json,
+        created_at: table.create_time,
+    })
+}
+
+// Describes how the pending revisions that diverged from the server were merged. The pending
+// revisions are kept if the merge failed, and they are merged again when the server pushes its
+// revisions next time.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct RevisionMergeSummary {
+    pub object_id: String,
+    pub local_revision_count: i64,
+    pub remote_revision_count: i64,
+    // The rev_id of the revision that has the merged local changes, 0 if the local changes were
+    // already included in the revisions of the server.
+    pub merged_rev_id: i64,
+    pub has_backup: bool,
+    pub error: Option<String>,
+}
+
+impl RevisionMergeSummary {
+    pub fn is_merged(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+pub fn subscribe_merge_summary() -> broadcast::Receiver<RevisionMergeSummary> {
+    MERGE_SUMMARY_NOTIFIER.subscribe()
+}
+
+pub(crate) fn notify_merge_summary(summary: RevisionMergeSummary) {
+    tracing::info!("Merged the diverged revisions: {:?}", summary);
+    let _ = MERGE_SUMMARY_NOTIFIER.send(summary);
+}
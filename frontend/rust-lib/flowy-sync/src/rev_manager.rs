@@ -1,10 +1,13 @@
-use crate::{RevisionCache, RevisionRecord};
+use crate::{
+    decrypt_revisions, sync_status::*, RevisionCache, RevisionCipher, RevisionMergeBackup, RevisionMergeBackupSql,
+    RevisionRecord,
+};
 use dashmap::DashMap;
 use flowy_collaboration::{
     entities::revision::{RepeatedRevision, Revision, RevisionRange, RevisionState},
     util::{pair_rev_id_from_revisions, RevIdCounter},
 };
-use flowy_error::{FlowyError, FlowyResult};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
 use lib_infra::future::FutureResult;
 use std::{collections::VecDeque, sync::Arc};
 use tokio::sync::RwLock;
@@ -24,11 +27,24 @@ pub struct RevisionManager {
     rev_id_counter: RevIdCounter,
     revision_cache: Arc<RevisionCache>,
     revision_sync_seq: Arc<RevisionSyncSequence>,
+    // Encrypts the revisions that are sent to the server and decrypts the revisions that are
+    // received from it. The revisions are stored in the rev_table as plaintext. Only the documents
+    // set the cipher, see RevisionCipher.
+    cipher: Option<Arc<RevisionCipher>>,
+    is_loaded: bool,
 
     #[cfg(feature = "flowy_unit_test")]
     revision_ack_notifier: tokio::sync::broadcast::Sender<i64>,
 }
 
+impl std::ops::Drop for RevisionManager {
+    fn drop(&mut self) {
+        if self.is_loaded {
+            did_close_object(&self.object_id);
+        }
+    }
+}
+
 impl RevisionManager {
     pub fn new(user_id: &str, object_id: &str, revision_cache: Arc<RevisionCache>) -> Self {
         let rev_id_counter = RevIdCounter::new(0);
@@ -42,17 +58,35 @@ impl RevisionManager {
             rev_id_counter,
             revision_cache,
             revision_sync_seq,
+            cipher: None,
+            is_loaded: false,
 
             #[cfg(feature = "flowy_unit_test")]
             revision_ack_notifier,
         }
     }
 
+    pub fn with_cipher(mut self, cipher: Option<Arc<RevisionCipher>>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    pub fn encrypt_revisions(&self, revisions: Vec<Revision>) -> FlowyResult<Vec<Revision>> {
+        match &self.cipher {
+            None => Ok(revisions),
+            Some(cipher) => cipher.encrypt_revisions(revisions),
+        }
+    }
+
+    pub fn decrypt_revisions(&self, revisions: Vec<Revision>) -> FlowyResult<Vec<Revision>> {
+        decrypt_revisions(self.cipher.as_deref(), revisions)
+    }
+
     pub async fn load<Builder>(&mut self, cloud: Arc<dyn RevisionCloudService>) -> FlowyResult<Builder::Output>
     where
         Builder: RevisionObjectBuilder,
     {
-        let (revisions, rev_id) = RevisionLoader {
+        let (revisions, rev_id, acked_rev_id) = RevisionLoader {
             object_id: self.object_id.clone(),
             user_id: self.user_id.clone(),
             cloud,
@@ -62,6 +96,15 @@ impl RevisionManager {
         .load()
         .await?;
         self.rev_id_counter.set(rev_id);
+        if !self.is_loaded {
+            self.is_loaded = true;
+            did_load_object(&self.object_id);
+        }
+        let pending_count = self.revision_sync_seq.pending_count().await;
+        update_sync_status(&self.object_id, |status| {
+            status.pending_count = pending_count;
+            status.last_acked_rev_id = acked_rev_id;
+        });
         Builder::build_with_revisions(&self.object_id, revisions)
     }
 
@@ -70,9 +113,27 @@ impl RevisionManager {
         let rev_id = pair_rev_id_from_revisions(&revisions).1;
         let _ = self
             .revision_cache
-            .reset_with_revisions(&self.object_id, revisions.into_inner())
+            .reset_with_revisions(&self.object_id, revisions.into_inner(), RevisionState::Sync)
+            .await?;
+        self.rev_id_counter.set(rev_id);
+        Ok(())
+    }
+
+    // Resets the object with the revisions that are already on the server, so they are not synced
+    // again.
+    #[tracing::instrument(level = "debug", skip(self, revisions), err)]
+    pub async fn reset_object_with_remote_revisions(&self, revisions: RepeatedRevision) -> FlowyResult<()> {
+        let rev_id = pair_rev_id_from_revisions(&revisions).1;
+        let _ = self
+            .revision_cache
+            .reset_with_revisions(&self.object_id, revisions.into_inner(), RevisionState::Ack)
             .await?;
         self.rev_id_counter.set(rev_id);
+        update_sync_status(&self.object_id, |status| {
+            status.pending_count = 0;
+            status.last_acked_rev_id = rev_id;
+            status.last_sync_time = timestamp();
+        });
         Ok(())
     }
 
@@ -86,6 +147,10 @@ impl RevisionManager {
             .add(revision.clone(), RevisionState::Ack, true)
             .await?;
         self.rev_id_counter.set(revision.rev_id);
+        update_sync_status(&self.object_id, |status| {
+            status.last_acked_rev_id = revision.rev_id;
+            status.last_sync_time = timestamp();
+        });
         Ok(())
     }
 
@@ -100,16 +165,29 @@ impl RevisionManager {
             .add(revision.clone(), RevisionState::Sync, true)
             .await?;
         self.revision_sync_seq.add_revision_record(record).await?;
+        let pending_count = self.revision_sync_seq.pending_count().await;
+        update_sync_status(&self.object_id, |status| status.pending_count = pending_count);
         Ok(())
     }
 
+    // Acks all the pending revisions up to the rev_id, the server acks a batch of revisions with the
+    // rev_id of the last one.
     #[tracing::instrument(level = "debug", skip(self), err)]
     pub async fn ack_revision(&self, rev_id: i64) -> Result<(), FlowyError> {
-        if self.revision_sync_seq.ack(&rev_id).await.is_ok() {
-            self.revision_cache.ack(rev_id).await;
+        if let Ok(acked_rev_ids) = self.revision_sync_seq.ack(&rev_id).await {
+            for acked_rev_id in acked_rev_ids {
+                self.revision_cache.ack(acked_rev_id).await;
 
-            #[cfg(feature = "flowy_unit_test")]
-            let _ = self.revision_ack_notifier.send(rev_id);
+                #[cfg(feature = "flowy_unit_test")]
+                let _ = self.revision_ack_notifier.send(acked_rev_id);
+            }
+
+            let pending_count = self.revision_sync_seq.pending_count().await;
+            update_sync_status(&self.object_id, |status| {
+                status.pending_count = pending_count;
+                status.last_acked_rev_id = rev_id;
+                status.last_sync_time = timestamp();
+            });
         }
         Ok(())
     }
@@ -144,6 +222,108 @@ impl RevisionManager {
         })
     }
 
+    // Returns the consecutive pending revisions from the next one to sync. The batch stops before
+    // the revision that would exceed the max_bytes, but it always contains the next revision.
+    pub fn next_sync_revisions(&self, max_count: usize, max_bytes: usize) -> FutureResult<Vec<Revision>, FlowyError> {
+        let revision_sync_seq = self.revision_sync_seq.clone();
+        let revision_cache = self.revision_cache.clone();
+        FutureResult::new(async move {
+            let mut revisions = vec![];
+            let mut bytes = 0;
+            for rev_id in revision_sync_seq.pending_rev_ids(max_count).await {
+                let revision = match revision_sync_seq.revs_map.get(&rev_id) {
+                    Some(record) => record.revision.clone(),
+                    None => match revision_cache.get(rev_id).await {
+                        Some(record) => record.revision,
+                        None => break,
+                    },
+                };
+
+                bytes += revision.delta_data.len();
+                if !revisions.is_empty() && bytes > max_bytes {
+                    break;
+                }
+                revisions.push(revision);
+            }
+            Ok(revisions)
+        })
+    }
+
+    // Returns all the revisions that are not acked by the server in order.
+    pub async fn pending_revisions(&self) -> FlowyResult<Vec<Revision>> {
+        let mut revisions = vec![];
+        for rev_id in self.revision_sync_seq.pending_rev_ids(usize::MAX).await {
+            match self.revision_cache.get(rev_id).await {
+                Some(record) => revisions.push(record.revision),
+                None => return Err(FlowyError::record_not_found().context(format!("Revision {} is lost", rev_id))),
+            }
+        }
+        Ok(revisions)
+    }
+
+    // Saves the local state before the pending revisions are replaced by rebase_pending_revisions.
+    pub fn save_merge_backup(&self, backup: &RevisionMergeBackup) -> FlowyResult<()> {
+        let conn = self.revision_cache.db_pool().get().map_err(internal_error)?;
+        RevisionMergeBackupSql::create(backup, &*conn)
+    }
+
+    // Replaces the pending revisions with the revisions of the server that were applied before them
+    // and the revision that has the merged pending changes, so only the merged revision is synced.
+    #[tracing::instrument(level = "debug", skip(self, remote_revisions, merged_revision), err)]
+    pub async fn rebase_pending_revisions(
+        &self,
+        remote_revisions: Vec<Revision>,
+        merged_revision: Option<Revision>,
+    ) -> FlowyResult<()> {
+        let pending_rev_ids = self.revision_sync_seq.pending_rev_ids(usize::MAX).await;
+        let start_rev_id = match (pending_rev_ids.first(), remote_revisions.first()) {
+            (Some(pending_rev_id), Some(revision)) if *pending_rev_id == revision.rev_id => *pending_rev_id,
+            _ => {
+                return Err(FlowyError::internal().context("The remote revisions don't start at the pending revisions"))
+            }
+        };
+        let end_rev_id = pending_rev_ids.last().copied().unwrap_or(start_rev_id);
+        let acked_rev_id = remote_revisions
+            .last()
+            .map_or(start_rev_id - 1, |revision| revision.rev_id);
+
+        let mut revisions = remote_revisions
+            .into_iter()
+            .map(|revision| (revision, RevisionState::Ack))
+            .collect::<Vec<_>>();
+        if let Some(merged_revision) = merged_revision.as_ref() {
+            revisions.push((merged_revision.clone(), RevisionState::Sync));
+        }
+        let _ = self
+            .revision_cache
+            .replace_revisions(start_rev_id, end_rev_id, revisions)
+            .await?;
+
+        self.revision_sync_seq.clear().await;
+        let rev_id = match merged_revision {
+            None => acked_rev_id,
+            Some(revision) => {
+                let rev_id = revision.rev_id;
+                let record = RevisionRecord {
+                    revision,
+                    state: RevisionState::Sync,
+                    write_to_disk: false,
+                };
+                let _ = self.revision_sync_seq.add_revision_record(record).await?;
+                rev_id
+            }
+        };
+        self.rev_id_counter.set(rev_id);
+
+        let pending_count = self.revision_sync_seq.pending_count().await;
+        update_sync_status(&self.object_id, |status| {
+            status.pending_count = pending_count;
+            status.last_acked_rev_id = acked_rev_id;
+            status.last_sync_time = timestamp();
+        });
+        Ok(())
+    }
+
     pub async fn latest_revision(&self) -> Revision {
         self.revision_cache.latest_revision().await
     }
@@ -191,20 +371,42 @@ impl RevisionSyncSequence {
         Ok(())
     }
 
-    async fn ack(&self, rev_id: &i64) -> FlowyResult<()> {
-        if let Some(pop_rev_id) = self.next_sync_rev_id().await {
-            if &pop_rev_id != rev_id {
-                let desc = format!(
-                    "The ack rev_id:{} is not equal to the current rev_id:{}",
-                    rev_id, pop_rev_id
-                );
-                return Err(FlowyError::internal().context(desc));
-            }
+    // Pops the pending revisions up to the rev_id and returns their ids.
+    async fn ack(&self, rev_id: &i64) -> FlowyResult<Vec<i64>> {
+        let mut local_revs = self.local_revs.write().await;
+        if !local_revs.contains(rev_id) {
+            let desc = format!(
+                "The ack rev_id:{} is not in the pending revisions:{:?}",
+                rev_id,
+                local_revs.front()
+            );
+            return Err(FlowyError::internal().context(desc));
+        }
 
+        let mut acked_rev_ids = vec![];
+        while let Some(pop_rev_id) = local_revs.front().copied() {
+            if pop_rev_id > *rev_id {
+                break;
+            }
             self.revs_map.remove(&pop_rev_id);
-            let _ = self.local_revs.write().await.pop_front();
+            let _ = local_revs.pop_front();
+            acked_rev_ids.push(pop_rev_id);
         }
-        Ok(())
+        Ok(acked_rev_ids)
+    }
+
+    async fn clear(&self) {
+        let mut local_revs = self.local_revs.write().await;
+        local_revs.clear();
+        self.revs_map.clear();
+    }
+
+    async fn pending_count(&self) -> i64 {
+        self.local_revs.read().await.len() as i64
+    }
+
+    async fn pending_rev_ids(&self, max_count: usize) -> Vec<i64> {
+        self.local_revs.read().await.iter().take(max_count).copied().collect()
     }
 
     async fn next_sync_revision_record(&self) -> Option<(i64, RevisionRecord)> {
@@ -228,10 +430,13 @@ struct RevisionLoader {
 }
 
 impl RevisionLoader {
-    async fn load(&self) -> Result<(Vec<Revision>, i64), FlowyError> {
+    // Returns the revisions, the rev_id of the last revision and the rev_id of the last acked
+    // revision.
+    async fn load(&self) -> Result<(Vec<Revision>, i64, i64), FlowyError> {
         let records = self.revision_cache.batch_get(&self.object_id)?;
         let revisions: Vec<Revision>;
         let mut rev_id = 0;
+        let mut acked_rev_id = 0;
         if records.is_empty() {
             let remote_revisions = self.cloud.fetch_object(&self.user_id, &self.object_id).await?;
             for revision in &remote_revisions {
@@ -241,9 +446,13 @@ impl RevisionLoader {
                     .add(revision.clone(), RevisionState::Ack, true)
                     .await?;
             }
+            acked_rev_id = rev_id;
             revisions = remote_revisions;
         } else {
             for record in records.clone() {
+                if record.state == RevisionState::Ack {
+                    acked_rev_id = record.revision.rev_id;
+                }
                 let f = || async {
                     rev_id = record.revision.rev_id;
                     if record.state == RevisionState::Sync {
@@ -266,7 +475,7 @@ impl RevisionLoader {
             debug_assert_eq!(rev_id, revision.rev_id);
         }
 
-        Ok((revisions, rev_id))
+        Ok((revisions, rev_id, acked_rev_id))
     }
 }
 
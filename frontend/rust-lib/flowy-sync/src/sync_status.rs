@@ -0,0 +1,149 @@
+use dashmap::DashMap;
+use lazy_static::lazy_static;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast;
+
+lazy_static! {
+    static ref SYNC_STATUS_CENTER: RevisionSyncStatusCenter = RevisionSyncStatusCenter::new();
+}
+
+// The sync status of the object that is loaded by a RevisionManager. The objects that are not
+// loaded only have their pending revisions in the rev_table.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct RevisionSyncStatus {
+    pub object_id: String,
+    pub pending_count: i64,
+    pub last_acked_rev_id: i64,
+    // The timestamp in seconds of the last ack from the server, 0 if the object was never synced
+    // since it was loaded.
+    pub last_sync_time: i64,
+}
+
+impl RevisionSyncStatus {
+    pub fn is_synced(&self) -> bool {
+        self.pending_count == 0
+    }
+}
+
+struct RevisionSyncStatusCenter {
+    statuses: DashMap<String, RevisionSyncStatus>,
+    // The number of the RevisionManagers that loaded the object. The temporary RevisionManager, e.g.
+    // the one that resets the object, doesn't load the object, so it won't remove the status.
+    load_counts: DashMap<String, usize>,
+    notifier: broadcast::Sender<RevisionSyncStatus>,
+}
+
+impl RevisionSyncStatusCenter {
+    fn new() -> Self {
+        let (notifier, _) = broadcast::channel(100);
+        Self {
+            statuses: DashMap::new(),
+            load_counts: DashMap::new(),
+            notifier,
+        }
+    }
+}
+
+pub fn read_sync_status(object_id: &str) -> Option<RevisionSyncStatus> {
+    SYNC_STATUS_CENTER
+        .statuses
+        .get(object_id)
+        .map(|status| status.value().clone())
+}
+
+pub fn read_all_sync_status() -> Vec<RevisionSyncStatus> {
+    SYNC_STATUS_CENTER
+        .statuses
+        .iter()
+        .map(|status| status.value().clone())
+        .collect()
+}
+
+pub fn subscribe_sync_status() -> broadcast::Receiver<RevisionSyncStatus> {
+    SYNC_STATUS_CENTER.notifier.subscribe()
+}
+
+// Updates the status of the object and notifies the subscribers if the status was changed.
+pub(crate) fn update_sync_status<F>(object_id: &str, f: F)
+where
+    F: FnOnce(&mut RevisionSyncStatus),
+{
+    let status = {
+        let mut status = SYNC_STATUS_CENTER
+            .statuses
+            .entry(object_id.to_owned())
+            .or_insert_with(|| RevisionSyncStatus {
+                object_id: object_id.to_owned(),
+                ..Default::default()
+            });
+        let old_status = status.clone();
+        f(&mut status);
+        if *status == old_status {
+            return;
+        }
+        status.clone()
+    };
+    let _ = SYNC_STATUS_CENTER.notifier.send(status);
+}
+
+pub(crate) fn did_load_object(object_id: &str) {
+    *SYNC_STATUS_CENTER.load_counts.entry(object_id.to_owned()).or_insert(0) += 1;
+}
+
+// The status is removed after all the RevisionManagers that loaded the object were dropped, the
+// pending revisions of the object are read from the rev_table again.
+pub(crate) fn did_close_object(object_id: &str) {
+    let is_closed = match SYNC_STATUS_CENTER.load_counts.get_mut(object_id) {
+        None => false,
+        Some(mut count) => {
+            *count = count.saturating_sub(1);
+            *count == 0
+        }
+    };
+    if is_closed {
+        SYNC_STATUS_CENTER.load_counts.remove(object_id);
+        SYNC_STATUS_CENTER.statuses.remove(object_id);
+    }
+}
+
+// Removes the statuses of all the objects, e.g. the user signed out, so the objects of the previous
+// user are not reported to the next one.
+pub fn clear_sync_status() {
+    SYNC_STATUS_CENTER.statuses.clear();
+}
+
+pub(crate) fn timestamp() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{did_close_object, did_load_object, read_sync_status, update_sync_status};
+
+    #[test]
+    fn sync_status_removed_after_all_closed() {
+        let object_id = "sync_status_removed_after_all_closed";
+        did_load_object(object_id);
+        did_load_object(object_id);
+        update_sync_status(object_id, |status| status.pending_count = 1);
+
+        did_close_object(object_id);
+        assert_eq!(read_sync_status(object_id).unwrap().pending_count, 1);
+
+        did_close_object(object_id);
+        assert!(read_sync_status(object_id).is_none());
+    }
+
+    #[test]
+    fn sync_status_kept_after_temporary_manager_closed() {
+        let object_id = "sync_status_kept_after_temporary_manager_closed";
+        update_sync_status(object_id, |status| status.pending_count = 1);
+
+        // The object was not loaded, e.g. the RevisionManager that only resets the object.
+        did_close_object(object_id);
+        assert!(read_sync_status(object_id).is_some());
+    }
+}
@@ -317,6 +317,11 @@ async fn tick(sender: mpsc::Sender<()>, duration: Duration) {
     }
 }
 
+// The pending revisions are sent in batches, and the server acks a batch at once, so reconnecting
+// after a long offline session doesn't wait for the ack of each revision.
+pub const MAX_BATCH_REVISIONS: usize = 100;
+pub const MAX_BATCH_BYTES: usize = 512 * 1024;
+
 #[derive(Clone)]
 enum Source {
     Custom,
@@ -361,12 +366,18 @@ impl CompositeWSSinkDataProvider {
                     return Ok(None);
                 }
 
-                match self.rev_manager.next_sync_revision().await? {
-                    Some(rev) => Ok(Some(ClientRevisionWSData::from_revisions(&self.object_id, vec![rev]))),
-                    None => Ok(Some(ClientRevisionWSData::ping(
+                let revisions = self
+                    .rev_manager
+                    .next_sync_revisions(MAX_BATCH_REVISIONS, MAX_BATCH_BYTES)
+                    .await?;
+                if revisions.is_empty() {
+                    Ok(Some(ClientRevisionWSData::ping(
                         &self.object_id,
                         self.rev_manager.rev_id(),
-                    ))),
+                    )))
+                } else {
+                    let revisions = self.rev_manager.encrypt_revisions(revisions)?;
+                    Ok(Some(ClientRevisionWSData::from_revisions(&self.object_id, revisions)))
                 }
             }
         };
@@ -380,8 +391,8 @@ impl CompositeWSSinkDataProvider {
                 let should_pop = match self.container.read().await.front() {
                     None => false,
                     Some(val) => {
-                        let expected_id = val.id();
-                        if expected_id == id {
+                        let expected_id = val.ack_id();
+                        if expected_id == id || val.id() == id {
                             true
                         } else {
                             tracing::error!("The front element's {} is not equal to the {}", expected_id, id);
@@ -409,6 +420,7 @@ impl ResolverRevisionSink for Arc<CompositeWSSinkDataProvider> {
     fn send(&self, revisions: Vec<Revision>) -> BoxResultFuture<(), FlowyError> {
         let sink = self.clone();
         Box::pin(async move {
+            let revisions = sink.rev_manager.encrypt_revisions(revisions)?;
             sink.push_data(ClientRevisionWSData::from_revisions(&sink.object_id, revisions))
                 .await;
             Ok(())
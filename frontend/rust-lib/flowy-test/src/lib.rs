@@ -3,7 +3,7 @@ pub mod helper;
 
 use crate::helper::*;
 use backend_service::configuration::{get_client_server_configuration, ClientServerConfiguration};
-use flowy_sdk::{FlowySDK, FlowySDKConfig};
+use flowy_sdk::{DocumentCacheConfig, FlowySDK, FlowySDKConfig};
 use flowy_user::entities::UserProfile;
 use lib_infra::uuid_string;
 
@@ -37,6 +37,18 @@ impl std::default::Default for FlowySDKTest {
 impl FlowySDKTest {
     pub fn new(server_config: ClientServerConfiguration) -> Self {
         let config = FlowySDKConfig::new(&root_dir(), server_config, &uuid_string()).log_filter("trace");
+        Self::with_config(config)
+    }
+
+    pub fn with_document_cache(document_cache: DocumentCacheConfig) -> Self {
+        let server_config = get_client_server_configuration().unwrap();
+        let config = FlowySDKConfig::new(&root_dir(), server_config, &uuid_string())
+            .log_filter("trace")
+            .document_cache(document_cache);
+        Self::with_config(config)
+    }
+
+    fn with_config(config: FlowySDKConfig) -> Self {
         let sdk = std::thread::spawn(|| FlowySDK::new(config)).join().unwrap();
         std::mem::forget(sdk.dispatcher());
         Self { inner: sdk }
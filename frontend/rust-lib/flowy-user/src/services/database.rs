@@ -1,4 +1,5 @@
-use flowy_database::{schema::user_table, DBConnection, Database};
+use crate::services::database_key::UserDatabaseKeyStore;
+use flowy_database::{schema::user_table, DBConnection, Database, DatabaseKey};
 use flowy_error::FlowyError;
 use flowy_user_data_model::entities::{SignInResponse, SignUpResponse, UpdateUserParams, UserProfile};
 use lazy_static::lazy_static;
@@ -13,12 +14,41 @@ lazy_static! {
 
 pub struct UserDB {
     db_dir: String,
+    // The databases are not encrypted if it's None.
+    key_store: Option<UserDatabaseKeyStore>,
 }
 
 impl UserDB {
-    pub fn new(db_dir: &str) -> Self {
+    pub fn new(db_dir: &str, device_key: Option<DatabaseKey>) -> Self {
+        let key_store = device_key.map(|device_key| UserDatabaseKeyStore::new(db_dir, device_key));
         Self {
             db_dir: db_dir.to_owned(),
+            key_store,
+        }
+    }
+
+    pub(crate) fn unlock_user_db(&self, user_id: &str, password: &str) -> Result<(), FlowyError> {
+        match &self.key_store {
+            None => Ok(()),
+            Some(key_store) => {
+                // The database may be replaced if the key can't be unlocked, it's reopened with the
+                // unlocked key.
+                let _ = self.close_user_db(user_id)?;
+                key_store.unlock(user_id, password)
+            }
+        }
+    }
+
+    pub(crate) fn lock_user_db(&self, user_id: &str) {
+        if let Some(key_store) = &self.key_store {
+            key_store.lock(user_id);
+        }
+    }
+
+    pub(crate) fn change_password(&self, user_id: &str, password: &str) -> Result<(), FlowyError> {
+        match &self.key_store {
+            None => Ok(()),
+            Some(key_store) => key_store.change_password(user_id, password),
         }
     }
 
@@ -29,7 +59,11 @@ impl UserDB {
 
         tracing::info!("open user db {}", user_id);
         let dir = format!("{}/{}", self.db_dir, user_id);
-        let db = flowy_database::init(&dir).map_err(|e| {
+        let key = match &self.key_store {
+            None => None,
+            Some(key_store) => Some(key_store.get_key(user_id)?),
+        };
+        let db = flowy_database::init_with_key(&dir, key).map_err(|e| {
             log::error!("init user db failed, {:?}, user_id: {}", e, user_id);
             FlowyError::internal().context(e)
         })?;
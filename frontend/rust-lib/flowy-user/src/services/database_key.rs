@@ -0,0 +1,327 @@
+use aes_gcm::{
+    aead::{Aead, NewAead},
+    Aes256Gcm, Key, Nonce,
+};
+use argon2::Argon2;
+use flowy_database::{kv::KV, DatabaseKey, DB_NAME};
+use flowy_error::FlowyError;
+use lib_sqlite::DATABASE_KEY_LEN;
+use rand::RngCore;
+use std::{
+    fs,
+    io::ErrorKind,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const DATABASE_KEY_FILE: &str = "database.key";
+const DATABASE_KEY_CACHE: &str = "user_database_key";
+const WRAPPED_KEY_VERSION: u8 = 1;
+const DEVICE_WRAPPED_KEY_VERSION: u8 = 2;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+// The database of each user is encrypted with a random key. The key is wrapped with the key that is
+// derived from the password and saved next to the database, so it's only unwrapped when the user
+// signs in. The unwrapped key is cached in the kv.db, which is encrypted with the key of the device,
+// until the user signs out. Changing the password wraps the same key again, so the database is not
+// encrypted again.
+pub(crate) struct UserDatabaseKeyStore {
+    root_dir: String,
+    // Wraps the key that is generated without the password, until the user signs in again.
+    device_key: DatabaseKey,
+}
+
+impl UserDatabaseKeyStore {
+    pub(crate) fn new(root_dir: &str, device_key: DatabaseKey) -> Self {
+        Self {
+            root_dir: root_dir.to_owned(),
+            device_key,
+        }
+    }
+
+    // Creates the key if the user database has no key, e.g. the user signed in before the
+    // encryption was enabled. The key is wrapped with the key of the device, so the database can
+    // still be opened if the app is killed before the user signs in again, and it's wrapped with
+    // the password when the user signs in next time.
+    pub(crate) fn get_key(&self, user_id: &str) -> Result<DatabaseKey, FlowyError> {
+        if let Some(key) = read_cached_key(user_id) {
+            return Ok(key);
+        }
+
+        let key = match self.read_wrapped_key(user_id)? {
+            Some(wrapped_key) if is_device_wrapped_key(&wrapped_key) => {
+                device_unwrap_key(&wrapped_key, &self.device_key)?
+            }
+            Some(_) => return Err(FlowyError::user_database_locked()),
+            None => {
+                let key = generate_key()?;
+                let _ = self.write_key_file(user_id, device_wrap_key(&key, &self.device_key)?)?;
+                key
+            }
+        };
+        cache_key(user_id, &key);
+        Ok(key)
+    }
+
+    // The key can't be unwrapped if the password was changed on another device, the password is
+    // verified by the server before, so the database is moved aside with its wrapped key and a
+    // new database is created with a new key. The objects are downloaded from the server again
+    // when they are opened, the revisions that were not synced are only in the moved database.
+    #[tracing::instrument(level = "debug", skip(self, password), err)]
+    pub(crate) fn unlock(&self, user_id: &str, password: &str) -> Result<(), FlowyError> {
+        let key = match read_cached_key(user_id) {
+            Some(key) => key,
+            None => match self.read_wrapped_key(user_id)? {
+                None => generate_key()?,
+                Some(wrapped_key) if is_device_wrapped_key(&wrapped_key) => {
+                    device_unwrap_key(&wrapped_key, &self.device_key)?
+                }
+                Some(wrapped_key) => match unwrap_key(&wrapped_key, password) {
+                    Ok(key) => key,
+                    Err(e) => {
+                        tracing::warn!("Unlock the database of {} failed: {:?}, create a new one", user_id, e);
+                        let _ = self.move_database_aside(user_id)?;
+                        generate_key()?
+                    }
+                },
+            },
+        };
+        let _ = self.save_wrapped_key(user_id, &key, password)?;
+        cache_key(user_id, &key);
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, password), err)]
+    pub(crate) fn change_password(&self, user_id: &str, password: &str) -> Result<(), FlowyError> {
+        let key = self.get_key(user_id)?;
+        self.save_wrapped_key(user_id, &key, password)
+    }
+
+    // The key can't be read without the password after the user signed out.
+    pub(crate) fn lock(&self, user_id: &str) {
+        if let Err(e) = KV::remove(&cache_key_name(user_id)) {
+            tracing::error!("Remove the database key of {} failed: {}", user_id, e);
+        }
+    }
+
+    fn key_file_path(&self, user_id: &str) -> PathBuf {
+        PathBuf::from(&self.root_dir).join(user_id).join(DATABASE_KEY_FILE)
+    }
+
+    // The files are renamed with the timestamp, so the database can be opened with the old password
+    // later.
+    fn move_database_aside(&self, user_id: &str) -> Result<(), FlowyError> {
+        let dir = PathBuf::from(&self.root_dir).join(user_id);
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+        let db_file = |suffix: &str| format!("{}{}", DB_NAME, suffix);
+        let file_names = vec![
+            db_file(""),
+            db_file("-wal"),
+            db_file("-shm"),
+            DATABASE_KEY_FILE.to_owned(),
+        ];
+        for file_name in file_names {
+            let path = dir.join(&file_name);
+            if path.exists() {
+                let backup_path = dir.join(format!("{}.{}.bak", file_name, timestamp));
+                let _ = fs::rename(&path, &backup_path).map_err(|e| FlowyError::internal().context(e))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn read_wrapped_key(&self, user_id: &str) -> Result<Option<Vec<u8>>, FlowyError> {
+        match fs::read(self.key_file_path(user_id)) {
+            Ok(wrapped_key) => Ok(Some(wrapped_key)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(FlowyError::internal().context(e)),
+        }
+    }
+
+    fn save_wrapped_key(&self, user_id: &str, key: &DatabaseKey, password: &str) -> Result<(), FlowyError> {
+        self.write_key_file(user_id, wrap_key(key, password)?)
+    }
+
+    fn write_key_file(&self, user_id: &str, wrapped_key: Vec<u8>) -> Result<(), FlowyError> {
+        let path = self.key_file_path(user_id);
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir).map_err(|e| FlowyError::internal().context(e))?;
+        }
+        // The old wrapped key is kept if the app was killed while writing.
+        let tmp_path = path.with_extension("tmp");
+        let _ = fs::write(&tmp_path, wrapped_key).map_err(|e| FlowyError::internal().context(e))?;
+        let _ = fs::rename(&tmp_path, &path).map_err(|e| FlowyError::internal().context(e))?;
+        Ok(())
+    }
+}
+
+fn cache_key_name(user_id: &str) -> String {
+    format!("{}:{}", DATABASE_KEY_CACHE, user_id)
+}
+
+fn read_cached_key(user_id: &str) -> Option<DatabaseKey> {
+    let hex = KV::get_str(&cache_key_name(user_id))?;
+    match DatabaseKey::from_hex(&hex) {
+        Ok(key) => Some(key),
+        Err(e) => {
+            tracing::error!("The cached database key of {} is invalid: {:?}", user_id, e);
+            None
+        }
+    }
+}
+
+fn cache_key(user_id: &str, key: &DatabaseKey) {
+    KV::set_str(&cache_key_name(user_id), key.to_hex());
+}
+
+fn generate_key() -> Result<DatabaseKey, FlowyError> {
+    let mut bytes = vec![0; DATABASE_KEY_LEN];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    DatabaseKey::from_bytes(bytes).map_err(|e| FlowyError::internal().context(e))
+}
+
+fn password_cipher(password: &str, salt: &[u8]) -> Result<Aes256Gcm, FlowyError> {
+    let mut key = [0; 32];
+    let _ = Argon2::default()
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|e| FlowyError::internal().context(format!("Derive the key from the password failed: {}", e)))?;
+    Ok(Aes256Gcm::new(Key::from_slice(&key)))
+}
+
+// [version][salt][nonce][ciphertext]
+fn wrap_key(key: &DatabaseKey, password: &str) -> Result<Vec<u8>, FlowyError> {
+    let mut salt = [0; SALT_LEN];
+    let mut nonce = [0; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut salt);
+    rand::thread_rng().fill_bytes(&mut nonce);
+    let ciphertext = password_cipher(password, &salt)?
+        .encrypt(Nonce::from_slice(&nonce), key.as_bytes())
+        .map_err(|_| FlowyError::internal().context("Wrap the database key failed"))?;
+
+    let mut wrapped_key = Vec::with_capacity(1 + SALT_LEN + NONCE_LEN + ciphertext.len());
+    wrapped_key.push(WRAPPED_KEY_VERSION);
+    wrapped_key.extend_from_slice(&salt);
+    wrapped_key.extend_from_slice(&nonce);
+    wrapped_key.extend_from_slice(&ciphertext);
+    Ok(wrapped_key)
+}
+
+// [version][nonce][ciphertext]
+fn device_wrap_key(key: &DatabaseKey, device_key: &DatabaseKey) -> Result<Vec<u8>, FlowyError> {
+    let mut nonce = [0; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce);
+    let ciphertext = Aes256Gcm::new(Key::from_slice(device_key.as_bytes()))
+        .encrypt(Nonce::from_slice(&nonce), key.as_bytes())
+        .map_err(|_| FlowyError::internal().context("Wrap the database key failed"))?;
+
+    let mut wrapped_key = Vec::with_capacity(1 + NONCE_LEN + ciphertext.len());
+    wrapped_key.push(DEVICE_WRAPPED_KEY_VERSION);
+    wrapped_key.extend_from_slice(&nonce);
+    wrapped_key.extend_from_slice(&ciphertext);
+    Ok(wrapped_key)
+}
+
+fn is_device_wrapped_key(wrapped_key: &[u8]) -> bool {
+    wrapped_key.first() == Some(&DEVICE_WRAPPED_KEY_VERSION)
+}
+
+fn device_unwrap_key(wrapped_key: &[u8], device_key: &DatabaseKey) -> Result<DatabaseKey, FlowyError> {
+    if wrapped_key.len() < 1 + NONCE_LEN || !is_device_wrapped_key(wrapped_key) {
+        return Err(FlowyError::internal().context("The wrapped database key is invalid"));
+    }
+    let (nonce, ciphertext) = wrapped_key[1..].split_at(NONCE_LEN);
+    let bytes = Aes256Gcm::new(Key::from_slice(device_key.as_bytes()))
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| FlowyError::user_database_locked().context("The key of the device is changed"))?;
+    DatabaseKey::from_bytes(bytes).map_err(|e| FlowyError::internal().context(e))
+}
+
+fn unwrap_key(wrapped_key: &[u8], password: &str) -> Result<DatabaseKey, FlowyError> {
+    if wrapped_key.len() < 1 + SALT_LEN + NONCE_LEN || wrapped_key[0] != WRAPPED_KEY_VERSION {
+        return Err(FlowyError::internal().context("The wrapped database key is invalid"));
+    }
+    let (salt, rest) = wrapped_key[1..].split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let bytes = password_cipher(password, salt)?
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| FlowyError::password_not_match().context("The local database can't be unlocked"))?;
+    DatabaseKey::from_bytes(bytes).map_err(|e| FlowyError::internal().context(e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{device_unwrap_key, device_wrap_key, generate_key, unwrap_key, wrap_key};
+
+    #[test]
+    fn wrap_database_key_test() {
+        let key = generate_key().unwrap();
+        let wrapped_key = wrap_key(&key, "password").unwrap();
+        assert_ne!(wrap_key(&key, "password").unwrap(), wrapped_key);
+        assert_eq!(unwrap_key(&wrapped_key, "password").unwrap(), key);
+        assert!(unwrap_key(&wrapped_key, "another password").is_err());
+        assert!(unwrap_key(&wrapped_key[..8], "password").is_err());
+    }
+
+    #[test]
+    fn device_wrap_database_key_test() {
+        let key = generate_key().unwrap();
+        let device_key = generate_key().unwrap();
+        let wrapped_key = device_wrap_key(&key, &device_key).unwrap();
+        assert_eq!(device_unwrap_key(&wrapped_key, &device_key).unwrap(), key);
+        assert!(device_unwrap_key(&wrapped_key, &generate_key().unwrap()).is_err());
+        assert!(unwrap_key(&wrapped_key, "password").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "sqlcipher")]
+    fn encrypted_database_unlock_test() {
+        use super::UserDatabaseKeyStore;
+        use flowy_database::DB_NAME;
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+        let root = std::env::temp_dir().join(format!("flowy_user_db_test_{}", nanos));
+        let root = root.to_str().unwrap().to_owned();
+        let user_id = format!("user_{}", nanos);
+        let dir = format!("{}/{}", root, user_id);
+        let store = UserDatabaseKeyStore::new(&root, generate_key().unwrap());
+
+        // The generated key is wrapped with the key of the device before the user signs in.
+        let key = store.get_key(&user_id).unwrap();
+        assert!(store.key_file_path(&user_id).exists());
+        assert!(flowy_database::init_with_key(&dir, Some(key.clone())).is_ok());
+        store.lock(&user_id);
+        assert_eq!(store.get_key(&user_id).unwrap(), key);
+
+        // The key is wrapped with the password after the user signs in.
+        store.unlock(&user_id, "password").unwrap();
+        store.lock(&user_id);
+        assert!(store.get_key(&user_id).is_err());
+        store.unlock(&user_id, "password").unwrap();
+        assert_eq!(store.get_key(&user_id).unwrap(), key);
+        assert!(flowy_database::init_with_key(&dir, Some(generate_key().unwrap())).is_err());
+        assert!(flowy_database::init_with_key(&dir, Some(key.clone())).is_ok());
+
+        // The password was changed on another device, the database is moved aside and a new one is
+        // created with a new key.
+        store.lock(&user_id);
+        store.unlock(&user_id, "new password").unwrap();
+        let new_key = store.get_key(&user_id).unwrap();
+        assert_ne!(new_key, key);
+        assert!(flowy_database::init_with_key(&dir, Some(new_key)).is_ok());
+        let backups = std::fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .filter(|file_name| file_name.ends_with(".bak"))
+            .collect::<Vec<String>>();
+        assert!(backups.iter().any(|file_name| file_name.starts_with(DB_NAME)));
+        assert!(backups.iter().any(|file_name| file_name.starts_with("database.key")));
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod database;
+mod database_key;
 pub mod notifier;
 mod user_session;
 pub use user_session::*;
@@ -11,7 +11,7 @@ use flowy_database::{
     kv::KV,
     query_dsl::*,
     schema::{user_table, user_table::dsl},
-    DBConnection, ExpressionMethods, UserDatabaseConnection,
+    DBConnection, DatabaseKey, ExpressionMethods, UserDatabaseConnection,
 };
 use flowy_user_data_model::entities::{
     SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserParams, UserProfile,
@@ -25,6 +25,8 @@ use tokio::sync::mpsc;
 pub struct UserSessionConfig {
     root_dir: String,
     session_cache_key: String,
+    // The key of the device, the user databases are not encrypted if it's None.
+    database_key: Option<DatabaseKey>,
 }
 
 impl UserSessionConfig {
@@ -32,8 +34,15 @@ impl UserSessionConfig {
         Self {
             root_dir: root_dir.to_owned(),
             session_cache_key: session_cache_key.to_owned(),
+            database_key: None,
         }
     }
+
+    // The user databases are encrypted with the key that is unlocked by the password of the user.
+    pub fn database_key(mut self, database_key: Option<DatabaseKey>) -> Self {
+        self.database_key = database_key;
+        self
+    }
 }
 
 pub struct UserSession {
@@ -46,7 +55,7 @@ pub struct UserSession {
 
 impl UserSession {
     pub fn new(config: UserSessionConfig, cloud_service: Arc<dyn UserCloudService>) -> Self {
-        let db = UserDB::new(&config.root_dir);
+        let db = UserDB::new(&config.root_dir, config.database_key.clone());
         let notifier = UserNotifier::new();
         Self {
             database: db,
@@ -84,7 +93,9 @@ impl UserSession {
         if self.is_user_login(&params.email) {
             self.user_profile().await
         } else {
+            let password = params.password.clone();
             let resp = self.cloud_service.sign_in(params).await?;
+            let _ = self.database.unlock_user_db(&resp.user_id, &password)?;
             let session: Session = resp.clone().into();
             let _ = self.set_session(Some(session))?;
             let user_table = self.save_user(resp.into()).await?;
@@ -99,7 +110,9 @@ impl UserSession {
         if self.is_user_login(&params.email) {
             self.user_profile().await
         } else {
+            let password = params.password.clone();
             let resp = self.cloud_service.sign_up(params).await?;
+            let _ = self.database.unlock_user_db(&resp.user_id, &password)?;
             let session: Session = resp.clone().into();
             let _ = self.set_session(Some(session))?;
             let user_table = self.save_user(resp.into()).await?;
@@ -118,6 +131,7 @@ impl UserSession {
         let _ =
             diesel::delete(dsl::user_table.filter(dsl::id.eq(&session.user_id))).execute(&*(self.db_connection()?))?;
         let _ = self.database.close_user_db(&session.user_id)?;
+        self.database.lock_user_db(&session.user_id);
         let _ = self.set_session(None)?;
         self.notifier.notify_logout(&session.token);
         let _ = self.sign_out_on_server(&session.token).await?;
@@ -129,9 +143,19 @@ impl UserSession {
     pub async fn update_user(&self, params: UpdateUserParams) -> Result<(), FlowyError> {
         let session = self.get_session()?;
         let changeset = UserTableChangeset::new(params.clone());
-        diesel_update_table!(user_table, changeset, &*self.db_connection()?);
-
-        let _ = self.update_user_on_server(&session.token, params).await?;
+        match params.password.clone() {
+            None => {
+                diesel_update_table!(user_table, changeset, &*self.db_connection()?);
+                let _ = self.update_user_on_server(&session.token, params).await?;
+            }
+            Some(password) => {
+                // The database key is re-wrapped only after the server accepts the new password, or the
+                // key would be wrapped with the password that can't be used to sign in.
+                let _ = self.cloud_service.update_user(&session.token, params).await?;
+                diesel_update_table!(user_table, changeset, &*self.db_connection()?);
+                let _ = self.database.change_password(&session.user_id, &password)?;
+            }
+        }
         Ok(())
     }
 
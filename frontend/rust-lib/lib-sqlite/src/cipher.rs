@@ -0,0 +1,140 @@
+use crate::{conn_ext::ConnectionExtension, errors::*, pragma::PragmaExtension};
+use diesel::{connection::Connection, SqliteConnection};
+use std::{
+    fs::{self, File},
+    io::{ErrorKind, Read},
+};
+
+pub const DATABASE_KEY_LEN: usize = 32;
+const PLAINTEXT_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+// The raw key of the SQLCipher database, it's passed to the SQLCipher as the hex blob literal, so
+// the SQLCipher uses it as the key instead of deriving the key from it.
+#[derive(Clone, PartialEq, Eq)]
+pub struct DatabaseKey(Vec<u8>);
+
+impl std::fmt::Debug for DatabaseKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("DatabaseKey(***)")
+    }
+}
+
+impl DatabaseKey {
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self> {
+        if bytes.len() != DATABASE_KEY_LEN {
+            return Err(format!("The length of the database key should be {}", DATABASE_KEY_LEN).into());
+        }
+        Ok(Self(bytes))
+    }
+
+    pub fn from_hex(s: &str) -> Result<Self> {
+        let bytes = (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(|| Error::from("The database key is not a hex string"))?;
+        Self::from_bytes(bytes)
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    fn sql_literal(&self) -> String {
+        format!("\"x'{}'\"", self.to_hex())
+    }
+}
+
+pub trait CipherExtension: ConnectionExtension + PragmaExtension {
+    // Must be called before any other statement of the connection. The key is not logged.
+    fn set_key(&self, key: &DatabaseKey) -> Result<()> {
+        let _ = self.pragma_get_cipher_version()?;
+        self.exec(format!("PRAGMA key = {}", key.sql_literal()))?;
+        // The key is checked when the database is read at the first time.
+        self.exec("SELECT count(*) FROM sqlite_master")
+            .map_err(|_| Error::from("The database can't be opened with the key"))?;
+        Ok(())
+    }
+
+    // Returns error if the SQLite is not the SQLCipher, the PRAGMA key is ignored by the SQLite.
+    fn pragma_get_cipher_version(&self) -> Result<String> {
+        self.pragma_get::<diesel::sql_types::Text, String>("cipher_version", None)
+            .map_err(|_| Error::from("The SQLite is built without the SQLCipher, enable the sqlcipher feature"))
+    }
+}
+impl CipherExtension for SqliteConnection {}
+
+// Returns true if the file is the database that is not encrypted. The file that doesn't exist or
+// is empty is not the plaintext database, it's created with the key.
+pub fn is_plaintext_database(path: &str) -> Result<bool> {
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(e.into()),
+    };
+    let mut header = [0; 16];
+    match file.read_exact(&mut header) {
+        Ok(_) => Ok(&header == PLAINTEXT_HEADER),
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+// Exports the plaintext database to a new database that is encrypted with the key, and replaces
+// the plaintext database with it. The plaintext database is kept if the export failed.
+pub fn encrypt_database(path: &str, key: &DatabaseKey) -> Result<()> {
+    let encrypted_path = format!("{}.encrypted", path);
+    let _ = fs::remove_file(&encrypted_path);
+    {
+        let conn = SqliteConnection::establish(path)?;
+        let _ = conn.pragma_get_cipher_version()?;
+        conn.exec(format!(
+            "ATTACH DATABASE '{}' AS encrypted KEY {}",
+            encrypted_path.replace('\'', "''"),
+            key.sql_literal()
+        ))?;
+        conn.exec("SELECT sqlcipher_export('encrypted')")?;
+        conn.exec("DETACH DATABASE encrypted")?;
+    }
+
+    fs::rename(&encrypted_path, path)?;
+    // The WAL of the plaintext database was exported, it can't be applied to the new database.
+    let _ = fs::remove_file(format!("{}-wal", path));
+    let _ = fs::remove_file(format!("{}-shm", path));
+    log::info!("Encrypted the plaintext database {}", path);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{is_plaintext_database, DatabaseKey};
+
+    #[test]
+    fn database_key_hex_test() {
+        let key = DatabaseKey::from_bytes((0..32).collect()).unwrap();
+        assert_eq!(DatabaseKey::from_hex(&key.to_hex()).unwrap(), key);
+        assert!(DatabaseKey::from_hex("00ff").is_err());
+        assert!(DatabaseKey::from_hex(&"zz".repeat(32)).is_err());
+        assert_eq!(format!("{:?}", key), "DatabaseKey(***)");
+    }
+
+    #[test]
+    fn plaintext_database_test() {
+        let dir = std::env::temp_dir().join(format!("lib-sqlite-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("plaintext.db");
+        let path = path.to_str().unwrap();
+        assert!(!is_plaintext_database(path).unwrap());
+
+        std::fs::write(path, b"SQLite format 3\0 and pages").unwrap();
+        assert!(is_plaintext_database(path).unwrap());
+
+        std::fs::write(path, vec![7; 64]).unwrap();
+        assert!(!is_plaintext_database(path).unwrap());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
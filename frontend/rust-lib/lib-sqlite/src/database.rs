@@ -1,4 +1,5 @@
 use crate::{
+    cipher::{encrypt_database, is_plaintext_database, DatabaseKey},
     errors::*,
     pool::{ConnectionManager, ConnectionPool, PoolConfig},
 };
@@ -14,13 +15,25 @@ pub type DBConnection = PooledConnection<ConnectionManager>;
 
 impl Database {
     pub fn new(dir: &str, name: &str, pool_config: PoolConfig) -> Result<Self> {
+        Self::new_with_key(dir, name, pool_config, None)
+    }
+
+    // Opens the database that is encrypted with the key. The database that was created without
+    // the key is encrypted before it's opened.
+    pub fn new_with_key(dir: &str, name: &str, pool_config: PoolConfig, key: Option<DatabaseKey>) -> Result<Self> {
         let uri = db_file_uri(dir, name);
 
         if !std::path::PathBuf::from(dir).exists() {
             log::error!("Create database failed. {} not exists", &dir);
         }
 
-        let pool = ConnectionPool::new(pool_config, &uri)?;
+        if let Some(key) = &key {
+            if is_plaintext_database(&uri)? {
+                let _ = encrypt_database(&uri, key)?;
+            }
+        }
+
+        let pool = ConnectionPool::new_with_key(pool_config, &uri, key)?;
         Ok(Self {
             uri,
             pool: Arc::new(pool),
@@ -1,3 +1,4 @@
+mod cipher;
 mod conn_ext;
 mod database;
 #[allow(deprecated, clippy::large_enum_variant)]
@@ -5,6 +6,7 @@ mod errors;
 mod pool;
 mod pragma;
 
+pub use cipher::{is_plaintext_database, DatabaseKey, DATABASE_KEY_LEN};
 pub use database::*;
 pub use pool::*;
 
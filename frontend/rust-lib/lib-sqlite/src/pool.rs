@@ -1,4 +1,4 @@
-use crate::{errors::*, pragma::*};
+use crate::{cipher::*, errors::*, pragma::*};
 use diesel::{connection::Connection, SqliteConnection};
 use r2d2::{CustomizeConnection, ManageConnection, Pool};
 use scheduled_thread_pool::ScheduledThreadPool;
@@ -27,7 +27,15 @@ impl ConnectionPool {
     where
         T: Into<String>,
     {
-        let manager = ConnectionManager::new(uri);
+        Self::new_with_key(config, uri, None)
+    }
+
+    // Each connection of the pool is opened with the key if it's not None.
+    pub fn new_with_key<T>(config: PoolConfig, uri: T, key: Option<DatabaseKey>) -> Result<Self>
+    where
+        T: Into<String>,
+    {
+        let manager = ConnectionManager::new(uri).key(key);
         let thread_pool = DB_POOL.clone();
         let config = Arc::new(config);
         let customizer_config = DatabaseCustomizerConfig::default();
@@ -81,6 +89,7 @@ impl PoolConfig {
 
 pub struct ConnectionManager {
     db_uri: String,
+    key: Option<DatabaseKey>,
 }
 
 impl ManageConnection for ConnectionManager {
@@ -88,7 +97,11 @@ impl ManageConnection for ConnectionManager {
     type Error = crate::Error;
 
     fn connect(&self) -> Result<Self::Connection> {
-        Ok(SqliteConnection::establish(&self.db_uri)?)
+        let conn = SqliteConnection::establish(&self.db_uri)?;
+        if let Some(key) = &self.key {
+            let _ = conn.set_key(key)?;
+        }
+        Ok(conn)
     }
 
     fn is_valid(&self, conn: &mut Self::Connection) -> Result<()> {
@@ -102,7 +115,15 @@ impl ManageConnection for ConnectionManager {
 
 impl ConnectionManager {
     pub fn new<S: Into<String>>(uri: S) -> Self {
-        ConnectionManager { db_uri: uri.into() }
+        ConnectionManager {
+            db_uri: uri.into(),
+            key: None,
+        }
+    }
+
+    pub fn key(mut self, key: Option<DatabaseKey>) -> Self {
+        self.key = key;
+        self
     }
 }
 
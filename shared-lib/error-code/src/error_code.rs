@@ -30,6 +30,9 @@ pub enum ErrorCode {
     #[display(fmt = "Workspace description too long")]
     WorkspaceNameTooLong = 104,
 
+    #[display(fmt = "Encryption passphrase of the workspace is too short")]
+    WorkspacePassphraseInvalid = 105,
+
     #[display(fmt = "App id can not be empty or whitespace")]
     AppIdInvalid = 110,
 
@@ -54,8 +57,63 @@ pub enum ErrorCode {
     #[display(fmt = "View name too long")]
     ViewNameTooLong = 125,
 
+    #[display(fmt = "View tag can not be empty or whitespace")]
+    ViewTagInvalid = 126,
+
+    #[display(fmt = "View property key can not be empty or whitespace")]
+    ViewPropertyKeyInvalid = 127,
+
+    #[display(fmt = "View property value does not match the property type")]
+    ViewPropertyValueInvalid = 128,
+
+    #[display(fmt = "Template id can not be empty or whitespace")]
+    TemplateIdInvalid = 130,
+
+    #[display(fmt = "Template type is invalid")]
+    TemplateTypeInvalid = 131,
+
+    #[display(fmt = "Field id can not be empty or whitespace")]
+    FieldIdInvalid = 140,
+
+    #[display(fmt = "Field name can not be empty or too long")]
+    FieldNameInvalid = 141,
+
+    #[display(fmt = "Row id can not be empty or whitespace")]
+    RowIdInvalid = 142,
+
+    #[display(fmt = "Cell data does not match the field type")]
+    CellDataInvalid = 143,
+
+    #[display(fmt = "Card id can not be empty or whitespace")]
+    CardIdInvalid = 150,
+
+    #[display(fmt = "Card title can not be empty or too long")]
+    CardTitleInvalid = 151,
+
+    #[display(fmt = "Column is not one of the options of the board property")]
+    BoardColumnInvalid = 152,
+
+    #[display(fmt = "Comment id can not be empty or whitespace")]
+    CommentIdInvalid = 160,
+
+    #[display(fmt = "Comment content can not be empty or too long")]
+    CommentContentInvalid = 161,
+
+    #[display(fmt = "Comment should be anchored to some text of the document")]
+    CommentRangeInvalid = 162,
+
+    #[display(fmt = "Revision can't be decrypted with the key of the workspace")]
+    RevisionDecryptFailed = 170,
+
+    #[display(fmt = "The object has revisions that are not synced, it can't be repaired")]
+    RevisionNotSynced = 171,
+
     #[display(fmt = "Connection error")]
     ConnectError = 200,
+    #[display(fmt = "The address of the LAN sync host is not valid")]
+    LanSyncAddrInvalid = 201,
+    #[display(fmt = "The secret of the LAN sync can not be empty or whitespace")]
+    LanSyncSecretInvalid = 202,
 
     #[display(fmt = "Email can not be empty or whitespace")]
     EmailIsEmpty = 300,
@@ -83,6 +141,8 @@ pub enum ErrorCode {
     UserIdInvalid = 311,
     #[display(fmt = "User not exist")]
     UserNotExist = 312,
+    #[display(fmt = "The local database of the user is locked, sign in again to unlock it")]
+    UserDatabaseLocked = 313,
 }
 
 impl ErrorCode {
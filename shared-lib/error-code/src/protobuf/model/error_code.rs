@@ -33,6 +33,7 @@ pub enum ErrorCode {
     AppColorStyleInvalid = 102,
     WorkspaceDescTooLong = 103,
     WorkspaceNameTooLong = 104,
+    WorkspacePassphraseInvalid = 105,
     AppIdInvalid = 110,
     AppNameInvalid = 111,
     ViewNameInvalid = 120,
@@ -41,7 +42,26 @@ pub enum ErrorCode {
     ViewDescTooLong = 123,
     ViewDataInvalid = 124,
     ViewNameTooLong = 125,
+    ViewTagInvalid = 126,
+    ViewPropertyKeyInvalid = 127,
+    ViewPropertyValueInvalid = 128,
+    TemplateIdInvalid = 130,
+    TemplateTypeInvalid = 131,
+    FieldIdInvalid = 140,
+    FieldNameInvalid = 141,
+    RowIdInvalid = 142,
+    CellDataInvalid = 143,
+    CardIdInvalid = 150,
+    CardTitleInvalid = 151,
+    BoardColumnInvalid = 152,
+    CommentIdInvalid = 160,
+    CommentContentInvalid = 161,
+    CommentRangeInvalid = 162,
+    RevisionDecryptFailed = 170,
+    RevisionNotSynced = 171,
     ConnectError = 200,
+    LanSyncAddrInvalid = 201,
+    LanSyncSecretInvalid = 202,
     EmailIsEmpty = 300,
     EmailFormatInvalid = 301,
     EmailAlreadyExists = 302,
@@ -55,6 +75,7 @@ pub enum ErrorCode {
     UserNameIsEmpty = 310,
     UserIdInvalid = 311,
     UserNotExist = 312,
+    UserDatabaseLocked = 313,
 }
 
 impl ::protobuf::ProtobufEnum for ErrorCode {
@@ -72,6 +93,7 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             102 => ::std::option::Option::Some(ErrorCode::AppColorStyleInvalid),
             103 => ::std::option::Option::Some(ErrorCode::WorkspaceDescTooLong),
             104 => ::std::option::Option::Some(ErrorCode::WorkspaceNameTooLong),
+            105 => ::std::option::Option::Some(ErrorCode::WorkspacePassphraseInvalid),
             110 => ::std::option::Option::Some(ErrorCode::AppIdInvalid),
             111 => ::std::option::Option::Some(ErrorCode::AppNameInvalid),
             120 => ::std::option::Option::Some(ErrorCode::ViewNameInvalid),
@@ -80,7 +102,26 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             123 => ::std::option::Option::Some(ErrorCode::ViewDescTooLong),
             124 => ::std::option::Option::Some(ErrorCode::ViewDataInvalid),
             125 => ::std::option::Option::Some(ErrorCode::ViewNameTooLong),
+            126 => ::std::option::Option::Some(ErrorCode::ViewTagInvalid),
+            127 => ::std::option::Option::Some(ErrorCode::ViewPropertyKeyInvalid),
+            128 => ::std::option::Option::Some(ErrorCode::ViewPropertyValueInvalid),
+            130 => ::std::option::Option::Some(ErrorCode::TemplateIdInvalid),
+            131 => ::std::option::Option::Some(ErrorCode::TemplateTypeInvalid),
+            140 => ::std::option::Option::Some(ErrorCode::FieldIdInvalid),
+            141 => ::std::option::Option::Some(ErrorCode::FieldNameInvalid),
+            142 => ::std::option::Option::Some(ErrorCode::RowIdInvalid),
+            143 => ::std::option::Option::Some(ErrorCode::CellDataInvalid),
+            150 => ::std::option::Option::Some(ErrorCode::CardIdInvalid),
+            151 => ::std::option::Option::Some(ErrorCode::CardTitleInvalid),
+            152 => ::std::option::Option::Some(ErrorCode::BoardColumnInvalid),
+            160 => ::std::option::Option::Some(ErrorCode::CommentIdInvalid),
+            161 => ::std::option::Option::Some(ErrorCode::CommentContentInvalid),
+            162 => ::std::option::Option::Some(ErrorCode::CommentRangeInvalid),
+            170 => ::std::option::Option::Some(ErrorCode::RevisionDecryptFailed),
+            171 => ::std::option::Option::Some(ErrorCode::RevisionNotSynced),
             200 => ::std::option::Option::Some(ErrorCode::ConnectError),
+            201 => ::std::option::Option::Some(ErrorCode::LanSyncAddrInvalid),
+            202 => ::std::option::Option::Some(ErrorCode::LanSyncSecretInvalid),
             300 => ::std::option::Option::Some(ErrorCode::EmailIsEmpty),
             301 => ::std::option::Option::Some(ErrorCode::EmailFormatInvalid),
             302 => ::std::option::Option::Some(ErrorCode::EmailAlreadyExists),
@@ -94,6 +135,7 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             310 => ::std::option::Option::Some(ErrorCode::UserNameIsEmpty),
             311 => ::std::option::Option::Some(ErrorCode::UserIdInvalid),
             312 => ::std::option::Option::Some(ErrorCode::UserNotExist),
+            313 => ::std::option::Option::Some(ErrorCode::UserDatabaseLocked),
             _ => ::std::option::Option::None
         }
     }
@@ -108,6 +150,7 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::AppColorStyleInvalid,
             ErrorCode::WorkspaceDescTooLong,
             ErrorCode::WorkspaceNameTooLong,
+            ErrorCode::WorkspacePassphraseInvalid,
             ErrorCode::AppIdInvalid,
             ErrorCode::AppNameInvalid,
             ErrorCode::ViewNameInvalid,
@@ -116,7 +159,26 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::ViewDescTooLong,
             ErrorCode::ViewDataInvalid,
             ErrorCode::ViewNameTooLong,
+            ErrorCode::ViewTagInvalid,
+            ErrorCode::ViewPropertyKeyInvalid,
+            ErrorCode::ViewPropertyValueInvalid,
+            ErrorCode::TemplateIdInvalid,
+            ErrorCode::TemplateTypeInvalid,
+            ErrorCode::FieldIdInvalid,
+            ErrorCode::FieldNameInvalid,
+            ErrorCode::RowIdInvalid,
+            ErrorCode::CellDataInvalid,
+            ErrorCode::CardIdInvalid,
+            ErrorCode::CardTitleInvalid,
+            ErrorCode::BoardColumnInvalid,
+            ErrorCode::CommentIdInvalid,
+            ErrorCode::CommentContentInvalid,
+            ErrorCode::CommentRangeInvalid,
+            ErrorCode::RevisionDecryptFailed,
+            ErrorCode::RevisionNotSynced,
             ErrorCode::ConnectError,
+            ErrorCode::LanSyncAddrInvalid,
+            ErrorCode::LanSyncSecretInvalid,
             ErrorCode::EmailIsEmpty,
             ErrorCode::EmailFormatInvalid,
             ErrorCode::EmailAlreadyExists,
@@ -130,6 +192,7 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::UserNameIsEmpty,
             ErrorCode::UserIdInvalid,
             ErrorCode::UserNotExist,
+            ErrorCode::UserDatabaseLocked,
         ];
         values
     }
@@ -158,86 +221,137 @@ impl ::protobuf::reflect::ProtobufValue for ErrorCode {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10error_code.proto*\xc4\x05\n\tErrorCode\x12\x0c\n\x08Internal\x10\0\
+    \n\x10error_code.proto*\xcc\t\n\tErrorCode\x12\x0c\n\x08Internal\x10\0\
     \x12\x14\n\x10UserUnauthorized\x10\x02\x12\x12\n\x0eRecordNotFound\x10\
     \x03\x12\x18\n\x14WorkspaceNameInvalid\x10d\x12\x16\n\x12WorkspaceIdInva\
     lid\x10e\x12\x18\n\x14AppColorStyleInvalid\x10f\x12\x18\n\x14WorkspaceDe\
-    scTooLong\x10g\x12\x18\n\x14WorkspaceNameTooLong\x10h\x12\x10\n\x0cAppId\
-    Invalid\x10n\x12\x12\n\x0eAppNameInvalid\x10o\x12\x13\n\x0fViewNameInval\
-    id\x10x\x12\x18\n\x14ViewThumbnailInvalid\x10y\x12\x11\n\rViewIdInvalid\
-    \x10z\x12\x13\n\x0fViewDescTooLong\x10{\x12\x13\n\x0fViewDataInvalid\x10\
-    |\x12\x13\n\x0fViewNameTooLong\x10}\x12\x11\n\x0cConnectError\x10\xc8\
-    \x01\x12\x11\n\x0cEmailIsEmpty\x10\xac\x02\x12\x17\n\x12EmailFormatInval\
-    id\x10\xad\x02\x12\x17\n\x12EmailAlreadyExists\x10\xae\x02\x12\x14\n\x0f\
-    PasswordIsEmpty\x10\xaf\x02\x12\x14\n\x0fPasswordTooLong\x10\xb0\x02\x12\
-    %\n\x20PasswordContainsForbidCharacters\x10\xb1\x02\x12\x1a\n\x15Passwor\
-    dFormatInvalid\x10\xb2\x02\x12\x15\n\x10PasswordNotMatch\x10\xb3\x02\x12\
+    scTooLong\x10g\x12\x18\n\x14WorkspaceNameTooLong\x10h\x12\x1e\n\x1aWorks\
+    pacePassphraseInvalid\x10i\x12\x10\n\x0cAppIdInvalid\x10n\x12\x12\n\x0eA\
+    ppNameInvalid\x10o\x12\x13\n\x0fViewNameInvalid\x10x\x12\x18\n\x14ViewTh\
+    umbnailInvalid\x10y\x12\x11\n\rViewIdInvalid\x10z\x12\x13\n\x0fViewDescT\
+    ooLong\x10{\x12\x13\n\x0fViewDataInvalid\x10|\x12\x13\n\x0fViewNameTooLo\
+    ng\x10}\x12\x12\n\x0eViewTagInvalid\x10~\x12\x1a\n\x16ViewPropertyKeyInv\
+    alid\x10\x7f\x12\x1d\n\x18ViewPropertyValueInvalid\x10\x80\x01\x12\x16\n\
+    \x11TemplateIdInvalid\x10\x82\x01\x12\x18\n\x13TemplateTypeInvalid\x10\
+    \x83\x01\x12\x13\n\x0eFieldIdInvalid\x10\x8c\x01\x12\x15\n\x10FieldNameI\
+    nvalid\x10\x8d\x01\x12\x11\n\x0cRowIdInvalid\x10\x8e\x01\x12\x14\n\x0fCe\
+    llDataInvalid\x10\x8f\x01\x12\x12\n\rCardIdInvalid\x10\x96\x01\x12\x15\n\
+    \x10CardTitleInvalid\x10\x97\x01\x12\x17\n\x12BoardColumnInvalid\x10\x98\
+    \x01\x12\x15\n\x10CommentIdInvalid\x10\xa0\x01\x12\x1a\n\x15CommentConte\
+    ntInvalid\x10\xa1\x01\x12\x18\n\x13CommentRangeInvalid\x10\xa2\x01\x12\
+    \x1a\n\x15RevisionDecryptFailed\x10\xaa\x01\x12\x16\n\x11RevisionNotSync\
+    ed\x10\xab\x01\x12\x11\n\x0cConnectError\x10\xc8\x01\x12\x17\n\x12LanSyn\
+    cAddrInvalid\x10\xc9\x01\x12\x19\n\x14LanSyncSecretInvalid\x10\xca\x01\
+    \x12\x11\n\x0cEmailIsEmpty\x10\xac\x02\x12\x17\n\x12EmailFormatInvalid\
+    \x10\xad\x02\x12\x17\n\x12EmailAlreadyExists\x10\xae\x02\x12\x14\n\x0fPa\
+    sswordIsEmpty\x10\xaf\x02\x12\x14\n\x0fPasswordTooLong\x10\xb0\x02\x12%\
+    \n\x20PasswordContainsForbidCharacters\x10\xb1\x02\x12\x1a\n\x15Password\
+    FormatInvalid\x10\xb2\x02\x12\x15\n\x10PasswordNotMatch\x10\xb3\x02\x12\
     \x14\n\x0fUserNameTooLong\x10\xb4\x02\x12'\n\"UserNameContainForbiddenCh\
     aracters\x10\xb5\x02\x12\x14\n\x0fUserNameIsEmpty\x10\xb6\x02\x12\x12\n\
-    \rUserIdInvalid\x10\xb7\x02\x12\x11\n\x0cUserNotExist\x10\xb8\x02J\xf8\t\
-    \n\x06\x12\x04\0\0!\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\
-    \x12\x04\x02\0!\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x0e\n\x0b\n\x04\
-    \x05\0\x02\0\x12\x03\x03\x04\x11\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\
-    \x04\x0c\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x0f\x10\n\x0b\n\x04\x05\
-    \0\x02\x01\x12\x03\x04\x04\x19\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\
-    \x04\x14\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x04\x17\x18\n\x0b\n\x04\
-    \x05\0\x02\x02\x12\x03\x05\x04\x17\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\
-    \x05\x04\x12\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x05\x15\x16\n\x0b\n\
-    \x04\x05\0\x02\x03\x12\x03\x06\x04\x1f\n\x0c\n\x05\x05\0\x02\x03\x01\x12\
-    \x03\x06\x04\x18\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x06\x1b\x1e\n\x0b\
-    \n\x04\x05\0\x02\x04\x12\x03\x07\x04\x1d\n\x0c\n\x05\x05\0\x02\x04\x01\
-    \x12\x03\x07\x04\x16\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x07\x19\x1c\n\
-    \x0b\n\x04\x05\0\x02\x05\x12\x03\x08\x04\x1f\n\x0c\n\x05\x05\0\x02\x05\
-    \x01\x12\x03\x08\x04\x18\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x08\x1b\
-    \x1e\n\x0b\n\x04\x05\0\x02\x06\x12\x03\t\x04\x1f\n\x0c\n\x05\x05\0\x02\
-    \x06\x01\x12\x03\t\x04\x18\n\x0c\n\x05\x05\0\x02\x06\x02\x12\x03\t\x1b\
-    \x1e\n\x0b\n\x04\x05\0\x02\x07\x12\x03\n\x04\x1f\n\x0c\n\x05\x05\0\x02\
-    \x07\x01\x12\x03\n\x04\x18\n\x0c\n\x05\x05\0\x02\x07\x02\x12\x03\n\x1b\
-    \x1e\n\x0b\n\x04\x05\0\x02\x08\x12\x03\x0b\x04\x17\n\x0c\n\x05\x05\0\x02\
-    \x08\x01\x12\x03\x0b\x04\x10\n\x0c\n\x05\x05\0\x02\x08\x02\x12\x03\x0b\
-    \x13\x16\n\x0b\n\x04\x05\0\x02\t\x12\x03\x0c\x04\x19\n\x0c\n\x05\x05\0\
-    \x02\t\x01\x12\x03\x0c\x04\x12\n\x0c\n\x05\x05\0\x02\t\x02\x12\x03\x0c\
-    \x15\x18\n\x0b\n\x04\x05\0\x02\n\x12\x03\r\x04\x1a\n\x0c\n\x05\x05\0\x02\
-    \n\x01\x12\x03\r\x04\x13\n\x0c\n\x05\x05\0\x02\n\x02\x12\x03\r\x16\x19\n\
-    \x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04\x1f\n\x0c\n\x05\x05\0\x02\x0b\
-    \x01\x12\x03\x0e\x04\x18\n\x0c\n\x05\x05\0\x02\x0b\x02\x12\x03\x0e\x1b\
-    \x1e\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\x04\x18\n\x0c\n\x05\x05\0\x02\
-    \x0c\x01\x12\x03\x0f\x04\x11\n\x0c\n\x05\x05\0\x02\x0c\x02\x12\x03\x0f\
-    \x14\x17\n\x0b\n\x04\x05\0\x02\r\x12\x03\x10\x04\x1a\n\x0c\n\x05\x05\0\
-    \x02\r\x01\x12\x03\x10\x04\x13\n\x0c\n\x05\x05\0\x02\r\x02\x12\x03\x10\
-    \x16\x19\n\x0b\n\x04\x05\0\x02\x0e\x12\x03\x11\x04\x1a\n\x0c\n\x05\x05\0\
-    \x02\x0e\x01\x12\x03\x11\x04\x13\n\x0c\n\x05\x05\0\x02\x0e\x02\x12\x03\
-    \x11\x16\x19\n\x0b\n\x04\x05\0\x02\x0f\x12\x03\x12\x04\x1a\n\x0c\n\x05\
-    \x05\0\x02\x0f\x01\x12\x03\x12\x04\x13\n\x0c\n\x05\x05\0\x02\x0f\x02\x12\
-    \x03\x12\x16\x19\n\x0b\n\x04\x05\0\x02\x10\x12\x03\x13\x04\x17\n\x0c\n\
-    \x05\x05\0\x02\x10\x01\x12\x03\x13\x04\x10\n\x0c\n\x05\x05\0\x02\x10\x02\
-    \x12\x03\x13\x13\x16\n\x0b\n\x04\x05\0\x02\x11\x12\x03\x14\x04\x17\n\x0c\
-    \n\x05\x05\0\x02\x11\x01\x12\x03\x14\x04\x10\n\x0c\n\x05\x05\0\x02\x11\
-    \x02\x12\x03\x14\x13\x16\n\x0b\n\x04\x05\0\x02\x12\x12\x03\x15\x04\x1d\n\
-    \x0c\n\x05\x05\0\x02\x12\x01\x12\x03\x15\x04\x16\n\x0c\n\x05\x05\0\x02\
-    \x12\x02\x12\x03\x15\x19\x1c\n\x0b\n\x04\x05\0\x02\x13\x12\x03\x16\x04\
-    \x1d\n\x0c\n\x05\x05\0\x02\x13\x01\x12\x03\x16\x04\x16\n\x0c\n\x05\x05\0\
-    \x02\x13\x02\x12\x03\x16\x19\x1c\n\x0b\n\x04\x05\0\x02\x14\x12\x03\x17\
-    \x04\x1a\n\x0c\n\x05\x05\0\x02\x14\x01\x12\x03\x17\x04\x13\n\x0c\n\x05\
-    \x05\0\x02\x14\x02\x12\x03\x17\x16\x19\n\x0b\n\x04\x05\0\x02\x15\x12\x03\
-    \x18\x04\x1a\n\x0c\n\x05\x05\0\x02\x15\x01\x12\x03\x18\x04\x13\n\x0c\n\
-    \x05\x05\0\x02\x15\x02\x12\x03\x18\x16\x19\n\x0b\n\x04\x05\0\x02\x16\x12\
-    \x03\x19\x04+\n\x0c\n\x05\x05\0\x02\x16\x01\x12\x03\x19\x04$\n\x0c\n\x05\
-    \x05\0\x02\x16\x02\x12\x03\x19'*\n\x0b\n\x04\x05\0\x02\x17\x12\x03\x1a\
-    \x04\x20\n\x0c\n\x05\x05\0\x02\x17\x01\x12\x03\x1a\x04\x19\n\x0c\n\x05\
-    \x05\0\x02\x17\x02\x12\x03\x1a\x1c\x1f\n\x0b\n\x04\x05\0\x02\x18\x12\x03\
-    \x1b\x04\x1b\n\x0c\n\x05\x05\0\x02\x18\x01\x12\x03\x1b\x04\x14\n\x0c\n\
-    \x05\x05\0\x02\x18\x02\x12\x03\x1b\x17\x1a\n\x0b\n\x04\x05\0\x02\x19\x12\
-    \x03\x1c\x04\x1a\n\x0c\n\x05\x05\0\x02\x19\x01\x12\x03\x1c\x04\x13\n\x0c\
-    \n\x05\x05\0\x02\x19\x02\x12\x03\x1c\x16\x19\n\x0b\n\x04\x05\0\x02\x1a\
-    \x12\x03\x1d\x04-\n\x0c\n\x05\x05\0\x02\x1a\x01\x12\x03\x1d\x04&\n\x0c\n\
-    \x05\x05\0\x02\x1a\x02\x12\x03\x1d),\n\x0b\n\x04\x05\0\x02\x1b\x12\x03\
-    \x1e\x04\x1a\n\x0c\n\x05\x05\0\x02\x1b\x01\x12\x03\x1e\x04\x13\n\x0c\n\
-    \x05\x05\0\x02\x1b\x02\x12\x03\x1e\x16\x19\n\x0b\n\x04\x05\0\x02\x1c\x12\
-    \x03\x1f\x04\x18\n\x0c\n\x05\x05\0\x02\x1c\x01\x12\x03\x1f\x04\x11\n\x0c\
-    \n\x05\x05\0\x02\x1c\x02\x12\x03\x1f\x14\x17\n\x0b\n\x04\x05\0\x02\x1d\
-    \x12\x03\x20\x04\x17\n\x0c\n\x05\x05\0\x02\x1d\x01\x12\x03\x20\x04\x10\n\
-    \x0c\n\x05\x05\0\x02\x1d\x02\x12\x03\x20\x13\x16b\x06proto3\
+    \rUserIdInvalid\x10\xb7\x02\x12\x11\n\x0cUserNotExist\x10\xb8\x02\x12\
+    \x17\n\x12UserDatabaseLocked\x10\xb9\x02J\xd5\x10\n\x06\x12\x04\0\06\x01\
+    \n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\06\x01\n\n\
+    \n\x03\x05\0\x01\x12\x03\x02\x05\x0e\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\
+    \x04\x11\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x0c\n\x0c\n\x05\x05\
+    \0\x02\0\x02\x12\x03\x03\x0f\x10\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\
+    \x04\x19\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x14\n\x0c\n\x05\
+    \x05\0\x02\x01\x02\x12\x03\x04\x17\x18\n\x0b\n\x04\x05\0\x02\x02\x12\x03\
+    \x05\x04\x17\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\x12\n\x0c\n\
+    \x05\x05\0\x02\x02\x02\x12\x03\x05\x15\x16\n\x0b\n\x04\x05\0\x02\x03\x12\
+    \x03\x06\x04\x1f\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x18\n\x0c\
+    \n\x05\x05\0\x02\x03\x02\x12\x03\x06\x1b\x1e\n\x0b\n\x04\x05\0\x02\x04\
+    \x12\x03\x07\x04\x1d\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x16\n\
+    \x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x07\x19\x1c\n\x0b\n\x04\x05\0\x02\
+    \x05\x12\x03\x08\x04\x1f\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x08\x04\
+    \x18\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x08\x1b\x1e\n\x0b\n\x04\x05\0\
+    \x02\x06\x12\x03\t\x04\x1f\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\t\x04\
+    \x18\n\x0c\n\x05\x05\0\x02\x06\x02\x12\x03\t\x1b\x1e\n\x0b\n\x04\x05\0\
+    \x02\x07\x12\x03\n\x04\x1f\n\x0c\n\x05\x05\0\x02\x07\x01\x12\x03\n\x04\
+    \x18\n\x0c\n\x05\x05\0\x02\x07\x02\x12\x03\n\x1b\x1e\n\x0b\n\x04\x05\0\
+    \x02\x08\x12\x03\x0b\x04%\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03\x0b\x04\
+    \x1e\n\x0c\n\x05\x05\0\x02\x08\x02\x12\x03\x0b!$\n\x0b\n\x04\x05\0\x02\t\
+    \x12\x03\x0c\x04\x17\n\x0c\n\x05\x05\0\x02\t\x01\x12\x03\x0c\x04\x10\n\
+    \x0c\n\x05\x05\0\x02\t\x02\x12\x03\x0c\x13\x16\n\x0b\n\x04\x05\0\x02\n\
+    \x12\x03\r\x04\x19\n\x0c\n\x05\x05\0\x02\n\x01\x12\x03\r\x04\x12\n\x0c\n\
+    \x05\x05\0\x02\n\x02\x12\x03\r\x15\x18\n\x0b\n\x04\x05\0\x02\x0b\x12\x03\
+    \x0e\x04\x1a\n\x0c\n\x05\x05\0\x02\x0b\x01\x12\x03\x0e\x04\x13\n\x0c\n\
+    \x05\x05\0\x02\x0b\x02\x12\x03\x0e\x16\x19\n\x0b\n\x04\x05\0\x02\x0c\x12\
+    \x03\x0f\x04\x1f\n\x0c\n\x05\x05\0\x02\x0c\x01\x12\x03\x0f\x04\x18\n\x0c\
+    \n\x05\x05\0\x02\x0c\x02\x12\x03\x0f\x1b\x1e\n\x0b\n\x04\x05\0\x02\r\x12\
+    \x03\x10\x04\x18\n\x0c\n\x05\x05\0\x02\r\x01\x12\x03\x10\x04\x11\n\x0c\n\
+    \x05\x05\0\x02\r\x02\x12\x03\x10\x14\x17\n\x0b\n\x04\x05\0\x02\x0e\x12\
+    \x03\x11\x04\x1a\n\x0c\n\x05\x05\0\x02\x0e\x01\x12\x03\x11\x04\x13\n\x0c\
+    \n\x05\x05\0\x02\x0e\x02\x12\x03\x11\x16\x19\n\x0b\n\x04\x05\0\x02\x0f\
+    \x12\x03\x12\x04\x1a\n\x0c\n\x05\x05\0\x02\x0f\x01\x12\x03\x12\x04\x13\n\
+    \x0c\n\x05\x05\0\x02\x0f\x02\x12\x03\x12\x16\x19\n\x0b\n\x04\x05\0\x02\
+    \x10\x12\x03\x13\x04\x1a\n\x0c\n\x05\x05\0\x02\x10\x01\x12\x03\x13\x04\
+    \x13\n\x0c\n\x05\x05\0\x02\x10\x02\x12\x03\x13\x16\x19\n\x0b\n\x04\x05\0\
+    \x02\x11\x12\x03\x14\x04\x19\n\x0c\n\x05\x05\0\x02\x11\x01\x12\x03\x14\
+    \x04\x12\n\x0c\n\x05\x05\0\x02\x11\x02\x12\x03\x14\x15\x18\n\x0b\n\x04\
+    \x05\0\x02\x12\x12\x03\x15\x04!\n\x0c\n\x05\x05\0\x02\x12\x01\x12\x03\
+    \x15\x04\x1a\n\x0c\n\x05\x05\0\x02\x12\x02\x12\x03\x15\x1d\x20\n\x0b\n\
+    \x04\x05\0\x02\x13\x12\x03\x16\x04#\n\x0c\n\x05\x05\0\x02\x13\x01\x12\
+    \x03\x16\x04\x1c\n\x0c\n\x05\x05\0\x02\x13\x02\x12\x03\x16\x1f\"\n\x0b\n\
+    \x04\x05\0\x02\x14\x12\x03\x17\x04\x1c\n\x0c\n\x05\x05\0\x02\x14\x01\x12\
+    \x03\x17\x04\x15\n\x0c\n\x05\x05\0\x02\x14\x02\x12\x03\x17\x18\x1b\n\x0b\
+    \n\x04\x05\0\x02\x15\x12\x03\x18\x04\x1e\n\x0c\n\x05\x05\0\x02\x15\x01\
+    \x12\x03\x18\x04\x17\n\x0c\n\x05\x05\0\x02\x15\x02\x12\x03\x18\x1a\x1d\n\
+    \x0b\n\x04\x05\0\x02\x16\x12\x03\x19\x04\x19\n\x0c\n\x05\x05\0\x02\x16\
+    \x01\x12\x03\x19\x04\x12\n\x0c\n\x05\x05\0\x02\x16\x02\x12\x03\x19\x15\
+    \x18\n\x0b\n\x04\x05\0\x02\x17\x12\x03\x1a\x04\x1b\n\x0c\n\x05\x05\0\x02\
+    \x17\x01\x12\x03\x1a\x04\x14\n\x0c\n\x05\x05\0\x02\x17\x02\x12\x03\x1a\
+    \x17\x1a\n\x0b\n\x04\x05\0\x02\x18\x12\x03\x1b\x04\x17\n\x0c\n\x05\x05\0\
+    \x02\x18\x01\x12\x03\x1b\x04\x10\n\x0c\n\x05\x05\0\x02\x18\x02\x12\x03\
+    \x1b\x13\x16\n\x0b\n\x04\x05\0\x02\x19\x12\x03\x1c\x04\x1a\n\x0c\n\x05\
+    \x05\0\x02\x19\x01\x12\x03\x1c\x04\x13\n\x0c\n\x05\x05\0\x02\x19\x02\x12\
+    \x03\x1c\x16\x19\n\x0b\n\x04\x05\0\x02\x1a\x12\x03\x1d\x04\x18\n\x0c\n\
+    \x05\x05\0\x02\x1a\x01\x12\x03\x1d\x04\x11\n\x0c\n\x05\x05\0\x02\x1a\x02\
+    \x12\x03\x1d\x14\x17\n\x0b\n\x04\x05\0\x02\x1b\x12\x03\x1e\x04\x1b\n\x0c\
+    \n\x05\x05\0\x02\x1b\x01\x12\x03\x1e\x04\x14\n\x0c\n\x05\x05\0\x02\x1b\
+    \x02\x12\x03\x1e\x17\x1a\n\x0b\n\x04\x05\0\x02\x1c\x12\x03\x1f\x04\x1d\n\
+    \x0c\n\x05\x05\0\x02\x1c\x01\x12\x03\x1f\x04\x16\n\x0c\n\x05\x05\0\x02\
+    \x1c\x02\x12\x03\x1f\x19\x1c\n\x0b\n\x04\x05\0\x02\x1d\x12\x03\x20\x04\
+    \x1b\n\x0c\n\x05\x05\0\x02\x1d\x01\x12\x03\x20\x04\x14\n\x0c\n\x05\x05\0\
+    \x02\x1d\x02\x12\x03\x20\x17\x1a\n\x0b\n\x04\x05\0\x02\x1e\x12\x03!\x04\
+    \x20\n\x0c\n\x05\x05\0\x02\x1e\x01\x12\x03!\x04\x19\n\x0c\n\x05\x05\0\
+    \x02\x1e\x02\x12\x03!\x1c\x1f\n\x0b\n\x04\x05\0\x02\x1f\x12\x03\"\x04\
+    \x1e\n\x0c\n\x05\x05\0\x02\x1f\x01\x12\x03\"\x04\x17\n\x0c\n\x05\x05\0\
+    \x02\x1f\x02\x12\x03\"\x1a\x1d\n\x0b\n\x04\x05\0\x02\x20\x12\x03#\x04\
+    \x20\n\x0c\n\x05\x05\0\x02\x20\x01\x12\x03#\x04\x19\n\x0c\n\x05\x05\0\
+    \x02\x20\x02\x12\x03#\x1c\x1f\n\x0b\n\x04\x05\0\x02!\x12\x03$\x04\x1c\n\
+    \x0c\n\x05\x05\0\x02!\x01\x12\x03$\x04\x15\n\x0c\n\x05\x05\0\x02!\x02\
+    \x12\x03$\x18\x1b\n\x0b\n\x04\x05\0\x02\"\x12\x03%\x04\x17\n\x0c\n\x05\
+    \x05\0\x02\"\x01\x12\x03%\x04\x10\n\x0c\n\x05\x05\0\x02\"\x02\x12\x03%\
+    \x13\x16\n\x0b\n\x04\x05\0\x02#\x12\x03&\x04\x1d\n\x0c\n\x05\x05\0\x02#\
+    \x01\x12\x03&\x04\x16\n\x0c\n\x05\x05\0\x02#\x02\x12\x03&\x19\x1c\n\x0b\
+    \n\x04\x05\0\x02$\x12\x03'\x04\x1f\n\x0c\n\x05\x05\0\x02$\x01\x12\x03'\
+    \x04\x18\n\x0c\n\x05\x05\0\x02$\x02\x12\x03'\x1b\x1e\n\x0b\n\x04\x05\0\
+    \x02%\x12\x03(\x04\x17\n\x0c\n\x05\x05\0\x02%\x01\x12\x03(\x04\x10\n\x0c\
+    \n\x05\x05\0\x02%\x02\x12\x03(\x13\x16\n\x0b\n\x04\x05\0\x02&\x12\x03)\
+    \x04\x1d\n\x0c\n\x05\x05\0\x02&\x01\x12\x03)\x04\x16\n\x0c\n\x05\x05\0\
+    \x02&\x02\x12\x03)\x19\x1c\n\x0b\n\x04\x05\0\x02'\x12\x03*\x04\x1d\n\x0c\
+    \n\x05\x05\0\x02'\x01\x12\x03*\x04\x16\n\x0c\n\x05\x05\0\x02'\x02\x12\
+    \x03*\x19\x1c\n\x0b\n\x04\x05\0\x02(\x12\x03+\x04\x1a\n\x0c\n\x05\x05\0\
+    \x02(\x01\x12\x03+\x04\x13\n\x0c\n\x05\x05\0\x02(\x02\x12\x03+\x16\x19\n\
+    \x0b\n\x04\x05\0\x02)\x12\x03,\x04\x1a\n\x0c\n\x05\x05\0\x02)\x01\x12\
+    \x03,\x04\x13\n\x0c\n\x05\x05\0\x02)\x02\x12\x03,\x16\x19\n\x0b\n\x04\
+    \x05\0\x02*\x12\x03-\x04+\n\x0c\n\x05\x05\0\x02*\x01\x12\x03-\x04$\n\x0c\
+    \n\x05\x05\0\x02*\x02\x12\x03-'*\n\x0b\n\x04\x05\0\x02+\x12\x03.\x04\x20\
+    \n\x0c\n\x05\x05\0\x02+\x01\x12\x03.\x04\x19\n\x0c\n\x05\x05\0\x02+\x02\
+    \x12\x03.\x1c\x1f\n\x0b\n\x04\x05\0\x02,\x12\x03/\x04\x1b\n\x0c\n\x05\
+    \x05\0\x02,\x01\x12\x03/\x04\x14\n\x0c\n\x05\x05\0\x02,\x02\x12\x03/\x17\
+    \x1a\n\x0b\n\x04\x05\0\x02-\x12\x030\x04\x1a\n\x0c\n\x05\x05\0\x02-\x01\
+    \x12\x030\x04\x13\n\x0c\n\x05\x05\0\x02-\x02\x12\x030\x16\x19\n\x0b\n\
+    \x04\x05\0\x02.\x12\x031\x04-\n\x0c\n\x05\x05\0\x02.\x01\x12\x031\x04&\n\
+    \x0c\n\x05\x05\0\x02.\x02\x12\x031),\n\x0b\n\x04\x05\0\x02/\x12\x032\x04\
+    \x1a\n\x0c\n\x05\x05\0\x02/\x01\x12\x032\x04\x13\n\x0c\n\x05\x05\0\x02/\
+    \x02\x12\x032\x16\x19\n\x0b\n\x04\x05\0\x020\x12\x033\x04\x18\n\x0c\n\
+    \x05\x05\0\x020\x01\x12\x033\x04\x11\n\x0c\n\x05\x05\0\x020\x02\x12\x033\
+    \x14\x17\n\x0b\n\x04\x05\0\x021\x12\x034\x04\x17\n\x0c\n\x05\x05\0\x021\
+    \x01\x12\x034\x04\x10\n\x0c\n\x05\x05\0\x021\x02\x12\x034\x13\x16\n\x0b\
+    \n\x04\x05\0\x022\x12\x035\x04\x1d\n\x0c\n\x05\x05\0\x022\x01\x12\x035\
+    \x04\x16\n\x0c\n\x05\x05\0\x022\x02\x12\x035\x19\x1cb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,579 @@
+use crate::{
+    board::builder::BoardPadBuilder,
+    entities::{
+        board_info::BoardDelta,
+        revision::{md5, Revision},
+    },
+    errors::{CollaborateError, CollaborateResult},
+};
+use flowy_core_data_model::entities::board::{Board, BoardColumn, BoardProperty, Card, CardProperty};
+use lib_ot::core::{FlowyStr, OperationTransformable, PlainDeltaBuilder};
+use serde::{Deserialize, Serialize};
+use std::{cmp::Ordering, collections::HashMap};
+
+pub const DEFAULT_BOARD_PROPERTY_KEY: &str = "Status";
+
+// The board is stored as lines of json, each line is a record of the board: the property or a card.
+// A change deletes the lines of the records it touches and inserts the new lines in their place, so
+// the deltas of the concurrent changes are always aligned to the lines after being transformed. If
+// the same card is changed concurrently, the card ends up with more than one line and the last one
+// wins until the card is changed again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoardPad {
+    pub(crate) records: Vec<Option<BoardRecord>>,
+    pub(crate) root: BoardDelta,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum BoardRecord {
+    Property(BoardProperty),
+    Card(CardRecord),
+}
+
+// The cards in the same column are ordered by their positions, and by their ids if the positions
+// are equal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct CardRecord {
+    #[serde(flatten)]
+    pub(crate) card: Card,
+    pub(crate) position: f64,
+}
+
+pub fn default_board_property() -> BoardProperty {
+    BoardProperty {
+        key: DEFAULT_BOARD_PROPERTY_KEY.to_owned(),
+        options: vec!["To Do".to_owned(), "In Progress".to_owned(), "Done".to_owned()],
+    }
+}
+
+pub fn default_board_delta() -> BoardDelta {
+    let line = record_to_line(&BoardRecord::Property(default_board_property())).unwrap();
+    PlainDeltaBuilder::new().insert(&line).build()
+}
+
+impl std::default::Default for BoardPad {
+    fn default() -> Self {
+        BoardPadBuilder::build_with_delta(default_board_delta()).unwrap()
+    }
+}
+
+pub struct BoardChange {
+    pub delta: BoardDelta,
+    /// md5: the md5 of the BoardPad's delta after applying the change.
+    pub md5: String,
+}
+
+impl BoardPad {
+    pub fn from_revisions(revisions: Vec<Revision>) -> CollaborateResult<Self> {
+        BoardPadBuilder::build_with_revisions(revisions)
+    }
+
+    pub fn from_delta(delta: BoardDelta) -> CollaborateResult<Self> {
+        BoardPadBuilder::build_with_delta(delta)
+    }
+
+    pub fn delta(&self) -> &BoardDelta {
+        &self.root
+    }
+
+    pub fn md5(&self) -> String {
+        md5(&self.root.to_bytes())
+    }
+
+    pub fn reset_board(&mut self, delta: BoardDelta) -> CollaborateResult<String> {
+        let board = BoardPad::from_delta(delta)?;
+        self.records = board.records;
+        self.root = board.root;
+        Ok(self.md5())
+    }
+
+    pub fn compose_remote_delta(&mut self, delta: BoardDelta) -> CollaborateResult<String> {
+        let composed_delta = self.root.compose(&delta)?;
+        self.reset_board(composed_delta)
+    }
+
+    pub fn property(&self) -> BoardProperty {
+        self.records
+            .iter()
+            .rev()
+            .find_map(|record| match record {
+                Some(BoardRecord::Property(property)) => Some(property.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(default_board_property)
+    }
+
+    pub fn board(&self, board_id: &str) -> Board {
+        let property = self.property();
+        let mut cards = self.card_records().into_values().collect::<Vec<CardRecord>>();
+        cards.sort_by(cmp_card_record);
+
+        let mut columns = property
+            .options
+            .iter()
+            .map(|option| BoardColumn {
+                name: option.clone(),
+                cards: vec![],
+            })
+            .collect::<Vec<BoardColumn>>();
+        let mut ungrouped_column = BoardColumn::default();
+        for record in cards {
+            let column = record.card.get_property(&property.key).unwrap_or("").to_owned();
+            match columns.iter_mut().find(|c| c.name == column) {
+                None => ungrouped_column.cards.push(record.card),
+                Some(column) => column.cards.push(record.card),
+            }
+        }
+
+        if !ungrouped_column.cards.is_empty() {
+            columns.insert(0, ungrouped_column);
+        }
+
+        Board {
+            id: board_id.to_owned(),
+            property,
+            columns,
+        }
+    }
+
+    pub fn read_card(&self, card_id: &str) -> Option<Card> {
+        self.card_record(card_id).map(|record| record.card)
+    }
+
+    pub fn update_property(&mut self, property: BoardProperty) -> CollaborateResult<Option<BoardChange>> {
+        if self.property() == property {
+            return Ok(None);
+        }
+
+        let line_indexes = self.line_indexes(|record| matches!(record, BoardRecord::Property(_)));
+        self.replace_records(line_indexes, vec![BoardRecord::Property(property)])
+    }
+
+    // The card is put at the end of the column. The column can be empty, which means the card
+    // doesn't belong to any option of the property.
+    pub fn create_card(&mut self, mut card: Card, column: &str) -> CollaborateResult<Option<BoardChange>> {
+        if self.card_record(&card.id).is_some() {
+            tracing::warn!("[BoardPad]: Duplicate card");
+            return Ok(None);
+        }
+
+        let property = self.property();
+        let _ = validate_column(&property, column)?;
+        card.set_property(&property.key, column);
+        let position = self.next_position(column, &card.id);
+        self.replace_records(vec![], vec![BoardRecord::Card(CardRecord { card, position })])
+    }
+
+    // Changing the value of the board property moves the card to the end of that column.
+    pub fn update_card(
+        &mut self,
+        card_id: &str,
+        title: Option<String>,
+        description: Option<String>,
+        properties: Vec<CardProperty>,
+    ) -> CollaborateResult<Option<BoardChange>> {
+        let old_record = self
+            .card_record(card_id)
+            .ok_or_else(CollaborateError::record_not_found)?;
+        let property = self.property();
+        let mut record = old_record.clone();
+        if let Some(title) = title {
+            record.card.title = title;
+        }
+        if let Some(description) = description {
+            record.card.description = description;
+        }
+        for card_property in properties {
+            if card_property.key == property.key {
+                let old_column = record.card.get_property(&property.key).unwrap_or("").to_owned();
+                if old_column != card_property.value {
+                    let _ = validate_column(&property, &card_property.value)?;
+                    record.position = self.next_position(&card_property.value, card_id);
+                }
+            }
+            record.card.set_property(&card_property.key, &card_property.value);
+        }
+
+        if record == old_record {
+            return Ok(None);
+        }
+        self.replace_card(record, vec![])
+    }
+
+    // Moves the card into the column, right after the after_card_id or at the top of the column if
+    // the after_card_id is None. The cards of the column are renumbered if there is no room between
+    // the two neighbours, and it's still a single change.
+    pub fn move_card(
+        &mut self,
+        card_id: &str,
+        column: &str,
+        after_card_id: Option<&str>,
+    ) -> CollaborateResult<Option<BoardChange>> {
+        let mut record = self
+            .card_record(card_id)
+            .ok_or_else(CollaborateError::record_not_found)?;
+        let property = self.property();
+        let _ = validate_column(&property, column)?;
+
+        let column_cards = self
+            .column_records(&property.key, column)
+            .into_iter()
+            .filter(|other| other.card.id != card_id)
+            .collect::<Vec<CardRecord>>();
+        let index = match after_card_id {
+            None => 0,
+            Some(after_card_id) => match column_cards.iter().position(|other| other.card.id == after_card_id) {
+                None => {
+                    return Err(CollaborateError::record_not_found().context(format!(
+                        "Can't find the card {} in the column {}",
+                        after_card_id, column
+                    )))
+                }
+                Some(after_index) => after_index + 1,
+            },
+        };
+
+        let old_column = record.card.get_property(&property.key).unwrap_or("").to_owned();
+        let prev = index.checked_sub(1).map(|i| column_cards[i].position);
+        let next = column_cards.get(index).map(|other| other.position);
+        if old_column == column && is_between(record.position, prev, next) {
+            return Ok(None);
+        }
+
+        record.card.set_property(&property.key, column);
+        match position_between(prev, next) {
+            Some(position) => {
+                record.position = position;
+                self.replace_card(record, vec![])
+            }
+            None => {
+                let mut column_cards = column_cards;
+                column_cards.insert(index, record);
+                for (i, column_card) in column_cards.iter_mut().enumerate() {
+                    column_card.position = (i + 1) as f64;
+                }
+                let first = column_cards.remove(0);
+                self.replace_card(first, column_cards)
+            }
+        }
+    }
+
+    pub fn delete_card(&mut self, card_id: &str) -> CollaborateResult<Option<BoardChange>> {
+        let line_indexes = self.line_indexes(|record| is_card(record, card_id));
+        if line_indexes.is_empty() {
+            return Ok(None);
+        }
+        self.replace_records(line_indexes, vec![])
+    }
+}
+
+impl BoardPad {
+    // Returns the cards by their ids. The last line wins if the card has more than one line.
+    fn card_records(&self) -> HashMap<String, CardRecord> {
+        let mut cards = HashMap::new();
+        for record in self.records.iter().flatten() {
+            if let BoardRecord::Card(card_record) = record {
+                cards.insert(card_record.card.id.clone(), card_record.clone());
+            }
+        }
+        cards
+    }
+
+    fn card_record(&self, card_id: &str) -> Option<CardRecord> {
+        self.records.iter().rev().find_map(|record| match record {
+            Some(BoardRecord::Card(card_record)) if card_record.card.id == card_id => Some(card_record.clone()),
+            _ => None,
+        })
+    }
+
+    fn column_records(&self, key: &str, column: &str) -> Vec<CardRecord> {
+        let mut cards = self
+            .card_records()
+            .into_values()
+            .filter(|record| record.card.get_property(key).unwrap_or("") == column)
+            .collect::<Vec<CardRecord>>();
+        cards.sort_by(cmp_card_record);
+        cards
+    }
+
+    fn next_position(&self, column: &str, card_id: &str) -> f64 {
+        let property = self.property();
+        self.column_records(&property.key, column)
+            .iter()
+            .filter(|record| record.card.id != card_id)
+            .map(|record| record.position)
+            .fold(0.0, f64::max)
+            + 1.0
+    }
+
+    fn line_indexes<P>(&self, predicate: P) -> Vec<usize>
+    where
+        P: Fn(&BoardRecord) -> bool,
+    {
+        self.records
+            .iter()
+            .enumerate()
+            .filter(|(_, record)| record.as_ref().map(|record| predicate(record)).unwrap_or(false))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    // Replaces the lines of the card and the other cards with the new records.
+    fn replace_card(&mut self, record: CardRecord, others: Vec<CardRecord>) -> CollaborateResult<Option<BoardChange>> {
+        let card_ids = std::iter::once(&record)
+            .chain(others.iter())
+            .map(|record| record.card.id.clone())
+            .collect::<Vec<String>>();
+        let line_indexes = self.line_indexes(|r| card_ids.iter().any(|card_id| is_card(r, card_id)));
+        let records = std::iter::once(record)
+            .chain(others.into_iter())
+            .map(BoardRecord::Card)
+            .collect::<Vec<BoardRecord>>();
+        self.replace_records(line_indexes, records)
+    }
+
+    // Deletes the lines at the line_indexes and inserts the new records at the position of the
+    // first deleted line, or at the end if no line is deleted.
+    fn replace_records(
+        &mut self,
+        line_indexes: Vec<usize>,
+        records: Vec<BoardRecord>,
+    ) -> CollaborateResult<Option<BoardChange>> {
+        let mut new_lines = String::new();
+        for record in &records {
+            new_lines.push_str(&record_to_line(record)?);
+        }
+
+        let text = self.root.apply("")?;
+        let lines = text.split_inclusive('\n').collect::<Vec<&str>>();
+        let mut delta_builder = PlainDeltaBuilder::new();
+        let mut inserted = false;
+        for (index, line) in lines.iter().enumerate() {
+            let len = FlowyStr::from(*line).utf16_size();
+            if line_indexes.contains(&index) {
+                if !inserted {
+                    delta_builder = delta_builder.insert(&new_lines);
+                    inserted = true;
+                }
+                delta_builder = delta_builder.delete(len);
+            } else {
+                delta_builder = delta_builder.retain(len);
+            }
+        }
+        if !inserted {
+            delta_builder = delta_builder.insert(&new_lines);
+        }
+
+        let delta = delta_builder.build();
+        let _ = self.compose_remote_delta(delta.clone())?;
+        Ok(Some(BoardChange { delta, md5: self.md5() }))
+    }
+}
+
+pub(crate) fn line_to_record(line: &str) -> Option<BoardRecord> {
+    if line.trim().is_empty() {
+        return None;
+    }
+
+    match serde_json::from_str(line) {
+        Ok(record) => Some(record),
+        Err(e) => {
+            tracing::error!("[BoardPad]: Deserialize the line failed: {}, {}", e, line);
+            None
+        }
+    }
+}
+
+fn record_to_line(record: &BoardRecord) -> CollaborateResult<String> {
+    let json = serde_json::to_string(record)
+        .map_err(|e| CollaborateError::internal().context(format!("Serial board record to json failed: {}", e)))?;
+    Ok(format!("{}\n", json))
+}
+
+fn is_card(record: &BoardRecord, card_id: &str) -> bool {
+    matches!(record, BoardRecord::Card(card_record) if card_record.card.id == card_id)
+}
+
+fn validate_column(property: &BoardProperty, column: &str) -> CollaborateResult<()> {
+    if column.is_empty() || property.options.iter().any(|option| option == column) {
+        Ok(())
+    } else {
+        Err(CollaborateError::internal().context(format!("{} is not the option of {}", column, property.key)))
+    }
+}
+
+fn cmp_card_record(a: &CardRecord, b: &CardRecord) -> Ordering {
+    a.position
+        .partial_cmp(&b.position)
+        .unwrap_or(Ordering::Equal)
+        .then_with(|| a.card.id.cmp(&b.card.id))
+}
+
+fn is_between(position: f64, prev: Option<f64>, next: Option<f64>) -> bool {
+    prev.map(|prev| prev < position).unwrap_or(true) && next.map(|next| position < next).unwrap_or(true)
+}
+
+// Returns None if there is no room between the prev and the next.
+fn position_between(prev: Option<f64>, next: Option<f64>) -> Option<f64> {
+    let position = match (prev, next) {
+        (None, None) => 1.0,
+        (Some(prev), None) => prev + 1.0,
+        (None, Some(next)) => next - 1.0,
+        (Some(prev), Some(next)) => prev + (next - prev) / 2.0,
+    };
+
+    if is_between(position, prev, next) {
+        Some(position)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::{default_board_delta, BoardPad, DEFAULT_BOARD_PROPERTY_KEY};
+    use flowy_core_data_model::entities::board::{Card, CardProperty};
+    use lib_ot::core::OperationTransformable;
+
+    fn make_card(id: &str) -> Card {
+        Card {
+            id: id.to_owned(),
+            title: format!("card {}", id),
+            ..Default::default()
+        }
+    }
+
+    fn make_pad(cards: Vec<(&str, &str)>) -> BoardPad {
+        let mut pad = BoardPad::from_delta(default_board_delta()).unwrap();
+        for (id, column) in cards {
+            let _ = pad.create_card(make_card(id), column).unwrap().unwrap();
+        }
+        pad
+    }
+
+    fn column_card_ids(pad: &BoardPad, column: &str) -> Vec<String> {
+        let board = pad.board("board");
+        match board.get_column(column) {
+            None => vec![],
+            Some(column) => column.cards.iter().map(|card| card.id.clone()).collect(),
+        }
+    }
+
+    #[test]
+    fn board_create_card_test() {
+        let pad = make_pad(vec![("1", "To Do"), ("2", "To Do"), ("3", "Done")]);
+        assert_eq!(column_card_ids(&pad, "To Do"), vec!["1", "2"]);
+        assert_eq!(column_card_ids(&pad, "Done"), vec!["3"]);
+        assert!(pad.board("board").get_column("").is_none());
+    }
+
+    #[test]
+    fn board_create_card_with_invalid_column_test() {
+        let mut pad = make_pad(vec![]);
+        assert!(pad.create_card(make_card("1"), "Backlog").is_err());
+    }
+
+    #[test]
+    fn board_move_card_test() {
+        let mut pad = make_pad(vec![("1", "To Do"), ("2", "To Do"), ("3", "Done")]);
+        let _ = pad.move_card("1", "Done", Some("3")).unwrap().unwrap();
+        assert_eq!(column_card_ids(&pad, "To Do"), vec!["2"]);
+        assert_eq!(column_card_ids(&pad, "Done"), vec!["3", "1"]);
+
+        let _ = pad.move_card("1", "Done", None).unwrap().unwrap();
+        assert_eq!(column_card_ids(&pad, "Done"), vec!["1", "3"]);
+        assert!(pad.move_card("1", "Done", None).unwrap().is_none());
+    }
+
+    #[test]
+    fn board_move_card_renumber_test() {
+        let mut pad = make_pad(vec![("1", "To Do"), ("2", "To Do"), ("3", "To Do")]);
+        // The room after the first card is halved by each move until the column is renumbered.
+        for i in 0..100 {
+            let card_id = if i % 2 == 0 { "3" } else { "2" };
+            let _ = pad.move_card(card_id, "To Do", Some("1")).unwrap().unwrap();
+        }
+        assert_eq!(column_card_ids(&pad, "To Do"), vec!["1", "2", "3"]);
+    }
+
+    #[test]
+    fn board_update_card_property_test() {
+        let mut pad = make_pad(vec![("1", "To Do"), ("2", "Done")]);
+        let properties = vec![CardProperty {
+            key: DEFAULT_BOARD_PROPERTY_KEY.to_owned(),
+            value: "Done".to_owned(),
+        }];
+        let _ = pad
+            .update_card("1", Some("new title".to_owned()), None, properties)
+            .unwrap()
+            .unwrap();
+        assert_eq!(column_card_ids(&pad, "Done"), vec!["2", "1"]);
+        assert_eq!(pad.read_card("1").unwrap().title, "new title");
+    }
+
+    #[test]
+    fn board_update_property_test() {
+        let mut pad = make_pad(vec![("1", "To Do"), ("2", "Done")]);
+        let mut property = pad.property();
+        property.options = vec!["Done".to_owned()];
+        let _ = pad.update_property(property).unwrap().unwrap();
+        let board = pad.board("board");
+        assert_eq!(board.columns.len(), 2);
+        assert_eq!(column_card_ids(&pad, ""), vec!["1"]);
+        assert_eq!(column_card_ids(&pad, "Done"), vec!["2"]);
+    }
+
+    #[test]
+    fn board_delete_card_test() {
+        let mut pad = make_pad(vec![("1", "To Do"), ("2", "To Do")]);
+        let _ = pad.delete_card("1").unwrap().unwrap();
+        assert_eq!(column_card_ids(&pad, "To Do"), vec!["2"]);
+        assert!(pad.delete_card("1").unwrap().is_none());
+    }
+
+    #[test]
+    fn board_concurrent_move_different_cards_test() {
+        let base = make_pad(vec![("1", "To Do"), ("2", "To Do"), ("3", "Done")]);
+        let mut pad_a = base.clone();
+        let mut pad_b = base.clone();
+        let change_a = pad_a.move_card("1", "Done", None).unwrap().unwrap();
+        let change_b = pad_b.move_card("2", "Done", Some("3")).unwrap().unwrap();
+
+        let (a_prime, b_prime) = change_a.delta.transform(&change_b.delta).unwrap();
+        let _ = pad_a.compose_remote_delta(b_prime).unwrap();
+        let _ = pad_b.compose_remote_delta(a_prime).unwrap();
+
+        assert_eq!(pad_a.board("board"), pad_b.board("board"));
+        assert_eq!(column_card_ids(&pad_a, "To Do"), Vec::<String>::new());
+        assert_eq!(column_card_ids(&pad_a, "Done"), vec!["1", "3", "2"]);
+    }
+
+    #[test]
+    fn board_concurrent_move_same_card_test() {
+        let base = make_pad(vec![("1", "To Do"), ("2", "Done")]);
+        let mut pad_a = base.clone();
+        let mut pad_b = base.clone();
+        let change_a = pad_a.move_card("1", "In Progress", None).unwrap().unwrap();
+        let change_b = pad_b.move_card("1", "Done", Some("2")).unwrap().unwrap();
+
+        let (a_prime, b_prime) = change_a.delta.transform(&change_b.delta).unwrap();
+        let _ = pad_a.compose_remote_delta(b_prime).unwrap();
+        let _ = pad_b.compose_remote_delta(a_prime).unwrap();
+
+        // Both sides converge and the card is in only one column.
+        assert_eq!(pad_a.delta().apply("").unwrap(), pad_b.delta().apply("").unwrap());
+        let board = pad_a.board("board");
+        let count = board
+            .columns
+            .iter()
+            .flat_map(|column| column.cards.iter())
+            .filter(|card| card.id == "1")
+            .count();
+        assert_eq!(count, 1);
+
+        // The duplicate line of the card is removed by the next change of the card.
+        let _ = pad_a.move_card("1", "To Do", None).unwrap().unwrap();
+        assert_eq!(pad_a.delta().apply("").unwrap().matches("\"id\":\"1\"").count(), 1);
+    }
+}
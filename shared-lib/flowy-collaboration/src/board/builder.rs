@@ -0,0 +1,32 @@
+use crate::{
+    board::{board_pad::line_to_record, default_board_delta, BoardPad},
+    entities::{board_info::BoardDelta, revision::Revision},
+    errors::CollaborateResult,
+};
+use lib_ot::core::OperationTransformable;
+
+pub(crate) struct BoardPadBuilder();
+
+impl BoardPadBuilder {
+    pub(crate) fn build_with_delta(mut delta: BoardDelta) -> CollaborateResult<BoardPad> {
+        if delta.is_empty() {
+            delta = default_board_delta();
+        }
+        let board_text = delta.apply("")?;
+        let records = board_text.split_inclusive('\n').map(line_to_record).collect();
+        Ok(BoardPad { records, root: delta })
+    }
+
+    pub(crate) fn build_with_revisions(revisions: Vec<Revision>) -> CollaborateResult<BoardPad> {
+        let mut board_delta = BoardDelta::new();
+        for revision in revisions {
+            if revision.delta_data.is_empty() {
+                tracing::warn!("revision delta_data is empty");
+            }
+
+            let delta = BoardDelta::from_bytes(revision.delta_data)?;
+            board_delta = board_delta.compose(&delta)?;
+        }
+        BoardPadBuilder::build_with_delta(board_delta)
+    }
+}
@@ -0,0 +1,4 @@
+mod board_pad;
+mod builder;
+
+pub use board_pad::*;
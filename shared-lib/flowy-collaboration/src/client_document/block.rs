@@ -0,0 +1,306 @@
+use crate::{
+    client_document::{document_lines, DocumentLine, TableCellRef},
+    errors::{CollaborateError, CollaborateResult},
+};
+use lib_ot::{
+    core::NEW_LINE,
+    rich_text::{RichTextAttribute, RichTextAttributeKey, RichTextAttributes, RichTextDelta, RichTextDeltaBuilder},
+};
+
+const MAX_INDENT: usize = 8;
+
+// The keys of the block attributes that decide the type of the line. The indent and the align are
+// kept when the type of the line is changed.
+const BLOCK_TYPE_KEYS: [RichTextAttributeKey; 6] = [
+    RichTextAttributeKey::Header,
+    RichTextAttributeKey::List,
+    RichTextAttributeKey::BlockQuote,
+    RichTextAttributeKey::CodeBlock,
+    RichTextAttributeKey::MathBlock,
+    RichTextAttributeKey::Toggle,
+];
+
+// The block edits of the lines. The index is any offset of the line that the command applies to.
+// The line is moved, indented or outdented with its children, which are the lines after it whose
+// indent is greater than its indent. Each command is applied as a single delta, so it's undone or
+// redone as a whole. The command that can't change the line, e.g. moving up the first line,
+// returns an empty delta.
+#[derive(Debug, Clone)]
+pub enum BlockCommand {
+    // Moves the line above the previous line whose indent is not greater than its indent.
+    MoveUp { index: usize },
+    // Moves the line below the next line and the children of the next line.
+    MoveDown { index: usize },
+    // A list item can only be nested one level deeper than the list item before it.
+    Indent { index: usize },
+    // Outdenting a list item that isn't indented turns it into a paragraph.
+    Outdent { index: usize },
+    // Switches the checkbox between checked and unchecked.
+    ToggleCheckbox { index: usize },
+    // Splits the line at the index, both lines keep the block attributes of the line.
+    Split { index: usize },
+    // Merges the line with the next line, the merged line keeps the block attributes of the line.
+    MergeWithNext { index: usize },
+    Convert { index: usize, block_type: BlockType },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum BlockType {
+    Paragraph,
+    Header(usize),
+    BulletList,
+    OrderedList,
+    Checkbox,
+    Quote,
+    CodeBlock,
+    Toggle,
+}
+
+impl BlockType {
+    fn attribute(&self) -> Option<RichTextAttribute> {
+        match self {
+            BlockType::Paragraph => None,
+            BlockType::Header(level) => Some(RichTextAttribute::Header(*level)),
+            BlockType::BulletList => Some(RichTextAttribute::Bullet(true)),
+            BlockType::OrderedList => Some(RichTextAttribute::Ordered(true)),
+            BlockType::Checkbox => Some(RichTextAttribute::UnChecked(true)),
+            BlockType::Quote => Some(RichTextAttribute::BlockQuote(true)),
+            BlockType::CodeBlock => Some(RichTextAttribute::CodeBlock(true)),
+            BlockType::Toggle => Some(RichTextAttribute::Toggle(false)),
+        }
+    }
+}
+
+pub(crate) fn block_command_delta(delta: &RichTextDelta, command: BlockCommand) -> CollaborateResult<RichTextDelta> {
+    let lines = document_lines(delta);
+    match command {
+        BlockCommand::MoveUp { index } => Ok(move_up_delta(&lines, find_line(&lines, index)?)),
+        BlockCommand::MoveDown { index } => Ok(move_down_delta(&lines, find_line(&lines, index)?)),
+        BlockCommand::Indent { index } => Ok(indent_delta(&lines, find_line(&lines, index)?)),
+        BlockCommand::Outdent { index } => Ok(outdent_delta(&lines, find_line(&lines, index)?)),
+        BlockCommand::ToggleCheckbox { index } => toggle_checkbox_delta(&lines[find_line(&lines, index)?], index),
+        BlockCommand::Split { index } => Ok(split_delta(&lines[find_line(&lines, index)?], index)),
+        BlockCommand::MergeWithNext { index } => Ok(merge_delta(&lines, find_line(&lines, index)?)),
+        BlockCommand::Convert { index, block_type } => {
+            Ok(convert_delta(&lines[find_line(&lines, index)?], &block_type))
+        }
+    }
+}
+
+// Returns the position of the line that contains the index. The cells of the tables are edited
+// with the table commands.
+fn find_line(lines: &[DocumentLine], index: usize) -> CollaborateResult<usize> {
+    let i = lines
+        .iter()
+        .position(|line| line.start <= index && index < line.start + line.len)
+        .ok_or_else(|| CollaborateError::out_of_bound().context(format!("Can't find the line at {}", index)))?;
+
+    let line = &lines[i];
+    if table_id(line).is_some() {
+        return Err(CollaborateError::internal().context("The table cell should be edited with the table command"));
+    }
+    if !line.has_newline() {
+        return Err(CollaborateError::out_of_bound().context(format!("The line at {} has no newline", index)));
+    }
+    Ok(i)
+}
+
+fn move_up_delta(lines: &[DocumentLine], i: usize) -> RichTextDelta {
+    let indent = lines[i].indent();
+    let prev = match lines[..i].iter().rposition(|line| line.indent() <= indent) {
+        None if i == 0 => return RichTextDelta::default(),
+        None => 0,
+        Some(prev) => table_start(lines, prev),
+    };
+
+    let block = &lines[i..block_end(lines, i)];
+    if !ends_with_newline(block) {
+        return RichTextDelta::default();
+    }
+
+    let builder = RichTextDeltaBuilder::new().retain(lines[prev].start);
+    insert_lines(builder, block)
+        .retain(lines[i].start - lines[prev].start)
+        .delete(lines_len(block))
+        .build()
+}
+
+fn move_down_delta(lines: &[DocumentLine], i: usize) -> RichTextDelta {
+    let end = block_end(lines, i);
+    if end == lines.len() {
+        return RichTextDelta::default();
+    }
+
+    let next_end = match table_id(&lines[end]) {
+        Some(_) => table_end(lines, end),
+        None => block_end(lines, end),
+    };
+    let (block, next) = (&lines[i..end], &lines[end..next_end]);
+    if !ends_with_newline(block) || !ends_with_newline(next) {
+        return RichTextDelta::default();
+    }
+
+    let builder = RichTextDeltaBuilder::new()
+        .retain(lines[i].start)
+        .delete(lines_len(block))
+        .retain(lines_len(next));
+    insert_lines(builder, block).build()
+}
+
+fn indent_delta(lines: &[DocumentLine], i: usize) -> RichTextDelta {
+    let line = &lines[i];
+    if line.indent() >= MAX_INDENT {
+        return RichTextDelta::default();
+    }
+
+    if list_value(line).is_some() {
+        let can_nest = i > 0 && list_value(&lines[i - 1]).is_some() && lines[i - 1].indent() >= line.indent();
+        if !can_nest {
+            return RichTextDelta::default();
+        }
+    }
+
+    format_lines_delta(&lines[i..block_end(lines, i)], |line| {
+        RichTextAttribute::Indent((line.indent() + 1).min(MAX_INDENT))
+    })
+}
+
+fn outdent_delta(lines: &[DocumentLine], i: usize) -> RichTextDelta {
+    let line = &lines[i];
+    if line.indent() == 0 {
+        return match list_value(line) {
+            None => RichTextDelta::default(),
+            Some(_) => format_lines_delta(&lines[i..=i], |_| RichTextAttribute::List("")),
+        };
+    }
+
+    format_lines_delta(&lines[i..block_end(lines, i)], |line| {
+        RichTextAttribute::Indent(line.indent().saturating_sub(1))
+    })
+}
+
+fn toggle_checkbox_delta(line: &DocumentLine, index: usize) -> CollaborateResult<RichTextDelta> {
+    let value = match list_value(line) {
+        Some("checked") => "unchecked",
+        Some("unchecked") => "checked",
+        _ => {
+            return Err(CollaborateError::record_not_found().context(format!("Can't find the checkbox at {}", index)));
+        }
+    };
+    Ok(format_lines_delta(std::slice::from_ref(line), |_| {
+        RichTextAttribute::List(value)
+    }))
+}
+
+fn split_delta(line: &DocumentLine, index: usize) -> RichTextDelta {
+    RichTextDeltaBuilder::new()
+        .retain(index)
+        .insert_with_attributes(NEW_LINE, line.attributes.clone())
+        .build()
+}
+
+// The newline of the line is deleted, so the text of the next line is moved to the end of the
+// line. The newline of the next line becomes the newline of the merged line, its attributes are
+// replaced with the attributes of the line.
+fn merge_delta(lines: &[DocumentLine], i: usize) -> RichTextDelta {
+    let line = &lines[i];
+    let next = match lines.get(i + 1) {
+        Some(next) if table_id(next).is_none() && next.has_newline() => next,
+        _ => return RichTextDelta::default(),
+    };
+
+    let mut attributes = line.attributes.clone();
+    next.attributes
+        .keys()
+        .filter(|key| !line.attributes.contains_key(*key))
+        .for_each(|key| attributes.delete(key));
+
+    RichTextDeltaBuilder::new()
+        .retain(line.start + line.len - 1)
+        .delete(1)
+        .retain(next.len - 1)
+        .retain_with_attributes(1, attributes)
+        .build()
+}
+
+fn convert_delta(line: &DocumentLine, block_type: &BlockType) -> RichTextDelta {
+    let mut attributes = RichTextAttributes::default();
+    BLOCK_TYPE_KEYS
+        .iter()
+        .filter(|key| line.attributes.contains_key(*key))
+        .for_each(|key| attributes.delete(key));
+    if let Some(attribute) = block_type.attribute() {
+        attributes.add(attribute);
+    }
+
+    RichTextDeltaBuilder::new()
+        .retain(line.start + line.len - 1)
+        .retain_with_attributes(1, attributes)
+        .build()
+}
+
+// Retains the lines and formats their newlines with the attribute.
+fn format_lines_delta<F>(lines: &[DocumentLine], attribute: F) -> RichTextDelta
+where
+    F: Fn(&DocumentLine) -> RichTextAttribute,
+{
+    let mut builder = RichTextDeltaBuilder::new().retain(lines[0].start);
+    for line in lines {
+        builder = builder
+            .retain(line.len - 1)
+            .retain_with_attributes(1, attribute(line).into());
+    }
+    builder.build()
+}
+
+fn insert_lines(mut builder: RichTextDeltaBuilder, lines: &[DocumentLine]) -> RichTextDeltaBuilder {
+    for line in lines {
+        for op in &line.ops {
+            builder = builder.insert_with_attributes(op.get_data(), op.get_attributes());
+        }
+        builder = builder.insert_with_attributes(NEW_LINE, line.attributes.clone());
+    }
+    builder
+}
+
+// Returns the position after the last child of the line.
+fn block_end(lines: &[DocumentLine], i: usize) -> usize {
+    let indent = lines[i].indent();
+    let children = lines[i + 1..].iter().take_while(|line| line.indent() > indent).count();
+    i + 1 + children
+}
+
+// The table is moved over as a whole, the line can't be moved into the table.
+fn table_start(lines: &[DocumentLine], i: usize) -> usize {
+    let mut start = i;
+    while start > 0 && table_id(&lines[start]).is_some() && table_id(&lines[start - 1]) == table_id(&lines[start]) {
+        start -= 1;
+    }
+    start
+}
+
+fn table_end(lines: &[DocumentLine], i: usize) -> usize {
+    let mut end = i + 1;
+    while end < lines.len() && table_id(&lines[end]).is_some() && table_id(&lines[end]) == table_id(&lines[i]) {
+        end += 1;
+    }
+    end
+}
+
+fn table_id(line: &DocumentLine) -> Option<String> {
+    TableCellRef::from_attributes(&line.attributes).map(|cell| cell.table_id)
+}
+
+fn list_value(line: &DocumentLine) -> Option<&str> {
+    line.attributes
+        .get(&RichTextAttributeKey::List)
+        .and_then(|value| value.0.as_deref())
+}
+
+fn lines_len(lines: &[DocumentLine]) -> usize {
+    lines.iter().map(|line| line.len).sum()
+}
+
+fn ends_with_newline(lines: &[DocumentLine]) -> bool {
+    lines.last().map_or(false, |line| line.has_newline())
+}
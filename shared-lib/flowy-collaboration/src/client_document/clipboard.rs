@@ -0,0 +1,159 @@
+use crate::{
+    client_document::{document_lines, document_lines_between, TableCellRef},
+    errors::{CollaborateError, CollaborateResult},
+};
+use lib_infra::uuid_string;
+use lib_ot::{
+    core::{DeltaIter, Interval, NEW_LINE},
+    rich_text::{RichTextAttributeKey, RichTextAttributes, RichTextDelta, RichTextDeltaBuilder},
+};
+use std::collections::HashMap;
+
+// Returns the inserts of the document in the interval. The block attributes of a line are only
+// copied if its newline is in the interval.
+pub(crate) fn copy_fragment(delta: &RichTextDelta, interval: Interval) -> RichTextDelta {
+    let mut fragment = RichTextDelta::new();
+    for op in DeltaIter::from_interval(delta, interval).ops() {
+        fragment.add(op);
+    }
+    fragment
+}
+
+// Returns the delta that inserts the fragment at the index. The pasted text keeps its inline
+// attributes, and the block attributes at the boundaries are merged like typing the newlines:
+// - The newline without block attributes takes the attributes of the line that it's pasted into,
+//   only the first one keeps the header, like PreserveBlockFormatOnInsert.
+// - The text after the last pasted newline stays in the line that it's pasted into, and the header
+//   of that line is removed if the line becomes empty, like ResetLineFormatOnNewLine.
+// - The pasted newlines take the cell of the table cell that they're pasted into, and the pasted
+//   tables get new ids, so they never share the ids with the tables that they were copied from.
+// - The comments are not pasted, their threads are anchored to the copied text.
+pub(crate) fn paste_delta(
+    delta: &RichTextDelta,
+    index: usize,
+    fragment: &RichTextDelta,
+) -> CollaborateResult<RichTextDelta> {
+    if fragment.ops.iter().any(|op| !op.is_insert()) {
+        return Err(CollaborateError::internal().context("The pasted fragment should only contain inserts"));
+    }
+
+    let target = document_lines_between(delta, index, index)
+        .into_iter()
+        .find(|line| line.has_newline() && line.start <= index && index < line.start + line.len);
+    let target_attributes = target.as_ref().map(|line| line.attributes.clone()).unwrap_or_default();
+    let in_table = TableCellRef::from_attributes(&target_attributes).is_some();
+
+    let mut table_ids = TableIds::default();
+    let mut builder = RichTextDeltaBuilder::new().retain(index);
+    let lines = document_lines(fragment);
+    for (i, line) in lines.iter().enumerate() {
+        for op in &line.ops {
+            let mut attributes = op.get_attributes();
+            attributes.remove(RichTextAttributeKey::Comment);
+            builder = builder.insert_with_attributes(op.get_data(), attributes);
+        }
+
+        if !line.has_newline() {
+            continue;
+        }
+
+        let attributes = table_ids.remap(&line.attributes);
+        let attributes = if in_table {
+            target_attributes.clone()
+        } else if !attributes.is_empty() {
+            attributes
+        } else if i == 0 {
+            target_attributes.clone()
+        } else {
+            let mut attributes = target_attributes.clone();
+            attributes.remove(RichTextAttributeKey::Header);
+            attributes
+        };
+        builder = builder.insert_with_attributes(NEW_LINE, attributes);
+    }
+
+    let ends_with_newline = lines.last().map_or(false, |line| line.has_newline());
+    if let Some(target) = target {
+        let is_line_end = index == target.start + target.len - 1;
+        if ends_with_newline && is_line_end && target_attributes.contains_key(&RichTextAttributeKey::Header) {
+            let mut reset_attributes = RichTextAttributes::new();
+            reset_attributes.delete(&RichTextAttributeKey::Header);
+            builder = builder.retain_with_attributes(1, reset_attributes);
+        }
+    }
+    Ok(builder.build())
+}
+
+// Maps the ids of the pasted table cells to the new ids.
+#[derive(Default)]
+struct TableIds {
+    tables: HashMap<String, String>,
+    rows: HashMap<String, String>,
+    columns: HashMap<String, String>,
+}
+
+impl TableIds {
+    fn remap(&mut self, attributes: &RichTextAttributes) -> RichTextAttributes {
+        let cell = match TableCellRef::from_attributes(attributes) {
+            None => return attributes.clone(),
+            Some(cell) => cell,
+        };
+
+        let cell = TableCellRef {
+            table_id: new_id(&mut self.tables, cell.table_id),
+            row_id: new_id(&mut self.rows, cell.row_id),
+            column_id: new_id(&mut self.columns, cell.column_id),
+        };
+        let mut attributes = attributes.clone();
+        attributes.add(cell.to_attribute());
+        attributes
+    }
+}
+
+fn new_id(ids: &mut HashMap<String, String>, id: String) -> String {
+    ids.entry(id).or_insert_with(uuid_string).clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client_document::{ClientDocument, NewlineDoc, TableCommand};
+    use lib_ot::{core::Interval, rich_text::RichTextAttribute};
+
+    #[test]
+    fn paste_table_with_new_ids_test() {
+        let mut document = ClientDocument::new::<NewlineDoc>();
+        document.insert(0, "a").unwrap();
+        document
+            .edit_table(TableCommand::InsertTable {
+                index: 0,
+                rows: 1,
+                columns: 2,
+            })
+            .unwrap();
+        document.insert(2, "x").unwrap();
+
+        let fragment = document.copy_delta(Interval::new(2, 5)).unwrap();
+        document.insert_delta(0, fragment).unwrap();
+        let tables = document.tables();
+        assert_eq!(tables.len(), 2);
+        assert_ne!(tables[0].id, tables[1].id);
+        assert_eq!(tables[0].texts(), tables[1].texts());
+    }
+
+    #[test]
+    fn paste_without_comment_test() {
+        let mut document = ClientDocument::new::<NewlineDoc>();
+        document.insert(0, "abc").unwrap();
+        document.add_comment_anchor(Interval::new(0, 3), "thread").unwrap();
+        let fragment = document.copy_delta(Interval::new(0, 3)).unwrap();
+        document.insert_delta(3, fragment).unwrap();
+        assert_eq!(document.to_plain_string(), "abcabc\n");
+        assert_eq!(document.comment_anchors().get("thread"), Some(&Interval::new(0, 3)));
+
+        document
+            .format(Interval::new(0, 1), RichTextAttribute::Bold(true))
+            .unwrap();
+        let fragment = document.copy_delta(Interval::new(0, 2)).unwrap();
+        assert_eq!(fragment.ops.len(), 2);
+    }
+}
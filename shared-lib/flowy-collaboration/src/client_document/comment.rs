@@ -0,0 +1,70 @@
+use lib_ot::{
+    core::{count_utf16_code_units, DeltaBuilder, DeltaIter, Interval, NEW_LINE},
+    rich_text::{plain_attributes, RichTextAttribute, RichTextAttributeKey, RichTextAttributes, RichTextDelta},
+};
+use std::collections::HashMap;
+
+// The comment threads are anchored to the text by the comment attribute. Its value is the ids of
+// the threads separated by comma, because the text of the threads can overlap. The anchor moves
+// with the text through every composed delta, and the thread is orphaned once its text is deleted.
+const COMMENT_ID_SEPARATOR: &str = ",";
+
+pub fn comment_ids(attributes: &RichTextAttributes) -> Vec<String> {
+    match attributes
+        .get(&RichTextAttributeKey::Comment)
+        .and_then(|value| value.0.as_ref())
+    {
+        None => vec![],
+        Some(s) => s
+            .split(COMMENT_ID_SEPARATOR)
+            .filter(|id| !id.is_empty())
+            .map(|id| id.to_owned())
+            .collect(),
+    }
+}
+
+// Adds the thread_id to the comment attribute of the text in the interval. The newlines are skipped
+// because the comment is an inline attribute.
+pub(crate) fn comment_anchor_delta(delta: &RichTextDelta, interval: Interval, thread_id: &str) -> RichTextDelta {
+    let mut new_delta = DeltaBuilder::new().retain(interval.start).build();
+    let mut iter = DeltaIter::from_offset(delta, interval.start);
+    let mut start = 0;
+    let end = interval.size();
+    while start < end && iter.has_next() {
+        let op = match iter.next_op_with_len(end - start) {
+            None => break,
+            Some(op) => op,
+        };
+        let mut ids = comment_ids(&op.get_attributes());
+        if !ids.iter().any(|id| id == thread_id) {
+            ids.push(thread_id.to_owned());
+        }
+        let attributes: RichTextAttributes = RichTextAttribute::Comment(ids.join(COMMENT_ID_SEPARATOR)).into();
+        for (i, segment) in op.get_data().split(NEW_LINE).enumerate() {
+            if i > 0 {
+                new_delta.retain(1, plain_attributes());
+            }
+            if !segment.is_empty() {
+                new_delta.retain(count_utf16_code_units(segment), attributes.clone());
+            }
+        }
+        start += op.len();
+    }
+    new_delta
+}
+
+// Returns the range of the text that each thread is anchored to. The range covers all the text of
+// the thread if its text was split by the other edits.
+pub(crate) fn comment_anchors(delta: &RichTextDelta) -> HashMap<String, Interval> {
+    let mut anchors: HashMap<String, Interval> = HashMap::new();
+    let mut offset = 0;
+    for op in delta.ops.iter() {
+        let len = op.len();
+        for id in comment_ids(&op.get_attributes()) {
+            let interval = anchors.entry(id).or_insert_with(|| Interval::new(offset, offset + len));
+            interval.end = offset + len;
+        }
+        offset += len;
+    }
+    anchors
+}
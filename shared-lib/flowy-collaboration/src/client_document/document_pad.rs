@@ -1,15 +1,28 @@
 use crate::{
     client_document::{
+        block::{block_command_delta, BlockCommand},
+        clipboard::{copy_fragment, paste_delta},
+        comment::{comment_anchor_delta, comment_anchors},
         default::initial_delta,
+        export::{delta_to_html, delta_to_markdown},
         history::{History, UndoResult},
+        outline::DocumentOutline,
+        statistics::{selection_statistics, StatisticsCache},
+        table::{document_tables, table_command_delta, DocumentTable, TableCommand},
+        toggle::{toggle_blocks, ToggleBlock},
         view::{ViewExtensions, RECORD_THRESHOLD},
     },
+    entities::{
+        outline::{DocumentSection, OutlineItem},
+        statistics::DocumentStatistics,
+    },
     errors::CollaborateError,
 };
 use lib_ot::{
     core::*,
     rich_text::{RichTextAttribute, RichTextDelta},
 };
+use std::collections::HashMap;
 use tokio::sync::mpsc;
 
 pub trait InitialDocumentText {
@@ -36,6 +49,8 @@ pub struct ClientDocument {
     view: ViewExtensions,
     last_edit_time: usize,
     notify: Option<mpsc::UnboundedSender<()>>,
+    outline: DocumentOutline,
+    statistics: StatisticsCache,
 }
 
 impl ClientDocument {
@@ -44,12 +59,16 @@ impl ClientDocument {
     }
 
     pub fn from_delta(delta: RichTextDelta) -> Self {
+        let outline = DocumentOutline::from_delta(&delta);
+        let statistics = StatisticsCache::from_delta(&delta);
         ClientDocument {
             delta,
             history: History::new(),
             view: ViewExtensions::new(),
             last_edit_time: 0,
             notify: None,
+            outline,
+            statistics,
         }
     }
 
@@ -84,15 +103,9 @@ impl ClientDocument {
     }
 
     pub fn set_delta(&mut self, data: RichTextDelta) {
-        tracing::trace!("document: {}", data.to_json());
-        self.delta = data;
-
-        match &self.notify {
-            None => {}
-            Some(notify) => {
-                let _ = notify.send(());
-            }
-        }
+        self.outline = DocumentOutline::from_delta(&data);
+        self.statistics = StatisticsCache::from_delta(&data);
+        self.replace_delta(data);
     }
 
     pub fn compose_delta(&mut self, delta: RichTextDelta) -> Result<(), CollaborateError> {
@@ -117,7 +130,7 @@ impl ClientDocument {
             self.history.record(undo_delta);
         }
 
-        self.set_delta(composed_delta);
+        self.apply_change(composed_delta, &delta);
         Ok(())
     }
 
@@ -169,6 +182,115 @@ impl ClientDocument {
         Ok(delta)
     }
 
+    // Pastes the fragment copied by the copy_delta. The paste is recorded as its own undo item.
+    pub fn insert_delta(&mut self, index: usize, fragment: RichTextDelta) -> Result<RichTextDelta, CollaborateError> {
+        self.replace_with_delta(Interval::new(index, index), fragment)
+    }
+
+    // The text in the interval is deleted first, then the fragment is pasted at the start of the
+    // interval. Both of them are composed into one delta.
+    pub fn replace_with_delta(
+        &mut self,
+        interval: Interval,
+        fragment: RichTextDelta,
+    ) -> Result<RichTextDelta, CollaborateError> {
+        let _ = validate_interval(&self.delta, &interval)?;
+        let mut delta = RichTextDelta::default();
+        if !interval.is_empty() {
+            delta = self.view.delete(&self.delta, interval)?;
+        }
+
+        let deleted = self.delta.compose(&delta)?;
+        let paste = paste_delta(&deleted, interval.start, &fragment)?;
+        let delta = delta.compose(&paste)?;
+        self.last_edit_time = 0;
+        self.compose_delta(delta.clone())?;
+        self.last_edit_time = 0;
+        Ok(delta)
+    }
+
+    pub fn copy_delta(&self, interval: Interval) -> Result<RichTextDelta, CollaborateError> {
+        let _ = validate_interval(&self.delta, &interval)?;
+        Ok(copy_fragment(&self.delta, interval))
+    }
+
+    // The table command is recorded as its own undo item, it's never composed with the previous or
+    // the next edit.
+    pub fn edit_table(&mut self, command: TableCommand) -> Result<RichTextDelta, CollaborateError> {
+        tracing::trace!("edit table: {:?}", command);
+        let delta = table_command_delta(&self.delta, command)?;
+        self.last_edit_time = 0;
+        self.compose_delta(delta.clone())?;
+        self.last_edit_time = 0;
+        Ok(delta)
+    }
+
+    // The block command is recorded as its own undo item like the table command.
+    pub fn edit_block(&mut self, command: BlockCommand) -> Result<RichTextDelta, CollaborateError> {
+        tracing::trace!("edit block: {:?}", command);
+        let delta = block_command_delta(&self.delta, command)?;
+        if delta.is_empty() {
+            return Ok(delta);
+        }
+
+        self.last_edit_time = 0;
+        self.compose_delta(delta.clone())?;
+        self.last_edit_time = 0;
+        Ok(delta)
+    }
+
+    pub fn tables(&self) -> Vec<DocumentTable> {
+        document_tables(&self.delta)
+    }
+
+    pub fn add_comment_anchor(
+        &mut self,
+        interval: Interval,
+        thread_id: &str,
+    ) -> Result<RichTextDelta, CollaborateError> {
+        let _ = validate_interval(&self.delta, &interval)?;
+        if interval.is_empty() {
+            return Err(CollaborateError::out_of_bound().context("The comment should be anchored to some text"));
+        }
+        let delta = comment_anchor_delta(&self.delta, interval, thread_id);
+        self.compose_delta(delta.clone())?;
+        Ok(delta)
+    }
+
+    pub fn comment_anchors(&self) -> HashMap<String, Interval> {
+        comment_anchors(&self.delta)
+    }
+
+    pub fn outline(&self) -> Vec<OutlineItem> {
+        self.outline.items().to_vec()
+    }
+
+    // Returns the section of the header at the index of the outline.
+    pub fn outline_section(&self, index: usize) -> Option<DocumentSection> {
+        self.outline.section(index, self.delta.utf16_target_len)
+    }
+
+    pub fn statistics(&self) -> DocumentStatistics {
+        self.statistics.statistics()
+    }
+
+    pub fn selection_statistics(&self, interval: Interval) -> Result<DocumentStatistics, CollaborateError> {
+        let _ = validate_interval(&self.delta, &interval)?;
+        Ok(selection_statistics(&self.delta, interval))
+    }
+
+    pub fn toggle_blocks(&self) -> Vec<ToggleBlock> {
+        toggle_blocks(&self.delta)
+    }
+
+    pub fn to_markdown(&self) -> String {
+        delta_to_markdown(&self.delta)
+    }
+
+    pub fn to_html(&self) -> String {
+        delta_to_html(&self.delta)
+    }
+
     pub fn can_undo(&self) -> bool {
         self.history.can_undo()
     }
@@ -182,7 +304,7 @@ impl ClientDocument {
             None => Err(CollaborateError::undo().context("Undo stack is empty")),
             Some(undo_delta) => {
                 let (new_delta, inverted_delta) = self.invert(&undo_delta)?;
-                self.set_delta(new_delta);
+                self.apply_change(new_delta, &undo_delta);
                 self.history.add_redo(inverted_delta);
                 Ok(UndoResult { delta: undo_delta })
             }
@@ -194,7 +316,7 @@ impl ClientDocument {
             None => Err(CollaborateError::redo()),
             Some(redo_delta) => {
                 let (new_delta, inverted_delta) = self.invert(&redo_delta)?;
-                self.set_delta(new_delta);
+                self.apply_change(new_delta, &redo_delta);
                 self.history.add_undo(inverted_delta);
                 Ok(UndoResult { delta: redo_delta })
             }
@@ -208,6 +330,26 @@ impl ClientDocument {
 }
 
 impl ClientDocument {
+    // The data is the document composed with the change. The outline and the statistics are
+    // updated with the change instead of being read from the whole document.
+    fn apply_change(&mut self, data: RichTextDelta, change: &RichTextDelta) {
+        self.outline.apply_change(&data, change);
+        self.statistics.apply_change(&data, change);
+        self.replace_delta(data);
+    }
+
+    fn replace_delta(&mut self, data: RichTextDelta) {
+        tracing::trace!("document: {}", data.to_json());
+        self.delta = data;
+
+        match &self.notify {
+            None => {}
+            Some(notify) => {
+                let _ = notify.send(());
+            }
+        }
+    }
+
     fn invert(&self, delta: &RichTextDelta) -> Result<(RichTextDelta, RichTextDelta), CollaborateError> {
         // c = a.compose(b)
         // d = b.invert(a)
@@ -0,0 +1,353 @@
+use crate::client_document::{document_blocks, highlight_code, DocumentBlock, DocumentLine, DocumentTable};
+use flowy_core_data_model::entities::highlight::HighlightKind;
+use lib_ot::rich_text::{
+    code_block_language, RichTextAttributeKey, RichTextAttributes, RichTextDelta, RichTextOperation,
+};
+
+// The code blocks and the math blocks are wrapped in fences, a new fence is opened if the
+// language of the code block changes.
+pub(crate) fn delta_to_markdown(delta: &RichTextDelta) -> String {
+    let mut output = vec![];
+    let mut open_raw_block: Option<RawBlock> = None;
+    for block in document_blocks(delta) {
+        let line = match block {
+            DocumentBlock::Table(table) => {
+                if let Some(raw_block) = open_raw_block.take() {
+                    output.push(raw_block.closing_fence().to_owned());
+                }
+                output.push(table_to_markdown(&table));
+                continue;
+            }
+            DocumentBlock::Line(line) => line,
+        };
+
+        let raw_block = RawBlock::from_attributes(&line.attributes);
+        if raw_block != open_raw_block {
+            if let Some(open_raw_block) = &open_raw_block {
+                output.push(open_raw_block.closing_fence().to_owned());
+            }
+            if let Some(raw_block) = &raw_block {
+                output.push(raw_block.opening_fence());
+            }
+            open_raw_block = raw_block;
+        }
+
+        if open_raw_block.is_some() {
+            output.push(line.text());
+        } else {
+            output.push(format!(
+                "{}{}",
+                markdown_line_prefix(&line.attributes),
+                inline_markdown(&line)
+            ));
+        }
+    }
+
+    if let Some(raw_block) = open_raw_block {
+        output.push(raw_block.closing_fence().to_owned());
+    }
+    output.join("\n")
+}
+
+pub(crate) fn delta_to_html(delta: &RichTextDelta) -> String {
+    let mut output = String::new();
+    let mut open_container: Option<&'static str> = None;
+    let mut open_raw_block: Option<(RawBlock, Vec<String>)> = None;
+    for block in document_blocks(delta) {
+        let line = match block {
+            DocumentBlock::Table(table) => {
+                close_raw_block(&mut output, &mut open_raw_block);
+                close_container(&mut output, &mut open_container);
+                output.push_str(&table_to_html(&table));
+                continue;
+            }
+            DocumentBlock::Line(line) => line,
+        };
+
+        // The lines of the raw block are exported together, because a comment or a string of the
+        // code, or a formula can span multiple lines.
+        if let Some(raw_block) = RawBlock::from_attributes(&line.attributes) {
+            close_container(&mut output, &mut open_container);
+            if open_raw_block.as_ref().map(|(open_raw_block, _)| open_raw_block) != Some(&raw_block) {
+                close_raw_block(&mut output, &mut open_raw_block);
+                open_raw_block = Some((raw_block, vec![]));
+            }
+            if let Some((_, lines)) = open_raw_block.as_mut() {
+                lines.push(line.text());
+            }
+            continue;
+        }
+        close_raw_block(&mut output, &mut open_raw_block);
+
+        let container = html_container(&line.attributes);
+        if container != open_container {
+            close_container(&mut output, &mut open_container);
+            if let Some(container) = container {
+                output.push_str(&format!("<{}>", container));
+            }
+            open_container = container;
+        }
+
+        match container {
+            Some(_) => output.push_str(&format!("<li>{}</li>", inline_html(&line))),
+            None => {
+                let tag = html_line_tag(&line.attributes);
+                output.push_str(&format!("<{}>{}</{}>", tag, inline_html(&line), tag));
+            }
+        }
+    }
+    close_raw_block(&mut output, &mut open_raw_block);
+    close_container(&mut output, &mut open_container);
+    output
+}
+
+// The lines whose text is exported as is.
+#[derive(PartialEq)]
+enum RawBlock {
+    // The code block with its language.
+    Code(Option<String>),
+    Math,
+}
+
+impl RawBlock {
+    fn from_attributes(attributes: &RichTextAttributes) -> Option<Self> {
+        if has_attribute(attributes, RichTextAttributeKey::CodeBlock) {
+            let language = code_block_language(attributes).map(|language| language.to_owned());
+            return Some(RawBlock::Code(language));
+        }
+        if has_attribute(attributes, RichTextAttributeKey::MathBlock) {
+            return Some(RawBlock::Math);
+        }
+        None
+    }
+
+    fn opening_fence(&self) -> String {
+        match self {
+            RawBlock::Code(language) => format!("```{}", language.as_deref().unwrap_or("")),
+            RawBlock::Math => "$$".to_owned(),
+        }
+    }
+
+    fn closing_fence(&self) -> &'static str {
+        match self {
+            RawBlock::Code(_) => "```",
+            RawBlock::Math => "$$",
+        }
+    }
+}
+
+// The code block without language is exported as the plain <pre>, and the math block is exported
+// as the display math of MathJax.
+fn close_raw_block(output: &mut String, open_raw_block: &mut Option<(RawBlock, Vec<String>)>) {
+    let (raw_block, lines) = match open_raw_block.take() {
+        None => return,
+        Some(open_raw_block) => open_raw_block,
+    };
+
+    let mut text = lines.join("\n");
+    match raw_block {
+        RawBlock::Code(None) => {
+            text.push('\n');
+            output.push_str(&format!("<pre>{}</pre>", escape_html(&text)));
+        }
+        RawBlock::Code(Some(language)) => {
+            text.push('\n');
+            output.push_str(&format!(
+                "<pre><code class=\"language-{}\">{}</code></pre>",
+                escape_html(&language),
+                highlight_html(&language, &text)
+            ));
+        }
+        RawBlock::Math => output.push_str(&format!(
+            "<div class=\"math-display\">\\[{}\\]</div>",
+            escape_html(&text)
+        )),
+    }
+}
+
+fn highlight_html(language: &str, code: &str) -> String {
+    let utf16 = code.encode_utf16().collect::<Vec<u16>>();
+    let text = |start: usize, end: usize| escape_html(&String::from_utf16_lossy(&utf16[start..end]));
+    let mut html = String::new();
+    let mut offset = 0;
+    for span in highlight_code(language, code) {
+        let (start, end) = (span.start as usize, span.end as usize);
+        let class = match span.kind {
+            HighlightKind::Keyword => "hl-keyword",
+            HighlightKind::StringLiteral => "hl-string",
+            HighlightKind::Number => "hl-number",
+            HighlightKind::Comment => "hl-comment",
+        };
+        html.push_str(&text(offset, start));
+        html.push_str(&format!("<span class=\"{}\">{}</span>", class, text(start, end)));
+        offset = end;
+    }
+    html.push_str(&text(offset, utf16.len()));
+    html
+}
+
+// The lines of a cell are joined with <br>, because a markdown table cell can't contain newlines.
+fn table_to_markdown(table: &DocumentTable) -> String {
+    let rows = table
+        .rows
+        .iter()
+        .map(|row| {
+            let cells = table
+                .columns
+                .iter()
+                .map(|column_id| match row.cell(column_id) {
+                    None => "".to_owned(),
+                    Some(cell) => cell
+                        .lines
+                        .iter()
+                        .map(|line| inline_markdown(line).replace('|', "\\|"))
+                        .collect::<Vec<String>>()
+                        .join("<br>"),
+                })
+                .collect::<Vec<String>>();
+            format!("| {} |", cells.join(" | "))
+        })
+        .collect::<Vec<String>>();
+
+    let separator = format!("|{}", " --- |".repeat(table.column_count()));
+    let mut lines = vec![];
+    let mut rows = rows.into_iter();
+    if let Some(header) = rows.next() {
+        lines.push(header);
+        lines.push(separator);
+    }
+    lines.extend(rows);
+    lines.join("\n")
+}
+
+fn table_to_html(table: &DocumentTable) -> String {
+    let mut html = "<table>".to_owned();
+    for row in &table.rows {
+        html.push_str("<tr>");
+        for column_id in &table.columns {
+            let content = match row.cell(column_id) {
+                None => "".to_owned(),
+                Some(cell) => cell.lines.iter().map(inline_html).collect::<Vec<String>>().join("<br>"),
+            };
+            html.push_str(&format!("<td>{}</td>", content));
+        }
+        html.push_str("</tr>");
+    }
+    html.push_str("</table>");
+    html
+}
+
+fn markdown_line_prefix(attributes: &RichTextAttributes) -> String {
+    let mut prefix = String::new();
+    if has_attribute(attributes, RichTextAttributeKey::BlockQuote) {
+        prefix.push_str("> ");
+    }
+    if let Some(level) = attribute_value(attributes, RichTextAttributeKey::Header) {
+        let level = level.parse::<usize>().unwrap_or(1);
+        prefix.push_str(&format!("{} ", "#".repeat(level)));
+    }
+    match attribute_value(attributes, RichTextAttributeKey::List) {
+        Some("bullet") => prefix.push_str("- "),
+        Some("ordered") => prefix.push_str("1. "),
+        Some("checked") => prefix.push_str("- [x] "),
+        Some("unchecked") => prefix.push_str("- [ ] "),
+        _ => {}
+    }
+    prefix
+}
+
+fn inline_markdown(line: &DocumentLine) -> String {
+    line.ops.iter().map(op_to_markdown).collect()
+}
+
+fn op_to_markdown(op: &RichTextOperation) -> String {
+    let attributes = op.get_attributes();
+    let mut s = op.get_data().to_owned();
+    if has_attribute(&attributes, RichTextAttributeKey::Formula) {
+        s = format!("${}$", s);
+    }
+    if has_attribute(&attributes, RichTextAttributeKey::InlineCode) {
+        s = format!("`{}`", s);
+    }
+    if has_attribute(&attributes, RichTextAttributeKey::Bold) {
+        s = format!("**{}**", s);
+    }
+    if has_attribute(&attributes, RichTextAttributeKey::Italic) {
+        s = format!("_{}_", s);
+    }
+    if has_attribute(&attributes, RichTextAttributeKey::StrikeThrough) {
+        s = format!("~~{}~~", s);
+    }
+    if let Some(link) = attribute_value(&attributes, RichTextAttributeKey::Link) {
+        s = format!("[{}]({})", s, link);
+    }
+    s
+}
+
+fn inline_html(line: &DocumentLine) -> String {
+    line.ops.iter().map(op_to_html).collect()
+}
+
+fn op_to_html(op: &RichTextOperation) -> String {
+    let attributes = op.get_attributes();
+    let mut s = escape_html(op.get_data());
+    if has_attribute(&attributes, RichTextAttributeKey::Formula) {
+        s = format!("<span class=\"math-inline\">\\({}\\)</span>", s);
+    }
+    let tags = [
+        (RichTextAttributeKey::InlineCode, "code"),
+        (RichTextAttributeKey::Bold, "strong"),
+        (RichTextAttributeKey::Italic, "em"),
+        (RichTextAttributeKey::Underline, "u"),
+        (RichTextAttributeKey::StrikeThrough, "s"),
+    ];
+    for (key, tag) in tags {
+        if has_attribute(&attributes, key) {
+            s = format!("<{}>{}</{}>", tag, s, tag);
+        }
+    }
+    if let Some(link) = attribute_value(&attributes, RichTextAttributeKey::Link) {
+        s = format!("<a href=\"{}\">{}</a>", escape_html(link), s);
+    }
+    s
+}
+
+// The consecutive lines of the lists are wrapped in one container.
+fn html_container(attributes: &RichTextAttributes) -> Option<&'static str> {
+    match attribute_value(attributes, RichTextAttributeKey::List) {
+        Some("ordered") => Some("ol"),
+        Some(_) => Some("ul"),
+        None => None,
+    }
+}
+
+fn close_container(output: &mut String, open_container: &mut Option<&'static str>) {
+    if let Some(container) = open_container.take() {
+        output.push_str(&format!("</{}>", container));
+    }
+}
+
+fn html_line_tag(attributes: &RichTextAttributes) -> String {
+    if let Some(level) = attribute_value(attributes, RichTextAttributeKey::Header) {
+        return format!("h{}", level.parse::<usize>().unwrap_or(1).min(6));
+    }
+    if has_attribute(attributes, RichTextAttributeKey::BlockQuote) {
+        return "blockquote".to_owned();
+    }
+    "p".to_owned()
+}
+
+fn attribute_value(attributes: &RichTextAttributes, key: RichTextAttributeKey) -> Option<&str> {
+    attributes.get(&key).and_then(|value| value.0.as_deref())
+}
+
+fn has_attribute(attributes: &RichTextAttributes, key: RichTextAttributeKey) -> bool {
+    attribute_value(attributes, key).is_some()
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,55 @@
+use crate::client_document::{document_lines_between, DeleteExt, TableCellRef};
+use lib_ot::{
+    core::{DeltaBuilder, Interval},
+    rich_text::RichTextDelta,
+};
+
+// Deleting a newline merges its line into the next line. The newline is kept if the two lines
+// don't belong to the same cell, otherwise the text of the cells would be merged into each other or
+// into the lines around the table.
+pub struct PreserveTableCellOnDelete {}
+impl DeleteExt for PreserveTableCellOnDelete {
+    fn ext_name(&self) -> &str {
+        "PreserveTableCellOnDelete"
+    }
+
+    fn apply(&self, delta: &RichTextDelta, interval: Interval) -> Option<RichTextDelta> {
+        if interval.is_empty() {
+            return None;
+        }
+
+        // The lines that contain the interval, including the line after the last deleted newline.
+        let lines = document_lines_between(delta, interval.start, interval.end);
+        let cells = lines
+            .iter()
+            .map(|line| TableCellRef::from_attributes(&line.attributes))
+            .collect::<Vec<Option<TableCellRef>>>();
+        if cells.iter().all(|cell| cell.is_none()) {
+            return None;
+        }
+
+        let kept_newlines = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.has_newline())
+            .map(|(i, line)| (i, line.start + line.len - 1))
+            .filter(|(_, newline)| interval.contains(*newline))
+            .filter(|(i, _)| {
+                let next_cell = cells.get(i + 1).cloned().flatten();
+                cells[*i] != next_cell
+            })
+            .map(|(_, newline)| newline)
+            .collect::<Vec<usize>>();
+        if kept_newlines.is_empty() {
+            return None;
+        }
+
+        let mut builder = DeltaBuilder::new().retain(interval.start);
+        let mut offset = interval.start;
+        for newline in kept_newlines {
+            builder = builder.delete(newline - offset).retain(1);
+            offset = newline + 1;
+        }
+        Some(builder.delete(interval.end - offset).build())
+    }
+}
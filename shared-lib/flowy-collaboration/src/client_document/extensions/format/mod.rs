@@ -1,7 +1,9 @@
 pub use format_at_position::*;
 pub use resolve_block_format::*;
 pub use resolve_inline_format::*;
+pub use resolve_table_format::*;
 
 mod format_at_position;
 mod resolve_block_format;
 mod resolve_inline_format;
+mod resolve_table_format;
@@ -0,0 +1,29 @@
+use lib_ot::{
+    core::{DeltaBuilder, Interval},
+    rich_text::{RichTextAttribute, RichTextAttributeKey, RichTextDelta},
+};
+
+use crate::client_document::FormatExt;
+
+// The table_cell attribute can only be changed by the table commands. Formatting the lines with it
+// would break the rows and the columns of the table, so it's ignored.
+pub struct ResolveTableFormat {}
+impl FormatExt for ResolveTableFormat {
+    fn ext_name(&self) -> &str {
+        "ResolveTableFormat"
+    }
+
+    fn apply(
+        &self,
+        _delta: &RichTextDelta,
+        _interval: Interval,
+        attribute: &RichTextAttribute,
+    ) -> Option<RichTextDelta> {
+        if attribute.key != RichTextAttributeKey::TableCell {
+            return None;
+        }
+
+        tracing::warn!("[ResolveTableFormat]: Use the table commands to edit the table");
+        Some(DeltaBuilder::new().build())
+    }
+}
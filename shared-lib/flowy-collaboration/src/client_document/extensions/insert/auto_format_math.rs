@@ -0,0 +1,97 @@
+use crate::{
+    client_document::{document_lines, DocumentLine, InsertExt},
+    util::is_newline,
+};
+use lib_ot::{
+    core::{count_utf16_code_units, DeltaBuilder},
+    rich_text::{RichTextAttribute, RichTextAttributeKey, RichTextDelta},
+};
+
+const MATH_DELIMITER: &str = "$";
+const MATH_BLOCK_DELIMITER: &str = "$$";
+
+// Typing the closing $ of $...$ formats the text between the dollars as the inline formula, and
+// entering a newline on a line that only contains $$ turns the line into a math block. The
+// dollars are removed, the LaTeX source is kept as the text of the document.
+pub struct AutoFormatMath {}
+impl InsertExt for AutoFormatMath {
+    fn ext_name(&self) -> &str {
+        "AutoFormatMath"
+    }
+
+    fn apply(&self, delta: &RichTextDelta, replace_len: usize, text: &str, index: usize) -> Option<RichTextDelta> {
+        if replace_len != 0 {
+            return None;
+        }
+
+        let line = document_lines(delta)
+            .into_iter()
+            .find(|line| line.start <= index && index < line.start + line.len)?;
+        let is_raw_line = [
+            RichTextAttributeKey::CodeBlock,
+            RichTextAttributeKey::MathBlock,
+            RichTextAttributeKey::TableCell,
+        ]
+        .iter()
+        .any(|key| line.attributes.contains_key(key));
+        if is_raw_line {
+            return None;
+        }
+
+        if is_newline(text) {
+            return format_math_block(&line, index);
+        }
+
+        if text == MATH_DELIMITER {
+            return format_inline_math(&line, index);
+        }
+
+        None
+    }
+}
+
+fn format_math_block(line: &DocumentLine, index: usize) -> Option<RichTextDelta> {
+    if !line.attributes.is_empty() || line.text() != MATH_BLOCK_DELIMITER {
+        return None;
+    }
+
+    let delimiter_len = count_utf16_code_units(MATH_BLOCK_DELIMITER);
+    if index != line.start + delimiter_len {
+        return None;
+    }
+
+    Some(
+        DeltaBuilder::new()
+            .retain(line.start)
+            .delete(delimiter_len)
+            .retain_with_attributes(1, RichTextAttribute::MathBlock(true).into())
+            .build(),
+    )
+}
+
+// The formula must not start or end with whitespace, so the text like "$5 and $" is not treated
+// as a formula.
+fn format_inline_math(line: &DocumentLine, index: usize) -> Option<RichTextDelta> {
+    let text_before_index = line
+        .text()
+        .encode_utf16()
+        .take(index - line.start)
+        .collect::<Vec<u16>>();
+    let text_before_index = String::from_utf16(&text_before_index).ok()?;
+    let (prefix, source) = text_before_index.rsplit_once(MATH_DELIMITER)?;
+    if source.is_empty() || source.starts_with(char::is_whitespace) || source.ends_with(char::is_whitespace) {
+        return None;
+    }
+
+    if prefix.ends_with(MATH_DELIMITER) {
+        return None;
+    }
+
+    Some(
+        DeltaBuilder::new()
+            .retain(line.start + count_utf16_code_units(prefix))
+            .delete(count_utf16_code_units(MATH_DELIMITER))
+            .retain_with_attributes(count_utf16_code_units(source), RichTextAttribute::Formula(true).into())
+            .build(),
+    )
+}
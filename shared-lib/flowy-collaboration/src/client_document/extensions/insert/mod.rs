@@ -1,17 +1,21 @@
 use crate::client_document::InsertExt;
 pub use auto_exit_block::*;
 pub use auto_format::*;
+pub use auto_format_math::*;
 pub use default_insert::*;
 use lib_ot::rich_text::RichTextDelta;
 pub use preserve_block_format::*;
 pub use preserve_inline_format::*;
+pub use preserve_table_cell::*;
 pub use reset_format_on_new_line::*;
 
 mod auto_exit_block;
 mod auto_format;
+mod auto_format_math;
 mod default_insert;
 mod preserve_block_format;
 mod preserve_inline_format;
+mod preserve_table_cell;
 mod reset_format_on_new_line;
 
 pub struct InsertEmbedsExt {}
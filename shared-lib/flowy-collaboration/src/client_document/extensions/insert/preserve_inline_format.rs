@@ -25,6 +25,19 @@ impl InsertExt for PreserveInlineFormat {
         }
 
         let mut attributes = prev.get_attributes();
+        let next = iter.next_op();
+
+        // The formula is only extended if the text is inserted inside it, so the text typed right
+        // after the formula is plain.
+        if attributes.contains_key(&RichTextAttributeKey::Formula) {
+            let is_inside_formula = next.as_ref().map_or(false, |next| {
+                next.get_attributes().contains_key(&RichTextAttributeKey::Formula)
+            });
+            if !is_inside_formula {
+                attributes.remove(RichTextAttributeKey::Formula);
+            }
+        }
+
         if attributes.is_empty() || !attributes.contains_key(&RichTextAttributeKey::Link) {
             return Some(
                 DeltaBuilder::new()
@@ -34,7 +47,6 @@ impl InsertExt for PreserveInlineFormat {
             );
         }
 
-        let next = iter.next_op();
         match &next {
             None => attributes = plain_attributes(),
             Some(next) => {
@@ -0,0 +1,39 @@
+use crate::{
+    client_document::{InsertExt, TableCellRef},
+    util::contain_newline,
+};
+use lib_ot::{
+    core::{DeltaBuilder, DeltaIter, NEW_LINE},
+    rich_text::{plain_attributes, RichTextDelta},
+};
+
+// The new lines inserted into a table cell belong to the same cell, so the rows and the columns of
+// the table are kept. It runs before the AutoExitBlock because the cell can't be exited by enter.
+pub struct PreserveTableCellOnInsert {}
+impl InsertExt for PreserveTableCellOnInsert {
+    fn ext_name(&self) -> &str {
+        "PreserveTableCellOnInsert"
+    }
+
+    fn apply(&self, delta: &RichTextDelta, replace_len: usize, text: &str, index: usize) -> Option<RichTextDelta> {
+        if !contain_newline(text) {
+            return None;
+        }
+
+        let mut iter = DeltaIter::from_offset(delta, index);
+        let (newline_op, _) = iter.next_op_with_newline()?;
+        let newline_attributes = newline_op.get_attributes();
+        let _ = TableCellRef::from_attributes(&newline_attributes)?;
+
+        let mut new_delta = DeltaBuilder::new().retain(index + replace_len).build();
+        for (i, line) in text.split(NEW_LINE).enumerate() {
+            if i > 0 {
+                new_delta.insert(NEW_LINE, newline_attributes.clone());
+            }
+            if !line.is_empty() {
+                new_delta.insert(line, plain_attributes());
+            }
+        }
+        Some(new_delta)
+    }
+}
@@ -0,0 +1,399 @@
+use flowy_core_data_model::entities::highlight::{HighlightKind, HighlightSpan};
+
+// The tokenizer only recognizes the keywords, the strings, the numbers and the comments of the
+// language, which is enough for highlighting the snippets. The text that is not covered by any
+// span is plain.
+struct LanguageSpec {
+    names: &'static [&'static str],
+    // The keywords are separated by whitespace.
+    keywords: &'static str,
+    line_comments: &'static [&'static str],
+    block_comment: Option<(&'static str, &'static str)>,
+    quotes: &'static [char],
+    // The quotes whose string can span multiple lines.
+    multiline_quotes: &'static [char],
+    // The single quote is the char literal only if it's closed right after one char, otherwise
+    // it's the lifetime or the label, like the rust.
+    char_literals: bool,
+    case_insensitive: bool,
+}
+
+const C_LIKE_QUOTES: &[char] = &['"', '\''];
+
+const LANGUAGES: &[LanguageSpec] = &[
+    LanguageSpec {
+        names: &["rust", "rs"],
+        keywords: "as async await break const continue crate dyn else enum extern false fn for if impl in let loop \
+            match mod move mut pub ref return self Self static struct super trait true type unsafe use where \
+            while",
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"'],
+        multiline_quotes: &['"'],
+        char_literals: true,
+        case_insensitive: false,
+    },
+    LanguageSpec {
+        names: &["javascript", "js", "typescript", "ts"],
+        keywords: "async await break case catch class const continue default delete do else export extends false \
+            finally for from function if import in instanceof interface let new null return static super switch \
+            this throw true try type typeof undefined var void while yield",
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"', '\'', '`'],
+        multiline_quotes: &['`'],
+        char_literals: false,
+        case_insensitive: false,
+    },
+    LanguageSpec {
+        names: &["python", "py"],
+        keywords: "and as assert async await break class continue def del elif else except False finally for from \
+            global if import in is lambda None nonlocal not or pass raise return True try while with yield",
+        line_comments: &["#"],
+        block_comment: None,
+        quotes: C_LIKE_QUOTES,
+        multiline_quotes: &[],
+        char_literals: false,
+        case_insensitive: false,
+    },
+    LanguageSpec {
+        names: &["dart"],
+        keywords: "abstract as async await break case catch class const continue default do else enum extends false \
+            final finally for if implements import in is late new null required return static super switch this \
+            throw true try var void while with yield",
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: C_LIKE_QUOTES,
+        multiline_quotes: &[],
+        char_literals: false,
+        case_insensitive: false,
+    },
+    LanguageSpec {
+        names: &["go", "golang"],
+        keywords: "break case chan const continue default defer else fallthrough false for func go goto if import \
+            interface map nil package range return select struct switch true type var",
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"', '\'', '`'],
+        multiline_quotes: &['`'],
+        char_literals: false,
+        case_insensitive: false,
+    },
+    LanguageSpec {
+        names: &["java", "kotlin", "kt"],
+        keywords: "abstract boolean break case catch class continue default do else enum extends false final finally \
+            for fun if implements import instanceof interface new null override package private protected public \
+            return static super switch this throw throws true try val var void when while",
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: C_LIKE_QUOTES,
+        multiline_quotes: &[],
+        char_literals: false,
+        case_insensitive: false,
+    },
+    LanguageSpec {
+        names: &["c", "cpp", "c++", "h"],
+        keywords:
+            "auto bool break case char class const continue default delete do double else enum extern false float \
+            for if include int long namespace new nullptr private public return short sizeof static struct \
+            switch template this true typedef unsigned using void while",
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: C_LIKE_QUOTES,
+        multiline_quotes: &[],
+        char_literals: false,
+        case_insensitive: false,
+    },
+    LanguageSpec {
+        names: &["json"],
+        keywords: "false null true",
+        line_comments: &[],
+        block_comment: None,
+        quotes: &['"'],
+        multiline_quotes: &[],
+        char_literals: false,
+        case_insensitive: false,
+    },
+    LanguageSpec {
+        names: &["sql"],
+        keywords: "and as asc by create delete desc distinct drop from group having in index insert into is join left \
+            limit not null on or order primary right select set table update values where",
+        line_comments: &["--"],
+        block_comment: Some(("/*", "*/")),
+        quotes: C_LIKE_QUOTES,
+        multiline_quotes: &[],
+        char_literals: false,
+        case_insensitive: true,
+    },
+    LanguageSpec {
+        names: &["shell", "bash", "sh", "zsh"],
+        keywords: "case do done echo elif else esac exit export fi for function if in local return then until while",
+        line_comments: &["#"],
+        block_comment: None,
+        quotes: C_LIKE_QUOTES,
+        multiline_quotes: &['"', '\''],
+        char_literals: false,
+        case_insensitive: false,
+    },
+];
+
+// Returns the names of the supported languages, the aliases of the languages are not included.
+pub fn supported_languages() -> Vec<&'static str> {
+    LANGUAGES.iter().map(|spec| spec.names[0]).collect()
+}
+
+// Returns the highlight spans of the code. The spans are ordered and never overlap. The code is
+// plain if the language is not supported.
+pub fn highlight_code(language: &str, code: &str) -> Vec<HighlightSpan> {
+    let language = language.trim().to_lowercase();
+    match LANGUAGES.iter().find(|spec| spec.names.contains(&language.as_str())) {
+        None => vec![],
+        Some(spec) => Tokenizer::new(spec, code).tokenize(),
+    }
+}
+
+struct Tokenizer<'a> {
+    spec: &'a LanguageSpec,
+    chars: Vec<char>,
+    // The utf16 offset of each char, and the length of the code at the end.
+    offsets: Vec<usize>,
+    spans: Vec<HighlightSpan>,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn new(spec: &'a LanguageSpec, code: &str) -> Self {
+        let chars = code.chars().collect::<Vec<char>>();
+        let mut offsets = Vec::with_capacity(chars.len() + 1);
+        let mut offset = 0;
+        for c in &chars {
+            offsets.push(offset);
+            offset += c.len_utf16();
+        }
+        offsets.push(offset);
+
+        Self {
+            spec,
+            chars,
+            offsets,
+            spans: vec![],
+        }
+    }
+
+    fn tokenize(mut self) -> Vec<HighlightSpan> {
+        let mut i = 0;
+        while i < self.chars.len() {
+            let c = self.chars[i];
+            i = if let Some(end) = self.comment_end(i) {
+                self.push_span(i, end, HighlightKind::Comment)
+            } else if self.spec.quotes.contains(&c) {
+                let end = self.string_end(i, c);
+                self.push_span(i, end, HighlightKind::StringLiteral)
+            } else if let Some(end) = self.char_literal_end(i) {
+                self.push_span(i, end, HighlightKind::StringLiteral)
+            } else if c.is_ascii_digit() {
+                let end = self.word_end(i, |c| c.is_ascii_alphanumeric() || c == '.' || c == '_');
+                self.push_span(i, end, HighlightKind::Number)
+            } else if is_ident_start(c) {
+                let end = self.word_end(i, is_ident_char);
+                let word = self.chars[i..end].iter().collect::<String>();
+                if self.is_keyword(&word) {
+                    self.push_span(i, end, HighlightKind::Keyword)
+                } else {
+                    end
+                }
+            } else {
+                i + 1
+            };
+        }
+        self.spans
+    }
+
+    fn push_span(&mut self, start: usize, end: usize, kind: HighlightKind) -> usize {
+        self.spans.push(HighlightSpan {
+            start: self.offsets[start] as i64,
+            end: self.offsets[end] as i64,
+            kind,
+        });
+        end
+    }
+
+    fn starts_with(&self, i: usize, pattern: &str) -> bool {
+        let mut pattern_chars = pattern.chars();
+        let mut index = i;
+        loop {
+            match pattern_chars.next() {
+                None => return true,
+                Some(c) => {
+                    if self.chars.get(index) != Some(&c) {
+                        return false;
+                    }
+                    index += 1;
+                }
+            }
+        }
+    }
+
+    // Returns the end of the comment that starts at i. The line comment ends before the newline,
+    // and the block comment that is not closed ends at the end of the code.
+    fn comment_end(&self, i: usize) -> Option<usize> {
+        if self.spec.line_comments.iter().any(|prefix| self.starts_with(i, prefix)) {
+            let end = (i..self.chars.len())
+                .find(|index| self.chars[*index] == '\n')
+                .unwrap_or_else(|| self.chars.len());
+            return Some(end);
+        }
+
+        let (start, end) = self.spec.block_comment?;
+        if !self.starts_with(i, start) {
+            return None;
+        }
+
+        let start_len = start.chars().count();
+        let end_len = end.chars().count();
+        let comment_end = (i + start_len..self.chars.len())
+            .find(|index| self.starts_with(*index, end))
+            .map(|index| index + end_len)
+            .unwrap_or_else(|| self.chars.len());
+        Some(comment_end)
+    }
+
+    // Returns the end of the string that starts at i. The string that is not closed ends at the
+    // end of the line, or at the end of the code if the string can span multiple lines.
+    fn string_end(&self, i: usize, quote: char) -> usize {
+        let is_multiline = self.spec.multiline_quotes.contains(&quote);
+        let mut index = i + 1;
+        while index < self.chars.len() {
+            match self.chars[index] {
+                '\\' => index += 2,
+                '\n' if !is_multiline => return index,
+                c if c == quote => return index + 1,
+                _ => index += 1,
+            }
+        }
+        self.chars.len()
+    }
+
+    // Returns the end of the char literal that starts at i, like 'a' or '\n'.
+    fn char_literal_end(&self, i: usize) -> Option<usize> {
+        if !self.spec.char_literals || self.chars[i] != '\'' {
+            return None;
+        }
+
+        match self.chars.get(i + 1) {
+            Some('\\') => (i + 3..self.chars.len().min(i + 12))
+                .find(|index| self.chars[*index] == '\'')
+                .map(|index| index + 1),
+            Some(_) if self.chars.get(i + 2) == Some(&'\'') => Some(i + 3),
+            _ => None,
+        }
+    }
+
+    fn word_end<F: Fn(char) -> bool>(&self, i: usize, f: F) -> usize {
+        (i + 1..self.chars.len())
+            .find(|index| !f(self.chars[*index]))
+            .unwrap_or_else(|| self.chars.len())
+    }
+
+    fn is_keyword(&self, word: &str) -> bool {
+        let mut keywords = self.spec.keywords.split_whitespace();
+        if self.spec.case_insensitive {
+            keywords.any(|keyword| keyword.eq_ignore_ascii_case(word))
+        } else {
+            keywords.any(|keyword| keyword == word)
+        }
+    }
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '$'
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client_document::highlight_code;
+    use flowy_core_data_model::entities::highlight::{HighlightKind, HighlightSpan};
+
+    fn spans(language: &str, code: &str) -> Vec<(String, HighlightKind)> {
+        let utf16 = code.encode_utf16().collect::<Vec<u16>>();
+        highlight_code(language, code)
+            .into_iter()
+            .map(|HighlightSpan { start, end, kind }| {
+                let text = String::from_utf16(&utf16[start as usize..end as usize]).unwrap();
+                (text, kind)
+            })
+            .collect()
+    }
+
+    fn span(text: &str, kind: HighlightKind) -> (String, HighlightKind) {
+        (text.to_owned(), kind)
+    }
+
+    #[test]
+    fn highlight_rust_test() {
+        let code = "fn main() {\n    let s = \"a\\\"b\"; // done\n    let c = 'x';\n}";
+        assert_eq!(
+            spans("rust", code),
+            vec![
+                span("fn", HighlightKind::Keyword),
+                span("let", HighlightKind::Keyword),
+                span("\"a\\\"b\"", HighlightKind::StringLiteral),
+                span("// done", HighlightKind::Comment),
+                span("let", HighlightKind::Keyword),
+                span("'x'", HighlightKind::StringLiteral),
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_rust_lifetime_test() {
+        let code = "fn f<'a>(s: &'a str) -> u8 { 10 }";
+        assert_eq!(
+            spans("rs", code),
+            vec![span("fn", HighlightKind::Keyword), span("10", HighlightKind::Number)]
+        );
+    }
+
+    #[test]
+    fn highlight_block_comment_test() {
+        let code = "/* 1\n2 */ const a = 1.5;";
+        assert_eq!(
+            spans("javascript", code),
+            vec![
+                span("/* 1\n2 */", HighlightKind::Comment),
+                span("const", HighlightKind::Keyword),
+                span("1.5", HighlightKind::Number),
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_case_insensitive_keywords_test() {
+        let code = "SELECT name FROM users -- all";
+        assert_eq!(
+            spans("SQL", code),
+            vec![
+                span("SELECT", HighlightKind::Keyword),
+                span("FROM", HighlightKind::Keyword),
+                span("-- all", HighlightKind::Comment),
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_utf16_offset_test() {
+        let code = "# 😀\nx = '😀'";
+        let spans = highlight_code("python", code);
+        assert_eq!(spans[0].end, 4);
+        assert_eq!((spans[1].start, spans[1].end), (9, 13));
+    }
+
+    #[test]
+    fn highlight_unsupported_language_test() {
+        assert!(highlight_code("brainfuck", "fn main() {}").is_empty());
+        assert!(highlight_code("", "fn main() {}").is_empty());
+    }
+}
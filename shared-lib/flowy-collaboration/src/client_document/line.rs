@@ -0,0 +1,150 @@
+use lib_ot::{
+    core::{count_utf16_code_units, OpBuilder, Operation, NEW_LINE},
+    rich_text::{RichTextAttributeKey, RichTextAttributes, RichTextDelta, RichTextOperation},
+};
+
+// A line of the document. The ops are the inserts before the newline, and the attributes are the
+// attributes of the newline, which are the block attributes of the line.
+#[derive(Debug, Clone)]
+pub struct DocumentLine {
+    pub ops: Vec<RichTextOperation>,
+    pub attributes: RichTextAttributes,
+    pub(crate) start: usize,
+    pub(crate) len: usize,
+}
+
+impl DocumentLine {
+    fn new(start: usize) -> Self {
+        Self {
+            ops: vec![],
+            attributes: RichTextAttributes::default(),
+            start,
+            len: 0,
+        }
+    }
+
+    pub fn text(&self) -> String {
+        self.ops.iter().map(|op| op.get_data()).collect()
+    }
+
+    pub(crate) fn has_newline(&self) -> bool {
+        self.len > self.ops.iter().map(|op| op.len()).sum::<usize>()
+    }
+
+    pub(crate) fn indent(&self) -> usize {
+        self.attributes
+            .get(&RichTextAttributeKey::Indent)
+            .and_then(|value| value.0.as_ref())
+            .and_then(|value| value.parse::<usize>().ok())
+            .unwrap_or(0)
+    }
+}
+
+// Splits the document into lines. The last line may not end with a newline if the document is
+// not a NewlineDoc.
+pub(crate) fn document_lines(delta: &RichTextDelta) -> Vec<DocumentLine> {
+    document_lines_between(delta, 0, usize::MAX)
+}
+
+// Returns the lines that contain any offset between the start and the end, both of them are
+// included. The lines after the end are not read.
+pub(crate) fn document_lines_between(delta: &RichTextDelta, start: usize, end: usize) -> Vec<DocumentLine> {
+    let is_included = |line: &DocumentLine| line.start <= end && line.start + line.len > start;
+    let mut lines = vec![];
+    let mut line = DocumentLine::new(0);
+    for op in delta.ops.iter().filter(|op| op.is_insert()) {
+        let attributes = op.get_attributes();
+        let segments = op.get_data().split(NEW_LINE).collect::<Vec<&str>>();
+        for (i, segment) in segments.iter().enumerate() {
+            if !segment.is_empty() {
+                line.ops
+                    .push(OpBuilder::insert(segment).attributes(attributes.clone()).build());
+                line.len += count_utf16_code_units(segment);
+            }
+
+            if i < segments.len() - 1 {
+                line.attributes = attributes.clone();
+                line.len += 1;
+                if line.start > end {
+                    return lines;
+                }
+
+                let next_line = DocumentLine::new(line.start + line.len);
+                let line = std::mem::replace(&mut line, next_line);
+                if is_included(&line) {
+                    lines.push(line);
+                }
+            }
+        }
+    }
+
+    if line.len > 0 && is_included(&line) {
+        lines.push(line);
+    }
+    lines
+}
+
+// The lines touched by the change, they are read from the document composed with the change. The
+// caches of the lines, e.g. the outline, keep the items before the start and move the items at or
+// after the old_end, which is the offset before the change, by the len_diff.
+pub(crate) struct ChangedLines {
+    pub(crate) lines: Vec<DocumentLine>,
+    pub(crate) start: usize,
+    pub(crate) old_end: i64,
+    pub(crate) len_diff: i64,
+}
+
+pub(crate) fn changed_lines(document: &RichTextDelta, change: &RichTextDelta) -> Option<ChangedLines> {
+    let ChangedRange { start, end, len_diff } = changed_range(change)?;
+
+    // The lines that contain the changed range are read again from the document. The texts
+    // before the start are not changed, and the texts after the end are only moved.
+    let new_end = (end as i64 + len_diff) as usize;
+    let lines = document_lines_between(document, start, new_end);
+    let region_start = lines.first().map_or(start, |line| line.start);
+    let region_end = lines.last().map_or(new_end, |line| line.start + line.len) as i64;
+    Some(ChangedLines {
+        lines,
+        start: region_start,
+        old_end: region_end - len_diff,
+        len_diff,
+    })
+}
+
+struct ChangedRange {
+    start: usize,
+    end: usize,
+    len_diff: i64,
+}
+
+// Returns the range of the document that is touched by the change, the start and the end are
+// the offsets in the document before the change.
+fn changed_range(change: &RichTextDelta) -> Option<ChangedRange> {
+    let mut range: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    let mut len_diff = 0;
+    for op in &change.ops {
+        let (touched_len, consumed_len) = match op {
+            Operation::Retain(retain) if retain.is_plain() => {
+                offset += retain.n;
+                continue;
+            }
+            Operation::Retain(retain) => (retain.n, retain.n),
+            Operation::Delete(n) => {
+                len_diff -= *n as i64;
+                (*n, *n)
+            }
+            Operation::Insert(insert) => {
+                len_diff += insert.utf16_size() as i64;
+                (0, 0)
+            }
+        };
+
+        let (start, end) = range.unwrap_or((offset, offset));
+        range = Some((start.min(offset), end.max(offset + touched_len)));
+        offset += consumed_len;
+    }
+
+    let (start, end) = range?;
+    Some(ChangedRange { start, end, len_diff })
+}
@@ -1,12 +1,28 @@
 #![allow(clippy::module_inception)]
 
+pub use block::{BlockCommand, BlockType};
+pub use comment::comment_ids;
 pub use document_pad::*;
 pub(crate) use extensions::*;
+pub use highlight::*;
+pub use line::*;
+pub use table::*;
+pub use toggle::ToggleBlock;
 pub use view::*;
 
+mod block;
+mod clipboard;
+mod comment;
 mod data;
 pub mod default;
 mod document_pad;
+mod export;
 mod extensions;
+mod highlight;
 pub mod history;
+mod line;
+mod outline;
+mod statistics;
+mod table;
+mod toggle;
 mod view;
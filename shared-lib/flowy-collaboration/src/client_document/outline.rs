@@ -0,0 +1,161 @@
+use crate::{
+    client_document::{changed_lines, document_lines_between, ChangedLines, DocumentLine},
+    entities::outline::{DocumentSection, OutlineItem},
+};
+use lib_ot::{
+    core::count_utf16_code_units,
+    rich_text::{RichTextAttributeKey, RichTextDelta},
+};
+
+// The outline is the list of the headers of the document. It's updated with each delta composed
+// into the document, only the lines touched by the delta are read again.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DocumentOutline {
+    items: Vec<OutlineItem>,
+}
+
+impl DocumentOutline {
+    pub(crate) fn from_delta(delta: &RichTextDelta) -> Self {
+        let items = document_lines_between(delta, 0, usize::MAX)
+            .iter()
+            .filter_map(outline_item)
+            .collect();
+        Self { items }
+    }
+
+    pub(crate) fn items(&self) -> &[OutlineItem] {
+        &self.items
+    }
+
+    // The document is the delta after the change was composed.
+    pub(crate) fn apply_change(&mut self, document: &RichTextDelta, change: &RichTextDelta) {
+        let ChangedLines {
+            lines,
+            start,
+            old_end,
+            len_diff,
+        } = match changed_lines(document, change) {
+            None => return,
+            Some(changed_lines) => changed_lines,
+        };
+
+        let mut items = vec![];
+        let mut moved_items = vec![];
+        for mut item in self.items.drain(..) {
+            if item.start < start as i64 {
+                items.push(item);
+            } else if item.start >= old_end {
+                item.start += len_diff;
+                item.end += len_diff;
+                moved_items.push(item);
+            }
+        }
+        items.extend(lines.iter().filter_map(outline_item));
+        items.extend(moved_items);
+        self.items = items;
+    }
+
+    pub(crate) fn section(&self, index: usize, document_len: usize) -> Option<DocumentSection> {
+        let item = self.items.get(index)?;
+        let end = self.items[index + 1..]
+            .iter()
+            .find(|next| next.level <= item.level)
+            .map_or(document_len as i64, |next| next.start);
+        Some(DocumentSection { start: item.start, end })
+    }
+}
+
+fn outline_item(line: &DocumentLine) -> Option<OutlineItem> {
+    let level = line
+        .attributes
+        .get(&RichTextAttributeKey::Header)?
+        .0
+        .as_ref()?
+        .parse::<i64>()
+        .ok()?;
+    let text = line.text();
+    let start = line.start as i64;
+    let end = start + count_utf16_code_units(&text) as i64;
+    Some(OutlineItem {
+        level,
+        text,
+        start,
+        end,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client_document::{ClientDocument, NewlineDoc};
+    use lib_ot::{core::Interval, rich_text::RichTextAttribute};
+
+    fn outline(document: &ClientDocument) -> Vec<(i64, String, i64, i64)> {
+        document
+            .outline()
+            .iter()
+            .map(|item| (item.level, item.text.clone(), item.start, item.end))
+            .collect()
+    }
+
+    fn assert_outline(document: &ClientDocument) {
+        let expected = ClientDocument::from_delta(document.delta().clone());
+        assert_eq!(outline(document), outline(&expected));
+    }
+
+    #[test]
+    fn outline_update_test() {
+        let mut document = ClientDocument::new::<NewlineDoc>();
+        document.insert(0, "title\nbody\nsub\nend").unwrap();
+        document
+            .format(Interval::new(0, 5), RichTextAttribute::Header(1))
+            .unwrap();
+        document
+            .format(Interval::new(11, 14), RichTextAttribute::Header(2))
+            .unwrap();
+        assert_eq!(
+            outline(&document),
+            vec![(1, "title".to_owned(), 0, 5), (2, "sub".to_owned(), 11, 14)]
+        );
+
+        document.insert(7, "😀").unwrap();
+        assert_eq!(
+            outline(&document),
+            vec![(1, "title".to_owned(), 0, 5), (2, "sub".to_owned(), 13, 16)]
+        );
+
+        document.insert(0, "a ").unwrap();
+        assert_outline(&document);
+        assert_eq!(outline(&document)[0], (1, "a title".to_owned(), 0, 7));
+
+        document.delete(Interval::new(6, 15)).unwrap();
+        assert_outline(&document);
+
+        document.undo().unwrap();
+        assert_outline(&document);
+        document.redo().unwrap();
+        assert_outline(&document);
+    }
+
+    #[test]
+    fn outline_section_test() {
+        let mut document = ClientDocument::new::<NewlineDoc>();
+        document.insert(0, "a\nb\nc\nd").unwrap();
+        document
+            .format(Interval::new(0, 1), RichTextAttribute::Header(1))
+            .unwrap();
+        document
+            .format(Interval::new(2, 3), RichTextAttribute::Header(2))
+            .unwrap();
+        document
+            .format(Interval::new(4, 5), RichTextAttribute::Header(1))
+            .unwrap();
+
+        let section = document.outline_section(0).unwrap();
+        assert_eq!((section.start, section.end), (0, 4));
+        let section = document.outline_section(1).unwrap();
+        assert_eq!((section.start, section.end), (2, 4));
+        let section = document.outline_section(2).unwrap();
+        assert_eq!((section.start, section.end), (4, 8));
+        assert!(document.outline_section(3).is_none());
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{
+    client_document::{changed_lines, document_lines_between, ChangedLines, DocumentLine},
+    entities::statistics::DocumentStatistics,
+};
+use lib_ot::{
+    core::Interval,
+    rich_text::{RichTextAttributeKey, RichTextDelta},
+};
+
+const WORDS_PER_MINUTE: i64 = 200;
+
+// The counts of each line are cached, and only the counts of the lines touched by the composed
+// delta are computed again, so the statistics don't read the whole document after each edit.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct StatisticsCache {
+    lines: Vec<LineCounts>,
+}
+
+impl StatisticsCache {
+    pub(crate) fn from_delta(delta: &RichTextDelta) -> Self {
+        let lines = document_lines_between(delta, 0, usize::MAX)
+            .iter()
+            .map(LineCounts::from_line)
+            .collect();
+        Self { lines }
+    }
+
+    // The document is the delta after the change was composed.
+    pub(crate) fn apply_change(&mut self, document: &RichTextDelta, change: &RichTextDelta) {
+        let ChangedLines {
+            lines,
+            start,
+            old_end,
+            len_diff,
+        } = match changed_lines(document, change) {
+            None => return,
+            Some(changed_lines) => changed_lines,
+        };
+
+        let mut counts = vec![];
+        let mut moved_counts = vec![];
+        for mut line_counts in self.lines.drain(..) {
+            if line_counts.start < start {
+                counts.push(line_counts);
+            } else if line_counts.start as i64 >= old_end {
+                line_counts.start = (line_counts.start as i64 + len_diff) as usize;
+                moved_counts.push(line_counts);
+            }
+        }
+        counts.extend(lines.iter().map(LineCounts::from_line));
+        counts.extend(moved_counts);
+        self.lines = counts;
+    }
+
+    pub(crate) fn statistics(&self) -> DocumentStatistics {
+        sum_counts(self.lines.iter())
+    }
+}
+
+// Returns the statistics of the text in the interval.
+pub(crate) fn selection_statistics(delta: &RichTextDelta, interval: Interval) -> DocumentStatistics {
+    let counts = document_lines_between(delta, interval.start, interval.end)
+        .iter()
+        .map(|line| {
+            let text = line.text().encode_utf16().collect::<Vec<u16>>();
+            let start = interval.start.saturating_sub(line.start).min(text.len());
+            let end = interval.end.saturating_sub(line.start).min(text.len()).max(start);
+            let text = String::from_utf16_lossy(&text[start..end]);
+            LineCounts::new(line.start + start, &text, is_header(line))
+        })
+        .collect::<Vec<LineCounts>>();
+    sum_counts(counts.iter())
+}
+
+#[derive(Debug, Clone)]
+struct LineCounts {
+    start: usize,
+    words: i64,
+    characters: i64,
+    characters_without_spaces: i64,
+    is_header: bool,
+}
+
+impl LineCounts {
+    fn new(start: usize, text: &str, is_header: bool) -> Self {
+        Self {
+            start,
+            words: count_words(text),
+            characters: text.chars().count() as i64,
+            characters_without_spaces: text.chars().filter(|c| !c.is_whitespace()).count() as i64,
+            is_header,
+        }
+    }
+
+    fn from_line(line: &DocumentLine) -> Self {
+        Self::new(line.start, &line.text(), is_header(line))
+    }
+
+    fn is_paragraph(&self) -> bool {
+        self.characters_without_spaces > 0
+    }
+}
+
+fn sum_counts<'a>(counts: impl Iterator<Item = &'a LineCounts>) -> DocumentStatistics {
+    let mut statistics = DocumentStatistics::default();
+    for line_counts in counts {
+        statistics.words += line_counts.words;
+        statistics.characters += line_counts.characters;
+        statistics.characters_without_spaces += line_counts.characters_without_spaces;
+        if line_counts.is_paragraph() {
+            statistics.paragraphs += 1;
+            if line_counts.is_header {
+                statistics.headers += 1;
+            }
+        }
+    }
+    statistics.reading_time_in_seconds = (statistics.words * 60 + WORDS_PER_MINUTE - 1) / WORDS_PER_MINUTE;
+    statistics
+}
+
+fn is_header(line: &DocumentLine) -> bool {
+    line.attributes.contains_key(&RichTextAttributeKey::Header)
+}
+
+// The Chinese and the Japanese characters are counted as one word each, because their words are
+// not separated by spaces. The other words are the runs of the alphanumeric characters, and the
+// apostrophes and the hyphens between the characters are part of the word, e.g. "don't".
+fn count_words(text: &str) -> i64 {
+    let mut words = 0;
+    let mut in_word = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if is_cjk(c) {
+            words += 1;
+            in_word = false;
+        } else if c.is_alphanumeric() {
+            if !in_word {
+                words += 1;
+                in_word = true;
+            }
+        } else if in_word && is_word_joiner(c) {
+            in_word = chars
+                .peek()
+                .map_or(false, |next| next.is_alphanumeric() && !is_cjk(*next));
+        } else {
+            in_word = false;
+        }
+    }
+    words
+}
+
+fn is_word_joiner(c: char) -> bool {
+    matches!(c, '\'' | '\u{2019}' | '-')
+}
+
+// The Hangul is not included, the Korean words are separated by spaces.
+fn is_cjk(c: char) -> bool {
+    matches!(c as u32,
+        0x3040..=0x30FF // Hiragana and Katakana
+        | 0x3400..=0x4DBF // CJK Unified Ideographs Extension A
+        | 0x4E00..=0x9FFF // CJK Unified Ideographs
+        | 0xF900..=0xFAFF // CJK Compatibility Ideographs
+        | 0x20000..=0x2FA1F // CJK Unified Ideographs Extension B to F and the supplement
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::count_words;
+    use crate::client_document::{ClientDocument, NewlineDoc};
+    use lib_ot::{core::Interval, rich_text::RichTextAttribute};
+
+    #[test]
+    fn count_words_test() {
+        assert_eq!(count_words(""), 0);
+        assert_eq!(count_words("Hello, world!"), 2);
+        assert_eq!(count_words("don't stop - well-known"), 3);
+        assert_eq!(count_words("héllo wörld 123"), 3);
+        assert_eq!(count_words("我爱你"), 3);
+        assert_eq!(count_words("AppFlowy是开源的"), 5);
+        assert_eq!(count_words("ひらがな カタカナ"), 8);
+        assert_eq!(count_words("안녕 하세요"), 2);
+    }
+
+    #[test]
+    fn statistics_update_test() {
+        let mut document = ClientDocument::new::<NewlineDoc>();
+        document.insert(0, "Title\nOne two three\n\nfour").unwrap();
+        document
+            .format(Interval::new(0, 5), RichTextAttribute::Header(1))
+            .unwrap();
+        let statistics = document.statistics();
+        assert_eq!(statistics.words, 5);
+        assert_eq!(statistics.characters, 22);
+        assert_eq!(statistics.characters_without_spaces, 20);
+        assert_eq!(statistics.paragraphs, 3);
+        assert_eq!(statistics.headers, 1);
+        assert_eq!(statistics.reading_time_in_seconds, 2);
+
+        document.insert(10, "😀 ").unwrap();
+        document.delete(Interval::new(0, 3)).unwrap();
+        document.undo().unwrap();
+        document.redo().unwrap();
+        let expected = ClientDocument::from_delta(document.delta().clone()).statistics();
+        assert_eq!(document.statistics(), expected);
+        assert_eq!(expected.words, 5);
+    }
+
+    #[test]
+    fn selection_statistics_test() {
+        let mut document = ClientDocument::new::<NewlineDoc>();
+        document.insert(0, "one two\nthree four").unwrap();
+        let statistics = document.selection_statistics(Interval::new(4, 13)).unwrap();
+        assert_eq!(statistics.words, 2);
+        assert_eq!(statistics.characters, 8);
+        assert_eq!(statistics.paragraphs, 2);
+    }
+}
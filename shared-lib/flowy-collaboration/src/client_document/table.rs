@@ -0,0 +1,350 @@
+use crate::{
+    client_document::line::{document_lines, DocumentLine},
+    errors::{CollaborateError, CollaborateResult},
+};
+use lib_infra::uuid_string;
+use lib_ot::{
+    core::{DeltaBuilder, NEW_LINE},
+    rich_text::{RichTextAttribute, RichTextAttributeKey, RichTextAttributes, RichTextDelta},
+};
+
+// A table is stored as the consecutive lines that have the table_cell attribute. Its value is
+// "{table_id}:{row_id}:{column_id}", and the cells are laid out row by row. A cell can have more
+// than one line, the lines of the cell share the same value.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TableCellRef {
+    pub table_id: String,
+    pub row_id: String,
+    pub column_id: String,
+}
+
+impl TableCellRef {
+    pub fn parse(s: &str) -> Option<Self> {
+        let ids = s.split(':').collect::<Vec<&str>>();
+        match ids.as_slice() {
+            [table_id, row_id, column_id] if !table_id.is_empty() && !row_id.is_empty() && !column_id.is_empty() => {
+                Some(Self {
+                    table_id: table_id.to_string(),
+                    row_id: row_id.to_string(),
+                    column_id: column_id.to_string(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    pub fn from_attributes(attributes: &RichTextAttributes) -> Option<Self> {
+        let value = attributes.get(&RichTextAttributeKey::TableCell)?;
+        value.0.as_ref().and_then(|s| Self::parse(s))
+    }
+
+    pub fn to_attribute(&self) -> RichTextAttribute {
+        RichTextAttribute::TableCell(format!("{}:{}:{}", self.table_id, self.row_id, self.column_id))
+    }
+
+    fn cell_attributes(table_id: &str, row_id: &str, column_id: &str) -> RichTextAttributes {
+        let cell = Self {
+            table_id: table_id.to_owned(),
+            row_id: row_id.to_owned(),
+            column_id: column_id.to_owned(),
+        };
+        cell.to_attribute().into()
+    }
+}
+
+// The structural changes of the tables. Each command is applied as a single delta, so it's undone
+// or redone as a whole.
+#[derive(Debug, Clone)]
+pub enum TableCommand {
+    // Inserts a table with empty cells after the line that contains the index.
+    InsertTable { index: usize, rows: usize, columns: usize },
+    // Inserts an empty row before the row at the row_index. The row is appended if the row_index is
+    // equal to the row count.
+    InsertRow { table_id: String, row_index: usize },
+    DeleteRow { table_id: String, row_index: usize },
+    // Inserts an empty column before the column at the column_index. The column is appended if the
+    // column_index is equal to the column count.
+    InsertColumn { table_id: String, column_index: usize },
+    DeleteColumn { table_id: String, column_index: usize },
+    DeleteTable { table_id: String },
+}
+
+#[derive(Debug, Clone)]
+pub struct DocumentTable {
+    pub id: String,
+    // The column ids in the order of their first appearance.
+    pub columns: Vec<String>,
+    pub rows: Vec<TableRow>,
+    start: usize,
+    len: usize,
+}
+
+impl DocumentTable {
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn column_count(&self) -> usize {
+        self.columns.len()
+    }
+
+    // Returns the texts of the cells row by row. The missing cell is treated as an empty cell.
+    pub fn texts(&self) -> Vec<Vec<String>> {
+        self.rows
+            .iter()
+            .map(|row| {
+                self.columns
+                    .iter()
+                    .map(|column_id| row.cell(column_id).map(|cell| cell.text()).unwrap_or_default())
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TableRow {
+    pub id: String,
+    pub cells: Vec<TableCell>,
+    start: usize,
+    len: usize,
+}
+
+impl TableRow {
+    pub fn cell(&self, column_id: &str) -> Option<&TableCell> {
+        self.cells.iter().find(|cell| cell.column_id == column_id)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TableCell {
+    pub column_id: String,
+    pub lines: Vec<DocumentLine>,
+    start: usize,
+    len: usize,
+}
+
+impl TableCell {
+    pub fn text(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| line.text())
+            .collect::<Vec<String>>()
+            .join(NEW_LINE)
+    }
+}
+
+pub(crate) enum DocumentBlock {
+    Line(DocumentLine),
+    Table(DocumentTable),
+}
+
+pub(crate) fn document_blocks(delta: &RichTextDelta) -> Vec<DocumentBlock> {
+    let mut blocks = vec![];
+    for line in document_lines(delta) {
+        let cell_ref = match TableCellRef::from_attributes(&line.attributes) {
+            None => {
+                blocks.push(DocumentBlock::Line(line));
+                continue;
+            }
+            Some(cell_ref) => cell_ref,
+        };
+
+        let is_same_table = matches!(blocks.last(), Some(DocumentBlock::Table(table)) if table.id == cell_ref.table_id);
+        if !is_same_table {
+            blocks.push(DocumentBlock::Table(DocumentTable {
+                id: cell_ref.table_id.clone(),
+                columns: vec![],
+                rows: vec![],
+                start: line.start,
+                len: 0,
+            }));
+        }
+        if let Some(DocumentBlock::Table(table)) = blocks.last_mut() {
+            push_table_line(table, cell_ref, line);
+        }
+    }
+    blocks
+}
+
+pub(crate) fn document_tables(delta: &RichTextDelta) -> Vec<DocumentTable> {
+    document_blocks(delta)
+        .into_iter()
+        .filter_map(|block| match block {
+            DocumentBlock::Table(table) => Some(table),
+            DocumentBlock::Line(_) => None,
+        })
+        .collect()
+}
+
+fn push_table_line(table: &mut DocumentTable, cell_ref: TableCellRef, line: DocumentLine) {
+    if !table.columns.contains(&cell_ref.column_id) {
+        table.columns.push(cell_ref.column_id.clone());
+    }
+    table.len += line.len;
+
+    if table.rows.last().map_or(true, |row| row.id != cell_ref.row_id) {
+        table.rows.push(TableRow {
+            id: cell_ref.row_id.clone(),
+            cells: vec![],
+            start: line.start,
+            len: 0,
+        });
+    }
+    let row = table.rows.last_mut().unwrap();
+    row.len += line.len;
+
+    if row
+        .cells
+        .last()
+        .map_or(true, |cell| cell.column_id != cell_ref.column_id)
+    {
+        row.cells.push(TableCell {
+            column_id: cell_ref.column_id,
+            lines: vec![],
+            start: line.start,
+            len: 0,
+        });
+    }
+    let cell = row.cells.last_mut().unwrap();
+    cell.len += line.len;
+    cell.lines.push(line);
+}
+
+pub(crate) fn table_command_delta(delta: &RichTextDelta, command: TableCommand) -> CollaborateResult<RichTextDelta> {
+    match command {
+        TableCommand::InsertTable { index, rows, columns } => insert_table_delta(delta, index, rows, columns),
+        TableCommand::InsertRow { table_id, row_index } => insert_row_delta(&find_table(delta, &table_id)?, row_index),
+        TableCommand::DeleteRow { table_id, row_index } => delete_row_delta(&find_table(delta, &table_id)?, row_index),
+        TableCommand::InsertColumn { table_id, column_index } => {
+            insert_column_delta(&find_table(delta, &table_id)?, column_index)
+        }
+        TableCommand::DeleteColumn { table_id, column_index } => {
+            delete_column_delta(&find_table(delta, &table_id)?, column_index)
+        }
+        TableCommand::DeleteTable { table_id } => Ok(delete_table_delta(&find_table(delta, &table_id)?)),
+    }
+}
+
+fn find_table(delta: &RichTextDelta, table_id: &str) -> CollaborateResult<DocumentTable> {
+    document_tables(delta)
+        .into_iter()
+        .find(|table| table.id == table_id)
+        .ok_or_else(|| CollaborateError::record_not_found().context(format!("Can't find the table: {}", table_id)))
+}
+
+fn insert_table_delta(
+    delta: &RichTextDelta,
+    index: usize,
+    rows: usize,
+    columns: usize,
+) -> CollaborateResult<RichTextDelta> {
+    if rows == 0 || columns == 0 {
+        return Err(CollaborateError::out_of_bound().context("The table should have at least one cell"));
+    }
+
+    // A newline is inserted to end the last line if it doesn't end with a newline, and a plain line
+    // is appended if the table is at the end of the document, so the text can be typed after it.
+    let lines = document_lines(delta);
+    let (retain, need_newline) = match lines.iter().find(|line| index < line.start + line.len) {
+        Some(line) => (line.start + line.len, !line.has_newline()),
+        None => (
+            delta.utf16_target_len,
+            lines.last().map_or(false, |line| !line.has_newline()),
+        ),
+    };
+
+    let table_id = uuid_string();
+    let column_ids = (0..columns).map(|_| uuid_string()).collect::<Vec<String>>();
+    let mut builder = DeltaBuilder::new().retain(retain);
+    if need_newline {
+        builder = builder.insert(NEW_LINE);
+    }
+    for _ in 0..rows {
+        let row_id = uuid_string();
+        for column_id in &column_ids {
+            let attributes = TableCellRef::cell_attributes(&table_id, &row_id, column_id);
+            builder = builder.insert_with_attributes(NEW_LINE, attributes);
+        }
+    }
+    if retain == delta.utf16_target_len {
+        builder = builder.insert(NEW_LINE);
+    }
+    Ok(builder.build())
+}
+
+fn insert_row_delta(table: &DocumentTable, row_index: usize) -> CollaborateResult<RichTextDelta> {
+    if row_index > table.rows.len() {
+        return Err(CollaborateError::out_of_bound());
+    }
+
+    let position = match table.rows.get(row_index) {
+        None => table.start + table.len,
+        Some(row) => row.start,
+    };
+    let row_id = uuid_string();
+    let mut builder = DeltaBuilder::new().retain(position);
+    for column_id in &table.columns {
+        let attributes = TableCellRef::cell_attributes(&table.id, &row_id, column_id);
+        builder = builder.insert_with_attributes(NEW_LINE, attributes);
+    }
+    Ok(builder.build())
+}
+
+fn delete_row_delta(table: &DocumentTable, row_index: usize) -> CollaborateResult<RichTextDelta> {
+    let row = table.rows.get(row_index).ok_or_else(CollaborateError::out_of_bound)?;
+    if table.rows.len() == 1 {
+        return Ok(delete_table_delta(table));
+    }
+    Ok(DeltaBuilder::new().retain(row.start).delete(row.len).build())
+}
+
+// Inserts an empty cell into every row, before the cell of the following columns.
+fn insert_column_delta(table: &DocumentTable, column_index: usize) -> CollaborateResult<RichTextDelta> {
+    if column_index > table.columns.len() {
+        return Err(CollaborateError::out_of_bound());
+    }
+
+    let column_id = uuid_string();
+    let following_columns = &table.columns[column_index..];
+    let mut builder = DeltaBuilder::new();
+    let mut offset = 0;
+    for row in &table.rows {
+        let position = row
+            .cells
+            .iter()
+            .find(|cell| following_columns.contains(&cell.column_id))
+            .map(|cell| cell.start)
+            .unwrap_or(row.start + row.len);
+        let attributes = TableCellRef::cell_attributes(&table.id, &row.id, &column_id);
+        builder = builder
+            .retain(position - offset)
+            .insert_with_attributes(NEW_LINE, attributes);
+        offset = position;
+    }
+    Ok(builder.build())
+}
+
+fn delete_column_delta(table: &DocumentTable, column_index: usize) -> CollaborateResult<RichTextDelta> {
+    let column_id = table
+        .columns
+        .get(column_index)
+        .ok_or_else(CollaborateError::out_of_bound)?;
+    if table.columns.len() == 1 {
+        return Ok(delete_table_delta(table));
+    }
+
+    let mut builder = DeltaBuilder::new();
+    let mut offset = 0;
+    for row in &table.rows {
+        for cell in row.cells.iter().filter(|cell| &cell.column_id == column_id) {
+            builder = builder.retain(cell.start - offset).delete(cell.len);
+            offset = cell.start + cell.len;
+        }
+    }
+    Ok(builder.build())
+}
+
+fn delete_table_delta(table: &DocumentTable) -> RichTextDelta {
+    DeltaBuilder::new().retain(table.start).delete(table.len).build()
+}
@@ -0,0 +1,86 @@
+use crate::client_document::document_lines;
+use lib_ot::{
+    core::{count_utf16_code_units, Interval},
+    rich_text::{RichTextAttributeKey, RichTextDelta, TOGGLE_COLLAPSED},
+};
+
+// The toggle line and the lines that belong to it. The children are the lines after the toggle
+// whose indent is greater than the indent of the toggle, the interval of the children includes
+// their newlines. The interval of the children is empty if the toggle has no children.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ToggleBlock {
+    pub interval: Interval,
+    pub children: Interval,
+    pub collapsed: bool,
+}
+
+pub(crate) fn toggle_blocks(delta: &RichTextDelta) -> Vec<ToggleBlock> {
+    let lines = document_lines(delta);
+    let mut blocks = vec![];
+    for (i, line) in lines.iter().enumerate() {
+        let value = match line.attributes.get(&RichTextAttributeKey::Toggle) {
+            None => continue,
+            Some(value) => value.0.as_deref(),
+        };
+
+        let text_end = line.start + count_utf16_code_units(&line.text());
+        let children_start = line.start + line.len;
+        let children_end = lines[i + 1..]
+            .iter()
+            .take_while(|child| child.indent() > line.indent())
+            .last()
+            .map_or(children_start, |child| child.start + child.len);
+        blocks.push(ToggleBlock {
+            interval: Interval::new(line.start, text_end),
+            children: Interval::new(children_start, children_end),
+            collapsed: value == Some(TOGGLE_COLLAPSED),
+        });
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client_document::{ClientDocument, NewlineDoc};
+    use lib_ot::{core::Interval, rich_text::RichTextAttribute};
+
+    #[test]
+    fn toggle_children_by_indent_test() {
+        let mut document = ClientDocument::new::<NewlineDoc>();
+        document.insert(0, "t\na\nb\nc").unwrap();
+        document
+            .format(Interval::new(0, 1), RichTextAttribute::Toggle(false))
+            .unwrap();
+        document
+            .format(Interval::new(2, 5), RichTextAttribute::Indent(1))
+            .unwrap();
+
+        let blocks = document.toggle_blocks();
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].interval, Interval::new(0, 1));
+        assert_eq!(blocks[0].children, Interval::new(2, 6));
+        assert!(!blocks[0].collapsed);
+
+        document
+            .format(Interval::new(0, 1), RichTextAttribute::Toggle(true))
+            .unwrap();
+        document
+            .format(Interval::new(4, 5), RichTextAttribute::Indent(0))
+            .unwrap();
+        let blocks = document.toggle_blocks();
+        assert_eq!(blocks[0].children, Interval::new(2, 4));
+        assert!(blocks[0].collapsed);
+    }
+
+    #[test]
+    fn toggle_without_children_test() {
+        let mut document = ClientDocument::new::<NewlineDoc>();
+        document.insert(0, "t\na").unwrap();
+        document
+            .format(Interval::new(0, 1), RichTextAttribute::Toggle(false))
+            .unwrap();
+
+        let blocks = document.toggle_blocks();
+        assert!(blocks[0].children.is_empty());
+    }
+}
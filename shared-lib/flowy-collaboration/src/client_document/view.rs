@@ -88,6 +88,8 @@ fn construct_insert_exts() -> Vec<InsertExtension> {
     vec![
         Box::new(InsertEmbedsExt {}),
         Box::new(ForceNewlineForInsertsAroundEmbedExt {}),
+        Box::new(PreserveTableCellOnInsert {}),
+        Box::new(AutoFormatMath {}),
         Box::new(AutoExitBlock {}),
         Box::new(PreserveBlockFormatOnInsert {}),
         Box::new(PreserveLineFormatOnSplit {}),
@@ -101,11 +103,16 @@ fn construct_insert_exts() -> Vec<InsertExtension> {
 fn construct_format_exts() -> Vec<FormatExtension> {
     vec![
         // Box::new(FormatLinkAtCaretPositionExt {}),
+        Box::new(ResolveTableFormat {}),
         Box::new(ResolveBlockFormat {}),
         Box::new(ResolveInlineFormat {}),
     ]
 }
 
 fn construct_delete_exts() -> Vec<DeleteExtension> {
-    vec![Box::new(PreserveLineFormatOnMerge {}), Box::new(DefaultDelete {})]
+    vec![
+        Box::new(PreserveTableCellOnDelete {}),
+        Box::new(PreserveLineFormatOnMerge {}),
+        Box::new(DefaultDelete {}),
+    ]
 }
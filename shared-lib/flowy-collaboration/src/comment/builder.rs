@@ -0,0 +1,29 @@
+use crate::{
+    comment::{comment_pad::line_to_record, CommentPad},
+    entities::{comment_info::CommentDelta, revision::Revision},
+    errors::CollaborateResult,
+};
+use lib_ot::core::OperationTransformable;
+
+pub(crate) struct CommentPadBuilder();
+
+impl CommentPadBuilder {
+    pub(crate) fn build_with_delta(delta: CommentDelta) -> CollaborateResult<CommentPad> {
+        let comment_text = delta.apply("")?;
+        let records = comment_text.split_inclusive('\n').map(line_to_record).collect();
+        Ok(CommentPad { records, root: delta })
+    }
+
+    pub(crate) fn build_with_revisions(revisions: Vec<Revision>) -> CollaborateResult<CommentPad> {
+        let mut comment_delta = CommentDelta::new();
+        for revision in revisions {
+            if revision.delta_data.is_empty() {
+                tracing::warn!("revision delta_data is empty");
+            }
+
+            let delta = CommentDelta::from_bytes(revision.delta_data)?;
+            comment_delta = comment_delta.compose(&delta)?;
+        }
+        CommentPadBuilder::build_with_delta(comment_delta)
+    }
+}
@@ -0,0 +1,406 @@
+use crate::{
+    comment::builder::CommentPadBuilder,
+    entities::{
+        comment_info::CommentDelta,
+        revision::{md5, Revision},
+    },
+    errors::{CollaborateError, CollaborateResult},
+};
+use flowy_core_data_model::entities::comment::{Comment, CommentThread};
+use lib_ot::core::{FlowyStr, OperationTransformable, PlainDeltaBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+// The comment threads of a document are stored as lines of json like the board, each line is a
+// thread or a reply. The replies are separate lines, so the concurrent replies to the same thread
+// never touch the same line. If the same thread is changed concurrently, the last line of the
+// thread wins until the thread is changed again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommentPad {
+    pub(crate) records: Vec<Option<CommentRecord>>,
+    pub(crate) root: CommentDelta,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum CommentRecord {
+    Thread(ThreadRecord),
+    Reply(Comment),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ThreadRecord {
+    pub(crate) id: String,
+    pub(crate) author: String,
+    pub(crate) content: String,
+    pub(crate) create_time: i64,
+    #[serde(default)]
+    pub(crate) resolved: bool,
+    #[serde(default)]
+    pub(crate) orphaned: bool,
+}
+
+pub fn comments_object_id(doc_id: &str) -> String {
+    format!("comments-{}", doc_id)
+}
+
+impl std::default::Default for CommentPad {
+    fn default() -> Self {
+        CommentPadBuilder::build_with_delta(CommentDelta::new()).unwrap()
+    }
+}
+
+pub struct CommentChange {
+    pub delta: CommentDelta,
+    /// md5: the md5 of the CommentPad's delta after applying the change.
+    pub md5: String,
+}
+
+impl CommentPad {
+    pub fn from_revisions(revisions: Vec<Revision>) -> CollaborateResult<Self> {
+        CommentPadBuilder::build_with_revisions(revisions)
+    }
+
+    pub fn from_delta(delta: CommentDelta) -> CollaborateResult<Self> {
+        CommentPadBuilder::build_with_delta(delta)
+    }
+
+    pub fn delta(&self) -> &CommentDelta {
+        &self.root
+    }
+
+    pub fn md5(&self) -> String {
+        md5(&self.root.to_bytes())
+    }
+
+    pub fn reset_comments(&mut self, delta: CommentDelta) -> CollaborateResult<String> {
+        let pad = CommentPad::from_delta(delta)?;
+        self.records = pad.records;
+        self.root = pad.root;
+        Ok(self.md5())
+    }
+
+    pub fn compose_remote_delta(&mut self, delta: CommentDelta) -> CollaborateResult<String> {
+        let composed_delta = self.root.compose(&delta)?;
+        self.reset_comments(composed_delta)
+    }
+
+    // Returns the threads ordered by their create time. The anchors of the threads are left empty,
+    // they are read from the document.
+    pub fn threads(&self, doc_id: &str) -> Vec<CommentThread> {
+        let mut replies: HashMap<String, Vec<Comment>> = HashMap::new();
+        for comment in self.reply_records().into_values() {
+            replies.entry(comment.thread_id.clone()).or_default().push(comment);
+        }
+
+        let mut threads = self
+            .thread_records()
+            .into_values()
+            .map(|record| {
+                let mut thread_replies = replies.remove(&record.id).unwrap_or_default();
+                thread_replies.sort_by(|a, b| a.create_time.cmp(&b.create_time).then_with(|| a.id.cmp(&b.id)));
+                make_thread(doc_id, record, thread_replies)
+            })
+            .collect::<Vec<CommentThread>>();
+        threads.sort_by(|a, b| a.create_time.cmp(&b.create_time).then_with(|| a.id.cmp(&b.id)));
+        threads
+    }
+
+    pub fn read_thread(&self, doc_id: &str, thread_id: &str) -> Option<CommentThread> {
+        self.threads(doc_id).into_iter().find(|thread| thread.id == thread_id)
+    }
+
+    // Only the id, the author, the content and the create time of the thread are saved. The replies
+    // are added by the reply.
+    pub fn create_thread(&mut self, thread: &CommentThread) -> CollaborateResult<Option<CommentChange>> {
+        if self.thread_record(&thread.id).is_some() {
+            tracing::warn!("[CommentPad]: Duplicate thread");
+            return Ok(None);
+        }
+
+        let record = ThreadRecord {
+            id: thread.id.clone(),
+            author: thread.author.clone(),
+            content: thread.content.clone(),
+            create_time: thread.create_time,
+            resolved: false,
+            orphaned: false,
+        };
+        self.replace_records(vec![], vec![CommentRecord::Thread(record)])
+    }
+
+    pub fn reply(&mut self, comment: Comment) -> CollaborateResult<Option<CommentChange>> {
+        if self.thread_record(&comment.thread_id).is_none() {
+            return Err(CollaborateError::record_not_found()
+                .context(format!("Can't find the comment thread: {}", comment.thread_id)));
+        }
+
+        if self.reply_records().contains_key(&comment.id) {
+            tracing::warn!("[CommentPad]: Duplicate reply");
+            return Ok(None);
+        }
+        self.replace_records(vec![], vec![CommentRecord::Reply(comment)])
+    }
+
+    pub fn resolve_thread(&mut self, thread_id: &str, resolved: bool) -> CollaborateResult<Option<CommentChange>> {
+        let mut record = self.thread_record(thread_id).ok_or_else(|| {
+            CollaborateError::record_not_found().context(format!("Can't find the comment thread: {}", thread_id))
+        })?;
+        if record.resolved == resolved {
+            return Ok(None);
+        }
+
+        record.resolved = resolved;
+        self.replace_threads(vec![record])
+    }
+
+    // Marks the threads that are not in the anchored_ids as orphaned, and the others as not
+    // orphaned, because undoing the deletion brings the text of the thread back. All the threads
+    // are updated in one change.
+    pub fn update_orphaned(&mut self, anchored_ids: &HashSet<String>) -> CollaborateResult<Option<CommentChange>> {
+        let records = self
+            .thread_records()
+            .into_values()
+            .filter(|record| record.orphaned == anchored_ids.contains(&record.id))
+            .map(|mut record| {
+                record.orphaned = !record.orphaned;
+                record
+            })
+            .collect::<Vec<ThreadRecord>>();
+        if records.is_empty() {
+            return Ok(None);
+        }
+        self.replace_threads(records)
+    }
+}
+
+impl CommentPad {
+    // Returns the threads by their ids. The last line wins if the thread has more than one line.
+    fn thread_records(&self) -> HashMap<String, ThreadRecord> {
+        let mut threads = HashMap::new();
+        for record in self.records.iter().flatten() {
+            if let CommentRecord::Thread(thread) = record {
+                threads.insert(thread.id.clone(), thread.clone());
+            }
+        }
+        threads
+    }
+
+    fn reply_records(&self) -> HashMap<String, Comment> {
+        let mut replies = HashMap::new();
+        for record in self.records.iter().flatten() {
+            if let CommentRecord::Reply(comment) = record {
+                replies.insert(comment.id.clone(), comment.clone());
+            }
+        }
+        replies
+    }
+
+    fn thread_record(&self, thread_id: &str) -> Option<ThreadRecord> {
+        self.records.iter().rev().find_map(|record| match record {
+            Some(CommentRecord::Thread(thread)) if thread.id == thread_id => Some(thread.clone()),
+            _ => None,
+        })
+    }
+
+    // Replaces the lines of the threads with the new records.
+    fn replace_threads(&mut self, records: Vec<ThreadRecord>) -> CollaborateResult<Option<CommentChange>> {
+        let thread_ids = records.iter().map(|record| record.id.clone()).collect::<Vec<String>>();
+        let line_indexes = self
+            .records
+            .iter()
+            .enumerate()
+            .filter(|(_, record)| match record {
+                Some(CommentRecord::Thread(thread)) => thread_ids.contains(&thread.id),
+                _ => false,
+            })
+            .map(|(index, _)| index)
+            .collect::<Vec<usize>>();
+        let records = records.into_iter().map(CommentRecord::Thread).collect();
+        self.replace_records(line_indexes, records)
+    }
+
+    // Deletes the lines at the line_indexes and inserts the new records at the position of the
+    // first deleted line, or at the end if no line is deleted.
+    fn replace_records(
+        &mut self,
+        line_indexes: Vec<usize>,
+        records: Vec<CommentRecord>,
+    ) -> CollaborateResult<Option<CommentChange>> {
+        let mut new_lines = String::new();
+        for record in &records {
+            new_lines.push_str(&record_to_line(record)?);
+        }
+
+        let text = self.root.apply("")?;
+        let lines = text.split_inclusive('\n').collect::<Vec<&str>>();
+        let mut delta_builder = PlainDeltaBuilder::new();
+        let mut inserted = false;
+        for (index, line) in lines.iter().enumerate() {
+            let len = FlowyStr::from(*line).utf16_size();
+            if line_indexes.contains(&index) {
+                if !inserted {
+                    delta_builder = delta_builder.insert(&new_lines);
+                    inserted = true;
+                }
+                delta_builder = delta_builder.delete(len);
+            } else {
+                delta_builder = delta_builder.retain(len);
+            }
+        }
+        if !inserted {
+            delta_builder = delta_builder.insert(&new_lines);
+        }
+
+        let delta = delta_builder.build();
+        let _ = self.compose_remote_delta(delta.clone())?;
+        Ok(Some(CommentChange { delta, md5: self.md5() }))
+    }
+}
+
+pub(crate) fn line_to_record(line: &str) -> Option<CommentRecord> {
+    if line.trim().is_empty() {
+        return None;
+    }
+
+    match serde_json::from_str(line) {
+        Ok(record) => Some(record),
+        Err(e) => {
+            tracing::error!("[CommentPad]: Deserialize the line failed: {}, {}", e, line);
+            None
+        }
+    }
+}
+
+fn record_to_line(record: &CommentRecord) -> CollaborateResult<String> {
+    let json = serde_json::to_string(record)
+        .map_err(|e| CollaborateError::internal().context(format!("Serial comment record to json failed: {}", e)))?;
+    Ok(format!("{}\n", json))
+}
+
+fn make_thread(doc_id: &str, record: ThreadRecord, replies: Vec<Comment>) -> CommentThread {
+    CommentThread {
+        id: record.id,
+        doc_id: doc_id.to_owned(),
+        author: record.author,
+        content: record.content,
+        create_time: record.create_time,
+        resolved: record.resolved,
+        orphaned: record.orphaned,
+        anchor_start: 0,
+        anchor_end: 0,
+        replies,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::comment::CommentPad;
+    use flowy_core_data_model::entities::comment::{Comment, CommentThread};
+    use lib_ot::core::OperationTransformable;
+    use std::collections::HashSet;
+
+    fn make_thread(id: &str, create_time: i64) -> CommentThread {
+        CommentThread {
+            id: id.to_owned(),
+            author: "nathan".to_owned(),
+            content: format!("thread {}", id),
+            create_time,
+            ..Default::default()
+        }
+    }
+
+    fn make_reply(id: &str, thread_id: &str, create_time: i64) -> Comment {
+        Comment {
+            id: id.to_owned(),
+            thread_id: thread_id.to_owned(),
+            author: "nathan".to_owned(),
+            content: format!("reply {}", id),
+            create_time,
+        }
+    }
+
+    fn thread_ids(pad: &CommentPad) -> Vec<String> {
+        pad.threads("doc").into_iter().map(|thread| thread.id).collect()
+    }
+
+    #[test]
+    fn comment_create_thread_test() {
+        let mut pad = CommentPad::default();
+        let _ = pad.create_thread(&make_thread("2", 2)).unwrap().unwrap();
+        let _ = pad.create_thread(&make_thread("1", 1)).unwrap().unwrap();
+        assert!(pad.create_thread(&make_thread("1", 1)).unwrap().is_none());
+        assert_eq!(thread_ids(&pad), vec!["1", "2"]);
+        assert_eq!(pad.read_thread("doc", "1").unwrap().doc_id, "doc");
+    }
+
+    #[test]
+    fn comment_reply_test() {
+        let mut pad = CommentPad::default();
+        let _ = pad.create_thread(&make_thread("1", 1)).unwrap().unwrap();
+        let _ = pad.reply(make_reply("b", "1", 3)).unwrap().unwrap();
+        let _ = pad.reply(make_reply("a", "1", 2)).unwrap().unwrap();
+        let replies = pad.read_thread("doc", "1").unwrap().replies;
+        assert_eq!(
+            replies.iter().map(|reply| reply.id.as_str()).collect::<Vec<&str>>(),
+            vec!["a", "b"]
+        );
+        assert!(pad.reply(make_reply("c", "2", 4)).is_err());
+    }
+
+    #[test]
+    fn comment_resolve_thread_test() {
+        let mut pad = CommentPad::default();
+        let _ = pad.create_thread(&make_thread("1", 1)).unwrap().unwrap();
+        let _ = pad.reply(make_reply("a", "1", 2)).unwrap().unwrap();
+        let _ = pad.resolve_thread("1", true).unwrap().unwrap();
+        assert!(pad.resolve_thread("1", true).unwrap().is_none());
+
+        let thread = pad.read_thread("doc", "1").unwrap();
+        assert!(thread.resolved);
+        assert_eq!(thread.replies.len(), 1);
+
+        let _ = pad.resolve_thread("1", false).unwrap().unwrap();
+        assert!(!pad.read_thread("doc", "1").unwrap().resolved);
+    }
+
+    #[test]
+    fn comment_update_orphaned_test() {
+        let mut pad = CommentPad::default();
+        let _ = pad.create_thread(&make_thread("1", 1)).unwrap().unwrap();
+        let _ = pad.create_thread(&make_thread("2", 2)).unwrap().unwrap();
+        let _ = pad.reply(make_reply("a", "1", 3)).unwrap().unwrap();
+
+        let anchored_ids = vec!["2".to_owned()].into_iter().collect::<HashSet<String>>();
+        let _ = pad.update_orphaned(&anchored_ids).unwrap().unwrap();
+        assert!(pad.update_orphaned(&anchored_ids).unwrap().is_none());
+
+        let thread = pad.read_thread("doc", "1").unwrap();
+        assert!(thread.orphaned);
+        assert_eq!(thread.replies.len(), 1);
+        assert!(!pad.read_thread("doc", "2").unwrap().orphaned);
+
+        let anchored_ids = vec!["1".to_owned(), "2".to_owned()]
+            .into_iter()
+            .collect::<HashSet<String>>();
+        let _ = pad.update_orphaned(&anchored_ids).unwrap().unwrap();
+        assert!(!pad.read_thread("doc", "1").unwrap().orphaned);
+    }
+
+    #[test]
+    fn comment_concurrent_reply_test() {
+        let mut base = CommentPad::default();
+        let _ = base.create_thread(&make_thread("1", 1)).unwrap().unwrap();
+        let mut pad_a = base.clone();
+        let mut pad_b = base.clone();
+        let change_a = pad_a.reply(make_reply("a", "1", 2)).unwrap().unwrap();
+        let change_b = pad_b.reply(make_reply("b", "1", 3)).unwrap().unwrap();
+
+        let (a_prime, b_prime) = change_a.delta.transform(&change_b.delta).unwrap();
+        let _ = pad_a.compose_remote_delta(b_prime).unwrap();
+        let _ = pad_b.compose_remote_delta(a_prime).unwrap();
+
+        assert_eq!(pad_a.threads("doc"), pad_b.threads("doc"));
+        assert_eq!(pad_a.read_thread("doc", "1").unwrap().replies.len(), 2);
+    }
+}
@@ -0,0 +1,4 @@
+mod builder;
+mod comment_pad;
+
+pub use comment_pad::*;
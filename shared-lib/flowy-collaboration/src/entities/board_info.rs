@@ -0,0 +1,19 @@
+use flowy_derive::ProtoBuf;
+use lib_ot::core::PlainDelta;
+
+pub type BoardDelta = PlainDelta;
+
+#[derive(ProtoBuf, Default, Debug, Clone, Eq, PartialEq)]
+pub struct BoardInfo {
+    #[pb(index = 1)]
+    pub board_id: String,
+
+    #[pb(index = 2)]
+    pub text: String,
+
+    #[pb(index = 3)]
+    pub rev_id: i64,
+
+    #[pb(index = 4)]
+    pub base_rev_id: i64,
+}
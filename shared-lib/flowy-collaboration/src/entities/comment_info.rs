@@ -0,0 +1,21 @@
+use flowy_derive::ProtoBuf;
+use lib_ot::core::PlainDelta;
+
+pub type CommentDelta = PlainDelta;
+
+// The comment threads of a document are synced as their own object, the object_id is made by the
+// comments_object_id with the id of the document.
+#[derive(ProtoBuf, Default, Debug, Clone, Eq, PartialEq)]
+pub struct CommentInfo {
+    #[pb(index = 1)]
+    pub object_id: String,
+
+    #[pb(index = 2)]
+    pub text: String,
+
+    #[pb(index = 3)]
+    pub rev_id: i64,
+
+    #[pb(index = 4)]
+    pub base_rev_id: i64,
+}
@@ -27,6 +27,11 @@ pub struct DocumentInfo {
 
     #[pb(index = 4)]
     pub base_rev_id: i64,
+
+    // The server can't compose the encrypted revisions into the text, so the revisions are returned
+    // instead and the text is empty.
+    #[pb(index = 5)]
+    pub encrypted_revisions: RepeatedRevision,
 }
 
 impl DocumentInfo {
@@ -53,6 +58,7 @@ impl std::convert::TryFrom<Revision> for DocumentInfo {
             text: doc_json,
             rev_id: revision.rev_id,
             base_rev_id: revision.base_rev_id,
+            encrypted_revisions: RepeatedRevision::empty(),
         })
     }
 }
@@ -0,0 +1,57 @@
+use crate::entities::revision::RevisionRange;
+use flowy_derive::ProtoBuf;
+
+// The document is repaired with the revisions of the server if the repair is true. The revisions
+// that are not synced are dropped.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocumentIntegrityRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub repair: bool,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocumentIntegrityReport {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub is_valid: bool,
+
+    #[pb(index = 3)]
+    pub revision_count: i64,
+
+    #[pb(index = 4)]
+    pub pending_count: i64,
+
+    #[pb(index = 5)]
+    pub last_rev_id: i64,
+
+    #[pb(index = 6)]
+    pub missing_ranges: Vec<RevisionRange>,
+
+    // The consecutive revisions whose delta data is empty or can't be deserialized.
+    #[pb(index = 7)]
+    pub invalid_ranges: Vec<RevisionRange>,
+
+    #[pb(index = 8, one_of)]
+    pub compose_error: Option<String>,
+
+    #[pb(index = 9)]
+    pub local_md5: String,
+
+    #[pb(index = 10)]
+    pub server_rev_id: i64,
+
+    #[pb(index = 11)]
+    pub server_md5: String,
+
+    #[pb(index = 12, one_of)]
+    pub server_error: Option<String>,
+
+    // The report is the result of checking the document again after it was repaired.
+    #[pb(index = 13)]
+    pub repaired: bool,
+}
@@ -0,0 +1,40 @@
+use flowy_derive::ProtoBuf;
+
+// The local state of the document before its pending revisions were merged with the revisions of
+// the server. The document_json is the base document composed with the pending revisions.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocumentMergeBackup {
+    #[pb(index = 1)]
+    pub id: i64,
+
+    #[pb(index = 2)]
+    pub doc_id: String,
+
+    #[pb(index = 3)]
+    pub base_rev_id: i64,
+
+    #[pb(index = 4)]
+    pub local_revision_count: i64,
+
+    #[pb(index = 5)]
+    pub document_json: String,
+
+    // The timestamp in seconds when the backup was created.
+    #[pb(index = 6)]
+    pub create_time: i64,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedDocumentMergeBackup {
+    #[pb(index = 1)]
+    pub items: Vec<DocumentMergeBackup>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocumentMergeBackupId {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub backup_id: i64,
+}
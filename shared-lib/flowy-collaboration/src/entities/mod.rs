@@ -1,5 +1,12 @@
+pub mod board_info;
+pub mod comment_info;
 pub mod document_info;
 pub mod folder_info;
+pub mod grid_info;
+pub mod integrity;
+pub mod merge_backup;
+pub mod outline;
 pub mod parser;
 pub mod revision;
+pub mod statistics;
 pub mod ws_data;
@@ -0,0 +1,45 @@
+use flowy_derive::ProtoBuf;
+
+// The header of the document. The start and the end are the utf16 offsets of the header's text,
+// the newline of the header is not included.
+#[derive(ProtoBuf, Default, Debug, Clone, Eq, PartialEq)]
+pub struct OutlineItem {
+    #[pb(index = 1)]
+    pub level: i64,
+
+    #[pb(index = 2)]
+    pub text: String,
+
+    #[pb(index = 3)]
+    pub start: i64,
+
+    #[pb(index = 4)]
+    pub end: i64,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedOutlineItem {
+    #[pb(index = 1)]
+    pub items: Vec<OutlineItem>,
+}
+
+// The index is the position of the header in the outline.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct JumpToSectionRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub index: i64,
+}
+
+// The section starts at its header and ends before the next header whose level is not greater
+// than the level of its header, or at the end of the document.
+#[derive(ProtoBuf, Default, Debug, Clone, Eq, PartialEq)]
+pub struct DocumentSection {
+    #[pb(index = 1)]
+    pub start: i64,
+
+    #[pb(index = 2)]
+    pub end: i64,
+}
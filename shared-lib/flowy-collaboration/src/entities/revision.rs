@@ -25,6 +25,10 @@ pub struct Revision {
 
     #[pb(index = 7)]
     pub user_id: String,
+
+    // The id of the key that encrypts the delta_data, it's empty if the delta_data is plaintext.
+    #[pb(index = 8)]
+    pub key_id: String,
 }
 
 impl std::convert::From<Vec<u8>> for Revision {
@@ -47,6 +51,10 @@ impl Revision {
         self.rev_id == 0
     }
 
+    pub fn is_encrypted(&self) -> bool {
+        !self.key_id.is_empty()
+    }
+
     pub fn initial_revision(user_id: &str, object_id: &str, delta_data: Bytes) -> Self {
         let md5 = md5(&delta_data);
         Self::new(object_id, 0, 0, delta_data, user_id, md5)
@@ -78,6 +86,7 @@ impl Revision {
             object_id,
             ty: RevType::DeprecatedLocal,
             user_id,
+            key_id: String::new(),
         }
     }
 }
@@ -93,6 +102,10 @@ impl std::fmt::Debug for Revision {
         let _ = f.write_fmt(format_args!("object_id {}, ", self.object_id))?;
         let _ = f.write_fmt(format_args!("base_rev_id {}, ", self.base_rev_id))?;
         let _ = f.write_fmt(format_args!("rev_id {}, ", self.rev_id))?;
+        if self.is_encrypted() {
+            let _ = f.write_fmt(format_args!("encrypted with {}", self.key_id))?;
+            return Ok(());
+        }
         match RichTextDelta::from_bytes(&self.delta_data) {
             Ok(delta) => {
                 let _ = f.write_fmt(format_args!("delta {:?}", delta.to_json()))?;
@@ -105,7 +118,7 @@ impl std::fmt::Debug for Revision {
     }
 }
 
-#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+#[derive(PartialEq, Eq, Debug, Default, ProtoBuf, Clone)]
 pub struct RepeatedRevision {
     #[pb(index = 1)]
     items: Vec<Revision>,
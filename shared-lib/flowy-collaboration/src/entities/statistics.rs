@@ -0,0 +1,59 @@
+use flowy_derive::ProtoBuf;
+
+// The characters don't include the newlines. The paragraphs are the lines that contain any text,
+// including the headers.
+#[derive(ProtoBuf, Default, Debug, Clone, Eq, PartialEq)]
+pub struct DocumentStatistics {
+    #[pb(index = 1)]
+    pub words: i64,
+
+    #[pb(index = 2)]
+    pub characters: i64,
+
+    #[pb(index = 3)]
+    pub characters_without_spaces: i64,
+
+    #[pb(index = 4)]
+    pub paragraphs: i64,
+
+    #[pb(index = 5)]
+    pub headers: i64,
+
+    #[pb(index = 6)]
+    pub reading_time_in_seconds: i64,
+}
+
+// The statistics of the selection if both the start and the end are set, otherwise the statistics
+// of the whole document. The start and the end are the utf16 offsets.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocumentStatisticsRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2, one_of)]
+    pub start: Option<i64>,
+
+    #[pb(index = 3, one_of)]
+    pub end: Option<i64>,
+}
+
+// The usage of the documents that are opened, which are evicted when the count or the estimated size
+// exceeds the budget. The sizes are in bytes.
+#[derive(ProtoBuf, Default, Debug, Clone, Eq, PartialEq)]
+pub struct OpenDocumentsUsage {
+    #[pb(index = 1)]
+    pub count: i64,
+
+    #[pb(index = 2)]
+    pub estimated_size: i64,
+
+    #[pb(index = 3)]
+    pub max_count: i64,
+
+    #[pb(index = 4)]
+    pub max_size: i64,
+
+    // The number of the documents that were evicted since the app was launched.
+    #[pb(index = 5)]
+    pub evicted_count: i64,
+}
@@ -69,6 +69,14 @@ impl ClientRevisionWSData {
     pub fn id(&self) -> String {
         self.data_id.clone()
     }
+
+    // The id that the server acks the message with, the rev_id of the last revision or the data_id.
+    pub fn ack_id(&self) -> String {
+        match self.revisions.last() {
+            None => self.data_id.clone(),
+            Some(revision) => revision.rev_id.to_string(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, ProtoBuf_Enum, Eq, PartialEq, Hash)]
@@ -63,6 +63,7 @@ impl FolderPadBuilder {
         Ok(FolderPad {
             workspaces: self.workspaces,
             trash: self.trash,
+            templates: vec![],
             root: PlainDeltaBuilder::new().insert(&json).build(),
         })
     }
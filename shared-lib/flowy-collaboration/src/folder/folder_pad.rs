@@ -5,10 +5,16 @@ use crate::{
     },
     errors::{CollaborateError, CollaborateResult},
     folder::builder::FolderPadBuilder,
+    util::cal_diff,
 };
-use dissimilar::*;
-use flowy_core_data_model::entities::{app::App, trash::Trash, view::View, workspace::Workspace};
-use lib_ot::core::{Delta, FlowyStr, OperationTransformable, PlainDeltaBuilder, PlainTextAttributes};
+use flowy_core_data_model::entities::{
+    app::{App, ColorStyle},
+    template::Template,
+    trash::Trash,
+    view::{View, ViewProperty},
+    workspace::Workspace,
+};
+use lib_ot::core::{OperationTransformable, PlainDeltaBuilder};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
@@ -16,6 +22,8 @@ use std::sync::Arc;
 pub struct FolderPad {
     pub(crate) workspaces: Vec<Arc<Workspace>>,
     pub(crate) trash: Vec<Arc<Trash>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) templates: Vec<Arc<Template>>,
     #[serde(skip)]
     pub(crate) root: FolderDelta,
 }
@@ -31,6 +39,7 @@ impl std::default::Default for FolderPad {
         FolderPad {
             workspaces: vec![],
             trash: vec![],
+            templates: vec![],
             root: default_folder_delta(),
         }
     }
@@ -66,6 +75,7 @@ impl FolderPad {
         let folder = FolderPad::from_delta(delta)?;
         self.workspaces = folder.workspaces;
         self.trash = folder.trash;
+        self.templates = folder.templates;
         self.root = folder.root;
 
         Ok(self.md5())
@@ -165,6 +175,7 @@ impl FolderPad {
         app_id: &str,
         name: Option<String>,
         desc: Option<String>,
+        color_style: Option<ColorStyle>,
     ) -> CollaborateResult<Option<FolderChange>> {
         self.with_app(app_id, move |app| {
             if let Some(name) = name {
@@ -174,6 +185,10 @@ impl FolderPad {
             if let Some(desc) = desc {
                 app.desc = desc;
             }
+
+            if let Some(color_style) = color_style {
+                app.color_style = color_style;
+            }
             Ok(Some(()))
         })
     }
@@ -186,14 +201,15 @@ impl FolderPad {
         })
     }
 
+    // The belong_to_id of the view can be the id of an app or the id of another view.
     pub fn create_view(&mut self, view: View) -> CollaborateResult<Option<FolderChange>> {
-        let app_id = view.belong_to_id.clone();
-        self.with_app(&app_id, move |app| {
-            if app.belongings.contains(&view) {
+        let belong_to_id = view.belong_to_id.clone();
+        self.with_belongings(&belong_to_id, move |belongings| {
+            if belongings.contains(&view) {
                 tracing::warn!("[RootFolder]: Duplicate view");
                 return Ok(None);
             }
-            app.belongings.push(view);
+            belongings.push(view);
             Ok(Some(()))
         })
     }
@@ -201,7 +217,7 @@ impl FolderPad {
     pub fn read_view(&self, view_id: &str) -> CollaborateResult<View> {
         for workspace in &self.workspaces {
             for app in &(*workspace.apps) {
-                if let Some(view) = app.belongings.iter().find(|b| b.id == view_id) {
+                if let Some(view) = find_view(&app.belongings, view_id) {
                     return Ok(view.clone());
                 }
             }
@@ -215,6 +231,10 @@ impl FolderPad {
                 if app.id == belong_to_id {
                     return Ok(app.belongings.clone().take_items());
                 }
+
+                if let Some(view) = find_view(&app.belongings, belong_to_id) {
+                    return Ok(view.belongings.clone().take_items());
+                }
             }
         }
         Ok(vec![])
@@ -227,8 +247,7 @@ impl FolderPad {
         desc: Option<String>,
         modified_time: i64,
     ) -> CollaborateResult<Option<FolderChange>> {
-        let view = self.read_view(view_id)?;
-        self.with_view(&view.belong_to_id, view_id, |view| {
+        self.with_view(view_id, |view| {
             if let Some(name) = name {
                 view.name = name;
             }
@@ -242,10 +261,56 @@ impl FolderPad {
         })
     }
 
+    pub fn update_view_tags(
+        &mut self,
+        view_id: &str,
+        tags: Vec<String>,
+        modified_time: i64,
+    ) -> CollaborateResult<Option<FolderChange>> {
+        self.with_view(view_id, |view| {
+            if view.tags == tags {
+                return Ok(None);
+            }
+
+            view.tags = tags;
+            view.modified_time = modified_time;
+            Ok(Some(()))
+        })
+    }
+
+    // Replaces the property with the same key, or removes it if the property is None.
+    pub fn update_view_property(
+        &mut self,
+        view_id: &str,
+        key: &str,
+        property: Option<ViewProperty>,
+        modified_time: i64,
+    ) -> CollaborateResult<Option<FolderChange>> {
+        self.with_view(view_id, |view| {
+            let index = view.properties.iter().position(|p| p.key == key);
+            match (index, property) {
+                (None, None) => return Ok(None),
+                (Some(index), None) => {
+                    view.properties.remove(index);
+                }
+                (Some(index), Some(property)) => {
+                    if view.properties[index] == property {
+                        return Ok(None);
+                    }
+                    view.properties[index] = property;
+                }
+                (None, Some(property)) => view.properties.push(property),
+            }
+
+            view.modified_time = modified_time;
+            Ok(Some(()))
+        })
+    }
+
     pub fn delete_view(&mut self, view_id: &str) -> CollaborateResult<Option<FolderChange>> {
         let view = self.read_view(view_id)?;
-        self.with_app(&view.belong_to_id, |app| {
-            app.belongings.retain(|view| view.id != view_id);
+        self.with_belongings(&view.belong_to_id, |belongings| {
+            belongings.retain(|view| view.id != view_id);
             Ok(Some(()))
         })
     }
@@ -282,6 +347,40 @@ impl FolderPad {
         }
     }
 
+    // Replaces the template if the view or app was already marked as template.
+    pub fn create_template(&mut self, template: Template) -> CollaborateResult<Option<FolderChange>> {
+        self.with_templates(|templates| {
+            templates.retain(|t| t.id != template.id);
+            templates.push(Arc::new(template));
+            Ok(Some(()))
+        })
+    }
+
+    pub fn read_templates(&self, template_id: Option<String>) -> CollaborateResult<Vec<Template>> {
+        match template_id {
+            None => Ok(self
+                .templates
+                .iter()
+                .map(|t| t.as_ref().clone())
+                .collect::<Vec<Template>>()),
+            Some(template_id) => match self.templates.iter().find(|t| t.id == template_id) {
+                Some(template) => Ok(vec![template.as_ref().clone()]),
+                None => Ok(vec![]),
+            },
+        }
+    }
+
+    pub fn delete_templates(&mut self, template_ids: Vec<String>) -> CollaborateResult<Option<FolderChange>> {
+        if !self.templates.iter().any(|t| template_ids.contains(&t.id)) {
+            return Ok(None);
+        }
+
+        self.with_templates(|templates| {
+            templates.retain(|t| !template_ids.contains(&t.id));
+            Ok(Some(()))
+        })
+    }
+
     pub fn md5(&self) -> String {
         md5(&self.root.to_bytes())
     }
@@ -336,6 +435,23 @@ impl FolderPad {
         }
     }
 
+    fn with_templates<F>(&mut self, f: F) -> CollaborateResult<Option<FolderChange>>
+    where
+        F: FnOnce(&mut Vec<Arc<Template>>) -> CollaborateResult<Option<()>>,
+    {
+        let cloned_self = self.clone();
+        match f(&mut self.templates)? {
+            None => Ok(None),
+            Some(_) => {
+                let old = cloned_self.to_json()?;
+                let new = self.to_json()?;
+                let delta = cal_diff(old, new);
+                self.root = self.root.compose(&delta)?;
+                Ok(Some(FolderChange { delta, md5: self.md5() }))
+            }
+        }
+    }
+
     fn with_app<F>(&mut self, app_id: &str, f: F) -> CollaborateResult<Option<FolderChange>>
     where
         F: FnOnce(&mut App) -> CollaborateResult<Option<()>>,
@@ -358,44 +474,81 @@ impl FolderPad {
         })
     }
 
-    fn with_view<F>(&mut self, belong_to_id: &str, view_id: &str, f: F) -> CollaborateResult<Option<FolderChange>>
+    fn with_view<F>(&mut self, view_id: &str, f: F) -> CollaborateResult<Option<FolderChange>>
     where
         F: FnOnce(&mut View) -> CollaborateResult<Option<()>>,
     {
-        self.with_app(belong_to_id, |app| {
-            match app.belongings.iter_mut().find(|view| view_id == view.id) {
-                None => {
-                    tracing::warn!("[RootFolder]: Can't find any view with id: {}", view_id);
-                    Ok(None)
-                }
-                Some(view) => f(view),
+        let workspace_id = match self.workspaces.iter().find(|workspace| {
+            workspace
+                .apps
+                .iter()
+                .any(|app| find_view(&app.belongings, view_id).is_some())
+        }) {
+            None => {
+                tracing::warn!("[RootFolder]: Can't find any view with id: {}", view_id);
+                return Ok(None);
             }
+            Some(workspace) => workspace.id.clone(),
+        };
+
+        self.with_workspace(&workspace_id, |workspace| {
+            // It's ok to unwrap because we get the workspace from the view_id.
+            let view = workspace
+                .apps
+                .iter_mut()
+                .find_map(|app| find_view_mut(&mut app.belongings, view_id))
+                .unwrap();
+            f(view)
         })
     }
 
+    // Modify the belongings of the app or the view with belong_to_id.
+    fn with_belongings<F>(&mut self, belong_to_id: &str, f: F) -> CollaborateResult<Option<FolderChange>>
+    where
+        F: FnOnce(&mut Vec<View>) -> CollaborateResult<Option<()>>,
+    {
+        let is_app = self
+            .workspaces
+            .iter()
+            .any(|workspace| workspace.apps.iter().any(|app| app.id == belong_to_id));
+
+        if is_app {
+            self.with_app(belong_to_id, |app| f(&mut app.belongings))
+        } else {
+            self.with_view(belong_to_id, |view| f(&mut view.belongings))
+        }
+    }
+
     fn to_json(&self) -> CollaborateResult<String> {
         serde_json::to_string(self)
             .map_err(|e| CollaborateError::internal().context(format!("serial trash to json failed: {}", e)))
     }
 }
 
-fn cal_diff(old: String, new: String) -> Delta<PlainTextAttributes> {
-    let chunks = dissimilar::diff(&old, &new);
-    let mut delta_builder = PlainDeltaBuilder::new();
-    for chunk in &chunks {
-        match chunk {
-            Chunk::Equal(s) => {
-                delta_builder = delta_builder.retain(FlowyStr::from(*s).utf16_size());
-            }
-            Chunk::Delete(s) => {
-                delta_builder = delta_builder.delete(FlowyStr::from(*s).utf16_size());
-            }
-            Chunk::Insert(s) => {
-                delta_builder = delta_builder.insert(*s);
-            }
+fn find_view<'a>(views: &'a [View], view_id: &str) -> Option<&'a View> {
+    for view in views {
+        if view.id == view_id {
+            return Some(view);
+        }
+
+        if let Some(view) = find_view(&view.belongings, view_id) {
+            return Some(view);
+        }
+    }
+    None
+}
+
+fn find_view_mut<'a>(views: &'a mut [View], view_id: &str) -> Option<&'a mut View> {
+    for view in views.iter_mut() {
+        if view.id == view_id {
+            return Some(view);
+        }
+
+        if let Some(view) = find_view_mut(&mut view.belongings, view_id) {
+            return Some(view);
         }
     }
-    delta_builder.build()
+    None
 }
 
 #[cfg(test)]
@@ -403,7 +556,13 @@ mod tests {
     #![allow(clippy::all)]
     use crate::{entities::folder_info::FolderDelta, folder::folder_pad::FolderPad};
     use chrono::Utc;
-    use flowy_core_data_model::entities::{app::App, trash::Trash, view::View, workspace::Workspace};
+    use flowy_core_data_model::entities::{
+        app::App,
+        template::{Template, TemplateType},
+        trash::Trash,
+        view::{View, ViewProperty, ViewPropertyType},
+        workspace::Workspace,
+    };
     use lib_ot::core::{OperationTransformable, PlainDelta, PlainDeltaBuilder};
 
     #[test]
@@ -485,7 +644,7 @@ mod tests {
     fn folder_update_app() {
         let (mut folder, initial_delta, app) = test_app_folder();
         let delta = folder
-            .update_app(&app.id, Some("🤪 rename app".to_owned()), None)
+            .update_app(&app.id, Some("🤪 rename app".to_owned()), None, None)
             .unwrap()
             .unwrap()
             .delta;
@@ -679,6 +838,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn folder_add_nested_view() {
+        let (folder, initial_delta, view, nested_view) = test_nested_view_folder();
+        let new_folder = make_folder_from_delta(initial_delta, vec![]);
+        assert_eq!(folder, new_folder);
+
+        let views = folder.read_views(&view.id).unwrap();
+        assert_eq!(views, vec![nested_view.clone()]);
+        assert_eq!(folder.read_view(&nested_view.id).unwrap(), nested_view);
+    }
+
+    #[test]
+    fn folder_update_nested_view() {
+        let (mut folder, initial_delta, _view, nested_view) = test_nested_view_folder();
+        let delta = folder
+            .update_view(&nested_view.id, Some("😦 rename nested view".to_owned()), None, 123)
+            .unwrap()
+            .unwrap()
+            .delta;
+
+        let new_folder = make_folder_from_delta(initial_delta, vec![delta]);
+        assert_eq!(folder, new_folder);
+
+        let updated_view = new_folder.read_view(&nested_view.id).unwrap();
+        assert_eq!(updated_view.name, "😦 rename nested view");
+        assert_eq!(updated_view.modified_time, 123);
+    }
+
+    #[test]
+    fn folder_delete_nested_view() {
+        let (mut folder, initial_delta, view, nested_view) = test_nested_view_folder();
+        let delta = folder.delete_view(&nested_view.id).unwrap().unwrap().delta;
+
+        let new_folder = make_folder_from_delta(initial_delta, vec![delta]);
+        assert_eq!(folder, new_folder);
+        assert!(new_folder.read_views(&view.id).unwrap().is_empty());
+        assert!(new_folder.read_view(&nested_view.id).is_err());
+        assert_eq!(new_folder.read_view(&view.id).unwrap().belongings.len(), 0);
+    }
+
     #[test]
     fn folder_add_trash() {
         let (folder, initial_delta, _trash) = test_trash();
@@ -716,6 +915,105 @@ mod tests {
         );
     }
 
+    #[test]
+    fn folder_update_view_tags() {
+        let (mut folder, initial_delta, view) = test_view_folder();
+        let delta = folder
+            .update_view_tags(&view.id, vec!["draft".to_owned(), "rust".to_owned()], 123)
+            .unwrap()
+            .unwrap()
+            .delta;
+
+        let new_folder = make_folder_from_delta(initial_delta, vec![delta]);
+        assert_eq!(folder, new_folder);
+        let new_view = new_folder.read_view(&view.id).unwrap();
+        assert_eq!(new_view.tags, vec!["draft".to_owned(), "rust".to_owned()]);
+        assert_eq!(new_view.modified_time, 123);
+
+        // Setting the same tags doesn't generate any change.
+        assert!(folder
+            .update_view_tags(&view.id, vec!["draft".to_owned(), "rust".to_owned()], 456)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn folder_update_view_property() {
+        let (mut folder, initial_delta, view) = test_view_folder();
+        let mut property = ViewProperty::default();
+        property.key = "status".to_owned();
+        property.ty = ViewPropertyType::Select;
+        property.value = "draft".to_owned();
+        property.options = vec!["draft".to_owned(), "published".to_owned()];
+
+        let delta_1 = folder
+            .update_view_property(&view.id, "status", Some(property.clone()), 0)
+            .unwrap()
+            .unwrap()
+            .delta;
+        assert_eq!(
+            folder.read_view(&view.id).unwrap().get_property("status"),
+            Some(&property)
+        );
+
+        property.value = "published".to_owned();
+        let delta_2 = folder
+            .update_view_property(&view.id, "status", Some(property.clone()), 0)
+            .unwrap()
+            .unwrap()
+            .delta;
+        let new_folder = make_folder_from_delta(initial_delta.clone(), vec![delta_1.clone(), delta_2.clone()]);
+        assert_eq!(folder, new_folder);
+        assert_eq!(new_folder.read_view(&view.id).unwrap().properties, vec![property]);
+
+        let delta_3 = folder
+            .update_view_property(&view.id, "status", None, 0)
+            .unwrap()
+            .unwrap()
+            .delta;
+        let new_folder = make_folder_from_delta(initial_delta, vec![delta_1, delta_2, delta_3]);
+        assert_eq!(folder, new_folder);
+        assert!(new_folder.read_view(&view.id).unwrap().properties.is_empty());
+    }
+
+    #[test]
+    fn folder_add_template() {
+        let (mut folder, initial_delta, view) = test_view_folder();
+        let mut template = Template::default();
+        template.id = view.id.clone();
+        template.name = view.name.clone();
+        template.ty = TemplateType::View;
+
+        let delta = folder.create_template(template.clone()).unwrap().unwrap().delta;
+        assert_eq!(folder, make_folder_from_delta(initial_delta, vec![delta]));
+        assert_eq!(
+            folder.read_templates(Some(view.id.clone())).unwrap(),
+            vec![template.clone()]
+        );
+
+        // Marking the same view as template again replaces the old one.
+        template.name = "🎃 my template".to_owned();
+        let _ = folder.create_template(template.clone()).unwrap().unwrap();
+        assert_eq!(folder.read_templates(None).unwrap(), vec![template]);
+    }
+
+    #[test]
+    fn folder_delete_template() {
+        let (mut folder, initial_delta, view) = test_view_folder();
+        let mut template = Template::default();
+        template.id = view.id.clone();
+        template.ty = TemplateType::View;
+
+        let delta_1 = folder.create_template(template).unwrap().unwrap().delta;
+        let delta_2 = folder.delete_templates(vec![view.id.clone()]).unwrap().unwrap().delta;
+        assert!(folder.delete_templates(vec![view.id.clone()]).unwrap().is_none());
+        assert!(folder.read_templates(None).unwrap().is_empty());
+
+        let new_folder = make_folder_from_delta(initial_delta, vec![delta_1, delta_2]);
+        assert_eq!(folder, new_folder);
+        assert!(!new_folder.to_json().unwrap().contains("templates"));
+    }
+
     fn test_folder() -> (FolderPad, FolderDelta, Workspace) {
         let mut folder = FolderPad::default();
         let folder_json = serde_json::to_string(&folder).unwrap();
@@ -758,6 +1056,27 @@ mod tests {
         (folder, initial_delta, view)
     }
 
+    fn test_nested_view_folder() -> (FolderPad, FolderDelta, View, View) {
+        let (mut folder, mut initial_delta, app) = test_app_folder();
+        let mut view = View::default();
+        view.id = "1".to_owned();
+        view.belong_to_id = app.id.clone();
+        view.name = "🎃 my first view".to_owned();
+        initial_delta = initial_delta
+            .compose(&folder.create_view(view.clone()).unwrap().unwrap().delta)
+            .unwrap();
+
+        let mut nested_view = View::default();
+        nested_view.id = "2".to_owned();
+        nested_view.belong_to_id = view.id.clone();
+        nested_view.name = "🎃 my first nested view".to_owned();
+        initial_delta = initial_delta
+            .compose(&folder.create_view(nested_view.clone()).unwrap().unwrap().delta)
+            .unwrap();
+
+        (folder, initial_delta, view, nested_view)
+    }
+
     fn test_trash() -> (FolderPad, FolderDelta, Trash) {
         let mut folder = FolderPad::default();
         let folder_json = serde_json::to_string(&folder).unwrap();
@@ -0,0 +1,32 @@
+use crate::{
+    entities::{grid_info::GridDelta, revision::Revision},
+    errors::CollaborateResult,
+    grid::{default_grid_delta, grid_pad::line_to_record, GridPad},
+};
+use lib_ot::core::OperationTransformable;
+
+pub(crate) struct GridPadBuilder();
+
+impl GridPadBuilder {
+    pub(crate) fn build_with_delta(mut delta: GridDelta) -> CollaborateResult<GridPad> {
+        if delta.is_empty() {
+            delta = default_grid_delta();
+        }
+        let grid_text = delta.apply("")?;
+        let records = grid_text.split_inclusive('\n').map(line_to_record).collect();
+        Ok(GridPad { records, root: delta })
+    }
+
+    pub(crate) fn build_with_revisions(revisions: Vec<Revision>) -> CollaborateResult<GridPad> {
+        let mut grid_delta = GridDelta::new();
+        for revision in revisions {
+            if revision.delta_data.is_empty() {
+                tracing::warn!("revision delta_data is empty");
+            }
+
+            let delta = GridDelta::from_bytes(revision.delta_data)?;
+            grid_delta = grid_delta.compose(&delta)?;
+        }
+        GridPadBuilder::build_with_delta(grid_delta)
+    }
+}
@@ -0,0 +1,604 @@
+use crate::{
+    entities::{
+        grid_info::GridDelta,
+        revision::{md5, Revision},
+    },
+    errors::{CollaborateError, CollaborateResult},
+    grid::builder::GridPadBuilder,
+};
+use flowy_core_data_model::entities::grid::{Cell, Field, Grid, Row};
+use lib_ot::core::{FlowyStr, OperationTransformable, PlainDeltaBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// The grid is stored as lines of json, each line is a record of the grid: a field or a row, the same
+// as the BoardPad. A change replaces the lines of the records it touches in place, so the deltas of
+// the concurrent changes are always aligned to the lines after being transformed. The fields and the
+// rows are ordered by their lines. If the same record is changed concurrently, it ends up with more
+// than one line and the last one wins until the record is changed again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridPad {
+    pub(crate) records: Vec<Option<GridRecord>>,
+    pub(crate) root: GridDelta,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum GridRecord {
+    Field(Field),
+    Row(Row),
+}
+
+impl GridRecord {
+    fn key(&self) -> RecordKey {
+        match self {
+            GridRecord::Field(field) => RecordKey::Field(field.id.clone()),
+            GridRecord::Row(row) => RecordKey::Row(row.id.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum RecordKey {
+    Field(String),
+    Row(String),
+}
+
+pub fn default_grid_delta() -> GridDelta {
+    PlainDeltaBuilder::new().build()
+}
+
+impl std::default::Default for GridPad {
+    fn default() -> Self {
+        GridPad {
+            records: vec![],
+            root: default_grid_delta(),
+        }
+    }
+}
+
+pub struct GridChange {
+    pub delta: GridDelta,
+    /// md5: the md5 of the GridPad's delta after applying the change.
+    pub md5: String,
+}
+
+impl GridPad {
+    pub fn from_revisions(revisions: Vec<Revision>) -> CollaborateResult<Self> {
+        GridPadBuilder::build_with_revisions(revisions)
+    }
+
+    pub fn from_delta(delta: GridDelta) -> CollaborateResult<Self> {
+        GridPadBuilder::build_with_delta(delta)
+    }
+
+    pub fn delta(&self) -> &GridDelta {
+        &self.root
+    }
+
+    pub fn md5(&self) -> String {
+        md5(&self.root.to_bytes())
+    }
+
+    pub fn reset_grid(&mut self, delta: GridDelta) -> CollaborateResult<String> {
+        let grid = GridPad::from_delta(delta)?;
+        self.records = grid.records;
+        self.root = grid.root;
+
+        Ok(self.md5())
+    }
+
+    pub fn compose_remote_delta(&mut self, delta: GridDelta) -> CollaborateResult<String> {
+        let composed_delta = self.root.compose(&delta)?;
+        self.reset_grid(composed_delta)
+    }
+
+    pub fn grid(&self, grid_id: &str) -> Grid {
+        Grid {
+            id: grid_id.to_owned(),
+            fields: self.fields(),
+            rows: self.rows(),
+        }
+    }
+
+    pub fn read_field(&self, field_id: &str) -> Option<Field> {
+        self.fields().into_iter().find(|field| field.id == field_id)
+    }
+
+    pub fn read_row(&self, row_id: &str) -> Option<Row> {
+        self.rows().into_iter().find(|row| row.id == row_id)
+    }
+
+    pub fn create_field(&mut self, field: Field) -> CollaborateResult<Option<GridChange>> {
+        if self.read_field(&field.id).is_some() {
+            tracing::warn!("[GridPad]: Duplicate field");
+            return Ok(None);
+        }
+        self.replace_records(vec![], vec![GridRecord::Field(field)])
+    }
+
+    // The cells of the SingleSelect field are removed if their data is not in the new options.
+    pub fn update_field(
+        &mut self,
+        field_id: &str,
+        name: Option<String>,
+        options: Option<Vec<String>>,
+    ) -> CollaborateResult<Option<GridChange>> {
+        let mut field = match self.read_field(field_id) {
+            None => {
+                tracing::warn!("[GridPad]: Can't find any field with id: {}", field_id);
+                return Ok(None);
+            }
+            Some(field) => field,
+        };
+
+        if let Some(name) = name {
+            field.name = name;
+        }
+
+        let mut records = vec![];
+        if let Some(options) = options {
+            field.options = options;
+            let is_invalid =
+                |cell: &Cell| cell.field_id == field.id && field.parse_cell_data(cell.data.clone()).is_err();
+            for mut row in self.rows() {
+                if row.cells.iter().any(is_invalid) {
+                    row.cells.retain(|cell| !is_invalid(cell));
+                    records.push(GridRecord::Row(row));
+                }
+            }
+        }
+        records.insert(0, GridRecord::Field(field));
+        self.replace_records(vec![], records)
+    }
+
+    // Deleting the field removes the cells of the field too.
+    pub fn delete_field(&mut self, field_id: &str) -> CollaborateResult<Option<GridChange>> {
+        if self.read_field(field_id).is_none() {
+            return Ok(None);
+        }
+
+        let records = self
+            .rows()
+            .into_iter()
+            .filter(|row| row.get_cell(field_id).is_some())
+            .map(|mut row| {
+                row.cells.retain(|cell| cell.field_id != field_id);
+                GridRecord::Row(row)
+            })
+            .collect::<Vec<GridRecord>>();
+        self.replace_records(vec![RecordKey::Field(field_id.to_owned())], records)
+    }
+
+    pub fn create_row(&mut self, row: Row) -> CollaborateResult<Option<GridChange>> {
+        if self.read_row(&row.id).is_some() {
+            tracing::warn!("[GridPad]: Duplicate row");
+            return Ok(None);
+        }
+        self.replace_records(vec![], vec![GridRecord::Row(row)])
+    }
+
+    pub fn delete_row(&mut self, row_id: &str) -> CollaborateResult<Option<GridChange>> {
+        if self.read_row(row_id).is_none() {
+            return Ok(None);
+        }
+        self.replace_records(vec![RecordKey::Row(row_id.to_owned())], vec![])
+    }
+
+    // Inserts the cells into the row or replaces the data of the existing cells. The cells should be
+    // validated against their fields before calling this method.
+    pub fn update_cells(&mut self, row_id: &str, cells: Vec<Cell>) -> CollaborateResult<Option<GridChange>> {
+        let fields = self.fields();
+        for cell in &cells {
+            if !fields.iter().any(|field| field.id == cell.field_id) {
+                return Err(CollaborateError::record_not_found()
+                    .context(format!("Can't find any field with id: {}", cell.field_id)));
+            }
+        }
+
+        self.with_row(row_id, |row| {
+            let mut is_changed = false;
+            for cell in cells {
+                match row.cells.iter_mut().find(|c| c.field_id == cell.field_id) {
+                    None => {
+                        row.cells.push(cell);
+                        is_changed = true;
+                    }
+                    Some(c) => {
+                        if c.data != cell.data {
+                            c.data = cell.data;
+                            is_changed = true;
+                        }
+                    }
+                }
+            }
+            is_changed
+        })
+    }
+
+    pub fn delete_cell(&mut self, row_id: &str, field_id: &str) -> CollaborateResult<Option<GridChange>> {
+        self.with_row(row_id, |row| {
+            if row.get_cell(field_id).is_none() {
+                return false;
+            }
+            row.cells.retain(|cell| cell.field_id != field_id);
+            true
+        })
+    }
+}
+
+impl GridPad {
+    // Returns the fields in the order of their first lines. The last line wins if the field has more
+    // than one line.
+    fn fields(&self) -> Vec<Field> {
+        self.ordered_records(|record| match record {
+            GridRecord::Field(field) => Some(field.clone()),
+            GridRecord::Row(_) => None,
+        })
+    }
+
+    fn rows(&self) -> Vec<Row> {
+        self.ordered_records(|record| match record {
+            GridRecord::Row(row) => Some(row.clone()),
+            GridRecord::Field(_) => None,
+        })
+    }
+
+    fn ordered_records<T, F>(&self, f: F) -> Vec<T>
+    where
+        F: Fn(&GridRecord) -> Option<T>,
+    {
+        let mut items: Vec<T> = vec![];
+        let mut indexes: HashMap<RecordKey, usize> = HashMap::new();
+        for record in self.records.iter().flatten() {
+            if let Some(item) = f(record) {
+                match indexes.get(&record.key()) {
+                    None => {
+                        indexes.insert(record.key(), items.len());
+                        items.push(item);
+                    }
+                    Some(index) => items[*index] = item,
+                }
+            }
+        }
+        items
+    }
+
+    fn with_row<F>(&mut self, row_id: &str, f: F) -> CollaborateResult<Option<GridChange>>
+    where
+        F: FnOnce(&mut Row) -> bool,
+    {
+        let mut row = match self.read_row(row_id) {
+            None => {
+                tracing::warn!("[GridPad]: Can't find any row with id: {}", row_id);
+                return Ok(None);
+            }
+            Some(row) => row,
+        };
+
+        if !f(&mut row) {
+            return Ok(None);
+        }
+        self.replace_records(vec![], vec![GridRecord::Row(row)])
+    }
+
+    // Deletes the lines of the deleted records and of the new records. Each new record is inserted at
+    // the position of its first line, or at the end if it doesn't have any line yet.
+    fn replace_records(
+        &mut self,
+        deleted: Vec<RecordKey>,
+        records: Vec<GridRecord>,
+    ) -> CollaborateResult<Option<GridChange>> {
+        let mut new_lines = HashMap::new();
+        let mut appended_lines = String::new();
+        for record in &records {
+            let key = record.key();
+            let line = record_to_line(record)?;
+            if self.records.iter().flatten().any(|r| r.key() == key) {
+                new_lines.insert(key, line);
+            } else {
+                appended_lines.push_str(&line);
+            }
+        }
+
+        let text = self.root.apply("")?;
+        let mut delta_builder = PlainDeltaBuilder::new();
+        for (line, record) in text.split_inclusive('\n').zip(self.records.iter()) {
+            let len = FlowyStr::from(line).utf16_size();
+            let key = record.as_ref().map(|record| record.key());
+            match key {
+                Some(key) if deleted.contains(&key) || records.iter().any(|r| r.key() == key) => {
+                    if let Some(new_line) = new_lines.remove(&key) {
+                        delta_builder = delta_builder.insert(&new_line);
+                    }
+                    delta_builder = delta_builder.delete(len);
+                }
+                _ => delta_builder = delta_builder.retain(len),
+            }
+        }
+        if !appended_lines.is_empty() {
+            delta_builder = delta_builder.insert(&appended_lines);
+        }
+
+        let delta = delta_builder.build();
+        let _ = self.compose_remote_delta(delta.clone())?;
+        Ok(Some(GridChange { delta, md5: self.md5() }))
+    }
+}
+
+pub(crate) fn line_to_record(line: &str) -> Option<GridRecord> {
+    if line.trim().is_empty() {
+        return None;
+    }
+
+    match serde_json::from_str(line) {
+        Ok(record) => Some(record),
+        Err(e) => {
+            tracing::error!("[GridPad]: Deserialize the line failed: {}, {}", e, line);
+            None
+        }
+    }
+}
+
+fn record_to_line(record: &GridRecord) -> CollaborateResult<String> {
+    let json = serde_json::to_string(record)
+        .map_err(|e| CollaborateError::internal().context(format!("Serial grid record to json failed: {}", e)))?;
+    Ok(format!("{}\n", json))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::all)]
+    use crate::{entities::grid_info::GridDelta, grid::grid_pad::GridPad};
+    use flowy_core_data_model::entities::grid::{Cell, Field, FieldType, Row};
+    use lib_ot::core::{OperationTransformable, PlainDelta};
+
+    #[test]
+    fn grid_add_field() {
+        let (grid, initial_delta, _) = test_grid();
+        let grid_from_delta = make_grid_from_delta(initial_delta, vec![]);
+        assert_grid_equal(
+            &grid,
+            &grid_from_delta,
+            r#"{"field":{"id":"f1","name":"😁 Name","field_type":"Text"}}
+"#,
+        );
+    }
+
+    #[test]
+    fn grid_update_field() {
+        let (mut grid, initial_delta, field) = test_grid();
+        let delta = grid
+            .update_field(&field.id, Some("Title".to_owned()), None)
+            .unwrap()
+            .unwrap()
+            .delta;
+
+        let grid_from_delta = make_grid_from_delta(initial_delta, vec![delta]);
+        assert_grid_equal(
+            &grid,
+            &grid_from_delta,
+            r#"{"field":{"id":"f1","name":"Title","field_type":"Text"}}
+"#,
+        );
+    }
+
+    #[test]
+    fn grid_update_field_options_removes_invalid_cells() {
+        let (mut grid, initial_delta, _) = test_grid();
+        let field = select_field();
+        let delta_1 = grid.create_field(field.clone()).unwrap().unwrap().delta;
+        let delta_2 = grid.create_row(test_row("r1")).unwrap().unwrap().delta;
+        let delta_3 = grid
+            .update_cells("r1", vec![test_cell(&field.id, "Done")])
+            .unwrap()
+            .unwrap()
+            .delta;
+        let delta_4 = grid
+            .update_field(&field.id, None, Some(vec!["Todo".to_owned()]))
+            .unwrap()
+            .unwrap()
+            .delta;
+
+        let grid_from_delta = make_grid_from_delta(initial_delta, vec![delta_1, delta_2, delta_3, delta_4]);
+        assert_grid_equal(
+            &grid,
+            &grid_from_delta,
+            r#"{"field":{"id":"f1","name":"😁 Name","field_type":"Text"}}
+{"field":{"id":"f2","name":"Status","field_type":"SingleSelect","options":["Todo"]}}
+{"row":{"id":"r1","cells":[]}}
+"#,
+        );
+    }
+
+    #[test]
+    fn grid_delete_field() {
+        let (mut grid, initial_delta, field) = test_grid();
+        let delta_1 = grid.create_row(test_row("r1")).unwrap().unwrap().delta;
+        let delta_2 = grid
+            .update_cells("r1", vec![test_cell(&field.id, "hello")])
+            .unwrap()
+            .unwrap()
+            .delta;
+        let delta_3 = grid.delete_field(&field.id).unwrap().unwrap().delta;
+
+        let grid_from_delta = make_grid_from_delta(initial_delta, vec![delta_1, delta_2, delta_3]);
+        assert_grid_equal(
+            &grid,
+            &grid_from_delta,
+            r#"{"row":{"id":"r1","cells":[]}}
+"#,
+        );
+    }
+
+    #[test]
+    fn grid_add_row_and_update_cells() {
+        let (mut grid, initial_delta, field) = test_grid();
+        let delta_1 = grid.create_row(test_row("r1")).unwrap().unwrap().delta;
+        let delta_2 = grid
+            .update_cells("r1", vec![test_cell(&field.id, "hello")])
+            .unwrap()
+            .unwrap()
+            .delta;
+        let delta_3 = grid
+            .update_cells("r1", vec![test_cell(&field.id, "hello world")])
+            .unwrap()
+            .unwrap()
+            .delta;
+        assert!(grid
+            .update_cells("r1", vec![test_cell(&field.id, "hello world")])
+            .unwrap()
+            .is_none());
+
+        let grid_from_delta = make_grid_from_delta(initial_delta, vec![delta_1, delta_2, delta_3]);
+        assert_grid_equal(
+            &grid,
+            &grid_from_delta,
+            r#"{"field":{"id":"f1","name":"😁 Name","field_type":"Text"}}
+{"row":{"id":"r1","cells":[{"field_id":"f1","data":"hello world"}]}}
+"#,
+        );
+    }
+
+    #[test]
+    fn grid_update_cell_of_unknown_field() {
+        let (mut grid, _, _) = test_grid();
+        let _ = grid.create_row(test_row("r1")).unwrap().unwrap();
+        assert!(grid.update_cells("r1", vec![test_cell("unknown", "hello")]).is_err());
+    }
+
+    #[test]
+    fn grid_delete_cell_and_row() {
+        let (mut grid, initial_delta, field) = test_grid();
+        let delta_1 = grid.create_row(test_row("r1")).unwrap().unwrap().delta;
+        let delta_2 = grid.create_row(test_row("r2")).unwrap().unwrap().delta;
+        let delta_3 = grid
+            .update_cells("r1", vec![test_cell(&field.id, "hello")])
+            .unwrap()
+            .unwrap()
+            .delta;
+        let delta_4 = grid.delete_cell("r1", &field.id).unwrap().unwrap().delta;
+        let delta_5 = grid.delete_row("r2").unwrap().unwrap().delta;
+        assert!(grid.delete_row("r2").unwrap().is_none());
+
+        let grid_from_delta = make_grid_from_delta(initial_delta, vec![delta_1, delta_2, delta_3, delta_4, delta_5]);
+        assert_grid_equal(
+            &grid,
+            &grid_from_delta,
+            r#"{"field":{"id":"f1","name":"😁 Name","field_type":"Text"}}
+{"row":{"id":"r1","cells":[]}}
+"#,
+        );
+    }
+
+    #[test]
+    fn grid_update_cells_keeps_row_order() {
+        let (mut grid, _, field) = test_grid();
+        let _ = grid.create_row(test_row("r1")).unwrap().unwrap();
+        let _ = grid.create_row(test_row("r2")).unwrap().unwrap();
+        let _ = grid
+            .update_cells("r1", vec![test_cell(&field.id, "hello")])
+            .unwrap()
+            .unwrap();
+        let row_ids = grid
+            .grid("g")
+            .rows
+            .into_iter()
+            .map(|row| row.id)
+            .collect::<Vec<String>>();
+        assert_eq!(row_ids, vec!["r1", "r2"]);
+    }
+
+    #[test]
+    fn grid_concurrent_create_rows() {
+        let (base, _, _) = test_grid();
+        let mut grid_a = base.clone();
+        let mut grid_b = base.clone();
+        let change_a = grid_a.create_row(test_row("r1")).unwrap().unwrap();
+        let change_b = grid_b.create_row(test_row("r2")).unwrap().unwrap();
+
+        let (a_prime, b_prime) = change_a.delta.transform(&change_b.delta).unwrap();
+        let _ = grid_a.compose_remote_delta(b_prime).unwrap();
+        let _ = grid_b.compose_remote_delta(a_prime).unwrap();
+
+        assert_eq!(grid_a.delta().apply("").unwrap(), grid_b.delta().apply("").unwrap());
+        assert_eq!(grid_a.grid("g"), grid_b.grid("g"));
+        assert_eq!(grid_a.grid("g").rows.len(), 2);
+        assert!(grid_a.records.iter().all(|record| record.is_some()));
+    }
+
+    #[test]
+    fn grid_concurrent_update_cells_of_same_row() {
+        let (mut base, _, field) = test_grid();
+        let _ = base.create_row(test_row("r1")).unwrap().unwrap();
+        let mut grid_a = base.clone();
+        let mut grid_b = base.clone();
+        let change_a = grid_a
+            .update_cells("r1", vec![test_cell(&field.id, "a")])
+            .unwrap()
+            .unwrap();
+        let change_b = grid_b.delete_row("r1").unwrap().unwrap();
+
+        let (a_prime, b_prime) = change_a.delta.transform(&change_b.delta).unwrap();
+        let _ = grid_a.compose_remote_delta(b_prime).unwrap();
+        let _ = grid_b.compose_remote_delta(a_prime).unwrap();
+
+        // Both sides converge and every line is still a valid record.
+        assert_eq!(grid_a.delta().apply("").unwrap(), grid_b.delta().apply("").unwrap());
+        assert_eq!(grid_a.grid("g"), grid_b.grid("g"));
+        assert!(grid_a.records.iter().all(|record| record.is_some()));
+    }
+
+    fn test_grid() -> (GridPad, GridDelta, Field) {
+        let mut grid = GridPad::default();
+        let mut delta = grid.delta().clone();
+
+        let field = Field {
+            id: "f1".to_owned(),
+            name: "😁 Name".to_owned(),
+            field_type: FieldType::Text,
+            options: vec![],
+        };
+        delta = delta
+            .compose(&grid.create_field(field.clone()).unwrap().unwrap().delta)
+            .unwrap();
+
+        (grid, delta, field)
+    }
+
+    fn select_field() -> Field {
+        Field {
+            id: "f2".to_owned(),
+            name: "Status".to_owned(),
+            field_type: FieldType::SingleSelect,
+            options: vec!["Todo".to_owned(), "Done".to_owned()],
+        }
+    }
+
+    fn test_row(row_id: &str) -> Row {
+        Row {
+            id: row_id.to_owned(),
+            cells: vec![],
+        }
+    }
+
+    fn test_cell(field_id: &str, data: &str) -> Cell {
+        Cell {
+            field_id: field_id.to_owned(),
+            data: data.to_owned(),
+        }
+    }
+
+    fn make_grid_from_delta(mut initial_delta: GridDelta, deltas: Vec<PlainDelta>) -> GridPad {
+        for delta in deltas {
+            initial_delta = initial_delta.compose(&delta).unwrap();
+        }
+        GridPad::from_delta(initial_delta).unwrap()
+    }
+
+    fn assert_grid_equal(old: &GridPad, new: &GridPad, expected: &str) {
+        assert_eq!(old, new);
+        assert_eq!(old.delta().apply("").unwrap(), expected);
+        assert_eq!(new.delta().apply("").unwrap(), expected);
+    }
+}
@@ -0,0 +1,4 @@
+mod builder;
+mod grid_pad;
+
+pub use grid_pad::*;
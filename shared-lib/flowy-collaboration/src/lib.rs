@@ -1,10 +1,17 @@
+pub mod board;
 pub mod client_document;
+pub mod comment;
 pub mod entities;
 pub mod errors;
 pub mod folder;
+pub mod grid;
 pub mod protobuf;
+pub mod server_board;
+pub mod server_comment;
 pub mod server_document;
 pub mod server_folder;
+pub mod server_grid;
+pub mod server_object;
 pub mod synchronizer;
 pub mod util;
 
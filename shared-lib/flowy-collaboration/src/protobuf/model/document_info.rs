@@ -246,6 +246,7 @@ pub struct DocumentInfo {
     pub text: ::std::string::String,
     pub rev_id: i64,
     pub base_rev_id: i64,
+    pub encrypted_revisions: ::protobuf::SingularPtrField<super::revision::RepeatedRevision>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -343,10 +344,48 @@ impl DocumentInfo {
     pub fn set_base_rev_id(&mut self, v: i64) {
         self.base_rev_id = v;
     }
+
+    // .RepeatedRevision encrypted_revisions = 5;
+
+
+    pub fn get_encrypted_revisions(&self) -> &super::revision::RepeatedRevision {
+        self.encrypted_revisions.as_ref().unwrap_or_else(|| <super::revision::RepeatedRevision as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_encrypted_revisions(&mut self) {
+        self.encrypted_revisions.clear();
+    }
+
+    pub fn has_encrypted_revisions(&self) -> bool {
+        self.encrypted_revisions.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_encrypted_revisions(&mut self, v: super::revision::RepeatedRevision) {
+        self.encrypted_revisions = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_encrypted_revisions(&mut self) -> &mut super::revision::RepeatedRevision {
+        if self.encrypted_revisions.is_none() {
+            self.encrypted_revisions.set_default();
+        }
+        self.encrypted_revisions.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_encrypted_revisions(&mut self) -> super::revision::RepeatedRevision {
+        self.encrypted_revisions.take().unwrap_or_else(|| super::revision::RepeatedRevision::new())
+    }
 }
 
 impl ::protobuf::Message for DocumentInfo {
     fn is_initialized(&self) -> bool {
+        for v in &self.encrypted_revisions {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -374,6 +413,9 @@ impl ::protobuf::Message for DocumentInfo {
                     let tmp = is.read_int64()?;
                     self.base_rev_id = tmp;
                 },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.encrypted_revisions)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -398,6 +440,10 @@ impl ::protobuf::Message for DocumentInfo {
         if self.base_rev_id != 0 {
             my_size += ::protobuf::rt::value_size(4, self.base_rev_id, ::protobuf::wire_format::WireTypeVarint);
         }
+        if let Some(ref v) = self.encrypted_revisions.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -416,6 +462,11 @@ impl ::protobuf::Message for DocumentInfo {
         if self.base_rev_id != 0 {
             os.write_int64(4, self.base_rev_id)?;
         }
+        if let Some(ref v) = self.encrypted_revisions.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -474,6 +525,11 @@ impl ::protobuf::Message for DocumentInfo {
                 |m: &DocumentInfo| { &m.base_rev_id },
                 |m: &mut DocumentInfo| { &mut m.base_rev_id },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::revision::RepeatedRevision>>(
+                "encrypted_revisions",
+                |m: &DocumentInfo| { &m.encrypted_revisions },
+                |m: &mut DocumentInfo| { &mut m.encrypted_revisions },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocumentInfo>(
                 "DocumentInfo",
                 fields,
@@ -494,6 +550,7 @@ impl ::protobuf::Clear for DocumentInfo {
         self.text.clear();
         self.rev_id = 0;
         self.base_rev_id = 0;
+        self.encrypted_revisions.clear();
         self.unknown_fields.clear();
     }
 }
@@ -1325,64 +1382,68 @@ impl ::protobuf::reflect::ProtobufValue for DocumentId {
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x13document_info.proto\x1a\x0erevision.proto\"R\n\x0fCreateDocParams\
     \x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12/\n\trevisions\x18\x02\x20\
-    \x01(\x0b2\x11.RepeatedRevisionR\trevisions\"p\n\x0cDocumentInfo\x12\x15\
-    \n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12\x12\n\x04text\x18\x02\x20\
-    \x01(\tR\x04text\x12\x15\n\x06rev_id\x18\x03\x20\x01(\x03R\x05revId\x12\
-    \x1e\n\x0bbase_rev_id\x18\x04\x20\x01(\x03R\tbaseRevId\"]\n\x13ResetDocu\
-    mentParams\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12/\n\trevi\
-    sions\x18\x02\x20\x01(\x0b2\x11.RepeatedRevisionR\trevisions\"E\n\rDocum\
-    entDelta\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12\x1d\n\ndel\
-    ta_json\x18\x02\x20\x01(\tR\tdeltaJson\"S\n\nNewDocUser\x12\x17\n\x07use\
-    r_id\x18\x01\x20\x01(\tR\x06userId\x12\x15\n\x06rev_id\x18\x02\x20\x01(\
-    \x03R\x05revId\x12\x15\n\x06doc_id\x18\x03\x20\x01(\tR\x05docId\"#\n\nDo\
-    cumentId\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docIdJ\xaf\x07\n\x06\
-    \x12\x04\0\0\x1c\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\
-    \x03\x01\0\x18\n\n\n\x02\x04\0\x12\x04\x03\0\x06\x01\n\n\n\x03\x04\0\x01\
-    \x12\x03\x03\x08\x17\n\x0b\n\x04\x04\0\x02\0\x12\x03\x04\x04\x12\n\x0c\n\
-    \x05\x04\0\x02\0\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\
-    \x03\x04\x0b\r\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x04\x10\x11\n\x0b\n\
-    \x04\x04\0\x02\x01\x12\x03\x05\x04#\n\x0c\n\x05\x04\0\x02\x01\x06\x12\
-    \x03\x05\x04\x14\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x05\x15\x1e\n\x0c\
-    \n\x05\x04\0\x02\x01\x03\x12\x03\x05!\"\n\n\n\x02\x04\x01\x12\x04\x07\0\
-    \x0c\x01\n\n\n\x03\x04\x01\x01\x12\x03\x07\x08\x14\n\x0b\n\x04\x04\x01\
-    \x02\0\x12\x03\x08\x04\x16\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x08\x04\
-    \n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x08\x0b\x11\n\x0c\n\x05\x04\x01\
-    \x02\0\x03\x12\x03\x08\x14\x15\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\t\x04\
-    \x14\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\t\x04\n\n\x0c\n\x05\x04\x01\
-    \x02\x01\x01\x12\x03\t\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\t\
-    \x12\x13\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\n\x04\x15\n\x0c\n\x05\x04\
-    \x01\x02\x02\x05\x12\x03\n\x04\t\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\
-    \n\n\x10\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\n\x13\x14\n\x0b\n\x04\
-    \x04\x01\x02\x03\x12\x03\x0b\x04\x1a\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\
-    \x03\x0b\x04\t\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x0b\n\x15\n\x0c\n\
-    \x05\x04\x01\x02\x03\x03\x12\x03\x0b\x18\x19\n\n\n\x02\x04\x02\x12\x04\r\
-    \0\x10\x01\n\n\n\x03\x04\x02\x01\x12\x03\r\x08\x1b\n\x0b\n\x04\x04\x02\
-    \x02\0\x12\x03\x0e\x04\x16\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0e\x04\
-    \n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0e\x0b\x11\n\x0c\n\x05\x04\x02\
-    \x02\0\x03\x12\x03\x0e\x14\x15\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0f\
-    \x04#\n\x0c\n\x05\x04\x02\x02\x01\x06\x12\x03\x0f\x04\x14\n\x0c\n\x05\
-    \x04\x02\x02\x01\x01\x12\x03\x0f\x15\x1e\n\x0c\n\x05\x04\x02\x02\x01\x03\
-    \x12\x03\x0f!\"\n\n\n\x02\x04\x03\x12\x04\x11\0\x14\x01\n\n\n\x03\x04\
-    \x03\x01\x12\x03\x11\x08\x15\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x12\x04\
-    \x16\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\x03\
-    \x02\0\x01\x12\x03\x12\x0b\x11\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x12\
-    \x14\x15\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x13\x04\x1a\n\x0c\n\x05\x04\
-    \x03\x02\x01\x05\x12\x03\x13\x04\n\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\
-    \x03\x13\x0b\x15\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x13\x18\x19\n\n\
-    \n\x02\x04\x04\x12\x04\x15\0\x19\x01\n\n\n\x03\x04\x04\x01\x12\x03\x15\
-    \x08\x12\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x16\x04\x17\n\x0c\n\x05\x04\
-    \x04\x02\0\x05\x12\x03\x16\x04\n\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\
-    \x16\x0b\x12\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x16\x15\x16\n\x0b\n\
-    \x04\x04\x04\x02\x01\x12\x03\x17\x04\x15\n\x0c\n\x05\x04\x04\x02\x01\x05\
-    \x12\x03\x17\x04\t\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x17\n\x10\n\
-    \x0c\n\x05\x04\x04\x02\x01\x03\x12\x03\x17\x13\x14\n\x0b\n\x04\x04\x04\
-    \x02\x02\x12\x03\x18\x04\x16\n\x0c\n\x05\x04\x04\x02\x02\x05\x12\x03\x18\
-    \x04\n\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03\x18\x0b\x11\n\x0c\n\x05\
-    \x04\x04\x02\x02\x03\x12\x03\x18\x14\x15\n\n\n\x02\x04\x05\x12\x04\x1a\0\
-    \x1c\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1a\x08\x12\n\x0b\n\x04\x04\x05\
-    \x02\0\x12\x03\x1b\x04\x16\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03\x1b\x04\
-    \n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03\x1b\x0b\x11\n\x0c\n\x05\x04\x05\
-    \x02\0\x03\x12\x03\x1b\x14\x15b\x06proto3\
+    \x01(\x0b2\x11.RepeatedRevisionR\trevisions\"\xb4\x01\n\x0cDocumentInfo\
+    \x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12\x12\n\x04text\x18\
+    \x02\x20\x01(\tR\x04text\x12\x15\n\x06rev_id\x18\x03\x20\x01(\x03R\x05re\
+    vId\x12\x1e\n\x0bbase_rev_id\x18\x04\x20\x01(\x03R\tbaseRevId\x12B\n\x13\
+    encrypted_revisions\x18\x05\x20\x01(\x0b2\x11.RepeatedRevisionR\x12encry\
+    ptedRevisions\"]\n\x13ResetDocumentParams\x12\x15\n\x06doc_id\x18\x01\
+    \x20\x01(\tR\x05docId\x12/\n\trevisions\x18\x02\x20\x01(\x0b2\x11.Repeat\
+    edRevisionR\trevisions\"E\n\rDocumentDelta\x12\x15\n\x06doc_id\x18\x01\
+    \x20\x01(\tR\x05docId\x12\x1d\n\ndelta_json\x18\x02\x20\x01(\tR\tdeltaJs\
+    on\"S\n\nNewDocUser\x12\x17\n\x07user_id\x18\x01\x20\x01(\tR\x06userId\
+    \x12\x15\n\x06rev_id\x18\x02\x20\x01(\x03R\x05revId\x12\x15\n\x06doc_id\
+    \x18\x03\x20\x01(\tR\x05docId\"#\n\nDocumentId\x12\x15\n\x06doc_id\x18\
+    \x01\x20\x01(\tR\x05docIdJ\xe6\x07\n\x06\x12\x04\0\0\x1d\x01\n\x08\n\x01\
+    \x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\x18\n\n\n\x02\x04\0\
+    \x12\x04\x03\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\x17\n\x0b\n\
+    \x04\x04\0\x02\0\x12\x03\x04\x04\x12\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\
+    \x04\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x0b\r\n\x0c\n\x05\x04\
+    \0\x02\0\x03\x12\x03\x04\x10\x11\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x05\
+    \x04#\n\x0c\n\x05\x04\0\x02\x01\x06\x12\x03\x05\x04\x14\n\x0c\n\x05\x04\
+    \0\x02\x01\x01\x12\x03\x05\x15\x1e\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\
+    \x05!\"\n\n\n\x02\x04\x01\x12\x04\x07\0\r\x01\n\n\n\x03\x04\x01\x01\x12\
+    \x03\x07\x08\x14\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x08\x04\x16\n\x0c\n\
+    \x05\x04\x01\x02\0\x05\x12\x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\
+    \x12\x03\x08\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x08\x14\x15\n\
+    \x0b\n\x04\x04\x01\x02\x01\x12\x03\t\x04\x14\n\x0c\n\x05\x04\x01\x02\x01\
+    \x05\x12\x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\t\x0b\x0f\n\
+    \x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\t\x12\x13\n\x0b\n\x04\x04\x01\x02\
+    \x02\x12\x03\n\x04\x15\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\n\x04\t\n\
+    \x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\n\n\x10\n\x0c\n\x05\x04\x01\x02\
+    \x02\x03\x12\x03\n\x13\x14\n\x0b\n\x04\x04\x01\x02\x03\x12\x03\x0b\x04\
+    \x1a\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\x0b\x04\t\n\x0c\n\x05\x04\
+    \x01\x02\x03\x01\x12\x03\x0b\n\x15\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\
+    \x03\x0b\x18\x19\n\x0b\n\x04\x04\x01\x02\x04\x12\x03\x0c\x04-\n\x0c\n\
+    \x05\x04\x01\x02\x04\x06\x12\x03\x0c\x04\x14\n\x0c\n\x05\x04\x01\x02\x04\
+    \x01\x12\x03\x0c\x15(\n\x0c\n\x05\x04\x01\x02\x04\x03\x12\x03\x0c+,\n\n\
+    \n\x02\x04\x02\x12\x04\x0e\0\x11\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0e\
+    \x08\x1b\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x0f\x04\x16\n\x0c\n\x05\x04\
+    \x02\x02\0\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\
+    \x0f\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x0f\x14\x15\n\x0b\n\
+    \x04\x04\x02\x02\x01\x12\x03\x10\x04#\n\x0c\n\x05\x04\x02\x02\x01\x06\
+    \x12\x03\x10\x04\x14\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x10\x15\x1e\
+    \n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x10!\"\n\n\n\x02\x04\x03\x12\
+    \x04\x12\0\x15\x01\n\n\n\x03\x04\x03\x01\x12\x03\x12\x08\x15\n\x0b\n\x04\
+    \x04\x03\x02\0\x12\x03\x13\x04\x16\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\
+    \x13\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x13\x0b\x11\n\x0c\n\x05\
+    \x04\x03\x02\0\x03\x12\x03\x13\x14\x15\n\x0b\n\x04\x04\x03\x02\x01\x12\
+    \x03\x14\x04\x1a\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x14\x04\n\n\x0c\
+    \n\x05\x04\x03\x02\x01\x01\x12\x03\x14\x0b\x15\n\x0c\n\x05\x04\x03\x02\
+    \x01\x03\x12\x03\x14\x18\x19\n\n\n\x02\x04\x04\x12\x04\x16\0\x1a\x01\n\n\
+    \n\x03\x04\x04\x01\x12\x03\x16\x08\x12\n\x0b\n\x04\x04\x04\x02\0\x12\x03\
+    \x17\x04\x17\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\x17\x04\n\n\x0c\n\x05\
+    \x04\x04\x02\0\x01\x12\x03\x17\x0b\x12\n\x0c\n\x05\x04\x04\x02\0\x03\x12\
+    \x03\x17\x15\x16\n\x0b\n\x04\x04\x04\x02\x01\x12\x03\x18\x04\x15\n\x0c\n\
+    \x05\x04\x04\x02\x01\x05\x12\x03\x18\x04\t\n\x0c\n\x05\x04\x04\x02\x01\
+    \x01\x12\x03\x18\n\x10\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03\x18\x13\
+    \x14\n\x0b\n\x04\x04\x04\x02\x02\x12\x03\x19\x04\x16\n\x0c\n\x05\x04\x04\
+    \x02\x02\x05\x12\x03\x19\x04\n\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03\
+    \x19\x0b\x11\n\x0c\n\x05\x04\x04\x02\x02\x03\x12\x03\x19\x14\x15\n\n\n\
+    \x02\x04\x05\x12\x04\x1b\0\x1d\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1b\x08\
+    \x12\n\x0b\n\x04\x04\x05\x02\0\x12\x03\x1c\x04\x16\n\x0c\n\x05\x04\x05\
+    \x02\0\x05\x12\x03\x1c\x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03\x1c\
+    \x0b\x11\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03\x1c\x14\x15b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,1011 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `integrity.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocumentIntegrityRequest {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub repair: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocumentIntegrityRequest {
+    fn default() -> &'a DocumentIntegrityRequest {
+        <DocumentIntegrityRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocumentIntegrityRequest {
+    pub fn new() -> DocumentIntegrityRequest {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // bool repair = 2;
+
+
+    pub fn get_repair(&self) -> bool {
+        self.repair
+    }
+    pub fn clear_repair(&mut self) {
+        self.repair = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_repair(&mut self, v: bool) {
+        self.repair = v;
+    }
+}
+
+impl ::protobuf::Message for DocumentIntegrityRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.repair = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if self.repair != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if self.repair != false {
+            os.write_bool(2, self.repair)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocumentIntegrityRequest {
+        DocumentIntegrityRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &DocumentIntegrityRequest| { &m.doc_id },
+                |m: &mut DocumentIntegrityRequest| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "repair",
+                |m: &DocumentIntegrityRequest| { &m.repair },
+                |m: &mut DocumentIntegrityRequest| { &mut m.repair },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocumentIntegrityRequest>(
+                "DocumentIntegrityRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocumentIntegrityRequest {
+        static instance: ::protobuf::rt::LazyV2<DocumentIntegrityRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocumentIntegrityRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for DocumentIntegrityRequest {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.repair = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocumentIntegrityRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocumentIntegrityRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocumentIntegrityReport {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub is_valid: bool,
+    pub revision_count: i64,
+    pub pending_count: i64,
+    pub last_rev_id: i64,
+    pub missing_ranges: ::protobuf::RepeatedField<super::revision::RevisionRange>,
+    pub invalid_ranges: ::protobuf::RepeatedField<super::revision::RevisionRange>,
+    pub local_md5: ::std::string::String,
+    pub server_rev_id: i64,
+    pub server_md5: ::std::string::String,
+    pub repaired: bool,
+    // message oneof groups
+    pub one_of_compose_error: ::std::option::Option<DocumentIntegrityReport_oneof_one_of_compose_error>,
+    pub one_of_server_error: ::std::option::Option<DocumentIntegrityReport_oneof_one_of_server_error>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocumentIntegrityReport {
+    fn default() -> &'a DocumentIntegrityReport {
+        <DocumentIntegrityReport as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum DocumentIntegrityReport_oneof_one_of_compose_error {
+    compose_error(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum DocumentIntegrityReport_oneof_one_of_server_error {
+    server_error(::std::string::String),
+}
+
+impl DocumentIntegrityReport {
+    pub fn new() -> DocumentIntegrityReport {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // bool is_valid = 2;
+
+
+    pub fn get_is_valid(&self) -> bool {
+        self.is_valid
+    }
+    pub fn clear_is_valid(&mut self) {
+        self.is_valid = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_valid(&mut self, v: bool) {
+        self.is_valid = v;
+    }
+
+    // int64 revision_count = 3;
+
+
+    pub fn get_revision_count(&self) -> i64 {
+        self.revision_count
+    }
+    pub fn clear_revision_count(&mut self) {
+        self.revision_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision_count(&mut self, v: i64) {
+        self.revision_count = v;
+    }
+
+    // int64 pending_count = 4;
+
+
+    pub fn get_pending_count(&self) -> i64 {
+        self.pending_count
+    }
+    pub fn clear_pending_count(&mut self) {
+        self.pending_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pending_count(&mut self, v: i64) {
+        self.pending_count = v;
+    }
+
+    // int64 last_rev_id = 5;
+
+
+    pub fn get_last_rev_id(&self) -> i64 {
+        self.last_rev_id
+    }
+    pub fn clear_last_rev_id(&mut self) {
+        self.last_rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_rev_id(&mut self, v: i64) {
+        self.last_rev_id = v;
+    }
+
+    // repeated .RevisionRange missing_ranges = 6;
+
+
+    pub fn get_missing_ranges(&self) -> &[super::revision::RevisionRange] {
+        &self.missing_ranges
+    }
+    pub fn clear_missing_ranges(&mut self) {
+        self.missing_ranges.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_missing_ranges(&mut self, v: ::protobuf::RepeatedField<super::revision::RevisionRange>) {
+        self.missing_ranges = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_missing_ranges(&mut self) -> &mut ::protobuf::RepeatedField<super::revision::RevisionRange> {
+        &mut self.missing_ranges
+    }
+
+    // Take field
+    pub fn take_missing_ranges(&mut self) -> ::protobuf::RepeatedField<super::revision::RevisionRange> {
+        ::std::mem::replace(&mut self.missing_ranges, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .RevisionRange invalid_ranges = 7;
+
+
+    pub fn get_invalid_ranges(&self) -> &[super::revision::RevisionRange] {
+        &self.invalid_ranges
+    }
+    pub fn clear_invalid_ranges(&mut self) {
+        self.invalid_ranges.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invalid_ranges(&mut self, v: ::protobuf::RepeatedField<super::revision::RevisionRange>) {
+        self.invalid_ranges = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_invalid_ranges(&mut self) -> &mut ::protobuf::RepeatedField<super::revision::RevisionRange> {
+        &mut self.invalid_ranges
+    }
+
+    // Take field
+    pub fn take_invalid_ranges(&mut self) -> ::protobuf::RepeatedField<super::revision::RevisionRange> {
+        ::std::mem::replace(&mut self.invalid_ranges, ::protobuf::RepeatedField::new())
+    }
+
+    // string compose_error = 8;
+
+
+    pub fn get_compose_error(&self) -> &str {
+        match self.one_of_compose_error {
+            ::std::option::Option::Some(DocumentIntegrityReport_oneof_one_of_compose_error::compose_error(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_compose_error(&mut self) {
+        self.one_of_compose_error = ::std::option::Option::None;
+    }
+
+    pub fn has_compose_error(&self) -> bool {
+        match self.one_of_compose_error {
+            ::std::option::Option::Some(DocumentIntegrityReport_oneof_one_of_compose_error::compose_error(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compose_error(&mut self, v: ::std::string::String) {
+        self.one_of_compose_error = ::std::option::Option::Some(DocumentIntegrityReport_oneof_one_of_compose_error::compose_error(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_compose_error(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(DocumentIntegrityReport_oneof_one_of_compose_error::compose_error(_)) = self.one_of_compose_error {
+        } else {
+            self.one_of_compose_error = ::std::option::Option::Some(DocumentIntegrityReport_oneof_one_of_compose_error::compose_error(::std::string::String::new()));
+        }
+        match self.one_of_compose_error {
+            ::std::option::Option::Some(DocumentIntegrityReport_oneof_one_of_compose_error::compose_error(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_compose_error(&mut self) -> ::std::string::String {
+        if self.has_compose_error() {
+            match self.one_of_compose_error.take() {
+                ::std::option::Option::Some(DocumentIntegrityReport_oneof_one_of_compose_error::compose_error(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string local_md5 = 9;
+
+
+    pub fn get_local_md5(&self) -> &str {
+        &self.local_md5
+    }
+    pub fn clear_local_md5(&mut self) {
+        self.local_md5.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_local_md5(&mut self, v: ::std::string::String) {
+        self.local_md5 = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_local_md5(&mut self) -> &mut ::std::string::String {
+        &mut self.local_md5
+    }
+
+    // Take field
+    pub fn take_local_md5(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.local_md5, ::std::string::String::new())
+    }
+
+    // int64 server_rev_id = 10;
+
+
+    pub fn get_server_rev_id(&self) -> i64 {
+        self.server_rev_id
+    }
+    pub fn clear_server_rev_id(&mut self) {
+        self.server_rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_server_rev_id(&mut self, v: i64) {
+        self.server_rev_id = v;
+    }
+
+    // string server_md5 = 11;
+
+
+    pub fn get_server_md5(&self) -> &str {
+        &self.server_md5
+    }
+    pub fn clear_server_md5(&mut self) {
+        self.server_md5.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_server_md5(&mut self, v: ::std::string::String) {
+        self.server_md5 = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_server_md5(&mut self) -> &mut ::std::string::String {
+        &mut self.server_md5
+    }
+
+    // Take field
+    pub fn take_server_md5(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.server_md5, ::std::string::String::new())
+    }
+
+    // string server_error = 12;
+
+
+    pub fn get_server_error(&self) -> &str {
+        match self.one_of_server_error {
+            ::std::option::Option::Some(DocumentIntegrityReport_oneof_one_of_server_error::server_error(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_server_error(&mut self) {
+        self.one_of_server_error = ::std::option::Option::None;
+    }
+
+    pub fn has_server_error(&self) -> bool {
+        match self.one_of_server_error {
+            ::std::option::Option::Some(DocumentIntegrityReport_oneof_one_of_server_error::server_error(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_server_error(&mut self, v: ::std::string::String) {
+        self.one_of_server_error = ::std::option::Option::Some(DocumentIntegrityReport_oneof_one_of_server_error::server_error(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_server_error(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(DocumentIntegrityReport_oneof_one_of_server_error::server_error(_)) = self.one_of_server_error {
+        } else {
+            self.one_of_server_error = ::std::option::Option::Some(DocumentIntegrityReport_oneof_one_of_server_error::server_error(::std::string::String::new()));
+        }
+        match self.one_of_server_error {
+            ::std::option::Option::Some(DocumentIntegrityReport_oneof_one_of_server_error::server_error(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_server_error(&mut self) -> ::std::string::String {
+        if self.has_server_error() {
+            match self.one_of_server_error.take() {
+                ::std::option::Option::Some(DocumentIntegrityReport_oneof_one_of_server_error::server_error(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // bool repaired = 13;
+
+
+    pub fn get_repaired(&self) -> bool {
+        self.repaired
+    }
+    pub fn clear_repaired(&mut self) {
+        self.repaired = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_repaired(&mut self, v: bool) {
+        self.repaired = v;
+    }
+}
+
+impl ::protobuf::Message for DocumentIntegrityReport {
+    fn is_initialized(&self) -> bool {
+        for v in &self.missing_ranges {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.invalid_ranges {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_valid = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.revision_count = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.pending_count = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.last_rev_id = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.missing_ranges)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.invalid_ranges)?;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_compose_error = ::std::option::Option::Some(DocumentIntegrityReport_oneof_one_of_compose_error::compose_error(is.read_string()?));
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.local_md5)?;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.server_rev_id = tmp;
+                },
+                11 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.server_md5)?;
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_server_error = ::std::option::Option::Some(DocumentIntegrityReport_oneof_one_of_server_error::server_error(is.read_string()?));
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.repaired = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if self.is_valid != false {
+            my_size += 2;
+        }
+        if self.revision_count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.revision_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.pending_count != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.pending_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.last_rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.last_rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.missing_ranges {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.invalid_ranges {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.local_md5.is_empty() {
+            my_size += ::protobuf::rt::string_size(9, &self.local_md5);
+        }
+        if self.server_rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.server_rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.server_md5.is_empty() {
+            my_size += ::protobuf::rt::string_size(11, &self.server_md5);
+        }
+        if self.repaired != false {
+            my_size += 2;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_compose_error {
+            match v {
+                &DocumentIntegrityReport_oneof_one_of_compose_error::compose_error(ref v) => {
+                    my_size += ::protobuf::rt::string_size(8, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_server_error {
+            match v {
+                &DocumentIntegrityReport_oneof_one_of_server_error::server_error(ref v) => {
+                    my_size += ::protobuf::rt::string_size(12, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if self.is_valid != false {
+            os.write_bool(2, self.is_valid)?;
+        }
+        if self.revision_count != 0 {
+            os.write_int64(3, self.revision_count)?;
+        }
+        if self.pending_count != 0 {
+            os.write_int64(4, self.pending_count)?;
+        }
+        if self.last_rev_id != 0 {
+            os.write_int64(5, self.last_rev_id)?;
+        }
+        for v in &self.missing_ranges {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.invalid_ranges {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.local_md5.is_empty() {
+            os.write_string(9, &self.local_md5)?;
+        }
+        if self.server_rev_id != 0 {
+            os.write_int64(10, self.server_rev_id)?;
+        }
+        if !self.server_md5.is_empty() {
+            os.write_string(11, &self.server_md5)?;
+        }
+        if self.repaired != false {
+            os.write_bool(13, self.repaired)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_compose_error {
+            match v {
+                &DocumentIntegrityReport_oneof_one_of_compose_error::compose_error(ref v) => {
+                    os.write_string(8, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_server_error {
+            match v {
+                &DocumentIntegrityReport_oneof_one_of_server_error::server_error(ref v) => {
+                    os.write_string(12, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocumentIntegrityReport {
+        DocumentIntegrityReport::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &DocumentIntegrityReport| { &m.doc_id },
+                |m: &mut DocumentIntegrityReport| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_valid",
+                |m: &DocumentIntegrityReport| { &m.is_valid },
+                |m: &mut DocumentIntegrityReport| { &mut m.is_valid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "revision_count",
+                |m: &DocumentIntegrityReport| { &m.revision_count },
+                |m: &mut DocumentIntegrityReport| { &mut m.revision_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "pending_count",
+                |m: &DocumentIntegrityReport| { &m.pending_count },
+                |m: &mut DocumentIntegrityReport| { &mut m.pending_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "last_rev_id",
+                |m: &DocumentIntegrityReport| { &m.last_rev_id },
+                |m: &mut DocumentIntegrityReport| { &mut m.last_rev_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::revision::RevisionRange>>(
+                "missing_ranges",
+                |m: &DocumentIntegrityReport| { &m.missing_ranges },
+                |m: &mut DocumentIntegrityReport| { &mut m.missing_ranges },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::revision::RevisionRange>>(
+                "invalid_ranges",
+                |m: &DocumentIntegrityReport| { &m.invalid_ranges },
+                |m: &mut DocumentIntegrityReport| { &mut m.invalid_ranges },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "compose_error",
+                DocumentIntegrityReport::has_compose_error,
+                DocumentIntegrityReport::get_compose_error,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "local_md5",
+                |m: &DocumentIntegrityReport| { &m.local_md5 },
+                |m: &mut DocumentIntegrityReport| { &mut m.local_md5 },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "server_rev_id",
+                |m: &DocumentIntegrityReport| { &m.server_rev_id },
+                |m: &mut DocumentIntegrityReport| { &mut m.server_rev_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "server_md5",
+                |m: &DocumentIntegrityReport| { &m.server_md5 },
+                |m: &mut DocumentIntegrityReport| { &mut m.server_md5 },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "server_error",
+                DocumentIntegrityReport::has_server_error,
+                DocumentIntegrityReport::get_server_error,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "repaired",
+                |m: &DocumentIntegrityReport| { &m.repaired },
+                |m: &mut DocumentIntegrityReport| { &mut m.repaired },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocumentIntegrityReport>(
+                "DocumentIntegrityReport",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocumentIntegrityReport {
+        static instance: ::protobuf::rt::LazyV2<DocumentIntegrityReport> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocumentIntegrityReport::new)
+    }
+}
+
+impl ::protobuf::Clear for DocumentIntegrityReport {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.is_valid = false;
+        self.revision_count = 0;
+        self.pending_count = 0;
+        self.last_rev_id = 0;
+        self.missing_ranges.clear();
+        self.invalid_ranges.clear();
+        self.one_of_compose_error = ::std::option::Option::None;
+        self.local_md5.clear();
+        self.server_rev_id = 0;
+        self.server_md5.clear();
+        self.one_of_server_error = ::std::option::Option::None;
+        self.repaired = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocumentIntegrityReport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocumentIntegrityReport {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0fintegrity.proto\x1a\x0erevision.proto\"I\n\x18DocumentIntegrityReq\
+    uest\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12\x16\n\x06repai\
+    r\x18\x02\x20\x01(\x08R\x06repair\"\x9c\x04\n\x17DocumentIntegrityReport\
+    \x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12\x19\n\x08is_valid\
+    \x18\x02\x20\x01(\x08R\x07isValid\x12%\n\x0erevision_count\x18\x03\x20\
+    \x01(\x03R\rrevisionCount\x12#\n\rpending_count\x18\x04\x20\x01(\x03R\
+    \x0cpendingCount\x12\x1e\n\x0blast_rev_id\x18\x05\x20\x01(\x03R\tlastRev\
+    Id\x125\n\x0emissing_ranges\x18\x06\x20\x03(\x0b2\x0e.RevisionRangeR\rmi\
+    ssingRanges\x125\n\x0einvalid_ranges\x18\x07\x20\x03(\x0b2\x0e.RevisionR\
+    angeR\rinvalidRanges\x12%\n\rcompose_error\x18\x08\x20\x01(\tH\0R\x0ccom\
+    poseError\x12\x1b\n\tlocal_md5\x18\t\x20\x01(\tR\x08localMd5\x12\"\n\rse\
+    rver_rev_id\x18\n\x20\x01(\x03R\x0bserverRevId\x12\x1d\n\nserver_md5\x18\
+    \x0b\x20\x01(\tR\tserverMd5\x12#\n\x0cserver_error\x18\x0c\x20\x01(\tH\
+    \x01R\x0bserverError\x12\x1a\n\x08repaired\x18\r\x20\x01(\x08R\x08repair\
+    edB\x16\n\x14one_of_compose_errorB\x15\n\x13one_of_server_errorJ\xd8\x07\
+    \n\x06\x12\x04\0\0\x15\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\
+    \0\x12\x03\x01\0\x18\n\n\n\x02\x04\0\x12\x04\x03\0\x06\x01\n\n\n\x03\x04\
+    \0\x01\x12\x03\x03\x08\x20\n\x0b\n\x04\x04\0\x02\0\x12\x03\x04\x04\x16\n\
+    \x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\
+    \x12\x03\x04\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x04\x14\x15\n\
+    \x0b\n\x04\x04\0\x02\x01\x12\x03\x05\x04\x14\n\x0c\n\x05\x04\0\x02\x01\
+    \x05\x12\x03\x05\x04\x08\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x05\t\x0f\
+    \n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x05\x12\x13\n\n\n\x02\x04\x01\x12\
+    \x04\x07\0\x15\x01\n\n\n\x03\x04\x01\x01\x12\x03\x07\x08\x1f\n\x0b\n\x04\
+    \x04\x01\x02\0\x12\x03\x08\x04\x16\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\
+    \x08\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x08\x0b\x11\n\x0c\n\x05\
+    \x04\x01\x02\0\x03\x12\x03\x08\x14\x15\n\x0b\n\x04\x04\x01\x02\x01\x12\
+    \x03\t\x04\x16\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\t\x04\x08\n\x0c\n\
+    \x05\x04\x01\x02\x01\x01\x12\x03\t\t\x11\n\x0c\n\x05\x04\x01\x02\x01\x03\
+    \x12\x03\t\x14\x15\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\n\x04\x1d\n\x0c\n\
+    \x05\x04\x01\x02\x02\x05\x12\x03\n\x04\t\n\x0c\n\x05\x04\x01\x02\x02\x01\
+    \x12\x03\n\n\x18\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\n\x1b\x1c\n\x0b\
+    \n\x04\x04\x01\x02\x03\x12\x03\x0b\x04\x1c\n\x0c\n\x05\x04\x01\x02\x03\
+    \x05\x12\x03\x0b\x04\t\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x0b\n\x17\
+    \n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\x0b\x1a\x1b\n\x0b\n\x04\x04\x01\
+    \x02\x04\x12\x03\x0c\x04\x1a\n\x0c\n\x05\x04\x01\x02\x04\x05\x12\x03\x0c\
+    \x04\t\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\x0c\n\x15\n\x0c\n\x05\x04\
+    \x01\x02\x04\x03\x12\x03\x0c\x18\x19\n\x0b\n\x04\x04\x01\x02\x05\x12\x03\
+    \r\x04.\n\x0c\n\x05\x04\x01\x02\x05\x04\x12\x03\r\x04\x0c\n\x0c\n\x05\
+    \x04\x01\x02\x05\x06\x12\x03\r\r\x1a\n\x0c\n\x05\x04\x01\x02\x05\x01\x12\
+    \x03\r\x1b)\n\x0c\n\x05\x04\x01\x02\x05\x03\x12\x03\r,-\n\x0b\n\x04\x04\
+    \x01\x02\x06\x12\x03\x0e\x04.\n\x0c\n\x05\x04\x01\x02\x06\x04\x12\x03\
+    \x0e\x04\x0c\n\x0c\n\x05\x04\x01\x02\x06\x06\x12\x03\x0e\r\x1a\n\x0c\n\
+    \x05\x04\x01\x02\x06\x01\x12\x03\x0e\x1b)\n\x0c\n\x05\x04\x01\x02\x06\
+    \x03\x12\x03\x0e,-\n\x0b\n\x04\x04\x01\x08\0\x12\x03\x0f\x04<\n\x0c\n\
+    \x05\x04\x01\x08\0\x01\x12\x03\x0f\n\x1e\n\x0b\n\x04\x04\x01\x02\x07\x12\
+    \x03\x0f!:\n\x0c\n\x05\x04\x01\x02\x07\x05\x12\x03\x0f!'\n\x0c\n\x05\x04\
+    \x01\x02\x07\x01\x12\x03\x0f(5\n\x0c\n\x05\x04\x01\x02\x07\x03\x12\x03\
+    \x0f89\n\x0b\n\x04\x04\x01\x02\x08\x12\x03\x10\x04\x19\n\x0c\n\x05\x04\
+    \x01\x02\x08\x05\x12\x03\x10\x04\n\n\x0c\n\x05\x04\x01\x02\x08\x01\x12\
+    \x03\x10\x0b\x14\n\x0c\n\x05\x04\x01\x02\x08\x03\x12\x03\x10\x17\x18\n\
+    \x0b\n\x04\x04\x01\x02\t\x12\x03\x11\x04\x1d\n\x0c\n\x05\x04\x01\x02\t\
+    \x05\x12\x03\x11\x04\t\n\x0c\n\x05\x04\x01\x02\t\x01\x12\x03\x11\n\x17\n\
+    \x0c\n\x05\x04\x01\x02\t\x03\x12\x03\x11\x1a\x1c\n\x0b\n\x04\x04\x01\x02\
+    \n\x12\x03\x12\x04\x1b\n\x0c\n\x05\x04\x01\x02\n\x05\x12\x03\x12\x04\n\n\
+    \x0c\n\x05\x04\x01\x02\n\x01\x12\x03\x12\x0b\x15\n\x0c\n\x05\x04\x01\x02\
+    \n\x03\x12\x03\x12\x18\x1a\n\x0b\n\x04\x04\x01\x08\x01\x12\x03\x13\x04;\
+    \n\x0c\n\x05\x04\x01\x08\x01\x01\x12\x03\x13\n\x1d\n\x0b\n\x04\x04\x01\
+    \x02\x0b\x12\x03\x13\x209\n\x0c\n\x05\x04\x01\x02\x0b\x05\x12\x03\x13\
+    \x20&\n\x0c\n\x05\x04\x01\x02\x0b\x01\x12\x03\x13'3\n\x0c\n\x05\x04\x01\
+    \x02\x0b\x03\x12\x03\x1368\n\x0b\n\x04\x04\x01\x02\x0c\x12\x03\x14\x04\
+    \x17\n\x0c\n\x05\x04\x01\x02\x0c\x05\x12\x03\x14\x04\x08\n\x0c\n\x05\x04\
+    \x01\x02\x0c\x01\x12\x03\x14\t\x11\n\x0c\n\x05\x04\x01\x02\x0c\x03\x12\
+    \x03\x14\x14\x16b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,779 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `merge_backup.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocumentMergeBackup {
+    // message fields
+    pub id: i64,
+    pub doc_id: ::std::string::String,
+    pub base_rev_id: i64,
+    pub local_revision_count: i64,
+    pub document_json: ::std::string::String,
+    pub create_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocumentMergeBackup {
+    fn default() -> &'a DocumentMergeBackup {
+        <DocumentMergeBackup as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocumentMergeBackup {
+    pub fn new() -> DocumentMergeBackup {
+        ::std::default::Default::default()
+    }
+
+    // int64 id = 1;
+
+
+    pub fn get_id(&self) -> i64 {
+        self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: i64) {
+        self.id = v;
+    }
+
+    // string doc_id = 2;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // int64 base_rev_id = 3;
+
+
+    pub fn get_base_rev_id(&self) -> i64 {
+        self.base_rev_id
+    }
+    pub fn clear_base_rev_id(&mut self) {
+        self.base_rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_base_rev_id(&mut self, v: i64) {
+        self.base_rev_id = v;
+    }
+
+    // int64 local_revision_count = 4;
+
+
+    pub fn get_local_revision_count(&self) -> i64 {
+        self.local_revision_count
+    }
+    pub fn clear_local_revision_count(&mut self) {
+        self.local_revision_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_local_revision_count(&mut self, v: i64) {
+        self.local_revision_count = v;
+    }
+
+    // string document_json = 5;
+
+
+    pub fn get_document_json(&self) -> &str {
+        &self.document_json
+    }
+    pub fn clear_document_json(&mut self) {
+        self.document_json.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_document_json(&mut self, v: ::std::string::String) {
+        self.document_json = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_document_json(&mut self) -> &mut ::std::string::String {
+        &mut self.document_json
+    }
+
+    // Take field
+    pub fn take_document_json(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.document_json, ::std::string::String::new())
+    }
+
+    // int64 create_time = 6;
+
+
+    pub fn get_create_time(&self) -> i64 {
+        self.create_time
+    }
+    pub fn clear_create_time(&mut self) {
+        self.create_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_time(&mut self, v: i64) {
+        self.create_time = v;
+    }
+}
+
+impl ::protobuf::Message for DocumentMergeBackup {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.base_rev_id = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.local_revision_count = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.document_json)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.create_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.doc_id);
+        }
+        if self.base_rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.base_rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.local_revision_count != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.local_revision_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.document_json.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.document_json);
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.create_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_int64(1, self.id)?;
+        }
+        if !self.doc_id.is_empty() {
+            os.write_string(2, &self.doc_id)?;
+        }
+        if self.base_rev_id != 0 {
+            os.write_int64(3, self.base_rev_id)?;
+        }
+        if self.local_revision_count != 0 {
+            os.write_int64(4, self.local_revision_count)?;
+        }
+        if !self.document_json.is_empty() {
+            os.write_string(5, &self.document_json)?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(6, self.create_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocumentMergeBackup {
+        DocumentMergeBackup::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "id",
+                |m: &DocumentMergeBackup| { &m.id },
+                |m: &mut DocumentMergeBackup| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &DocumentMergeBackup| { &m.doc_id },
+                |m: &mut DocumentMergeBackup| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "base_rev_id",
+                |m: &DocumentMergeBackup| { &m.base_rev_id },
+                |m: &mut DocumentMergeBackup| { &mut m.base_rev_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "local_revision_count",
+                |m: &DocumentMergeBackup| { &m.local_revision_count },
+                |m: &mut DocumentMergeBackup| { &mut m.local_revision_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "document_json",
+                |m: &DocumentMergeBackup| { &m.document_json },
+                |m: &mut DocumentMergeBackup| { &mut m.document_json },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "create_time",
+                |m: &DocumentMergeBackup| { &m.create_time },
+                |m: &mut DocumentMergeBackup| { &mut m.create_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocumentMergeBackup>(
+                "DocumentMergeBackup",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocumentMergeBackup {
+        static instance: ::protobuf::rt::LazyV2<DocumentMergeBackup> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocumentMergeBackup::new)
+    }
+}
+
+impl ::protobuf::Clear for DocumentMergeBackup {
+    fn clear(&mut self) {
+        self.id = 0;
+        self.doc_id.clear();
+        self.base_rev_id = 0;
+        self.local_revision_count = 0;
+        self.document_json.clear();
+        self.create_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocumentMergeBackup {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocumentMergeBackup {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedDocumentMergeBackup {
+    // message fields
+    pub items: ::protobuf::RepeatedField<DocumentMergeBackup>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedDocumentMergeBackup {
+    fn default() -> &'a RepeatedDocumentMergeBackup {
+        <RepeatedDocumentMergeBackup as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedDocumentMergeBackup {
+    pub fn new() -> RepeatedDocumentMergeBackup {
+        ::std::default::Default::default()
+    }
+
+    // repeated .DocumentMergeBackup items = 1;
+
+
+    pub fn get_items(&self) -> &[DocumentMergeBackup] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<DocumentMergeBackup>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<DocumentMergeBackup> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<DocumentMergeBackup> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedDocumentMergeBackup {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedDocumentMergeBackup {
+        RepeatedDocumentMergeBackup::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DocumentMergeBackup>>(
+                "items",
+                |m: &RepeatedDocumentMergeBackup| { &m.items },
+                |m: &mut RepeatedDocumentMergeBackup| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedDocumentMergeBackup>(
+                "RepeatedDocumentMergeBackup",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedDocumentMergeBackup {
+        static instance: ::protobuf::rt::LazyV2<RepeatedDocumentMergeBackup> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedDocumentMergeBackup::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedDocumentMergeBackup {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedDocumentMergeBackup {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedDocumentMergeBackup {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocumentMergeBackupId {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub backup_id: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocumentMergeBackupId {
+    fn default() -> &'a DocumentMergeBackupId {
+        <DocumentMergeBackupId as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocumentMergeBackupId {
+    pub fn new() -> DocumentMergeBackupId {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // int64 backup_id = 2;
+
+
+    pub fn get_backup_id(&self) -> i64 {
+        self.backup_id
+    }
+    pub fn clear_backup_id(&mut self) {
+        self.backup_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_backup_id(&mut self, v: i64) {
+        self.backup_id = v;
+    }
+}
+
+impl ::protobuf::Message for DocumentMergeBackupId {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.backup_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if self.backup_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.backup_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if self.backup_id != 0 {
+            os.write_int64(2, self.backup_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocumentMergeBackupId {
+        DocumentMergeBackupId::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &DocumentMergeBackupId| { &m.doc_id },
+                |m: &mut DocumentMergeBackupId| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "backup_id",
+                |m: &DocumentMergeBackupId| { &m.backup_id },
+                |m: &mut DocumentMergeBackupId| { &mut m.backup_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocumentMergeBackupId>(
+                "DocumentMergeBackupId",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocumentMergeBackupId {
+        static instance: ::protobuf::rt::LazyV2<DocumentMergeBackupId> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocumentMergeBackupId::new)
+    }
+}
+
+impl ::protobuf::Clear for DocumentMergeBackupId {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.backup_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocumentMergeBackupId {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocumentMergeBackupId {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x12merge_backup.proto\"\xd4\x01\n\x13DocumentMergeBackup\x12\x0e\n\
+    \x02id\x18\x01\x20\x01(\x03R\x02id\x12\x15\n\x06doc_id\x18\x02\x20\x01(\
+    \tR\x05docId\x12\x1e\n\x0bbase_rev_id\x18\x03\x20\x01(\x03R\tbaseRevId\
+    \x120\n\x14local_revision_count\x18\x04\x20\x01(\x03R\x12localRevisionCo\
+    unt\x12#\n\rdocument_json\x18\x05\x20\x01(\tR\x0cdocumentJson\x12\x1f\n\
+    \x0bcreate_time\x18\x06\x20\x01(\x03R\ncreateTime\"I\n\x1bRepeatedDocume\
+    ntMergeBackup\x12*\n\x05items\x18\x01\x20\x03(\x0b2\x14.DocumentMergeBac\
+    kupR\x05items\"K\n\x15DocumentMergeBackupId\x12\x15\n\x06doc_id\x18\x01\
+    \x20\x01(\tR\x05docId\x12\x1b\n\tbackup_id\x18\x02\x20\x01(\x03R\x08back\
+    upIdJ\xd7\x04\n\x06\x12\x04\0\0\x10\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\
+    \n\n\n\x02\x04\0\x12\x04\x02\0\t\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\
+    \x1b\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x11\n\x0c\n\x05\x04\0\x02\0\
+    \x05\x12\x03\x03\x04\t\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\n\x0c\n\
+    \x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x0f\x10\n\x0b\n\x04\x04\0\x02\x01\
+    \x12\x03\x04\x04\x16\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\
+    \x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\x11\n\x0c\n\x05\x04\0\x02\
+    \x01\x03\x12\x03\x04\x14\x15\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\
+    \x1a\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\t\n\x0c\n\x05\x04\0\
+    \x02\x02\x01\x12\x03\x05\n\x15\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\
+    \x18\x19\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x04#\n\x0c\n\x05\x04\0\
+    \x02\x03\x05\x12\x03\x06\x04\t\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x06\
+    \n\x1e\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x06!\"\n\x0b\n\x04\x04\0\
+    \x02\x04\x12\x03\x07\x04\x1d\n\x0c\n\x05\x04\0\x02\x04\x05\x12\x03\x07\
+    \x04\n\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\x0b\x18\n\x0c\n\x05\x04\
+    \0\x02\x04\x03\x12\x03\x07\x1b\x1c\n\x0b\n\x04\x04\0\x02\x05\x12\x03\x08\
+    \x04\x1a\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\x08\x04\t\n\x0c\n\x05\x04\
+    \0\x02\x05\x01\x12\x03\x08\n\x15\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\
+    \x08\x18\x19\n\n\n\x02\x04\x01\x12\x04\n\0\x0c\x01\n\n\n\x03\x04\x01\x01\
+    \x12\x03\n\x08#\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x0b\x04+\n\x0c\n\x05\
+    \x04\x01\x02\0\x04\x12\x03\x0b\x04\x0c\n\x0c\n\x05\x04\x01\x02\0\x06\x12\
+    \x03\x0b\r\x20\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0b!&\n\x0c\n\x05\
+    \x04\x01\x02\0\x03\x12\x03\x0b)*\n\n\n\x02\x04\x02\x12\x04\r\0\x10\x01\n\
+    \n\n\x03\x04\x02\x01\x12\x03\r\x08\x1d\n\x0b\n\x04\x04\x02\x02\0\x12\x03\
+    \x0e\x04\x16\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\
+    \x04\x02\x02\0\x01\x12\x03\x0e\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x03\x12\
+    \x03\x0e\x14\x15\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0f\x04\x18\n\x0c\n\
+    \x05\x04\x02\x02\x01\x05\x12\x03\x0f\x04\t\n\x0c\n\x05\x04\x02\x02\x01\
+    \x01\x12\x03\x0f\n\x13\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0f\x16\
+    \x17b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -12,3 +12,24 @@ pub use revision::*;
 
 mod document_info;
 pub use document_info::*;
+
+mod grid_info;
+pub use grid_info::*;
+
+mod board_info;
+pub use board_info::*;
+
+mod comment_info;
+pub use comment_info::*;
+
+mod outline;
+pub use outline::*;
+
+mod statistics;
+pub use statistics::*;
+
+mod integrity;
+pub use integrity::*;
+
+mod merge_backup;
+pub use merge_backup::*;
@@ -33,6 +33,7 @@ pub struct Revision {
     pub object_id: ::std::string::String,
     pub ty: RevType,
     pub user_id: ::std::string::String,
+    pub key_id: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -197,6 +198,32 @@ impl Revision {
     pub fn take_user_id(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
     }
+
+    // string key_id = 8;
+
+
+    pub fn get_key_id(&self) -> &str {
+        &self.key_id
+    }
+    pub fn clear_key_id(&mut self) {
+        self.key_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_id(&mut self, v: ::std::string::String) {
+        self.key_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key_id(&mut self) -> &mut ::std::string::String {
+        &mut self.key_id
+    }
+
+    // Take field
+    pub fn take_key_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key_id, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for Revision {
@@ -237,6 +264,9 @@ impl ::protobuf::Message for Revision {
                 7 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
                 },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key_id)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -270,6 +300,9 @@ impl ::protobuf::Message for Revision {
         if !self.user_id.is_empty() {
             my_size += ::protobuf::rt::string_size(7, &self.user_id);
         }
+        if !self.key_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.key_id);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -297,6 +330,9 @@ impl ::protobuf::Message for Revision {
         if !self.user_id.is_empty() {
             os.write_string(7, &self.user_id)?;
         }
+        if !self.key_id.is_empty() {
+            os.write_string(8, &self.key_id)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -370,6 +406,11 @@ impl ::protobuf::Message for Revision {
                 |m: &Revision| { &m.user_id },
                 |m: &mut Revision| { &mut m.user_id },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key_id",
+                |m: &Revision| { &m.key_id },
+                |m: &mut Revision| { &mut m.key_id },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<Revision>(
                 "Revision",
                 fields,
@@ -393,6 +434,7 @@ impl ::protobuf::Clear for Revision {
         self.object_id.clear();
         self.ty = RevType::DeprecatedLocal;
         self.user_id.clear();
+        self.key_id.clear();
         self.unknown_fields.clear();
     }
 }
@@ -1057,68 +1099,72 @@ impl ::protobuf::reflect::ProtobufValue for RevType {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0erevision.proto\"\xc2\x01\n\x08Revision\x12\x1e\n\x0bbase_rev_id\
+    \n\x0erevision.proto\"\xd9\x01\n\x08Revision\x12\x1e\n\x0bbase_rev_id\
     \x18\x01\x20\x01(\x03R\tbaseRevId\x12\x15\n\x06rev_id\x18\x02\x20\x01(\
     \x03R\x05revId\x12\x1d\n\ndelta_data\x18\x03\x20\x01(\x0cR\tdeltaData\
     \x12\x10\n\x03md5\x18\x04\x20\x01(\tR\x03md5\x12\x1b\n\tobject_id\x18\
     \x05\x20\x01(\tR\x08objectId\x12\x18\n\x02ty\x18\x06\x20\x01(\x0e2\x08.R\
-    evTypeR\x02ty\x12\x17\n\x07user_id\x18\x07\x20\x01(\tR\x06userId\"3\n\
-    \x10RepeatedRevision\x12\x1f\n\x05items\x18\x01\x20\x03(\x0b2\t.Revision\
-    R\x05items\"\x1d\n\x05RevId\x12\x14\n\x05value\x18\x01\x20\x01(\x03R\x05\
-    value\"T\n\rRevisionRange\x12\x1b\n\tobject_id\x18\x01\x20\x01(\tR\x08ob\
-    jectId\x12\x14\n\x05start\x18\x02\x20\x01(\x03R\x05start\x12\x10\n\x03en\
-    d\x18\x03\x20\x01(\x03R\x03end*\"\n\rRevisionState\x12\x08\n\x04Sync\x10\
-    \0\x12\x07\n\x03Ack\x10\x01*4\n\x07RevType\x12\x13\n\x0fDeprecatedLocal\
-    \x10\0\x12\x14\n\x10DeprecatedRemote\x10\x01J\xe8\x07\n\x06\x12\x04\0\0\
-    \x1d\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\n\
-    \x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x10\n\x0b\n\x04\x04\0\x02\0\x12\
-    \x03\x03\x04\x1a\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\t\n\x0c\n\
-    \x05\x04\0\x02\0\x01\x12\x03\x03\n\x15\n\x0c\n\x05\x04\0\x02\0\x03\x12\
-    \x03\x03\x18\x19\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x15\n\x0c\n\
-    \x05\x04\0\x02\x01\x05\x12\x03\x04\x04\t\n\x0c\n\x05\x04\0\x02\x01\x01\
-    \x12\x03\x04\n\x10\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x13\x14\n\
-    \x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\x19\n\x0c\n\x05\x04\0\x02\x02\
-    \x05\x12\x03\x05\x04\t\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\n\x14\n\
-    \x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x17\x18\n\x0b\n\x04\x04\0\x02\
-    \x03\x12\x03\x06\x04\x13\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x04\n\
-    \n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x06\x0b\x0e\n\x0c\n\x05\x04\0\x02\
-    \x03\x03\x12\x03\x06\x11\x12\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x07\x04\
-    \x19\n\x0c\n\x05\x04\0\x02\x04\x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\0\
-    \x02\x04\x01\x12\x03\x07\x0b\x14\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\
-    \x07\x17\x18\n\x0b\n\x04\x04\0\x02\x05\x12\x03\x08\x04\x13\n\x0c\n\x05\
-    \x04\0\x02\x05\x06\x12\x03\x08\x04\x0b\n\x0c\n\x05\x04\0\x02\x05\x01\x12\
-    \x03\x08\x0c\x0e\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\x08\x11\x12\n\x0b\
-    \n\x04\x04\0\x02\x06\x12\x03\t\x04\x17\n\x0c\n\x05\x04\0\x02\x06\x05\x12\
-    \x03\t\x04\n\n\x0c\n\x05\x04\0\x02\x06\x01\x12\x03\t\x0b\x12\n\x0c\n\x05\
-    \x04\0\x02\x06\x03\x12\x03\t\x15\x16\n\n\n\x02\x04\x01\x12\x04\x0b\0\r\
-    \x01\n\n\n\x03\x04\x01\x01\x12\x03\x0b\x08\x18\n\x0b\n\x04\x04\x01\x02\0\
-    \x12\x03\x0c\x04\x20\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03\x0c\x04\x0c\n\
-    \x0c\n\x05\x04\x01\x02\0\x06\x12\x03\x0c\r\x15\n\x0c\n\x05\x04\x01\x02\0\
-    \x01\x12\x03\x0c\x16\x1b\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0c\x1e\
-    \x1f\n\n\n\x02\x04\x02\x12\x04\x0e\0\x10\x01\n\n\n\x03\x04\x02\x01\x12\
-    \x03\x0e\x08\r\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x0f\x04\x14\n\x0c\n\x05\
-    \x04\x02\x02\0\x05\x12\x03\x0f\x04\t\n\x0c\n\x05\x04\x02\x02\0\x01\x12\
-    \x03\x0f\n\x0f\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x0f\x12\x13\n\n\n\
-    \x02\x04\x03\x12\x04\x11\0\x15\x01\n\n\n\x03\x04\x03\x01\x12\x03\x11\x08\
-    \x15\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x12\x04\x19\n\x0c\n\x05\x04\x03\
-    \x02\0\x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x12\
-    \x0b\x14\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x12\x17\x18\n\x0b\n\x04\
-    \x04\x03\x02\x01\x12\x03\x13\x04\x14\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\
-    \x03\x13\x04\t\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x13\n\x0f\n\x0c\n\
-    \x05\x04\x03\x02\x01\x03\x12\x03\x13\x12\x13\n\x0b\n\x04\x04\x03\x02\x02\
-    \x12\x03\x14\x04\x12\n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x14\x04\t\n\
-    \x0c\n\x05\x04\x03\x02\x02\x01\x12\x03\x14\n\r\n\x0c\n\x05\x04\x03\x02\
-    \x02\x03\x12\x03\x14\x10\x11\n\n\n\x02\x05\0\x12\x04\x16\0\x19\x01\n\n\n\
-    \x03\x05\0\x01\x12\x03\x16\x05\x12\n\x0b\n\x04\x05\0\x02\0\x12\x03\x17\
-    \x04\r\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x17\x04\x08\n\x0c\n\x05\x05\0\
-    \x02\0\x02\x12\x03\x17\x0b\x0c\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x18\x04\
-    \x0c\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x18\x04\x07\n\x0c\n\x05\x05\0\
-    \x02\x01\x02\x12\x03\x18\n\x0b\n\n\n\x02\x05\x01\x12\x04\x1a\0\x1d\x01\n\
-    \n\n\x03\x05\x01\x01\x12\x03\x1a\x05\x0c\n\x0b\n\x04\x05\x01\x02\0\x12\
-    \x03\x1b\x04\x18\n\x0c\n\x05\x05\x01\x02\0\x01\x12\x03\x1b\x04\x13\n\x0c\
-    \n\x05\x05\x01\x02\0\x02\x12\x03\x1b\x16\x17\n\x0b\n\x04\x05\x01\x02\x01\
-    \x12\x03\x1c\x04\x19\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\x1c\x04\x14\
-    \n\x0c\n\x05\x05\x01\x02\x01\x02\x12\x03\x1c\x17\x18b\x06proto3\
+    evTypeR\x02ty\x12\x17\n\x07user_id\x18\x07\x20\x01(\tR\x06userId\x12\x15\
+    \n\x06key_id\x18\x08\x20\x01(\tR\x05keyId\"3\n\x10RepeatedRevision\x12\
+    \x1f\n\x05items\x18\x01\x20\x03(\x0b2\t.RevisionR\x05items\"\x1d\n\x05Re\
+    vId\x12\x14\n\x05value\x18\x01\x20\x01(\x03R\x05value\"T\n\rRevisionRang\
+    e\x12\x1b\n\tobject_id\x18\x01\x20\x01(\tR\x08objectId\x12\x14\n\x05star\
+    t\x18\x02\x20\x01(\x03R\x05start\x12\x10\n\x03end\x18\x03\x20\x01(\x03R\
+    \x03end*\"\n\rRevisionState\x12\x08\n\x04Sync\x10\0\x12\x07\n\x03Ack\x10\
+    \x01*4\n\x07RevType\x12\x13\n\x0fDeprecatedLocal\x10\0\x12\x14\n\x10Depr\
+    ecatedRemote\x10\x01J\x9f\x08\n\x06\x12\x04\0\0\x1e\x01\n\x08\n\x01\x0c\
+    \x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x0b\x01\n\n\n\x03\x04\0\
+    \x01\x12\x03\x02\x08\x10\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x1a\n\
+    \x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\t\n\x0c\n\x05\x04\0\x02\0\x01\
+    \x12\x03\x03\n\x15\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x18\x19\n\x0b\
+    \n\x04\x04\0\x02\x01\x12\x03\x04\x04\x15\n\x0c\n\x05\x04\0\x02\x01\x05\
+    \x12\x03\x04\x04\t\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\n\x10\n\x0c\
+    \n\x05\x04\0\x02\x01\x03\x12\x03\x04\x13\x14\n\x0b\n\x04\x04\0\x02\x02\
+    \x12\x03\x05\x04\x19\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\t\n\
+    \x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\n\x14\n\x0c\n\x05\x04\0\x02\x02\
+    \x03\x12\x03\x05\x17\x18\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x04\x13\n\
+    \x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\0\x02\x03\
+    \x01\x12\x03\x06\x0b\x0e\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x06\x11\
+    \x12\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x07\x04\x19\n\x0c\n\x05\x04\0\x02\
+    \x04\x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\x0b\
+    \x14\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x07\x17\x18\n\x0b\n\x04\x04\0\
+    \x02\x05\x12\x03\x08\x04\x13\n\x0c\n\x05\x04\0\x02\x05\x06\x12\x03\x08\
+    \x04\x0b\n\x0c\n\x05\x04\0\x02\x05\x01\x12\x03\x08\x0c\x0e\n\x0c\n\x05\
+    \x04\0\x02\x05\x03\x12\x03\x08\x11\x12\n\x0b\n\x04\x04\0\x02\x06\x12\x03\
+    \t\x04\x17\n\x0c\n\x05\x04\0\x02\x06\x05\x12\x03\t\x04\n\n\x0c\n\x05\x04\
+    \0\x02\x06\x01\x12\x03\t\x0b\x12\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03\t\
+    \x15\x16\n\x0b\n\x04\x04\0\x02\x07\x12\x03\n\x04\x16\n\x0c\n\x05\x04\0\
+    \x02\x07\x05\x12\x03\n\x04\n\n\x0c\n\x05\x04\0\x02\x07\x01\x12\x03\n\x0b\
+    \x11\n\x0c\n\x05\x04\0\x02\x07\x03\x12\x03\n\x14\x15\n\n\n\x02\x04\x01\
+    \x12\x04\x0c\0\x0e\x01\n\n\n\x03\x04\x01\x01\x12\x03\x0c\x08\x18\n\x0b\n\
+    \x04\x04\x01\x02\0\x12\x03\r\x04\x20\n\x0c\n\x05\x04\x01\x02\0\x04\x12\
+    \x03\r\x04\x0c\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03\r\r\x15\n\x0c\n\x05\
+    \x04\x01\x02\0\x01\x12\x03\r\x16\x1b\n\x0c\n\x05\x04\x01\x02\0\x03\x12\
+    \x03\r\x1e\x1f\n\n\n\x02\x04\x02\x12\x04\x0f\0\x11\x01\n\n\n\x03\x04\x02\
+    \x01\x12\x03\x0f\x08\r\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x10\x04\x14\n\
+    \x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x10\x04\t\n\x0c\n\x05\x04\x02\x02\0\
+    \x01\x12\x03\x10\n\x0f\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x10\x12\x13\
+    \n\n\n\x02\x04\x03\x12\x04\x12\0\x16\x01\n\n\n\x03\x04\x03\x01\x12\x03\
+    \x12\x08\x15\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x13\x04\x19\n\x0c\n\x05\
+    \x04\x03\x02\0\x05\x12\x03\x13\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\
+    \x03\x13\x0b\x14\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x13\x17\x18\n\x0b\
+    \n\x04\x04\x03\x02\x01\x12\x03\x14\x04\x14\n\x0c\n\x05\x04\x03\x02\x01\
+    \x05\x12\x03\x14\x04\t\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x14\n\x0f\
+    \n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x14\x12\x13\n\x0b\n\x04\x04\x03\
+    \x02\x02\x12\x03\x15\x04\x12\n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x15\
+    \x04\t\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x03\x15\n\r\n\x0c\n\x05\x04\
+    \x03\x02\x02\x03\x12\x03\x15\x10\x11\n\n\n\x02\x05\0\x12\x04\x17\0\x1a\
+    \x01\n\n\n\x03\x05\0\x01\x12\x03\x17\x05\x12\n\x0b\n\x04\x05\0\x02\0\x12\
+    \x03\x18\x04\r\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x18\x04\x08\n\x0c\n\
+    \x05\x05\0\x02\0\x02\x12\x03\x18\x0b\x0c\n\x0b\n\x04\x05\0\x02\x01\x12\
+    \x03\x19\x04\x0c\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x19\x04\x07\n\x0c\
+    \n\x05\x05\0\x02\x01\x02\x12\x03\x19\n\x0b\n\n\n\x02\x05\x01\x12\x04\x1b\
+    \0\x1e\x01\n\n\n\x03\x05\x01\x01\x12\x03\x1b\x05\x0c\n\x0b\n\x04\x05\x01\
+    \x02\0\x12\x03\x1c\x04\x18\n\x0c\n\x05\x05\x01\x02\0\x01\x12\x03\x1c\x04\
+    \x13\n\x0c\n\x05\x05\x01\x02\0\x02\x12\x03\x1c\x16\x17\n\x0b\n\x04\x05\
+    \x01\x02\x01\x12\x03\x1d\x04\x19\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\
+    \x1d\x04\x14\n\x0c\n\x05\x05\x01\x02\x01\x02\x12\x03\x1d\x17\x18b\x06pro\
+    to3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,991 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `statistics.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocumentStatistics {
+    // message fields
+    pub words: i64,
+    pub characters: i64,
+    pub characters_without_spaces: i64,
+    pub paragraphs: i64,
+    pub headers: i64,
+    pub reading_time_in_seconds: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocumentStatistics {
+    fn default() -> &'a DocumentStatistics {
+        <DocumentStatistics as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocumentStatistics {
+    pub fn new() -> DocumentStatistics {
+        ::std::default::Default::default()
+    }
+
+    // int64 words = 1;
+
+
+    pub fn get_words(&self) -> i64 {
+        self.words
+    }
+    pub fn clear_words(&mut self) {
+        self.words = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_words(&mut self, v: i64) {
+        self.words = v;
+    }
+
+    // int64 characters = 2;
+
+
+    pub fn get_characters(&self) -> i64 {
+        self.characters
+    }
+    pub fn clear_characters(&mut self) {
+        self.characters = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_characters(&mut self, v: i64) {
+        self.characters = v;
+    }
+
+    // int64 characters_without_spaces = 3;
+
+
+    pub fn get_characters_without_spaces(&self) -> i64 {
+        self.characters_without_spaces
+    }
+    pub fn clear_characters_without_spaces(&mut self) {
+        self.characters_without_spaces = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_characters_without_spaces(&mut self, v: i64) {
+        self.characters_without_spaces = v;
+    }
+
+    // int64 paragraphs = 4;
+
+
+    pub fn get_paragraphs(&self) -> i64 {
+        self.paragraphs
+    }
+    pub fn clear_paragraphs(&mut self) {
+        self.paragraphs = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_paragraphs(&mut self, v: i64) {
+        self.paragraphs = v;
+    }
+
+    // int64 headers = 5;
+
+
+    pub fn get_headers(&self) -> i64 {
+        self.headers
+    }
+    pub fn clear_headers(&mut self) {
+        self.headers = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_headers(&mut self, v: i64) {
+        self.headers = v;
+    }
+
+    // int64 reading_time_in_seconds = 6;
+
+
+    pub fn get_reading_time_in_seconds(&self) -> i64 {
+        self.reading_time_in_seconds
+    }
+    pub fn clear_reading_time_in_seconds(&mut self) {
+        self.reading_time_in_seconds = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reading_time_in_seconds(&mut self, v: i64) {
+        self.reading_time_in_seconds = v;
+    }
+}
+
+impl ::protobuf::Message for DocumentStatistics {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.words = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.characters = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.characters_without_spaces = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.paragraphs = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.headers = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.reading_time_in_seconds = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.words != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.words, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.characters != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.characters, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.characters_without_spaces != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.characters_without_spaces, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.paragraphs != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.paragraphs, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.headers != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.headers, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.reading_time_in_seconds != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.reading_time_in_seconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.words != 0 {
+            os.write_int64(1, self.words)?;
+        }
+        if self.characters != 0 {
+            os.write_int64(2, self.characters)?;
+        }
+        if self.characters_without_spaces != 0 {
+            os.write_int64(3, self.characters_without_spaces)?;
+        }
+        if self.paragraphs != 0 {
+            os.write_int64(4, self.paragraphs)?;
+        }
+        if self.headers != 0 {
+            os.write_int64(5, self.headers)?;
+        }
+        if self.reading_time_in_seconds != 0 {
+            os.write_int64(6, self.reading_time_in_seconds)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocumentStatistics {
+        DocumentStatistics::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "words",
+                |m: &DocumentStatistics| { &m.words },
+                |m: &mut DocumentStatistics| { &mut m.words },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "characters",
+                |m: &DocumentStatistics| { &m.characters },
+                |m: &mut DocumentStatistics| { &mut m.characters },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "characters_without_spaces",
+                |m: &DocumentStatistics| { &m.characters_without_spaces },
+                |m: &mut DocumentStatistics| { &mut m.characters_without_spaces },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "paragraphs",
+                |m: &DocumentStatistics| { &m.paragraphs },
+                |m: &mut DocumentStatistics| { &mut m.paragraphs },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "headers",
+                |m: &DocumentStatistics| { &m.headers },
+                |m: &mut DocumentStatistics| { &mut m.headers },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "reading_time_in_seconds",
+                |m: &DocumentStatistics| { &m.reading_time_in_seconds },
+                |m: &mut DocumentStatistics| { &mut m.reading_time_in_seconds },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocumentStatistics>(
+                "DocumentStatistics",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocumentStatistics {
+        static instance: ::protobuf::rt::LazyV2<DocumentStatistics> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocumentStatistics::new)
+    }
+}
+
+impl ::protobuf::Clear for DocumentStatistics {
+    fn clear(&mut self) {
+        self.words = 0;
+        self.characters = 0;
+        self.characters_without_spaces = 0;
+        self.paragraphs = 0;
+        self.headers = 0;
+        self.reading_time_in_seconds = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocumentStatistics {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocumentStatistics {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocumentStatisticsRequest {
+    // message fields
+    pub doc_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_start: ::std::option::Option<DocumentStatisticsRequest_oneof_one_of_start>,
+    pub one_of_end: ::std::option::Option<DocumentStatisticsRequest_oneof_one_of_end>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocumentStatisticsRequest {
+    fn default() -> &'a DocumentStatisticsRequest {
+        <DocumentStatisticsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum DocumentStatisticsRequest_oneof_one_of_start {
+    start(i64),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum DocumentStatisticsRequest_oneof_one_of_end {
+    end(i64),
+}
+
+impl DocumentStatisticsRequest {
+    pub fn new() -> DocumentStatisticsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // int64 start = 2;
+
+
+    pub fn get_start(&self) -> i64 {
+        match self.one_of_start {
+            ::std::option::Option::Some(DocumentStatisticsRequest_oneof_one_of_start::start(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_start(&mut self) {
+        self.one_of_start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        match self.one_of_start {
+            ::std::option::Option::Some(DocumentStatisticsRequest_oneof_one_of_start::start(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: i64) {
+        self.one_of_start = ::std::option::Option::Some(DocumentStatisticsRequest_oneof_one_of_start::start(v))
+    }
+
+    // int64 end = 3;
+
+
+    pub fn get_end(&self) -> i64 {
+        match self.one_of_end {
+            ::std::option::Option::Some(DocumentStatisticsRequest_oneof_one_of_end::end(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_end(&mut self) {
+        self.one_of_end = ::std::option::Option::None;
+    }
+
+    pub fn has_end(&self) -> bool {
+        match self.one_of_end {
+            ::std::option::Option::Some(DocumentStatisticsRequest_oneof_one_of_end::end(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end(&mut self, v: i64) {
+        self.one_of_end = ::std::option::Option::Some(DocumentStatisticsRequest_oneof_one_of_end::end(v))
+    }
+}
+
+impl ::protobuf::Message for DocumentStatisticsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_start = ::std::option::Option::Some(DocumentStatisticsRequest_oneof_one_of_start::start(is.read_int64()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_end = ::std::option::Option::Some(DocumentStatisticsRequest_oneof_one_of_end::end(is.read_int64()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_start {
+            match v {
+                &DocumentStatisticsRequest_oneof_one_of_start::start(v) => {
+                    my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_end {
+            match v {
+                &DocumentStatisticsRequest_oneof_one_of_end::end(v) => {
+                    my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_start {
+            match v {
+                &DocumentStatisticsRequest_oneof_one_of_start::start(v) => {
+                    os.write_int64(2, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_end {
+            match v {
+                &DocumentStatisticsRequest_oneof_one_of_end::end(v) => {
+                    os.write_int64(3, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocumentStatisticsRequest {
+        DocumentStatisticsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &DocumentStatisticsRequest| { &m.doc_id },
+                |m: &mut DocumentStatisticsRequest| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor::<_>(
+                "start",
+                DocumentStatisticsRequest::has_start,
+                DocumentStatisticsRequest::get_start,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor::<_>(
+                "end",
+                DocumentStatisticsRequest::has_end,
+                DocumentStatisticsRequest::get_end,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocumentStatisticsRequest>(
+                "DocumentStatisticsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocumentStatisticsRequest {
+        static instance: ::protobuf::rt::LazyV2<DocumentStatisticsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocumentStatisticsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for DocumentStatisticsRequest {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.one_of_start = ::std::option::Option::None;
+        self.one_of_end = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocumentStatisticsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocumentStatisticsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct OpenDocumentsUsage {
+    // message fields
+    pub count: i64,
+    pub estimated_size: i64,
+    pub max_count: i64,
+    pub max_size: i64,
+    pub evicted_count: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a OpenDocumentsUsage {
+    fn default() -> &'a OpenDocumentsUsage {
+        <OpenDocumentsUsage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl OpenDocumentsUsage {
+    pub fn new() -> OpenDocumentsUsage {
+        ::std::default::Default::default()
+    }
+
+    // int64 count = 1;
+
+
+    pub fn get_count(&self) -> i64 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: i64) {
+        self.count = v;
+    }
+
+    // int64 estimated_size = 2;
+
+
+    pub fn get_estimated_size(&self) -> i64 {
+        self.estimated_size
+    }
+    pub fn clear_estimated_size(&mut self) {
+        self.estimated_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_estimated_size(&mut self, v: i64) {
+        self.estimated_size = v;
+    }
+
+    // int64 max_count = 3;
+
+
+    pub fn get_max_count(&self) -> i64 {
+        self.max_count
+    }
+    pub fn clear_max_count(&mut self) {
+        self.max_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_count(&mut self, v: i64) {
+        self.max_count = v;
+    }
+
+    // int64 max_size = 4;
+
+
+    pub fn get_max_size(&self) -> i64 {
+        self.max_size
+    }
+    pub fn clear_max_size(&mut self) {
+        self.max_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_size(&mut self, v: i64) {
+        self.max_size = v;
+    }
+
+    // int64 evicted_count = 5;
+
+
+    pub fn get_evicted_count(&self) -> i64 {
+        self.evicted_count
+    }
+    pub fn clear_evicted_count(&mut self) {
+        self.evicted_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_evicted_count(&mut self, v: i64) {
+        self.evicted_count = v;
+    }
+}
+
+impl ::protobuf::Message for OpenDocumentsUsage {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.count = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.estimated_size = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.max_count = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.max_size = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.evicted_count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.estimated_size != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.estimated_size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.max_count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.max_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.max_size != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.max_size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.evicted_count != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.evicted_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.count != 0 {
+            os.write_int64(1, self.count)?;
+        }
+        if self.estimated_size != 0 {
+            os.write_int64(2, self.estimated_size)?;
+        }
+        if self.max_count != 0 {
+            os.write_int64(3, self.max_count)?;
+        }
+        if self.max_size != 0 {
+            os.write_int64(4, self.max_size)?;
+        }
+        if self.evicted_count != 0 {
+            os.write_int64(5, self.evicted_count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> OpenDocumentsUsage {
+        OpenDocumentsUsage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "count",
+                |m: &OpenDocumentsUsage| { &m.count },
+                |m: &mut OpenDocumentsUsage| { &mut m.count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "estimated_size",
+                |m: &OpenDocumentsUsage| { &m.estimated_size },
+                |m: &mut OpenDocumentsUsage| { &mut m.estimated_size },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "max_count",
+                |m: &OpenDocumentsUsage| { &m.max_count },
+                |m: &mut OpenDocumentsUsage| { &mut m.max_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "max_size",
+                |m: &OpenDocumentsUsage| { &m.max_size },
+                |m: &mut OpenDocumentsUsage| { &mut m.max_size },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "evicted_count",
+                |m: &OpenDocumentsUsage| { &m.evicted_count },
+                |m: &mut OpenDocumentsUsage| { &mut m.evicted_count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<OpenDocumentsUsage>(
+                "OpenDocumentsUsage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static OpenDocumentsUsage {
+        static instance: ::protobuf::rt::LazyV2<OpenDocumentsUsage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(OpenDocumentsUsage::new)
+    }
+}
+
+impl ::protobuf::Clear for OpenDocumentsUsage {
+    fn clear(&mut self) {
+        self.count = 0;
+        self.estimated_size = 0;
+        self.max_count = 0;
+        self.max_size = 0;
+        self.evicted_count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for OpenDocumentsUsage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OpenDocumentsUsage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x10statistics.proto\"\xf7\x01\n\x12DocumentStatistics\x12\x14\n\x05wo\
+    rds\x18\x01\x20\x01(\x03R\x05words\x12\x1e\n\ncharacters\x18\x02\x20\x01\
+    (\x03R\ncharacters\x12:\n\x19characters_without_spaces\x18\x03\x20\x01(\
+    \x03R\x17charactersWithoutSpaces\x12\x1e\n\nparagraphs\x18\x04\x20\x01(\
+    \x03R\nparagraphs\x12\x18\n\x07headers\x18\x05\x20\x01(\x03R\x07headers\
+    \x125\n\x17reading_time_in_seconds\x18\x06\x20\x01(\x03R\x14readingTimeI\
+    nSeconds\"|\n\x19DocumentStatisticsRequest\x12\x15\n\x06doc_id\x18\x01\
+    \x20\x01(\tR\x05docId\x12\x16\n\x05start\x18\x02\x20\x01(\x03H\0R\x05sta\
+    rt\x12\x12\n\x03end\x18\x03\x20\x01(\x03H\x01R\x03endB\x0e\n\x0cone_of_s\
+    tartB\x0c\n\none_of_end\"\xae\x01\n\x12OpenDocumentsUsage\x12\x14\n\x05c\
+    ount\x18\x01\x20\x01(\x03R\x05count\x12%\n\x0eestimated_size\x18\x02\x20\
+    \x01(\x03R\restimatedSize\x12\x1b\n\tmax_count\x18\x03\x20\x01(\x03R\x08\
+    maxCount\x12\x19\n\x08max_size\x18\x04\x20\x01(\x03R\x07maxSize\x12#\n\r\
+    evicted_count\x18\x05\x20\x01(\x03R\x0cevictedCountJ\x92\x07\n\x06\x12\
+    \x04\0\0\x15\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\
+    \x02\0\t\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x1a\n\x0b\n\x04\x04\0\
+    \x02\0\x12\x03\x03\x04\x14\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\t\
+    \n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\n\x0f\n\x0c\n\x05\x04\0\x02\0\
+    \x03\x12\x03\x03\x12\x13\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x19\n\
+    \x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\t\n\x0c\n\x05\x04\0\x02\x01\
+    \x01\x12\x03\x04\n\x14\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x17\x18\
+    \n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04(\n\x0c\n\x05\x04\0\x02\x02\
+    \x05\x12\x03\x05\x04\t\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\n#\n\
+    \x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05&'\n\x0b\n\x04\x04\0\x02\x03\x12\
+    \x03\x06\x04\x19\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x04\t\n\x0c\n\
+    \x05\x04\0\x02\x03\x01\x12\x03\x06\n\x14\n\x0c\n\x05\x04\0\x02\x03\x03\
+    \x12\x03\x06\x17\x18\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x07\x04\x16\n\x0c\
+    \n\x05\x04\0\x02\x04\x05\x12\x03\x07\x04\t\n\x0c\n\x05\x04\0\x02\x04\x01\
+    \x12\x03\x07\n\x11\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x07\x14\x15\n\
+    \x0b\n\x04\x04\0\x02\x05\x12\x03\x08\x04&\n\x0c\n\x05\x04\0\x02\x05\x05\
+    \x12\x03\x08\x04\t\n\x0c\n\x05\x04\0\x02\x05\x01\x12\x03\x08\n!\n\x0c\n\
+    \x05\x04\0\x02\x05\x03\x12\x03\x08$%\n\n\n\x02\x04\x01\x12\x04\n\0\x0e\
+    \x01\n\n\n\x03\x04\x01\x01\x12\x03\n\x08!\n\x0b\n\x04\x04\x01\x02\0\x12\
+    \x03\x0b\x04\x16\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x0b\x04\n\n\x0c\n\
+    \x05\x04\x01\x02\0\x01\x12\x03\x0b\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x03\
+    \x12\x03\x0b\x14\x15\n\x0b\n\x04\x04\x01\x08\0\x12\x03\x0c\x04+\n\x0c\n\
+    \x05\x04\x01\x08\0\x01\x12\x03\x0c\n\x16\n\x0b\n\x04\x04\x01\x02\x01\x12\
+    \x03\x0c\x19)\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\x0c\x19\x1e\n\x0c\
+    \n\x05\x04\x01\x02\x01\x01\x12\x03\x0c\x1f$\n\x0c\n\x05\x04\x01\x02\x01\
+    \x03\x12\x03\x0c'(\n\x0b\n\x04\x04\x01\x08\x01\x12\x03\r\x04'\n\x0c\n\
+    \x05\x04\x01\x08\x01\x01\x12\x03\r\n\x14\n\x0b\n\x04\x04\x01\x02\x02\x12\
+    \x03\r\x17%\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\r\x17\x1c\n\x0c\n\
+    \x05\x04\x01\x02\x02\x01\x12\x03\r\x1d\x20\n\x0c\n\x05\x04\x01\x02\x02\
+    \x03\x12\x03\r#$\n\n\n\x02\x04\x02\x12\x04\x0f\0\x15\x01\n\n\n\x03\x04\
+    \x02\x01\x12\x03\x0f\x08\x1a\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x10\x04\
+    \x14\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x10\x04\t\n\x0c\n\x05\x04\x02\
+    \x02\0\x01\x12\x03\x10\n\x0f\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x10\
+    \x12\x13\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x11\x04\x1d\n\x0c\n\x05\x04\
+    \x02\x02\x01\x05\x12\x03\x11\x04\t\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\
+    \x03\x11\n\x18\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x11\x1b\x1c\n\x0b\
+    \n\x04\x04\x02\x02\x02\x12\x03\x12\x04\x18\n\x0c\n\x05\x04\x02\x02\x02\
+    \x05\x12\x03\x12\x04\t\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x12\n\x13\
+    \n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x12\x16\x17\n\x0b\n\x04\x04\x02\
+    \x02\x03\x12\x03\x13\x04\x17\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x13\
+    \x04\t\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x13\n\x12\n\x0c\n\x05\x04\
+    \x02\x02\x03\x03\x12\x03\x13\x15\x16\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\
+    \x14\x04\x1c\n\x0c\n\x05\x04\x02\x02\x04\x05\x12\x03\x14\x04\t\n\x0c\n\
+    \x05\x04\x02\x02\x04\x01\x12\x03\x14\n\x17\n\x0c\n\x05\x04\x02\x02\x04\
+    \x03\x12\x03\x14\x1a\x1bb\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,31 @@
+use crate::{
+    entities::board_info::BoardInfo,
+    server_object::{ServerObjectInfo, ServerPlainTextObject},
+};
+
+pub type ServerBoard = ServerPlainTextObject<BoardInfo>;
+
+impl ServerObjectInfo for BoardInfo {
+    const NAME: &'static str = "Board";
+
+    fn from_text(object_id: &str, text: String, rev_id: i64, base_rev_id: i64) -> Self {
+        Self {
+            board_id: object_id.to_owned(),
+            text,
+            rev_id,
+            base_rev_id,
+        }
+    }
+
+    fn object_id(&self) -> &str {
+        &self.board_id
+    }
+
+    fn text(&self) -> &str {
+        &self.text
+    }
+
+    fn rev_id(&self) -> i64 {
+        self.rev_id
+    }
+}
@@ -0,0 +1,7 @@
+mod board_pad;
+
+pub use board_pad::*;
+
+use crate::server_object::ServerObjectManager;
+
+pub type ServerBoardManager = ServerObjectManager<ServerBoard>;
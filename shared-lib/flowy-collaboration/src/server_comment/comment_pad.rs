@@ -0,0 +1,31 @@
+use crate::{
+    entities::comment_info::CommentInfo,
+    server_object::{ServerObjectInfo, ServerPlainTextObject},
+};
+
+pub type ServerComment = ServerPlainTextObject<CommentInfo>;
+
+impl ServerObjectInfo for CommentInfo {
+    const NAME: &'static str = "Comment";
+
+    fn from_text(object_id: &str, text: String, rev_id: i64, base_rev_id: i64) -> Self {
+        Self {
+            object_id: object_id.to_owned(),
+            text,
+            rev_id,
+            base_rev_id,
+        }
+    }
+
+    fn object_id(&self) -> &str {
+        &self.object_id
+    }
+
+    fn text(&self) -> &str {
+        &self.text
+    }
+
+    fn rev_id(&self) -> i64 {
+        self.rev_id
+    }
+}
@@ -0,0 +1,7 @@
+mod comment_pad;
+
+pub use comment_pad::*;
+
+use crate::server_object::ServerObjectManager;
+
+pub type ServerCommentManager = ServerObjectManager<ServerComment>;
@@ -4,7 +4,7 @@ use crate::{
     protobuf::{ClientRevisionWSData, RepeatedRevision as RepeatedRevisionPB, Revision as RevisionPB},
     server_document::document_pad::ServerDocument,
     synchronizer::{RevisionSyncPersistence, RevisionSyncResponse, RevisionSynchronizer, RevisionUser},
-    util::rev_id_from_str,
+    util::{ack_id_from_revisions, rev_id_from_str},
 };
 use async_stream::stream;
 use dashmap::DashMap;
@@ -83,7 +83,7 @@ impl ServerDocumentManager {
     ) -> Result<(), CollaborateError> {
         let repeated_revision = client_data.take_revisions();
         let cloned_user = user.clone();
-        let ack_id = rev_id_from_str(&client_data.data_id)?;
+        let ack_id = ack_id_from_revisions(&client_data.data_id, &repeated_revision)?;
         let object_id = client_data.object_id;
 
         let result = match self.get_document_handler(&object_id).await {
@@ -210,9 +210,17 @@ impl OpenDocumentHandler {
         let (sender, receiver) = mpsc::channel(1000);
         let users = DashMap::new();
 
-        let delta = RichTextDelta::from_bytes(&doc.text)?;
+        let is_encrypted = !doc.encrypted_revisions.is_empty();
+        let delta = if is_encrypted {
+            RichTextDelta::new()
+        } else {
+            RichTextDelta::from_bytes(&doc.text)?
+        };
         let sync_object = ServerDocument::from_delta(&doc_id, delta);
         let synchronizer = Arc::new(DocumentRevisionSynchronizer::new(doc.rev_id, sync_object, persistence));
+        if is_encrypted {
+            synchronizer.enable_relay();
+        }
 
         let queue = DocumentCommandRunner::new(&doc.doc_id, receiver, synchronizer);
         tokio::task::spawn(queue.run());
@@ -7,7 +7,7 @@ use crate::{
     protobuf::{ClientRevisionWSData, RepeatedRevision as RepeatedRevisionPB, Revision as RevisionPB},
     server_folder::folder_pad::ServerFolder,
     synchronizer::{RevisionSyncPersistence, RevisionSyncResponse, RevisionSynchronizer, RevisionUser},
-    util::rev_id_from_str,
+    util::{ack_id_from_revisions, rev_id_from_str},
 };
 use async_stream::stream;
 use futures::stream::StreamExt;
@@ -86,7 +86,7 @@ impl ServerFolderManager {
     ) -> Result<(), CollaborateError> {
         let repeated_revision = client_data.take_revisions();
         let cloned_user = user.clone();
-        let ack_id = rev_id_from_str(&client_data.data_id)?;
+        let ack_id = ack_id_from_revisions(&client_data.data_id, &repeated_revision)?;
         let folder_id = client_data.object_id;
         let user_id = user.user_id();
 
@@ -0,0 +1,31 @@
+use crate::{
+    entities::grid_info::GridInfo,
+    server_object::{ServerObjectInfo, ServerPlainTextObject},
+};
+
+pub type ServerGrid = ServerPlainTextObject<GridInfo>;
+
+impl ServerObjectInfo for GridInfo {
+    const NAME: &'static str = "Grid";
+
+    fn from_text(object_id: &str, text: String, rev_id: i64, base_rev_id: i64) -> Self {
+        Self {
+            grid_id: object_id.to_owned(),
+            text,
+            rev_id,
+            base_rev_id,
+        }
+    }
+
+    fn object_id(&self) -> &str {
+        &self.grid_id
+    }
+
+    fn text(&self) -> &str {
+        &self.text
+    }
+
+    fn rev_id(&self) -> i64 {
+        self.rev_id
+    }
+}
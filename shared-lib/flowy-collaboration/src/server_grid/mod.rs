@@ -0,0 +1,7 @@
+mod grid_pad;
+
+pub use grid_pad::*;
+
+use crate::server_object::ServerObjectManager;
+
+pub type ServerGridManager = ServerObjectManager<ServerGrid>;
@@ -0,0 +1,5 @@
+mod object_manager;
+mod plain_text_object;
+
+pub use object_manager::*;
+pub use plain_text_object::*;
@@ -0,0 +1,347 @@
+use crate::{
+    entities::ws_data::ServerRevisionWSDataBuilder,
+    errors::{internal_error, CollaborateError, CollaborateResult},
+    protobuf::{ClientRevisionWSData, RepeatedRevision as RepeatedRevisionPB, Revision as RevisionPB},
+    synchronizer::{
+        RevisionSyncObject, RevisionSyncPersistence, RevisionSyncResponse, RevisionSynchronizer, RevisionUser,
+    },
+    util::{ack_id_from_revisions, rev_id_from_str},
+};
+use async_stream::stream;
+use futures::stream::StreamExt;
+use lib_infra::future::BoxResultFuture;
+use lib_ot::core::{PlainDelta, PlainTextAttributes};
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
+use tokio::{
+    sync::{mpsc, oneshot, RwLock},
+    task::spawn_blocking,
+};
+
+// The info of the object that is composed from its revisions by the persistence.
+pub trait ServerObjectInfo: Send + Sync + 'static {
+    // The name of the object that is used in the logs and the errors.
+    const NAME: &'static str;
+
+    fn from_text(object_id: &str, text: String, rev_id: i64, base_rev_id: i64) -> Self;
+    fn object_id(&self) -> &str;
+    fn text(&self) -> &str;
+    fn rev_id(&self) -> i64;
+}
+
+// The object that is synced as a plain text delta by the server, e.g. the grid, the board and the
+// comment threads of a document. Each type of object is handled by its own ServerObjectManager.
+pub trait ServerSyncObject: RevisionSyncObject<PlainTextAttributes> + Sized {
+    type Info: ServerObjectInfo;
+    const NAME: &'static str;
+
+    fn from_delta(object_id: &str, delta: PlainDelta) -> Self;
+}
+
+pub trait ServerObjectPersistence<T: ServerSyncObject>: Send + Sync + Debug {
+    fn read_object(&self, user_id: &str, object_id: &str) -> BoxResultFuture<T::Info, CollaborateError>;
+
+    fn create_object(
+        &self,
+        user_id: &str,
+        object_id: &str,
+        repeated_revision: RepeatedRevisionPB,
+    ) -> BoxResultFuture<Option<T::Info>, CollaborateError>;
+
+    fn save_object_revisions(&self, repeated_revision: RepeatedRevisionPB) -> BoxResultFuture<(), CollaborateError>;
+
+    fn read_object_revisions(
+        &self,
+        object_id: &str,
+        rev_ids: Option<Vec<i64>>,
+    ) -> BoxResultFuture<Vec<RevisionPB>, CollaborateError>;
+
+    fn reset_object_revisions(
+        &self,
+        object_id: &str,
+        repeated_revision: RepeatedRevisionPB,
+    ) -> BoxResultFuture<(), CollaborateError>;
+}
+
+impl<T: ServerSyncObject> RevisionSyncPersistence for Arc<dyn ServerObjectPersistence<T>> {
+    fn read_revisions(
+        &self,
+        object_id: &str,
+        rev_ids: Option<Vec<i64>>,
+    ) -> BoxResultFuture<Vec<RevisionPB>, CollaborateError> {
+        (**self).read_object_revisions(object_id, rev_ids)
+    }
+
+    fn save_revisions(&self, repeated_revision: RepeatedRevisionPB) -> BoxResultFuture<(), CollaborateError> {
+        (**self).save_object_revisions(repeated_revision)
+    }
+
+    fn reset_object(
+        &self,
+        object_id: &str,
+        repeated_revision: RepeatedRevisionPB,
+    ) -> BoxResultFuture<(), CollaborateError> {
+        (**self).reset_object_revisions(object_id, repeated_revision)
+    }
+}
+
+pub struct ServerObjectManager<T: ServerSyncObject> {
+    object_handlers: Arc<RwLock<HashMap<String, Arc<OpenObjectHandler>>>>,
+    persistence: Arc<dyn ServerObjectPersistence<T>>,
+}
+
+impl<T: ServerSyncObject> ServerObjectManager<T> {
+    pub fn new(persistence: Arc<dyn ServerObjectPersistence<T>>) -> Self {
+        Self {
+            object_handlers: Arc::new(RwLock::new(HashMap::new())),
+            persistence,
+        }
+    }
+
+    pub async fn handle_client_revisions(
+        &self,
+        user: Arc<dyn RevisionUser>,
+        mut client_data: ClientRevisionWSData,
+    ) -> Result<(), CollaborateError> {
+        let repeated_revision = client_data.take_revisions();
+        let cloned_user = user.clone();
+        let ack_id = ack_id_from_revisions(&client_data.data_id, &repeated_revision)?;
+        let object_id = client_data.object_id;
+        let user_id = user.user_id();
+
+        let result = match self.get_object_handler(&user_id, &object_id).await {
+            None => {
+                let _ = self
+                    .create_object(&user_id, &object_id, repeated_revision)
+                    .await
+                    .map_err(|e| {
+                        CollaborateError::internal().context(format!("Server create {} failed: {}", T::NAME, e))
+                    })?;
+                Ok(())
+            }
+            Some(handler) => {
+                let _ = handler.apply_revisions(user, repeated_revision).await?;
+                Ok(())
+            }
+        };
+
+        if result.is_ok() {
+            cloned_user.receive(RevisionSyncResponse::Ack(
+                ServerRevisionWSDataBuilder::build_ack_message(&object_id, ack_id),
+            ));
+        }
+        result
+    }
+
+    pub async fn handle_client_ping(
+        &self,
+        user: Arc<dyn RevisionUser>,
+        client_data: ClientRevisionWSData,
+    ) -> Result<(), CollaborateError> {
+        let user_id = user.user_id();
+        let rev_id = rev_id_from_str(&client_data.data_id)?;
+        let object_id = client_data.object_id.clone();
+        match self.get_object_handler(&user_id, &object_id).await {
+            None => {
+                tracing::trace!("{}:{} doesn't exist, ignore client ping", T::NAME, object_id);
+                Ok(())
+            }
+            Some(handler) => {
+                let _ = handler.apply_ping(rev_id, user).await?;
+                Ok(())
+            }
+        }
+    }
+
+    async fn get_object_handler(&self, user_id: &str, object_id: &str) -> Option<Arc<OpenObjectHandler>> {
+        let object_id = object_id.to_owned();
+        if let Some(handler) = self.object_handlers.read().await.get(&object_id).cloned() {
+            return Some(handler);
+        }
+
+        let mut write_guard = self.object_handlers.write().await;
+        match self.persistence.read_object(user_id, &object_id).await {
+            Ok(info) => {
+                let handler = self.create_object_handler(info).await.map_err(internal_error).unwrap();
+                write_guard.insert(object_id, handler.clone());
+                drop(write_guard);
+                Some(handler)
+            }
+            Err(_) => None,
+        }
+    }
+
+    async fn create_object_handler(&self, info: T::Info) -> Result<Arc<OpenObjectHandler>, CollaborateError> {
+        let persistence = self.persistence.clone();
+        let handle = spawn_blocking(|| OpenObjectHandler::new::<T>(info, persistence))
+            .await
+            .map_err(|e| CollaborateError::internal().context(format!("Create {} handler failed: {}", T::NAME, e)))?;
+        Ok(Arc::new(handle?))
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, repeated_revision), err)]
+    async fn create_object(
+        &self,
+        user_id: &str,
+        object_id: &str,
+        repeated_revision: RepeatedRevisionPB,
+    ) -> Result<Arc<OpenObjectHandler>, CollaborateError> {
+        match self
+            .persistence
+            .create_object(user_id, object_id, repeated_revision)
+            .await?
+        {
+            Some(info) => {
+                let handler = self.create_object_handler(info).await?;
+                self.object_handlers
+                    .write()
+                    .await
+                    .insert(object_id.to_owned(), handler.clone());
+                Ok(handler)
+            }
+            None => Err(CollaborateError::internal().context(String::new())),
+        }
+    }
+}
+
+type ObjectRevisionSynchronizer = RevisionSynchronizer<PlainTextAttributes>;
+
+struct OpenObjectHandler {
+    object_id: String,
+    sender: mpsc::Sender<ObjectCommand>,
+}
+
+impl OpenObjectHandler {
+    fn new<T: ServerSyncObject>(
+        info: T::Info,
+        persistence: Arc<dyn ServerObjectPersistence<T>>,
+    ) -> CollaborateResult<Self> {
+        let (sender, receiver) = mpsc::channel(1000);
+        let object_id = info.object_id().to_owned();
+        let delta = PlainDelta::from_bytes(info.text())?;
+        let sync_object = T::from_delta(&object_id, delta);
+        let synchronizer = Arc::new(ObjectRevisionSynchronizer::new(info.rev_id(), sync_object, persistence));
+
+        let queue = ObjectCommandRunner::new(&object_id, receiver, synchronizer);
+        tokio::task::spawn(queue.run());
+
+        Ok(Self { object_id, sender })
+    }
+
+    #[tracing::instrument(
+        name = "server_object_apply_revision",
+        level = "trace",
+        skip(self, user, repeated_revision),
+        err
+    )]
+    async fn apply_revisions(
+        &self,
+        user: Arc<dyn RevisionUser>,
+        repeated_revision: RepeatedRevisionPB,
+    ) -> CollaborateResult<()> {
+        let (ret, rx) = oneshot::channel();
+        let msg = ObjectCommand::ApplyRevisions {
+            user,
+            repeated_revision,
+            ret,
+        };
+
+        self.send(msg, rx).await?
+    }
+
+    async fn apply_ping(&self, rev_id: i64, user: Arc<dyn RevisionUser>) -> Result<(), CollaborateError> {
+        let (ret, rx) = oneshot::channel();
+        let msg = ObjectCommand::Ping { user, rev_id, ret };
+        self.send(msg, rx).await?
+    }
+
+    async fn send<T>(&self, msg: ObjectCommand, rx: oneshot::Receiver<T>) -> CollaborateResult<T> {
+        let _ = self
+            .sender
+            .send(msg)
+            .await
+            .map_err(|e| CollaborateError::internal().context(format!("Send object command failed: {}", e)))?;
+        Ok(rx.await.map_err(internal_error)?)
+    }
+}
+
+impl std::ops::Drop for OpenObjectHandler {
+    fn drop(&mut self) {
+        tracing::trace!("{} OpenObjectHandler was dropped", self.object_id);
+    }
+}
+
+enum ObjectCommand {
+    ApplyRevisions {
+        user: Arc<dyn RevisionUser>,
+        repeated_revision: RepeatedRevisionPB,
+        ret: oneshot::Sender<CollaborateResult<()>>,
+    },
+    Ping {
+        user: Arc<dyn RevisionUser>,
+        rev_id: i64,
+        ret: oneshot::Sender<CollaborateResult<()>>,
+    },
+}
+
+struct ObjectCommandRunner {
+    object_id: String,
+    receiver: Option<mpsc::Receiver<ObjectCommand>>,
+    synchronizer: Arc<ObjectRevisionSynchronizer>,
+}
+impl ObjectCommandRunner {
+    fn new(
+        object_id: &str,
+        receiver: mpsc::Receiver<ObjectCommand>,
+        synchronizer: Arc<ObjectRevisionSynchronizer>,
+    ) -> Self {
+        Self {
+            object_id: object_id.to_owned(),
+            receiver: Some(receiver),
+            synchronizer,
+        }
+    }
+
+    async fn run(mut self) {
+        let mut receiver = self
+            .receiver
+            .take()
+            .expect("ObjectCommandRunner's receiver should only take one time");
+
+        let stream = stream! {
+            loop {
+                match receiver.recv().await {
+                    Some(msg) => yield msg,
+                    None => break,
+                }
+            }
+        };
+        stream.for_each(|msg| self.handle_message(msg)).await;
+    }
+
+    async fn handle_message(&self, msg: ObjectCommand) {
+        match msg {
+            ObjectCommand::ApplyRevisions {
+                user,
+                repeated_revision,
+                ret,
+            } => {
+                let result = self
+                    .synchronizer
+                    .sync_revisions(user, repeated_revision)
+                    .await
+                    .map_err(internal_error);
+                let _ = ret.send(result);
+            }
+            ObjectCommand::Ping { user, rev_id, ret } => {
+                let result = self.synchronizer.pong(user, rev_id).await.map_err(internal_error);
+                let _ = ret.send(result);
+            }
+        }
+    }
+}
+
+impl std::ops::Drop for ObjectCommandRunner {
+    fn drop(&mut self) {
+        tracing::trace!("{} ObjectCommandRunner was dropped", self.object_id);
+    }
+}
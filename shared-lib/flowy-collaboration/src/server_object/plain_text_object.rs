@@ -0,0 +1,56 @@
+use crate::{
+    errors::CollaborateError,
+    server_object::{ServerObjectInfo, ServerSyncObject},
+    synchronizer::RevisionSyncObject,
+};
+use lib_ot::core::{Delta, OperationTransformable, PlainDelta, PlainTextAttributes};
+use std::marker::PhantomData;
+
+// The server side of the object that is synced as a plain text delta. The objects only differ in
+// their info, e.g. the ServerGrid is the ServerPlainTextObject<GridInfo>.
+pub struct ServerPlainTextObject<I> {
+    object_id: String,
+    delta: PlainDelta,
+    phantom: PhantomData<I>,
+}
+
+impl<I: ServerObjectInfo> ServerSyncObject for ServerPlainTextObject<I> {
+    type Info = I;
+    const NAME: &'static str = I::NAME;
+
+    fn from_delta(object_id: &str, delta: PlainDelta) -> Self {
+        Self {
+            object_id: object_id.to_owned(),
+            delta,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I: ServerObjectInfo> RevisionSyncObject<PlainTextAttributes> for ServerPlainTextObject<I> {
+    fn id(&self) -> &str {
+        &self.object_id
+    }
+
+    fn compose(&mut self, other: &Delta<PlainTextAttributes>) -> Result<(), CollaborateError> {
+        let new_delta = self.delta.compose(other)?;
+        self.delta = new_delta;
+        Ok(())
+    }
+
+    fn transform(
+        &self,
+        other: &Delta<PlainTextAttributes>,
+    ) -> Result<(Delta<PlainTextAttributes>, Delta<PlainTextAttributes>), CollaborateError> {
+        let value = self.delta.transform(other)?;
+        Ok(value)
+    }
+
+    fn to_json(&self) -> String {
+        self.delta.to_json()
+    }
+
+    fn set_delta(&mut self, new_delta: Delta<PlainTextAttributes>) {
+        self.delta = new_delta;
+    }
+}
@@ -15,7 +15,7 @@ use std::{
     cmp::Ordering,
     fmt::Debug,
     sync::{
-        atomic::{AtomicI64, Ordering::SeqCst},
+        atomic::{AtomicBool, AtomicI64, Ordering::SeqCst},
         Arc,
     },
     time::Duration,
@@ -61,6 +61,9 @@ pub struct RevisionSynchronizer<T: Attributes> {
     rev_id: AtomicI64,
     object: Arc<RwLock<dyn RevisionSyncObject<T>>>,
     persistence: Arc<dyn RevisionSyncPersistence>,
+    // The server can't read the encrypted revisions, so it only persists them and keeps them in
+    // order once the object has any encrypted revision. The object is not updated anymore.
+    is_relay: AtomicBool,
 }
 
 impl<T> RevisionSynchronizer<T>
@@ -80,9 +83,19 @@ where
             rev_id: AtomicI64::new(rev_id),
             object,
             persistence,
+            is_relay: AtomicBool::new(false),
         }
     }
 
+    // Called if the object is built from the encrypted revisions.
+    pub fn enable_relay(&self) {
+        self.is_relay.store(true, SeqCst);
+    }
+
+    pub fn is_relay(&self) -> bool {
+        self.is_relay.load(SeqCst)
+    }
+
     #[tracing::instrument(level = "debug", skip(self, user, repeated_revision), err)]
     pub async fn sync_revisions(
         &self,
@@ -99,12 +112,21 @@ where
             return Ok(());
         }
 
-        let server_base_rev_id = self.rev_id.load(SeqCst);
-        let first_revision = repeated_revision.get_items().first().unwrap().clone();
-        if self.is_applied_before(&first_revision, &self.persistence).await {
-            // Server has received this revision before, so ignore the following revisions
+        // The client resends the whole batch if the ack is lost, so the revisions that the server
+        // has received before are skipped and only the rest of the batch is applied.
+        let applied_count = self.applied_count(&repeated_revision).await;
+        if applied_count == repeated_revision.get_items().len() {
             return Ok(());
         }
+        let mut repeated_revision = repeated_revision;
+        if applied_count > 0 {
+            let revisions = repeated_revision.take_items().into_vec().split_off(applied_count);
+            repeated_revision.set_items(revisions.into());
+        }
+
+        let server_base_rev_id = self.rev_id.load(SeqCst);
+        let first_revision = repeated_revision.get_items().first().unwrap().clone();
+        let last_rev_id = repeated_revision.get_items().last().unwrap().rev_id;
 
         match server_base_rev_id.cmp(&first_revision.rev_id) {
             Ordering::Less => {
@@ -116,24 +138,22 @@ where
                     }
                     let _ = self.persistence.save_revisions(repeated_revision).await?;
                 } else {
-                    // The server delta is outdated, pull the missing revision from the client.
+                    // The server delta is outdated, pull the missing revisions and the revisions of
+                    // the batch from the client, because the client acks the whole batch.
                     let range = RevisionRange {
                         object_id: self.object_id.clone(),
                         start: server_rev_id,
-                        end: first_revision.rev_id,
+                        end: last_rev_id,
                     };
                     let msg = ServerRevisionWSDataBuilder::build_pull_message(&self.object_id, range);
                     user.receive(RevisionSyncResponse::Pull(msg));
                 }
             }
-            Ordering::Equal => {
-                // Do nothing
-                tracing::warn!("Applied {} revision rev_id is the same as cur_rev_id", self.object_id);
-            }
-            Ordering::Greater => {
-                // The client delta is outdated. Transform the client revision delta and then
-                // send the prime delta to the client. Client should compose the this prime
-                // delta.
+            Ordering::Equal | Ordering::Greater => {
+                // The client delta is outdated. The server has a different revision with the same
+                // rev_id if they are equal, because the applied revisions were skipped. Push the
+                // revisions from the first revision of the client, so the client merges its
+                // revisions with them.
                 let from_rev_id = first_revision.rev_id;
                 let to_rev_id = server_base_rev_id;
                 let _ = self.push_revisions_to_user(user, from_rev_id, to_rev_id).await;
@@ -172,9 +192,17 @@ where
         tracing::Span::current().record("object_id", &object_id.as_str());
         let revisions: Vec<RevisionPB> = repeated_revision.get_items().to_vec();
         let (_, rev_id) = pair_rev_id_from_revision_pbs(&revisions);
-        let delta = make_delta_from_revision_pb(revisions)?;
+        let is_relay = revisions.iter().any(|revision| !revision.get_key_id().is_empty());
+        let delta = if is_relay {
+            None
+        } else {
+            Some(make_delta_from_revision_pb(revisions)?)
+        };
         let _ = self.persistence.reset_object(&object_id, repeated_revision).await?;
-        self.object.write().set_delta(delta);
+        if let Some(delta) = delta {
+            self.object.write().set_delta(delta);
+        }
+        self.is_relay.store(is_relay, SeqCst);
         let _ = self.rev_id.fetch_update(SeqCst, SeqCst, |_e| Some(rev_id));
         Ok(())
     }
@@ -184,8 +212,13 @@ where
     }
 
     fn compose_revision(&self, revision: &RevisionPB) -> Result<(), CollaborateError> {
-        let delta = Delta::<T>::from_bytes(&revision.delta_data)?;
-        let _ = self.compose_delta(delta)?;
+        if !revision.get_key_id().is_empty() {
+            self.enable_relay();
+        }
+        if !self.is_relay() {
+            let delta = Delta::<T>::from_bytes(&revision.delta_data)?;
+            let _ = self.compose_delta(delta)?;
+        }
         let _ = self.rev_id.fetch_update(SeqCst, SeqCst, |_e| Some(revision.rev_id));
         Ok(())
     }
@@ -215,6 +248,18 @@ where
         self.rev_id.load(SeqCst)
     }
 
+    // Returns the number of the leading revisions that were applied before.
+    async fn applied_count(&self, repeated_revision: &RepeatedRevisionPB) -> usize {
+        let mut count = 0;
+        for revision in repeated_revision.get_items() {
+            if !self.is_applied_before(revision, &self.persistence).await {
+                break;
+            }
+            count += 1;
+        }
+        count
+    }
+
     async fn is_applied_before(
         &self,
         new_revision: &RevisionPB,
@@ -264,3 +309,212 @@ where
 fn next(rev_id: i64) -> i64 {
     rev_id + 1
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        RevisionSyncObject, RevisionSyncPersistence, RevisionSyncResponse, RevisionSynchronizer, RevisionUser,
+    };
+    use crate::{
+        entities::{
+            revision::RepeatedRevision,
+            ws_data::{ServerRevisionWSData, ServerRevisionWSDataType},
+        },
+        errors::CollaborateError,
+        protobuf::{RepeatedRevision as RepeatedRevisionPB, Revision as RevisionPB},
+        util::md5,
+    };
+    use bytes::Bytes;
+    use lib_infra::future::BoxResultFuture;
+    use lib_ot::core::{OperationTransformable, PlainDelta, PlainDeltaBuilder, PlainTextAttributes};
+    use parking_lot::RwLock;
+    use std::{collections::BTreeMap, convert::TryFrom, sync::Arc};
+
+    struct MockObject {
+        delta: PlainDelta,
+    }
+
+    impl RevisionSyncObject<PlainTextAttributes> for MockObject {
+        fn id(&self) -> &str {
+            "object"
+        }
+
+        fn compose(&mut self, other: &PlainDelta) -> Result<(), CollaborateError> {
+            self.delta = self.delta.compose(other)?;
+            Ok(())
+        }
+
+        fn transform(&self, other: &PlainDelta) -> Result<(PlainDelta, PlainDelta), CollaborateError> {
+            Ok(self.delta.transform(other)?)
+        }
+
+        fn to_json(&self) -> String {
+            self.delta.to_json()
+        }
+
+        fn set_delta(&mut self, new_delta: PlainDelta) {
+            self.delta = new_delta;
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct MockPersistence {
+        revisions: Arc<RwLock<BTreeMap<i64, RevisionPB>>>,
+    }
+
+    impl RevisionSyncPersistence for MockPersistence {
+        fn read_revisions(
+            &self,
+            _object_id: &str,
+            rev_ids: Option<Vec<i64>>,
+        ) -> BoxResultFuture<Vec<RevisionPB>, CollaborateError> {
+            let revisions = self.revisions.read();
+            let revisions = match rev_ids {
+                None => revisions.values().cloned().collect(),
+                Some(rev_ids) => rev_ids
+                    .iter()
+                    .flat_map(|rev_id| revisions.get(rev_id).cloned())
+                    .collect(),
+            };
+            Box::pin(async move { Ok(revisions) })
+        }
+
+        fn save_revisions(&self, repeated_revision: RepeatedRevisionPB) -> BoxResultFuture<(), CollaborateError> {
+            let mut revisions = self.revisions.write();
+            for revision in repeated_revision.get_items() {
+                revisions.insert(revision.rev_id, revision.clone());
+            }
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn reset_object(
+            &self,
+            _object_id: &str,
+            repeated_revision: RepeatedRevisionPB,
+        ) -> BoxResultFuture<(), CollaborateError> {
+            let mut revisions = self.revisions.write();
+            revisions.clear();
+            for revision in repeated_revision.get_items() {
+                revisions.insert(revision.rev_id, revision.clone());
+            }
+            Box::pin(async move { Ok(()) })
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct MockUser {
+        received: RwLock<Vec<ServerRevisionWSData>>,
+    }
+
+    impl RevisionUser for MockUser {
+        fn user_id(&self) -> String {
+            "user".to_owned()
+        }
+
+        fn receive(&self, resp: RevisionSyncResponse) {
+            let data = match resp {
+                RevisionSyncResponse::Pull(data) => data,
+                RevisionSyncResponse::Push(data) => data,
+                RevisionSyncResponse::Ack(data) => data,
+            };
+            self.received.write().push(data);
+        }
+    }
+
+    fn make_revision(rev_id: i64, delta: PlainDelta) -> RevisionPB {
+        let bytes = delta.to_bytes();
+        let mut revision = RevisionPB::new();
+        revision.set_object_id("object".to_owned());
+        revision.set_base_rev_id(rev_id - 1);
+        revision.set_rev_id(rev_id);
+        revision.set_md5(md5(&bytes));
+        revision.set_delta_data(bytes.to_vec());
+        revision
+    }
+
+    fn make_repeated_revision(revisions: Vec<RevisionPB>) -> RepeatedRevisionPB {
+        let mut repeated_revision = RepeatedRevisionPB::new();
+        repeated_revision.set_items(revisions.into());
+        repeated_revision
+    }
+
+    // The server has the revisions 1 and 2, the document is "abc1".
+    fn make_synchronizer() -> (
+        RevisionSynchronizer<PlainTextAttributes>,
+        MockPersistence,
+        Vec<RevisionPB>,
+    ) {
+        let revisions = vec![
+            make_revision(1, PlainDeltaBuilder::new().insert("abc").build()),
+            make_revision(2, PlainDeltaBuilder::new().retain(3).insert("1").build()),
+        ];
+        let persistence = MockPersistence::default();
+        for revision in &revisions {
+            persistence.revisions.write().insert(revision.rev_id, revision.clone());
+        }
+        let object = MockObject {
+            delta: PlainDeltaBuilder::new().insert("abc1").build(),
+        };
+        let synchronizer = RevisionSynchronizer::new(2, object, persistence.clone());
+        (synchronizer, persistence, revisions)
+    }
+
+    fn object_text(synchronizer: &RevisionSynchronizer<PlainTextAttributes>) -> String {
+        PlainDelta::from_json(&synchronizer.object_json())
+            .unwrap()
+            .apply("")
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn sync_revisions_with_same_rev_id_test() {
+        let (synchronizer, persistence, server_revisions) = make_synchronizer();
+        let user = Arc::new(MockUser::default());
+
+        // The client has a different revision 2, so the server pushes its revision 2 instead of
+        // applying the revisions of the client.
+        let client_revisions = vec![
+            make_revision(2, PlainDeltaBuilder::new().retain(3).insert("2").build()),
+            make_revision(3, PlainDeltaBuilder::new().retain(4).insert("3").build()),
+        ];
+        synchronizer
+            .sync_revisions(user.clone(), make_repeated_revision(client_revisions))
+            .await
+            .unwrap();
+
+        assert_eq!(synchronizer.rev_id(), 2);
+        assert_eq!(object_text(&synchronizer), "abc1");
+        assert_eq!(persistence.revisions.read().len(), 2);
+
+        let received = user.received.read();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].ty, ServerRevisionWSDataType::ServerPushRev);
+        let pushed_revisions = RepeatedRevision::try_from(Bytes::from(received[0].data.clone())).unwrap();
+        let pushed_revisions = pushed_revisions
+            .iter()
+            .map(|revision| (revision.rev_id, revision.md5.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(pushed_revisions, vec![(2, server_revisions[1].get_md5().to_owned())]);
+    }
+
+    #[tokio::test]
+    async fn sync_revisions_resent_after_lost_ack_test() {
+        let (synchronizer, persistence, server_revisions) = make_synchronizer();
+        let user = Arc::new(MockUser::default());
+
+        // The revision 2 was applied before, only the revision 3 is applied.
+        let client_revisions = vec![
+            server_revisions[1].clone(),
+            make_revision(3, PlainDeltaBuilder::new().retain(4).insert("2").build()),
+        ];
+        synchronizer
+            .sync_revisions(user.clone(), make_repeated_revision(client_revisions))
+            .await
+            .unwrap();
+
+        assert_eq!(synchronizer.rev_id(), 3);
+        assert_eq!(object_text(&synchronizer), "abc12");
+        assert_eq!(persistence.revisions.read().len(), 3);
+        assert!(user.received.read().is_empty());
+    }
+}
@@ -9,9 +9,14 @@ use crate::{
         DocumentInfo as DocumentInfoPB, FolderInfo as FolderInfoPB, RepeatedRevision as RepeatedRevisionPB,
         Revision as RevisionPB,
     },
+    server_object::ServerObjectInfo,
 };
+use dissimilar::Chunk;
 use lib_ot::{
-    core::{Attributes, Delta, OperationTransformable, NEW_LINE, WHITESPACE},
+    core::{
+        Attributes, Delta, FlowyStr, OperationTransformable, PlainDelta, PlainDeltaBuilder, PlainTextAttributes,
+        NEW_LINE, WHITESPACE,
+    },
     rich_text::RichTextDelta,
 };
 use serde::de::DeserializeOwned;
@@ -193,6 +198,34 @@ pub fn make_folder_pb_from_revisions_pb(
     Ok(Some(folder_info))
 }
 
+// Composes the plain text deltas of the revisions into the info of the object, e.g. the grid, the
+// board and the comment threads of a document.
+#[inline]
+pub fn make_object_info_from_revisions_pb<T: ServerObjectInfo>(
+    object_id: &str,
+    mut revisions: RepeatedRevisionPB,
+) -> Result<Option<T>, CollaborateError> {
+    let revisions = revisions.take_items();
+    if revisions.is_empty() {
+        return Ok(None);
+    }
+
+    let mut delta = PlainDelta::new();
+    let mut base_rev_id = 0;
+    let mut rev_id = 0;
+    for revision in revisions {
+        base_rev_id = revision.base_rev_id;
+        rev_id = revision.rev_id;
+        if revision.delta_data.is_empty() {
+            tracing::warn!("revision delta_data is empty");
+        }
+        let revision_delta = PlainDelta::from_bytes(revision.delta_data)?;
+        delta = delta.compose(&revision_delta)?;
+    }
+
+    Ok(Some(T::from_text(object_id, delta.to_json(), rev_id, base_rev_id)))
+}
+
 #[inline]
 pub fn make_document_info_from_revisions_pb(
     doc_id: &str,
@@ -219,6 +252,16 @@ pub fn make_document_info_pb_from_revisions_pb(
         return Ok(None);
     }
 
+    if revisions.iter().any(|revision| !revision.get_key_id().is_empty()) {
+        let last_revision = revisions.last().unwrap();
+        let mut document_info = DocumentInfoPB::new();
+        document_info.set_doc_id(doc_id.to_owned());
+        document_info.set_base_rev_id(last_revision.base_rev_id);
+        document_info.set_rev_id(last_revision.rev_id);
+        document_info.set_encrypted_revisions(repeated_revision_pb_from_revisions(revisions.into_vec()));
+        return Ok(Some(document_info));
+    }
+
     let mut document_delta = RichTextDelta::new();
     let mut base_rev_id = 0;
     let mut rev_id = 0;
@@ -251,3 +294,33 @@ pub fn rev_id_from_str(s: &str) -> Result<i64, CollaborateError> {
         .map_err(|e| CollaborateError::internal().context(format!("Parse rev_id from {} failed. {}", s, e)))?;
     Ok(rev_id)
 }
+
+// The client batches the consecutive revisions into one message, and the server acks them with the
+// rev_id of the last revision, so the client acks all the revisions up to it at once. The message
+// without revisions is acked with its data_id.
+pub fn ack_id_from_revisions(data_id: &str, repeated_revision: &RepeatedRevisionPB) -> Result<i64, CollaborateError> {
+    match repeated_revision.get_items().last() {
+        None => rev_id_from_str(data_id),
+        Some(revision) => Ok(revision.rev_id),
+    }
+}
+
+// Returns the delta that transforms the old json string into the new one.
+pub fn cal_diff(old: String, new: String) -> Delta<PlainTextAttributes> {
+    let chunks = dissimilar::diff(&old, &new);
+    let mut delta_builder = PlainDeltaBuilder::new();
+    for chunk in &chunks {
+        match chunk {
+            Chunk::Equal(s) => {
+                delta_builder = delta_builder.retain(FlowyStr::from(*s).utf16_size());
+            }
+            Chunk::Delete(s) => {
+                delta_builder = delta_builder.delete(FlowyStr::from(*s).utf16_size());
+            }
+            Chunk::Insert(s) => {
+                delta_builder = delta_builder.insert(*s);
+            }
+        }
+    }
+    delta_builder.build()
+}
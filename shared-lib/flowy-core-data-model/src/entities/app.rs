@@ -36,6 +36,10 @@ pub struct App {
 
     #[pb(index = 8)]
     pub create_time: i64,
+
+    #[pb(index = 9)]
+    #[serde(default)]
+    pub color_style: ColorStyle,
 }
 
 #[derive(Eq, PartialEq, Debug, Default, ProtoBuf, Clone, Serialize, Deserialize)]
@@ -62,7 +66,7 @@ pub struct CreateAppRequest {
     pub color_style: ColorStyle,
 }
 
-#[derive(ProtoBuf, Default, Debug, Clone)]
+#[derive(Eq, PartialEq, ProtoBuf, Default, Debug, Clone, Serialize, Deserialize)]
 pub struct ColorStyle {
     #[pb(index = 1)]
     pub theme_color: String,
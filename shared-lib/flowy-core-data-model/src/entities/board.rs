@@ -0,0 +1,347 @@
+use crate::{
+    errors::ErrorCode,
+    parser::{
+        board::{CardIdentify, CardTitle},
+        view::{ViewIdentify, ViewPropertyKey},
+    },
+};
+use flowy_derive::ProtoBuf;
+use serde::{Deserialize, Serialize};
+use std::convert::TryInto;
+
+// The board shares the same id with the view that it belongs to. The columns are the options of the
+// board property, and the cards are grouped into the columns by their value of the property.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct Board {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub property: BoardProperty,
+
+    #[pb(index = 3)]
+    pub columns: Vec<BoardColumn>,
+}
+
+impl Board {
+    pub fn get_column(&self, name: &str) -> Option<&BoardColumn> {
+        self.columns.iter().find(|column| column.name == name)
+    }
+}
+
+// The select property that the columns of the board are driven by.
+#[derive(Eq, PartialEq, ProtoBuf, Default, Debug, Clone, Serialize, Deserialize)]
+pub struct BoardProperty {
+    #[pb(index = 1)]
+    pub key: String,
+
+    #[pb(index = 2)]
+    pub options: Vec<String>,
+}
+
+// The column with empty name contains the cards that don't belong to any option of the property.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct BoardColumn {
+    #[pb(index = 1)]
+    pub name: String,
+
+    #[pb(index = 2)]
+    pub cards: Vec<Card>,
+}
+
+#[derive(Eq, PartialEq, ProtoBuf, Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Card {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub title: String,
+
+    // The json of the description's delta.
+    #[pb(index = 3)]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+
+    #[pb(index = 4)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub properties: Vec<CardProperty>,
+}
+
+impl Card {
+    pub fn get_property(&self, key: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|property| property.key == key)
+            .map(|property| property.value.as_str())
+    }
+
+    // Removes the property if the value is empty.
+    pub fn set_property(&mut self, key: &str, value: &str) {
+        self.properties.retain(|property| property.key != key);
+        if !value.is_empty() {
+            self.properties.push(CardProperty {
+                key: key.to_owned(),
+                value: value.to_owned(),
+            });
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, ProtoBuf, Default, Debug, Clone, Serialize, Deserialize)]
+pub struct CardProperty {
+    #[pb(index = 1)]
+    pub key: String,
+
+    #[pb(index = 2)]
+    pub value: String,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryBoardRequest {
+    #[pb(index = 1)]
+    pub board_id: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct BoardId {
+    #[pb(index = 1)]
+    pub board_id: String,
+}
+
+impl TryInto<BoardId> for QueryBoardRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<BoardId, Self::Error> {
+        let board_id = ViewIdentify::parse(self.board_id)?.0;
+        Ok(BoardId { board_id })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct UpdateBoardPropertyRequest {
+    #[pb(index = 1)]
+    pub board_id: String,
+
+    #[pb(index = 2)]
+    pub key: String,
+
+    #[pb(index = 3)]
+    pub options: Vec<String>,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct UpdateBoardPropertyParams {
+    #[pb(index = 1)]
+    pub board_id: String,
+
+    #[pb(index = 2)]
+    pub property: BoardProperty,
+}
+
+impl TryInto<UpdateBoardPropertyParams> for UpdateBoardPropertyRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateBoardPropertyParams, Self::Error> {
+        let board_id = ViewIdentify::parse(self.board_id)?.0;
+        let key = ViewPropertyKey::parse(self.key)?.0;
+        if self.options.iter().any(|option| option.trim().is_empty()) {
+            return Err(ErrorCode::BoardColumnInvalid);
+        }
+
+        Ok(UpdateBoardPropertyParams {
+            board_id,
+            property: BoardProperty {
+                key,
+                options: self.options,
+            },
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct CreateCardRequest {
+    #[pb(index = 1)]
+    pub board_id: String,
+
+    #[pb(index = 2)]
+    pub title: String,
+
+    // The card is put at the end of the column.
+    #[pb(index = 3)]
+    pub column: String,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct CreateCardParams {
+    #[pb(index = 1)]
+    pub board_id: String,
+
+    #[pb(index = 2)]
+    pub title: String,
+
+    #[pb(index = 3)]
+    pub column: String,
+}
+
+impl TryInto<CreateCardParams> for CreateCardRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateCardParams, Self::Error> {
+        let board_id = ViewIdentify::parse(self.board_id)?.0;
+        let title = CardTitle::parse(self.title)?.0;
+
+        Ok(CreateCardParams {
+            board_id,
+            title,
+            column: self.column,
+        })
+    }
+}
+
+// The properties are merged into the card's properties, and the property with empty value is
+// removed. Changing the value of the board property moves the card to the end of that column.
+#[derive(Default, ProtoBuf)]
+pub struct UpdateCardRequest {
+    #[pb(index = 1)]
+    pub board_id: String,
+
+    #[pb(index = 2)]
+    pub card_id: String,
+
+    #[pb(index = 3, one_of)]
+    pub title: Option<String>,
+
+    #[pb(index = 4, one_of)]
+    pub description: Option<String>,
+
+    #[pb(index = 5)]
+    pub properties: Vec<CardProperty>,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct UpdateCardParams {
+    #[pb(index = 1)]
+    pub board_id: String,
+
+    #[pb(index = 2)]
+    pub card_id: String,
+
+    #[pb(index = 3, one_of)]
+    pub title: Option<String>,
+
+    #[pb(index = 4, one_of)]
+    pub description: Option<String>,
+
+    #[pb(index = 5)]
+    pub properties: Vec<CardProperty>,
+}
+
+impl TryInto<UpdateCardParams> for UpdateCardRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateCardParams, Self::Error> {
+        let board_id = ViewIdentify::parse(self.board_id)?.0;
+        let card_id = CardIdentify::parse(self.card_id)?.0;
+        let title = match self.title {
+            None => None,
+            Some(title) => Some(CardTitle::parse(title)?.0),
+        };
+
+        let mut properties = vec![];
+        for property in self.properties {
+            let key = ViewPropertyKey::parse(property.key)?.0;
+            properties.push(CardProperty {
+                key,
+                value: property.value,
+            });
+        }
+
+        Ok(UpdateCardParams {
+            board_id,
+            card_id,
+            title,
+            description: self.description,
+            properties,
+        })
+    }
+}
+
+// Moves the card into the column, right after the after_card_id. The card is put at the top of the
+// column if the after_card_id is None.
+#[derive(Default, ProtoBuf)]
+pub struct MoveCardRequest {
+    #[pb(index = 1)]
+    pub board_id: String,
+
+    #[pb(index = 2)]
+    pub card_id: String,
+
+    #[pb(index = 3)]
+    pub column: String,
+
+    #[pb(index = 4, one_of)]
+    pub after_card_id: Option<String>,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct MoveCardParams {
+    #[pb(index = 1)]
+    pub board_id: String,
+
+    #[pb(index = 2)]
+    pub card_id: String,
+
+    #[pb(index = 3)]
+    pub column: String,
+
+    #[pb(index = 4, one_of)]
+    pub after_card_id: Option<String>,
+}
+
+impl TryInto<MoveCardParams> for MoveCardRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<MoveCardParams, Self::Error> {
+        let board_id = ViewIdentify::parse(self.board_id)?.0;
+        let card_id = CardIdentify::parse(self.card_id)?.0;
+        let after_card_id = match self.after_card_id {
+            None => None,
+            Some(after_card_id) => Some(CardIdentify::parse(after_card_id)?.0),
+        };
+
+        Ok(MoveCardParams {
+            board_id,
+            card_id,
+            column: self.column,
+            after_card_id,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryCardRequest {
+    #[pb(index = 1)]
+    pub board_id: String,
+
+    #[pb(index = 2)]
+    pub card_id: String,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct CardIdentifier {
+    #[pb(index = 1)]
+    pub board_id: String,
+
+    #[pb(index = 2)]
+    pub card_id: String,
+}
+
+impl TryInto<CardIdentifier> for QueryCardRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CardIdentifier, Self::Error> {
+        let board_id = ViewIdentify::parse(self.board_id)?.0;
+        let card_id = CardIdentify::parse(self.card_id)?.0;
+        Ok(CardIdentifier { board_id, card_id })
+    }
+}
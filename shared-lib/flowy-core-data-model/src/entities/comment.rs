@@ -0,0 +1,222 @@
+use crate::{
+    errors::ErrorCode,
+    impl_def_and_def_mut,
+    parser::{
+        comment::{CommentContent, CommentIdentify},
+        view::ViewIdentify,
+    },
+};
+use flowy_derive::ProtoBuf;
+use serde::{Deserialize, Serialize};
+use std::convert::TryInto;
+
+// The comment thread is anchored to a range of the document's text. The anchor_start and the
+// anchor_end are the range in utf16 code units, and they are zero if the thread is orphaned, which
+// means its text was deleted. The orphaned thread is kept with its replies.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct CommentThread {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub doc_id: String,
+
+    #[pb(index = 3)]
+    pub author: String,
+
+    #[pb(index = 4)]
+    pub content: String,
+
+    #[pb(index = 5)]
+    pub create_time: i64,
+
+    #[pb(index = 6)]
+    pub resolved: bool,
+
+    #[pb(index = 7)]
+    pub orphaned: bool,
+
+    #[pb(index = 8)]
+    pub anchor_start: i64,
+
+    #[pb(index = 9)]
+    pub anchor_end: i64,
+
+    #[pb(index = 10)]
+    pub replies: Vec<Comment>,
+}
+
+#[derive(Eq, PartialEq, ProtoBuf, Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Comment {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub thread_id: String,
+
+    #[pb(index = 3)]
+    pub author: String,
+
+    #[pb(index = 4)]
+    pub content: String,
+
+    #[pb(index = 5)]
+    pub create_time: i64,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedCommentThread {
+    #[pb(index = 1)]
+    pub items: Vec<CommentThread>,
+}
+
+impl_def_and_def_mut!(RepeatedCommentThread, CommentThread);
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryCommentsRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct QueryCommentsParams {
+    #[pb(index = 1)]
+    pub doc_id: String,
+}
+
+impl TryInto<QueryCommentsParams> for QueryCommentsRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<QueryCommentsParams, Self::Error> {
+        let doc_id = ViewIdentify::parse(self.doc_id)?.0;
+        Ok(QueryCommentsParams { doc_id })
+    }
+}
+
+// Creates a thread anchored to the text between the start and the end of the document.
+#[derive(Default, ProtoBuf)]
+pub struct CreateCommentRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub start: i64,
+
+    #[pb(index = 3)]
+    pub end: i64,
+
+    #[pb(index = 4)]
+    pub content: String,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct CreateCommentParams {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub start: i64,
+
+    #[pb(index = 3)]
+    pub end: i64,
+
+    #[pb(index = 4)]
+    pub content: String,
+}
+
+impl TryInto<CreateCommentParams> for CreateCommentRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateCommentParams, Self::Error> {
+        let doc_id = ViewIdentify::parse(self.doc_id)?.0;
+        let content = CommentContent::parse(self.content)?.0;
+        if self.start < 0 || self.end <= self.start {
+            return Err(ErrorCode::CommentRangeInvalid);
+        }
+
+        Ok(CreateCommentParams {
+            doc_id,
+            start: self.start,
+            end: self.end,
+            content,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ReplyCommentRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub thread_id: String,
+
+    #[pb(index = 3)]
+    pub content: String,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ReplyCommentParams {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub thread_id: String,
+
+    #[pb(index = 3)]
+    pub content: String,
+}
+
+impl TryInto<ReplyCommentParams> for ReplyCommentRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ReplyCommentParams, Self::Error> {
+        let doc_id = ViewIdentify::parse(self.doc_id)?.0;
+        let thread_id = CommentIdentify::parse(self.thread_id)?.0;
+        let content = CommentContent::parse(self.content)?.0;
+        Ok(ReplyCommentParams {
+            doc_id,
+            thread_id,
+            content,
+        })
+    }
+}
+
+// The resolved thread can be reopened by setting the resolved to false.
+#[derive(Default, ProtoBuf)]
+pub struct ResolveCommentRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub thread_id: String,
+
+    #[pb(index = 3)]
+    pub resolved: bool,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ResolveCommentParams {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub thread_id: String,
+
+    #[pb(index = 3)]
+    pub resolved: bool,
+}
+
+impl TryInto<ResolveCommentParams> for ResolveCommentRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ResolveCommentParams, Self::Error> {
+        let doc_id = ViewIdentify::parse(self.doc_id)?.0;
+        let thread_id = CommentIdentify::parse(self.thread_id)?.0;
+        Ok(ResolveCommentParams {
+            doc_id,
+            thread_id,
+            resolved: self.resolved,
+        })
+    }
+}
@@ -0,0 +1,413 @@
+use crate::{
+    errors::ErrorCode,
+    parser::{
+        grid::{CellData, FieldIdentify, FieldName, RowIdentify},
+        view::ViewIdentify,
+    },
+};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use serde::{Deserialize, Serialize};
+use std::convert::TryInto;
+
+// The grid shares the same id with the view that it belongs to.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct Grid {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub fields: Vec<Field>,
+
+    #[pb(index = 3)]
+    pub rows: Vec<Row>,
+}
+
+#[derive(Eq, PartialEq, ProtoBuf, Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Field {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub field_type: FieldType,
+
+    // The options of the SingleSelect field.
+    #[pb(index = 4)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<String>,
+}
+
+impl Field {
+    // Returns the cell data if it matches the type of the field.
+    pub fn parse_cell_data(&self, data: String) -> Result<String, ErrorCode> {
+        let data = CellData::parse(&self.field_type, data, &self.options)?.0;
+        Ok(data)
+    }
+}
+
+#[derive(Eq, PartialEq, Debug, ProtoBuf_Enum, Clone, Serialize, Deserialize)]
+pub enum FieldType {
+    Text = 0,
+    Number = 1,
+    DateTime = 2,
+    SingleSelect = 3,
+    Checkbox = 4,
+}
+
+impl std::default::Default for FieldType {
+    fn default() -> Self {
+        FieldType::Text
+    }
+}
+
+impl std::convert::TryFrom<i32> for FieldType {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(FieldType::Text),
+            1 => Ok(FieldType::Number),
+            2 => Ok(FieldType::DateTime),
+            3 => Ok(FieldType::SingleSelect),
+            4 => Ok(FieldType::Checkbox),
+            _ => Err(format!("Invalid field type: {}", value)),
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, ProtoBuf, Default, Debug, Clone, Serialize, Deserialize)]
+pub struct FieldOptions {
+    #[pb(index = 1)]
+    pub items: Vec<String>,
+}
+
+#[derive(Eq, PartialEq, ProtoBuf, Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Row {
+    #[pb(index = 1)]
+    pub id: String,
+
+    // The row only contains the cells that have data.
+    #[pb(index = 2)]
+    pub cells: Vec<Cell>,
+}
+
+impl Row {
+    pub fn get_cell(&self, field_id: &str) -> Option<&Cell> {
+        self.cells.iter().find(|cell| cell.field_id == field_id)
+    }
+}
+
+#[derive(Eq, PartialEq, ProtoBuf, Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Cell {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    #[pb(index = 2)]
+    pub data: String,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryGridRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct GridId {
+    #[pb(index = 1)]
+    pub grid_id: String,
+}
+
+impl TryInto<GridId> for QueryGridRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<GridId, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        Ok(GridId { grid_id })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct CreateFieldRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub field_type: FieldType,
+
+    #[pb(index = 4)]
+    pub options: Vec<String>,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct CreateFieldParams {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub field_type: FieldType,
+
+    #[pb(index = 4)]
+    pub options: Vec<String>,
+}
+
+impl TryInto<CreateFieldParams> for CreateFieldRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateFieldParams, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let name = FieldName::parse(self.name)?.0;
+
+        Ok(CreateFieldParams {
+            grid_id,
+            name,
+            field_type: self.field_type,
+            options: self.options,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct UpdateFieldRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub field_id: String,
+
+    #[pb(index = 3, one_of)]
+    pub name: Option<String>,
+
+    #[pb(index = 4, one_of)]
+    pub options: Option<FieldOptions>,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct UpdateFieldParams {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub field_id: String,
+
+    #[pb(index = 3, one_of)]
+    pub name: Option<String>,
+
+    #[pb(index = 4, one_of)]
+    pub options: Option<FieldOptions>,
+}
+
+impl TryInto<UpdateFieldParams> for UpdateFieldRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateFieldParams, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let field_id = FieldIdentify::parse(self.field_id)?.0;
+        let name = match self.name {
+            None => None,
+            Some(name) => Some(FieldName::parse(name)?.0),
+        };
+
+        Ok(UpdateFieldParams {
+            grid_id,
+            field_id,
+            name,
+            options: self.options,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryFieldRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub field_id: String,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct FieldIdentifier {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub field_id: String,
+}
+
+impl TryInto<FieldIdentifier> for QueryFieldRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<FieldIdentifier, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let field_id = FieldIdentify::parse(self.field_id)?.0;
+        Ok(FieldIdentifier { grid_id, field_id })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryRowRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct RowIdentifier {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+}
+
+impl TryInto<RowIdentifier> for QueryRowRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<RowIdentifier, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let row_id = RowIdentify::parse(self.row_id)?.0;
+        Ok(RowIdentifier { grid_id, row_id })
+    }
+}
+
+// Updates the cells of the row at once. The cell data is validated against the field type when the
+// change is applied, because the request doesn't know the fields of the grid.
+#[derive(Default, ProtoBuf)]
+pub struct UpdateRowRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    #[pb(index = 3)]
+    pub cells: Vec<Cell>,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct UpdateRowParams {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    #[pb(index = 3)]
+    pub cells: Vec<Cell>,
+}
+
+impl TryInto<UpdateRowParams> for UpdateRowRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateRowParams, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let row_id = RowIdentify::parse(self.row_id)?.0;
+        let mut cells = vec![];
+        for cell in self.cells {
+            let field_id = FieldIdentify::parse(cell.field_id)?.0;
+            cells.push(Cell {
+                field_id,
+                data: cell.data,
+            });
+        }
+
+        Ok(UpdateRowParams { grid_id, row_id, cells })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct UpdateCellRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    #[pb(index = 3)]
+    pub field_id: String,
+
+    #[pb(index = 4)]
+    pub data: String,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct UpdateCellParams {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    #[pb(index = 3)]
+    pub field_id: String,
+
+    #[pb(index = 4)]
+    pub data: String,
+}
+
+impl TryInto<UpdateCellParams> for UpdateCellRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateCellParams, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let row_id = RowIdentify::parse(self.row_id)?.0;
+        let field_id = FieldIdentify::parse(self.field_id)?.0;
+
+        Ok(UpdateCellParams {
+            grid_id,
+            row_id,
+            field_id,
+            data: self.data,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryCellRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    #[pb(index = 3)]
+    pub field_id: String,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct CellIdentifier {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    #[pb(index = 3)]
+    pub field_id: String,
+}
+
+impl TryInto<CellIdentifier> for QueryCellRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CellIdentifier, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let row_id = RowIdentify::parse(self.row_id)?.0;
+        let field_id = FieldIdentify::parse(self.field_id)?.0;
+        Ok(CellIdentifier {
+            grid_id,
+            row_id,
+            field_id,
+        })
+    }
+}
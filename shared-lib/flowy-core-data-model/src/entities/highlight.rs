@@ -0,0 +1,48 @@
+use crate::impl_def_and_def_mut;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(PartialEq, Eq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum HighlightKind {
+    Keyword = 0,
+    StringLiteral = 1,
+    Number = 2,
+    Comment = 3,
+}
+
+impl std::default::Default for HighlightKind {
+    fn default() -> Self {
+        HighlightKind::Keyword
+    }
+}
+
+// The start and the end of the span are the offsets of the code in utf16 code units, the same as
+// the offsets of the document.
+#[derive(PartialEq, Eq, ProtoBuf, Default, Debug, Clone)]
+pub struct HighlightSpan {
+    #[pb(index = 1)]
+    pub start: i64,
+
+    #[pb(index = 2)]
+    pub end: i64,
+
+    #[pb(index = 3)]
+    pub kind: HighlightKind,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedHighlightSpan {
+    #[pb(index = 1)]
+    pub items: Vec<HighlightSpan>,
+}
+
+impl_def_and_def_mut!(RepeatedHighlightSpan, HighlightSpan);
+
+// The code is tokenized as plain text if the language is not supported.
+#[derive(Default, ProtoBuf)]
+pub struct HighlightCodeRequest {
+    #[pb(index = 1)]
+    pub language: String,
+
+    #[pb(index = 2)]
+    pub code: String,
+}
@@ -1,5 +1,10 @@
 pub mod app;
+pub mod board;
+pub mod comment;
+pub mod grid;
+pub mod highlight;
 pub mod share;
+pub mod template;
 pub mod trash;
 pub mod view;
 pub mod workspace;
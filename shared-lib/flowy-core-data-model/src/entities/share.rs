@@ -7,6 +7,7 @@ pub enum ExportType {
     Text = 0,
     Markdown = 1,
     Link = 2,
+    Html = 3,
 }
 
 impl std::default::Default for ExportType {
@@ -21,6 +22,7 @@ impl std::convert::From<i32> for ExportType {
             0 => ExportType::Text,
             1 => ExportType::Markdown,
             2 => ExportType::Link,
+            3 => ExportType::Html,
             _ => {
                 log::error!("Invalid export type: {}", val);
                 ExportType::Text
@@ -0,0 +1,165 @@
+use crate::{
+    errors::ErrorCode,
+    impl_def_and_def_mut,
+    parser::{
+        app::AppIdentify,
+        template::TemplateIdentify,
+        view::{ViewIdentify, ViewName},
+    },
+};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use serde::{Deserialize, Serialize};
+use std::convert::TryInto;
+
+#[derive(Eq, PartialEq, ProtoBuf, Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Template {
+    // The id of the view or the app that was marked as template.
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub workspace_id: String,
+
+    #[pb(index = 3)]
+    pub name: String,
+
+    #[pb(index = 4)]
+    pub ty: TemplateType,
+
+    #[pb(index = 5)]
+    pub create_time: i64,
+
+    #[pb(index = 6)]
+    pub created_by: String,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedTemplate {
+    #[pb(index = 1)]
+    pub items: Vec<Template>,
+}
+
+impl_def_and_def_mut!(RepeatedTemplate, Template);
+
+#[derive(Eq, PartialEq, Debug, ProtoBuf_Enum, Clone, Serialize, Deserialize)]
+pub enum TemplateType {
+    Unknown = 0,
+    View = 1,
+    App = 2,
+}
+
+impl std::convert::TryFrom<i32> for TemplateType {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TemplateType::Unknown),
+            1 => Ok(TemplateType::View),
+            2 => Ok(TemplateType::App),
+            _ => Err(format!("Invalid template type: {}", value)),
+        }
+    }
+}
+
+impl std::default::Default for TemplateType {
+    fn default() -> Self {
+        TemplateType::Unknown
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct CreateTemplateRequest {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub ty: TemplateType,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct CreateTemplateParams {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub ty: TemplateType,
+}
+
+impl TryInto<CreateTemplateParams> for CreateTemplateRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateTemplateParams, Self::Error> {
+        let id = match self.ty {
+            TemplateType::View => ViewIdentify::parse(self.id)?.0,
+            TemplateType::App => AppIdentify::parse(self.id)?.0,
+            TemplateType::Unknown => return Err(ErrorCode::TemplateTypeInvalid),
+        };
+
+        Ok(CreateTemplateParams { id, ty: self.ty })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryTemplateRequest {
+    #[pb(index = 1)]
+    pub template_id: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct TemplateId {
+    #[pb(index = 1)]
+    pub template_id: String,
+}
+
+impl TryInto<TemplateId> for QueryTemplateRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<TemplateId, Self::Error> {
+        let template_id = TemplateIdentify::parse(self.template_id)?.0;
+        Ok(TemplateId { template_id })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct CreateViewFromTemplateRequest {
+    #[pb(index = 1)]
+    pub template_id: String,
+
+    #[pb(index = 2)]
+    pub belong_to_id: String,
+
+    // Use the name of the template if the name is None
+    #[pb(index = 3, one_of)]
+    pub name: Option<String>,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct CreateViewFromTemplateParams {
+    #[pb(index = 1)]
+    pub template_id: String,
+
+    #[pb(index = 2)]
+    pub belong_to_id: String,
+
+    #[pb(index = 3, one_of)]
+    pub name: Option<String>,
+}
+
+impl TryInto<CreateViewFromTemplateParams> for CreateViewFromTemplateRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateViewFromTemplateParams, Self::Error> {
+        let template_id = TemplateIdentify::parse(self.template_id)?.0;
+        let belong_to_id = AppIdentify::parse(self.belong_to_id)?.0;
+        let name = match self.name {
+            None => None,
+            Some(name) => Some(ViewName::parse(name)?.0),
+        };
+
+        Ok(CreateViewFromTemplateParams {
+            template_id,
+            belong_to_id,
+            name,
+        })
+    }
+}
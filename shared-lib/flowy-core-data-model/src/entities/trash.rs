@@ -1,4 +1,7 @@
-use crate::{entities::app::App, impl_def_and_def_mut};
+use crate::{
+    entities::{app::App, workspace::Workspace},
+    impl_def_and_def_mut,
+};
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use serde::{Deserialize, Serialize};
 use std::fmt::Formatter;
@@ -19,6 +22,29 @@ pub struct Trash {
 
     #[pb(index = 5)]
     pub ty: TrashType,
+
+    #[pb(index = 6)]
+    #[serde(default)]
+    pub deleted_time: i64,
+
+    #[pb(index = 7)]
+    #[serde(default)]
+    pub deleted_by: String,
+}
+
+impl Trash {
+    // The trash that was created before the deleted_time was recorded falls back to the modified_time,
+    // the time that the item was modified at the last time before being deleted.
+    pub fn is_expired(&self, policy: &TrashRetentionPolicy, now: i64) -> bool {
+        if !policy.is_enabled() {
+            return false;
+        }
+        let deleted_time = match self.deleted_time {
+            0 => self.modified_time,
+            deleted_time => deleted_time,
+        };
+        now - deleted_time >= policy.retention_days * SECONDS_PER_DAY
+    }
 }
 
 #[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
@@ -37,6 +63,22 @@ impl std::convert::From<App> for Trash {
             modified_time: app.modified_time,
             create_time: app.create_time,
             ty: TrashType::App,
+            deleted_time: 0,
+            deleted_by: "".to_owned(),
+        }
+    }
+}
+
+impl std::convert::From<Workspace> for Trash {
+    fn from(workspace: Workspace) -> Self {
+        Trash {
+            id: workspace.id,
+            name: workspace.name,
+            modified_time: workspace.modified_time,
+            create_time: workspace.create_time,
+            ty: TrashType::Workspace,
+            deleted_time: 0,
+            deleted_by: "".to_owned(),
         }
     }
 }
@@ -46,6 +88,7 @@ pub enum TrashType {
     Unknown = 0,
     View = 1,
     App = 2,
+    Workspace = 3,
 }
 
 impl std::convert::TryFrom<i32> for TrashType {
@@ -56,6 +99,7 @@ impl std::convert::TryFrom<i32> for TrashType {
             0 => Ok(TrashType::Unknown),
             1 => Ok(TrashType::View),
             2 => Ok(TrashType::App),
+            3 => Ok(TrashType::Workspace),
             _ => Err(format!("Invalid trash type: {}", value)),
         }
     }
@@ -67,6 +111,27 @@ impl std::default::Default for TrashType {
     }
 }
 
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+// The trash is kept forever until the user enables the retention policy.
+pub const DEFAULT_TRASH_RETENTION_DAYS: i64 = 0;
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct TrashRetentionPolicy {
+    // The trash will be deleted permanently after retention_days. Zero means keep the trash forever.
+    #[pb(index = 1)]
+    pub retention_days: i64,
+}
+
+impl TrashRetentionPolicy {
+    pub fn new(retention_days: i64) -> Self {
+        Self { retention_days }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.retention_days > 0
+    }
+}
+
 #[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
 pub struct RepeatedTrashId {
     #[pb(index = 1)]
@@ -4,7 +4,9 @@ use crate::{
     impl_def_and_def_mut,
     parser::{
         app::AppIdentify,
-        view::{ViewDesc, ViewIdentify, ViewName, ViewThumbnail},
+        view::{
+            ViewDesc, ViewIdentify, ViewName, ViewPropertyKey, ViewPropertyValue, ViewTag, ViewTags, ViewThumbnail,
+        },
     },
 };
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
@@ -39,6 +41,20 @@ pub struct View {
 
     #[pb(index = 9)]
     pub create_time: i64,
+
+    #[pb(index = 10)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    #[pb(index = 11)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub properties: Vec<ViewProperty>,
+}
+
+impl View {
+    pub fn get_property(&self, key: &str) -> Option<&ViewProperty> {
+        self.properties.iter().find(|property| property.key == key)
+    }
 }
 
 #[derive(Eq, PartialEq, Debug, Default, ProtoBuf, Clone, Serialize, Deserialize)]
@@ -58,6 +74,8 @@ impl std::convert::From<View> for Trash {
             modified_time: view.modified_time,
             create_time: view.create_time,
             ty: TrashType::View,
+            deleted_time: 0,
+            deleted_by: "".to_owned(),
         }
     }
 }
@@ -66,6 +84,8 @@ impl std::convert::From<View> for Trash {
 pub enum ViewType {
     Blank = 0,
     Doc = 1,
+    Grid = 2,
+    Board = 3,
 }
 
 impl std::default::Default for ViewType {
@@ -77,6 +97,8 @@ impl std::default::Default for ViewType {
 impl std::convert::From<i32> for ViewType {
     fn from(val: i32) -> Self {
         match val {
+            3 => ViewType::Board,
+            2 => ViewType::Grid,
             1 => ViewType::Doc,
             0 => ViewType::Blank,
             _ => {
@@ -310,3 +332,191 @@ impl TryInto<UpdateViewParams> for UpdateViewRequest {
         })
     }
 }
+
+#[derive(Eq, PartialEq, ProtoBuf, Default, Debug, Clone, Serialize, Deserialize)]
+pub struct ViewProperty {
+    #[pb(index = 1)]
+    pub key: String,
+
+    #[pb(index = 2)]
+    pub ty: ViewPropertyType,
+
+    #[pb(index = 3)]
+    pub value: String,
+
+    // The options of the ViewPropertyType::Select
+    #[pb(index = 4)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<String>,
+}
+
+#[derive(Eq, PartialEq, Debug, ProtoBuf_Enum, Clone, Serialize, Deserialize)]
+pub enum ViewPropertyType {
+    Text = 0,
+    Number = 1,
+    Date = 2,
+    Select = 3,
+}
+
+impl std::default::Default for ViewPropertyType {
+    fn default() -> Self {
+        ViewPropertyType::Text
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct UpdateViewTagsRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct UpdateViewTagsParams {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub tags: Vec<String>,
+}
+
+impl TryInto<UpdateViewTagsParams> for UpdateViewTagsRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateViewTagsParams, Self::Error> {
+        let view_id = ViewIdentify::parse(self.view_id)?.0;
+        let tags = ViewTags::parse(self.tags)?.0;
+        Ok(UpdateViewTagsParams { view_id, tags })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct UpdateViewPropertyRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub key: String,
+
+    #[pb(index = 3)]
+    pub ty: ViewPropertyType,
+
+    // Remove the property if the value is None
+    #[pb(index = 4, one_of)]
+    pub value: Option<String>,
+
+    #[pb(index = 5)]
+    pub options: Vec<String>,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct UpdateViewPropertyParams {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub key: String,
+
+    #[pb(index = 3, one_of)]
+    pub property: Option<ViewProperty>,
+}
+
+impl TryInto<UpdateViewPropertyParams> for UpdateViewPropertyRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateViewPropertyParams, Self::Error> {
+        let view_id = ViewIdentify::parse(self.view_id)?.0;
+        let key = ViewPropertyKey::parse(self.key)?.0;
+        let property = match self.value {
+            None => None,
+            Some(value) => {
+                let value = ViewPropertyValue::parse(&self.ty, value, &self.options)?.0;
+                Some(ViewProperty {
+                    key: key.clone(),
+                    ty: self.ty,
+                    value,
+                    options: self.options,
+                })
+            }
+        };
+
+        Ok(UpdateViewPropertyParams { view_id, key, property })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ViewFilterRequest {
+    #[pb(index = 1, one_of)]
+    pub tag: Option<String>,
+
+    #[pb(index = 2, one_of)]
+    pub property_key: Option<String>,
+
+    // Match any value of the property if the property_value is None
+    #[pb(index = 3, one_of)]
+    pub property_value: Option<String>,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct ViewFilterParams {
+    #[pb(index = 1, one_of)]
+    pub tag: Option<String>,
+
+    #[pb(index = 2, one_of)]
+    pub property_key: Option<String>,
+
+    #[pb(index = 3, one_of)]
+    pub property_value: Option<String>,
+}
+
+impl ViewFilterParams {
+    pub fn is_match(&self, view: &View) -> bool {
+        if let Some(tag) = &self.tag {
+            if !view.tags.contains(tag) {
+                return false;
+            }
+        }
+
+        if let Some(key) = &self.property_key {
+            match view.get_property(key) {
+                None => return false,
+                Some(property) => {
+                    if let Some(value) = &self.property_value {
+                        if &property.value != value {
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+        true
+    }
+}
+
+impl TryInto<ViewFilterParams> for ViewFilterRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ViewFilterParams, Self::Error> {
+        let tag = match self.tag {
+            None => None,
+            Some(tag) => Some(ViewTag::parse(tag)?.0),
+        };
+
+        let property_key = match self.property_key {
+            None => None,
+            Some(key) => Some(ViewPropertyKey::parse(key)?.0),
+        };
+
+        if property_key.is_none() && self.property_value.is_some() {
+            return Err(ErrorCode::ViewPropertyKeyInvalid);
+        }
+
+        Ok(ViewFilterParams {
+            tag,
+            property_key,
+            property_value: self.property_value,
+        })
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     entities::{app::RepeatedApp, view::View},
     errors::*,
     impl_def_and_def_mut,
-    parser::workspace::{WorkspaceDesc, WorkspaceIdentify, WorkspaceName},
+    parser::workspace::{WorkspaceDesc, WorkspaceIdentify, WorkspaceName, WorkspacePassphrase},
 };
 use flowy_derive::ProtoBuf;
 use serde::{Deserialize, Serialize};
@@ -158,3 +158,44 @@ impl TryInto<UpdateWorkspaceParams> for UpdateWorkspaceRequest {
         })
     }
 }
+
+#[derive(ProtoBuf, Default)]
+pub struct WorkspaceEncryptionRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub passphrase: String,
+}
+
+#[derive(Clone, ProtoBuf, Default)]
+pub struct WorkspaceEncryptionParams {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub passphrase: String,
+}
+
+// The passphrase is never printed.
+impl std::fmt::Debug for WorkspaceEncryptionParams {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WorkspaceEncryptionParams")
+            .field("workspace_id", &self.workspace_id)
+            .finish()
+    }
+}
+
+impl TryInto<WorkspaceEncryptionParams> for WorkspaceEncryptionRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<WorkspaceEncryptionParams, Self::Error> {
+        let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?;
+        let passphrase = WorkspacePassphrase::parse(self.passphrase)?;
+
+        Ok(WorkspaceEncryptionParams {
+            workspace_id: workspace_id.0,
+            passphrase: passphrase.0,
+        })
+    }
+}
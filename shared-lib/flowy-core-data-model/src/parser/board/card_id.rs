@@ -0,0 +1,20 @@
+use crate::errors::ErrorCode;
+
+#[derive(Debug)]
+pub struct CardIdentify(pub String);
+
+impl CardIdentify {
+    pub fn parse(s: String) -> Result<CardIdentify, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::CardIdInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for CardIdentify {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
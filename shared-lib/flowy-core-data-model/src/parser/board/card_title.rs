@@ -0,0 +1,25 @@
+use crate::errors::ErrorCode;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug)]
+pub struct CardTitle(pub String);
+
+impl CardTitle {
+    pub fn parse(s: String) -> Result<CardTitle, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::CardTitleInvalid);
+        }
+
+        if s.graphemes(true).count() > 256 {
+            return Err(ErrorCode::CardTitleInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for CardTitle {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
@@ -0,0 +1,5 @@
+mod card_id;
+mod card_title;
+
+pub use card_id::*;
+pub use card_title::*;
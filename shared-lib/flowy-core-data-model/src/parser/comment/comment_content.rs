@@ -0,0 +1,25 @@
+use crate::errors::ErrorCode;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug)]
+pub struct CommentContent(pub String);
+
+impl CommentContent {
+    pub fn parse(s: String) -> Result<CommentContent, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::CommentContentInvalid);
+        }
+
+        if s.graphemes(true).count() > 4096 {
+            return Err(ErrorCode::CommentContentInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for CommentContent {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
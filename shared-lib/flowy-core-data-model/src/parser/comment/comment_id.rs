@@ -0,0 +1,20 @@
+use crate::errors::ErrorCode;
+
+#[derive(Debug)]
+pub struct CommentIdentify(pub String);
+
+impl CommentIdentify {
+    pub fn parse(s: String) -> Result<CommentIdentify, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::CommentIdInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for CommentIdentify {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
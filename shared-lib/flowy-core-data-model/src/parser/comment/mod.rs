@@ -0,0 +1,5 @@
+mod comment_content;
+mod comment_id;
+
+pub use comment_content::*;
+pub use comment_id::*;
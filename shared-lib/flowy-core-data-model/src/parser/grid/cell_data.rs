@@ -0,0 +1,26 @@
+use crate::{entities::grid::FieldType, errors::ErrorCode};
+
+pub const CHECKBOX_CHECKED: &str = "Yes";
+pub const CHECKBOX_UNCHECKED: &str = "No";
+
+#[derive(Debug)]
+pub struct CellData(pub String);
+
+impl CellData {
+    // Number is stored as f64 string, DateTime is stored as the timestamp in seconds and Checkbox is
+    // stored as Yes or No. The value of the SingleSelect must be one of the options.
+    pub fn parse(ty: &FieldType, data: String, options: &[String]) -> Result<CellData, ErrorCode> {
+        let is_valid = match ty {
+            FieldType::Text => true,
+            FieldType::Number => data.parse::<f64>().map(|n| n.is_finite()).unwrap_or(false),
+            FieldType::DateTime => data.parse::<i64>().is_ok(),
+            FieldType::SingleSelect => options.contains(&data),
+            FieldType::Checkbox => data == CHECKBOX_CHECKED || data == CHECKBOX_UNCHECKED,
+        };
+
+        if !is_valid {
+            return Err(ErrorCode::CellDataInvalid);
+        }
+        Ok(Self(data))
+    }
+}
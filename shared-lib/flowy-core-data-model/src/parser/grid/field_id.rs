@@ -0,0 +1,20 @@
+use crate::errors::ErrorCode;
+
+#[derive(Debug)]
+pub struct FieldIdentify(pub String);
+
+impl FieldIdentify {
+    pub fn parse(s: String) -> Result<FieldIdentify, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::FieldIdInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for FieldIdentify {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
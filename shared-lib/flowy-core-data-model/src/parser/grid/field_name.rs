@@ -0,0 +1,25 @@
+use crate::errors::ErrorCode;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug)]
+pub struct FieldName(pub String);
+
+impl FieldName {
+    pub fn parse(s: String) -> Result<FieldName, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::FieldNameInvalid);
+        }
+
+        if s.graphemes(true).count() > 256 {
+            return Err(ErrorCode::FieldNameInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for FieldName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
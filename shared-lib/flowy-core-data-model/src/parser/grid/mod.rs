@@ -0,0 +1,9 @@
+mod cell_data;
+mod field_id;
+mod field_name;
+mod row_id;
+
+pub use cell_data::*;
+pub use field_id::*;
+pub use field_name::*;
+pub use row_id::*;
@@ -0,0 +1,20 @@
+use crate::errors::ErrorCode;
+
+#[derive(Debug)]
+pub struct RowIdentify(pub String);
+
+impl RowIdentify {
+    pub fn parse(s: String) -> Result<RowIdentify, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::RowIdInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for RowIdentify {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
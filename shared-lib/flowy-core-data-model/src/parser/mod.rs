@@ -1,4 +1,8 @@
 pub mod app;
+pub mod board;
+pub mod comment;
+pub mod grid;
+pub mod template;
 pub mod trash;
 pub mod view;
 pub mod workspace;
@@ -0,0 +1,3 @@
+mod template_id;
+
+pub use template_id::*;
@@ -0,0 +1,20 @@
+use crate::errors::ErrorCode;
+
+#[derive(Debug)]
+pub struct TemplateIdentify(pub String);
+
+impl TemplateIdentify {
+    pub fn parse(s: String) -> Result<TemplateIdentify, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::TemplateIdInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for TemplateIdentify {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
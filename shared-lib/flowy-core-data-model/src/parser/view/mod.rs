@@ -2,10 +2,14 @@ mod delta_data;
 mod view_desc;
 mod view_id;
 mod view_name;
+mod view_property;
+mod view_tag;
 mod view_thumbnail;
 
 pub use delta_data::*;
 pub use view_desc::*;
 pub use view_id::*;
 pub use view_name::*;
+pub use view_property::*;
+pub use view_tag::*;
 pub use view_thumbnail::*;
@@ -0,0 +1,41 @@
+use crate::{entities::view::ViewPropertyType, errors::ErrorCode};
+
+#[derive(Debug)]
+pub struct ViewPropertyKey(pub String);
+
+impl ViewPropertyKey {
+    pub fn parse(s: String) -> Result<ViewPropertyKey, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::ViewPropertyKeyInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for ViewPropertyKey {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Debug)]
+pub struct ViewPropertyValue(pub String);
+
+impl ViewPropertyValue {
+    // Number is stored as f64 string, Date is stored as the timestamp in seconds. The value of the
+    // Select must be one of the options if the options are not empty.
+    pub fn parse(ty: &ViewPropertyType, value: String, options: &[String]) -> Result<ViewPropertyValue, ErrorCode> {
+        let is_valid = match ty {
+            ViewPropertyType::Text => true,
+            ViewPropertyType::Number => value.parse::<f64>().map(|n| n.is_finite()).unwrap_or(false),
+            ViewPropertyType::Date => value.parse::<i64>().is_ok(),
+            ViewPropertyType::Select => !value.trim().is_empty() && (options.is_empty() || options.contains(&value)),
+        };
+
+        if !is_valid {
+            return Err(ErrorCode::ViewPropertyValueInvalid);
+        }
+        Ok(Self(value))
+    }
+}
@@ -0,0 +1,38 @@
+use crate::errors::ErrorCode;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug)]
+pub struct ViewTag(pub String);
+
+impl ViewTag {
+    pub fn parse(s: String) -> Result<ViewTag, ErrorCode> {
+        let s = s.trim().to_owned();
+        if s.is_empty() || s.graphemes(true).count() > 64 {
+            return Err(ErrorCode::ViewTagInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for ViewTag {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Debug)]
+pub struct ViewTags(pub Vec<String>);
+
+impl ViewTags {
+    pub fn parse(tags: Vec<String>) -> Result<ViewTags, ErrorCode> {
+        let mut view_tags: Vec<String> = vec![];
+        for tag in tags {
+            let tag = ViewTag::parse(tag)?.0;
+            if !view_tags.contains(&tag) {
+                view_tags.push(tag);
+            }
+        }
+        Ok(Self(view_tags))
+    }
+}
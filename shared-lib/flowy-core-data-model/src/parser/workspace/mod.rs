@@ -1,7 +1,9 @@
 mod workspace_desc;
 mod workspace_id;
 mod workspace_name;
+mod workspace_passphrase;
 
 pub use workspace_desc::*;
 pub use workspace_id::*;
 pub use workspace_name::*;
+pub use workspace_passphrase::*;
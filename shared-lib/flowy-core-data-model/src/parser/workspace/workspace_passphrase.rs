@@ -0,0 +1,21 @@
+use crate::errors::ErrorCode;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug)]
+pub struct WorkspacePassphrase(pub String);
+
+impl WorkspacePassphrase {
+    pub fn parse(s: String) -> Result<WorkspacePassphrase, ErrorCode> {
+        if s.graphemes(true).count() < 8 {
+            return Err(ErrorCode::WorkspacePassphraseInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for WorkspacePassphrase {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
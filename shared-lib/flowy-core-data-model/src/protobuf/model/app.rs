@@ -34,6 +34,7 @@ pub struct App {
     pub version: i64,
     pub modified_time: i64,
     pub create_time: i64,
+    pub color_style: ::protobuf::SingularPtrField<ColorStyle>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -231,6 +232,39 @@ impl App {
     pub fn set_create_time(&mut self, v: i64) {
         self.create_time = v;
     }
+
+    // .ColorStyle color_style = 9;
+
+
+    pub fn get_color_style(&self) -> &ColorStyle {
+        self.color_style.as_ref().unwrap_or_else(|| <ColorStyle as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_color_style(&mut self) {
+        self.color_style.clear();
+    }
+
+    pub fn has_color_style(&self) -> bool {
+        self.color_style.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_color_style(&mut self, v: ColorStyle) {
+        self.color_style = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_color_style(&mut self) -> &mut ColorStyle {
+        if self.color_style.is_none() {
+            self.color_style.set_default();
+        }
+        self.color_style.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_color_style(&mut self) -> ColorStyle {
+        self.color_style.take().unwrap_or_else(|| ColorStyle::new())
+    }
 }
 
 impl ::protobuf::Message for App {
@@ -240,6 +274,11 @@ impl ::protobuf::Message for App {
                 return false;
             }
         };
+        for v in &self.color_style {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -283,6 +322,9 @@ impl ::protobuf::Message for App {
                     let tmp = is.read_int64()?;
                     self.create_time = tmp;
                 },
+                9 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.color_style)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -320,6 +362,10 @@ impl ::protobuf::Message for App {
         if self.create_time != 0 {
             my_size += ::protobuf::rt::value_size(8, self.create_time, ::protobuf::wire_format::WireTypeVarint);
         }
+        if let Some(ref v) = self.color_style.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -352,6 +398,11 @@ impl ::protobuf::Message for App {
         if self.create_time != 0 {
             os.write_int64(8, self.create_time)?;
         }
+        if let Some(ref v) = self.color_style.as_ref() {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -430,6 +481,11 @@ impl ::protobuf::Message for App {
                 |m: &App| { &m.create_time },
                 |m: &mut App| { &mut m.create_time },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ColorStyle>>(
+                "color_style",
+                |m: &App| { &m.color_style },
+                |m: &mut App| { &mut m.color_style },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<App>(
                 "App",
                 fields,
@@ -454,6 +510,7 @@ impl ::protobuf::Clear for App {
         self.version = 0;
         self.modified_time = 0;
         self.create_time = 0;
+        self.color_style.clear();
         self.unknown_fields.clear();
     }
 }
@@ -2649,13 +2706,14 @@ impl ::protobuf::reflect::ProtobufValue for UpdateAppParams {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\tapp.proto\x1a\nview.proto\"\xef\x01\n\x03App\x12\x0e\n\x02id\x18\x01\
+    \n\tapp.proto\x1a\nview.proto\"\x9d\x02\n\x03App\x12\x0e\n\x02id\x18\x01\
     \x20\x01(\tR\x02id\x12!\n\x0cworkspace_id\x18\x02\x20\x01(\tR\x0bworkspa\
     ceId\x12\x12\n\x04name\x18\x03\x20\x01(\tR\x04name\x12\x12\n\x04desc\x18\
     \x04\x20\x01(\tR\x04desc\x12-\n\nbelongings\x18\x05\x20\x01(\x0b2\r.Repe\
     atedViewR\nbelongings\x12\x18\n\x07version\x18\x06\x20\x01(\x03R\x07vers\
     ion\x12#\n\rmodified_time\x18\x07\x20\x01(\x03R\x0cmodifiedTime\x12\x1f\
-    \n\x0bcreate_time\x18\x08\x20\x01(\x03R\ncreateTime\")\n\x0bRepeatedApp\
+    \n\x0bcreate_time\x18\x08\x20\x01(\x03R\ncreateTime\x12,\n\x0bcolor_styl\
+    e\x18\t\x20\x01(\x0b2\x0b.ColorStyleR\ncolorStyle\")\n\x0bRepeatedApp\
     \x12\x1a\n\x05items\x18\x01\x20\x03(\x0b2\x04.AppR\x05items\"\x8b\x01\n\
     \x10CreateAppRequest\x12!\n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworks\
     paceId\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x12\n\x04desc\
@@ -2678,9 +2736,9 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x20\x01(\tH\x01R\x04desc\x12.\n\x0bcolor_style\x18\x04\x20\x01(\x0b2\
     \x0b.ColorStyleH\x02R\ncolorStyle\x12\x1b\n\x08is_trash\x18\x05\x20\x01(\
     \x08H\x03R\x07isTrashB\r\n\x0bone_of_nameB\r\n\x0bone_of_descB\x14\n\x12\
-    one_of_color_styleB\x11\n\x0fone_of_is_trashJ\xdb\x10\n\x06\x12\x04\0\02\
+    one_of_color_styleB\x11\n\x0fone_of_is_trashJ\x92\x11\n\x06\x12\x04\0\03\
     \x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\x14\n\
-    \n\n\x02\x04\0\x12\x04\x03\0\x0c\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\
+    \n\n\x02\x04\0\x12\x04\x03\0\r\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\
     \x0b\n\x0b\n\x04\x04\0\x02\0\x12\x03\x04\x04\x12\n\x0c\n\x05\x04\0\x02\0\
     \x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x0b\r\n\
     \x0c\n\x05\x04\0\x02\0\x03\x12\x03\x04\x10\x11\n\x0b\n\x04\x04\0\x02\x01\
@@ -2702,86 +2760,89 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x12\x03\n\n\x17\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03\n\x1a\x1b\n\x0b\n\
     \x04\x04\0\x02\x07\x12\x03\x0b\x04\x1a\n\x0c\n\x05\x04\0\x02\x07\x05\x12\
     \x03\x0b\x04\t\n\x0c\n\x05\x04\0\x02\x07\x01\x12\x03\x0b\n\x15\n\x0c\n\
-    \x05\x04\0\x02\x07\x03\x12\x03\x0b\x18\x19\n\n\n\x02\x04\x01\x12\x04\r\0\
-    \x0f\x01\n\n\n\x03\x04\x01\x01\x12\x03\r\x08\x13\n\x0b\n\x04\x04\x01\x02\
-    \0\x12\x03\x0e\x04\x1b\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03\x0e\x04\x0c\
-    \n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03\x0e\r\x10\n\x0c\n\x05\x04\x01\x02\
-    \0\x01\x12\x03\x0e\x11\x16\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0e\x19\
-    \x1a\n\n\n\x02\x04\x02\x12\x04\x10\0\x15\x01\n\n\n\x03\x04\x02\x01\x12\
-    \x03\x10\x08\x18\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x11\x04\x1c\n\x0c\n\
-    \x05\x04\x02\x02\0\x05\x12\x03\x11\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\
-    \x12\x03\x11\x0b\x17\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x11\x1a\x1b\n\
-    \x0b\n\x04\x04\x02\x02\x01\x12\x03\x12\x04\x14\n\x0c\n\x05\x04\x02\x02\
-    \x01\x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x12\
-    \x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x12\x12\x13\n\x0b\n\x04\
-    \x04\x02\x02\x02\x12\x03\x13\x04\x14\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\
-    \x03\x13\x04\n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x13\x0b\x0f\n\x0c\
-    \n\x05\x04\x02\x02\x02\x03\x12\x03\x13\x12\x13\n\x0b\n\x04\x04\x02\x02\
-    \x03\x12\x03\x14\x04\x1f\n\x0c\n\x05\x04\x02\x02\x03\x06\x12\x03\x14\x04\
-    \x0e\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x14\x0f\x1a\n\x0c\n\x05\x04\
-    \x02\x02\x03\x03\x12\x03\x14\x1d\x1e\n\n\n\x02\x04\x03\x12\x04\x16\0\x18\
-    \x01\n\n\n\x03\x04\x03\x01\x12\x03\x16\x08\x12\n\x0b\n\x04\x04\x03\x02\0\
-    \x12\x03\x17\x04\x1b\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x17\x04\n\n\
-    \x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x17\x0b\x16\n\x0c\n\x05\x04\x03\x02\
-    \0\x03\x12\x03\x17\x19\x1a\n\n\n\x02\x04\x04\x12\x04\x19\0\x1e\x01\n\n\n\
-    \x03\x04\x04\x01\x12\x03\x19\x08\x17\n\x0b\n\x04\x04\x04\x02\0\x12\x03\
-    \x1a\x04\x1c\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\x1a\x04\n\n\x0c\n\x05\
-    \x04\x04\x02\0\x01\x12\x03\x1a\x0b\x17\n\x0c\n\x05\x04\x04\x02\0\x03\x12\
-    \x03\x1a\x1a\x1b\n\x0b\n\x04\x04\x04\x02\x01\x12\x03\x1b\x04\x14\n\x0c\n\
-    \x05\x04\x04\x02\x01\x05\x12\x03\x1b\x04\n\n\x0c\n\x05\x04\x04\x02\x01\
-    \x01\x12\x03\x1b\x0b\x0f\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03\x1b\x12\
-    \x13\n\x0b\n\x04\x04\x04\x02\x02\x12\x03\x1c\x04\x14\n\x0c\n\x05\x04\x04\
-    \x02\x02\x05\x12\x03\x1c\x04\n\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03\
-    \x1c\x0b\x0f\n\x0c\n\x05\x04\x04\x02\x02\x03\x12\x03\x1c\x12\x13\n\x0b\n\
-    \x04\x04\x04\x02\x03\x12\x03\x1d\x04\x1f\n\x0c\n\x05\x04\x04\x02\x03\x06\
-    \x12\x03\x1d\x04\x0e\n\x0c\n\x05\x04\x04\x02\x03\x01\x12\x03\x1d\x0f\x1a\
-    \n\x0c\n\x05\x04\x04\x02\x03\x03\x12\x03\x1d\x1d\x1e\n\n\n\x02\x04\x05\
-    \x12\x04\x1f\0!\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1f\x08\x17\n\x0b\n\
-    \x04\x04\x05\x02\0\x12\x03\x20\x04\x20\n\x0c\n\x05\x04\x05\x02\0\x04\x12\
-    \x03\x20\x04\x0c\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03\x20\r\x13\n\x0c\n\
-    \x05\x04\x05\x02\0\x01\x12\x03\x20\x14\x1b\n\x0c\n\x05\x04\x05\x02\0\x03\
-    \x12\x03\x20\x1e\x1f\n\n\n\x02\x04\x06\x12\x04\"\0$\x01\n\n\n\x03\x04\
-    \x06\x01\x12\x03\"\x08\r\n\x0b\n\x04\x04\x06\x02\0\x12\x03#\x04\x16\n\
-    \x0c\n\x05\x04\x06\x02\0\x05\x12\x03#\x04\n\n\x0c\n\x05\x04\x06\x02\0\
-    \x01\x12\x03#\x0b\x11\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03#\x14\x15\n\n\
-    \n\x02\x04\x07\x12\x04%\0+\x01\n\n\n\x03\x04\x07\x01\x12\x03%\x08\x18\n\
-    \x0b\n\x04\x04\x07\x02\0\x12\x03&\x04\x16\n\x0c\n\x05\x04\x07\x02\0\x05\
-    \x12\x03&\x04\n\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03&\x0b\x11\n\x0c\n\
-    \x05\x04\x07\x02\0\x03\x12\x03&\x14\x15\n\x0b\n\x04\x04\x07\x08\0\x12\
-    \x03'\x04*\n\x0c\n\x05\x04\x07\x08\0\x01\x12\x03'\n\x15\n\x0b\n\x04\x04\
-    \x07\x02\x01\x12\x03'\x18(\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x03'\x18\
-    \x1e\n\x0c\n\x05\x04\x07\x02\x01\x01\x12\x03'\x1f#\n\x0c\n\x05\x04\x07\
-    \x02\x01\x03\x12\x03'&'\n\x0b\n\x04\x04\x07\x08\x01\x12\x03(\x04*\n\x0c\
-    \n\x05\x04\x07\x08\x01\x01\x12\x03(\n\x15\n\x0b\n\x04\x04\x07\x02\x02\
-    \x12\x03(\x18(\n\x0c\n\x05\x04\x07\x02\x02\x05\x12\x03(\x18\x1e\n\x0c\n\
-    \x05\x04\x07\x02\x02\x01\x12\x03(\x1f#\n\x0c\n\x05\x04\x07\x02\x02\x03\
-    \x12\x03(&'\n\x0b\n\x04\x04\x07\x08\x02\x12\x03)\x04<\n\x0c\n\x05\x04\
-    \x07\x08\x02\x01\x12\x03)\n\x1c\n\x0b\n\x04\x04\x07\x02\x03\x12\x03)\x1f\
-    :\n\x0c\n\x05\x04\x07\x02\x03\x06\x12\x03)\x1f)\n\x0c\n\x05\x04\x07\x02\
-    \x03\x01\x12\x03)*5\n\x0c\n\x05\x04\x07\x02\x03\x03\x12\x03)89\n\x0b\n\
-    \x04\x04\x07\x08\x03\x12\x03*\x040\n\x0c\n\x05\x04\x07\x08\x03\x01\x12\
-    \x03*\n\x19\n\x0b\n\x04\x04\x07\x02\x04\x12\x03*\x1c.\n\x0c\n\x05\x04\
-    \x07\x02\x04\x05\x12\x03*\x1c\x20\n\x0c\n\x05\x04\x07\x02\x04\x01\x12\
-    \x03*!)\n\x0c\n\x05\x04\x07\x02\x04\x03\x12\x03*,-\n\n\n\x02\x04\x08\x12\
-    \x04,\02\x01\n\n\n\x03\x04\x08\x01\x12\x03,\x08\x17\n\x0b\n\x04\x04\x08\
-    \x02\0\x12\x03-\x04\x16\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x03-\x04\n\n\
-    \x0c\n\x05\x04\x08\x02\0\x01\x12\x03-\x0b\x11\n\x0c\n\x05\x04\x08\x02\0\
-    \x03\x12\x03-\x14\x15\n\x0b\n\x04\x04\x08\x08\0\x12\x03.\x04*\n\x0c\n\
-    \x05\x04\x08\x08\0\x01\x12\x03.\n\x15\n\x0b\n\x04\x04\x08\x02\x01\x12\
-    \x03.\x18(\n\x0c\n\x05\x04\x08\x02\x01\x05\x12\x03.\x18\x1e\n\x0c\n\x05\
-    \x04\x08\x02\x01\x01\x12\x03.\x1f#\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\
-    \x03.&'\n\x0b\n\x04\x04\x08\x08\x01\x12\x03/\x04*\n\x0c\n\x05\x04\x08\
-    \x08\x01\x01\x12\x03/\n\x15\n\x0b\n\x04\x04\x08\x02\x02\x12\x03/\x18(\n\
-    \x0c\n\x05\x04\x08\x02\x02\x05\x12\x03/\x18\x1e\n\x0c\n\x05\x04\x08\x02\
-    \x02\x01\x12\x03/\x1f#\n\x0c\n\x05\x04\x08\x02\x02\x03\x12\x03/&'\n\x0b\
-    \n\x04\x04\x08\x08\x02\x12\x030\x04<\n\x0c\n\x05\x04\x08\x08\x02\x01\x12\
-    \x030\n\x1c\n\x0b\n\x04\x04\x08\x02\x03\x12\x030\x1f:\n\x0c\n\x05\x04\
-    \x08\x02\x03\x06\x12\x030\x1f)\n\x0c\n\x05\x04\x08\x02\x03\x01\x12\x030*\
-    5\n\x0c\n\x05\x04\x08\x02\x03\x03\x12\x03089\n\x0b\n\x04\x04\x08\x08\x03\
-    \x12\x031\x040\n\x0c\n\x05\x04\x08\x08\x03\x01\x12\x031\n\x19\n\x0b\n\
-    \x04\x04\x08\x02\x04\x12\x031\x1c.\n\x0c\n\x05\x04\x08\x02\x04\x05\x12\
-    \x031\x1c\x20\n\x0c\n\x05\x04\x08\x02\x04\x01\x12\x031!)\n\x0c\n\x05\x04\
-    \x08\x02\x04\x03\x12\x031,-b\x06proto3\
+    \x05\x04\0\x02\x07\x03\x12\x03\x0b\x18\x19\n\x0b\n\x04\x04\0\x02\x08\x12\
+    \x03\x0c\x04\x1f\n\x0c\n\x05\x04\0\x02\x08\x06\x12\x03\x0c\x04\x0e\n\x0c\
+    \n\x05\x04\0\x02\x08\x01\x12\x03\x0c\x0f\x1a\n\x0c\n\x05\x04\0\x02\x08\
+    \x03\x12\x03\x0c\x1d\x1e\n\n\n\x02\x04\x01\x12\x04\x0e\0\x10\x01\n\n\n\
+    \x03\x04\x01\x01\x12\x03\x0e\x08\x13\n\x0b\n\x04\x04\x01\x02\0\x12\x03\
+    \x0f\x04\x1b\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03\x0f\x04\x0c\n\x0c\n\
+    \x05\x04\x01\x02\0\x06\x12\x03\x0f\r\x10\n\x0c\n\x05\x04\x01\x02\0\x01\
+    \x12\x03\x0f\x11\x16\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0f\x19\x1a\n\
+    \n\n\x02\x04\x02\x12\x04\x11\0\x16\x01\n\n\n\x03\x04\x02\x01\x12\x03\x11\
+    \x08\x18\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x12\x04\x1c\n\x0c\n\x05\x04\
+    \x02\x02\0\x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\
+    \x12\x0b\x17\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x12\x1a\x1b\n\x0b\n\
+    \x04\x04\x02\x02\x01\x12\x03\x13\x04\x14\n\x0c\n\x05\x04\x02\x02\x01\x05\
+    \x12\x03\x13\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x13\x0b\x0f\n\
+    \x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x13\x12\x13\n\x0b\n\x04\x04\x02\
+    \x02\x02\x12\x03\x14\x04\x14\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x14\
+    \x04\n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x14\x0b\x0f\n\x0c\n\x05\
+    \x04\x02\x02\x02\x03\x12\x03\x14\x12\x13\n\x0b\n\x04\x04\x02\x02\x03\x12\
+    \x03\x15\x04\x1f\n\x0c\n\x05\x04\x02\x02\x03\x06\x12\x03\x15\x04\x0e\n\
+    \x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x15\x0f\x1a\n\x0c\n\x05\x04\x02\
+    \x02\x03\x03\x12\x03\x15\x1d\x1e\n\n\n\x02\x04\x03\x12\x04\x17\0\x19\x01\
+    \n\n\n\x03\x04\x03\x01\x12\x03\x17\x08\x12\n\x0b\n\x04\x04\x03\x02\0\x12\
+    \x03\x18\x04\x1b\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x18\x04\n\n\x0c\n\
+    \x05\x04\x03\x02\0\x01\x12\x03\x18\x0b\x16\n\x0c\n\x05\x04\x03\x02\0\x03\
+    \x12\x03\x18\x19\x1a\n\n\n\x02\x04\x04\x12\x04\x1a\0\x1f\x01\n\n\n\x03\
+    \x04\x04\x01\x12\x03\x1a\x08\x17\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x1b\
+    \x04\x1c\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\x1b\x04\n\n\x0c\n\x05\x04\
+    \x04\x02\0\x01\x12\x03\x1b\x0b\x17\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\
+    \x1b\x1a\x1b\n\x0b\n\x04\x04\x04\x02\x01\x12\x03\x1c\x04\x14\n\x0c\n\x05\
+    \x04\x04\x02\x01\x05\x12\x03\x1c\x04\n\n\x0c\n\x05\x04\x04\x02\x01\x01\
+    \x12\x03\x1c\x0b\x0f\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03\x1c\x12\x13\
+    \n\x0b\n\x04\x04\x04\x02\x02\x12\x03\x1d\x04\x14\n\x0c\n\x05\x04\x04\x02\
+    \x02\x05\x12\x03\x1d\x04\n\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03\x1d\
+    \x0b\x0f\n\x0c\n\x05\x04\x04\x02\x02\x03\x12\x03\x1d\x12\x13\n\x0b\n\x04\
+    \x04\x04\x02\x03\x12\x03\x1e\x04\x1f\n\x0c\n\x05\x04\x04\x02\x03\x06\x12\
+    \x03\x1e\x04\x0e\n\x0c\n\x05\x04\x04\x02\x03\x01\x12\x03\x1e\x0f\x1a\n\
+    \x0c\n\x05\x04\x04\x02\x03\x03\x12\x03\x1e\x1d\x1e\n\n\n\x02\x04\x05\x12\
+    \x04\x20\0\"\x01\n\n\n\x03\x04\x05\x01\x12\x03\x20\x08\x17\n\x0b\n\x04\
+    \x04\x05\x02\0\x12\x03!\x04\x20\n\x0c\n\x05\x04\x05\x02\0\x04\x12\x03!\
+    \x04\x0c\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03!\r\x13\n\x0c\n\x05\x04\
+    \x05\x02\0\x01\x12\x03!\x14\x1b\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03!\
+    \x1e\x1f\n\n\n\x02\x04\x06\x12\x04#\0%\x01\n\n\n\x03\x04\x06\x01\x12\x03\
+    #\x08\r\n\x0b\n\x04\x04\x06\x02\0\x12\x03$\x04\x16\n\x0c\n\x05\x04\x06\
+    \x02\0\x05\x12\x03$\x04\n\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03$\x0b\x11\
+    \n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03$\x14\x15\n\n\n\x02\x04\x07\x12\
+    \x04&\0,\x01\n\n\n\x03\x04\x07\x01\x12\x03&\x08\x18\n\x0b\n\x04\x04\x07\
+    \x02\0\x12\x03'\x04\x16\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03'\x04\n\n\
+    \x0c\n\x05\x04\x07\x02\0\x01\x12\x03'\x0b\x11\n\x0c\n\x05\x04\x07\x02\0\
+    \x03\x12\x03'\x14\x15\n\x0b\n\x04\x04\x07\x08\0\x12\x03(\x04*\n\x0c\n\
+    \x05\x04\x07\x08\0\x01\x12\x03(\n\x15\n\x0b\n\x04\x04\x07\x02\x01\x12\
+    \x03(\x18(\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x03(\x18\x1e\n\x0c\n\x05\
+    \x04\x07\x02\x01\x01\x12\x03(\x1f#\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\
+    \x03(&'\n\x0b\n\x04\x04\x07\x08\x01\x12\x03)\x04*\n\x0c\n\x05\x04\x07\
+    \x08\x01\x01\x12\x03)\n\x15\n\x0b\n\x04\x04\x07\x02\x02\x12\x03)\x18(\n\
+    \x0c\n\x05\x04\x07\x02\x02\x05\x12\x03)\x18\x1e\n\x0c\n\x05\x04\x07\x02\
+    \x02\x01\x12\x03)\x1f#\n\x0c\n\x05\x04\x07\x02\x02\x03\x12\x03)&'\n\x0b\
+    \n\x04\x04\x07\x08\x02\x12\x03*\x04<\n\x0c\n\x05\x04\x07\x08\x02\x01\x12\
+    \x03*\n\x1c\n\x0b\n\x04\x04\x07\x02\x03\x12\x03*\x1f:\n\x0c\n\x05\x04\
+    \x07\x02\x03\x06\x12\x03*\x1f)\n\x0c\n\x05\x04\x07\x02\x03\x01\x12\x03**\
+    5\n\x0c\n\x05\x04\x07\x02\x03\x03\x12\x03*89\n\x0b\n\x04\x04\x07\x08\x03\
+    \x12\x03+\x040\n\x0c\n\x05\x04\x07\x08\x03\x01\x12\x03+\n\x19\n\x0b\n\
+    \x04\x04\x07\x02\x04\x12\x03+\x1c.\n\x0c\n\x05\x04\x07\x02\x04\x05\x12\
+    \x03+\x1c\x20\n\x0c\n\x05\x04\x07\x02\x04\x01\x12\x03+!)\n\x0c\n\x05\x04\
+    \x07\x02\x04\x03\x12\x03+,-\n\n\n\x02\x04\x08\x12\x04-\03\x01\n\n\n\x03\
+    \x04\x08\x01\x12\x03-\x08\x17\n\x0b\n\x04\x04\x08\x02\0\x12\x03.\x04\x16\
+    \n\x0c\n\x05\x04\x08\x02\0\x05\x12\x03.\x04\n\n\x0c\n\x05\x04\x08\x02\0\
+    \x01\x12\x03.\x0b\x11\n\x0c\n\x05\x04\x08\x02\0\x03\x12\x03.\x14\x15\n\
+    \x0b\n\x04\x04\x08\x08\0\x12\x03/\x04*\n\x0c\n\x05\x04\x08\x08\0\x01\x12\
+    \x03/\n\x15\n\x0b\n\x04\x04\x08\x02\x01\x12\x03/\x18(\n\x0c\n\x05\x04\
+    \x08\x02\x01\x05\x12\x03/\x18\x1e\n\x0c\n\x05\x04\x08\x02\x01\x01\x12\
+    \x03/\x1f#\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\x03/&'\n\x0b\n\x04\x04\
+    \x08\x08\x01\x12\x030\x04*\n\x0c\n\x05\x04\x08\x08\x01\x01\x12\x030\n\
+    \x15\n\x0b\n\x04\x04\x08\x02\x02\x12\x030\x18(\n\x0c\n\x05\x04\x08\x02\
+    \x02\x05\x12\x030\x18\x1e\n\x0c\n\x05\x04\x08\x02\x02\x01\x12\x030\x1f#\
+    \n\x0c\n\x05\x04\x08\x02\x02\x03\x12\x030&'\n\x0b\n\x04\x04\x08\x08\x02\
+    \x12\x031\x04<\n\x0c\n\x05\x04\x08\x08\x02\x01\x12\x031\n\x1c\n\x0b\n\
+    \x04\x04\x08\x02\x03\x12\x031\x1f:\n\x0c\n\x05\x04\x08\x02\x03\x06\x12\
+    \x031\x1f)\n\x0c\n\x05\x04\x08\x02\x03\x01\x12\x031*5\n\x0c\n\x05\x04\
+    \x08\x02\x03\x03\x12\x03189\n\x0b\n\x04\x04\x08\x08\x03\x12\x032\x040\n\
+    \x0c\n\x05\x04\x08\x08\x03\x01\x12\x032\n\x19\n\x0b\n\x04\x04\x08\x02\
+    \x04\x12\x032\x1c.\n\x0c\n\x05\x04\x08\x02\x04\x05\x12\x032\x1c\x20\n\
+    \x0c\n\x05\x04\x08\x02\x04\x01\x12\x032!)\n\x0c\n\x05\x04\x08\x02\x04\
+    \x03\x12\x032,-b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,4548 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `board.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Board {
+    // message fields
+    pub id: ::std::string::String,
+    pub property: ::protobuf::SingularPtrField<BoardProperty>,
+    pub columns: ::protobuf::RepeatedField<BoardColumn>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Board {
+    fn default() -> &'a Board {
+        <Board as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Board {
+    pub fn new() -> Board {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // .BoardProperty property = 2;
+
+
+    pub fn get_property(&self) -> &BoardProperty {
+        self.property.as_ref().unwrap_or_else(|| <BoardProperty as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_property(&mut self) {
+        self.property.clear();
+    }
+
+    pub fn has_property(&self) -> bool {
+        self.property.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_property(&mut self, v: BoardProperty) {
+        self.property = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_property(&mut self) -> &mut BoardProperty {
+        if self.property.is_none() {
+            self.property.set_default();
+        }
+        self.property.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_property(&mut self) -> BoardProperty {
+        self.property.take().unwrap_or_else(|| BoardProperty::new())
+    }
+
+    // repeated .BoardColumn columns = 3;
+
+
+    pub fn get_columns(&self) -> &[BoardColumn] {
+        &self.columns
+    }
+    pub fn clear_columns(&mut self) {
+        self.columns.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_columns(&mut self, v: ::protobuf::RepeatedField<BoardColumn>) {
+        self.columns = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_columns(&mut self) -> &mut ::protobuf::RepeatedField<BoardColumn> {
+        &mut self.columns
+    }
+
+    // Take field
+    pub fn take_columns(&mut self) -> ::protobuf::RepeatedField<BoardColumn> {
+        ::std::mem::replace(&mut self.columns, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Board {
+    fn is_initialized(&self) -> bool {
+        for v in &self.property {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.columns {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.property)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.columns)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if let Some(ref v) = self.property.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.columns {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if let Some(ref v) = self.property.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.columns {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Board {
+        Board::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &Board| { &m.id },
+                |m: &mut Board| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BoardProperty>>(
+                "property",
+                |m: &Board| { &m.property },
+                |m: &mut Board| { &mut m.property },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BoardColumn>>(
+                "columns",
+                |m: &Board| { &m.columns },
+                |m: &mut Board| { &mut m.columns },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Board>(
+                "Board",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Board {
+        static instance: ::protobuf::rt::LazyV2<Board> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Board::new)
+    }
+}
+
+impl ::protobuf::Clear for Board {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.property.clear();
+        self.columns.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Board {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Board {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BoardProperty {
+    // message fields
+    pub key: ::std::string::String,
+    pub options: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BoardProperty {
+    fn default() -> &'a BoardProperty {
+        <BoardProperty as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BoardProperty {
+    pub fn new() -> BoardProperty {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // repeated string options = 2;
+
+
+    pub fn get_options(&self) -> &[::std::string::String] {
+        &self.options
+    }
+    pub fn clear_options(&mut self) {
+        self.options.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_options(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.options = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_options(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.options
+    }
+
+    // Take field
+    pub fn take_options(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.options, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BoardProperty {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.options)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        for value in &self.options {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        for v in &self.options {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BoardProperty {
+        BoardProperty::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key",
+                |m: &BoardProperty| { &m.key },
+                |m: &mut BoardProperty| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "options",
+                |m: &BoardProperty| { &m.options },
+                |m: &mut BoardProperty| { &mut m.options },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BoardProperty>(
+                "BoardProperty",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BoardProperty {
+        static instance: ::protobuf::rt::LazyV2<BoardProperty> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BoardProperty::new)
+    }
+}
+
+impl ::protobuf::Clear for BoardProperty {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.options.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BoardProperty {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BoardProperty {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BoardColumn {
+    // message fields
+    pub name: ::std::string::String,
+    pub cards: ::protobuf::RepeatedField<Card>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BoardColumn {
+    fn default() -> &'a BoardColumn {
+        <BoardColumn as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BoardColumn {
+    pub fn new() -> BoardColumn {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // repeated .Card cards = 2;
+
+
+    pub fn get_cards(&self) -> &[Card] {
+        &self.cards
+    }
+    pub fn clear_cards(&mut self) {
+        self.cards.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cards(&mut self, v: ::protobuf::RepeatedField<Card>) {
+        self.cards = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cards(&mut self) -> &mut ::protobuf::RepeatedField<Card> {
+        &mut self.cards
+    }
+
+    // Take field
+    pub fn take_cards(&mut self) -> ::protobuf::RepeatedField<Card> {
+        ::std::mem::replace(&mut self.cards, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BoardColumn {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cards {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cards)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        for value in &self.cards {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        for v in &self.cards {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BoardColumn {
+        BoardColumn::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &BoardColumn| { &m.name },
+                |m: &mut BoardColumn| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Card>>(
+                "cards",
+                |m: &BoardColumn| { &m.cards },
+                |m: &mut BoardColumn| { &mut m.cards },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BoardColumn>(
+                "BoardColumn",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BoardColumn {
+        static instance: ::protobuf::rt::LazyV2<BoardColumn> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BoardColumn::new)
+    }
+}
+
+impl ::protobuf::Clear for BoardColumn {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.cards.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BoardColumn {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BoardColumn {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Card {
+    // message fields
+    pub id: ::std::string::String,
+    pub title: ::std::string::String,
+    pub description: ::std::string::String,
+    pub properties: ::protobuf::RepeatedField<CardProperty>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Card {
+    fn default() -> &'a Card {
+        <Card as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Card {
+    pub fn new() -> Card {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string title = 2;
+
+
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+    pub fn clear_title(&mut self) {
+        self.title.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_title(&mut self, v: ::std::string::String) {
+        self.title = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_title(&mut self) -> &mut ::std::string::String {
+        &mut self.title
+    }
+
+    // Take field
+    pub fn take_title(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.title, ::std::string::String::new())
+    }
+
+    // string description = 3;
+
+
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+    pub fn clear_description(&mut self) {
+        self.description.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.description = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        &mut self.description
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.description, ::std::string::String::new())
+    }
+
+    // repeated .CardProperty properties = 4;
+
+
+    pub fn get_properties(&self) -> &[CardProperty] {
+        &self.properties
+    }
+    pub fn clear_properties(&mut self) {
+        self.properties.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_properties(&mut self, v: ::protobuf::RepeatedField<CardProperty>) {
+        self.properties = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_properties(&mut self) -> &mut ::protobuf::RepeatedField<CardProperty> {
+        &mut self.properties
+    }
+
+    // Take field
+    pub fn take_properties(&mut self) -> ::protobuf::RepeatedField<CardProperty> {
+        ::std::mem::replace(&mut self.properties, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Card {
+    fn is_initialized(&self) -> bool {
+        for v in &self.properties {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.title)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.description)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.properties)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.title.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.title);
+        }
+        if !self.description.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.description);
+        }
+        for value in &self.properties {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.title.is_empty() {
+            os.write_string(2, &self.title)?;
+        }
+        if !self.description.is_empty() {
+            os.write_string(3, &self.description)?;
+        }
+        for v in &self.properties {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Card {
+        Card::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &Card| { &m.id },
+                |m: &mut Card| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "title",
+                |m: &Card| { &m.title },
+                |m: &mut Card| { &mut m.title },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "description",
+                |m: &Card| { &m.description },
+                |m: &mut Card| { &mut m.description },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CardProperty>>(
+                "properties",
+                |m: &Card| { &m.properties },
+                |m: &mut Card| { &mut m.properties },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Card>(
+                "Card",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Card {
+        static instance: ::protobuf::rt::LazyV2<Card> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Card::new)
+    }
+}
+
+impl ::protobuf::Clear for Card {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.title.clear();
+        self.description.clear();
+        self.properties.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Card {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Card {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CardProperty {
+    // message fields
+    pub key: ::std::string::String,
+    pub value: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CardProperty {
+    fn default() -> &'a CardProperty {
+        <CardProperty as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CardProperty {
+    pub fn new() -> CardProperty {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // string value = 2;
+
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.value, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for CardProperty {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_string(2, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CardProperty {
+        CardProperty::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key",
+                |m: &CardProperty| { &m.key },
+                |m: &mut CardProperty| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "value",
+                |m: &CardProperty| { &m.value },
+                |m: &mut CardProperty| { &mut m.value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CardProperty>(
+                "CardProperty",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CardProperty {
+        static instance: ::protobuf::rt::LazyV2<CardProperty> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CardProperty::new)
+    }
+}
+
+impl ::protobuf::Clear for CardProperty {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CardProperty {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CardProperty {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryBoardRequest {
+    // message fields
+    pub board_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryBoardRequest {
+    fn default() -> &'a QueryBoardRequest {
+        <QueryBoardRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryBoardRequest {
+    pub fn new() -> QueryBoardRequest {
+        ::std::default::Default::default()
+    }
+
+    // string board_id = 1;
+
+
+    pub fn get_board_id(&self) -> &str {
+        &self.board_id
+    }
+    pub fn clear_board_id(&mut self) {
+        self.board_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_board_id(&mut self, v: ::std::string::String) {
+        self.board_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_board_id(&mut self) -> &mut ::std::string::String {
+        &mut self.board_id
+    }
+
+    // Take field
+    pub fn take_board_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.board_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for QueryBoardRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.board_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.board_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.board_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.board_id.is_empty() {
+            os.write_string(1, &self.board_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryBoardRequest {
+        QueryBoardRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "board_id",
+                |m: &QueryBoardRequest| { &m.board_id },
+                |m: &mut QueryBoardRequest| { &mut m.board_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryBoardRequest>(
+                "QueryBoardRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryBoardRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryBoardRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryBoardRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryBoardRequest {
+    fn clear(&mut self) {
+        self.board_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryBoardRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryBoardRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BoardId {
+    // message fields
+    pub board_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BoardId {
+    fn default() -> &'a BoardId {
+        <BoardId as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BoardId {
+    pub fn new() -> BoardId {
+        ::std::default::Default::default()
+    }
+
+    // string board_id = 1;
+
+
+    pub fn get_board_id(&self) -> &str {
+        &self.board_id
+    }
+    pub fn clear_board_id(&mut self) {
+        self.board_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_board_id(&mut self, v: ::std::string::String) {
+        self.board_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_board_id(&mut self) -> &mut ::std::string::String {
+        &mut self.board_id
+    }
+
+    // Take field
+    pub fn take_board_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.board_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for BoardId {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.board_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.board_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.board_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.board_id.is_empty() {
+            os.write_string(1, &self.board_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BoardId {
+        BoardId::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "board_id",
+                |m: &BoardId| { &m.board_id },
+                |m: &mut BoardId| { &mut m.board_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BoardId>(
+                "BoardId",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BoardId {
+        static instance: ::protobuf::rt::LazyV2<BoardId> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BoardId::new)
+    }
+}
+
+impl ::protobuf::Clear for BoardId {
+    fn clear(&mut self) {
+        self.board_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BoardId {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BoardId {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateBoardPropertyRequest {
+    // message fields
+    pub board_id: ::std::string::String,
+    pub key: ::std::string::String,
+    pub options: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateBoardPropertyRequest {
+    fn default() -> &'a UpdateBoardPropertyRequest {
+        <UpdateBoardPropertyRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdateBoardPropertyRequest {
+    pub fn new() -> UpdateBoardPropertyRequest {
+        ::std::default::Default::default()
+    }
+
+    // string board_id = 1;
+
+
+    pub fn get_board_id(&self) -> &str {
+        &self.board_id
+    }
+    pub fn clear_board_id(&mut self) {
+        self.board_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_board_id(&mut self, v: ::std::string::String) {
+        self.board_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_board_id(&mut self) -> &mut ::std::string::String {
+        &mut self.board_id
+    }
+
+    // Take field
+    pub fn take_board_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.board_id, ::std::string::String::new())
+    }
+
+    // string key = 2;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // repeated string options = 3;
+
+
+    pub fn get_options(&self) -> &[::std::string::String] {
+        &self.options
+    }
+    pub fn clear_options(&mut self) {
+        self.options.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_options(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.options = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_options(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.options
+    }
+
+    // Take field
+    pub fn take_options(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.options, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for UpdateBoardPropertyRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.board_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.options)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.board_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.board_id);
+        }
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.key);
+        }
+        for value in &self.options {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.board_id.is_empty() {
+            os.write_string(1, &self.board_id)?;
+        }
+        if !self.key.is_empty() {
+            os.write_string(2, &self.key)?;
+        }
+        for v in &self.options {
+            os.write_string(3, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateBoardPropertyRequest {
+        UpdateBoardPropertyRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "board_id",
+                |m: &UpdateBoardPropertyRequest| { &m.board_id },
+                |m: &mut UpdateBoardPropertyRequest| { &mut m.board_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key",
+                |m: &UpdateBoardPropertyRequest| { &m.key },
+                |m: &mut UpdateBoardPropertyRequest| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "options",
+                |m: &UpdateBoardPropertyRequest| { &m.options },
+                |m: &mut UpdateBoardPropertyRequest| { &mut m.options },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateBoardPropertyRequest>(
+                "UpdateBoardPropertyRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateBoardPropertyRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdateBoardPropertyRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateBoardPropertyRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateBoardPropertyRequest {
+    fn clear(&mut self) {
+        self.board_id.clear();
+        self.key.clear();
+        self.options.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateBoardPropertyRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateBoardPropertyRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateBoardPropertyParams {
+    // message fields
+    pub board_id: ::std::string::String,
+    pub property: ::protobuf::SingularPtrField<BoardProperty>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateBoardPropertyParams {
+    fn default() -> &'a UpdateBoardPropertyParams {
+        <UpdateBoardPropertyParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdateBoardPropertyParams {
+    pub fn new() -> UpdateBoardPropertyParams {
+        ::std::default::Default::default()
+    }
+
+    // string board_id = 1;
+
+
+    pub fn get_board_id(&self) -> &str {
+        &self.board_id
+    }
+    pub fn clear_board_id(&mut self) {
+        self.board_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_board_id(&mut self, v: ::std::string::String) {
+        self.board_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_board_id(&mut self) -> &mut ::std::string::String {
+        &mut self.board_id
+    }
+
+    // Take field
+    pub fn take_board_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.board_id, ::std::string::String::new())
+    }
+
+    // .BoardProperty property = 2;
+
+
+    pub fn get_property(&self) -> &BoardProperty {
+        self.property.as_ref().unwrap_or_else(|| <BoardProperty as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_property(&mut self) {
+        self.property.clear();
+    }
+
+    pub fn has_property(&self) -> bool {
+        self.property.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_property(&mut self, v: BoardProperty) {
+        self.property = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_property(&mut self) -> &mut BoardProperty {
+        if self.property.is_none() {
+            self.property.set_default();
+        }
+        self.property.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_property(&mut self) -> BoardProperty {
+        self.property.take().unwrap_or_else(|| BoardProperty::new())
+    }
+}
+
+impl ::protobuf::Message for UpdateBoardPropertyParams {
+    fn is_initialized(&self) -> bool {
+        for v in &self.property {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.board_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.property)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.board_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.board_id);
+        }
+        if let Some(ref v) = self.property.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.board_id.is_empty() {
+            os.write_string(1, &self.board_id)?;
+        }
+        if let Some(ref v) = self.property.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateBoardPropertyParams {
+        UpdateBoardPropertyParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "board_id",
+                |m: &UpdateBoardPropertyParams| { &m.board_id },
+                |m: &mut UpdateBoardPropertyParams| { &mut m.board_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BoardProperty>>(
+                "property",
+                |m: &UpdateBoardPropertyParams| { &m.property },
+                |m: &mut UpdateBoardPropertyParams| { &mut m.property },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateBoardPropertyParams>(
+                "UpdateBoardPropertyParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateBoardPropertyParams {
+        static instance: ::protobuf::rt::LazyV2<UpdateBoardPropertyParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateBoardPropertyParams::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateBoardPropertyParams {
+    fn clear(&mut self) {
+        self.board_id.clear();
+        self.property.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateBoardPropertyParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateBoardPropertyParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateCardRequest {
+    // message fields
+    pub board_id: ::std::string::String,
+    pub title: ::std::string::String,
+    pub column: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateCardRequest {
+    fn default() -> &'a CreateCardRequest {
+        <CreateCardRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateCardRequest {
+    pub fn new() -> CreateCardRequest {
+        ::std::default::Default::default()
+    }
+
+    // string board_id = 1;
+
+
+    pub fn get_board_id(&self) -> &str {
+        &self.board_id
+    }
+    pub fn clear_board_id(&mut self) {
+        self.board_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_board_id(&mut self, v: ::std::string::String) {
+        self.board_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_board_id(&mut self) -> &mut ::std::string::String {
+        &mut self.board_id
+    }
+
+    // Take field
+    pub fn take_board_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.board_id, ::std::string::String::new())
+    }
+
+    // string title = 2;
+
+
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+    pub fn clear_title(&mut self) {
+        self.title.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_title(&mut self, v: ::std::string::String) {
+        self.title = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_title(&mut self) -> &mut ::std::string::String {
+        &mut self.title
+    }
+
+    // Take field
+    pub fn take_title(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.title, ::std::string::String::new())
+    }
+
+    // string column = 3;
+
+
+    pub fn get_column(&self) -> &str {
+        &self.column
+    }
+    pub fn clear_column(&mut self) {
+        self.column.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_column(&mut self, v: ::std::string::String) {
+        self.column = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_column(&mut self) -> &mut ::std::string::String {
+        &mut self.column
+    }
+
+    // Take field
+    pub fn take_column(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.column, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for CreateCardRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.board_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.title)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.column)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.board_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.board_id);
+        }
+        if !self.title.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.title);
+        }
+        if !self.column.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.column);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.board_id.is_empty() {
+            os.write_string(1, &self.board_id)?;
+        }
+        if !self.title.is_empty() {
+            os.write_string(2, &self.title)?;
+        }
+        if !self.column.is_empty() {
+            os.write_string(3, &self.column)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateCardRequest {
+        CreateCardRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "board_id",
+                |m: &CreateCardRequest| { &m.board_id },
+                |m: &mut CreateCardRequest| { &mut m.board_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "title",
+                |m: &CreateCardRequest| { &m.title },
+                |m: &mut CreateCardRequest| { &mut m.title },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "column",
+                |m: &CreateCardRequest| { &m.column },
+                |m: &mut CreateCardRequest| { &mut m.column },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateCardRequest>(
+                "CreateCardRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateCardRequest {
+        static instance: ::protobuf::rt::LazyV2<CreateCardRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateCardRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateCardRequest {
+    fn clear(&mut self) {
+        self.board_id.clear();
+        self.title.clear();
+        self.column.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateCardRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateCardRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateCardParams {
+    // message fields
+    pub board_id: ::std::string::String,
+    pub title: ::std::string::String,
+    pub column: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateCardParams {
+    fn default() -> &'a CreateCardParams {
+        <CreateCardParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateCardParams {
+    pub fn new() -> CreateCardParams {
+        ::std::default::Default::default()
+    }
+
+    // string board_id = 1;
+
+
+    pub fn get_board_id(&self) -> &str {
+        &self.board_id
+    }
+    pub fn clear_board_id(&mut self) {
+        self.board_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_board_id(&mut self, v: ::std::string::String) {
+        self.board_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_board_id(&mut self) -> &mut ::std::string::String {
+        &mut self.board_id
+    }
+
+    // Take field
+    pub fn take_board_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.board_id, ::std::string::String::new())
+    }
+
+    // string title = 2;
+
+
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+    pub fn clear_title(&mut self) {
+        self.title.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_title(&mut self, v: ::std::string::String) {
+        self.title = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_title(&mut self) -> &mut ::std::string::String {
+        &mut self.title
+    }
+
+    // Take field
+    pub fn take_title(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.title, ::std::string::String::new())
+    }
+
+    // string column = 3;
+
+
+    pub fn get_column(&self) -> &str {
+        &self.column
+    }
+    pub fn clear_column(&mut self) {
+        self.column.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_column(&mut self, v: ::std::string::String) {
+        self.column = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_column(&mut self) -> &mut ::std::string::String {
+        &mut self.column
+    }
+
+    // Take field
+    pub fn take_column(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.column, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for CreateCardParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.board_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.title)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.column)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.board_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.board_id);
+        }
+        if !self.title.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.title);
+        }
+        if !self.column.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.column);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.board_id.is_empty() {
+            os.write_string(1, &self.board_id)?;
+        }
+        if !self.title.is_empty() {
+            os.write_string(2, &self.title)?;
+        }
+        if !self.column.is_empty() {
+            os.write_string(3, &self.column)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateCardParams {
+        CreateCardParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "board_id",
+                |m: &CreateCardParams| { &m.board_id },
+                |m: &mut CreateCardParams| { &mut m.board_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "title",
+                |m: &CreateCardParams| { &m.title },
+                |m: &mut CreateCardParams| { &mut m.title },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "column",
+                |m: &CreateCardParams| { &m.column },
+                |m: &mut CreateCardParams| { &mut m.column },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateCardParams>(
+                "CreateCardParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateCardParams {
+        static instance: ::protobuf::rt::LazyV2<CreateCardParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateCardParams::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateCardParams {
+    fn clear(&mut self) {
+        self.board_id.clear();
+        self.title.clear();
+        self.column.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateCardParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateCardParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateCardRequest {
+    // message fields
+    pub board_id: ::std::string::String,
+    pub card_id: ::std::string::String,
+    pub properties: ::protobuf::RepeatedField<CardProperty>,
+    // message oneof groups
+    pub one_of_title: ::std::option::Option<UpdateCardRequest_oneof_one_of_title>,
+    pub one_of_description: ::std::option::Option<UpdateCardRequest_oneof_one_of_description>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateCardRequest {
+    fn default() -> &'a UpdateCardRequest {
+        <UpdateCardRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateCardRequest_oneof_one_of_title {
+    title(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateCardRequest_oneof_one_of_description {
+    description(::std::string::String),
+}
+
+impl UpdateCardRequest {
+    pub fn new() -> UpdateCardRequest {
+        ::std::default::Default::default()
+    }
+
+    // string board_id = 1;
+
+
+    pub fn get_board_id(&self) -> &str {
+        &self.board_id
+    }
+    pub fn clear_board_id(&mut self) {
+        self.board_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_board_id(&mut self, v: ::std::string::String) {
+        self.board_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_board_id(&mut self) -> &mut ::std::string::String {
+        &mut self.board_id
+    }
+
+    // Take field
+    pub fn take_board_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.board_id, ::std::string::String::new())
+    }
+
+    // string card_id = 2;
+
+
+    pub fn get_card_id(&self) -> &str {
+        &self.card_id
+    }
+    pub fn clear_card_id(&mut self) {
+        self.card_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_card_id(&mut self, v: ::std::string::String) {
+        self.card_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_card_id(&mut self) -> &mut ::std::string::String {
+        &mut self.card_id
+    }
+
+    // Take field
+    pub fn take_card_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.card_id, ::std::string::String::new())
+    }
+
+    // string title = 3;
+
+
+    pub fn get_title(&self) -> &str {
+        match self.one_of_title {
+            ::std::option::Option::Some(UpdateCardRequest_oneof_one_of_title::title(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_title(&mut self) {
+        self.one_of_title = ::std::option::Option::None;
+    }
+
+    pub fn has_title(&self) -> bool {
+        match self.one_of_title {
+            ::std::option::Option::Some(UpdateCardRequest_oneof_one_of_title::title(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_title(&mut self, v: ::std::string::String) {
+        self.one_of_title = ::std::option::Option::Some(UpdateCardRequest_oneof_one_of_title::title(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_title(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateCardRequest_oneof_one_of_title::title(_)) = self.one_of_title {
+        } else {
+            self.one_of_title = ::std::option::Option::Some(UpdateCardRequest_oneof_one_of_title::title(::std::string::String::new()));
+        }
+        match self.one_of_title {
+            ::std::option::Option::Some(UpdateCardRequest_oneof_one_of_title::title(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_title(&mut self) -> ::std::string::String {
+        if self.has_title() {
+            match self.one_of_title.take() {
+                ::std::option::Option::Some(UpdateCardRequest_oneof_one_of_title::title(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string description = 4;
+
+
+    pub fn get_description(&self) -> &str {
+        match self.one_of_description {
+            ::std::option::Option::Some(UpdateCardRequest_oneof_one_of_description::description(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_description(&mut self) {
+        self.one_of_description = ::std::option::Option::None;
+    }
+
+    pub fn has_description(&self) -> bool {
+        match self.one_of_description {
+            ::std::option::Option::Some(UpdateCardRequest_oneof_one_of_description::description(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.one_of_description = ::std::option::Option::Some(UpdateCardRequest_oneof_one_of_description::description(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateCardRequest_oneof_one_of_description::description(_)) = self.one_of_description {
+        } else {
+            self.one_of_description = ::std::option::Option::Some(UpdateCardRequest_oneof_one_of_description::description(::std::string::String::new()));
+        }
+        match self.one_of_description {
+            ::std::option::Option::Some(UpdateCardRequest_oneof_one_of_description::description(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        if self.has_description() {
+            match self.one_of_description.take() {
+                ::std::option::Option::Some(UpdateCardRequest_oneof_one_of_description::description(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // repeated .CardProperty properties = 5;
+
+
+    pub fn get_properties(&self) -> &[CardProperty] {
+        &self.properties
+    }
+    pub fn clear_properties(&mut self) {
+        self.properties.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_properties(&mut self, v: ::protobuf::RepeatedField<CardProperty>) {
+        self.properties = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_properties(&mut self) -> &mut ::protobuf::RepeatedField<CardProperty> {
+        &mut self.properties
+    }
+
+    // Take field
+    pub fn take_properties(&mut self) -> ::protobuf::RepeatedField<CardProperty> {
+        ::std::mem::replace(&mut self.properties, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for UpdateCardRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.properties {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.board_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.card_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_title = ::std::option::Option::Some(UpdateCardRequest_oneof_one_of_title::title(is.read_string()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_description = ::std::option::Option::Some(UpdateCardRequest_oneof_one_of_description::description(is.read_string()?));
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.properties)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.board_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.board_id);
+        }
+        if !self.card_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.card_id);
+        }
+        for value in &self.properties {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let ::std::option::Option::Some(ref v) = self.one_of_title {
+            match v {
+                &UpdateCardRequest_oneof_one_of_title::title(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_description {
+            match v {
+                &UpdateCardRequest_oneof_one_of_description::description(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.board_id.is_empty() {
+            os.write_string(1, &self.board_id)?;
+        }
+        if !self.card_id.is_empty() {
+            os.write_string(2, &self.card_id)?;
+        }
+        for v in &self.properties {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let ::std::option::Option::Some(ref v) = self.one_of_title {
+            match v {
+                &UpdateCardRequest_oneof_one_of_title::title(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_description {
+            match v {
+                &UpdateCardRequest_oneof_one_of_description::description(ref v) => {
+                    os.write_string(4, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateCardRequest {
+        UpdateCardRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "board_id",
+                |m: &UpdateCardRequest| { &m.board_id },
+                |m: &mut UpdateCardRequest| { &mut m.board_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "card_id",
+                |m: &UpdateCardRequest| { &m.card_id },
+                |m: &mut UpdateCardRequest| { &mut m.card_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "title",
+                UpdateCardRequest::has_title,
+                UpdateCardRequest::get_title,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "description",
+                UpdateCardRequest::has_description,
+                UpdateCardRequest::get_description,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CardProperty>>(
+                "properties",
+                |m: &UpdateCardRequest| { &m.properties },
+                |m: &mut UpdateCardRequest| { &mut m.properties },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateCardRequest>(
+                "UpdateCardRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateCardRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdateCardRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateCardRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateCardRequest {
+    fn clear(&mut self) {
+        self.board_id.clear();
+        self.card_id.clear();
+        self.one_of_title = ::std::option::Option::None;
+        self.one_of_description = ::std::option::Option::None;
+        self.properties.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateCardRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateCardRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateCardParams {
+    // message fields
+    pub board_id: ::std::string::String,
+    pub card_id: ::std::string::String,
+    pub properties: ::protobuf::RepeatedField<CardProperty>,
+    // message oneof groups
+    pub one_of_title: ::std::option::Option<UpdateCardParams_oneof_one_of_title>,
+    pub one_of_description: ::std::option::Option<UpdateCardParams_oneof_one_of_description>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateCardParams {
+    fn default() -> &'a UpdateCardParams {
+        <UpdateCardParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateCardParams_oneof_one_of_title {
+    title(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateCardParams_oneof_one_of_description {
+    description(::std::string::String),
+}
+
+impl UpdateCardParams {
+    pub fn new() -> UpdateCardParams {
+        ::std::default::Default::default()
+    }
+
+    // string board_id = 1;
+
+
+    pub fn get_board_id(&self) -> &str {
+        &self.board_id
+    }
+    pub fn clear_board_id(&mut self) {
+        self.board_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_board_id(&mut self, v: ::std::string::String) {
+        self.board_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_board_id(&mut self) -> &mut ::std::string::String {
+        &mut self.board_id
+    }
+
+    // Take field
+    pub fn take_board_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.board_id, ::std::string::String::new())
+    }
+
+    // string card_id = 2;
+
+
+    pub fn get_card_id(&self) -> &str {
+        &self.card_id
+    }
+    pub fn clear_card_id(&mut self) {
+        self.card_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_card_id(&mut self, v: ::std::string::String) {
+        self.card_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_card_id(&mut self) -> &mut ::std::string::String {
+        &mut self.card_id
+    }
+
+    // Take field
+    pub fn take_card_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.card_id, ::std::string::String::new())
+    }
+
+    // string title = 3;
+
+
+    pub fn get_title(&self) -> &str {
+        match self.one_of_title {
+            ::std::option::Option::Some(UpdateCardParams_oneof_one_of_title::title(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_title(&mut self) {
+        self.one_of_title = ::std::option::Option::None;
+    }
+
+    pub fn has_title(&self) -> bool {
+        match self.one_of_title {
+            ::std::option::Option::Some(UpdateCardParams_oneof_one_of_title::title(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_title(&mut self, v: ::std::string::String) {
+        self.one_of_title = ::std::option::Option::Some(UpdateCardParams_oneof_one_of_title::title(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_title(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateCardParams_oneof_one_of_title::title(_)) = self.one_of_title {
+        } else {
+            self.one_of_title = ::std::option::Option::Some(UpdateCardParams_oneof_one_of_title::title(::std::string::String::new()));
+        }
+        match self.one_of_title {
+            ::std::option::Option::Some(UpdateCardParams_oneof_one_of_title::title(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_title(&mut self) -> ::std::string::String {
+        if self.has_title() {
+            match self.one_of_title.take() {
+                ::std::option::Option::Some(UpdateCardParams_oneof_one_of_title::title(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string description = 4;
+
+
+    pub fn get_description(&self) -> &str {
+        match self.one_of_description {
+            ::std::option::Option::Some(UpdateCardParams_oneof_one_of_description::description(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_description(&mut self) {
+        self.one_of_description = ::std::option::Option::None;
+    }
+
+    pub fn has_description(&self) -> bool {
+        match self.one_of_description {
+            ::std::option::Option::Some(UpdateCardParams_oneof_one_of_description::description(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.one_of_description = ::std::option::Option::Some(UpdateCardParams_oneof_one_of_description::description(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateCardParams_oneof_one_of_description::description(_)) = self.one_of_description {
+        } else {
+            self.one_of_description = ::std::option::Option::Some(UpdateCardParams_oneof_one_of_description::description(::std::string::String::new()));
+        }
+        match self.one_of_description {
+            ::std::option::Option::Some(UpdateCardParams_oneof_one_of_description::description(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        if self.has_description() {
+            match self.one_of_description.take() {
+                ::std::option::Option::Some(UpdateCardParams_oneof_one_of_description::description(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // repeated .CardProperty properties = 5;
+
+
+    pub fn get_properties(&self) -> &[CardProperty] {
+        &self.properties
+    }
+    pub fn clear_properties(&mut self) {
+        self.properties.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_properties(&mut self, v: ::protobuf::RepeatedField<CardProperty>) {
+        self.properties = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_properties(&mut self) -> &mut ::protobuf::RepeatedField<CardProperty> {
+        &mut self.properties
+    }
+
+    // Take field
+    pub fn take_properties(&mut self) -> ::protobuf::RepeatedField<CardProperty> {
+        ::std::mem::replace(&mut self.properties, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for UpdateCardParams {
+    fn is_initialized(&self) -> bool {
+        for v in &self.properties {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.board_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.card_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_title = ::std::option::Option::Some(UpdateCardParams_oneof_one_of_title::title(is.read_string()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_description = ::std::option::Option::Some(UpdateCardParams_oneof_one_of_description::description(is.read_string()?));
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.properties)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.board_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.board_id);
+        }
+        if !self.card_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.card_id);
+        }
+        for value in &self.properties {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let ::std::option::Option::Some(ref v) = self.one_of_title {
+            match v {
+                &UpdateCardParams_oneof_one_of_title::title(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_description {
+            match v {
+                &UpdateCardParams_oneof_one_of_description::description(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.board_id.is_empty() {
+            os.write_string(1, &self.board_id)?;
+        }
+        if !self.card_id.is_empty() {
+            os.write_string(2, &self.card_id)?;
+        }
+        for v in &self.properties {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let ::std::option::Option::Some(ref v) = self.one_of_title {
+            match v {
+                &UpdateCardParams_oneof_one_of_title::title(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_description {
+            match v {
+                &UpdateCardParams_oneof_one_of_description::description(ref v) => {
+                    os.write_string(4, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateCardParams {
+        UpdateCardParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "board_id",
+                |m: &UpdateCardParams| { &m.board_id },
+                |m: &mut UpdateCardParams| { &mut m.board_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "card_id",
+                |m: &UpdateCardParams| { &m.card_id },
+                |m: &mut UpdateCardParams| { &mut m.card_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "title",
+                UpdateCardParams::has_title,
+                UpdateCardParams::get_title,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "description",
+                UpdateCardParams::has_description,
+                UpdateCardParams::get_description,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CardProperty>>(
+                "properties",
+                |m: &UpdateCardParams| { &m.properties },
+                |m: &mut UpdateCardParams| { &mut m.properties },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateCardParams>(
+                "UpdateCardParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateCardParams {
+        static instance: ::protobuf::rt::LazyV2<UpdateCardParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateCardParams::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateCardParams {
+    fn clear(&mut self) {
+        self.board_id.clear();
+        self.card_id.clear();
+        self.one_of_title = ::std::option::Option::None;
+        self.one_of_description = ::std::option::Option::None;
+        self.properties.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateCardParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateCardParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MoveCardRequest {
+    // message fields
+    pub board_id: ::std::string::String,
+    pub card_id: ::std::string::String,
+    pub column: ::std::string::String,
+    // message oneof groups
+    pub one_of_after_card_id: ::std::option::Option<MoveCardRequest_oneof_one_of_after_card_id>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MoveCardRequest {
+    fn default() -> &'a MoveCardRequest {
+        <MoveCardRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum MoveCardRequest_oneof_one_of_after_card_id {
+    after_card_id(::std::string::String),
+}
+
+impl MoveCardRequest {
+    pub fn new() -> MoveCardRequest {
+        ::std::default::Default::default()
+    }
+
+    // string board_id = 1;
+
+
+    pub fn get_board_id(&self) -> &str {
+        &self.board_id
+    }
+    pub fn clear_board_id(&mut self) {
+        self.board_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_board_id(&mut self, v: ::std::string::String) {
+        self.board_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_board_id(&mut self) -> &mut ::std::string::String {
+        &mut self.board_id
+    }
+
+    // Take field
+    pub fn take_board_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.board_id, ::std::string::String::new())
+    }
+
+    // string card_id = 2;
+
+
+    pub fn get_card_id(&self) -> &str {
+        &self.card_id
+    }
+    pub fn clear_card_id(&mut self) {
+        self.card_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_card_id(&mut self, v: ::std::string::String) {
+        self.card_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_card_id(&mut self) -> &mut ::std::string::String {
+        &mut self.card_id
+    }
+
+    // Take field
+    pub fn take_card_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.card_id, ::std::string::String::new())
+    }
+
+    // string column = 3;
+
+
+    pub fn get_column(&self) -> &str {
+        &self.column
+    }
+    pub fn clear_column(&mut self) {
+        self.column.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_column(&mut self, v: ::std::string::String) {
+        self.column = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_column(&mut self) -> &mut ::std::string::String {
+        &mut self.column
+    }
+
+    // Take field
+    pub fn take_column(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.column, ::std::string::String::new())
+    }
+
+    // string after_card_id = 4;
+
+
+    pub fn get_after_card_id(&self) -> &str {
+        match self.one_of_after_card_id {
+            ::std::option::Option::Some(MoveCardRequest_oneof_one_of_after_card_id::after_card_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_after_card_id(&mut self) {
+        self.one_of_after_card_id = ::std::option::Option::None;
+    }
+
+    pub fn has_after_card_id(&self) -> bool {
+        match self.one_of_after_card_id {
+            ::std::option::Option::Some(MoveCardRequest_oneof_one_of_after_card_id::after_card_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_after_card_id(&mut self, v: ::std::string::String) {
+        self.one_of_after_card_id = ::std::option::Option::Some(MoveCardRequest_oneof_one_of_after_card_id::after_card_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_after_card_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(MoveCardRequest_oneof_one_of_after_card_id::after_card_id(_)) = self.one_of_after_card_id {
+        } else {
+            self.one_of_after_card_id = ::std::option::Option::Some(MoveCardRequest_oneof_one_of_after_card_id::after_card_id(::std::string::String::new()));
+        }
+        match self.one_of_after_card_id {
+            ::std::option::Option::Some(MoveCardRequest_oneof_one_of_after_card_id::after_card_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_after_card_id(&mut self) -> ::std::string::String {
+        if self.has_after_card_id() {
+            match self.one_of_after_card_id.take() {
+                ::std::option::Option::Some(MoveCardRequest_oneof_one_of_after_card_id::after_card_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for MoveCardRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.board_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.card_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.column)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_after_card_id = ::std::option::Option::Some(MoveCardRequest_oneof_one_of_after_card_id::after_card_id(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.board_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.board_id);
+        }
+        if !self.card_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.card_id);
+        }
+        if !self.column.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.column);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_after_card_id {
+            match v {
+                &MoveCardRequest_oneof_one_of_after_card_id::after_card_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.board_id.is_empty() {
+            os.write_string(1, &self.board_id)?;
+        }
+        if !self.card_id.is_empty() {
+            os.write_string(2, &self.card_id)?;
+        }
+        if !self.column.is_empty() {
+            os.write_string(3, &self.column)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_after_card_id {
+            match v {
+                &MoveCardRequest_oneof_one_of_after_card_id::after_card_id(ref v) => {
+                    os.write_string(4, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MoveCardRequest {
+        MoveCardRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "board_id",
+                |m: &MoveCardRequest| { &m.board_id },
+                |m: &mut MoveCardRequest| { &mut m.board_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "card_id",
+                |m: &MoveCardRequest| { &m.card_id },
+                |m: &mut MoveCardRequest| { &mut m.card_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "column",
+                |m: &MoveCardRequest| { &m.column },
+                |m: &mut MoveCardRequest| { &mut m.column },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "after_card_id",
+                MoveCardRequest::has_after_card_id,
+                MoveCardRequest::get_after_card_id,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MoveCardRequest>(
+                "MoveCardRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MoveCardRequest {
+        static instance: ::protobuf::rt::LazyV2<MoveCardRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MoveCardRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for MoveCardRequest {
+    fn clear(&mut self) {
+        self.board_id.clear();
+        self.card_id.clear();
+        self.column.clear();
+        self.one_of_after_card_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MoveCardRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveCardRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MoveCardParams {
+    // message fields
+    pub board_id: ::std::string::String,
+    pub card_id: ::std::string::String,
+    pub column: ::std::string::String,
+    // message oneof groups
+    pub one_of_after_card_id: ::std::option::Option<MoveCardParams_oneof_one_of_after_card_id>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MoveCardParams {
+    fn default() -> &'a MoveCardParams {
+        <MoveCardParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum MoveCardParams_oneof_one_of_after_card_id {
+    after_card_id(::std::string::String),
+}
+
+impl MoveCardParams {
+    pub fn new() -> MoveCardParams {
+        ::std::default::Default::default()
+    }
+
+    // string board_id = 1;
+
+
+    pub fn get_board_id(&self) -> &str {
+        &self.board_id
+    }
+    pub fn clear_board_id(&mut self) {
+        self.board_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_board_id(&mut self, v: ::std::string::String) {
+        self.board_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_board_id(&mut self) -> &mut ::std::string::String {
+        &mut self.board_id
+    }
+
+    // Take field
+    pub fn take_board_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.board_id, ::std::string::String::new())
+    }
+
+    // string card_id = 2;
+
+
+    pub fn get_card_id(&self) -> &str {
+        &self.card_id
+    }
+    pub fn clear_card_id(&mut self) {
+        self.card_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_card_id(&mut self, v: ::std::string::String) {
+        self.card_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_card_id(&mut self) -> &mut ::std::string::String {
+        &mut self.card_id
+    }
+
+    // Take field
+    pub fn take_card_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.card_id, ::std::string::String::new())
+    }
+
+    // string column = 3;
+
+
+    pub fn get_column(&self) -> &str {
+        &self.column
+    }
+    pub fn clear_column(&mut self) {
+        self.column.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_column(&mut self, v: ::std::string::String) {
+        self.column = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_column(&mut self) -> &mut ::std::string::String {
+        &mut self.column
+    }
+
+    // Take field
+    pub fn take_column(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.column, ::std::string::String::new())
+    }
+
+    // string after_card_id = 4;
+
+
+    pub fn get_after_card_id(&self) -> &str {
+        match self.one_of_after_card_id {
+            ::std::option::Option::Some(MoveCardParams_oneof_one_of_after_card_id::after_card_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_after_card_id(&mut self) {
+        self.one_of_after_card_id = ::std::option::Option::None;
+    }
+
+    pub fn has_after_card_id(&self) -> bool {
+        match self.one_of_after_card_id {
+            ::std::option::Option::Some(MoveCardParams_oneof_one_of_after_card_id::after_card_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_after_card_id(&mut self, v: ::std::string::String) {
+        self.one_of_after_card_id = ::std::option::Option::Some(MoveCardParams_oneof_one_of_after_card_id::after_card_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_after_card_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(MoveCardParams_oneof_one_of_after_card_id::after_card_id(_)) = self.one_of_after_card_id {
+        } else {
+            self.one_of_after_card_id = ::std::option::Option::Some(MoveCardParams_oneof_one_of_after_card_id::after_card_id(::std::string::String::new()));
+        }
+        match self.one_of_after_card_id {
+            ::std::option::Option::Some(MoveCardParams_oneof_one_of_after_card_id::after_card_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_after_card_id(&mut self) -> ::std::string::String {
+        if self.has_after_card_id() {
+            match self.one_of_after_card_id.take() {
+                ::std::option::Option::Some(MoveCardParams_oneof_one_of_after_card_id::after_card_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for MoveCardParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.board_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.card_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.column)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_after_card_id = ::std::option::Option::Some(MoveCardParams_oneof_one_of_after_card_id::after_card_id(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.board_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.board_id);
+        }
+        if !self.card_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.card_id);
+        }
+        if !self.column.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.column);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_after_card_id {
+            match v {
+                &MoveCardParams_oneof_one_of_after_card_id::after_card_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.board_id.is_empty() {
+            os.write_string(1, &self.board_id)?;
+        }
+        if !self.card_id.is_empty() {
+            os.write_string(2, &self.card_id)?;
+        }
+        if !self.column.is_empty() {
+            os.write_string(3, &self.column)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_after_card_id {
+            match v {
+                &MoveCardParams_oneof_one_of_after_card_id::after_card_id(ref v) => {
+                    os.write_string(4, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MoveCardParams {
+        MoveCardParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "board_id",
+                |m: &MoveCardParams| { &m.board_id },
+                |m: &mut MoveCardParams| { &mut m.board_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "card_id",
+                |m: &MoveCardParams| { &m.card_id },
+                |m: &mut MoveCardParams| { &mut m.card_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "column",
+                |m: &MoveCardParams| { &m.column },
+                |m: &mut MoveCardParams| { &mut m.column },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "after_card_id",
+                MoveCardParams::has_after_card_id,
+                MoveCardParams::get_after_card_id,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MoveCardParams>(
+                "MoveCardParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MoveCardParams {
+        static instance: ::protobuf::rt::LazyV2<MoveCardParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MoveCardParams::new)
+    }
+}
+
+impl ::protobuf::Clear for MoveCardParams {
+    fn clear(&mut self) {
+        self.board_id.clear();
+        self.card_id.clear();
+        self.column.clear();
+        self.one_of_after_card_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MoveCardParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveCardParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryCardRequest {
+    // message fields
+    pub board_id: ::std::string::String,
+    pub card_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryCardRequest {
+    fn default() -> &'a QueryCardRequest {
+        <QueryCardRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryCardRequest {
+    pub fn new() -> QueryCardRequest {
+        ::std::default::Default::default()
+    }
+
+    // string board_id = 1;
+
+
+    pub fn get_board_id(&self) -> &str {
+        &self.board_id
+    }
+    pub fn clear_board_id(&mut self) {
+        self.board_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_board_id(&mut self, v: ::std::string::String) {
+        self.board_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_board_id(&mut self) -> &mut ::std::string::String {
+        &mut self.board_id
+    }
+
+    // Take field
+    pub fn take_board_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.board_id, ::std::string::String::new())
+    }
+
+    // string card_id = 2;
+
+
+    pub fn get_card_id(&self) -> &str {
+        &self.card_id
+    }
+    pub fn clear_card_id(&mut self) {
+        self.card_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_card_id(&mut self, v: ::std::string::String) {
+        self.card_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_card_id(&mut self) -> &mut ::std::string::String {
+        &mut self.card_id
+    }
+
+    // Take field
+    pub fn take_card_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.card_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for QueryCardRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.board_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.card_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.board_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.board_id);
+        }
+        if !self.card_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.card_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.board_id.is_empty() {
+            os.write_string(1, &self.board_id)?;
+        }
+        if !self.card_id.is_empty() {
+            os.write_string(2, &self.card_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryCardRequest {
+        QueryCardRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "board_id",
+                |m: &QueryCardRequest| { &m.board_id },
+                |m: &mut QueryCardRequest| { &mut m.board_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "card_id",
+                |m: &QueryCardRequest| { &m.card_id },
+                |m: &mut QueryCardRequest| { &mut m.card_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryCardRequest>(
+                "QueryCardRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryCardRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryCardRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryCardRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryCardRequest {
+    fn clear(&mut self) {
+        self.board_id.clear();
+        self.card_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryCardRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryCardRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CardIdentifier {
+    // message fields
+    pub board_id: ::std::string::String,
+    pub card_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CardIdentifier {
+    fn default() -> &'a CardIdentifier {
+        <CardIdentifier as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CardIdentifier {
+    pub fn new() -> CardIdentifier {
+        ::std::default::Default::default()
+    }
+
+    // string board_id = 1;
+
+
+    pub fn get_board_id(&self) -> &str {
+        &self.board_id
+    }
+    pub fn clear_board_id(&mut self) {
+        self.board_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_board_id(&mut self, v: ::std::string::String) {
+        self.board_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_board_id(&mut self) -> &mut ::std::string::String {
+        &mut self.board_id
+    }
+
+    // Take field
+    pub fn take_board_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.board_id, ::std::string::String::new())
+    }
+
+    // string card_id = 2;
+
+
+    pub fn get_card_id(&self) -> &str {
+        &self.card_id
+    }
+    pub fn clear_card_id(&mut self) {
+        self.card_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_card_id(&mut self, v: ::std::string::String) {
+        self.card_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_card_id(&mut self) -> &mut ::std::string::String {
+        &mut self.card_id
+    }
+
+    // Take field
+    pub fn take_card_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.card_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for CardIdentifier {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.board_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.card_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.board_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.board_id);
+        }
+        if !self.card_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.card_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.board_id.is_empty() {
+            os.write_string(1, &self.board_id)?;
+        }
+        if !self.card_id.is_empty() {
+            os.write_string(2, &self.card_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CardIdentifier {
+        CardIdentifier::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "board_id",
+                |m: &CardIdentifier| { &m.board_id },
+                |m: &mut CardIdentifier| { &mut m.board_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "card_id",
+                |m: &CardIdentifier| { &m.card_id },
+                |m: &mut CardIdentifier| { &mut m.card_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CardIdentifier>(
+                "CardIdentifier",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CardIdentifier {
+        static instance: ::protobuf::rt::LazyV2<CardIdentifier> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CardIdentifier::new)
+    }
+}
+
+impl ::protobuf::Clear for CardIdentifier {
+    fn clear(&mut self) {
+        self.board_id.clear();
+        self.card_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CardIdentifier {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CardIdentifier {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0bboard.proto\"k\n\x05Board\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02i\
+    d\x12*\n\x08property\x18\x02\x20\x01(\x0b2\x0e.BoardPropertyR\x08propert\
+    y\x12&\n\x07columns\x18\x03\x20\x03(\x0b2\x0c.BoardColumnR\x07columns\";\
+    \n\rBoardProperty\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x18\n\
+    \x07options\x18\x02\x20\x03(\tR\x07options\">\n\x0bBoardColumn\x12\x12\n\
+    \x04name\x18\x01\x20\x01(\tR\x04name\x12\x1b\n\x05cards\x18\x02\x20\x03(\
+    \x0b2\x05.CardR\x05cards\"}\n\x04Card\x12\x0e\n\x02id\x18\x01\x20\x01(\t\
+    R\x02id\x12\x14\n\x05title\x18\x02\x20\x01(\tR\x05title\x12\x20\n\x0bdes\
+    cription\x18\x03\x20\x01(\tR\x0bdescription\x12-\n\nproperties\x18\x04\
+    \x20\x03(\x0b2\r.CardPropertyR\nproperties\"6\n\x0cCardProperty\x12\x10\
+    \n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\
+    \tR\x05value\".\n\x11QueryBoardRequest\x12\x19\n\x08board_id\x18\x01\x20\
+    \x01(\tR\x07boardId\"$\n\x07BoardId\x12\x19\n\x08board_id\x18\x01\x20\
+    \x01(\tR\x07boardId\"c\n\x1aUpdateBoardPropertyRequest\x12\x19\n\x08boar\
+    d_id\x18\x01\x20\x01(\tR\x07boardId\x12\x10\n\x03key\x18\x02\x20\x01(\tR\
+    \x03key\x12\x18\n\x07options\x18\x03\x20\x03(\tR\x07options\"b\n\x19Upda\
+    teBoardPropertyParams\x12\x19\n\x08board_id\x18\x01\x20\x01(\tR\x07board\
+    Id\x12*\n\x08property\x18\x02\x20\x01(\x0b2\x0e.BoardPropertyR\x08proper\
+    ty\"\\\n\x11CreateCardRequest\x12\x19\n\x08board_id\x18\x01\x20\x01(\tR\
+    \x07boardId\x12\x14\n\x05title\x18\x02\x20\x01(\tR\x05title\x12\x16\n\
+    \x06column\x18\x03\x20\x01(\tR\x06column\"[\n\x10CreateCardParams\x12\
+    \x19\n\x08board_id\x18\x01\x20\x01(\tR\x07boardId\x12\x14\n\x05title\x18\
+    \x02\x20\x01(\tR\x05title\x12\x16\n\x06column\x18\x03\x20\x01(\tR\x06col\
+    umn\"\xd8\x01\n\x11UpdateCardRequest\x12\x19\n\x08board_id\x18\x01\x20\
+    \x01(\tR\x07boardId\x12\x17\n\x07card_id\x18\x02\x20\x01(\tR\x06cardId\
+    \x12\x16\n\x05title\x18\x03\x20\x01(\tH\0R\x05title\x12\"\n\x0bdescripti\
+    on\x18\x04\x20\x01(\tH\x01R\x0bdescription\x12-\n\nproperties\x18\x05\
+    \x20\x03(\x0b2\r.CardPropertyR\npropertiesB\x0e\n\x0cone_of_titleB\x14\n\
+    \x12one_of_description\"\xd7\x01\n\x10UpdateCardParams\x12\x19\n\x08boar\
+    d_id\x18\x01\x20\x01(\tR\x07boardId\x12\x17\n\x07card_id\x18\x02\x20\x01\
+    (\tR\x06cardId\x12\x16\n\x05title\x18\x03\x20\x01(\tH\0R\x05title\x12\"\
+    \n\x0bdescription\x18\x04\x20\x01(\tH\x01R\x0bdescription\x12-\n\nproper\
+    ties\x18\x05\x20\x03(\x0b2\r.CardPropertyR\npropertiesB\x0e\n\x0cone_of_\
+    titleB\x14\n\x12one_of_description\"\x9b\x01\n\x0fMoveCardRequest\x12\
+    \x19\n\x08board_id\x18\x01\x20\x01(\tR\x07boardId\x12\x17\n\x07card_id\
+    \x18\x02\x20\x01(\tR\x06cardId\x12\x16\n\x06column\x18\x03\x20\x01(\tR\
+    \x06column\x12$\n\rafter_card_id\x18\x04\x20\x01(\tH\0R\x0bafterCardIdB\
+    \x16\n\x14one_of_after_card_id\"\x9a\x01\n\x0eMoveCardParams\x12\x19\n\
+    \x08board_id\x18\x01\x20\x01(\tR\x07boardId\x12\x17\n\x07card_id\x18\x02\
+    \x20\x01(\tR\x06cardId\x12\x16\n\x06column\x18\x03\x20\x01(\tR\x06column\
+    \x12$\n\rafter_card_id\x18\x04\x20\x01(\tH\0R\x0bafterCardIdB\x16\n\x14o\
+    ne_of_after_card_id\"F\n\x10QueryCardRequest\x12\x19\n\x08board_id\x18\
+    \x01\x20\x01(\tR\x07boardId\x12\x17\n\x07card_id\x18\x02\x20\x01(\tR\x06\
+    cardId\"D\n\x0eCardIdentifier\x12\x19\n\x08board_id\x18\x01\x20\x01(\tR\
+    \x07boardId\x12\x17\n\x07card_id\x18\x02\x20\x01(\tR\x06cardIdJ\xfe\x19\
+    \n\x06\x12\x04\0\0S\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\
+    \x12\x04\x02\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\r\n\x0b\n\x04\
+    \x04\0\x02\0\x12\x03\x03\x04\x12\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\
+    \x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\r\n\x0c\n\x05\x04\0\
+    \x02\0\x03\x12\x03\x03\x10\x11\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\
+    \x1f\n\x0c\n\x05\x04\0\x02\x01\x06\x12\x03\x04\x04\x11\n\x0c\n\x05\x04\0\
+    \x02\x01\x01\x12\x03\x04\x12\x1a\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\
+    \x04\x1d\x1e\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04%\n\x0c\n\x05\x04\
+    \0\x02\x02\x04\x12\x03\x05\x04\x0c\n\x0c\n\x05\x04\0\x02\x02\x06\x12\x03\
+    \x05\r\x18\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x19\x20\n\x0c\n\x05\
+    \x04\0\x02\x02\x03\x12\x03\x05#$\n\n\n\x02\x04\x01\x12\x04\x07\0\n\x01\n\
+    \n\n\x03\x04\x01\x01\x12\x03\x07\x08\x15\n\x0b\n\x04\x04\x01\x02\0\x12\
+    \x03\x08\x04\x13\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x08\x04\n\n\x0c\n\
+    \x05\x04\x01\x02\0\x01\x12\x03\x08\x0b\x0e\n\x0c\n\x05\x04\x01\x02\0\x03\
+    \x12\x03\x08\x11\x12\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\t\x04\x20\n\x0c\
+    \n\x05\x04\x01\x02\x01\x04\x12\x03\t\x04\x0c\n\x0c\n\x05\x04\x01\x02\x01\
+    \x05\x12\x03\t\r\x13\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\t\x14\x1b\n\
+    \x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\t\x1e\x1f\n\n\n\x02\x04\x02\x12\
+    \x04\x0b\0\x0e\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0b\x08\x13\n\x0b\n\x04\
+    \x04\x02\x02\0\x12\x03\x0c\x04\x14\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\
+    \x0c\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0c\x0b\x0f\n\x0c\n\x05\
+    \x04\x02\x02\0\x03\x12\x03\x0c\x12\x13\n\x0b\n\x04\x04\x02\x02\x01\x12\
+    \x03\r\x04\x1c\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\x03\r\x04\x0c\n\x0c\n\
+    \x05\x04\x02\x02\x01\x06\x12\x03\r\r\x11\n\x0c\n\x05\x04\x02\x02\x01\x01\
+    \x12\x03\r\x12\x17\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\r\x1a\x1b\n\n\
+    \n\x02\x04\x03\x12\x04\x0f\0\x14\x01\n\n\n\x03\x04\x03\x01\x12\x03\x0f\
+    \x08\x0c\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x10\x04\x12\n\x0c\n\x05\x04\
+    \x03\x02\0\x05\x12\x03\x10\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\
+    \x10\x0b\r\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x10\x10\x11\n\x0b\n\x04\
+    \x04\x03\x02\x01\x12\x03\x11\x04\x15\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\
+    \x03\x11\x04\n\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x11\x0b\x10\n\x0c\
+    \n\x05\x04\x03\x02\x01\x03\x12\x03\x11\x13\x14\n\x0b\n\x04\x04\x03\x02\
+    \x02\x12\x03\x12\x04\x1b\n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x12\x04\
+    \n\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x03\x12\x0b\x16\n\x0c\n\x05\x04\
+    \x03\x02\x02\x03\x12\x03\x12\x19\x1a\n\x0b\n\x04\x04\x03\x02\x03\x12\x03\
+    \x13\x04)\n\x0c\n\x05\x04\x03\x02\x03\x04\x12\x03\x13\x04\x0c\n\x0c\n\
+    \x05\x04\x03\x02\x03\x06\x12\x03\x13\r\x19\n\x0c\n\x05\x04\x03\x02\x03\
+    \x01\x12\x03\x13\x1a$\n\x0c\n\x05\x04\x03\x02\x03\x03\x12\x03\x13'(\n\n\
+    \n\x02\x04\x04\x12\x04\x15\0\x18\x01\n\n\n\x03\x04\x04\x01\x12\x03\x15\
+    \x08\x14\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x16\x04\x13\n\x0c\n\x05\x04\
+    \x04\x02\0\x05\x12\x03\x16\x04\n\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\
+    \x16\x0b\x0e\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x16\x11\x12\n\x0b\n\
+    \x04\x04\x04\x02\x01\x12\x03\x17\x04\x15\n\x0c\n\x05\x04\x04\x02\x01\x05\
+    \x12\x03\x17\x04\n\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x17\x0b\x10\n\
+    \x0c\n\x05\x04\x04\x02\x01\x03\x12\x03\x17\x13\x14\n\n\n\x02\x04\x05\x12\
+    \x04\x19\0\x1b\x01\n\n\n\x03\x04\x05\x01\x12\x03\x19\x08\x19\n\x0b\n\x04\
+    \x04\x05\x02\0\x12\x03\x1a\x04\x18\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03\
+    \x1a\x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03\x1a\x0b\x13\n\x0c\n\x05\
+    \x04\x05\x02\0\x03\x12\x03\x1a\x16\x17\n\n\n\x02\x04\x06\x12\x04\x1c\0\
+    \x1e\x01\n\n\n\x03\x04\x06\x01\x12\x03\x1c\x08\x0f\n\x0b\n\x04\x04\x06\
+    \x02\0\x12\x03\x1d\x04\x18\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03\x1d\x04\
+    \n\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03\x1d\x0b\x13\n\x0c\n\x05\x04\x06\
+    \x02\0\x03\x12\x03\x1d\x16\x17\n\n\n\x02\x04\x07\x12\x04\x1f\0#\x01\n\n\
+    \n\x03\x04\x07\x01\x12\x03\x1f\x08\"\n\x0b\n\x04\x04\x07\x02\0\x12\x03\
+    \x20\x04\x18\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03\x20\x04\n\n\x0c\n\x05\
+    \x04\x07\x02\0\x01\x12\x03\x20\x0b\x13\n\x0c\n\x05\x04\x07\x02\0\x03\x12\
+    \x03\x20\x16\x17\n\x0b\n\x04\x04\x07\x02\x01\x12\x03!\x04\x13\n\x0c\n\
+    \x05\x04\x07\x02\x01\x05\x12\x03!\x04\n\n\x0c\n\x05\x04\x07\x02\x01\x01\
+    \x12\x03!\x0b\x0e\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x03!\x11\x12\n\x0b\
+    \n\x04\x04\x07\x02\x02\x12\x03\"\x04\x20\n\x0c\n\x05\x04\x07\x02\x02\x04\
+    \x12\x03\"\x04\x0c\n\x0c\n\x05\x04\x07\x02\x02\x05\x12\x03\"\r\x13\n\x0c\
+    \n\x05\x04\x07\x02\x02\x01\x12\x03\"\x14\x1b\n\x0c\n\x05\x04\x07\x02\x02\
+    \x03\x12\x03\"\x1e\x1f\n\n\n\x02\x04\x08\x12\x04$\0'\x01\n\n\n\x03\x04\
+    \x08\x01\x12\x03$\x08!\n\x0b\n\x04\x04\x08\x02\0\x12\x03%\x04\x18\n\x0c\
+    \n\x05\x04\x08\x02\0\x05\x12\x03%\x04\n\n\x0c\n\x05\x04\x08\x02\0\x01\
+    \x12\x03%\x0b\x13\n\x0c\n\x05\x04\x08\x02\0\x03\x12\x03%\x16\x17\n\x0b\n\
+    \x04\x04\x08\x02\x01\x12\x03&\x04\x1f\n\x0c\n\x05\x04\x08\x02\x01\x06\
+    \x12\x03&\x04\x11\n\x0c\n\x05\x04\x08\x02\x01\x01\x12\x03&\x12\x1a\n\x0c\
+    \n\x05\x04\x08\x02\x01\x03\x12\x03&\x1d\x1e\n\n\n\x02\x04\t\x12\x04(\0,\
+    \x01\n\n\n\x03\x04\t\x01\x12\x03(\x08\x19\n\x0b\n\x04\x04\t\x02\0\x12\
+    \x03)\x04\x18\n\x0c\n\x05\x04\t\x02\0\x05\x12\x03)\x04\n\n\x0c\n\x05\x04\
+    \t\x02\0\x01\x12\x03)\x0b\x13\n\x0c\n\x05\x04\t\x02\0\x03\x12\x03)\x16\
+    \x17\n\x0b\n\x04\x04\t\x02\x01\x12\x03*\x04\x15\n\x0c\n\x05\x04\t\x02\
+    \x01\x05\x12\x03*\x04\n\n\x0c\n\x05\x04\t\x02\x01\x01\x12\x03*\x0b\x10\n\
+    \x0c\n\x05\x04\t\x02\x01\x03\x12\x03*\x13\x14\n\x0b\n\x04\x04\t\x02\x02\
+    \x12\x03+\x04\x16\n\x0c\n\x05\x04\t\x02\x02\x05\x12\x03+\x04\n\n\x0c\n\
+    \x05\x04\t\x02\x02\x01\x12\x03+\x0b\x11\n\x0c\n\x05\x04\t\x02\x02\x03\
+    \x12\x03+\x14\x15\n\n\n\x02\x04\n\x12\x04-\01\x01\n\n\n\x03\x04\n\x01\
+    \x12\x03-\x08\x18\n\x0b\n\x04\x04\n\x02\0\x12\x03.\x04\x18\n\x0c\n\x05\
+    \x04\n\x02\0\x05\x12\x03.\x04\n\n\x0c\n\x05\x04\n\x02\0\x01\x12\x03.\x0b\
+    \x13\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03.\x16\x17\n\x0b\n\x04\x04\n\x02\
+    \x01\x12\x03/\x04\x15\n\x0c\n\x05\x04\n\x02\x01\x05\x12\x03/\x04\n\n\x0c\
+    \n\x05\x04\n\x02\x01\x01\x12\x03/\x0b\x10\n\x0c\n\x05\x04\n\x02\x01\x03\
+    \x12\x03/\x13\x14\n\x0b\n\x04\x04\n\x02\x02\x12\x030\x04\x16\n\x0c\n\x05\
+    \x04\n\x02\x02\x05\x12\x030\x04\n\n\x0c\n\x05\x04\n\x02\x02\x01\x12\x030\
+    \x0b\x11\n\x0c\n\x05\x04\n\x02\x02\x03\x12\x030\x14\x15\n\n\n\x02\x04\
+    \x0b\x12\x042\08\x01\n\n\n\x03\x04\x0b\x01\x12\x032\x08\x19\n\x0b\n\x04\
+    \x04\x0b\x02\0\x12\x033\x04\x18\n\x0c\n\x05\x04\x0b\x02\0\x05\x12\x033\
+    \x04\n\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\x033\x0b\x13\n\x0c\n\x05\x04\
+    \x0b\x02\0\x03\x12\x033\x16\x17\n\x0b\n\x04\x04\x0b\x02\x01\x12\x034\x04\
+    \x17\n\x0c\n\x05\x04\x0b\x02\x01\x05\x12\x034\x04\n\n\x0c\n\x05\x04\x0b\
+    \x02\x01\x01\x12\x034\x0b\x12\n\x0c\n\x05\x04\x0b\x02\x01\x03\x12\x034\
+    \x15\x16\n\x0b\n\x04\x04\x0b\x08\0\x12\x035\x04,\n\x0c\n\x05\x04\x0b\x08\
+    \0\x01\x12\x035\n\x16\n\x0b\n\x04\x04\x0b\x02\x02\x12\x035\x19*\n\x0c\n\
+    \x05\x04\x0b\x02\x02\x05\x12\x035\x19\x1f\n\x0c\n\x05\x04\x0b\x02\x02\
+    \x01\x12\x035\x20%\n\x0c\n\x05\x04\x0b\x02\x02\x03\x12\x035()\n\x0b\n\
+    \x04\x04\x0b\x08\x01\x12\x036\x048\n\x0c\n\x05\x04\x0b\x08\x01\x01\x12\
+    \x036\n\x1c\n\x0b\n\x04\x04\x0b\x02\x03\x12\x036\x1f6\n\x0c\n\x05\x04\
+    \x0b\x02\x03\x05\x12\x036\x1f%\n\x0c\n\x05\x04\x0b\x02\x03\x01\x12\x036&\
+    1\n\x0c\n\x05\x04\x0b\x02\x03\x03\x12\x03645\n\x0b\n\x04\x04\x0b\x02\x04\
+    \x12\x037\x04)\n\x0c\n\x05\x04\x0b\x02\x04\x04\x12\x037\x04\x0c\n\x0c\n\
+    \x05\x04\x0b\x02\x04\x06\x12\x037\r\x19\n\x0c\n\x05\x04\x0b\x02\x04\x01\
+    \x12\x037\x1a$\n\x0c\n\x05\x04\x0b\x02\x04\x03\x12\x037'(\n\n\n\x02\x04\
+    \x0c\x12\x049\0?\x01\n\n\n\x03\x04\x0c\x01\x12\x039\x08\x18\n\x0b\n\x04\
+    \x04\x0c\x02\0\x12\x03:\x04\x18\n\x0c\n\x05\x04\x0c\x02\0\x05\x12\x03:\
+    \x04\n\n\x0c\n\x05\x04\x0c\x02\0\x01\x12\x03:\x0b\x13\n\x0c\n\x05\x04\
+    \x0c\x02\0\x03\x12\x03:\x16\x17\n\x0b\n\x04\x04\x0c\x02\x01\x12\x03;\x04\
+    \x17\n\x0c\n\x05\x04\x0c\x02\x01\x05\x12\x03;\x04\n\n\x0c\n\x05\x04\x0c\
+    \x02\x01\x01\x12\x03;\x0b\x12\n\x0c\n\x05\x04\x0c\x02\x01\x03\x12\x03;\
+    \x15\x16\n\x0b\n\x04\x04\x0c\x08\0\x12\x03<\x04,\n\x0c\n\x05\x04\x0c\x08\
+    \0\x01\x12\x03<\n\x16\n\x0b\n\x04\x04\x0c\x02\x02\x12\x03<\x19*\n\x0c\n\
+    \x05\x04\x0c\x02\x02\x05\x12\x03<\x19\x1f\n\x0c\n\x05\x04\x0c\x02\x02\
+    \x01\x12\x03<\x20%\n\x0c\n\x05\x04\x0c\x02\x02\x03\x12\x03<()\n\x0b\n\
+    \x04\x04\x0c\x08\x01\x12\x03=\x048\n\x0c\n\x05\x04\x0c\x08\x01\x01\x12\
+    \x03=\n\x1c\n\x0b\n\x04\x04\x0c\x02\x03\x12\x03=\x1f6\n\x0c\n\x05\x04\
+    \x0c\x02\x03\x05\x12\x03=\x1f%\n\x0c\n\x05\x04\x0c\x02\x03\x01\x12\x03=&\
+    1\n\x0c\n\x05\x04\x0c\x02\x03\x03\x12\x03=45\n\x0b\n\x04\x04\x0c\x02\x04\
+    \x12\x03>\x04)\n\x0c\n\x05\x04\x0c\x02\x04\x04\x12\x03>\x04\x0c\n\x0c\n\
+    \x05\x04\x0c\x02\x04\x06\x12\x03>\r\x19\n\x0c\n\x05\x04\x0c\x02\x04\x01\
+    \x12\x03>\x1a$\n\x0c\n\x05\x04\x0c\x02\x04\x03\x12\x03>'(\n\n\n\x02\x04\
+    \r\x12\x04@\0E\x01\n\n\n\x03\x04\r\x01\x12\x03@\x08\x17\n\x0b\n\x04\x04\
+    \r\x02\0\x12\x03A\x04\x18\n\x0c\n\x05\x04\r\x02\0\x05\x12\x03A\x04\n\n\
+    \x0c\n\x05\x04\r\x02\0\x01\x12\x03A\x0b\x13\n\x0c\n\x05\x04\r\x02\0\x03\
+    \x12\x03A\x16\x17\n\x0b\n\x04\x04\r\x02\x01\x12\x03B\x04\x17\n\x0c\n\x05\
+    \x04\r\x02\x01\x05\x12\x03B\x04\n\n\x0c\n\x05\x04\r\x02\x01\x01\x12\x03B\
+    \x0b\x12\n\x0c\n\x05\x04\r\x02\x01\x03\x12\x03B\x15\x16\n\x0b\n\x04\x04\
+    \r\x02\x02\x12\x03C\x04\x16\n\x0c\n\x05\x04\r\x02\x02\x05\x12\x03C\x04\n\
+    \n\x0c\n\x05\x04\r\x02\x02\x01\x12\x03C\x0b\x11\n\x0c\n\x05\x04\r\x02\
+    \x02\x03\x12\x03C\x14\x15\n\x0b\n\x04\x04\r\x08\0\x12\x03D\x04<\n\x0c\n\
+    \x05\x04\r\x08\0\x01\x12\x03D\n\x1e\n\x0b\n\x04\x04\r\x02\x03\x12\x03D!:\
+    \n\x0c\n\x05\x04\r\x02\x03\x05\x12\x03D!'\n\x0c\n\x05\x04\r\x02\x03\x01\
+    \x12\x03D(5\n\x0c\n\x05\x04\r\x02\x03\x03\x12\x03D89\n\n\n\x02\x04\x0e\
+    \x12\x04F\0K\x01\n\n\n\x03\x04\x0e\x01\x12\x03F\x08\x16\n\x0b\n\x04\x04\
+    \x0e\x02\0\x12\x03G\x04\x18\n\x0c\n\x05\x04\x0e\x02\0\x05\x12\x03G\x04\n\
+    \n\x0c\n\x05\x04\x0e\x02\0\x01\x12\x03G\x0b\x13\n\x0c\n\x05\x04\x0e\x02\
+    \0\x03\x12\x03G\x16\x17\n\x0b\n\x04\x04\x0e\x02\x01\x12\x03H\x04\x17\n\
+    \x0c\n\x05\x04\x0e\x02\x01\x05\x12\x03H\x04\n\n\x0c\n\x05\x04\x0e\x02\
+    \x01\x01\x12\x03H\x0b\x12\n\x0c\n\x05\x04\x0e\x02\x01\x03\x12\x03H\x15\
+    \x16\n\x0b\n\x04\x04\x0e\x02\x02\x12\x03I\x04\x16\n\x0c\n\x05\x04\x0e\
+    \x02\x02\x05\x12\x03I\x04\n\n\x0c\n\x05\x04\x0e\x02\x02\x01\x12\x03I\x0b\
+    \x11\n\x0c\n\x05\x04\x0e\x02\x02\x03\x12\x03I\x14\x15\n\x0b\n\x04\x04\
+    \x0e\x08\0\x12\x03J\x04<\n\x0c\n\x05\x04\x0e\x08\0\x01\x12\x03J\n\x1e\n\
+    \x0b\n\x04\x04\x0e\x02\x03\x12\x03J!:\n\x0c\n\x05\x04\x0e\x02\x03\x05\
+    \x12\x03J!'\n\x0c\n\x05\x04\x0e\x02\x03\x01\x12\x03J(5\n\x0c\n\x05\x04\
+    \x0e\x02\x03\x03\x12\x03J89\n\n\n\x02\x04\x0f\x12\x04L\0O\x01\n\n\n\x03\
+    \x04\x0f\x01\x12\x03L\x08\x18\n\x0b\n\x04\x04\x0f\x02\0\x12\x03M\x04\x18\
+    \n\x0c\n\x05\x04\x0f\x02\0\x05\x12\x03M\x04\n\n\x0c\n\x05\x04\x0f\x02\0\
+    \x01\x12\x03M\x0b\x13\n\x0c\n\x05\x04\x0f\x02\0\x03\x12\x03M\x16\x17\n\
+    \x0b\n\x04\x04\x0f\x02\x01\x12\x03N\x04\x17\n\x0c\n\x05\x04\x0f\x02\x01\
+    \x05\x12\x03N\x04\n\n\x0c\n\x05\x04\x0f\x02\x01\x01\x12\x03N\x0b\x12\n\
+    \x0c\n\x05\x04\x0f\x02\x01\x03\x12\x03N\x15\x16\n\n\n\x02\x04\x10\x12\
+    \x04P\0S\x01\n\n\n\x03\x04\x10\x01\x12\x03P\x08\x16\n\x0b\n\x04\x04\x10\
+    \x02\0\x12\x03Q\x04\x18\n\x0c\n\x05\x04\x10\x02\0\x05\x12\x03Q\x04\n\n\
+    \x0c\n\x05\x04\x10\x02\0\x01\x12\x03Q\x0b\x13\n\x0c\n\x05\x04\x10\x02\0\
+    \x03\x12\x03Q\x16\x17\n\x0b\n\x04\x04\x10\x02\x01\x12\x03R\x04\x17\n\x0c\
+    \n\x05\x04\x10\x02\x01\x05\x12\x03R\x04\n\n\x0c\n\x05\x04\x10\x02\x01\
+    \x01\x12\x03R\x0b\x12\n\x0c\n\x05\x04\x10\x02\x01\x03\x12\x03R\x15\x16b\
+    \x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,3000 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `comment.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CommentThread {
+    // message fields
+    pub id: ::std::string::String,
+    pub doc_id: ::std::string::String,
+    pub author: ::std::string::String,
+    pub content: ::std::string::String,
+    pub create_time: i64,
+    pub resolved: bool,
+    pub orphaned: bool,
+    pub anchor_start: i64,
+    pub anchor_end: i64,
+    pub replies: ::protobuf::RepeatedField<Comment>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CommentThread {
+    fn default() -> &'a CommentThread {
+        <CommentThread as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CommentThread {
+    pub fn new() -> CommentThread {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string doc_id = 2;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // string author = 3;
+
+
+    pub fn get_author(&self) -> &str {
+        &self.author
+    }
+    pub fn clear_author(&mut self) {
+        self.author.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_author(&mut self, v: ::std::string::String) {
+        self.author = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_author(&mut self) -> &mut ::std::string::String {
+        &mut self.author
+    }
+
+    // Take field
+    pub fn take_author(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.author, ::std::string::String::new())
+    }
+
+    // string content = 4;
+
+
+    pub fn get_content(&self) -> &str {
+        &self.content
+    }
+    pub fn clear_content(&mut self) {
+        self.content.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content(&mut self, v: ::std::string::String) {
+        self.content = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content(&mut self) -> &mut ::std::string::String {
+        &mut self.content
+    }
+
+    // Take field
+    pub fn take_content(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.content, ::std::string::String::new())
+    }
+
+    // int64 create_time = 5;
+
+
+    pub fn get_create_time(&self) -> i64 {
+        self.create_time
+    }
+    pub fn clear_create_time(&mut self) {
+        self.create_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_time(&mut self, v: i64) {
+        self.create_time = v;
+    }
+
+    // bool resolved = 6;
+
+
+    pub fn get_resolved(&self) -> bool {
+        self.resolved
+    }
+    pub fn clear_resolved(&mut self) {
+        self.resolved = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resolved(&mut self, v: bool) {
+        self.resolved = v;
+    }
+
+    // bool orphaned = 7;
+
+
+    pub fn get_orphaned(&self) -> bool {
+        self.orphaned
+    }
+    pub fn clear_orphaned(&mut self) {
+        self.orphaned = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_orphaned(&mut self, v: bool) {
+        self.orphaned = v;
+    }
+
+    // int64 anchor_start = 8;
+
+
+    pub fn get_anchor_start(&self) -> i64 {
+        self.anchor_start
+    }
+    pub fn clear_anchor_start(&mut self) {
+        self.anchor_start = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_anchor_start(&mut self, v: i64) {
+        self.anchor_start = v;
+    }
+
+    // int64 anchor_end = 9;
+
+
+    pub fn get_anchor_end(&self) -> i64 {
+        self.anchor_end
+    }
+    pub fn clear_anchor_end(&mut self) {
+        self.anchor_end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_anchor_end(&mut self, v: i64) {
+        self.anchor_end = v;
+    }
+
+    // repeated .Comment replies = 10;
+
+
+    pub fn get_replies(&self) -> &[Comment] {
+        &self.replies
+    }
+    pub fn clear_replies(&mut self) {
+        self.replies.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_replies(&mut self, v: ::protobuf::RepeatedField<Comment>) {
+        self.replies = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_replies(&mut self) -> &mut ::protobuf::RepeatedField<Comment> {
+        &mut self.replies
+    }
+
+    // Take field
+    pub fn take_replies(&mut self) -> ::protobuf::RepeatedField<Comment> {
+        ::std::mem::replace(&mut self.replies, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CommentThread {
+    fn is_initialized(&self) -> bool {
+        for v in &self.replies {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.author)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.content)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.create_time = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.resolved = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.orphaned = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.anchor_start = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.anchor_end = tmp;
+                },
+                10 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.replies)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.doc_id);
+        }
+        if !self.author.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.author);
+        }
+        if !self.content.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.content);
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.create_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.resolved != false {
+            my_size += 2;
+        }
+        if self.orphaned != false {
+            my_size += 2;
+        }
+        if self.anchor_start != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.anchor_start, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.anchor_end != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.anchor_end, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.replies {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.doc_id.is_empty() {
+            os.write_string(2, &self.doc_id)?;
+        }
+        if !self.author.is_empty() {
+            os.write_string(3, &self.author)?;
+        }
+        if !self.content.is_empty() {
+            os.write_string(4, &self.content)?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(5, self.create_time)?;
+        }
+        if self.resolved != false {
+            os.write_bool(6, self.resolved)?;
+        }
+        if self.orphaned != false {
+            os.write_bool(7, self.orphaned)?;
+        }
+        if self.anchor_start != 0 {
+            os.write_int64(8, self.anchor_start)?;
+        }
+        if self.anchor_end != 0 {
+            os.write_int64(9, self.anchor_end)?;
+        }
+        for v in &self.replies {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CommentThread {
+        CommentThread::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &CommentThread| { &m.id },
+                |m: &mut CommentThread| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &CommentThread| { &m.doc_id },
+                |m: &mut CommentThread| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "author",
+                |m: &CommentThread| { &m.author },
+                |m: &mut CommentThread| { &mut m.author },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "content",
+                |m: &CommentThread| { &m.content },
+                |m: &mut CommentThread| { &mut m.content },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "create_time",
+                |m: &CommentThread| { &m.create_time },
+                |m: &mut CommentThread| { &mut m.create_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "resolved",
+                |m: &CommentThread| { &m.resolved },
+                |m: &mut CommentThread| { &mut m.resolved },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "orphaned",
+                |m: &CommentThread| { &m.orphaned },
+                |m: &mut CommentThread| { &mut m.orphaned },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "anchor_start",
+                |m: &CommentThread| { &m.anchor_start },
+                |m: &mut CommentThread| { &mut m.anchor_start },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "anchor_end",
+                |m: &CommentThread| { &m.anchor_end },
+                |m: &mut CommentThread| { &mut m.anchor_end },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Comment>>(
+                "replies",
+                |m: &CommentThread| { &m.replies },
+                |m: &mut CommentThread| { &mut m.replies },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CommentThread>(
+                "CommentThread",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CommentThread {
+        static instance: ::protobuf::rt::LazyV2<CommentThread> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CommentThread::new)
+    }
+}
+
+impl ::protobuf::Clear for CommentThread {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.doc_id.clear();
+        self.author.clear();
+        self.content.clear();
+        self.create_time = 0;
+        self.resolved = false;
+        self.orphaned = false;
+        self.anchor_start = 0;
+        self.anchor_end = 0;
+        self.replies.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CommentThread {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CommentThread {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Comment {
+    // message fields
+    pub id: ::std::string::String,
+    pub thread_id: ::std::string::String,
+    pub author: ::std::string::String,
+    pub content: ::std::string::String,
+    pub create_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Comment {
+    fn default() -> &'a Comment {
+        <Comment as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Comment {
+    pub fn new() -> Comment {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string thread_id = 2;
+
+
+    pub fn get_thread_id(&self) -> &str {
+        &self.thread_id
+    }
+    pub fn clear_thread_id(&mut self) {
+        self.thread_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thread_id(&mut self, v: ::std::string::String) {
+        self.thread_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_thread_id(&mut self) -> &mut ::std::string::String {
+        &mut self.thread_id
+    }
+
+    // Take field
+    pub fn take_thread_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.thread_id, ::std::string::String::new())
+    }
+
+    // string author = 3;
+
+
+    pub fn get_author(&self) -> &str {
+        &self.author
+    }
+    pub fn clear_author(&mut self) {
+        self.author.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_author(&mut self, v: ::std::string::String) {
+        self.author = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_author(&mut self) -> &mut ::std::string::String {
+        &mut self.author
+    }
+
+    // Take field
+    pub fn take_author(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.author, ::std::string::String::new())
+    }
+
+    // string content = 4;
+
+
+    pub fn get_content(&self) -> &str {
+        &self.content
+    }
+    pub fn clear_content(&mut self) {
+        self.content.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content(&mut self, v: ::std::string::String) {
+        self.content = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content(&mut self) -> &mut ::std::string::String {
+        &mut self.content
+    }
+
+    // Take field
+    pub fn take_content(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.content, ::std::string::String::new())
+    }
+
+    // int64 create_time = 5;
+
+
+    pub fn get_create_time(&self) -> i64 {
+        self.create_time
+    }
+    pub fn clear_create_time(&mut self) {
+        self.create_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_time(&mut self, v: i64) {
+        self.create_time = v;
+    }
+}
+
+impl ::protobuf::Message for Comment {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.thread_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.author)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.content)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.create_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.thread_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.thread_id);
+        }
+        if !self.author.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.author);
+        }
+        if !self.content.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.content);
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.create_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.thread_id.is_empty() {
+            os.write_string(2, &self.thread_id)?;
+        }
+        if !self.author.is_empty() {
+            os.write_string(3, &self.author)?;
+        }
+        if !self.content.is_empty() {
+            os.write_string(4, &self.content)?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(5, self.create_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Comment {
+        Comment::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &Comment| { &m.id },
+                |m: &mut Comment| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "thread_id",
+                |m: &Comment| { &m.thread_id },
+                |m: &mut Comment| { &mut m.thread_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "author",
+                |m: &Comment| { &m.author },
+                |m: &mut Comment| { &mut m.author },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "content",
+                |m: &Comment| { &m.content },
+                |m: &mut Comment| { &mut m.content },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "create_time",
+                |m: &Comment| { &m.create_time },
+                |m: &mut Comment| { &mut m.create_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Comment>(
+                "Comment",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Comment {
+        static instance: ::protobuf::rt::LazyV2<Comment> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Comment::new)
+    }
+}
+
+impl ::protobuf::Clear for Comment {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.thread_id.clear();
+        self.author.clear();
+        self.content.clear();
+        self.create_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Comment {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Comment {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedCommentThread {
+    // message fields
+    pub items: ::protobuf::RepeatedField<CommentThread>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedCommentThread {
+    fn default() -> &'a RepeatedCommentThread {
+        <RepeatedCommentThread as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedCommentThread {
+    pub fn new() -> RepeatedCommentThread {
+        ::std::default::Default::default()
+    }
+
+    // repeated .CommentThread items = 1;
+
+
+    pub fn get_items(&self) -> &[CommentThread] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<CommentThread>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<CommentThread> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<CommentThread> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedCommentThread {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedCommentThread {
+        RepeatedCommentThread::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CommentThread>>(
+                "items",
+                |m: &RepeatedCommentThread| { &m.items },
+                |m: &mut RepeatedCommentThread| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedCommentThread>(
+                "RepeatedCommentThread",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedCommentThread {
+        static instance: ::protobuf::rt::LazyV2<RepeatedCommentThread> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedCommentThread::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedCommentThread {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedCommentThread {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedCommentThread {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryCommentsRequest {
+    // message fields
+    pub doc_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryCommentsRequest {
+    fn default() -> &'a QueryCommentsRequest {
+        <QueryCommentsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryCommentsRequest {
+    pub fn new() -> QueryCommentsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for QueryCommentsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryCommentsRequest {
+        QueryCommentsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &QueryCommentsRequest| { &m.doc_id },
+                |m: &mut QueryCommentsRequest| { &mut m.doc_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryCommentsRequest>(
+                "QueryCommentsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryCommentsRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryCommentsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryCommentsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryCommentsRequest {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryCommentsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryCommentsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryCommentsParams {
+    // message fields
+    pub doc_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryCommentsParams {
+    fn default() -> &'a QueryCommentsParams {
+        <QueryCommentsParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryCommentsParams {
+    pub fn new() -> QueryCommentsParams {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for QueryCommentsParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryCommentsParams {
+        QueryCommentsParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &QueryCommentsParams| { &m.doc_id },
+                |m: &mut QueryCommentsParams| { &mut m.doc_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryCommentsParams>(
+                "QueryCommentsParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryCommentsParams {
+        static instance: ::protobuf::rt::LazyV2<QueryCommentsParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryCommentsParams::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryCommentsParams {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryCommentsParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryCommentsParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateCommentRequest {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub start: i64,
+    pub end: i64,
+    pub content: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateCommentRequest {
+    fn default() -> &'a CreateCommentRequest {
+        <CreateCommentRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateCommentRequest {
+    pub fn new() -> CreateCommentRequest {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // int64 start = 2;
+
+
+    pub fn get_start(&self) -> i64 {
+        self.start
+    }
+    pub fn clear_start(&mut self) {
+        self.start = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: i64) {
+        self.start = v;
+    }
+
+    // int64 end = 3;
+
+
+    pub fn get_end(&self) -> i64 {
+        self.end
+    }
+    pub fn clear_end(&mut self) {
+        self.end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end(&mut self, v: i64) {
+        self.end = v;
+    }
+
+    // string content = 4;
+
+
+    pub fn get_content(&self) -> &str {
+        &self.content
+    }
+    pub fn clear_content(&mut self) {
+        self.content.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content(&mut self, v: ::std::string::String) {
+        self.content = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content(&mut self) -> &mut ::std::string::String {
+        &mut self.content
+    }
+
+    // Take field
+    pub fn take_content(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.content, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for CreateCommentRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.start = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.end = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.content)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if self.start != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.start, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.end, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.content.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.content);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if self.start != 0 {
+            os.write_int64(2, self.start)?;
+        }
+        if self.end != 0 {
+            os.write_int64(3, self.end)?;
+        }
+        if !self.content.is_empty() {
+            os.write_string(4, &self.content)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateCommentRequest {
+        CreateCommentRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &CreateCommentRequest| { &m.doc_id },
+                |m: &mut CreateCommentRequest| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "start",
+                |m: &CreateCommentRequest| { &m.start },
+                |m: &mut CreateCommentRequest| { &mut m.start },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "end",
+                |m: &CreateCommentRequest| { &m.end },
+                |m: &mut CreateCommentRequest| { &mut m.end },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "content",
+                |m: &CreateCommentRequest| { &m.content },
+                |m: &mut CreateCommentRequest| { &mut m.content },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateCommentRequest>(
+                "CreateCommentRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateCommentRequest {
+        static instance: ::protobuf::rt::LazyV2<CreateCommentRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateCommentRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateCommentRequest {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.start = 0;
+        self.end = 0;
+        self.content.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateCommentRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateCommentRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateCommentParams {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub start: i64,
+    pub end: i64,
+    pub content: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateCommentParams {
+    fn default() -> &'a CreateCommentParams {
+        <CreateCommentParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateCommentParams {
+    pub fn new() -> CreateCommentParams {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // int64 start = 2;
+
+
+    pub fn get_start(&self) -> i64 {
+        self.start
+    }
+    pub fn clear_start(&mut self) {
+        self.start = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: i64) {
+        self.start = v;
+    }
+
+    // int64 end = 3;
+
+
+    pub fn get_end(&self) -> i64 {
+        self.end
+    }
+    pub fn clear_end(&mut self) {
+        self.end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end(&mut self, v: i64) {
+        self.end = v;
+    }
+
+    // string content = 4;
+
+
+    pub fn get_content(&self) -> &str {
+        &self.content
+    }
+    pub fn clear_content(&mut self) {
+        self.content.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content(&mut self, v: ::std::string::String) {
+        self.content = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content(&mut self) -> &mut ::std::string::String {
+        &mut self.content
+    }
+
+    // Take field
+    pub fn take_content(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.content, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for CreateCommentParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.start = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.end = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.content)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if self.start != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.start, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.end, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.content.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.content);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if self.start != 0 {
+            os.write_int64(2, self.start)?;
+        }
+        if self.end != 0 {
+            os.write_int64(3, self.end)?;
+        }
+        if !self.content.is_empty() {
+            os.write_string(4, &self.content)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateCommentParams {
+        CreateCommentParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &CreateCommentParams| { &m.doc_id },
+                |m: &mut CreateCommentParams| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "start",
+                |m: &CreateCommentParams| { &m.start },
+                |m: &mut CreateCommentParams| { &mut m.start },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "end",
+                |m: &CreateCommentParams| { &m.end },
+                |m: &mut CreateCommentParams| { &mut m.end },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "content",
+                |m: &CreateCommentParams| { &m.content },
+                |m: &mut CreateCommentParams| { &mut m.content },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateCommentParams>(
+                "CreateCommentParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateCommentParams {
+        static instance: ::protobuf::rt::LazyV2<CreateCommentParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateCommentParams::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateCommentParams {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.start = 0;
+        self.end = 0;
+        self.content.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateCommentParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateCommentParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReplyCommentRequest {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub thread_id: ::std::string::String,
+    pub content: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReplyCommentRequest {
+    fn default() -> &'a ReplyCommentRequest {
+        <ReplyCommentRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReplyCommentRequest {
+    pub fn new() -> ReplyCommentRequest {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // string thread_id = 2;
+
+
+    pub fn get_thread_id(&self) -> &str {
+        &self.thread_id
+    }
+    pub fn clear_thread_id(&mut self) {
+        self.thread_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thread_id(&mut self, v: ::std::string::String) {
+        self.thread_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_thread_id(&mut self) -> &mut ::std::string::String {
+        &mut self.thread_id
+    }
+
+    // Take field
+    pub fn take_thread_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.thread_id, ::std::string::String::new())
+    }
+
+    // string content = 3;
+
+
+    pub fn get_content(&self) -> &str {
+        &self.content
+    }
+    pub fn clear_content(&mut self) {
+        self.content.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content(&mut self, v: ::std::string::String) {
+        self.content = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content(&mut self) -> &mut ::std::string::String {
+        &mut self.content
+    }
+
+    // Take field
+    pub fn take_content(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.content, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ReplyCommentRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.thread_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.content)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if !self.thread_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.thread_id);
+        }
+        if !self.content.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.content);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if !self.thread_id.is_empty() {
+            os.write_string(2, &self.thread_id)?;
+        }
+        if !self.content.is_empty() {
+            os.write_string(3, &self.content)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReplyCommentRequest {
+        ReplyCommentRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &ReplyCommentRequest| { &m.doc_id },
+                |m: &mut ReplyCommentRequest| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "thread_id",
+                |m: &ReplyCommentRequest| { &m.thread_id },
+                |m: &mut ReplyCommentRequest| { &mut m.thread_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "content",
+                |m: &ReplyCommentRequest| { &m.content },
+                |m: &mut ReplyCommentRequest| { &mut m.content },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ReplyCommentRequest>(
+                "ReplyCommentRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ReplyCommentRequest {
+        static instance: ::protobuf::rt::LazyV2<ReplyCommentRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ReplyCommentRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ReplyCommentRequest {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.thread_id.clear();
+        self.content.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReplyCommentRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReplyCommentRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReplyCommentParams {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub thread_id: ::std::string::String,
+    pub content: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReplyCommentParams {
+    fn default() -> &'a ReplyCommentParams {
+        <ReplyCommentParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReplyCommentParams {
+    pub fn new() -> ReplyCommentParams {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // string thread_id = 2;
+
+
+    pub fn get_thread_id(&self) -> &str {
+        &self.thread_id
+    }
+    pub fn clear_thread_id(&mut self) {
+        self.thread_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thread_id(&mut self, v: ::std::string::String) {
+        self.thread_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_thread_id(&mut self) -> &mut ::std::string::String {
+        &mut self.thread_id
+    }
+
+    // Take field
+    pub fn take_thread_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.thread_id, ::std::string::String::new())
+    }
+
+    // string content = 3;
+
+
+    pub fn get_content(&self) -> &str {
+        &self.content
+    }
+    pub fn clear_content(&mut self) {
+        self.content.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content(&mut self, v: ::std::string::String) {
+        self.content = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content(&mut self) -> &mut ::std::string::String {
+        &mut self.content
+    }
+
+    // Take field
+    pub fn take_content(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.content, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ReplyCommentParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.thread_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.content)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if !self.thread_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.thread_id);
+        }
+        if !self.content.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.content);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if !self.thread_id.is_empty() {
+            os.write_string(2, &self.thread_id)?;
+        }
+        if !self.content.is_empty() {
+            os.write_string(3, &self.content)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReplyCommentParams {
+        ReplyCommentParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &ReplyCommentParams| { &m.doc_id },
+                |m: &mut ReplyCommentParams| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "thread_id",
+                |m: &ReplyCommentParams| { &m.thread_id },
+                |m: &mut ReplyCommentParams| { &mut m.thread_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "content",
+                |m: &ReplyCommentParams| { &m.content },
+                |m: &mut ReplyCommentParams| { &mut m.content },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ReplyCommentParams>(
+                "ReplyCommentParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ReplyCommentParams {
+        static instance: ::protobuf::rt::LazyV2<ReplyCommentParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ReplyCommentParams::new)
+    }
+}
+
+impl ::protobuf::Clear for ReplyCommentParams {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.thread_id.clear();
+        self.content.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReplyCommentParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReplyCommentParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResolveCommentRequest {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub thread_id: ::std::string::String,
+    pub resolved: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ResolveCommentRequest {
+    fn default() -> &'a ResolveCommentRequest {
+        <ResolveCommentRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ResolveCommentRequest {
+    pub fn new() -> ResolveCommentRequest {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // string thread_id = 2;
+
+
+    pub fn get_thread_id(&self) -> &str {
+        &self.thread_id
+    }
+    pub fn clear_thread_id(&mut self) {
+        self.thread_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thread_id(&mut self, v: ::std::string::String) {
+        self.thread_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_thread_id(&mut self) -> &mut ::std::string::String {
+        &mut self.thread_id
+    }
+
+    // Take field
+    pub fn take_thread_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.thread_id, ::std::string::String::new())
+    }
+
+    // bool resolved = 3;
+
+
+    pub fn get_resolved(&self) -> bool {
+        self.resolved
+    }
+    pub fn clear_resolved(&mut self) {
+        self.resolved = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resolved(&mut self, v: bool) {
+        self.resolved = v;
+    }
+}
+
+impl ::protobuf::Message for ResolveCommentRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.thread_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.resolved = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if !self.thread_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.thread_id);
+        }
+        if self.resolved != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if !self.thread_id.is_empty() {
+            os.write_string(2, &self.thread_id)?;
+        }
+        if self.resolved != false {
+            os.write_bool(3, self.resolved)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ResolveCommentRequest {
+        ResolveCommentRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &ResolveCommentRequest| { &m.doc_id },
+                |m: &mut ResolveCommentRequest| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "thread_id",
+                |m: &ResolveCommentRequest| { &m.thread_id },
+                |m: &mut ResolveCommentRequest| { &mut m.thread_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "resolved",
+                |m: &ResolveCommentRequest| { &m.resolved },
+                |m: &mut ResolveCommentRequest| { &mut m.resolved },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ResolveCommentRequest>(
+                "ResolveCommentRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ResolveCommentRequest {
+        static instance: ::protobuf::rt::LazyV2<ResolveCommentRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ResolveCommentRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ResolveCommentRequest {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.thread_id.clear();
+        self.resolved = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResolveCommentRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResolveCommentRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResolveCommentParams {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub thread_id: ::std::string::String,
+    pub resolved: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ResolveCommentParams {
+    fn default() -> &'a ResolveCommentParams {
+        <ResolveCommentParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ResolveCommentParams {
+    pub fn new() -> ResolveCommentParams {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // string thread_id = 2;
+
+
+    pub fn get_thread_id(&self) -> &str {
+        &self.thread_id
+    }
+    pub fn clear_thread_id(&mut self) {
+        self.thread_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thread_id(&mut self, v: ::std::string::String) {
+        self.thread_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_thread_id(&mut self) -> &mut ::std::string::String {
+        &mut self.thread_id
+    }
+
+    // Take field
+    pub fn take_thread_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.thread_id, ::std::string::String::new())
+    }
+
+    // bool resolved = 3;
+
+
+    pub fn get_resolved(&self) -> bool {
+        self.resolved
+    }
+    pub fn clear_resolved(&mut self) {
+        self.resolved = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resolved(&mut self, v: bool) {
+        self.resolved = v;
+    }
+}
+
+impl ::protobuf::Message for ResolveCommentParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.thread_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.resolved = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if !self.thread_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.thread_id);
+        }
+        if self.resolved != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if !self.thread_id.is_empty() {
+            os.write_string(2, &self.thread_id)?;
+        }
+        if self.resolved != false {
+            os.write_bool(3, self.resolved)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ResolveCommentParams {
+        ResolveCommentParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &ResolveCommentParams| { &m.doc_id },
+                |m: &mut ResolveCommentParams| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "thread_id",
+                |m: &ResolveCommentParams| { &m.thread_id },
+                |m: &mut ResolveCommentParams| { &mut m.thread_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "resolved",
+                |m: &ResolveCommentParams| { &m.resolved },
+                |m: &mut ResolveCommentParams| { &mut m.resolved },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ResolveCommentParams>(
+                "ResolveCommentParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ResolveCommentParams {
+        static instance: ::protobuf::rt::LazyV2<ResolveCommentParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ResolveCommentParams::new)
+    }
+}
+
+impl ::protobuf::Clear for ResolveCommentParams {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.thread_id.clear();
+        self.resolved = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResolveCommentParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResolveCommentParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\rcomment.proto\"\xa7\x02\n\rCommentThread\x12\x0e\n\x02id\x18\x01\x20\
+    \x01(\tR\x02id\x12\x15\n\x06doc_id\x18\x02\x20\x01(\tR\x05docId\x12\x16\
+    \n\x06author\x18\x03\x20\x01(\tR\x06author\x12\x18\n\x07content\x18\x04\
+    \x20\x01(\tR\x07content\x12\x1f\n\x0bcreate_time\x18\x05\x20\x01(\x03R\n\
+    createTime\x12\x1a\n\x08resolved\x18\x06\x20\x01(\x08R\x08resolved\x12\
+    \x1a\n\x08orphaned\x18\x07\x20\x01(\x08R\x08orphaned\x12!\n\x0canchor_st\
+    art\x18\x08\x20\x01(\x03R\x0banchorStart\x12\x1d\n\nanchor_end\x18\t\x20\
+    \x01(\x03R\tanchorEnd\x12\"\n\x07replies\x18\n\x20\x03(\x0b2\x08.Comment\
+    R\x07replies\"\x89\x01\n\x07Comment\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\
+    \x02id\x12\x1b\n\tthread_id\x18\x02\x20\x01(\tR\x08threadId\x12\x16\n\
+    \x06author\x18\x03\x20\x01(\tR\x06author\x12\x18\n\x07content\x18\x04\
+    \x20\x01(\tR\x07content\x12\x1f\n\x0bcreate_time\x18\x05\x20\x01(\x03R\n\
+    createTime\"=\n\x15RepeatedCommentThread\x12$\n\x05items\x18\x01\x20\x03\
+    (\x0b2\x0e.CommentThreadR\x05items\"-\n\x14QueryCommentsRequest\x12\x15\
+    \n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\",\n\x13QueryCommentsParams\
+    \x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\"o\n\x14CreateCommentR\
+    equest\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12\x14\n\x05sta\
+    rt\x18\x02\x20\x01(\x03R\x05start\x12\x10\n\x03end\x18\x03\x20\x01(\x03R\
+    \x03end\x12\x18\n\x07content\x18\x04\x20\x01(\tR\x07content\"n\n\x13Crea\
+    teCommentParams\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12\x14\
+    \n\x05start\x18\x02\x20\x01(\x03R\x05start\x12\x10\n\x03end\x18\x03\x20\
+    \x01(\x03R\x03end\x12\x18\n\x07content\x18\x04\x20\x01(\tR\x07content\"c\
+    \n\x13ReplyCommentRequest\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05doc\
+    Id\x12\x1b\n\tthread_id\x18\x02\x20\x01(\tR\x08threadId\x12\x18\n\x07con\
+    tent\x18\x03\x20\x01(\tR\x07content\"b\n\x12ReplyCommentParams\x12\x15\n\
+    \x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12\x1b\n\tthread_id\x18\x02\x20\
+    \x01(\tR\x08threadId\x12\x18\n\x07content\x18\x03\x20\x01(\tR\x07content\
+    \"g\n\x15ResolveCommentRequest\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\
+    \x05docId\x12\x1b\n\tthread_id\x18\x02\x20\x01(\tR\x08threadId\x12\x1a\n\
+    \x08resolved\x18\x03\x20\x01(\x08R\x08resolved\"f\n\x14ResolveCommentPar\
+    ams\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12\x1b\n\tthread_i\
+    d\x18\x02\x20\x01(\tR\x08threadId\x12\x1a\n\x08resolved\x18\x03\x20\x01(\
+    \x08R\x08resolvedJ\xe0\x12\n\x06\x12\x04\0\0=\x01\n\x08\n\x01\x0c\x12\
+    \x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\r\x01\n\n\n\x03\x04\0\x01\x12\
+    \x03\x02\x08\x15\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x12\n\x0c\n\x05\
+    \x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\
+    \x03\x0b\r\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x10\x11\n\x0b\n\x04\
+    \x04\0\x02\x01\x12\x03\x04\x04\x16\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\
+    \x04\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\x11\n\x0c\n\x05\
+    \x04\0\x02\x01\x03\x12\x03\x04\x14\x15\n\x0b\n\x04\x04\0\x02\x02\x12\x03\
+    \x05\x04\x16\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\n\n\x0c\n\x05\
+    \x04\0\x02\x02\x01\x12\x03\x05\x0b\x11\n\x0c\n\x05\x04\0\x02\x02\x03\x12\
+    \x03\x05\x14\x15\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x04\x17\n\x0c\n\
+    \x05\x04\0\x02\x03\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\0\x02\x03\x01\
+    \x12\x03\x06\x0b\x12\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x06\x15\x16\n\
+    \x0b\n\x04\x04\0\x02\x04\x12\x03\x07\x04\x1a\n\x0c\n\x05\x04\0\x02\x04\
+    \x05\x12\x03\x07\x04\t\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\n\x15\n\
+    \x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x07\x18\x19\n\x0b\n\x04\x04\0\x02\
+    \x05\x12\x03\x08\x04\x16\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\x08\x04\
+    \x08\n\x0c\n\x05\x04\0\x02\x05\x01\x12\x03\x08\t\x11\n\x0c\n\x05\x04\0\
+    \x02\x05\x03\x12\x03\x08\x14\x15\n\x0b\n\x04\x04\0\x02\x06\x12\x03\t\x04\
+    \x16\n\x0c\n\x05\x04\0\x02\x06\x05\x12\x03\t\x04\x08\n\x0c\n\x05\x04\0\
+    \x02\x06\x01\x12\x03\t\t\x11\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03\t\x14\
+    \x15\n\x0b\n\x04\x04\0\x02\x07\x12\x03\n\x04\x1b\n\x0c\n\x05\x04\0\x02\
+    \x07\x05\x12\x03\n\x04\t\n\x0c\n\x05\x04\0\x02\x07\x01\x12\x03\n\n\x16\n\
+    \x0c\n\x05\x04\0\x02\x07\x03\x12\x03\n\x19\x1a\n\x0b\n\x04\x04\0\x02\x08\
+    \x12\x03\x0b\x04\x19\n\x0c\n\x05\x04\0\x02\x08\x05\x12\x03\x0b\x04\t\n\
+    \x0c\n\x05\x04\0\x02\x08\x01\x12\x03\x0b\n\x14\n\x0c\n\x05\x04\0\x02\x08\
+    \x03\x12\x03\x0b\x17\x18\n\x0b\n\x04\x04\0\x02\t\x12\x03\x0c\x04\"\n\x0c\
+    \n\x05\x04\0\x02\t\x04\x12\x03\x0c\x04\x0c\n\x0c\n\x05\x04\0\x02\t\x06\
+    \x12\x03\x0c\r\x14\n\x0c\n\x05\x04\0\x02\t\x01\x12\x03\x0c\x15\x1c\n\x0c\
+    \n\x05\x04\0\x02\t\x03\x12\x03\x0c\x1f!\n\n\n\x02\x04\x01\x12\x04\x0e\0\
+    \x14\x01\n\n\n\x03\x04\x01\x01\x12\x03\x0e\x08\x0f\n\x0b\n\x04\x04\x01\
+    \x02\0\x12\x03\x0f\x04\x12\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x0f\x04\
+    \n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0f\x0b\r\n\x0c\n\x05\x04\x01\
+    \x02\0\x03\x12\x03\x0f\x10\x11\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x10\
+    \x04\x19\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\x10\x04\n\n\x0c\n\x05\
+    \x04\x01\x02\x01\x01\x12\x03\x10\x0b\x14\n\x0c\n\x05\x04\x01\x02\x01\x03\
+    \x12\x03\x10\x17\x18\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x11\x04\x16\n\
+    \x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\x11\x04\n\n\x0c\n\x05\x04\x01\x02\
+    \x02\x01\x12\x03\x11\x0b\x11\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x11\
+    \x14\x15\n\x0b\n\x04\x04\x01\x02\x03\x12\x03\x12\x04\x17\n\x0c\n\x05\x04\
+    \x01\x02\x03\x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\
+    \x03\x12\x0b\x12\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\x12\x15\x16\n\
+    \x0b\n\x04\x04\x01\x02\x04\x12\x03\x13\x04\x1a\n\x0c\n\x05\x04\x01\x02\
+    \x04\x05\x12\x03\x13\x04\t\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\x13\n\
+    \x15\n\x0c\n\x05\x04\x01\x02\x04\x03\x12\x03\x13\x18\x19\n\n\n\x02\x04\
+    \x02\x12\x04\x15\0\x17\x01\n\n\n\x03\x04\x02\x01\x12\x03\x15\x08\x1d\n\
+    \x0b\n\x04\x04\x02\x02\0\x12\x03\x16\x04%\n\x0c\n\x05\x04\x02\x02\0\x04\
+    \x12\x03\x16\x04\x0c\n\x0c\n\x05\x04\x02\x02\0\x06\x12\x03\x16\r\x1a\n\
+    \x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x16\x1b\x20\n\x0c\n\x05\x04\x02\x02\
+    \0\x03\x12\x03\x16#$\n\n\n\x02\x04\x03\x12\x04\x18\0\x1a\x01\n\n\n\x03\
+    \x04\x03\x01\x12\x03\x18\x08\x1c\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x19\
+    \x04\x16\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x19\x04\n\n\x0c\n\x05\x04\
+    \x03\x02\0\x01\x12\x03\x19\x0b\x11\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\
+    \x19\x14\x15\n\n\n\x02\x04\x04\x12\x04\x1b\0\x1d\x01\n\n\n\x03\x04\x04\
+    \x01\x12\x03\x1b\x08\x1b\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x1c\x04\x16\n\
+    \x0c\n\x05\x04\x04\x02\0\x05\x12\x03\x1c\x04\n\n\x0c\n\x05\x04\x04\x02\0\
+    \x01\x12\x03\x1c\x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x1c\x14\
+    \x15\n\n\n\x02\x04\x05\x12\x04\x1e\0#\x01\n\n\n\x03\x04\x05\x01\x12\x03\
+    \x1e\x08\x1c\n\x0b\n\x04\x04\x05\x02\0\x12\x03\x1f\x04\x16\n\x0c\n\x05\
+    \x04\x05\x02\0\x05\x12\x03\x1f\x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\
+    \x03\x1f\x0b\x11\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03\x1f\x14\x15\n\x0b\
+    \n\x04\x04\x05\x02\x01\x12\x03\x20\x04\x14\n\x0c\n\x05\x04\x05\x02\x01\
+    \x05\x12\x03\x20\x04\t\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03\x20\n\x0f\
+    \n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03\x20\x12\x13\n\x0b\n\x04\x04\x05\
+    \x02\x02\x12\x03!\x04\x12\n\x0c\n\x05\x04\x05\x02\x02\x05\x12\x03!\x04\t\
+    \n\x0c\n\x05\x04\x05\x02\x02\x01\x12\x03!\n\r\n\x0c\n\x05\x04\x05\x02\
+    \x02\x03\x12\x03!\x10\x11\n\x0b\n\x04\x04\x05\x02\x03\x12\x03\"\x04\x17\
+    \n\x0c\n\x05\x04\x05\x02\x03\x05\x12\x03\"\x04\n\n\x0c\n\x05\x04\x05\x02\
+    \x03\x01\x12\x03\"\x0b\x12\n\x0c\n\x05\x04\x05\x02\x03\x03\x12\x03\"\x15\
+    \x16\n\n\n\x02\x04\x06\x12\x04$\0)\x01\n\n\n\x03\x04\x06\x01\x12\x03$\
+    \x08\x1b\n\x0b\n\x04\x04\x06\x02\0\x12\x03%\x04\x16\n\x0c\n\x05\x04\x06\
+    \x02\0\x05\x12\x03%\x04\n\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03%\x0b\x11\
+    \n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03%\x14\x15\n\x0b\n\x04\x04\x06\x02\
+    \x01\x12\x03&\x04\x14\n\x0c\n\x05\x04\x06\x02\x01\x05\x12\x03&\x04\t\n\
+    \x0c\n\x05\x04\x06\x02\x01\x01\x12\x03&\n\x0f\n\x0c\n\x05\x04\x06\x02\
+    \x01\x03\x12\x03&\x12\x13\n\x0b\n\x04\x04\x06\x02\x02\x12\x03'\x04\x12\n\
+    \x0c\n\x05\x04\x06\x02\x02\x05\x12\x03'\x04\t\n\x0c\n\x05\x04\x06\x02\
+    \x02\x01\x12\x03'\n\r\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x03'\x10\x11\n\
+    \x0b\n\x04\x04\x06\x02\x03\x12\x03(\x04\x17\n\x0c\n\x05\x04\x06\x02\x03\
+    \x05\x12\x03(\x04\n\n\x0c\n\x05\x04\x06\x02\x03\x01\x12\x03(\x0b\x12\n\
+    \x0c\n\x05\x04\x06\x02\x03\x03\x12\x03(\x15\x16\n\n\n\x02\x04\x07\x12\
+    \x04*\0.\x01\n\n\n\x03\x04\x07\x01\x12\x03*\x08\x1b\n\x0b\n\x04\x04\x07\
+    \x02\0\x12\x03+\x04\x16\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03+\x04\n\n\
+    \x0c\n\x05\x04\x07\x02\0\x01\x12\x03+\x0b\x11\n\x0c\n\x05\x04\x07\x02\0\
+    \x03\x12\x03+\x14\x15\n\x0b\n\x04\x04\x07\x02\x01\x12\x03,\x04\x19\n\x0c\
+    \n\x05\x04\x07\x02\x01\x05\x12\x03,\x04\n\n\x0c\n\x05\x04\x07\x02\x01\
+    \x01\x12\x03,\x0b\x14\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x03,\x17\x18\n\
+    \x0b\n\x04\x04\x07\x02\x02\x12\x03-\x04\x17\n\x0c\n\x05\x04\x07\x02\x02\
+    \x05\x12\x03-\x04\n\n\x0c\n\x05\x04\x07\x02\x02\x01\x12\x03-\x0b\x12\n\
+    \x0c\n\x05\x04\x07\x02\x02\x03\x12\x03-\x15\x16\n\n\n\x02\x04\x08\x12\
+    \x04/\03\x01\n\n\n\x03\x04\x08\x01\x12\x03/\x08\x1a\n\x0b\n\x04\x04\x08\
+    \x02\0\x12\x030\x04\x16\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x030\x04\n\n\
+    \x0c\n\x05\x04\x08\x02\0\x01\x12\x030\x0b\x11\n\x0c\n\x05\x04\x08\x02\0\
+    \x03\x12\x030\x14\x15\n\x0b\n\x04\x04\x08\x02\x01\x12\x031\x04\x19\n\x0c\
+    \n\x05\x04\x08\x02\x01\x05\x12\x031\x04\n\n\x0c\n\x05\x04\x08\x02\x01\
+    \x01\x12\x031\x0b\x14\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\x031\x17\x18\n\
+    \x0b\n\x04\x04\x08\x02\x02\x12\x032\x04\x17\n\x0c\n\x05\x04\x08\x02\x02\
+    \x05\x12\x032\x04\n\n\x0c\n\x05\x04\x08\x02\x02\x01\x12\x032\x0b\x12\n\
+    \x0c\n\x05\x04\x08\x02\x02\x03\x12\x032\x15\x16\n\n\n\x02\x04\t\x12\x044\
+    \08\x01\n\n\n\x03\x04\t\x01\x12\x034\x08\x1d\n\x0b\n\x04\x04\t\x02\0\x12\
+    \x035\x04\x16\n\x0c\n\x05\x04\t\x02\0\x05\x12\x035\x04\n\n\x0c\n\x05\x04\
+    \t\x02\0\x01\x12\x035\x0b\x11\n\x0c\n\x05\x04\t\x02\0\x03\x12\x035\x14\
+    \x15\n\x0b\n\x04\x04\t\x02\x01\x12\x036\x04\x19\n\x0c\n\x05\x04\t\x02\
+    \x01\x05\x12\x036\x04\n\n\x0c\n\x05\x04\t\x02\x01\x01\x12\x036\x0b\x14\n\
+    \x0c\n\x05\x04\t\x02\x01\x03\x12\x036\x17\x18\n\x0b\n\x04\x04\t\x02\x02\
+    \x12\x037\x04\x16\n\x0c\n\x05\x04\t\x02\x02\x05\x12\x037\x04\x08\n\x0c\n\
+    \x05\x04\t\x02\x02\x01\x12\x037\t\x11\n\x0c\n\x05\x04\t\x02\x02\x03\x12\
+    \x037\x14\x15\n\n\n\x02\x04\n\x12\x049\0=\x01\n\n\n\x03\x04\n\x01\x12\
+    \x039\x08\x1c\n\x0b\n\x04\x04\n\x02\0\x12\x03:\x04\x16\n\x0c\n\x05\x04\n\
+    \x02\0\x05\x12\x03:\x04\n\n\x0c\n\x05\x04\n\x02\0\x01\x12\x03:\x0b\x11\n\
+    \x0c\n\x05\x04\n\x02\0\x03\x12\x03:\x14\x15\n\x0b\n\x04\x04\n\x02\x01\
+    \x12\x03;\x04\x19\n\x0c\n\x05\x04\n\x02\x01\x05\x12\x03;\x04\n\n\x0c\n\
+    \x05\x04\n\x02\x01\x01\x12\x03;\x0b\x14\n\x0c\n\x05\x04\n\x02\x01\x03\
+    \x12\x03;\x17\x18\n\x0b\n\x04\x04\n\x02\x02\x12\x03<\x04\x16\n\x0c\n\x05\
+    \x04\n\x02\x02\x05\x12\x03<\x04\x08\n\x0c\n\x05\x04\n\x02\x02\x01\x12\
+    \x03<\t\x11\n\x0c\n\x05\x04\n\x02\x02\x03\x12\x03<\x14\x15b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
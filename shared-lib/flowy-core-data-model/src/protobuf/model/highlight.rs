@@ -0,0 +1,719 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `highlight.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct HighlightSpan {
+    // message fields
+    pub start: i64,
+    pub end: i64,
+    pub kind: HighlightKind,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a HighlightSpan {
+    fn default() -> &'a HighlightSpan {
+        <HighlightSpan as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HighlightSpan {
+    pub fn new() -> HighlightSpan {
+        ::std::default::Default::default()
+    }
+
+    // int64 start = 1;
+
+
+    pub fn get_start(&self) -> i64 {
+        self.start
+    }
+    pub fn clear_start(&mut self) {
+        self.start = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: i64) {
+        self.start = v;
+    }
+
+    // int64 end = 2;
+
+
+    pub fn get_end(&self) -> i64 {
+        self.end
+    }
+    pub fn clear_end(&mut self) {
+        self.end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end(&mut self, v: i64) {
+        self.end = v;
+    }
+
+    // .HighlightKind kind = 3;
+
+
+    pub fn get_kind(&self) -> HighlightKind {
+        self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind = HighlightKind::Keyword;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: HighlightKind) {
+        self.kind = v;
+    }
+}
+
+impl ::protobuf::Message for HighlightSpan {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.start = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.end = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.kind, 3, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.start != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.start, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.end, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.kind != HighlightKind::Keyword {
+            my_size += ::protobuf::rt::enum_size(3, self.kind);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.start != 0 {
+            os.write_int64(1, self.start)?;
+        }
+        if self.end != 0 {
+            os.write_int64(2, self.end)?;
+        }
+        if self.kind != HighlightKind::Keyword {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.kind))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> HighlightSpan {
+        HighlightSpan::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "start",
+                |m: &HighlightSpan| { &m.start },
+                |m: &mut HighlightSpan| { &mut m.start },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "end",
+                |m: &HighlightSpan| { &m.end },
+                |m: &mut HighlightSpan| { &mut m.end },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<HighlightKind>>(
+                "kind",
+                |m: &HighlightSpan| { &m.kind },
+                |m: &mut HighlightSpan| { &mut m.kind },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<HighlightSpan>(
+                "HighlightSpan",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static HighlightSpan {
+        static instance: ::protobuf::rt::LazyV2<HighlightSpan> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(HighlightSpan::new)
+    }
+}
+
+impl ::protobuf::Clear for HighlightSpan {
+    fn clear(&mut self) {
+        self.start = 0;
+        self.end = 0;
+        self.kind = HighlightKind::Keyword;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for HighlightSpan {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HighlightSpan {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedHighlightSpan {
+    // message fields
+    pub items: ::protobuf::RepeatedField<HighlightSpan>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedHighlightSpan {
+    fn default() -> &'a RepeatedHighlightSpan {
+        <RepeatedHighlightSpan as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedHighlightSpan {
+    pub fn new() -> RepeatedHighlightSpan {
+        ::std::default::Default::default()
+    }
+
+    // repeated .HighlightSpan items = 1;
+
+
+    pub fn get_items(&self) -> &[HighlightSpan] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<HighlightSpan>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<HighlightSpan> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<HighlightSpan> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedHighlightSpan {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedHighlightSpan {
+        RepeatedHighlightSpan::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HighlightSpan>>(
+                "items",
+                |m: &RepeatedHighlightSpan| { &m.items },
+                |m: &mut RepeatedHighlightSpan| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedHighlightSpan>(
+                "RepeatedHighlightSpan",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedHighlightSpan {
+        static instance: ::protobuf::rt::LazyV2<RepeatedHighlightSpan> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedHighlightSpan::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedHighlightSpan {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedHighlightSpan {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedHighlightSpan {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct HighlightCodeRequest {
+    // message fields
+    pub language: ::std::string::String,
+    pub code: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a HighlightCodeRequest {
+    fn default() -> &'a HighlightCodeRequest {
+        <HighlightCodeRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HighlightCodeRequest {
+    pub fn new() -> HighlightCodeRequest {
+        ::std::default::Default::default()
+    }
+
+    // string language = 1;
+
+
+    pub fn get_language(&self) -> &str {
+        &self.language
+    }
+    pub fn clear_language(&mut self) {
+        self.language.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_language(&mut self, v: ::std::string::String) {
+        self.language = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_language(&mut self) -> &mut ::std::string::String {
+        &mut self.language
+    }
+
+    // Take field
+    pub fn take_language(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.language, ::std::string::String::new())
+    }
+
+    // string code = 2;
+
+
+    pub fn get_code(&self) -> &str {
+        &self.code
+    }
+    pub fn clear_code(&mut self) {
+        self.code.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_code(&mut self, v: ::std::string::String) {
+        self.code = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_code(&mut self) -> &mut ::std::string::String {
+        &mut self.code
+    }
+
+    // Take field
+    pub fn take_code(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.code, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for HighlightCodeRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.language)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.code)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.language.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.language);
+        }
+        if !self.code.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.code);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.language.is_empty() {
+            os.write_string(1, &self.language)?;
+        }
+        if !self.code.is_empty() {
+            os.write_string(2, &self.code)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> HighlightCodeRequest {
+        HighlightCodeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "language",
+                |m: &HighlightCodeRequest| { &m.language },
+                |m: &mut HighlightCodeRequest| { &mut m.language },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "code",
+                |m: &HighlightCodeRequest| { &m.code },
+                |m: &mut HighlightCodeRequest| { &mut m.code },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<HighlightCodeRequest>(
+                "HighlightCodeRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static HighlightCodeRequest {
+        static instance: ::protobuf::rt::LazyV2<HighlightCodeRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(HighlightCodeRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for HighlightCodeRequest {
+    fn clear(&mut self) {
+        self.language.clear();
+        self.code.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for HighlightCodeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HighlightCodeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum HighlightKind {
+    Keyword = 0,
+    StringLiteral = 1,
+    Number = 2,
+    Comment = 3,
+}
+
+impl ::protobuf::ProtobufEnum for HighlightKind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<HighlightKind> {
+        match value {
+            0 => ::std::option::Option::Some(HighlightKind::Keyword),
+            1 => ::std::option::Option::Some(HighlightKind::StringLiteral),
+            2 => ::std::option::Option::Some(HighlightKind::Number),
+            3 => ::std::option::Option::Some(HighlightKind::Comment),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [HighlightKind] = &[
+            HighlightKind::Keyword,
+            HighlightKind::StringLiteral,
+            HighlightKind::Number,
+            HighlightKind::Comment,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<HighlightKind>("HighlightKind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for HighlightKind {
+}
+
+impl ::std::default::Default for HighlightKind {
+    fn default() -> Self {
+        HighlightKind::Keyword
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HighlightKind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0fhighlight.proto\"[\n\rHighlightSpan\x12\x14\n\x05start\x18\x01\x20\
+    \x01(\x03R\x05start\x12\x10\n\x03end\x18\x02\x20\x01(\x03R\x03end\x12\"\
+    \n\x04kind\x18\x03\x20\x01(\x0e2\x0e.HighlightKindR\x04kind\"=\n\x15Repe\
+    atedHighlightSpan\x12$\n\x05items\x18\x01\x20\x03(\x0b2\x0e.HighlightSpa\
+    nR\x05items\"F\n\x14HighlightCodeRequest\x12\x1a\n\x08language\x18\x01\
+    \x20\x01(\tR\x08language\x12\x12\n\x04code\x18\x02\x20\x01(\tR\x04code*H\
+    \n\rHighlightKind\x12\x0b\n\x07Keyword\x10\0\x12\x11\n\rStringLiteral\
+    \x10\x01\x12\n\n\x06Number\x10\x02\x12\x0b\n\x07Comment\x10\x03J\xee\x04\
+    \n\x06\x12\x04\0\0\x13\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\
+    \0\x12\x04\x02\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x15\n\x0b\n\
+    \x04\x04\0\x02\0\x12\x03\x03\x04\x14\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\
+    \x03\x04\t\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\n\x0f\n\x0c\n\x05\x04\
+    \0\x02\0\x03\x12\x03\x03\x12\x13\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\
+    \x04\x12\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\t\n\x0c\n\x05\x04\
+    \0\x02\x01\x01\x12\x03\x04\n\r\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\
+    \x10\x11\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\x1b\n\x0c\n\x05\x04\0\
+    \x02\x02\x06\x12\x03\x05\x04\x11\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\
+    \x05\x12\x16\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x19\x1a\n\n\n\x02\
+    \x04\x01\x12\x04\x07\0\t\x01\n\n\n\x03\x04\x01\x01\x12\x03\x07\x08\x1d\n\
+    \x0b\n\x04\x04\x01\x02\0\x12\x03\x08\x04%\n\x0c\n\x05\x04\x01\x02\0\x04\
+    \x12\x03\x08\x04\x0c\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03\x08\r\x1a\n\
+    \x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x08\x1b\x20\n\x0c\n\x05\x04\x01\x02\
+    \0\x03\x12\x03\x08#$\n\n\n\x02\x04\x02\x12\x04\n\0\r\x01\n\n\n\x03\x04\
+    \x02\x01\x12\x03\n\x08\x1c\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x0b\x04\x18\
+    \n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0b\x04\n\n\x0c\n\x05\x04\x02\x02\
+    \0\x01\x12\x03\x0b\x0b\x13\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x0b\x16\
+    \x17\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0c\x04\x14\n\x0c\n\x05\x04\x02\
+    \x02\x01\x05\x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\
+    \x0c\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0c\x12\x13\n\n\n\
+    \x02\x05\0\x12\x04\x0e\0\x13\x01\n\n\n\x03\x05\0\x01\x12\x03\x0e\x05\x12\
+    \n\x0b\n\x04\x05\0\x02\0\x12\x03\x0f\x04\x10\n\x0c\n\x05\x05\0\x02\0\x01\
+    \x12\x03\x0f\x04\x0b\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x0f\x0e\x0f\n\
+    \x0b\n\x04\x05\0\x02\x01\x12\x03\x10\x04\x16\n\x0c\n\x05\x05\0\x02\x01\
+    \x01\x12\x03\x10\x04\x11\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x10\x14\
+    \x15\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x11\x04\x0f\n\x0c\n\x05\x05\0\x02\
+    \x02\x01\x12\x03\x11\x04\n\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x11\r\
+    \x0e\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x12\x04\x10\n\x0c\n\x05\x05\0\x02\
+    \x03\x01\x12\x03\x12\x04\x0b\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x12\
+    \x0e\x0fb\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -18,3 +18,18 @@ pub use trash::*;
 
 mod workspace;
 pub use workspace::*;
+
+mod template;
+pub use template::*;
+
+mod grid;
+pub use grid::*;
+
+mod board;
+pub use board::*;
+
+mod comment;
+pub use comment::*;
+
+mod highlight;
+pub use highlight::*;
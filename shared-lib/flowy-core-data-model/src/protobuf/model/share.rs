@@ -408,6 +408,7 @@ pub enum ExportType {
     Text = 0,
     Markdown = 1,
     Link = 2,
+    Html = 3,
 }
 
 impl ::protobuf::ProtobufEnum for ExportType {
@@ -420,6 +421,7 @@ impl ::protobuf::ProtobufEnum for ExportType {
             0 => ::std::option::Option::Some(ExportType::Text),
             1 => ::std::option::Option::Some(ExportType::Markdown),
             2 => ::std::option::Option::Some(ExportType::Link),
+            3 => ::std::option::Option::Some(ExportType::Html),
             _ => ::std::option::Option::None
         }
     }
@@ -429,6 +431,7 @@ impl ::protobuf::ProtobufEnum for ExportType {
             ExportType::Text,
             ExportType::Markdown,
             ExportType::Link,
+            ExportType::Html,
         ];
         values
     }
@@ -461,29 +464,32 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x01(\tR\x05docId\x12,\n\x0bexport_type\x18\x02\x20\x01(\x0e2\x0b.Export\
     TypeR\nexportType\"N\n\nExportData\x12\x12\n\x04data\x18\x01\x20\x01(\tR\
     \x04data\x12,\n\x0bexport_type\x18\x02\x20\x01(\x0e2\x0b.ExportTypeR\nex\
-    portType*.\n\nExportType\x12\x08\n\x04Text\x10\0\x12\x0c\n\x08Markdown\
-    \x10\x01\x12\x08\n\x04Link\x10\x02J\xb1\x03\n\x06\x12\x04\0\0\x0e\x01\n\
-    \x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x05\x01\n\n\
-    \n\x03\x04\0\x01\x12\x03\x02\x08\x15\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\
-    \x04\x16\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\
-    \x02\0\x01\x12\x03\x03\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\
-    \x14\x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x1f\n\x0c\n\x05\x04\0\
-    \x02\x01\x06\x12\x03\x04\x04\x0e\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\
-    \x04\x0f\x1a\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x1d\x1e\n\n\n\x02\
-    \x04\x01\x12\x04\x06\0\t\x01\n\n\n\x03\x04\x01\x01\x12\x03\x06\x08\x12\n\
-    \x0b\n\x04\x04\x01\x02\0\x12\x03\x07\x04\x14\n\x0c\n\x05\x04\x01\x02\0\
-    \x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x07\x0b\x0f\
-    \n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x07\x12\x13\n\x0b\n\x04\x04\x01\
-    \x02\x01\x12\x03\x08\x04\x1f\n\x0c\n\x05\x04\x01\x02\x01\x06\x12\x03\x08\
-    \x04\x0e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x08\x0f\x1a\n\x0c\n\x05\
-    \x04\x01\x02\x01\x03\x12\x03\x08\x1d\x1e\n\n\n\x02\x05\0\x12\x04\n\0\x0e\
-    \x01\n\n\n\x03\x05\0\x01\x12\x03\n\x05\x0f\n\x0b\n\x04\x05\0\x02\0\x12\
-    \x03\x0b\x04\r\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x0b\x04\x08\n\x0c\n\
-    \x05\x05\0\x02\0\x02\x12\x03\x0b\x0b\x0c\n\x0b\n\x04\x05\0\x02\x01\x12\
-    \x03\x0c\x04\x11\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x0c\x04\x0c\n\x0c\
-    \n\x05\x05\0\x02\x01\x02\x12\x03\x0c\x0f\x10\n\x0b\n\x04\x05\0\x02\x02\
-    \x12\x03\r\x04\r\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\r\x04\x08\n\x0c\n\
-    \x05\x05\0\x02\x02\x02\x12\x03\r\x0b\x0cb\x06proto3\
+    portType*8\n\nExportType\x12\x08\n\x04Text\x10\0\x12\x0c\n\x08Markdown\
+    \x10\x01\x12\x08\n\x04Link\x10\x02\x12\x08\n\x04Html\x10\x03J\xda\x03\n\
+    \x06\x12\x04\0\0\x0f\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\
+    \x12\x04\x02\0\x05\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x15\n\x0b\n\
+    \x04\x04\0\x02\0\x12\x03\x03\x04\x16\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\
+    \x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x11\n\x0c\n\x05\
+    \x04\0\x02\0\x03\x12\x03\x03\x14\x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03\
+    \x04\x04\x1f\n\x0c\n\x05\x04\0\x02\x01\x06\x12\x03\x04\x04\x0e\n\x0c\n\
+    \x05\x04\0\x02\x01\x01\x12\x03\x04\x0f\x1a\n\x0c\n\x05\x04\0\x02\x01\x03\
+    \x12\x03\x04\x1d\x1e\n\n\n\x02\x04\x01\x12\x04\x06\0\t\x01\n\n\n\x03\x04\
+    \x01\x01\x12\x03\x06\x08\x12\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x07\x04\
+    \x14\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\x01\
+    \x02\0\x01\x12\x03\x07\x0b\x0f\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x07\
+    \x12\x13\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x08\x04\x1f\n\x0c\n\x05\x04\
+    \x01\x02\x01\x06\x12\x03\x08\x04\x0e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\
+    \x03\x08\x0f\x1a\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x08\x1d\x1e\n\n\
+    \n\x02\x05\0\x12\x04\n\0\x0f\x01\n\n\n\x03\x05\0\x01\x12\x03\n\x05\x0f\n\
+    \x0b\n\x04\x05\0\x02\0\x12\x03\x0b\x04\r\n\x0c\n\x05\x05\0\x02\0\x01\x12\
+    \x03\x0b\x04\x08\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x0b\x0b\x0c\n\x0b\n\
+    \x04\x05\0\x02\x01\x12\x03\x0c\x04\x11\n\x0c\n\x05\x05\0\x02\x01\x01\x12\
+    \x03\x0c\x04\x0c\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x0c\x0f\x10\n\x0b\
+    \n\x04\x05\0\x02\x02\x12\x03\r\x04\r\n\x0c\n\x05\x05\0\x02\x02\x01\x12\
+    \x03\r\x04\x08\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\r\x0b\x0c\n\x0b\n\
+    \x04\x05\0\x02\x03\x12\x03\x0e\x04\r\n\x0c\n\x05\x05\0\x02\x03\x01\x12\
+    \x03\x0e\x04\x08\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x0e\x0b\x0cb\x06p\
+    roto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,1965 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `template.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Template {
+    // message fields
+    pub id: ::std::string::String,
+    pub workspace_id: ::std::string::String,
+    pub name: ::std::string::String,
+    pub ty: TemplateType,
+    pub create_time: i64,
+    pub created_by: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Template {
+    fn default() -> &'a Template {
+        <Template as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Template {
+    pub fn new() -> Template {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string workspace_id = 2;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string name = 3;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // .TemplateType ty = 4;
+
+
+    pub fn get_ty(&self) -> TemplateType {
+        self.ty
+    }
+    pub fn clear_ty(&mut self) {
+        self.ty = TemplateType::Unknown;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ty(&mut self, v: TemplateType) {
+        self.ty = v;
+    }
+
+    // int64 create_time = 5;
+
+
+    pub fn get_create_time(&self) -> i64 {
+        self.create_time
+    }
+    pub fn clear_create_time(&mut self) {
+        self.create_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_time(&mut self, v: i64) {
+        self.create_time = v;
+    }
+
+    // string created_by = 6;
+
+
+    pub fn get_created_by(&self) -> &str {
+        &self.created_by
+    }
+    pub fn clear_created_by(&mut self) {
+        self.created_by.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_by(&mut self, v: ::std::string::String) {
+        self.created_by = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_created_by(&mut self) -> &mut ::std::string::String {
+        &mut self.created_by
+    }
+
+    // Take field
+    pub fn take_created_by(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.created_by, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Template {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 4, &mut self.unknown_fields)?
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.create_time = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.created_by)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.workspace_id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.name);
+        }
+        if self.ty != TemplateType::Unknown {
+            my_size += ::protobuf::rt::enum_size(4, self.ty);
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.create_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.created_by.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.created_by);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.workspace_id.is_empty() {
+            os.write_string(2, &self.workspace_id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(3, &self.name)?;
+        }
+        if self.ty != TemplateType::Unknown {
+            os.write_enum(4, ::protobuf::ProtobufEnum::value(&self.ty))?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(5, self.create_time)?;
+        }
+        if !self.created_by.is_empty() {
+            os.write_string(6, &self.created_by)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Template {
+        Template::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &Template| { &m.id },
+                |m: &mut Template| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &Template| { &m.workspace_id },
+                |m: &mut Template| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &Template| { &m.name },
+                |m: &mut Template| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<TemplateType>>(
+                "ty",
+                |m: &Template| { &m.ty },
+                |m: &mut Template| { &mut m.ty },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "create_time",
+                |m: &Template| { &m.create_time },
+                |m: &mut Template| { &mut m.create_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "created_by",
+                |m: &Template| { &m.created_by },
+                |m: &mut Template| { &mut m.created_by },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Template>(
+                "Template",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Template {
+        static instance: ::protobuf::rt::LazyV2<Template> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Template::new)
+    }
+}
+
+impl ::protobuf::Clear for Template {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.workspace_id.clear();
+        self.name.clear();
+        self.ty = TemplateType::Unknown;
+        self.create_time = 0;
+        self.created_by.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Template {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Template {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedTemplate {
+    // message fields
+    pub items: ::protobuf::RepeatedField<Template>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedTemplate {
+    fn default() -> &'a RepeatedTemplate {
+        <RepeatedTemplate as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedTemplate {
+    pub fn new() -> RepeatedTemplate {
+        ::std::default::Default::default()
+    }
+
+    // repeated .Template items = 1;
+
+
+    pub fn get_items(&self) -> &[Template] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<Template>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<Template> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<Template> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedTemplate {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedTemplate {
+        RepeatedTemplate::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Template>>(
+                "items",
+                |m: &RepeatedTemplate| { &m.items },
+                |m: &mut RepeatedTemplate| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedTemplate>(
+                "RepeatedTemplate",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedTemplate {
+        static instance: ::protobuf::rt::LazyV2<RepeatedTemplate> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedTemplate::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedTemplate {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedTemplate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedTemplate {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateTemplateRequest {
+    // message fields
+    pub id: ::std::string::String,
+    pub ty: TemplateType,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateTemplateRequest {
+    fn default() -> &'a CreateTemplateRequest {
+        <CreateTemplateRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateTemplateRequest {
+    pub fn new() -> CreateTemplateRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // .TemplateType ty = 2;
+
+
+    pub fn get_ty(&self) -> TemplateType {
+        self.ty
+    }
+    pub fn clear_ty(&mut self) {
+        self.ty = TemplateType::Unknown;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ty(&mut self, v: TemplateType) {
+        self.ty = v;
+    }
+}
+
+impl ::protobuf::Message for CreateTemplateRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if self.ty != TemplateType::Unknown {
+            my_size += ::protobuf::rt::enum_size(2, self.ty);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if self.ty != TemplateType::Unknown {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.ty))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateTemplateRequest {
+        CreateTemplateRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &CreateTemplateRequest| { &m.id },
+                |m: &mut CreateTemplateRequest| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<TemplateType>>(
+                "ty",
+                |m: &CreateTemplateRequest| { &m.ty },
+                |m: &mut CreateTemplateRequest| { &mut m.ty },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateTemplateRequest>(
+                "CreateTemplateRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateTemplateRequest {
+        static instance: ::protobuf::rt::LazyV2<CreateTemplateRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateTemplateRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateTemplateRequest {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.ty = TemplateType::Unknown;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateTemplateRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateTemplateRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateTemplateParams {
+    // message fields
+    pub id: ::std::string::String,
+    pub ty: TemplateType,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateTemplateParams {
+    fn default() -> &'a CreateTemplateParams {
+        <CreateTemplateParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateTemplateParams {
+    pub fn new() -> CreateTemplateParams {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // .TemplateType ty = 2;
+
+
+    pub fn get_ty(&self) -> TemplateType {
+        self.ty
+    }
+    pub fn clear_ty(&mut self) {
+        self.ty = TemplateType::Unknown;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ty(&mut self, v: TemplateType) {
+        self.ty = v;
+    }
+}
+
+impl ::protobuf::Message for CreateTemplateParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if self.ty != TemplateType::Unknown {
+            my_size += ::protobuf::rt::enum_size(2, self.ty);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if self.ty != TemplateType::Unknown {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.ty))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateTemplateParams {
+        CreateTemplateParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &CreateTemplateParams| { &m.id },
+                |m: &mut CreateTemplateParams| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<TemplateType>>(
+                "ty",
+                |m: &CreateTemplateParams| { &m.ty },
+                |m: &mut CreateTemplateParams| { &mut m.ty },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateTemplateParams>(
+                "CreateTemplateParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateTemplateParams {
+        static instance: ::protobuf::rt::LazyV2<CreateTemplateParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateTemplateParams::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateTemplateParams {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.ty = TemplateType::Unknown;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateTemplateParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateTemplateParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryTemplateRequest {
+    // message fields
+    pub template_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryTemplateRequest {
+    fn default() -> &'a QueryTemplateRequest {
+        <QueryTemplateRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryTemplateRequest {
+    pub fn new() -> QueryTemplateRequest {
+        ::std::default::Default::default()
+    }
+
+    // string template_id = 1;
+
+
+    pub fn get_template_id(&self) -> &str {
+        &self.template_id
+    }
+    pub fn clear_template_id(&mut self) {
+        self.template_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_template_id(&mut self, v: ::std::string::String) {
+        self.template_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_template_id(&mut self) -> &mut ::std::string::String {
+        &mut self.template_id
+    }
+
+    // Take field
+    pub fn take_template_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.template_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for QueryTemplateRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.template_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.template_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.template_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.template_id.is_empty() {
+            os.write_string(1, &self.template_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryTemplateRequest {
+        QueryTemplateRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "template_id",
+                |m: &QueryTemplateRequest| { &m.template_id },
+                |m: &mut QueryTemplateRequest| { &mut m.template_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryTemplateRequest>(
+                "QueryTemplateRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryTemplateRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryTemplateRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryTemplateRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryTemplateRequest {
+    fn clear(&mut self) {
+        self.template_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryTemplateRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryTemplateRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TemplateId {
+    // message fields
+    pub template_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TemplateId {
+    fn default() -> &'a TemplateId {
+        <TemplateId as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TemplateId {
+    pub fn new() -> TemplateId {
+        ::std::default::Default::default()
+    }
+
+    // string template_id = 1;
+
+
+    pub fn get_template_id(&self) -> &str {
+        &self.template_id
+    }
+    pub fn clear_template_id(&mut self) {
+        self.template_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_template_id(&mut self, v: ::std::string::String) {
+        self.template_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_template_id(&mut self) -> &mut ::std::string::String {
+        &mut self.template_id
+    }
+
+    // Take field
+    pub fn take_template_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.template_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for TemplateId {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.template_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.template_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.template_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.template_id.is_empty() {
+            os.write_string(1, &self.template_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TemplateId {
+        TemplateId::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "template_id",
+                |m: &TemplateId| { &m.template_id },
+                |m: &mut TemplateId| { &mut m.template_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TemplateId>(
+                "TemplateId",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static TemplateId {
+        static instance: ::protobuf::rt::LazyV2<TemplateId> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TemplateId::new)
+    }
+}
+
+impl ::protobuf::Clear for TemplateId {
+    fn clear(&mut self) {
+        self.template_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TemplateId {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TemplateId {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateViewFromTemplateRequest {
+    // message fields
+    pub template_id: ::std::string::String,
+    pub belong_to_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_name: ::std::option::Option<CreateViewFromTemplateRequest_oneof_one_of_name>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateViewFromTemplateRequest {
+    fn default() -> &'a CreateViewFromTemplateRequest {
+        <CreateViewFromTemplateRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum CreateViewFromTemplateRequest_oneof_one_of_name {
+    name(::std::string::String),
+}
+
+impl CreateViewFromTemplateRequest {
+    pub fn new() -> CreateViewFromTemplateRequest {
+        ::std::default::Default::default()
+    }
+
+    // string template_id = 1;
+
+
+    pub fn get_template_id(&self) -> &str {
+        &self.template_id
+    }
+    pub fn clear_template_id(&mut self) {
+        self.template_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_template_id(&mut self, v: ::std::string::String) {
+        self.template_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_template_id(&mut self) -> &mut ::std::string::String {
+        &mut self.template_id
+    }
+
+    // Take field
+    pub fn take_template_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.template_id, ::std::string::String::new())
+    }
+
+    // string belong_to_id = 2;
+
+
+    pub fn get_belong_to_id(&self) -> &str {
+        &self.belong_to_id
+    }
+    pub fn clear_belong_to_id(&mut self) {
+        self.belong_to_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_belong_to_id(&mut self, v: ::std::string::String) {
+        self.belong_to_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_belong_to_id(&mut self) -> &mut ::std::string::String {
+        &mut self.belong_to_id
+    }
+
+    // Take field
+    pub fn take_belong_to_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.belong_to_id, ::std::string::String::new())
+    }
+
+    // string name = 3;
+
+
+    pub fn get_name(&self) -> &str {
+        match self.one_of_name {
+            ::std::option::Option::Some(CreateViewFromTemplateRequest_oneof_one_of_name::name(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_name(&mut self) {
+        self.one_of_name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        match self.one_of_name {
+            ::std::option::Option::Some(CreateViewFromTemplateRequest_oneof_one_of_name::name(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.one_of_name = ::std::option::Option::Some(CreateViewFromTemplateRequest_oneof_one_of_name::name(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CreateViewFromTemplateRequest_oneof_one_of_name::name(_)) = self.one_of_name {
+        } else {
+            self.one_of_name = ::std::option::Option::Some(CreateViewFromTemplateRequest_oneof_one_of_name::name(::std::string::String::new()));
+        }
+        match self.one_of_name {
+            ::std::option::Option::Some(CreateViewFromTemplateRequest_oneof_one_of_name::name(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        if self.has_name() {
+            match self.one_of_name.take() {
+                ::std::option::Option::Some(CreateViewFromTemplateRequest_oneof_one_of_name::name(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for CreateViewFromTemplateRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.template_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.belong_to_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_name = ::std::option::Option::Some(CreateViewFromTemplateRequest_oneof_one_of_name::name(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.template_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.template_id);
+        }
+        if !self.belong_to_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.belong_to_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &CreateViewFromTemplateRequest_oneof_one_of_name::name(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.template_id.is_empty() {
+            os.write_string(1, &self.template_id)?;
+        }
+        if !self.belong_to_id.is_empty() {
+            os.write_string(2, &self.belong_to_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &CreateViewFromTemplateRequest_oneof_one_of_name::name(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateViewFromTemplateRequest {
+        CreateViewFromTemplateRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "template_id",
+                |m: &CreateViewFromTemplateRequest| { &m.template_id },
+                |m: &mut CreateViewFromTemplateRequest| { &mut m.template_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "belong_to_id",
+                |m: &CreateViewFromTemplateRequest| { &m.belong_to_id },
+                |m: &mut CreateViewFromTemplateRequest| { &mut m.belong_to_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "name",
+                CreateViewFromTemplateRequest::has_name,
+                CreateViewFromTemplateRequest::get_name,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateViewFromTemplateRequest>(
+                "CreateViewFromTemplateRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateViewFromTemplateRequest {
+        static instance: ::protobuf::rt::LazyV2<CreateViewFromTemplateRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateViewFromTemplateRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateViewFromTemplateRequest {
+    fn clear(&mut self) {
+        self.template_id.clear();
+        self.belong_to_id.clear();
+        self.one_of_name = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateViewFromTemplateRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateViewFromTemplateRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateViewFromTemplateParams {
+    // message fields
+    pub template_id: ::std::string::String,
+    pub belong_to_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_name: ::std::option::Option<CreateViewFromTemplateParams_oneof_one_of_name>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateViewFromTemplateParams {
+    fn default() -> &'a CreateViewFromTemplateParams {
+        <CreateViewFromTemplateParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum CreateViewFromTemplateParams_oneof_one_of_name {
+    name(::std::string::String),
+}
+
+impl CreateViewFromTemplateParams {
+    pub fn new() -> CreateViewFromTemplateParams {
+        ::std::default::Default::default()
+    }
+
+    // string template_id = 1;
+
+
+    pub fn get_template_id(&self) -> &str {
+        &self.template_id
+    }
+    pub fn clear_template_id(&mut self) {
+        self.template_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_template_id(&mut self, v: ::std::string::String) {
+        self.template_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_template_id(&mut self) -> &mut ::std::string::String {
+        &mut self.template_id
+    }
+
+    // Take field
+    pub fn take_template_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.template_id, ::std::string::String::new())
+    }
+
+    // string belong_to_id = 2;
+
+
+    pub fn get_belong_to_id(&self) -> &str {
+        &self.belong_to_id
+    }
+    pub fn clear_belong_to_id(&mut self) {
+        self.belong_to_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_belong_to_id(&mut self, v: ::std::string::String) {
+        self.belong_to_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_belong_to_id(&mut self) -> &mut ::std::string::String {
+        &mut self.belong_to_id
+    }
+
+    // Take field
+    pub fn take_belong_to_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.belong_to_id, ::std::string::String::new())
+    }
+
+    // string name = 3;
+
+
+    pub fn get_name(&self) -> &str {
+        match self.one_of_name {
+            ::std::option::Option::Some(CreateViewFromTemplateParams_oneof_one_of_name::name(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_name(&mut self) {
+        self.one_of_name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        match self.one_of_name {
+            ::std::option::Option::Some(CreateViewFromTemplateParams_oneof_one_of_name::name(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.one_of_name = ::std::option::Option::Some(CreateViewFromTemplateParams_oneof_one_of_name::name(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CreateViewFromTemplateParams_oneof_one_of_name::name(_)) = self.one_of_name {
+        } else {
+            self.one_of_name = ::std::option::Option::Some(CreateViewFromTemplateParams_oneof_one_of_name::name(::std::string::String::new()));
+        }
+        match self.one_of_name {
+            ::std::option::Option::Some(CreateViewFromTemplateParams_oneof_one_of_name::name(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        if self.has_name() {
+            match self.one_of_name.take() {
+                ::std::option::Option::Some(CreateViewFromTemplateParams_oneof_one_of_name::name(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for CreateViewFromTemplateParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.template_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.belong_to_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_name = ::std::option::Option::Some(CreateViewFromTemplateParams_oneof_one_of_name::name(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.template_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.template_id);
+        }
+        if !self.belong_to_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.belong_to_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &CreateViewFromTemplateParams_oneof_one_of_name::name(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.template_id.is_empty() {
+            os.write_string(1, &self.template_id)?;
+        }
+        if !self.belong_to_id.is_empty() {
+            os.write_string(2, &self.belong_to_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &CreateViewFromTemplateParams_oneof_one_of_name::name(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateViewFromTemplateParams {
+        CreateViewFromTemplateParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "template_id",
+                |m: &CreateViewFromTemplateParams| { &m.template_id },
+                |m: &mut CreateViewFromTemplateParams| { &mut m.template_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "belong_to_id",
+                |m: &CreateViewFromTemplateParams| { &m.belong_to_id },
+                |m: &mut CreateViewFromTemplateParams| { &mut m.belong_to_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "name",
+                CreateViewFromTemplateParams::has_name,
+                CreateViewFromTemplateParams::get_name,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateViewFromTemplateParams>(
+                "CreateViewFromTemplateParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateViewFromTemplateParams {
+        static instance: ::protobuf::rt::LazyV2<CreateViewFromTemplateParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateViewFromTemplateParams::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateViewFromTemplateParams {
+    fn clear(&mut self) {
+        self.template_id.clear();
+        self.belong_to_id.clear();
+        self.one_of_name = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateViewFromTemplateParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateViewFromTemplateParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum TemplateType {
+    Unknown = 0,
+    View = 1,
+    App = 2,
+}
+
+impl ::protobuf::ProtobufEnum for TemplateType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<TemplateType> {
+        match value {
+            0 => ::std::option::Option::Some(TemplateType::Unknown),
+            1 => ::std::option::Option::Some(TemplateType::View),
+            2 => ::std::option::Option::Some(TemplateType::App),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [TemplateType] = &[
+            TemplateType::Unknown,
+            TemplateType::View,
+            TemplateType::App,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<TemplateType>("TemplateType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for TemplateType {
+}
+
+impl ::std::default::Default for TemplateType {
+    fn default() -> Self {
+        TemplateType::Unknown
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TemplateType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0etemplate.proto\"\xb0\x01\n\x08Template\x12\x0e\n\x02id\x18\x01\x20\
+    \x01(\tR\x02id\x12!\n\x0cworkspace_id\x18\x02\x20\x01(\tR\x0bworkspaceId\
+    \x12\x12\n\x04name\x18\x03\x20\x01(\tR\x04name\x12\x1d\n\x02ty\x18\x04\
+    \x20\x01(\x0e2\r.TemplateTypeR\x02ty\x12\x1f\n\x0bcreate_time\x18\x05\
+    \x20\x01(\x03R\ncreateTime\x12\x1d\n\ncreated_by\x18\x06\x20\x01(\tR\tcr\
+    eatedBy\"3\n\x10RepeatedTemplate\x12\x1f\n\x05items\x18\x01\x20\x03(\x0b\
+    2\t.TemplateR\x05items\"F\n\x15CreateTemplateRequest\x12\x0e\n\x02id\x18\
+    \x01\x20\x01(\tR\x02id\x12\x1d\n\x02ty\x18\x02\x20\x01(\x0e2\r.TemplateT\
+    ypeR\x02ty\"E\n\x14CreateTemplateParams\x12\x0e\n\x02id\x18\x01\x20\x01(\
+    \tR\x02id\x12\x1d\n\x02ty\x18\x02\x20\x01(\x0e2\r.TemplateTypeR\x02ty\"7\
+    \n\x14QueryTemplateRequest\x12\x1f\n\x0btemplate_id\x18\x01\x20\x01(\tR\
+    \ntemplateId\"-\n\nTemplateId\x12\x1f\n\x0btemplate_id\x18\x01\x20\x01(\
+    \tR\ntemplateId\"\x87\x01\n\x1dCreateViewFromTemplateRequest\x12\x1f\n\
+    \x0btemplate_id\x18\x01\x20\x01(\tR\ntemplateId\x12\x20\n\x0cbelong_to_i\
+    d\x18\x02\x20\x01(\tR\nbelongToId\x12\x14\n\x04name\x18\x03\x20\x01(\tH\
+    \0R\x04nameB\r\n\x0bone_of_name\"\x86\x01\n\x1cCreateViewFromTemplatePar\
+    ams\x12\x1f\n\x0btemplate_id\x18\x01\x20\x01(\tR\ntemplateId\x12\x20\n\
+    \x0cbelong_to_id\x18\x02\x20\x01(\tR\nbelongToId\x12\x14\n\x04name\x18\
+    \x03\x20\x01(\tH\0R\x04nameB\r\n\x0bone_of_name*.\n\x0cTemplateType\x12\
+    \x0b\n\x07Unknown\x10\0\x12\x08\n\x04View\x10\x01\x12\x07\n\x03App\x10\
+    \x02J\xbe\x0b\n\x06\x12\x04\0\0)\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\
+    \n\x02\x04\0\x12\x04\x02\0\t\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x10\
+    \n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x12\n\x0c\n\x05\x04\0\x02\0\x05\
+    \x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\r\n\x0c\n\
+    \x05\x04\0\x02\0\x03\x12\x03\x03\x10\x11\n\x0b\n\x04\x04\0\x02\x01\x12\
+    \x03\x04\x04\x1c\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\x0c\n\
+    \x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\x17\n\x0c\n\x05\x04\0\x02\x01\x03\
+    \x12\x03\x04\x1a\x1b\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\x14\n\x0c\
+    \n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\
+    \x12\x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x12\x13\n\
+    \x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x04\x18\n\x0c\n\x05\x04\0\x02\x03\
+    \x06\x12\x03\x06\x04\x10\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x06\x11\
+    \x13\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x06\x16\x17\n\x0b\n\x04\x04\0\
+    \x02\x04\x12\x03\x07\x04\x1a\n\x0c\n\x05\x04\0\x02\x04\x05\x12\x03\x07\
+    \x04\t\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\n\x15\n\x0c\n\x05\x04\0\
+    \x02\x04\x03\x12\x03\x07\x18\x19\n\x0b\n\x04\x04\0\x02\x05\x12\x03\x08\
+    \x04\x1a\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\x08\x04\n\n\x0c\n\x05\x04\
+    \0\x02\x05\x01\x12\x03\x08\x0b\x15\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\
+    \x08\x18\x19\n\n\n\x02\x04\x01\x12\x04\n\0\x0c\x01\n\n\n\x03\x04\x01\x01\
+    \x12\x03\n\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x0b\x04\x20\n\x0c\n\
+    \x05\x04\x01\x02\0\x04\x12\x03\x0b\x04\x0c\n\x0c\n\x05\x04\x01\x02\0\x06\
+    \x12\x03\x0b\r\x15\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0b\x16\x1b\n\
+    \x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0b\x1e\x1f\n\n\n\x02\x04\x02\x12\
+    \x04\r\0\x10\x01\n\n\n\x03\x04\x02\x01\x12\x03\r\x08\x1d\n\x0b\n\x04\x04\
+    \x02\x02\0\x12\x03\x0e\x04\x12\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0e\
+    \x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0e\x0b\r\n\x0c\n\x05\x04\
+    \x02\x02\0\x03\x12\x03\x0e\x10\x11\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\
+    \x0f\x04\x18\n\x0c\n\x05\x04\x02\x02\x01\x06\x12\x03\x0f\x04\x10\n\x0c\n\
+    \x05\x04\x02\x02\x01\x01\x12\x03\x0f\x11\x13\n\x0c\n\x05\x04\x02\x02\x01\
+    \x03\x12\x03\x0f\x16\x17\n\n\n\x02\x04\x03\x12\x04\x11\0\x14\x01\n\n\n\
+    \x03\x04\x03\x01\x12\x03\x11\x08\x1c\n\x0b\n\x04\x04\x03\x02\0\x12\x03\
+    \x12\x04\x12\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x12\x04\n\n\x0c\n\x05\
+    \x04\x03\x02\0\x01\x12\x03\x12\x0b\r\n\x0c\n\x05\x04\x03\x02\0\x03\x12\
+    \x03\x12\x10\x11\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x13\x04\x18\n\x0c\n\
+    \x05\x04\x03\x02\x01\x06\x12\x03\x13\x04\x10\n\x0c\n\x05\x04\x03\x02\x01\
+    \x01\x12\x03\x13\x11\x13\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x13\x16\
+    \x17\n\n\n\x02\x04\x04\x12\x04\x15\0\x17\x01\n\n\n\x03\x04\x04\x01\x12\
+    \x03\x15\x08\x1c\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x16\x04\x1b\n\x0c\n\
+    \x05\x04\x04\x02\0\x05\x12\x03\x16\x04\n\n\x0c\n\x05\x04\x04\x02\0\x01\
+    \x12\x03\x16\x0b\x16\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x16\x19\x1a\n\
+    \n\n\x02\x04\x05\x12\x04\x18\0\x1a\x01\n\n\n\x03\x04\x05\x01\x12\x03\x18\
+    \x08\x12\n\x0b\n\x04\x04\x05\x02\0\x12\x03\x19\x04\x1b\n\x0c\n\x05\x04\
+    \x05\x02\0\x05\x12\x03\x19\x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03\
+    \x19\x0b\x16\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03\x19\x19\x1a\n\n\n\x02\
+    \x04\x06\x12\x04\x1b\0\x1f\x01\n\n\n\x03\x04\x06\x01\x12\x03\x1b\x08%\n\
+    \x0b\n\x04\x04\x06\x02\0\x12\x03\x1c\x04\x1b\n\x0c\n\x05\x04\x06\x02\0\
+    \x05\x12\x03\x1c\x04\n\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03\x1c\x0b\x16\
+    \n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03\x1c\x19\x1a\n\x0b\n\x04\x04\x06\
+    \x02\x01\x12\x03\x1d\x04\x1c\n\x0c\n\x05\x04\x06\x02\x01\x05\x12\x03\x1d\
+    \x04\n\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03\x1d\x0b\x17\n\x0c\n\x05\
+    \x04\x06\x02\x01\x03\x12\x03\x1d\x1a\x1b\n\x0b\n\x04\x04\x06\x08\0\x12\
+    \x03\x1e\x04*\n\x0c\n\x05\x04\x06\x08\0\x01\x12\x03\x1e\n\x15\n\x0b\n\
+    \x04\x04\x06\x02\x02\x12\x03\x1e\x18(\n\x0c\n\x05\x04\x06\x02\x02\x05\
+    \x12\x03\x1e\x18\x1e\n\x0c\n\x05\x04\x06\x02\x02\x01\x12\x03\x1e\x1f#\n\
+    \x0c\n\x05\x04\x06\x02\x02\x03\x12\x03\x1e&'\n\n\n\x02\x04\x07\x12\x04\
+    \x20\0$\x01\n\n\n\x03\x04\x07\x01\x12\x03\x20\x08$\n\x0b\n\x04\x04\x07\
+    \x02\0\x12\x03!\x04\x1b\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03!\x04\n\n\
+    \x0c\n\x05\x04\x07\x02\0\x01\x12\x03!\x0b\x16\n\x0c\n\x05\x04\x07\x02\0\
+    \x03\x12\x03!\x19\x1a\n\x0b\n\x04\x04\x07\x02\x01\x12\x03\"\x04\x1c\n\
+    \x0c\n\x05\x04\x07\x02\x01\x05\x12\x03\"\x04\n\n\x0c\n\x05\x04\x07\x02\
+    \x01\x01\x12\x03\"\x0b\x17\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x03\"\x1a\
+    \x1b\n\x0b\n\x04\x04\x07\x08\0\x12\x03#\x04*\n\x0c\n\x05\x04\x07\x08\0\
+    \x01\x12\x03#\n\x15\n\x0b\n\x04\x04\x07\x02\x02\x12\x03#\x18(\n\x0c\n\
+    \x05\x04\x07\x02\x02\x05\x12\x03#\x18\x1e\n\x0c\n\x05\x04\x07\x02\x02\
+    \x01\x12\x03#\x1f#\n\x0c\n\x05\x04\x07\x02\x02\x03\x12\x03#&'\n\n\n\x02\
+    \x05\0\x12\x04%\0)\x01\n\n\n\x03\x05\0\x01\x12\x03%\x05\x11\n\x0b\n\x04\
+    \x05\0\x02\0\x12\x03&\x04\x10\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03&\x04\
+    \x0b\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03&\x0e\x0f\n\x0b\n\x04\x05\0\x02\
+    \x01\x12\x03'\x04\r\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03'\x04\x08\n\x0c\
+    \n\x05\x05\0\x02\x01\x02\x12\x03'\x0b\x0c\n\x0b\n\x04\x05\0\x02\x02\x12\
+    \x03(\x04\x0c\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03(\x04\x07\n\x0c\n\x05\
+    \x05\0\x02\x02\x02\x12\x03(\n\x0bb\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -31,6 +31,8 @@ pub struct Trash {
     pub modified_time: i64,
     pub create_time: i64,
     pub ty: TrashType,
+    pub deleted_time: i64,
+    pub deleted_by: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -143,6 +145,47 @@ impl Trash {
     pub fn set_ty(&mut self, v: TrashType) {
         self.ty = v;
     }
+
+    // int64 deleted_time = 6;
+
+
+    pub fn get_deleted_time(&self) -> i64 {
+        self.deleted_time
+    }
+    pub fn clear_deleted_time(&mut self) {
+        self.deleted_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deleted_time(&mut self, v: i64) {
+        self.deleted_time = v;
+    }
+
+    // string deleted_by = 7;
+
+
+    pub fn get_deleted_by(&self) -> &str {
+        &self.deleted_by
+    }
+    pub fn clear_deleted_by(&mut self) {
+        self.deleted_by.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deleted_by(&mut self, v: ::std::string::String) {
+        self.deleted_by = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_deleted_by(&mut self) -> &mut ::std::string::String {
+        &mut self.deleted_by
+    }
+
+    // Take field
+    pub fn take_deleted_by(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.deleted_by, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for Trash {
@@ -177,6 +220,16 @@ impl ::protobuf::Message for Trash {
                 5 => {
                     ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 5, &mut self.unknown_fields)?
                 },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.deleted_time = tmp;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.deleted_by)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -204,6 +257,12 @@ impl ::protobuf::Message for Trash {
         if self.ty != TrashType::Unknown {
             my_size += ::protobuf::rt::enum_size(5, self.ty);
         }
+        if self.deleted_time != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.deleted_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.deleted_by.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.deleted_by);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -225,6 +284,12 @@ impl ::protobuf::Message for Trash {
         if self.ty != TrashType::Unknown {
             os.write_enum(5, ::protobuf::ProtobufEnum::value(&self.ty))?;
         }
+        if self.deleted_time != 0 {
+            os.write_int64(6, self.deleted_time)?;
+        }
+        if !self.deleted_by.is_empty() {
+            os.write_string(7, &self.deleted_by)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -288,6 +353,16 @@ impl ::protobuf::Message for Trash {
                 |m: &Trash| { &m.ty },
                 |m: &mut Trash| { &mut m.ty },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "deleted_time",
+                |m: &Trash| { &m.deleted_time },
+                |m: &mut Trash| { &mut m.deleted_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "deleted_by",
+                |m: &Trash| { &m.deleted_by },
+                |m: &mut Trash| { &mut m.deleted_by },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<Trash>(
                 "Trash",
                 fields,
@@ -309,6 +384,8 @@ impl ::protobuf::Clear for Trash {
         self.modified_time = 0;
         self.create_time = 0;
         self.ty = TrashType::Unknown;
+        self.deleted_time = 0;
+        self.deleted_by.clear();
         self.unknown_fields.clear();
     }
 }
@@ -491,6 +568,158 @@ impl ::protobuf::reflect::ProtobufValue for RepeatedTrash {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct TrashRetentionPolicy {
+    // message fields
+    pub retention_days: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TrashRetentionPolicy {
+    fn default() -> &'a TrashRetentionPolicy {
+        <TrashRetentionPolicy as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TrashRetentionPolicy {
+    pub fn new() -> TrashRetentionPolicy {
+        ::std::default::Default::default()
+    }
+
+    // int64 retention_days = 1;
+
+
+    pub fn get_retention_days(&self) -> i64 {
+        self.retention_days
+    }
+    pub fn clear_retention_days(&mut self) {
+        self.retention_days = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_retention_days(&mut self, v: i64) {
+        self.retention_days = v;
+    }
+}
+
+impl ::protobuf::Message for TrashRetentionPolicy {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.retention_days = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.retention_days != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.retention_days, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.retention_days != 0 {
+            os.write_int64(1, self.retention_days)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TrashRetentionPolicy {
+        TrashRetentionPolicy::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "retention_days",
+                |m: &TrashRetentionPolicy| { &m.retention_days },
+                |m: &mut TrashRetentionPolicy| { &mut m.retention_days },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TrashRetentionPolicy>(
+                "TrashRetentionPolicy",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static TrashRetentionPolicy {
+        static instance: ::protobuf::rt::LazyV2<TrashRetentionPolicy> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TrashRetentionPolicy::new)
+    }
+}
+
+impl ::protobuf::Clear for TrashRetentionPolicy {
+    fn clear(&mut self) {
+        self.retention_days = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TrashRetentionPolicy {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TrashRetentionPolicy {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct RepeatedTrashId {
     // message fields
@@ -887,6 +1116,7 @@ pub enum TrashType {
     Unknown = 0,
     View = 1,
     App = 2,
+    Workspace = 3,
 }
 
 impl ::protobuf::ProtobufEnum for TrashType {
@@ -899,6 +1129,7 @@ impl ::protobuf::ProtobufEnum for TrashType {
             0 => ::std::option::Option::Some(TrashType::Unknown),
             1 => ::std::option::Option::Some(TrashType::View),
             2 => ::std::option::Option::Some(TrashType::App),
+            3 => ::std::option::Option::Some(TrashType::Workspace),
             _ => ::std::option::Option::None
         }
     }
@@ -908,6 +1139,7 @@ impl ::protobuf::ProtobufEnum for TrashType {
             TrashType::Unknown,
             TrashType::View,
             TrashType::App,
+            TrashType::Workspace,
         ];
         values
     }
@@ -936,58 +1168,72 @@ impl ::protobuf::reflect::ProtobufValue for TrashType {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0btrash.proto\"\x8d\x01\n\x05Trash\x12\x0e\n\x02id\x18\x01\x20\x01(\
+    \n\x0btrash.proto\"\xcf\x01\n\x05Trash\x12\x0e\n\x02id\x18\x01\x20\x01(\
     \tR\x02id\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12#\n\rmodified\
     _time\x18\x03\x20\x01(\x03R\x0cmodifiedTime\x12\x1f\n\x0bcreate_time\x18\
     \x04\x20\x01(\x03R\ncreateTime\x12\x1a\n\x02ty\x18\x05\x20\x01(\x0e2\n.T\
-    rashTypeR\x02ty\"-\n\rRepeatedTrash\x12\x1c\n\x05items\x18\x01\x20\x03(\
-    \x0b2\x06.TrashR\x05items\"P\n\x0fRepeatedTrashId\x12\x1e\n\x05items\x18\
-    \x01\x20\x03(\x0b2\x08.TrashIdR\x05items\x12\x1d\n\ndelete_all\x18\x02\
-    \x20\x01(\x08R\tdeleteAll\"5\n\x07TrashId\x12\x0e\n\x02id\x18\x01\x20\
-    \x01(\tR\x02id\x12\x1a\n\x02ty\x18\x02\x20\x01(\x0e2\n.TrashTypeR\x02ty*\
-    +\n\tTrashType\x12\x0b\n\x07Unknown\x10\0\x12\x08\n\x04View\x10\x01\x12\
-    \x07\n\x03App\x10\x02J\xc7\x06\n\x06\x12\x04\0\0\x18\x01\n\x08\n\x01\x0c\
-    \x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x08\x01\n\n\n\x03\x04\0\
-    \x01\x12\x03\x02\x08\r\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x12\n\x0c\
-    \n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\
-    \x03\x03\x0b\r\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x10\x11\n\x0b\n\
-    \x04\x04\0\x02\x01\x12\x03\x04\x04\x14\n\x0c\n\x05\x04\0\x02\x01\x05\x12\
-    \x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\x0f\n\x0c\n\
-    \x05\x04\0\x02\x01\x03\x12\x03\x04\x12\x13\n\x0b\n\x04\x04\0\x02\x02\x12\
-    \x03\x05\x04\x1c\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\t\n\x0c\n\
-    \x05\x04\0\x02\x02\x01\x12\x03\x05\n\x17\n\x0c\n\x05\x04\0\x02\x02\x03\
-    \x12\x03\x05\x1a\x1b\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x04\x1a\n\x0c\
-    \n\x05\x04\0\x02\x03\x05\x12\x03\x06\x04\t\n\x0c\n\x05\x04\0\x02\x03\x01\
-    \x12\x03\x06\n\x15\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x06\x18\x19\n\
-    \x0b\n\x04\x04\0\x02\x04\x12\x03\x07\x04\x15\n\x0c\n\x05\x04\0\x02\x04\
-    \x06\x12\x03\x07\x04\r\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\x0e\x10\
-    \n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x07\x13\x14\n\n\n\x02\x04\x01\x12\
-    \x04\t\0\x0b\x01\n\n\n\x03\x04\x01\x01\x12\x03\t\x08\x15\n\x0b\n\x04\x04\
-    \x01\x02\0\x12\x03\n\x04\x1d\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03\n\x04\
-    \x0c\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03\n\r\x12\n\x0c\n\x05\x04\x01\
-    \x02\0\x01\x12\x03\n\x13\x18\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\n\x1b\
-    \x1c\n\n\n\x02\x04\x02\x12\x04\x0c\0\x0f\x01\n\n\n\x03\x04\x02\x01\x12\
-    \x03\x0c\x08\x17\n\x0b\n\x04\x04\x02\x02\0\x12\x03\r\x04\x1f\n\x0c\n\x05\
-    \x04\x02\x02\0\x04\x12\x03\r\x04\x0c\n\x0c\n\x05\x04\x02\x02\0\x06\x12\
-    \x03\r\r\x14\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\r\x15\x1a\n\x0c\n\x05\
-    \x04\x02\x02\0\x03\x12\x03\r\x1d\x1e\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\
-    \x0e\x04\x18\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x0e\x04\x08\n\x0c\n\
-    \x05\x04\x02\x02\x01\x01\x12\x03\x0e\t\x13\n\x0c\n\x05\x04\x02\x02\x01\
-    \x03\x12\x03\x0e\x16\x17\n\n\n\x02\x04\x03\x12\x04\x10\0\x13\x01\n\n\n\
-    \x03\x04\x03\x01\x12\x03\x10\x08\x0f\n\x0b\n\x04\x04\x03\x02\0\x12\x03\
-    \x11\x04\x12\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x11\x04\n\n\x0c\n\x05\
-    \x04\x03\x02\0\x01\x12\x03\x11\x0b\r\n\x0c\n\x05\x04\x03\x02\0\x03\x12\
-    \x03\x11\x10\x11\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x12\x04\x15\n\x0c\n\
-    \x05\x04\x03\x02\x01\x06\x12\x03\x12\x04\r\n\x0c\n\x05\x04\x03\x02\x01\
-    \x01\x12\x03\x12\x0e\x10\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x12\x13\
-    \x14\n\n\n\x02\x05\0\x12\x04\x14\0\x18\x01\n\n\n\x03\x05\0\x01\x12\x03\
-    \x14\x05\x0e\n\x0b\n\x04\x05\0\x02\0\x12\x03\x15\x04\x10\n\x0c\n\x05\x05\
-    \0\x02\0\x01\x12\x03\x15\x04\x0b\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x15\
-    \x0e\x0f\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x16\x04\r\n\x0c\n\x05\x05\0\
-    \x02\x01\x01\x12\x03\x16\x04\x08\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\
-    \x16\x0b\x0c\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x17\x04\x0c\n\x0c\n\x05\
-    \x05\0\x02\x02\x01\x12\x03\x17\x04\x07\n\x0c\n\x05\x05\0\x02\x02\x02\x12\
-    \x03\x17\n\x0bb\x06proto3\
+    rashTypeR\x02ty\x12!\n\x0cdeleted_time\x18\x06\x20\x01(\x03R\x0bdeletedT\
+    ime\x12\x1d\n\ndeleted_by\x18\x07\x20\x01(\tR\tdeletedBy\"-\n\rRepeatedT\
+    rash\x12\x1c\n\x05items\x18\x01\x20\x03(\x0b2\x06.TrashR\x05items\"=\n\
+    \x14TrashRetentionPolicy\x12%\n\x0eretention_days\x18\x01\x20\x01(\x03R\
+    \rretentionDays\"P\n\x0fRepeatedTrashId\x12\x1e\n\x05items\x18\x01\x20\
+    \x03(\x0b2\x08.TrashIdR\x05items\x12\x1d\n\ndelete_all\x18\x02\x20\x01(\
+    \x08R\tdeleteAll\"5\n\x07TrashId\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02\
+    id\x12\x1a\n\x02ty\x18\x02\x20\x01(\x0e2\n.TrashTypeR\x02ty*:\n\tTrashTy\
+    pe\x12\x0b\n\x07Unknown\x10\0\x12\x08\n\x04View\x10\x01\x12\x07\n\x03App\
+    \x10\x02\x12\r\n\tWorkspace\x10\x03J\xad\x08\n\x06\x12\x04\0\0\x1e\x01\n\
+    \x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\n\x01\n\n\n\
+    \x03\x04\0\x01\x12\x03\x02\x08\r\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\
+    \x12\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\
+    \0\x01\x12\x03\x03\x0b\r\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x10\x11\
+    \n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x14\n\x0c\n\x05\x04\0\x02\x01\
+    \x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\x0f\
+    \n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x12\x13\n\x0b\n\x04\x04\0\x02\
+    \x02\x12\x03\x05\x04\x1c\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\t\
+    \n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\n\x17\n\x0c\n\x05\x04\0\x02\
+    \x02\x03\x12\x03\x05\x1a\x1b\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x04\
+    \x1a\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x04\t\n\x0c\n\x05\x04\0\
+    \x02\x03\x01\x12\x03\x06\n\x15\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x06\
+    \x18\x19\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x07\x04\x15\n\x0c\n\x05\x04\0\
+    \x02\x04\x06\x12\x03\x07\x04\r\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\
+    \x0e\x10\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x07\x13\x14\n\x0b\n\x04\
+    \x04\0\x02\x05\x12\x03\x08\x04\x1b\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\
+    \x08\x04\t\n\x0c\n\x05\x04\0\x02\x05\x01\x12\x03\x08\n\x16\n\x0c\n\x05\
+    \x04\0\x02\x05\x03\x12\x03\x08\x19\x1a\n\x0b\n\x04\x04\0\x02\x06\x12\x03\
+    \t\x04\x1a\n\x0c\n\x05\x04\0\x02\x06\x05\x12\x03\t\x04\n\n\x0c\n\x05\x04\
+    \0\x02\x06\x01\x12\x03\t\x0b\x15\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03\t\
+    \x18\x19\n\n\n\x02\x04\x01\x12\x04\x0b\0\r\x01\n\n\n\x03\x04\x01\x01\x12\
+    \x03\x0b\x08\x15\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x0c\x04\x1d\n\x0c\n\
+    \x05\x04\x01\x02\0\x04\x12\x03\x0c\x04\x0c\n\x0c\n\x05\x04\x01\x02\0\x06\
+    \x12\x03\x0c\r\x12\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0c\x13\x18\n\
+    \x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0c\x1b\x1c\n\n\n\x02\x04\x02\x12\
+    \x04\x0e\0\x10\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0e\x08\x1c\n\x0b\n\x04\
+    \x04\x02\x02\0\x12\x03\x0f\x04\x1d\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\
+    \x0f\x04\t\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0f\n\x18\n\x0c\n\x05\
+    \x04\x02\x02\0\x03\x12\x03\x0f\x1b\x1c\n\n\n\x02\x04\x03\x12\x04\x11\0\
+    \x14\x01\n\n\n\x03\x04\x03\x01\x12\x03\x11\x08\x17\n\x0b\n\x04\x04\x03\
+    \x02\0\x12\x03\x12\x04\x1f\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x12\x04\
+    \x0c\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03\x12\r\x14\n\x0c\n\x05\x04\x03\
+    \x02\0\x01\x12\x03\x12\x15\x1a\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x12\
+    \x1d\x1e\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x13\x04\x18\n\x0c\n\x05\x04\
+    \x03\x02\x01\x05\x12\x03\x13\x04\x08\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\
+    \x03\x13\t\x13\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x13\x16\x17\n\n\n\
+    \x02\x04\x04\x12\x04\x15\0\x18\x01\n\n\n\x03\x04\x04\x01\x12\x03\x15\x08\
+    \x0f\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x16\x04\x12\n\x0c\n\x05\x04\x04\
+    \x02\0\x05\x12\x03\x16\x04\n\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x16\
+    \x0b\r\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x16\x10\x11\n\x0b\n\x04\x04\
+    \x04\x02\x01\x12\x03\x17\x04\x15\n\x0c\n\x05\x04\x04\x02\x01\x06\x12\x03\
+    \x17\x04\r\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x17\x0e\x10\n\x0c\n\
+    \x05\x04\x04\x02\x01\x03\x12\x03\x17\x13\x14\n\n\n\x02\x05\0\x12\x04\x19\
+    \0\x1e\x01\n\n\n\x03\x05\0\x01\x12\x03\x19\x05\x0e\n\x0b\n\x04\x05\0\x02\
+    \0\x12\x03\x1a\x04\x10\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x1a\x04\x0b\n\
+    \x0c\n\x05\x05\0\x02\0\x02\x12\x03\x1a\x0e\x0f\n\x0b\n\x04\x05\0\x02\x01\
+    \x12\x03\x1b\x04\r\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x1b\x04\x08\n\
+    \x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x1b\x0b\x0c\n\x0b\n\x04\x05\0\x02\
+    \x02\x12\x03\x1c\x04\x0c\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x1c\x04\
+    \x07\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x1c\n\x0b\n\x0b\n\x04\x05\0\
+    \x02\x03\x12\x03\x1d\x04\x12\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x1d\
+    \x04\r\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x1d\x10\x11b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -35,6 +35,8 @@ pub struct View {
     pub belongings: ::protobuf::SingularPtrField<RepeatedView>,
     pub modified_time: i64,
     pub create_time: i64,
+    pub tags: ::protobuf::RepeatedField<::std::string::String>,
+    pub properties: ::protobuf::RepeatedField<ViewProperty>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -247,6 +249,56 @@ impl View {
     pub fn set_create_time(&mut self, v: i64) {
         self.create_time = v;
     }
+
+    // repeated string tags = 10;
+
+
+    pub fn get_tags(&self) -> &[::std::string::String] {
+        &self.tags
+    }
+    pub fn clear_tags(&mut self) {
+        self.tags.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tags(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tags = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tags(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tags
+    }
+
+    // Take field
+    pub fn take_tags(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tags, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .ViewProperty properties = 11;
+
+
+    pub fn get_properties(&self) -> &[ViewProperty] {
+        &self.properties
+    }
+    pub fn clear_properties(&mut self) {
+        self.properties.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_properties(&mut self, v: ::protobuf::RepeatedField<ViewProperty>) {
+        self.properties = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_properties(&mut self) -> &mut ::protobuf::RepeatedField<ViewProperty> {
+        &mut self.properties
+    }
+
+    // Take field
+    pub fn take_properties(&mut self) -> ::protobuf::RepeatedField<ViewProperty> {
+        ::std::mem::replace(&mut self.properties, ::protobuf::RepeatedField::new())
+    }
 }
 
 impl ::protobuf::Message for View {
@@ -256,6 +308,11 @@ impl ::protobuf::Message for View {
                 return false;
             }
         };
+        for v in &self.properties {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -302,6 +359,12 @@ impl ::protobuf::Message for View {
                     let tmp = is.read_int64()?;
                     self.create_time = tmp;
                 },
+                10 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tags)?;
+                },
+                11 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.properties)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -342,6 +405,13 @@ impl ::protobuf::Message for View {
         if self.create_time != 0 {
             my_size += ::protobuf::rt::value_size(9, self.create_time, ::protobuf::wire_format::WireTypeVarint);
         }
+        for value in &self.tags {
+            my_size += ::protobuf::rt::string_size(10, &value);
+        };
+        for value in &self.properties {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -377,6 +447,14 @@ impl ::protobuf::Message for View {
         if self.create_time != 0 {
             os.write_int64(9, self.create_time)?;
         }
+        for v in &self.tags {
+            os.write_string(10, &v)?;
+        };
+        for v in &self.properties {
+            os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -460,6 +538,16 @@ impl ::protobuf::Message for View {
                 |m: &View| { &m.create_time },
                 |m: &mut View| { &mut m.create_time },
             ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tags",
+                |m: &View| { &m.tags },
+                |m: &mut View| { &mut m.tags },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ViewProperty>>(
+                "properties",
+                |m: &View| { &m.properties },
+                |m: &mut View| { &mut m.properties },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<View>(
                 "View",
                 fields,
@@ -485,6 +573,8 @@ impl ::protobuf::Clear for View {
         self.belongings.clear();
         self.modified_time = 0;
         self.create_time = 0;
+        self.tags.clear();
+        self.properties.clear();
         self.unknown_fields.clear();
     }
 }
@@ -2704,204 +2794,2426 @@ impl ::protobuf::reflect::ProtobufValue for UpdateViewParams {
     }
 }
 
-#[derive(Clone,PartialEq,Eq,Debug,Hash)]
-pub enum ViewType {
-    Blank = 0,
-    Doc = 1,
+#[derive(PartialEq,Clone,Default)]
+pub struct ViewProperty {
+    // message fields
+    pub key: ::std::string::String,
+    pub ty: ViewPropertyType,
+    pub value: ::std::string::String,
+    pub options: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
 }
 
-impl ::protobuf::ProtobufEnum for ViewType {
-    fn value(&self) -> i32 {
-        *self as i32
+impl<'a> ::std::default::Default for &'a ViewProperty {
+    fn default() -> &'a ViewProperty {
+        <ViewProperty as ::protobuf::Message>::default_instance()
     }
+}
 
-    fn from_i32(value: i32) -> ::std::option::Option<ViewType> {
-        match value {
-            0 => ::std::option::Option::Some(ViewType::Blank),
-            1 => ::std::option::Option::Some(ViewType::Doc),
-            _ => ::std::option::Option::None
+impl ViewProperty {
+    pub fn new() -> ViewProperty {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // .ViewPropertyType ty = 2;
+
+
+    pub fn get_ty(&self) -> ViewPropertyType {
+        self.ty
+    }
+    pub fn clear_ty(&mut self) {
+        self.ty = ViewPropertyType::Text;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ty(&mut self, v: ViewPropertyType) {
+        self.ty = v;
+    }
+
+    // string value = 3;
+
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.value, ::std::string::String::new())
+    }
+
+    // repeated string options = 4;
+
+
+    pub fn get_options(&self) -> &[::std::string::String] {
+        &self.options
+    }
+    pub fn clear_options(&mut self) {
+        self.options.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_options(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.options = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_options(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.options
+    }
+
+    // Take field
+    pub fn take_options(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.options, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ViewProperty {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.value)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.options)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
         }
+        ::std::result::Result::Ok(())
     }
 
-    fn values() -> &'static [Self] {
-        static values: &'static [ViewType] = &[
-            ViewType::Blank,
-            ViewType::Doc,
-        ];
-        values
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if self.ty != ViewPropertyType::Text {
+            my_size += ::protobuf::rt::enum_size(2, self.ty);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.value);
+        }
+        for value in &self.options {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
-        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if self.ty != ViewPropertyType::Text {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.ty))?;
+        }
+        if !self.value.is_empty() {
+            os.write_string(3, &self.value)?;
+        }
+        for v in &self.options {
+            os.write_string(4, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ViewProperty {
+        ViewProperty::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
-            ::protobuf::reflect::EnumDescriptor::new_pb_name::<ViewType>("ViewType", file_descriptor_proto())
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key",
+                |m: &ViewProperty| { &m.key },
+                |m: &mut ViewProperty| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ViewPropertyType>>(
+                "ty",
+                |m: &ViewProperty| { &m.ty },
+                |m: &mut ViewProperty| { &mut m.ty },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "value",
+                |m: &ViewProperty| { &m.value },
+                |m: &mut ViewProperty| { &mut m.value },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "options",
+                |m: &ViewProperty| { &m.options },
+                |m: &mut ViewProperty| { &mut m.options },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ViewProperty>(
+                "ViewProperty",
+                fields,
+                file_descriptor_proto()
+            )
         })
     }
+
+    fn default_instance() -> &'static ViewProperty {
+        static instance: ::protobuf::rt::LazyV2<ViewProperty> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ViewProperty::new)
+    }
 }
 
-impl ::std::marker::Copy for ViewType {
+impl ::protobuf::Clear for ViewProperty {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.ty = ViewPropertyType::Text;
+        self.value.clear();
+        self.options.clear();
+        self.unknown_fields.clear();
+    }
 }
 
-impl ::std::default::Default for ViewType {
-    fn default() -> Self {
-        ViewType::Blank
+impl ::std::fmt::Debug for ViewProperty {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ViewType {
+impl ::protobuf::reflect::ProtobufValue for ViewProperty {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateViewTagsRequest {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub tags: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateViewTagsRequest {
+    fn default() -> &'a UpdateViewTagsRequest {
+        <UpdateViewTagsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdateViewTagsRequest {
+    pub fn new() -> UpdateViewTagsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // repeated string tags = 2;
+
+
+    pub fn get_tags(&self) -> &[::std::string::String] {
+        &self.tags
+    }
+    pub fn clear_tags(&mut self) {
+        self.tags.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tags(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tags = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tags(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tags
+    }
+
+    // Take field
+    pub fn take_tags(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tags, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for UpdateViewTagsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tags)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        for value in &self.tags {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        for v in &self.tags {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateViewTagsRequest {
+        UpdateViewTagsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &UpdateViewTagsRequest| { &m.view_id },
+                |m: &mut UpdateViewTagsRequest| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tags",
+                |m: &UpdateViewTagsRequest| { &m.tags },
+                |m: &mut UpdateViewTagsRequest| { &mut m.tags },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateViewTagsRequest>(
+                "UpdateViewTagsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateViewTagsRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdateViewTagsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateViewTagsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateViewTagsRequest {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.tags.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateViewTagsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateViewTagsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateViewTagsParams {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub tags: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateViewTagsParams {
+    fn default() -> &'a UpdateViewTagsParams {
+        <UpdateViewTagsParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdateViewTagsParams {
+    pub fn new() -> UpdateViewTagsParams {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // repeated string tags = 2;
+
+
+    pub fn get_tags(&self) -> &[::std::string::String] {
+        &self.tags
+    }
+    pub fn clear_tags(&mut self) {
+        self.tags.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tags(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tags = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tags(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tags
+    }
+
+    // Take field
+    pub fn take_tags(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tags, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for UpdateViewTagsParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tags)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        for value in &self.tags {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        for v in &self.tags {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateViewTagsParams {
+        UpdateViewTagsParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &UpdateViewTagsParams| { &m.view_id },
+                |m: &mut UpdateViewTagsParams| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tags",
+                |m: &UpdateViewTagsParams| { &m.tags },
+                |m: &mut UpdateViewTagsParams| { &mut m.tags },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateViewTagsParams>(
+                "UpdateViewTagsParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateViewTagsParams {
+        static instance: ::protobuf::rt::LazyV2<UpdateViewTagsParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateViewTagsParams::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateViewTagsParams {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.tags.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateViewTagsParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateViewTagsParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateViewPropertyRequest {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub key: ::std::string::String,
+    pub ty: ViewPropertyType,
+    pub options: ::protobuf::RepeatedField<::std::string::String>,
+    // message oneof groups
+    pub one_of_value: ::std::option::Option<UpdateViewPropertyRequest_oneof_one_of_value>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateViewPropertyRequest {
+    fn default() -> &'a UpdateViewPropertyRequest {
+        <UpdateViewPropertyRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateViewPropertyRequest_oneof_one_of_value {
+    value(::std::string::String),
+}
+
+impl UpdateViewPropertyRequest {
+    pub fn new() -> UpdateViewPropertyRequest {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string key = 2;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // .ViewPropertyType ty = 3;
+
+
+    pub fn get_ty(&self) -> ViewPropertyType {
+        self.ty
+    }
+    pub fn clear_ty(&mut self) {
+        self.ty = ViewPropertyType::Text;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ty(&mut self, v: ViewPropertyType) {
+        self.ty = v;
+    }
+
+    // string value = 4;
+
+
+    pub fn get_value(&self) -> &str {
+        match self.one_of_value {
+            ::std::option::Option::Some(UpdateViewPropertyRequest_oneof_one_of_value::value(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_value(&mut self) {
+        self.one_of_value = ::std::option::Option::None;
+    }
+
+    pub fn has_value(&self) -> bool {
+        match self.one_of_value {
+            ::std::option::Option::Some(UpdateViewPropertyRequest_oneof_one_of_value::value(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.one_of_value = ::std::option::Option::Some(UpdateViewPropertyRequest_oneof_one_of_value::value(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateViewPropertyRequest_oneof_one_of_value::value(_)) = self.one_of_value {
+        } else {
+            self.one_of_value = ::std::option::Option::Some(UpdateViewPropertyRequest_oneof_one_of_value::value(::std::string::String::new()));
+        }
+        match self.one_of_value {
+            ::std::option::Option::Some(UpdateViewPropertyRequest_oneof_one_of_value::value(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        if self.has_value() {
+            match self.one_of_value.take() {
+                ::std::option::Option::Some(UpdateViewPropertyRequest_oneof_one_of_value::value(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // repeated string options = 5;
+
+
+    pub fn get_options(&self) -> &[::std::string::String] {
+        &self.options
+    }
+    pub fn clear_options(&mut self) {
+        self.options.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_options(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.options = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_options(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.options
+    }
+
+    // Take field
+    pub fn take_options(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.options, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for UpdateViewPropertyRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_value = ::std::option::Option::Some(UpdateViewPropertyRequest_oneof_one_of_value::value(is.read_string()?));
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.options)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.key);
+        }
+        if self.ty != ViewPropertyType::Text {
+            my_size += ::protobuf::rt::enum_size(3, self.ty);
+        }
+        for value in &self.options {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        if let ::std::option::Option::Some(ref v) = self.one_of_value {
+            match v {
+                &UpdateViewPropertyRequest_oneof_one_of_value::value(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if !self.key.is_empty() {
+            os.write_string(2, &self.key)?;
+        }
+        if self.ty != ViewPropertyType::Text {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.ty))?;
+        }
+        for v in &self.options {
+            os.write_string(5, &v)?;
+        };
+        if let ::std::option::Option::Some(ref v) = self.one_of_value {
+            match v {
+                &UpdateViewPropertyRequest_oneof_one_of_value::value(ref v) => {
+                    os.write_string(4, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateViewPropertyRequest {
+        UpdateViewPropertyRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &UpdateViewPropertyRequest| { &m.view_id },
+                |m: &mut UpdateViewPropertyRequest| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key",
+                |m: &UpdateViewPropertyRequest| { &m.key },
+                |m: &mut UpdateViewPropertyRequest| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ViewPropertyType>>(
+                "ty",
+                |m: &UpdateViewPropertyRequest| { &m.ty },
+                |m: &mut UpdateViewPropertyRequest| { &mut m.ty },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "value",
+                UpdateViewPropertyRequest::has_value,
+                UpdateViewPropertyRequest::get_value,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "options",
+                |m: &UpdateViewPropertyRequest| { &m.options },
+                |m: &mut UpdateViewPropertyRequest| { &mut m.options },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateViewPropertyRequest>(
+                "UpdateViewPropertyRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateViewPropertyRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdateViewPropertyRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateViewPropertyRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateViewPropertyRequest {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.key.clear();
+        self.ty = ViewPropertyType::Text;
+        self.one_of_value = ::std::option::Option::None;
+        self.options.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateViewPropertyRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateViewPropertyRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateViewPropertyParams {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub key: ::std::string::String,
+    // message oneof groups
+    pub one_of_property: ::std::option::Option<UpdateViewPropertyParams_oneof_one_of_property>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateViewPropertyParams {
+    fn default() -> &'a UpdateViewPropertyParams {
+        <UpdateViewPropertyParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateViewPropertyParams_oneof_one_of_property {
+    property(ViewProperty),
+}
+
+impl UpdateViewPropertyParams {
+    pub fn new() -> UpdateViewPropertyParams {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string key = 2;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // .ViewProperty property = 3;
+
+
+    pub fn get_property(&self) -> &ViewProperty {
+        match self.one_of_property {
+            ::std::option::Option::Some(UpdateViewPropertyParams_oneof_one_of_property::property(ref v)) => v,
+            _ => <ViewProperty as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_property(&mut self) {
+        self.one_of_property = ::std::option::Option::None;
+    }
+
+    pub fn has_property(&self) -> bool {
+        match self.one_of_property {
+            ::std::option::Option::Some(UpdateViewPropertyParams_oneof_one_of_property::property(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_property(&mut self, v: ViewProperty) {
+        self.one_of_property = ::std::option::Option::Some(UpdateViewPropertyParams_oneof_one_of_property::property(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_property(&mut self) -> &mut ViewProperty {
+        if let ::std::option::Option::Some(UpdateViewPropertyParams_oneof_one_of_property::property(_)) = self.one_of_property {
+        } else {
+            self.one_of_property = ::std::option::Option::Some(UpdateViewPropertyParams_oneof_one_of_property::property(ViewProperty::new()));
+        }
+        match self.one_of_property {
+            ::std::option::Option::Some(UpdateViewPropertyParams_oneof_one_of_property::property(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_property(&mut self) -> ViewProperty {
+        if self.has_property() {
+            match self.one_of_property.take() {
+                ::std::option::Option::Some(UpdateViewPropertyParams_oneof_one_of_property::property(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ViewProperty::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for UpdateViewPropertyParams {
+    fn is_initialized(&self) -> bool {
+        if let Some(UpdateViewPropertyParams_oneof_one_of_property::property(ref v)) = self.one_of_property {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_property = ::std::option::Option::Some(UpdateViewPropertyParams_oneof_one_of_property::property(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.key);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_property {
+            match v {
+                &UpdateViewPropertyParams_oneof_one_of_property::property(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if !self.key.is_empty() {
+            os.write_string(2, &self.key)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_property {
+            match v {
+                &UpdateViewPropertyParams_oneof_one_of_property::property(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateViewPropertyParams {
+        UpdateViewPropertyParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &UpdateViewPropertyParams| { &m.view_id },
+                |m: &mut UpdateViewPropertyParams| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key",
+                |m: &UpdateViewPropertyParams| { &m.key },
+                |m: &mut UpdateViewPropertyParams| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ViewProperty>(
+                "property",
+                UpdateViewPropertyParams::has_property,
+                UpdateViewPropertyParams::get_property,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateViewPropertyParams>(
+                "UpdateViewPropertyParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateViewPropertyParams {
+        static instance: ::protobuf::rt::LazyV2<UpdateViewPropertyParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateViewPropertyParams::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateViewPropertyParams {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.key.clear();
+        self.one_of_property = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateViewPropertyParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateViewPropertyParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ViewFilterRequest {
+    // message oneof groups
+    pub one_of_tag: ::std::option::Option<ViewFilterRequest_oneof_one_of_tag>,
+    pub one_of_property_key: ::std::option::Option<ViewFilterRequest_oneof_one_of_property_key>,
+    pub one_of_property_value: ::std::option::Option<ViewFilterRequest_oneof_one_of_property_value>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ViewFilterRequest {
+    fn default() -> &'a ViewFilterRequest {
+        <ViewFilterRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ViewFilterRequest_oneof_one_of_tag {
+    tag(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ViewFilterRequest_oneof_one_of_property_key {
+    property_key(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ViewFilterRequest_oneof_one_of_property_value {
+    property_value(::std::string::String),
+}
+
+impl ViewFilterRequest {
+    pub fn new() -> ViewFilterRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tag = 1;
+
+
+    pub fn get_tag(&self) -> &str {
+        match self.one_of_tag {
+            ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_tag::tag(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_tag(&mut self) {
+        self.one_of_tag = ::std::option::Option::None;
+    }
+
+    pub fn has_tag(&self) -> bool {
+        match self.one_of_tag {
+            ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_tag::tag(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tag(&mut self, v: ::std::string::String) {
+        self.one_of_tag = ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_tag::tag(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tag(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_tag::tag(_)) = self.one_of_tag {
+        } else {
+            self.one_of_tag = ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_tag::tag(::std::string::String::new()));
+        }
+        match self.one_of_tag {
+            ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_tag::tag(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_tag(&mut self) -> ::std::string::String {
+        if self.has_tag() {
+            match self.one_of_tag.take() {
+                ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_tag::tag(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string property_key = 2;
+
+
+    pub fn get_property_key(&self) -> &str {
+        match self.one_of_property_key {
+            ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_property_key::property_key(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_property_key(&mut self) {
+        self.one_of_property_key = ::std::option::Option::None;
+    }
+
+    pub fn has_property_key(&self) -> bool {
+        match self.one_of_property_key {
+            ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_property_key::property_key(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_property_key(&mut self, v: ::std::string::String) {
+        self.one_of_property_key = ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_property_key::property_key(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_property_key(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_property_key::property_key(_)) = self.one_of_property_key {
+        } else {
+            self.one_of_property_key = ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_property_key::property_key(::std::string::String::new()));
+        }
+        match self.one_of_property_key {
+            ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_property_key::property_key(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_property_key(&mut self) -> ::std::string::String {
+        if self.has_property_key() {
+            match self.one_of_property_key.take() {
+                ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_property_key::property_key(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string property_value = 3;
+
+
+    pub fn get_property_value(&self) -> &str {
+        match self.one_of_property_value {
+            ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_property_value::property_value(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_property_value(&mut self) {
+        self.one_of_property_value = ::std::option::Option::None;
+    }
+
+    pub fn has_property_value(&self) -> bool {
+        match self.one_of_property_value {
+            ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_property_value::property_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_property_value(&mut self, v: ::std::string::String) {
+        self.one_of_property_value = ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_property_value::property_value(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_property_value(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_property_value::property_value(_)) = self.one_of_property_value {
+        } else {
+            self.one_of_property_value = ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_property_value::property_value(::std::string::String::new()));
+        }
+        match self.one_of_property_value {
+            ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_property_value::property_value(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_property_value(&mut self) -> ::std::string::String {
+        if self.has_property_value() {
+            match self.one_of_property_value.take() {
+                ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_property_value::property_value(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for ViewFilterRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_tag = ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_tag::tag(is.read_string()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_property_key = ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_property_key::property_key(is.read_string()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_property_value = ::std::option::Option::Some(ViewFilterRequest_oneof_one_of_property_value::property_value(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.one_of_tag {
+            match v {
+                &ViewFilterRequest_oneof_one_of_tag::tag(ref v) => {
+                    my_size += ::protobuf::rt::string_size(1, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_property_key {
+            match v {
+                &ViewFilterRequest_oneof_one_of_property_key::property_key(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_property_value {
+            match v {
+                &ViewFilterRequest_oneof_one_of_property_value::property_value(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.one_of_tag {
+            match v {
+                &ViewFilterRequest_oneof_one_of_tag::tag(ref v) => {
+                    os.write_string(1, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_property_key {
+            match v {
+                &ViewFilterRequest_oneof_one_of_property_key::property_key(ref v) => {
+                    os.write_string(2, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_property_value {
+            match v {
+                &ViewFilterRequest_oneof_one_of_property_value::property_value(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ViewFilterRequest {
+        ViewFilterRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "tag",
+                ViewFilterRequest::has_tag,
+                ViewFilterRequest::get_tag,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "property_key",
+                ViewFilterRequest::has_property_key,
+                ViewFilterRequest::get_property_key,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "property_value",
+                ViewFilterRequest::has_property_value,
+                ViewFilterRequest::get_property_value,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ViewFilterRequest>(
+                "ViewFilterRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ViewFilterRequest {
+        static instance: ::protobuf::rt::LazyV2<ViewFilterRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ViewFilterRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ViewFilterRequest {
+    fn clear(&mut self) {
+        self.one_of_tag = ::std::option::Option::None;
+        self.one_of_property_key = ::std::option::Option::None;
+        self.one_of_property_value = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ViewFilterRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ViewFilterRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ViewFilterParams {
+    // message oneof groups
+    pub one_of_tag: ::std::option::Option<ViewFilterParams_oneof_one_of_tag>,
+    pub one_of_property_key: ::std::option::Option<ViewFilterParams_oneof_one_of_property_key>,
+    pub one_of_property_value: ::std::option::Option<ViewFilterParams_oneof_one_of_property_value>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ViewFilterParams {
+    fn default() -> &'a ViewFilterParams {
+        <ViewFilterParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ViewFilterParams_oneof_one_of_tag {
+    tag(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ViewFilterParams_oneof_one_of_property_key {
+    property_key(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ViewFilterParams_oneof_one_of_property_value {
+    property_value(::std::string::String),
+}
+
+impl ViewFilterParams {
+    pub fn new() -> ViewFilterParams {
+        ::std::default::Default::default()
+    }
+
+    // string tag = 1;
+
+
+    pub fn get_tag(&self) -> &str {
+        match self.one_of_tag {
+            ::std::option::Option::Some(ViewFilterParams_oneof_one_of_tag::tag(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_tag(&mut self) {
+        self.one_of_tag = ::std::option::Option::None;
+    }
+
+    pub fn has_tag(&self) -> bool {
+        match self.one_of_tag {
+            ::std::option::Option::Some(ViewFilterParams_oneof_one_of_tag::tag(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tag(&mut self, v: ::std::string::String) {
+        self.one_of_tag = ::std::option::Option::Some(ViewFilterParams_oneof_one_of_tag::tag(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tag(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ViewFilterParams_oneof_one_of_tag::tag(_)) = self.one_of_tag {
+        } else {
+            self.one_of_tag = ::std::option::Option::Some(ViewFilterParams_oneof_one_of_tag::tag(::std::string::String::new()));
+        }
+        match self.one_of_tag {
+            ::std::option::Option::Some(ViewFilterParams_oneof_one_of_tag::tag(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_tag(&mut self) -> ::std::string::String {
+        if self.has_tag() {
+            match self.one_of_tag.take() {
+                ::std::option::Option::Some(ViewFilterParams_oneof_one_of_tag::tag(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string property_key = 2;
+
+
+    pub fn get_property_key(&self) -> &str {
+        match self.one_of_property_key {
+            ::std::option::Option::Some(ViewFilterParams_oneof_one_of_property_key::property_key(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_property_key(&mut self) {
+        self.one_of_property_key = ::std::option::Option::None;
+    }
+
+    pub fn has_property_key(&self) -> bool {
+        match self.one_of_property_key {
+            ::std::option::Option::Some(ViewFilterParams_oneof_one_of_property_key::property_key(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_property_key(&mut self, v: ::std::string::String) {
+        self.one_of_property_key = ::std::option::Option::Some(ViewFilterParams_oneof_one_of_property_key::property_key(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_property_key(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ViewFilterParams_oneof_one_of_property_key::property_key(_)) = self.one_of_property_key {
+        } else {
+            self.one_of_property_key = ::std::option::Option::Some(ViewFilterParams_oneof_one_of_property_key::property_key(::std::string::String::new()));
+        }
+        match self.one_of_property_key {
+            ::std::option::Option::Some(ViewFilterParams_oneof_one_of_property_key::property_key(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_property_key(&mut self) -> ::std::string::String {
+        if self.has_property_key() {
+            match self.one_of_property_key.take() {
+                ::std::option::Option::Some(ViewFilterParams_oneof_one_of_property_key::property_key(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string property_value = 3;
+
+
+    pub fn get_property_value(&self) -> &str {
+        match self.one_of_property_value {
+            ::std::option::Option::Some(ViewFilterParams_oneof_one_of_property_value::property_value(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_property_value(&mut self) {
+        self.one_of_property_value = ::std::option::Option::None;
+    }
+
+    pub fn has_property_value(&self) -> bool {
+        match self.one_of_property_value {
+            ::std::option::Option::Some(ViewFilterParams_oneof_one_of_property_value::property_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_property_value(&mut self, v: ::std::string::String) {
+        self.one_of_property_value = ::std::option::Option::Some(ViewFilterParams_oneof_one_of_property_value::property_value(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_property_value(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ViewFilterParams_oneof_one_of_property_value::property_value(_)) = self.one_of_property_value {
+        } else {
+            self.one_of_property_value = ::std::option::Option::Some(ViewFilterParams_oneof_one_of_property_value::property_value(::std::string::String::new()));
+        }
+        match self.one_of_property_value {
+            ::std::option::Option::Some(ViewFilterParams_oneof_one_of_property_value::property_value(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_property_value(&mut self) -> ::std::string::String {
+        if self.has_property_value() {
+            match self.one_of_property_value.take() {
+                ::std::option::Option::Some(ViewFilterParams_oneof_one_of_property_value::property_value(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for ViewFilterParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_tag = ::std::option::Option::Some(ViewFilterParams_oneof_one_of_tag::tag(is.read_string()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_property_key = ::std::option::Option::Some(ViewFilterParams_oneof_one_of_property_key::property_key(is.read_string()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_property_value = ::std::option::Option::Some(ViewFilterParams_oneof_one_of_property_value::property_value(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.one_of_tag {
+            match v {
+                &ViewFilterParams_oneof_one_of_tag::tag(ref v) => {
+                    my_size += ::protobuf::rt::string_size(1, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_property_key {
+            match v {
+                &ViewFilterParams_oneof_one_of_property_key::property_key(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_property_value {
+            match v {
+                &ViewFilterParams_oneof_one_of_property_value::property_value(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.one_of_tag {
+            match v {
+                &ViewFilterParams_oneof_one_of_tag::tag(ref v) => {
+                    os.write_string(1, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_property_key {
+            match v {
+                &ViewFilterParams_oneof_one_of_property_key::property_key(ref v) => {
+                    os.write_string(2, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_property_value {
+            match v {
+                &ViewFilterParams_oneof_one_of_property_value::property_value(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ViewFilterParams {
+        ViewFilterParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "tag",
+                ViewFilterParams::has_tag,
+                ViewFilterParams::get_tag,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "property_key",
+                ViewFilterParams::has_property_key,
+                ViewFilterParams::get_property_key,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "property_value",
+                ViewFilterParams::has_property_value,
+                ViewFilterParams::get_property_value,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ViewFilterParams>(
+                "ViewFilterParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ViewFilterParams {
+        static instance: ::protobuf::rt::LazyV2<ViewFilterParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ViewFilterParams::new)
+    }
+}
+
+impl ::protobuf::Clear for ViewFilterParams {
+    fn clear(&mut self) {
+        self.one_of_tag = ::std::option::Option::None;
+        self.one_of_property_key = ::std::option::Option::None;
+        self.one_of_property_value = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ViewFilterParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ViewFilterParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ViewType {
+    Blank = 0,
+    Doc = 1,
+    Grid = 2,
+    Board = 3,
+}
+
+impl ::protobuf::ProtobufEnum for ViewType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ViewType> {
+        match value {
+            0 => ::std::option::Option::Some(ViewType::Blank),
+            1 => ::std::option::Option::Some(ViewType::Doc),
+            2 => ::std::option::Option::Some(ViewType::Grid),
+            3 => ::std::option::Option::Some(ViewType::Board),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ViewType] = &[
+            ViewType::Blank,
+            ViewType::Doc,
+            ViewType::Grid,
+            ViewType::Board,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<ViewType>("ViewType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for ViewType {
+}
+
+impl ::std::default::Default for ViewType {
+    fn default() -> Self {
+        ViewType::Blank
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ViewType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ViewPropertyType {
+    Text = 0,
+    Number = 1,
+    Date = 2,
+    Select = 3,
+}
+
+impl ::protobuf::ProtobufEnum for ViewPropertyType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ViewPropertyType> {
+        match value {
+            0 => ::std::option::Option::Some(ViewPropertyType::Text),
+            1 => ::std::option::Option::Some(ViewPropertyType::Number),
+            2 => ::std::option::Option::Some(ViewPropertyType::Date),
+            3 => ::std::option::Option::Some(ViewPropertyType::Select),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ViewPropertyType] = &[
+            ViewPropertyType::Text,
+            ViewPropertyType::Number,
+            ViewPropertyType::Date,
+            ViewPropertyType::Select,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<ViewPropertyType>("ViewPropertyType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for ViewPropertyType {
+}
+
+impl ::std::default::Default for ViewPropertyType {
+    fn default() -> Self {
+        ViewPropertyType::Text
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ViewPropertyType {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
     }
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\nview.proto\"\x97\x02\n\x04View\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\
+    \n\nview.proto\"\xda\x02\n\x04View\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\
     \x02id\x12\x20\n\x0cbelong_to_id\x18\x02\x20\x01(\tR\nbelongToId\x12\x12\
     \n\x04name\x18\x03\x20\x01(\tR\x04name\x12\x12\n\x04desc\x18\x04\x20\x01\
     (\tR\x04desc\x12&\n\tview_type\x18\x05\x20\x01(\x0e2\t.ViewTypeR\x08view\
     Type\x12\x18\n\x07version\x18\x06\x20\x01(\x03R\x07version\x12-\n\nbelon\
     gings\x18\x07\x20\x01(\x0b2\r.RepeatedViewR\nbelongings\x12#\n\rmodified\
     _time\x18\x08\x20\x01(\x03R\x0cmodifiedTime\x12\x1f\n\x0bcreate_time\x18\
-    \t\x20\x01(\x03R\ncreateTime\"+\n\x0cRepeatedView\x12\x1b\n\x05items\x18\
-    \x01\x20\x03(\x0b2\x05.ViewR\x05items\"\xb9\x01\n\x11CreateViewRequest\
-    \x12\x20\n\x0cbelong_to_id\x18\x01\x20\x01(\tR\nbelongToId\x12\x12\n\x04\
-    name\x18\x02\x20\x01(\tR\x04name\x12\x12\n\x04desc\x18\x03\x20\x01(\tR\
-    \x04desc\x12\x1e\n\tthumbnail\x18\x04\x20\x01(\tH\0R\tthumbnail\x12&\n\t\
-    view_type\x18\x05\x20\x01(\x0e2\t.ViewTypeR\x08viewTypeB\x12\n\x10one_of\
-    _thumbnail\"\xd8\x01\n\x10CreateViewParams\x12\x20\n\x0cbelong_to_id\x18\
+    \t\x20\x01(\x03R\ncreateTime\x12\x12\n\x04tags\x18\n\x20\x03(\tR\x04tags\
+    \x12-\n\nproperties\x18\x0b\x20\x03(\x0b2\r.ViewPropertyR\nproperties\"+\
+    \n\x0cRepeatedView\x12\x1b\n\x05items\x18\x01\x20\x03(\x0b2\x05.ViewR\
+    \x05items\"\xb9\x01\n\x11CreateViewRequest\x12\x20\n\x0cbelong_to_id\x18\
     \x01\x20\x01(\tR\nbelongToId\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04na\
-    me\x12\x12\n\x04desc\x18\x03\x20\x01(\tR\x04desc\x12\x1c\n\tthumbnail\
-    \x18\x04\x20\x01(\tR\tthumbnail\x12&\n\tview_type\x18\x05\x20\x01(\x0e2\
-    \t.ViewTypeR\x08viewType\x12\x1b\n\tview_data\x18\x06\x20\x01(\tR\x08vie\
-    wData\x12\x17\n\x07view_id\x18\x07\x20\x01(\tR\x06viewId\"-\n\x10QueryVi\
-    ewRequest\x12\x19\n\x08view_ids\x18\x01\x20\x03(\tR\x07viewIds\"!\n\x06V\
-    iewId\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\x06viewId\"&\n\x0eRepeate\
-    dViewId\x12\x14\n\x05items\x18\x01\x20\x03(\tR\x05items\"\xaa\x01\n\x11U\
-    pdateViewRequest\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\x06viewId\x12\
-    \x14\n\x04name\x18\x02\x20\x01(\tH\0R\x04name\x12\x14\n\x04desc\x18\x03\
-    \x20\x01(\tH\x01R\x04desc\x12\x1e\n\tthumbnail\x18\x04\x20\x01(\tH\x02R\
-    \tthumbnailB\r\n\x0bone_of_nameB\r\n\x0bone_of_descB\x12\n\x10one_of_thu\
-    mbnail\"\xa9\x01\n\x10UpdateViewParams\x12\x17\n\x07view_id\x18\x01\x20\
-    \x01(\tR\x06viewId\x12\x14\n\x04name\x18\x02\x20\x01(\tH\0R\x04name\x12\
-    \x14\n\x04desc\x18\x03\x20\x01(\tH\x01R\x04desc\x12\x1e\n\tthumbnail\x18\
-    \x04\x20\x01(\tH\x02R\tthumbnailB\r\n\x0bone_of_nameB\r\n\x0bone_of_desc\
-    B\x12\n\x10one_of_thumbnail*\x1e\n\x08ViewType\x12\t\n\x05Blank\x10\0\
-    \x12\x07\n\x03Doc\x10\x01J\xd2\x12\n\x06\x12\x04\0\08\x01\n\x08\n\x01\
-    \x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x0c\x01\n\n\n\x03\x04\
-    \0\x01\x12\x03\x02\x08\x0c\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x12\n\
-    \x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\
-    \x12\x03\x03\x0b\r\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x10\x11\n\x0b\
-    \n\x04\x04\0\x02\x01\x12\x03\x04\x04\x1c\n\x0c\n\x05\x04\0\x02\x01\x05\
-    \x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\x17\n\
-    \x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x1a\x1b\n\x0b\n\x04\x04\0\x02\
-    \x02\x12\x03\x05\x04\x14\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\n\
-    \n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\
-    \x02\x03\x12\x03\x05\x12\x13\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x04\
-    \x14\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\0\
-    \x02\x03\x01\x12\x03\x06\x0b\x0f\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\
-    \x06\x12\x13\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x07\x04\x1b\n\x0c\n\x05\
-    \x04\0\x02\x04\x06\x12\x03\x07\x04\x0c\n\x0c\n\x05\x04\0\x02\x04\x01\x12\
-    \x03\x07\r\x16\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x07\x19\x1a\n\x0b\n\
-    \x04\x04\0\x02\x05\x12\x03\x08\x04\x16\n\x0c\n\x05\x04\0\x02\x05\x05\x12\
-    \x03\x08\x04\t\n\x0c\n\x05\x04\0\x02\x05\x01\x12\x03\x08\n\x11\n\x0c\n\
-    \x05\x04\0\x02\x05\x03\x12\x03\x08\x14\x15\n\x0b\n\x04\x04\0\x02\x06\x12\
-    \x03\t\x04\x20\n\x0c\n\x05\x04\0\x02\x06\x06\x12\x03\t\x04\x10\n\x0c\n\
-    \x05\x04\0\x02\x06\x01\x12\x03\t\x11\x1b\n\x0c\n\x05\x04\0\x02\x06\x03\
-    \x12\x03\t\x1e\x1f\n\x0b\n\x04\x04\0\x02\x07\x12\x03\n\x04\x1c\n\x0c\n\
-    \x05\x04\0\x02\x07\x05\x12\x03\n\x04\t\n\x0c\n\x05\x04\0\x02\x07\x01\x12\
-    \x03\n\n\x17\n\x0c\n\x05\x04\0\x02\x07\x03\x12\x03\n\x1a\x1b\n\x0b\n\x04\
-    \x04\0\x02\x08\x12\x03\x0b\x04\x1a\n\x0c\n\x05\x04\0\x02\x08\x05\x12\x03\
-    \x0b\x04\t\n\x0c\n\x05\x04\0\x02\x08\x01\x12\x03\x0b\n\x15\n\x0c\n\x05\
-    \x04\0\x02\x08\x03\x12\x03\x0b\x18\x19\n\n\n\x02\x04\x01\x12\x04\r\0\x0f\
-    \x01\n\n\n\x03\x04\x01\x01\x12\x03\r\x08\x14\n\x0b\n\x04\x04\x01\x02\0\
-    \x12\x03\x0e\x04\x1c\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03\x0e\x04\x0c\n\
-    \x0c\n\x05\x04\x01\x02\0\x06\x12\x03\x0e\r\x11\n\x0c\n\x05\x04\x01\x02\0\
-    \x01\x12\x03\x0e\x12\x17\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0e\x1a\
-    \x1b\n\n\n\x02\x04\x02\x12\x04\x10\0\x16\x01\n\n\n\x03\x04\x02\x01\x12\
-    \x03\x10\x08\x19\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x11\x04\x1c\n\x0c\n\
-    \x05\x04\x02\x02\0\x05\x12\x03\x11\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\
-    \x12\x03\x11\x0b\x17\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x11\x1a\x1b\n\
-    \x0b\n\x04\x04\x02\x02\x01\x12\x03\x12\x04\x14\n\x0c\n\x05\x04\x02\x02\
-    \x01\x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x12\
-    \x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x12\x12\x13\n\x0b\n\x04\
-    \x04\x02\x02\x02\x12\x03\x13\x04\x14\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\
-    \x03\x13\x04\n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x13\x0b\x0f\n\x0c\
-    \n\x05\x04\x02\x02\x02\x03\x12\x03\x13\x12\x13\n\x0b\n\x04\x04\x02\x08\0\
-    \x12\x03\x14\x044\n\x0c\n\x05\x04\x02\x08\0\x01\x12\x03\x14\n\x1a\n\x0b\
-    \n\x04\x04\x02\x02\x03\x12\x03\x14\x1d2\n\x0c\n\x05\x04\x02\x02\x03\x05\
-    \x12\x03\x14\x1d#\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x14$-\n\x0c\n\
-    \x05\x04\x02\x02\x03\x03\x12\x03\x1401\n\x0b\n\x04\x04\x02\x02\x04\x12\
-    \x03\x15\x04\x1b\n\x0c\n\x05\x04\x02\x02\x04\x06\x12\x03\x15\x04\x0c\n\
-    \x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\x15\r\x16\n\x0c\n\x05\x04\x02\x02\
-    \x04\x03\x12\x03\x15\x19\x1a\n\n\n\x02\x04\x03\x12\x04\x17\0\x1f\x01\n\n\
-    \n\x03\x04\x03\x01\x12\x03\x17\x08\x18\n\x0b\n\x04\x04\x03\x02\0\x12\x03\
-    \x18\x04\x1c\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x18\x04\n\n\x0c\n\x05\
-    \x04\x03\x02\0\x01\x12\x03\x18\x0b\x17\n\x0c\n\x05\x04\x03\x02\0\x03\x12\
-    \x03\x18\x1a\x1b\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x19\x04\x14\n\x0c\n\
-    \x05\x04\x03\x02\x01\x05\x12\x03\x19\x04\n\n\x0c\n\x05\x04\x03\x02\x01\
-    \x01\x12\x03\x19\x0b\x0f\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x19\x12\
-    \x13\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\x1a\x04\x14\n\x0c\n\x05\x04\x03\
-    \x02\x02\x05\x12\x03\x1a\x04\n\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x03\
-    \x1a\x0b\x0f\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\x1a\x12\x13\n\x0b\n\
-    \x04\x04\x03\x02\x03\x12\x03\x1b\x04\x19\n\x0c\n\x05\x04\x03\x02\x03\x05\
-    \x12\x03\x1b\x04\n\n\x0c\n\x05\x04\x03\x02\x03\x01\x12\x03\x1b\x0b\x14\n\
-    \x0c\n\x05\x04\x03\x02\x03\x03\x12\x03\x1b\x17\x18\n\x0b\n\x04\x04\x03\
-    \x02\x04\x12\x03\x1c\x04\x1b\n\x0c\n\x05\x04\x03\x02\x04\x06\x12\x03\x1c\
-    \x04\x0c\n\x0c\n\x05\x04\x03\x02\x04\x01\x12\x03\x1c\r\x16\n\x0c\n\x05\
-    \x04\x03\x02\x04\x03\x12\x03\x1c\x19\x1a\n\x0b\n\x04\x04\x03\x02\x05\x12\
-    \x03\x1d\x04\x19\n\x0c\n\x05\x04\x03\x02\x05\x05\x12\x03\x1d\x04\n\n\x0c\
-    \n\x05\x04\x03\x02\x05\x01\x12\x03\x1d\x0b\x14\n\x0c\n\x05\x04\x03\x02\
-    \x05\x03\x12\x03\x1d\x17\x18\n\x0b\n\x04\x04\x03\x02\x06\x12\x03\x1e\x04\
-    \x17\n\x0c\n\x05\x04\x03\x02\x06\x05\x12\x03\x1e\x04\n\n\x0c\n\x05\x04\
-    \x03\x02\x06\x01\x12\x03\x1e\x0b\x12\n\x0c\n\x05\x04\x03\x02\x06\x03\x12\
-    \x03\x1e\x15\x16\n\n\n\x02\x04\x04\x12\x04\x20\0\"\x01\n\n\n\x03\x04\x04\
-    \x01\x12\x03\x20\x08\x18\n\x0b\n\x04\x04\x04\x02\0\x12\x03!\x04!\n\x0c\n\
-    \x05\x04\x04\x02\0\x04\x12\x03!\x04\x0c\n\x0c\n\x05\x04\x04\x02\0\x05\
-    \x12\x03!\r\x13\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03!\x14\x1c\n\x0c\n\
-    \x05\x04\x04\x02\0\x03\x12\x03!\x1f\x20\n\n\n\x02\x04\x05\x12\x04#\0%\
-    \x01\n\n\n\x03\x04\x05\x01\x12\x03#\x08\x0e\n\x0b\n\x04\x04\x05\x02\0\
-    \x12\x03$\x04\x17\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03$\x04\n\n\x0c\n\
-    \x05\x04\x05\x02\0\x01\x12\x03$\x0b\x12\n\x0c\n\x05\x04\x05\x02\0\x03\
-    \x12\x03$\x15\x16\n\n\n\x02\x04\x06\x12\x04&\0(\x01\n\n\n\x03\x04\x06\
-    \x01\x12\x03&\x08\x16\n\x0b\n\x04\x04\x06\x02\0\x12\x03'\x04\x1e\n\x0c\n\
-    \x05\x04\x06\x02\0\x04\x12\x03'\x04\x0c\n\x0c\n\x05\x04\x06\x02\0\x05\
-    \x12\x03'\r\x13\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03'\x14\x19\n\x0c\n\
-    \x05\x04\x06\x02\0\x03\x12\x03'\x1c\x1d\n\n\n\x02\x04\x07\x12\x04)\0.\
-    \x01\n\n\n\x03\x04\x07\x01\x12\x03)\x08\x19\n\x0b\n\x04\x04\x07\x02\0\
-    \x12\x03*\x04\x17\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03*\x04\n\n\x0c\n\
-    \x05\x04\x07\x02\0\x01\x12\x03*\x0b\x12\n\x0c\n\x05\x04\x07\x02\0\x03\
-    \x12\x03*\x15\x16\n\x0b\n\x04\x04\x07\x08\0\x12\x03+\x04*\n\x0c\n\x05\
-    \x04\x07\x08\0\x01\x12\x03+\n\x15\n\x0b\n\x04\x04\x07\x02\x01\x12\x03+\
-    \x18(\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x03+\x18\x1e\n\x0c\n\x05\x04\
-    \x07\x02\x01\x01\x12\x03+\x1f#\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x03+&\
-    '\n\x0b\n\x04\x04\x07\x08\x01\x12\x03,\x04*\n\x0c\n\x05\x04\x07\x08\x01\
-    \x01\x12\x03,\n\x15\n\x0b\n\x04\x04\x07\x02\x02\x12\x03,\x18(\n\x0c\n\
-    \x05\x04\x07\x02\x02\x05\x12\x03,\x18\x1e\n\x0c\n\x05\x04\x07\x02\x02\
-    \x01\x12\x03,\x1f#\n\x0c\n\x05\x04\x07\x02\x02\x03\x12\x03,&'\n\x0b\n\
-    \x04\x04\x07\x08\x02\x12\x03-\x044\n\x0c\n\x05\x04\x07\x08\x02\x01\x12\
-    \x03-\n\x1a\n\x0b\n\x04\x04\x07\x02\x03\x12\x03-\x1d2\n\x0c\n\x05\x04\
-    \x07\x02\x03\x05\x12\x03-\x1d#\n\x0c\n\x05\x04\x07\x02\x03\x01\x12\x03-$\
-    -\n\x0c\n\x05\x04\x07\x02\x03\x03\x12\x03-01\n\n\n\x02\x04\x08\x12\x04/\
-    \04\x01\n\n\n\x03\x04\x08\x01\x12\x03/\x08\x18\n\x0b\n\x04\x04\x08\x02\0\
-    \x12\x030\x04\x17\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x030\x04\n\n\x0c\n\
-    \x05\x04\x08\x02\0\x01\x12\x030\x0b\x12\n\x0c\n\x05\x04\x08\x02\0\x03\
-    \x12\x030\x15\x16\n\x0b\n\x04\x04\x08\x08\0\x12\x031\x04*\n\x0c\n\x05\
-    \x04\x08\x08\0\x01\x12\x031\n\x15\n\x0b\n\x04\x04\x08\x02\x01\x12\x031\
-    \x18(\n\x0c\n\x05\x04\x08\x02\x01\x05\x12\x031\x18\x1e\n\x0c\n\x05\x04\
-    \x08\x02\x01\x01\x12\x031\x1f#\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\x031&\
-    '\n\x0b\n\x04\x04\x08\x08\x01\x12\x032\x04*\n\x0c\n\x05\x04\x08\x08\x01\
-    \x01\x12\x032\n\x15\n\x0b\n\x04\x04\x08\x02\x02\x12\x032\x18(\n\x0c\n\
-    \x05\x04\x08\x02\x02\x05\x12\x032\x18\x1e\n\x0c\n\x05\x04\x08\x02\x02\
-    \x01\x12\x032\x1f#\n\x0c\n\x05\x04\x08\x02\x02\x03\x12\x032&'\n\x0b\n\
-    \x04\x04\x08\x08\x02\x12\x033\x044\n\x0c\n\x05\x04\x08\x08\x02\x01\x12\
-    \x033\n\x1a\n\x0b\n\x04\x04\x08\x02\x03\x12\x033\x1d2\n\x0c\n\x05\x04\
-    \x08\x02\x03\x05\x12\x033\x1d#\n\x0c\n\x05\x04\x08\x02\x03\x01\x12\x033$\
-    -\n\x0c\n\x05\x04\x08\x02\x03\x03\x12\x03301\n\n\n\x02\x05\0\x12\x045\08\
-    \x01\n\n\n\x03\x05\0\x01\x12\x035\x05\r\n\x0b\n\x04\x05\0\x02\0\x12\x036\
-    \x04\x0e\n\x0c\n\x05\x05\0\x02\0\x01\x12\x036\x04\t\n\x0c\n\x05\x05\0\
-    \x02\0\x02\x12\x036\x0c\r\n\x0b\n\x04\x05\0\x02\x01\x12\x037\x04\x0c\n\
-    \x0c\n\x05\x05\0\x02\x01\x01\x12\x037\x04\x07\n\x0c\n\x05\x05\0\x02\x01\
-    \x02\x12\x037\n\x0bb\x06proto3\
+    me\x12\x12\n\x04desc\x18\x03\x20\x01(\tR\x04desc\x12\x1e\n\tthumbnail\
+    \x18\x04\x20\x01(\tH\0R\tthumbnail\x12&\n\tview_type\x18\x05\x20\x01(\
+    \x0e2\t.ViewTypeR\x08viewTypeB\x12\n\x10one_of_thumbnail\"\xd8\x01\n\x10\
+    CreateViewParams\x12\x20\n\x0cbelong_to_id\x18\x01\x20\x01(\tR\nbelongTo\
+    Id\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x12\n\x04desc\x18\
+    \x03\x20\x01(\tR\x04desc\x12\x1c\n\tthumbnail\x18\x04\x20\x01(\tR\tthumb\
+    nail\x12&\n\tview_type\x18\x05\x20\x01(\x0e2\t.ViewTypeR\x08viewType\x12\
+    \x1b\n\tview_data\x18\x06\x20\x01(\tR\x08viewData\x12\x17\n\x07view_id\
+    \x18\x07\x20\x01(\tR\x06viewId\"-\n\x10QueryViewRequest\x12\x19\n\x08vie\
+    w_ids\x18\x01\x20\x03(\tR\x07viewIds\"!\n\x06ViewId\x12\x17\n\x07view_id\
+    \x18\x01\x20\x01(\tR\x06viewId\"&\n\x0eRepeatedViewId\x12\x14\n\x05items\
+    \x18\x01\x20\x03(\tR\x05items\"\xaa\x01\n\x11UpdateViewRequest\x12\x17\n\
+    \x07view_id\x18\x01\x20\x01(\tR\x06viewId\x12\x14\n\x04name\x18\x02\x20\
+    \x01(\tH\0R\x04name\x12\x14\n\x04desc\x18\x03\x20\x01(\tH\x01R\x04desc\
+    \x12\x1e\n\tthumbnail\x18\x04\x20\x01(\tH\x02R\tthumbnailB\r\n\x0bone_of\
+    _nameB\r\n\x0bone_of_descB\x12\n\x10one_of_thumbnail\"\xa9\x01\n\x10Upda\
+    teViewParams\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\x06viewId\x12\x14\
+    \n\x04name\x18\x02\x20\x01(\tH\0R\x04name\x12\x14\n\x04desc\x18\x03\x20\
+    \x01(\tH\x01R\x04desc\x12\x1e\n\tthumbnail\x18\x04\x20\x01(\tH\x02R\tthu\
+    mbnailB\r\n\x0bone_of_nameB\r\n\x0bone_of_descB\x12\n\x10one_of_thumbnai\
+    l\"s\n\x0cViewProperty\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12!\
+    \n\x02ty\x18\x02\x20\x01(\x0e2\x11.ViewPropertyTypeR\x02ty\x12\x14\n\x05\
+    value\x18\x03\x20\x01(\tR\x05value\x12\x18\n\x07options\x18\x04\x20\x03(\
+    \tR\x07options\"D\n\x15UpdateViewTagsRequest\x12\x17\n\x07view_id\x18\
+    \x01\x20\x01(\tR\x06viewId\x12\x12\n\x04tags\x18\x02\x20\x03(\tR\x04tags\
+    \"C\n\x14UpdateViewTagsParams\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\
+    \x06viewId\x12\x12\n\x04tags\x18\x02\x20\x03(\tR\x04tags\"\xab\x01\n\x19\
+    UpdateViewPropertyRequest\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\x06vi\
+    ewId\x12\x10\n\x03key\x18\x02\x20\x01(\tR\x03key\x12!\n\x02ty\x18\x03\
+    \x20\x01(\x0e2\x11.ViewPropertyTypeR\x02ty\x12\x16\n\x05value\x18\x04\
+    \x20\x01(\tH\0R\x05value\x12\x18\n\x07options\x18\x05\x20\x03(\tR\x07opt\
+    ionsB\x0e\n\x0cone_of_value\"\x85\x01\n\x18UpdateViewPropertyParams\x12\
+    \x17\n\x07view_id\x18\x01\x20\x01(\tR\x06viewId\x12\x10\n\x03key\x18\x02\
+    \x20\x01(\tR\x03key\x12+\n\x08property\x18\x03\x20\x01(\x0b2\r.ViewPrope\
+    rtyH\0R\x08propertyB\x11\n\x0fone_of_property\"\xb3\x01\n\x11ViewFilterR\
+    equest\x12\x12\n\x03tag\x18\x01\x20\x01(\tH\0R\x03tag\x12#\n\x0cproperty\
+    _key\x18\x02\x20\x01(\tH\x01R\x0bpropertyKey\x12'\n\x0eproperty_value\
+    \x18\x03\x20\x01(\tH\x02R\rpropertyValueB\x0c\n\none_of_tagB\x15\n\x13on\
+    e_of_property_keyB\x17\n\x15one_of_property_value\"\xb2\x01\n\x10ViewFil\
+    terParams\x12\x12\n\x03tag\x18\x01\x20\x01(\tH\0R\x03tag\x12#\n\x0cprope\
+    rty_key\x18\x02\x20\x01(\tH\x01R\x0bpropertyKey\x12'\n\x0eproperty_value\
+    \x18\x03\x20\x01(\tH\x02R\rpropertyValueB\x0c\n\none_of_tagB\x15\n\x13on\
+    e_of_property_keyB\x17\n\x15one_of_property_value*3\n\x08ViewType\x12\t\
+    \n\x05Blank\x10\0\x12\x07\n\x03Doc\x10\x01\x12\x08\n\x04Grid\x10\x02\x12\
+    \t\n\x05Board\x10\x03*>\n\x10ViewPropertyType\x12\x08\n\x04Text\x10\0\
+    \x12\n\n\x06Number\x10\x01\x12\x08\n\x04Date\x10\x02\x12\n\n\x06Select\
+    \x10\x03J\xdc\"\n\x06\x12\x04\0\0f\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\
+    \n\n\x02\x04\0\x12\x04\x02\0\x0e\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\
+    \x0c\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x12\n\x0c\n\x05\x04\0\x02\0\
+    \x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\r\n\
+    \x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x10\x11\n\x0b\n\x04\x04\0\x02\x01\
+    \x12\x03\x04\x04\x1c\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\
+    \x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\x17\n\x0c\n\x05\x04\0\x02\
+    \x01\x03\x12\x03\x04\x1a\x1b\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\
+    \x14\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\
+    \x02\x02\x01\x12\x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\
+    \x05\x12\x13\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x04\x14\n\x0c\n\x05\
+    \x04\0\x02\x03\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\0\x02\x03\x01\x12\
+    \x03\x06\x0b\x0f\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x06\x12\x13\n\x0b\
+    \n\x04\x04\0\x02\x04\x12\x03\x07\x04\x1b\n\x0c\n\x05\x04\0\x02\x04\x06\
+    \x12\x03\x07\x04\x0c\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\r\x16\n\
+    \x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x07\x19\x1a\n\x0b\n\x04\x04\0\x02\
+    \x05\x12\x03\x08\x04\x16\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\x08\x04\t\
+    \n\x0c\n\x05\x04\0\x02\x05\x01\x12\x03\x08\n\x11\n\x0c\n\x05\x04\0\x02\
+    \x05\x03\x12\x03\x08\x14\x15\n\x0b\n\x04\x04\0\x02\x06\x12\x03\t\x04\x20\
+    \n\x0c\n\x05\x04\0\x02\x06\x06\x12\x03\t\x04\x10\n\x0c\n\x05\x04\0\x02\
+    \x06\x01\x12\x03\t\x11\x1b\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03\t\x1e\
+    \x1f\n\x0b\n\x04\x04\0\x02\x07\x12\x03\n\x04\x1c\n\x0c\n\x05\x04\0\x02\
+    \x07\x05\x12\x03\n\x04\t\n\x0c\n\x05\x04\0\x02\x07\x01\x12\x03\n\n\x17\n\
+    \x0c\n\x05\x04\0\x02\x07\x03\x12\x03\n\x1a\x1b\n\x0b\n\x04\x04\0\x02\x08\
+    \x12\x03\x0b\x04\x1a\n\x0c\n\x05\x04\0\x02\x08\x05\x12\x03\x0b\x04\t\n\
+    \x0c\n\x05\x04\0\x02\x08\x01\x12\x03\x0b\n\x15\n\x0c\n\x05\x04\0\x02\x08\
+    \x03\x12\x03\x0b\x18\x19\n\x0b\n\x04\x04\0\x02\t\x12\x03\x0c\x04\x1e\n\
+    \x0c\n\x05\x04\0\x02\t\x04\x12\x03\x0c\x04\x0c\n\x0c\n\x05\x04\0\x02\t\
+    \x05\x12\x03\x0c\r\x13\n\x0c\n\x05\x04\0\x02\t\x01\x12\x03\x0c\x14\x18\n\
+    \x0c\n\x05\x04\0\x02\t\x03\x12\x03\x0c\x1b\x1d\n\x0b\n\x04\x04\0\x02\n\
+    \x12\x03\r\x04*\n\x0c\n\x05\x04\0\x02\n\x04\x12\x03\r\x04\x0c\n\x0c\n\
+    \x05\x04\0\x02\n\x06\x12\x03\r\r\x19\n\x0c\n\x05\x04\0\x02\n\x01\x12\x03\
+    \r\x1a$\n\x0c\n\x05\x04\0\x02\n\x03\x12\x03\r')\n\n\n\x02\x04\x01\x12\
+    \x04\x0f\0\x11\x01\n\n\n\x03\x04\x01\x01\x12\x03\x0f\x08\x14\n\x0b\n\x04\
+    \x04\x01\x02\0\x12\x03\x10\x04\x1c\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03\
+    \x10\x04\x0c\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03\x10\r\x11\n\x0c\n\x05\
+    \x04\x01\x02\0\x01\x12\x03\x10\x12\x17\n\x0c\n\x05\x04\x01\x02\0\x03\x12\
+    \x03\x10\x1a\x1b\n\n\n\x02\x04\x02\x12\x04\x12\0\x18\x01\n\n\n\x03\x04\
+    \x02\x01\x12\x03\x12\x08\x19\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x13\x04\
+    \x1c\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x13\x04\n\n\x0c\n\x05\x04\x02\
+    \x02\0\x01\x12\x03\x13\x0b\x17\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x13\
+    \x1a\x1b\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x14\x04\x14\n\x0c\n\x05\x04\
+    \x02\x02\x01\x05\x12\x03\x14\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\
+    \x03\x14\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x14\x12\x13\n\
+    \x0b\n\x04\x04\x02\x02\x02\x12\x03\x15\x04\x14\n\x0c\n\x05\x04\x02\x02\
+    \x02\x05\x12\x03\x15\x04\n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x15\
+    \x0b\x0f\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x15\x12\x13\n\x0b\n\x04\
+    \x04\x02\x08\0\x12\x03\x16\x044\n\x0c\n\x05\x04\x02\x08\0\x01\x12\x03\
+    \x16\n\x1a\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\x16\x1d2\n\x0c\n\x05\x04\
+    \x02\x02\x03\x05\x12\x03\x16\x1d#\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\
+    \x03\x16$-\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x1601\n\x0b\n\x04\x04\
+    \x02\x02\x04\x12\x03\x17\x04\x1b\n\x0c\n\x05\x04\x02\x02\x04\x06\x12\x03\
+    \x17\x04\x0c\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\x17\r\x16\n\x0c\n\
+    \x05\x04\x02\x02\x04\x03\x12\x03\x17\x19\x1a\n\n\n\x02\x04\x03\x12\x04\
+    \x19\0!\x01\n\n\n\x03\x04\x03\x01\x12\x03\x19\x08\x18\n\x0b\n\x04\x04\
+    \x03\x02\0\x12\x03\x1a\x04\x1c\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x1a\
+    \x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x1a\x0b\x17\n\x0c\n\x05\x04\
+    \x03\x02\0\x03\x12\x03\x1a\x1a\x1b\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\
+    \x1b\x04\x14\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x1b\x04\n\n\x0c\n\
+    \x05\x04\x03\x02\x01\x01\x12\x03\x1b\x0b\x0f\n\x0c\n\x05\x04\x03\x02\x01\
+    \x03\x12\x03\x1b\x12\x13\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\x1c\x04\x14\
+    \n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x1c\x04\n\n\x0c\n\x05\x04\x03\
+    \x02\x02\x01\x12\x03\x1c\x0b\x0f\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\
+    \x1c\x12\x13\n\x0b\n\x04\x04\x03\x02\x03\x12\x03\x1d\x04\x19\n\x0c\n\x05\
+    \x04\x03\x02\x03\x05\x12\x03\x1d\x04\n\n\x0c\n\x05\x04\x03\x02\x03\x01\
+    \x12\x03\x1d\x0b\x14\n\x0c\n\x05\x04\x03\x02\x03\x03\x12\x03\x1d\x17\x18\
+    \n\x0b\n\x04\x04\x03\x02\x04\x12\x03\x1e\x04\x1b\n\x0c\n\x05\x04\x03\x02\
+    \x04\x06\x12\x03\x1e\x04\x0c\n\x0c\n\x05\x04\x03\x02\x04\x01\x12\x03\x1e\
+    \r\x16\n\x0c\n\x05\x04\x03\x02\x04\x03\x12\x03\x1e\x19\x1a\n\x0b\n\x04\
+    \x04\x03\x02\x05\x12\x03\x1f\x04\x19\n\x0c\n\x05\x04\x03\x02\x05\x05\x12\
+    \x03\x1f\x04\n\n\x0c\n\x05\x04\x03\x02\x05\x01\x12\x03\x1f\x0b\x14\n\x0c\
+    \n\x05\x04\x03\x02\x05\x03\x12\x03\x1f\x17\x18\n\x0b\n\x04\x04\x03\x02\
+    \x06\x12\x03\x20\x04\x17\n\x0c\n\x05\x04\x03\x02\x06\x05\x12\x03\x20\x04\
+    \n\n\x0c\n\x05\x04\x03\x02\x06\x01\x12\x03\x20\x0b\x12\n\x0c\n\x05\x04\
+    \x03\x02\x06\x03\x12\x03\x20\x15\x16\n\n\n\x02\x04\x04\x12\x04\"\0$\x01\
+    \n\n\n\x03\x04\x04\x01\x12\x03\"\x08\x18\n\x0b\n\x04\x04\x04\x02\0\x12\
+    \x03#\x04!\n\x0c\n\x05\x04\x04\x02\0\x04\x12\x03#\x04\x0c\n\x0c\n\x05\
+    \x04\x04\x02\0\x05\x12\x03#\r\x13\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03#\
+    \x14\x1c\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03#\x1f\x20\n\n\n\x02\x04\
+    \x05\x12\x04%\0'\x01\n\n\n\x03\x04\x05\x01\x12\x03%\x08\x0e\n\x0b\n\x04\
+    \x04\x05\x02\0\x12\x03&\x04\x17\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03&\
+    \x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03&\x0b\x12\n\x0c\n\x05\x04\
+    \x05\x02\0\x03\x12\x03&\x15\x16\n\n\n\x02\x04\x06\x12\x04(\0*\x01\n\n\n\
+    \x03\x04\x06\x01\x12\x03(\x08\x16\n\x0b\n\x04\x04\x06\x02\0\x12\x03)\x04\
+    \x1e\n\x0c\n\x05\x04\x06\x02\0\x04\x12\x03)\x04\x0c\n\x0c\n\x05\x04\x06\
+    \x02\0\x05\x12\x03)\r\x13\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03)\x14\x19\
+    \n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03)\x1c\x1d\n\n\n\x02\x04\x07\x12\
+    \x04+\00\x01\n\n\n\x03\x04\x07\x01\x12\x03+\x08\x19\n\x0b\n\x04\x04\x07\
+    \x02\0\x12\x03,\x04\x17\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03,\x04\n\n\
+    \x0c\n\x05\x04\x07\x02\0\x01\x12\x03,\x0b\x12\n\x0c\n\x05\x04\x07\x02\0\
+    \x03\x12\x03,\x15\x16\n\x0b\n\x04\x04\x07\x08\0\x12\x03-\x04*\n\x0c\n\
+    \x05\x04\x07\x08\0\x01\x12\x03-\n\x15\n\x0b\n\x04\x04\x07\x02\x01\x12\
+    \x03-\x18(\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x03-\x18\x1e\n\x0c\n\x05\
+    \x04\x07\x02\x01\x01\x12\x03-\x1f#\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\
+    \x03-&'\n\x0b\n\x04\x04\x07\x08\x01\x12\x03.\x04*\n\x0c\n\x05\x04\x07\
+    \x08\x01\x01\x12\x03.\n\x15\n\x0b\n\x04\x04\x07\x02\x02\x12\x03.\x18(\n\
+    \x0c\n\x05\x04\x07\x02\x02\x05\x12\x03.\x18\x1e\n\x0c\n\x05\x04\x07\x02\
+    \x02\x01\x12\x03.\x1f#\n\x0c\n\x05\x04\x07\x02\x02\x03\x12\x03.&'\n\x0b\
+    \n\x04\x04\x07\x08\x02\x12\x03/\x044\n\x0c\n\x05\x04\x07\x08\x02\x01\x12\
+    \x03/\n\x1a\n\x0b\n\x04\x04\x07\x02\x03\x12\x03/\x1d2\n\x0c\n\x05\x04\
+    \x07\x02\x03\x05\x12\x03/\x1d#\n\x0c\n\x05\x04\x07\x02\x03\x01\x12\x03/$\
+    -\n\x0c\n\x05\x04\x07\x02\x03\x03\x12\x03/01\n\n\n\x02\x04\x08\x12\x041\
+    \06\x01\n\n\n\x03\x04\x08\x01\x12\x031\x08\x18\n\x0b\n\x04\x04\x08\x02\0\
+    \x12\x032\x04\x17\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x032\x04\n\n\x0c\n\
+    \x05\x04\x08\x02\0\x01\x12\x032\x0b\x12\n\x0c\n\x05\x04\x08\x02\0\x03\
+    \x12\x032\x15\x16\n\x0b\n\x04\x04\x08\x08\0\x12\x033\x04*\n\x0c\n\x05\
+    \x04\x08\x08\0\x01\x12\x033\n\x15\n\x0b\n\x04\x04\x08\x02\x01\x12\x033\
+    \x18(\n\x0c\n\x05\x04\x08\x02\x01\x05\x12\x033\x18\x1e\n\x0c\n\x05\x04\
+    \x08\x02\x01\x01\x12\x033\x1f#\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\x033&\
+    '\n\x0b\n\x04\x04\x08\x08\x01\x12\x034\x04*\n\x0c\n\x05\x04\x08\x08\x01\
+    \x01\x12\x034\n\x15\n\x0b\n\x04\x04\x08\x02\x02\x12\x034\x18(\n\x0c\n\
+    \x05\x04\x08\x02\x02\x05\x12\x034\x18\x1e\n\x0c\n\x05\x04\x08\x02\x02\
+    \x01\x12\x034\x1f#\n\x0c\n\x05\x04\x08\x02\x02\x03\x12\x034&'\n\x0b\n\
+    \x04\x04\x08\x08\x02\x12\x035\x044\n\x0c\n\x05\x04\x08\x08\x02\x01\x12\
+    \x035\n\x1a\n\x0b\n\x04\x04\x08\x02\x03\x12\x035\x1d2\n\x0c\n\x05\x04\
+    \x08\x02\x03\x05\x12\x035\x1d#\n\x0c\n\x05\x04\x08\x02\x03\x01\x12\x035$\
+    -\n\x0c\n\x05\x04\x08\x02\x03\x03\x12\x03501\n\n\n\x02\x04\t\x12\x047\0<\
+    \x01\n\n\n\x03\x04\t\x01\x12\x037\x08\x14\n\x0b\n\x04\x04\t\x02\0\x12\
+    \x038\x04\x13\n\x0c\n\x05\x04\t\x02\0\x05\x12\x038\x04\n\n\x0c\n\x05\x04\
+    \t\x02\0\x01\x12\x038\x0b\x0e\n\x0c\n\x05\x04\t\x02\0\x03\x12\x038\x11\
+    \x12\n\x0b\n\x04\x04\t\x02\x01\x12\x039\x04\x1c\n\x0c\n\x05\x04\t\x02\
+    \x01\x06\x12\x039\x04\x14\n\x0c\n\x05\x04\t\x02\x01\x01\x12\x039\x15\x17\
+    \n\x0c\n\x05\x04\t\x02\x01\x03\x12\x039\x1a\x1b\n\x0b\n\x04\x04\t\x02\
+    \x02\x12\x03:\x04\x15\n\x0c\n\x05\x04\t\x02\x02\x05\x12\x03:\x04\n\n\x0c\
+    \n\x05\x04\t\x02\x02\x01\x12\x03:\x0b\x10\n\x0c\n\x05\x04\t\x02\x02\x03\
+    \x12\x03:\x13\x14\n\x0b\n\x04\x04\t\x02\x03\x12\x03;\x04\x20\n\x0c\n\x05\
+    \x04\t\x02\x03\x04\x12\x03;\x04\x0c\n\x0c\n\x05\x04\t\x02\x03\x05\x12\
+    \x03;\r\x13\n\x0c\n\x05\x04\t\x02\x03\x01\x12\x03;\x14\x1b\n\x0c\n\x05\
+    \x04\t\x02\x03\x03\x12\x03;\x1e\x1f\n\n\n\x02\x04\n\x12\x04=\0@\x01\n\n\
+    \n\x03\x04\n\x01\x12\x03=\x08\x1d\n\x0b\n\x04\x04\n\x02\0\x12\x03>\x04\
+    \x17\n\x0c\n\x05\x04\n\x02\0\x05\x12\x03>\x04\n\n\x0c\n\x05\x04\n\x02\0\
+    \x01\x12\x03>\x0b\x12\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03>\x15\x16\n\x0b\
+    \n\x04\x04\n\x02\x01\x12\x03?\x04\x1d\n\x0c\n\x05\x04\n\x02\x01\x04\x12\
+    \x03?\x04\x0c\n\x0c\n\x05\x04\n\x02\x01\x05\x12\x03?\r\x13\n\x0c\n\x05\
+    \x04\n\x02\x01\x01\x12\x03?\x14\x18\n\x0c\n\x05\x04\n\x02\x01\x03\x12\
+    \x03?\x1b\x1c\n\n\n\x02\x04\x0b\x12\x04A\0D\x01\n\n\n\x03\x04\x0b\x01\
+    \x12\x03A\x08\x1c\n\x0b\n\x04\x04\x0b\x02\0\x12\x03B\x04\x17\n\x0c\n\x05\
+    \x04\x0b\x02\0\x05\x12\x03B\x04\n\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\x03B\
+    \x0b\x12\n\x0c\n\x05\x04\x0b\x02\0\x03\x12\x03B\x15\x16\n\x0b\n\x04\x04\
+    \x0b\x02\x01\x12\x03C\x04\x1d\n\x0c\n\x05\x04\x0b\x02\x01\x04\x12\x03C\
+    \x04\x0c\n\x0c\n\x05\x04\x0b\x02\x01\x05\x12\x03C\r\x13\n\x0c\n\x05\x04\
+    \x0b\x02\x01\x01\x12\x03C\x14\x18\n\x0c\n\x05\x04\x0b\x02\x01\x03\x12\
+    \x03C\x1b\x1c\n\n\n\x02\x04\x0c\x12\x04E\0K\x01\n\n\n\x03\x04\x0c\x01\
+    \x12\x03E\x08!\n\x0b\n\x04\x04\x0c\x02\0\x12\x03F\x04\x17\n\x0c\n\x05\
+    \x04\x0c\x02\0\x05\x12\x03F\x04\n\n\x0c\n\x05\x04\x0c\x02\0\x01\x12\x03F\
+    \x0b\x12\n\x0c\n\x05\x04\x0c\x02\0\x03\x12\x03F\x15\x16\n\x0b\n\x04\x04\
+    \x0c\x02\x01\x12\x03G\x04\x13\n\x0c\n\x05\x04\x0c\x02\x01\x05\x12\x03G\
+    \x04\n\n\x0c\n\x05\x04\x0c\x02\x01\x01\x12\x03G\x0b\x0e\n\x0c\n\x05\x04\
+    \x0c\x02\x01\x03\x12\x03G\x11\x12\n\x0b\n\x04\x04\x0c\x02\x02\x12\x03H\
+    \x04\x1c\n\x0c\n\x05\x04\x0c\x02\x02\x06\x12\x03H\x04\x14\n\x0c\n\x05\
+    \x04\x0c\x02\x02\x01\x12\x03H\x15\x17\n\x0c\n\x05\x04\x0c\x02\x02\x03\
+    \x12\x03H\x1a\x1b\n\x0b\n\x04\x04\x0c\x08\0\x12\x03I\x04,\n\x0c\n\x05\
+    \x04\x0c\x08\0\x01\x12\x03I\n\x16\n\x0b\n\x04\x04\x0c\x02\x03\x12\x03I\
+    \x19*\n\x0c\n\x05\x04\x0c\x02\x03\x05\x12\x03I\x19\x1f\n\x0c\n\x05\x04\
+    \x0c\x02\x03\x01\x12\x03I\x20%\n\x0c\n\x05\x04\x0c\x02\x03\x03\x12\x03I(\
+    )\n\x0b\n\x04\x04\x0c\x02\x04\x12\x03J\x04\x20\n\x0c\n\x05\x04\x0c\x02\
+    \x04\x04\x12\x03J\x04\x0c\n\x0c\n\x05\x04\x0c\x02\x04\x05\x12\x03J\r\x13\
+    \n\x0c\n\x05\x04\x0c\x02\x04\x01\x12\x03J\x14\x1b\n\x0c\n\x05\x04\x0c\
+    \x02\x04\x03\x12\x03J\x1e\x1f\n\n\n\x02\x04\r\x12\x04L\0P\x01\n\n\n\x03\
+    \x04\r\x01\x12\x03L\x08\x20\n\x0b\n\x04\x04\r\x02\0\x12\x03M\x04\x17\n\
+    \x0c\n\x05\x04\r\x02\0\x05\x12\x03M\x04\n\n\x0c\n\x05\x04\r\x02\0\x01\
+    \x12\x03M\x0b\x12\n\x0c\n\x05\x04\r\x02\0\x03\x12\x03M\x15\x16\n\x0b\n\
+    \x04\x04\r\x02\x01\x12\x03N\x04\x13\n\x0c\n\x05\x04\r\x02\x01\x05\x12\
+    \x03N\x04\n\n\x0c\n\x05\x04\r\x02\x01\x01\x12\x03N\x0b\x0e\n\x0c\n\x05\
+    \x04\r\x02\x01\x03\x12\x03N\x11\x12\n\x0b\n\x04\x04\r\x08\0\x12\x03O\x04\
+    8\n\x0c\n\x05\x04\r\x08\0\x01\x12\x03O\n\x19\n\x0b\n\x04\x04\r\x02\x02\
+    \x12\x03O\x1c6\n\x0c\n\x05\x04\r\x02\x02\x06\x12\x03O\x1c(\n\x0c\n\x05\
+    \x04\r\x02\x02\x01\x12\x03O)1\n\x0c\n\x05\x04\r\x02\x02\x03\x12\x03O45\n\
+    \n\n\x02\x04\x0e\x12\x04Q\0U\x01\n\n\n\x03\x04\x0e\x01\x12\x03Q\x08\x19\
+    \n\x0b\n\x04\x04\x0e\x08\0\x12\x03R\x04(\n\x0c\n\x05\x04\x0e\x08\0\x01\
+    \x12\x03R\n\x14\n\x0b\n\x04\x04\x0e\x02\0\x12\x03R\x17&\n\x0c\n\x05\x04\
+    \x0e\x02\0\x05\x12\x03R\x17\x1d\n\x0c\n\x05\x04\x0e\x02\0\x01\x12\x03R\
+    \x1e!\n\x0c\n\x05\x04\x0e\x02\0\x03\x12\x03R$%\n\x0b\n\x04\x04\x0e\x08\
+    \x01\x12\x03S\x04:\n\x0c\n\x05\x04\x0e\x08\x01\x01\x12\x03S\n\x1d\n\x0b\
+    \n\x04\x04\x0e\x02\x01\x12\x03S\x208\n\x0c\n\x05\x04\x0e\x02\x01\x05\x12\
+    \x03S\x20&\n\x0c\n\x05\x04\x0e\x02\x01\x01\x12\x03S'3\n\x0c\n\x05\x04\
+    \x0e\x02\x01\x03\x12\x03S67\n\x0b\n\x04\x04\x0e\x08\x02\x12\x03T\x04>\n\
+    \x0c\n\x05\x04\x0e\x08\x02\x01\x12\x03T\n\x1f\n\x0b\n\x04\x04\x0e\x02\
+    \x02\x12\x03T\"<\n\x0c\n\x05\x04\x0e\x02\x02\x05\x12\x03T\"(\n\x0c\n\x05\
+    \x04\x0e\x02\x02\x01\x12\x03T)7\n\x0c\n\x05\x04\x0e\x02\x02\x03\x12\x03T\
+    :;\n\n\n\x02\x04\x0f\x12\x04V\0Z\x01\n\n\n\x03\x04\x0f\x01\x12\x03V\x08\
+    \x18\n\x0b\n\x04\x04\x0f\x08\0\x12\x03W\x04(\n\x0c\n\x05\x04\x0f\x08\0\
+    \x01\x12\x03W\n\x14\n\x0b\n\x04\x04\x0f\x02\0\x12\x03W\x17&\n\x0c\n\x05\
+    \x04\x0f\x02\0\x05\x12\x03W\x17\x1d\n\x0c\n\x05\x04\x0f\x02\0\x01\x12\
+    \x03W\x1e!\n\x0c\n\x05\x04\x0f\x02\0\x03\x12\x03W$%\n\x0b\n\x04\x04\x0f\
+    \x08\x01\x12\x03X\x04:\n\x0c\n\x05\x04\x0f\x08\x01\x01\x12\x03X\n\x1d\n\
+    \x0b\n\x04\x04\x0f\x02\x01\x12\x03X\x208\n\x0c\n\x05\x04\x0f\x02\x01\x05\
+    \x12\x03X\x20&\n\x0c\n\x05\x04\x0f\x02\x01\x01\x12\x03X'3\n\x0c\n\x05\
+    \x04\x0f\x02\x01\x03\x12\x03X67\n\x0b\n\x04\x04\x0f\x08\x02\x12\x03Y\x04\
+    >\n\x0c\n\x05\x04\x0f\x08\x02\x01\x12\x03Y\n\x1f\n\x0b\n\x04\x04\x0f\x02\
+    \x02\x12\x03Y\"<\n\x0c\n\x05\x04\x0f\x02\x02\x05\x12\x03Y\"(\n\x0c\n\x05\
+    \x04\x0f\x02\x02\x01\x12\x03Y)7\n\x0c\n\x05\x04\x0f\x02\x02\x03\x12\x03Y\
+    :;\n\n\n\x02\x05\0\x12\x04[\0`\x01\n\n\n\x03\x05\0\x01\x12\x03[\x05\r\n\
+    \x0b\n\x04\x05\0\x02\0\x12\x03\\\x04\x0e\n\x0c\n\x05\x05\0\x02\0\x01\x12\
+    \x03\\\x04\t\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\\\x0c\r\n\x0b\n\x04\x05\
+    \0\x02\x01\x12\x03]\x04\x0c\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03]\x04\
+    \x07\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03]\n\x0b\n\x0b\n\x04\x05\0\x02\
+    \x02\x12\x03^\x04\r\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03^\x04\x08\n\x0c\
+    \n\x05\x05\0\x02\x02\x02\x12\x03^\x0b\x0c\n\x0b\n\x04\x05\0\x02\x03\x12\
+    \x03_\x04\x0e\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03_\x04\t\n\x0c\n\x05\
+    \x05\0\x02\x03\x02\x12\x03_\x0c\r\n\n\n\x02\x05\x01\x12\x04a\0f\x01\n\n\
+    \n\x03\x05\x01\x01\x12\x03a\x05\x15\n\x0b\n\x04\x05\x01\x02\0\x12\x03b\
+    \x04\r\n\x0c\n\x05\x05\x01\x02\0\x01\x12\x03b\x04\x08\n\x0c\n\x05\x05\
+    \x01\x02\0\x02\x12\x03b\x0b\x0c\n\x0b\n\x04\x05\x01\x02\x01\x12\x03c\x04\
+    \x0f\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03c\x04\n\n\x0c\n\x05\x05\x01\
+    \x02\x01\x02\x12\x03c\r\x0e\n\x0b\n\x04\x05\x01\x02\x02\x12\x03d\x04\r\n\
+    \x0c\n\x05\x05\x01\x02\x02\x01\x12\x03d\x04\x08\n\x0c\n\x05\x05\x01\x02\
+    \x02\x02\x12\x03d\x0b\x0c\n\x0b\n\x04\x05\x01\x02\x03\x12\x03e\x04\x0f\n\
+    \x0c\n\x05\x05\x01\x02\x03\x01\x12\x03e\x04\n\n\x0c\n\x05\x05\x01\x02\
+    \x03\x02\x12\x03e\r\x0eb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
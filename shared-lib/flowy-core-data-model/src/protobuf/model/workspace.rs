@@ -2265,6 +2265,408 @@ impl ::protobuf::reflect::ProtobufValue for UpdateWorkspaceParams {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkspaceEncryptionRequest {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub passphrase: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WorkspaceEncryptionRequest {
+    fn default() -> &'a WorkspaceEncryptionRequest {
+        <WorkspaceEncryptionRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WorkspaceEncryptionRequest {
+    pub fn new() -> WorkspaceEncryptionRequest {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string passphrase = 2;
+
+
+    pub fn get_passphrase(&self) -> &str {
+        &self.passphrase
+    }
+    pub fn clear_passphrase(&mut self) {
+        self.passphrase.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_passphrase(&mut self, v: ::std::string::String) {
+        self.passphrase = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_passphrase(&mut self) -> &mut ::std::string::String {
+        &mut self.passphrase
+    }
+
+    // Take field
+    pub fn take_passphrase(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.passphrase, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for WorkspaceEncryptionRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.passphrase)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.passphrase.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.passphrase);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.passphrase.is_empty() {
+            os.write_string(2, &self.passphrase)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkspaceEncryptionRequest {
+        WorkspaceEncryptionRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &WorkspaceEncryptionRequest| { &m.workspace_id },
+                |m: &mut WorkspaceEncryptionRequest| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "passphrase",
+                |m: &WorkspaceEncryptionRequest| { &m.passphrase },
+                |m: &mut WorkspaceEncryptionRequest| { &mut m.passphrase },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkspaceEncryptionRequest>(
+                "WorkspaceEncryptionRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorkspaceEncryptionRequest {
+        static instance: ::protobuf::rt::LazyV2<WorkspaceEncryptionRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorkspaceEncryptionRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for WorkspaceEncryptionRequest {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.passphrase.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkspaceEncryptionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkspaceEncryptionRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkspaceEncryptionParams {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub passphrase: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WorkspaceEncryptionParams {
+    fn default() -> &'a WorkspaceEncryptionParams {
+        <WorkspaceEncryptionParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WorkspaceEncryptionParams {
+    pub fn new() -> WorkspaceEncryptionParams {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string passphrase = 2;
+
+
+    pub fn get_passphrase(&self) -> &str {
+        &self.passphrase
+    }
+    pub fn clear_passphrase(&mut self) {
+        self.passphrase.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_passphrase(&mut self, v: ::std::string::String) {
+        self.passphrase = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_passphrase(&mut self) -> &mut ::std::string::String {
+        &mut self.passphrase
+    }
+
+    // Take field
+    pub fn take_passphrase(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.passphrase, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for WorkspaceEncryptionParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.passphrase)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.passphrase.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.passphrase);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.passphrase.is_empty() {
+            os.write_string(2, &self.passphrase)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkspaceEncryptionParams {
+        WorkspaceEncryptionParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &WorkspaceEncryptionParams| { &m.workspace_id },
+                |m: &mut WorkspaceEncryptionParams| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "passphrase",
+                |m: &WorkspaceEncryptionParams| { &m.passphrase },
+                |m: &mut WorkspaceEncryptionParams| { &mut m.passphrase },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkspaceEncryptionParams>(
+                "WorkspaceEncryptionParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorkspaceEncryptionParams {
+        static instance: ::protobuf::rt::LazyV2<WorkspaceEncryptionParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorkspaceEncryptionParams::new)
+    }
+}
+
+impl ::protobuf::Clear for WorkspaceEncryptionParams {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.passphrase.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkspaceEncryptionParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkspaceEncryptionParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x0fworkspace.proto\x1a\tapp.proto\x1a\nview.proto\"\xab\x01\n\tWorksp\
     ace\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12\x12\n\x04name\x18\x02\
@@ -2288,84 +2690,100 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x0bone_of_desc\"q\n\x15UpdateWorkspaceParams\x12\x0e\n\x02id\x18\x01\
     \x20\x01(\tR\x02id\x12\x14\n\x04name\x18\x02\x20\x01(\tH\0R\x04name\x12\
     \x14\n\x04desc\x18\x03\x20\x01(\tH\x01R\x04descB\r\n\x0bone_of_nameB\r\n\
-    \x0bone_of_descJ\xce\x0c\n\x06\x12\x04\0\0*\x01\n\x08\n\x01\x0c\x12\x03\
-    \0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\x13\n\t\n\x02\x03\x01\x12\x03\x02\
-    \0\x14\n\n\n\x02\x04\0\x12\x04\x04\0\x0b\x01\n\n\n\x03\x04\0\x01\x12\x03\
-    \x04\x08\x11\n\x0b\n\x04\x04\0\x02\0\x12\x03\x05\x04\x12\n\x0c\n\x05\x04\
-    \0\x02\0\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x05\
-    \x0b\r\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x05\x10\x11\n\x0b\n\x04\x04\0\
-    \x02\x01\x12\x03\x06\x04\x14\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x06\
-    \x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x06\x0b\x0f\n\x0c\n\x05\x04\
-    \0\x02\x01\x03\x12\x03\x06\x12\x13\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x07\
-    \x04\x14\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\
-    \0\x02\x02\x01\x12\x03\x07\x0b\x0f\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\
-    \x07\x12\x13\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x08\x04\x19\n\x0c\n\x05\
-    \x04\0\x02\x03\x06\x12\x03\x08\x04\x0f\n\x0c\n\x05\x04\0\x02\x03\x01\x12\
-    \x03\x08\x10\x14\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x08\x17\x18\n\x0b\
-    \n\x04\x04\0\x02\x04\x12\x03\t\x04\x1c\n\x0c\n\x05\x04\0\x02\x04\x05\x12\
-    \x03\t\x04\t\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\t\n\x17\n\x0c\n\x05\
-    \x04\0\x02\x04\x03\x12\x03\t\x1a\x1b\n\x0b\n\x04\x04\0\x02\x05\x12\x03\n\
-    \x04\x1a\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\n\x04\t\n\x0c\n\x05\x04\0\
-    \x02\x05\x01\x12\x03\n\n\x15\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\n\x18\
-    \x19\n\n\n\x02\x04\x01\x12\x04\x0c\0\x0e\x01\n\n\n\x03\x04\x01\x01\x12\
-    \x03\x0c\x08\x19\n\x0b\n\x04\x04\x01\x02\0\x12\x03\r\x04!\n\x0c\n\x05\
-    \x04\x01\x02\0\x04\x12\x03\r\x04\x0c\n\x0c\n\x05\x04\x01\x02\0\x06\x12\
-    \x03\r\r\x16\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\r\x17\x1c\n\x0c\n\x05\
-    \x04\x01\x02\0\x03\x12\x03\r\x1f\x20\n\n\n\x02\x04\x02\x12\x04\x0f\0\x12\
-    \x01\n\n\n\x03\x04\x02\x01\x12\x03\x0f\x08\x1e\n\x0b\n\x04\x04\x02\x02\0\
-    \x12\x03\x10\x04\x14\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x10\x04\n\n\
-    \x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x10\x0b\x0f\n\x0c\n\x05\x04\x02\x02\
-    \0\x03\x12\x03\x10\x12\x13\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x11\x04\
-    \x14\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x11\x04\n\n\x0c\n\x05\x04\
-    \x02\x02\x01\x01\x12\x03\x11\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\
-    \x03\x11\x12\x13\n\n\n\x02\x04\x03\x12\x04\x13\0\x16\x01\n\n\n\x03\x04\
-    \x03\x01\x12\x03\x13\x08\x1d\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x14\x04\
-    \x14\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x14\x04\n\n\x0c\n\x05\x04\x03\
-    \x02\0\x01\x12\x03\x14\x0b\x0f\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x14\
-    \x12\x13\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x15\x04\x14\n\x0c\n\x05\x04\
-    \x03\x02\x01\x05\x12\x03\x15\x04\n\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\
-    \x03\x15\x0b\x0f\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x15\x12\x13\n\n\
-    \n\x02\x04\x04\x12\x04\x17\0\x19\x01\n\n\n\x03\x04\x04\x01\x12\x03\x17\
-    \x08\x1d\n\x0b\n\x04\x04\x04\x08\0\x12\x03\x18\x04:\n\x0c\n\x05\x04\x04\
-    \x08\0\x01\x12\x03\x18\n\x1d\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x18\x208\
-    \n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\x18\x20&\n\x0c\n\x05\x04\x04\x02\
-    \0\x01\x12\x03\x18'3\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x1867\n\n\n\
-    \x02\x04\x05\x12\x04\x1a\0\x1c\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1a\x08\
-    \x13\n\x0b\n\x04\x04\x05\x08\0\x12\x03\x1b\x04:\n\x0c\n\x05\x04\x05\x08\
-    \0\x01\x12\x03\x1b\n\x1d\n\x0b\n\x04\x04\x05\x02\0\x12\x03\x1b\x208\n\
-    \x0c\n\x05\x04\x05\x02\0\x05\x12\x03\x1b\x20&\n\x0c\n\x05\x04\x05\x02\0\
-    \x01\x12\x03\x1b'3\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03\x1b67\n\n\n\x02\
-    \x04\x06\x12\x04\x1d\0\x20\x01\n\n\n\x03\x04\x06\x01\x12\x03\x1d\x08\x1f\
-    \n\x0b\n\x04\x04\x06\x02\0\x12\x03\x1e\x04\x1c\n\x0c\n\x05\x04\x06\x02\0\
-    \x06\x12\x03\x1e\x04\r\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03\x1e\x0e\x17\
-    \n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03\x1e\x1a\x1b\n\x0b\n\x04\x04\x06\
-    \x08\0\x12\x03\x1f\x046\n\x0c\n\x05\x04\x06\x08\0\x01\x12\x03\x1f\n\x1c\
-    \n\x0b\n\x04\x04\x06\x02\x01\x12\x03\x1f\x1f4\n\x0c\n\x05\x04\x06\x02\
-    \x01\x06\x12\x03\x1f\x1f#\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03\x1f$/\
-    \n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x03\x1f23\n\n\n\x02\x04\x07\x12\x04\
-    !\0%\x01\n\n\n\x03\x04\x07\x01\x12\x03!\x08\x1e\n\x0b\n\x04\x04\x07\x02\
-    \0\x12\x03\"\x04\x12\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03\"\x04\n\n\x0c\
-    \n\x05\x04\x07\x02\0\x01\x12\x03\"\x0b\r\n\x0c\n\x05\x04\x07\x02\0\x03\
-    \x12\x03\"\x10\x11\n\x0b\n\x04\x04\x07\x08\0\x12\x03#\x04*\n\x0c\n\x05\
-    \x04\x07\x08\0\x01\x12\x03#\n\x15\n\x0b\n\x04\x04\x07\x02\x01\x12\x03#\
-    \x18(\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x03#\x18\x1e\n\x0c\n\x05\x04\
-    \x07\x02\x01\x01\x12\x03#\x1f#\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x03#&\
-    '\n\x0b\n\x04\x04\x07\x08\x01\x12\x03$\x04*\n\x0c\n\x05\x04\x07\x08\x01\
-    \x01\x12\x03$\n\x15\n\x0b\n\x04\x04\x07\x02\x02\x12\x03$\x18(\n\x0c\n\
-    \x05\x04\x07\x02\x02\x05\x12\x03$\x18\x1e\n\x0c\n\x05\x04\x07\x02\x02\
-    \x01\x12\x03$\x1f#\n\x0c\n\x05\x04\x07\x02\x02\x03\x12\x03$&'\n\n\n\x02\
-    \x04\x08\x12\x04&\0*\x01\n\n\n\x03\x04\x08\x01\x12\x03&\x08\x1d\n\x0b\n\
-    \x04\x04\x08\x02\0\x12\x03'\x04\x12\n\x0c\n\x05\x04\x08\x02\0\x05\x12\
-    \x03'\x04\n\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x03'\x0b\r\n\x0c\n\x05\x04\
-    \x08\x02\0\x03\x12\x03'\x10\x11\n\x0b\n\x04\x04\x08\x08\0\x12\x03(\x04*\
-    \n\x0c\n\x05\x04\x08\x08\0\x01\x12\x03(\n\x15\n\x0b\n\x04\x04\x08\x02\
-    \x01\x12\x03(\x18(\n\x0c\n\x05\x04\x08\x02\x01\x05\x12\x03(\x18\x1e\n\
-    \x0c\n\x05\x04\x08\x02\x01\x01\x12\x03(\x1f#\n\x0c\n\x05\x04\x08\x02\x01\
-    \x03\x12\x03(&'\n\x0b\n\x04\x04\x08\x08\x01\x12\x03)\x04*\n\x0c\n\x05\
-    \x04\x08\x08\x01\x01\x12\x03)\n\x15\n\x0b\n\x04\x04\x08\x02\x02\x12\x03)\
-    \x18(\n\x0c\n\x05\x04\x08\x02\x02\x05\x12\x03)\x18\x1e\n\x0c\n\x05\x04\
-    \x08\x02\x02\x01\x12\x03)\x1f#\n\x0c\n\x05\x04\x08\x02\x02\x03\x12\x03)&\
-    'b\x06proto3\
+    \x0bone_of_desc\"_\n\x1aWorkspaceEncryptionRequest\x12!\n\x0cworkspace_i\
+    d\x18\x01\x20\x01(\tR\x0bworkspaceId\x12\x1e\n\npassphrase\x18\x02\x20\
+    \x01(\tR\npassphrase\"^\n\x19WorkspaceEncryptionParams\x12!\n\x0cworkspa\
+    ce_id\x18\x01\x20\x01(\tR\x0bworkspaceId\x12\x1e\n\npassphrase\x18\x02\
+    \x20\x01(\tR\npassphraseJ\xda\x0e\n\x06\x12\x04\0\02\x01\n\x08\n\x01\x0c\
+    \x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\x13\n\t\n\x02\x03\x01\x12\
+    \x03\x02\0\x14\n\n\n\x02\x04\0\x12\x04\x04\0\x0b\x01\n\n\n\x03\x04\0\x01\
+    \x12\x03\x04\x08\x11\n\x0b\n\x04\x04\0\x02\0\x12\x03\x05\x04\x12\n\x0c\n\
+    \x05\x04\0\x02\0\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\
+    \x03\x05\x0b\r\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x05\x10\x11\n\x0b\n\
+    \x04\x04\0\x02\x01\x12\x03\x06\x04\x14\n\x0c\n\x05\x04\0\x02\x01\x05\x12\
+    \x03\x06\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x06\x0b\x0f\n\x0c\n\
+    \x05\x04\0\x02\x01\x03\x12\x03\x06\x12\x13\n\x0b\n\x04\x04\0\x02\x02\x12\
+    \x03\x07\x04\x14\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x07\x04\n\n\x0c\n\
+    \x05\x04\0\x02\x02\x01\x12\x03\x07\x0b\x0f\n\x0c\n\x05\x04\0\x02\x02\x03\
+    \x12\x03\x07\x12\x13\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x08\x04\x19\n\x0c\
+    \n\x05\x04\0\x02\x03\x06\x12\x03\x08\x04\x0f\n\x0c\n\x05\x04\0\x02\x03\
+    \x01\x12\x03\x08\x10\x14\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x08\x17\
+    \x18\n\x0b\n\x04\x04\0\x02\x04\x12\x03\t\x04\x1c\n\x0c\n\x05\x04\0\x02\
+    \x04\x05\x12\x03\t\x04\t\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\t\n\x17\n\
+    \x0c\n\x05\x04\0\x02\x04\x03\x12\x03\t\x1a\x1b\n\x0b\n\x04\x04\0\x02\x05\
+    \x12\x03\n\x04\x1a\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\n\x04\t\n\x0c\n\
+    \x05\x04\0\x02\x05\x01\x12\x03\n\n\x15\n\x0c\n\x05\x04\0\x02\x05\x03\x12\
+    \x03\n\x18\x19\n\n\n\x02\x04\x01\x12\x04\x0c\0\x0e\x01\n\n\n\x03\x04\x01\
+    \x01\x12\x03\x0c\x08\x19\n\x0b\n\x04\x04\x01\x02\0\x12\x03\r\x04!\n\x0c\
+    \n\x05\x04\x01\x02\0\x04\x12\x03\r\x04\x0c\n\x0c\n\x05\x04\x01\x02\0\x06\
+    \x12\x03\r\r\x16\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\r\x17\x1c\n\x0c\n\
+    \x05\x04\x01\x02\0\x03\x12\x03\r\x1f\x20\n\n\n\x02\x04\x02\x12\x04\x0f\0\
+    \x12\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0f\x08\x1e\n\x0b\n\x04\x04\x02\
+    \x02\0\x12\x03\x10\x04\x14\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x10\x04\
+    \n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x10\x0b\x0f\n\x0c\n\x05\x04\x02\
+    \x02\0\x03\x12\x03\x10\x12\x13\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x11\
+    \x04\x14\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x11\x04\n\n\x0c\n\x05\
+    \x04\x02\x02\x01\x01\x12\x03\x11\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\
+    \x12\x03\x11\x12\x13\n\n\n\x02\x04\x03\x12\x04\x13\0\x16\x01\n\n\n\x03\
+    \x04\x03\x01\x12\x03\x13\x08\x1d\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x14\
+    \x04\x14\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x14\x04\n\n\x0c\n\x05\x04\
+    \x03\x02\0\x01\x12\x03\x14\x0b\x0f\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\
+    \x14\x12\x13\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x15\x04\x14\n\x0c\n\x05\
+    \x04\x03\x02\x01\x05\x12\x03\x15\x04\n\n\x0c\n\x05\x04\x03\x02\x01\x01\
+    \x12\x03\x15\x0b\x0f\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x15\x12\x13\
+    \n\n\n\x02\x04\x04\x12\x04\x17\0\x19\x01\n\n\n\x03\x04\x04\x01\x12\x03\
+    \x17\x08\x1d\n\x0b\n\x04\x04\x04\x08\0\x12\x03\x18\x04:\n\x0c\n\x05\x04\
+    \x04\x08\0\x01\x12\x03\x18\n\x1d\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x18\
+    \x208\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\x18\x20&\n\x0c\n\x05\x04\x04\
+    \x02\0\x01\x12\x03\x18'3\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x1867\n\n\
+    \n\x02\x04\x05\x12\x04\x1a\0\x1c\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1a\
+    \x08\x13\n\x0b\n\x04\x04\x05\x08\0\x12\x03\x1b\x04:\n\x0c\n\x05\x04\x05\
+    \x08\0\x01\x12\x03\x1b\n\x1d\n\x0b\n\x04\x04\x05\x02\0\x12\x03\x1b\x208\
+    \n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03\x1b\x20&\n\x0c\n\x05\x04\x05\x02\
+    \0\x01\x12\x03\x1b'3\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03\x1b67\n\n\n\
+    \x02\x04\x06\x12\x04\x1d\0\x20\x01\n\n\n\x03\x04\x06\x01\x12\x03\x1d\x08\
+    \x1f\n\x0b\n\x04\x04\x06\x02\0\x12\x03\x1e\x04\x1c\n\x0c\n\x05\x04\x06\
+    \x02\0\x06\x12\x03\x1e\x04\r\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03\x1e\
+    \x0e\x17\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03\x1e\x1a\x1b\n\x0b\n\x04\
+    \x04\x06\x08\0\x12\x03\x1f\x046\n\x0c\n\x05\x04\x06\x08\0\x01\x12\x03\
+    \x1f\n\x1c\n\x0b\n\x04\x04\x06\x02\x01\x12\x03\x1f\x1f4\n\x0c\n\x05\x04\
+    \x06\x02\x01\x06\x12\x03\x1f\x1f#\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\
+    \x03\x1f$/\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x03\x1f23\n\n\n\x02\x04\
+    \x07\x12\x04!\0%\x01\n\n\n\x03\x04\x07\x01\x12\x03!\x08\x1e\n\x0b\n\x04\
+    \x04\x07\x02\0\x12\x03\"\x04\x12\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03\"\
+    \x04\n\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03\"\x0b\r\n\x0c\n\x05\x04\x07\
+    \x02\0\x03\x12\x03\"\x10\x11\n\x0b\n\x04\x04\x07\x08\0\x12\x03#\x04*\n\
+    \x0c\n\x05\x04\x07\x08\0\x01\x12\x03#\n\x15\n\x0b\n\x04\x04\x07\x02\x01\
+    \x12\x03#\x18(\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x03#\x18\x1e\n\x0c\n\
+    \x05\x04\x07\x02\x01\x01\x12\x03#\x1f#\n\x0c\n\x05\x04\x07\x02\x01\x03\
+    \x12\x03#&'\n\x0b\n\x04\x04\x07\x08\x01\x12\x03$\x04*\n\x0c\n\x05\x04\
+    \x07\x08\x01\x01\x12\x03$\n\x15\n\x0b\n\x04\x04\x07\x02\x02\x12\x03$\x18\
+    (\n\x0c\n\x05\x04\x07\x02\x02\x05\x12\x03$\x18\x1e\n\x0c\n\x05\x04\x07\
+    \x02\x02\x01\x12\x03$\x1f#\n\x0c\n\x05\x04\x07\x02\x02\x03\x12\x03$&'\n\
+    \n\n\x02\x04\x08\x12\x04&\0*\x01\n\n\n\x03\x04\x08\x01\x12\x03&\x08\x1d\
+    \n\x0b\n\x04\x04\x08\x02\0\x12\x03'\x04\x12\n\x0c\n\x05\x04\x08\x02\0\
+    \x05\x12\x03'\x04\n\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x03'\x0b\r\n\x0c\n\
+    \x05\x04\x08\x02\0\x03\x12\x03'\x10\x11\n\x0b\n\x04\x04\x08\x08\0\x12\
+    \x03(\x04*\n\x0c\n\x05\x04\x08\x08\0\x01\x12\x03(\n\x15\n\x0b\n\x04\x04\
+    \x08\x02\x01\x12\x03(\x18(\n\x0c\n\x05\x04\x08\x02\x01\x05\x12\x03(\x18\
+    \x1e\n\x0c\n\x05\x04\x08\x02\x01\x01\x12\x03(\x1f#\n\x0c\n\x05\x04\x08\
+    \x02\x01\x03\x12\x03(&'\n\x0b\n\x04\x04\x08\x08\x01\x12\x03)\x04*\n\x0c\
+    \n\x05\x04\x08\x08\x01\x01\x12\x03)\n\x15\n\x0b\n\x04\x04\x08\x02\x02\
+    \x12\x03)\x18(\n\x0c\n\x05\x04\x08\x02\x02\x05\x12\x03)\x18\x1e\n\x0c\n\
+    \x05\x04\x08\x02\x02\x01\x12\x03)\x1f#\n\x0c\n\x05\x04\x08\x02\x02\x03\
+    \x12\x03)&'\n\n\n\x02\x04\t\x12\x04+\0.\x01\n\n\n\x03\x04\t\x01\x12\x03+\
+    \x08\"\n\x0b\n\x04\x04\t\x02\0\x12\x03,\x04\x1c\n\x0c\n\x05\x04\t\x02\0\
+    \x05\x12\x03,\x04\n\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03,\x0b\x17\n\x0c\n\
+    \x05\x04\t\x02\0\x03\x12\x03,\x1a\x1b\n\x0b\n\x04\x04\t\x02\x01\x12\x03-\
+    \x04\x1a\n\x0c\n\x05\x04\t\x02\x01\x05\x12\x03-\x04\n\n\x0c\n\x05\x04\t\
+    \x02\x01\x01\x12\x03-\x0b\x15\n\x0c\n\x05\x04\t\x02\x01\x03\x12\x03-\x18\
+    \x19\n\n\n\x02\x04\n\x12\x04/\02\x01\n\n\n\x03\x04\n\x01\x12\x03/\x08!\n\
+    \x0b\n\x04\x04\n\x02\0\x12\x030\x04\x1c\n\x0c\n\x05\x04\n\x02\0\x05\x12\
+    \x030\x04\n\n\x0c\n\x05\x04\n\x02\0\x01\x12\x030\x0b\x17\n\x0c\n\x05\x04\
+    \n\x02\0\x03\x12\x030\x1a\x1b\n\x0b\n\x04\x04\n\x02\x01\x12\x031\x04\x1a\
+    \n\x0c\n\x05\x04\n\x02\x01\x05\x12\x031\x04\n\n\x0c\n\x05\x04\n\x02\x01\
+    \x01\x12\x031\x0b\x15\n\x0c\n\x05\x04\n\x02\x01\x03\x12\x031\x18\x19b\
+    \x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
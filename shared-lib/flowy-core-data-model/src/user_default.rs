@@ -1,5 +1,5 @@
 use crate::entities::{
-    app::{App, RepeatedApp},
+    app::{App, ColorStyle, RepeatedApp},
     view::{RepeatedView, View, ViewType},
     workspace::Workspace,
 };
@@ -42,6 +42,7 @@ fn create_default_app(workspace_id: String, time: chrono::DateTime<Utc>) -> App
         version: 0,
         modified_time: time.timestamp(),
         create_time: time.timestamp(),
+        color_style: ColorStyle::default(),
     }
 }
 
@@ -61,5 +62,7 @@ fn create_default_view(app_id: String, time: chrono::DateTime<Utc>) -> View {
         belongings: Default::default(),
         modified_time: time.timestamp(),
         create_time: time.timestamp(),
+        tags: vec![],
+        properties: vec![],
     }
 }
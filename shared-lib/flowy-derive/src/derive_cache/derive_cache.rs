@@ -21,6 +21,13 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "FlowyError"
         | "SubscribeObject"
         | "NetworkState"
+        | "SyncObjectId"
+        | "ObjectSyncStatus"
+        | "ObjectMergeSummary"
+        | "SyncStatus"
+        | "LanSyncSetting"
+        | "LanSyncHostRequest"
+        | "LanSyncPeerRequest"
         | "SignInRequest"
         | "SignInParams"
         | "SignInResponse"
@@ -35,6 +42,9 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "ServerRevisionWSData"
         | "NewDocumentUser"
         | "FolderInfo"
+        | "GridInfo"
+        | "BoardInfo"
+        | "CommentInfo"
         | "Revision"
         | "RepeatedRevision"
         | "RevId"
@@ -54,6 +64,8 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "CurrentWorkspaceSetting"
         | "UpdateWorkspaceRequest"
         | "UpdateWorkspaceParams"
+        | "WorkspaceEncryptionRequest"
+        | "WorkspaceEncryptionParams"
         | "ExportRequest"
         | "ExportData"
         | "App"
@@ -67,6 +79,7 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "UpdateAppParams"
         | "Trash"
         | "RepeatedTrash"
+        | "TrashRetentionPolicy"
         | "RepeatedTrashId"
         | "TrashId"
         | "View"
@@ -78,6 +91,85 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "RepeatedViewId"
         | "UpdateViewRequest"
         | "UpdateViewParams"
+        | "ViewProperty"
+        | "UpdateViewTagsRequest"
+        | "UpdateViewTagsParams"
+        | "UpdateViewPropertyRequest"
+        | "UpdateViewPropertyParams"
+        | "ViewFilterRequest"
+        | "ViewFilterParams"
+        | "Template"
+        | "RepeatedTemplate"
+        | "CreateTemplateRequest"
+        | "CreateTemplateParams"
+        | "QueryTemplateRequest"
+        | "TemplateId"
+        | "CreateViewFromTemplateRequest"
+        | "CreateViewFromTemplateParams"
+        | "Grid"
+        | "Field"
+        | "FieldOptions"
+        | "Row"
+        | "Cell"
+        | "QueryGridRequest"
+        | "GridId"
+        | "CreateFieldRequest"
+        | "CreateFieldParams"
+        | "UpdateFieldRequest"
+        | "UpdateFieldParams"
+        | "QueryFieldRequest"
+        | "FieldIdentifier"
+        | "QueryRowRequest"
+        | "RowIdentifier"
+        | "UpdateRowRequest"
+        | "UpdateRowParams"
+        | "UpdateCellRequest"
+        | "UpdateCellParams"
+        | "QueryCellRequest"
+        | "CellIdentifier"
+        | "Board"
+        | "BoardProperty"
+        | "BoardColumn"
+        | "Card"
+        | "CardProperty"
+        | "QueryBoardRequest"
+        | "BoardId"
+        | "UpdateBoardPropertyRequest"
+        | "UpdateBoardPropertyParams"
+        | "CreateCardRequest"
+        | "CreateCardParams"
+        | "UpdateCardRequest"
+        | "UpdateCardParams"
+        | "MoveCardRequest"
+        | "MoveCardParams"
+        | "QueryCardRequest"
+        | "CardIdentifier"
+        | "CommentThread"
+        | "Comment"
+        | "RepeatedCommentThread"
+        | "QueryCommentsRequest"
+        | "QueryCommentsParams"
+        | "CreateCommentRequest"
+        | "CreateCommentParams"
+        | "ReplyCommentRequest"
+        | "ReplyCommentParams"
+        | "ResolveCommentRequest"
+        | "ResolveCommentParams"
+        | "HighlightSpan"
+        | "RepeatedHighlightSpan"
+        | "HighlightCodeRequest"
+        | "OutlineItem"
+        | "RepeatedOutlineItem"
+        | "JumpToSectionRequest"
+        | "DocumentSection"
+        | "DocumentStatistics"
+        | "DocumentStatisticsRequest"
+        | "OpenDocumentsUsage"
+        | "DocumentIntegrityRequest"
+        | "DocumentIntegrityReport"
+        | "DocumentMergeBackup"
+        | "RepeatedDocumentMergeBackup"
+        | "DocumentMergeBackupId"
         | "WSError"
         | "WebSocketRawMessage"
         => TypeCategory::Protobuf,
@@ -86,6 +178,9 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "FFIStatusCode"
         | "NetworkEvent"
         | "NetworkType"
+        | "SyncConnectState"
+        | "LanSyncRole"
+        | "NetworkNotification"
         | "UserEvent"
         | "UserNotification"
         | "ClientRevisionWSDataType"
@@ -95,6 +190,10 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "ExportType"
         | "TrashType"
         | "ViewType"
+        | "ViewPropertyType"
+        | "TemplateType"
+        | "FieldType"
+        | "HighlightKind"
         | "ErrorCode"
         | "WSChannel"
         => TypeCategory::Enum,
@@ -199,6 +199,19 @@ pub fn attributes_except_header(op: &RichTextOperation) -> RichTextAttributes {
     attributes
 }
 
+pub const TOGGLE_EXPANDED: &str = "expanded";
+pub const TOGGLE_COLLAPSED: &str = "collapsed";
+
+// Returns the language of the code block, or None if the attributes are not a code block or the
+// code block has no language.
+pub fn code_block_language(attributes: &RichTextAttributes) -> Option<&str> {
+    let value = attributes.get(&RichTextAttributeKey::CodeBlock)?.0.as_deref()?;
+    match value {
+        "true" => None,
+        language => Some(language),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct RichTextAttribute {
     pub key: RichTextAttributeKey,
@@ -218,6 +231,8 @@ impl RichTextAttribute {
     inline_attribute!(Size, usize);
     inline_attribute!(Background, String);
     inline_attribute!(InlineCode, bool);
+    inline_attribute!(Comment, String);
+    inline_attribute!(Formula, bool);
 
     // block
     block_attribute!(Header, usize);
@@ -226,6 +241,8 @@ impl RichTextAttribute {
     block_attribute!(List, &str);
     block_attribute!(CodeBlock, bool);
     block_attribute!(BlockQuote, bool);
+    block_attribute!(TableCell, String);
+    block_attribute!(MathBlock, bool);
 
     // ignore
     ignore_attribute!(Width, usize);
@@ -237,6 +254,35 @@ impl RichTextAttribute {
     list_attribute!(Checked, "checked");
     list_attribute!(UnChecked, "unchecked");
 
+    // The language of the code block is saved as the value of the code_block, and the code block
+    // without the language is saved as true.
+    pub fn CodeBlockLanguage(language: &str) -> Self {
+        let language = language.trim();
+        if language.is_empty() {
+            return RichTextAttribute::CodeBlock(true);
+        }
+
+        Self {
+            key: RichTextAttributeKey::CodeBlock,
+            value: language.to_lowercase().into(),
+            scope: AttributeScope::Block,
+        }
+    }
+
+    // The lines after the toggle whose indent is greater than the indent of the toggle are the
+    // children of the toggle, they are hidden if the toggle is collapsed.
+    pub fn Toggle(collapsed: bool) -> Self {
+        let value = match collapsed {
+            true => TOGGLE_COLLAPSED,
+            false => TOGGLE_EXPANDED,
+        };
+        Self {
+            key: RichTextAttributeKey::Toggle,
+            value: value.into(),
+            scope: AttributeScope::Block,
+        }
+    }
+
     pub fn to_json(&self) -> String {
         match serde_json::to_string(self) {
             Ok(json) => json,
@@ -303,6 +349,16 @@ pub enum RichTextAttributeKey {
     Height,
     #[serde(rename = "header")]
     Header,
+    #[serde(rename = "table_cell")]
+    TableCell,
+    #[serde(rename = "comment")]
+    Comment,
+    #[serde(rename = "formula")]
+    Formula,
+    #[serde(rename = "math_block")]
+    MathBlock,
+    #[serde(rename = "toggle")]
+    Toggle,
 }
 
 // pub trait AttributeValueData<'a>: Serialize + Deserialize<'a> {}
@@ -372,6 +428,9 @@ lazy_static! {
         RichTextAttributeKey::CodeBlock,
         RichTextAttributeKey::List,
         RichTextAttributeKey::BlockQuote,
+        RichTextAttributeKey::TableCell,
+        RichTextAttributeKey::MathBlock,
+        RichTextAttributeKey::Toggle,
     ]);
     static ref INLINE_KEYS: HashSet<RichTextAttributeKey> = HashSet::from_iter(vec![
         RichTextAttributeKey::Bold,
@@ -384,6 +443,8 @@ lazy_static! {
         RichTextAttributeKey::Size,
         RichTextAttributeKey::Background,
         RichTextAttributeKey::InlineCode,
+        RichTextAttributeKey::Comment,
+        RichTextAttributeKey::Formula,
     ]);
     static ref INGORE_KEYS: HashSet<RichTextAttributeKey> =
         HashSet::from_iter(vec![RichTextAttributeKey::Width, RichTextAttributeKey::Height,]);
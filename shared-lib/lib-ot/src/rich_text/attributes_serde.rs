@@ -51,13 +51,20 @@ where
             | RichTextAttributeKey::Italic
             | RichTextAttributeKey::Underline
             | RichTextAttributeKey::StrikeThrough
-            | RichTextAttributeKey::CodeBlock
             | RichTextAttributeKey::InlineCode
+            | RichTextAttributeKey::Formula
+            | RichTextAttributeKey::MathBlock
             | RichTextAttributeKey::BlockQuote => match &v.parse::<bool>() {
                 Ok(value) => map_serializer.serialize_entry(&key, value)?,
                 Err(e) => log::error!("Serial {:?} failed. {:?}", &key, e),
             },
 
+            // The code block is true or the language of the code block.
+            RichTextAttributeKey::CodeBlock => match &v.parse::<bool>() {
+                Ok(value) => map_serializer.serialize_entry(&key, value)?,
+                Err(_) => map_serializer.serialize_entry(&key, v)?,
+            },
+
             RichTextAttributeKey::Font
             | RichTextAttributeKey::Size
             | RichTextAttributeKey::Header
@@ -72,7 +79,10 @@ where
             | RichTextAttributeKey::Color
             | RichTextAttributeKey::Background
             | RichTextAttributeKey::Align
-            | RichTextAttributeKey::List => {
+            | RichTextAttributeKey::List
+            | RichTextAttributeKey::TableCell
+            | RichTextAttributeKey::Comment
+            | RichTextAttributeKey::Toggle => {
                 map_serializer.serialize_entry(&key, v)?;
             }
         }
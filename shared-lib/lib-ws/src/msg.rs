@@ -16,6 +16,9 @@ pub struct WebSocketRawMessage {
 pub enum WSChannel {
     Document = 0,
     Folder = 1,
+    Grid = 2,
+    Board = 3,
+    Comment = 4,
 }
 
 impl std::default::Default for WSChannel {
@@ -29,6 +32,9 @@ impl ToString for WSChannel {
         match self {
             WSChannel::Document => "0".to_string(),
             WSChannel::Folder => "1".to_string(),
+            WSChannel::Grid => "2".to_string(),
+            WSChannel::Board => "3".to_string(),
+            WSChannel::Comment => "4".to_string(),
         }
     }
 }
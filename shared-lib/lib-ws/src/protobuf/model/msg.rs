@@ -217,6 +217,9 @@ impl ::protobuf::reflect::ProtobufValue for WebSocketRawMessage {
 pub enum WSChannel {
     Document = 0,
     Folder = 1,
+    Grid = 2,
+    Board = 3,
+    Comment = 4,
 }
 
 impl ::protobuf::ProtobufEnum for WSChannel {
@@ -228,6 +231,9 @@ impl ::protobuf::ProtobufEnum for WSChannel {
         match value {
             0 => ::std::option::Option::Some(WSChannel::Document),
             1 => ::std::option::Option::Some(WSChannel::Folder),
+            2 => ::std::option::Option::Some(WSChannel::Grid),
+            3 => ::std::option::Option::Some(WSChannel::Board),
+            4 => ::std::option::Option::Some(WSChannel::Comment),
             _ => ::std::option::Option::None
         }
     }
@@ -236,6 +242,9 @@ impl ::protobuf::ProtobufEnum for WSChannel {
         static values: &'static [WSChannel] = &[
             WSChannel::Document,
             WSChannel::Folder,
+            WSChannel::Grid,
+            WSChannel::Board,
+            WSChannel::Comment,
         ];
         values
     }
@@ -266,20 +275,27 @@ impl ::protobuf::reflect::ProtobufValue for WSChannel {
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\tmsg.proto\"O\n\x13WebSocketRawMessage\x12$\n\x07channel\x18\x01\x20\
     \x01(\x0e2\n.WSChannelR\x07channel\x12\x12\n\x04data\x18\x02\x20\x01(\
-    \x0cR\x04data*%\n\tWSChannel\x12\x0c\n\x08Document\x10\0\x12\n\n\x06Fold\
-    er\x10\x01J\x82\x02\n\x06\x12\x04\0\0\t\x01\n\x08\n\x01\x0c\x12\x03\0\0\
-    \x12\n\n\n\x02\x04\0\x12\x04\x02\0\x05\x01\n\n\n\x03\x04\0\x01\x12\x03\
-    \x02\x08\x1b\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x1a\n\x0c\n\x05\x04\
-    \0\x02\0\x06\x12\x03\x03\x04\r\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\
-    \x0e\x15\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x18\x19\n\x0b\n\x04\x04\
-    \0\x02\x01\x12\x03\x04\x04\x13\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\
-    \x04\t\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\n\x0e\n\x0c\n\x05\x04\0\
-    \x02\x01\x03\x12\x03\x04\x11\x12\n\n\n\x02\x05\0\x12\x04\x06\0\t\x01\n\n\
-    \n\x03\x05\0\x01\x12\x03\x06\x05\x0e\n\x0b\n\x04\x05\0\x02\0\x12\x03\x07\
-    \x04\x11\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x07\x04\x0c\n\x0c\n\x05\x05\
-    \0\x02\0\x02\x12\x03\x07\x0f\x10\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x08\
-    \x04\x0f\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x08\x04\n\n\x0c\n\x05\x05\
-    \0\x02\x01\x02\x12\x03\x08\r\x0eb\x06proto3\
+    \x0cR\x04data*G\n\tWSChannel\x12\x0c\n\x08Document\x10\0\x12\n\n\x06Fold\
+    er\x10\x01\x12\x08\n\x04Grid\x10\x02\x12\t\n\x05Board\x10\x03\x12\x0b\n\
+    \x07Comment\x10\x04J\xfd\x02\n\x06\x12\x04\0\0\x0c\x01\n\x08\n\x01\x0c\
+    \x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x05\x01\n\n\n\x03\x04\0\
+    \x01\x12\x03\x02\x08\x1b\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x1a\n\
+    \x0c\n\x05\x04\0\x02\0\x06\x12\x03\x03\x04\r\n\x0c\n\x05\x04\0\x02\0\x01\
+    \x12\x03\x03\x0e\x15\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x18\x19\n\
+    \x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x13\n\x0c\n\x05\x04\0\x02\x01\
+    \x05\x12\x03\x04\x04\t\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\n\x0e\n\
+    \x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x11\x12\n\n\n\x02\x05\0\x12\x04\
+    \x06\0\x0c\x01\n\n\n\x03\x05\0\x01\x12\x03\x06\x05\x0e\n\x0b\n\x04\x05\0\
+    \x02\0\x12\x03\x07\x04\x11\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x07\x04\
+    \x0c\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x07\x0f\x10\n\x0b\n\x04\x05\0\
+    \x02\x01\x12\x03\x08\x04\x0f\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x08\
+    \x04\n\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x08\r\x0e\n\x0b\n\x04\x05\0\
+    \x02\x02\x12\x03\t\x04\r\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\t\x04\x08\
+    \n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\t\x0b\x0c\n\x0b\n\x04\x05\0\x02\
+    \x03\x12\x03\n\x04\x0e\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\n\x04\t\n\
+    \x0c\n\x05\x05\0\x02\x03\x02\x12\x03\n\x0c\r\n\x0b\n\x04\x05\0\x02\x04\
+    \x12\x03\x0b\x04\x10\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x0b\x04\x0b\n\
+    \x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x0b\x0e\x0fb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -40,6 +40,8 @@ pub struct WSController {
     addr: Arc<RwLock<Option<String>>>,
     sender: Arc<RwLock<Option<Arc<WSSender>>>>,
     conn_state_notify: Arc<RwLock<WSConnectStateNotifier>>,
+    // The text message that is sent first after each connection.
+    handshake: Option<String>,
 }
 
 impl std::fmt::Display for WSController {
@@ -55,6 +57,7 @@ impl std::default::Default for WSController {
             addr: Arc::new(RwLock::new(None)),
             sender: Arc::new(RwLock::new(None)),
             conn_state_notify: Arc::new(RwLock::new(WSConnectStateNotifier::default())),
+            handshake: None,
         }
     }
 }
@@ -64,6 +67,15 @@ impl WSController {
         WSController::default()
     }
 
+    // The server may verify the handshake before handling the other messages, e.g. the secret that
+    // shouldn't be the part of the url.
+    pub fn with_handshake(handshake: String) -> Self {
+        WSController {
+            handshake: Some(handshake),
+            ..WSController::default()
+        }
+    }
+
     pub fn add_ws_message_receiver(&self, handler: Arc<dyn WSMessageReceiver>) -> Result<(), WSError> {
         let source = handler.source();
         if self.handlers.contains_key(&source) {
@@ -105,6 +117,7 @@ impl WSController {
         let action = WSConnectAction {
             addr,
             handlers: self.handlers.clone(),
+            handshake: self.handshake.clone(),
         };
         let retry = Retry::spawn(strategy, action);
         conn_state_notify.update_state(WSConnectState::Connecting);
@@ -277,6 +290,7 @@ impl WSSender {
 struct WSConnectAction {
     addr: String,
     handlers: Handlers,
+    handshake: Option<String>,
 }
 
 impl Action for WSConnectAction {
@@ -287,7 +301,8 @@ impl Action for WSConnectAction {
     fn run(&mut self) -> Self::Future {
         let addr = self.addr.clone();
         let handlers = self.handlers.clone();
-        Box::pin(WSConnectActionFut::new(addr, handlers))
+        let handshake = self.handshake.clone();
+        Box::pin(WSConnectActionFut::new(addr, handlers, handshake))
     }
 }
 
@@ -307,7 +322,7 @@ struct WSConnectActionFut {
 }
 
 impl WSConnectActionFut {
-    fn new(addr: String, handlers: Handlers) -> Self {
+    fn new(addr: String, handlers: Handlers, handshake: Option<String>) -> Self {
         //                Stream                             User
         //               ┌───────────────┐                 ┌──────────────┐
         // ┌──────┐      │  ┌─────────┐  │    ┌────────┐   │  ┌────────┐  │
@@ -320,6 +335,10 @@ impl WSConnectActionFut {
         //               └───────────────┘                 └──────────────┘
         let (msg_tx, msg_rx) = futures_channel::mpsc::unbounded();
         let (ws_tx, ws_rx) = futures_channel::mpsc::unbounded();
+        // The messages in the ws_rx are written after connecting, so the handshake goes first.
+        if let Some(handshake) = handshake {
+            let _ = ws_tx.unbounded_send(Message::Text(handshake));
+        }
         let sender = WSSender(ws_tx);
         let handlers_fut = WSHandlerFuture::new(handlers, msg_rx);
         let conn = WSConnectionFuture::new(msg_tx, ws_rx, addr.clone());